@@ -0,0 +1,101 @@
+//! Benchmarks for detecting merged PRs in the target branch history.
+//!
+//! `check_pr_merged_in_history` runs once per PR against the full commit
+//! history during migration analysis, so its cost grows with both the number
+//! of PRs and the length of the history.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use mergers::git::{CommitHistory, check_pr_merged_in_history};
+use std::collections::HashSet;
+
+/// Generates a synthetic target branch history and the PRs to look up.
+///
+/// # Arguments
+///
+/// * `num_commits` - Number of commits in the history
+/// * `num_prs` - Number of PRs to check
+/// * `merged_rate` - Fraction of the PRs merged into the history (0.0 to 1.0)
+fn generate_history(
+    num_commits: usize,
+    num_prs: usize,
+    merged_rate: f64,
+) -> (CommitHistory, Vec<(i32, String)>) {
+    let prs: Vec<(i32, String)> = (0..num_prs)
+        .map(|i| {
+            let id = 10_000 + i as i32;
+            (
+                id,
+                format!("Fix handling of edge case {} in module {}", i, i % 17),
+            )
+        })
+        .collect();
+    let merged = (num_prs as f64 * merged_rate) as usize;
+
+    let mut commit_messages: Vec<String> = (0..num_commits)
+        .map(|i| {
+            format!(
+                "Update dependency {} to version 1.{}.{}",
+                i % 41,
+                i % 13,
+                i % 7
+            )
+        })
+        .collect();
+
+    // Spread the merged PRs evenly through the history
+    let stride = (num_commits / merged.max(1)).max(1);
+    for (k, (id, title)) in prs.iter().take(merged).enumerate() {
+        if let Some(message) = commit_messages.get_mut(k * stride) {
+            *message = format!("Merged PR {}: {}", id, title);
+        }
+    }
+
+    let history = CommitHistory {
+        commit_hashes: (0..num_commits)
+            .map(|i| format!("{:040x}", i))
+            .collect::<HashSet<_>>(),
+        commit_messages,
+        commit_bodies: (0..num_commits / 20)
+            .map(|i| format!("(cherry picked from commit {:040x})", i * 7))
+            .collect(),
+    };
+    (history, prs)
+}
+
+/// Benchmark checking every PR against histories of growing length.
+fn bench_history_matching(c: &mut Criterion) {
+    let mut group = c.benchmark_group("history_matching");
+    group.sample_size(20);
+
+    // Scenarios: (name, num_commits, num_prs, merged_rate)
+    let scenarios = [
+        ("small", 1_000, 50, 0.5),
+        ("medium", 10_000, 200, 0.5),
+        ("large", 50_000, 300, 0.5),
+        // No PR is merged, so every strategy scans the whole history
+        ("none_merged", 5_000, 50, 0.0),
+    ];
+
+    for (name, num_commits, num_prs, merged_rate) in scenarios {
+        let (history, prs) = generate_history(num_commits, num_prs, merged_rate);
+
+        group.throughput(Throughput::Elements(num_prs as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("check_pr_merged", name),
+            &(&history, &prs),
+            |b, (history, prs)| {
+                b.iter(|| {
+                    prs.iter()
+                        .filter(|(id, title)| check_pr_merged_in_history(*id, title, history))
+                        .count()
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_history_matching);
+criterion_main!(benches);
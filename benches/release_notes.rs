@@ -0,0 +1,121 @@
+//! Benchmarks for release notes generation.
+//!
+//! Release notes are built from every PR in a release together with its work
+//! items: titles are parsed for conventional commit prefixes, work items shared
+//! by several PRs are merged into one entry, and the entries are formatted.
+
+use chrono::NaiveDate;
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use mergers::api::UrlBuilder;
+use mergers::models::{PullRequestWithWorkItems, ReleaseNotesOutputFormat};
+use mergers::release_notes::{
+    ReleaseNotesLocale, build_entries_from_prs, format_output, generate_from_prs,
+};
+use serde_json::json;
+
+/// Generates synthetic PRs with linked work items.
+///
+/// # Arguments
+///
+/// * `num_prs` - Number of PRs to generate
+/// * `work_items_per_pr` - Number of work items linked to each PR
+/// * `shared_rate` - Fraction of work items also linked to the previous PR (0.0 to 1.0)
+fn generate_prs(
+    num_prs: usize,
+    work_items_per_pr: usize,
+    shared_rate: f64,
+) -> Vec<PullRequestWithWorkItems> {
+    const PREFIXES: [&str; 5] = ["feat: ", "fix: ", "refactor: ", "Feature: ", ""];
+    let shared = (work_items_per_pr as f64 * shared_rate) as usize;
+
+    (0..num_prs)
+        .map(|i| {
+            let pr = serde_json::from_value(json!({
+                "pullRequestId": 1_000 + i,
+                "title": format!("{}Improve handling of case {}", PREFIXES[i % PREFIXES.len()], i),
+                "closedDate": "2024-01-15T10:30:00Z",
+                "createdBy": { "displayName": format!("Developer {}", i % 12) },
+                "lastMergeCommit": { "commitId": format!("{:040x}", i) },
+                "labels": null,
+            }))
+            .expect("valid pull request");
+
+            let work_items = (0..work_items_per_pr)
+                .map(|j| {
+                    // Shared work items reuse the previous PR's ids
+                    let owner = if j < shared { i.saturating_sub(1) } else { i };
+                    let id = 50_000 + owner * work_items_per_pr + j;
+                    serde_json::from_value(json!({
+                        "id": id,
+                        "fields": {
+                            "System.Title": format!("Work item {} for release", id),
+                            "System.State": "Resolved",
+                            "System.WorkItemType": "Task",
+                        },
+                    }))
+                    .expect("valid work item")
+                })
+                .collect();
+
+            PullRequestWithWorkItems {
+                pr,
+                work_items,
+                selected: true,
+            }
+        })
+        .collect()
+}
+
+/// Benchmark building release note entries from PRs and work items.
+fn bench_build_entries(c: &mut Criterion) {
+    let mut group = c.benchmark_group("release_notes_build");
+    let urls = UrlBuilder::new("org", "project", "repo");
+
+    // Scenarios: (name, num_prs, work_items_per_pr, shared_rate)
+    let scenarios = [
+        ("small", 30, 2, 0.0),
+        ("medium", 150, 3, 0.3),
+        ("large", 500, 4, 0.5),
+    ];
+
+    for (name, num_prs, work_items_per_pr, shared_rate) in scenarios {
+        let prs = generate_prs(num_prs, work_items_per_pr, shared_rate);
+
+        group.throughput(Throughput::Elements(num_prs as u64));
+
+        group.bench_with_input(BenchmarkId::new("entries", name), &prs, |b, prs| {
+            b.iter(|| build_entries_from_prs(prs, &urls));
+        });
+    }
+
+    group.finish();
+}
+
+/// Benchmark formatting entries in every output format.
+fn bench_format(c: &mut Criterion) {
+    let mut group = c.benchmark_group("release_notes_format");
+    let urls = UrlBuilder::new("org", "project", "repo");
+    let prs = generate_prs(300, 3, 0.3);
+    let entries = build_entries_from_prs(&prs, &urls);
+    let locale = ReleaseNotesLocale::default();
+
+    for (name, format) in [
+        ("markdown", ReleaseNotesOutputFormat::Markdown),
+        ("json", ReleaseNotesOutputFormat::Json),
+        ("plain", ReleaseNotesOutputFormat::Plain),
+    ] {
+        group.bench_with_input(BenchmarkId::new("grouped", name), &entries, |b, entries| {
+            b.iter(|| format_output(entries, None, format, true, true, &locale));
+        });
+    }
+
+    let release_date = NaiveDate::from_ymd_opt(2024, 1, 15).expect("valid date");
+    group.bench_function("document", |b| {
+        b.iter(|| generate_from_prs("v1.0.0", &prs, &urls, release_date, &locale));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_build_entries, bench_format);
+criterion_main!(benches);
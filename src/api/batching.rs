@@ -0,0 +1,282 @@
+//! Fetching work items by id in batches.
+//!
+//! Loading a release fetches the work items linked to every PR. Requesting
+//! them PR by PR costs one request per PR and fetches work items shared by
+//! several PRs more than once. [`WorkItemBatches`] instead takes the ids of
+//! all PRs at once, drops duplicates and requests them in batches of up to
+//! 200 (the `workitemsbatch` API's limit), several batches at a time.
+//!
+//! Batches adapt to failures: a batch the API rejects (400 or 404, e.g.
+//! because one of its ids does not exist) is split in half and both halves
+//! are requested again, down to single ids, so an id that cannot be fetched
+//! only loses itself. Any other failure, such as throttling, an expired PAT,
+//! a server error or a lost connection, would fail every half the same way,
+//! so it is returned right away after the client's own retries.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::api::batching::WorkItemBatches;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> anyhow::Result<()> {
+//! let batches = WorkItemBatches {
+//!     batch_size: 2,
+//!     ..WorkItemBatches::default()
+//! };
+//! let mut fetched = batches
+//!     .run(&[3, 1, 2, 3], |ids| async move {
+//!         Ok(ids.iter().map(|id| id * 10).collect())
+//!     })
+//!     .await?;
+//! fetched.sort();
+//! assert_eq!(fetched, [10, 20, 30]);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashSet;
+use std::future::Future;
+
+use anyhow::Result;
+use futures::stream::{self, StreamExt};
+
+use crate::error::ApiError;
+
+/// Most work items the `workitemsbatch` API returns per request.
+pub const MAX_WORK_ITEM_BATCH_SIZE: usize = 200;
+
+/// Settings for fetching work items by id in batches.
+#[derive(Debug, Clone)]
+pub struct WorkItemBatches {
+    /// Ids requested per batch, at most [`MAX_WORK_ITEM_BATCH_SIZE`].
+    pub batch_size: usize,
+    /// Most batch requests in flight at once.
+    pub max_in_flight: usize,
+}
+
+impl Default for WorkItemBatches {
+    fn default() -> Self {
+        Self {
+            batch_size: MAX_WORK_ITEM_BATCH_SIZE,
+            max_in_flight: 4,
+        }
+    }
+}
+
+impl WorkItemBatches {
+    /// Fetches the items for `ids`, each id once.
+    ///
+    /// `fetch_batch` is called with the ids of one batch and returns the
+    /// items found for them, in any order. Ids that fail on their own are
+    /// logged and left out; an error is returned if a batch failed for a
+    /// reason other than being rejected, or if no id could be fetched at all.
+    pub async fn run<T, F, Fut>(&self, ids: &[i32], fetch_batch: F) -> Result<Vec<T>>
+    where
+        F: Fn(Vec<i32>) -> Fut,
+        Fut: Future<Output = Result<Vec<T>>>,
+    {
+        let mut seen = HashSet::new();
+        let unique: Vec<i32> = ids.iter().copied().filter(|id| seen.insert(*id)).collect();
+        let batch_size = self.batch_size.clamp(1, MAX_WORK_ITEM_BATCH_SIZE);
+        let mut pending: Vec<Vec<i32>> = unique.chunks(batch_size).map(<[i32]>::to_vec).collect();
+
+        let mut items = Vec::new();
+        let mut failed = Vec::new();
+        let mut last_error = None;
+        while !pending.is_empty() {
+            let results: Vec<(Vec<i32>, Result<Vec<T>>)> = stream::iter(pending)
+                .map(|batch| {
+                    let request = fetch_batch(batch.clone());
+                    async move { (batch, request.await) }
+                })
+                .buffer_unordered(self.max_in_flight.max(1))
+                .collect()
+                .await;
+
+            pending = Vec::new();
+            for (batch, result) in results {
+                match result {
+                    Ok(batch_items) => items.extend(batch_items),
+                    Err(e) if !is_rejected_batch(&e) => return Err(e),
+                    Err(e) if batch.len() > 1 => {
+                        tracing::debug!(
+                            "Work item batch of {} failed, splitting it: {:#}",
+                            batch.len(),
+                            e
+                        );
+                        let (first, second) = batch.split_at(batch.len() / 2);
+                        pending.push(first.to_vec());
+                        pending.push(second.to_vec());
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to fetch work item {}: {:#}", batch[0], e);
+                        failed.extend(batch);
+                        last_error = Some(e);
+                    }
+                }
+            }
+        }
+
+        if let Some(e) = last_error
+            && failed.len() == unique.len()
+        {
+            return Err(e);
+        }
+        Ok(items)
+    }
+}
+
+/// Returns `true` if the API rejected the request itself (400 or 404),
+/// which splitting the batch can narrow down to the offending ids.
+fn is_rejected_batch(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        let status = match cause.downcast_ref::<ApiError>() {
+            Some(ApiError::NotFound { .. }) => Some(404),
+            Some(ApiError::RequestFailed { status, .. }) => Some(*status),
+            Some(_) => None,
+            None => cause
+                .downcast_ref::<azure_core::Error>()
+                .and_then(|e| e.http_status())
+                .map(u16::from),
+        };
+        matches!(status, Some(400 | 404))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// # Deduplicated Batches
+    ///
+    /// Tests that ids are fetched once, in batches of the configured size.
+    ///
+    /// ## Test Scenario
+    /// - Fetches 450 ids, 50 of them twice, in batches of 200
+    ///
+    /// ## Expected Outcome
+    /// - Three batches of 200, 200 and 50 ids are requested
+    /// - Every id's item is returned once
+    #[tokio::test]
+    async fn test_deduplicated_batches() {
+        let ids: Vec<i32> = (0..450).chain(0..50).collect();
+        let requested = Mutex::new(Vec::new());
+        let batches = WorkItemBatches::default();
+
+        let mut items = batches
+            .run(&ids, |batch| {
+                requested.lock().unwrap().push(batch.len());
+                async move { Ok(batch) }
+            })
+            .await
+            .unwrap();
+
+        let mut sizes = requested.into_inner().unwrap();
+        sizes.sort();
+        assert_eq!(sizes, [50, 200, 200]);
+        items.sort();
+        assert_eq!(items, (0..450).collect::<Vec<_>>());
+    }
+
+    /// # Splitting Failed Batches
+    ///
+    /// Tests that a failing batch is narrowed down to the failing id.
+    ///
+    /// ## Test Scenario
+    /// - Fetches ids 1 to 8 in one batch; any batch containing 5 is rejected
+    ///   with a 404
+    /// - Fetches only id 5
+    ///
+    /// ## Expected Outcome
+    /// - Every id except 5 is returned
+    /// - Fetching only failing ids returns the error
+    #[tokio::test]
+    async fn test_splitting_failed_batches() {
+        let fetch = |batch: Vec<i32>| async move {
+            if batch.contains(&5) {
+                return Err(ApiError::NotFound {
+                    resource: "work item 5".to_string(),
+                }
+                .into());
+            }
+            Ok(batch)
+        };
+        let batches = WorkItemBatches {
+            batch_size: 8,
+            ..WorkItemBatches::default()
+        };
+
+        let mut items = batches
+            .run(&(1..=8).collect::<Vec<_>>(), fetch)
+            .await
+            .unwrap();
+        items.sort();
+        assert_eq!(items, [1, 2, 3, 4, 6, 7, 8]);
+
+        assert!(batches.run(&[5], fetch).await.is_err());
+    }
+
+    /// # Throttled Batches
+    ///
+    /// Tests that throttled batches are not split.
+    ///
+    /// ## Test Scenario
+    /// - Fetches a batch whose request is throttled
+    ///
+    /// ## Expected Outcome
+    /// - The throttling error is returned after a single request
+    #[tokio::test]
+    async fn test_throttled_batches() {
+        let requests = Mutex::new(0);
+        let result: Result<Vec<i32>> = WorkItemBatches::default()
+            .run(&[1, 2, 3], |_| {
+                *requests.lock().unwrap() += 1;
+                async {
+                    Err(ApiError::RateLimited {
+                        retry_after_seconds: 0,
+                    }
+                    .into())
+                }
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(*requests.lock().unwrap(), 1);
+    }
+
+    /// # Unauthorized Batches
+    ///
+    /// Tests that batches failing for a reason other than a rejected request
+    /// are not split.
+    ///
+    /// ## Test Scenario
+    /// - Fetches a batch whose request always fails with a 401 response
+    ///
+    /// ## Expected Outcome
+    /// - The error is returned after a single request
+    #[tokio::test]
+    async fn test_unauthorized_batches() {
+        let requests = Mutex::new(0);
+        let result: Result<Vec<i32>> = WorkItemBatches::default()
+            .run(&[1, 2, 3], |_| {
+                *requests.lock().unwrap() += 1;
+                async {
+                    Err(azure_core::Error::with_message(
+                        azure_core::error::ErrorKind::HttpResponse {
+                            status: azure_core::http::StatusCode::Unauthorized,
+                            error_code: None,
+                            raw_response: None,
+                        },
+                        "TF400813: not authorized",
+                    )
+                    .into())
+                }
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(*requests.lock().unwrap(), 1);
+    }
+}
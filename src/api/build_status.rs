@@ -0,0 +1,410 @@
+//! Build and branch policy status of pull requests.
+//!
+//! Before a PR is cherry-picked, mergers can check that its last merge commit
+//! built successfully and that the PR passed its branch policies. Builds are
+//! the runs of `refs/pull/{id}/merge` for that commit; policies are the PR's
+//! blocking policy evaluations (reviewers, linked work items, build
+//! validation, ...).
+
+use super::client::{AzureDevOpsClient, EndpointClass};
+use anyhow::{Context, Result};
+use azure_devops_rust_api::policy::models::{
+    PolicyEvaluationRecord, policy_evaluation_record::Status as EvaluationStatus,
+};
+use serde::Deserialize;
+
+/// Outcome of a single build or policy check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckState {
+    /// The build succeeded or the policy is approved (or not applicable).
+    Succeeded,
+    /// The build did not succeed or the policy is rejected.
+    Failed,
+    /// The build or policy evaluation has not finished.
+    Pending,
+}
+
+/// A build or policy check of a pull request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckOutcome {
+    /// Build definition or policy name.
+    pub name: String,
+    /// Outcome of the check.
+    pub state: CheckState,
+}
+
+impl CheckOutcome {
+    fn new(name: impl Into<String>, state: CheckState) -> Self {
+        Self {
+            name: name.into(),
+            state,
+        }
+    }
+}
+
+/// Build and branch policy status of one pull request.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrChecks {
+    /// Latest build of each definition that built the last merge commit.
+    pub builds: Vec<CheckOutcome>,
+    /// Blocking policy evaluations of the PR.
+    pub policies: Vec<CheckOutcome>,
+}
+
+impl PrChecks {
+    /// Describes every check that did not pass, e.g. `build 'CI' failed`.
+    ///
+    /// A merge commit without any build fails: there is nothing showing
+    /// that it built.
+    pub fn failures(&self) -> Vec<String> {
+        let mut failures = Vec::new();
+        if self.builds.is_empty() {
+            failures.push("no build of the merge commit".to_string());
+        }
+        let kinds = [("build", &self.builds), ("policy", &self.policies)];
+        for (kind, outcomes) in kinds {
+            for outcome in outcomes.iter() {
+                let verb = match outcome.state {
+                    CheckState::Succeeded => continue,
+                    CheckState::Failed => "failed",
+                    CheckState::Pending => "pending",
+                };
+                failures.push(format!("{} '{}' {}", kind, outcome.name, verb));
+            }
+        }
+        failures
+    }
+
+    /// Returns true if every build succeeded and every blocking policy passed.
+    pub fn passed(&self) -> bool {
+        self.failures().is_empty()
+    }
+}
+
+/// A build as listed by the builds API, keeping only what the check needs.
+#[derive(Debug, Deserialize)]
+struct BuildRecord {
+    id: i32,
+    #[serde(default)]
+    definition: Option<BuildDefinitionRecord>,
+    #[serde(default)]
+    status: Option<String>,
+    #[serde(default)]
+    result: Option<String>,
+    #[serde(rename = "sourceVersion", default)]
+    source_version: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BuildDefinitionRecord {
+    id: i32,
+    #[serde(default)]
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BuildList {
+    #[serde(default)]
+    value: Vec<BuildRecord>,
+}
+
+impl BuildRecord {
+    fn state(&self) -> CheckState {
+        match (self.status.as_deref(), self.result.as_deref()) {
+            (Some("completed"), Some("succeeded")) => CheckState::Succeeded,
+            (Some("completed"), _) => CheckState::Failed,
+            _ => CheckState::Pending,
+        }
+    }
+}
+
+/// Keeps the latest build of each definition that built `commit_id`.
+fn latest_builds(builds: Vec<BuildRecord>, commit_id: &str) -> Vec<CheckOutcome> {
+    let mut latest: Vec<BuildRecord> = Vec::new();
+    for build in builds
+        .into_iter()
+        .filter(|build| build.source_version.as_deref() == Some(commit_id))
+    {
+        let definition = build.definition.as_ref().map(|d| d.id);
+        match latest
+            .iter_mut()
+            .find(|kept| kept.definition.as_ref().map(|d| d.id) == definition)
+        {
+            Some(kept) if kept.id < build.id => *kept = build,
+            Some(_) => {}
+            None => latest.push(build),
+        }
+    }
+    latest
+        .iter()
+        .map(|build| {
+            let name = build
+                .definition
+                .as_ref()
+                .and_then(|d| d.name.clone())
+                .unwrap_or_else(|| format!("#{}", build.id));
+            CheckOutcome::new(name, build.state())
+        })
+        .collect()
+}
+
+/// Maps a policy evaluation to a check, or `None` for policies that do not
+/// block completion.
+fn policy_outcome(record: &PolicyEvaluationRecord) -> Option<CheckOutcome> {
+    let configuration = record.configuration.as_ref();
+    if configuration.is_some_and(|c| c.is_enabled == Some(false) || c.is_blocking == Some(false)) {
+        return None;
+    }
+    let state = match record.status.as_ref()? {
+        EvaluationStatus::Approved | EvaluationStatus::NotApplicable => CheckState::Succeeded,
+        EvaluationStatus::Rejected | EvaluationStatus::Broken => CheckState::Failed,
+        EvaluationStatus::Queued | EvaluationStatus::Running => CheckState::Pending,
+    };
+    // Build validation policies carry the pipeline name in their settings
+    let name = configuration
+        .and_then(|c| c.settings.as_ref())
+        .and_then(|settings| settings.get("displayName"))
+        .and_then(|name| name.as_str())
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .or_else(|| {
+            configuration
+                .and_then(|c| {
+                    c.versioned_policy_configuration_ref
+                        .policy_configuration_ref
+                        .type_
+                        .as_ref()
+                })
+                .map(|policy_type| policy_type.display_name.clone())
+        })
+        .unwrap_or_else(|| "unnamed policy".to_string());
+    Some(CheckOutcome::new(name, state))
+}
+
+impl AzureDevOpsClient {
+    /// Fetches the ID of the configured project.
+    ///
+    /// Policy evaluations are looked up by an artifact ID containing it.
+    pub async fn fetch_project_id(&self) -> Result<String> {
+        let repo = self
+            .git(EndpointClass::Read)
+            .repositories_client()
+            .get_repository(self.organization(), self.repository(), self.project())
+            .await
+            .context("Failed to fetch repository details")?;
+        repo.project
+            .id
+            .context("Repository details are missing the project ID")
+    }
+
+    /// Fetches the latest build of each definition that built the PR's last
+    /// merge commit.
+    pub async fn fetch_merge_commit_builds(
+        &self,
+        pr_id: i32,
+        commit_id: &str,
+    ) -> Result<Vec<CheckOutcome>> {
+        let response = self
+            .build(EndpointClass::Read)
+            .builds_client()
+            .list(self.organization(), self.project())
+            .branch_name(format!("refs/pull/{}/merge", pr_id))
+            .send()
+            .await
+            .with_context(|| format!("Failed to list builds of PR #{}", pr_id))?;
+        let body = response.into_raw_response().into_body();
+        let builds: BuildList = serde_json::from_slice(&body)
+            .with_context(|| format!("Failed to decode builds of PR #{}", pr_id))?;
+        Ok(latest_builds(builds.value, commit_id))
+    }
+
+    /// Fetches the blocking branch policy evaluations of a PR.
+    pub async fn fetch_policy_evaluations(
+        &self,
+        pr_id: i32,
+        project_id: &str,
+    ) -> Result<Vec<CheckOutcome>> {
+        let artifact_id = format!("vstfs:///CodeReview/CodeReviewId/{}/{}", project_id, pr_id);
+        let evaluations = self
+            .policy(EndpointClass::Read)
+            .evaluations_client()
+            .list(self.organization(), self.project(), artifact_id)
+            .await
+            .with_context(|| format!("Failed to list policy evaluations of PR #{}", pr_id))?;
+        Ok(evaluations
+            .value
+            .iter()
+            .filter_map(policy_outcome)
+            .collect())
+    }
+
+    /// Fetches the build and branch policy status of a PR.
+    ///
+    /// `project_id` comes from [`fetch_project_id`](Self::fetch_project_id).
+    pub async fn fetch_pr_checks(
+        &self,
+        pr_id: i32,
+        commit_id: &str,
+        project_id: &str,
+    ) -> Result<PrChecks> {
+        let (builds, policies) = futures::try_join!(
+            self.fetch_merge_commit_builds(pr_id, commit_id),
+            self.fetch_policy_evaluations(pr_id, project_id),
+        )?;
+        Ok(PrChecks { builds, policies })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(id: i32, definition: i32, status: &str, result: Option<&str>) -> BuildRecord {
+        BuildRecord {
+            id,
+            definition: Some(BuildDefinitionRecord {
+                id: definition,
+                name: Some(format!("Pipeline {}", definition)),
+            }),
+            status: Some(status.to_string()),
+            result: result.map(str::to_string),
+            source_version: Some("abc123".to_string()),
+        }
+    }
+
+    /// # Latest Builds Of A Merge Commit
+    ///
+    /// Tests reducing the builds of a PR merge ref to one per definition.
+    ///
+    /// ## Test Scenario
+    /// - Lists a failed build retried successfully, a running build of a
+    ///   second definition and a build of an older merge commit
+    ///
+    /// ## Expected Outcome
+    /// - Only the latest build of each definition for the commit is kept
+    /// - Its status and result map to succeeded, failed or pending
+    #[test]
+    fn test_latest_builds() {
+        let mut stale = build(5, 3, "completed", Some("failed"));
+        stale.source_version = Some("old".to_string());
+        let builds = vec![
+            build(10, 1, "completed", Some("failed")),
+            build(12, 1, "completed", Some("succeeded")),
+            build(11, 2, "inProgress", None),
+            stale,
+        ];
+
+        let outcomes = latest_builds(builds, "abc123");
+        assert_eq!(
+            outcomes,
+            vec![
+                CheckOutcome::new("Pipeline 1", CheckState::Succeeded),
+                CheckOutcome::new("Pipeline 2", CheckState::Pending),
+            ]
+        );
+
+        let outcomes = latest_builds(
+            vec![build(1, 1, "completed", Some("partiallySucceeded"))],
+            "abc123",
+        );
+        assert_eq!(outcomes[0].state, CheckState::Failed);
+    }
+
+    /// # Policy Evaluation Outcomes
+    ///
+    /// Tests mapping policy evaluation records to checks.
+    ///
+    /// ## Test Scenario
+    /// - Decodes a rejected build validation policy, an approved reviewer
+    ///   policy and a non-blocking policy
+    ///
+    /// ## Expected Outcome
+    /// - Build validation is named after its pipeline, other policies after
+    ///   their type
+    /// - Non-blocking policies are left out
+    #[test]
+    fn test_policy_outcomes() {
+        let records: Vec<PolicyEvaluationRecord> = serde_json::from_value(serde_json::json!([
+            {
+                "status": "rejected",
+                "configuration": {
+                    "isBlocking": true,
+                    "isEnabled": true,
+                    "type": {"id": "0609b952", "displayName": "Build", "url": "https://dev.azure.com/org/_apis/policy/types/0609b952"},
+                    "settings": {"displayName": "PR validation"}
+                }
+            },
+            {
+                "status": "approved",
+                "configuration": {
+                    "isBlocking": true,
+                    "isEnabled": true,
+                    "type": {"id": "fa4e907d", "displayName": "Minimum number of reviewers", "url": "https://dev.azure.com/org/_apis/policy/types/fa4e907d"}
+                }
+            },
+            {
+                "status": "rejected",
+                "configuration": {
+                    "isBlocking": false,
+                    "isEnabled": true,
+                    "type": {"id": "40e92b44", "displayName": "Work item linking", "url": "https://dev.azure.com/org/_apis/policy/types/40e92b44"}
+                }
+            }
+        ]))
+        .unwrap();
+
+        let outcomes: Vec<_> = records.iter().filter_map(policy_outcome).collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                CheckOutcome::new("PR validation", CheckState::Failed),
+                CheckOutcome::new("Minimum number of reviewers", CheckState::Succeeded),
+            ]
+        );
+    }
+
+    /// # Check Failures
+    ///
+    /// Tests describing the checks a PR did not pass.
+    ///
+    /// ## Test Scenario
+    /// - Builds checks with a failed build and a pending policy, passing
+    ///   checks, and checks without any build
+    ///
+    /// ## Expected Outcome
+    /// - Each failing or pending check is described
+    /// - Passing checks have no failures
+    /// - A merge commit without builds fails
+    #[test]
+    fn test_pr_checks_failures() {
+        let checks = PrChecks {
+            builds: vec![
+                CheckOutcome::new("CI", CheckState::Failed),
+                CheckOutcome::new("Lint", CheckState::Succeeded),
+            ],
+            policies: vec![CheckOutcome::new(
+                "Comment requirements",
+                CheckState::Pending,
+            )],
+        };
+        assert_eq!(
+            checks.failures(),
+            vec![
+                "build 'CI' failed".to_string(),
+                "policy 'Comment requirements' pending".to_string(),
+            ]
+        );
+        assert!(!checks.passed());
+
+        let checks = PrChecks {
+            builds: vec![CheckOutcome::new("CI", CheckState::Succeeded)],
+            policies: Vec::new(),
+        };
+        assert!(checks.passed());
+
+        assert_eq!(
+            PrChecks::default().failures(),
+            vec!["no build of the merge commit".to_string()]
+        );
+    }
+}
@@ -3,21 +3,136 @@
 //! This module provides a client for interacting with Azure DevOps APIs,
 //! specifically for managing pull requests and work items in merge workflows.
 
-use super::mappers::extract_work_item_id;
+use super::batching::WorkItemBatches;
+use super::mappers::{extract_work_item_id, window_date};
+use super::paging::{PagePipeline, PageProgress};
+use super::retry::{RequestBudget, RetryPolicy, RetrySettings};
+use super::schema::{SchemaDiagnostics, SchemaWarning};
+use super::version::{ApiVersionPolicy, DEFAULT_API_VERSION};
+use crate::cache::RepoCache;
 use crate::models::{
-    MergeCommit, PullRequest, PullRequestWithWorkItems, RepoDetails, WorkItem, WorkItemHistory,
+    MergeCommit, PullRequest, PullRequestWithWorkItems, RepoDetails, SinceField, WorkItem,
+    WorkItemHistory,
 };
-use crate::utils::parse_since_date;
+use crate::profiling;
+use crate::utils::{Clock, IdGen, parse_since_date};
 use anyhow::{Context, Result};
-use azure_devops_rust_api::{git, wit};
+use azure_devops_rust_api::{artifacts, build, git, policy, wiki, wit};
 use chrono::{DateTime, Utc};
 use futures::stream::{self, StreamExt};
 use secrecy::{ExposeSecret, SecretString};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 /// Type alias for state color cache: state_name -> (r, g, b)
 type StateColorCache =
     std::sync::Arc<std::sync::RwLock<std::collections::HashMap<String, (u8, u8, u8)>>>;
 
+/// Tracing target of the mutation audit log.
+///
+/// Every label change, work item update and wiki page publish is logged under
+/// this target with the identity that performed it, whether or not it succeeded.
+pub const AUDIT_LOG_TARGET: &str = "mergers::audit";
+
+/// Pull request fields read when listing pull requests.
+const PULL_REQUEST_FIELDS: &[&str] = &["pullRequestId", "title", "createdBy", "closedDate"];
+
+/// Work item fields read when fetching work items.
+const WORK_ITEM_FIELDS: &[&str] = &["id", "fields"];
+
+/// Work item fields requested for the work items linked to a PR.
+const LINKED_WORK_ITEM_FIELDS: &str = "System.Title,System.State,System.WorkItemType,System.AssignedTo,System.IterationPath,System.Description,Microsoft.VSTS.TCM.ReproSteps";
+
+/// Class of an Azure DevOps endpoint, which decides the credential used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndpointClass {
+    /// Requests that only read data (pull requests, work items, history).
+    Read,
+    /// Requests that change data (pull request labels, work item states, wiki pages).
+    Write,
+}
+
+/// A PAT together with the API clients authenticated with it.
+#[derive(Clone)]
+struct Identity {
+    /// Audit label, e.g. `write-pat:1a2b3c4d`.
+    label: String,
+    git_client: git::Client,
+    wit_client: wit::Client,
+    artifacts_client: artifacts::Client,
+    wiki_client: wiki::Client,
+    build_client: build::Client,
+    policy_client: policy::Client,
+}
+
+impl Identity {
+    /// Builds the clients for one endpoint class.
+    ///
+    /// The generated clients' own retry is disabled in favor of a
+    /// [`RetryPolicy`] honoring `Retry-After` and drawing from `budget`.
+    fn new(
+        name: &str,
+        pat: &SecretString,
+        version_policy: &ApiVersionPolicy,
+        class: EndpointClass,
+        budget: &RequestBudget,
+    ) -> Self {
+        let ado_credential =
+            azure_devops_rust_api::Credential::Pat(pat.expose_secret().to_string());
+        let policies: Vec<std::sync::Arc<dyn azure_core::http::policies::Policy>> = vec![
+            std::sync::Arc::new(version_policy.clone()),
+            std::sync::Arc::new(RetryPolicy::new(
+                RetrySettings::for_class(class),
+                budget.clone(),
+            )),
+        ];
+
+        let git_client = git::ClientBuilder::new(ado_credential.clone())
+            .retry(azure_core::http::RetryOptions::none())
+            .per_call_policies(policies.clone())
+            .build();
+        let wit_client = wit::ClientBuilder::new(ado_credential.clone())
+            .retry(azure_core::http::RetryOptions::none())
+            .per_call_policies(policies.clone())
+            .build();
+        let artifacts_client = artifacts::ClientBuilder::new(ado_credential.clone())
+            .retry(azure_core::http::RetryOptions::none())
+            .per_call_policies(policies.clone())
+            .build();
+        let wiki_client = wiki::ClientBuilder::new(ado_credential.clone())
+            .retry(azure_core::http::RetryOptions::none())
+            .per_call_policies(policies.clone())
+            .build();
+        let build_client = build::ClientBuilder::new(ado_credential.clone())
+            .retry(azure_core::http::RetryOptions::none())
+            .per_call_policies(policies.clone())
+            .build();
+        let policy_client = policy::ClientBuilder::new(ado_credential)
+            .retry(azure_core::http::RetryOptions::none())
+            .per_call_policies(policies)
+            .build();
+
+        Self {
+            label: format!("{}:{}", name, pat_fingerprint(pat)),
+            git_client,
+            wit_client,
+            artifacts_client,
+            wiki_client,
+            build_client,
+            policy_client,
+        }
+    }
+}
+
+/// Returns the first 8 hex digits of the SHA-256 of a PAT.
+///
+/// This identifies which token was used in logs without revealing it.
+fn pat_fingerprint(pat: &SecretString) -> String {
+    let digest = Sha256::digest(pat.expose_secret().as_bytes());
+    digest[..4].iter().map(|b| format!("{:02x}", b)).collect()
+}
+
 /// Azure DevOps API client for pull request and work item management.
 ///
 /// This client uses the official azure_devops_rust_api crate for API interactions,
@@ -48,10 +163,29 @@ pub struct AzureDevOpsClient {
     organization: String,
     project: String,
     repository: String,
-    git_client: git::Client,
-    wit_client: wit::Client,
+    /// Credential used for read requests.
+    read_identity: Identity,
+    /// Credential used for mutating requests; the read credential unless a
+    /// separate write PAT is configured.
+    write_identity: Identity,
     /// Cache of work item state colors: state_name -> (r, g, b)
     state_color_cache: StateColorCache,
+    /// Policy applying the API version override and collecting deprecation notices.
+    version_policy: ApiVersionPolicy,
+    /// Additional work item fields to request (e.g. a release gate field).
+    extra_work_item_fields: Vec<String>,
+    /// Pull request date compared against `since` when listing pull requests.
+    since_field: SinceField,
+    /// Identity ids sent as `searchCriteria.creatorId` and
+    /// `searchCriteria.reviewerId` when listing pull requests.
+    creator_id: Option<String>,
+    reviewer_id: Option<String>,
+    /// Schema mismatches found while decoding list responses.
+    schema: SchemaDiagnostics,
+    /// Limit on requests in flight, shared by both identities.
+    request_budget: RequestBudget,
+    /// On-disk cache of PR merge commits and linked work items.
+    cache: Option<Arc<RepoCache>>,
 }
 
 impl AzureDevOpsClient {
@@ -87,24 +221,232 @@ impl AzureDevOpsClient {
         repository: String,
         pat: SecretString,
     ) -> Result<Self> {
-        let ado_credential =
-            azure_devops_rust_api::Credential::Pat(pat.expose_secret().to_string());
+        Self::new_with_api_version(organization, project, repository, pat, None)
+    }
 
-        let git_client = git::ClientBuilder::new(ado_credential.clone()).build();
-        let wit_client = wit::ClientBuilder::new(ado_credential).build();
+    /// Creates a new Azure DevOps API client targeting a specific API version.
+    ///
+    /// When `api_version` is `None`, each endpoint uses the version the generated
+    /// API client was built for. Otherwise, the `api-version` query parameter of
+    /// every request is rewritten (see [`resolve_api_version`](super::version::resolve_api_version)),
+    /// which allows talking to older Azure DevOps Server releases.
+    pub fn new_with_api_version(
+        organization: String,
+        project: String,
+        repository: String,
+        pat: SecretString,
+        api_version: Option<String>,
+    ) -> Result<Self> {
+        let version_policy = ApiVersionPolicy::new(api_version);
+        let request_budget = RequestBudget::default();
+        let read_identity = Identity::new(
+            "pat",
+            &pat,
+            &version_policy,
+            EndpointClass::Read,
+            &request_budget,
+        );
+        let write_identity = Identity::new(
+            "pat",
+            &pat,
+            &version_policy,
+            EndpointClass::Write,
+            &request_budget,
+        );
 
         Ok(Self {
             organization,
             project,
             repository,
-            git_client,
-            wit_client,
+            read_identity,
+            write_identity,
             state_color_cache: std::sync::Arc::new(std::sync::RwLock::new(
                 std::collections::HashMap::new(),
             )),
+            version_policy,
+            extra_work_item_fields: Vec::new(),
+            since_field: SinceField::default(),
+            creator_id: None,
+            reviewer_id: None,
+            schema: SchemaDiagnostics::default(),
+            request_budget,
+            cache: None,
         })
     }
 
+    /// Selects the pull request date compared against `since`.
+    ///
+    /// See [`fetch_pull_requests_with_progress`](Self::fetch_pull_requests_with_progress)
+    /// for how each field is listed.
+    pub fn with_since_field(mut self, since_field: SinceField) -> Self {
+        self.since_field = since_field;
+        self
+    }
+
+    /// Lists only pull requests created or reviewed by the given identity ids.
+    ///
+    /// Azure DevOps filters by identity id, not by name (see
+    /// [`PrFilter`](crate::core::operations::PrFilter) for matching names).
+    pub fn with_pull_request_identities(
+        mut self,
+        creator_id: Option<String>,
+        reviewer_id: Option<String>,
+    ) -> Self {
+        self.creator_id = creator_id;
+        self.reviewer_id = reviewer_id;
+        self
+    }
+
+    /// Requests additional work item fields alongside the default ones.
+    ///
+    /// The values end up in [`WorkItemFields::custom_fields`](crate::models::WorkItemFields::custom_fields).
+    pub fn with_extra_work_item_fields(mut self, fields: Vec<String>) -> Self {
+        self.extra_work_item_fields = fields;
+        self
+    }
+
+    /// Sends mutating requests with a separate PAT.
+    ///
+    /// Reads keep using the PAT the client was created with, so a broad
+    /// read-only token and a restricted write token can be combined. See
+    /// [`EndpointClass`] for which requests count as mutations.
+    pub fn with_write_pat(mut self, write_pat: SecretString) -> Self {
+        self.write_identity = Identity::new(
+            "write-pat",
+            &write_pat,
+            &self.version_policy,
+            EndpointClass::Write,
+            &self.request_budget,
+        );
+        self
+    }
+
+    /// Limits the number of Azure DevOps requests in flight at once.
+    ///
+    /// The limit is shared by every clone of the client and by reads and
+    /// writes alike, so it bounds the total load regardless of how many tasks
+    /// issue requests. Defaults to
+    /// [`DEFAULT_MAX_CONCURRENT_REQUESTS`](super::retry::DEFAULT_MAX_CONCURRENT_REQUESTS).
+    pub fn with_max_concurrent_requests(self, limit: usize) -> Self {
+        self.request_budget.set_limit(limit);
+        self
+    }
+
+    /// Reads the time from `clock` instead of the system clock, e.g. for the
+    /// names of responses stored by `--dump-raw-response`.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.schema.set_clock(clock);
+        self
+    }
+
+    /// Takes the ids in the names of stored responses from `ids`.
+    pub fn with_id_gen(mut self, ids: Arc<dyn IdGen>) -> Self {
+        self.schema.set_id_gen(ids);
+        self
+    }
+
+    /// Caches PR merge commits and linked work items in `cache`.
+    ///
+    /// See [`crate::cache`] for what is cached and for how long.
+    pub fn with_cache(mut self, cache: Arc<RepoCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Caches PR merge commits and linked work items on disk, unless the
+    /// cache is disabled (see [`RepoCache::open`]).
+    pub fn with_disk_cache(mut self) -> Self {
+        self.cache = RepoCache::open(&self.organization, &self.project, &self.repository);
+        self
+    }
+
+    /// Returns the on-disk cache, if enabled.
+    pub fn cache(&self) -> Option<&Arc<RepoCache>> {
+        self.cache.as_ref()
+    }
+
+    /// Drops a work item from the on-disk cache after it was written to,
+    /// so the next load fetches it again.
+    fn evict_cached_work_item(&self, work_item_id: i32) {
+        if let Some(cache) = &self.cache {
+            cache.evict_work_item(work_item_id);
+            cache.save();
+        }
+    }
+
+    /// Writes the changes to the on-disk cache, if enabled.
+    pub fn save_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.save();
+        }
+    }
+
+    /// Returns the number of Azure DevOps requests allowed in flight at once.
+    pub fn max_concurrent_requests(&self) -> usize {
+        self.request_budget.limit()
+    }
+
+    /// Returns the audit label of the credential used for an endpoint class.
+    ///
+    /// The label names the configured token (`pat` or `write-pat`) and a short
+    /// SHA-256 fingerprint of it, never the token itself.
+    pub fn identity(&self, class: EndpointClass) -> &str {
+        &self.credential(class).label
+    }
+
+    fn credential(&self, class: EndpointClass) -> &Identity {
+        match class {
+            EndpointClass::Read => &self.read_identity,
+            EndpointClass::Write => &self.write_identity,
+        }
+    }
+
+    pub(super) fn git(&self, class: EndpointClass) -> &git::Client {
+        &self.credential(class).git_client
+    }
+
+    fn wit(&self, class: EndpointClass) -> &wit::Client {
+        &self.credential(class).wit_client
+    }
+
+    pub(super) fn artifacts(&self, class: EndpointClass) -> &artifacts::Client {
+        &self.credential(class).artifacts_client
+    }
+
+    pub(super) fn wiki(&self, class: EndpointClass) -> &wiki::Client {
+        &self.credential(class).wiki_client
+    }
+
+    pub(super) fn build(&self, class: EndpointClass) -> &build::Client {
+        &self.credential(class).build_client
+    }
+
+    pub(super) fn policy(&self, class: EndpointClass) -> &policy::Client {
+        &self.credential(class).policy_client
+    }
+
+    /// Records which identity performed a mutation and how it went.
+    pub(super) fn audit(&self, action: &str, target: &str, result: &Result<()>) {
+        let identity = self.identity(EndpointClass::Write);
+        match result {
+            Ok(()) => tracing::info!(
+                target: AUDIT_LOG_TARGET,
+                identity,
+                action,
+                target,
+                "mutation succeeded"
+            ),
+            Err(e) => tracing::warn!(
+                target: AUDIT_LOG_TARGET,
+                identity,
+                action,
+                target,
+                error = %e,
+                "mutation failed"
+            ),
+        }
+    }
+
     /// Creates a new client with pool configuration (backward compatibility).
     ///
     /// Note: Pool configuration is handled internally by azure_devops_rust_api.
@@ -123,7 +465,8 @@ impl AzureDevOpsClient {
 
     /// Creates a new client with full configuration (backward compatibility).
     ///
-    /// Note: Pool and retry configuration is handled internally by azure_devops_rust_api.
+    /// Note: Pool configuration is handled internally by azure_devops_rust_api,
+    /// and retries are configured per endpoint class (see [`RetrySettings`]).
     /// These parameters are accepted for API compatibility but not used.
     #[allow(unused_variables)]
     pub fn new_with_full_config(
@@ -153,11 +496,36 @@ impl AzureDevOpsClient {
         &self.repository
     }
 
-    /// Returns the max retries value (backward compatibility).
+    /// Returns the API version requests are sent with.
     ///
-    /// Note: Retry logic is handled internally by azure_devops_rust_api.
+    /// This is the configured override, or [`DEFAULT_API_VERSION`] when none is set.
+    pub fn api_version(&self) -> &str {
+        self.version_policy
+            .override_version()
+            .unwrap_or(DEFAULT_API_VERSION)
+    }
+
+    /// Returns the distinct API deprecation notices received so far.
+    pub fn deprecation_notices(&self) -> Vec<String> {
+        self.version_policy.notices()
+    }
+
+    /// Returns the distinct response schema mismatches found so far.
+    pub fn schema_warnings(&self) -> Vec<SchemaWarning> {
+        self.schema.warnings()
+    }
+
+    /// Appends the extra work item fields to a comma-separated field list.
+    fn work_item_fields(&self, default_fields: &str) -> String {
+        std::iter::once(default_fields)
+            .chain(self.extra_work_item_fields.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Returns how often a throttled or failing read request is retried.
     pub fn max_retries(&self) -> u32 {
-        3
+        RetrySettings::for_class(EndpointClass::Read).max_retries
     }
 
     /// Fetches all pull requests for a given branch using pagination.
@@ -165,12 +533,35 @@ impl AzureDevOpsClient {
     /// This method implements pagination to ensure all pull requests are retrieved.
     /// If `since` is provided, stops fetching when encountering PRs older than the specified date.
     #[must_use = "this returns the fetched pull requests which should be used"]
-    #[tracing::instrument(skip(self), fields(dev_branch = %dev_branch))]
     pub async fn fetch_pull_requests(
         &self,
         dev_branch: &str,
         since: Option<&str>,
     ) -> Result<Vec<PullRequest>> {
+        self.fetch_pull_requests_with_progress(dev_branch, since, |_| {})
+            .await
+    }
+
+    /// Fetches all pull requests for a given branch, reporting each page.
+    ///
+    /// Following pages are requested while the current one is processed (see
+    /// [`PagePipeline`]). A PR can shift onto the next page when another PR completes during the
+    /// listing, so duplicates are dropped.
+    ///
+    /// Completed PRs are listed newest completion first, so with the default
+    /// [`SinceField::Closed`] the listing stops at the first PR closed before
+    /// `since`. Commit dates do not follow that order: with the other fields
+    /// every page is read and PRs are kept by their commit date, falling back
+    /// to the closed date when the listing carries no commit date.
+    #[must_use = "this returns the fetched pull requests which should be used"]
+    #[tracing::instrument(skip(self, on_page), fields(dev_branch = %dev_branch))]
+    pub async fn fetch_pull_requests_with_progress(
+        &self,
+        dev_branch: &str,
+        since: Option<&str>,
+        on_page: impl FnMut(PageProgress),
+    ) -> Result<Vec<PullRequest>> {
+        let _phase = profiling::phase("fetch pull requests");
         tracing::info!("Fetching pull requests for branch: {}", dev_branch);
 
         // Parse the since date if provided
@@ -182,83 +573,63 @@ impl AzureDevOpsClient {
         };
 
         let target_ref = format!("refs/heads/{}", dev_branch);
-        let mut all_prs = Vec::new();
-        let mut skip = 0;
-        let top = 100;
-        let max_requests = 100;
-        let mut request_count = 0;
-
-        loop {
-            request_count += 1;
-            tracing::debug!(
-                "Fetching PR page: request #{}, skip={}, top={}",
-                request_count,
-                skip,
-                top
-            );
-
-            if request_count > max_requests {
-                tracing::error!(
-                    "Exceeded maximum number of requests ({}), retrieved {} PRs",
-                    max_requests,
-                    all_prs.len()
-                );
-                anyhow::bail!(
-                    "Exceeded maximum number of requests ({}) while fetching pull requests. Retrieved {} PRs so far.",
-                    max_requests,
-                    all_prs.len()
-                );
-            }
-
-            // Fetch page of PRs
-            let response = self
-                .git_client
+        let pipeline = PagePipeline::default();
+        let top = pipeline.page_size as i32;
+
+        let since_field = self.since_field;
+        let schema = &self.schema;
+        let fetch_page = |skip: usize| {
+            let mut request = self
+                .git(EndpointClass::Read)
                 .pull_requests_client()
                 .get_pull_requests(&self.organization, &self.repository, &self.project)
                 .search_criteria_target_ref_name(&target_ref)
                 .search_criteria_status("completed")
                 .top(top)
-                .skip(skip)
-                .await
-                .context("Failed to fetch pull requests")?;
-
-            let fetched_count = response.value.len();
-            tracing::debug!(
-                "Retrieved {} PRs in this batch, {} total so far",
-                fetched_count,
-                all_prs.len()
-            );
-
-            // Convert and filter PRs by date
-            let mut reached_date_limit = false;
-            for pr in response.value {
-                let converted_pr: PullRequest = pr.into();
-
-                if let Some(since_dt) = since_date
-                    && let Some(closed_date_str) = &converted_pr.closed_date
-                    && let Ok(closed_date) = DateTime::parse_from_rfc3339(closed_date_str)
-                {
-                    let closed_date_utc = closed_date.with_timezone(&Utc);
-                    if closed_date_utc < since_dt {
-                        tracing::debug!("Reached date limit at PR {}", converted_pr.id);
-                        reached_date_limit = true;
-                        break;
-                    }
-                }
-                all_prs.push(converted_pr);
+                .skip(skip as i32);
+            if let Some(creator_id) = &self.creator_id {
+                request = request.search_criteria_creator_id(creator_id);
             }
-
-            if reached_date_limit || fetched_count < top as usize {
-                tracing::debug!(
-                    "Fetch complete: reached_date_limit={}, is_last_page={}",
-                    reached_date_limit,
-                    fetched_count < top as usize
-                );
-                break;
+            if let Some(reviewer_id) = &self.reviewer_id {
+                request = request.search_criteria_reviewer_id(reviewer_id);
+            }
+            async move {
+                tracing::debug!("Fetching PR page: skip={}, top={}", skip, top);
+                let response = request
+                    .send()
+                    .await
+                    .context("Failed to fetch pull requests")?;
+                let body = response.into_raw_response().into_body();
+                let prs: Vec<git::models::GitPullRequest> =
+                    schema.decode_list("pull requests", PULL_REQUEST_FIELDS, &body)?;
+                Ok(prs
+                    .into_iter()
+                    .map(|pr| {
+                        let date = window_date(&pr, since_field);
+                        (PullRequest::from(pr), date)
+                    })
+                    .collect::<Vec<_>>())
             }
+        };
+        let before_since = |date: Option<DateTime<Utc>>| matches!((since_date, date), (Some(since_dt), Some(date)) if date < since_dt);
+        let within_since = |(pr, date): &(PullRequest, Option<DateTime<Utc>>)| {
+            if since_field == SinceField::Closed && before_since(*date) {
+                tracing::debug!("Reached date limit at PR {}", pr.id);
+                return false;
+            }
+            true
+        };
 
-            skip += top;
-        }
+        let mut all_prs: Vec<PullRequest> = pipeline
+            .run(fetch_page, within_since, on_page)
+            .await?
+            .into_iter()
+            .filter(|(_, date)| !before_since(*date))
+            .map(|(pr, _)| pr)
+            .collect();
+
+        let mut seen = std::collections::HashSet::new();
+        all_prs.retain(|pr| seen.insert(pr.id));
 
         tracing::info!("Fetched {} total pull requests", all_prs.len());
         Ok(all_prs)
@@ -267,49 +638,112 @@ impl AzureDevOpsClient {
     /// Fetches work items linked to a pull request.
     #[must_use = "this returns the fetched work items which should be used"]
     pub async fn fetch_work_items_for_pr(&self, pr_id: i32) -> Result<Vec<WorkItem>> {
-        // Get work item refs linked to the PR
+        let ids = self.fetch_work_item_ids_for_pr(pr_id).await?;
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // Batch fetch work items
+        let ids_str = ids
+            .iter()
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let fields = self.work_item_fields(LINKED_WORK_ITEM_FIELDS);
+        self.list_work_items(&ids_str, &fields)
+            .await
+            .context("Failed to fetch work items")
+    }
+
+    /// Fetches the ids of the work items linked to a pull request.
+    #[must_use = "this returns the fetched work item ids which should be used"]
+    pub async fn fetch_work_item_ids_for_pr(&self, pr_id: i32) -> Result<Vec<i32>> {
         let refs = self
-            .git_client
+            .git(EndpointClass::Read)
             .pull_request_work_items_client()
             .list(&self.organization, &self.repository, pr_id, &self.project)
             .await
             .context("Failed to fetch work item references for PR")?;
 
-        if refs.value.is_empty() {
-            return Ok(vec![]);
-        }
-
-        // Extract work item IDs from the refs
-        let ids: Vec<i32> = refs
+        Ok(refs
             .value
             .iter()
             .filter_map(|r| r.url.as_ref().and_then(|url| extract_work_item_id(url)))
-            .collect();
+            .collect())
+    }
 
-        if ids.is_empty() {
-            return Ok(vec![]);
-        }
+    /// Fetches the work items linked to PRs by id through the
+    /// `workitemsbatch` API (see [`WorkItemBatches`]).
+    ///
+    /// Each id is requested once, however many PRs link it. Work items that
+    /// were deleted or cannot be read are left out.
+    #[must_use = "this returns the fetched work items which should be used"]
+    pub async fn fetch_work_items_batched(&self, ids: &[i32]) -> Result<Vec<WorkItem>> {
+        Ok(self
+            .fetch_linked_work_items_batched(ids)
+            .await?
+            .into_iter()
+            .map(WorkItem::from)
+            .collect())
+    }
 
-        // Batch fetch work items
-        let ids_str = ids
-            .iter()
-            .map(|i| i.to_string())
-            .collect::<Vec<_>>()
-            .join(",");
+    /// Fetches the work items linked to PRs by id, keeping their revision.
+    async fn fetch_linked_work_items_batched(
+        &self,
+        ids: &[i32],
+    ) -> Result<Vec<wit::models::WorkItem>> {
+        let fields: Vec<String> = self
+            .work_item_fields(LINKED_WORK_ITEM_FIELDS)
+            .split(',')
+            .map(str::to_string)
+            .collect();
+        WorkItemBatches::default()
+            .run(ids, |batch| self.list_work_items_batch(batch, &fields))
+            .await
+            .context("Failed to fetch work items")
+    }
 
-        let work_items = self
-            .wit_client
-            .work_items_client()
-            .list(
-                &self.organization,
-                &ids_str,
-                &self.project,
-            )
-            .fields("System.Title,System.State,System.WorkItemType,System.AssignedTo,System.IterationPath,System.Description,Microsoft.VSTS.TCM.ReproSteps")
+    /// Fetches the current revision of work items by id.
+    async fn fetch_work_item_revisions(&self, ids: &[i32]) -> Result<HashMap<i32, i32>> {
+        let fields = ["System.Rev".to_string()];
+        let work_items = WorkItemBatches::default()
+            .run(ids, |batch| self.list_work_items_batch(batch, &fields))
             .await
-            .context("Failed to fetch work items")?;
+            .context("Failed to fetch work item revisions")?;
+        Ok(work_items
+            .into_iter()
+            .filter_map(|wi| Some((wi.id, wi.rev?)))
+            .collect())
+    }
 
-        Ok(work_items.value.into_iter().map(WorkItem::from).collect())
+    /// Fetches one batch of work items through the `workitemsbatch` API.
+    async fn list_work_items_batch(
+        &self,
+        ids: Vec<i32>,
+        fields: &[String],
+    ) -> Result<Vec<wit::models::WorkItem>> {
+        let mut request = wit::models::WorkItemBatchGetRequest::new();
+        request.ids = ids;
+        request.fields = fields.to_vec();
+        request.error_policy = Some(wit::models::work_item_batch_get_request::ErrorPolicy::Omit);
+        let response = self
+            .wit(EndpointClass::Read)
+            .work_items_client()
+            .get_work_items_batch(&self.organization, request, &self.project)
+            .send()
+            .await?;
+        let body = response.into_raw_response().into_body();
+        // Omitted work items come back as `null` entries
+        let mut envelope: serde_json::Value = serde_json::from_slice(&body)?;
+        if let Some(serde_json::Value::Array(items)) = envelope.get_mut("value") {
+            items.retain(|item| !item.is_null());
+        }
+        self.schema.decode_list(
+            "work items batch",
+            WORK_ITEM_FIELDS,
+            &serde_json::to_vec(&envelope)?,
+        )
     }
 
     /// Fetches work items by a list of IDs directly.
@@ -336,21 +770,34 @@ impl AzureDevOpsClient {
             .collect::<Vec<_>>()
             .join(",");
 
-        let work_items = self
-            .wit_client
-            .work_items_client()
-            .list(&self.organization, &ids_str, &self.project)
-            .fields("System.Title,System.State,System.WorkItemType,System.AssignedTo,System.IterationPath")
+        let fields = self.work_item_fields(
+            "System.Title,System.State,System.WorkItemType,System.AssignedTo,System.IterationPath",
+        );
+        self.list_work_items(&ids_str, &fields)
             .await
-            .context("Failed to fetch work items by IDs")?;
+            .context("Failed to fetch work items by IDs")
+    }
 
-        Ok(work_items.value.into_iter().map(WorkItem::from).collect())
+    /// Fetches a batch of work items, decoding each one separately.
+    async fn list_work_items(&self, ids: &str, fields: &str) -> Result<Vec<WorkItem>> {
+        let response = self
+            .wit(EndpointClass::Read)
+            .work_items_client()
+            .list(&self.organization, ids, &self.project)
+            .fields(fields)
+            .send()
+            .await?;
+        let body = response.into_raw_response().into_body();
+        let work_items: Vec<wit::models::WorkItem> =
+            self.schema
+                .decode_list("work items", WORK_ITEM_FIELDS, &body)?;
+        Ok(work_items.into_iter().map(WorkItem::from).collect())
     }
 
     /// Fetches repository details including SSH URL.
     pub async fn fetch_repo_details(&self) -> Result<RepoDetails> {
         let repo = self
-            .git_client
+            .git(EndpointClass::Read)
             .repositories_client()
             .get_repository(&self.organization, &self.repository, &self.project)
             .await
@@ -362,18 +809,26 @@ impl AzureDevOpsClient {
     /// Fetches the merge commit for a pull request.
     #[must_use = "this returns the merge commit which should be used"]
     pub async fn fetch_pr_commit(&self, pr_id: i32) -> Result<MergeCommit> {
+        if let Some(commit_id) = self.cache.as_ref().and_then(|c| c.pr_commit(pr_id)) {
+            return Ok(MergeCommit { commit_id });
+        }
         let pr = self
-            .git_client
+            .git(EndpointClass::Read)
             .pull_requests_client()
             .get_pull_request(&self.organization, &self.repository, pr_id, &self.project)
             .await
             .context("Failed to fetch pull request details")?;
 
-        pr.last_merge_commit
+        let commit = pr
+            .last_merge_commit
             .map(|c| MergeCommit {
                 commit_id: c.commit_id.unwrap_or_default(),
             })
-            .ok_or_else(|| anyhow::anyhow!("Pull request {} has no merge commit", pr_id))
+            .ok_or_else(|| anyhow::anyhow!("Pull request {} has no merge commit", pr_id))?;
+        if let Some(cache) = &self.cache {
+            cache.set_pr_commit(pr_id, &commit.commit_id);
+        }
+        Ok(commit)
     }
 
     /// Adds a label to a pull request.
@@ -384,7 +839,8 @@ impl AzureDevOpsClient {
             name: label.to_string(),
         };
 
-        self.git_client
+        let result = self
+            .git(EndpointClass::Write)
             .pull_request_labels_client()
             .create(
                 &self.organization,
@@ -394,9 +850,44 @@ impl AzureDevOpsClient {
                 &self.project,
             )
             .await
-            .context("Failed to add label to pull request")?;
+            .map(|_| ())
+            .context("Failed to add label to pull request");
 
-        Ok(())
+        self.audit(
+            &format!("add label {}", label),
+            &format!("PR {}", pr_id),
+            &result,
+        );
+        result
+    }
+
+    /// Removes a label from a pull request.
+    ///
+    /// The label itself is not deleted from the project; it is only detached
+    /// from this pull request.
+    #[must_use = "this operation can fail and the result should be checked"]
+    #[tracing::instrument(skip(self))]
+    pub async fn remove_label_from_pr(&self, pr_id: i32, label: &str) -> Result<()> {
+        let result = self
+            .git(EndpointClass::Write)
+            .pull_request_labels_client()
+            .delete(
+                &self.organization,
+                &self.repository,
+                pr_id,
+                label,
+                &self.project,
+            )
+            .await
+            .map(|_| ())
+            .context("Failed to remove label from pull request");
+
+        self.audit(
+            &format!("remove label {}", label),
+            &format!("PR {}", pr_id),
+            &result,
+        );
+        result
     }
 
     /// Updates the state of a work item.
@@ -409,20 +900,211 @@ impl AzureDevOpsClient {
             from: None,
         }];
 
-        self.wit_client
+        let result = self
+            .wit(EndpointClass::Write)
+            .work_items_client()
+            .update(&self.organization, patch, work_item_id, &self.project)
+            .await
+            .map(|_| ())
+            .context("Failed to update work item state");
+        self.evict_cached_work_item(work_item_id);
+
+        self.audit(
+            &format!("set state {}", new_state),
+            &format!("work item {}", work_item_id),
+            &result,
+        );
+        result
+    }
+
+    /// Sets a field of a work item, e.g. `Microsoft.VSTS.Build.IntegrationBuild`.
+    #[must_use = "this operation can fail and the result should be checked"]
+    pub async fn update_work_item_field(
+        &self,
+        work_item_id: i32,
+        field: &str,
+        value: &str,
+    ) -> Result<()> {
+        self.update_work_item_fields(work_item_id, &[(field.to_string(), value.to_string())])
+            .await
+    }
+
+    /// Sets several fields of a work item in one update.
+    #[must_use = "this operation can fail and the result should be checked"]
+    pub async fn update_work_item_fields(
+        &self,
+        work_item_id: i32,
+        values: &[(String, String)],
+    ) -> Result<()> {
+        let patch = values
+            .iter()
+            .map(|(field, value)| wit::models::JsonPatchOperation {
+                op: Some(wit::models::json_patch_operation::Op::Add),
+                path: Some(format!("/fields/{}", field)),
+                value: Some(serde_json::json!(value)),
+                from: None,
+            })
+            .collect();
+
+        let result = self
+            .wit(EndpointClass::Write)
             .work_items_client()
             .update(&self.organization, patch, work_item_id, &self.project)
             .await
-            .context("Failed to update work item state")?;
+            .map(|_| ())
+            .context("Failed to update work item fields");
+        self.evict_cached_work_item(work_item_id);
+
+        let action = values
+            .iter()
+            .map(|(field, value)| format!("{} {}", field, value))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.audit(
+            &format!("set {}", action),
+            &format!("work item {}", work_item_id),
+            &result,
+        );
+        result
+    }
+
+    /// Reads the current values of some fields of a work item.
+    ///
+    /// Fields the work item has no value for are left out.
+    pub async fn fetch_work_item_field_values(
+        &self,
+        work_item_id: i32,
+        fields: &[&str],
+    ) -> Result<HashMap<String, String>> {
+        let work_item = self
+            .wit(EndpointClass::Read)
+            .work_items_client()
+            .get_work_item(&self.organization, work_item_id, &self.project)
+            .fields(fields.join(","))
+            .await
+            .context("Failed to fetch work item fields")?;
+
+        Ok(fields
+            .iter()
+            .filter_map(|field| {
+                let value = match work_item.fields.get(*field)? {
+                    serde_json::Value::String(text) => text.clone(),
+                    serde_json::Value::Null => return None,
+                    other => other.to_string(),
+                };
+                Some((field.to_string(), value))
+            })
+            .collect())
+    }
+
+    /// Adds a comment to a work item.
+    #[must_use = "this operation can fail and the result should be checked"]
+    pub async fn add_work_item_comment(&self, work_item_id: i32, text: &str) -> Result<()> {
+        let comment = wit::models::CommentCreate {
+            text: Some(text.to_string()),
+        };
+
+        let result = self
+            .wit(EndpointClass::Write)
+            .comments_client()
+            .add_comment(&self.organization, comment, &self.project, work_item_id)
+            .await
+            .map(|_| ())
+            .context("Failed to add work item comment");
+        self.evict_cached_work_item(work_item_id);
+
+        self.audit(
+            "add comment",
+            &format!("work item {}", work_item_id),
+            &result,
+        );
+        result
+    }
+
+    /// Links work items to the active pull request from `source_branch`
+    /// into `target_branch`.
+    ///
+    /// Each work item gets an artifact link to the pull request, which makes
+    /// it show up among the PR's work items as well. Work items already
+    /// linked to the PR are skipped. Returns the IDs of the work items that
+    /// were linked, or `None` if there is no such pull request yet.
+    #[must_use = "this operation can fail and the result should be checked"]
+    #[tracing::instrument(skip(self, work_item_ids))]
+    pub async fn link_work_items_to_pull_request(
+        &self,
+        source_branch: &str,
+        target_branch: &str,
+        work_item_ids: &[i32],
+    ) -> Result<Option<Vec<i32>>> {
+        let prs = self
+            .git(EndpointClass::Read)
+            .pull_requests_client()
+            .get_pull_requests(&self.organization, &self.repository, &self.project)
+            .search_criteria_source_ref_name(format!("refs/heads/{}", source_branch))
+            .search_criteria_target_ref_name(format!("refs/heads/{}", target_branch))
+            .search_criteria_status("active")
+            .top(1)
+            .await
+            .context("Failed to find pull request")?;
+        let Some(pr) = prs.value.into_iter().next() else {
+            return Ok(None);
+        };
+        let artifact_id = pr
+            .artifact_id
+            .with_context(|| format!("Pull request {} has no artifact ID", pr.pull_request_id))?;
+
+        let refs = self
+            .git(EndpointClass::Read)
+            .pull_request_work_items_client()
+            .list(
+                &self.organization,
+                &self.repository,
+                pr.pull_request_id,
+                &self.project,
+            )
+            .await
+            .context("Failed to fetch work item references for PR")?;
+        let linked: HashSet<i32> = refs
+            .value
+            .iter()
+            .filter_map(|r| r.url.as_ref().and_then(|url| extract_work_item_id(url)))
+            .collect();
 
-        Ok(())
+        let mut added = Vec::new();
+        for &work_item_id in work_item_ids {
+            if linked.contains(&work_item_id) || added.contains(&work_item_id) {
+                continue;
+            }
+            let result = self
+                .wit(EndpointClass::Write)
+                .work_items_client()
+                .update(
+                    &self.organization,
+                    pull_request_link_patch(&artifact_id),
+                    work_item_id,
+                    &self.project,
+                )
+                .await
+                .map(|_| ())
+                .context("Failed to link work item to pull request");
+            self.evict_cached_work_item(work_item_id);
+
+            self.audit(
+                &format!("link PR {}", pr.pull_request_id),
+                &format!("work item {}", work_item_id),
+                &result,
+            );
+            result?;
+            added.push(work_item_id);
+        }
+        Ok(Some(added))
     }
 
     /// Fetches the revision history for a work item.
     #[must_use = "this returns the work item history which should be used"]
     pub async fn fetch_work_item_history(&self, work_item_id: i32) -> Result<Vec<WorkItemHistory>> {
         let updates = self
-            .wit_client
+            .wit(EndpointClass::Read)
             .updates_client()
             .list(&self.organization, work_item_id, &self.project)
             .await
@@ -444,7 +1126,7 @@ impl AzureDevOpsClient {
         work_item_type: &str,
     ) -> Result<std::collections::HashMap<String, String>> {
         let states = self
-            .wit_client
+            .wit(EndpointClass::Read)
             .work_item_type_states_client()
             .list(&self.organization, &self.project, work_item_type)
             .await
@@ -514,13 +1196,18 @@ impl AzureDevOpsClient {
         max_concurrent: usize,
     ) -> Result<Vec<WorkItem>> {
         let work_items = self.fetch_work_items_for_pr(pr_id).await?;
+        Ok(self.with_histories(work_items, max_concurrent).await)
+    }
 
-        if work_items.is_empty() {
-            return Ok(work_items);
-        }
-
-        // Fetch history for all work items in parallel with concurrency limit
-        let work_items_with_history: Vec<WorkItem> = stream::iter(work_items)
+    /// Adds the state history to each work item, fetching up to
+    /// `max_concurrent` histories at once. Work items whose history cannot
+    /// be fetched keep an empty one.
+    pub async fn with_histories(
+        &self,
+        work_items: Vec<WorkItem>,
+        max_concurrent: usize,
+    ) -> Vec<WorkItem> {
+        stream::iter(work_items)
             .map(|work_item| {
                 let client = self.clone();
                 async move {
@@ -531,11 +1218,126 @@ impl AzureDevOpsClient {
                     wi
                 }
             })
-            .buffer_unordered(max_concurrent)
+            .buffer_unordered(max_concurrent.max(1))
+            .collect()
+            .await
+    }
+
+    /// Fetches the work items linked to each of `pr_ids`, with history.
+    ///
+    /// The links are listed per PR, then every linked work item is fetched
+    /// once in batches (see [`fetch_work_items_batched`](Self::fetch_work_items_batched))
+    /// instead of PR by PR. PRs whose links cannot be listed get no work
+    /// items.
+    pub async fn fetch_work_items_with_history_for_prs(
+        &self,
+        pr_ids: &[i32],
+        max_concurrent_prs: usize,
+        max_concurrent_history: usize,
+    ) -> Result<HashMap<i32, Vec<WorkItem>>> {
+        let links: Vec<(i32, Vec<i32>)> = stream::iter(pr_ids.iter().copied())
+            .map(|pr_id| async move {
+                let ids = self
+                    .fetch_work_item_ids_for_pr(pr_id)
+                    .await
+                    .unwrap_or_else(|e| {
+                        tracing::warn!("Failed to list work items of PR #{}: {:#}", pr_id, e);
+                        Vec::new()
+                    });
+                (pr_id, ids)
+            })
+            .buffer_unordered(max_concurrent_prs.max(1))
             .collect()
             .await;
+        self.fetch_linked_work_items(links, max_concurrent_history)
+            .await
+    }
+
+    /// Fetches the work items of `links` (PR id and its linked work item
+    /// ids) in batches and returns them per PR, with history.
+    pub async fn fetch_linked_work_items(
+        &self,
+        links: Vec<(i32, Vec<i32>)>,
+        max_concurrent_history: usize,
+    ) -> Result<HashMap<i32, Vec<WorkItem>>> {
+        let ids: Vec<i32> = links
+            .iter()
+            .flat_map(|(_, ids)| ids.iter().copied())
+            .collect();
+        let work_items = match &self.cache {
+            Some(cache) => {
+                self.fetch_cached_work_items(cache, &ids, max_concurrent_history)
+                    .await?
+            }
+            None if ids.is_empty() => Vec::new(),
+            None => {
+                let work_items = self.fetch_work_items_batched(&ids).await?;
+                self.with_histories(work_items, max_concurrent_history)
+                    .await
+            }
+        };
+        let work_items: HashMap<i32, WorkItem> =
+            work_items.into_iter().map(|wi| (wi.id, wi)).collect();
+        Ok(links
+            .into_iter()
+            .map(|(pr_id, ids)| {
+                let items = ids
+                    .iter()
+                    .filter_map(|id| work_items.get(id).cloned())
+                    .collect();
+                (pr_id, items)
+            })
+            .collect())
+    }
+
+    /// Returns the work items for `ids` with history, from the cache where
+    /// possible.
+    ///
+    /// Cached work items are used if their revision is unchanged, which is
+    /// checked with one batch request for `System.Rev`; the others are
+    /// fetched with their history and cached.
+    async fn fetch_cached_work_items(
+        &self,
+        cache: &RepoCache,
+        ids: &[i32],
+        max_concurrent_history: usize,
+    ) -> Result<Vec<WorkItem>> {
+        let mut seen = HashSet::new();
+        let ids: Vec<i32> = ids.iter().copied().filter(|id| seen.insert(*id)).collect();
+        let lookup = cache.work_items(&ids);
+        let mut work_items = Vec::new();
+        let mut missing = lookup.missing;
+
+        if !lookup.cached.is_empty() {
+            let cached_ids: Vec<i32> = lookup.cached.iter().map(|(id, _)| *id).collect();
+            let revisions = self.fetch_work_item_revisions(&cached_ids).await?;
+            let (unchanged, changed): (Vec<_>, Vec<_>) = lookup
+                .cached
+                .into_iter()
+                .partition(|(id, rev)| revisions.get(id) == Some(rev));
+            let unchanged: Vec<i32> = unchanged.into_iter().map(|(id, _)| id).collect();
+            work_items.extend(cache.revalidate_work_items(&unchanged));
+            missing.extend(changed.into_iter().map(|(id, _)| id));
+        }
+
+        if !missing.is_empty() {
+            let fetched = self.fetch_linked_work_items_batched(&missing).await?;
+            let revisions: HashMap<i32, Option<i32>> =
+                fetched.iter().map(|wi| (wi.id, wi.rev)).collect();
+            let fetched = self
+                .with_histories(
+                    fetched.into_iter().map(WorkItem::from).collect(),
+                    max_concurrent_history,
+                )
+                .await;
+            for work_item in &fetched {
+                cache.set_work_item(work_item, revisions.get(&work_item.id).copied().flatten());
+            }
+            work_items.extend(fetched);
+        }
 
-        Ok(work_items_with_history)
+        cache.save();
+        Ok(work_items)
     }
 
     /// Fetches work items with history for a PR (sequential, for backward compatibility).
@@ -554,28 +1356,28 @@ impl AzureDevOpsClient {
         max_concurrent_prs: usize,
         max_concurrent_history: usize,
     ) -> Vec<PullRequestWithWorkItems> {
+        let _phase = profiling::phase("fetch work items");
         // First, fetch all work items with history
-        let mut results: Vec<PullRequestWithWorkItems> = stream::iter(prs.iter().cloned())
-            .map(|pr| {
-                let client = self.clone();
-                async move {
-                    let work_items = client
-                        .fetch_work_items_with_history_for_pr_parallel(
-                            pr.id,
-                            max_concurrent_history,
-                        )
-                        .await
-                        .unwrap_or_default();
-                    PullRequestWithWorkItems {
-                        pr,
-                        work_items,
-                        selected: false,
-                    }
-                }
+        let pr_ids: Vec<i32> = prs.iter().map(|pr| pr.id).collect();
+        let mut work_items = self
+            .fetch_work_items_with_history_for_prs(
+                &pr_ids,
+                max_concurrent_prs,
+                max_concurrent_history,
+            )
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to fetch work items: {:#}", e);
+                HashMap::new()
+            });
+        let mut results: Vec<PullRequestWithWorkItems> = prs
+            .iter()
+            .map(|pr| PullRequestWithWorkItems {
+                pr: pr.clone(),
+                work_items: work_items.remove(&pr.id).unwrap_or_default(),
+                selected: false,
             })
-            .buffer_unordered(max_concurrent_prs)
-            .collect()
-            .await;
+            .collect();
 
         // Collect all work items to fetch their colors
         let all_work_items: Vec<WorkItem> = results
@@ -676,6 +1478,20 @@ impl AzureDevOpsClient {
     }
 }
 
+/// JSON patch adding an artifact link from a work item to a pull request.
+fn pull_request_link_patch(artifact_id: &str) -> Vec<wit::models::JsonPatchOperation> {
+    vec![wit::models::JsonPatchOperation {
+        op: Some(wit::models::json_patch_operation::Op::Add),
+        path: Some("/relations/-".to_string()),
+        value: Some(serde_json::json!({
+            "rel": "ArtifactLink",
+            "url": artifact_id,
+            "attributes": { "name": "Pull Request" },
+        })),
+        from: None,
+    }]
+}
+
 /// Converts a hex color string (e.g., "007acc" or "#007acc") to an RGB tuple.
 ///
 /// Returns None if the hex string is invalid.
@@ -827,6 +1643,30 @@ mod tests {
         assert_eq!(super::hex_to_rgb("zzzzzz"), None);
     }
 
+    // ==================== Pull Request Links ====================
+
+    /// # Pull Request Link Patch
+    ///
+    /// Tests the patch that links a work item to a pull request.
+    ///
+    /// ## Test Scenario
+    /// - Builds the patch for a pull request artifact ID
+    ///
+    /// ## Expected Outcome
+    /// - A single operation appends an `ArtifactLink` relation to the PR
+    #[test]
+    fn test_pull_request_link_patch() {
+        let artifact_id = "vstfs:///Git/PullRequestId/proj%2Frepo%2F42";
+        let patch = super::pull_request_link_patch(artifact_id);
+
+        assert_eq!(patch.len(), 1);
+        assert_eq!(patch[0].path.as_deref(), Some("/relations/-"));
+        let value = patch[0].value.as_ref().unwrap();
+        assert_eq!(value["rel"], "ArtifactLink");
+        assert_eq!(value["url"], artifact_id);
+        assert_eq!(value["attributes"]["name"], "Pull Request");
+    }
+
     // ==================== Client Creation ====================
 
     /// # Client Creation with String PAT
@@ -933,6 +1773,36 @@ mod tests {
         assert_eq!(client.max_retries(), 3);
     }
 
+    /// # Shared Request Budget
+    ///
+    /// Tests that the request limit is shared by clones of the client.
+    ///
+    /// ## Test Scenario
+    /// - Creates a client, clones it, then limits concurrent requests
+    ///
+    /// ## Expected Outcome
+    /// - Both the client and its earlier clone report the new limit
+    #[test]
+    fn test_max_concurrent_requests_shared() {
+        let client = AzureDevOpsClient::new(
+            "org".to_string(),
+            "proj".to_string(),
+            "repo".to_string(),
+            "pat".to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            client.max_concurrent_requests(),
+            super::super::retry::DEFAULT_MAX_CONCURRENT_REQUESTS
+        );
+
+        let clone = client.clone();
+        let client = client.with_max_concurrent_requests(8);
+
+        assert_eq!(client.max_concurrent_requests(), 8);
+        assert_eq!(clone.max_concurrent_requests(), 8);
+    }
+
     /// # All Accessor Methods
     ///
     /// Tests all accessor methods return correct values.
@@ -952,6 +1822,65 @@ mod tests {
         assert_eq!(client.max_retries(), 3);
     }
 
+    /// # API Version Accessor
+    ///
+    /// Tests that the client reports the default or overridden API version.
+    #[test]
+    fn test_api_version_accessor() {
+        let client = AzureDevOpsClient::new(
+            "org".to_string(),
+            "proj".to_string(),
+            "repo".to_string(),
+            "pat".to_string(),
+        )
+        .unwrap();
+        assert_eq!(client.api_version(), DEFAULT_API_VERSION);
+        assert!(client.deprecation_notices().is_empty());
+
+        let client = AzureDevOpsClient::new_with_api_version(
+            "org".to_string(),
+            "proj".to_string(),
+            "repo".to_string(),
+            SecretString::from("pat".to_string()),
+            Some("6.0".to_string()),
+        )
+        .unwrap();
+        assert_eq!(client.api_version(), "6.0");
+    }
+
+    /// # Write PAT Identity
+    ///
+    /// Tests which credential each endpoint class is sent with.
+    ///
+    /// ## Test Scenario
+    /// - Creates a client with one PAT, then adds a separate write PAT
+    ///
+    /// ## Expected Outcome
+    /// - Without a write PAT both classes use the same identity
+    /// - With a write PAT only writes switch to it
+    /// - Identity labels contain a fingerprint, never the token
+    #[test]
+    fn test_write_pat_identity() {
+        let client = AzureDevOpsClient::new(
+            "org".to_string(),
+            "proj".to_string(),
+            "repo".to_string(),
+            "read-token".to_string(),
+        )
+        .unwrap();
+        let read = client.identity(EndpointClass::Read).to_string();
+        assert!(read.starts_with("pat:"));
+        assert_eq!(read.len(), "pat:".len() + 8);
+        assert_eq!(client.identity(EndpointClass::Write), read);
+
+        let client = client.with_write_pat(SecretString::from("write-token".to_string()));
+        let write = client.identity(EndpointClass::Write);
+        assert_eq!(client.identity(EndpointClass::Read), read);
+        assert!(write.starts_with("write-pat:"));
+        assert!(!write.contains("write-token"));
+        assert_ne!(write[write.len() - 8..], read[read.len() - 8..]);
+    }
+
     // ==================== Parse Terminal States ====================
 
     /// # Parse Terminal States - Basic
@@ -1063,6 +1992,7 @@ mod tests {
                 description: None,
                 repro_steps: None,
                 state_color: None,
+                custom_fields: Default::default(),
             },
             history: vec![],
         };
@@ -1096,6 +2026,7 @@ mod tests {
                 description: None,
                 repro_steps: None,
                 state_color: None,
+                custom_fields: Default::default(),
             },
             history: vec![],
         };
@@ -1129,6 +2060,7 @@ mod tests {
                 description: None,
                 repro_steps: None,
                 state_color: None,
+                custom_fields: Default::default(),
             },
             history: vec![],
         };
@@ -1162,6 +2094,7 @@ mod tests {
                 description: None,
                 repro_steps: None,
                 state_color: None,
+                custom_fields: Default::default(),
             },
             history: vec![],
         };
@@ -1195,6 +2128,7 @@ mod tests {
                 description: None,
                 repro_steps: None,
                 state_color: None,
+                custom_fields: Default::default(),
             },
             history: vec![],
         };
@@ -1216,6 +2150,7 @@ mod tests {
                 },
                 last_merge_commit: None,
                 labels: None,
+                reviewers: Vec::new(),
             },
             work_items,
             selected: false,
@@ -1234,6 +2169,7 @@ mod tests {
                 description: None,
                 repro_steps: None,
                 state_color: None,
+                custom_fields: Default::default(),
             },
             history: vec![],
         }
@@ -1415,6 +2351,7 @@ mod tests {
             },
             last_merge_commit: None,
             labels,
+            reviewers: Vec::new(),
         }
     }
 
@@ -1628,6 +2565,7 @@ mod tests {
             },
             last_merge_commit: None,
             labels: None,
+            reviewers: Vec::new(),
         };
 
         let pr_with_other_label = PullRequest {
@@ -1642,6 +2580,7 @@ mod tests {
             labels: Some(vec![Label {
                 name: "bug".to_string(),
             }]),
+            reviewers: Vec::new(),
         };
 
         let pr_with_merged_tag = PullRequest {
@@ -1656,6 +2595,7 @@ mod tests {
             labels: Some(vec![Label {
                 name: "merged-v1.0".to_string(),
             }]),
+            reviewers: Vec::new(),
         };
 
         let pr_with_mixed_labels = PullRequest {
@@ -1675,6 +2615,7 @@ mod tests {
                     name: "merged-hotfix".to_string(),
                 },
             ]),
+            reviewers: Vec::new(),
         };
 
         let prs = vec![
@@ -1732,6 +2673,7 @@ mod tests {
             labels: Some(vec![Label {
                 name: "merged-v1".to_string(),
             }]),
+            reviewers: Vec::new(),
         };
 
         let pr2 = PullRequest {
@@ -1746,6 +2688,7 @@ mod tests {
             labels: Some(vec![Label {
                 name: "merged-v2".to_string(),
             }]),
+            reviewers: Vec::new(),
         };
 
         let filtered = filter_prs_without_merged_tag(vec![pr1, pr2]);
@@ -1838,6 +2781,7 @@ mod tests {
                 description: None,
                 repro_steps: None,
                 state_color: None,
+                custom_fields: Default::default(),
             },
             history: vec![],
         };
@@ -1853,6 +2797,7 @@ mod tests {
                 description: None,
                 repro_steps: None,
                 state_color: None,
+                custom_fields: Default::default(),
             },
             history: vec![],
         };
@@ -1868,6 +2813,7 @@ mod tests {
                 description: None,
                 repro_steps: None,
                 state_color: None,
+                custom_fields: Default::default(),
             },
             history: vec![],
         };
@@ -1912,4 +2858,64 @@ mod tests {
             vec!["Closed", "Done"]
         );
     }
+
+    /// # Cached Merge Commits and Work Items
+    ///
+    /// Tests that cached merge commits are returned without any request and
+    /// cached work items only after revalidating them.
+    ///
+    /// ## Test Scenario
+    /// - Caches a PR merge commit and a work item, then fetches both through
+    ///   a client for an organization that does not exist
+    /// - Writes the work item's state through the same client
+    ///
+    /// ## Expected Outcome
+    /// - The merge commit comes from the cache
+    /// - Fetching the work item fails, since its revision has to be checked
+    /// - The failed write still evicts the work item from the cache
+    #[tokio::test]
+    async fn test_cached_merge_commits_and_work_items() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let cache = Arc::new(RepoCache::open_in(temp.path(), chrono::Duration::hours(1)));
+        cache.set_pr_commit(42, "abc123");
+        let work_item = WorkItem {
+            id: 7,
+            fields: WorkItemFields {
+                title: Some("Crash on export".to_string()),
+                state: Some("Done".to_string()),
+                work_item_type: Some("Bug".to_string()),
+                assigned_to: None,
+                iteration_path: None,
+                description: None,
+                repro_steps: None,
+                state_color: None,
+                custom_fields: HashMap::new(),
+            },
+            history: Vec::new(),
+        };
+        cache.set_work_item(&work_item, Some(3));
+
+        let client = AzureDevOpsClient::new(
+            "invalid-org-that-does-not-exist".to_string(),
+            "proj".to_string(),
+            "repo".to_string(),
+            "pat".to_string(),
+        )
+        .unwrap()
+        .with_cache(cache);
+
+        let commit = client.fetch_pr_commit(42).await.unwrap();
+        assert_eq!(commit.commit_id, "abc123");
+
+        assert!(
+            client
+                .fetch_linked_work_items(vec![(42, vec![7]), (43, vec![7])], 4)
+                .await
+                .is_err()
+        );
+
+        assert_eq!(client.cache().unwrap().work_items(&[7]).cached, [(7, 3)]);
+        assert!(client.update_work_item_state(7, "Closed").await.is_err());
+        assert_eq!(client.cache().unwrap().work_items(&[7]).missing, [7]);
+    }
 }
@@ -0,0 +1,206 @@
+//! Azure Artifacts feed lookups.
+//!
+//! After a merge, the package built from the patch branch can be looked up in
+//! an Azure Artifacts feed. Its version is stamped on the released work items
+//! and listed in the release notes.
+//!
+//! # Config Format
+//!
+//! ```toml
+//! [artifacts_feed]
+//! feed = "releases"
+//! package = "Contoso.Service"
+//! # project = "Platform"
+//! ```
+//!
+//! The version matching a merge is the most recently published version equal
+//! to the merge version, or extending it with `.`, `-` or `+`: merging `v1.4`
+//! matches `1.4`, `1.4.1207` and `1.4.0-patch.3`, but not `1.40.0`.
+
+use super::client::{AzureDevOpsClient, EndpointClass};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Work item field recording the build a work item was integrated in.
+pub const INTEGRATION_BUILD_FIELD: &str = "Microsoft.VSTS.Build.IntegrationBuild";
+
+/// A package in an Azure Artifacts feed, as configured in `[artifacts_feed]`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArtifactsFeed {
+    /// Name or ID of the feed.
+    pub feed: String,
+    /// Name of the package the patch branch build publishes.
+    pub package: String,
+    /// Project of a project-scoped feed. Defaults to the merge project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+}
+
+/// The version of a package released by a merge.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackageVersion {
+    /// Name of the package.
+    pub package: String,
+    /// Version found in the feed, e.g. `1.4.1207`.
+    pub version: String,
+}
+
+impl std::fmt::Display for PackageVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.package, self.version)
+    }
+}
+
+/// A version of a package listed in a feed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedPackageVersion {
+    /// Display version.
+    pub version: String,
+    /// When the version was published, as a Unix timestamp.
+    pub published_at: Option<i64>,
+}
+
+/// Picks the version of a package produced for `merge_version`.
+///
+/// Returns the most recently published version equal to `merge_version`
+/// (without a leading `v`) or extending it with `.`, `-` or `+`.
+pub fn select_package_version<'a>(
+    versions: &'a [FeedPackageVersion],
+    merge_version: &str,
+) -> Option<&'a FeedPackageVersion> {
+    let wanted = merge_version.trim_start_matches(['v', 'V']);
+    versions
+        .iter()
+        .filter(|candidate| {
+            candidate
+                .version
+                .strip_prefix(wanted)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(['.', '-', '+']))
+        })
+        .max_by_key(|candidate| candidate.published_at)
+}
+
+impl AzureDevOpsClient {
+    /// Lists the versions of the configured package in its feed.
+    ///
+    /// Deleted versions are left out.
+    pub async fn fetch_package_versions(
+        &self,
+        feed: &ArtifactsFeed,
+    ) -> Result<Vec<FeedPackageVersion>> {
+        let packages = self
+            .artifacts(EndpointClass::Read)
+            .artifact_details_client()
+            .get_packages(
+                self.organization(),
+                &feed.feed,
+                feed.project.as_deref().unwrap_or(self.project()),
+            )
+            .package_name_query(&feed.package)
+            .include_all_versions(true)
+            .await
+            .with_context(|| format!("Failed to list packages of feed '{}'", feed.feed))?
+            .value;
+
+        Ok(packages
+            .into_iter()
+            .filter(|package| {
+                package
+                    .name
+                    .as_deref()
+                    .is_some_and(|name| name.eq_ignore_ascii_case(&feed.package))
+            })
+            .flat_map(|package| package.versions)
+            .filter(|version| version.is_deleted != Some(true))
+            .filter_map(|version| {
+                Some(FeedPackageVersion {
+                    version: version.version?,
+                    published_at: version.publish_date.map(|date| date.unix_timestamp()),
+                })
+            })
+            .collect())
+    }
+
+    /// Finds the version of the configured package built for `merge_version`.
+    ///
+    /// See [`select_package_version`] for how the version is matched.
+    pub async fn resolve_package_version(
+        &self,
+        feed: &ArtifactsFeed,
+        merge_version: &str,
+    ) -> Result<PackageVersion> {
+        let versions = self.fetch_package_versions(feed).await?;
+        let found = select_package_version(&versions, merge_version).with_context(|| {
+            format!(
+                "No version of package '{}' matching {} found in feed '{}'",
+                feed.package, merge_version, feed.feed
+            )
+        })?;
+        Ok(PackageVersion {
+            package: feed.package.clone(),
+            version: found.version.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(version: &str, published_at: i64) -> FeedPackageVersion {
+        FeedPackageVersion {
+            version: version.to_string(),
+            published_at: Some(published_at),
+        }
+    }
+
+    /// # Package Version Selection
+    ///
+    /// Tests picking the feed version produced for a merge version.
+    ///
+    /// ## Test Scenario
+    /// - Lists versions extending the merge version, an unrelated version
+    ///   sharing its digits and older builds
+    ///
+    /// ## Expected Outcome
+    /// - The newest matching version is selected, ignoring a leading `v`
+    /// - `1.40.0` does not match `1.4`
+    /// - No version is selected when none match
+    #[test]
+    fn test_select_package_version() {
+        let versions = vec![
+            version("1.4.1200", 10),
+            version("1.4.1207", 30),
+            version("1.40.0", 50),
+            version("1.4.0-patch.3", 20),
+            version("1.3.9", 40),
+        ];
+
+        let selected = select_package_version(&versions, "v1.4").unwrap();
+        assert_eq!(selected.version, "1.4.1207");
+
+        let selected = select_package_version(&versions, "1.3.9").unwrap();
+        assert_eq!(selected.version, "1.3.9");
+
+        assert!(select_package_version(&versions, "v2.0").is_none());
+        assert!(select_package_version(&versions, "1.4.12").is_none());
+    }
+
+    /// # Package Version Display
+    ///
+    /// Tests how a released package version is written.
+    ///
+    /// ## Test Scenario
+    /// - Formats a package version
+    ///
+    /// ## Expected Outcome
+    /// - The package name is followed by the version
+    #[test]
+    fn test_package_version_display() {
+        let package = PackageVersion {
+            package: "Contoso.Service".to_string(),
+            version: "1.4.1207".to_string(),
+        };
+        assert_eq!(package.to_string(), "Contoso.Service 1.4.1207");
+    }
+}
@@ -4,8 +4,8 @@
 //! types from the azure_devops_rust_api crate to our simpler, purpose-built domain models.
 
 use crate::models::{
-    CreatedBy, Label, MergeCommit, PullRequest, RepoDetails, WorkItem, WorkItemFieldChange,
-    WorkItemFields, WorkItemHistory, WorkItemHistoryFields,
+    CreatedBy, Label, MergeCommit, PullRequest, RepoDetails, SinceField, WorkItem,
+    WorkItemFieldChange, WorkItemFields, WorkItemHistory, WorkItemHistoryFields,
 };
 use azure_devops_rust_api::git::models as git_models;
 use azure_devops_rust_api::wit::models as wit_models;
@@ -44,10 +44,59 @@ impl From<git_models::GitPullRequest> for PullRequest {
                         .collect(),
                 )
             },
+            reviewers: pr
+                .reviewers
+                .into_iter()
+                .map(|r| CreatedBy {
+                    display_name: r
+                        .identity_ref
+                        .graph_subject_base
+                        .display_name
+                        .unwrap_or_default(),
+                })
+                .collect(),
         }
     }
 }
 
+/// Returns the date of `pr` compared against `--since`.
+///
+/// Commit dates are the committer date, or the author date when the listing
+/// has no committer. PRs whose commit carries neither fall back to the
+/// closed date.
+pub(crate) fn window_date(
+    pr: &git_models::GitPullRequest,
+    field: SinceField,
+) -> Option<chrono::DateTime<chrono::Utc>> {
+    let commit = match field {
+        SinceField::Closed => None,
+        SinceField::MergeCommit => pr.last_merge_commit.as_ref(),
+        SinceField::TargetCommit => pr.last_merge_target_commit.as_ref(),
+    };
+    commit
+        .and_then(|c| {
+            c.committer
+                .as_ref()
+                .and_then(|u| u.date)
+                .or_else(|| c.author.as_ref().and_then(|u| u.date))
+        })
+        .or(pr.closed_date)
+        .and_then(|d| chrono::DateTime::from_timestamp(d.unix_timestamp(), d.nanosecond()))
+}
+
+/// Work item fields mapped to dedicated `WorkItemFields` members.
+///
+/// Any other field returned by the API ends up in `custom_fields`.
+const MAPPED_WORK_ITEM_FIELDS: &[&str] = &[
+    "System.Title",
+    "System.State",
+    "System.WorkItemType",
+    "System.AssignedTo",
+    "System.IterationPath",
+    "System.Description",
+    "Microsoft.VSTS.TCM.ReproSteps",
+];
+
 /// Convert azure_devops_rust_api WorkItem to our WorkItem model.
 impl From<wit_models::WorkItem> for WorkItem {
     fn from(wi: wit_models::WorkItem) -> Self {
@@ -83,6 +132,15 @@ impl From<wit_models::WorkItem> for WorkItem {
                     .get("Microsoft.VSTS.TCM.ReproSteps")
                     .and_then(|v| v.as_str().map(String::from)),
                 state_color: None, // Populated separately from API
+                custom_fields: fields
+                    .as_object()
+                    .map(|map| {
+                        map.iter()
+                            .filter(|(key, _)| !MAPPED_WORK_ITEM_FIELDS.contains(&key.as_str()))
+                            .map(|(key, value)| (key.clone(), value.clone()))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
             },
             history: vec![], // History is populated separately
         }
@@ -428,6 +486,44 @@ mod tests {
         assert!(converted.labels.is_none()); // Empty vec becomes None
     }
 
+    /// # Since Window Date
+    ///
+    /// Tests the date compared against `--since` for each since field.
+    ///
+    /// ## Test Scenario
+    /// - Creates a PR closed at one time whose merge commit was committed later
+    /// - Reads the window date for every since field
+    ///
+    /// ## Expected Outcome
+    /// - `closed` uses the closed date, `merge-commit` the committer date
+    /// - `target-commit` falls back to the closed date without a target commit
+    #[test]
+    fn test_window_date() {
+        let closed = time::OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+        let committed = time::OffsetDateTime::from_unix_timestamp(1_710_000_000).unwrap();
+        let mut pr = create_test_git_pull_request(
+            1,
+            None,
+            Some(closed),
+            None,
+            Some("abc123".to_string()),
+            vec![],
+        );
+        if let Some(commit) = pr.last_merge_commit.as_mut() {
+            commit.committer = Some(git_models::GitUserDate {
+                date: Some(committed),
+                email: None,
+                image_url: None,
+                name: None,
+            });
+        }
+
+        let timestamp = |field| window_date(&pr, field).map(|d| d.timestamp());
+        assert_eq!(timestamp(SinceField::Closed), Some(1_700_000_000));
+        assert_eq!(timestamp(SinceField::MergeCommit), Some(1_710_000_000));
+        assert_eq!(timestamp(SinceField::TargetCommit), Some(1_700_000_000));
+    }
+
     /// # GitPullRequest to PullRequest - Multiple Labels
     ///
     /// Tests conversion with multiple labels.
@@ -546,6 +642,33 @@ mod tests {
         assert!(converted.fields.iteration_path.is_none());
         assert!(converted.fields.description.is_none());
         assert!(converted.fields.repro_steps.is_none());
+        assert!(converted.fields.custom_fields.is_empty());
+    }
+
+    /// # WorkItem Conversion - Custom Fields
+    ///
+    /// Tests that fields without a dedicated member are kept as custom fields.
+    ///
+    /// ## Test Scenario
+    /// - Creates a WorkItem with a system field and a custom field
+    /// - Converts to our WorkItem model
+    ///
+    /// ## Expected Outcome
+    /// - Only the custom field appears in `custom_fields`
+    #[test]
+    fn test_work_item_from_wit_work_item_custom_fields() {
+        let fields = json!({
+            "System.Title": "Gated Work Item",
+            "Custom.QAApproved": true
+        });
+        let wi = create_test_work_item(790, fields);
+        let converted: WorkItem = wi.into();
+
+        assert_eq!(converted.fields.custom_fields.len(), 1);
+        assert_eq!(
+            converted.fields.custom_fields.get("Custom.QAApproved"),
+            Some(&json!(true))
+        );
     }
 
     /// # WorkItem Conversion - Partial Fields
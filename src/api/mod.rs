@@ -5,10 +5,17 @@
 //!
 //! ## Features
 //!
-//! - Pull request fetching with pagination support
-//! - Work item retrieval and state management
+//! - Pull request fetching with prefetching pagination and page progress
+//! - Work item retrieval in deduplicated batches, and state management
 //! - Terminal state analysis for migration workflows
 //! - PR labeling and tagging
+//! - Package version lookups in Azure Artifacts feeds
+//! - Build and branch policy status of pull requests
+//! - Publishing release notes to wiki pages
+//! - API version overrides and deprecation notice detection
+//! - Retries honoring `Retry-After` within a shared request budget
+//! - Tolerant decoding of list responses with schema drift diagnostics
+//! - Web URLs for pull requests, work items, commits and branch comparisons
 //!
 //! ## Example
 //!
@@ -31,16 +38,33 @@
 //! # }
 //! ```
 
+pub mod batching;
+pub mod build_status;
 mod client;
+pub mod feeds;
 mod mappers;
+pub mod paging;
+pub mod retry;
+pub mod schema;
 pub mod traits;
+pub mod urls;
+pub mod version;
+pub mod wiki;
 
 // Re-export the client and its public items
+pub use build_status::{CheckOutcome, CheckState, PrChecks};
 pub use client::{
-    AzureDevOpsClient, extract_merged_tags, filter_prs_with_tag, filter_prs_without_merged_tag,
+    AUDIT_LOG_TARGET, AzureDevOpsClient, EndpointClass, extract_merged_tags, filter_prs_with_tag,
+    filter_prs_without_merged_tag,
 };
+pub use feeds::{ArtifactsFeed, INTEGRATION_BUILD_FIELD, PackageVersion};
+pub use paging::{PageProgress, is_rate_limited};
+pub use retry::{RequestBudget, RetryPolicy, RetrySettings};
+pub use schema::{SchemaDiagnostics, SchemaWarning, SchemaWarningKind};
 pub use traits::{
     GitOperations, PullRequestOperations, PullRequestWorkItemsOperations, RealGitOperations,
     RealWitOperations, RepositoryOperations, WitOperations, WorkItemOperations,
     WorkItemUpdatesOperations,
 };
+pub use urls::{UrlBuilder, UrlTemplates};
+pub use wiki::{PublishedWikiPage, WikiPageTarget};
@@ -0,0 +1,308 @@
+//! Pipelined `$top`/`$skip` pagination.
+//!
+//! List endpoints such as pull requests return at most one page per request.
+//! Fetching the pages one after another makes large `--since` windows slow,
+//! so [`PagePipeline`] keeps the next pages in flight while the current one is
+//! processed. The number of requests in flight starts at one and doubles with
+//! every full page, up to a limit, so short lists cost a single request and
+//! long ones ramp up quickly. Pages are always consumed in order. Throttled
+//! requests are retried by the API client, so a failed page ends the listing.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::api::paging::PagePipeline;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> anyhow::Result<()> {
+//! let numbers: Vec<u32> = (0..250).collect();
+//! let pipeline = PagePipeline {
+//!     page_size: 100,
+//!     ..PagePipeline::default()
+//! };
+//! let fetched = pipeline
+//!     .run(
+//!         |skip| {
+//!             let page = numbers.iter().copied().skip(skip).take(100).collect();
+//!             async move { Ok(page) }
+//!         },
+//!         |n| *n < 180,
+//!         |progress| println!("{} pages", progress.pages),
+//!     )
+//!     .await?;
+//! assert_eq!(fetched.len(), 180);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+
+use anyhow::Result;
+use futures::stream::{FuturesOrdered, StreamExt};
+
+use crate::error::ApiError;
+
+/// Returns `true` if the error means the API is rate limiting requests.
+pub fn is_rate_limited(error: &anyhow::Error) -> bool {
+    if error.chain().any(|e| {
+        matches!(
+            e.downcast_ref::<ApiError>(),
+            Some(ApiError::RateLimited { .. })
+        )
+    }) {
+        return true;
+    }
+    let message = format!("{:#}", error).to_lowercase();
+    message.contains("429") || message.contains("too many requests")
+}
+
+/// Page-level progress reported after every page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageProgress {
+    /// Pages received so far.
+    pub pages: usize,
+    /// Items kept so far.
+    pub items: usize,
+}
+
+/// Settings for fetching a paginated list with prefetching.
+#[derive(Debug, Clone)]
+pub struct PagePipeline {
+    /// Items requested per page (`$top`).
+    pub page_size: usize,
+    /// Pages fetched before giving up, to bound runaway listings.
+    pub max_pages: usize,
+    /// Most page requests in flight at once.
+    pub max_in_flight: usize,
+}
+
+impl Default for PagePipeline {
+    fn default() -> Self {
+        Self {
+            page_size: 100,
+            max_pages: 100,
+            max_in_flight: 8,
+        }
+    }
+}
+
+impl PagePipeline {
+    /// Fetches pages until one is short or `keep` rejects an item.
+    ///
+    /// `fetch_page` is called with the number of items to skip. Items are
+    /// returned in page order; the first item `keep` rejects ends the listing
+    /// (e.g. the first PR older than `--since`) and requests still in flight
+    /// are dropped. `on_page` is called after every page.
+    pub async fn run<T, F, Fut>(
+        &self,
+        fetch_page: F,
+        mut keep: impl FnMut(&T) -> bool,
+        mut on_page: impl FnMut(PageProgress),
+    ) -> Result<Vec<T>>
+    where
+        F: Fn(usize) -> Fut,
+        Fut: Future<Output = Result<Vec<T>>>,
+    {
+        let page_size = self.page_size.max(1);
+        let max_in_flight = self.max_in_flight.max(1);
+        let fetch = |page: usize| fetch_page(page * page_size);
+
+        let mut in_flight = FuturesOrdered::new();
+        let mut next_page = 0;
+        let mut depth = 1;
+        let mut items = Vec::new();
+        let mut pages = 0;
+
+        loop {
+            while in_flight.len() < depth && next_page < self.max_pages {
+                in_flight.push_back(fetch(next_page));
+                next_page += 1;
+            }
+
+            let Some(page_items) = in_flight.next().await else {
+                return Err(ApiError::PaginationLimitExceeded {
+                    max: self.max_pages,
+                    retrieved: items.len(),
+                }
+                .into());
+            };
+
+            let page_items = page_items?;
+            pages += 1;
+
+            let full = page_items.len() >= page_size;
+            let mut stopped = false;
+            for item in page_items {
+                if !keep(&item) {
+                    stopped = true;
+                    break;
+                }
+                items.push(item);
+            }
+            on_page(PageProgress {
+                pages,
+                items: items.len(),
+            });
+
+            if stopped || !full {
+                tracing::debug!(
+                    "Pagination complete after {} pages: stopped={}, last_page_full={}",
+                    pages,
+                    stopped,
+                    full
+                );
+                return Ok(items);
+            }
+            depth = (depth * 2).min(max_in_flight);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn pipeline() -> PagePipeline {
+        PagePipeline {
+            page_size: 10,
+            max_pages: 20,
+            max_in_flight: 4,
+        }
+    }
+
+    /// # Rate Limit Detection
+    ///
+    /// Tests recognizing throttling errors.
+    ///
+    /// ## Test Scenario
+    /// - Checks a typed rate limit error, a wrapped HTTP 429 message and an
+    ///   unrelated error
+    ///
+    /// ## Expected Outcome
+    /// - Only the first two are rate limits
+    #[test]
+    fn test_is_rate_limited() {
+        let typed = anyhow::Error::new(ApiError::RateLimited {
+            retry_after_seconds: 0,
+        })
+        .context("Failed to add label to pull request");
+        assert!(is_rate_limited(&typed));
+
+        let http = anyhow::anyhow!("HTTP status client error (429 Too Many Requests)")
+            .context("Failed to add label to pull request");
+        assert!(is_rate_limited(&http));
+
+        assert!(!is_rate_limited(&anyhow::anyhow!("Permission denied")));
+    }
+
+    /// # Prefetching Pages
+    ///
+    /// Tests that pages are prefetched, consumed in order and stop early.
+    ///
+    /// ## Test Scenario
+    /// - Lists 95 items in pages of 10, keeping items below 67
+    ///
+    /// ## Expected Outcome
+    /// - Items 0..67 are returned in order with progress for each page
+    /// - More than one request was in flight, but not past the limit
+    #[tokio::test]
+    async fn test_pipeline_prefetches_in_order() {
+        let requested = Mutex::new(Vec::new());
+        let mut progress = Vec::new();
+
+        let items = pipeline()
+            .run(
+                |skip| {
+                    requested.lock().unwrap().push(skip);
+                    let page: Vec<usize> = (skip..(skip + 10).min(95)).collect();
+                    async move {
+                        // Yield so several requests are pending at once
+                        tokio::task::yield_now().await;
+                        Ok(page)
+                    }
+                },
+                |n| *n < 67,
+                |p| progress.push(p),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(items, (0..67).collect::<Vec<_>>());
+        assert_eq!(progress.len(), 7);
+        assert_eq!(
+            progress.last(),
+            Some(&PageProgress {
+                pages: 7,
+                items: 67
+            })
+        );
+        let requested = requested.into_inner().unwrap();
+        assert!(requested.len() > 7, "next pages were prefetched");
+        assert!(requested.len() <= 7 + 4);
+    }
+
+    /// # Failed Page
+    ///
+    /// Tests that a failed page ends the listing without being requested
+    /// again, since the client already retries throttled requests.
+    ///
+    /// ## Test Scenario
+    /// - The second page of a listing is throttled
+    ///
+    /// ## Expected Outcome
+    /// - The throttling error is returned
+    /// - Skip 10 was requested once
+    #[tokio::test]
+    async fn test_pipeline_returns_failed_page() {
+        let requested = Mutex::new(Vec::new());
+        let error = pipeline()
+            .run(
+                |skip| {
+                    requested.lock().unwrap().push(skip);
+                    let result = if skip == 10 {
+                        Err(anyhow::anyhow!("429 Too Many Requests"))
+                    } else {
+                        Ok((skip..(skip + 10).min(25)).collect::<Vec<_>>())
+                    };
+                    async move { result }
+                },
+                |_| true,
+                |_| {},
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.to_string(), "429 Too Many Requests");
+        let requested = requested.into_inner().unwrap();
+        assert_eq!(requested.iter().filter(|s| **s == 10).count(), 1);
+    }
+
+    /// # Page Limit
+    ///
+    /// Tests that endless listings stop at `max_pages`.
+    ///
+    /// ## Test Scenario
+    /// - Every page is full
+    ///
+    /// ## Expected Outcome
+    /// - An error reports the limit and the items fetched so far
+    #[tokio::test]
+    async fn test_pipeline_page_limit() {
+        let error = pipeline()
+            .run(
+                |skip| async move { Ok((skip..skip + 10).collect::<Vec<_>>()) },
+                |_| true,
+                |_| {},
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<ApiError>(),
+            Some(ApiError::PaginationLimitExceeded {
+                max: 20,
+                retrieved: 200
+            })
+        ));
+    }
+}
@@ -0,0 +1,460 @@
+//! Retries for throttled and transiently failing Azure DevOps requests.
+//!
+//! Azure DevOps throttles clients that send many requests in a short time,
+//! answering with `429 Too Many Requests` (or `503`) and a `Retry-After`
+//! header. Without handling, a single throttled request fails the whole data
+//! loading phase it belongs to. This module provides a pipeline policy that:
+//!
+//! - Waits for the duration the server asks for in `Retry-After` (or
+//!   `retry-after-ms`), falling back to exponential backoff
+//! - Retries transient server errors and connection failures for reads only,
+//!   since a mutation that failed with a 5xx may already have been applied
+//! - Bounds the number of requests in flight through a [`RequestBudget`]
+//!   shared by every client created from the same configuration
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mergers::api::EndpointClass;
+//! use mergers::api::retry::{RetrySettings, parse_retry_after};
+//! use std::time::Duration;
+//!
+//! let read = RetrySettings::for_class(EndpointClass::Read);
+//! let write = RetrySettings::for_class(EndpointClass::Write);
+//!
+//! // Throttling is retried for every request, server errors only for reads
+//! assert!(read.retries_status(429) && write.retries_status(429));
+//! assert!(read.retries_status(502) && !write.retries_status(502));
+//!
+//! // The server's Retry-After wins over the computed backoff
+//! let retry_after = parse_retry_after("7", chrono::Utc::now());
+//! assert_eq!(read.delay(0, retry_after), Duration::from_secs(7));
+//! ```
+
+use super::client::EndpointClass;
+use async_trait::async_trait;
+use azure_core::error::ErrorKind;
+use azure_core::http::{
+    Context, Request,
+    headers::{Headers, RETRY_AFTER, RETRY_AFTER_MS, X_MS_RETRY_AFTER_MS},
+    policies::{Policy, PolicyResult},
+};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+/// Requests allowed in flight when no network concurrency is configured.
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 100;
+
+/// Longest `Retry-After` honored; longer waits are capped to this.
+pub const MAX_RETRY_AFTER: Duration = Duration::from_secs(300);
+
+/// Status codes retried for every request, including mutations.
+///
+/// Both mean the request was rejected before being processed.
+const THROTTLE_STATUSES: &[u16] = &[429, 503];
+
+/// Status codes additionally retried for read requests.
+const TRANSIENT_STATUSES: &[u16] = &[408, 500, 502, 504];
+
+/// How a request is retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetrySettings {
+    /// Retries after the first attempt; `0` disables retrying.
+    pub max_retries: u32,
+    /// Wait before the first retry when the server gives no `Retry-After`;
+    /// doubles on each further retry.
+    pub initial_backoff: Duration,
+    /// Longest computed backoff.
+    pub max_backoff: Duration,
+    /// Whether transient server errors and connection failures are retried
+    /// in addition to throttling.
+    pub retry_server_errors: bool,
+}
+
+impl RetrySettings {
+    /// Returns the settings used for an endpoint class.
+    ///
+    /// Reads retry throttling, transient server errors and connection
+    /// failures. Writes only retry throttling, as anything else may have
+    /// reached the server and retrying could apply a mutation twice.
+    pub fn for_class(class: EndpointClass) -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+            retry_server_errors: class == EndpointClass::Read,
+        }
+    }
+
+    /// Settings that never retry.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::for_class(EndpointClass::Write)
+        }
+    }
+
+    /// Returns whether a response with this status is retried.
+    pub fn retries_status(&self, status: u16) -> bool {
+        THROTTLE_STATUSES.contains(&status)
+            || (self.retry_server_errors && TRANSIENT_STATUSES.contains(&status))
+    }
+
+    /// Returns the wait before retry number `retry` (starting at 0).
+    ///
+    /// A server-provided `Retry-After` is used as is, capped at
+    /// [`MAX_RETRY_AFTER`]; otherwise the backoff doubles per retry up to
+    /// `max_backoff`.
+    pub fn delay(&self, retry: u32, retry_after: Option<Duration>) -> Duration {
+        match retry_after {
+            Some(wait) => wait.min(MAX_RETRY_AFTER),
+            None => self
+                .initial_backoff
+                .saturating_mul(2u32.saturating_pow(retry))
+                .min(self.max_backoff),
+        }
+    }
+}
+
+/// Parses a `Retry-After` header value.
+///
+/// The value is either a number of seconds or an HTTP date; dates in the past
+/// yield a zero wait. Returns `None` for anything else.
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - now)
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// Reads the wait requested by the server from response headers.
+///
+/// The millisecond variants are preferred as they are more precise.
+fn retry_after(headers: &Headers) -> Option<Duration> {
+    [RETRY_AFTER_MS, X_MS_RETRY_AFTER_MS]
+        .iter()
+        .find_map(|name| headers.get_optional_str(name)?.trim().parse::<u64>().ok())
+        .map(Duration::from_millis)
+        .or_else(|| parse_retry_after(headers.get_optional_str(&RETRY_AFTER)?, Utc::now()))
+}
+
+/// Limit on Azure DevOps requests in flight, shared between clients.
+///
+/// Cloning a budget shares it, so every client created from the same
+/// [`AzureDevOpsClient`](crate::api::AzureDevOpsClient) draws from one pool
+/// regardless of how many tasks issue requests concurrently.
+#[derive(Debug, Clone)]
+pub struct RequestBudget {
+    permits: Arc<Semaphore>,
+    limit: Arc<AtomicUsize>,
+}
+
+impl RequestBudget {
+    /// Creates a budget allowing `limit` requests in flight (at least one).
+    pub fn new(limit: usize) -> Self {
+        let limit = limit.max(1);
+        Self {
+            permits: Arc::new(Semaphore::new(limit)),
+            limit: Arc::new(AtomicUsize::new(limit)),
+        }
+    }
+
+    /// Returns the number of requests allowed in flight.
+    pub fn limit(&self) -> usize {
+        self.limit.load(Ordering::SeqCst)
+    }
+
+    /// Changes the number of requests allowed in flight (at least one).
+    ///
+    /// Lowering the limit takes effect as in-flight requests complete.
+    pub fn set_limit(&self, limit: usize) {
+        let limit = limit.max(1);
+        let previous = self.limit.swap(limit, Ordering::SeqCst);
+        if limit > previous {
+            self.permits.add_permits(limit - previous);
+        } else {
+            let excess = previous - limit;
+            let forgotten = self.permits.forget_permits(excess);
+            if forgotten < excess {
+                // The remaining permits are in use; retire them once released.
+                let permits = Arc::clone(&self.permits);
+                let remaining = (excess - forgotten) as u32;
+                tokio::spawn(async move {
+                    if let Ok(held) = permits.acquire_many(remaining).await {
+                        held.forget();
+                    }
+                });
+            }
+        }
+    }
+
+    /// Returns the number of requests that could start right now.
+    pub fn available(&self) -> usize {
+        self.permits.available_permits()
+    }
+}
+
+impl Default for RequestBudget {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CONCURRENT_REQUESTS)
+    }
+}
+
+/// Pipeline policy retrying throttled and transiently failing requests.
+///
+/// The policy is installed as a per-call policy on every Azure DevOps client
+/// created by [`AzureDevOpsClient`](crate::api::AzureDevOpsClient), replacing
+/// the generated client's own retry so that `Retry-After` is honored. A budget
+/// permit is held only while a request is in flight, so waiting for a retry
+/// does not block other requests.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    settings: RetrySettings,
+    budget: RequestBudget,
+}
+
+impl RetryPolicy {
+    /// Creates a policy with the given settings drawing from a budget.
+    pub fn new(settings: RetrySettings, budget: RequestBudget) -> Self {
+        Self { settings, budget }
+    }
+
+    /// Returns the retry settings.
+    pub fn settings(&self) -> &RetrySettings {
+        &self.settings
+    }
+}
+
+#[async_trait]
+impl Policy for RetryPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let mut retries = 0;
+        loop {
+            let result = {
+                let _permit = self.budget.permits.acquire().await;
+                next[0].send(ctx, request, &next[1..]).await
+            };
+
+            let (reason, wait) = match &result {
+                Ok(response) => {
+                    let status = u16::from(response.status());
+                    if !self.settings.retries_status(status) {
+                        return result;
+                    }
+                    (format!("HTTP {}", status), retry_after(response.headers()))
+                }
+                Err(e) if self.settings.retry_server_errors && *e.kind() == ErrorKind::Io => {
+                    (e.to_string(), None)
+                }
+                Err(_) => return result,
+            };
+
+            if retries >= self.settings.max_retries {
+                return result;
+            }
+            let delay = self.settings.delay(retries, wait);
+            retries += 1;
+            tracing::warn!(
+                endpoint = %request.url().path(),
+                "Azure DevOps request failed ({}), retrying in {:?} ({}/{})",
+                reason,
+                delay,
+                retries,
+                self.settings.max_retries
+            );
+
+            tokio::time::sleep(delay).await;
+            request.body_mut().reset().await?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::http::{AsyncRawResponse, Method, StatusCode, Url};
+    use std::sync::Mutex;
+
+    /// Policy answering with a fixed sequence of statuses, then 200.
+    #[derive(Debug)]
+    struct ScriptedTransport {
+        statuses: Mutex<Vec<u16>>,
+        calls: AtomicUsize,
+    }
+
+    impl ScriptedTransport {
+        fn new(statuses: &[u16]) -> Arc<Self> {
+            Arc::new(Self {
+                statuses: Mutex::new(statuses.iter().rev().copied().collect()),
+                calls: AtomicUsize::new(0),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl Policy for ScriptedTransport {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let status = self.statuses.lock().unwrap().pop().unwrap_or(200);
+            let mut headers = Headers::new();
+            headers.insert(RETRY_AFTER, "0");
+            Ok(AsyncRawResponse::from_bytes(
+                StatusCode::from(status),
+                headers,
+                "",
+            ))
+        }
+    }
+
+    async fn send(settings: RetrySettings, transport: &Arc<ScriptedTransport>) -> u16 {
+        let policy = RetryPolicy::new(settings, RequestBudget::new(1));
+        let next: Vec<Arc<dyn Policy>> = vec![transport.clone()];
+        let mut request = Request::new(
+            Url::parse("https://dev.azure.com/org/_apis/git").unwrap(),
+            Method::Get,
+        );
+        let response = policy
+            .send(&Context::new(), &mut request, &next)
+            .await
+            .unwrap();
+        u16::from(response.status())
+    }
+
+    /// # Retry-After Parsing
+    ///
+    /// Tests parsing of both `Retry-After` formats.
+    ///
+    /// ## Test Scenario
+    /// - Parses a number of seconds, a future and a past HTTP date, and garbage
+    ///
+    /// ## Expected Outcome
+    /// - Seconds and future dates yield the wait, past dates zero, garbage `None`
+    #[test]
+    fn test_parse_retry_after() {
+        let now = DateTime::parse_from_rfc2822("Wed, 21 Oct 2026 07:28:00 GMT")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(
+            parse_retry_after(" 30 ", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2026 07:28:45 GMT", now),
+            Some(Duration::from_secs(45))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2026 07:00:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+
+    /// # Retry Delay and Status Classes
+    ///
+    /// Tests backoff computation and which statuses each endpoint class retries.
+    ///
+    /// ## Test Scenario
+    /// - Computes delays with and without `Retry-After`
+    /// - Checks throttling and server error statuses for reads and writes
+    ///
+    /// ## Expected Outcome
+    /// - Backoff doubles up to the maximum; `Retry-After` is capped
+    /// - Writes retry only throttling, reads also transient server errors
+    #[test]
+    fn test_retry_settings() {
+        let read = RetrySettings::for_class(EndpointClass::Read);
+        let write = RetrySettings::for_class(EndpointClass::Write);
+
+        assert_eq!(read.delay(0, None), Duration::from_secs(1));
+        assert_eq!(read.delay(2, None), Duration::from_secs(4));
+        assert_eq!(read.delay(10, None), read.max_backoff);
+        assert_eq!(
+            read.delay(0, Some(Duration::from_secs(3600))),
+            MAX_RETRY_AFTER
+        );
+
+        for status in [429, 503] {
+            assert!(read.retries_status(status) && write.retries_status(status));
+        }
+        for status in [408, 500, 502, 504] {
+            assert!(read.retries_status(status) && !write.retries_status(status));
+        }
+        assert!(!read.retries_status(404));
+        assert_eq!(RetrySettings::none().max_retries, 0);
+    }
+
+    /// # Retry Policy
+    ///
+    /// Tests that the policy retries throttled and failing requests.
+    ///
+    /// ## Test Scenario
+    /// - Sends a read answered with 429 then 200
+    /// - Sends a write answered with 500
+    /// - Sends a read answered with more 503s than retries allow
+    ///
+    /// ## Expected Outcome
+    /// - The read succeeds after one retry
+    /// - The write is not retried
+    /// - The last failure is returned once retries are exhausted
+    #[tokio::test]
+    async fn test_retry_policy() {
+        let read = RetrySettings::for_class(EndpointClass::Read);
+        let write = RetrySettings::for_class(EndpointClass::Write);
+
+        let transport = ScriptedTransport::new(&[429]);
+        assert_eq!(send(read, &transport).await, 200);
+        assert_eq!(transport.calls.load(Ordering::SeqCst), 2);
+
+        let transport = ScriptedTransport::new(&[500]);
+        assert_eq!(send(write, &transport).await, 500);
+        assert_eq!(transport.calls.load(Ordering::SeqCst), 1);
+
+        let transport = ScriptedTransport::new(&[503; 5]);
+        assert_eq!(send(read, &transport).await, 503);
+        assert_eq!(transport.calls.load(Ordering::SeqCst), 4);
+    }
+
+    /// # Request Budget Limit
+    ///
+    /// Tests raising and lowering the number of requests in flight.
+    ///
+    /// ## Test Scenario
+    /// - Creates a budget, raises its limit, then lowers it
+    ///
+    /// ## Expected Outcome
+    /// - Available permits follow the limit; zero is treated as one
+    #[tokio::test]
+    async fn test_request_budget() {
+        let budget = RequestBudget::new(0);
+        assert_eq!(budget.limit(), 1);
+
+        budget.set_limit(8);
+        assert_eq!(budget.available(), 8);
+
+        let shared = budget.clone();
+        shared.set_limit(3);
+        assert_eq!(budget.limit(), 3);
+        assert_eq!(budget.available(), 3);
+    }
+}
@@ -0,0 +1,419 @@
+//! Tolerant decoding of Azure DevOps list responses.
+//!
+//! The generated API client deserializes a whole response at once, so a
+//! single renamed or retyped field in one item fails the entire request with
+//! an opaque message. List endpoints used while loading are decoded item by
+//! item instead:
+//!
+//! - Items that do not deserialize are skipped and reported
+//! - Fields the model does not know about are reported once per endpoint
+//! - Fields mergers relies on that are missing or `null` are reported
+//!
+//! Warnings are collected by [`SchemaDiagnostics`] and shown in the
+//! diagnostics panel of the PR selection screen. With `--dump-raw-response
+//! <DIR>`, every response that produced a new warning is stored in `DIR` so
+//! it can be attached to a bug report.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::api::schema::SchemaDiagnostics;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Item {
+//!     id: i32,
+//!     title: String,
+//! }
+//!
+//! let diagnostics = SchemaDiagnostics::default();
+//! let body = br#"{"count": 2, "value": [
+//!     {"id": 1, "title": "First", "priority": 2},
+//!     {"id": 2, "name": "Second"}
+//! ]}"#;
+//! let items: Vec<Item> = diagnostics.decode_list("items", &["title"], body)?;
+//!
+//! assert_eq!(items.len(), 1);
+//! assert_eq!(diagnostics.warnings().len(), 3);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use anyhow::Result;
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use crate::utils::{Clock, IdGen, SystemClock, TimestampIdGen};
+
+static DUMP_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Stores responses that produce schema warnings in `dir` (`--dump-raw-response`).
+pub fn enable_raw_response_dump(dir: PathBuf) {
+    let _ = DUMP_DIR.set(dir);
+}
+
+/// Returns the directory responses are stored in, if enabled.
+pub fn raw_response_dump_dir() -> Option<&'static Path> {
+    DUMP_DIR.get().map(PathBuf::as_path)
+}
+
+/// What kind of mismatch a [`SchemaWarning`] reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SchemaWarningKind {
+    /// An item could not be deserialized and was left out.
+    SkippedItem,
+    /// A field mergers relies on was missing or `null`.
+    MissingField,
+    /// Fields the model does not know about were ignored.
+    UnknownFields,
+}
+
+/// A mismatch between a response and the expected schema.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SchemaWarning {
+    /// Kind of mismatch.
+    pub kind: SchemaWarningKind,
+    /// Endpoint the response came from, e.g. `pull requests`.
+    pub endpoint: String,
+    /// Description of the mismatch.
+    pub message: String,
+}
+
+impl SchemaWarning {
+    /// Returns `true` if loaded data is incomplete because of the mismatch.
+    pub fn affects_data(&self) -> bool {
+        self.kind != SchemaWarningKind::UnknownFields
+    }
+}
+
+impl fmt::Display for SchemaWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.endpoint, self.message)
+    }
+}
+
+/// Collects schema warnings across all requests of a client.
+///
+/// Clones share the same warnings. Each distinct warning is kept and logged
+/// once.
+#[derive(Clone)]
+pub struct SchemaDiagnostics {
+    warnings: Arc<Mutex<BTreeSet<SchemaWarning>>>,
+    /// Clock and ids naming the files of `--dump-raw-response`.
+    clock: Arc<dyn Clock>,
+    ids: Arc<dyn IdGen>,
+}
+
+impl Default for SchemaDiagnostics {
+    fn default() -> Self {
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+        Self {
+            warnings: Arc::default(),
+            ids: Arc::new(TimestampIdGen::new(clock.clone())),
+            clock,
+        }
+    }
+}
+
+impl fmt::Debug for SchemaDiagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SchemaDiagnostics")
+            .field("warnings", &self.warnings)
+            .field("clock", &self.clock)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SchemaDiagnostics {
+    /// Names dumped responses after the time of `clock`, with ids read from
+    /// the same clock.
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.ids = Arc::new(TimestampIdGen::new(clock.clone()));
+        self.clock = clock;
+    }
+
+    /// Replaces the source of ids in the names of dumped responses.
+    pub fn set_id_gen(&mut self, ids: Arc<dyn IdGen>) {
+        self.ids = ids;
+    }
+
+    /// Returns the distinct warnings recorded so far.
+    pub fn warnings(&self) -> Vec<SchemaWarning> {
+        self.warnings
+            .lock()
+            .map(|w| w.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Records a warning, returning `true` if it had not been seen before.
+    fn record(&self, kind: SchemaWarningKind, endpoint: &str, message: String) -> bool {
+        let warning = SchemaWarning {
+            kind,
+            endpoint: endpoint.to_string(),
+            message,
+        };
+        let is_new = self
+            .warnings
+            .lock()
+            .map(|mut w| w.insert(warning.clone()))
+            .unwrap_or(false);
+        if is_new {
+            tracing::warn!("Azure DevOps response schema mismatch: {}", warning);
+        }
+        is_new
+    }
+
+    /// Decodes the `value` list of a response body item by item.
+    ///
+    /// `expected_fields` are the top-level item fields mergers relies on.
+    /// Only a body that is not a JSON list envelope is an error.
+    pub fn decode_list<T>(
+        &self,
+        endpoint: &str,
+        expected_fields: &[&str],
+        body: &[u8],
+    ) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned + Serialize,
+    {
+        let items = match serde_json::from_slice::<Value>(body) {
+            Ok(Value::Object(mut envelope)) => match envelope.remove("value") {
+                Some(Value::Array(items)) => items,
+                _ => {
+                    let hint = self.dump_hint(endpoint, body);
+                    anyhow::bail!("The {} response has no `value` list{}", endpoint, hint);
+                }
+            },
+            Ok(_) => {
+                let hint = self.dump_hint(endpoint, body);
+                anyhow::bail!("The {} response is not a JSON object{}", endpoint, hint);
+            }
+            Err(e) => {
+                let hint = self.dump_hint(endpoint, body);
+                anyhow::bail!("The {} response is not valid JSON: {}{}", endpoint, e, hint);
+            }
+        };
+
+        let mut new_warning = false;
+        let mut unknown_fields = BTreeSet::new();
+        let mut missing_fields = BTreeSet::new();
+        let mut decoded = Vec::with_capacity(items.len());
+
+        for (index, item) in items.iter().enumerate() {
+            for field in expected_fields {
+                if item.get(field).is_none_or(Value::is_null) {
+                    missing_fields.insert(*field);
+                }
+            }
+
+            match T::deserialize(item) {
+                Ok(parsed) => {
+                    if let (Some(raw), Ok(Value::Object(known))) =
+                        (item.as_object(), serde_json::to_value(&parsed))
+                    {
+                        unknown_fields.extend(
+                            raw.iter()
+                                .filter(|(key, value)| {
+                                    !known.contains_key(*key) && !is_empty(value)
+                                })
+                                .map(|(key, _)| key.clone()),
+                        );
+                    }
+                    decoded.push(parsed);
+                }
+                Err(e) => {
+                    new_warning |= self.record(
+                        SchemaWarningKind::SkippedItem,
+                        endpoint,
+                        format!("skipped {}: {}", describe_item(item, index), e),
+                    );
+                }
+            }
+        }
+
+        for field in missing_fields {
+            new_warning |= self.record(
+                SchemaWarningKind::MissingField,
+                endpoint,
+                format!("field `{}` is missing or null", field),
+            );
+        }
+        if !unknown_fields.is_empty() {
+            let fields = unknown_fields
+                .iter()
+                .map(|field| format!("`{}`", field))
+                .collect::<Vec<_>>()
+                .join(", ");
+            new_warning |= self.record(
+                SchemaWarningKind::UnknownFields,
+                endpoint,
+                format!("unknown fields ignored: {}", fields),
+            );
+        }
+
+        if new_warning {
+            self.dump_hint(endpoint, body);
+        }
+        Ok(decoded)
+    }
+
+    /// Stores `body` if `--dump-raw-response` is set, returning a hint naming the file.
+    fn dump_hint(&self, endpoint: &str, body: &[u8]) -> String {
+        let Some(dir) = raw_response_dump_dir() else {
+            return String::new();
+        };
+        match dump_response(dir, endpoint, body, self.clock.as_ref(), self.ids.as_ref()) {
+            Ok(path) => {
+                tracing::warn!("Stored the {} response in {}", endpoint, path.display());
+                format!(" (response stored in {})", path.display())
+            }
+            Err(e) => {
+                tracing::warn!("Failed to store the {} response: {:#}", endpoint, e);
+                String::new()
+            }
+        }
+    }
+}
+
+/// `null`, empty lists and empty objects are left out when models serialize.
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Array(items) => items.is_empty(),
+        Value::Object(fields) => fields.is_empty(),
+        _ => false,
+    }
+}
+
+fn describe_item(item: &Value, index: usize) -> String {
+    ["id", "pullRequestId"]
+        .iter()
+        .find_map(|key| item.get(key).and_then(Value::as_i64))
+        .map(|id| format!("item {}", id))
+        .unwrap_or_else(|| format!("item at index {}", index))
+}
+
+/// Writes `body` to `<dir>/<endpoint>-<timestamp>-<id>.json`, with the
+/// current time of `clock` and an id from `ids`.
+pub fn dump_response(
+    dir: &Path,
+    endpoint: &str,
+    body: &[u8],
+    clock: &dyn Clock,
+    ids: &dyn IdGen,
+) -> Result<PathBuf> {
+    use anyhow::Context;
+
+    std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let name = endpoint
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect::<String>();
+    let path = dir.join(format!(
+        "{}.json",
+        ids.next_id(&format!(
+            "{}-{}",
+            name,
+            clock.now().format("%Y%m%dT%H%M%SZ")
+        ))
+    ));
+    std::fs::write(&path, body).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{FixedClock, SequentialIdGen};
+    use chrono::{TimeZone, Utc};
+    use serde::Deserialize;
+    use tempfile::TempDir;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Item {
+        id: i32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        labels: Vec<String>,
+    }
+
+    /// # Decode List Leniently
+    ///
+    /// Tests decoding a list response whose items drifted from the model.
+    ///
+    /// ## Test Scenario
+    /// - Decodes items with an unknown field, a missing expected field, a
+    ///   retyped field, and empty values the model leaves out
+    /// - Decodes the same response again
+    /// - Decodes bodies without a list
+    ///
+    /// ## Expected Outcome
+    /// - Valid items are kept, the retyped item is skipped and reported
+    /// - Unknown and missing fields are reported, empty values are not
+    /// - Repeated warnings are recorded once
+    /// - A body without a `value` list is an error
+    #[test]
+    fn test_decode_list() {
+        let diagnostics = SchemaDiagnostics::default();
+        let body = br#"{"count": 3, "value": [
+            {"id": 1, "title": "First", "isDraft": false, "labels": []},
+            {"id": 2, "title": null, "reviewers": {}},
+            {"id": 3, "title": 42}
+        ]}"#;
+
+        let items: Vec<Item> = diagnostics.decode_list("items", &["title"], body).unwrap();
+        assert_eq!(items.iter().map(|i| i.id).collect::<Vec<_>>(), vec![1, 2]);
+
+        let warnings = diagnostics.warnings();
+        let messages: Vec<_> = warnings.iter().map(ToString::to_string).collect();
+        assert_eq!(messages.len(), 3, "{:?}", messages);
+        assert!(messages[0].starts_with("items: skipped item 3: invalid type"));
+        assert_eq!(messages[1], "items: field `title` is missing or null");
+        assert_eq!(messages[2], "items: unknown fields ignored: `isDraft`");
+        assert!(warnings[0].affects_data());
+        assert!(!warnings[2].affects_data());
+
+        let _: Vec<Item> = diagnostics.decode_list("items", &["title"], body).unwrap();
+        assert_eq!(diagnostics.warnings().len(), 3);
+
+        for body in [&br#"{"count": 0}"#[..], b"[]", b"<html>"] {
+            assert!(diagnostics.decode_list::<Item>("items", &[], body).is_err());
+        }
+    }
+
+    /// # Dump Raw Response
+    ///
+    /// Tests storing a response body for a bug report.
+    ///
+    /// ## Test Scenario
+    /// - Dumps two bodies for an endpoint name with spaces into a new
+    ///   directory with a fixed clock
+    ///
+    /// ## Expected Outcome
+    /// - The directory is created and the file holds the body unchanged
+    /// - Files are named after the clock's time and distinct ids
+    #[test]
+    fn test_dump_response() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("responses");
+        let clock = FixedClock::new(Utc.with_ymd_and_hms(2025, 3, 1, 12, 30, 0).unwrap());
+        let ids = SequentialIdGen::new();
+
+        let path = dump_response(&dir, "pull requests", b"{\"value\": 1}", &clock, &ids).unwrap();
+        let second = dump_response(&dir, "pull requests", b"{}", &clock, &ids).unwrap();
+
+        assert_eq!(
+            path.file_name().unwrap(),
+            "pull-requests-20250301T123000Z-1.json"
+        );
+        assert_eq!(
+            second.file_name().unwrap(),
+            "pull-requests-20250301T123000Z-2.json"
+        );
+        assert_eq!(std::fs::read(&path).unwrap(), b"{\"value\": 1}");
+    }
+}
@@ -0,0 +1,434 @@
+//! Web URLs for Azure DevOps pages.
+//!
+//! [`UrlBuilder`] is the one place that turns organization, project and
+//! repository names into links for the browser, release notes and reports.
+//! Names are percent-encoded as path segments, so projects like `My Project`
+//! or `Team (EU)` produce valid links, and Azure DevOps Server (on-premise)
+//! collections are supported through [`UrlBuilder::with_collection_url`].
+//! Boards behind vanity domains or other server layouts can override the pull
+//! request and work item links with [`UrlTemplates`].
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mergers::api::UrlBuilder;
+//!
+//! let urls = UrlBuilder::new("my-org", "My Project", "my-repo");
+//! assert_eq!(
+//!     urls.pull_request_url(42),
+//!     "https://dev.azure.com/my-org/My%20Project/_git/my-repo/pullrequest/42"
+//! );
+//! assert_eq!(
+//!     urls.work_item_url(7),
+//!     "https://dev.azure.com/my-org/My%20Project/_workitems/edit/7"
+//! );
+//! ```
+
+use anyhow::{Context, Result};
+use url::Url;
+
+/// Base URL of Azure DevOps Services.
+pub const DEFAULT_BASE_URL: &str = "https://dev.azure.com";
+
+/// Placeholders that URL templates may use.
+const TEMPLATE_PLACEHOLDERS: [&str; 4] = ["{organization}", "{project}", "{repository}", "{id}"];
+
+/// Custom link patterns for pull requests and work items.
+///
+/// A template is a full URL containing `{id}` and optionally
+/// `{organization}`, `{project}` and `{repository}`, e.g.
+/// `https://boards.example.com/{project}/items/{id}`. The names are
+/// percent-encoded as path segments when substituted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UrlTemplates {
+    pull_request: Option<String>,
+    work_item: Option<String>,
+}
+
+impl UrlTemplates {
+    /// Validates the templates; `None` keeps the standard layout.
+    pub fn new(pull_request: Option<String>, work_item: Option<String>) -> Result<Self> {
+        for template in pull_request.iter().chain(&work_item) {
+            validate_template(template)?;
+        }
+        Ok(Self {
+            pull_request,
+            work_item,
+        })
+    }
+
+    /// Returns the pull request URL template, if configured.
+    pub fn pull_request(&self) -> Option<&str> {
+        self.pull_request.as_deref()
+    }
+
+    /// Returns the work item URL template, if configured.
+    pub fn work_item(&self) -> Option<&str> {
+        self.work_item.as_deref()
+    }
+}
+
+/// Checks that a template has an `{id}`, only known placeholders and
+/// expands to an HTTP(S) URL.
+fn validate_template(template: &str) -> Result<()> {
+    if !template.contains("{id}") {
+        anyhow::bail!("URL template must contain {{id}}: {}", template);
+    }
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let end = rest[start..]
+            .find('}')
+            .with_context(|| format!("Unclosed placeholder in URL template: {}", template))?;
+        let placeholder = &rest[start..start + end + 1];
+        if !TEMPLATE_PLACEHOLDERS.contains(&placeholder) {
+            anyhow::bail!(
+                "Unknown placeholder {} in URL template: {} (expected one of {})",
+                placeholder,
+                template,
+                TEMPLATE_PLACEHOLDERS.join(", ")
+            );
+        }
+        rest = &rest[start + end + 1..];
+    }
+
+    let sample = TEMPLATE_PLACEHOLDERS
+        .iter()
+        .fold(template.to_string(), |url, placeholder| {
+            url.replace(placeholder, "x")
+        });
+    let url = Url::parse(&sample).with_context(|| format!("Invalid URL template: {}", template))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        anyhow::bail!("URL template must be an http(s) URL: {}", template);
+    }
+    Ok(())
+}
+
+/// Percent-encodes `value` as a single URL path segment.
+fn encode_segment(value: &str) -> String {
+    let mut url = Url::parse("http://localhost/").expect("valid URL");
+    url.path_segments_mut().expect("valid URL").push(value);
+    url.path()[1..].to_string()
+}
+
+/// Builds web URLs for a repository's pull requests, work items, commits and
+/// branch comparisons.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlBuilder {
+    /// Collection URL: `https://dev.azure.com/{organization}` or an
+    /// on-premise collection such as `https://tfs.example.com/tfs/DefaultCollection`.
+    collection: Url,
+    organization: String,
+    project: String,
+    repository: String,
+    templates: UrlTemplates,
+}
+
+impl UrlBuilder {
+    /// Creates a builder for an Azure DevOps Services organization.
+    pub fn new(
+        organization: impl AsRef<str>,
+        project: impl Into<String>,
+        repository: impl Into<String>,
+    ) -> Self {
+        let mut collection = Url::parse(DEFAULT_BASE_URL).expect("valid base URL");
+        collection
+            .path_segments_mut()
+            .expect("valid base URL")
+            .pop_if_empty()
+            .push(organization.as_ref());
+        Self {
+            collection,
+            organization: organization.as_ref().to_string(),
+            project: project.into(),
+            repository: repository.into(),
+            templates: UrlTemplates::default(),
+        }
+    }
+
+    /// Creates a builder for an Azure DevOps Server collection.
+    ///
+    /// `collection_url` is the collection's web URL, e.g.
+    /// `https://tfs.example.com/tfs/DefaultCollection`.
+    pub fn with_collection_url(
+        collection_url: &str,
+        project: impl Into<String>,
+        repository: impl Into<String>,
+    ) -> Result<Self> {
+        let collection = Url::parse(collection_url)
+            .with_context(|| format!("Invalid collection URL: {}", collection_url))?;
+        if collection.cannot_be_a_base() {
+            anyhow::bail!("Invalid collection URL: {}", collection_url);
+        }
+        // The collection name stands in for `{organization}` in templates
+        let organization = collection
+            .path_segments()
+            .and_then(|mut segments| segments.rfind(|s| !s.is_empty()))
+            .unwrap_or_default()
+            .to_string();
+        Ok(Self {
+            collection,
+            organization,
+            project: project.into(),
+            repository: repository.into(),
+            templates: UrlTemplates::default(),
+        })
+    }
+
+    /// Uses custom templates for pull request and work item URLs.
+    pub fn with_templates(mut self, templates: UrlTemplates) -> Self {
+        self.templates = templates;
+        self
+    }
+
+    /// Returns the project's home page.
+    pub fn project_url(&self) -> String {
+        self.url(&[]).to_string()
+    }
+
+    /// Returns the repository's web URL, which is also its HTTPS clone URL.
+    pub fn repository_url(&self) -> String {
+        self.url(&["_git", &self.repository]).to_string()
+    }
+
+    /// Returns the URL of a pull request.
+    pub fn pull_request_url(&self, pr_id: i32) -> String {
+        if let Some(template) = self.templates.pull_request() {
+            return self.expand(template, pr_id);
+        }
+        self.url(&["_git", &self.repository, "pullrequest", &pr_id.to_string()])
+            .to_string()
+    }
+
+    /// Returns the URL of a work item.
+    pub fn work_item_url(&self, work_item_id: i32) -> String {
+        if let Some(template) = self.templates.work_item() {
+            return self.expand(template, work_item_id);
+        }
+        self.url(&["_workitems", "edit", &work_item_id.to_string()])
+            .to_string()
+    }
+
+    /// Returns the URL of a commit in the repository.
+    pub fn commit_url(&self, commit_id: &str) -> String {
+        self.url(&["_git", &self.repository, "commit", commit_id])
+            .to_string()
+    }
+
+    /// Returns the URL comparing `target_branch` against `base_branch`.
+    pub fn compare_url(&self, base_branch: &str, target_branch: &str) -> String {
+        let mut url = self.url(&["_git", &self.repository, "branchCompare"]);
+        url.query_pairs_mut()
+            .append_pair("baseVersion", &format!("GB{}", base_branch))
+            .append_pair("targetVersion", &format!("GB{}", target_branch));
+        url.to_string()
+    }
+
+    /// Returns the URL of the repository file browser on `branch`.
+    pub fn branch_url(&self, branch: &str) -> String {
+        let mut url = self.url(&["_git", &self.repository]);
+        url.query_pairs_mut()
+            .append_pair("version", &format!("GB{}", branch));
+        url.to_string()
+    }
+
+    /// Returns the URL of the page creating a pull request from
+    /// `source_branch` into `target_branch`.
+    pub fn create_pull_request_url(&self, source_branch: &str, target_branch: &str) -> String {
+        let mut url = self.url(&["_git", &self.repository, "pullrequestcreate"]);
+        url.query_pairs_mut()
+            .append_pair("sourceRef", source_branch)
+            .append_pair("targetRef", target_branch);
+        url.to_string()
+    }
+
+    /// Substitutes the placeholders of a validated template.
+    fn expand(&self, template: &str, id: i32) -> String {
+        template
+            .replace("{organization}", &encode_segment(&self.organization))
+            .replace("{project}", &encode_segment(&self.project))
+            .replace("{repository}", &encode_segment(&self.repository))
+            .replace("{id}", &id.to_string())
+    }
+
+    /// Appends the project and `segments` to the collection URL.
+    fn url(&self, segments: &[&str]) -> Url {
+        let mut url = self.collection.clone();
+        url.path_segments_mut()
+            .expect("collection URL is a base")
+            .pop_if_empty()
+            .push(&self.project)
+            .extend(segments);
+        url
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// # Azure DevOps Services URLs
+    ///
+    /// Tests the URLs built for a dev.azure.com organization.
+    ///
+    /// ## Test Scenario
+    /// - Builds every URL kind for a plain organization, project and repository
+    ///
+    /// ## Expected Outcome
+    /// - URLs follow the Azure DevOps web layout
+    #[test]
+    fn test_services_urls() {
+        let urls = UrlBuilder::new("org", "proj", "repo");
+
+        assert_eq!(urls.project_url(), "https://dev.azure.com/org/proj");
+        assert_eq!(
+            urls.repository_url(),
+            "https://dev.azure.com/org/proj/_git/repo"
+        );
+        assert_eq!(
+            urls.pull_request_url(123),
+            "https://dev.azure.com/org/proj/_git/repo/pullrequest/123"
+        );
+        assert_eq!(
+            urls.work_item_url(456),
+            "https://dev.azure.com/org/proj/_workitems/edit/456"
+        );
+        assert_eq!(
+            urls.commit_url("abc123"),
+            "https://dev.azure.com/org/proj/_git/repo/commit/abc123"
+        );
+    }
+
+    /// # Escaping Names
+    ///
+    /// Tests that names with special characters stay inside their segment.
+    ///
+    /// ## Test Scenario
+    /// - Uses an organization, project and repository with spaces, parentheses,
+    ///   brackets, `#` and `/`
+    ///
+    /// ## Expected Outcome
+    /// - Spaces, `/` and `#` are percent-encoded; no extra path segments appear
+    #[test]
+    fn test_escaping_names() {
+        let urls = UrlBuilder::new("My Org", "Team (EU) [Core]", "api/v2#1");
+
+        assert_eq!(
+            urls.pull_request_url(1),
+            "https://dev.azure.com/My%20Org/Team%20(EU)%20[Core]/_git/api%2Fv2%231/pullrequest/1"
+        );
+    }
+
+    /// # On-Premise Collection URLs
+    ///
+    /// Tests URLs for an Azure DevOps Server collection.
+    ///
+    /// ## Test Scenario
+    /// - Builds URLs from collection URLs with and without a trailing slash
+    /// - Parses an invalid collection URL
+    ///
+    /// ## Expected Outcome
+    /// - The project follows the collection path exactly once
+    /// - The invalid URL is rejected
+    #[test]
+    fn test_collection_urls() {
+        for collection in [
+            "https://tfs.example.com/tfs/DefaultCollection",
+            "https://tfs.example.com/tfs/DefaultCollection/",
+        ] {
+            let urls = UrlBuilder::with_collection_url(collection, "proj", "repo").unwrap();
+            assert_eq!(
+                urls.work_item_url(7),
+                "https://tfs.example.com/tfs/DefaultCollection/proj/_workitems/edit/7"
+            );
+        }
+
+        assert!(UrlBuilder::with_collection_url("not a url", "proj", "repo").is_err());
+        assert!(UrlBuilder::with_collection_url("mailto:team@example.com", "p", "r").is_err());
+    }
+
+    /// # Custom URL Templates
+    ///
+    /// Tests pull request and work item links built from templates.
+    ///
+    /// ## Test Scenario
+    /// - Sets templates for a vanity domain and an on-premise layout
+    /// - Validates templates without `{id}`, with an unknown placeholder and
+    ///   with a non-HTTP scheme
+    ///
+    /// ## Expected Outcome
+    /// - Placeholders are substituted and names percent-encoded
+    /// - URLs without a template keep the standard layout
+    /// - Invalid templates are rejected
+    #[test]
+    fn test_url_templates() {
+        let templates = UrlTemplates::new(
+            Some("https://code.example.com/{project}/{repository}/pr/{id}".to_string()),
+            Some(
+                "https://tfs.example.com/tfs/{organization}/{project}/_workitems/edit/{id}"
+                    .to_string(),
+            ),
+        )
+        .unwrap();
+        let urls =
+            UrlBuilder::new("Default Collection", "My Project", "repo").with_templates(templates);
+
+        assert_eq!(
+            urls.pull_request_url(42),
+            "https://code.example.com/My%20Project/repo/pr/42"
+        );
+        assert_eq!(
+            urls.work_item_url(7),
+            "https://tfs.example.com/tfs/Default%20Collection/My%20Project/_workitems/edit/7"
+        );
+        assert_eq!(
+            urls.commit_url("abc"),
+            "https://dev.azure.com/Default%20Collection/My%20Project/_git/repo/commit/abc"
+        );
+
+        let collection =
+            UrlBuilder::with_collection_url("https://tfs.example.com/tfs/Main/", "p", "r")
+                .unwrap()
+                .with_templates(
+                    UrlTemplates::new(
+                        None,
+                        Some("https://wi.example.com/{organization}/{id}".into()),
+                    )
+                    .unwrap(),
+                );
+        assert_eq!(collection.work_item_url(3), "https://wi.example.com/Main/3");
+
+        assert!(UrlTemplates::new(Some("https://example.com/pr".into()), None).is_err());
+        assert!(UrlTemplates::new(None, Some("https://example.com/{team}/{id}".into())).is_err());
+        assert!(UrlTemplates::new(None, Some("ftp://example.com/{id}".into())).is_err());
+        assert!(UrlTemplates::new(None, Some("https://example.com/{id".into())).is_err());
+    }
+
+    /// # Branch URLs
+    ///
+    /// Tests the query strings of branch comparison, branch browsing and
+    /// pull request creation URLs.
+    ///
+    /// ## Test Scenario
+    /// - Builds each URL for a branch containing a slash
+    ///
+    /// ## Expected Outcome
+    /// - Branches are passed as encoded query values, `GB`-prefixed where
+    ///   the page expects a version
+    #[test]
+    fn test_compare_url() {
+        let urls = UrlBuilder::new("org", "proj", "repo");
+
+        assert_eq!(
+            urls.compare_url("main", "patch/main-v1.0.0"),
+            "https://dev.azure.com/org/proj/_git/repo/branchCompare?baseVersion=GBmain&targetVersion=GBpatch%2Fmain-v1.0.0"
+        );
+        assert_eq!(
+            urls.branch_url("patch/main-v1.0.0"),
+            "https://dev.azure.com/org/proj/_git/repo?version=GBpatch%2Fmain-v1.0.0"
+        );
+        assert_eq!(
+            urls.create_pull_request_url("patch/main-v1.0.0", "main"),
+            "https://dev.azure.com/org/proj/_git/repo/pullrequestcreate?sourceRef=patch%2Fmain-v1.0.0&targetRef=main"
+        );
+    }
+}
@@ -0,0 +1,286 @@
+//! Azure DevOps REST API version handling.
+//!
+//! Azure DevOps periodically retires API versions, and on-premise Azure DevOps
+//! Server installations only understand the versions that shipped with their
+//! release. This module keeps the version strings in one place and provides a
+//! pipeline policy that:
+//!
+//! - Rewrites the `api-version` query parameter when an override is configured
+//!   (`api_version` in the config file or `MERGERS_API_VERSION`)
+//! - Inspects response headers for deprecation notices and logs each distinct
+//!   notice once
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mergers::api::version::{DEFAULT_API_VERSION, resolve_api_version};
+//!
+//! // Preview endpoints keep their preview suffix when overridden
+//! assert_eq!(resolve_api_version("7.1-preview.1", "6.0"), "6.0-preview");
+//! assert_eq!(resolve_api_version("7.1", "6.0"), "6.0");
+//! assert_eq!(DEFAULT_API_VERSION, "7.1");
+//! ```
+
+use async_trait::async_trait;
+use azure_core::http::{
+    Context, Request,
+    policies::{Policy, PolicyResult},
+};
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex};
+
+/// The Azure DevOps REST API version mergers is developed and tested against.
+///
+/// Individual endpoints may use a preview variant of this version
+/// (e.g. `7.1-preview.1`); those are selected by the generated API client.
+pub const DEFAULT_API_VERSION: &str = "7.1";
+
+/// Name of the query parameter carrying the API version.
+pub const API_VERSION_PARAM: &str = "api-version";
+
+/// Response headers that Azure DevOps (and RFC 8594-style gateways) use to
+/// announce deprecated or soon-to-be-removed API versions.
+pub const DEPRECATION_HEADERS: &[&str] = &["deprecation", "sunset", "warning"];
+
+/// Computes the API version to send for a request when an override is configured.
+///
+/// If the override already carries a suffix (e.g. `6.0-preview.2`), it is used
+/// verbatim. Otherwise, a `-preview` suffix is kept when the endpoint's own
+/// version was a preview version, since preview endpoints reject plain versions.
+#[must_use]
+pub fn resolve_api_version(endpoint_version: &str, override_version: &str) -> String {
+    let override_version = override_version.trim();
+    if override_version.contains('-') {
+        return override_version.to_string();
+    }
+
+    if endpoint_version.contains("-preview") {
+        format!("{}-preview", override_version)
+    } else {
+        override_version.to_string()
+    }
+}
+
+/// Extracts deprecation notices from a set of response headers.
+///
+/// Header names are matched case-insensitively against [`DEPRECATION_HEADERS`].
+/// Each notice is formatted as `"<header>: <value>"`.
+#[must_use]
+pub fn deprecation_notices<'a>(
+    headers: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> Vec<String> {
+    headers
+        .into_iter()
+        .filter(|(name, _)| {
+            DEPRECATION_HEADERS
+                .iter()
+                .any(|h| h.eq_ignore_ascii_case(name))
+        })
+        .map(|(name, value)| format!("{}: {}", name.to_ascii_lowercase(), value.trim()))
+        .collect()
+}
+
+/// Pipeline policy applying the configured API version and collecting deprecation notices.
+///
+/// The policy is installed as a per-call policy on every Azure DevOps client
+/// created by [`AzureDevOpsClient`](crate::api::AzureDevOpsClient). Notices
+/// are deduplicated so that a deprecated endpoint hit hundreds of times during
+/// data loading is only logged once.
+#[derive(Debug, Clone, Default)]
+pub struct ApiVersionPolicy {
+    override_version: Option<String>,
+    notices: Arc<Mutex<BTreeSet<String>>>,
+}
+
+impl ApiVersionPolicy {
+    /// Creates a new policy with an optional API version override.
+    pub fn new(override_version: Option<String>) -> Self {
+        Self {
+            override_version: override_version.filter(|v| !v.trim().is_empty()),
+            notices: Arc::new(Mutex::new(BTreeSet::new())),
+        }
+    }
+
+    /// Returns the configured override, if any.
+    pub fn override_version(&self) -> Option<&str> {
+        self.override_version.as_deref()
+    }
+
+    /// Returns all distinct deprecation notices seen so far.
+    pub fn notices(&self) -> Vec<String> {
+        self.notices
+            .lock()
+            .map(|n| n.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Records a notice, returning `true` if it had not been seen before.
+    fn record(&self, notice: String) -> bool {
+        self.notices
+            .lock()
+            .map(|mut n| n.insert(notice))
+            .unwrap_or(false)
+    }
+
+    /// Rewrites the `api-version` query parameter of a URL according to the override.
+    fn apply_override(&self, url: &mut url::Url) {
+        let Some(override_version) = &self.override_version else {
+            return;
+        };
+
+        let pairs: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect();
+        let endpoint_version = pairs
+            .iter()
+            .find(|(k, _)| k == API_VERSION_PARAM)
+            .map(|(_, v)| v.clone())
+            .unwrap_or_else(|| DEFAULT_API_VERSION.to_string());
+        let version = resolve_api_version(&endpoint_version, override_version);
+
+        let mut query = url.query_pairs_mut();
+        query.clear();
+        for (k, v) in pairs.iter().filter(|(k, _)| k != API_VERSION_PARAM) {
+            query.append_pair(k, v);
+        }
+        query.append_pair(API_VERSION_PARAM, &version);
+    }
+}
+
+#[async_trait]
+impl Policy for ApiVersionPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        self.apply_override(request.url_mut());
+        let path = request.url().path().to_string();
+
+        let response = next[0].send(ctx, request, &next[1..]).await?;
+
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()));
+        for notice in deprecation_notices(headers) {
+            if self.record(notice.clone()) {
+                tracing::warn!(
+                    endpoint = %path,
+                    "Azure DevOps API deprecation notice: {}",
+                    notice
+                );
+            }
+        }
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// # Resolve API Version Override
+    ///
+    /// Tests how an override is combined with an endpoint's own version.
+    ///
+    /// ## Test Scenario
+    /// - Resolves plain and preview endpoint versions against plain and suffixed overrides
+    ///
+    /// ## Expected Outcome
+    /// - Plain overrides keep the preview suffix of preview endpoints
+    /// - Suffixed overrides are used verbatim
+    #[test]
+    fn test_resolve_api_version() {
+        assert_eq!(resolve_api_version("7.1", "6.0"), "6.0");
+        assert_eq!(resolve_api_version("7.1-preview", "6.0"), "6.0-preview");
+        assert_eq!(resolve_api_version("7.1-preview.3", " 6.0 "), "6.0-preview");
+        assert_eq!(
+            resolve_api_version("7.1-preview.3", "6.0-preview.2"),
+            "6.0-preview.2"
+        );
+        assert_eq!(resolve_api_version("7.1", "5.1-preview"), "5.1-preview");
+    }
+
+    /// # Deprecation Notice Detection
+    ///
+    /// Tests that deprecation headers are detected case-insensitively.
+    ///
+    /// ## Test Scenario
+    /// - Passes a mix of regular and deprecation-related headers
+    ///
+    /// ## Expected Outcome
+    /// - Only Deprecation, Sunset and Warning headers are reported
+    #[test]
+    fn test_deprecation_notices() {
+        let headers = vec![
+            ("Content-Type", "application/json"),
+            ("Deprecation", "true"),
+            ("SUNSET", "Wed, 01 Jan 2025 00:00:00 GMT"),
+            ("warning", "299 - \"api-version 5.0 is deprecated\""),
+            ("x-vss-e2eid", "abc"),
+        ];
+
+        let notices = deprecation_notices(headers);
+
+        assert_eq!(notices.len(), 3);
+        assert_eq!(notices[0], "deprecation: true");
+        assert!(notices[1].starts_with("sunset: "));
+        assert!(notices[2].contains("api-version 5.0 is deprecated"));
+    }
+
+    /// # API Version Query Rewrite
+    ///
+    /// Tests that the policy rewrites the `api-version` parameter in place.
+    ///
+    /// ## Test Scenario
+    /// - Applies an override to a URL with other query parameters
+    /// - Applies no override to the same URL
+    ///
+    /// ## Expected Outcome
+    /// - The version is replaced and other parameters are preserved
+    /// - Without override the URL is untouched
+    #[test]
+    fn test_apply_override() {
+        let original = "https://dev.azure.com/org/proj/_apis/git/pullrequests?searchCriteria.status=completed&api-version=7.1-preview.1";
+
+        let policy = ApiVersionPolicy::new(Some("6.0".to_string()));
+        let mut url = url::Url::parse(original).unwrap();
+        policy.apply_override(&mut url);
+        let pairs: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect();
+        assert!(pairs.contains(&("searchCriteria.status".to_string(), "completed".to_string())));
+        assert!(pairs.contains(&("api-version".to_string(), "6.0-preview".to_string())));
+        assert_eq!(pairs.iter().filter(|(k, _)| k == "api-version").count(), 1);
+
+        let policy = ApiVersionPolicy::new(None);
+        let mut url = url::Url::parse(original).unwrap();
+        policy.apply_override(&mut url);
+        assert_eq!(url.as_str(), original);
+    }
+
+    /// # Deprecation Notice Deduplication
+    ///
+    /// Tests that notices are only recorded once.
+    ///
+    /// ## Test Scenario
+    /// - Records the same notice twice and a different notice once
+    ///
+    /// ## Expected Outcome
+    /// - Only the first occurrence of each notice is reported as new
+    #[test]
+    fn test_notice_deduplication() {
+        let policy = ApiVersionPolicy::new(Some("  ".to_string()));
+        assert_eq!(policy.override_version(), None);
+
+        assert!(policy.record("deprecation: true".to_string()));
+        assert!(!policy.record("deprecation: true".to_string()));
+        assert!(policy.record("sunset: tomorrow".to_string()));
+        assert_eq!(policy.notices().len(), 2);
+    }
+}
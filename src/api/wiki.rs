@@ -0,0 +1,213 @@
+//! Azure DevOps wiki publishing.
+//!
+//! Release notes can be pushed to a wiki page, so they land where the team
+//! already looks. The page is created if it does not exist and replaced
+//! otherwise.
+//!
+//! # Config Format
+//!
+//! ```toml
+//! [release_notes_wiki]
+//! wiki = "Contoso.wiki"
+//! page = "/Releases/{version}"
+//! # project = "Platform"
+//! ```
+//!
+//! `{version}` in the page path is replaced with the release version.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::api::WikiPageTarget;
+//!
+//! let target = WikiPageTarget {
+//!     wiki: "Contoso.wiki".to_string(),
+//!     page: "Releases/{version}".to_string(),
+//!     project: None,
+//! };
+//! assert_eq!(target.page_path("1.4.0"), "/Releases/1.4.0");
+//! ```
+
+use super::client::{AzureDevOpsClient, EndpointClass};
+use anyhow::{Context, Result};
+use azure_core::http::StatusCode;
+use azure_devops_rust_api::wiki::models::WikiPageCreateOrUpdateParameters;
+use serde::{Deserialize, Serialize};
+
+/// A wiki page release notes are published to, as configured in
+/// `[release_notes_wiki]`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WikiPageTarget {
+    /// Name or ID of the wiki.
+    pub wiki: String,
+    /// Path of the page; `{version}` is replaced with the release version.
+    pub page: String,
+    /// Project of the wiki. Defaults to the configured project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+}
+
+impl WikiPageTarget {
+    /// Returns the page path for a release, always starting with `/`.
+    pub fn page_path(&self, version: &str) -> String {
+        let path = self.page.replace("{version}", version);
+        if path.starts_with('/') {
+            path
+        } else {
+            format!("/{}", path)
+        }
+    }
+}
+
+/// A wiki page release notes were published to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishedWikiPage {
+    /// Path of the page in the wiki.
+    pub path: String,
+    /// Web URL of the page, when Azure DevOps returned one.
+    pub url: Option<String>,
+    /// Whether the page was created rather than replaced.
+    pub created: bool,
+}
+
+impl std::fmt::Display for PublishedWikiPage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.url.as_deref().unwrap_or(&self.path))
+    }
+}
+
+impl AzureDevOpsClient {
+    /// Returns the ETag of a wiki page, or `None` if the page does not exist.
+    pub async fn fetch_wiki_page_version(
+        &self,
+        target: &WikiPageTarget,
+        path: &str,
+    ) -> Result<Option<String>> {
+        let response = self
+            .wiki(EndpointClass::Read)
+            .pages_client()
+            .get_page(
+                self.organization(),
+                target.project.as_deref().unwrap_or(self.project()),
+                &target.wiki,
+            )
+            .path(path)
+            .send()
+            .await;
+        match response {
+            Ok(response) => Ok(Some(
+                response
+                    .headers()
+                    .e_tag()
+                    .context("Wiki page response has no ETag")?
+                    .to_string(),
+            )),
+            Err(e) if e.http_status() == Some(StatusCode::NotFound) => Ok(None),
+            Err(e) => Err(e).with_context(|| {
+                format!("Failed to read page '{}' of wiki '{}'", path, target.wiki)
+            }),
+        }
+    }
+
+    /// Creates or replaces a wiki page with `content`.
+    pub async fn publish_wiki_page(
+        &self,
+        target: &WikiPageTarget,
+        path: &str,
+        content: &str,
+        comment: &str,
+    ) -> Result<PublishedWikiPage> {
+        let version = self.fetch_wiki_page_version(target, path).await?;
+        let created = version.is_none();
+
+        let result = self
+            .wiki(EndpointClass::Write)
+            .pages_client()
+            .create_or_update(
+                self.organization(),
+                WikiPageCreateOrUpdateParameters {
+                    content: Some(content.to_string()),
+                },
+                target.project.as_deref().unwrap_or(self.project()),
+                &target.wiki,
+                path,
+                version.unwrap_or_default(),
+            )
+            .comment(comment)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to publish page '{}' of wiki '{}'",
+                    path, target.wiki
+                )
+            });
+
+        let audit_target = format!("wiki {} page {}", target.wiki, path);
+        self.audit(
+            "publish wiki page",
+            &audit_target,
+            &result
+                .as_ref()
+                .map(|_| ())
+                .map_err(|e| anyhow::anyhow!("{:#}", e)),
+        );
+
+        let page = result?;
+        Ok(PublishedWikiPage {
+            path: page.path.unwrap_or_else(|| path.to_string()),
+            url: page.remote_url,
+            created,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// # Wiki Page Path
+    ///
+    /// Tests building the page path of a release.
+    ///
+    /// ## Test Scenario
+    /// - Renders paths with and without a leading slash and a placeholder
+    ///
+    /// ## Expected Outcome
+    /// - `{version}` is replaced and the path always starts with `/`
+    #[test]
+    fn test_wiki_page_path() {
+        let mut target = WikiPageTarget {
+            wiki: "Contoso.wiki".to_string(),
+            page: "/Releases/{version}".to_string(),
+            project: None,
+        };
+        assert_eq!(target.page_path("v1.4"), "/Releases/v1.4");
+
+        target.page = "Release Notes".to_string();
+        assert_eq!(target.page_path("v1.4"), "/Release Notes");
+    }
+
+    /// # Published Wiki Page Display
+    ///
+    /// Tests how a published page is reported.
+    ///
+    /// ## Test Scenario
+    /// - Formats a page with and without a web URL
+    ///
+    /// ## Expected Outcome
+    /// - The URL is shown when known, the path otherwise
+    #[test]
+    fn test_published_wiki_page_display() {
+        let mut page = PublishedWikiPage {
+            path: "/Releases/v1.4".to_string(),
+            url: Some("https://dev.azure.com/org/proj/_wiki/wikis/Contoso.wiki/12".to_string()),
+            created: true,
+        };
+        assert_eq!(
+            page.to_string(),
+            "https://dev.azure.com/org/proj/_wiki/wikis/Contoso.wiki/12"
+        );
+        page.url = None;
+        assert_eq!(page.to_string(), "/Releases/v1.4");
+    }
+}
@@ -1,28 +1,47 @@
 use anyhow::Result;
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
+use std::collections::HashSet;
 use std::io;
 use std::path::PathBuf;
 use std::process;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use mergers::{
     Args, AzureDevOpsClient, Commands, Config,
     config::Config as RawConfig,
+    core::operations::{
+        ConflictTimeout, ConflictTimeoutAction, PrTableLayout, ReleaseTagMode,
+        release_history::{self, ReleaseMetrics},
+        resolve_target_branches,
+    },
     core::runner::{
-        MergeRunnerConfig, NonInteractiveRunner, OutputFormat, ReleaseNotesRunner, RunResult,
+        AnalyzeRunner, BatchRepo, BatchRunner, BatchRunnerConfig, FanOutRunner, MergeRunnerConfig,
+        NonInteractiveRunner, OutputFormat, ReleaseNotesRunner, RunResult, StateReleaseNotesConfig,
+        StateReleaseNotesRunner, WatchRunner, WatchRunnerConfig, watch::notify_new_candidates,
+    },
+    core::state::{
+        MergeStateFile, RUNS_DIR_NAME, SCHEMA_VERSION, active_run_dirs, existing_path_for_repo,
+        find_run_state, gc_run_dirs, runs_dir, session_path, state_dir, state_files,
     },
     logging::{init_logging, parse_early_log_config},
     models::{
-        MergeAbortArgs, MergeArgs, MergeCompleteArgs, MergeContinueArgs, MergeSkipArgs,
-        MergeStatusArgs, MergeSubcommand, ReleaseNotesArgs,
+        AnalyzeArgs, AppConfig, BatchArgs, DefaultModeConfig, GcArgs, MergeAbortArgs, MergeArgs,
+        MergeCompleteArgs, MergeContinueArgs, MergeRecoverArgs, MergeResolveArgs, MergeSkipArgs,
+        MergeStatusArgs, MergeSubcommand, MetricsArgs, MetricsOutputFormat, PromoteArgs,
+        ReleaseNotesArgs, ReleaseNotesFromStateArgs, ReleaseNotesSubcommand, StateSubcommand,
+        StateUpgradeArgs, WatchArgs,
     },
     parsed_property::ParsedProperty,
-    ui::{App, run_app},
+    ui::{Accessibility, App, Hyperlinks, run_app},
+    utils::{CopyDestination, default_clock, native_path},
 };
 
 #[tokio::main]
@@ -37,6 +56,26 @@ async fn main() -> Result<()> {
 
     let args = Args::parse_with_default_mode();
 
+    // Handle --profile-run flag (shared by all commands)
+    if raw_args.iter().any(|arg| arg == "--profile-run") {
+        mergers::profiling::enable();
+    }
+
+    // Handle --dump-raw-response flag (shared by all commands)
+    if let Some(dir) = raw_args
+        .windows(2)
+        .find(|w| w[0] == "--dump-raw-response")
+        .map(|w| w[1].clone())
+        .or_else(|| {
+            raw_args
+                .iter()
+                .find_map(|arg| arg.strip_prefix("--dump-raw-response="))
+                .map(str::to_string)
+        })
+    {
+        mergers::api::schema::enable_raw_response_dump(PathBuf::from(dir));
+    }
+
     // Handle --create-config flag
     if args.create_config {
         Config::create_sample_config()?;
@@ -68,6 +107,14 @@ async fn main() -> Result<()> {
                     let result = run_skip(skip_args).await;
                     handle_run_result(result);
                 }
+                Some(MergeSubcommand::Resolve(resolve_args)) => {
+                    let result = run_resolve(resolve_args).await;
+                    handle_run_result(result);
+                }
+                Some(MergeSubcommand::Recover(recover_args)) => {
+                    let result = run_recover(recover_args).await;
+                    handle_run_result(result);
+                }
                 // No subcommand with -n flag → non-interactive merge mode
                 None if merge_args.ni.non_interactive => {
                     let result = run_non_interactive_merge(merge_args).await;
@@ -79,11 +126,71 @@ async fn main() -> Result<()> {
                 }
             }
         }
+        // Resume or roll back a paused merge (non-TUI)
+        Some(Commands::Continue(continue_args)) => {
+            let result = run_continue(&continue_args.merge_args()).await;
+            handle_run_result(result);
+        }
+        Some(Commands::Abort(abort_args)) => {
+            let result = run_abort(&abort_args.merge_args());
+            handle_run_result(result);
+        }
         // Release notes command (non-TUI)
         Some(Commands::ReleaseNotes(release_notes_args)) => {
-            if let Err(e) = run_release_notes(release_notes_args.clone()).await {
-                eprintln!("Error: {}", e);
-                process::exit(1);
+            let result = match &release_notes_args.subcommand {
+                Some(ReleaseNotesSubcommand::FromState(from_state_args)) => {
+                    run_release_notes_from_state(from_state_args)
+                }
+                None => run_release_notes(release_notes_args.clone()).await,
+            };
+            if let Err(e) = result {
+                exit_with_error(e);
+            }
+        }
+        // Promote a release candidate (non-TUI)
+        Some(Commands::Promote(promote_args)) => {
+            let result = run_promote(promote_args).await;
+            handle_run_result(result);
+        }
+        // Non-interactive merge of several repositories (non-TUI)
+        Some(Commands::Batch(batch_args)) => match run_batch(batch_args).await {
+            Ok(exit_code) => {
+                print_profile_report();
+                process::exit(exit_code as i32);
+            }
+            Err(e) => exit_with_error(e),
+        },
+        // Dependency graph export (non-TUI)
+        Some(Commands::Analyze(analyze_args)) => {
+            if let Err(e) = run_analyze(analyze_args.clone()).await {
+                exit_with_error(e);
+            }
+        }
+        // Watch for new merge candidates, optionally jumping into the TUI
+        Some(Commands::Watch(watch_args)) => {
+            if let Err(e) = run_watch(watch_args.clone()).await {
+                exit_with_error(e);
+            }
+        }
+        // Release metrics export (non-TUI, offline)
+        Some(Commands::Metrics(metrics_args)) => {
+            if let Err(e) = run_metrics(metrics_args) {
+                exit_with_error(e);
+            }
+        }
+        // Run directory cleanup (non-TUI, offline)
+        Some(Commands::Gc(gc_args)) => {
+            if let Err(e) = run_gc(gc_args) {
+                exit_with_error(e);
+            }
+        }
+        // State file maintenance (non-TUI, offline)
+        Some(Commands::State(state_args)) => {
+            let result = match &state_args.subcommand {
+                StateSubcommand::Upgrade(upgrade_args) => run_state_upgrade(upgrade_args),
+            };
+            if let Err(e) = result {
+                exit_with_error(e);
             }
         }
         // Migrate, Cleanup, or no command → TUI mode
@@ -92,9 +199,24 @@ async fn main() -> Result<()> {
         }
     }
 
+    print_profile_report();
     Ok(())
 }
 
+/// Prints per-phase timings when the run was started with `--profile-run`.
+fn print_profile_report() {
+    if let Some(report) = mergers::profiling::report() {
+        eprint!("{}", report);
+    }
+}
+
+/// Prints an error and exits with the exit code for its category.
+fn exit_with_error(e: anyhow::Error) -> ! {
+    eprintln!("Error: {}", e);
+    print_profile_report();
+    process::exit(mergers::error::exit_code(&e) as i32);
+}
+
 /// Handles run result by printing messages and setting exit code.
 fn handle_run_result(result: RunResult) {
     if let Some(ref msg) = result.message {
@@ -109,6 +231,7 @@ fn handle_run_result(result: RunResult) {
         eprintln!("State file: {}", path.display());
     }
 
+    print_profile_report();
     process::exit(result.exit_code as i32);
 }
 
@@ -116,14 +239,64 @@ fn handle_run_result(result: RunResult) {
 async fn run_interactive_tui(args: Args) -> Result<()> {
     // Resolve configuration from CLI args, environment variables, and config file
     let config = Arc::new(args.resolve_config()?);
+    run_tui(config, HashSet::new()).await
+}
 
+/// Runs the TUI with a resolved configuration.
+///
+/// In merge mode, PRs in `preselected_pr_ids` are selected once loaded.
+async fn run_tui(config: Arc<AppConfig>, preselected_pr_ids: HashSet<i32>) -> Result<()> {
     // Create Azure DevOps client
-    let client = AzureDevOpsClient::new(
+    let client = AzureDevOpsClient::new_with_api_version(
         config.shared().organization.value().clone(),
         config.shared().project.value().clone(),
         config.shared().repository.value().clone(),
-        config.shared().pat.value().clone(),
+        config.shared().pat.value().clone().into(),
+        config
+            .shared()
+            .api_version
+            .as_ref()
+            .map(|p| p.value().clone()),
     )?;
+    let clock = default_clock()?;
+    let client = client
+        .with_since_field(config.shared().since_field)
+        .with_max_concurrent_requests(*config.shared().max_concurrent_network.value())
+        .with_clock(clock.clone())
+        .with_disk_cache();
+    // Send mutations with the write PAT when one is configured
+    let client = match &config.shared().write_pat {
+        Some(write_pat) => client.with_write_pat(write_pat.value().clone().into()),
+        None => client,
+    };
+    // Request the release gate field along with the default work item fields
+    let client = match config.as_ref() {
+        AppConfig::Default {
+            default:
+                DefaultModeConfig {
+                    release_gate: Some(gate),
+                    ..
+                },
+            ..
+        } => client.with_extra_work_item_fields(vec![gate.field.clone()]),
+        _ => client,
+    };
+    // Let Azure DevOps filter by author and reviewer identity ids
+    let client = match config.as_ref() {
+        AppConfig::Default { default, .. } => client.with_pull_request_identities(
+            default.pr_filter.creator_id().map(str::to_string),
+            default.pr_filter.reviewer_id().map(str::to_string),
+        ),
+        _ => client,
+    };
+
+    // Resolve the selection session file before taking over the terminal
+    let session = match config.as_ref() {
+        AppConfig::Default { default, .. } => {
+            default.session.as_deref().map(session_path).transpose()?
+        }
+        _ => None,
+    };
 
     // Pull requests will be fetched by the appropriate loading state
     let pr_with_work_items = Vec::new();
@@ -137,6 +310,20 @@ async fn run_interactive_tui(args: Args) -> Result<()> {
 
     // Create app
     let mut app = App::new(pr_with_work_items, config.clone(), client);
+    app.set_clock(clock);
+    app.set_accessibility(Accessibility::from_env());
+    app.set_hyperlinks(Hyperlinks::from_env());
+    if let App::Merge(merge_app) = &mut app {
+        merge_app.set_preselected_pr_ids(preselected_pr_ids);
+        if let Ok(path) = PrTableLayout::path_for(
+            merge_app.organization(),
+            merge_app.project(),
+            merge_app.repository(),
+        ) {
+            merge_app.load_pr_table_layout(path);
+        }
+        merge_app.set_session_path(session);
+    }
 
     // Run app with unified state machine
     let result = run_app(&mut terminal, &mut app).await;
@@ -163,24 +350,334 @@ async fn run_release_notes(args: ReleaseNotesArgs) -> Result<()> {
     let runner_config = app_config.into_release_notes_runner_config();
     let runner = ReleaseNotesRunner::new(runner_config);
 
-    let output = runner.run().await?;
+    let notes = runner.run().await?;
+    println!("{}", notes.output);
+    report_copy(runner.copy_output(&notes.output)?);
+    if let Some(page) = runner.publish_to_wiki(&notes).await? {
+        let action = if page.created { "created" } else { "updated" };
+        eprintln!("Release notes wiki page {}: {}", action, page);
+    }
+
+    Ok(())
+}
+
+/// Runs `mergers release-notes from-state`: generates release notes from a
+/// state file without the API.
+fn run_release_notes_from_state(args: &ReleaseNotesFromStateArgs) -> Result<()> {
+    let config = RawConfig::load_from_file()
+        .unwrap_or_default()
+        .merge(RawConfig::load_from_env());
+    let state_path = match &args.state_file {
+        Some(path) => path.clone(),
+        None => find_run_state(&runs_dir()?, args.run.as_deref())?,
+    };
+    let runner = StateReleaseNotesRunner::new(StateReleaseNotesConfig {
+        state_path,
+        output_format: args.format,
+        grouped: args.group,
+        include_prs: args.include_prs,
+        copy_to_clipboard: args.copy,
+        clipboard: config.clipboard_options(),
+        url_templates: config.url_templates()?,
+        locale: config.release_notes_locale(args.locale.as_deref())?,
+    });
+
+    let output = runner.run()?;
     println!("{}", output);
+    report_copy(runner.copy_output(&output)?);
 
     Ok(())
 }
 
+/// Tells the user where copied release notes went.
+fn report_copy(destination: Option<CopyDestination>) {
+    match destination {
+        Some(CopyDestination::File(path)) => eprintln!(
+            "Clipboard unavailable; release notes written to {}",
+            path.display()
+        ),
+        Some(destination) => eprintln!("Release notes copied to {}", destination),
+        None => {}
+    }
+}
+
+/// Runs the metrics command.
+///
+/// Only releases of the repository from `--repository`, the environment or
+/// the config file are included, unless `--all-repositories` is given.
+fn run_metrics(args: &MetricsArgs) -> Result<()> {
+    let repository = if args.all_repositories {
+        None
+    } else {
+        args.shared.repository.clone().or_else(|| {
+            RawConfig::load_from_file()
+                .unwrap_or_default()
+                .merge(RawConfig::load_from_env())
+                .repository
+                .map(|p| p.value().clone())
+        })
+    };
+
+    let records: Vec<_> = release_history::load_history(&release_history::history_path()?)?
+        .into_iter()
+        .filter(|record| {
+            record.matches(repository.as_deref(), args.shared.target_branch.as_deref())
+        })
+        .collect();
+    let metrics = ReleaseMetrics::compute(&records);
+    let output = match args.output_format() {
+        MetricsOutputFormat::Json => format!("{}\n", metrics.to_json()?),
+        MetricsOutputFormat::Csv => metrics.to_csv(),
+    };
+    match &args.output {
+        Some(path) => {
+            std::fs::write(path, output)?;
+            eprintln!(
+                "Metrics for {} release(s) written to {}",
+                metrics.releases.len(),
+                path.display()
+            );
+        }
+        None => print!("{}", output),
+    }
+
+    Ok(())
+}
+
+/// Runs the gc command.
+fn run_gc(args: &GcArgs) -> Result<()> {
+    let state_dir = state_dir()?;
+    let report = gc_run_dirs(
+        &state_dir.join(RUNS_DIR_NAME),
+        &active_run_dirs(&state_dir),
+        args.max_age.map(chrono::Duration::from_std).transpose()?,
+        default_clock()?.now(),
+        args.dry_run,
+    )?;
+
+    let verb = if args.dry_run {
+        "Would remove"
+    } else {
+        "Removed"
+    };
+    for entry in &report.removed {
+        println!("{} {}", verb, entry.path.display());
+    }
+    eprintln!(
+        "{} {} run director{} ({:.1} MiB); kept {} in progress, {} recent",
+        verb,
+        report.removed.len(),
+        if report.removed.len() == 1 {
+            "y"
+        } else {
+            "ies"
+        },
+        report.freed() as f64 / (1024.0 * 1024.0),
+        report.active.len(),
+        report.recent.len(),
+    );
+
+    Ok(())
+}
+
+/// Runs `mergers state upgrade`: rewrites state files in the current schema version.
+fn run_state_upgrade(args: &StateUpgradeArgs) -> Result<()> {
+    let paths = match &args.repo {
+        Some(repo) => {
+            let Some(path) = existing_path_for_repo(&native_path(repo))? else {
+                anyhow::bail!("No state file found for {}", repo);
+            };
+            vec![path]
+        }
+        None => state_files(&state_dir()?),
+    };
+
+    let verb = if args.dry_run {
+        "Would upgrade"
+    } else {
+        "Upgraded"
+    };
+    let mut upgraded = 0;
+    let mut failed = 0;
+    for path in &paths {
+        match MergeStateFile::upgrade(path, args.dry_run) {
+            Ok(Some(version)) => {
+                upgraded += 1;
+                println!(
+                    "{} {} (schema {} -> {})",
+                    verb,
+                    path.display(),
+                    version,
+                    SCHEMA_VERSION
+                );
+            }
+            Ok(None) => {}
+            Err(e) => {
+                failed += 1;
+                eprintln!("Failed to upgrade {}: {:#}", path.display(), e);
+            }
+        }
+    }
+    eprintln!(
+        "{} {} state file(s) to schema {}; {} already current",
+        verb,
+        upgraded,
+        SCHEMA_VERSION,
+        paths.len() - upgraded - failed
+    );
+
+    if failed > 0 {
+        anyhow::bail!("{} state file(s) could not be upgraded", failed);
+    }
+    Ok(())
+}
+
+/// Runs the analyze command.
+async fn run_analyze(args: AnalyzeArgs) -> Result<()> {
+    let output_path = args.output.clone();
+    let output_format = args.output_format();
+    let app_config = Args {
+        command: Some(Commands::Analyze(args)),
+        create_config: false,
+    }
+    .resolve_config()?;
+    let runner = AnalyzeRunner::new(app_config.into_analyze_runner_config());
+
+    let report = runner.build_report().await?;
+    if let Some(plan) = &report.plan {
+        let recommended = plan.recommended_simulation();
+        eprintln!(
+            "Recommended ordering: {} ({} predicted conflict(s))",
+            recommended.ordering,
+            recommended.conflicts.len()
+        );
+    }
+    let output = report.render(output_format)?;
+    match output_path {
+        Some(path) => {
+            std::fs::write(&path, output)?;
+            eprintln!("Dependency report written to {}", path.display());
+        }
+        None => println!("{}", output),
+    }
+
+    Ok(())
+}
+
+/// Key pressed while `mergers watch` waits for the next poll.
+enum WatchKey {
+    /// Open the merge TUI with the pending candidates.
+    Merge,
+    /// Stop watching.
+    Quit,
+    /// The poll interval elapsed.
+    Timeout,
+}
+
+/// Runs the watch command.
+async fn run_watch(args: WatchArgs) -> Result<()> {
+    let interval = args.interval;
+    let notify = args.notify;
+    let select_by_state = args.select_by_state.clone();
+    let config = Arc::new(
+        Args {
+            command: Some(Commands::Watch(args)),
+            create_config: false,
+        }
+        .resolve_config()?,
+    );
+    let mut runner = WatchRunner::new(WatchRunnerConfig::from_shared(
+        config.shared(),
+        select_by_state,
+    ))?
+    .with_clock(default_clock()?);
+
+    eprintln!(
+        "Watching '{}' for new merge candidates every {} min (Enter/m: open merge TUI, q: quit)",
+        config.shared().dev_branch.value(),
+        interval.as_secs() / 60
+    );
+
+    loop {
+        match runner.poll().await {
+            Ok(outcome) => {
+                let now = runner.clock().local_now().format("%H:%M");
+                for candidate in &outcome.new_candidates {
+                    println!(
+                        "[{}] New candidate: PR #{} {} ({})",
+                        now, candidate.pr_id, candidate.title, candidate.author
+                    );
+                }
+                notify_new_candidates(notify, &outcome.new_candidates);
+                eprintln!(
+                    "[{}] {} candidate(s), {} new since last review",
+                    now, outcome.total_candidates, outcome.pending_candidates
+                );
+            }
+            Err(e) => eprintln!("Poll failed: {:#}", e),
+        }
+
+        match wait_for_watch_key(interval)? {
+            WatchKey::Merge => {
+                run_tui(config.clone(), runner.list().pending_ids()).await?;
+                runner.acknowledge()?;
+            }
+            WatchKey::Quit => break,
+            WatchKey::Timeout => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Waits up to `timeout` for a watch key press.
+///
+/// Raw mode is only enabled while waiting so that poll output and Ctrl+C
+/// behave normally in between.
+fn wait_for_watch_key(timeout: Duration) -> Result<WatchKey> {
+    enable_raw_mode()?;
+    let deadline = Instant::now() + timeout;
+    let result = loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break Ok(WatchKey::Timeout);
+        }
+        match event::poll(remaining) {
+            Ok(false) => {}
+            Ok(true) => match event::read() {
+                Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => match key.code {
+                    KeyCode::Enter | KeyCode::Char('m') => break Ok(WatchKey::Merge),
+                    KeyCode::Char('q') | KeyCode::Esc => break Ok(WatchKey::Quit),
+                    KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        break Ok(WatchKey::Quit);
+                    }
+                    _ => {}
+                },
+                Ok(_) => {}
+                Err(e) => break Err(e.into()),
+            },
+            Err(e) => break Err(e.into()),
+        }
+    };
+    disable_raw_mode()?;
+    result
+}
+
 /// Runs a non-interactive merge operation.
 async fn run_non_interactive_merge(args: &MergeArgs) -> RunResult {
     let config = match build_runner_config_from_merge_args(args) {
         Ok(c) => c,
         Err(e) => {
             return RunResult::error(
-                mergers::core::ExitCode::GeneralError,
+                mergers::core::ExitCode::ConfigError,
                 format!("Configuration error: {}", e),
             );
         }
     };
 
+    if !config.fan_out.is_empty() {
+        return FanOutRunner::new(config).run_to_result().await;
+    }
     let mut runner = NonInteractiveRunner::new(config);
     runner.run().await
 }
@@ -188,16 +685,19 @@ async fn run_non_interactive_merge(args: &MergeArgs) -> RunResult {
 /// Continues a merge operation after conflict resolution.
 async fn run_continue(args: &MergeContinueArgs) -> RunResult {
     let config = match build_minimal_runner_config(args.output, args.quiet) {
-        Ok(c) => c,
+        Ok(c) => MergeRunnerConfig {
+            reports: args.report.clone(),
+            ..c
+        },
         Err(e) => {
             return RunResult::error(
-                mergers::core::ExitCode::GeneralError,
+                mergers::core::ExitCode::ConfigError,
                 format!("Configuration error: {}", e),
             );
         }
     };
 
-    let repo_path = args.repo.as_ref().map(PathBuf::from);
+    let repo_path = args.repo.as_deref().map(native_path);
     let mut runner = NonInteractiveRunner::new(config);
     runner.continue_merge(repo_path.as_deref()).await
 }
@@ -205,16 +705,19 @@ async fn run_continue(args: &MergeContinueArgs) -> RunResult {
 /// Aborts a merge operation.
 fn run_abort(args: &MergeAbortArgs) -> RunResult {
     let config = match build_minimal_runner_config(args.output, args.quiet) {
-        Ok(c) => c,
+        Ok(c) => MergeRunnerConfig {
+            reports: args.report.clone(),
+            ..c
+        },
         Err(e) => {
             return RunResult::error(
-                mergers::core::ExitCode::GeneralError,
+                mergers::core::ExitCode::ConfigError,
                 format!("Configuration error: {}", e),
             );
         }
     };
 
-    let repo_path = args.repo.as_ref().map(PathBuf::from);
+    let repo_path = args.repo.as_deref().map(native_path);
     let mut runner = NonInteractiveRunner::new(config);
     runner.abort(repo_path.as_deref())
 }
@@ -225,13 +728,13 @@ fn run_status(args: &MergeStatusArgs) -> RunResult {
         Ok(c) => c,
         Err(e) => {
             return RunResult::error(
-                mergers::core::ExitCode::GeneralError,
+                mergers::core::ExitCode::ConfigError,
                 format!("Configuration error: {}", e),
             );
         }
     };
 
-    let repo_path = args.repo.as_ref().map(PathBuf::from);
+    let repo_path = args.repo.as_deref().map(native_path);
     let mut runner = NonInteractiveRunner::new(config);
     runner.status(repo_path.as_deref())
 }
@@ -239,16 +742,22 @@ fn run_status(args: &MergeStatusArgs) -> RunResult {
 /// Completes a merge operation.
 async fn run_complete(args: &MergeCompleteArgs) -> RunResult {
     let config = match build_minimal_runner_config(args.output, args.quiet) {
-        Ok(c) => c,
+        Ok(c) => MergeRunnerConfig {
+            release_tag: c.release_tag.max(ReleaseTagMode::from_flags(
+                args.release_tag,
+                args.push_release_tag,
+            )),
+            ..c
+        },
         Err(e) => {
             return RunResult::error(
-                mergers::core::ExitCode::GeneralError,
+                mergers::core::ExitCode::ConfigError,
                 format!("Configuration error: {}", e),
             );
         }
     };
 
-    let repo_path = args.repo.as_ref().map(PathBuf::from);
+    let repo_path = args.repo.as_deref().map(native_path);
     let mut runner = NonInteractiveRunner::new(config);
     runner
         .complete(repo_path.as_deref(), &args.next_state)
@@ -258,20 +767,129 @@ async fn run_complete(args: &MergeCompleteArgs) -> RunResult {
 /// Skips the current conflicting PR and continues.
 async fn run_skip(args: &MergeSkipArgs) -> RunResult {
     let config = match build_minimal_runner_config(args.output, args.quiet) {
-        Ok(c) => c,
+        Ok(c) => MergeRunnerConfig {
+            reports: args.report.clone(),
+            ..c
+        },
         Err(e) => {
             return RunResult::error(
-                mergers::core::ExitCode::GeneralError,
+                mergers::core::ExitCode::ConfigError,
                 format!("Configuration error: {}", e),
             );
         }
     };
 
-    let repo_path = args.repo.as_ref().map(PathBuf::from);
+    let repo_path = args.repo.as_deref().map(native_path);
     let mut runner = NonInteractiveRunner::new(config);
     runner.skip(repo_path.as_deref()).await
 }
 
+/// Resolves conflicts file by file, then continues the merge.
+async fn run_resolve(args: &MergeResolveArgs) -> RunResult {
+    let config = match build_minimal_runner_config(args.output, args.quiet) {
+        Ok(c) => c,
+        Err(e) => {
+            return RunResult::error(
+                mergers::core::ExitCode::ConfigError,
+                format!("Configuration error: {}", e),
+            );
+        }
+    };
+
+    let repo_path = args.repo.as_deref().map(native_path);
+    let mut runner = NonInteractiveRunner::new(config);
+    runner
+        .resolve(
+            repo_path.as_deref(),
+            &args.resolve_tool(),
+            !args.no_reuse,
+            !args.no_continue,
+        )
+        .await
+}
+
+/// Inspects and recovers a merge interrupted by a crash.
+async fn run_recover(args: &MergeRecoverArgs) -> RunResult {
+    let config = match build_minimal_runner_config(args.output, args.quiet) {
+        Ok(c) => c,
+        Err(e) => {
+            return RunResult::error(
+                mergers::core::ExitCode::ConfigError,
+                format!("Configuration error: {}", e),
+            );
+        }
+    };
+
+    let repo_path = args.repo.as_deref().map(native_path);
+    let mut runner = NonInteractiveRunner::new(config);
+    runner
+        .recover(
+            repo_path.as_deref(),
+            args.action(),
+            args.rescue_branch.as_deref(),
+        )
+        .await
+}
+
+/// Promotes a release candidate to the final release.
+async fn run_promote(args: &PromoteArgs) -> RunResult {
+    let Some(version) = args.release_version() else {
+        return RunResult::error(
+            mergers::core::ExitCode::GeneralError,
+            format!(
+                "Cannot derive the release version from '{}'; pass --version",
+                args.from
+            ),
+        );
+    };
+
+    let config = match build_runner_config_from_merge_args(&args.to_merge_args(version)) {
+        Ok(c) => c,
+        Err(e) => {
+            return RunResult::error(
+                mergers::core::ExitCode::ConfigError,
+                format!("Configuration error: {}", e),
+            );
+        }
+    };
+
+    let mut runner = NonInteractiveRunner::new(config);
+    runner.promote(&args.from).await
+}
+
+/// Merges the repositories of the `[[repos]]` config section.
+///
+/// Returns the aggregated exit code of the merges.
+async fn run_batch(args: &BatchArgs) -> Result<mergers::core::ExitCode> {
+    let repos = RawConfig::load_from_file()?.batch_repos(&args.repos)?;
+
+    let repos = repos
+        .iter()
+        .map(|repo| {
+            let config = build_runner_config_from_merge_args(&args.to_merge_args(repo))
+                .map(|mut config| {
+                    // A local_repo set outside [[repos]] belongs to a single repository
+                    config.local_repo = repo.local_repo.as_ref().map(PathBuf::from);
+                    config
+                })
+                .map_err(|e| e.to_string());
+            BatchRepo {
+                name: repo.name().to_string(),
+                repository: repo.repository.clone(),
+                config,
+            }
+        })
+        .collect();
+
+    let mut runner = BatchRunner::new(BatchRunnerConfig {
+        repos,
+        parallel: usize::from(args.parallel),
+        output_format: args.output,
+        quiet: args.quiet,
+    });
+    Ok(runner.run().await?.exit_code)
+}
+
 /// Builds MergeRunnerConfig from MergeArgs with full config resolution.
 fn build_runner_config_from_merge_args(args: &MergeArgs) -> Result<MergeRunnerConfig> {
     let shared = &args.shared;
@@ -309,6 +927,21 @@ fn build_runner_config_from_merge_args(args: &MergeArgs) -> Result<MergeRunnerCo
         .merge(env_config)
         .merge(cli_config);
 
+    let strict_states = merged.strict_work_item_states(args.strict_states)?;
+    let release_gate = merged.release_gate()?;
+    let build_checks = merged.build_check_mode(args.check_builds, args.require_green_build);
+    let release_tag = merged.release_tag_mode(args.release_tag, args.push_release_tag);
+    let sign_commits = merged.sign_commits(args.sign_commits);
+    let squash_message = merged.squash_message(args.squash, args.squash_message.as_deref());
+    let mainline_fallback = merged.mainline_fallback(args.mainline_fallback);
+    let url_templates = merged.url_templates()?;
+    let release_notes_locale = merged.release_notes_locale(None)?;
+    let selection_caps = merged.selection_caps();
+    let binary_conflicts = merged.binary_conflict_policy()?;
+    let cherry_pick_order = merged.cherry_pick_order(args.cherry_pick_order);
+    let work_item_fields = merged.work_item_field_rules()?;
+    let notifications = merged.notifications();
+
     // Extract required values
     let organization = merged
         .organization
@@ -330,16 +963,17 @@ fn build_runner_config_from_merge_args(args: &MergeArgs) -> Result<MergeRunnerCo
         .ok_or_else(|| anyhow::anyhow!("pat is required"))?
         .value()
         .clone();
+    let write_pat = merged.write_pat.map(|p| p.value().clone());
 
     // Extract optional values with defaults
     let dev_branch = merged
         .dev_branch
         .map(|p| p.value().clone())
         .unwrap_or_else(|| "dev".to_string());
-    let target_branch = merged
+    let (target_branch, fan_out) = merged
         .target_branch
-        .map(|p| p.value().clone())
-        .unwrap_or_else(|| "next".to_string());
+        .map(|p| resolve_target_branches(p.value()))
+        .unwrap_or_else(|| ("next".to_string(), Vec::new()));
     let tag_prefix = merged
         .tag_prefix
         .map(|p| p.value().clone())
@@ -359,6 +993,17 @@ fn build_runner_config_from_merge_args(args: &MergeArgs) -> Result<MergeRunnerCo
         .map(|p| *p.value())
         .unwrap_or(10);
     let since = shared.since.clone();
+    let since_field = merged.since_field.map(|p| *p.value()).unwrap_or_default();
+    let api_version = merged.api_version.map(|p| p.value().clone());
+
+    let conflict_timeout = args.ni.conflict_timeout.map(|wait| {
+        ConflictTimeout::new(
+            wait,
+            args.ni
+                .on_conflict_timeout
+                .unwrap_or(ConflictTimeoutAction::Skip),
+        )
+    });
 
     // Version is required for non-interactive mode
     let version = args
@@ -366,18 +1011,33 @@ fn build_runner_config_from_merge_args(args: &MergeArgs) -> Result<MergeRunnerCo
         .version
         .clone()
         .ok_or_else(|| anyhow::anyhow!("version is required for non-interactive mode"))?;
+    if args.ni.rc && !fan_out.is_empty() {
+        anyhow::bail!("release candidates are created for one target branch at a time");
+    }
 
     Ok(MergeRunnerConfig {
         organization,
         project,
         repository,
         pat,
+        write_pat,
         dev_branch,
         target_branch,
         version,
         tag_prefix,
         work_item_state,
         select_by_states: args.ni.select_by_state.clone(),
+        select_file: args.select_file.clone(),
+        strict_states,
+        release_gate,
+        build_checks,
+        release_tag,
+        sign_commits,
+        fan_out,
+        squash_message,
+        mainline_fallback,
+        url_templates,
+        release_notes_locale,
         local_repo,
         run_hooks,
         output_format: args.ni.output,
@@ -386,6 +1046,23 @@ fn build_runner_config_from_merge_args(args: &MergeArgs) -> Result<MergeRunnerCo
         max_concurrent_network,
         max_concurrent_processing,
         since,
+        since_field,
+        api_version,
+        release_candidate: args.ni.rc,
+        status_port: args.ni.status_port,
+        serve: args.ni.serve,
+        selection_hard_cap: selection_caps.hard,
+        force: args.ni.force,
+        work_item_comment: merged.work_item_comment.map(|p| p.value().clone()),
+        artifacts_feed: merged.artifacts_feed.map(|p| p.value().clone()),
+        work_item_fields,
+        notifications,
+        binary_conflicts,
+        cherry_pick_order,
+        conflict_timeout,
+        dry_run: args.ni.dry_run,
+        reports: args.ni.report.clone(),
+        clock: default_clock()?,
     })
 }
 
@@ -396,6 +1073,14 @@ fn build_minimal_runner_config(output: OutputFormat, quiet: bool) -> Result<Merg
     let file_config = RawConfig::load_from_file()?;
     let env_config = RawConfig::load_from_env();
     let merged = file_config.merge(env_config);
+    let binary_conflicts = merged.binary_conflict_policy()?;
+    let cherry_pick_order = merged.cherry_pick_order(None);
+    let work_item_fields = merged.work_item_field_rules()?;
+    let notifications = merged.notifications();
+    let release_tag = merged.release_tag_mode(false, false);
+    let sign_commits = merged.sign_commits(false);
+    let url_templates = merged.url_templates()?;
+    let release_notes_locale = merged.release_notes_locale(None)?;
 
     // Extract values, using empty strings for optional ones since these commands
     // will read the state file which has the actual values
@@ -412,13 +1097,14 @@ fn build_minimal_runner_config(output: OutputFormat, quiet: bool) -> Result<Merg
         .map(|p| p.value().clone())
         .unwrap_or_default();
     let pat = merged.pat.map(|p| p.value().clone()).unwrap_or_default();
+    let write_pat = merged.write_pat.map(|p| p.value().clone());
     let dev_branch = merged
         .dev_branch
         .map(|p| p.value().clone())
         .unwrap_or_else(|| "dev".to_string());
     let target_branch = merged
         .target_branch
-        .map(|p| p.value().clone())
+        .map(|p| resolve_target_branches(p.value()).0)
         .unwrap_or_else(|| "next".to_string());
     let tag_prefix = merged
         .tag_prefix
@@ -444,12 +1130,24 @@ fn build_minimal_runner_config(output: OutputFormat, quiet: bool) -> Result<Merg
         project,
         repository,
         pat,
+        write_pat,
         dev_branch,
         target_branch,
         version: String::new(), // Not needed for continue/abort/status/complete
         tag_prefix,
         work_item_state,
         select_by_states: None,
+        select_file: None,
+        strict_states: None,
+        release_gate: None,
+        build_checks: Default::default(),
+        release_tag,
+        sign_commits,
+        fan_out: Vec::new(),
+        squash_message: None,
+        mainline_fallback: false,
+        url_templates,
+        release_notes_locale,
         local_repo,
         run_hooks,
         output_format: output,
@@ -458,5 +1156,22 @@ fn build_minimal_runner_config(output: OutputFormat, quiet: bool) -> Result<Merg
         max_concurrent_network,
         max_concurrent_processing,
         since: None, // Not needed for continue/abort/status/complete
+        since_field: Default::default(),
+        api_version: merged.api_version.map(|p| p.value().clone()),
+        release_candidate: false,
+        status_port: None,
+        serve: None,
+        selection_hard_cap: None,
+        force: false,
+        work_item_comment: merged.work_item_comment.map(|p| p.value().clone()),
+        artifacts_feed: merged.artifacts_feed.map(|p| p.value().clone()),
+        work_item_fields,
+        notifications,
+        binary_conflicts,
+        cherry_pick_order,
+        conflict_timeout: None,
+        dry_run: false,
+        reports: Vec::new(),
+        clock: default_clock()?,
     })
 }
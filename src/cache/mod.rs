@@ -0,0 +1,410 @@
+//! On-disk cache of Azure DevOps data.
+//!
+//! Each TUI launch loads the same PRs, work items and work item histories
+//! again, which adds up on a release day with many launches. [`RepoCache`]
+//! keeps what is expensive to fetch on disk, per organization, project and
+//! repository:
+//!
+//! - PR merge commits, by PR id
+//! - linked work items with their state history, by work item id
+//! - the files changed by a commit, by commit id
+//!
+//! Work items change while a release is prepared, so cached work items are
+//! always revalidated by their revision, which acts as etag: only the
+//! revisions are requested, and only work items that changed are fetched
+//! again with their history. Work items mergers updates itself are evicted.
+//! PR merge commits are used without any request for [`cache_ttl`] after
+//! they were fetched. Commits cannot change, so their file lists never need
+//! revalidating. Every entry is dropped after [`MAX_ENTRY_AGE_DAYS`].
+//!
+//! The cache lives in `$XDG_CACHE_HOME/mergers` (or `~/.cache/mergers`),
+//! next to the release notes' [`WorkItemCache`].
+
+pub mod release_notes;
+pub mod store;
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use anyhow::Result;
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+use crate::core::operations::dependency_analysis::FileChange;
+use crate::models::{WorkItem, WorkItemFields, WorkItemHistory};
+use crate::utils::{TimeUnit, parse_duration_in};
+pub use release_notes::WorkItemCache;
+pub use store::{CacheEntry, CacheStore, Lookup, MAX_ENTRY_AGE_DAYS};
+
+/// Environment variable overriding how long PR merge commits are used
+/// without revalidating them, in hours or with a unit (e.g. `"90m"`).
+///
+/// `0` disables the cache.
+pub const CACHE_TTL_ENV: &str = "MERGERS_CACHE_TTL_HOURS";
+
+/// Hours PR merge commits are used without revalidating them.
+pub const DEFAULT_CACHE_TTL_HOURS: i64 = 12;
+
+/// Returns the directory holding mergers' caches.
+///
+/// Uses `$XDG_CACHE_HOME` if set, otherwise `~/.cache`.
+pub fn cache_dir() -> Result<PathBuf> {
+    let base = std::env::var("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| {
+            dirs::home_dir()
+                .map(|d| d.join(".cache"))
+                .ok_or(std::env::VarError::NotPresent)
+        })
+        .map_err(|_| anyhow::anyhow!("Could not determine home directory for cache path"))?;
+    Ok(base.join("mergers"))
+}
+
+/// Returns how long PR merge commits are used without revalidating them,
+/// from [`CACHE_TTL_ENV`], or `None` if the cache is disabled.
+pub fn cache_ttl() -> Option<Duration> {
+    let default = Duration::hours(DEFAULT_CACHE_TTL_HOURS);
+    let ttl = match std::env::var(CACHE_TTL_ENV) {
+        Ok(value) => match parse_duration_in(value.trim(), TimeUnit::Hours) {
+            Ok(ttl) => Duration::from_std(ttl).unwrap_or(default),
+            Err(e) => {
+                tracing::warn!("Ignoring {}={}: {:#}", CACHE_TTL_ENV, value, e);
+                default
+            }
+        },
+        Err(_) => default,
+    };
+    (ttl > Duration::zero()).then_some(ttl)
+}
+
+/// A work item as cached, with its state history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedWorkItem {
+    pub fields: WorkItemFields,
+    #[serde(default)]
+    pub history: Vec<WorkItemHistory>,
+}
+
+/// Work items looked up in a [`RepoCache`].
+#[derive(Debug, Default)]
+pub struct WorkItemLookup {
+    /// Cached work items' ids with the revision they were cached at; they
+    /// are used once that revision is confirmed as current.
+    pub cached: Vec<(i32, i32)>,
+    /// Ids of work items that are not cached.
+    pub missing: Vec<i32>,
+}
+
+/// Cached Azure DevOps data of one repository.
+///
+/// Shared by every clone of the [`AzureDevOpsClient`](crate::api::AzureDevOpsClient)
+/// it is attached to. Changes are written by [`save`](Self::save).
+#[derive(Debug)]
+pub struct RepoCache {
+    pr_commits: Mutex<CacheStore<String>>,
+    work_items: Mutex<CacheStore<CachedWorkItem>>,
+    commit_files: Mutex<CacheStore<Vec<FileChange>>>,
+}
+
+impl RepoCache {
+    /// Opens the cache of a repository in [`cache_dir`], or returns `None`
+    /// if the cache is disabled or has no directory.
+    pub fn open(organization: &str, project: &str, repository: &str) -> Option<Arc<Self>> {
+        let ttl = cache_ttl()?;
+        let dir = match cache_dir() {
+            Ok(dir) => dir,
+            Err(e) => {
+                tracing::debug!("Not caching Azure DevOps data: {:#}", e);
+                return None;
+            }
+        };
+        let dir = repo_cache_dir(&dir, organization, project, repository);
+        Some(Arc::new(Self::open_in(&dir, ttl)))
+    }
+
+    /// Opens the cache stored in `dir`.
+    pub fn open_in(dir: &Path, ttl: Duration) -> Self {
+        Self {
+            pr_commits: Mutex::new(CacheStore::open(dir.join("pr_commits.json"), ttl)),
+            work_items: Mutex::new(CacheStore::open(dir.join("work_items.json"), ttl)),
+            commit_files: Mutex::new(CacheStore::open(dir.join("commit_files.json"), ttl)),
+        }
+    }
+
+    /// Writes changed entries to disk.
+    ///
+    /// The cache is best-effort, so failures are only logged.
+    pub fn save(&self) {
+        let results = [
+            lock(&self.pr_commits).save(),
+            lock(&self.work_items).save(),
+            lock(&self.commit_files).save(),
+        ];
+        for e in results.into_iter().filter_map(Result::err) {
+            tracing::warn!("Failed to save cache: {:#}", e);
+        }
+    }
+
+    /// Returns the merge commit cached for a PR, if fresh.
+    pub fn pr_commit(&self, pr_id: i32) -> Option<String> {
+        match lock(&self.pr_commits).lookup(&pr_id.to_string()) {
+            Lookup::Fresh(commit_id) => Some(commit_id.clone()),
+            _ => None,
+        }
+    }
+
+    /// Caches the merge commit of a PR.
+    pub fn set_pr_commit(&self, pr_id: i32, commit_id: &str) {
+        lock(&self.pr_commits).insert(pr_id.to_string(), commit_id.to_string(), None);
+    }
+
+    /// Looks up work items by id.
+    ///
+    /// Work items are never used without revalidating them, however recently
+    /// they were fetched; those cached without a revision count as missing.
+    pub fn work_items(&self, ids: &[i32]) -> WorkItemLookup {
+        let store = lock(&self.work_items);
+        let mut lookup = WorkItemLookup::default();
+        for &id in ids {
+            let rev = store
+                .entry(&id.to_string())
+                .and_then(|entry| entry.etag.as_deref()?.parse().ok());
+            match rev {
+                Some(rev) => lookup.cached.push((id, rev)),
+                None => lookup.missing.push(id),
+            }
+        }
+        lookup
+    }
+
+    /// Marks cached work items as current and returns them.
+    pub fn revalidate_work_items(&self, ids: &[i32]) -> Vec<WorkItem> {
+        let mut store = lock(&self.work_items);
+        ids.iter()
+            .filter_map(|&id| {
+                store
+                    .revalidate(&id.to_string())
+                    .map(|cached| to_work_item(id, cached))
+            })
+            .collect()
+    }
+
+    /// Caches a work item with its history at revision `rev`.
+    pub fn set_work_item(&self, work_item: &WorkItem, rev: Option<i32>) {
+        lock(&self.work_items).insert(
+            work_item.id.to_string(),
+            CachedWorkItem {
+                fields: work_item.fields.clone(),
+                history: work_item.history.clone(),
+            },
+            rev.map(|rev| rev.to_string()),
+        );
+    }
+
+    /// Drops a work item, e.g. after mergers changed it.
+    pub fn evict_work_item(&self, id: i32) {
+        lock(&self.work_items).remove(&id.to_string());
+    }
+
+    /// Returns the files changed by a commit, if cached.
+    pub fn commit_files(&self, commit_id: &str) -> Option<Vec<FileChange>> {
+        match lock(&self.commit_files).lookup(commit_id) {
+            Lookup::Fresh(files) => Some(files.clone()),
+            Lookup::Stale(entry) => Some(entry.value.clone()),
+            Lookup::Missing => None,
+        }
+    }
+
+    /// Caches the files changed by a commit.
+    pub fn set_commit_files(&self, commit_id: &str, files: &[FileChange]) {
+        lock(&self.commit_files).insert(commit_id.to_string(), files.to_vec(), None);
+    }
+}
+
+fn lock<V>(store: &Mutex<CacheStore<V>>) -> MutexGuard<'_, CacheStore<V>> {
+    store.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn to_work_item(id: i32, cached: &CachedWorkItem) -> WorkItem {
+    WorkItem {
+        id,
+        fields: cached.fields.clone(),
+        history: cached.history.clone(),
+    }
+}
+
+/// Returns the directory in `root` holding a repository's cache.
+fn repo_cache_dir(root: &Path, organization: &str, project: &str, repository: &str) -> PathBuf {
+    root.join(path_component(organization))
+        .join(path_component(project))
+        .join(path_component(repository))
+}
+
+/// Makes a name usable as a single path component.
+fn path_component(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::operations::dependency_analysis::ChangeType;
+    use crate::models::{WorkItemFieldChange, WorkItemHistoryFields};
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn work_item(id: i32, title: &str) -> WorkItem {
+        WorkItem {
+            id,
+            fields: WorkItemFields {
+                title: Some(title.to_string()),
+                state: Some("Done".to_string()),
+                work_item_type: Some("Bug".to_string()),
+                assigned_to: None,
+                iteration_path: None,
+                description: None,
+                repro_steps: None,
+                state_color: Some((0, 128, 0)),
+                custom_fields: HashMap::from([(
+                    "Custom.QAApproved".to_string(),
+                    serde_json::json!(true),
+                )]),
+            },
+            history: vec![WorkItemHistory {
+                rev: 3,
+                revised_date: "2026-10-01T10:00:00Z".to_string(),
+                fields: Some(WorkItemHistoryFields {
+                    state: Some(WorkItemFieldChange {
+                        new_value: Some("Done".to_string()),
+                    }),
+                    changed_date: None,
+                }),
+            }],
+        }
+    }
+
+    /// # Work Item Round Trip
+    ///
+    /// Tests that work items come back from disk as they were cached.
+    ///
+    /// ## Test Scenario
+    /// - Caches a work item with a custom field and history, saves and
+    ///   reopens the cache
+    /// - Revalidates it, then evicts it
+    ///
+    /// ## Expected Outcome
+    /// - The just cached work item is listed with its revision rather than
+    ///   used as-is; uncached ids are missing
+    /// - Revalidating returns its fields, custom field and history; the state
+    ///   color is not cached
+    /// - Once evicted it is missing, also after reopening the cache
+    #[test]
+    fn test_work_item_round_trip() {
+        let temp = TempDir::new().unwrap();
+        let cache = RepoCache::open_in(temp.path(), Duration::hours(1));
+        cache.set_work_item(&work_item(7, "Crash on export"), Some(3));
+        cache.save();
+
+        let cache = RepoCache::open_in(temp.path(), Duration::hours(1));
+        let lookup = cache.work_items(&[7, 8]);
+        assert_eq!(lookup.cached, [(7, 3)]);
+        assert_eq!(lookup.missing, [8]);
+        let revalidated = cache.revalidate_work_items(&[7, 8]);
+        assert_eq!(revalidated.len(), 1);
+        let cached = &revalidated[0];
+        assert_eq!(cached.fields.title.as_deref(), Some("Crash on export"));
+        assert_eq!(cached.fields.state_color, None);
+        assert_eq!(
+            cached.fields.custom_fields.get("Custom.QAApproved"),
+            Some(&serde_json::json!(true))
+        );
+        assert_eq!(cached.history[0].rev, 3);
+
+        cache.evict_work_item(7);
+        cache.save();
+        assert_eq!(cache.work_items(&[7]).missing, [7]);
+        let cache = RepoCache::open_in(temp.path(), Duration::hours(1));
+        assert_eq!(cache.work_items(&[7]).missing, [7]);
+    }
+
+    /// # PR Commits and Commit Files
+    ///
+    /// Tests the caches that need no revalidation.
+    ///
+    /// ## Test Scenario
+    /// - Caches a PR merge commit and a commit's changed files, then reopens
+    ///   the cache with a zero TTL
+    ///
+    /// ## Expected Outcome
+    /// - The PR merge commit is stale and no longer returned
+    /// - The commit's files are still returned
+    #[test]
+    fn test_pr_commits_and_commit_files() {
+        let temp = TempDir::new().unwrap();
+        let cache = RepoCache::open_in(temp.path(), Duration::hours(1));
+        cache.set_pr_commit(42, "abc123");
+        cache.set_commit_files(
+            "abc123",
+            &[FileChange::new(
+                "src/lib.rs".to_string(),
+                ChangeType::Modify,
+            )],
+        );
+        assert_eq!(cache.pr_commit(42).as_deref(), Some("abc123"));
+        cache.save();
+
+        let cache = RepoCache::open_in(temp.path(), Duration::zero());
+        assert_eq!(cache.pr_commit(42), None);
+        let files = cache.commit_files("abc123").unwrap();
+        assert_eq!(files[0].path, "src/lib.rs");
+        assert!(cache.commit_files("def456").is_none());
+    }
+
+    /// # Cache Location and TTL
+    ///
+    /// Tests where repository caches are stored and how the TTL is read.
+    ///
+    /// ## Test Scenario
+    /// - Builds the cache directory of a project name with a space and slash
+    /// - Sets `MERGERS_CACHE_TTL_HOURS` to various values
+    ///
+    /// ## Expected Outcome
+    /// - Names are made safe for use as path components
+    /// - The TTL defaults to 12 hours, takes hours or a unit, falls back to
+    ///   the default when invalid, and `0` disables the cache
+    #[test]
+    #[serial_test::serial]
+    fn test_cache_location_and_ttl() {
+        assert_eq!(
+            repo_cache_dir(Path::new("/cache"), "org", "My Project/A", "repo"),
+            Path::new("/cache/org/My_Project_A/repo")
+        );
+
+        // SAFETY: serialized test; no other thread reads this variable
+        unsafe { std::env::remove_var(CACHE_TTL_ENV) };
+        assert_eq!(cache_ttl(), Some(Duration::hours(DEFAULT_CACHE_TTL_HOURS)));
+
+        unsafe { std::env::set_var(CACHE_TTL_ENV, "2") };
+        assert_eq!(cache_ttl(), Some(Duration::hours(2)));
+
+        unsafe { std::env::set_var(CACHE_TTL_ENV, "90m") };
+        assert_eq!(cache_ttl(), Some(Duration::minutes(90)));
+
+        unsafe { std::env::set_var(CACHE_TTL_ENV, "soon") };
+        assert_eq!(cache_ttl(), Some(Duration::hours(DEFAULT_CACHE_TTL_HOURS)));
+
+        unsafe { std::env::set_var(CACHE_TTL_ENV, "0") };
+        assert_eq!(cache_ttl(), None);
+        assert!(RepoCache::open("org", "proj", "repo").is_none());
+
+        unsafe { std::env::remove_var(CACHE_TTL_ENV) };
+    }
+}
@@ -162,17 +162,7 @@ impl WorkItemCache {
 
     /// Get the cache file path.
     fn get_cache_path() -> Result<PathBuf> {
-        // Use XDG_CACHE_HOME if set, otherwise ~/.cache
-        let cache_dir = std::env::var("XDG_CACHE_HOME")
-            .map(PathBuf::from)
-            .or_else(|_| {
-                dirs::home_dir()
-                    .map(|d| d.join(".cache"))
-                    .ok_or(std::env::VarError::NotPresent)
-            })
-            .map_err(|_| anyhow::anyhow!("Could not determine home directory for cache path"))?;
-
-        Ok(cache_dir.join("mergers").join("work_items.json"))
+        Ok(super::cache_dir()?.join("work_items.json"))
     }
 }
 
@@ -0,0 +1,248 @@
+//! A JSON file of cached values with their fetch time and etag.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Current store file format; files with another version are discarded.
+const STORE_VERSION: u32 = 1;
+
+/// Days after which an entry is dropped, however it could be revalidated.
+pub const MAX_ENTRY_AGE_DAYS: i64 = 7;
+
+/// A cached value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry<V> {
+    pub value: V,
+    /// When the value was last fetched or revalidated.
+    pub fetched_at: DateTime<Utc>,
+    /// Version tag to revalidate the value with once it is stale.
+    #[serde(default)]
+    pub etag: Option<String>,
+}
+
+/// Result of looking up a key in a [`CacheStore`].
+#[derive(Debug)]
+pub enum Lookup<'a, V> {
+    /// Fetched or revalidated within the TTL; usable as-is.
+    Fresh(&'a V),
+    /// Older than the TTL; needs revalidating before use.
+    Stale(&'a CacheEntry<V>),
+    Missing,
+}
+
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "V: DeserializeOwned"))]
+struct StoreFile<V> {
+    version: u32,
+    entries: HashMap<String, CacheEntry<V>>,
+}
+
+/// Cached values stored in one JSON file, keyed by string.
+#[derive(Debug)]
+pub struct CacheStore<V> {
+    path: PathBuf,
+    ttl: Duration,
+    entries: HashMap<String, CacheEntry<V>>,
+    dirty: bool,
+}
+
+impl<V: Serialize + DeserializeOwned> CacheStore<V> {
+    /// Loads the store at `path`, dropping entries older than
+    /// [`MAX_ENTRY_AGE_DAYS`].
+    ///
+    /// A missing, unreadable or outdated file gives an empty store, which
+    /// replaces the file on the next [`save`](Self::save).
+    pub fn open(path: PathBuf, ttl: Duration) -> Self {
+        let entries = match Self::read(&path) {
+            Ok(entries) => entries,
+            Err(e) => {
+                tracing::debug!("Ignoring cache file {}: {:#}", path.display(), e);
+                HashMap::new()
+            }
+        };
+        let mut store = Self {
+            path,
+            ttl,
+            entries,
+            dirty: false,
+        };
+        store.prune(Utc::now());
+        store
+    }
+
+    fn read(path: &Path) -> Result<HashMap<String, CacheEntry<V>>> {
+        if !path.exists() {
+            return Ok(HashMap::new());
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read cache file: {}", path.display()))?;
+        let file: StoreFile<V> = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse cache file: {}", path.display()))?;
+        if file.version != STORE_VERSION {
+            anyhow::bail!("Unsupported cache file version {}", file.version);
+        }
+        Ok(file.entries)
+    }
+
+    /// Writes the store to disk if it changed since it was opened.
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create cache directory: {}", parent.display())
+            })?;
+        }
+        let file = serde_json::json!({
+            "version": STORE_VERSION,
+            "entries": &self.entries,
+        });
+        let content = serde_json::to_string(&file).context("Failed to serialize cache")?;
+        // Write a sibling file first so concurrent runs never read half a file
+        let tmp = self
+            .path
+            .with_extension(format!("json.{}.tmp", std::process::id()));
+        fs::write(&tmp, content)
+            .with_context(|| format!("Failed to write cache file: {}", tmp.display()))?;
+        fs::rename(&tmp, &self.path)
+            .with_context(|| format!("Failed to write cache file: {}", self.path.display()))?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Looks up the value cached for `key`.
+    pub fn lookup(&self, key: &str) -> Lookup<'_, V> {
+        match self.entries.get(key) {
+            Some(entry) if Utc::now() - entry.fetched_at <= self.ttl => Lookup::Fresh(&entry.value),
+            Some(entry) => Lookup::Stale(entry),
+            None => Lookup::Missing,
+        }
+    }
+
+    /// Caches `value` for `key`, fetched now.
+    pub fn insert(&mut self, key: String, value: V, etag: Option<String>) {
+        self.entries.insert(
+            key,
+            CacheEntry {
+                value,
+                fetched_at: Utc::now(),
+                etag,
+            },
+        );
+        self.dirty = true;
+    }
+
+    /// Returns the entry cached for `key`, fresh or stale.
+    pub fn entry(&self, key: &str) -> Option<&CacheEntry<V>> {
+        self.entries.get(key)
+    }
+
+    /// Drops the entry of `key`, e.g. after the value was changed remotely.
+    pub fn remove(&mut self, key: &str) {
+        self.dirty |= self.entries.remove(key).is_some();
+    }
+
+    /// Marks the value of `key` as fetched now, after its etag was found to
+    /// be current. Returns the value, or `None` if `key` is not cached.
+    pub fn revalidate(&mut self, key: &str) -> Option<&V> {
+        let entry = self.entries.get_mut(key)?;
+        entry.fetched_at = Utc::now();
+        self.dirty = true;
+        Some(&entry.value)
+    }
+
+    /// Returns the number of cached entries, fresh or stale.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether nothing is cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn prune(&mut self, now: DateTime<Utc>) {
+        let before = self.entries.len();
+        self.entries
+            .retain(|_, entry| now - entry.fetched_at <= Duration::days(MAX_ENTRY_AGE_DAYS));
+        self.dirty |= self.entries.len() != before;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// # Fresh and Stale Entries
+    ///
+    /// Tests how entries age in a store.
+    ///
+    /// ## Test Scenario
+    /// - Caches one value now and backdates another past the TTL
+    /// - Revalidates the stale value
+    ///
+    /// ## Expected Outcome
+    /// - The new value is fresh, the backdated one stale with its etag
+    /// - After revalidating, the backdated value is fresh again
+    #[test]
+    fn test_fresh_and_stale_entries() {
+        let temp = TempDir::new().unwrap();
+        let mut store: CacheStore<String> =
+            CacheStore::open(temp.path().join("store.json"), Duration::hours(1));
+
+        store.insert("new".to_string(), "a".to_string(), None);
+        store.insert("old".to_string(), "b".to_string(), Some("7".to_string()));
+        store.entries.get_mut("old").unwrap().fetched_at = Utc::now() - Duration::hours(2);
+
+        assert!(matches!(store.lookup("new"), Lookup::Fresh(value) if value == "a"));
+        assert!(matches!(
+            store.lookup("old"),
+            Lookup::Stale(entry) if entry.etag.as_deref() == Some("7")
+        ));
+        assert!(matches!(store.lookup("other"), Lookup::Missing));
+
+        assert_eq!(store.revalidate("old").map(String::as_str), Some("b"));
+        assert!(matches!(store.lookup("old"), Lookup::Fresh(_)));
+        assert!(store.revalidate("other").is_none());
+    }
+
+    /// # Saving and Pruning
+    ///
+    /// Tests that stores survive a reopen and drop expired entries.
+    ///
+    /// ## Test Scenario
+    /// - Saves a store with a recent entry and one older than
+    ///   `MAX_ENTRY_AGE_DAYS`, then reopens it
+    /// - Writes a file with an unknown version and opens it
+    ///
+    /// ## Expected Outcome
+    /// - Only the recent entry is loaded again
+    /// - The unknown version gives an empty store
+    #[test]
+    fn test_saving_and_pruning() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("nested").join("store.json");
+        let mut store: CacheStore<Vec<i32>> = CacheStore::open(path.clone(), Duration::hours(1));
+        store.insert("recent".to_string(), vec![1, 2], None);
+        store.insert("expired".to_string(), vec![3], None);
+        store.entries.get_mut("expired").unwrap().fetched_at =
+            Utc::now() - Duration::days(MAX_ENTRY_AGE_DAYS + 1);
+        store.save().unwrap();
+
+        let store: CacheStore<Vec<i32>> = CacheStore::open(path.clone(), Duration::hours(1));
+        assert_eq!(store.len(), 1);
+        assert!(matches!(store.lookup("recent"), Lookup::Fresh(value) if value == &[1, 2]));
+
+        fs::write(&path, r#"{"version": 99, "entries": {}}"#).unwrap();
+        let store: CacheStore<Vec<i32>> = CacheStore::open(path, Duration::hours(1));
+        assert!(store.is_empty());
+    }
+}
@@ -21,14 +21,71 @@
 //! let merged = config.merge(env_config);
 //! ```
 
-use crate::core::operations::{HookTriggerConfig, HooksConfig};
-use crate::{git_config, models::SharedArgs, parsed_property::ParsedProperty};
+use crate::api::{ArtifactsFeed, UrlTemplates, WikiPageTarget};
+use crate::core::operations::{
+    BinaryConflictPolicy, BinaryConflictRule, BuildCheckMode, CherryPickOrder, CherryPickSettings,
+    DEFAULT_SQUASH_MESSAGE, HookTriggerConfig, HooksConfig, PrTab, PrTabConfig, ReleaseGate,
+    ReleaseTagMode, SelectionCaps, WorkItemFieldRule, build_pr_tabs, parse_work_item_states,
+    work_item_fields::validate_field_rules,
+};
+use crate::core::runner::batch::{BatchRepoConfig, select_batch_repos};
+use crate::notify::NotificationsConfig;
+use crate::release_notes::{
+    ReleaseNotesLocale, ReleaseNotesLocaleConfig, TaskPatternConfig, TaskPatterns,
+};
+use crate::utils::{ClipboardMethod, ClipboardOptions, TimeUnit, native_path, parse_duration_in};
+use crate::{git_config, models::SinceField, parsed_property::ParsedProperty};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
+/// A duration in the config file: a bare number in the setting's unit, or a
+/// string such as `"90s"` or `"1h30m"`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum ConfigDuration {
+    Number(u64),
+    Text(String),
+}
+
+impl ConfigDuration {
+    fn parse(&self, unit: TimeUnit) -> Result<std::time::Duration> {
+        match self {
+            ConfigDuration::Number(amount) => unit.duration(*amount),
+            ConfigDuration::Text(text) => parse_duration_in(text, unit),
+        }
+    }
+
+    fn raw(&self) -> String {
+        match self {
+            ConfigDuration::Number(amount) => amount.to_string(),
+            ConfigDuration::Text(text) => text.clone(),
+        }
+    }
+}
+
+/// The target branch in the config file: one branch, or a list of branches
+/// to fan out to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum ConfigTargetBranch {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl ConfigTargetBranch {
+    /// Returns the branches as a comma-separated list, the form the
+    /// setting takes on the command line.
+    fn raw(&self) -> String {
+        match self {
+            ConfigTargetBranch::One(branch) => branch.clone(),
+            ConfigTargetBranch::Many(branches) => branches.join(","),
+        }
+    }
+}
+
 /// Temporary struct for deserializing TOML configuration
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 struct ConfigFile {
@@ -36,8 +93,9 @@ struct ConfigFile {
     pub project: Option<String>,
     pub repository: Option<String>,
     pub pat: Option<String>,
+    pub write_pat: Option<String>,
     pub dev_branch: Option<String>,
-    pub target_branch: Option<String>,
+    pub target_branch: Option<ConfigTargetBranch>,
     pub local_repo: Option<String>,
     pub work_item_state: Option<String>,
     pub parallel_limit: Option<usize>,
@@ -45,6 +103,22 @@ struct ConfigFile {
     pub max_concurrent_processing: Option<usize>,
     pub tag_prefix: Option<String>,
     pub run_hooks: Option<bool>,
+    pub strict_states: Option<bool>,
+    pub sign_commits: Option<bool>,
+    pub squash: Option<bool>,
+    pub squash_message: Option<String>,
+    pub mainline_fallback: Option<bool>,
+    pub known_work_item_states: Option<Vec<String>>,
+    pub terminal_work_item_states: Option<Vec<String>>,
+    pub work_item_comment: Option<String>,
+    pub release_gate: Option<String>,
+    pub check_builds: Option<bool>,
+    pub require_green_build: Option<bool>,
+    pub release_tag: Option<bool>,
+    pub push_release_tag: Option<bool>,
+    pub auto_refresh: Option<ConfigDuration>,
+    pub selection_soft_cap: Option<usize>,
+    pub selection_hard_cap: Option<usize>,
     // UI Settings
     pub show_dependency_highlights: Option<bool>,
     pub show_work_item_highlights: Option<bool>,
@@ -53,6 +127,39 @@ struct ConfigFile {
     pub hooks: Option<HooksConfig>,
     // Release Notes Settings
     pub repo_aliases: Option<std::collections::HashMap<String, String>>,
+    // API Settings
+    pub api_version: Option<String>,
+    // Git Settings
+    pub history_depth: Option<usize>,
+    // Filtering Settings
+    pub since_field: Option<SinceField>,
+    // Clipboard Settings
+    pub clipboard: Option<ClipboardMethod>,
+    pub clipboard_file: Option<String>,
+    // Link Settings
+    pub pull_request_url_template: Option<String>,
+    pub work_item_url_template: Option<String>,
+    // PR Selection Tabs
+    pub pr_tabs: Option<Vec<PrTabConfig>>,
+    // Binary Conflict Policies
+    pub binary_conflicts: Option<Vec<BinaryConflictRule>>,
+    // Azure Artifacts Package Version
+    pub artifacts_feed: Option<ArtifactsFeed>,
+    // Release Notes Wiki Page
+    pub release_notes_wiki: Option<WikiPageTarget>,
+    // Chat Notifications
+    pub notifications: Option<NotificationsConfig>,
+    // Cherry-Pick Settings
+    pub cherry_pick: Option<CherryPickSettings>,
+    // Work Item Field Updates
+    pub work_item_fields: Option<Vec<WorkItemFieldRule>>,
+    // Release Notes Work Item Reference Patterns
+    pub task_patterns: Option<Vec<TaskPatternConfig>>,
+    // Release Notes Languages
+    pub release_notes_locale: Option<String>,
+    pub release_notes_locales: Option<HashMap<String, ReleaseNotesLocaleConfig>>,
+    // Batch Repositories
+    pub repos: Option<Vec<BatchRepoConfig>>,
 }
 
 /// Application configuration assembled from CLI arguments, environment variables, config file, and defaults.
@@ -66,9 +173,12 @@ pub struct Config {
     pub repository: Option<ParsedProperty<String>>,
     /// Personal access token for authenticating with Azure DevOps.
     pub pat: Option<ParsedProperty<String>>,
+    /// Separate personal access token used for mutating requests (labels, work item states).
+    pub write_pat: Option<ParsedProperty<String>>,
     /// Name of the development branch to fetch pull requests from.
     pub dev_branch: Option<ParsedProperty<String>>,
-    /// Name of the target branch to merge pull requests into.
+    /// Name of the target branch to merge pull requests into, or a
+    /// comma-separated list of branches to fan out to.
     pub target_branch: Option<ParsedProperty<String>>,
     /// Path to a local repository to use instead of cloning.
     pub local_repo: Option<ParsedProperty<String>>,
@@ -84,6 +194,34 @@ pub struct Config {
     pub tag_prefix: Option<ParsedProperty<String>>,
     /// Whether to run git hooks during merge operations.
     pub run_hooks: Option<ParsedProperty<bool>>,
+    /// Whether to fail when work items are in states not listed in `known_work_item_states`.
+    pub strict_states: Option<ParsedProperty<bool>>,
+    /// Whether cherry-picked commits and release tags are signed with the git signing key.
+    pub sign_commits: Option<ParsedProperty<bool>>,
+    /// Whether the commits picked for a PR are squashed into one commit.
+    pub squash: Option<ParsedProperty<bool>>,
+    /// Commit message template of squashed PRs.
+    pub squash_message: Option<ParsedProperty<String>>,
+    /// Whether a merge commit whose first parent conflicts is retried with its second parent.
+    pub mainline_fallback: Option<ParsedProperty<bool>>,
+    /// Work item states the merge workflow expects; used by strict state checking.
+    pub known_work_item_states: Option<ParsedProperty<Vec<String>>>,
+    /// Work item states whose PRs are hidden from PR selection and bulk selection.
+    pub terminal_work_item_states: Option<ParsedProperty<Vec<String>>>,
+    /// Comment template posted on each released work item when a merge completes.
+    pub work_item_comment: Option<ParsedProperty<String>>,
+    /// Work item field gate required for release, e.g. `Custom.QAApproved == true`.
+    pub release_gate: Option<ParsedProperty<String>>,
+    /// Whether PR builds and branch policies are checked, and whether failing PRs are excluded.
+    pub build_checks: Option<ParsedProperty<BuildCheckMode>>,
+    /// Whether a release tag is created when a merge completes, and whether it is pushed.
+    pub release_tag: Option<ParsedProperty<ReleaseTagMode>>,
+    /// Minutes between background PR list refreshes in PR selection.
+    pub auto_refresh: Option<ParsedProperty<std::time::Duration>>,
+    /// Selected PR count above which the TUI asks for confirmation before cherry-picking.
+    pub selection_soft_cap: Option<ParsedProperty<usize>>,
+    /// Selected PR count above which non-interactive runs fail unless forced.
+    pub selection_hard_cap: Option<ParsedProperty<usize>>,
     /// Whether to highlight PR dependency relationships in the TUI.
     pub show_dependency_highlights: Option<ParsedProperty<bool>>,
     /// Whether to highlight work item relationships in the TUI.
@@ -92,6 +230,42 @@ pub struct Config {
     pub hooks: Option<HooksConfig>,
     /// Repository aliases (e.g., "api" -> "/path/to/api-backend")
     pub repo_aliases: Option<ParsedProperty<std::collections::HashMap<String, String>>>,
+    /// Azure DevOps REST API version override (e.g., "6.0" for older Azure DevOps Server releases).
+    pub api_version: Option<ParsedProperty<String>>,
+    /// Maximum number of target branch commits read when checking which PRs are merged.
+    pub history_depth: Option<ParsedProperty<usize>>,
+    /// Pull request date compared against `--since`.
+    pub since_field: Option<ParsedProperty<SinceField>>,
+    /// How copied text reaches the user (auto, system, osc52 or file).
+    pub clipboard: Option<ParsedProperty<ClipboardMethod>>,
+    /// File written by the clipboard file fallback.
+    pub clipboard_file: Option<ParsedProperty<String>>,
+    /// Pull request link template for on-premise servers or vanity domains.
+    pub pull_request_url_template: Option<ParsedProperty<String>>,
+    /// Work item link template for on-premise servers or vanity domains.
+    pub work_item_url_template: Option<ParsedProperty<String>>,
+    /// User-defined PR grouping tabs for the selection screen.
+    pub pr_tabs: Option<ParsedProperty<Vec<PrTabConfig>>>,
+    /// Sides kept for conflicted binary files matching path globs.
+    pub binary_conflicts: Option<ParsedProperty<Vec<BinaryConflictRule>>>,
+    /// Feed package whose version is stamped on released work items.
+    pub artifacts_feed: Option<ParsedProperty<ArtifactsFeed>>,
+    /// Wiki page release notes are published to.
+    pub release_notes_wiki: Option<ParsedProperty<WikiPageTarget>>,
+    /// Chat webhooks notified when a merge finishes.
+    pub notifications: Option<ParsedProperty<NotificationsConfig>>,
+    /// Order in which the selected PRs are cherry-picked.
+    pub cherry_pick_order: Option<ParsedProperty<CherryPickOrder>>,
+    /// Templated fields written on released work items.
+    pub work_item_fields: Option<ParsedProperty<Vec<WorkItemFieldRule>>>,
+    /// Patterns of work item references in PR text for release notes.
+    pub task_patterns: Option<ParsedProperty<Vec<TaskPatternConfig>>>,
+    /// Locale profile or built-in locale used for release notes by default.
+    pub release_notes_locale: Option<ParsedProperty<String>>,
+    /// Named release notes locale profiles.
+    pub release_notes_locales: Option<ParsedProperty<HashMap<String, ReleaseNotesLocaleConfig>>>,
+    /// Repositories merged by the batch command.
+    pub repos: Option<ParsedProperty<Vec<BatchRepoConfig>>>,
 }
 
 impl Default for Config {
@@ -101,6 +275,7 @@ impl Default for Config {
             project: None,
             repository: None,
             pat: None,
+            write_pat: None,
             dev_branch: Some(ParsedProperty::Default("dev".to_string())),
             target_branch: Some(ParsedProperty::Default("next".to_string())),
             local_repo: None,
@@ -116,7 +291,40 @@ impl Default for Config {
             // Hooks - empty by default
             hooks: None,
             // Release Notes Settings
+            known_work_item_states: None,
+            terminal_work_item_states: None,
+            work_item_comment: None,
+            release_gate: None,
+            build_checks: None,
+            release_tag: None,
+            auto_refresh: None,
+            selection_soft_cap: None,
+            selection_hard_cap: None,
+            strict_states: None,
+            sign_commits: None,
+            squash: None,
+            squash_message: None,
+            mainline_fallback: None,
             repo_aliases: None,
+            // API Settings - use the API client's built-in versions
+            api_version: None,
+            history_depth: None,
+            since_field: None,
+            clipboard: None,
+            clipboard_file: None,
+            pull_request_url_template: None,
+            work_item_url_template: None,
+            pr_tabs: None,
+            binary_conflicts: None,
+            artifacts_feed: None,
+            release_notes_wiki: None,
+            notifications: None,
+            cherry_pick_order: None,
+            work_item_fields: None,
+            task_patterns: None,
+            release_notes_locale: None,
+            release_notes_locales: None,
+            repos: None,
         }
     }
 }
@@ -150,15 +358,19 @@ impl Config {
             pat: config_file
                 .pat
                 .map(|v| ParsedProperty::File(v.clone(), config_path.clone(), v)),
+            write_pat: config_file
+                .write_pat
+                .map(|v| ParsedProperty::File(v.clone(), config_path.clone(), v)),
             dev_branch: config_file
                 .dev_branch
                 .map(|v| ParsedProperty::File(v.clone(), config_path.clone(), v)),
-            target_branch: config_file
-                .target_branch
-                .map(|v| ParsedProperty::File(v.clone(), config_path.clone(), v)),
+            target_branch: config_file.target_branch.map(|v| {
+                let raw = v.raw();
+                ParsedProperty::File(raw.clone(), config_path.clone(), raw)
+            }),
             local_repo: config_file
                 .local_repo
-                .map(|v| ParsedProperty::File(v.clone(), config_path.clone(), v)),
+                .map(|v| ParsedProperty::File(native_repo_path(&v), config_path.clone(), v)),
             work_item_state: config_file
                 .work_item_state
                 .map(|v| ParsedProperty::File(v.clone(), config_path.clone(), v)),
@@ -177,6 +389,54 @@ impl Config {
             run_hooks: config_file
                 .run_hooks
                 .map(|v| ParsedProperty::File(v, config_path.clone(), v.to_string())),
+            strict_states: config_file
+                .strict_states
+                .map(|v| ParsedProperty::File(v, config_path.clone(), v.to_string())),
+            sign_commits: config_file
+                .sign_commits
+                .map(|v| ParsedProperty::File(v, config_path.clone(), v.to_string())),
+            squash: config_file
+                .squash
+                .map(|v| ParsedProperty::File(v, config_path.clone(), v.to_string())),
+            squash_message: config_file
+                .squash_message
+                .map(|v| ParsedProperty::File(v.clone(), config_path.clone(), v)),
+            mainline_fallback: config_file
+                .mainline_fallback
+                .map(|v| ParsedProperty::File(v, config_path.clone(), v.to_string())),
+            known_work_item_states: config_file
+                .known_work_item_states
+                .map(|v| ParsedProperty::File(v.clone(), config_path.clone(), v.join(","))),
+            terminal_work_item_states: config_file
+                .terminal_work_item_states
+                .map(|v| ParsedProperty::File(v.clone(), config_path.clone(), v.join(","))),
+            work_item_comment: config_file
+                .work_item_comment
+                .map(|v| ParsedProperty::File(v.clone(), config_path.clone(), v)),
+            release_gate: config_file
+                .release_gate
+                .map(|v| ParsedProperty::File(v.clone(), config_path.clone(), v)),
+            build_checks: build_check_mode(
+                config_file.check_builds,
+                config_file.require_green_build,
+            )
+            .map(|(v, raw)| ParsedProperty::File(v, config_path.clone(), raw)),
+            release_tag: release_tag_mode(config_file.release_tag, config_file.push_release_tag)
+                .map(|(v, raw)| ParsedProperty::File(v, config_path.clone(), raw)),
+            auto_refresh: config_file
+                .auto_refresh
+                .map(|v| {
+                    v.parse(TimeUnit::Minutes)
+                        .map(|d| ParsedProperty::File(d, config_path.clone(), v.raw()))
+                        .context("Invalid auto_refresh in config file")
+                })
+                .transpose()?,
+            selection_soft_cap: config_file
+                .selection_soft_cap
+                .map(|v| ParsedProperty::File(v, config_path.clone(), v.to_string())),
+            selection_hard_cap: config_file
+                .selection_hard_cap
+                .map(|v| ParsedProperty::File(v, config_path.clone(), v.to_string())),
             show_dependency_highlights: config_file
                 .show_dependency_highlights
                 .map(|v| ParsedProperty::File(v, config_path.clone(), v.to_string())),
@@ -187,6 +447,79 @@ impl Config {
             repo_aliases: config_file
                 .repo_aliases
                 .map(|v| ParsedProperty::File(v.clone(), config_path.clone(), format!("{:?}", v))),
+            api_version: config_file
+                .api_version
+                .map(|v| ParsedProperty::File(v.clone(), config_path.clone(), v)),
+            history_depth: config_file
+                .history_depth
+                .map(|v| ParsedProperty::File(v, config_path.clone(), v.to_string())),
+            since_field: config_file
+                .since_field
+                .map(|v| ParsedProperty::File(v, config_path.clone(), v.to_string())),
+            clipboard: config_file
+                .clipboard
+                .map(|v| ParsedProperty::File(v, config_path.clone(), v.to_string())),
+            clipboard_file: config_file
+                .clipboard_file
+                .map(|v| ParsedProperty::File(v.clone(), config_path.clone(), v)),
+            pull_request_url_template: config_file
+                .pull_request_url_template
+                .map(|v| ParsedProperty::File(v.clone(), config_path.clone(), v)),
+            work_item_url_template: config_file
+                .work_item_url_template
+                .map(|v| ParsedProperty::File(v.clone(), config_path.clone(), v)),
+            pr_tabs: config_file.pr_tabs.map(|v| {
+                let names = v.iter().map(|tab| tab.name.as_str()).collect::<Vec<_>>();
+                ParsedProperty::File(v.clone(), config_path.clone(), names.join(","))
+            }),
+            binary_conflicts: config_file.binary_conflicts.map(|v| {
+                let rules = v
+                    .iter()
+                    .map(|rule| format!("{}={}", rule.paths.join("|"), rule.resolve))
+                    .collect::<Vec<_>>();
+                ParsedProperty::File(v.clone(), config_path.clone(), rules.join(","))
+            }),
+            artifacts_feed: config_file.artifacts_feed.map(|v| {
+                let display = format!("{}/{}", v.feed, v.package);
+                ParsedProperty::File(v, config_path.clone(), display)
+            }),
+            release_notes_wiki: config_file.release_notes_wiki.map(|v| {
+                let display = format!("{}{}", v.wiki, v.page);
+                ParsedProperty::File(v, config_path.clone(), display)
+            }),
+            notifications: config_file.notifications.map(|v| {
+                let channels = v
+                    .webhooks()
+                    .iter()
+                    .map(|(channel, _)| channel.to_string())
+                    .collect::<Vec<_>>();
+                ParsedProperty::File(v, config_path.clone(), channels.join(","))
+            }),
+            cherry_pick_order: config_file
+                .cherry_pick
+                .and_then(|v| v.order)
+                .map(|v| ParsedProperty::File(v, config_path.clone(), v.to_string())),
+            work_item_fields: config_file.work_item_fields.map(|v| {
+                let fields = v.iter().map(|rule| rule.field.as_str()).collect::<Vec<_>>();
+                ParsedProperty::File(v.clone(), config_path.clone(), fields.join(","))
+            }),
+            task_patterns: config_file.task_patterns.map(|v| {
+                let names = v.iter().map(|p| p.name.as_str()).collect::<Vec<_>>();
+                ParsedProperty::File(v.clone(), config_path.clone(), names.join(","))
+            }),
+            release_notes_locale: config_file
+                .release_notes_locale
+                .map(|v| ParsedProperty::File(v.clone(), config_path.clone(), v)),
+            release_notes_locales: config_file.release_notes_locales.map(|v| {
+                let mut names = v.keys().cloned().collect::<Vec<_>>();
+                names.sort();
+                ParsedProperty::File(v, config_path.clone(), names.join(","))
+            }),
+            repos: config_file.repos.map(|v| {
+                let names = v.iter().map(BatchRepoConfig::name).collect::<Vec<_>>();
+                let display = names.join(",");
+                ParsedProperty::File(v, config_path.clone(), display)
+            }),
         })
     }
 
@@ -226,6 +559,7 @@ impl Config {
                 )),
                 repository: Some(ParsedProperty::Git(azure_config.repository, remote_url)),
                 pat: None,
+                write_pat: None,
                 dev_branch: None,
                 target_branch: None,
                 local_repo: None,
@@ -238,7 +572,39 @@ impl Config {
                 show_dependency_highlights: None,
                 show_work_item_highlights: None,
                 hooks: None,
+                known_work_item_states: None,
+                terminal_work_item_states: None,
+                work_item_comment: None,
+                release_gate: None,
+                build_checks: None,
+                release_tag: None,
+                auto_refresh: None,
+                selection_soft_cap: None,
+                selection_hard_cap: None,
+                strict_states: None,
+                sign_commits: None,
+                squash: None,
+                squash_message: None,
+                mainline_fallback: None,
                 repo_aliases: None,
+                api_version: None,
+                history_depth: None,
+                since_field: None,
+                clipboard: None,
+                clipboard_file: None,
+                pull_request_url_template: None,
+                work_item_url_template: None,
+                pr_tabs: None,
+                binary_conflicts: None,
+                artifacts_feed: None,
+                release_notes_wiki: None,
+                notifications: None,
+                cherry_pick_order: None,
+                work_item_fields: None,
+                task_patterns: None,
+                release_notes_locale: None,
+                release_notes_locales: None,
+                repos: None,
             };
         }
 
@@ -254,6 +620,7 @@ impl Config {
                 )),
                 repository: Some(ParsedProperty::Git(generic_config.repository, remote_url)),
                 pat: None,
+                write_pat: None,
                 dev_branch: None,
                 target_branch: None,
                 local_repo: None,
@@ -266,7 +633,39 @@ impl Config {
                 show_dependency_highlights: None,
                 show_work_item_highlights: None,
                 hooks: None,
+                known_work_item_states: None,
+                terminal_work_item_states: None,
+                work_item_comment: None,
+                release_gate: None,
+                build_checks: None,
+                release_tag: None,
+                auto_refresh: None,
+                selection_soft_cap: None,
+                selection_hard_cap: None,
+                strict_states: None,
+                sign_commits: None,
+                squash: None,
+                squash_message: None,
+                mainline_fallback: None,
                 repo_aliases: None,
+                api_version: None,
+                history_depth: None,
+                since_field: None,
+                clipboard: None,
+                clipboard_file: None,
+                pull_request_url_template: None,
+                work_item_url_template: None,
+                pr_tabs: None,
+                binary_conflicts: None,
+                artifacts_feed: None,
+                release_notes_wiki: None,
+                notifications: None,
+                cherry_pick_order: None,
+                work_item_fields: None,
+                task_patterns: None,
+                release_notes_locale: None,
+                release_notes_locales: None,
+                repos: None,
             };
         }
 
@@ -323,6 +722,9 @@ impl Config {
             pat: std::env::var("MERGERS_PAT")
                 .ok()
                 .map(|v| ParsedProperty::Env(v.clone(), v)),
+            write_pat: std::env::var("MERGERS_WRITE_PAT")
+                .ok()
+                .map(|v| ParsedProperty::Env(v.clone(), v)),
             dev_branch: std::env::var("MERGERS_DEV_BRANCH")
                 .ok()
                 .map(|v| ParsedProperty::Env(v.clone(), v)),
@@ -331,7 +733,7 @@ impl Config {
                 .map(|v| ParsedProperty::Env(v.clone(), v)),
             local_repo: std::env::var("MERGERS_LOCAL_REPO")
                 .ok()
-                .map(|v| ParsedProperty::Env(v.clone(), v)),
+                .map(|v| ParsedProperty::Env(native_repo_path(&v), v)),
             work_item_state: std::env::var("MERGERS_WORK_ITEM_STATE")
                 .ok()
                 .map(|v| ParsedProperty::Env(v.clone(), v)),
@@ -352,6 +754,72 @@ impl Config {
                     .ok()
                     .map(|v| ParsedProperty::Env(v, s.clone()))
             }),
+            strict_states: std::env::var("MERGERS_STRICT_STATES").ok().and_then(|s| {
+                s.parse::<bool>()
+                    .ok()
+                    .map(|v| ParsedProperty::Env(v, s.clone()))
+            }),
+            sign_commits: std::env::var("MERGERS_SIGN_COMMITS").ok().and_then(|s| {
+                s.parse::<bool>()
+                    .ok()
+                    .map(|v| ParsedProperty::Env(v, s.clone()))
+            }),
+            squash: std::env::var("MERGERS_SQUASH").ok().and_then(|s| {
+                s.parse::<bool>()
+                    .ok()
+                    .map(|v| ParsedProperty::Env(v, s.clone()))
+            }),
+            squash_message: std::env::var("MERGERS_SQUASH_MESSAGE")
+                .ok()
+                .map(|v| ParsedProperty::Env(v.clone(), v)),
+            mainline_fallback: std::env::var("MERGERS_MAINLINE_FALLBACK")
+                .ok()
+                .and_then(|s| {
+                    s.parse::<bool>()
+                        .ok()
+                        .map(|v| ParsedProperty::Env(v, s.clone()))
+                }),
+            known_work_item_states: std::env::var("MERGERS_KNOWN_WORK_ITEM_STATES")
+                .ok()
+                .map(|s| ParsedProperty::Env(parse_work_item_states(&s), s)),
+            terminal_work_item_states: std::env::var("MERGERS_TERMINAL_WORK_ITEM_STATES")
+                .ok()
+                .map(|s| ParsedProperty::Env(parse_work_item_states(&s), s)),
+            work_item_comment: std::env::var("MERGERS_WORK_ITEM_COMMENT")
+                .ok()
+                .map(|v| ParsedProperty::Env(v.clone(), v)),
+            release_gate: std::env::var("MERGERS_RELEASE_GATE")
+                .ok()
+                .map(|v| ParsedProperty::Env(v.clone(), v)),
+            build_checks: build_check_mode(
+                std::env::var("MERGERS_CHECK_BUILDS")
+                    .ok()
+                    .and_then(|s| s.parse::<bool>().ok()),
+                std::env::var("MERGERS_REQUIRE_GREEN_BUILD")
+                    .ok()
+                    .and_then(|s| s.parse::<bool>().ok()),
+            )
+            .map(|(v, raw)| ParsedProperty::Env(v, raw)),
+            release_tag: release_tag_mode(
+                std::env::var("MERGERS_RELEASE_TAG")
+                    .ok()
+                    .and_then(|s| s.parse::<bool>().ok()),
+                std::env::var("MERGERS_PUSH_RELEASE_TAG")
+                    .ok()
+                    .and_then(|s| s.parse::<bool>().ok()),
+            )
+            .map(|(v, raw)| ParsedProperty::Env(v, raw)),
+            auto_refresh: std::env::var("MERGERS_AUTO_REFRESH").ok().and_then(|s| {
+                parse_duration_in(&s, TimeUnit::Minutes)
+                    .ok()
+                    .map(|v| ParsedProperty::Env(v, s))
+            }),
+            selection_soft_cap: std::env::var("MERGERS_SELECTION_SOFT_CAP")
+                .ok()
+                .and_then(|s| s.parse().ok().map(|v| ParsedProperty::Env(v, s))),
+            selection_hard_cap: std::env::var("MERGERS_SELECTION_HARD_CAP")
+                .ok()
+                .and_then(|s| s.parse().ok().map(|v| ParsedProperty::Env(v, s))),
             show_dependency_highlights: std::env::var("MERGERS_SHOW_DEPENDENCY_HIGHLIGHTS")
                 .ok()
                 .and_then(|s| {
@@ -373,6 +841,49 @@ impl Config {
             },
             // repo_aliases is configured via file only, not environment variables
             repo_aliases: None,
+            api_version: std::env::var("MERGERS_API_VERSION")
+                .ok()
+                .map(|v| ParsedProperty::Env(v.clone(), v)),
+            history_depth: std::env::var("MERGERS_HISTORY_DEPTH")
+                .ok()
+                .and_then(|s| s.parse().ok().map(|v| ParsedProperty::Env(v, s))),
+            since_field: std::env::var("MERGERS_SINCE_FIELD").ok().and_then(|s| {
+                s.parse::<SinceField>()
+                    .ok()
+                    .map(|v| ParsedProperty::Env(v, s))
+            }),
+            clipboard: std::env::var("MERGERS_CLIPBOARD").ok().and_then(|s| {
+                s.parse::<ClipboardMethod>()
+                    .ok()
+                    .map(|v| ParsedProperty::Env(v, s))
+            }),
+            clipboard_file: std::env::var("MERGERS_CLIPBOARD_FILE")
+                .ok()
+                .map(|v| ParsedProperty::Env(v.clone(), v)),
+            pull_request_url_template: std::env::var("MERGERS_PULL_REQUEST_URL_TEMPLATE")
+                .ok()
+                .map(|v| ParsedProperty::Env(v.clone(), v)),
+            work_item_url_template: std::env::var("MERGERS_WORK_ITEM_URL_TEMPLATE")
+                .ok()
+                .map(|v| ParsedProperty::Env(v.clone(), v)),
+            // Tabs are tables; only the config file can define them
+            pr_tabs: None,
+            binary_conflicts: None,
+            artifacts_feed: None,
+            release_notes_wiki: None,
+            notifications: None,
+            cherry_pick_order: std::env::var("MERGERS_CHERRY_PICK_ORDER")
+                .ok()
+                .and_then(|s| {
+                    s.parse::<CherryPickOrder>()
+                        .ok()
+                        .map(|v| ParsedProperty::Env(v, s))
+                }),
+            work_item_fields: None,
+            task_patterns: None,
+            release_notes_locale: None,
+            release_notes_locales: None,
+            repos: None,
         }
     }
 
@@ -402,6 +913,240 @@ impl Config {
         Ok(mergers_config_dir.join("config.toml"))
     }
 
+    /// Returns the known work item states if strict state checking is enabled.
+    ///
+    /// Strict checking is enabled by `--strict-states` (`strict_flag`) or the
+    /// `strict_states` setting. Returns an error if it is enabled but no
+    /// `known_work_item_states` are configured.
+    pub fn strict_work_item_states(&self, strict_flag: bool) -> Result<Option<Vec<String>>> {
+        let strict = strict_flag || self.strict_states.as_ref().is_some_and(|p| *p.value());
+        if !strict {
+            return Ok(None);
+        }
+
+        match self.known_work_item_states.as_ref() {
+            Some(states) if !states.value().is_empty() => Ok(Some(states.value().clone())),
+            _ => anyhow::bail!(
+                "Strict state checking requires known_work_item_states in the config file \
+                 or MERGERS_KNOWN_WORK_ITEM_STATES"
+            ),
+        }
+    }
+
+    /// Returns whether commits and tags are signed.
+    ///
+    /// Signing is enabled by `--sign-commits` (`sign_flag`) or the
+    /// `sign_commits` setting.
+    pub fn sign_commits(&self, sign_flag: bool) -> bool {
+        sign_flag || self.sign_commits.as_ref().is_some_and(|p| *p.value())
+    }
+
+    /// Returns whether a merge commit whose first parent conflicts is retried
+    /// with its second parent as mainline.
+    ///
+    /// The retry is enabled by `--mainline-fallback` (`fallback_flag`) or the
+    /// `mainline_fallback` setting; without either, the conflict is reported.
+    pub fn mainline_fallback(&self, fallback_flag: bool) -> bool {
+        fallback_flag || self.mainline_fallback.as_ref().is_some_and(|p| *p.value())
+    }
+
+    /// Returns the commit message template PRs are squashed with, or `None`
+    /// when squash mode is off.
+    ///
+    /// Squash mode is enabled by `--squash` (`squash_flag`) or the `squash`
+    /// setting. The template is `--squash-message` (`message_flag`), the
+    /// `squash_message` setting, or [`DEFAULT_SQUASH_MESSAGE`].
+    pub fn squash_message(&self, squash_flag: bool, message_flag: Option<&str>) -> Option<String> {
+        let enabled = squash_flag || self.squash.as_ref().is_some_and(|p| *p.value());
+        enabled.then(|| {
+            message_flag
+                .map(String::from)
+                .or_else(|| self.squash_message.as_ref().map(|p| p.value().clone()))
+                .unwrap_or_else(|| DEFAULT_SQUASH_MESSAGE.to_string())
+        })
+    }
+
+    /// Returns the work item states whose PRs are hidden in PR selection.
+    pub fn terminal_work_item_states(&self) -> Vec<String> {
+        self.terminal_work_item_states
+            .as_ref()
+            .map(|p| p.value().clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns the comment template posted on released work items, if any.
+    pub fn work_item_comment(&self) -> Option<String> {
+        self.work_item_comment.as_ref().map(|p| p.value().clone())
+    }
+
+    /// Parses the configured release gate, if any.
+    pub fn release_gate(&self) -> Result<Option<ReleaseGate>> {
+        self.release_gate
+            .as_ref()
+            .map(|p| p.value().parse::<ReleaseGate>())
+            .transpose()
+    }
+
+    /// Returns how PR builds and branch policies are checked.
+    ///
+    /// `check_builds` and `require_green_build` are the `--check-builds` and
+    /// `--require-green-build` flags, which can only make the check stricter.
+    pub fn build_check_mode(
+        &self,
+        check_builds: bool,
+        require_green_build: bool,
+    ) -> BuildCheckMode {
+        let configured = self
+            .build_checks
+            .as_ref()
+            .map(|p| *p.value())
+            .unwrap_or_default();
+        match BuildCheckMode::from_flags(check_builds, require_green_build) {
+            BuildCheckMode::Off => configured,
+            BuildCheckMode::Flag if configured == BuildCheckMode::Require => configured,
+            cli => cli,
+        }
+    }
+
+    /// Returns whether a release tag is created when a merge completes.
+    ///
+    /// `release_tag` and `push_release_tag` are the `--release-tag` and
+    /// `--push-release-tag` flags, which can only turn more on.
+    pub fn release_tag_mode(&self, release_tag: bool, push_release_tag: bool) -> ReleaseTagMode {
+        self.release_tag
+            .as_ref()
+            .map(|p| *p.value())
+            .unwrap_or_default()
+            .max(ReleaseTagMode::from_flags(release_tag, push_release_tag))
+    }
+
+    /// Validates the configured pull request and work item URL templates.
+    pub fn url_templates(&self) -> Result<UrlTemplates> {
+        UrlTemplates::new(
+            self.pull_request_url_template
+                .as_ref()
+                .map(|p| p.value().clone()),
+            self.work_item_url_template
+                .as_ref()
+                .map(|p| p.value().clone()),
+        )
+    }
+
+    /// Compiles the configured PR grouping tabs.
+    pub fn pr_tabs(&self) -> Result<Vec<PrTab>> {
+        match &self.pr_tabs {
+            Some(tabs) => build_pr_tabs(tabs.value()),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Compiles the configured binary conflict policies.
+    pub fn binary_conflict_policy(&self) -> Result<BinaryConflictPolicy> {
+        match &self.binary_conflicts {
+            Some(rules) => BinaryConflictPolicy::new(rules.value()),
+            None => Ok(BinaryConflictPolicy::default()),
+        }
+    }
+
+    /// Returns the feed package looked up after a merge, if any.
+    pub fn artifacts_feed(&self) -> Option<ArtifactsFeed> {
+        self.artifacts_feed.as_ref().map(|p| p.value().clone())
+    }
+
+    /// Returns the wiki page release notes are published to, if any.
+    pub fn release_notes_wiki(&self) -> Option<WikiPageTarget> {
+        self.release_notes_wiki.as_ref().map(|p| p.value().clone())
+    }
+
+    /// Returns the chat webhooks notified when a merge finishes, if any.
+    pub fn notifications(&self) -> Option<NotificationsConfig> {
+        self.notifications
+            .as_ref()
+            .map(|p| p.value().clone())
+            .filter(|config| !config.webhooks().is_empty())
+    }
+
+    /// Returns the order in which the selected PRs are cherry-picked.
+    ///
+    /// `cli` is the `--cherry-pick-order` flag, which wins over the config.
+    pub fn cherry_pick_order(&self, cli: Option<CherryPickOrder>) -> CherryPickOrder {
+        cli.or_else(|| self.cherry_pick_order.as_ref().map(|p| *p.value()))
+            .unwrap_or_default()
+    }
+
+    /// Returns the configured work item field updates after checking them.
+    pub fn work_item_field_rules(&self) -> Result<Vec<WorkItemFieldRule>> {
+        match &self.work_item_fields {
+            Some(rules) => {
+                validate_field_rules(rules.value())?;
+                Ok(rules.value().clone())
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Compiles the configured work item reference patterns for `repository`.
+    pub fn task_patterns(&self, repository: &str) -> Result<TaskPatterns> {
+        match &self.task_patterns {
+            Some(patterns) => TaskPatterns::new(patterns.value(), repository),
+            None => Ok(TaskPatterns::default()),
+        }
+    }
+
+    /// Resolves the release notes locale.
+    ///
+    /// `name` (e.g. from `--locale`) takes precedence over the configured
+    /// `release_notes_locale`. A name is looked up in the locale profiles
+    /// first and is otherwise a built-in locale; without a name, release
+    /// notes are English.
+    pub fn release_notes_locale(&self, name: Option<&str>) -> Result<ReleaseNotesLocale> {
+        let Some(name) = name.or(self
+            .release_notes_locale
+            .as_ref()
+            .map(|p| p.value().as_str()))
+        else {
+            return Ok(ReleaseNotesLocale::default());
+        };
+        match self
+            .release_notes_locales
+            .as_ref()
+            .and_then(|profiles| profiles.value().get(name))
+        {
+            Some(profile) => ReleaseNotesLocale::from_config(name, profile),
+            None => ReleaseNotesLocale::builtin(name),
+        }
+    }
+
+    /// Validates the repositories of the batch command and keeps the ones
+    /// named in `only` (all of them when `only` is empty).
+    pub fn batch_repos(&self, only: &[String]) -> Result<Vec<BatchRepoConfig>> {
+        let repos = self.repos.as_ref().map(|p| p.value().as_slice());
+        select_batch_repos(repos.unwrap_or_default(), only)
+    }
+
+    /// Returns the selection caps, using the defaults for unset values.
+    pub fn selection_caps(&self) -> SelectionCaps {
+        SelectionCaps::new(
+            self.selection_soft_cap.as_ref().map(|p| *p.value()),
+            self.selection_hard_cap.as_ref().map(|p| *p.value()),
+        )
+    }
+
+    /// Returns the clipboard settings, using auto-detection when unset.
+    pub fn clipboard_options(&self) -> ClipboardOptions {
+        ClipboardOptions {
+            method: self
+                .clipboard
+                .as_ref()
+                .map(|p| *p.value())
+                .unwrap_or_default(),
+            file: self
+                .clipboard_file
+                .as_ref()
+                .map(|p| PathBuf::from(p.value())),
+        }
+    }
+
     /// Merge this config with another, preferring values from other when they exist
     pub fn merge(self, other: Self) -> Self {
         // Merge hooks configs - if both exist, merge them; otherwise take whichever exists
@@ -417,6 +1162,7 @@ impl Config {
             project: other.project.or(self.project),
             repository: other.repository.or(self.repository),
             pat: other.pat.or(self.pat),
+            write_pat: other.write_pat.or(self.write_pat),
             dev_branch: other.dev_branch.or(self.dev_branch),
             target_branch: other.target_branch.or(self.target_branch),
             local_repo: other.local_repo.or(self.local_repo),
@@ -428,6 +1174,22 @@ impl Config {
                 .or(self.max_concurrent_processing),
             tag_prefix: other.tag_prefix.or(self.tag_prefix),
             run_hooks: other.run_hooks.or(self.run_hooks),
+            strict_states: other.strict_states.or(self.strict_states),
+            sign_commits: other.sign_commits.or(self.sign_commits),
+            squash: other.squash.or(self.squash),
+            squash_message: other.squash_message.or(self.squash_message),
+            mainline_fallback: other.mainline_fallback.or(self.mainline_fallback),
+            known_work_item_states: other.known_work_item_states.or(self.known_work_item_states),
+            terminal_work_item_states: other
+                .terminal_work_item_states
+                .or(self.terminal_work_item_states),
+            work_item_comment: other.work_item_comment.or(self.work_item_comment),
+            release_gate: other.release_gate.or(self.release_gate),
+            build_checks: other.build_checks.or(self.build_checks),
+            release_tag: other.release_tag.or(self.release_tag),
+            auto_refresh: other.auto_refresh.or(self.auto_refresh),
+            selection_soft_cap: other.selection_soft_cap.or(self.selection_soft_cap),
+            selection_hard_cap: other.selection_hard_cap.or(self.selection_hard_cap),
             show_dependency_highlights: other
                 .show_dependency_highlights
                 .or(self.show_dependency_highlights),
@@ -436,6 +1198,26 @@ impl Config {
                 .or(self.show_work_item_highlights),
             hooks: merged_hooks,
             repo_aliases: other.repo_aliases.or(self.repo_aliases),
+            api_version: other.api_version.or(self.api_version),
+            history_depth: other.history_depth.or(self.history_depth),
+            since_field: other.since_field.or(self.since_field),
+            clipboard: other.clipboard.or(self.clipboard),
+            clipboard_file: other.clipboard_file.or(self.clipboard_file),
+            pull_request_url_template: other
+                .pull_request_url_template
+                .or(self.pull_request_url_template),
+            work_item_url_template: other.work_item_url_template.or(self.work_item_url_template),
+            pr_tabs: other.pr_tabs.or(self.pr_tabs),
+            binary_conflicts: other.binary_conflicts.or(self.binary_conflicts),
+            artifacts_feed: other.artifacts_feed.or(self.artifacts_feed),
+            release_notes_wiki: other.release_notes_wiki.or(self.release_notes_wiki),
+            notifications: other.notifications.or(self.notifications),
+            cherry_pick_order: other.cherry_pick_order.or(self.cherry_pick_order),
+            work_item_fields: other.work_item_fields.or(self.work_item_fields),
+            task_patterns: other.task_patterns.or(self.task_patterns),
+            release_notes_locale: other.release_notes_locale.or(self.release_notes_locale),
+            release_notes_locales: other.release_notes_locales.or(self.release_notes_locales),
+            repos: other.repos.or(self.repos),
         }
     }
 
@@ -465,10 +1247,16 @@ impl Config {
 # Personal Access Token (required, but consider using environment variable MERGERS_PAT instead)
 # pat = "your-pat-token"
 
+# Separate token for mutating requests such as PR labels and work item state
+# updates (optional, falls back to pat; or use MERGERS_WRITE_PAT)
+# write_pat = "your-write-pat-token"
+
 # Development branch name (optional, defaults to "dev")
 dev_branch = "dev"
 
 # Target branch name (optional, defaults to "next")
+# A list picks the same PRs into each branch, in a worktree per branch:
+# target_branch = ["release/6.6", "release/6.7"]
 target_branch = "next"
 
 # Local repository path (optional, uses git worktree instead of cloning)
@@ -510,11 +1298,222 @@ show_work_item_highlights = true
 # Commands to run after 'complete' command finishes (tagging, work item updates)
 # post_complete = ["./scripts/notify-slack.sh"]
 
+# Work item states the merge workflow expects (optional)
+# With strict_states = true (or --strict-states), data loading fails when a
+# PR's work items are in any other state, catching process template drift early.
+# known_work_item_states = ["Ready for Next", "Next Merged"]
+# strict_states = false
+
+# Signed commits (optional)
+# With sign_commits = true (or --sign-commits), cherry-picks are committed with
+# -S and release tags are created with -s, using git's user.signingKey and
+# gpg.format (GPG or SSH). Picks git cannot sign fail instead of landing unsigned.
+# sign_commits = false
+
+# Squashed PRs (optional)
+# With squash = true (or --squash), the commits picked for a PR are squashed
+# into one commit. squash_message is its message: {id} is the PR number,
+# {title} its title and {task_ids} its work item ids (e.g. "123,#456").
+# squash = false
+# squash_message = "Merged PR {id}: {title} (rwi:#{task_ids})"
+
+# Second-parent retry of merge commits (optional)
+# Merge commits are picked with their first parent as mainline (-m 1). With
+# mainline_fallback = true (or --mainline-fallback), a two-parent merge commit
+# that conflicts is retried with -m 2. Only enable this for repositories whose
+# merge commits have reversed parents: -m 2 of a regular merge commit applies
+# the target line's changes instead of the PR's.
+# mainline_fallback = false
+
+# Work item states that need no further merging (optional)
+# PRs whose work items are all in these states are hidden in PR selection
+# (toggle with 'h') and skipped by bulk selection.
+# terminal_work_item_states = ["Next Merged", "Closed"]
+
+# Comment posted on each released work item when a merge completes (optional)
+# Placeholders: {version}, {branch}, {target_branch}, {prs}, {work_item_id},
+# {work_item_title}. A work item linked to several PRs gets one comment.
+# work_item_comment = "Shipped in {version}, branch {branch}, PR {prs}"
+
+# Release gate on a custom work item field (optional)
+# PRs whose work items do not have this value are flagged in PR selection and
+# skipped by bulk selection. A bare field name means "== true".
+# release_gate = "Custom.QAApproved == true"
+
+# Build and branch policy checks (optional)
+# With check_builds = true (or --check-builds), PRs whose last merge commit has
+# no successful build or that fail a blocking branch policy are flagged in PR
+# selection. require_green_build = true (or --require-green-build) also leaves
+# them out of the merge. The PAT needs the Build (Read) scope.
+# check_builds = false
+# require_green_build = false
+
+# Release tag (optional)
+# With release_tag = true (or --release-tag), completing a merge tags the last
+# cherry-picked commit as v<version>, with the release notes as the annotated
+# tag message. push_release_tag = true (or --push-release-tag) also pushes the
+# tag to origin.
+# release_tag = false
+# push_release_tag = false
+
+# Refresh the PR list in the background while in PR selection (optional).
+# A bare number is minutes; strings like "90s" or "1h" work too.
+# New PRs are announced in a banner and added with 'a'.
+# auto_refresh = 5
+
+# Guard rails against selecting too many PRs (optional, 0 disables)
+# Above the soft cap the TUI asks for confirmation, showing the total diff
+# size, before cherry-picking. Above the hard cap non-interactive runs fail
+# with exit code 6 unless --force is given.
+# selection_soft_cap = 50
+# selection_hard_cap = 100
+
+# Azure DevOps REST API version override (optional)
+# Only needed for Azure DevOps Server installations that do not support the
+# API versions used by default. Preview endpoints keep their "-preview" suffix.
+# api_version = "6.0"
+
+# How far back to read the target branch history (optional)
+# Migration analysis and cleanup check at most this many of the newest target
+# branch commits, which bounds memory on very large repositories. PRs merged
+# before that window are reported as not merged. Unlimited by default.
+# history_depth = 50000
+
+# Pull request date compared against --since (optional)
+# "closed" (default) uses the completion date. "merge-commit" uses the date of
+# the PR's last merge commit and "target-commit" the date of the target branch
+# commit it last merged onto.
+# since_field = "closed"
+
+# How copied text (e.g. release notes --copy) reaches you (optional)
+# "auto" tries the system clipboard, then the OSC 52 terminal escape sequence
+# (works over SSH), then writes to clipboard_file. Use "system", "osc52" or
+# "file" to force one method.
+# clipboard = "auto"
+# clipboard_file = "/tmp/mergers-clipboard.txt"
+
+# Custom link layouts for on-premise servers or vanity domains (optional)
+# Used by the browser actions and release notes. Placeholders: {organization},
+# {project}, {repository} and {id}; names are URL-encoded.
+# pull_request_url_template = "https://tfs.example.com/tfs/{organization}/{project}/_git/{repository}/pullrequest/{id}"
+# work_item_url_template = "https://boards.example.com/{project}/_workitems/edit/{id}"
+
+# Language of release notes headings and dates (optional)
+# A built-in locale ("en", "de", "fr") or a profile defined below; --locale
+# overrides it per run.
+# release_notes_locale = "customer"
+
 # Repository aliases for quick access
 # Maps short names to full paths (usable with any command)
 # [repo_aliases]
 # api = "/path/to/api-backend"
 # web = "/path/to/web-frontend"
+
+# PR grouping tabs in PR selection, switched with [ and ] (optional)
+# A PR shows in every tab whose path globs match a changed file or whose
+# title patterns match its title. Path rules need local_repo.
+# [[pr_tabs]]
+# name = "Backend"
+# paths = ["src/server/**", "*.sql"]
+# [[pr_tabs]]
+# name = "Infra"
+# paths = ["deploy/**"]
+# titles = ["(?i)^\\[infra\\]"]
+
+# Binary conflict policies (optional)
+# Conflicted binary files (images, .resx) matching the globs are resolved to
+# "ours" (target branch) or "theirs" (the PR) without stopping the
+# cherry-pick. The first matching rule wins; other binary conflicts get a
+# chooser on the conflict screen.
+# [[binary_conflicts]]
+# paths = ["*.png", "assets/**"]
+# resolve = "theirs"
+# [[binary_conflicts]]
+# paths = ["*.resx"]
+# resolve = "ours"
+
+# Cherry-pick order (optional)
+# "closed-date" (default) picks the oldest closed PR first. "topological" uses
+# the dependency analysis (needs local_repo) to pick PRs after the PRs they
+# depend on; independent PRs keep their closed-date place. The
+# --cherry-pick-order flag overrides it.
+# [cherry_pick]
+# order = "topological"
+
+# Work item field updates (optional)
+# Fields written on every released work item when a merge completes, besides
+# the state. Values use the work_item_comment placeholders. "append" adds the
+# value to a ";"-separated list such as tags; "set" (default) replaces it.
+# [[work_item_fields]]
+# field = "Custom.ReleaseVersion"
+# value = "{version}"
+# [[work_item_fields]]
+# field = "System.Tags"
+# value = "released-{version}"
+# mode = "append"
+
+# Azure Artifacts package version (optional)
+# When a merge completes, the newest version of the package matching the merge
+# version (1.4 matches 1.4.1207) is looked up in the feed, written to the
+# "Integrated in build" field of the released work items and listed in the
+# release notes. "project" is needed for feeds of another project.
+# [artifacts_feed]
+# feed = "releases"
+# package = "Contoso.Service"
+
+# Release notes wiki page (optional)
+# `mergers release-notes` creates or replaces this page of an Azure DevOps
+# wiki with the markdown notes. {version} is replaced with the release
+# version. "project" is needed for wikis of another project.
+# [release_notes_wiki]
+# wiki = "Contoso.wiki"
+# page = "/Releases/{version}"
+
+# Chat notifications (optional)
+# When a merge finishes, a summary (merged PRs, conflicts, version and a link
+# to the release notes) is posted to these incoming webhooks. {version} in
+# release_notes_url is replaced with the release version.
+# [notifications]
+# slack_webhook = "https://hooks.slack.com/services/T000/B000/XXXX"
+# teams_webhook = "https://contoso.webhook.office.com/webhookb2/..."
+# release_notes_url = "https://dev.azure.com/contoso/Platform/_wiki/wikis/Contoso.wiki?pagePath=/Releases/{version}"
+
+# Work item references in PR titles and descriptions (optional)
+# Release notes include the referenced work items as if they were linked.
+# The ID is the pattern's "id" group or its first group. "section" routes
+# matches to features, fixes, refactors or other; "repositories" limits a
+# pattern to some repositories.
+# [[task_patterns]]
+# name = "boards"
+# pattern = "AB#(\\d+)"
+# [[task_patterns]]
+# name = "hotfix"
+# pattern = "(?:^|\\s)#(?P<id>\\d+)\\b"
+# section = "fixes"
+
+# Release notes locale profiles (optional)
+# A profile starts from a built-in locale and overrides its strftime date
+# format and headings: features, fixes, refactors, other, unreferenced,
+# title, release_date, summary ({count} work items), no_changes and
+# previously_released.
+# [release_notes_locales.customer]
+# locale = "de"
+# date_format = "%-d. %B %Y"
+# [release_notes_locales.customer.headings]
+# features = "Neuerungen"
+
+# Repositories merged by `mergers batch` (optional)
+# Unset organization, project and branches fall back to the settings above;
+# without local_repo the repository is cloned. "name" (default: repository)
+# selects entries with --repos.
+# [[repos]]
+# name = "api"
+# repository = "api-backend"
+# local_repo = "/path/to/api-backend"
+# [[repos]]
+# project = "Web"
+# repository = "web-frontend"
+# target_branch = "release"
 "#;
 
         fs::write(&config_path, sample_config).with_context(|| {
@@ -567,7 +1566,8 @@ show_work_item_highlights = true
     /// Converts SharedArgs fields into `ParsedProperty::Cli` variants.
     /// Command-specific fields (work_item_state, run_hooks, etc.) are left as None
     /// and should be set by the caller if needed.
-    pub fn from_shared_args(shared: &SharedArgs) -> Self {
+    #[cfg(feature = "cli")]
+    pub fn from_shared_args(shared: &crate::models::SharedArgs) -> Self {
         let cli_local_repo = shared.path.as_ref().or(shared.local_repo.as_ref());
         Config {
             organization: shared
@@ -586,6 +1586,10 @@ show_work_item_highlights = true
                 .pat
                 .as_ref()
                 .map(|v| ParsedProperty::Cli(v.clone(), v.clone())),
+            write_pat: shared
+                .write_pat
+                .as_ref()
+                .map(|v| ParsedProperty::Cli(v.clone(), v.clone())),
             dev_branch: shared
                 .dev_branch
                 .as_ref()
@@ -594,7 +1598,7 @@ show_work_item_highlights = true
                 .target_branch
                 .as_ref()
                 .map(|v| ParsedProperty::Cli(v.clone(), v.clone())),
-            local_repo: cli_local_repo.map(|v| ParsedProperty::Cli(v.clone(), v.clone())),
+            local_repo: cli_local_repo.map(|v| ParsedProperty::Cli(native_repo_path(v), v.clone())),
             parallel_limit: shared
                 .parallel_limit
                 .map(|v| ParsedProperty::Cli(v, v.to_string())),
@@ -616,8 +1620,47 @@ show_work_item_highlights = true
             show_work_item_highlights: None,
             // Hooks: not set via CLI, only via config file or env vars
             hooks: None,
+            // Strict state checking: set by merge command flags
+            known_work_item_states: None,
+            terminal_work_item_states: None,
+            work_item_comment: None,
+            strict_states: None,
+            sign_commits: None,
+            squash: None,
+            squash_message: None,
+            mainline_fallback: None,
+            // Release gate: not set via CLI, only via config file or env vars
+            release_gate: None,
+            build_checks: None,
+            release_tag: None,
+            // Auto-refresh: set by merge command flags
+            auto_refresh: None,
+            selection_soft_cap: None,
+            selection_hard_cap: None,
             // Repo aliases: not set via CLI
             repo_aliases: None,
+            // API version: not set via CLI, only via config file or env vars
+            api_version: None,
+            history_depth: None,
+            since_field: shared
+                .since_field
+                .map(|v| ParsedProperty::Cli(v, v.to_string())),
+            // Clipboard: not set via CLI, only via config file or env vars
+            clipboard: None,
+            clipboard_file: None,
+            pull_request_url_template: None,
+            work_item_url_template: None,
+            pr_tabs: None,
+            binary_conflicts: None,
+            artifacts_feed: None,
+            release_notes_wiki: None,
+            notifications: None,
+            cherry_pick_order: None,
+            work_item_fields: None,
+            task_patterns: None,
+            release_notes_locale: None,
+            release_notes_locales: None,
+            repos: None,
         }
     }
 }
@@ -642,10 +1685,10 @@ pub fn resolve_repo_path(
             if let Some(alias_map) = aliases
                 && let Some(path) = alias_map.get(input)
             {
-                return Ok(PathBuf::from(path));
+                return Ok(native_path(path));
             }
 
-            let path = PathBuf::from(input);
+            let path = native_path(input);
             if path.exists() {
                 Ok(path)
             } else {
@@ -658,6 +1701,60 @@ pub fn resolve_repo_path(
     }
 }
 
+/// A repository path in the form the current environment can open.
+///
+/// Translates between WSL mount paths and Windows drive paths, see
+/// [`native_path`].
+fn native_repo_path(path: &str) -> String {
+    native_path(path).to_string_lossy().into_owned()
+}
+
+/// The build check mode set by the `check_builds` and `require_green_build`
+/// settings, with the settings as written, or `None` if neither is set.
+fn build_check_mode(
+    check_builds: Option<bool>,
+    require_green_build: Option<bool>,
+) -> Option<(BuildCheckMode, String)> {
+    let raw = [
+        ("check_builds", check_builds),
+        ("require_green_build", require_green_build),
+    ]
+    .iter()
+    .filter_map(|(name, value)| value.map(|v| format!("{} = {}", name, v)))
+    .collect::<Vec<_>>();
+    if raw.is_empty() {
+        return None;
+    }
+    let mode = BuildCheckMode::from_flags(
+        check_builds.unwrap_or(false),
+        require_green_build.unwrap_or(false),
+    );
+    Some((mode, raw.join(", ")))
+}
+
+/// The release tag mode set by the `release_tag` and `push_release_tag`
+/// settings, with the settings as written, or `None` if neither is set.
+fn release_tag_mode(
+    release_tag: Option<bool>,
+    push_release_tag: Option<bool>,
+) -> Option<(ReleaseTagMode, String)> {
+    let raw = [
+        ("release_tag", release_tag),
+        ("push_release_tag", push_release_tag),
+    ]
+    .iter()
+    .filter_map(|(name, value)| value.map(|v| format!("{} = {}", name, v)))
+    .collect::<Vec<_>>();
+    if raw.is_empty() {
+        return None;
+    }
+    let mode = ReleaseTagMode::from_flags(
+        release_tag.unwrap_or(false),
+        push_release_tag.unwrap_or(false),
+    );
+    Some((mode, raw.join(", ")))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -739,6 +1836,9 @@ mod tests {
         unsafe {
             env::set_var("MERGERS_PAT", "test-pat");
         }
+        unsafe {
+            env::set_var("MERGERS_WRITE_PAT", "test-write-pat");
+        }
         unsafe {
             env::set_var("MERGERS_DEV_BRANCH", "develop");
         }
@@ -794,6 +1894,13 @@ mod tests {
                 "test-pat".to_string()
             ))
         );
+        assert_eq!(
+            config.write_pat,
+            Some(ParsedProperty::Env(
+                "test-write-pat".to_string(),
+                "test-write-pat".to_string()
+            ))
+        );
         assert_eq!(
             config.dev_branch,
             Some(ParsedProperty::Env(
@@ -849,6 +1956,9 @@ mod tests {
         unsafe {
             env::remove_var("MERGERS_PAT");
         }
+        unsafe {
+            env::remove_var("MERGERS_WRITE_PAT");
+        }
         unsafe {
             env::remove_var("MERGERS_DEV_BRANCH");
         }
@@ -981,6 +2091,7 @@ mod tests {
             project: Some(ParsedProperty::Default("base-project".to_string())),
             repository: None,
             pat: Some(ParsedProperty::Default("base-pat".to_string())),
+            write_pat: None,
             dev_branch: Some(ParsedProperty::Default("base-dev".to_string())),
             target_branch: None,
             local_repo: None,
@@ -993,7 +2104,39 @@ mod tests {
             show_dependency_highlights: None,
             show_work_item_highlights: None,
             hooks: None,
+            known_work_item_states: None,
+            terminal_work_item_states: None,
+            work_item_comment: None,
+            release_gate: None,
+            build_checks: None,
+            release_tag: None,
+            auto_refresh: None,
+            selection_soft_cap: None,
+            selection_hard_cap: None,
+            strict_states: None,
+            sign_commits: None,
+            squash: None,
+            squash_message: None,
+            mainline_fallback: None,
             repo_aliases: None,
+            api_version: None,
+            history_depth: None,
+            since_field: None,
+            clipboard: None,
+            clipboard_file: None,
+            pull_request_url_template: None,
+            work_item_url_template: None,
+            pr_tabs: None,
+            binary_conflicts: None,
+            artifacts_feed: None,
+            release_notes_wiki: None,
+            notifications: None,
+            cherry_pick_order: None,
+            work_item_fields: None,
+            task_patterns: None,
+            release_notes_locale: None,
+            release_notes_locales: None,
+            repos: None,
         };
 
         let other = Config {
@@ -1001,6 +2144,7 @@ mod tests {
             project: None,
             repository: Some(ParsedProperty::Default("other-repo".to_string())),
             pat: None,
+            write_pat: None,
             dev_branch: Some(ParsedProperty::Default("other-dev".to_string())),
             target_branch: Some(ParsedProperty::Default("other-target".to_string())),
             local_repo: Some(ParsedProperty::Default("/other/path".to_string())),
@@ -1013,7 +2157,39 @@ mod tests {
             show_dependency_highlights: None,
             show_work_item_highlights: None,
             hooks: None,
+            known_work_item_states: None,
+            terminal_work_item_states: None,
+            work_item_comment: None,
+            release_gate: None,
+            build_checks: None,
+            release_tag: None,
+            auto_refresh: None,
+            selection_soft_cap: None,
+            selection_hard_cap: None,
+            strict_states: None,
+            sign_commits: None,
+            squash: None,
+            squash_message: None,
+            mainline_fallback: None,
             repo_aliases: None,
+            api_version: None,
+            history_depth: None,
+            since_field: None,
+            clipboard: None,
+            clipboard_file: None,
+            pull_request_url_template: None,
+            work_item_url_template: None,
+            pr_tabs: None,
+            binary_conflicts: None,
+            artifacts_feed: None,
+            release_notes_wiki: None,
+            notifications: None,
+            cherry_pick_order: None,
+            work_item_fields: None,
+            task_patterns: None,
+            release_notes_locale: None,
+            release_notes_locales: None,
+            repos: None,
         };
 
         let merged = base.merge(other);
@@ -1084,6 +2260,7 @@ mod tests {
             project: None,
             repository: None,
             pat: None,
+            write_pat: None,
             dev_branch: None,
             target_branch: None,
             local_repo: None,
@@ -1096,7 +2273,39 @@ mod tests {
             show_dependency_highlights: None,
             show_work_item_highlights: None,
             hooks: None,
+            known_work_item_states: None,
+            terminal_work_item_states: None,
+            work_item_comment: None,
+            release_gate: None,
+            build_checks: None,
+            release_tag: None,
+            auto_refresh: None,
+            selection_soft_cap: None,
+            selection_hard_cap: None,
+            strict_states: None,
+            sign_commits: None,
+            squash: None,
+            squash_message: None,
+            mainline_fallback: None,
             repo_aliases: None,
+            api_version: None,
+            history_depth: None,
+            since_field: None,
+            clipboard: None,
+            clipboard_file: None,
+            pull_request_url_template: None,
+            work_item_url_template: None,
+            pr_tabs: None,
+            binary_conflicts: None,
+            artifacts_feed: None,
+            release_notes_wiki: None,
+            notifications: None,
+            cherry_pick_order: None,
+            work_item_fields: None,
+            task_patterns: None,
+            release_notes_locale: None,
+            release_notes_locales: None,
+            repos: None,
         };
 
         let empty2 = Config {
@@ -1104,6 +2313,7 @@ mod tests {
             project: None,
             repository: None,
             pat: None,
+            write_pat: None,
             dev_branch: None,
             target_branch: None,
             local_repo: None,
@@ -1116,7 +2326,39 @@ mod tests {
             show_dependency_highlights: None,
             show_work_item_highlights: None,
             hooks: None,
+            known_work_item_states: None,
+            terminal_work_item_states: None,
+            work_item_comment: None,
+            release_gate: None,
+            build_checks: None,
+            release_tag: None,
+            auto_refresh: None,
+            selection_soft_cap: None,
+            selection_hard_cap: None,
+            strict_states: None,
+            sign_commits: None,
+            squash: None,
+            squash_message: None,
+            mainline_fallback: None,
             repo_aliases: None,
+            api_version: None,
+            history_depth: None,
+            since_field: None,
+            clipboard: None,
+            clipboard_file: None,
+            pull_request_url_template: None,
+            work_item_url_template: None,
+            pr_tabs: None,
+            binary_conflicts: None,
+            artifacts_feed: None,
+            release_notes_wiki: None,
+            notifications: None,
+            cherry_pick_order: None,
+            work_item_fields: None,
+            task_patterns: None,
+            release_notes_locale: None,
+            release_notes_locales: None,
+            repos: None,
         };
 
         let merged = empty1.merge(empty2);
@@ -1550,6 +2792,7 @@ invalid toml syntax here [
             project: Some(ParsedProperty::Default("test-project".to_string())),
             repository: Some(ParsedProperty::Default("test-repo".to_string())),
             pat: Some(ParsedProperty::Default("test-pat".to_string())),
+            write_pat: None,
             dev_branch: Some(ParsedProperty::Default("develop".to_string())),
             target_branch: Some(ParsedProperty::Default("main".to_string())),
             local_repo: Some(ParsedProperty::Default("/tmp/repo".to_string())),
@@ -1562,7 +2805,39 @@ invalid toml syntax here [
             show_dependency_highlights: Some(ParsedProperty::Default(true)),
             show_work_item_highlights: Some(ParsedProperty::Default(true)),
             hooks: None,
+            known_work_item_states: None,
+            terminal_work_item_states: None,
+            work_item_comment: None,
+            release_gate: None,
+            build_checks: None,
+            release_tag: None,
+            auto_refresh: None,
+            selection_soft_cap: None,
+            selection_hard_cap: None,
+            strict_states: None,
+            sign_commits: None,
+            squash: None,
+            squash_message: None,
+            mainline_fallback: None,
             repo_aliases: None,
+            api_version: None,
+            history_depth: None,
+            since_field: None,
+            clipboard: None,
+            clipboard_file: None,
+            pull_request_url_template: None,
+            work_item_url_template: None,
+            pr_tabs: None,
+            binary_conflicts: None,
+            artifacts_feed: None,
+            release_notes_wiki: None,
+            notifications: None,
+            cherry_pick_order: None,
+            work_item_fields: None,
+            task_patterns: None,
+            release_notes_locale: None,
+            release_notes_locales: None,
+            repos: None,
         };
 
         // Test serialization to TOML (serializes with enum variant info)
@@ -1867,6 +3142,7 @@ show_work_item_highlights = true
             project: None,
             repository: None,
             pat: None,
+            write_pat: None,
             dev_branch: None,
             target_branch: None,
             local_repo: None,
@@ -1879,7 +3155,39 @@ show_work_item_highlights = true
             show_dependency_highlights: Some(ParsedProperty::Default(true)),
             show_work_item_highlights: Some(ParsedProperty::Default(true)),
             hooks: None,
+            known_work_item_states: None,
+            terminal_work_item_states: None,
+            work_item_comment: None,
+            release_gate: None,
+            build_checks: None,
+            release_tag: None,
+            auto_refresh: None,
+            selection_soft_cap: None,
+            selection_hard_cap: None,
+            strict_states: None,
+            sign_commits: None,
+            squash: None,
+            squash_message: None,
+            mainline_fallback: None,
             repo_aliases: None,
+            api_version: None,
+            history_depth: None,
+            since_field: None,
+            clipboard: None,
+            clipboard_file: None,
+            pull_request_url_template: None,
+            work_item_url_template: None,
+            pr_tabs: None,
+            binary_conflicts: None,
+            artifacts_feed: None,
+            release_notes_wiki: None,
+            notifications: None,
+            cherry_pick_order: None,
+            work_item_fields: None,
+            task_patterns: None,
+            release_notes_locale: None,
+            release_notes_locales: None,
+            repos: None,
         };
 
         let override_config = Config {
@@ -1887,6 +3195,7 @@ show_work_item_highlights = true
             project: None,
             repository: None,
             pat: None,
+            write_pat: None,
             dev_branch: None,
             target_branch: None,
             local_repo: None,
@@ -1899,7 +3208,39 @@ show_work_item_highlights = true
             show_dependency_highlights: Some(ParsedProperty::Default(false)),
             show_work_item_highlights: None, // Should keep base value
             hooks: None,
+            known_work_item_states: None,
+            terminal_work_item_states: None,
+            work_item_comment: None,
+            release_gate: None,
+            build_checks: None,
+            release_tag: None,
+            auto_refresh: None,
+            selection_soft_cap: None,
+            selection_hard_cap: None,
+            strict_states: None,
+            sign_commits: None,
+            squash: None,
+            squash_message: None,
+            mainline_fallback: None,
             repo_aliases: None,
+            api_version: None,
+            history_depth: None,
+            since_field: None,
+            clipboard: None,
+            clipboard_file: None,
+            pull_request_url_template: None,
+            work_item_url_template: None,
+            pr_tabs: None,
+            binary_conflicts: None,
+            artifacts_feed: None,
+            release_notes_wiki: None,
+            notifications: None,
+            cherry_pick_order: None,
+            work_item_fields: None,
+            task_patterns: None,
+            release_notes_locale: None,
+            release_notes_locales: None,
+            repos: None,
         };
 
         let merged = base.merge(override_config);
@@ -2172,4 +3513,521 @@ execution = "async"
         // Post-merge should be preserved from config1
         assert_eq!(hooks.post_merge.commands, vec!["echo base-merge"]);
     }
+
+    /// # Terminal Work Item States
+    ///
+    /// Tests loading the states whose PRs are hidden in PR selection.
+    ///
+    /// ## Test Scenario
+    /// - Reads the states from a default config
+    /// - Sets `MERGERS_TERMINAL_WORK_ITEM_STATES` to a comma-separated list
+    ///
+    /// ## Expected Outcome
+    /// - No states are configured by default
+    /// - The environment variable is split into trimmed states
+    #[test]
+    #[file_serial(env_tests)]
+    fn test_terminal_work_item_states() {
+        assert!(Config::default().terminal_work_item_states().is_empty());
+
+        unsafe {
+            env::set_var("MERGERS_TERMINAL_WORK_ITEM_STATES", "Next Merged, Closed");
+        }
+        let config = Config::load_from_env();
+        unsafe {
+            env::remove_var("MERGERS_TERMINAL_WORK_ITEM_STATES");
+        }
+
+        assert_eq!(
+            config.terminal_work_item_states(),
+            vec!["Next Merged".to_string(), "Closed".to_string()]
+        );
+    }
+
+    /// # Work Item Comment Template
+    ///
+    /// Tests loading the release comment template from the environment.
+    ///
+    /// ## Test Scenario
+    /// - Reads the template from a default config
+    /// - Sets `MERGERS_WORK_ITEM_COMMENT`
+    ///
+    /// ## Expected Outcome
+    /// - No comment is configured by default
+    /// - The environment variable is used as the template
+    #[test]
+    #[file_serial(env_tests)]
+    fn test_work_item_comment() {
+        assert!(Config::default().work_item_comment().is_none());
+
+        unsafe {
+            env::set_var("MERGERS_WORK_ITEM_COMMENT", "Shipped in v{version}");
+        }
+        let config = Config::load_from_env();
+        unsafe {
+            env::remove_var("MERGERS_WORK_ITEM_COMMENT");
+        }
+
+        assert_eq!(
+            config.work_item_comment().as_deref(),
+            Some("Shipped in v{version}")
+        );
+    }
+
+    /// # Strict Work Item States Resolution
+    ///
+    /// Tests how strict state checking is resolved from the flag and config.
+    ///
+    /// ## Test Scenario
+    /// - Resolves with strict mode off, via flag, and via config
+    /// - Resolves with strict mode on but no known states configured
+    ///
+    /// ## Expected Outcome
+    /// - Off returns None, on returns the known states
+    /// - Missing known states is an error
+    #[test]
+    fn test_strict_work_item_states() {
+        let known = vec!["Active".to_string(), "Resolved".to_string()];
+        let config = Config {
+            known_work_item_states: Some(ParsedProperty::Default(known.clone())),
+            ..Default::default()
+        };
+        assert_eq!(config.strict_work_item_states(false).unwrap(), None);
+        assert_eq!(
+            config.strict_work_item_states(true).unwrap(),
+            Some(known.clone())
+        );
+
+        let config = Config {
+            strict_states: Some(ParsedProperty::Default(true)),
+            known_work_item_states: Some(ParsedProperty::Default(known.clone())),
+            ..Default::default()
+        };
+        assert_eq!(config.strict_work_item_states(false).unwrap(), Some(known));
+
+        let config = Config::default();
+        assert!(config.strict_work_item_states(true).is_err());
+    }
+
+    /// # URL Templates
+    ///
+    /// Tests resolving the pull request and work item URL templates.
+    ///
+    /// ## Test Scenario
+    /// - Resolves with no templates, a valid work item template from the
+    ///   file, and a template without `{id}`
+    ///
+    /// ## Expected Outcome
+    /// - Unset templates keep the standard layout
+    /// - The configured template is returned
+    /// - The invalid template is an error naming it
+    #[test]
+    fn test_url_templates() {
+        assert_eq!(
+            Config::default().url_templates().unwrap(),
+            UrlTemplates::default()
+        );
+
+        let template = "https://boards.example.com/{project}/{id}".to_string();
+        let config = Config {
+            work_item_url_template: Some(ParsedProperty::File(
+                template.clone(),
+                PathBuf::from("config.toml"),
+                template.clone(),
+            )),
+            ..Default::default()
+        };
+        let templates = config.url_templates().unwrap();
+        assert_eq!(templates.work_item(), Some(template.as_str()));
+        assert_eq!(templates.pull_request(), None);
+
+        let config = Config {
+            pull_request_url_template: Some(ParsedProperty::Default(
+                "https://code.example.com/pr".to_string(),
+            )),
+            ..Default::default()
+        };
+        let error = config.url_templates().unwrap_err();
+        assert!(error.to_string().contains("https://code.example.com/pr"));
+    }
+
+    /// # PR Tabs
+    ///
+    /// Tests reading PR grouping tabs from the config file format.
+    ///
+    /// ## Test Scenario
+    /// - Parses two `[[pr_tabs]]` tables, one with paths and one with titles
+    /// - Compiles them, then a tab without rules
+    ///
+    /// ## Expected Outcome
+    /// - Both tabs are compiled in file order
+    /// - No tabs are configured by default
+    /// - The tab without rules is an error naming it
+    #[test]
+    fn test_pr_tabs() {
+        let config_file: ConfigFile = toml::from_str(
+            r#"
+            [[pr_tabs]]
+            name = "Backend"
+            paths = ["src/server/**"]
+
+            [[pr_tabs]]
+            name = "Infra"
+            titles = ["(?i)^\\[infra\\]"]
+            "#,
+        )
+        .unwrap();
+        let tabs = config_file.pr_tabs.unwrap();
+        let config = Config {
+            pr_tabs: Some(ParsedProperty::File(
+                tabs,
+                PathBuf::from("config.toml"),
+                "Backend,Infra".to_string(),
+            )),
+            ..Default::default()
+        };
+        let tabs = config.pr_tabs().unwrap();
+        let names: Vec<&str> = tabs.iter().map(|tab| tab.name()).collect();
+        assert_eq!(names, vec!["Backend", "Infra"]);
+        assert!(tabs[1].matches("[Infra] Bump runners", &[]));
+
+        assert!(Config::default().pr_tabs().unwrap().is_empty());
+
+        let config = Config {
+            pr_tabs: Some(ParsedProperty::Default(vec![PrTabConfig {
+                name: "Web".to_string(),
+                ..Default::default()
+            }])),
+            ..Default::default()
+        };
+        assert!(config.pr_tabs().unwrap_err().to_string().contains("'Web'"));
+    }
+
+    /// # Batch Repositories
+    ///
+    /// Tests reading the repositories of the batch command from the config
+    /// file format.
+    ///
+    /// ## Test Scenario
+    /// - Parses two `[[repos]]` tables, one named and one overriding the project
+    /// - Selects all of them, then one by name, then with no repositories
+    ///
+    /// ## Expected Outcome
+    /// - Repositories keep the file order; unnamed entries use the repository
+    /// - `only` keeps the named entry
+    /// - Without `[[repos]]` entries the batch command is an error
+    #[test]
+    fn test_batch_repos() {
+        let config_file: ConfigFile = toml::from_str(
+            r#"
+            [[repos]]
+            name = "api"
+            repository = "api-backend"
+            local_repo = "/src/api"
+
+            [[repos]]
+            project = "Web"
+            repository = "web-frontend"
+            "#,
+        )
+        .unwrap();
+        let config = Config {
+            repos: Some(ParsedProperty::File(
+                config_file.repos.unwrap(),
+                PathBuf::from("config.toml"),
+                "api,web-frontend".to_string(),
+            )),
+            ..Default::default()
+        };
+
+        let repos = config.batch_repos(&[]).unwrap();
+        let names: Vec<&str> = repos.iter().map(|repo| repo.name()).collect();
+        assert_eq!(names, vec!["api", "web-frontend"]);
+        assert_eq!(repos[0].local_repo.as_deref(), Some("/src/api"));
+        assert_eq!(repos[1].project.as_deref(), Some("Web"));
+        assert_eq!(repos[1].organization, None);
+
+        let repos = config.batch_repos(&["web-frontend".to_string()]).unwrap();
+        assert_eq!(repos.len(), 1);
+        assert_eq!(repos[0].repository, "web-frontend");
+
+        let error = Config::default().batch_repos(&[]).unwrap_err();
+        assert!(error.to_string().contains("[[repos]]"));
+    }
+
+    /// # Selection Caps
+    ///
+    /// Tests resolving the PR selection guard rails.
+    ///
+    /// ## Test Scenario
+    /// - Reads caps from defaults, then a config with a custom soft cap and a
+    ///   disabled hard cap
+    ///
+    /// ## Expected Outcome
+    /// - Defaults are 50 and 100
+    /// - A hard cap of 0 disables it
+    #[test]
+    fn test_selection_caps() {
+        assert_eq!(Config::default().selection_caps(), SelectionCaps::default());
+
+        let config = Config {
+            selection_soft_cap: Some(ParsedProperty::File(
+                20,
+                PathBuf::from("config.toml"),
+                "20".to_string(),
+            )),
+            selection_hard_cap: Some(ParsedProperty::Env(0, "0".to_string())),
+            ..Default::default()
+        };
+        let caps = config.selection_caps();
+        assert_eq!(caps.soft, Some(20));
+        assert_eq!(caps.hard, None);
+    }
+
+    /// # Config Durations
+    ///
+    /// Tests duration settings written as numbers and as strings.
+    ///
+    /// ## Test Scenario
+    /// - Deserializes `auto_refresh` as a bare number and as "90s"
+    /// - Parses an unknown unit
+    ///
+    /// ## Expected Outcome
+    /// - A bare number is read in minutes, a string by the shared parser
+    /// - The raw value is kept as written
+    /// - The unknown unit is rejected
+    #[test]
+    fn test_config_durations() {
+        let minutes: ConfigFile = toml::from_str("auto_refresh = 5").unwrap();
+        let minutes = minutes.auto_refresh.unwrap();
+        assert_eq!(
+            minutes.parse(TimeUnit::Minutes).unwrap(),
+            std::time::Duration::from_secs(300)
+        );
+        assert_eq!(minutes.raw(), "5");
+
+        let text: ConfigFile = toml::from_str(r#"auto_refresh = "90s""#).unwrap();
+        let text = text.auto_refresh.unwrap();
+        assert_eq!(
+            text.parse(TimeUnit::Minutes).unwrap(),
+            std::time::Duration::from_secs(90)
+        );
+        assert_eq!(text.raw(), "90s");
+
+        let bad: ConfigFile = toml::from_str(r#"auto_refresh = "5 fortnights""#).unwrap();
+        assert!(bad.auto_refresh.unwrap().parse(TimeUnit::Minutes).is_err());
+    }
+    /// # Cherry-Pick Order
+    ///
+    /// Tests the `[cherry_pick]` table and the flag overriding it.
+    ///
+    /// ## Test Scenario
+    /// - Deserializes `order = "topological"` from a `[cherry_pick]` table
+    /// - Resolves the order with and without `--cherry-pick-order`
+    /// - Parses an unknown order
+    ///
+    /// ## Expected Outcome
+    /// - The file order applies unless the flag is given
+    /// - Without any setting the order is by closed date
+    /// - The unknown order is rejected
+    #[test]
+    fn test_cherry_pick_order() {
+        let file: ConfigFile = toml::from_str("[cherry_pick]\norder = \"topological\"").unwrap();
+        let order = file
+            .cherry_pick
+            .and_then(|settings| settings.order)
+            .unwrap();
+        let config = Config {
+            cherry_pick_order: Some(ParsedProperty::File(
+                order,
+                PathBuf::from("config.toml"),
+                order.to_string(),
+            )),
+            ..Default::default()
+        };
+
+        assert_eq!(config.cherry_pick_order(None), CherryPickOrder::Topological);
+        assert_eq!(
+            config.cherry_pick_order(Some(CherryPickOrder::ClosedDate)),
+            CherryPickOrder::ClosedDate
+        );
+        assert_eq!(
+            Config::default().cherry_pick_order(None),
+            CherryPickOrder::ClosedDate
+        );
+        assert!(toml::from_str::<ConfigFile>("[cherry_pick]\norder = \"random\"").is_err());
+    }
+
+    /// # Build Check Settings
+    ///
+    /// Tests `check_builds`, `require_green_build` and the flags raising them.
+    ///
+    /// ## Test Scenario
+    /// - Deserializes `require_green_build = true` and resolves the mode with
+    ///   and without flags
+    /// - Resolves the mode of a default config with `--check-builds`
+    ///
+    /// ## Expected Outcome
+    /// - Requiring green builds from the file is kept when only
+    ///   `--check-builds` is given
+    /// - Flags enable checks the config does not
+    /// - Without any setting nothing is checked
+    #[test]
+    fn test_build_check_mode() {
+        let file: ConfigFile = toml::from_str("require_green_build = true").unwrap();
+        let (mode, raw) = build_check_mode(file.check_builds, file.require_green_build).unwrap();
+        assert_eq!(raw, "require_green_build = true");
+        let config = Config {
+            build_checks: Some(ParsedProperty::File(
+                mode,
+                PathBuf::from("config.toml"),
+                raw,
+            )),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.build_check_mode(false, false),
+            BuildCheckMode::Require
+        );
+        assert_eq!(
+            config.build_check_mode(true, false),
+            BuildCheckMode::Require
+        );
+
+        let config = Config::default();
+        assert_eq!(config.build_check_mode(false, false), BuildCheckMode::Off);
+        assert_eq!(config.build_check_mode(true, false), BuildCheckMode::Flag);
+        assert_eq!(config.build_check_mode(true, true), BuildCheckMode::Require);
+        assert!(build_check_mode(None, None).is_none());
+    }
+
+    /// # Release Tag Settings
+    ///
+    /// Tests `release_tag`, `push_release_tag` and the flags raising them.
+    ///
+    /// ## Test Scenario
+    /// - Deserializes `push_release_tag = true` and resolves the mode with
+    ///   and without flags
+    /// - Resolves the mode of a default config with `--release-tag`
+    ///
+    /// ## Expected Outcome
+    /// - Pushing from the file is kept when only `--release-tag` is given
+    /// - Flags enable the tag the config does not
+    /// - Without any setting no tag is created
+    #[test]
+    fn test_release_tag_mode() {
+        let file: ConfigFile = toml::from_str("push_release_tag = true").unwrap();
+        let (mode, raw) = release_tag_mode(file.release_tag, file.push_release_tag).unwrap();
+        assert_eq!(raw, "push_release_tag = true");
+        let config = Config {
+            release_tag: Some(ParsedProperty::File(
+                mode,
+                PathBuf::from("config.toml"),
+                raw,
+            )),
+            ..Default::default()
+        };
+
+        assert_eq!(config.release_tag_mode(false, false), ReleaseTagMode::Push);
+        assert_eq!(config.release_tag_mode(true, false), ReleaseTagMode::Push);
+
+        let config = Config::default();
+        assert_eq!(config.release_tag_mode(false, false), ReleaseTagMode::Off);
+        assert_eq!(config.release_tag_mode(true, false), ReleaseTagMode::Create);
+        assert_eq!(config.release_tag_mode(true, true), ReleaseTagMode::Push);
+        assert!(release_tag_mode(None, None).is_none());
+    }
+
+    /// # Sign Commits Setting
+    ///
+    /// Tests `sign_commits` and the `--sign-commits` flag.
+    ///
+    /// ## Test Scenario
+    /// - Deserializes `sign_commits = true` into a config
+    /// - Resolves signing of that config and of a default config
+    ///
+    /// ## Expected Outcome
+    /// - The setting or the flag enables signing; neither leaves it off
+    #[test]
+    fn test_sign_commits() {
+        let file: ConfigFile = toml::from_str("sign_commits = true").unwrap();
+        let config = Config {
+            sign_commits: file
+                .sign_commits
+                .map(|v| ParsedProperty::File(v, PathBuf::from("config.toml"), v.to_string())),
+            ..Default::default()
+        };
+        assert!(config.sign_commits(false));
+
+        let config = Config::default();
+        assert!(!config.sign_commits(false));
+        assert!(config.sign_commits(true));
+    }
+
+    /// # Squash Setting
+    ///
+    /// Tests `squash`, `squash_message` and the `--squash` flags.
+    ///
+    /// ## Test Scenario
+    /// - Deserializes `squash = true` with a custom message into a config
+    /// - Resolves the squash message of that config and of a default config
+    ///
+    /// ## Expected Outcome
+    /// - The setting or the flag enables squashing; neither leaves it off
+    /// - The flag's message wins over the setting's, which wins over the default
+    #[test]
+    fn test_squash() {
+        let file: ConfigFile = toml::from_str(
+            r#"
+squash = true
+squash_message = "PR {id}: {title}"
+"#,
+        )
+        .unwrap();
+        let path = PathBuf::from("config.toml");
+        let config = Config {
+            squash: file
+                .squash
+                .map(|v| ParsedProperty::File(v, path.clone(), v.to_string())),
+            squash_message: file
+                .squash_message
+                .map(|v| ParsedProperty::File(v.clone(), path.clone(), v)),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.squash_message(false, None).as_deref(),
+            Some("PR {id}: {title}")
+        );
+        assert_eq!(
+            config.squash_message(false, Some("{title}")).as_deref(),
+            Some("{title}")
+        );
+
+        let config = Config::default();
+        assert!(config.squash_message(false, None).is_none());
+        assert_eq!(
+            config.squash_message(true, None).as_deref(),
+            Some(DEFAULT_SQUASH_MESSAGE)
+        );
+    }
+
+    /// # Target Branch List
+    ///
+    /// Tests a list of target branches in the config file.
+    ///
+    /// ## Test Scenario
+    /// - Deserializes `target_branch` as a string and as a list
+    ///
+    /// ## Expected Outcome
+    /// - Both become the comma-separated form used on the command line
+    #[test]
+    fn test_target_branch_list() {
+        let file: ConfigFile = toml::from_str(r#"target_branch = "next""#).unwrap();
+        assert_eq!(file.target_branch.unwrap().raw(), "next");
+
+        let file: ConfigFile =
+            toml::from_str(r#"target_branch = ["release/6.6", "release/6.7"]"#).unwrap();
+        assert_eq!(file.target_branch.unwrap().raw(), "release/6.6,release/6.7");
+    }
 }
@@ -47,6 +47,18 @@ pub enum ExitCode {
 
     /// A hook failed and was configured to abort.
     HookFailed = 8,
+
+    /// Azure DevOps rejected the credentials (401/403).
+    AuthFailed = 9,
+
+    /// Any other Azure DevOps API failure.
+    ApiError = 10,
+
+    /// Configuration is missing or invalid.
+    ConfigError = 11,
+
+    /// A git operation failed (other than a conflict).
+    GitError = 12,
 }
 
 impl ExitCode {
@@ -67,6 +79,10 @@ impl ExitCode {
             ExitCode::NoPRsMatched => "No pull requests matched the selection criteria",
             ExitCode::Locked => "Another merge operation is in progress",
             ExitCode::HookFailed => "A hook failed and was configured to abort the workflow",
+            ExitCode::AuthFailed => "Azure DevOps authentication failed",
+            ExitCode::ApiError => "Azure DevOps API request failed",
+            ExitCode::ConfigError => "Configuration is missing or invalid",
+            ExitCode::GitError => "Git operation failed",
         }
     }
 }
@@ -107,6 +123,10 @@ mod tests {
         assert_eq!(ExitCode::NoPRsMatched.code(), 6);
         assert_eq!(ExitCode::Locked.code(), 7);
         assert_eq!(ExitCode::HookFailed.code(), 8);
+        assert_eq!(ExitCode::AuthFailed.code(), 9);
+        assert_eq!(ExitCode::ApiError.code(), 10);
+        assert_eq!(ExitCode::ConfigError.code(), 11);
+        assert_eq!(ExitCode::GitError.code(), 12);
     }
 
     /// # Exit Code Descriptions
@@ -129,6 +149,10 @@ mod tests {
         assert!(!ExitCode::NoPRsMatched.description().is_empty());
         assert!(!ExitCode::Locked.description().is_empty());
         assert!(!ExitCode::HookFailed.description().is_empty());
+        assert!(!ExitCode::AuthFailed.description().is_empty());
+        assert!(!ExitCode::ApiError.description().is_empty());
+        assert!(!ExitCode::ConfigError.description().is_empty());
+        assert!(!ExitCode::GitError.description().is_empty());
     }
 
     /// # Exit Code Display
@@ -0,0 +1,359 @@
+//! Detection and path-based resolution of binary file conflicts.
+//!
+//! Conflicts in binary files (images, `.resx` resources, archives) have no
+//! hunks to merge by hand; one side has to win. Policies configured in the
+//! config file pick a side for binary files matching path globs, so these
+//! conflicts are resolved without stopping the cherry-pick. Binary files
+//! without a matching policy are left for the conflict screen's chooser.
+//!
+//! In a cherry-pick, "ours" is the target branch and "theirs" is the
+//! commit being picked.
+//!
+//! # Config Format
+//!
+//! ```toml
+//! [[binary_conflicts]]
+//! paths = ["*.png", "assets/**"]
+//! resolve = "theirs"
+//!
+//! [[binary_conflicts]]
+//! paths = ["*.resx"]
+//! resolve = "ours"
+//! ```
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::core::operations::{BinaryConflictPolicy, BinaryConflictRule, ConflictSide};
+//!
+//! let policy = BinaryConflictPolicy::new(&[BinaryConflictRule {
+//!     paths: vec!["*.png".to_string()],
+//!     resolve: ConflictSide::Theirs,
+//! }])
+//! .unwrap();
+//! assert_eq!(policy.side_for("assets/logo.png"), Some(ConflictSide::Theirs));
+//! assert_eq!(policy.side_for("Strings.resx"), None);
+//! ```
+
+use std::fmt;
+use std::path::Path;
+
+use anyhow::Result;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::core::operations::pr_tabs::glob_to_regex;
+use crate::git;
+
+/// Which version of a conflicted file to keep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictSide {
+    /// The target branch's version.
+    Ours,
+    /// The version from the commit being cherry-picked.
+    Theirs,
+}
+
+impl fmt::Display for ConflictSide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConflictSide::Ours => write!(f, "ours"),
+            ConflictSide::Theirs => write!(f, "theirs"),
+        }
+    }
+}
+
+/// A binary conflict policy as written in the config file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BinaryConflictRule {
+    /// Globs matched against the conflicted file's path.
+    pub paths: Vec<String>,
+    /// Side kept for matching binary files.
+    pub resolve: ConflictSide,
+}
+
+/// Compiled binary conflict policies, checked in config order.
+#[derive(Debug, Clone, Default)]
+pub struct BinaryConflictPolicy {
+    rules: Vec<(Vec<Regex>, ConflictSide)>,
+}
+
+impl BinaryConflictPolicy {
+    /// Compiles the configured rules.
+    ///
+    /// Returns an error if a rule has no path globs.
+    pub fn new(rules: &[BinaryConflictRule]) -> Result<Self> {
+        let rules = rules
+            .iter()
+            .map(|rule| {
+                if rule.paths.iter().all(|glob| glob.trim().is_empty()) {
+                    anyhow::bail!(
+                        "binary_conflicts rule resolving to '{}' needs at least one path",
+                        rule.resolve
+                    );
+                }
+                let paths = rule
+                    .paths
+                    .iter()
+                    .filter(|glob| !glob.trim().is_empty())
+                    .map(|glob| glob_to_regex(glob))
+                    .collect();
+                Ok((paths, rule.resolve))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { rules })
+    }
+
+    /// Returns `true` if no rules are configured.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Side configured for `path` by the first matching rule.
+    pub fn side_for(&self, path: &str) -> Option<ConflictSide> {
+        self.rules
+            .iter()
+            .find(|(paths, _)| paths.iter().any(|regex| regex.is_match(path)))
+            .map(|(_, side)| *side)
+    }
+}
+
+/// Binary conflicts resolved by policy, and the files still conflicted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BinaryResolution {
+    /// Files resolved by a policy, with the side that was kept.
+    pub resolved: Vec<(String, ConflictSide)>,
+    /// Files that still need manual resolution.
+    pub remaining: Vec<String>,
+}
+
+/// Resolves binary conflicts that match a policy.
+///
+/// Each matching file is checked out from its configured side and staged.
+/// Text files and binary files without a policy are returned in
+/// [`BinaryResolution::remaining`]. The cherry-pick is not continued.
+pub fn resolve_binary_conflicts(
+    repo_path: &Path,
+    conflicted_files: &[String],
+    policy: &BinaryConflictPolicy,
+) -> Result<BinaryResolution> {
+    let mut resolution = BinaryResolution::default();
+    for file in conflicted_files {
+        match policy.side_for(file) {
+            Some(side) if git::is_binary_conflict(repo_path, file)? => {
+                git::checkout_conflict_side(repo_path, file, side)?;
+                resolution.resolved.push((file.clone(), side));
+            }
+            _ => resolution.remaining.push(file.clone()),
+        }
+    }
+    Ok(resolution)
+}
+
+/// Applies the policies to a paused cherry-pick.
+///
+/// When every conflict was a binary file covered by a policy, the
+/// cherry-pick is continued (signed if `sign`) and the returned `remaining`
+/// list is empty. Otherwise the cherry-pick stays paused on the remaining files.
+pub fn apply_binary_policy(
+    repo_path: &Path,
+    conflicted_files: &[String],
+    policy: &BinaryConflictPolicy,
+    sign: bool,
+) -> Result<BinaryResolution> {
+    if policy.is_empty() {
+        return Ok(BinaryResolution {
+            resolved: Vec::new(),
+            remaining: conflicted_files.to_vec(),
+        });
+    }
+    let resolution = resolve_binary_conflicts(repo_path, conflicted_files, policy)?;
+    if resolution.remaining.is_empty() {
+        git::continue_cherry_pick(repo_path, sign)?;
+    }
+    Ok(resolution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(repo: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    /// Creates a repository with a cherry-pick paused on a binary
+    /// `logo.png` and, optionally, a text `notes.txt`.
+    fn setup_binary_conflict(with_text_conflict: bool) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        git(repo, &["init", "-q", "-b", "main"]);
+        git(repo, &["config", "user.name", "Test User"]);
+        git(repo, &["config", "user.email", "test@example.com"]);
+        git(repo, &["config", "commit.gpgsign", "false"]);
+
+        std::fs::write(repo.join("logo.png"), b"\x89PNG\0base").unwrap();
+        std::fs::write(repo.join("notes.txt"), "original\n").unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-q", "-m", "Initial"]);
+        git(repo, &["checkout", "-q", "-b", "feature"]);
+        std::fs::write(repo.join("logo.png"), b"\x89PNG\0feature").unwrap();
+        if with_text_conflict {
+            std::fs::write(repo.join("notes.txt"), "feature\n").unwrap();
+        }
+        git(repo, &["commit", "-q", "-am", "Feature"]);
+        git(repo, &["checkout", "-q", "main"]);
+        std::fs::write(repo.join("logo.png"), b"\x89PNG\0main").unwrap();
+        if with_text_conflict {
+            std::fs::write(repo.join("notes.txt"), "main\n").unwrap();
+        }
+        git(repo, &["commit", "-q", "-am", "Main"]);
+
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(["cherry-pick", "feature"])
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "cherry-pick should conflict");
+        temp_dir
+    }
+
+    fn png_policy(side: ConflictSide) -> BinaryConflictPolicy {
+        BinaryConflictPolicy::new(&[BinaryConflictRule {
+            paths: vec!["*.png".to_string(), "*.txt".to_string()],
+            resolve: side,
+        }])
+        .unwrap()
+    }
+
+    /// # Policy Matching
+    ///
+    /// Tests compiling rules and picking the side for a path.
+    ///
+    /// ## Test Scenario
+    /// - Parses two rules from TOML, then compiles them
+    /// - Compiles a rule without paths
+    ///
+    /// ## Expected Outcome
+    /// - The first matching rule decides the side
+    /// - Unmatched paths have no side
+    /// - A rule without paths is an error
+    #[test]
+    fn test_policy_matching() {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            binary_conflicts: Vec<BinaryConflictRule>,
+        }
+        let wrapper: Wrapper = toml::from_str(
+            r#"
+            [[binary_conflicts]]
+            paths = ["assets/**"]
+            resolve = "theirs"
+
+            [[binary_conflicts]]
+            paths = ["*.png", "*.resx"]
+            resolve = "ours"
+            "#,
+        )
+        .unwrap();
+        let policy = BinaryConflictPolicy::new(&wrapper.binary_conflicts).unwrap();
+
+        assert_eq!(
+            policy.side_for("assets/logo.png"),
+            Some(ConflictSide::Theirs)
+        );
+        assert_eq!(policy.side_for("web/icon.png"), Some(ConflictSide::Ours));
+        assert_eq!(policy.side_for("Strings.resx"), Some(ConflictSide::Ours));
+        assert_eq!(policy.side_for("src/main.rs"), None);
+        assert!(BinaryConflictPolicy::default().is_empty());
+
+        let error = BinaryConflictPolicy::new(&[BinaryConflictRule {
+            paths: Vec::new(),
+            resolve: ConflictSide::Ours,
+        }])
+        .unwrap_err();
+        assert!(error.to_string().contains("at least one path"));
+    }
+
+    /// # Binary Conflict Auto-Resolution
+    ///
+    /// Tests resolving a binary conflict by policy and continuing the
+    /// cherry-pick.
+    ///
+    /// ## Test Scenario
+    /// - Pauses a cherry-pick on a conflicted binary file only
+    /// - Applies a policy taking "theirs" for `*.png`
+    ///
+    /// ## Expected Outcome
+    /// - The file is resolved to the picked commit's content
+    /// - The cherry-pick is continued
+    #[test]
+    fn test_apply_binary_policy_continues() {
+        let temp_dir = setup_binary_conflict(false);
+        let repo = temp_dir.path();
+        let files = git::unresolved_files(repo).unwrap();
+        assert_eq!(files, vec!["logo.png"]);
+
+        let resolution =
+            apply_binary_policy(repo, &files, &png_policy(ConflictSide::Theirs), false).unwrap();
+
+        assert_eq!(
+            resolution.resolved,
+            vec![("logo.png".to_string(), ConflictSide::Theirs)]
+        );
+        assert!(resolution.remaining.is_empty());
+        assert!(!git::cherry_pick_in_progress(repo));
+        assert_eq!(
+            std::fs::read(repo.join("logo.png")).unwrap(),
+            b"\x89PNG\0feature"
+        );
+    }
+
+    /// # Text Conflicts Left Alone
+    ///
+    /// Tests that policies only resolve binary files.
+    ///
+    /// ## Test Scenario
+    /// - Pauses a cherry-pick on a binary and a text file
+    /// - Applies a policy whose globs match both files
+    ///
+    /// ## Expected Outcome
+    /// - Only the binary file is resolved, to the target branch's content
+    /// - The text file remains and the cherry-pick stays paused
+    #[test]
+    fn test_apply_binary_policy_leaves_text_conflicts() {
+        let temp_dir = setup_binary_conflict(true);
+        let repo = temp_dir.path();
+        let files = git::unresolved_files(repo).unwrap();
+        assert!(git::is_binary_conflict(repo, "logo.png").unwrap());
+        assert!(!git::is_binary_conflict(repo, "notes.txt").unwrap());
+
+        let resolution =
+            apply_binary_policy(repo, &files, &png_policy(ConflictSide::Ours), false).unwrap();
+
+        assert_eq!(
+            resolution.resolved,
+            vec![("logo.png".to_string(), ConflictSide::Ours)]
+        );
+        assert_eq!(resolution.remaining, vec!["notes.txt"]);
+        assert!(git::cherry_pick_in_progress(repo));
+        assert_eq!(git::unresolved_files(repo).unwrap(), vec!["notes.txt"]);
+        assert_eq!(
+            std::fs::read(repo.join("logo.png")).unwrap(),
+            b"\x89PNG\0main"
+        );
+    }
+}
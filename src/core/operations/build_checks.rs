@@ -0,0 +1,287 @@
+//! Build and branch policy checks of PRs before they are cherry-picked.
+//!
+//! A PR merged on the dev branch may still have a red build or a rejected
+//! branch policy. With checks enabled, the builds of each PR's last merge
+//! commit and its blocking policy evaluations are looked up; failing PRs are
+//! flagged in the PR selection table and, when green builds are required,
+//! excluded from the merge.
+//!
+//! # Config Format
+//!
+//! ```toml
+//! # Flag PRs whose merge commit did not build or that failed a branch policy
+//! check_builds = true
+//! # Also leave them out of the merge (implies check_builds)
+//! require_green_build = true
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::Result;
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{AzureDevOpsClient, PrChecks};
+
+/// Whether PR builds and branch policies are checked, and what happens to
+/// PRs failing the check.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BuildCheckMode {
+    /// No checks are run.
+    #[default]
+    Off,
+    /// Failing PRs are flagged but can still be merged.
+    Flag,
+    /// Failing PRs are flagged and left out of the merge.
+    Require,
+}
+
+impl BuildCheckMode {
+    /// Returns the mode for the `check_builds` and `require_green_build` settings.
+    pub fn from_flags(check_builds: bool, require_green_build: bool) -> Self {
+        if require_green_build {
+            BuildCheckMode::Require
+        } else if check_builds {
+            BuildCheckMode::Flag
+        } else {
+            BuildCheckMode::Off
+        }
+    }
+
+    /// Returns true if PRs are checked.
+    pub fn is_enabled(self) -> bool {
+        self != BuildCheckMode::Off
+    }
+
+    /// Returns true if PRs failing the check are left out of the merge.
+    pub fn excludes_failing(self) -> bool {
+        self == BuildCheckMode::Require
+    }
+}
+
+impl FromStr for BuildCheckMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "off" => Ok(BuildCheckMode::Off),
+            "flag" => Ok(BuildCheckMode::Flag),
+            "require" => Ok(BuildCheckMode::Require),
+            other => anyhow::bail!(
+                "Invalid build check mode '{}' (expected off, flag or require)",
+                other
+            ),
+        }
+    }
+}
+
+impl fmt::Display for BuildCheckMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildCheckMode::Off => write!(f, "off"),
+            BuildCheckMode::Flag => write!(f, "flag"),
+            BuildCheckMode::Require => write!(f, "require"),
+        }
+    }
+}
+
+/// Failed build and policy checks of the checked PRs, keyed by PR id.
+///
+/// PRs that were not checked pass.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BuildCheckIndex {
+    /// PR id → descriptions of the checks it did not pass.
+    failures: HashMap<i32, Vec<String>>,
+}
+
+impl BuildCheckIndex {
+    /// Records the checks of a PR; a PR whose status could not be fetched fails.
+    pub fn record(&mut self, pr_id: i32, checks: Result<PrChecks>) {
+        let failures = match checks {
+            Ok(checks) => checks.failures(),
+            Err(e) => vec![format!("status unavailable: {}", e)],
+        };
+        if failures.is_empty() {
+            self.failures.remove(&pr_id);
+        } else {
+            self.failures.insert(pr_id, failures);
+        }
+    }
+
+    /// Adds the failures recorded by another check of different PRs.
+    pub fn extend(&mut self, other: BuildCheckIndex) {
+        self.failures.extend(other.failures);
+    }
+
+    /// Returns the checks `pr_id` did not pass, if any.
+    pub fn failures(&self, pr_id: i32) -> Option<&[String]> {
+        self.failures.get(&pr_id).map(Vec::as_slice)
+    }
+
+    /// Returns true unless `pr_id` failed a check.
+    pub fn passes(&self, pr_id: i32) -> bool {
+        !self.failures.contains_key(&pr_id)
+    }
+
+    /// Returns the number of PRs that failed a check.
+    pub fn failing_count(&self) -> usize {
+        self.failures.len()
+    }
+
+    /// Returns `true` if no PR failed a check.
+    pub fn is_empty(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Short label for `pr_id`, e.g. `build 'CI' failed` or
+    /// `build 'CI' failed +1`.
+    pub fn label(&self, pr_id: i32) -> Option<String> {
+        let failures = self.failures(pr_id)?;
+        let first = failures.first()?;
+        Some(match failures.len() - 1 {
+            0 => first.clone(),
+            more => format!("{} +{}", first, more),
+        })
+    }
+}
+
+/// Checks the builds and branch policies of PRs, given as PR id and last
+/// merge commit pairs.
+///
+/// Up to `concurrency` PRs are checked at a time. Errors fail the affected
+/// PRs rather than the whole check.
+pub async fn check_pull_requests(
+    client: &AzureDevOpsClient,
+    prs: Vec<(i32, String)>,
+    concurrency: usize,
+) -> BuildCheckIndex {
+    let mut index = BuildCheckIndex::default();
+    if prs.is_empty() {
+        return index;
+    }
+
+    let project_id = match client.fetch_project_id().await {
+        Ok(id) => id,
+        Err(e) => {
+            let message = format!("{:#}", e);
+            for (pr_id, _) in prs {
+                index.record(pr_id, Err(anyhow::anyhow!("{}", message)));
+            }
+            return index;
+        }
+    };
+
+    let results: Vec<(i32, Result<PrChecks>)> = stream::iter(prs)
+        .map(|(pr_id, commit_id)| {
+            let project_id = &project_id;
+            async move {
+                let checks = client.fetch_pr_checks(pr_id, &commit_id, project_id).await;
+                (pr_id, checks)
+            }
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await;
+
+    for (pr_id, checks) in results {
+        if let Err(ref e) = checks {
+            tracing::warn!("Failed to check builds of PR #{}: {:#}", pr_id, e);
+        }
+        index.record(pr_id, checks);
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{CheckOutcome, CheckState};
+
+    fn checks(build: CheckState, policy: CheckState) -> PrChecks {
+        PrChecks {
+            builds: vec![CheckOutcome {
+                name: "CI".to_string(),
+                state: build,
+            }],
+            policies: vec![CheckOutcome {
+                name: "Minimum number of reviewers".to_string(),
+                state: policy,
+            }],
+        }
+    }
+
+    /// # Build Check Mode
+    ///
+    /// Tests deriving the check mode from the config flags.
+    ///
+    /// ## Test Scenario
+    /// - Combines `check_builds` and `require_green_build`, and parses modes
+    ///
+    /// ## Expected Outcome
+    /// - `require_green_build` wins and implies checking
+    /// - Only the require mode excludes failing PRs
+    #[test]
+    fn test_build_check_mode() {
+        assert_eq!(
+            BuildCheckMode::from_flags(false, false),
+            BuildCheckMode::Off
+        );
+        assert_eq!(
+            BuildCheckMode::from_flags(true, false),
+            BuildCheckMode::Flag
+        );
+        assert_eq!(
+            BuildCheckMode::from_flags(false, true),
+            BuildCheckMode::Require
+        );
+        assert!(!BuildCheckMode::Off.is_enabled());
+        assert!(BuildCheckMode::Flag.is_enabled());
+        assert!(!BuildCheckMode::Flag.excludes_failing());
+        assert!(BuildCheckMode::Require.excludes_failing());
+
+        assert_eq!(
+            "Require".parse::<BuildCheckMode>().unwrap(),
+            BuildCheckMode::Require
+        );
+        assert_eq!(BuildCheckMode::Flag.to_string(), "flag");
+        assert!("green".parse::<BuildCheckMode>().is_err());
+    }
+
+    /// # Build Check Index
+    ///
+    /// Tests recording the checks of several PRs.
+    ///
+    /// ## Test Scenario
+    /// - Records a passing PR, a PR with a failed build and a rejected
+    ///   policy, and a PR whose status could not be fetched
+    ///
+    /// ## Expected Outcome
+    /// - Only failing PRs are flagged, with a label naming the first failure
+    /// - Unchecked PRs pass
+    #[test]
+    fn test_build_check_index() {
+        let mut index = BuildCheckIndex::default();
+        index.record(1, Ok(checks(CheckState::Succeeded, CheckState::Succeeded)));
+        index.record(2, Ok(checks(CheckState::Failed, CheckState::Failed)));
+        index.record(3, Err(anyhow::anyhow!("Failed to list builds of PR #3")));
+
+        assert!(index.passes(1));
+        assert!(!index.passes(2));
+        assert!(!index.passes(3));
+        assert!(index.passes(4));
+        assert_eq!(index.failing_count(), 2);
+
+        assert_eq!(index.label(1), None);
+        assert_eq!(index.label(2).as_deref(), Some("build 'CI' failed +1"));
+        assert_eq!(
+            index.failures(3).unwrap(),
+            ["status unavailable: Failed to list builds of PR #3".to_string()]
+        );
+
+        index.record(2, Ok(checks(CheckState::Succeeded, CheckState::Succeeded)));
+        assert!(index.passes(2));
+    }
+}
@@ -5,11 +5,28 @@
 //!
 //! Note: The full implementation integrates with the existing git module.
 //! This module provides types and interfaces for non-interactive mode.
+//!
+//! # Squash Mode
+//!
+//! Release branches may require one commit per PR. In squash mode the
+//! commits picked for a PR, including those made while resolving its
+//! conflicts, are squashed into one commit whose message is rendered from a
+//! template:
+//!
+//! ```toml
+//! squash = true
+//! squash_message = "Merged PR {id}: {title} (rwi:#{task_ids})"
+//! ```
 
+use std::collections::HashSet;
+use std::fmt;
 use std::path::Path;
+use std::str::FromStr;
 
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 
+use crate::core::operations::PRDependencyGraph;
 use crate::git::{self, CherryPickResult};
 use crate::models::CherryPickStatus;
 
@@ -87,6 +104,74 @@ pub enum CherryPickProgress {
     },
 }
 
+/// Order in which the selected PRs are cherry-picked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "kebab-case")]
+pub enum CherryPickOrder {
+    /// Oldest closed PR first.
+    #[default]
+    ClosedDate,
+    /// Dependencies before the PRs that build on them, independent PRs by
+    /// closed date.
+    Topological,
+}
+
+impl FromStr for CherryPickOrder {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "closed-date" => Ok(CherryPickOrder::ClosedDate),
+            "topological" => Ok(CherryPickOrder::Topological),
+            other => anyhow::bail!(
+                "Invalid cherry-pick order '{}' (expected closed-date or topological)",
+                other
+            ),
+        }
+    }
+}
+
+impl fmt::Display for CherryPickOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CherryPickOrder::ClosedDate => write!(f, "closed-date"),
+            CherryPickOrder::Topological => write!(f, "topological"),
+        }
+    }
+}
+
+/// The `[cherry_pick]` table of the config file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CherryPickSettings {
+    /// Order in which the selected PRs are cherry-picked.
+    pub order: Option<CherryPickOrder>,
+}
+
+/// Default commit message template of squashed PRs.
+pub const DEFAULT_SQUASH_MESSAGE: &str = "Merged PR {id}: {title} (rwi:#{task_ids})";
+
+/// Renders the commit message of a squashed PR.
+///
+/// The template supports `{id}` (the PR number), `{title}` and
+/// `{task_ids}` (the work item ids joined by `,#`, e.g. `123,#456`).
+pub fn render_squash_message(
+    template: &str,
+    pr_id: i32,
+    pr_title: &str,
+    work_item_ids: &[i32],
+) -> String {
+    let task_ids = work_item_ids
+        .iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",#");
+    template
+        .replace("{id}", &pr_id.to_string())
+        .replace("{title}", pr_title)
+        .replace("{task_ids}", &task_ids)
+}
+
 /// Configuration for cherry-pick operations.
 #[derive(Debug, Clone)]
 pub struct CherryPickConfig {
@@ -94,6 +179,16 @@ pub struct CherryPickConfig {
     pub run_hooks: bool,
     /// Whether this is a worktree (vs a clone).
     pub is_worktree: bool,
+    /// Order in which the selected PRs are cherry-picked.
+    pub order: CherryPickOrder,
+    /// Whether picked commits are signed (`sign_commits`).
+    pub sign_commits: bool,
+    /// Commit message template PRs are squashed with (`None` when squash
+    /// mode is off).
+    pub squash_message: Option<String>,
+    /// Whether a merge commit whose first parent conflicts is retried with
+    /// its second parent as mainline (`mainline_fallback`).
+    pub mainline_fallback: bool,
 }
 
 impl Default for CherryPickConfig {
@@ -101,6 +196,10 @@ impl Default for CherryPickConfig {
         Self {
             run_hooks: false,
             is_worktree: true,
+            order: CherryPickOrder::default(),
+            sign_commits: false,
+            squash_message: None,
+            mainline_fallback: false,
         }
     }
 }
@@ -181,8 +280,57 @@ impl CherryPickOperation {
         self.config.run_hooks
     }
 
+    /// Orders PRs for cherry-picking.
+    ///
+    /// `by_date` lists the PR ids oldest closed first, the
+    /// [`CherryPickOrder::ClosedDate`] order. With
+    /// [`CherryPickOrder::Topological`], PRs that depend on or are depended
+    /// on by another PR of `by_date` are reordered among themselves to
+    /// follow the graph's topological order, so dependencies are picked
+    /// first. Independent PRs, and PRs the graph does not order (e.g. in a
+    /// dependency cycle), keep their closed-date position.
+    pub fn order(&self, by_date: &[i32], graph: Option<&PRDependencyGraph>) -> Vec<i32> {
+        let Some(graph) = graph.filter(|_| self.config.order == CherryPickOrder::Topological)
+        else {
+            return by_date.to_vec();
+        };
+        let candidates: HashSet<i32> = by_date.iter().copied().collect();
+        let related = |pr_id: &i32| {
+            graph.get_node(*pr_id).is_some_and(|node| {
+                node.dependencies
+                    .iter()
+                    .any(|dep| !dep.category.is_independent() && candidates.contains(&dep.to_pr_id))
+                    || node.dependents.iter().any(|id| candidates.contains(id))
+            })
+        };
+        let topological: Vec<i32> = graph
+            .topological_order
+            .iter()
+            .copied()
+            .filter(|id| candidates.contains(id) && related(id))
+            .collect();
+        let placed: HashSet<i32> = topological.iter().copied().collect();
+
+        // Related PRs take over each other's slots in topological order
+        let mut topological = topological.into_iter();
+        by_date
+            .iter()
+            .map(|&id| {
+                if placed.contains(&id) {
+                    topological.next().unwrap_or(id)
+                } else {
+                    id
+                }
+            })
+            .collect()
+    }
+
     /// Cherry-picks a single commit using the git module.
     ///
+    /// With `mainline_fallback`, merge commits that do not apply with their
+    /// first parent as mainline are retried with the second (see
+    /// [`git::cherry_pick_with_parent_fallback`]).
+    ///
     /// # Arguments
     ///
     /// * `repo_path` - Path to the repository
@@ -190,13 +338,49 @@ impl CherryPickOperation {
     ///
     /// # Returns
     ///
-    /// The outcome of the cherry-pick operation.
+    /// The outcome of the cherry-pick operation and the mainline parent it
+    /// used.
     ///
     /// Note: The `run_hooks` config option is currently not implemented.
     /// Git hooks run based on the repository's configuration.
-    pub fn cherry_pick_commit(&self, repo_path: &Path, commit_id: &str) -> CherryPickOutcome {
-        match crate::git::cherry_pick_commit(repo_path, commit_id) {
-            Ok(cp_result) => cp_result.into(),
+    pub fn cherry_pick_commit(&self, repo_path: &Path, commit_id: &str) -> (CherryPickOutcome, u8) {
+        match git::cherry_pick_with_parent_fallback(
+            repo_path,
+            commit_id,
+            self.config.sign_commits,
+            self.config.mainline_fallback,
+        ) {
+            Ok((cp_result, mainline)) => (cp_result.into(), mainline),
+            Err(e) => (
+                CherryPickOutcome::Failed {
+                    message: e.to_string(),
+                },
+                1,
+            ),
+        }
+    }
+
+    /// Squashes the commits picked for a PR on top of `base` into one commit
+    /// with the rendered squash message.
+    ///
+    /// Returns [`CherryPickOutcome::Success`] right away when squash mode is
+    /// off. A squash that fails, e.g. because the commit cannot be signed,
+    /// resets the branch to `base`, so the PR is reported as failed rather
+    /// than landing as several commits.
+    pub fn squash_pr(
+        &self,
+        repo_path: &Path,
+        base: &str,
+        pr_id: i32,
+        pr_title: &str,
+        work_item_ids: &[i32],
+    ) -> CherryPickOutcome {
+        let Some(template) = &self.config.squash_message else {
+            return CherryPickOutcome::Success;
+        };
+        let message = render_squash_message(template, pr_id, pr_title, work_item_ids);
+        match git::squash_commits(repo_path, base, &message, self.config.sign_commits) {
+            Ok(()) => CherryPickOutcome::Success,
             Err(e) => CherryPickOutcome::Failed {
                 message: e.to_string(),
             },
@@ -261,6 +445,27 @@ pub fn get_conflicted_files(repo_path: &Path) -> Result<Vec<String>> {
 mod tests {
     use super::*;
 
+    /// # Render Squash Message
+    ///
+    /// Verifies the placeholders of the squash commit message template.
+    ///
+    /// ## Test Scenario
+    /// - Renders the default template with two work items and with none
+    ///
+    /// ## Expected Outcome
+    /// - `{id}` and `{title}` become the PR's; `{task_ids}` lists the work items
+    #[test]
+    fn test_render_squash_message() {
+        assert_eq!(
+            render_squash_message(DEFAULT_SQUASH_MESSAGE, 42, "Fix login", &[100, 101]),
+            "Merged PR 42: Fix login (rwi:#100,#101)"
+        );
+        assert_eq!(
+            render_squash_message("PR {id} {task_ids}", 7, "Title", &[]),
+            "PR 7 "
+        );
+    }
+
     /// # Cherry Pick Outcome From Result
     ///
     /// Verifies conversion from CherryPickResult to CherryPickOutcome.
@@ -327,6 +532,61 @@ mod tests {
         let config = CherryPickConfig::default();
         assert!(!config.run_hooks);
         assert!(config.is_worktree);
+        assert_eq!(config.order, CherryPickOrder::ClosedDate);
+    }
+
+    /// # Topological Cherry-Pick Order
+    ///
+    /// Verifies ordering PRs by their dependencies.
+    ///
+    /// ## Test Scenario
+    /// - Five candidate PRs; 4 closed first but depends on 2, 3 depends on 6,
+    ///   which is not a candidate, 1 and 5 are independent
+    ///
+    /// ## Expected Outcome
+    /// - Closed-date order ignores the graph
+    /// - Topological order swaps the related PRs into dependency order
+    /// - Independent PRs and PRs only related to non-candidates keep their slot
+    #[test]
+    fn test_cherry_pick_order() {
+        use crate::core::operations::{DependencyCategory, PRDependency, PRDependencyNode};
+
+        let mut graph = PRDependencyGraph::new();
+        for id in 1..=6 {
+            graph.add_node(PRDependencyNode::new(id, format!("PR {}", id), id != 6));
+        }
+        for (from, to) in [(4, 2), (3, 6)] {
+            graph
+                .get_node_mut(from)
+                .unwrap()
+                .dependencies
+                .push(PRDependency {
+                    from_pr_id: from,
+                    to_pr_id: to,
+                    category: DependencyCategory::PartiallyDependent {
+                        shared_files: vec!["src/lib.rs".to_string()],
+                    },
+                });
+            graph.get_node_mut(to).unwrap().dependents.push(from);
+        }
+        graph.topological_order = vec![6, 5, 3, 2, 1, 4];
+        let by_date = [4, 1, 2, 3, 5];
+
+        let closed_date = CherryPickOperation::new(CherryPickConfig::default());
+        assert_eq!(closed_date.order(&by_date, Some(&graph)), by_date);
+
+        let topological = CherryPickOperation::new(CherryPickConfig {
+            order: CherryPickOrder::Topological,
+            ..Default::default()
+        });
+        assert_eq!(topological.order(&by_date, Some(&graph)), [2, 1, 4, 3, 5]);
+        assert_eq!(topological.order(&by_date, None), by_date);
+
+        assert_eq!(
+            "Topological".parse::<CherryPickOrder>().unwrap(),
+            CherryPickOrder::Topological
+        );
+        assert!("random".parse::<CherryPickOrder>().is_err());
     }
 
     /// # Cherry Pick Item Creation
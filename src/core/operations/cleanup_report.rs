@@ -0,0 +1,304 @@
+//! Report of a cleanup run.
+//!
+//! Lists which patch branches were deleted, kept or failed to delete and why.
+//! Deleted branches carry the commit they pointed to, so an accidental
+//! deletion can be undone with `git branch <name> <sha>`. The report is
+//! exported from the cleanup results screen as JSON and markdown.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::core::operations::{CleanupOutcome, CleanupReport};
+//! use mergers::models::{CleanupBranch, CleanupStatus};
+//!
+//! let branch = CleanupBranch {
+//!     name: "patch/main-1.2.0".to_string(),
+//!     target: "main".to_string(),
+//!     version: "1.2.0".to_string(),
+//!     is_merged: true,
+//!     is_merged_remote: Some(true),
+//!     selected: true,
+//!     status: CleanupStatus::Success,
+//!     tip: Some("a1b2c3d".to_string()),
+//! };
+//!
+//! let report = CleanupReport::new("main", &[branch]);
+//! assert_eq!(
+//!     report.branches[0].outcome,
+//!     CleanupOutcome::Deleted { tip: Some("a1b2c3d".to_string()) }
+//! );
+//! assert!(report.to_markdown().contains("git branch patch/main-1.2.0 a1b2c3d"));
+//! ```
+
+use crate::models::{CleanupBranch, CleanupStatus};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// What happened to a patch branch during cleanup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum CleanupOutcome {
+    /// The branch was deleted; `tip` is the commit it pointed to.
+    Deleted { tip: Option<String> },
+    /// The branch was not deleted.
+    Kept { reason: String },
+    /// Deleting the branch failed.
+    Failed { reason: String },
+}
+
+/// A patch branch and its cleanup outcome.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CleanupReportEntry {
+    pub name: String,
+    pub target: String,
+    pub version: String,
+    #[serde(flatten)]
+    pub outcome: CleanupOutcome,
+}
+
+/// Outcome of every patch branch considered by a cleanup run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CleanupReport {
+    /// Target branch the cleanup ran against.
+    pub target: String,
+    /// Branches in the order they were listed.
+    pub branches: Vec<CleanupReportEntry>,
+}
+
+impl CleanupReport {
+    /// Builds the report from the branches of a finished cleanup.
+    pub fn new(target: &str, branches: &[CleanupBranch]) -> Self {
+        let branches = branches
+            .iter()
+            .map(|branch| CleanupReportEntry {
+                name: branch.name.clone(),
+                target: branch.target.clone(),
+                version: branch.version.clone(),
+                outcome: outcome(branch),
+            })
+            .collect();
+        Self {
+            target: target.to_string(),
+            branches,
+        }
+    }
+
+    fn entries<'a>(
+        &'a self,
+        filter: impl Fn(&CleanupOutcome) -> bool + 'a,
+    ) -> impl Iterator<Item = &'a CleanupReportEntry> + 'a {
+        self.branches
+            .iter()
+            .filter(move |entry| filter(&entry.outcome))
+    }
+
+    /// Serializes the report as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).context("Failed to serialize cleanup report")
+    }
+
+    /// Renders the report as markdown, with a restore command per deleted branch.
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# Cleanup Report: {}\n", self.target);
+
+        let deleted: Vec<_> = self
+            .entries(|o| matches!(o, CleanupOutcome::Deleted { .. }))
+            .collect();
+        out.push_str(&format!("\n## Deleted ({})\n\n", deleted.len()));
+        if deleted.is_empty() {
+            out.push_str("No branches were deleted.\n");
+        } else {
+            out.push_str("| Branch | Version | Tip | Restore |\n");
+            out.push_str("|--------|---------|-----|---------|\n");
+            for entry in deleted {
+                let tip = match &entry.outcome {
+                    CleanupOutcome::Deleted { tip: Some(tip) } => tip.as_str(),
+                    _ => "",
+                };
+                let restore = if tip.is_empty() {
+                    "unknown".to_string()
+                } else {
+                    format!("`git branch {} {}`", entry.name, tip)
+                };
+                out.push_str(&format!(
+                    "| `{}` | {} | `{}` | {} |\n",
+                    entry.name, entry.version, tip, restore
+                ));
+            }
+        }
+
+        for (heading, failed) in [("Kept", false), ("Failed", true)] {
+            let entries: Vec<_> = self
+                .entries(move |o| match o {
+                    CleanupOutcome::Kept { .. } => !failed,
+                    CleanupOutcome::Failed { .. } => failed,
+                    CleanupOutcome::Deleted { .. } => false,
+                })
+                .collect();
+            out.push_str(&format!("\n## {} ({})\n\n", heading, entries.len()));
+            if entries.is_empty() {
+                out.push_str("None.\n");
+                continue;
+            }
+            out.push_str("| Branch | Version | Reason |\n");
+            out.push_str("|--------|---------|--------|\n");
+            for entry in entries {
+                let reason = match &entry.outcome {
+                    CleanupOutcome::Kept { reason } | CleanupOutcome::Failed { reason } => {
+                        reason.as_str()
+                    }
+                    CleanupOutcome::Deleted { .. } => "",
+                };
+                out.push_str(&format!(
+                    "| `{}` | {} | {} |\n",
+                    entry.name,
+                    entry.version,
+                    reason.replace('|', "\\|")
+                ));
+            }
+        }
+
+        out
+    }
+
+    /// Writes `cleanup-report-<target>.json` and `.md` into `dir`.
+    ///
+    /// Returns the paths of the JSON and markdown files.
+    pub fn write_to(&self, dir: &Path) -> Result<(PathBuf, PathBuf)> {
+        let stem = format!(
+            "cleanup-report-{}",
+            self.target.replace(['/', '\\', ' '], "_")
+        );
+        let json_path = dir.join(format!("{}.json", stem));
+        let markdown_path = dir.join(format!("{}.md", stem));
+
+        std::fs::write(&json_path, self.to_json()?)
+            .with_context(|| format!("Failed to write {}", json_path.display()))?;
+        std::fs::write(&markdown_path, self.to_markdown())
+            .with_context(|| format!("Failed to write {}", markdown_path.display()))?;
+
+        Ok((json_path, markdown_path))
+    }
+}
+
+fn outcome(branch: &CleanupBranch) -> CleanupOutcome {
+    if !branch.selected {
+        let reason = if !branch.is_merged {
+            format!("Not merged into {}", branch.target)
+        } else if branch.is_merged_remote == Some(false) {
+            format!("Not merged into origin/{}", branch.target)
+        } else {
+            "Not selected".to_string()
+        };
+        return CleanupOutcome::Kept { reason };
+    }
+
+    match &branch.status {
+        CleanupStatus::Success => CleanupOutcome::Deleted {
+            tip: branch.tip.clone(),
+        },
+        CleanupStatus::Failed(reason) => CleanupOutcome::Failed {
+            reason: reason.clone(),
+        },
+        CleanupStatus::Pending | CleanupStatus::InProgress => CleanupOutcome::Kept {
+            reason: "Cleanup did not finish".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn branch(
+        version: &str,
+        is_merged: bool,
+        selected: bool,
+        status: CleanupStatus,
+        tip: Option<&str>,
+    ) -> CleanupBranch {
+        CleanupBranch {
+            name: format!("patch/main-{}", version),
+            target: "main".to_string(),
+            version: version.to_string(),
+            is_merged,
+            is_merged_remote: Some(is_merged),
+            selected,
+            status,
+            tip: tip.map(str::to_string),
+        }
+    }
+
+    /// # Cleanup Report
+    ///
+    /// Tests building and exporting the report of a cleanup run.
+    ///
+    /// ## Test Scenario
+    /// - Builds a report from a deleted, a failed, an unmerged and an
+    ///   unselected merged branch
+    /// - Renders it as markdown and JSON and writes both files
+    ///
+    /// ## Expected Outcome
+    /// - Each branch gets its outcome and reason
+    /// - The markdown lists a restore command for the deleted branch
+    /// - The JSON carries the outcome, tip and reason of every branch
+    #[test]
+    fn test_cleanup_report() {
+        let report = CleanupReport::new(
+            "main",
+            &[
+                branch("1.0.0", true, true, CleanupStatus::Success, Some("a1b2c3d")),
+                branch(
+                    "1.1.0",
+                    true,
+                    true,
+                    CleanupStatus::Failed("branch is checked out".to_string()),
+                    None,
+                ),
+                branch("1.2.0", false, false, CleanupStatus::Pending, None),
+                branch("1.3.0", true, false, CleanupStatus::Pending, None),
+            ],
+        );
+
+        let outcomes: Vec<_> = report.branches.iter().map(|e| e.outcome.clone()).collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                CleanupOutcome::Deleted {
+                    tip: Some("a1b2c3d".to_string())
+                },
+                CleanupOutcome::Failed {
+                    reason: "branch is checked out".to_string()
+                },
+                CleanupOutcome::Kept {
+                    reason: "Not merged into main".to_string()
+                },
+                CleanupOutcome::Kept {
+                    reason: "Not selected".to_string()
+                },
+            ]
+        );
+
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("## Deleted (1)"));
+        assert!(markdown.contains("`git branch patch/main-1.0.0 a1b2c3d`"));
+        assert!(markdown.contains("## Kept (2)"));
+        assert!(markdown.contains("| `patch/main-1.2.0` | 1.2.0 | Not merged into main |"));
+        assert!(markdown.contains("## Failed (1)"));
+        assert!(markdown.contains("branch is checked out"));
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(json["target"], "main");
+        assert_eq!(json["branches"][0]["outcome"], "deleted");
+        assert_eq!(json["branches"][0]["tip"], "a1b2c3d");
+        assert_eq!(json["branches"][1]["outcome"], "failed");
+        assert_eq!(json["branches"][2]["reason"], "Not merged into main");
+
+        let temp_dir = TempDir::new().unwrap();
+        let (json_path, markdown_path) = report.write_to(temp_dir.path()).unwrap();
+        assert!(json_path.ends_with("cleanup-report-main.json"));
+        assert_eq!(std::fs::read_to_string(markdown_path).unwrap(), markdown);
+    }
+}
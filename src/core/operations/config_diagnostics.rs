@@ -0,0 +1,241 @@
+//! Checks for suspicious configuration values.
+//!
+//! Some settings are valid on their own but make a later operation fail or
+//! do something unintended: a local repository that does not exist, a dev
+//! branch equal to the target branch, or an empty tag prefix. The settings
+//! confirmation screen shows these diagnostics next to the setting, with the
+//! place the value came from, before anything runs.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::core::operations::{ConfigSetting, diagnose_shared_config};
+//! use mergers::parsed_property::ParsedProperty;
+//! # use mergers::models::SharedConfig;
+//! # fn config(shared: SharedConfig) {
+//! let mut shared = shared;
+//! shared.tag_prefix = ParsedProperty::Default(String::new());
+//! let diagnostics = diagnose_shared_config(&shared, true);
+//! assert!(diagnostics.iter().any(|d| d.setting == ConfigSetting::TagPrefix));
+//! # }
+//! ```
+
+use std::path::Path;
+
+use crate::models::{AppConfig, SharedConfig};
+use crate::parsed_property::ParsedProperty;
+
+/// A setting a diagnostic is reported for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigSetting {
+    TargetBranch,
+    LocalRepo,
+    TagPrefix,
+}
+
+impl ConfigSetting {
+    /// CLI flag, environment variable and config file key of the setting.
+    fn names(self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            ConfigSetting::TargetBranch => {
+                ("--target-branch", "MERGERS_TARGET_BRANCH", "target_branch")
+            }
+            ConfigSetting::LocalRepo => ("--local-repo", "MERGERS_LOCAL_REPO", "local_repo"),
+            ConfigSetting::TagPrefix => ("--tag-prefix", "MERGERS_TAG_PREFIX", "tag_prefix"),
+        }
+    }
+
+    /// Where to change the setting, given the source of its current value.
+    pub fn location<T>(self, property: &ParsedProperty<T>) -> String {
+        let (flag, env, key) = self.names();
+        match property {
+            ParsedProperty::Cli(..) => format!("`{}`", flag),
+            ParsedProperty::Env(..) => format!("`{}`", env),
+            ParsedProperty::File(_, path, _) => format!("`{}` in {}", key, path.display()),
+            ParsedProperty::Git(..) | ParsedProperty::Default(..) => {
+                format!("`{}` or `{}`", flag, key)
+            }
+        }
+    }
+}
+
+/// A suspicious setting and how to fix it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigDiagnostic {
+    /// Setting the diagnostic is about.
+    pub setting: ConfigSetting,
+    /// What is wrong with the value.
+    pub message: String,
+    /// How to fix it.
+    pub remediation: String,
+}
+
+/// Checks the settings of a resolved configuration.
+///
+/// Branch checks only apply to modes that move commits between the dev and
+/// target branch.
+pub fn diagnose_config(config: &AppConfig) -> Vec<ConfigDiagnostic> {
+    let uses_both_branches = matches!(
+        config,
+        AppConfig::Default { .. } | AppConfig::Migration { .. } | AppConfig::Analyze { .. }
+    );
+    diagnose_shared_config(config.shared(), uses_both_branches)
+}
+
+/// Checks the settings shared by all modes.
+pub fn diagnose_shared_config(
+    shared: &SharedConfig,
+    uses_both_branches: bool,
+) -> Vec<ConfigDiagnostic> {
+    let mut diagnostics = Vec::new();
+
+    if uses_both_branches && shared.dev_branch.value().trim() == shared.target_branch.value().trim()
+    {
+        diagnostics.push(ConfigDiagnostic {
+            setting: ConfigSetting::TargetBranch,
+            message: format!(
+                "Same as the dev branch '{}'; PRs would be cherry-picked onto the branch they were merged into",
+                shared.dev_branch.value()
+            ),
+            remediation: format!(
+                "Set {} to the release branch",
+                ConfigSetting::TargetBranch.location(&shared.target_branch)
+            ),
+        });
+    }
+
+    if let Some(local_repo) = &shared.local_repo {
+        let path = Path::new(local_repo.value());
+        let message = if !path.exists() {
+            Some("Path does not exist".to_string())
+        } else if !path.is_dir() {
+            Some("Path is not a directory".to_string())
+        } else if !path.ancestors().any(|dir| dir.join(".git").exists()) {
+            Some("Path is not inside a git repository".to_string())
+        } else {
+            None
+        };
+        if let Some(message) = message {
+            diagnostics.push(ConfigDiagnostic {
+                setting: ConfigSetting::LocalRepo,
+                message,
+                remediation: format!(
+                    "Fix {} or remove it to clone the repository instead",
+                    ConfigSetting::LocalRepo.location(local_repo)
+                ),
+            });
+        }
+    }
+
+    if shared.tag_prefix.value().trim().is_empty() {
+        diagnostics.push(ConfigDiagnostic {
+            setting: ConfigSetting::TagPrefix,
+            message: "Empty; PRs would be labeled with the bare version".to_string(),
+            remediation: format!(
+                "Set {} (default `merged-`)",
+                ConfigSetting::TagPrefix.location(&shared.tag_prefix)
+            ),
+        });
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn shared(
+        dev: &str,
+        target: &str,
+        local_repo: Option<&Path>,
+        tag_prefix: &str,
+    ) -> SharedConfig {
+        SharedConfig {
+            organization: ParsedProperty::Default("org".to_string()),
+            project: ParsedProperty::Default("project".to_string()),
+            repository: ParsedProperty::Default("repo".to_string()),
+            pat: ParsedProperty::Default("pat".to_string()),
+            write_pat: None,
+            dev_branch: ParsedProperty::Default(dev.to_string()),
+            target_branch: ParsedProperty::Cli(target.to_string(), target.to_string()),
+            local_repo: local_repo.map(|path| {
+                ParsedProperty::File(
+                    path.display().to_string(),
+                    PathBuf::from("/home/dev/.config/mergers/config.toml"),
+                    path.display().to_string(),
+                )
+            }),
+            parallel_limit: ParsedProperty::Default(4),
+            max_concurrent_network: ParsedProperty::Default(10),
+            max_concurrent_processing: ParsedProperty::Default(5),
+            tag_prefix: ParsedProperty::Env(tag_prefix.to_string(), tag_prefix.to_string()),
+            since: None,
+            since_field: Default::default(),
+            skip_confirmation: false,
+            api_version: None,
+            history_depth: None,
+            url_templates: Default::default(),
+            release_notes_locale: Default::default(),
+        }
+    }
+
+    /// # Config Diagnostics
+    ///
+    /// Tests the checks for suspicious settings.
+    ///
+    /// ## Test Scenario
+    /// - Checks a config with equal branches, a missing local repo and an
+    ///   empty tag prefix
+    /// - Checks a directory that is not a git repository, and a valid config
+    ///
+    /// ## Expected Outcome
+    /// - Each problem is reported for its setting, pointing to where the
+    ///   value was set
+    /// - Branches are not compared for modes that use only one of them
+    /// - A valid config has no diagnostics
+    #[test]
+    fn test_diagnose_shared_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let missing = temp_dir.path().join("missing");
+
+        let diagnostics = diagnose_shared_config(&shared("dev", "dev", Some(&missing), " "), true);
+        let settings: Vec<_> = diagnostics.iter().map(|d| d.setting).collect();
+        assert_eq!(
+            settings,
+            vec![
+                ConfigSetting::TargetBranch,
+                ConfigSetting::LocalRepo,
+                ConfigSetting::TagPrefix
+            ]
+        );
+        assert!(diagnostics[0].remediation.contains("`--target-branch`"));
+        assert_eq!(diagnostics[1].message, "Path does not exist");
+        assert!(
+            diagnostics[1]
+                .remediation
+                .contains("`local_repo` in /home/dev/.config/mergers/config.toml")
+        );
+        assert!(diagnostics[2].remediation.contains("`MERGERS_TAG_PREFIX`"));
+
+        let diagnostics = diagnose_shared_config(
+            &shared("dev", "dev", Some(temp_dir.path()), "merged-"),
+            false,
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "Path is not inside a git repository"
+        );
+
+        std::fs::create_dir(temp_dir.path().join(".git")).unwrap();
+        let nested = temp_dir.path().join("src");
+        std::fs::create_dir(&nested).unwrap();
+        assert!(
+            diagnose_shared_config(&shared("dev", "next", Some(&nested), "merged-"), true)
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,244 @@
+//! Three-way view of conflicted text files.
+//!
+//! A conflicted file is merged again from its index stages with diff3-style
+//! markers (see [`git::conflict_diff3`]) and split into hunks holding the
+//! target branch's lines (`ours`), the common ancestor's (`base`) and the
+//! picked commit's (`theirs`), with a few lines of context around each.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::core::operations::conflict_hunks::parse_diff3;
+//!
+//! let merged = "\
+//! fn main() {
+//! <<<<<<< ours
+//!     run(1);
+//! ||||||| base
+//!     run(0);
+//! =======
+//!     run(2);
+//! >>>>>>> theirs
+//! }
+//! ";
+//! let hunks = parse_diff3(merged);
+//! assert_eq!(hunks.len(), 1);
+//! assert_eq!(hunks[0].ours_line, 2);
+//! assert_eq!(hunks[0].base, vec!["    run(0);"]);
+//! assert_eq!(hunks[0].after, vec!["}"]);
+//! ```
+
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::git;
+
+/// Lines of context kept before and after each hunk.
+pub const CONTEXT_LINES: usize = 3;
+
+/// One conflicting region of a file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConflictHunk {
+    /// Line of the target branch's version where the hunk starts (1-based).
+    pub ours_line: usize,
+    /// Unchanged lines before the hunk.
+    pub before: Vec<String>,
+    /// The target branch's lines.
+    pub ours: Vec<String>,
+    /// The common ancestor's lines.
+    pub base: Vec<String>,
+    /// The picked commit's lines.
+    pub theirs: Vec<String>,
+    /// Unchanged lines after the hunk.
+    pub after: Vec<String>,
+}
+
+/// Section of diff3 output being read.
+enum Section {
+    Context,
+    Ours,
+    Base,
+    Theirs,
+}
+
+/// Splits `git merge-file --diff3` output labeled `ours`, `base` and
+/// `theirs` into conflict hunks.
+pub fn parse_diff3(merged: &str) -> Vec<ConflictHunk> {
+    let mut hunks: Vec<ConflictHunk> = Vec::new();
+    let mut context: Vec<String> = Vec::new();
+    let mut current = ConflictHunk::default();
+    let mut section = Section::Context;
+    let mut ours_lines = 0;
+
+    for line in merged.lines() {
+        match section {
+            Section::Context if line == "<<<<<<< ours" => {
+                close_context(&mut hunks, &context);
+                let skip = context.len().saturating_sub(CONTEXT_LINES);
+                current = ConflictHunk {
+                    ours_line: ours_lines + 1,
+                    before: context.split_off(skip),
+                    ..ConflictHunk::default()
+                };
+                context.clear();
+                section = Section::Ours;
+            }
+            Section::Context => {
+                ours_lines += 1;
+                context.push(line.to_string());
+            }
+            Section::Ours if line == "||||||| base" => section = Section::Base,
+            Section::Ours => {
+                ours_lines += 1;
+                current.ours.push(line.to_string());
+            }
+            Section::Base if line == "=======" => section = Section::Theirs,
+            Section::Base => current.base.push(line.to_string()),
+            Section::Theirs if line == ">>>>>>> theirs" => {
+                hunks.push(std::mem::take(&mut current));
+                section = Section::Context;
+            }
+            Section::Theirs => current.theirs.push(line.to_string()),
+        }
+    }
+    close_context(&mut hunks, &context);
+    hunks
+}
+
+/// Gives the last hunk the context lines following it.
+fn close_context(hunks: &mut [ConflictHunk], context: &[String]) {
+    if let Some(previous) = hunks.last_mut() {
+        previous.after = context.iter().take(CONTEXT_LINES).cloned().collect();
+    }
+}
+
+/// Loads the conflict hunks of a conflicted text file.
+pub fn load_conflict_hunks(repo_path: &Path, file: &str) -> Result<Vec<ConflictHunk>> {
+    Ok(parse_diff3(&git::conflict_diff3(repo_path, file)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(repo: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    /// # Diff3 Parsing
+    ///
+    /// Tests splitting diff3 output into hunks.
+    ///
+    /// ## Test Scenario
+    /// - Parses two conflicts separated by more context than is kept, the
+    ///   second one with an empty base
+    ///
+    /// ## Expected Outcome
+    /// - Each hunk holds its three sides
+    /// - At most three context lines are kept on each side
+    /// - Hunk start lines count the target branch's lines
+    #[test]
+    fn test_parse_diff3() {
+        let merged = "\
+one
+two
+three
+four
+<<<<<<< ours
+ours 1
+ours 2
+||||||| base
+base 1
+=======
+theirs 1
+>>>>>>> theirs
+five
+six
+seven
+eight
+<<<<<<< ours
+added in ours
+||||||| base
+=======
+added in theirs
+>>>>>>> theirs
+";
+        let hunks = parse_diff3(merged);
+        assert_eq!(hunks.len(), 2);
+
+        assert_eq!(hunks[0].ours_line, 5);
+        assert_eq!(hunks[0].before, vec!["two", "three", "four"]);
+        assert_eq!(hunks[0].ours, vec!["ours 1", "ours 2"]);
+        assert_eq!(hunks[0].base, vec!["base 1"]);
+        assert_eq!(hunks[0].theirs, vec!["theirs 1"]);
+        assert_eq!(hunks[0].after, vec!["five", "six", "seven"]);
+
+        assert_eq!(hunks[1].ours_line, 11);
+        assert_eq!(hunks[1].before, vec!["six", "seven", "eight"]);
+        assert!(hunks[1].base.is_empty());
+        assert_eq!(hunks[1].theirs, vec!["added in theirs"]);
+        assert!(hunks[1].after.is_empty());
+
+        assert!(parse_diff3("no conflicts\n").is_empty());
+    }
+
+    /// # Loading Conflict Hunks
+    ///
+    /// Tests reading the hunks of a paused cherry-pick.
+    ///
+    /// ## Test Scenario
+    /// - Cherry-picks a commit changing two lines the target branch changed
+    ///   too, then edits the conflicted working tree file
+    ///
+    /// ## Expected Outcome
+    /// - Both conflicts are listed with the base's lines
+    /// - Edits to the working tree file do not change the hunks
+    #[test]
+    fn test_load_conflict_hunks() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        git(repo, &["init", "-q", "-b", "main"]);
+        git(repo, &["config", "user.name", "Test User"]);
+        git(repo, &["config", "user.email", "test@example.com"]);
+        git(repo, &["config", "commit.gpgsign", "false"]);
+
+        std::fs::write(repo.join("f.txt"), "a\nb\nc\nd\ne\n").unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-q", "-m", "Initial"]);
+        git(repo, &["checkout", "-q", "-b", "feature"]);
+        std::fs::write(repo.join("f.txt"), "a\nB2\nc\nd\nE2\n").unwrap();
+        git(repo, &["commit", "-q", "-am", "Feature"]);
+        git(repo, &["checkout", "-q", "main"]);
+        std::fs::write(repo.join("f.txt"), "a\nB1\nc\nd\nE1\n").unwrap();
+        git(repo, &["commit", "-q", "-am", "Main"]);
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(["cherry-pick", "feature"])
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "cherry-pick should conflict");
+        std::fs::write(repo.join("f.txt"), "edited\n").unwrap();
+
+        let hunks = load_conflict_hunks(repo, "f.txt").unwrap();
+        assert_eq!(hunks.len(), 2);
+        assert_eq!(hunks[0].ours_line, 2);
+        assert_eq!(hunks[0].ours, vec!["B1"]);
+        assert_eq!(hunks[0].base, vec!["b"]);
+        assert_eq!(hunks[0].theirs, vec!["B2"]);
+        assert_eq!(hunks[1].ours_line, 5);
+        assert_eq!(hunks[1].before, vec!["c", "d"]);
+    }
+}
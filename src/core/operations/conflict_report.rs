@@ -0,0 +1,435 @@
+//! Machine-readable report of a paused cherry-pick's conflicts.
+//!
+//! When a non-interactive merge pauses at a conflict, a JSON report is written
+//! to `conflicts-<hash>.json` next to the merge's state file. Like a SARIF
+//! result, each conflicted file is listed with line regions:
+//!
+//! - `regions`: the conflicting lines of the target branch's version
+//! - `changed_lines`: the lines the paused PR's commit changes
+//! - `conflicting_prs`: the PRs picked earlier in this merge that changed the
+//!   file, in pick order, with the lines they changed that the paused PR
+//!   changes too
+//!
+//! `resolution_order` lists the files to resolve first: files overlapping an
+//! earlier PR (whose changes explain the conflict) before the others, then
+//! the smallest conflicts first.
+//!
+//! The report is removed once the merge is past the conflict.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::conflict_hunks::{ConflictHunk, load_conflict_hunks};
+use super::dependency_analysis::{FileChange, LineRange};
+use crate::core::state::{
+    MergePhase, MergeStateFile, StateItemStatus, compute_repo_hash, state_dir,
+};
+use crate::git;
+
+/// Current report format.
+pub const CONFLICT_REPORT_VERSION: u32 = 1;
+
+/// Conflicts of the PR a merge is paused at.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConflictReport {
+    /// Report format version.
+    pub version: u32,
+    /// Version the merge creates.
+    pub merge_version: String,
+    /// Branch the PRs are picked onto.
+    pub target_branch: String,
+    /// PR whose cherry-pick conflicted.
+    pub pr_id: i32,
+    /// Title of the PR.
+    pub pr_title: String,
+    /// Commit being cherry-picked.
+    pub commit_id: String,
+    /// Conflicted files, in the order git reported them.
+    pub files: Vec<ConflictedFile>,
+    /// Conflicted file paths in the suggested order to resolve them.
+    pub resolution_order: Vec<String>,
+}
+
+/// A conflicted file of a [`ConflictReport`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConflictedFile {
+    /// Path relative to the repository root.
+    pub path: String,
+    /// Conflicting lines of the target branch's version. Empty for binary
+    /// files and files deleted on one side.
+    pub regions: Vec<LineRange>,
+    /// Lines the paused PR's commit changes.
+    pub changed_lines: Vec<LineRange>,
+    /// PRs picked earlier in the merge that changed the file.
+    pub conflicting_prs: Vec<ConflictingPr>,
+}
+
+impl ConflictedFile {
+    /// Returns whether an earlier PR changed lines this PR changes too.
+    pub fn overlaps_earlier_pr(&self) -> bool {
+        self.conflicting_prs
+            .iter()
+            .any(|pr| !pr.overlapping_lines.is_empty())
+    }
+
+    /// Returns the number of conflicting lines.
+    fn conflict_size(&self) -> u32 {
+        self.regions.iter().map(LineRange::len).sum()
+    }
+}
+
+/// An earlier PR of the merge that changed a conflicted file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConflictingPr {
+    pub pr_id: i32,
+    pub pr_title: String,
+    /// Commit of the PR on the dev branch.
+    pub commit_id: String,
+    /// Lines changed by both this PR and the paused one.
+    pub overlapping_lines: Vec<LineRange>,
+}
+
+impl ConflictReport {
+    /// Builds the report of a merge paused at a conflict in `repo_path`.
+    ///
+    /// Returns `None` if the merge is not paused. Files or commits git cannot
+    /// read are reported without line ranges.
+    pub fn collect(repo_path: &Path, state: &MergeStateFile) -> Option<Self> {
+        if state.phase != MergePhase::AwaitingConflictResolution {
+            return None;
+        }
+        let item = state.cherry_pick_items.get(state.current_index)?;
+        let files = state.conflicted_files.clone().unwrap_or_default();
+
+        let regions: HashMap<String, Vec<LineRange>> = files
+            .iter()
+            .map(|file| {
+                let hunks = load_conflict_hunks(repo_path, file).unwrap_or_default();
+                (file.clone(), hunks.iter().map(hunk_range).collect())
+            })
+            .collect();
+        let changes: HashMap<i32, Vec<FileChange>> = state.cherry_pick_items
+            [..=state.current_index]
+            .iter()
+            .filter(|other| other.pr_id == item.pr_id || other.status == StateItemStatus::Success)
+            .map(|other| {
+                let changes = git::get_commit_changes_with_ranges(repo_path, &other.commit_id)
+                    .unwrap_or_default();
+                (other.pr_id, changes)
+            })
+            .collect();
+
+        Some(Self::from_changes(state, &regions, &changes))
+    }
+
+    /// Builds the report from the conflict regions of each conflicted file
+    /// and the file changes of each PR picked so far, keyed by PR id.
+    pub fn from_changes(
+        state: &MergeStateFile,
+        regions: &HashMap<String, Vec<LineRange>>,
+        changes: &HashMap<i32, Vec<FileChange>>,
+    ) -> Self {
+        let item = &state.cherry_pick_items[state.current_index];
+        let change_of = |pr_id: i32, path: &str| {
+            changes.get(&pr_id).and_then(|changes| {
+                changes
+                    .iter()
+                    .find(|c| c.path == path || c.original_path.as_deref() == Some(path))
+            })
+        };
+
+        let files: Vec<ConflictedFile> = state
+            .conflicted_files
+            .iter()
+            .flatten()
+            .map(|path| {
+                let own = change_of(item.pr_id, path);
+                let conflicting_prs = state.cherry_pick_items[..state.current_index]
+                    .iter()
+                    .filter(|earlier| earlier.status == StateItemStatus::Success)
+                    .filter_map(|earlier| {
+                        let change = change_of(earlier.pr_id, path)?;
+                        Some(ConflictingPr {
+                            pr_id: earlier.pr_id,
+                            pr_title: earlier.pr_title.clone(),
+                            commit_id: earlier.commit_id.clone(),
+                            overlapping_lines: own
+                                .map(|own| own.get_overlapping_ranges(change))
+                                .unwrap_or_default(),
+                        })
+                    })
+                    .collect();
+                ConflictedFile {
+                    path: path.clone(),
+                    regions: regions.get(path).cloned().unwrap_or_default(),
+                    changed_lines: own.map(|c| c.line_ranges.clone()).unwrap_or_default(),
+                    conflicting_prs,
+                }
+            })
+            .collect();
+
+        let mut order: Vec<&ConflictedFile> = files.iter().collect();
+        order.sort_by(|a, b| {
+            b.overlaps_earlier_pr()
+                .cmp(&a.overlaps_earlier_pr())
+                .then(a.conflict_size().cmp(&b.conflict_size()))
+                .then(a.path.cmp(&b.path))
+        });
+        let resolution_order = order.iter().map(|file| file.path.clone()).collect();
+
+        Self {
+            version: CONFLICT_REPORT_VERSION,
+            merge_version: state.merge_version.clone(),
+            target_branch: state.target_branch.clone(),
+            pr_id: item.pr_id,
+            pr_title: item.pr_title.clone(),
+            commit_id: item.commit_id.clone(),
+            files,
+            resolution_order,
+        }
+    }
+
+    /// Returns the file a conflict report is written to: next to the state
+    /// file of the merge in `repo_path`.
+    pub fn path_for_repo(repo_path: &Path) -> Result<PathBuf> {
+        let hash = compute_repo_hash(repo_path)?;
+        Ok(state_dir()?.join(format!("conflicts-{}.json", hash)))
+    }
+
+    /// Writes the report as pretty-printed JSON.
+    pub fn write(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create state directory: {}", parent.display())
+            })?;
+        }
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize report")?;
+        std::fs::write(path, json)
+            .with_context(|| format!("Failed to write conflict report: {}", path.display()))
+    }
+
+    /// Reads the conflict report of the merge in `repo_path` and returns it
+    /// with its path, or `None` if there is no readable report.
+    pub fn load_for_repo(repo_path: &Path) -> Option<(Self, PathBuf)> {
+        let path = Self::path_for_repo(repo_path).ok()?;
+        let content = std::fs::read_to_string(&path).ok()?;
+        let report = serde_json::from_str(&content).ok()?;
+        Some((report, path))
+    }
+
+    /// Removes the conflict report of the merge in `repo_path`, if any.
+    pub fn remove_for_repo(repo_path: &Path) -> Result<()> {
+        let path = Self::path_for_repo(repo_path)?;
+        match std::fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e)
+                .with_context(|| format!("Failed to remove conflict report: {}", path.display())),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Lines of the target branch's version covered by a conflict hunk.
+///
+/// A hunk where the target branch has no lines is reported as the line it
+/// would start at.
+fn hunk_range(hunk: &ConflictHunk) -> LineRange {
+    let start = hunk.ours_line as u32;
+    LineRange::new(start, start + (hunk.ours.len() as u32).max(1) - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::operations::dependency_analysis::ChangeType;
+    use crate::core::state::StateCherryPickItem;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(repo: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    fn item(pr_id: i32, commit_id: &str, status: StateItemStatus) -> StateCherryPickItem {
+        StateCherryPickItem {
+            commit_id: commit_id.to_string(),
+            pr_id,
+            pr_title: format!("PR {}", pr_id),
+            status,
+            work_item_ids: Vec::new(),
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
+        }
+    }
+
+    fn paused_state(
+        repo_path: &Path,
+        items: Vec<StateCherryPickItem>,
+        files: &[&str],
+    ) -> MergeStateFile {
+        let mut state = MergeStateFile::new(
+            repo_path.to_path_buf(),
+            None,
+            false,
+            "org".to_string(),
+            "project".to_string(),
+            "repo".to_string(),
+            "dev".to_string(),
+            "main".to_string(),
+            "v1.0.0".to_string(),
+            "Done".to_string(),
+            "merged-".to_string(),
+            false,
+        );
+        state.current_index = items.len() - 1;
+        state.cherry_pick_items = items;
+        state.phase = MergePhase::AwaitingConflictResolution;
+        state.conflicted_files = Some(files.iter().map(|f| f.to_string()).collect());
+        state
+    }
+
+    /// # Report From Changes
+    ///
+    /// Tests building a report from known conflict regions and file changes.
+    ///
+    /// ## Test Scenario
+    /// - Pauses at PR 3 with two conflicted files; PR 1 was picked and
+    ///   changed overlapping lines of `b.rs`, PR 2 was skipped and changed
+    ///   `a.rs`
+    ///
+    /// ## Expected Outcome
+    /// - Only the picked PR is listed as conflicting, with the overlap
+    /// - `b.rs` is suggested first since its conflict overlaps PR 1
+    #[test]
+    fn test_report_from_changes() {
+        let state = paused_state(
+            Path::new("/repo"),
+            vec![
+                item(1, "c1", StateItemStatus::Success),
+                item(2, "c2", StateItemStatus::Skipped),
+                item(3, "c3", StateItemStatus::Conflict),
+            ],
+            &["a.rs", "b.rs"],
+        );
+        let regions = HashMap::from([
+            ("a.rs".to_string(), vec![LineRange::new(3, 4)]),
+            ("b.rs".to_string(), vec![LineRange::new(10, 20)]),
+        ]);
+        let modified = |path: &str, ranges: Vec<LineRange>| {
+            FileChange::with_ranges(path.to_string(), ChangeType::Modify, ranges)
+        };
+        let changes = HashMap::from([
+            (1, vec![modified("b.rs", vec![LineRange::new(12, 15)])]),
+            (2, vec![modified("a.rs", vec![LineRange::new(3, 3)])]),
+            (
+                3,
+                vec![
+                    modified("a.rs", vec![LineRange::new(3, 4)]),
+                    modified("b.rs", vec![LineRange::new(14, 18)]),
+                ],
+            ),
+        ]);
+
+        let report = ConflictReport::from_changes(&state, &regions, &changes);
+        assert_eq!(report.pr_id, 3);
+        assert_eq!(report.files.len(), 2);
+        assert!(report.files[0].conflicting_prs.is_empty());
+        assert_eq!(report.files[0].changed_lines, vec![LineRange::new(3, 4)]);
+        let conflicting = &report.files[1].conflicting_prs;
+        assert_eq!(conflicting.len(), 1);
+        assert_eq!(conflicting[0].pr_id, 1);
+        assert_eq!(
+            conflicting[0].overlapping_lines,
+            vec![LineRange::new(14, 15)]
+        );
+        assert_eq!(report.resolution_order, vec!["b.rs", "a.rs"]);
+    }
+
+    /// # Collecting a Report
+    ///
+    /// Tests building the report of a real paused cherry-pick.
+    ///
+    /// ## Test Scenario
+    /// - Picks a PR changing line 4 onto a target branch with a hotfix on
+    ///   line 2, then cherry-picks a PR rewriting lines 2 to 4
+    ///
+    /// ## Expected Outcome
+    /// - The conflict region covers the target's lines 2 to 4
+    /// - The earlier PR is listed with its overlapping line 4
+    /// - The JSON report round-trips through its file
+    #[test]
+    fn test_collect_report() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        git(repo, &["init", "-q", "-b", "main"]);
+        git(repo, &["config", "user.name", "Test User"]);
+        git(repo, &["config", "user.email", "test@example.com"]);
+        git(repo, &["config", "commit.gpgsign", "false"]);
+        std::fs::write(repo.join("f.txt"), "a\nb\nc\nd\ne\n").unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-q", "-m", "Initial"]);
+
+        git(repo, &["checkout", "-q", "-b", "dev"]);
+        std::fs::write(repo.join("f.txt"), "a\nb\nc\nD\ne\n").unwrap();
+        git(repo, &["commit", "-q", "-am", "First"]);
+        let first = git(repo, &["rev-parse", "HEAD"]);
+        std::fs::write(repo.join("f.txt"), "a\nB\nC\nDD\ne\n").unwrap();
+        git(repo, &["commit", "-q", "-am", "Second"]);
+        let second = git(repo, &["rev-parse", "HEAD"]);
+
+        git(repo, &["checkout", "-q", "main"]);
+        std::fs::write(repo.join("f.txt"), "a\nhotfix\nc\nd\ne\n").unwrap();
+        git(repo, &["commit", "-q", "-am", "Hotfix"]);
+        git(repo, &["cherry-pick", &first]);
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(["cherry-pick", &second])
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "cherry-pick should conflict");
+
+        let state = paused_state(
+            repo,
+            vec![
+                item(1, &first, StateItemStatus::Success),
+                item(2, &second, StateItemStatus::Conflict),
+            ],
+            &["f.txt"],
+        );
+        let report = ConflictReport::collect(repo, &state).unwrap();
+        let file = &report.files[0];
+        assert_eq!(file.regions, vec![LineRange::new(2, 4)]);
+        assert_eq!(file.changed_lines, vec![LineRange::new(2, 4)]);
+        assert_eq!(file.conflicting_prs[0].pr_id, 1);
+        assert_eq!(
+            file.conflicting_prs[0].overlapping_lines,
+            vec![LineRange::single(4)]
+        );
+
+        let path = repo.join("report").join("conflicts.json");
+        report.write(&path).unwrap();
+        let read: ConflictReport =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(read, report);
+
+        let mut resolved = state;
+        resolved.phase = MergePhase::CherryPicking;
+        assert!(ConflictReport::collect(repo, &resolved).is_none());
+    }
+}
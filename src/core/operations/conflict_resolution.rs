@@ -0,0 +1,251 @@
+//! Guided conflict resolution for `mergers merge resolve`.
+//!
+//! Opens each conflicted file of a paused cherry-pick in the user's editor
+//! (`$VISUAL`, then `$EDITOR`) or in `git mergetool`, checks that the
+//! conflict is gone, and stages the file.
+
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{Context, Result};
+
+use crate::git;
+
+/// Editor used when neither `$VISUAL` nor `$EDITOR` is set.
+#[cfg(unix)]
+pub const DEFAULT_EDITOR: &str = "vi";
+#[cfg(windows)]
+pub const DEFAULT_EDITOR: &str = "notepad";
+
+/// How conflicted files are opened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolveTool {
+    /// An editor command; the file path is appended as the last argument.
+    Editor(String),
+    /// `git mergetool`, with an optional tool name overriding `merge.tool`.
+    MergeTool(Option<String>),
+}
+
+impl ResolveTool {
+    /// Returns the editor from `$VISUAL` or `$EDITOR`, falling back to
+    /// [`DEFAULT_EDITOR`].
+    pub fn from_env() -> Self {
+        Self::editor_from(std::env::var("VISUAL").ok(), std::env::var("EDITOR").ok())
+    }
+
+    fn editor_from(visual: Option<String>, editor: Option<String>) -> Self {
+        let command = [visual, editor]
+            .into_iter()
+            .flatten()
+            .find(|value| !value.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_EDITOR.to_string());
+        ResolveTool::Editor(command)
+    }
+}
+
+/// Returns `true` if `content` still contains conflict markers.
+///
+/// A lone `=======` line is not a marker on its own, since it is also a
+/// Markdown heading underline.
+pub fn has_conflict_markers(content: &str) -> bool {
+    content
+        .lines()
+        .any(|line| line.starts_with("<<<<<<< ") || line.starts_with(">>>>>>> "))
+}
+
+/// Opens a conflicted file and stages it if the conflict was resolved.
+///
+/// Returns `false` when the file is still conflicted: conflict markers remain
+/// after editing, or the merge tool left it unmerged.
+pub fn resolve_file(repo_path: &Path, file: &str, tool: &ResolveTool) -> Result<bool> {
+    match tool {
+        ResolveTool::Editor(editor) => {
+            run_editor(editor, repo_path, file)?;
+            let path = repo_path.join(file);
+            // Deleted files and binary content cannot hold text markers
+            if let Ok(content) = std::fs::read_to_string(&path)
+                && has_conflict_markers(&content)
+            {
+                return Ok(false);
+            }
+            git::stage_file(repo_path, file)?;
+            Ok(true)
+        }
+        ResolveTool::MergeTool(name) => {
+            git::run_mergetool(repo_path, name.as_deref(), file)?;
+            Ok(!git::unresolved_files(repo_path)?.iter().any(|f| f == file))
+        }
+    }
+}
+
+/// Runs the editor command on `file` through the shell, so that editors
+/// configured with arguments (e.g. `code --wait`) work.
+fn run_editor(editor: &str, repo_path: &Path, file: &str) -> Result<()> {
+    // Use sh -c on Unix, cmd /C on Windows
+    #[cfg(unix)]
+    let (shell, shell_arg) = ("sh", "-c");
+
+    #[cfg(windows)]
+    let (shell, shell_arg) = ("cmd", "/C");
+
+    let status = Command::new(shell)
+        .arg(shell_arg)
+        .arg(format!("{} \"{}\"", editor, file))
+        .current_dir(repo_path)
+        .status()
+        .with_context(|| format!("Failed to start editor '{}'", editor))?;
+
+    if !status.success() {
+        anyhow::bail!("Editor '{}' exited with {}", editor, status);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(repo: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    /// Creates a repository with a cherry-pick paused on `conflict.txt`.
+    fn setup_conflicted_repo() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        git(repo, &["init", "-q", "-b", "main"]);
+        git(repo, &["config", "user.name", "Test User"]);
+        git(repo, &["config", "user.email", "test@example.com"]);
+        git(repo, &["config", "commit.gpgsign", "false"]);
+
+        std::fs::write(repo.join("conflict.txt"), "original\n").unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-q", "-m", "Initial"]);
+        git(repo, &["checkout", "-q", "-b", "feature"]);
+        std::fs::write(repo.join("conflict.txt"), "feature\n").unwrap();
+        git(repo, &["commit", "-q", "-am", "Feature"]);
+        git(repo, &["checkout", "-q", "main"]);
+        std::fs::write(repo.join("conflict.txt"), "main\n").unwrap();
+        git(repo, &["commit", "-q", "-am", "Main"]);
+
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(["cherry-pick", "feature"])
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "cherry-pick should conflict");
+        temp_dir
+    }
+
+    /// # Editor Selection
+    ///
+    /// Tests choosing the editor from `$VISUAL` and `$EDITOR`.
+    ///
+    /// ## Test Scenario
+    /// - Resolves the editor with different combinations of variables
+    ///
+    /// ## Expected Outcome
+    /// - `$VISUAL` wins over `$EDITOR`; blank values are ignored
+    /// - Without either, the default editor is used
+    #[test]
+    fn test_editor_selection() {
+        let editor = |visual: Option<&str>, editor: Option<&str>| {
+            ResolveTool::editor_from(visual.map(String::from), editor.map(String::from))
+        };
+
+        assert_eq!(
+            editor(Some("code --wait"), Some("vim")),
+            ResolveTool::Editor("code --wait".to_string())
+        );
+        assert_eq!(
+            editor(Some(" "), Some("vim")),
+            ResolveTool::Editor("vim".to_string())
+        );
+        assert_eq!(
+            editor(None, None),
+            ResolveTool::Editor(DEFAULT_EDITOR.to_string())
+        );
+    }
+
+    /// # Conflict Marker Detection
+    ///
+    /// Tests detecting leftover conflict markers.
+    ///
+    /// ## Test Scenario
+    /// - Checks content with full markers, resolved content, and lookalikes
+    ///
+    /// ## Expected Outcome
+    /// - Only line-leading `<<<<<<<` and `>>>>>>>` markers count as unresolved
+    #[test]
+    fn test_has_conflict_markers() {
+        assert!(has_conflict_markers(
+            "<<<<<<< HEAD\nmain\n=======\nfeature\n>>>>>>> abc123 (Feature)\n"
+        ));
+        assert!(!has_conflict_markers("main\nfeature\n"));
+        assert!(!has_conflict_markers("Title\n=======\n"));
+        assert!(!has_conflict_markers("// ======= section =======\n"));
+    }
+
+    /// # Resolve File With Editor
+    ///
+    /// Tests verifying and staging a file after the editor exits.
+    ///
+    /// ## Test Scenario
+    /// - Opens a conflicted file with an editor that changes nothing
+    /// - Opens it with an editor that removes the conflict markers
+    ///
+    /// ## Expected Outcome
+    /// - The untouched file is reported unresolved and stays unmerged
+    /// - The cleaned file is staged and no unresolved files remain
+    #[cfg(unix)]
+    #[test]
+    fn test_resolve_file_with_editor() {
+        let temp_dir = setup_conflicted_repo();
+        let repo = temp_dir.path();
+        assert_eq!(git::unresolved_files(repo).unwrap(), vec!["conflict.txt"]);
+
+        let noop = ResolveTool::Editor("true".to_string());
+        assert!(!resolve_file(repo, "conflict.txt", &noop).unwrap());
+        assert_eq!(git::unresolved_files(repo).unwrap(), vec!["conflict.txt"]);
+
+        let strip_markers = ResolveTool::Editor("sed -i.orig -e '/^[<=>]\\{7\\}/d'".to_string());
+        assert!(resolve_file(repo, "conflict.txt", &strip_markers).unwrap());
+        assert!(git::unresolved_files(repo).unwrap().is_empty());
+    }
+
+    /// # Editor Failure
+    ///
+    /// Tests that a failing editor is reported as an error.
+    ///
+    /// ## Test Scenario
+    /// - Opens a conflicted file with an editor that exits non-zero
+    ///
+    /// ## Expected Outcome
+    /// - Returns an error naming the editor
+    #[cfg(unix)]
+    #[test]
+    fn test_resolve_file_editor_failure() {
+        let temp_dir = setup_conflicted_repo();
+
+        let err = resolve_file(
+            temp_dir.path(),
+            "conflict.txt",
+            &ResolveTool::Editor("false".to_string()),
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("Editor 'false'"));
+    }
+}
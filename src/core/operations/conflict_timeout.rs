@@ -0,0 +1,264 @@
+//! Time-boxed conflict handling for unattended merges.
+//!
+//! Without a policy, a non-interactive merge stops at the first conflict and
+//! waits for `merge continue`. With one, the runner keeps the conflict open
+//! for a while so a human can resolve and stage the files in the worktree,
+//! then settles it on its own: skipping the PR, aborting the merge, or
+//! keeping one side of every conflicted file.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use mergers::core::operations::conflict_timeout::{
+//!     ConflictDecision, ConflictTimeout, ConflictTimeoutAction,
+//! };
+//!
+//! let policy = ConflictTimeout::new(Duration::from_secs(600), ConflictTimeoutAction::Skip);
+//! assert_eq!(policy.poll_interval(), Duration::from_secs(5));
+//! assert_eq!(ConflictDecision::from(policy.action), ConflictDecision::Skipped);
+//! ```
+
+use std::fmt;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::core::operations::binary_conflicts::ConflictSide;
+use crate::git;
+
+/// Longest pause between two checks for a human resolution.
+const MAX_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// What happens to a conflict nobody resolved in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictTimeoutAction {
+    /// Skip the conflicting PR and continue with the next one.
+    Skip,
+    /// Abort the whole merge.
+    Abort,
+    /// Keep the target branch's version of every conflicted file.
+    Ours,
+    /// Keep the picked commit's version of every conflicted file.
+    Theirs,
+}
+
+impl ConflictTimeoutAction {
+    /// Side kept by the resolution strategies.
+    pub fn side(self) -> Option<ConflictSide> {
+        match self {
+            ConflictTimeoutAction::Ours => Some(ConflictSide::Ours),
+            ConflictTimeoutAction::Theirs => Some(ConflictSide::Theirs),
+            ConflictTimeoutAction::Skip | ConflictTimeoutAction::Abort => None,
+        }
+    }
+}
+
+impl fmt::Display for ConflictTimeoutAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConflictTimeoutAction::Skip => write!(f, "skip"),
+            ConflictTimeoutAction::Abort => write!(f, "abort"),
+            ConflictTimeoutAction::Ours => write!(f, "ours"),
+            ConflictTimeoutAction::Theirs => write!(f, "theirs"),
+        }
+    }
+}
+
+/// How a conflict held open by the policy was settled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictDecision {
+    /// A human resolved and staged every file in time.
+    Resolved,
+    /// The PR was skipped.
+    Skipped,
+    /// The merge was aborted.
+    Aborted,
+    /// The target branch's version of every conflicted file was kept.
+    KeptOurs,
+    /// The picked commit's version of every conflicted file was kept.
+    KeptTheirs,
+}
+
+impl From<ConflictTimeoutAction> for ConflictDecision {
+    fn from(action: ConflictTimeoutAction) -> Self {
+        match action {
+            ConflictTimeoutAction::Skip => ConflictDecision::Skipped,
+            ConflictTimeoutAction::Abort => ConflictDecision::Aborted,
+            ConflictTimeoutAction::Ours => ConflictDecision::KeptOurs,
+            ConflictTimeoutAction::Theirs => ConflictDecision::KeptTheirs,
+        }
+    }
+}
+
+impl fmt::Display for ConflictDecision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConflictDecision::Resolved => write!(f, "resolved in time"),
+            ConflictDecision::Skipped => write!(f, "skipped"),
+            ConflictDecision::Aborted => write!(f, "aborted the merge"),
+            ConflictDecision::KeptOurs => write!(f, "kept ours"),
+            ConflictDecision::KeptTheirs => write!(f, "kept theirs"),
+        }
+    }
+}
+
+/// How long a conflict is held open and what happens afterwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConflictTimeout {
+    /// Time a human has to resolve the conflict.
+    pub wait: Duration,
+    /// Action taken once `wait` has passed.
+    pub action: ConflictTimeoutAction,
+}
+
+impl ConflictTimeout {
+    /// Creates a policy.
+    pub fn new(wait: Duration, action: ConflictTimeoutAction) -> Self {
+        Self { wait, action }
+    }
+
+    /// Pause between two checks of the worktree.
+    pub fn poll_interval(&self) -> Duration {
+        (self.wait / 10).clamp(Duration::from_millis(10), MAX_POLL_INTERVAL)
+    }
+
+    /// Waits until every conflict in `repo_path` is resolved and staged.
+    ///
+    /// Returns `false` if conflicts remain once the wait is over.
+    pub fn wait_for_resolution(&self, repo_path: &Path) -> bool {
+        let deadline = Instant::now() + self.wait;
+        loop {
+            if git::check_conflicts_resolved(repo_path).unwrap_or(false) {
+                return true;
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return false;
+            }
+            std::thread::sleep(self.poll_interval().min(deadline - now));
+        }
+    }
+}
+
+/// Resolves every conflicted file in `repo_path` by keeping `side`.
+///
+/// Returns the resolved files.
+pub fn keep_side(repo_path: &Path, side: ConflictSide) -> Result<Vec<String>> {
+    let files = git::unresolved_files(repo_path)?;
+    for file in &files {
+        git::checkout_conflict_side(repo_path, file, side)?;
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(repo: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    /// Creates a repository paused in a conflicting cherry-pick of `f.txt`.
+    fn conflicted_repo() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        git(repo, &["init", "-q", "-b", "main"]);
+        git(repo, &["config", "user.name", "Test User"]);
+        git(repo, &["config", "user.email", "test@example.com"]);
+        git(repo, &["config", "commit.gpgsign", "false"]);
+        std::fs::write(repo.join("f.txt"), "base\n").unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-q", "-m", "Initial"]);
+        git(repo, &["checkout", "-q", "-b", "feature"]);
+        std::fs::write(repo.join("f.txt"), "feature\n").unwrap();
+        git(repo, &["commit", "-q", "-am", "Feature"]);
+        git(repo, &["checkout", "-q", "main"]);
+        std::fs::write(repo.join("f.txt"), "main\n").unwrap();
+        git(repo, &["commit", "-q", "-am", "Main"]);
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(["cherry-pick", "feature"])
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "cherry-pick should conflict");
+        temp_dir
+    }
+
+    /// # Conflict Timeout Polling
+    ///
+    /// Tests the poll interval and the decision each action leads to.
+    ///
+    /// ## Test Scenario
+    /// - Builds policies with short and long waits
+    ///
+    /// ## Expected Outcome
+    /// - The interval is a tenth of the wait, between 10ms and 5s
+    /// - Only the strategies keep a side
+    #[test]
+    fn test_conflict_timeout_policy() {
+        let short = ConflictTimeout::new(Duration::from_millis(50), ConflictTimeoutAction::Ours);
+        assert_eq!(short.poll_interval(), Duration::from_millis(10));
+        let medium = ConflictTimeout::new(Duration::from_secs(20), ConflictTimeoutAction::Skip);
+        assert_eq!(medium.poll_interval(), Duration::from_secs(2));
+        let long = ConflictTimeout::new(Duration::from_secs(3600), ConflictTimeoutAction::Abort);
+        assert_eq!(long.poll_interval(), Duration::from_secs(5));
+
+        assert_eq!(ConflictTimeoutAction::Ours.side(), Some(ConflictSide::Ours));
+        assert_eq!(
+            ConflictTimeoutAction::Theirs.side(),
+            Some(ConflictSide::Theirs)
+        );
+        assert_eq!(ConflictTimeoutAction::Skip.side(), None);
+        assert_eq!(
+            ConflictDecision::from(ConflictTimeoutAction::Theirs),
+            ConflictDecision::KeptTheirs
+        );
+    }
+
+    /// # Waiting For a Resolution
+    ///
+    /// Tests waiting on a paused cherry-pick.
+    ///
+    /// ## Test Scenario
+    /// - Waits on an unresolved conflict
+    /// - Keeps the picked commit's side and waits again
+    ///
+    /// ## Expected Outcome
+    /// - The first wait times out
+    /// - The file is resolved to the picked version and the second wait
+    ///   returns at once
+    #[test]
+    fn test_wait_for_resolution() {
+        let temp_dir = conflicted_repo();
+        let repo = temp_dir.path();
+        let policy = ConflictTimeout::new(Duration::from_millis(30), ConflictTimeoutAction::Theirs);
+        assert!(!policy.wait_for_resolution(repo));
+
+        let resolved = keep_side(repo, ConflictSide::Theirs).unwrap();
+        assert_eq!(resolved, vec!["f.txt"]);
+        assert_eq!(
+            std::fs::read_to_string(repo.join("f.txt")).unwrap(),
+            "feature\n"
+        );
+        assert!(policy.wait_for_resolution(repo));
+    }
+}
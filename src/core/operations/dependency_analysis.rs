@@ -11,12 +11,20 @@
 //! dependency relationships, enabling validation such as detecting when
 //! a selected PR depends on an unselected one.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write as _;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
 use rayon::prelude::*;
 use roaring::RoaringBitmap;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 
+use crate::cache::RepoCache;
+use crate::git;
+use crate::models::PullRequestWithWorkItems;
+use crate::profiling;
 use crate::utils::truncate_str;
 
 /// A range of lines in a file.
@@ -249,6 +257,9 @@ pub struct PRDependencyNode {
     pub dependencies: Vec<PRDependency>,
     /// PRs that depend on this PR (edges coming in).
     pub dependents: Vec<i32>,
+    /// Paths of all files changed by this PR.
+    #[serde(default)]
+    pub changed_files: Vec<String>,
 }
 
 impl PRDependencyNode {
@@ -260,6 +271,7 @@ impl PRDependencyNode {
             is_selected,
             dependencies: Vec::new(),
             dependents: Vec::new(),
+            changed_files: Vec::new(),
         }
     }
 
@@ -299,6 +311,7 @@ pub struct DependencyCounts {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PRDependencyGraph {
     /// Nodes in the graph, keyed by PR ID.
+    #[serde(serialize_with = "serialize_nodes_sorted")]
     pub nodes: HashMap<i32, PRDependencyNode>,
     /// PRs in topological order (dependencies before dependents).
     pub topological_order: Vec<i32>,
@@ -408,8 +421,82 @@ impl Default for PRDependencyGraph {
     }
 }
 
+/// Serializes graph nodes ordered by PR ID so exported graphs are stable.
+fn serialize_nodes_sorted<S: Serializer>(
+    nodes: &HashMap<i32, PRDependencyNode>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    nodes
+        .iter()
+        .collect::<BTreeMap<_, _>>()
+        .serialize(serializer)
+}
+
+impl PRDependencyGraph {
+    /// Renders the graph in Graphviz DOT format.
+    ///
+    /// Edges point from a PR to the PR it depends on. Dependent edges are
+    /// solid red and labelled with the number of overlapping files; partially
+    /// dependent edges are dashed. Selected PRs are filled.
+    pub fn to_dot(&self) -> String {
+        let mut ids: Vec<i32> = self.nodes.keys().copied().collect();
+        ids.sort_unstable();
+
+        let mut dot = String::from("digraph pr_dependencies {\n");
+        dot.push_str("    rankdir=RL;\n");
+        dot.push_str("    node [shape=box, fontname=\"Helvetica\"];\n");
+
+        for id in &ids {
+            let node = &self.nodes[id];
+            let label = escape_dot(&format!("#{} {}", node.pr_id, node.pr_title));
+            let style = if node.is_selected {
+                ", style=filled, fillcolor=lightblue"
+            } else {
+                ""
+            };
+            let _ = writeln!(dot, "    pr{} [label=\"{}\"{}];", node.pr_id, label, style);
+        }
+
+        for id in &ids {
+            for dep in &self.nodes[id].dependencies {
+                match &dep.category {
+                    DependencyCategory::Independent => {}
+                    DependencyCategory::PartiallyDependent { shared_files } => {
+                        let _ = writeln!(
+                            dot,
+                            "    pr{} -> pr{} [style=dashed, label=\"{} shared\"];",
+                            dep.from_pr_id,
+                            dep.to_pr_id,
+                            shared_files.len()
+                        );
+                    }
+                    DependencyCategory::Dependent {
+                        overlapping_files, ..
+                    } => {
+                        let _ = writeln!(
+                            dot,
+                            "    pr{} -> pr{} [color=red, label=\"{} overlapping\"];",
+                            dep.from_pr_id,
+                            dep.to_pr_id,
+                            overlapping_files.len()
+                        );
+                    }
+                }
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Escapes a string for use inside a quoted DOT label.
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
 /// Summary statistics for a dependency graph.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct GraphSummary {
     /// Total number of PRs in the graph.
     pub total_prs: usize,
@@ -530,6 +617,10 @@ pub struct DependencyAnalysisConfig {
     pub warn_on_partial: bool,
     /// Whether to fail on any unselected dependencies.
     pub fail_on_unselected: bool,
+    /// Whether [`DependencyAnalyzer::analyze_repository`] reads the changed
+    /// line ranges. Without them, PRs sharing a file are at most partially
+    /// dependent, but the analysis only needs `git show --name-status`.
+    pub line_ranges: bool,
 }
 
 impl Default for DependencyAnalysisConfig {
@@ -537,10 +628,97 @@ impl Default for DependencyAnalysisConfig {
         Self {
             warn_on_partial: true,
             fail_on_unselected: false,
+            line_ranges: true,
         }
     }
 }
 
+// ==================== Rename Chains ====================
+
+/// Groups the paths that name the same file across renames.
+///
+/// A rename `a -> b` in one PR and `b -> c` in a later one make `a`, `b` and
+/// `c` one file, identified by its latest path `c`. Copies keep their own
+/// identity, since the source file lives on.
+#[derive(Debug, Default)]
+struct RenameChains {
+    /// Path -> identifying path, for paths that were part of a rename.
+    canonical: HashMap<String, String>,
+}
+
+impl RenameChains {
+    /// Collects the renames in all PR changes.
+    fn build(pr_changes: &HashMap<i32, Vec<FileChange>>) -> Self {
+        let mut ids: HashMap<&str, usize> = HashMap::new();
+        let mut parent: Vec<usize> = Vec::new();
+        let mut renamed_from: HashSet<&str> = HashSet::new();
+
+        fn find(parent: &mut [usize], mut id: usize) -> usize {
+            while parent[id] != id {
+                parent[id] = parent[parent[id]];
+                id = parent[id];
+            }
+            id
+        }
+
+        for change in pr_changes.values().flatten() {
+            let (ChangeType::Rename, Some(original)) = (change.change_type, &change.original_path)
+            else {
+                continue;
+            };
+            let [from, to] = [original.as_str(), change.path.as_str()].map(|path| {
+                let next_id = parent.len();
+                let id = *ids.entry(path).or_insert(next_id);
+                if id == next_id {
+                    parent.push(next_id);
+                }
+                find(&mut parent, id)
+            });
+            parent[from] = to;
+            renamed_from.insert(original);
+        }
+
+        let mut groups: HashMap<usize, Vec<&str>> = HashMap::new();
+        for (&path, &id) in &ids {
+            groups.entry(find(&mut parent, id)).or_default().push(path);
+        }
+
+        let mut canonical = HashMap::new();
+        for members in groups.values() {
+            // The latest name is the one never renamed away; a chain that
+            // loops back falls back to the smallest path.
+            let name = members
+                .iter()
+                .filter(|path| !renamed_from.contains(*path))
+                .min()
+                .or_else(|| members.iter().min())
+                .expect("groups are never empty");
+            for member in members {
+                canonical.insert(member.to_string(), name.to_string());
+            }
+        }
+        Self { canonical }
+    }
+
+    /// Returns the path identifying the file at `path`.
+    fn canonical<'a>(&'a self, path: &'a str) -> &'a str {
+        self.canonical.get(path).map_or(path, String::as_str)
+    }
+
+    /// Returns the identifying paths of the files a change touches.
+    ///
+    /// A copy also touches its source; a rename touches a single file.
+    fn touched<'a>(&'a self, change: &'a FileChange) -> impl Iterator<Item = &'a str> {
+        let path = self.canonical(&change.path);
+        let original = change
+            .original_path
+            .as_deref()
+            .map(|original| self.canonical(original))
+            .filter(|original| *original != path);
+        std::iter::once(path).chain(original)
+    }
+}
+
 // ==================== Bitmap Index for Optimized Analysis ====================
 
 /// Pre-computed bitmap index for fast dependency analysis.
@@ -550,9 +728,10 @@ impl Default for DependencyAnalysisConfig {
 /// analysis for large PR sets (100+ PRs).
 #[derive(Debug)]
 pub struct PRBitmapIndex {
-    /// Map file path -> unique integer ID for bitmap indexing
+    /// Map file path -> integer ID for bitmap indexing (shared by all paths
+    /// of a rename chain)
     file_dict: HashMap<String, u32>,
-    /// Reverse map: file ID -> file path
+    /// Reverse map: file ID -> file path (the latest path of a rename chain)
     file_dict_reverse: HashMap<u32, String>,
     /// Map PR ID -> bitmap of file IDs it touches
     pr_file_bitmaps: HashMap<i32, RoaringBitmap>,
@@ -564,30 +743,31 @@ impl PRBitmapIndex {
     /// Builds a bitmap index from PR file changes.
     ///
     /// This is a three-pass algorithm:
-    /// 1. Build file path -> integer dictionary
+    /// 1. Build file path -> integer dictionary, following rename chains
     /// 2. Build file bitmaps per PR (parallelized)
     /// 3. Build line bitmaps per (PR, file) (parallelized)
     pub fn build(pr_changes: &HashMap<i32, Vec<FileChange>>) -> Self {
-        // Pass 1: Build file dictionary (sequential - needs unique IDs)
+        let _phase = profiling::phase("build bitmap index");
+        let renames = RenameChains::build(pr_changes);
+
+        // Pass 1: Build file dictionary (sequential - needs unique IDs).
+        // Every path of a rename chain maps to the ID of the chain's file.
         let mut file_dict = HashMap::new();
         let mut file_dict_reverse = HashMap::new();
-        let mut next_id = 0u32;
-
-        for changes in pr_changes.values() {
-            for change in changes {
-                if !file_dict.contains_key(&change.path) {
-                    file_dict.insert(change.path.clone(), next_id);
-                    file_dict_reverse.insert(next_id, change.path.clone());
-                    next_id += 1;
-                }
-                // Also handle original_path for renames
-                if let Some(ref orig) = change.original_path
-                    && !file_dict.contains_key(orig)
-                {
-                    file_dict.insert(orig.clone(), next_id);
-                    file_dict_reverse.insert(next_id, orig.clone());
-                    next_id += 1;
+        let mut identity_ids: HashMap<&str, u32> = HashMap::new();
+
+        for change in pr_changes.values().flatten() {
+            for path in std::iter::once(&change.path).chain(&change.original_path) {
+                if file_dict.contains_key(path) {
+                    continue;
                 }
+                let identity = renames.canonical(path);
+                let next_id = identity_ids.len() as u32;
+                let file_id = *identity_ids.entry(identity).or_insert_with(|| {
+                    file_dict_reverse.insert(next_id, identity.to_string());
+                    next_id
+                });
+                file_dict.insert(path.clone(), file_id);
             }
         }
 
@@ -610,29 +790,26 @@ impl PRBitmapIndex {
             })
             .collect();
 
-        // Pass 3: Build line bitmaps per (PR, file) (parallel)
-        // We need to collect into a Vec first to avoid borrow issues, then convert to HashMap
+        // Pass 3: Build line bitmaps per (PR, file) (parallel). A PR touching
+        // several paths of one rename chain gets their lines merged.
         let file_dict_ref = &file_dict;
         let pr_line_bitmaps: HashMap<(i32, u32), RoaringBitmap> = pr_changes
             .par_iter()
             .flat_map_iter(|(pr_id, changes)| {
-                let pr_id = *pr_id;
-                changes.iter().filter_map(move |change| {
-                    let file_id = file_dict_ref.get(&change.path)?;
-                    let mut bitmap = RoaringBitmap::new();
+                let mut lines: HashMap<(i32, u32), RoaringBitmap> = HashMap::new();
+                for change in changes {
+                    let Some(&file_id) = file_dict_ref.get(&change.path) else {
+                        continue;
+                    };
                     for range in &change.line_ranges {
                         // Insert all line numbers in the range
-                        for line in range.start..=range.end {
-                            bitmap.insert(line);
-                        }
+                        lines
+                            .entry((*pr_id, file_id))
+                            .or_default()
+                            .insert_range(range.start..=range.end);
                     }
-                    // Only store if there are line ranges
-                    if bitmap.is_empty() {
-                        None
-                    } else {
-                        Some(((pr_id, *file_id), bitmap))
-                    }
-                })
+                }
+                lines
             })
             .collect();
 
@@ -657,12 +834,16 @@ impl PRBitmapIndex {
     }
 
     /// Returns the file path for a file ID.
+    ///
+    /// For a renamed file this is its latest path.
     #[must_use]
     pub fn get_file_path(&self, file_id: u32) -> Option<&String> {
         self.file_dict_reverse.get(&file_id)
     }
 
     /// Returns the file ID for a file path.
+    ///
+    /// All paths of a rename chain share one ID.
     #[must_use]
     pub fn get_file_id(&self, path: &str) -> Option<u32> {
         self.file_dict.get(path).copied()
@@ -699,20 +880,32 @@ fn bitmap_to_ranges(bitmap: &RoaringBitmap) -> Vec<LineRange> {
 /// to determine if they modify the same files and whether the line ranges overlap.
 pub struct DependencyAnalyzer {
     config: DependencyAnalysisConfig,
+    /// Cache of the files changed by each commit.
+    cache: Option<Arc<RepoCache>>,
 }
 
 impl DependencyAnalyzer {
     /// Creates a new analyzer with default configuration.
     pub fn new() -> Self {
-        Self {
-            config: DependencyAnalysisConfig::default(),
-        }
+        Self::with_config(DependencyAnalysisConfig::default())
     }
 
     /// Creates a new analyzer with custom configuration.
     #[must_use]
     pub fn with_config(config: DependencyAnalysisConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            cache: None,
+        }
+    }
+
+    /// Reads the files changed by each commit from `cache` when
+    /// [`line_ranges`](DependencyAnalysisConfig::line_ranges) are not needed,
+    /// caching the ones read from git.
+    #[must_use]
+    pub fn with_cache(mut self, cache: Option<Arc<RepoCache>>) -> Self {
+        self.cache = cache;
+        self
     }
 
     /// Analyzes dependencies between PRs based on their file changes.
@@ -730,12 +923,15 @@ impl DependencyAnalyzer {
         prs: &[PRInfo],
         pr_changes: &HashMap<i32, Vec<FileChange>>,
     ) -> DependencyAnalysisResult {
+        let _phase = profiling::phase("dependency analysis");
         let mut graph = PRDependencyGraph::new();
         let mut warnings = Vec::new();
+        let renames = RenameChains::build(pr_changes);
 
         // Build nodes for all PRs
         for pr in prs {
-            let node = PRDependencyNode::new(pr.id, pr.title.clone(), pr.is_selected);
+            let mut node = PRDependencyNode::new(pr.id, pr.title.clone(), pr.is_selected);
+            node.changed_files = Self::changed_paths(pr_changes.get(&pr.id));
             graph.add_node(node);
         }
 
@@ -746,7 +942,7 @@ impl DependencyAnalyzer {
             for prev_pr in prs.iter().take(idx) {
                 let prev_changes = pr_changes.get(&prev_pr.id);
 
-                let category = Self::categorize_dependency(current_changes, prev_changes);
+                let category = Self::categorize_dependency(current_changes, prev_changes, &renames);
 
                 // Only record non-independent dependencies
                 if !category.is_independent() {
@@ -815,13 +1011,15 @@ impl DependencyAnalyzer {
         prs: &[PRInfo],
         pr_changes: &HashMap<i32, Vec<FileChange>>,
     ) -> DependencyAnalysisResult {
+        let _phase = profiling::phase("dependency analysis");
         // Build bitmap index for fast comparison (parallelized internally)
         let index = PRBitmapIndex::build(pr_changes);
 
         // Build nodes for all PRs (sequential - fast)
         let mut graph = PRDependencyGraph::new();
         for pr in prs {
-            let node = PRDependencyNode::new(pr.id, pr.title.clone(), pr.is_selected);
+            let mut node = PRDependencyNode::new(pr.id, pr.title.clone(), pr.is_selected);
+            node.changed_files = Self::changed_paths(pr_changes.get(&pr.id));
             graph.add_node(node);
         }
 
@@ -934,10 +1132,121 @@ impl DependencyAnalyzer {
         DependencyAnalysisResult { graph, warnings }
     }
 
+    /// Reads each PR's changes from a local repository and analyzes them.
+    ///
+    /// PRs are ordered by closed date (oldest first) before comparison. The
+    /// changes of each PR's merge commit are read with git in parallel, with
+    /// line ranges unless disabled in the config;
+    /// `on_progress` is called with `(analyzed, total, pr_id)` after each PR.
+    ///
+    /// Returns `None` if `cancel` is set before the analysis finishes.
+    pub fn analyze_repository(
+        &self,
+        repo_path: &Path,
+        prs: &[PullRequestWithWorkItems],
+        on_progress: impl Fn(usize, usize, i32) + Sync,
+        cancel: &AtomicBool,
+    ) -> Option<DependencyAnalysisResult> {
+        let mut pr_infos: Vec<PRInfo> = prs
+            .iter()
+            .map(|pr_with_wi| {
+                PRInfo::new(
+                    pr_with_wi.pr.id,
+                    pr_with_wi.pr.title.clone(),
+                    pr_with_wi.selected,
+                    pr_with_wi
+                        .pr
+                        .last_merge_commit
+                        .as_ref()
+                        .map(|c| c.commit_id.clone()),
+                )
+            })
+            .collect();
+
+        // Sort PRs by closed date (oldest first) for correct dependency analysis
+        let pr_dates: HashMap<i32, Option<&String>> = prs
+            .iter()
+            .map(|pr| (pr.pr.id, pr.pr.closed_date.as_ref()))
+            .collect();
+
+        pr_infos.sort_by(|a, b| {
+            let date_a = pr_dates.get(&a.id).copied().flatten();
+            let date_b = pr_dates.get(&b.id).copied().flatten();
+
+            match (date_a, date_b) {
+                (Some(da), Some(db)) => da.cmp(db),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => a.id.cmp(&b.id),
+            }
+        });
+
+        // Parallel fetch of file changes for each PR
+        let total = pr_infos.len();
+        let analyzed = AtomicUsize::new(0);
+        let pr_changes: HashMap<i32, Vec<FileChange>> = pr_infos
+            .par_iter()
+            .filter_map(|pr_info| {
+                if cancel.load(Ordering::Relaxed) {
+                    return None;
+                }
+                let changes = pr_info.commit_id.as_ref().map(|commit_id| {
+                    if self.config.line_ranges {
+                        git::get_commit_changes_with_ranges(repo_path, commit_id)
+                    } else {
+                        self.commit_file_changes(repo_path, commit_id)
+                    }
+                    .unwrap_or_default()
+                });
+
+                let done = analyzed.fetch_add(1, Ordering::Relaxed) + 1;
+                on_progress(done, total, pr_info.id);
+
+                changes.map(|changes| (pr_info.id, changes))
+            })
+            .collect();
+
+        if cancel.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        Some(self.analyze_parallel(&pr_infos, &pr_changes))
+    }
+
+    /// Returns the files changed by a commit, from the cache if possible.
+    fn commit_file_changes(
+        &self,
+        repo_path: &Path,
+        commit_id: &str,
+    ) -> anyhow::Result<Vec<FileChange>> {
+        if let Some(changes) = self.cache.as_ref().and_then(|c| c.commit_files(commit_id)) {
+            return Ok(changes);
+        }
+        let changes = git::get_commit_file_changes(repo_path, commit_id)?;
+        if let Some(cache) = &self.cache {
+            cache.set_commit_files(commit_id, &changes);
+        }
+        Ok(changes)
+    }
+
+    /// Returns the sorted, deduplicated paths of a PR's file changes.
+    fn changed_paths(changes: Option<&Vec<FileChange>>) -> Vec<String> {
+        let mut paths: Vec<String> = changes
+            .map(|c| c.iter().map(|fc| fc.path.clone()).collect())
+            .unwrap_or_default();
+        paths.sort();
+        paths.dedup();
+        paths
+    }
+
     /// Categorizes the dependency between two sets of file changes.
+    ///
+    /// Files are compared by identity, so a path and its names before or
+    /// after a rename in `renames` count as the same file.
     fn categorize_dependency(
         current: Option<&Vec<FileChange>>,
         previous: Option<&Vec<FileChange>>,
+        renames: &RenameChains,
     ) -> DependencyCategory {
         let current = match current {
             Some(c) if !c.is_empty() => c,
@@ -950,27 +1259,10 @@ impl DependencyAnalyzer {
         };
 
         // Find files that appear in both changesets
-        let current_files: HashSet<&str> = current
-            .iter()
-            .flat_map(|c| {
-                let mut files = vec![c.path.as_str()];
-                if let Some(ref orig) = c.original_path {
-                    files.push(orig.as_str());
-                }
-                files
-            })
-            .collect();
-
-        let previous_files: HashSet<&str> = previous
-            .iter()
-            .flat_map(|c| {
-                let mut files = vec![c.path.as_str()];
-                if let Some(ref orig) = c.original_path {
-                    files.push(orig.as_str());
-                }
-                files
-            })
-            .collect();
+        let current_files: HashSet<&str> =
+            current.iter().flat_map(|c| renames.touched(c)).collect();
+        let previous_files: HashSet<&str> =
+            previous.iter().flat_map(|c| renames.touched(c)).collect();
 
         let shared_files: Vec<String> = current_files
             .intersection(&previous_files)
@@ -981,25 +1273,40 @@ impl DependencyAnalyzer {
             return DependencyCategory::Independent;
         }
 
-        // Check for overlapping line ranges in shared files
+        // Check for overlapping line ranges in shared files. Line ranges
+        // belong to a change's new path.
+        let ranges_of = |changes: &'_ [FileChange], file: &str| -> Vec<LineRange> {
+            changes
+                .iter()
+                .filter(|c| renames.canonical(&c.path) == file)
+                .flat_map(|c| c.line_ranges.iter().cloned())
+                .collect()
+        };
         let mut overlapping_files = Vec::new();
 
         for shared_file in &shared_files {
-            let current_change = current.iter().find(|c| {
-                c.path == *shared_file || c.original_path.as_deref() == Some(shared_file)
-            });
-            let previous_change = previous.iter().find(|c| {
-                c.path == *shared_file || c.original_path.as_deref() == Some(shared_file)
-            });
+            let current_ranges = ranges_of(current, shared_file);
+            let previous_ranges = ranges_of(previous, shared_file);
 
-            if let (Some(curr), Some(prev)) = (current_change, previous_change) {
-                let overlapping_ranges = curr.get_overlapping_ranges(prev);
-                if !overlapping_ranges.is_empty() {
-                    overlapping_files.push(OverlappingFile {
-                        path: shared_file.clone(),
-                        overlapping_ranges,
-                    });
-                }
+            let overlapping_ranges: Vec<LineRange> = current_ranges
+                .iter()
+                .flat_map(|range1| {
+                    previous_ranges
+                        .iter()
+                        .filter(|range2| range1.overlaps(range2))
+                        .map(|range2| {
+                            LineRange::new(
+                                range1.start.max(range2.start),
+                                range1.end.min(range2.end),
+                            )
+                        })
+                })
+                .collect();
+            if !overlapping_ranges.is_empty() {
+                overlapping_files.push(OverlappingFile {
+                    path: shared_file.clone(),
+                    overlapping_ranges,
+                });
             }
         }
 
@@ -1779,4 +2086,383 @@ mod tests {
             }
         }
     }
+
+    /// # Graph DOT Export
+    ///
+    /// Tests rendering a dependency graph in Graphviz DOT format.
+    ///
+    /// ## Test Scenario
+    /// - Builds a graph with a dependent edge, a partial edge and a quoted title
+    ///
+    /// ## Expected Outcome
+    /// - Nodes are emitted in PR id order with escaped labels
+    /// - Dependent and partial edges use distinct styles
+    #[test]
+    fn test_graph_to_dot() {
+        let mut graph = PRDependencyGraph::new();
+        let mut second = PRDependencyNode::new(2, "Use \"new\" API".to_string(), true);
+        second.dependencies.push(PRDependency {
+            from_pr_id: 2,
+            to_pr_id: 1,
+            category: DependencyCategory::Dependent {
+                shared_files: vec!["src/lib.rs".to_string()],
+                overlapping_files: vec![OverlappingFile {
+                    path: "src/lib.rs".to_string(),
+                    overlapping_ranges: vec![LineRange::new(1, 5)],
+                }],
+            },
+        });
+        let mut third = PRDependencyNode::new(3, "Docs".to_string(), false);
+        third.dependencies.push(PRDependency {
+            from_pr_id: 3,
+            to_pr_id: 1,
+            category: DependencyCategory::PartiallyDependent {
+                shared_files: vec!["README.md".to_string()],
+            },
+        });
+        graph.add_node(third);
+        graph.add_node(second);
+        graph.add_node(PRDependencyNode::new(1, "Add API".to_string(), false));
+
+        let dot = graph.to_dot();
+
+        let first_node = dot.find("pr1 [").unwrap();
+        let second_node = dot.find("pr2 [").unwrap();
+        assert!(first_node < second_node);
+        assert!(dot.contains(r##"pr2 [label="#2 Use \"new\" API", style=filled"##));
+        assert!(dot.contains(r#"pr2 -> pr1 [color=red, label="1 overlapping"];"#));
+        assert!(dot.contains(r#"pr3 -> pr1 [style=dashed, label="1 shared"];"#));
+        assert!(dot.starts_with("digraph pr_dependencies {"));
+    }
+
+    /// # Graph JSON Node Order
+    ///
+    /// Tests that serialized graphs list nodes in PR id order.
+    ///
+    /// ## Test Scenario
+    /// - Serializes a graph whose nodes were inserted out of order
+    ///
+    /// ## Expected Outcome
+    /// - Node keys appear in ascending order and the graph deserializes again
+    #[test]
+    fn test_graph_serialization_sorted() {
+        let mut graph = PRDependencyGraph::new();
+        for id in [30, 10, 20] {
+            graph.add_node(PRDependencyNode::new(id, format!("PR {}", id), false));
+        }
+
+        let json = serde_json::to_string(&graph).unwrap();
+        let positions: Vec<usize> = ["\"10\"", "\"20\"", "\"30\""]
+            .iter()
+            .map(|key| json.find(key).unwrap())
+            .collect();
+        assert!(positions.windows(2).all(|w| w[0] < w[1]));
+
+        let restored: PRDependencyGraph = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.nodes.len(), 3);
+    }
+
+    // ==================== Rename Chains ====================
+
+    fn renamed(from: &str, to: &str, ranges: Vec<LineRange>) -> FileChange {
+        FileChange {
+            original_path: Some(from.to_string()),
+            ..FileChange::with_ranges(to.to_string(), ChangeType::Rename, ranges)
+        }
+    }
+
+    fn edited(path: &str, start: u32, end: u32) -> FileChange {
+        FileChange::with_ranges(
+            path.to_string(),
+            ChangeType::Modify,
+            vec![LineRange::new(start, end)],
+        )
+    }
+
+    fn selected_prs(count: i32) -> Vec<PRInfo> {
+        (1..=count)
+            .map(|id| PRInfo::new(id, format!("PR {}", id), true, None))
+            .collect()
+    }
+
+    /// Runs both analyzers and returns the category from `from` to `to`,
+    /// asserting they agree.
+    fn category_between(
+        pr_changes: &HashMap<i32, Vec<FileChange>>,
+        from: i32,
+        to: i32,
+    ) -> DependencyCategory {
+        let prs = selected_prs(pr_changes.len() as i32);
+        let analyzer = DependencyAnalyzer::new();
+        let categories: Vec<DependencyCategory> = [
+            analyzer.analyze(&prs, pr_changes),
+            analyzer.analyze_parallel(&prs, pr_changes),
+        ]
+        .iter()
+        .map(|result| {
+            result
+                .graph
+                .get_node(from)
+                .unwrap()
+                .dependencies
+                .iter()
+                .find(|dep| dep.to_pr_id == to)
+                .map_or(DependencyCategory::Independent, |dep| dep.category.clone())
+        })
+        .collect();
+        assert_eq!(
+            std::mem::discriminant(&categories[0]),
+            std::mem::discriminant(&categories[1]),
+            "sequential and parallel analysis disagree for {} -> {}",
+            from,
+            to
+        );
+        categories[0].clone()
+    }
+
+    /// # Rename Then Edit
+    ///
+    /// Tests a PR editing a file that an earlier PR renamed and edited.
+    ///
+    /// ## Test Scenario
+    /// - PR 1 renames `old.rs` to `new.rs` and edits lines 10-20
+    /// - PR 2 edits lines 15-25 of `new.rs`
+    ///
+    /// ## Expected Outcome
+    /// - PR 2 depends on PR 1 with overlapping lines in `new.rs`
+    #[test]
+    fn test_rename_then_edit_new_path() {
+        let pr_changes = HashMap::from([
+            (
+                1,
+                vec![renamed("old.rs", "new.rs", vec![LineRange::new(10, 20)])],
+            ),
+            (2, vec![edited("new.rs", 15, 25)]),
+        ]);
+
+        match category_between(&pr_changes, 2, 1) {
+            DependencyCategory::Dependent {
+                shared_files,
+                overlapping_files,
+            } => {
+                assert_eq!(shared_files, vec!["new.rs".to_string()]);
+                assert_eq!(overlapping_files[0].path, "new.rs");
+                assert_eq!(
+                    overlapping_files[0].overlapping_ranges,
+                    vec![LineRange::new(15, 20)]
+                );
+            }
+            other => panic!("expected Dependent, got {:?}", other),
+        }
+    }
+
+    /// # Edit, Rename, Edit
+    ///
+    /// Tests following a file's identity through a rename made by another PR.
+    ///
+    /// ## Test Scenario
+    /// - PR 1 edits lines 10-20 of `old.rs`
+    /// - PR 2 renames `old.rs` to `new.rs` without content changes
+    /// - PR 3 edits lines 15-18 of `new.rs`
+    ///
+    /// ## Expected Outcome
+    /// - PR 3 depends on PR 1 with overlapping lines, reported as `new.rs`
+    /// - PR 3 partially depends on the renaming PR 2
+    #[test]
+    fn test_edit_across_rename_by_other_pr() {
+        let pr_changes = HashMap::from([
+            (1, vec![edited("old.rs", 10, 20)]),
+            (2, vec![renamed("old.rs", "new.rs", Vec::new())]),
+            (3, vec![edited("new.rs", 15, 18)]),
+        ]);
+
+        match category_between(&pr_changes, 3, 1) {
+            DependencyCategory::Dependent {
+                shared_files,
+                overlapping_files,
+            } => {
+                assert_eq!(shared_files, vec!["new.rs".to_string()]);
+                assert_eq!(
+                    overlapping_files[0].overlapping_ranges,
+                    vec![LineRange::new(15, 18)]
+                );
+            }
+            other => panic!("expected Dependent, got {:?}", other),
+        }
+        assert!(matches!(
+            category_between(&pr_changes, 3, 2),
+            DependencyCategory::PartiallyDependent { .. }
+        ));
+    }
+
+    /// # Rename Chain
+    ///
+    /// Tests following a file through several renames in separate PRs.
+    ///
+    /// ## Test Scenario
+    /// - PR 1 renames `a.rs` to `b.rs`, PR 2 renames `b.rs` to `c.rs`
+    /// - PR 3 edits `c.rs`
+    ///
+    /// ## Expected Outcome
+    /// - PR 3 depends on both renaming PRs, sharing the file `c.rs`
+    /// - The bitmap index gives all three paths one file ID named `c.rs`
+    #[test]
+    fn test_rename_chain() {
+        let pr_changes = HashMap::from([
+            (1, vec![renamed("a.rs", "b.rs", Vec::new())]),
+            (2, vec![renamed("b.rs", "c.rs", Vec::new())]),
+            (3, vec![edited("c.rs", 1, 5)]),
+        ]);
+
+        for to in [1, 2] {
+            match category_between(&pr_changes, 3, to) {
+                DependencyCategory::PartiallyDependent { shared_files } => {
+                    assert_eq!(shared_files, vec!["c.rs".to_string()]);
+                }
+                other => panic!("expected PartiallyDependent on {}, got {:?}", to, other),
+            }
+        }
+
+        let index = PRBitmapIndex::build(&pr_changes);
+        let file_id = index.get_file_id("c.rs").unwrap();
+        assert_eq!(index.get_file_id("a.rs"), Some(file_id));
+        assert_eq!(index.get_file_id("b.rs"), Some(file_id));
+        assert_eq!(index.get_file_path(file_id).unwrap(), "c.rs");
+    }
+
+    /// # Rename Back
+    ///
+    /// Tests a rename chain that returns to its first name.
+    ///
+    /// ## Test Scenario
+    /// - PR 1 renames `a.rs` to `b.rs`, PR 2 renames it back
+    /// - PR 3 edits `b.rs`, PR 4 edits `a.rs`
+    ///
+    /// ## Expected Outcome
+    /// - Both paths are one file: PR 4 partially depends on PR 1 and its
+    ///   lines overlap PR 3's edit of `b.rs`
+    #[test]
+    fn test_rename_cycle() {
+        let pr_changes = HashMap::from([
+            (1, vec![renamed("a.rs", "b.rs", Vec::new())]),
+            (2, vec![renamed("b.rs", "a.rs", Vec::new())]),
+            (3, vec![edited("b.rs", 1, 2)]),
+            (4, vec![edited("a.rs", 1, 2)]),
+        ]);
+
+        assert!(matches!(
+            category_between(&pr_changes, 4, 1),
+            DependencyCategory::PartiallyDependent { .. }
+        ));
+        assert!(matches!(
+            category_between(&pr_changes, 4, 3),
+            DependencyCategory::Dependent { .. }
+        ));
+    }
+
+    /// # Copies Keep Their Identity
+    ///
+    /// Tests that a copied file is not merged with its source.
+    ///
+    /// ## Test Scenario
+    /// - PR 1 copies `a.rs` to `b.rs`
+    /// - PR 2 edits `b.rs`, PR 3 edits `a.rs`
+    ///
+    /// ## Expected Outcome
+    /// - PR 3 does not depend on PR 2
+    /// - Both depend on the copying PR
+    #[test]
+    fn test_copy_is_not_a_rename() {
+        let copied = FileChange {
+            original_path: Some("a.rs".to_string()),
+            ..FileChange::new("b.rs".to_string(), ChangeType::Copy)
+        };
+        let pr_changes = HashMap::from([
+            (1, vec![copied]),
+            (2, vec![edited("b.rs", 1, 5)]),
+            (3, vec![edited("a.rs", 1, 5)]),
+        ]);
+
+        assert_eq!(
+            category_between(&pr_changes, 3, 2),
+            DependencyCategory::Independent
+        );
+        assert!(!category_between(&pr_changes, 2, 1).is_independent());
+        assert!(!category_between(&pr_changes, 3, 1).is_independent());
+    }
+
+    /// # File-Level Repository Analysis
+    ///
+    /// Tests analyzing a repository with and without line ranges.
+    ///
+    /// ## Test Scenario
+    /// - Two commits edit the same line of one file
+    /// - The repository is analyzed with the default config and with
+    ///   `line_ranges` disabled
+    ///
+    /// ## Expected Outcome
+    /// - With line ranges the later PR is dependent on the earlier one
+    /// - Without them it is only partially dependent on the same file
+    #[test]
+    #[cfg(feature = "tui")]
+    fn test_analyze_repository_without_line_ranges() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .current_dir(repo)
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {:?} failed", args);
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+        git(&["init"]);
+        git(&["config", "user.name", "Test User"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "commit.gpgsign", "false"]);
+
+        let mut commits = Vec::new();
+        for content in ["one\n", "two\n", "three\n"] {
+            std::fs::write(repo.join("shared.rs"), content).unwrap();
+            git(&["add", "."]);
+            git(&["commit", "-m", content.trim()]);
+            commits.push(git(&["rev-parse", "HEAD"]));
+        }
+
+        let mut prs = crate::ui::testing::create_test_pull_requests();
+        prs.truncate(2);
+        for (pr, commit) in prs.iter_mut().zip(&commits[1..]) {
+            pr.pr.last_merge_commit = Some(crate::models::MergeCommit {
+                commit_id: commit.clone(),
+            });
+        }
+        let (earlier, later) = (prs[0].pr.id, prs[1].pr.id);
+
+        let category = |config: DependencyAnalysisConfig| {
+            let result = DependencyAnalyzer::with_config(config)
+                .analyze_repository(repo, &prs, |_, _, _| {}, &AtomicBool::new(false))
+                .unwrap();
+            result.graph.nodes[&later].dependencies[0].clone()
+        };
+
+        let ranged = category(DependencyAnalysisConfig::default());
+        assert_eq!(ranged.to_pr_id, earlier);
+        assert!(matches!(
+            ranged.category,
+            DependencyCategory::Dependent { .. }
+        ));
+
+        let file_level = category(DependencyAnalysisConfig {
+            line_ranges: false,
+            ..Default::default()
+        });
+        assert_eq!(file_level.to_pr_id, earlier);
+        assert_eq!(
+            file_level.category,
+            DependencyCategory::PartiallyDependent {
+                shared_files: vec!["shared.rs".to_string()]
+            }
+        );
+    }
 }
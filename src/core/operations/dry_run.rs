@@ -0,0 +1,281 @@
+//! Simulation of a whole merge for `mergers merge --dry-run`.
+//!
+//! A dry run picks the same PRs in the same order as a real merge, but
+//! simulates each cherry-pick with `git merge-tree` (see
+//! [`git::simulate_cherry_pick`]) instead of applying it. Nothing is checked
+//! out, no branch or tag is created, and the PRs and work items are left
+//! untouched; the runner reports what a merge and its completion would do.
+//!
+//! Without a local repository, [`clone_for_dry_run`] makes a shallow clone
+//! of the target branch and fetches the selected PRs' merge commits with
+//! their parents into it.
+//!
+//! Conflicts in binary files covered by the
+//! [binary conflict policy](super::binary_conflicts) are predicted as
+//! resolved, as the real merge would resolve them without stopping.
+
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::core::operations::BinaryConflictPolicy;
+use crate::core::output::{DryRunItem, DryRunOutcome};
+use crate::git::{self, SimulatedCherryPick};
+use crate::models::PullRequestWithWorkItems;
+
+/// Picks of a simulated merge and the selected PRs that were left out.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SimulatedMerge {
+    /// Selected PRs in cherry-pick order with their predicted outcome.
+    pub items: Vec<DryRunItem>,
+    /// Selected PRs without a merge commit, which a merge skips.
+    pub skipped_pr_ids: Vec<i32>,
+}
+
+/// Simulates cherry-picking the selected PRs in order onto `base`.
+///
+/// A PR that would conflict or fail is assumed to be skipped, so later PRs
+/// are simulated without its changes. This also applies to conflicts
+/// resolved by the binary policy, whose chosen side is not simulated.
+pub fn simulate_merge(
+    repo_path: &Path,
+    base: &str,
+    prs: &[PullRequestWithWorkItems],
+    binary_conflicts: &BinaryConflictPolicy,
+) -> Result<SimulatedMerge> {
+    let mut tree = git::tree_id(repo_path, base)?;
+    let mut merge = SimulatedMerge::default();
+
+    for pr in prs.iter().filter(|pr| pr.selected) {
+        let Some(commit) = &pr.pr.last_merge_commit else {
+            merge.skipped_pr_ids.push(pr.pr.id);
+            continue;
+        };
+
+        let mut item = DryRunItem {
+            pr_id: pr.pr.id,
+            pr_title: pr.pr.title.clone(),
+            commit_id: commit.commit_id.clone(),
+            outcome: DryRunOutcome::Clean,
+            conflicted_files: Vec::new(),
+            error: None,
+            work_item_ids: pr.work_items.iter().map(|wi| wi.id).collect(),
+        };
+
+        match git::simulate_cherry_pick(repo_path, &tree, &commit.commit_id) {
+            Ok(SimulatedCherryPick::Clean { tree: next }) => tree = next,
+            Ok(SimulatedCherryPick::Conflict { files }) => {
+                item.outcome =
+                    if resolved_by_policy(repo_path, &commit.commit_id, &files, binary_conflicts) {
+                        DryRunOutcome::ResolvedByPolicy
+                    } else {
+                        DryRunOutcome::Conflict
+                    };
+                item.conflicted_files = files;
+            }
+            Err(e) => {
+                item.outcome = DryRunOutcome::Failed;
+                item.error = Some(format!("{:#}", e));
+            }
+        }
+        merge.items.push(item);
+    }
+
+    Ok(merge)
+}
+
+/// Clones the target branch of `url` shallowly into `path` and fetches the
+/// merge commits of the selected PRs with their parents, which
+/// [`simulate_merge`] needs.
+pub fn clone_for_dry_run(
+    url: &str,
+    target_branch: &str,
+    path: &Path,
+    prs: &[PullRequestWithWorkItems],
+) -> Result<()> {
+    git::shallow_clone_repo_into(url, target_branch, path)?;
+    let commit_ids: Vec<String> = prs
+        .iter()
+        .filter(|pr| pr.selected)
+        .filter_map(|pr| Some(pr.pr.last_merge_commit.as_ref()?.commit_id.clone()))
+        .collect();
+    git::fetch_commits_with_parents(path, &commit_ids)
+}
+
+/// Whether every conflicted file is a binary file the policy picks a side for.
+fn resolved_by_policy(
+    repo_path: &Path,
+    commit_id: &str,
+    files: &[String],
+    policy: &BinaryConflictPolicy,
+) -> bool {
+    !policy.is_empty()
+        && !files.is_empty()
+        && files.iter().all(|file| {
+            policy.side_for(file).is_some()
+                && git::is_binary_change(repo_path, commit_id, file).unwrap_or(false)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::operations::{BinaryConflictRule, ConflictSide};
+    use crate::models::{CreatedBy, MergeCommit, PullRequest};
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(repo: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    /// Commits `files` on a branch off `main` and returns the commit.
+    fn commit_on_branch(repo: &Path, branch: &str, files: &[(&str, &[u8])]) -> String {
+        git(repo, &["checkout", "-q", "-b", branch, "main"]);
+        for (name, content) in files {
+            std::fs::write(repo.join(name), content).unwrap();
+        }
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-q", "-m", branch]);
+        let commit = git(repo, &["rev-parse", "HEAD"]);
+        git(repo, &["checkout", "-q", "main"]);
+        commit
+    }
+
+    fn pr(id: i32, commit: Option<String>) -> PullRequestWithWorkItems {
+        PullRequestWithWorkItems {
+            pr: PullRequest {
+                id,
+                title: format!("PR {}", id),
+                closed_date: None,
+                created_by: CreatedBy {
+                    display_name: "Dev".to_string(),
+                },
+                last_merge_commit: commit.map(|commit_id| MergeCommit { commit_id }),
+                labels: None,
+                description: None,
+                reviewers: Vec::new(),
+            },
+            work_items: Vec::new(),
+            selected: true,
+        }
+    }
+
+    /// # Simulate Merge
+    ///
+    /// Tests predicting the outcome of each pick of a merge.
+    ///
+    /// ## Test Scenario
+    /// - `main` changes a text file and a PNG after the PRs branched off
+    /// - PR 1 adds a new file, PR 2 edits the text file, PR 3 edits the PNG
+    /// - PR 4 has no merge commit, PR 5 is not selected
+    /// - A policy keeps "theirs" for `*.png`
+    ///
+    /// ## Expected Outcome
+    /// - PR 1 is clean, PR 2 conflicts, PR 3 is resolved by the policy
+    /// - PR 4 is skipped, PR 5 is left out
+    /// - The checked-out branch is left unchanged
+    #[test]
+    fn test_simulate_merge() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        git(repo, &["init", "-q", "-b", "main"]);
+        git(repo, &["config", "user.email", "test@example.com"]);
+        git(repo, &["config", "user.name", "Test User"]);
+        std::fs::write(repo.join("notes.txt"), "original").unwrap();
+        std::fs::write(repo.join("logo.png"), b"\x89PNG\0base").unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-q", "-m", "initial"]);
+
+        let added = commit_on_branch(repo, "pr1", &[("new.txt", b"new")]);
+        let text = commit_on_branch(repo, "pr2", &[("notes.txt", b"feature")]);
+        let binary = commit_on_branch(repo, "pr3", &[("logo.png", b"\x89PNG\0feature")]);
+        let unselected = commit_on_branch(repo, "pr5", &[("other.txt", b"other")]);
+
+        std::fs::write(repo.join("notes.txt"), "main").unwrap();
+        std::fs::write(repo.join("logo.png"), b"\x89PNG\0main").unwrap();
+        git(repo, &["commit", "-q", "-am", "main changes"]);
+        let head = git(repo, &["rev-parse", "HEAD"]);
+
+        let mut prs = vec![
+            pr(1, Some(added)),
+            pr(2, Some(text)),
+            pr(3, Some(binary)),
+            pr(4, None),
+            pr(5, Some(unselected)),
+        ];
+        prs[4].selected = false;
+        let policy = BinaryConflictPolicy::new(&[BinaryConflictRule {
+            paths: vec!["*.png".to_string()],
+            resolve: ConflictSide::Theirs,
+        }])
+        .unwrap();
+
+        let merge = simulate_merge(repo, "main", &prs, &policy).unwrap();
+
+        let outcomes: Vec<(i32, DryRunOutcome)> = merge
+            .items
+            .iter()
+            .map(|item| (item.pr_id, item.outcome))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                (1, DryRunOutcome::Clean),
+                (2, DryRunOutcome::Conflict),
+                (3, DryRunOutcome::ResolvedByPolicy),
+            ]
+        );
+        assert_eq!(merge.items[1].conflicted_files, vec!["notes.txt"]);
+        assert_eq!(merge.skipped_pr_ids, vec![4]);
+        assert_eq!(git(repo, &["rev-parse", "HEAD"]), head);
+    }
+
+    /// # Dry Run Without a Local Repository
+    ///
+    /// Tests simulating a merge in the clone made when no local repository
+    /// is given.
+    ///
+    /// ## Test Scenario
+    /// - An origin repository has `main` and a PR commit on another branch
+    /// - Clones `main` for a dry run selecting that PR, then simulates it
+    ///
+    /// ## Expected Outcome
+    /// - The PR's commit is fetched with its parent into the shallow clone
+    /// - The pick is predicted clean instead of failing on a missing commit
+    #[test]
+    fn test_clone_for_dry_run() {
+        let temp_dir = TempDir::new().unwrap();
+        let origin = temp_dir.path().join("origin");
+        std::fs::create_dir(&origin).unwrap();
+        git(&origin, &["init", "-q", "-b", "main"]);
+        git(&origin, &["config", "user.email", "test@example.com"]);
+        git(&origin, &["config", "user.name", "Test User"]);
+        std::fs::write(origin.join("notes.txt"), "original").unwrap();
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "-q", "-m", "initial"]);
+        let added = commit_on_branch(&origin, "pr1", &[("new.txt", b"new")]);
+        std::fs::write(origin.join("notes.txt"), "main").unwrap();
+        git(&origin, &["commit", "-q", "-am", "main changes"]);
+
+        let clone = temp_dir.path().join("clone");
+        let url = format!("file://{}", origin.display());
+        let prs = vec![pr(1, Some(added.clone()))];
+        clone_for_dry_run(&url, "main", &clone, &prs).unwrap();
+
+        assert!(git::commit_exists(&clone, &format!("{}^1", added)));
+        let merge = simulate_merge(&clone, "main", &prs, &BinaryConflictPolicy::default()).unwrap();
+        assert_eq!(merge.items[0].outcome, DryRunOutcome::Clean);
+    }
+}
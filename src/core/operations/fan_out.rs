@@ -0,0 +1,138 @@
+//! Hotfix fan-out: cherry-picking the same PRs into several target branches.
+//!
+//! With a list of target branches, the selected PRs are picked into each
+//! target in turn. Every target gets a worktree, patch branch and state file
+//! of its own, so a conflict on one release branch does not hold up the
+//! others. The state file of each target lists all targets of the run.
+//!
+//! # Config Format
+//!
+//! ```toml
+//! # One branch, or a list to fan out to
+//! target_branch = ["release/6.6", "release/6.7"]
+//! ```
+//!
+//! On the command line and in `MERGERS_TARGET_BRANCH`, the branches are
+//! separated by commas: `--target-branch release/6.6,release/6.7`.
+
+/// Splits a comma-separated list of target branches.
+///
+/// Blank entries and repeated branches are dropped; the first occurrence
+/// keeps its place.
+pub fn split_target_branches(value: &str) -> Vec<String> {
+    let mut branches: Vec<String> = Vec::new();
+    for branch in value.split(',').map(str::trim) {
+        if !branch.is_empty() && !branches.iter().any(|b| b == branch) {
+            branches.push(branch.to_string());
+        }
+    }
+    branches
+}
+
+/// Splits a target branch setting into the branch the run starts with and
+/// the targets to fan out to.
+///
+/// A single branch does not fan out, so its target list is empty.
+pub fn resolve_target_branches(value: &str) -> (String, Vec<String>) {
+    let branches = split_target_branches(value);
+    let primary = branches.first().cloned().unwrap_or_default();
+    let fan_out = if branches.len() > 1 {
+        branches
+    } else {
+        Vec::new()
+    };
+    (primary, fan_out)
+}
+
+/// Returns the version the worktree of `target_branch` is named after in a
+/// fan-out, e.g. `1.2.0-release-6.6` for `next-1.2.0-release-6.6`.
+///
+/// Targets share the version, so the branch is added to keep their
+/// worktrees apart.
+pub fn worktree_version(version: &str, target_branch: &str) -> String {
+    let target: String = target_branch
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("{}-{}", version, target)
+}
+
+/// Outcome of the cherry-picks into one target branch of a fan-out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FanOutTargetResult {
+    /// Target branch the PRs were picked into.
+    pub target_branch: String,
+    /// Branch holding the picked commits.
+    pub patch_branch: String,
+    /// Number of PRs picked.
+    pub picked: usize,
+    /// Number of PRs that failed.
+    pub failed: usize,
+    /// Number of PRs skipped.
+    pub skipped: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// # Target Branch Lists
+    ///
+    /// Tests splitting the target branch setting.
+    ///
+    /// ## Test Scenario
+    /// - Resolves a single branch, a list with blanks and repeats, and an
+    ///   empty value
+    ///
+    /// ## Expected Outcome
+    /// - A single branch does not fan out
+    /// - A list starts with its first branch and fans out to every distinct
+    ///   branch in order
+    #[test]
+    fn test_resolve_target_branches() {
+        assert_eq!(
+            resolve_target_branches("release/6.6"),
+            ("release/6.6".to_string(), Vec::new())
+        );
+        assert_eq!(
+            resolve_target_branches(" release/6.6, ,release/6.7,release/6.6 "),
+            (
+                "release/6.6".to_string(),
+                vec!["release/6.6".to_string(), "release/6.7".to_string()]
+            )
+        );
+        assert_eq!(
+            resolve_target_branches("release/6.6,release/6.6"),
+            ("release/6.6".to_string(), Vec::new())
+        );
+        assert_eq!(resolve_target_branches(""), (String::new(), Vec::new()));
+    }
+
+    /// # Fan-out Worktree Names
+    ///
+    /// Tests the version naming the worktree of a fan-out target.
+    ///
+    /// ## Test Scenario
+    /// - Names the worktrees of two release branches of the same version
+    ///
+    /// ## Expected Outcome
+    /// - Each target gets a distinct name without path separators
+    #[test]
+    fn test_worktree_version() {
+        assert_eq!(
+            worktree_version("1.2.0", "release/6.6"),
+            "1.2.0-release-6.6"
+        );
+        assert_eq!(worktree_version("1.2.0", "hotfix"), "1.2.0-hotfix");
+        assert_ne!(
+            worktree_version("1.2.0", "release/6.6"),
+            worktree_version("1.2.0", "release/6.7")
+        );
+    }
+}
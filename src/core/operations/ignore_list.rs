@@ -0,0 +1,328 @@
+//! Skip list for PRs that must never be merged to the target branch.
+//!
+//! Some PRs (experimental features, dev-only tooling) should never reach the
+//! release branch. Instead of deselecting them on every run, they can be
+//! listed in a `.mergers-ignore` file at the repository root. Data loading
+//! removes matching PRs from the selectable list; the TUI can still show them
+//! greyed out together with the reason they were excluded.
+//!
+//! # File Format
+//!
+//! One rule per line. Blank lines and lines starting with `#` are ignored.
+//! A rule is either a PR id (optionally prefixed with `!`) or a regular
+//! expression matched against the PR title. An optional reason can follow
+//! the rule, separated by ` # `:
+//!
+//! ```text
+//! # Experimental features stay on dev
+//! 1234 # experimental search
+//! !1240
+//! ^\[experimental\] # experimental feature
+//! (?i)do not merge
+//! ```
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::core::operations::IgnoreList;
+//!
+//! let list = IgnoreList::parse("1234 # experimental\n^WIP").unwrap();
+//! assert_eq!(list.reason_for(1234, "Add search"), Some("experimental".to_string()));
+//! assert!(list.reason_for(99, "WIP: refactor").is_some());
+//! assert!(list.reason_for(99, "Fix login").is_none());
+//! ```
+
+use crate::models::PullRequest;
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::path::Path;
+
+/// Name of the skip-list file looked up at the repository root.
+pub const IGNORE_FILE_NAME: &str = ".mergers-ignore";
+
+/// What a single skip-list rule matches.
+#[derive(Debug, Clone)]
+pub enum IgnorePattern {
+    /// Matches a PR by id.
+    PrId(i32),
+    /// Matches PRs whose title matches the regular expression.
+    Title(Regex),
+}
+
+/// A single rule from the skip-list file.
+#[derive(Debug, Clone)]
+pub struct IgnoreRule {
+    /// The pattern PRs are matched against.
+    pub pattern: IgnorePattern,
+    /// Reason given in the file, if any.
+    pub reason: Option<String>,
+}
+
+impl IgnoreRule {
+    /// Returns `true` if the rule matches the given PR.
+    pub fn matches(&self, pr_id: i32, title: &str) -> bool {
+        match &self.pattern {
+            IgnorePattern::PrId(id) => *id == pr_id,
+            IgnorePattern::Title(regex) => regex.is_match(title),
+        }
+    }
+
+    /// Reason shown for PRs excluded by this rule.
+    ///
+    /// Falls back to a description of the pattern when the file gives no reason.
+    pub fn display_reason(&self) -> String {
+        match (&self.reason, &self.pattern) {
+            (Some(reason), _) => reason.clone(),
+            (None, IgnorePattern::PrId(_)) => "listed in .mergers-ignore".to_string(),
+            (None, IgnorePattern::Title(regex)) => format!("title matches /{}/", regex.as_str()),
+        }
+    }
+}
+
+/// A PR removed from the selectable list by the skip list.
+#[derive(Debug, Clone)]
+pub struct IgnoredPullRequest {
+    /// The excluded PR.
+    pub pr: PullRequest,
+    /// Why the PR was excluded.
+    pub reason: String,
+}
+
+/// Parsed contents of a `.mergers-ignore` file.
+#[derive(Debug, Clone, Default)]
+pub struct IgnoreList {
+    rules: Vec<IgnoreRule>,
+}
+
+impl IgnoreList {
+    /// Parses skip-list file contents.
+    ///
+    /// Returns an error naming the offending line if a title pattern is not a
+    /// valid regular expression.
+    pub fn parse(contents: &str) -> Result<Self> {
+        let mut rules = Vec::new();
+
+        for (index, raw_line) in contents.lines().enumerate() {
+            let line = raw_line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (pattern, reason) = match line.split_once(" # ") {
+                Some((pattern, reason)) => (pattern.trim(), Some(reason.trim())),
+                None => (line, None),
+            };
+            let reason = reason.filter(|r| !r.is_empty()).map(String::from);
+
+            let id_text = pattern.strip_prefix('!').unwrap_or(pattern);
+            let pattern = match id_text.parse::<i32>() {
+                Ok(id) => IgnorePattern::PrId(id),
+                Err(_) => IgnorePattern::Title(Regex::new(pattern).with_context(|| {
+                    format!(
+                        "Invalid title pattern on line {} of {}: {}",
+                        index + 1,
+                        IGNORE_FILE_NAME,
+                        pattern
+                    )
+                })?),
+            };
+
+            rules.push(IgnoreRule { pattern, reason });
+        }
+
+        Ok(Self { rules })
+    }
+
+    /// Loads the skip list from `<repo_root>/.mergers-ignore`.
+    ///
+    /// Returns `Ok(None)` if the file does not exist.
+    pub fn load(repo_root: &Path) -> Result<Option<Self>> {
+        let path = repo_root.join(IGNORE_FILE_NAME);
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&contents).map(Some)
+    }
+
+    /// Returns all parsed rules in file order.
+    pub fn rules(&self) -> &[IgnoreRule] {
+        &self.rules
+    }
+
+    /// Returns `true` if the list has no rules.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Returns the exclusion reason for a PR, or `None` if no rule matches.
+    ///
+    /// The first matching rule wins.
+    pub fn reason_for(&self, pr_id: i32, title: &str) -> Option<String> {
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(pr_id, title))
+            .map(IgnoreRule::display_reason)
+    }
+
+    /// Splits PRs into those that remain selectable and those excluded by the list.
+    ///
+    /// The relative order of both partitions is preserved.
+    pub fn partition(&self, prs: Vec<PullRequest>) -> (Vec<PullRequest>, Vec<IgnoredPullRequest>) {
+        let mut kept = Vec::with_capacity(prs.len());
+        let mut ignored = Vec::new();
+
+        for pr in prs {
+            match self.reason_for(pr.id, &pr.title) {
+                Some(reason) => ignored.push(IgnoredPullRequest { pr, reason }),
+                None => kept.push(pr),
+            }
+        }
+
+        (kept, ignored)
+    }
+}
+
+/// Applies the skip list found in `repo_root` (if any) to a list of PRs.
+///
+/// Without a `.mergers-ignore` file all PRs are kept.
+pub fn apply_ignore_file(
+    repo_root: &Path,
+    prs: Vec<PullRequest>,
+) -> Result<(Vec<PullRequest>, Vec<IgnoredPullRequest>)> {
+    match IgnoreList::load(repo_root)? {
+        Some(list) => {
+            let (kept, ignored) = list.partition(prs);
+            if !ignored.is_empty() {
+                tracing::info!(
+                    "Excluded {} pull request(s) listed in {}",
+                    ignored.len(),
+                    IGNORE_FILE_NAME
+                );
+            }
+            Ok((kept, ignored))
+        }
+        None => Ok((prs, Vec::new())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CreatedBy;
+    use tempfile::TempDir;
+
+    fn pr(id: i32, title: &str) -> PullRequest {
+        PullRequest {
+            id,
+            title: title.to_string(),
+            description: None,
+            closed_date: None,
+            created_by: CreatedBy {
+                display_name: "Test User".to_string(),
+            },
+            last_merge_commit: None,
+            labels: None,
+            reviewers: Vec::new(),
+        }
+    }
+
+    /// # Parse Skip List
+    ///
+    /// Tests parsing ids, prefixed ids, title patterns, comments and reasons.
+    ///
+    /// ## Test Scenario
+    /// - Parses a file with every supported line form
+    ///
+    /// ## Expected Outcome
+    /// - Comments and blank lines are skipped
+    /// - Ids and regexes are recognized and reasons are attached
+    #[test]
+    fn test_parse_ignore_list() {
+        let list = IgnoreList::parse(
+            "# header comment\n\n1234 # experimental search\n!1240\n^\\[experimental\\] # feature flag\n(?i)do not merge\n",
+        )
+        .unwrap();
+
+        assert_eq!(list.rules().len(), 4);
+        assert!(matches!(list.rules()[0].pattern, IgnorePattern::PrId(1234)));
+        assert_eq!(
+            list.rules()[0].reason.as_deref(),
+            Some("experimental search")
+        );
+        assert!(matches!(list.rules()[1].pattern, IgnorePattern::PrId(1240)));
+        assert!(list.rules()[1].reason.is_none());
+        assert!(matches!(list.rules()[2].pattern, IgnorePattern::Title(_)));
+        assert_eq!(list.rules()[2].reason.as_deref(), Some("feature flag"));
+    }
+
+    /// # Invalid Title Pattern
+    ///
+    /// Tests that an invalid regex is reported with its line number.
+    ///
+    /// ## Test Scenario
+    /// - Parses a file whose second rule is an unbalanced regex
+    ///
+    /// ## Expected Outcome
+    /// - Parsing fails and the error names line 2
+    #[test]
+    fn test_parse_invalid_pattern() {
+        let err = IgnoreList::parse("1\n[unclosed").unwrap_err();
+        assert!(err.to_string().contains("line 2"));
+    }
+
+    /// # Partition PRs
+    ///
+    /// Tests splitting PRs into kept and ignored lists with reasons.
+    ///
+    /// ## Test Scenario
+    /// - Partitions PRs matched by id, by title, and not matched at all
+    ///
+    /// ## Expected Outcome
+    /// - Unmatched PRs are kept in order
+    /// - Ignored PRs carry the file reason or a generated one
+    #[test]
+    fn test_partition() {
+        let list = IgnoreList::parse("2 # experimental\n^\\[WIP\\]").unwrap();
+        let (kept, ignored) = list.partition(vec![
+            pr(1, "Fix login"),
+            pr(2, "Add search"),
+            pr(3, "[WIP] Refactor"),
+            pr(4, "Update docs"),
+        ]);
+
+        assert_eq!(kept.iter().map(|p| p.id).collect::<Vec<_>>(), vec![1, 4]);
+        assert_eq!(ignored.len(), 2);
+        assert_eq!(ignored[0].pr.id, 2);
+        assert_eq!(ignored[0].reason, "experimental");
+        assert_eq!(ignored[1].pr.id, 3);
+        assert_eq!(ignored[1].reason, "title matches /^\\[WIP\\]/");
+    }
+
+    /// # Apply Ignore File
+    ///
+    /// Tests loading the skip list from a repository root.
+    ///
+    /// ## Test Scenario
+    /// - Applies the file from a directory without and with `.mergers-ignore`
+    ///
+    /// ## Expected Outcome
+    /// - Without the file all PRs are kept
+    /// - With the file listed PRs are excluded
+    #[test]
+    fn test_apply_ignore_file() {
+        let dir = TempDir::new().unwrap();
+
+        let (kept, ignored) = apply_ignore_file(dir.path(), vec![pr(1, "A"), pr(2, "B")]).unwrap();
+        assert_eq!(kept.len(), 2);
+        assert!(ignored.is_empty());
+
+        std::fs::write(dir.path().join(IGNORE_FILE_NAME), "2\n").unwrap();
+        let (kept, ignored) = apply_ignore_file(dir.path(), vec![pr(1, "A"), pr(2, "B")]).unwrap();
+        assert_eq!(kept.len(), 1);
+        assert_eq!(ignored[0].pr.id, 2);
+        assert_eq!(ignored[0].reason, "listed in .mergers-ignore");
+    }
+}
@@ -0,0 +1,301 @@
+//! What-if planner for the order in which PRs are cherry-picked.
+//!
+//! The same set of PRs can conflict more or less depending on the order they
+//! are applied in. The planner builds a few candidate orderings (by closing
+//! date, topological by dependency, and by conflict risk), simulates each one
+//! against the target branch with `git merge-tree` (see
+//! [`git::simulate_cherry_pick`]), and recommends the ordering with the fewest
+//! predicted conflicts. Nothing is checked out and no branch is changed.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::core::operations::{DependencyCategory, PRDependencyGraph};
+use crate::git::{self, SimulatedCherryPick};
+use crate::models::PullRequestWithWorkItems;
+
+/// A strategy for ordering the PRs of a merge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanOrdering {
+    /// Oldest closed PR first, the order the merge workflow uses.
+    ClosingDate,
+    /// Dependencies before the PRs that build on them.
+    Topological,
+    /// PRs overlapping the fewest other candidates first.
+    Risk,
+}
+
+impl fmt::Display for PlanOrdering {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PlanOrdering::ClosingDate => "closing date",
+            PlanOrdering::Topological => "topological",
+            PlanOrdering::Risk => "risk",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A conflict predicted while simulating an ordering.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PredictedConflict {
+    /// The PR whose cherry-pick conflicts.
+    pub pr_id: i32,
+    /// Conflicted file paths.
+    pub files: Vec<String>,
+}
+
+/// The simulated outcome of one ordering.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OrderingSimulation {
+    /// The ordering strategy.
+    pub ordering: PlanOrdering,
+    /// PR IDs in cherry-pick order.
+    pub pr_ids: Vec<i32>,
+    /// Predicted conflicts, in cherry-pick order.
+    ///
+    /// A conflicting PR is assumed to be skipped, so later PRs are simulated
+    /// without its changes.
+    pub conflicts: Vec<PredictedConflict>,
+}
+
+/// Simulated orderings and the recommended one.
+#[derive(Debug, Clone, Serialize)]
+pub struct MergePlan {
+    /// The ref the simulations started from.
+    pub target: String,
+    /// One simulation per ordering strategy.
+    pub simulations: Vec<OrderingSimulation>,
+    /// The ordering with the fewest predicted conflicts.
+    pub recommended: PlanOrdering,
+    /// PRs left out of the simulation because they have no merge commit.
+    pub skipped_pr_ids: Vec<i32>,
+}
+
+impl MergePlan {
+    /// Returns the simulation of the recommended ordering.
+    pub fn recommended_simulation(&self) -> &OrderingSimulation {
+        self.simulations
+            .iter()
+            .find(|sim| sim.ordering == self.recommended)
+            .expect("recommended ordering is always simulated")
+    }
+}
+
+/// Builds the candidate orderings for `prs`.
+///
+/// PRs missing from the dependency graph's topological order (e.g. in a
+/// dependency cycle) are appended to the topological ordering by closing date.
+pub fn candidate_orderings(
+    prs: &[PullRequestWithWorkItems],
+    graph: &PRDependencyGraph,
+) -> Vec<(PlanOrdering, Vec<i32>)> {
+    let mut by_date: Vec<&PullRequestWithWorkItems> = prs.iter().collect();
+    // Missing dates sort last; RFC 3339 timestamps compare chronologically
+    by_date.sort_by_key(|pr| {
+        (
+            pr.pr.closed_date.is_none(),
+            pr.pr.closed_date.clone(),
+            pr.pr.id,
+        )
+    });
+    let closing_date: Vec<i32> = by_date.iter().map(|pr| pr.pr.id).collect();
+
+    let candidates: HashSet<i32> = closing_date.iter().copied().collect();
+    let mut topological: Vec<i32> = graph
+        .topological_order
+        .iter()
+        .copied()
+        .filter(|id| candidates.contains(id))
+        .collect();
+    let placed: HashSet<i32> = topological.iter().copied().collect();
+    topological.extend(closing_date.iter().filter(|id| !placed.contains(id)));
+
+    let scores = risk_scores(graph, &candidates);
+    let date_rank: HashMap<i32, usize> = closing_date
+        .iter()
+        .enumerate()
+        .map(|(rank, id)| (*id, rank))
+        .collect();
+    let mut risk = closing_date.clone();
+    risk.sort_by_key(|id| (scores.get(id).copied().unwrap_or(0), date_rank[id]));
+
+    vec![
+        (PlanOrdering::ClosingDate, closing_date),
+        (PlanOrdering::Topological, topological),
+        (PlanOrdering::Risk, risk),
+    ]
+}
+
+/// Scores each candidate by its overlap with other candidates.
+///
+/// Overlapping lines count twice as much as shared files.
+fn risk_scores(graph: &PRDependencyGraph, candidates: &HashSet<i32>) -> HashMap<i32, usize> {
+    let mut scores: HashMap<i32, usize> = HashMap::new();
+    for node in graph.nodes.values() {
+        for dep in &node.dependencies {
+            if !candidates.contains(&dep.from_pr_id) || !candidates.contains(&dep.to_pr_id) {
+                continue;
+            }
+            let weight = match dep.category {
+                DependencyCategory::Independent => 0,
+                DependencyCategory::PartiallyDependent { .. } => 1,
+                DependencyCategory::Dependent { .. } => 2,
+            };
+            *scores.entry(dep.from_pr_id).or_default() += weight;
+            *scores.entry(dep.to_pr_id).or_default() += weight;
+        }
+    }
+    scores
+}
+
+/// Simulates cherry-picking `commits` in order onto `start_tree`.
+fn simulate_ordering(
+    repo_path: &Path,
+    start_tree: &str,
+    ordering: PlanOrdering,
+    pr_ids: Vec<i32>,
+    commits: &HashMap<i32, String>,
+) -> Result<OrderingSimulation> {
+    let mut tree = start_tree.to_string();
+    let mut conflicts = Vec::new();
+    for pr_id in &pr_ids {
+        match git::simulate_cherry_pick(repo_path, &tree, &commits[pr_id])? {
+            SimulatedCherryPick::Clean { tree: next } => tree = next,
+            SimulatedCherryPick::Conflict { files } => conflicts.push(PredictedConflict {
+                pr_id: *pr_id,
+                files,
+            }),
+        }
+    }
+    Ok(OrderingSimulation {
+        ordering,
+        pr_ids,
+        conflicts,
+    })
+}
+
+/// Simulates every candidate ordering of `prs` onto `target` and recommends
+/// the one with the fewest predicted conflicts.
+///
+/// Ties go to the earlier strategy in [`candidate_orderings`], so the
+/// closing-date order is kept unless another order is strictly better.
+pub fn plan_merge(
+    repo_path: &Path,
+    target: &str,
+    prs: &[PullRequestWithWorkItems],
+    graph: &PRDependencyGraph,
+) -> Result<MergePlan> {
+    let start_tree = git::tree_id(repo_path, target)?;
+
+    let commits: HashMap<i32, String> = prs
+        .iter()
+        .filter_map(|pr| {
+            let commit = pr.pr.last_merge_commit.as_ref()?;
+            Some((pr.pr.id, commit.commit_id.clone()))
+        })
+        .collect();
+    let (plannable, skipped): (Vec<_>, Vec<_>) = prs
+        .iter()
+        .cloned()
+        .partition(|pr| commits.contains_key(&pr.pr.id));
+
+    let simulations = candidate_orderings(&plannable, graph)
+        .into_iter()
+        .map(|(ordering, pr_ids)| {
+            simulate_ordering(repo_path, &start_tree, ordering, pr_ids, &commits)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // `min_by_key` keeps the first of equally good orderings
+    let recommended = simulations
+        .iter()
+        .min_by_key(|sim| sim.conflicts.len())
+        .map(|sim| sim.ordering)
+        .unwrap_or(PlanOrdering::ClosingDate);
+
+    Ok(MergePlan {
+        target: target.to_string(),
+        simulations,
+        recommended,
+        skipped_pr_ids: skipped.iter().map(|pr| pr.pr.id).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::operations::{PRDependency, PRDependencyNode};
+    use crate::models::{CreatedBy, PullRequest};
+
+    fn pr(id: i32, closed_date: &str) -> PullRequestWithWorkItems {
+        PullRequestWithWorkItems {
+            pr: PullRequest {
+                id,
+                title: format!("PR {}", id),
+                closed_date: Some(closed_date.to_string()),
+                created_by: CreatedBy {
+                    display_name: "Dev".to_string(),
+                },
+                last_merge_commit: None,
+                labels: None,
+                description: None,
+                reviewers: Vec::new(),
+            },
+            work_items: Vec::new(),
+            selected: true,
+        }
+    }
+
+    /// # Candidate Orderings
+    ///
+    /// Tests the orderings built for the planner.
+    ///
+    /// ## Test Scenario
+    /// - Three PRs closed in order 1, 2, 3
+    /// - PR 1 depends on PR 3 (overlapping lines); PR 2 is independent
+    ///
+    /// ## Expected Outcome
+    /// - Closing date keeps the closing order
+    /// - Topological puts PR 3 before PR 1
+    /// - Risk puts the independent PR first
+    #[test]
+    fn test_candidate_orderings() {
+        let prs = vec![
+            pr(3, "2024-01-03T00:00:00Z"),
+            pr(1, "2024-01-01T00:00:00Z"),
+            pr(2, "2024-01-02T00:00:00Z"),
+        ];
+
+        let mut graph = PRDependencyGraph::new();
+        let mut first = PRDependencyNode::new(1, "PR 1".to_string(), true);
+        first.dependencies.push(PRDependency {
+            from_pr_id: 1,
+            to_pr_id: 3,
+            category: DependencyCategory::Dependent {
+                shared_files: vec!["src/lib.rs".to_string()],
+                overlapping_files: Vec::new(),
+            },
+        });
+        let mut third = PRDependencyNode::new(3, "PR 3".to_string(), true);
+        third.dependents.push(1);
+        graph.add_node(first);
+        graph.add_node(PRDependencyNode::new(2, "PR 2".to_string(), true));
+        graph.add_node(third);
+        graph.compute_topological_order();
+
+        let orderings: HashMap<PlanOrdering, Vec<i32>> =
+            candidate_orderings(&prs, &graph).into_iter().collect();
+
+        assert_eq!(orderings[&PlanOrdering::ClosingDate], vec![1, 2, 3]);
+        let topological = &orderings[&PlanOrdering::Topological];
+        let pos = |id| topological.iter().position(|x| *x == id).unwrap();
+        assert!(pos(3) < pos(1));
+        assert_eq!(orderings[&PlanOrdering::Risk], vec![2, 1, 3]);
+    }
+}
@@ -0,0 +1,429 @@
+//! Chunked, resumable tagging of migration PRs.
+//!
+//! Migration mode labels every eligible PR with `{tag_prefix}{version}`. With
+//! thousands of PRs a single pass easily hits Azure DevOps rate limits, and a
+//! failure midway used to lose all progress. Here the PRs are tagged in
+//! chunks whose size adapts to throttling, and every result is recorded in a
+//! [`TaggingProgress`] file in the state directory so an interrupted run can
+//! skip the PRs that are already tagged.
+//!
+//! Throttled requests are retried by the API client. A PR that is still
+//! throttled afterwards is recorded as failed, and the next chunk is smaller
+//! and starts after a pause.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::core::operations::migration_tagging::{ChunkPlanner, TaggingProgress};
+//!
+//! let mut progress = TaggingProgress::new("merged-1.0.0");
+//! progress.record(1, None);
+//! progress.record(2, Some("Permission denied".to_string()));
+//! assert!(progress.is_tagged(1));
+//! assert_eq!(progress.failed().len(), 1);
+//!
+//! // A throttled chunk halves the chunk size; clean chunks grow it again
+//! let mut planner = ChunkPlanner::new(20);
+//! planner.on_throttled();
+//! assert_eq!(planner.size(), 10);
+//! planner.on_chunk_done();
+//! assert_eq!(planner.size(), 15);
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::api::{AzureDevOpsClient, is_rate_limited};
+use crate::core::state::state_dir;
+
+/// Largest chunk tagged at once.
+pub const MAX_CHUNK_SIZE: usize = 50;
+
+/// Tagging results recorded so far for one tag.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaggingProgress {
+    /// The label being applied.
+    pub tag_name: String,
+    /// PRs the label was applied to.
+    #[serde(default)]
+    tagged: BTreeSet<i32>,
+    /// PRs whose last attempt failed, with the error message.
+    #[serde(default)]
+    failed: BTreeMap<i32, String>,
+}
+
+impl TaggingProgress {
+    /// Creates empty progress for `tag_name`.
+    pub fn new(tag_name: impl Into<String>) -> Self {
+        Self {
+            tag_name: tag_name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the progress file for a tag in a repository.
+    ///
+    /// The file lives in the state directory (see [`state_dir`]) and is
+    /// keyed by a hash of the organization, project, repository and tag.
+    pub fn path_for(
+        organization: &str,
+        project: &str,
+        repository: &str,
+        tag_name: &str,
+    ) -> Result<PathBuf> {
+        let mut hasher = Sha256::new();
+        hasher.update(format!(
+            "{}/{}/{}/{}",
+            organization, project, repository, tag_name
+        ));
+        let hash: String = hasher.finalize()[..8]
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        Ok(state_dir()?.join(format!("tagging-{}.json", hash)))
+    }
+
+    /// Loads progress, returning empty progress if the file does not exist.
+    pub fn load(path: &Path, tag_name: &str) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::new(tag_name));
+        }
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read tagging progress: {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse tagging progress: {}", path.display()))
+    }
+
+    /// Saves progress, creating the parent directory if needed.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!(
+                    "Failed to create tagging progress directory: {}",
+                    parent.display()
+                )
+            })?;
+        }
+        let contents = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, contents)
+            .with_context(|| format!("Failed to write tagging progress: {}", path.display()))
+    }
+
+    /// Records the outcome of tagging one PR; `error` is `None` on success.
+    pub fn record(&mut self, pr_id: i32, error: Option<String>) {
+        match error {
+            None => {
+                self.failed.remove(&pr_id);
+                self.tagged.insert(pr_id);
+            }
+            Some(error) => {
+                self.failed.insert(pr_id, error);
+            }
+        }
+    }
+
+    /// Returns `true` if the label was already applied to the PR.
+    pub fn is_tagged(&self, pr_id: i32) -> bool {
+        self.tagged.contains(&pr_id)
+    }
+
+    /// PRs whose last attempt failed, with the error message.
+    pub fn failed(&self) -> &BTreeMap<i32, String> {
+        &self.failed
+    }
+}
+
+/// Adapts the chunk size and the pause between chunks to API throttling.
+///
+/// A throttled chunk halves the size and doubles the pause; each chunk that
+/// completes without throttling grows the size by half again, up to the
+/// maximum, and resets the pause.
+#[derive(Debug, Clone)]
+pub struct ChunkPlanner {
+    size: usize,
+    max_size: usize,
+    backoff: Duration,
+    min_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl ChunkPlanner {
+    /// Creates a planner starting at `max_size` with a 2-60s pause.
+    pub fn new(max_size: usize) -> Self {
+        Self::with_backoff(max_size, Duration::from_secs(2), Duration::from_secs(60))
+    }
+
+    /// Creates a planner with custom pause bounds.
+    pub fn with_backoff(max_size: usize, min_backoff: Duration, max_backoff: Duration) -> Self {
+        let max_size = max_size.max(1);
+        Self {
+            size: max_size,
+            max_size,
+            backoff: min_backoff,
+            min_backoff,
+            max_backoff,
+        }
+    }
+
+    /// Number of PRs to tag in the next chunk.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Shrinks the chunk after throttling; returns how long to wait.
+    pub fn on_throttled(&mut self) -> Duration {
+        self.size = (self.size / 2).max(1);
+        let wait = self.backoff;
+        self.backoff = (self.backoff * 2).min(self.max_backoff);
+        wait
+    }
+
+    /// Grows the chunk after one completed without throttling.
+    pub fn on_chunk_done(&mut self) {
+        self.size = (self.size + self.size.div_ceil(2)).min(self.max_size);
+        self.backoff = self.min_backoff;
+    }
+}
+
+/// A PR to tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaggingTarget {
+    /// The PR ID.
+    pub pr_id: i32,
+    /// The PR title, for error reports.
+    pub title: String,
+}
+
+/// Progress reported while tagging.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaggingUpdate {
+    /// A chunk of `size` PRs is being tagged.
+    ChunkStarted { size: usize },
+    /// The API throttled the last chunk; the next starts after `wait`.
+    Throttled { wait: Duration },
+    /// One PR finished; `error` is `None` on success.
+    Tagged {
+        target: TaggingTarget,
+        error: Option<String>,
+    },
+}
+
+/// Labels PRs in throttling-aware chunks.
+///
+/// `on_update` is called for every chunk, every throttling pause and every
+/// finished PR.
+pub async fn tag_prs<F>(
+    client: &AzureDevOpsClient,
+    tag_name: &str,
+    targets: Vec<TaggingTarget>,
+    planner: ChunkPlanner,
+    on_update: F,
+) where
+    F: FnMut(TaggingUpdate),
+{
+    tag_prs_with(
+        targets,
+        planner,
+        |pr_id| client.add_label_to_pr(pr_id, tag_name),
+        on_update,
+    )
+    .await;
+}
+
+/// Runs [`tag_prs`] with a custom tagging operation.
+pub async fn tag_prs_with<T, Fut, F>(
+    targets: Vec<TaggingTarget>,
+    mut planner: ChunkPlanner,
+    tag: T,
+    mut on_update: F,
+) where
+    T: Fn(i32) -> Fut,
+    Fut: Future<Output = Result<()>>,
+    F: FnMut(TaggingUpdate),
+{
+    let mut queue: VecDeque<TaggingTarget> = targets.into();
+
+    while !queue.is_empty() {
+        let size = planner.size().min(queue.len());
+        on_update(TaggingUpdate::ChunkStarted { size });
+        let chunk: Vec<TaggingTarget> = queue.drain(..size).collect();
+
+        let outcomes: Vec<(TaggingTarget, Result<()>)> = stream::iter(chunk)
+            .map(|target| {
+                let request = tag(target.pr_id);
+                async move { (target, request.await) }
+            })
+            .buffer_unordered(size)
+            .collect()
+            .await;
+
+        // The client already retried throttled requests, so they fail here
+        let mut throttled = false;
+        for (target, outcome) in outcomes {
+            let error = outcome.err().map(|e| {
+                throttled |= is_rate_limited(&e);
+                format!("{:#}", e)
+            });
+            on_update(TaggingUpdate::Tagged { target, error });
+        }
+
+        if throttled {
+            let wait = planner.on_throttled();
+            on_update(TaggingUpdate::Throttled { wait });
+            tokio::time::sleep(wait).await;
+        } else {
+            planner.on_chunk_done();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use tempfile::TempDir;
+
+    fn target(pr_id: i32) -> TaggingTarget {
+        TaggingTarget {
+            pr_id,
+            title: format!("PR {}", pr_id),
+        }
+    }
+
+    /// # Tagging Progress Persistence
+    ///
+    /// Tests saving and loading tagging progress.
+    ///
+    /// ## Test Scenario
+    /// - Loads progress from a missing file
+    /// - Records a failure, then a success for the same PR, and saves
+    /// - Loads the file again
+    ///
+    /// ## Expected Outcome
+    /// - A missing file gives empty progress for the tag
+    /// - A later success clears the failure
+    /// - The loaded progress equals the saved one
+    #[test]
+    fn test_tagging_progress_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("nested").join("tagging.json");
+
+        let mut progress = TaggingProgress::load(&path, "merged-1.0.0").unwrap();
+        assert_eq!(progress, TaggingProgress::new("merged-1.0.0"));
+
+        progress.record(7, Some("timeout".to_string()));
+        progress.record(8, Some("denied".to_string()));
+        progress.record(7, None);
+        progress.save(&path).unwrap();
+
+        let loaded = TaggingProgress::load(&path, "merged-1.0.0").unwrap();
+        assert_eq!(loaded, progress);
+        assert!(loaded.is_tagged(7));
+        assert!(!loaded.is_tagged(8));
+        assert_eq!(loaded.failed().keys().copied().collect::<Vec<_>>(), vec![8]);
+    }
+
+    /// # Chunk Planner
+    ///
+    /// Tests chunk size and delay adaptation.
+    ///
+    /// ## Test Scenario
+    /// - Throttles a planner with max size 8 three times, then completes
+    ///   chunks until it is back at full size
+    ///
+    /// ## Expected Outcome
+    /// - Size halves down to 1 and the delay doubles up to its cap
+    /// - Clean chunks grow the size back to the maximum and reset the delay
+    #[test]
+    fn test_chunk_planner() {
+        let mut planner =
+            ChunkPlanner::with_backoff(8, Duration::from_secs(1), Duration::from_secs(3));
+        assert_eq!(planner.on_throttled(), Duration::from_secs(1));
+        assert_eq!(planner.on_throttled(), Duration::from_secs(2));
+        assert_eq!(planner.on_throttled(), Duration::from_secs(3));
+        assert_eq!(planner.on_throttled(), Duration::from_secs(3));
+        assert_eq!(planner.size(), 1);
+
+        let mut sizes = Vec::new();
+        while planner.size() < 8 {
+            planner.on_chunk_done();
+            sizes.push(planner.size());
+        }
+        assert_eq!(sizes, vec![2, 3, 5, 8]);
+        assert_eq!(planner.on_throttled(), Duration::from_secs(1));
+    }
+
+    /// # Throttling-Aware Tagging
+    ///
+    /// Tests that throttling shrinks the following chunks without retrying.
+    ///
+    /// ## Test Scenario
+    /// - Tags 6 PRs with a max chunk of 4
+    /// - PR 2 is throttled and PR 4 fails otherwise
+    ///
+    /// ## Expected Outcome
+    /// - Chunks are 4, then 2 after throttling
+    /// - PRs 2 and 4 are reported with their errors
+    /// - Every PR is requested once and gets exactly one result
+    #[tokio::test]
+    async fn test_tag_prs_throttled() {
+        let requested = Mutex::new(Vec::new());
+        let updates = Mutex::new(Vec::new());
+
+        tag_prs_with(
+            (1..=6).map(target).collect(),
+            ChunkPlanner::with_backoff(4, Duration::ZERO, Duration::ZERO),
+            |pr_id| {
+                requested.lock().unwrap().push(pr_id);
+                let result = match pr_id {
+                    2 => Err(anyhow::anyhow!("429 Too Many Requests")),
+                    4 => Err(anyhow::anyhow!("Permission denied")),
+                    _ => Ok(()),
+                };
+                async move { result }
+            },
+            |update| updates.lock().unwrap().push(update),
+        )
+        .await;
+
+        let updates = updates.into_inner().unwrap();
+        let chunks: Vec<usize> = updates
+            .iter()
+            .filter_map(|u| match u {
+                TaggingUpdate::ChunkStarted { size } => Some(*size),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(chunks, vec![4, 2]);
+
+        let mut results: Vec<(i32, Option<String>)> = updates
+            .into_iter()
+            .filter_map(|u| match u {
+                TaggingUpdate::Tagged { target, error } => Some((target.pr_id, error)),
+                _ => None,
+            })
+            .collect();
+        results.sort();
+        assert_eq!(
+            results,
+            vec![
+                (1, None),
+                (2, Some("429 Too Many Requests".to_string())),
+                (3, None),
+                (4, Some("Permission denied".to_string())),
+                (5, None),
+                (6, None),
+            ]
+        );
+        let mut requested = requested.into_inner().unwrap();
+        requested.sort();
+        assert_eq!(requested, (1..=6).collect::<Vec<_>>());
+    }
+}
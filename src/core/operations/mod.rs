@@ -6,26 +6,93 @@
 //!
 //! # Modules
 //!
+//! - [`build_checks`] - Build and branch policy checks of PRs before they are cherry-picked
+//! - [`cleanup_report`] - Exported outcome of a cleanup run with restorable branch tips
+//! - [`config_diagnostics`] - Suspicious settings reported before anything runs
 //! - [`data_loading`] - Fetching PRs and work items from Azure DevOps
-//! - [`pr_selection`] - Filtering and selecting PRs by work item state
+//! - [`pr_selection`] - Filtering and selecting PRs by work item state, selection totals
 //! - [`work_item_grouping`] - Grouping PRs that share work items
 //! - [`dependency_analysis`] - Analyzing file-level dependencies between PRs
+//! - [`merge_plan`] - What-if planner comparing cherry-pick orderings
+//! - [`dry_run`] - Simulating a whole merge without changing anything
+//! - [`fan_out`] - Cherry-picking the same PRs into several target branches
+//! - [`migration_tagging`] - Chunked, resumable tagging of migration PRs
+//! - [`binary_conflicts`] - Detecting binary file conflicts and resolving them by path policy
 //! - [`cherry_pick`] - Cherry-picking commits with conflict handling
+//! - [`conflict_hunks`] - Three-way hunks of conflicted text files
+//! - [`conflict_report`] - Machine-readable report of a paused cherry-pick's conflicts
+//! - [`conflict_resolution`] - Editor and `git mergetool` driven conflict resolution
+//! - [`conflict_timeout`] - Settling conflicts of unattended merges after a wait
 //! - [`post_merge`] - Tagging PRs and updating work items
+//! - [`release_candidate`] - Release candidate (`-rcN`) versioning for `mergers promote`
+//! - [`release_history`] - Completed merge history and time-to-release metrics
+//! - [`release_gate`] - "Ready for release" gate on a custom work item field
+//! - [`release_labels`] - Grouping and bulk removal of `tag_prefix` release labels
+//! - [`release_tag`] - Annotated `v<version>` git tags created on completion
 //! - [`hooks`] - User-defined shell command hooks for merge workflows
+//! - [`ignore_list`] - `.mergers-ignore` skip list for permanently excluded PRs
+//! - [`pr_filter`] - Narrowing the PR list by author, reviewer and changed paths
+//! - [`pr_tabs`] - User-defined PR grouping tabs for the selection screen
+//! - [`pr_table_layout`] - Saved column widths and sort order of the PR table
+//! - [`revert_detection`] - Flagging PRs reverted on the dev branch and their reverts
+//! - [`recovery`] - Inspecting and resuming merges interrupted by a crash
+//! - [`selection_file`] - Importing a PR selection from a list of PR and work item ids
+//! - [`watch_list`] - Merge candidates accumulated by `mergers watch`
+//! - [`work_item_fields`] - Templated work item field updates applied on completion
 
+pub mod binary_conflicts;
+pub mod build_checks;
 pub mod cherry_pick;
+pub mod cleanup_report;
+pub mod config_diagnostics;
+pub mod conflict_hunks;
+pub mod conflict_report;
+pub mod conflict_resolution;
+pub mod conflict_timeout;
 pub mod data_loading;
 pub mod dependency_analysis;
+pub mod dry_run;
+pub mod fan_out;
 pub mod hooks;
+pub mod ignore_list;
+pub mod merge_plan;
+pub mod migration_tagging;
 pub mod post_merge;
+pub mod pr_filter;
 pub mod pr_selection;
+pub mod pr_table_layout;
+pub mod pr_tabs;
+pub mod recovery;
+pub mod release_candidate;
+pub mod release_gate;
+pub mod release_history;
+pub mod release_labels;
+pub mod release_tag;
+pub mod revert_detection;
+pub mod saved_resolutions;
+pub mod selection_file;
+pub mod watch_list;
+pub mod work_item_fields;
 pub mod work_item_grouping;
 
 // Re-export commonly used types
+pub use binary_conflicts::{
+    BinaryConflictPolicy, BinaryConflictRule, BinaryResolution, ConflictSide, apply_binary_policy,
+    resolve_binary_conflicts,
+};
+pub use build_checks::{BuildCheckIndex, BuildCheckMode, check_pull_requests};
 pub use cherry_pick::{
-    CherryPickConfig, CherryPickOperation, CherryPickOutcome, CherryPickProgress,
+    CherryPickConfig, CherryPickOperation, CherryPickOrder, CherryPickOutcome, CherryPickProgress,
+    CherryPickSettings, DEFAULT_SQUASH_MESSAGE,
+};
+pub use cleanup_report::{CleanupOutcome, CleanupReport, CleanupReportEntry};
+pub use config_diagnostics::{
+    ConfigDiagnostic, ConfigSetting, diagnose_config, diagnose_shared_config,
 };
+pub use conflict_hunks::{ConflictHunk, load_conflict_hunks};
+pub use conflict_report::{ConflictReport, ConflictedFile, ConflictingPr};
+pub use conflict_resolution::{ResolveTool, has_conflict_markers, resolve_file};
+pub use conflict_timeout::{ConflictDecision, ConflictTimeout, ConflictTimeoutAction};
 pub use data_loading::{
     DataLoadingConfig, DataLoadingOperation, DataLoadingProgress, DataLoadingResult,
 };
@@ -34,16 +101,43 @@ pub use dependency_analysis::{
     DependencyCategory, DependencyWarning, FileChange, LineRange, OverlappingFile, PRBitmapIndex,
     PRDependency, PRDependencyGraph, PRDependencyNode, PRInfo,
 };
+pub use dry_run::{SimulatedMerge, clone_for_dry_run, simulate_merge};
+pub use fan_out::{FanOutTargetResult, resolve_target_branches, split_target_branches};
 pub use hooks::{
     HookCommandResult, HookContext, HookExecutionMode, HookExecutor, HookFailureMode, HookOutcome,
     HookProgress, HookResult, HookTrigger, HookTriggerConfig, HooksConfig,
 };
+pub use ignore_list::{
+    IGNORE_FILE_NAME, IgnoreList, IgnorePattern, IgnoreRule, IgnoredPullRequest, apply_ignore_file,
+};
+pub use merge_plan::{MergePlan, OrderingSimulation, PlanOrdering, PredictedConflict, plan_merge};
+pub use migration_tagging::{
+    ChunkPlanner, MAX_CHUNK_SIZE, TaggingProgress, TaggingTarget, TaggingUpdate, tag_prs,
+};
 pub use post_merge::{
     PostMergeConfig, PostMergeOperation, PostMergeProgress, PostMergeTask, PostMergeTaskResult,
+    WorkItemTransition, work_item_transitions,
 };
+pub use pr_filter::PrFilter;
 pub use pr_selection::{
-    filter_prs_by_work_item_states, parse_work_item_states, select_prs_by_work_item_states,
+    ConflictRisk, SelectionCaps, SelectionSummary, all_work_items_in_states,
+    describe_unknown_states, filter_prs_by_work_item_states, find_unknown_work_item_states,
+    parse_work_item_states, select_prs_by_work_item_states, summarize_selection,
+};
+pub use pr_table_layout::{PrColumn, PrTableLayout, SortKey};
+pub use pr_tabs::{PrTab, PrTabConfig, build_pr_tabs};
+pub use recovery::RecoverAction;
+pub use release_gate::ReleaseGate;
+pub use release_labels::{
+    LabelRemoval, LabelRemovalResult, ReleaseLabel, apply_removal_results, group_release_labels,
+    remove_labels, selected_removals,
 };
+pub use release_tag::{ReleaseTag, ReleaseTagMode};
+pub use revert_detection::RevertIndex;
+pub use saved_resolutions::SavedResolutions;
+pub use selection_file::{SelectionFile, SelectionId, SelectionImport};
+pub use watch_list::{WatchCandidate, WatchList};
+pub use work_item_fields::{FieldUpdate, FieldUpdateMode, WorkItemFieldRule};
 pub use work_item_grouping::{
     SelectionWarning, WorkItemPrIndex, check_selection_warning, get_work_item_title,
 };
@@ -1,9 +1,18 @@
 //! Post-merge operations for tagging PRs and updating work items.
 //!
 //! This module provides the core logic for completing a merge by tagging
-//! successful PRs and updating work item states in Azure DevOps.
+//! successful PRs, updating work item states and optionally commenting on
+//! the released work items in Azure DevOps. Configured
+//! [field updates](super::work_item_fields) are written on the released work
+//! items too. When an Azure Artifacts feed is configured, the version of the
+//! package built for the merge is looked up and written to the released work
+//! items' "Integrated in build" field. With a [release tag](super::release_tag)
+//! enabled, the patch branch is tagged `v<version>` last.
 
-use std::sync::Arc;
+use super::release_tag::ReleaseTag;
+use super::work_item_fields::{FieldUpdate, WorkItemFieldRule, apply_field_updates};
+use crate::api::{ArtifactsFeed, INTEGRATION_BUILD_FIELD, PackageVersion};
+use std::sync::{Arc, Mutex};
 
 /// A task to be performed as part of post-merge completion.
 #[derive(Debug, Clone)]
@@ -26,6 +35,44 @@ pub enum PostMergeTask {
         /// The new state to set.
         new_state: String,
     },
+    /// Post a release comment on a work item.
+    CommentWorkItem {
+        /// The work item ID to comment on.
+        work_item_id: i32,
+        /// The work item title (for display).
+        work_item_title: String,
+        /// The rendered comment text.
+        comment: String,
+    },
+    /// Write templated fields on a work item.
+    UpdateWorkItemFields {
+        /// The work item ID to update.
+        work_item_id: i32,
+        /// The work item title (for display).
+        work_item_title: String,
+        /// The rendered field updates, in config order.
+        updates: Vec<FieldUpdate>,
+    },
+    /// Look up the version of the package built for the merge.
+    ResolvePackageVersion {
+        /// The package name.
+        package: String,
+    },
+    /// Write the resolved package version to a work item's
+    /// "Integrated in build" field.
+    StampWorkItemBuild {
+        /// The work item ID to stamp.
+        work_item_id: i32,
+        /// The work item title (for display).
+        work_item_title: String,
+    },
+    /// Create the annotated release tag on the patch branch.
+    CreateReleaseTag {
+        /// The tag name (e.g., "v1.0.0").
+        tag: String,
+        /// Whether the tag is pushed to `origin`.
+        push: bool,
+    },
 }
 
 impl PostMergeTask {
@@ -42,14 +89,59 @@ impl PostMergeTask {
             } => {
                 format!("Update work item #{} to '{}'", work_item_id, new_state)
             }
+            PostMergeTask::CommentWorkItem { work_item_id, .. } => {
+                format!("Comment on work item #{}", work_item_id)
+            }
+            PostMergeTask::UpdateWorkItemFields {
+                work_item_id,
+                updates,
+                ..
+            } => {
+                format!(
+                    "Update {} field(s) of work item #{}",
+                    updates.len(),
+                    work_item_id
+                )
+            }
+            PostMergeTask::ResolvePackageVersion { package } => {
+                format!("Look up the version of package '{}'", package)
+            }
+            PostMergeTask::StampWorkItemBuild { work_item_id, .. } => {
+                format!("Stamp work item #{} with the package version", work_item_id)
+            }
+            PostMergeTask::CreateReleaseTag { tag, push: false } => {
+                format!("Create release tag '{}'", tag)
+            }
+            PostMergeTask::CreateReleaseTag { tag, push: true } => {
+                format!("Create and push release tag '{}'", tag)
+            }
         }
     }
 
-    /// Returns the target ID (PR or work item ID).
+    /// Returns the task type used in progress events and summaries.
+    pub fn task_type(&self) -> &'static str {
+        match self {
+            PostMergeTask::TagPR { .. } => "tag_pr",
+            PostMergeTask::UpdateWorkItem { .. } => "update_work_item",
+            PostMergeTask::CommentWorkItem { .. } => "comment_work_item",
+            PostMergeTask::UpdateWorkItemFields { .. } => "update_work_item_fields",
+            PostMergeTask::ResolvePackageVersion { .. } => "resolve_package_version",
+            PostMergeTask::StampWorkItemBuild { .. } => "stamp_work_item_build",
+            PostMergeTask::CreateReleaseTag { .. } => "create_release_tag",
+        }
+    }
+
+    /// Returns the target ID (PR or work item ID), or 0 for the package
+    /// version lookup and the release tag.
     pub fn target_id(&self) -> i32 {
         match self {
             PostMergeTask::TagPR { pr_id, .. } => *pr_id,
-            PostMergeTask::UpdateWorkItem { work_item_id, .. } => *work_item_id,
+            PostMergeTask::UpdateWorkItem { work_item_id, .. }
+            | PostMergeTask::CommentWorkItem { work_item_id, .. }
+            | PostMergeTask::UpdateWorkItemFields { work_item_id, .. }
+            | PostMergeTask::StampWorkItemBuild { work_item_id, .. } => *work_item_id,
+            PostMergeTask::ResolvePackageVersion { .. }
+            | PostMergeTask::CreateReleaseTag { .. } => 0,
         }
     }
 }
@@ -152,6 +244,19 @@ pub struct PostMergeConfig {
     pub version: String,
     /// State to set work items to.
     pub work_item_state: String,
+    /// Target branch the PRs were merged into.
+    pub target_branch: String,
+    /// Template for the comment posted on each released work item.
+    /// No comments are posted when unset.
+    pub comment_template: Option<String>,
+    /// Templated fields written on each released work item.
+    /// No fields are written when empty.
+    pub work_item_fields: Vec<WorkItemFieldRule>,
+    /// Feed package whose version is stamped on each released work item.
+    /// No version is looked up when unset.
+    pub artifacts_feed: Option<ArtifactsFeed>,
+    /// Release tag created on the patch branch. No tag is created when unset.
+    pub release_tag: Option<ReleaseTag>,
 }
 
 /// Result of the complete post-merge operation.
@@ -163,6 +268,8 @@ pub struct PostMergeResult {
     pub success_count: usize,
     /// Number of failed tasks.
     pub failed_count: usize,
+    /// Package version found in the artifacts feed, if one was looked up.
+    pub package_version: Option<PackageVersion>,
 }
 
 impl PostMergeResult {
@@ -204,12 +311,23 @@ pub struct WorkItemInfo {
 pub struct PostMergeOperation {
     client: Arc<crate::api::AzureDevOpsClient>,
     config: PostMergeConfig,
+    /// Package version found by the [`PostMergeTask::ResolvePackageVersion`] task.
+    package_version: Mutex<Option<PackageVersion>>,
 }
 
 impl PostMergeOperation {
     /// Creates a new post-merge operation.
     pub fn new(client: Arc<crate::api::AzureDevOpsClient>, config: PostMergeConfig) -> Self {
-        Self { client, config }
+        Self {
+            client,
+            config,
+            package_version: Mutex::new(None),
+        }
+    }
+
+    /// Returns the package version found in the artifacts feed, if any.
+    pub fn package_version(&self) -> Option<PackageVersion> {
+        self.package_version.lock().unwrap().clone()
     }
 
     /// Builds the task queue from completed PRs.
@@ -243,6 +361,43 @@ impl PostMergeOperation {
             }
         }
 
+        if let Some(template) = &self.config.comment_template {
+            tasks.extend(
+                work_item_comments(template, &self.config, completed_prs)
+                    .into_iter()
+                    .map(TaskWithResult::new),
+            );
+        }
+
+        tasks.extend(
+            work_item_field_updates(&self.config, completed_prs)
+                .into_iter()
+                .map(TaskWithResult::new),
+        );
+
+        if let Some(feed) = &self.config.artifacts_feed {
+            tasks.push(TaskWithResult::new(PostMergeTask::ResolvePackageVersion {
+                package: feed.package.clone(),
+            }));
+            tasks.extend(
+                released_work_items(completed_prs)
+                    .into_iter()
+                    .map(|(wi, _)| {
+                        TaskWithResult::new(PostMergeTask::StampWorkItemBuild {
+                            work_item_id: wi.id,
+                            work_item_title: wi.title.clone(),
+                        })
+                    }),
+            );
+        }
+
+        if let Some(release_tag) = &self.config.release_tag {
+            tasks.push(TaskWithResult::new(PostMergeTask::CreateReleaseTag {
+                tag: release_tag.name.clone(),
+                push: release_tag.push,
+            }));
+        }
+
         tasks
     }
 
@@ -279,6 +434,87 @@ impl PostMergeOperation {
                     message: e.to_string(),
                 },
             },
+            PostMergeTask::CommentWorkItem {
+                work_item_id,
+                comment,
+                ..
+            } => match self
+                .client
+                .add_work_item_comment(*work_item_id, comment)
+                .await
+            {
+                Ok(_) => PostMergeTaskResult::Success,
+                Err(e) => PostMergeTaskResult::Failed {
+                    message: e.to_string(),
+                },
+            },
+            PostMergeTask::UpdateWorkItemFields {
+                work_item_id,
+                updates,
+                ..
+            } => match apply_field_updates(&self.client, *work_item_id, updates).await {
+                Ok(_) => PostMergeTaskResult::Success,
+                Err(e) => PostMergeTaskResult::Failed {
+                    message: e.to_string(),
+                },
+            },
+            PostMergeTask::ResolvePackageVersion { .. } => {
+                let Some(feed) = &self.config.artifacts_feed else {
+                    return PostMergeTaskResult::Failed {
+                        message: "No artifacts feed configured".to_string(),
+                    };
+                };
+                match self
+                    .client
+                    .resolve_package_version(feed, &self.config.version)
+                    .await
+                {
+                    Ok(package) => {
+                        *self.package_version.lock().unwrap() = Some(package);
+                        PostMergeTaskResult::Success
+                    }
+                    Err(e) => PostMergeTaskResult::Failed {
+                        message: format!("{:#}", e),
+                    },
+                }
+            }
+            PostMergeTask::StampWorkItemBuild { work_item_id, .. } => {
+                let Some(package) = self.package_version() else {
+                    return PostMergeTaskResult::Failed {
+                        message: "Package version was not found".to_string(),
+                    };
+                };
+                match self
+                    .client
+                    .update_work_item_field(
+                        *work_item_id,
+                        INTEGRATION_BUILD_FIELD,
+                        &package.version,
+                    )
+                    .await
+                {
+                    Ok(_) => PostMergeTaskResult::Success,
+                    Err(e) => PostMergeTaskResult::Failed {
+                        message: e.to_string(),
+                    },
+                }
+            }
+            PostMergeTask::CreateReleaseTag { .. } => {
+                let Some(release_tag) = self.config.release_tag.clone() else {
+                    return PostMergeTaskResult::Failed {
+                        message: "No release tag configured".to_string(),
+                    };
+                };
+                match tokio::task::spawn_blocking(move || release_tag.create()).await {
+                    Ok(Ok(())) => PostMergeTaskResult::Success,
+                    Ok(Err(e)) => PostMergeTaskResult::Failed {
+                        message: format!("{:#}", e),
+                    },
+                    Err(e) => PostMergeTaskResult::Failed {
+                        message: format!("Task error: {}", e),
+                    },
+                }
+            }
         }
     }
 
@@ -356,6 +592,7 @@ impl PostMergeOperation {
             tasks: tasks.to_vec(),
             success_count,
             failed_count,
+            package_version: self.package_version(),
         }
     }
 
@@ -389,6 +626,188 @@ impl PostMergeOperation {
     }
 }
 
+/// Builds one comment task per released work item.
+///
+/// Work items linked to several PRs get a single comment listing all of
+/// them. The template supports `{version}`, `{branch}` (the
+/// `patch/<target>-<version>` branch), `{target_branch}`, `{prs}`
+/// (e.g. `!456, !457`), `{work_item_id}` and `{work_item_title}`.
+pub fn work_item_comments(
+    template: &str,
+    config: &PostMergeConfig,
+    completed_prs: &[CompletedPRInfo],
+) -> Vec<PostMergeTask> {
+    released_work_items(completed_prs)
+        .into_iter()
+        .map(|(wi, pr_ids)| PostMergeTask::CommentWorkItem {
+            work_item_id: wi.id,
+            work_item_title: wi.title.clone(),
+            comment: render_work_item_template(template, config, wi.id, &wi.title, &pr_ids),
+        })
+        .collect()
+}
+
+/// Builds one field update task per released work item.
+///
+/// Values are rendered like [`work_item_comments`]. Returns no tasks when no
+/// field updates are configured.
+pub fn work_item_field_updates(
+    config: &PostMergeConfig,
+    completed_prs: &[CompletedPRInfo],
+) -> Vec<PostMergeTask> {
+    if config.work_item_fields.is_empty() {
+        return Vec::new();
+    }
+    released_work_items(completed_prs)
+        .into_iter()
+        .map(|(wi, pr_ids)| PostMergeTask::UpdateWorkItemFields {
+            work_item_id: wi.id,
+            work_item_title: wi.title.clone(),
+            updates: render_field_updates(config, wi.id, &wi.title, &pr_ids),
+        })
+        .collect()
+}
+
+/// Renders the configured field updates for one released work item.
+pub fn render_field_updates(
+    config: &PostMergeConfig,
+    work_item_id: i32,
+    work_item_title: &str,
+    pr_ids: &[i32],
+) -> Vec<FieldUpdate> {
+    config
+        .work_item_fields
+        .iter()
+        .map(|rule| FieldUpdate {
+            field: rule.field.clone(),
+            value: render_work_item_template(
+                &rule.value,
+                config,
+                work_item_id,
+                work_item_title,
+                pr_ids,
+            ),
+            mode: rule.mode,
+        })
+        .collect()
+}
+
+/// Substitutes the placeholders of a comment or field value template for
+/// one released work item.
+fn render_work_item_template(
+    template: &str,
+    config: &PostMergeConfig,
+    work_item_id: i32,
+    work_item_title: &str,
+    pr_ids: &[i32],
+) -> String {
+    let branch = format!("patch/{}-{}", config.target_branch, config.version);
+    let prs = pr_ids
+        .iter()
+        .map(|id| format!("!{}", id))
+        .collect::<Vec<_>>()
+        .join(", ");
+    template
+        .replace("{version}", &config.version)
+        .replace("{branch}", &branch)
+        .replace("{target_branch}", &config.target_branch)
+        .replace("{prs}", &prs)
+        .replace("{work_item_id}", &work_item_id.to_string())
+        .replace("{work_item_title}", work_item_title)
+}
+
+/// Lists each released work item once, with the IDs of the PRs linking it.
+fn released_work_items(completed_prs: &[CompletedPRInfo]) -> Vec<(&WorkItemInfo, Vec<i32>)> {
+    let mut released: Vec<(&WorkItemInfo, Vec<i32>)> = Vec::new();
+    for pr in completed_prs {
+        for wi in &pr.work_items {
+            match released.iter_mut().find(|(item, _)| item.id == wi.id) {
+                Some((_, pr_ids)) => pr_ids.push(pr.pr_id),
+                None => released.push((wi, vec![pr.pr_id])),
+            }
+        }
+    }
+    released
+}
+
+/// A planned change to one work item, reviewed before post-merge tasks run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkItemTransition {
+    /// The work item ID.
+    pub work_item_id: i32,
+    /// The work item title.
+    pub title: String,
+    /// State of the work item when the PRs were loaded.
+    pub current_state: Option<String>,
+    /// State the work item will be set to.
+    pub target_state: String,
+    /// What will be written to the work item, in execution order.
+    pub fields: Vec<String>,
+    /// Configured field updates rendered for the work item.
+    pub field_updates: Vec<FieldUpdate>,
+}
+
+impl WorkItemTransition {
+    /// Returns true if the work item is already in the target state.
+    pub fn is_unchanged(&self) -> bool {
+        self.current_state.as_deref() == Some(self.target_state.as_str())
+    }
+}
+
+/// Lists the changes post-merge tasks will make to each released work item.
+///
+/// Work items linked to several PRs are listed once, in the order they are
+/// first released. `System.State` is always written; `Comment` is added
+/// when a comment template is configured, then the fields of configured field
+/// updates, and the "Integrated in build" field when an artifacts feed is.
+pub fn work_item_transitions(
+    prs: &[crate::models::PullRequestWithWorkItems],
+    successful_pr_ids: &[i32],
+    config: &PostMergeConfig,
+) -> Vec<WorkItemTransition> {
+    let mut fields = vec!["System.State".to_string()];
+    if config.comment_template.is_some() {
+        fields.push("Comment".to_string());
+    }
+    for rule in &config.work_item_fields {
+        if !fields.contains(&rule.field) {
+            fields.push(rule.field.clone());
+        }
+    }
+    if config.artifacts_feed.is_some() {
+        fields.push(INTEGRATION_BUILD_FIELD.to_string());
+    }
+
+    let mut transitions: Vec<WorkItemTransition> = Vec::new();
+    for pr in prs
+        .iter()
+        .filter(|pr| successful_pr_ids.contains(&pr.pr.id))
+    {
+        for wi in &pr.work_items {
+            if transitions.iter().any(|t| t.work_item_id == wi.id) {
+                continue;
+            }
+            let title = wi.fields.title.clone().unwrap_or_default();
+            let mut pr_ids: Vec<i32> = prs
+                .iter()
+                .filter(|pr| successful_pr_ids.contains(&pr.pr.id))
+                .filter(|pr| pr.work_items.iter().any(|other| other.id == wi.id))
+                .map(|pr| pr.pr.id)
+                .collect();
+            pr_ids.dedup();
+            transitions.push(WorkItemTransition {
+                work_item_id: wi.id,
+                field_updates: render_field_updates(config, wi.id, &title, &pr_ids),
+                title,
+                current_state: wi.fields.state.clone(),
+                target_state: config.work_item_state.clone(),
+                fields: fields.clone(),
+            });
+        }
+    }
+    transitions
+}
+
 /// Extracts work items info from PRs for post-merge tasks.
 pub fn extract_completed_pr_info(
     prs: &[crate::models::PullRequestWithWorkItems],
@@ -414,6 +833,7 @@ pub fn extract_completed_pr_info(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::operations::work_item_fields::FieldUpdateMode;
 
     /// # Post Merge Task Description
     ///
@@ -533,6 +953,7 @@ mod tests {
             tasks: Vec::new(),
             success_count: 5,
             failed_count: 0,
+            package_version: None,
         };
         assert!(success.all_succeeded());
 
@@ -540,6 +961,7 @@ mod tests {
             tasks: Vec::new(),
             success_count: 3,
             failed_count: 2,
+            package_version: None,
         };
         assert!(!partial.all_succeeded());
     }
@@ -597,6 +1019,7 @@ mod tests {
                     },
                     labels: None,
                     last_merge_commit: None,
+                    reviewers: Vec::new(),
                 },
                 work_items: vec![WorkItem {
                     id: 101,
@@ -609,6 +1032,7 @@ mod tests {
                         description: None,
                         repro_steps: None,
                         state_color: None,
+                        custom_fields: Default::default(),
                     },
                     history: Vec::new(),
                 }],
@@ -625,6 +1049,7 @@ mod tests {
                     },
                     labels: None,
                     last_merge_commit: None,
+                    reviewers: Vec::new(),
                 },
                 work_items: vec![],
                 selected: false,
@@ -638,4 +1063,394 @@ mod tests {
         assert_eq!(result[0].work_items.len(), 1);
         assert_eq!(result[0].work_items[0].id, 101);
     }
+
+    /// # Work Item Comments
+    ///
+    /// Verifies release comments are rendered and batched per work item.
+    ///
+    /// ## Test Scenario
+    /// - Two PRs share work item 101, the second also links work item 102
+    /// - Renders a template using every placeholder
+    ///
+    /// ## Expected Outcome
+    /// - One comment per work item, in first-seen order
+    /// - Work item 101 lists both PRs
+    /// - All placeholders are substituted
+    #[test]
+    fn test_work_item_comments() {
+        let config = PostMergeConfig {
+            tag_prefix: "merged-".to_string(),
+            version: "1.2.3".to_string(),
+            work_item_state: "Done".to_string(),
+            target_branch: "main".to_string(),
+            comment_template: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            release_tag: None,
+        };
+        let wi = |id: i32, title: &str| WorkItemInfo {
+            id,
+            title: title.to_string(),
+        };
+        let prs = vec![
+            CompletedPRInfo {
+                pr_id: 456,
+                pr_title: "PR 456".to_string(),
+                work_items: vec![wi(101, "Login fix")],
+            },
+            CompletedPRInfo {
+                pr_id: 457,
+                pr_title: "PR 457".to_string(),
+                work_items: vec![wi(101, "Login fix"), wi(102, "Cache")],
+            },
+        ];
+
+        let tasks = work_item_comments(
+            "Shipped in v{version}, branch {branch} ({target_branch}), PR {prs} [#{work_item_id} {work_item_title}]",
+            &config,
+            &prs,
+        );
+
+        let comments: Vec<(i32, String)> = tasks
+            .iter()
+            .map(|task| match task {
+                PostMergeTask::CommentWorkItem {
+                    work_item_id,
+                    comment,
+                    ..
+                } => (*work_item_id, comment.clone()),
+                other => panic!("unexpected task {:?}", other),
+            })
+            .collect();
+        assert_eq!(
+            comments,
+            vec![
+                (
+                    101,
+                    "Shipped in v1.2.3, branch patch/main-1.2.3 (main), PR !456, !457 [#101 Login fix]"
+                        .to_string()
+                ),
+                (
+                    102,
+                    "Shipped in v1.2.3, branch patch/main-1.2.3 (main), PR !457 [#102 Cache]"
+                        .to_string()
+                ),
+            ]
+        );
+        assert_eq!(tasks[0].task_type(), "comment_work_item");
+        assert_eq!(tasks[0].target_id(), 101);
+    }
+
+    /// # Work Item Transitions
+    ///
+    /// Verifies the review list of work item changes.
+    ///
+    /// ## Test Scenario
+    /// - PR #100 (work item #1001, Closed) succeeded, PR #101 did not
+    /// - PR #100 is listed twice, as when several PRs share a work item
+    /// - Lists transitions with and without a comment template, an
+    ///   artifacts feed and field updates
+    ///
+    /// ## Expected Outcome
+    /// - Only work items of successful PRs are listed, once each
+    /// - The current and target states and written fields are reported
+    /// - Field updates are rendered for each work item
+    #[test]
+    #[cfg(feature = "tui")]
+    fn test_work_item_transitions() {
+        let mut prs = crate::ui::testing::create_test_pull_requests();
+        prs.push(prs[0].clone());
+        let mut config = PostMergeConfig {
+            tag_prefix: "merged-".to_string(),
+            version: "1.2.3".to_string(),
+            work_item_state: "Closed".to_string(),
+            target_branch: "main".to_string(),
+            comment_template: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            release_tag: None,
+        };
+
+        let transitions = work_item_transitions(&prs, &[100], &config);
+        assert_eq!(transitions.len(), 1);
+        assert_eq!(transitions[0].work_item_id, 1001);
+        assert_eq!(transitions[0].current_state.as_deref(), Some("Closed"));
+        assert_eq!(transitions[0].target_state, "Closed");
+        assert_eq!(transitions[0].fields, vec!["System.State"]);
+        assert!(transitions[0].is_unchanged());
+
+        config.work_item_state = "Next Merged".to_string();
+        config.comment_template = Some("Shipped in {version}".to_string());
+        let transitions = work_item_transitions(&prs, &[100, 101], &config);
+        let ids: Vec<i32> = transitions.iter().map(|t| t.work_item_id).collect();
+        assert_eq!(ids, vec![1001, 1002]);
+        assert_eq!(transitions[1].fields, vec!["System.State", "Comment"]);
+        assert!(!transitions[1].is_unchanged());
+
+        config.artifacts_feed = Some(ArtifactsFeed {
+            feed: "releases".to_string(),
+            package: "Contoso.Service".to_string(),
+            project: None,
+        });
+        let transitions = work_item_transitions(&prs, &[100], &config);
+        assert_eq!(
+            transitions[0].fields,
+            vec!["System.State", "Comment", INTEGRATION_BUILD_FIELD]
+        );
+        assert!(transitions[0].field_updates.is_empty());
+
+        config.work_item_fields = vec![WorkItemFieldRule {
+            field: "Custom.ReleaseNote".to_string(),
+            value: "{version} via {prs}".to_string(),
+            mode: FieldUpdateMode::Set,
+        }];
+        let transitions = work_item_transitions(&prs, &[100], &config);
+        assert_eq!(
+            transitions[0].fields,
+            vec![
+                "System.State",
+                "Comment",
+                "Custom.ReleaseNote",
+                INTEGRATION_BUILD_FIELD
+            ]
+        );
+        assert_eq!(
+            transitions[0].field_updates[0].to_string(),
+            "Custom.ReleaseNote = 1.2.3 via !100"
+        );
+    }
+
+    /// # Field Update Tasks
+    ///
+    /// Verifies the tasks writing configured fields on work items.
+    ///
+    /// ## Test Scenario
+    /// - Configures a set and an append field update
+    /// - Two PRs share work item 101, the second also links work item 102
+    ///
+    /// ## Expected Outcome
+    /// - One field update task per work item follows the state updates
+    /// - Values are rendered with the work item's PRs and title
+    #[test]
+    fn test_work_item_field_update_tasks() {
+        let config = PostMergeConfig {
+            tag_prefix: "merged-".to_string(),
+            version: "1.2.3".to_string(),
+            work_item_state: "Done".to_string(),
+            target_branch: "main".to_string(),
+            comment_template: None,
+            artifacts_feed: None,
+            work_item_fields: vec![
+                WorkItemFieldRule {
+                    field: "Custom.ReleaseVersion".to_string(),
+                    value: "{version} ({prs})".to_string(),
+                    mode: FieldUpdateMode::Set,
+                },
+                WorkItemFieldRule {
+                    field: "System.Tags".to_string(),
+                    value: "released-{version}".to_string(),
+                    mode: FieldUpdateMode::Append,
+                },
+            ],
+            release_tag: None,
+        };
+        let wi = |id: i32| WorkItemInfo {
+            id,
+            title: format!("Work item {}", id),
+        };
+        let prs = vec![
+            CompletedPRInfo {
+                pr_id: 456,
+                pr_title: "PR 456".to_string(),
+                work_items: vec![wi(101)],
+            },
+            CompletedPRInfo {
+                pr_id: 457,
+                pr_title: "PR 457".to_string(),
+                work_items: vec![wi(101), wi(102)],
+            },
+        ];
+
+        let tasks = work_item_field_updates(&config, &prs);
+        assert_eq!(tasks.len(), 2);
+        let PostMergeTask::UpdateWorkItemFields {
+            work_item_id,
+            updates,
+            ..
+        } = &tasks[0]
+        else {
+            panic!("expected a field update task");
+        };
+        assert_eq!(*work_item_id, 101);
+        let updates: Vec<String> = updates.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            updates,
+            vec![
+                "Custom.ReleaseVersion = 1.2.3 (!456, !457)",
+                "System.Tags += released-1.2.3"
+            ]
+        );
+        assert_eq!(
+            tasks[1].description(),
+            "Update 2 field(s) of work item #102"
+        );
+        assert_eq!(tasks[1].task_type(), "update_work_item_fields");
+
+        let client = crate::api::AzureDevOpsClient::new(
+            "org".to_string(),
+            "project".to_string(),
+            "repo".to_string(),
+            "pat".to_string(),
+        )
+        .unwrap();
+        let operation = PostMergeOperation::new(Arc::new(client), config);
+        let types: Vec<(&str, i32)> = operation
+            .build_task_queue(&prs)
+            .iter()
+            .map(|t| (t.task.task_type(), t.task.target_id()))
+            .collect();
+        assert_eq!(
+            &types[5..],
+            &[
+                ("update_work_item_fields", 101),
+                ("update_work_item_fields", 102)
+            ]
+        );
+    }
+
+    /// # Package Version Tasks
+    ///
+    /// Verifies the tasks stamping the package version on work items.
+    ///
+    /// ## Test Scenario
+    /// - Configures an artifacts feed
+    /// - Two PRs share work item 101, the second also links work item 102
+    /// - Builds the task queue and runs a stamp task before any lookup
+    ///
+    /// ## Expected Outcome
+    /// - The lookup follows the tag and state tasks
+    /// - Each work item is stamped once, after the lookup
+    /// - Stamping fails while no package version was found
+    #[tokio::test]
+    async fn test_package_version_tasks() {
+        let client = crate::api::AzureDevOpsClient::new(
+            "org".to_string(),
+            "project".to_string(),
+            "repo".to_string(),
+            "pat".to_string(),
+        )
+        .unwrap();
+        let config = PostMergeConfig {
+            tag_prefix: "merged-".to_string(),
+            version: "1.2.3".to_string(),
+            work_item_state: "Done".to_string(),
+            target_branch: "main".to_string(),
+            comment_template: None,
+            artifacts_feed: Some(ArtifactsFeed {
+                feed: "releases".to_string(),
+                package: "Contoso.Service".to_string(),
+                project: None,
+            }),
+            work_item_fields: Vec::new(),
+            release_tag: None,
+        };
+        let operation = PostMergeOperation::new(Arc::new(client), config);
+        let wi = |id: i32| WorkItemInfo {
+            id,
+            title: format!("Work item {}", id),
+        };
+        let prs = vec![
+            CompletedPRInfo {
+                pr_id: 456,
+                pr_title: "PR 456".to_string(),
+                work_items: vec![wi(101)],
+            },
+            CompletedPRInfo {
+                pr_id: 457,
+                pr_title: "PR 457".to_string(),
+                work_items: vec![wi(101), wi(102)],
+            },
+        ];
+
+        let tasks = operation.build_task_queue(&prs);
+        let types: Vec<(&str, i32)> = tasks
+            .iter()
+            .map(|t| (t.task.task_type(), t.task.target_id()))
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                ("tag_pr", 456),
+                ("update_work_item", 101),
+                ("tag_pr", 457),
+                ("update_work_item", 101),
+                ("update_work_item", 102),
+                ("resolve_package_version", 0),
+                ("stamp_work_item_build", 101),
+                ("stamp_work_item_build", 102),
+            ]
+        );
+
+        let result = operation.execute_task(&tasks[6].task).await;
+        assert!(matches!(
+            result,
+            PostMergeTaskResult::Failed { ref message } if message == "Package version was not found"
+        ));
+        assert!(operation.package_version().is_none());
+    }
+
+    /// # Release Tag Task
+    ///
+    /// Verifies the task creating the release tag.
+    ///
+    /// ## Test Scenario
+    /// - Configures a pushed release tag in a directory that is no repository
+    /// - Builds the task queue and runs the tag task
+    ///
+    /// ## Expected Outcome
+    /// - The tag task comes last and describes the push
+    /// - Running it reports the git error
+    #[tokio::test]
+    async fn test_release_tag_task() {
+        let client = crate::api::AzureDevOpsClient::new(
+            "org".to_string(),
+            "project".to_string(),
+            "repo".to_string(),
+            "pat".to_string(),
+        )
+        .unwrap();
+        let repo = tempfile::TempDir::new().unwrap();
+        let config = PostMergeConfig {
+            tag_prefix: "merged-".to_string(),
+            version: "1.2.3".to_string(),
+            work_item_state: "Done".to_string(),
+            target_branch: "main".to_string(),
+            comment_template: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            release_tag: Some(ReleaseTag::new(
+                repo.path().to_path_buf(),
+                "1.2.3",
+                "# Release Notes - 1.2.3".to_string(),
+                true,
+            )),
+        };
+        let operation = PostMergeOperation::new(Arc::new(client), config);
+        let prs = vec![CompletedPRInfo {
+            pr_id: 456,
+            pr_title: "PR 456".to_string(),
+            work_items: Vec::new(),
+        }];
+
+        let tasks = operation.build_task_queue(&prs);
+        let task = &tasks.last().unwrap().task;
+        assert_eq!(task.task_type(), "create_release_tag");
+        assert_eq!(task.description(), "Create and push release tag 'v1.2.3'");
+
+        let result = operation.execute_task(task).await;
+        assert!(matches!(
+            result,
+            PostMergeTaskResult::Failed { ref message } if message.contains("Failed to create tag v1.2.3")
+        ));
+    }
 }
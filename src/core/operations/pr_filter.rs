@@ -0,0 +1,333 @@
+//! Narrowing the PR list by author, reviewer and changed paths.
+//!
+//! Repositories with hundreds of completed PRs are hard to scroll through.
+//! A filter keeps only the PRs created by an author, reviewed by a reviewer
+//! and changing files that match path globs. Names match case-insensitively
+//! anywhere in the display name.
+//!
+//! An author or reviewer given as an identity id (a GUID) is sent to Azure
+//! DevOps as search criteria instead, so only matching PRs are fetched.
+//!
+//! # Filter Syntax
+//!
+//! The TUI filter prompt takes space-separated terms; values with spaces are
+//! quoted:
+//!
+//! ```text
+//! author:"Alice Johnson" reviewer:bob path:src/server/** path:*.sql
+//! ```
+//!
+//! Changed files come from dependency analysis, so path globs only match
+//! when `local_repo` is configured.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::core::operations::PrFilter;
+//!
+//! let filter: PrFilter = "author:alice path:src/**".parse().unwrap();
+//! assert_eq!(filter.author(), Some("alice"));
+//! assert_eq!(filter.paths(), ["src/**"]);
+//! assert_eq!(filter.to_string(), "author:alice path:src/**");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{Result, bail};
+use regex::Regex;
+
+use crate::core::operations::pr_tabs::glob_to_regex;
+use crate::models::PullRequest;
+
+/// Author, reviewer and path criteria a PR must all meet to be listed.
+#[derive(Debug, Clone, Default)]
+pub struct PrFilter {
+    author: Option<String>,
+    reviewer: Option<String>,
+    paths: Vec<String>,
+    path_regexes: Vec<Regex>,
+}
+
+impl PrFilter {
+    /// Creates a filter; blank names and globs are left out.
+    pub fn new(author: Option<String>, reviewer: Option<String>, paths: Vec<String>) -> Self {
+        let non_blank = |value: String| {
+            let value = value.trim().to_string();
+            (!value.is_empty()).then_some(value)
+        };
+        let paths: Vec<String> = paths.into_iter().filter_map(non_blank).collect();
+        let path_regexes = paths.iter().map(|glob| glob_to_regex(glob)).collect();
+        Self {
+            author: author.and_then(non_blank),
+            reviewer: reviewer.and_then(non_blank),
+            paths,
+            path_regexes,
+        }
+    }
+
+    /// Returns true if the filter keeps every PR.
+    pub fn is_empty(&self) -> bool {
+        self.author.is_none() && self.reviewer.is_none() && self.paths.is_empty()
+    }
+
+    /// Author name or identity id.
+    pub fn author(&self) -> Option<&str> {
+        self.author.as_deref()
+    }
+
+    /// Reviewer name or identity id.
+    pub fn reviewer(&self) -> Option<&str> {
+        self.reviewer.as_deref()
+    }
+
+    /// Globs matched against changed file paths.
+    pub fn paths(&self) -> &[String] {
+        &self.paths
+    }
+
+    /// Returns the author when it is an identity id, to filter server-side.
+    pub fn creator_id(&self) -> Option<&str> {
+        self.author().filter(|author| is_identity_id(author))
+    }
+
+    /// Returns the reviewer when it is an identity id, to filter server-side.
+    pub fn reviewer_id(&self) -> Option<&str> {
+        self.reviewer().filter(|reviewer| is_identity_id(reviewer))
+    }
+
+    /// Returns `true` if `pr` meets every criterion.
+    ///
+    /// Identity ids are matched by Azure DevOps when the PRs are listed, so
+    /// every listed PR meets them. Path globs need at least one matching
+    /// file among `changed_files`.
+    pub fn matches(&self, pr: &PullRequest, changed_files: &[String]) -> bool {
+        let name_matches = |criterion: &str, name: &str| {
+            is_identity_id(criterion) || name.to_lowercase().contains(&criterion.to_lowercase())
+        };
+        self.author
+            .as_deref()
+            .is_none_or(|author| name_matches(author, &pr.created_by.display_name))
+            && self.reviewer.as_deref().is_none_or(|reviewer| {
+                pr.reviewers
+                    .iter()
+                    .any(|r| name_matches(reviewer, &r.display_name))
+            })
+            && (self.path_regexes.is_empty()
+                || changed_files
+                    .iter()
+                    .any(|path| self.path_regexes.iter().any(|regex| regex.is_match(path))))
+    }
+}
+
+impl FromStr for PrFilter {
+    type Err = anyhow::Error;
+
+    /// Parses `author:NAME reviewer:NAME path:GLOB` terms (see the
+    /// [module docs](self)).
+    fn from_str(input: &str) -> Result<Self> {
+        let mut author = None;
+        let mut reviewer = None;
+        let mut paths = Vec::new();
+        for term in split_terms(input)? {
+            let Some((key, value)) = term.split_once(':') else {
+                bail!(
+                    "Invalid filter term '{}' (expected author:, reviewer: or path:)",
+                    term
+                );
+            };
+            if value.is_empty() {
+                bail!("Filter term '{}:' needs a value", key);
+            }
+            match key.to_lowercase().as_str() {
+                "author" => author = Some(value.to_string()),
+                "reviewer" => reviewer = Some(value.to_string()),
+                "path" => paths.push(value.to_string()),
+                _ => bail!(
+                    "Unknown filter '{}' (expected author:, reviewer: or path:)",
+                    key
+                ),
+            }
+        }
+        Ok(Self::new(author, reviewer, paths))
+    }
+}
+
+impl fmt::Display for PrFilter {
+    /// Writes the filter in the syntax [`FromStr`] parses.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let terms = self
+            .author
+            .iter()
+            .map(|author| ("author", author))
+            .chain(self.reviewer.iter().map(|reviewer| ("reviewer", reviewer)))
+            .chain(self.paths.iter().map(|path| ("path", path)));
+        for (index, (key, value)) in terms.enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            if value.contains(char::is_whitespace) {
+                write!(f, "{}:\"{}\"", key, value)?;
+            } else {
+                write!(f, "{}:{}", key, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Splits filter input at whitespace outside double quotes, dropping the quotes.
+fn split_terms(input: &str) -> Result<Vec<String>> {
+    let mut terms = Vec::new();
+    let mut term = String::new();
+    let mut quoted = false;
+    for c in input.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if !term.is_empty() {
+                    terms.push(std::mem::take(&mut term));
+                }
+            }
+            c => term.push(c),
+        }
+    }
+    if quoted {
+        bail!("Unterminated quote in filter");
+    }
+    if !term.is_empty() {
+        terms.push(term);
+    }
+    Ok(terms)
+}
+
+/// Returns true if `value` is a GUID like `0b1c2d3e-4f50-6172-8394-a5b6c7d8e9f0`.
+fn is_identity_id(value: &str) -> bool {
+    let groups: Vec<&str> = value.split('-').collect();
+    groups.len() == 5
+        && groups
+            .iter()
+            .zip([8, 4, 4, 4, 12])
+            .all(|(group, len)| group.len() == len && group.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CreatedBy;
+
+    const ALICE_ID: &str = "0b1c2d3e-4f50-6172-8394-a5b6c7d8e9f0";
+
+    fn pr(author: &str, reviewers: &[&str]) -> PullRequest {
+        PullRequest {
+            id: 1,
+            title: "PR".to_string(),
+            description: None,
+            closed_date: None,
+            created_by: CreatedBy {
+                display_name: author.to_string(),
+            },
+            last_merge_commit: None,
+            labels: None,
+            reviewers: reviewers
+                .iter()
+                .map(|name| CreatedBy {
+                    display_name: name.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    fn files(paths: &[&str]) -> Vec<String> {
+        paths.iter().map(|p| p.to_string()).collect()
+    }
+
+    /// # Matching PRs
+    ///
+    /// Tests matching PRs by author, reviewer and changed paths.
+    ///
+    /// ## Test Scenario
+    /// - Matches PRs against each criterion alone and combined
+    ///
+    /// ## Expected Outcome
+    /// - Names match case-insensitively anywhere in the display name
+    /// - Path globs need a matching changed file
+    /// - Every criterion must match
+    #[test]
+    fn test_matches() {
+        let alice = pr("Alice Johnson", &["Bob Smith", "Carol White"]);
+        let changed = files(&["src/server/api.rs", "README.md"]);
+
+        assert!(PrFilter::default().matches(&alice, &[]));
+        assert!(PrFilter::new(Some("alice".to_string()), None, vec![]).matches(&alice, &[]));
+        assert!(!PrFilter::new(Some("bob".to_string()), None, vec![]).matches(&alice, &[]));
+        assert!(PrFilter::new(None, Some("CAROL".to_string()), vec![]).matches(&alice, &[]));
+        assert!(!PrFilter::new(None, Some("dave".to_string()), vec![]).matches(&alice, &[]));
+
+        let server = PrFilter::new(None, None, vec!["src/server/**".to_string()]);
+        assert!(server.matches(&alice, &changed));
+        assert!(!server.matches(&alice, &files(&["web/app.ts"])));
+        assert!(!server.matches(&alice, &[]));
+
+        let combined = PrFilter::new(
+            Some("johnson".to_string()),
+            Some("bob".to_string()),
+            vec!["*.md".to_string()],
+        );
+        assert!(combined.matches(&alice, &changed));
+        assert!(!combined.matches(&pr("Dave", &["Bob"]), &changed));
+    }
+
+    /// # Identity Ids
+    ///
+    /// Tests authors and reviewers given as identity ids.
+    ///
+    /// ## Test Scenario
+    /// - Creates filters with GUIDs and with names
+    ///
+    /// ## Expected Outcome
+    /// - GUIDs are returned for server-side filtering and match every PR
+    /// - Names are not returned as ids
+    #[test]
+    fn test_identity_ids() {
+        let filter = PrFilter::new(Some(ALICE_ID.to_string()), Some("bob".to_string()), vec![]);
+        assert_eq!(filter.creator_id(), Some(ALICE_ID));
+        assert_eq!(filter.reviewer_id(), None);
+        assert!(filter.matches(&pr("Someone", &["Bob"]), &[]));
+
+        assert!(!is_identity_id("0b1c2d3e-4f50-6172-8394"));
+        assert!(!is_identity_id("zb1c2d3e-4f50-6172-8394-a5b6c7d8e9f0"));
+    }
+
+    /// # Parsing Filters
+    ///
+    /// Tests parsing and writing the filter prompt syntax.
+    ///
+    /// ## Test Scenario
+    /// - Parses terms with quoted values, repeated paths and blank input
+    /// - Parses malformed terms
+    ///
+    /// ## Expected Outcome
+    /// - Terms round-trip through `Display`
+    /// - Blank input gives an empty filter
+    /// - Malformed terms are rejected
+    #[test]
+    fn test_parse() {
+        let filter: PrFilter = r#"Author:"Alice Johnson" reviewer:bob path:src/** path:*.sql"#
+            .parse()
+            .unwrap();
+        assert_eq!(filter.author(), Some("Alice Johnson"));
+        assert_eq!(filter.reviewer(), Some("bob"));
+        assert_eq!(filter.paths(), ["src/**", "*.sql"]);
+        assert_eq!(
+            filter.to_string(),
+            r#"author:"Alice Johnson" reviewer:bob path:src/** path:*.sql"#
+        );
+
+        assert!("  ".parse::<PrFilter>().unwrap().is_empty());
+        assert!("alice".parse::<PrFilter>().is_err());
+        assert!("author:".parse::<PrFilter>().is_err());
+        assert!("label:bug".parse::<PrFilter>().is_err());
+        assert!("author:\"Alice".parse::<PrFilter>().is_err());
+    }
+}
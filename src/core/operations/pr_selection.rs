@@ -3,8 +3,15 @@
 //! This module provides functions to filter and select pull requests based on
 //! the states of their associated work items. This is primarily used for
 //! non-interactive mode where PRs are automatically selected.
+//!
+//! It also provides [`summarize_selection`], which computes live totals for
+//! the current selection (shown in the TUI's selection summary panel), and
+//! [`find_unknown_work_item_states`] for strict state checking.
+//! [`SelectionCaps`] guards against accidentally merging hundreds of PRs.
 
+use crate::core::operations::dependency_analysis::{DependencyCategory, PRDependencyGraph};
 use crate::models::PullRequestWithWorkItems;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 /// Filters PRs to only those where ALL work items are in one of the specified states.
 ///
@@ -31,26 +38,25 @@ pub fn filter_prs_by_work_item_states<'a>(
     prs: &'a [PullRequestWithWorkItems],
     states: &[String],
 ) -> Vec<&'a PullRequestWithWorkItems> {
-    // Normalize states to lowercase for case-insensitive matching
-    let normalized_states: Vec<String> = states.iter().map(|s| s.to_lowercase()).collect();
-
     prs.iter()
-        .filter(|pr| {
-            // Must have at least one work item
-            if pr.work_items.is_empty() {
-                return false;
-            }
+        .filter(|pr| all_work_items_in_states(pr, states))
+        .collect()
+}
 
-            // All work items must be in one of the specified states
-            pr.work_items.iter().all(|wi| {
-                wi.fields
-                    .state
-                    .as_ref()
-                    .map(|state| normalized_states.contains(&state.to_lowercase()))
-                    .unwrap_or(false)
-            })
+/// Returns true if the PR has work items and all of them are in one of the
+/// specified states (case-insensitive).
+///
+/// Used to hide PRs whose work items already reached a terminal state.
+pub fn all_work_items_in_states(pr: &PullRequestWithWorkItems, states: &[String]) -> bool {
+    let normalized_states: Vec<String> = states.iter().map(|s| s.to_lowercase()).collect();
+
+    !pr.work_items.is_empty()
+        && pr.work_items.iter().all(|wi| {
+            wi.fields
+                .state
+                .as_ref()
+                .is_some_and(|state| normalized_states.contains(&state.to_lowercase()))
         })
-        .collect()
 }
 
 /// Selects PRs in-place where ALL work items are in one of the specified states.
@@ -76,26 +82,10 @@ pub fn select_prs_by_work_item_states(
     prs: &mut [PullRequestWithWorkItems],
     states: &[String],
 ) -> usize {
-    // Normalize states to lowercase for case-insensitive matching
-    let normalized_states: Vec<String> = states.iter().map(|s| s.to_lowercase()).collect();
-
     let mut selected_count = 0;
 
     for pr in prs.iter_mut() {
-        let should_select = if pr.work_items.is_empty() {
-            // Must have at least one work item
-            false
-        } else {
-            // All work items must be in one of the specified states
-            pr.work_items.iter().all(|wi| {
-                wi.fields
-                    .state
-                    .as_ref()
-                    .map(|state| normalized_states.contains(&state.to_lowercase()))
-                    .unwrap_or(false)
-            })
-        };
-
+        let should_select = all_work_items_in_states(pr, states);
         pr.selected = should_select;
         if should_select {
             selected_count += 1;
@@ -122,6 +112,217 @@ pub fn parse_work_item_states(states_str: &str) -> Vec<String> {
         .collect()
 }
 
+/// Finds work item states that are not in the known set.
+///
+/// Used by `--strict-states` to catch process template drift: a state the
+/// merge workflow does not expect usually means the template changed.
+/// Matching is case-insensitive and work items without a state are ignored.
+///
+/// # Returns
+///
+/// A map from each unknown state to the IDs of the PRs using it, both sorted.
+pub fn find_unknown_work_item_states(
+    prs: &[PullRequestWithWorkItems],
+    known_states: &[String],
+) -> BTreeMap<String, Vec<i32>> {
+    let known: HashSet<String> = known_states.iter().map(|s| s.to_lowercase()).collect();
+    let mut unknown: BTreeMap<String, BTreeSet<i32>> = BTreeMap::new();
+
+    for pr in prs {
+        for state in pr
+            .work_items
+            .iter()
+            .filter_map(|wi| wi.fields.state.as_ref())
+        {
+            if !known.contains(&state.to_lowercase()) {
+                unknown.entry(state.clone()).or_default().insert(pr.pr.id);
+            }
+        }
+    }
+
+    unknown
+        .into_iter()
+        .map(|(state, ids)| (state, ids.into_iter().collect()))
+        .collect()
+}
+
+/// Formats unknown work item states for error messages.
+///
+/// Produces e.g. `'In Review' (PR #12, #15), 'Blocked' (PR #3)`.
+pub fn describe_unknown_states(unknown: &BTreeMap<String, Vec<i32>>) -> String {
+    unknown
+        .iter()
+        .map(|(state, ids)| {
+            let ids = ids
+                .iter()
+                .map(|id| format!("#{}", id))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("'{}' (PR {})", state, ids)
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Estimated risk of cherry-pick conflicts for a selection.
+///
+/// The estimate is based on dependencies of selected PRs on PRs that are
+/// *not* selected: cherry-picking a PR without the changes it builds upon is
+/// the most common cause of conflicts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConflictRisk {
+    /// Dependency analysis is not available.
+    Unknown,
+    /// No selected PR depends on an unselected PR.
+    Low,
+    /// Selected PRs share files (but not lines) with unselected PRs.
+    Medium,
+    /// Selected PRs modify the same lines as unselected PRs.
+    High,
+}
+
+impl ConflictRisk {
+    /// Returns a short display label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConflictRisk::Unknown => "Unknown",
+            ConflictRisk::Low => "Low",
+            ConflictRisk::Medium => "Medium",
+            ConflictRisk::High => "High",
+        }
+    }
+}
+
+/// Live totals for the currently selected PRs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectionSummary {
+    /// Number of selected PRs.
+    pub pr_count: usize,
+    /// Number of distinct work items linked to the selected PRs.
+    pub work_item_count: usize,
+    /// Number of distinct files changed by the selected PRs, if known.
+    pub files_touched: Option<usize>,
+    /// Estimated conflict risk of the selection.
+    pub conflict_risk: ConflictRisk,
+    /// Unselected PRs (from the list) that selected PRs depend on, sorted by ID.
+    pub missing_dependencies: Vec<i32>,
+}
+
+/// Computes a [`SelectionSummary`] for the selected PRs.
+///
+/// File counts, conflict risk and missing dependencies require a dependency
+/// graph; without one, `files_touched` is `None`, the risk is
+/// [`ConflictRisk::Unknown`] and no missing dependencies are reported.
+///
+/// # Arguments
+///
+/// * `prs` - All PRs in the selection list
+/// * `graph` - The dependency graph, if dependency analysis was run
+pub fn summarize_selection(
+    prs: &[PullRequestWithWorkItems],
+    graph: Option<&PRDependencyGraph>,
+) -> SelectionSummary {
+    let selected: Vec<&PullRequestWithWorkItems> = prs.iter().filter(|pr| pr.selected).collect();
+    let selected_ids: HashSet<i32> = selected.iter().map(|pr| pr.pr.id).collect();
+    let listed_ids: HashSet<i32> = prs.iter().map(|pr| pr.pr.id).collect();
+
+    let work_item_count = selected
+        .iter()
+        .flat_map(|pr| pr.work_items.iter().map(|wi| wi.id))
+        .collect::<HashSet<_>>()
+        .len();
+
+    let Some(graph) = graph else {
+        return SelectionSummary {
+            pr_count: selected.len(),
+            work_item_count,
+            files_touched: None,
+            conflict_risk: ConflictRisk::Unknown,
+            missing_dependencies: Vec::new(),
+        };
+    };
+
+    let mut files = HashSet::new();
+    let mut missing = BTreeSet::new();
+    let mut conflict_risk = ConflictRisk::Low;
+
+    for node in selected.iter().filter_map(|pr| graph.get_node(pr.pr.id)) {
+        files.extend(node.changed_files.iter());
+
+        for dep in &node.dependencies {
+            if selected_ids.contains(&dep.to_pr_id) || !listed_ids.contains(&dep.to_pr_id) {
+                continue;
+            }
+            let risk = match dep.category {
+                DependencyCategory::Independent => continue,
+                DependencyCategory::PartiallyDependent { .. } => ConflictRisk::Medium,
+                DependencyCategory::Dependent { .. } => ConflictRisk::High,
+            };
+            conflict_risk = conflict_risk.max(risk);
+            missing.insert(dep.to_pr_id);
+        }
+    }
+
+    SelectionSummary {
+        pr_count: selected.len(),
+        work_item_count,
+        files_touched: Some(files.len()),
+        conflict_risk,
+        missing_dependencies: missing.into_iter().collect(),
+    }
+}
+
+/// Default selected PR count above which the TUI asks for confirmation.
+pub const DEFAULT_SELECTION_SOFT_CAP: usize = 50;
+
+/// Default selected PR count above which non-interactive runs fail.
+pub const DEFAULT_SELECTION_HARD_CAP: usize = 100;
+
+/// Limits on how many PRs can be merged in one run without extra confirmation.
+///
+/// A `None` cap is disabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectionCaps {
+    /// Above this count the TUI shows a confirmation dialog.
+    pub soft: Option<usize>,
+    /// Above this count non-interactive runs fail unless forced.
+    pub hard: Option<usize>,
+}
+
+impl Default for SelectionCaps {
+    fn default() -> Self {
+        Self {
+            soft: Some(DEFAULT_SELECTION_SOFT_CAP),
+            hard: Some(DEFAULT_SELECTION_HARD_CAP),
+        }
+    }
+}
+
+impl SelectionCaps {
+    /// Builds caps from configured values, where `0` disables a cap and
+    /// `None` keeps the default.
+    pub fn new(soft: Option<usize>, hard: Option<usize>) -> Self {
+        let resolve = |value: Option<usize>, default: usize| match value.unwrap_or(default) {
+            0 => None,
+            cap => Some(cap),
+        };
+        Self {
+            soft: resolve(soft, DEFAULT_SELECTION_SOFT_CAP),
+            hard: resolve(hard, DEFAULT_SELECTION_HARD_CAP),
+        }
+    }
+
+    /// Returns `true` if `count` PRs need confirmation in the TUI.
+    pub fn exceeds_soft(&self, count: usize) -> bool {
+        self.soft.is_some_and(|cap| count > cap)
+    }
+
+    /// Returns `true` if `count` PRs are refused in non-interactive mode.
+    pub fn exceeds_hard(&self, count: usize) -> bool {
+        self.hard.is_some_and(|cap| count > cap)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,6 +346,7 @@ mod tests {
                     description: None,
                     repro_steps: None,
                     state_color: None,
+                    custom_fields: Default::default(),
                 },
                 history: Vec::new(),
             })
@@ -161,6 +363,7 @@ mod tests {
                 },
                 labels: None,
                 last_merge_commit: None,
+                reviewers: Vec::new(),
             },
             work_items,
             selected: false,
@@ -302,6 +505,31 @@ mod tests {
     /// - Creates multiple PRs with different work item states
     /// - Selects by "Ready" state
     ///
+    /// # All Work Items In States
+    ///
+    /// Verifies the per-PR check used to hide terminal PRs.
+    ///
+    /// ## Test Scenario
+    /// - Checks PRs with closed, mixed and no work items against "Closed"
+    /// - Checks a closed PR against an empty state list
+    ///
+    /// ## Expected Outcome
+    /// - Only the PR whose work items are all closed matches
+    /// - Nothing matches when no states are configured
+    #[test]
+    fn test_all_work_items_in_states() {
+        let closed = create_pr_with_work_items(1, vec![("WI 1", Some("closed"))]);
+        let mixed =
+            create_pr_with_work_items(2, vec![("WI 1", Some("Closed")), ("WI 2", Some("Active"))]);
+        let empty = create_pr_with_work_items(3, vec![]);
+        let states = vec!["Closed".to_string()];
+
+        assert!(all_work_items_in_states(&closed, &states));
+        assert!(!all_work_items_in_states(&mixed, &states));
+        assert!(!all_work_items_in_states(&empty, &states));
+        assert!(!all_work_items_in_states(&closed, &[]));
+    }
+
     /// ## Expected Outcome
     /// - Only matching PRs are selected
     /// - Returns correct count
@@ -421,4 +649,167 @@ mod tests {
         assert_eq!(result[1].pr.id, 75);
         assert_eq!(result[2].pr.id, 25);
     }
+
+    /// # Unknown Work Item States
+    ///
+    /// Tests detecting work item states outside the known set.
+    ///
+    /// ## Test Scenario
+    /// - Checks PRs with known, unknown (in different case) and missing states
+    ///
+    /// ## Expected Outcome
+    /// - Known states match case-insensitively and missing states are ignored
+    /// - Unknown states are grouped with sorted, deduplicated PR IDs
+    /// - The description lists each state with its PRs
+    #[test]
+    fn test_find_unknown_work_item_states() {
+        let prs = vec![
+            create_pr_with_work_items(3, vec![("WI", Some("Blocked"))]),
+            create_pr_with_work_items(
+                1,
+                vec![("WI 1", Some("ready")), ("WI 2", Some("In Review"))],
+            ),
+            create_pr_with_work_items(2, vec![("WI", Some("In Review")), ("WI", None)]),
+            create_pr_with_work_items(4, vec![("WI", Some("In Review"))]),
+        ];
+        let known = vec!["Ready".to_string(), "Next Merged".to_string()];
+
+        let unknown = find_unknown_work_item_states(&prs, &known);
+        assert_eq!(unknown.len(), 2);
+        assert_eq!(unknown["Blocked"], vec![3]);
+        assert_eq!(unknown["In Review"], vec![1, 2, 4]);
+        assert_eq!(
+            describe_unknown_states(&unknown),
+            "'Blocked' (PR #3), 'In Review' (PR #1, #2, #4)"
+        );
+
+        let known = vec![
+            "ready".to_string(),
+            "in review".to_string(),
+            "blocked".to_string(),
+        ];
+        assert!(find_unknown_work_item_states(&prs, &known).is_empty());
+    }
+
+    /// # Selection Summary Without Dependency Graph
+    ///
+    /// Verifies totals when dependency analysis was not run.
+    ///
+    /// ## Test Scenario
+    /// - Selects two PRs sharing one work item ID
+    /// - Summarizes without a graph
+    ///
+    /// ## Expected Outcome
+    /// - PR and distinct work item counts are reported
+    /// - File count is unknown and risk is Unknown
+    #[test]
+    fn test_summarize_selection_without_graph() {
+        let mut prs = vec![
+            create_pr_with_work_items(1, vec![("WI 1", Some("Ready"))]),
+            create_pr_with_work_items(2, vec![("WI 1", Some("Ready")), ("WI 2", None)]),
+            create_pr_with_work_items(3, vec![("WI 1", Some("Ready"))]),
+        ];
+        prs[0].selected = true;
+        prs[1].selected = true;
+
+        let summary = summarize_selection(&prs, None);
+
+        assert_eq!(summary.pr_count, 2);
+        assert_eq!(summary.work_item_count, 2);
+        assert_eq!(summary.files_touched, None);
+        assert_eq!(summary.conflict_risk, ConflictRisk::Unknown);
+        assert!(summary.missing_dependencies.is_empty());
+    }
+
+    /// # Selection Summary With Missing Dependencies
+    ///
+    /// Verifies file totals, missing dependencies and conflict risk.
+    ///
+    /// ## Test Scenario
+    /// - PR 3 depends on PR 1 (overlapping lines) and PR 2 (shared file)
+    /// - Only PR 3 is selected, then PRs 1 and 3
+    ///
+    /// ## Expected Outcome
+    /// - Both unselected dependencies are reported and risk is High
+    /// - Selecting PR 1 lowers the risk to Medium
+    #[test]
+    fn test_summarize_selection_with_graph() {
+        use crate::core::operations::dependency_analysis::{
+            OverlappingFile, PRDependency, PRDependencyNode,
+        };
+
+        let mut prs = vec![
+            create_pr_with_work_items(1, vec![]),
+            create_pr_with_work_items(2, vec![]),
+            create_pr_with_work_items(3, vec![]),
+        ];
+        prs[2].selected = true;
+
+        let mut graph = PRDependencyGraph::new();
+        let mut node1 = PRDependencyNode::new(1, "PR 1".to_string(), false);
+        node1.changed_files = vec!["a.rs".to_string()];
+        graph.add_node(node1);
+        graph.add_node(PRDependencyNode::new(2, "PR 2".to_string(), false));
+        let mut node3 = PRDependencyNode::new(3, "PR 3".to_string(), true);
+        node3.changed_files = vec!["a.rs".to_string(), "b.rs".to_string()];
+        node3.dependencies = vec![
+            PRDependency {
+                from_pr_id: 3,
+                to_pr_id: 1,
+                category: DependencyCategory::Dependent {
+                    shared_files: vec!["a.rs".to_string()],
+                    overlapping_files: vec![OverlappingFile {
+                        path: "a.rs".to_string(),
+                        overlapping_ranges: vec![],
+                    }],
+                },
+            },
+            PRDependency {
+                from_pr_id: 3,
+                to_pr_id: 2,
+                category: DependencyCategory::PartiallyDependent {
+                    shared_files: vec!["b.rs".to_string()],
+                },
+            },
+        ];
+        graph.add_node(node3);
+
+        let summary = summarize_selection(&prs, Some(&graph));
+        assert_eq!(summary.pr_count, 1);
+        assert_eq!(summary.files_touched, Some(2));
+        assert_eq!(summary.missing_dependencies, vec![1, 2]);
+        assert_eq!(summary.conflict_risk, ConflictRisk::High);
+
+        prs[0].selected = true;
+        let summary = summarize_selection(&prs, Some(&graph));
+        assert_eq!(summary.files_touched, Some(2));
+        assert_eq!(summary.missing_dependencies, vec![2]);
+        assert_eq!(summary.conflict_risk, ConflictRisk::Medium);
+    }
+
+    /// # Selection Caps
+    ///
+    /// Tests resolving configured caps and checking counts against them.
+    ///
+    /// ## Test Scenario
+    /// - Builds caps from unset, custom and zero values
+    ///
+    /// ## Expected Outcome
+    /// - Unset values use the defaults, zero disables a cap
+    /// - Counts equal to a cap do not exceed it
+    #[test]
+    fn test_selection_caps() {
+        assert_eq!(SelectionCaps::new(None, None), SelectionCaps::default());
+
+        let caps = SelectionCaps::new(Some(10), Some(20));
+        assert!(!caps.exceeds_soft(10));
+        assert!(caps.exceeds_soft(11));
+        assert!(!caps.exceeds_hard(20));
+        assert!(caps.exceeds_hard(21));
+
+        let disabled = SelectionCaps::new(Some(0), Some(0));
+        assert_eq!(disabled.soft, None);
+        assert!(!disabled.exceeds_soft(10_000));
+        assert!(!disabled.exceeds_hard(10_000));
+    }
 }
@@ -0,0 +1,348 @@
+//! Column widths and sort order of the PR selection table.
+//!
+//! Columns can be widened or narrowed and the table sorted by several columns
+//! at once, so long PR titles and work item lists fit without truncation. The
+//! layout is kept in a small JSON file in the state directory, one per
+//! repository, and restored the next time PR selection opens.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::core::operations::pr_table_layout::{PrColumn, PrTableLayout};
+//!
+//! let mut layout = PrTableLayout::default();
+//! layout.sort_by(PrColumn::Author);
+//! layout.add_sort_key(PrColumn::Date);
+//! assert_eq!(layout.sort_rank(PrColumn::Date), Some((1, false)));
+//!
+//! layout.resize(PrColumn::Title, 40, 4);
+//! assert_eq!(layout.width(PrColumn::Title), Some(44));
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::core::state::state_dir;
+
+/// Narrowest width a column can be resized to.
+pub const MIN_COLUMN_WIDTH: u16 = 3;
+
+/// Widest width a column can be resized to.
+pub const MAX_COLUMN_WIDTH: u16 = 200;
+
+/// A column of the PR selection table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PrColumn {
+    /// Selection marker.
+    Selected,
+    /// Position in the cherry-pick order.
+    Order,
+    /// PR number.
+    Id,
+    /// Closing date.
+    Date,
+    /// PR title.
+    Title,
+    /// PR author.
+    Author,
+    /// Linked work items and their states.
+    WorkItems,
+    /// Partial and full dependency counts.
+    Dependencies,
+}
+
+impl PrColumn {
+    /// All columns in table order.
+    pub const ALL: [PrColumn; 8] = [
+        PrColumn::Selected,
+        PrColumn::Order,
+        PrColumn::Id,
+        PrColumn::Date,
+        PrColumn::Title,
+        PrColumn::Author,
+        PrColumn::WorkItems,
+        PrColumn::Dependencies,
+    ];
+
+    /// Position of the column in the table.
+    pub fn index(self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|&column| column == self)
+            .unwrap_or_default()
+    }
+
+    /// The column header.
+    pub fn header(self) -> &'static str {
+        match self {
+            PrColumn::Selected => "",
+            PrColumn::Order => "Pick",
+            PrColumn::Id => "PR #",
+            PrColumn::Date => "Date",
+            PrColumn::Title => "Title",
+            PrColumn::Author => "Author",
+            PrColumn::WorkItems => "Work Items",
+            PrColumn::Dependencies => "PR Dependencies",
+        }
+    }
+
+    /// The neighbouring column, wrapping around at the ends.
+    pub fn step(self, forward: bool) -> Self {
+        let count = Self::ALL.len();
+        let index = if forward {
+            (self.index() + 1) % count
+        } else {
+            (self.index() + count - 1) % count
+        };
+        Self::ALL[index]
+    }
+}
+
+/// One key of a multi-column sort.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SortKey {
+    /// The column sorted by.
+    pub column: PrColumn,
+    /// Whether the column sorts in descending order.
+    #[serde(default)]
+    pub descending: bool,
+}
+
+/// Column widths and sort order of the PR selection table.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrTableLayout {
+    /// Widths of resized columns; other columns keep their default width.
+    #[serde(default)]
+    widths: BTreeMap<PrColumn, u16>,
+    /// Sort keys, most significant first. Empty keeps the loaded order.
+    #[serde(default)]
+    sort: Vec<SortKey>,
+}
+
+impl PrTableLayout {
+    /// Returns the layout file for a repository.
+    ///
+    /// The file lives in the state directory (see [`state_dir`]) and is
+    /// keyed by a hash of the organization, project and repository.
+    pub fn path_for(organization: &str, project: &str, repository: &str) -> Result<PathBuf> {
+        let mut hasher = Sha256::new();
+        hasher.update(format!("{}/{}/{}", organization, project, repository));
+        let hash: String = hasher.finalize()[..8]
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        Ok(state_dir()?.join(format!("pr-table-{}.json", hash)))
+    }
+
+    /// Loads a layout, returning the default layout if the file does not exist.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read table layout: {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse table layout: {}", path.display()))
+    }
+
+    /// Saves the layout, creating the parent directory if needed.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!(
+                    "Failed to create table layout directory: {}",
+                    parent.display()
+                )
+            })?;
+        }
+        let contents = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, contents)
+            .with_context(|| format!("Failed to write table layout: {}", path.display()))
+    }
+
+    /// Width of a resized column, or `None` for the default width.
+    pub fn width(&self, column: PrColumn) -> Option<u16> {
+        self.widths.get(&column).copied()
+    }
+
+    /// Changes a column's width by `delta`, starting from `current`.
+    ///
+    /// `current` is the width the column is drawn with, used when the column
+    /// has not been resized yet.
+    pub fn resize(&mut self, column: PrColumn, current: u16, delta: i16) {
+        let base = self.width(column).unwrap_or(current);
+        let width = base
+            .saturating_add_signed(delta)
+            .clamp(MIN_COLUMN_WIDTH, MAX_COLUMN_WIDTH);
+        self.widths.insert(column, width);
+    }
+
+    /// The sort keys, most significant first.
+    pub fn sort_keys(&self) -> &[SortKey] {
+        &self.sort
+    }
+
+    /// Sorts by a single column, or reverses it if it is already the
+    /// primary key.
+    pub fn sort_by(&mut self, column: PrColumn) {
+        match self.sort.first_mut() {
+            Some(key) if key.column == column => key.descending = !key.descending,
+            _ => {
+                self.sort = vec![SortKey {
+                    column,
+                    descending: false,
+                }]
+            }
+        }
+    }
+
+    /// Adds a column as the least significant sort key, or reverses it if
+    /// it is already a key.
+    pub fn add_sort_key(&mut self, column: PrColumn) {
+        match self.sort.iter_mut().find(|key| key.column == column) {
+            Some(key) => key.descending = !key.descending,
+            None => self.sort.push(SortKey {
+                column,
+                descending: false,
+            }),
+        }
+    }
+
+    /// Position of a column among the sort keys and whether it is descending.
+    pub fn sort_rank(&self, column: PrColumn) -> Option<(usize, bool)> {
+        self.sort
+            .iter()
+            .position(|key| key.column == column)
+            .map(|rank| (rank, self.sort[rank].descending))
+    }
+
+    /// Restores the default widths and order.
+    pub fn reset(&mut self) {
+        self.widths.clear();
+        self.sort.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// # Sort Keys
+    ///
+    /// Tests building a multi-column sort.
+    ///
+    /// ## Test Scenario
+    /// - Sorts by author, adds date as secondary key, reverses both
+    /// - Sorts by title
+    ///
+    /// ## Expected Outcome
+    /// - Keys keep their order; repeating a key reverses it
+    /// - Sorting by a new column replaces all keys
+    #[test]
+    fn test_sort_keys() {
+        let mut layout = PrTableLayout::default();
+        layout.sort_by(PrColumn::Author);
+        layout.add_sort_key(PrColumn::Date);
+        assert_eq!(layout.sort_rank(PrColumn::Author), Some((0, false)));
+        assert_eq!(layout.sort_rank(PrColumn::Date), Some((1, false)));
+
+        layout.sort_by(PrColumn::Author);
+        layout.add_sort_key(PrColumn::Date);
+        assert_eq!(layout.sort_rank(PrColumn::Author), Some((0, true)));
+        assert_eq!(layout.sort_rank(PrColumn::Date), Some((1, true)));
+
+        layout.sort_by(PrColumn::Title);
+        assert_eq!(
+            layout.sort_keys(),
+            &[SortKey {
+                column: PrColumn::Title,
+                descending: false
+            }]
+        );
+        assert_eq!(layout.sort_rank(PrColumn::Author), None);
+    }
+
+    /// # Column Resizing
+    ///
+    /// Tests widening and narrowing columns.
+    ///
+    /// ## Test Scenario
+    /// - Widens a column from its drawn width, then narrows it repeatedly
+    /// - Resets the layout
+    ///
+    /// ## Expected Outcome
+    /// - The first resize starts from the drawn width, later ones from the stored width
+    /// - Widths never drop below the minimum
+    /// - Reset restores the default widths
+    #[test]
+    fn test_resize() {
+        let mut layout = PrTableLayout::default();
+        assert_eq!(layout.width(PrColumn::Title), None);
+
+        layout.resize(PrColumn::Title, 30, 2);
+        assert_eq!(layout.width(PrColumn::Title), Some(32));
+        layout.resize(PrColumn::Title, 99, 2);
+        assert_eq!(layout.width(PrColumn::Title), Some(34));
+
+        for _ in 0..20 {
+            layout.resize(PrColumn::Title, 0, -2);
+        }
+        assert_eq!(layout.width(PrColumn::Title), Some(MIN_COLUMN_WIDTH));
+
+        layout.reset();
+        assert_eq!(layout, PrTableLayout::default());
+    }
+
+    /// # Layout Persistence
+    ///
+    /// Tests saving and loading a layout file.
+    ///
+    /// ## Test Scenario
+    /// - Loads from a missing file
+    /// - Saves a layout with widths and sort keys, then loads it
+    ///
+    /// ## Expected Outcome
+    /// - A missing file loads the default layout
+    /// - The loaded layout equals the saved one
+    #[test]
+    fn test_save_and_load() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("nested").join("layout.json");
+        assert_eq!(
+            PrTableLayout::load(&path).unwrap(),
+            PrTableLayout::default()
+        );
+
+        let mut layout = PrTableLayout::default();
+        layout.resize(PrColumn::WorkItems, 20, 10);
+        layout.sort_by(PrColumn::Date);
+        layout.sort_by(PrColumn::Date);
+        layout.add_sort_key(PrColumn::Id);
+        layout.save(&path).unwrap();
+
+        assert_eq!(PrTableLayout::load(&path).unwrap(), layout);
+    }
+
+    /// # Column Navigation
+    ///
+    /// Tests moving the focused column.
+    ///
+    /// ## Test Scenario
+    /// - Steps forward from the last column and backward from the first
+    ///
+    /// ## Expected Outcome
+    /// - Focus wraps around at both ends
+    #[test]
+    fn test_column_step() {
+        assert_eq!(PrColumn::Dependencies.step(true), PrColumn::Selected);
+        assert_eq!(PrColumn::Selected.step(false), PrColumn::Dependencies);
+        assert_eq!(PrColumn::Date.step(true), PrColumn::Title);
+        assert_eq!(PrColumn::Title.index(), 4);
+    }
+}
@@ -0,0 +1,239 @@
+//! User-defined PR grouping tabs for the selection screen.
+//!
+//! Large release branches mix changes from several areas. Tabs configured
+//! in the config file (e.g. "Backend", "Frontend", "Infra") split the PR
+//! selection table into groups, each defined by path globs matched against
+//! the files a PR changes and regular expressions matched against its title.
+//! A PR belongs to every tab with a matching rule.
+//!
+//! # Config Format
+//!
+//! ```toml
+//! [[pr_tabs]]
+//! name = "Backend"
+//! paths = ["src/server/**", "*.sql"]
+//!
+//! [[pr_tabs]]
+//! name = "Infra"
+//! paths = ["deploy/**"]
+//! titles = ["(?i)^\\[infra\\]"]
+//! ```
+//!
+//! Changed files come from dependency analysis, so path rules only match
+//! when `local_repo` is configured.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::core::operations::{PrTab, PrTabConfig};
+//!
+//! let tab = PrTab::new(&PrTabConfig {
+//!     name: "Backend".to_string(),
+//!     paths: vec!["src/server/**".to_string()],
+//!     titles: vec!["(?i)api".to_string()],
+//! })
+//! .unwrap();
+//! assert!(tab.matches("Fix login", &["src/server/auth/login.rs".to_string()]));
+//! assert!(tab.matches("Update API docs", &[]));
+//! assert!(!tab.matches("Fix login", &["web/login.ts".to_string()]));
+//! ```
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// A tab as written in the config file.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct PrTabConfig {
+    /// Name shown in the tab bar.
+    pub name: String,
+    /// Globs matched against changed file paths.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+    /// Regular expressions matched against PR titles.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub titles: Vec<String>,
+}
+
+/// A validated PR grouping tab.
+#[derive(Debug, Clone)]
+pub struct PrTab {
+    name: String,
+    paths: Vec<Regex>,
+    titles: Vec<Regex>,
+}
+
+impl PrTab {
+    /// Compiles a configured tab.
+    ///
+    /// Returns an error naming the tab if it has no rules or a title
+    /// pattern is not a valid regular expression.
+    pub fn new(config: &PrTabConfig) -> Result<Self> {
+        let name = config.name.trim();
+        if name.is_empty() {
+            anyhow::bail!("PR tab names must not be empty");
+        }
+        if config.paths.is_empty() && config.titles.is_empty() {
+            anyhow::bail!("PR tab '{}' needs at least one path or title rule", name);
+        }
+
+        let paths = config
+            .paths
+            .iter()
+            .map(|glob| glob_to_regex(glob))
+            .collect();
+        let titles = config
+            .titles
+            .iter()
+            .map(|pattern| {
+                Regex::new(pattern).with_context(|| {
+                    format!("Invalid title pattern in PR tab '{}': {}", name, pattern)
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            name: name.to_string(),
+            paths,
+            titles,
+        })
+    }
+
+    /// Name shown in the tab bar.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns `true` if the title or any changed file matches a rule.
+    pub fn matches(&self, title: &str, changed_files: &[String]) -> bool {
+        self.titles.iter().any(|regex| regex.is_match(title))
+            || changed_files
+                .iter()
+                .any(|path| self.paths.iter().any(|regex| regex.is_match(path)))
+    }
+}
+
+/// Compiles all configured tabs, keeping their order.
+pub fn build_pr_tabs(configs: &[PrTabConfig]) -> Result<Vec<PrTab>> {
+    configs.iter().map(PrTab::new).collect()
+}
+
+/// Translates a path glob into an anchored regular expression.
+///
+/// `*` and `?` stay within one path segment and `**` spans segments. A glob
+/// without a `/` matches the file name at any depth, like in `.gitignore`.
+pub(crate) fn glob_to_regex(glob: &str) -> Regex {
+    let glob = glob.trim().trim_start_matches('/');
+    let mut pattern = String::from(if glob.contains('/') { "^" } else { "(^|/)" });
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    // `**/` also matches no directories at all
+                    chars.next();
+                    pattern.push_str("(.*/)?");
+                } else {
+                    pattern.push_str(".*");
+                }
+            }
+            '*' => pattern.push_str("[^/]*"),
+            '?' => pattern.push_str("[^/]"),
+            c => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    pattern.push('$');
+    Regex::new(&pattern).expect("escaped glob is a valid regex")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tab(paths: &[&str], titles: &[&str]) -> PrTab {
+        PrTab::new(&PrTabConfig {
+            name: "Tab".to_string(),
+            paths: paths.iter().map(|p| p.to_string()).collect(),
+            titles: titles.iter().map(|t| t.to_string()).collect(),
+        })
+        .unwrap()
+    }
+
+    fn files(paths: &[&str]) -> Vec<String> {
+        paths.iter().map(|p| p.to_string()).collect()
+    }
+
+    /// # Path Globs
+    ///
+    /// Tests matching changed files against path globs.
+    ///
+    /// ## Test Scenario
+    /// - Matches paths against `src/server/**`, `*.sql`, `docs/*.md` and
+    ///   `**/Cargo.toml`
+    ///
+    /// ## Expected Outcome
+    /// - `**` spans directories, `*` stays within one
+    /// - Globs without `/` match file names at any depth
+    #[test]
+    fn test_path_globs() {
+        let server = tab(&["src/server/**"], &[]);
+        assert!(server.matches("", &files(&["src/server/api/users.rs"])));
+        assert!(!server.matches("", &files(&["src/web/server.ts"])));
+
+        let sql = tab(&["*.sql"], &[]);
+        assert!(sql.matches("", &files(&["schema.sql"])));
+        assert!(sql.matches("", &files(&["db/migrations/001.sql"])));
+        assert!(!sql.matches("", &files(&["db/schema.sqlite"])));
+
+        let docs = tab(&["docs/*.md"], &[]);
+        assert!(docs.matches("", &files(&["docs/index.md"])));
+        assert!(!docs.matches("", &files(&["docs/api/index.md"])));
+
+        let manifests = tab(&["**/Cargo.toml"], &[]);
+        assert!(manifests.matches("", &files(&["Cargo.toml"])));
+        assert!(manifests.matches("", &files(&["crates/core/Cargo.toml"])));
+    }
+
+    /// # Title Patterns
+    ///
+    /// Tests matching PR titles and validating tab configs.
+    ///
+    /// ## Test Scenario
+    /// - Matches titles against a case-insensitive pattern
+    /// - Builds tabs without rules, without a name and with a bad regex
+    ///
+    /// ## Expected Outcome
+    /// - Titles match regardless of changed files
+    /// - Invalid tabs are rejected with the tab name in the error
+    #[test]
+    fn test_title_patterns_and_validation() {
+        let infra = tab(&[], &["(?i)^\\[infra\\]"]);
+        assert!(infra.matches("[Infra] Bump runners", &[]));
+        assert!(!infra.matches("Fix infra docs", &[]));
+
+        let error = PrTab::new(&PrTabConfig {
+            name: "Empty".to_string(),
+            ..Default::default()
+        })
+        .unwrap_err();
+        assert!(error.to_string().contains("'Empty'"));
+
+        assert!(
+            PrTab::new(&PrTabConfig {
+                name: " ".to_string(),
+                titles: vec!["x".to_string()],
+                ..Default::default()
+            })
+            .is_err()
+        );
+
+        let error = build_pr_tabs(&[PrTabConfig {
+            name: "Broken".to_string(),
+            titles: vec!["(".to_string()],
+            ..Default::default()
+        }])
+        .unwrap_err();
+        assert!(error.to_string().contains("'Broken'"));
+    }
+}
@@ -0,0 +1,446 @@
+//! Recovery of merges interrupted by a crash.
+//!
+//! When the machine or terminal dies mid-merge, the state file and the
+//! worktree can disagree: a pick may be committed on the patch branch while
+//! the state file still lists it as pending, or a cherry-pick may be stopped
+//! half-way. [`inspect`] compares the state file with the patch branch and
+//! reports exactly which PRs were applied, [`reconcile`] writes those
+//! findings back so the merge can resume from the first missing PR.
+//!
+//! Picks are found on the patch branch by the commit recorded in the state
+//! file or, for picks the state file never heard of, by patch-id.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::core::output::{ItemStatus, RecoveryInfo, RecoveryItem};
+use crate::core::state::{MergePhase, MergeStateFile, StateItemStatus};
+use crate::git;
+use crate::utils::Clock;
+
+/// What `merge recover` does after inspecting the merge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoverAction {
+    /// Only report what was applied.
+    Inspect,
+    /// Record the applied picks and continue with the remaining PRs.
+    Resume,
+    /// Remove the worktree and patch branch.
+    Abort,
+    /// Keep the applied commits on a rescue branch.
+    Export,
+}
+
+/// Name of the patch branch a merge commits its picks to.
+pub fn patch_branch(state: &MergeStateFile) -> String {
+    format!("patch/{}-{}", state.target_branch, state.merge_version)
+}
+
+/// Default name of the branch `merge recover --export` creates, stamped
+/// with the current time of `clock`.
+pub fn default_rescue_branch(state: &MergeStateFile, clock: &dyn Clock) -> String {
+    format!(
+        "rescue/{}-{}-{}",
+        state.target_branch,
+        state.merge_version,
+        clock.now().format("%Y%m%d-%H%M%S")
+    )
+}
+
+/// Repository and revision holding the applied picks.
+///
+/// This is `HEAD` of the worktree, or the patch branch in the base
+/// repository if the worktree is gone.
+pub fn picks_location(state: &MergeStateFile) -> Option<(PathBuf, String)> {
+    if state.repo_path.exists() {
+        return Some((state.repo_path.clone(), "HEAD".to_string()));
+    }
+    let base = state.base_repo_path.as_ref()?;
+    let branch = patch_branch(state);
+    git::branch_exists(base, &branch)
+        .unwrap_or(false)
+        .then(|| (base.clone(), branch))
+}
+
+/// Compares the state file with the patch branch.
+pub fn inspect(state: &MergeStateFile) -> Result<RecoveryInfo> {
+    let worktree_exists = state.repo_path.exists();
+    let mut info = RecoveryInfo {
+        version: state.merge_version.clone(),
+        target_branch: state.target_branch.clone(),
+        phase: state.phase.to_string(),
+        repo_path: state.repo_path.clone(),
+        worktree_exists,
+        branch: patch_branch(state),
+        head: None,
+        cherry_pick_in_progress: false,
+        uncommitted_changes: false,
+        conflicted_files: Vec::new(),
+        resume_index: 0,
+        items: Vec::new(),
+        rescue_branch: None,
+    };
+
+    if worktree_exists {
+        info.head = git::head_commit(&state.repo_path).ok();
+        info.cherry_pick_in_progress = git::cherry_pick_in_progress(&state.repo_path);
+        info.uncommitted_changes = git::has_uncommitted_changes(&state.repo_path)?;
+        if info.cherry_pick_in_progress {
+            info.conflicted_files = git::unresolved_files(&state.repo_path).unwrap_or_default();
+        }
+    }
+
+    let applied = match picks_location(state) {
+        Some((repo, revision)) => find_applied(state, &repo, &revision)?,
+        None => HashMap::new(),
+    };
+
+    info.items = state
+        .cherry_pick_items
+        .iter()
+        .map(|item| RecoveryItem {
+            pr_id: item.pr_id,
+            pr_title: item.pr_title.clone(),
+            commit_id: item.commit_id.clone(),
+            recorded: item_status(&item.status),
+            applied_commit: applied.get(&item.commit_id).cloned(),
+        })
+        .collect();
+    info.resume_index = info
+        .items
+        .iter()
+        .position(|item| {
+            item.applied_commit.is_none()
+                && matches!(
+                    item.recorded,
+                    ItemStatus::Pending | ItemStatus::Conflict | ItemStatus::Success
+                )
+        })
+        .unwrap_or(info.items.len());
+
+    Ok(info)
+}
+
+/// Writes the findings of [`inspect`] back into the state file.
+///
+/// Applied picks are marked as succeeded, picks missing from the branch are
+/// pending again, and the merge continues at the first PR still to pick. A
+/// stopped cherry-pick leaves the merge awaiting conflict resolution.
+pub fn reconcile(state: &mut MergeStateFile, info: &RecoveryInfo) {
+    for (item, found) in state.cherry_pick_items.iter_mut().zip(&info.items) {
+        if let Some(commit) = &found.applied_commit {
+            item.status = StateItemStatus::Success;
+            item.picked_commit_id = Some(commit.clone());
+        } else if found.is_missing() {
+            item.status = StateItemStatus::Pending;
+            item.picked_commit_id = None;
+        }
+    }
+
+    state.current_index = info.resume_index;
+    if info.cherry_pick_in_progress && info.resume_index < state.cherry_pick_items.len() {
+        let item = &mut state.cherry_pick_items[info.resume_index];
+        item.status = StateItemStatus::Conflict;
+        item.had_conflict = true;
+        state.phase = MergePhase::AwaitingConflictResolution;
+        state.conflicted_files = Some(info.conflicted_files.clone());
+    } else {
+        state.phase = if info.resume_index < state.cherry_pick_items.len() {
+            MergePhase::CherryPicking
+        } else {
+            MergePhase::ReadyForCompletion
+        };
+        state.conflicted_files = None;
+    }
+}
+
+/// Maps each item's commit to the commit on the patch branch applying it.
+fn find_applied(
+    state: &MergeStateFile,
+    repo: &Path,
+    revision: &str,
+) -> Result<HashMap<String, String>> {
+    let base = [
+        format!("origin/{}", state.target_branch),
+        state.target_branch.clone(),
+    ]
+    .into_iter()
+    .find(|base| git::commit_exists(repo, base));
+    let Some(base) = base else {
+        return Ok(HashMap::new());
+    };
+
+    let picks = git::branch_log_since(repo, revision, &base)?;
+    let pick_ids: HashSet<&str> = picks.iter().map(|pick| pick.commit_id.as_str()).collect();
+    let picks_by_patch: HashMap<&str, &str> = picks
+        .iter()
+        .filter_map(|pick| Some((pick.patch_id.as_deref()?, pick.commit_id.as_str())))
+        .collect();
+
+    let originals: Vec<String> = state
+        .cherry_pick_items
+        .iter()
+        .map(|item| item.commit_id.clone())
+        .filter(|commit| git::commit_exists(repo, commit))
+        .collect();
+    let original_patches = git::patch_ids(repo, &originals)?;
+
+    Ok(state
+        .cherry_pick_items
+        .iter()
+        .filter_map(|item| {
+            let recorded = item
+                .picked_commit_id
+                .as_deref()
+                .filter(|commit| pick_ids.contains(commit));
+            let by_patch = || {
+                original_patches
+                    .get(&item.commit_id)
+                    .and_then(|patch| picks_by_patch.get(patch.as_str()).copied())
+            };
+            let applied = recorded.or_else(by_patch)?;
+            Some((item.commit_id.clone(), applied.to_string()))
+        })
+        .collect())
+}
+
+fn item_status(status: &StateItemStatus) -> ItemStatus {
+    match status {
+        StateItemStatus::Pending => ItemStatus::Pending,
+        StateItemStatus::Success => ItemStatus::Success,
+        StateItemStatus::Conflict => ItemStatus::Conflict,
+        StateItemStatus::Skipped => ItemStatus::Skipped,
+        StateItemStatus::Failed { .. } => ItemStatus::Failed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::state::StateCherryPickItem;
+    use crate::utils::FixedClock;
+    use chrono::{TimeZone, Utc};
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(repo: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    fn item(pr_id: i32, commit_id: &str, status: StateItemStatus) -> StateCherryPickItem {
+        StateCherryPickItem {
+            commit_id: commit_id.to_string(),
+            pr_id,
+            pr_title: format!("PR {}", pr_id),
+            status,
+            work_item_ids: vec![],
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
+        }
+    }
+
+    /// Creates a repository whose patch branch holds picks of the first two
+    /// of three `dev` commits, with only the first recorded in the state.
+    fn setup_interrupted_merge() -> (TempDir, MergeStateFile) {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        git(repo, &["init", "-q", "-b", "main"]);
+        git(repo, &["config", "user.name", "Test User"]);
+        git(repo, &["config", "user.email", "test@example.com"]);
+        git(repo, &["config", "commit.gpgsign", "false"]);
+        std::fs::write(repo.join("base.txt"), "base\n").unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-q", "-m", "Initial"]);
+
+        git(repo, &["checkout", "-q", "-b", "dev"]);
+        let mut commits = Vec::new();
+        for n in 1..=3 {
+            std::fs::write(repo.join(format!("pr{}.txt", n)), "change\n").unwrap();
+            git(repo, &["add", "."]);
+            git(repo, &["commit", "-q", "-m", &format!("PR {}", n)]);
+            commits.push(git(repo, &["rev-parse", "HEAD"]));
+        }
+
+        git(repo, &["checkout", "-q", "-b", "patch/main-1.0.0", "main"]);
+        git(repo, &["cherry-pick", &commits[0]]);
+        let first_pick = git(repo, &["rev-parse", "HEAD"]);
+        git(repo, &["cherry-pick", &commits[1]]);
+
+        let mut state = MergeStateFile::new(
+            repo.to_path_buf(),
+            None,
+            false,
+            "org".to_string(),
+            "project".to_string(),
+            "repo".to_string(),
+            "dev".to_string(),
+            "main".to_string(),
+            "1.0.0".to_string(),
+            "Done".to_string(),
+            "merged-".to_string(),
+            false,
+        );
+        state.phase = MergePhase::CherryPicking;
+        state.cherry_pick_items = vec![
+            item(1, &commits[0], StateItemStatus::Success),
+            item(2, &commits[1], StateItemStatus::Pending),
+            item(3, &commits[2], StateItemStatus::Pending),
+        ];
+        state.cherry_pick_items[0].picked_commit_id = Some(first_pick);
+        state.current_index = 1;
+        (temp_dir, state)
+    }
+
+    /// # Default Rescue Branch
+    ///
+    /// Tests the name of the branch an export creates by default.
+    ///
+    /// ## Test Scenario
+    /// - Names the rescue branch of a merge with a fixed clock
+    ///
+    /// ## Expected Outcome
+    /// - The name holds the target branch, version and the clock's time
+    #[test]
+    fn test_default_rescue_branch() {
+        let (_temp_dir, state) = setup_interrupted_merge();
+        let clock = FixedClock::new(Utc.with_ymd_and_hms(2025, 3, 1, 12, 30, 5).unwrap());
+
+        assert_eq!(
+            default_rescue_branch(&state, &clock),
+            "rescue/main-1.0.0-20250301-123005"
+        );
+    }
+
+    /// # Inspect Interrupted Merge
+    ///
+    /// Tests that inspection finds picks the state file never recorded.
+    ///
+    /// ## Test Scenario
+    /// - The patch branch holds picks of PR 1 and PR 2
+    /// - The state file only records PR 1
+    ///
+    /// ## Expected Outcome
+    /// - PR 1 is found by its recorded commit, PR 2 by patch-id
+    /// - PR 2 is reported as unrecorded and the merge resumes at PR 3
+    #[test]
+    fn test_inspect_finds_unrecorded_picks() {
+        let (_temp_dir, state) = setup_interrupted_merge();
+        let info = inspect(&state).unwrap();
+
+        assert!(info.worktree_exists);
+        assert!(!info.cherry_pick_in_progress);
+        assert!(!info.uncommitted_changes);
+        assert_eq!(info.branch, "patch/main-1.0.0");
+        assert_eq!(
+            info.items[0].applied_commit,
+            state.cherry_pick_items[0].picked_commit_id
+        );
+        assert_eq!(info.items[1].applied_commit, info.head);
+        assert!(info.items[2].applied_commit.is_none());
+
+        let unrecorded: Vec<i32> = info.unrecorded().map(|item| item.pr_id).collect();
+        assert_eq!(unrecorded, vec![2]);
+        assert_eq!(info.missing().count(), 0);
+        assert_eq!(info.resume_index, 2);
+    }
+
+    /// # Reconcile Interrupted Merge
+    ///
+    /// Tests writing the inspection back into the state file.
+    ///
+    /// ## Test Scenario
+    /// - Reconciles the state with the inspection of an interrupted merge
+    ///
+    /// ## Expected Outcome
+    /// - PR 2 is marked as succeeded with the commit found on the branch
+    /// - The merge continues at PR 3 in the cherry-picking phase
+    #[test]
+    fn test_reconcile_records_applied_picks() {
+        let (_temp_dir, mut state) = setup_interrupted_merge();
+        let info = inspect(&state).unwrap();
+        reconcile(&mut state, &info);
+
+        assert_eq!(state.cherry_pick_items[1].status, StateItemStatus::Success);
+        assert_eq!(state.cherry_pick_items[1].picked_commit_id, info.head);
+        assert_eq!(state.cherry_pick_items[2].status, StateItemStatus::Pending);
+        assert_eq!(state.current_index, 2);
+        assert_eq!(state.phase, MergePhase::CherryPicking);
+    }
+
+    /// # Reconcile Missing Pick
+    ///
+    /// Tests that a pick recorded as succeeded but absent from the branch is
+    /// picked again.
+    ///
+    /// ## Test Scenario
+    /// - Resets the patch branch to before the first pick
+    ///
+    /// ## Expected Outcome
+    /// - PR 1 is reported missing and set back to pending
+    /// - The merge resumes at PR 1
+    #[test]
+    fn test_reconcile_missing_pick() {
+        let (temp_dir, mut state) = setup_interrupted_merge();
+        git(temp_dir.path(), &["reset", "-q", "--hard", "main"]);
+
+        let info = inspect(&state).unwrap();
+        let missing: Vec<i32> = info.missing().map(|item| item.pr_id).collect();
+        assert_eq!(missing, vec![1]);
+        assert_eq!(info.resume_index, 0);
+
+        reconcile(&mut state, &info);
+        assert_eq!(state.cherry_pick_items[0].status, StateItemStatus::Pending);
+        assert!(state.cherry_pick_items[0].picked_commit_id.is_none());
+        assert_eq!(state.current_index, 0);
+    }
+
+    /// # Reconcile Stopped Cherry-Pick
+    ///
+    /// Tests that a cherry-pick stopped on a conflict resumes as a conflict.
+    ///
+    /// ## Test Scenario
+    /// - Starts a conflicting cherry-pick of PR 3 in the worktree
+    ///
+    /// ## Expected Outcome
+    /// - The conflict and its files are reported
+    /// - PR 3 is marked as conflicted and the merge awaits resolution
+    #[test]
+    fn test_reconcile_stopped_cherry_pick() {
+        let (temp_dir, mut state) = setup_interrupted_merge();
+        let repo = temp_dir.path();
+        std::fs::write(repo.join("pr3.txt"), "conflict\n").unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-q", "-m", "Conflicting change"]);
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(["cherry-pick", &state.cherry_pick_items[2].commit_id])
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "cherry-pick should conflict");
+
+        let info = inspect(&state).unwrap();
+        assert!(info.cherry_pick_in_progress);
+        assert_eq!(info.conflicted_files, vec!["pr3.txt".to_string()]);
+
+        reconcile(&mut state, &info);
+        assert_eq!(state.phase, MergePhase::AwaitingConflictResolution);
+        assert_eq!(state.cherry_pick_items[2].status, StateItemStatus::Conflict);
+        assert_eq!(state.current_index, 2);
+    }
+}
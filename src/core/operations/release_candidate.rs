@@ -0,0 +1,89 @@
+//! Release candidate (RC) versioning helpers.
+//!
+//! A release candidate is a regular merge whose version carries an `-rcN`
+//! suffix (`1.2.0-rc1`, `1.2.0-rc2`, ...), producing the worktree
+//! `next-1.2.0-rc1` and branch `patch/<target>-1.2.0-rc1`. Once an RC is
+//! accepted, `mergers promote` turns it into the final release version.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::core::operations::release_candidate::{parse_rc_version, rc_version};
+//!
+//! assert_eq!(rc_version("1.2.0", 2), "1.2.0-rc2");
+//! assert_eq!(parse_rc_version("1.2.0-rc2"), Some(("1.2.0", 2)));
+//! assert_eq!(parse_rc_version("1.2.0"), None);
+//! ```
+
+/// Suffix separating the release version from the RC number.
+pub const RC_SUFFIX: &str = "-rc";
+
+/// Builds the version string of release candidate `number` for `release_version`.
+pub fn rc_version(release_version: &str, number: u32) -> String {
+    format!("{}{}{}", release_version, RC_SUFFIX, number)
+}
+
+/// Splits an RC version into its release version and RC number.
+///
+/// Returns `None` if the version has no `-rcN` suffix.
+pub fn parse_rc_version(version: &str) -> Option<(&str, u32)> {
+    let (release_version, number) = version.rsplit_once(RC_SUFFIX)?;
+    if release_version.is_empty() || number.is_empty() {
+        return None;
+    }
+    if !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    number.parse().ok().map(|number| (release_version, number))
+}
+
+/// Returns the first RC number for `release_version` that is not taken.
+///
+/// `exists` is called with candidate RC versions (`1.2.0-rc1`, `1.2.0-rc2`,
+/// ...) and should report whether a worktree or branch for that version
+/// already exists.
+pub fn next_rc_number(release_version: &str, exists: impl Fn(&str) -> bool) -> u32 {
+    (1..)
+        .find(|n| !exists(&rc_version(release_version, *n)))
+        .unwrap_or(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// # Parse RC Versions
+    ///
+    /// Tests splitting RC versions into release version and number.
+    ///
+    /// ## Test Scenario
+    /// - Parses valid RC versions and versions without a valid suffix
+    ///
+    /// ## Expected Outcome
+    /// - Valid versions are split, others return `None`
+    #[test]
+    fn test_parse_rc_version() {
+        assert_eq!(parse_rc_version("1.2.0-rc1"), Some(("1.2.0", 1)));
+        assert_eq!(parse_rc_version("v2.0-rc12"), Some(("v2.0", 12)));
+        assert_eq!(parse_rc_version("1.2.0"), None);
+        assert_eq!(parse_rc_version("1.2.0-rc"), None);
+        assert_eq!(parse_rc_version("1.2.0-rcx"), None);
+        assert_eq!(parse_rc_version("-rc1"), None);
+    }
+
+    /// # Next RC Number
+    ///
+    /// Tests picking the first free RC number.
+    ///
+    /// ## Test Scenario
+    /// - Computes the next number with no existing RCs and with rc1/rc2 taken
+    ///
+    /// ## Expected Outcome
+    /// - Returns 1 and 3 respectively
+    #[test]
+    fn test_next_rc_number() {
+        assert_eq!(next_rc_number("1.2.0", |_| false), 1);
+        let taken = ["1.2.0-rc1", "1.2.0-rc2"];
+        assert_eq!(next_rc_number("1.2.0", |v| taken.contains(&v)), 3);
+    }
+}
@@ -0,0 +1,202 @@
+//! "Ready for release" gate on a custom work item field.
+//!
+//! Teams often track release readiness in a custom field such as
+//! `Custom.QAApproved`. A [`ReleaseGate`] names that field and the value it
+//! must have; PRs whose work items fail the gate are flagged in the TUI and
+//! left out of bulk selection.
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::models::{PullRequestWithWorkItems, WorkItem};
+
+/// A required value for a work item field, e.g. `Custom.QAApproved == true`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseGate {
+    /// Reference name of the field (e.g. `Custom.QAApproved`).
+    pub field: String,
+    /// Value the field must have, compared case-insensitively.
+    pub expected: String,
+}
+
+impl ReleaseGate {
+    /// Returns true if the work item's gate field has the expected value.
+    ///
+    /// A missing field fails the gate.
+    pub fn passes(&self, work_item: &WorkItem) -> bool {
+        work_item
+            .fields
+            .custom_fields
+            .get(&self.field)
+            .and_then(field_value_to_string)
+            .is_some_and(|value| value.eq_ignore_ascii_case(&self.expected))
+    }
+
+    /// Returns the IDs of the PR's work items that fail the gate.
+    pub fn failing_work_items(&self, pr: &PullRequestWithWorkItems) -> Vec<i32> {
+        pr.work_items
+            .iter()
+            .filter(|wi| !self.passes(wi))
+            .map(|wi| wi.id)
+            .collect()
+    }
+
+    /// Returns true if all of the PR's work items pass the gate.
+    ///
+    /// PRs without work items have nothing to fail and pass.
+    pub fn pr_passes(&self, pr: &PullRequestWithWorkItems) -> bool {
+        pr.work_items.iter().all(|wi| self.passes(wi))
+    }
+}
+
+impl FromStr for ReleaseGate {
+    type Err = anyhow::Error;
+
+    /// Parses `Field == value`, or a bare `Field` meaning `Field == true`.
+    fn from_str(s: &str) -> Result<Self> {
+        let (field, expected) = match s.split_once("==") {
+            Some((field, expected)) => (field.trim(), expected.trim()),
+            None => (s.trim(), "true"),
+        };
+        let expected = expected.trim_matches(|c| c == '"' || c == '\'');
+
+        if field.is_empty() || field.contains(char::is_whitespace) {
+            anyhow::bail!(
+                "Invalid release gate '{}' (expected e.g. 'Custom.QAApproved == true')",
+                s
+            );
+        }
+        if expected.is_empty() {
+            anyhow::bail!("Release gate '{}' is missing a value after '=='", s);
+        }
+
+        Ok(Self {
+            field: field.to_string(),
+            expected: expected.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for ReleaseGate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} == {}", self.field, self.expected)
+    }
+}
+
+fn field_value_to_string(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::Null => None,
+        serde_json::Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{PullRequest, WorkItemFields};
+    use std::collections::HashMap;
+
+    fn work_item(id: i32, qa_approved: Option<serde_json::Value>) -> WorkItem {
+        let mut custom_fields = HashMap::new();
+        if let Some(value) = qa_approved {
+            custom_fields.insert("Custom.QAApproved".to_string(), value);
+        }
+        WorkItem {
+            id,
+            fields: WorkItemFields {
+                title: Some(format!("Work item {}", id)),
+                state: Some("Resolved".to_string()),
+                work_item_type: None,
+                assigned_to: None,
+                iteration_path: None,
+                description: None,
+                repro_steps: None,
+                state_color: None,
+                custom_fields,
+            },
+            history: vec![],
+        }
+    }
+
+    fn pr_with(work_items: Vec<WorkItem>) -> PullRequestWithWorkItems {
+        PullRequestWithWorkItems {
+            pr: PullRequest {
+                id: 1,
+                title: "Test PR".to_string(),
+                closed_date: None,
+                created_by: crate::models::CreatedBy {
+                    display_name: "Test User".to_string(),
+                },
+                last_merge_commit: None,
+                labels: None,
+                description: None,
+                reviewers: Vec::new(),
+            },
+            work_items,
+            selected: false,
+        }
+    }
+
+    /// # Release Gate Parsing
+    ///
+    /// Tests parsing gate expressions from configuration.
+    ///
+    /// ## Test Scenario
+    /// - Parses an explicit comparison, a quoted value, and a bare field
+    /// - Parses expressions without a field or value
+    ///
+    /// ## Expected Outcome
+    /// - Valid expressions yield the field and expected value
+    /// - A bare field expects `true`; invalid expressions are rejected
+    #[test]
+    fn test_release_gate_parsing() {
+        let gate: ReleaseGate = "Custom.QAApproved == true".parse().unwrap();
+        assert_eq!(gate.field, "Custom.QAApproved");
+        assert_eq!(gate.expected, "true");
+
+        let gate: ReleaseGate = "Custom.Signoff=='Done'".parse().unwrap();
+        assert_eq!(gate.expected, "Done");
+        assert_eq!(gate.to_string(), "Custom.Signoff == Done");
+
+        let gate: ReleaseGate = "Custom.QAApproved".parse().unwrap();
+        assert_eq!(gate.expected, "true");
+
+        assert!("== true".parse::<ReleaseGate>().is_err());
+        assert!("Custom.QAApproved ==".parse::<ReleaseGate>().is_err());
+    }
+
+    /// # Release Gate Evaluation
+    ///
+    /// Tests evaluating the gate against work items and PRs.
+    ///
+    /// ## Test Scenario
+    /// - Evaluates boolean, string, missing and null field values
+    /// - Evaluates PRs with passing, failing and no work items
+    ///
+    /// ## Expected Outcome
+    /// - Values match case-insensitively; missing and null fail
+    /// - A PR passes only when every work item passes
+    #[test]
+    fn test_release_gate_evaluation() {
+        let gate: ReleaseGate = "Custom.QAApproved == true".parse().unwrap();
+
+        assert!(gate.passes(&work_item(1, Some(serde_json::json!(true)))));
+        assert!(gate.passes(&work_item(2, Some(serde_json::json!("True")))));
+        assert!(!gate.passes(&work_item(3, Some(serde_json::json!(false)))));
+        assert!(!gate.passes(&work_item(4, Some(serde_json::Value::Null))));
+        assert!(!gate.passes(&work_item(5, None)));
+
+        let pr = pr_with(vec![
+            work_item(1, Some(serde_json::json!(true))),
+            work_item(5, None),
+        ]);
+        assert!(!gate.pr_passes(&pr));
+        assert_eq!(gate.failing_work_items(&pr), vec![5]);
+
+        assert!(gate.pr_passes(&pr_with(vec![work_item(1, Some(serde_json::json!(true)))])));
+        assert!(gate.pr_passes(&pr_with(vec![])));
+    }
+}
@@ -0,0 +1,612 @@
+//! Release history and time-to-release metrics for `mergers metrics`.
+//!
+//! Every completed merge appends a [`ReleaseRecord`] to
+//! `release-history.jsonl` in the state directory. A record keeps, per PR,
+//! when the PR was completed on the dev branch, whether it shipped or was
+//! deferred (skipped or never picked), and whether its cherry-pick hit a
+//! conflict. [`ReleaseMetrics`] summarizes the history for dashboards: time
+//! from PR completion to release, conflicts per release and PRs deferred
+//! more than once.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chrono::{Duration, Utc};
+//! use mergers::core::operations::release_history::{
+//!     ReleaseMetrics, ReleaseOutcome, ReleaseRecord, ReleasedPr,
+//! };
+//! use mergers::core::state::MergeStatus;
+//!
+//! let completed_at = Utc::now();
+//! let record = ReleaseRecord {
+//!     organization: "org".to_string(),
+//!     project: "project".to_string(),
+//!     repository: "repo".to_string(),
+//!     target_branch: "main".to_string(),
+//!     version: "1.2.3".to_string(),
+//!     started_at: completed_at - Duration::hours(1),
+//!     completed_at,
+//!     status: MergeStatus::Success,
+//!     prs: vec![ReleasedPr {
+//!         pr_id: 456,
+//!         pr_title: "Fix login".to_string(),
+//!         closed_at: Some(completed_at - Duration::hours(48)),
+//!         outcome: ReleaseOutcome::Released,
+//!         had_conflict: true,
+//!     }],
+//! };
+//!
+//! let metrics = ReleaseMetrics::compute(&[record]);
+//! assert_eq!(metrics.releases[0].conflicts, 1);
+//! assert_eq!(metrics.median_hours_to_release, Some(48.0));
+//! ```
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::state::{MergeStateFile, MergeStatus, StateItemStatus, state_dir};
+
+/// Name of the release history file in the state directory.
+pub const HISTORY_FILE_NAME: &str = "release-history.jsonl";
+
+/// What happened to a PR in a release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReleaseOutcome {
+    /// Cherry-picked into the release.
+    Released,
+    /// Skipped or never picked; left for a later release.
+    Deferred,
+    /// The cherry-pick failed.
+    Failed,
+}
+
+/// A PR considered for a release.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReleasedPr {
+    /// The PR ID.
+    pub pr_id: i32,
+    /// The PR title.
+    pub pr_title: String,
+    /// When the PR was completed on the dev branch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closed_at: Option<DateTime<Utc>>,
+    /// What happened to the PR.
+    pub outcome: ReleaseOutcome,
+    /// Whether the cherry-pick hit a conflict.
+    #[serde(default)]
+    pub had_conflict: bool,
+}
+
+/// One completed merge in the release history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReleaseRecord {
+    /// Azure DevOps organization name.
+    pub organization: String,
+    /// Azure DevOps project name.
+    pub project: String,
+    /// Azure DevOps repository name.
+    pub repository: String,
+    /// Target branch of the release.
+    pub target_branch: String,
+    /// Release version.
+    pub version: String,
+    /// When the merge was started.
+    pub started_at: DateTime<Utc>,
+    /// When the merge was completed.
+    pub completed_at: DateTime<Utc>,
+    /// Final status of the merge.
+    pub status: MergeStatus,
+    /// PRs considered for the release.
+    pub prs: Vec<ReleasedPr>,
+}
+
+impl ReleaseRecord {
+    /// Builds the record of a completed merge, or `None` if the merge has
+    /// not completed yet.
+    pub fn from_state(state: &MergeStateFile) -> Option<Self> {
+        let completed_at = state.completed_at?;
+        Some(Self {
+            organization: state.organization.clone(),
+            project: state.project.clone(),
+            repository: state.repository.clone(),
+            target_branch: state.target_branch.clone(),
+            version: state.merge_version.clone(),
+            started_at: state.created_at,
+            completed_at,
+            status: state.final_status.unwrap_or(MergeStatus::Success),
+            prs: state
+                .cherry_pick_items
+                .iter()
+                .map(|item| ReleasedPr {
+                    pr_id: item.pr_id,
+                    pr_title: item.pr_title.clone(),
+                    closed_at: item.pr_closed_at,
+                    outcome: match item.status {
+                        StateItemStatus::Success => ReleaseOutcome::Released,
+                        StateItemStatus::Failed { .. } => ReleaseOutcome::Failed,
+                        StateItemStatus::Pending
+                        | StateItemStatus::Conflict
+                        | StateItemStatus::Skipped => ReleaseOutcome::Deferred,
+                    },
+                    had_conflict: item.had_conflict,
+                })
+                .collect(),
+        })
+    }
+
+    /// Whether the record belongs to the given repository and target branch.
+    ///
+    /// `None` matches any value.
+    pub fn matches(&self, repository: Option<&str>, target_branch: Option<&str>) -> bool {
+        repository.is_none_or(|repo| self.repository == repo)
+            && target_branch.is_none_or(|branch| self.target_branch == branch)
+    }
+}
+
+/// Returns the release history file in the state directory.
+pub fn history_path() -> Result<PathBuf> {
+    Ok(state_dir()?.join(HISTORY_FILE_NAME))
+}
+
+/// Appends a record to the history file, creating it if needed.
+pub fn append_record(path: &Path, record: &ReleaseRecord) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).with_context(|| {
+            format!(
+                "Failed to create release history directory: {}",
+                parent.display()
+            )
+        })?;
+    }
+    let line = serde_json::to_string(record)?;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open release history: {}", path.display()))?;
+    writeln!(file, "{}", line)
+        .with_context(|| format!("Failed to write release history: {}", path.display()))
+}
+
+/// Loads all records, returning an empty history if the file does not exist.
+pub fn load_history(path: &Path) -> Result<Vec<ReleaseRecord>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read release history: {}", path.display()))?;
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            serde_json::from_str(line).with_context(|| {
+                format!(
+                    "Failed to parse release history: {}:{}",
+                    path.display(),
+                    idx + 1
+                )
+            })
+        })
+        .collect()
+}
+
+/// Appends a completed merge to the release history in the state directory.
+///
+/// Does nothing for merges that have not completed.
+pub fn record_release(state: &MergeStateFile) -> Result<()> {
+    match ReleaseRecord::from_state(state) {
+        Some(record) => append_record(&history_path()?, &record),
+        None => Ok(()),
+    }
+}
+
+/// Metrics of a single release.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReleaseMetricsRow {
+    /// Release version.
+    pub version: String,
+    /// Repository name.
+    pub repository: String,
+    /// Target branch of the release.
+    pub target_branch: String,
+    /// When the release was completed.
+    pub completed_at: DateTime<Utc>,
+    /// PRs cherry-picked into the release.
+    pub released: usize,
+    /// PRs skipped or left for a later release.
+    pub deferred: usize,
+    /// PRs whose cherry-pick failed.
+    pub failed: usize,
+    /// PRs whose cherry-pick hit a conflict.
+    pub conflicts: usize,
+    /// Deferred PRs that had already been deferred by an earlier release.
+    pub repeatedly_deferred: usize,
+    /// Median hours from PR completion on the dev branch to the release.
+    pub median_hours_to_release: Option<f64>,
+}
+
+/// A PR deferred by more than one release.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RepeatedlyDeferredPr {
+    /// The PR ID.
+    pub pr_id: i32,
+    /// The PR title.
+    pub pr_title: String,
+    /// Number of releases that deferred the PR.
+    pub times_deferred: usize,
+    /// Version that finally released the PR, if any.
+    pub released_in: Option<String>,
+}
+
+/// Time-to-release metrics over the release history.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReleaseMetrics {
+    /// Per-release metrics, oldest first.
+    pub releases: Vec<ReleaseMetricsRow>,
+    /// Median hours from PR completion to release over all releases.
+    pub median_hours_to_release: Option<f64>,
+    /// PRs deferred more than once.
+    pub repeatedly_deferred: Vec<RepeatedlyDeferredPr>,
+}
+
+impl ReleaseMetrics {
+    /// Computes the metrics of a release history.
+    pub fn compute(records: &[ReleaseRecord]) -> Self {
+        let mut records: Vec<&ReleaseRecord> = records.iter().collect();
+        records.sort_by_key(|record| record.completed_at);
+
+        let mut deferrals: HashMap<i32, RepeatedlyDeferredPr> = HashMap::new();
+        let mut all_hours = Vec::new();
+        let mut releases = Vec::with_capacity(records.len());
+
+        for record in records {
+            let mut hours = Vec::new();
+            let mut row = ReleaseMetricsRow {
+                version: record.version.clone(),
+                repository: record.repository.clone(),
+                target_branch: record.target_branch.clone(),
+                completed_at: record.completed_at,
+                released: 0,
+                deferred: 0,
+                failed: 0,
+                conflicts: 0,
+                repeatedly_deferred: 0,
+                median_hours_to_release: None,
+            };
+
+            for pr in &record.prs {
+                if pr.had_conflict {
+                    row.conflicts += 1;
+                }
+                match pr.outcome {
+                    ReleaseOutcome::Released => {
+                        row.released += 1;
+                        if let Some(closed_at) = pr.closed_at {
+                            let elapsed = record.completed_at - closed_at;
+                            hours.push(elapsed.num_seconds() as f64 / 3600.0);
+                        }
+                        if let Some(entry) = deferrals.get_mut(&pr.pr_id) {
+                            entry.released_in = Some(record.version.clone());
+                        }
+                    }
+                    ReleaseOutcome::Deferred => {
+                        row.deferred += 1;
+                        let entry =
+                            deferrals
+                                .entry(pr.pr_id)
+                                .or_insert_with(|| RepeatedlyDeferredPr {
+                                    pr_id: pr.pr_id,
+                                    pr_title: pr.pr_title.clone(),
+                                    times_deferred: 0,
+                                    released_in: None,
+                                });
+                        entry.times_deferred += 1;
+                        if entry.times_deferred > 1 {
+                            row.repeatedly_deferred += 1;
+                        }
+                    }
+                    ReleaseOutcome::Failed => row.failed += 1,
+                }
+            }
+
+            row.median_hours_to_release = median(&mut hours);
+            all_hours.extend(hours);
+            releases.push(row);
+        }
+
+        let mut repeatedly_deferred: Vec<RepeatedlyDeferredPr> = deferrals
+            .into_values()
+            .filter(|pr| pr.times_deferred > 1)
+            .collect();
+        repeatedly_deferred.sort_by(|a, b| {
+            b.times_deferred
+                .cmp(&a.times_deferred)
+                .then(a.pr_id.cmp(&b.pr_id))
+        });
+
+        Self {
+            releases,
+            median_hours_to_release: median(&mut all_hours),
+            repeatedly_deferred,
+        }
+    }
+
+    /// Renders the metrics as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).context("Failed to serialize release metrics")
+    }
+
+    /// Renders the per-release metrics as CSV with a header row.
+    pub fn to_csv(&self) -> String {
+        let mut output = String::from(
+            "version,repository,target_branch,completed_at,released,deferred,failed,conflicts,repeatedly_deferred,median_hours_to_release\n",
+        );
+        for row in &self.releases {
+            output.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{},{}\n",
+                csv_field(&row.version),
+                csv_field(&row.repository),
+                csv_field(&row.target_branch),
+                row.completed_at.to_rfc3339(),
+                row.released,
+                row.deferred,
+                row.failed,
+                row.conflicts,
+                row.repeatedly_deferred,
+                row.median_hours_to_release
+                    .map(|hours| format!("{:.2}", hours))
+                    .unwrap_or_default(),
+            ));
+        }
+        output
+    }
+}
+
+/// Returns the median of the values, or `None` if there are none.
+fn median(values: &mut [f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    Some(if values.len() % 2 == 0 {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    })
+}
+
+/// Quotes a CSV field if it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn pr(pr_id: i32, outcome: ReleaseOutcome, closed_hours_ago: Option<i64>) -> ReleasedPr {
+        ReleasedPr {
+            pr_id,
+            pr_title: format!("PR {}", pr_id),
+            closed_at: closed_hours_ago.map(|hours| base_time() - Duration::hours(hours)),
+            outcome,
+            had_conflict: false,
+        }
+    }
+
+    fn base_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap()
+    }
+
+    fn record(version: &str, days: i64, prs: Vec<ReleasedPr>) -> ReleaseRecord {
+        let completed_at = base_time() + Duration::days(days);
+        ReleaseRecord {
+            organization: "org".to_string(),
+            project: "project".to_string(),
+            repository: "repo".to_string(),
+            target_branch: "main".to_string(),
+            version: version.to_string(),
+            started_at: completed_at - Duration::hours(1),
+            completed_at,
+            status: MergeStatus::Success,
+            prs,
+        }
+    }
+
+    /// # Release Metrics
+    ///
+    /// Tests computing metrics over several releases.
+    ///
+    /// ## Test Scenario
+    /// - Three releases given out of order
+    /// - PR 3 is deferred twice and released in the third release
+    /// - PR 4 is deferred twice and never released
+    /// - One PR in the first release hit a conflict
+    ///
+    /// ## Expected Outcome
+    /// - Releases are ordered by completion time
+    /// - Counts, conflicts and per-release medians are computed
+    /// - PRs deferred more than once are listed, most deferred first
+    #[test]
+    fn test_release_metrics() {
+        let mut conflicted = pr(1, ReleaseOutcome::Released, Some(10));
+        conflicted.had_conflict = true;
+        let records = vec![
+            record(
+                "1.2.0",
+                7,
+                vec![
+                    pr(3, ReleaseOutcome::Released, Some(0)),
+                    pr(4, ReleaseOutcome::Deferred, None),
+                ],
+            ),
+            record(
+                "1.0.0",
+                0,
+                vec![
+                    conflicted,
+                    pr(2, ReleaseOutcome::Released, Some(20)),
+                    pr(3, ReleaseOutcome::Deferred, None),
+                    pr(4, ReleaseOutcome::Deferred, None),
+                    pr(5, ReleaseOutcome::Failed, None),
+                ],
+            ),
+            record(
+                "1.1.0",
+                1,
+                vec![
+                    pr(3, ReleaseOutcome::Deferred, None),
+                    pr(4, ReleaseOutcome::Deferred, None),
+                ],
+            ),
+        ];
+
+        let metrics = ReleaseMetrics::compute(&records);
+
+        let versions: Vec<&str> = metrics
+            .releases
+            .iter()
+            .map(|row| row.version.as_str())
+            .collect();
+        assert_eq!(versions, vec!["1.0.0", "1.1.0", "1.2.0"]);
+
+        let first = &metrics.releases[0];
+        assert_eq!(
+            (
+                first.released,
+                first.deferred,
+                first.failed,
+                first.conflicts
+            ),
+            (2, 2, 1, 1)
+        );
+        assert_eq!(first.median_hours_to_release, Some(15.0));
+        assert_eq!(metrics.releases[1].repeatedly_deferred, 2);
+        assert_eq!(metrics.releases[1].median_hours_to_release, None);
+        // PR 3 closed at the base time and shipped 7 days later
+        assert_eq!(metrics.releases[2].median_hours_to_release, Some(168.0));
+        assert_eq!(metrics.median_hours_to_release, Some(20.0));
+
+        assert_eq!(
+            metrics.repeatedly_deferred,
+            vec![
+                RepeatedlyDeferredPr {
+                    pr_id: 4,
+                    pr_title: "PR 4".to_string(),
+                    times_deferred: 3,
+                    released_in: None,
+                },
+                RepeatedlyDeferredPr {
+                    pr_id: 3,
+                    pr_title: "PR 3".to_string(),
+                    times_deferred: 2,
+                    released_in: Some("1.2.0".to_string()),
+                },
+            ]
+        );
+
+        let csv = metrics.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("version,repository,target_branch,completed_at"));
+        assert_eq!(
+            lines[1],
+            "1.0.0,repo,main,2025-06-01T12:00:00+00:00,2,2,1,1,0,15.00"
+        );
+        assert!(lines[2].ends_with(",0,2,0,0,2,"));
+    }
+
+    /// # Release History Round Trip
+    ///
+    /// Tests recording completed merges and loading them back.
+    ///
+    /// ## Test Scenario
+    /// - Builds records from an in-progress and a completed state file
+    /// - Appends the completed record twice to a history file
+    ///
+    /// ## Expected Outcome
+    /// - In-progress merges produce no record
+    /// - Item statuses map to release outcomes and keep the conflict flag
+    /// - Loading returns every appended record; a missing file is empty
+    #[test]
+    fn test_release_history_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(HISTORY_FILE_NAME);
+        assert!(load_history(&path).unwrap().is_empty());
+
+        let mut state = MergeStateFile::builder()
+            .repo_path(dir.path())
+            .organization("org")
+            .project("project")
+            .repository("repo")
+            .dev_branch("dev")
+            .target_branch("main")
+            .merge_version("1.0.0")
+            .work_item_state("Done")
+            .tag_prefix("merged-")
+            .build();
+        for (pr_id, status, had_conflict) in [
+            (1, StateItemStatus::Success, true),
+            (2, StateItemStatus::Skipped, false),
+            (
+                3,
+                StateItemStatus::Failed {
+                    message: "boom".to_string(),
+                },
+                false,
+            ),
+        ] {
+            state
+                .cherry_pick_items
+                .push(crate::core::state::StateCherryPickItem {
+                    commit_id: format!("commit{}", pr_id),
+                    pr_id,
+                    pr_title: format!("PR {}", pr_id),
+                    status,
+                    work_item_ids: Vec::new(),
+                    work_item_titles: Default::default(),
+                    picked_commit_id: None,
+                    mainline_parent: None,
+                    pr_closed_at: Some(base_time()),
+                    had_conflict,
+                });
+        }
+        assert!(ReleaseRecord::from_state(&state).is_none());
+
+        state.completed_at = Some(base_time() + Duration::days(1));
+        state.final_status = Some(MergeStatus::PartialSuccess);
+        let record = ReleaseRecord::from_state(&state).unwrap();
+        let outcomes: Vec<(ReleaseOutcome, bool)> = record
+            .prs
+            .iter()
+            .map(|pr| (pr.outcome, pr.had_conflict))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                (ReleaseOutcome::Released, true),
+                (ReleaseOutcome::Deferred, false),
+                (ReleaseOutcome::Failed, false),
+            ]
+        );
+        assert!(record.matches(Some("repo"), None));
+        assert!(!record.matches(Some("repo"), Some("next")));
+
+        append_record(&path, &record).unwrap();
+        append_record(&path, &record).unwrap();
+        assert_eq!(load_history(&path).unwrap(), vec![record.clone(), record]);
+    }
+}
@@ -0,0 +1,300 @@
+//! Release labels applied to PRs by merge mode.
+//!
+//! After a merge, every included PR is labeled `{tag_prefix}{version}`. This
+//! module groups those labels by version so a withdrawn release can be found
+//! and its labels removed from all of its PRs in one batch.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::core::operations::group_release_labels;
+//! use mergers::models::{CreatedBy, Label, PullRequest};
+//!
+//! let pr = |id: i32, labels: &[&str]| PullRequest {
+//!     id,
+//!     title: format!("PR {}", id),
+//!     closed_date: None,
+//!     created_by: CreatedBy { display_name: "Dev".to_string() },
+//!     last_merge_commit: None,
+//!     labels: Some(labels.iter().map(|name| Label { name: name.to_string() }).collect()),
+//!     description: None,
+//!     reviewers: Vec::new(),
+//! };
+//!
+//! let labels = group_release_labels(
+//!     &[pr(1, &["merged-1.2.0", "hotfix"]), pr(2, &["merged-1.2.0"]), pr(3, &["merged-1.10.0"])],
+//!     "merged-",
+//! );
+//! assert_eq!(labels[0].version, "1.10.0");
+//! assert_eq!(labels[1].version, "1.2.0");
+//! assert_eq!(labels[1].pr_ids, vec![1, 2]);
+//! ```
+
+use crate::api::AzureDevOpsClient;
+use crate::models::PullRequest;
+use futures::stream::{self, StreamExt};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+/// A release label and the PRs that carry it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseLabel {
+    /// Full label name, e.g. `merged-1.2.0`.
+    pub name: String,
+    /// Label name without the tag prefix, e.g. `1.2.0`.
+    pub version: String,
+    /// PRs carrying the label, in ascending id order.
+    pub pr_ids: Vec<i32>,
+    /// Whether the label is selected for removal.
+    pub selected: bool,
+}
+
+/// Removal of one label from one PR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelRemoval {
+    /// The PR to remove the label from.
+    pub pr_id: i32,
+    /// The label to remove.
+    pub label: String,
+}
+
+/// Outcome of a single [`LabelRemoval`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelRemovalResult {
+    /// The removal that was attempted.
+    pub removal: LabelRemoval,
+    /// The error message if the API call failed.
+    pub error: Option<String>,
+}
+
+/// Groups the labels starting with `tag_prefix` by version.
+///
+/// Labels are ordered newest version first; numeric version parts are
+/// compared as numbers, so `1.10.0` sorts before `1.2.0`, which sorts before
+/// `1.2.0-rc1`.
+pub fn group_release_labels(prs: &[PullRequest], tag_prefix: &str) -> Vec<ReleaseLabel> {
+    let mut groups: BTreeMap<&str, Vec<i32>> = BTreeMap::new();
+    for pr in prs {
+        for label in pr.labels.iter().flatten() {
+            if label.name.starts_with(tag_prefix) && label.name.len() > tag_prefix.len() {
+                groups.entry(label.name.as_str()).or_default().push(pr.id);
+            }
+        }
+    }
+
+    let mut labels: Vec<ReleaseLabel> = groups
+        .into_iter()
+        .map(|(name, mut pr_ids)| {
+            pr_ids.sort_unstable();
+            pr_ids.dedup();
+            ReleaseLabel {
+                name: name.to_string(),
+                version: name[tag_prefix.len()..].to_string(),
+                pr_ids,
+                selected: false,
+            }
+        })
+        .collect();
+    labels.sort_by(|a, b| compare_versions(&b.version, &a.version));
+    labels
+}
+
+/// Lists one removal per PR for every selected label.
+pub fn selected_removals(labels: &[ReleaseLabel]) -> Vec<LabelRemoval> {
+    labels
+        .iter()
+        .filter(|label| label.selected)
+        .flat_map(|label| {
+            label.pr_ids.iter().map(|pr_id| LabelRemoval {
+                pr_id: *pr_id,
+                label: label.name.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Drops successfully removed labels from `labels`.
+///
+/// Labels left on no PR are removed from the list. Labels with failed
+/// removals stay selected so the removal can be retried.
+pub fn apply_removal_results(labels: &mut Vec<ReleaseLabel>, results: &[LabelRemovalResult]) {
+    for result in results.iter().filter(|r| r.error.is_none()) {
+        if let Some(label) = labels.iter_mut().find(|l| l.name == result.removal.label) {
+            label.pr_ids.retain(|id| *id != result.removal.pr_id);
+        }
+    }
+    labels.retain(|label| !label.pr_ids.is_empty());
+}
+
+/// Removes labels from PRs with at most `max_concurrent` requests in flight.
+///
+/// `on_result` is called as each request completes, in completion order.
+pub async fn remove_labels<F>(
+    client: &AzureDevOpsClient,
+    removals: Vec<LabelRemoval>,
+    max_concurrent: usize,
+    mut on_result: F,
+) where
+    F: FnMut(LabelRemovalResult),
+{
+    stream::iter(removals)
+        .map(|removal| async move {
+            let error = client
+                .remove_label_from_pr(removal.pr_id, &removal.label)
+                .await
+                .err()
+                .map(|e| format!("{:#}", e));
+            LabelRemovalResult { removal, error }
+        })
+        .buffer_unordered(max_concurrent.max(1))
+        .for_each(|result| {
+            on_result(result);
+            async {}
+        })
+        .await;
+}
+
+/// Compares versions part by part, numerically where both parts are numbers.
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    let mut a_parts = a.split(['.', '-']);
+    let mut b_parts = b.split(['.', '-']);
+    loop {
+        match (a_parts.next(), b_parts.next()) {
+            (None, None) => return Ordering::Equal,
+            // A pre-release suffix (`1.2.0-rc1`) sorts before the release.
+            (None, Some(y)) => return numeric_or(y, Ordering::Less, Ordering::Greater),
+            (Some(x), None) => return numeric_or(x, Ordering::Greater, Ordering::Less),
+            (Some(x), Some(y)) => {
+                let ordering = match (x.parse::<u64>(), y.parse::<u64>()) {
+                    (Ok(x), Ok(y)) => x.cmp(&y),
+                    _ => x.cmp(y),
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+    }
+}
+
+/// Returns `numeric` if `part` is a number, `other` otherwise.
+fn numeric_or(part: &str, numeric: Ordering, other: Ordering) -> Ordering {
+    if part.parse::<u64>().is_ok() {
+        numeric
+    } else {
+        other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CreatedBy, Label};
+
+    fn pr(id: i32, labels: &[&str]) -> PullRequest {
+        PullRequest {
+            id,
+            title: format!("PR {}", id),
+            closed_date: None,
+            created_by: CreatedBy {
+                display_name: "Dev".to_string(),
+            },
+            last_merge_commit: None,
+            labels: Some(
+                labels
+                    .iter()
+                    .map(|name| Label {
+                        name: name.to_string(),
+                    })
+                    .collect(),
+            ),
+            description: None,
+            reviewers: Vec::new(),
+        }
+    }
+
+    /// # Group Release Labels
+    ///
+    /// Tests grouping tag-prefixed labels by version.
+    ///
+    /// ## Test Scenario
+    /// - PRs carry release labels, unrelated labels and a bare prefix label
+    /// - One PR lists the same label twice
+    ///
+    /// ## Expected Outcome
+    /// - Only prefixed labels with a version are returned, newest first
+    /// - Each label lists its PRs once, in ascending order
+    #[test]
+    fn test_group_release_labels() {
+        let prs = vec![
+            pr(3, &["merged-1.2.0", "merged-1.2.0"]),
+            pr(1, &["merged-1.2.0", "needs-review"]),
+            pr(2, &["merged-1.10.0", "merged-"]),
+            pr(4, &["merged-1.2.0-rc1"]),
+            PullRequest {
+                labels: None,
+                ..pr(5, &[])
+            },
+        ];
+
+        let labels = group_release_labels(&prs, "merged-");
+
+        let versions: Vec<&str> = labels.iter().map(|l| l.version.as_str()).collect();
+        assert_eq!(versions, vec!["1.10.0", "1.2.0", "1.2.0-rc1"]);
+        assert_eq!(labels[1].name, "merged-1.2.0");
+        assert_eq!(labels[1].pr_ids, vec![1, 3]);
+        assert!(labels.iter().all(|l| !l.selected));
+    }
+
+    /// # Removal Batch
+    ///
+    /// Tests building the removals for selected labels and applying results.
+    ///
+    /// ## Test Scenario
+    /// - Selects one of two labels, builds its removals
+    /// - Applies results with one success and one failure, then all successes
+    ///
+    /// ## Expected Outcome
+    /// - One removal per PR of the selected label only
+    /// - A partly removed label keeps its failed PR; a fully removed one is dropped
+    #[test]
+    fn test_removals() {
+        let mut labels = group_release_labels(&[pr(1, &["v1.1", "v1.0"]), pr(2, &["v1.1"])], "v");
+        labels[0].selected = true;
+
+        let removals = selected_removals(&labels);
+        assert_eq!(
+            removals,
+            vec![
+                LabelRemoval {
+                    pr_id: 1,
+                    label: "v1.1".to_string()
+                },
+                LabelRemoval {
+                    pr_id: 2,
+                    label: "v1.1".to_string()
+                },
+            ]
+        );
+
+        let mut results = vec![
+            LabelRemovalResult {
+                removal: removals[0].clone(),
+                error: None,
+            },
+            LabelRemovalResult {
+                removal: removals[1].clone(),
+                error: Some("forbidden".to_string()),
+            },
+        ];
+        apply_removal_results(&mut labels, &results);
+        assert_eq!(labels.len(), 2);
+        assert_eq!(labels[0].pr_ids, vec![2]);
+        assert!(labels[0].selected);
+
+        results[1].error = None;
+        apply_removal_results(&mut labels, &results);
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].name, "v1.0");
+    }
+}
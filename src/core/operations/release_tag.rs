@@ -0,0 +1,146 @@
+//! Annotated git release tags created when a merge completes.
+//!
+//! PR labels and work item states record a release in Azure DevOps only.
+//! With a release tag enabled, completing a merge also tags the last
+//! cherry-picked commit as `v<version>`, with the release notes as the tag
+//! message, and optionally pushes the tag to `origin`.
+//!
+//! # Config Format
+//!
+//! ```toml
+//! # Tag the patch branch as v<version> when the merge completes
+//! release_tag = true
+//! # Also push the tag to origin (implies release_tag)
+//! push_release_tag = true
+//! ```
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::git;
+
+/// Whether a release tag is created on completion, and whether it is pushed.
+///
+/// Modes are ordered from off to push, so `max` combines a configured mode
+/// with flags that can only turn more on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReleaseTagMode {
+    /// No tag is created.
+    #[default]
+    Off,
+    /// The tag is created in the local repository.
+    Create,
+    /// The tag is created and pushed to `origin`.
+    Push,
+}
+
+impl ReleaseTagMode {
+    /// Returns the mode for the `release_tag` and `push_release_tag` settings.
+    pub fn from_flags(release_tag: bool, push_release_tag: bool) -> Self {
+        if push_release_tag {
+            ReleaseTagMode::Push
+        } else if release_tag {
+            ReleaseTagMode::Create
+        } else {
+            ReleaseTagMode::Off
+        }
+    }
+
+    /// Returns true if a tag is created.
+    pub fn is_enabled(self) -> bool {
+        self != ReleaseTagMode::Off
+    }
+
+    /// Returns true if the tag is pushed to `origin`.
+    pub fn pushes(self) -> bool {
+        self == ReleaseTagMode::Push
+    }
+}
+
+/// A release tag to create on the HEAD of the patch branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseTag {
+    /// Repository (or worktree) holding the patch branch.
+    pub repo_path: PathBuf,
+    /// Tag name, e.g. `v1.2.3`.
+    pub name: String,
+    /// Tag message, usually the release notes.
+    pub message: String,
+    /// Whether the tag is pushed to `origin`.
+    pub push: bool,
+    /// Whether the tag is signed (`sign_commits`).
+    pub sign: bool,
+}
+
+impl ReleaseTag {
+    /// Describes the release tag of `version` in `repo_path`.
+    pub fn new(repo_path: PathBuf, version: &str, message: String, push: bool) -> Self {
+        Self {
+            repo_path,
+            name: git::release_tag_name(version),
+            message,
+            push,
+            sign: false,
+        }
+    }
+
+    /// Signs the tag with the configured GPG or SSH key.
+    pub fn signed(mut self, sign: bool) -> Self {
+        self.sign = sign;
+        self
+    }
+
+    /// Creates the tag and pushes it when requested.
+    pub fn create(&self) -> Result<()> {
+        git::create_annotated_tag(&self.repo_path, &self.name, &self.message, self.sign)?;
+        if self.push {
+            git::push_tag(&self.repo_path, &self.name)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// # Release Tag Mode
+    ///
+    /// Tests deriving the release tag mode from the config flags.
+    ///
+    /// ## Test Scenario
+    /// - Combines `release_tag` and `push_release_tag`
+    ///
+    /// ## Expected Outcome
+    /// - `push_release_tag` wins and implies creating the tag
+    /// - Only the push mode pushes
+    #[test]
+    fn test_release_tag_mode() {
+        assert_eq!(
+            ReleaseTagMode::from_flags(false, false),
+            ReleaseTagMode::Off
+        );
+        assert_eq!(
+            ReleaseTagMode::from_flags(true, false),
+            ReleaseTagMode::Create
+        );
+        assert_eq!(
+            ReleaseTagMode::from_flags(false, true),
+            ReleaseTagMode::Push
+        );
+        assert!(!ReleaseTagMode::Off.is_enabled());
+        assert!(ReleaseTagMode::Create.is_enabled());
+        assert!(!ReleaseTagMode::Create.pushes());
+        assert!(ReleaseTagMode::Push.pushes());
+        assert_eq!(
+            ReleaseTagMode::Push.max(ReleaseTagMode::Create),
+            ReleaseTagMode::Push
+        );
+
+        let tag = ReleaseTag::new(PathBuf::from("repo"), "1.2.3", String::new(), false);
+        assert_eq!(tag.name, "v1.2.3");
+    }
+}
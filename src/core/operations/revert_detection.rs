@@ -0,0 +1,220 @@
+//! Detection of PRs reverted on the dev branch.
+//!
+//! A PR whose change was later rolled back upstream should not be
+//! cherry-picked. Reverts are recognized among the fetched PRs by:
+//!
+//! - a description referencing the original PR, e.g. `Reverts !1234`, as
+//!   written by Azure DevOps' revert button
+//! - a description containing `This reverts commit <sha>`, as written by
+//!   `git revert`, where `<sha>` is the merge commit of the original PR
+//! - a title of the form `Revert "<original title>"`
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::core::operations::RevertIndex;
+//! use mergers::models::{CreatedBy, PullRequest};
+//!
+//! let pr = |id: i32, title: &str, description: Option<&str>| PullRequest {
+//!     id,
+//!     title: title.to_string(),
+//!     closed_date: None,
+//!     created_by: CreatedBy { display_name: "Dev".to_string() },
+//!     last_merge_commit: None,
+//!     labels: None,
+//!     description: description.map(str::to_string),
+//!     reviewers: Vec::new(),
+//! };
+//!
+//! let reverts = RevertIndex::detect([
+//!     &pr(1, "Add cache", None),
+//!     &pr(2, "Revert \"Add cache\"", Some("Reverts !1")),
+//! ]);
+//! assert_eq!(reverts.reverted_by(1), Some(2));
+//! assert_eq!(reverts.reverts(2), Some(1));
+//! ```
+
+use crate::models::PullRequest;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+static PR_REFERENCE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\breverts?\b[^!\n]*!(\d+)").unwrap());
+static COMMIT_REFERENCE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\breverts commit ([0-9a-f]{7,40})\b").unwrap());
+
+/// Revert relationships between PRs, keyed by PR id.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RevertIndex {
+    /// Reverted PR id → id of the PR reverting it.
+    reverted_by: HashMap<i32, i32>,
+    /// Revert PR id → id of the PR it reverts.
+    reverts: HashMap<i32, i32>,
+}
+
+impl RevertIndex {
+    /// Finds the reverts among `prs` whose original PR is also in `prs`.
+    ///
+    /// When several PRs revert the same original, the latest one (highest
+    /// id) is reported.
+    pub fn detect<'a>(prs: impl IntoIterator<Item = &'a PullRequest>) -> Self {
+        let prs: Vec<&PullRequest> = prs.into_iter().collect();
+        let mut index = Self::default();
+
+        let mut sorted = prs.clone();
+        sorted.sort_by_key(|pr| pr.id);
+        for revert in sorted {
+            if let Some(original) = find_original(revert, &prs) {
+                index.reverts.insert(revert.id, original);
+                index.reverted_by.insert(original, revert.id);
+            }
+        }
+
+        index
+    }
+
+    /// Returns the PR that reverted `pr_id`, if any.
+    pub fn reverted_by(&self, pr_id: i32) -> Option<i32> {
+        self.reverted_by.get(&pr_id).copied()
+    }
+
+    /// Returns the PR that `pr_id` reverts, if it is a revert.
+    pub fn reverts(&self, pr_id: i32) -> Option<i32> {
+        self.reverts.get(&pr_id).copied()
+    }
+
+    /// Returns the number of reverted PRs.
+    pub fn reverted_count(&self) -> usize {
+        self.reverted_by.len()
+    }
+
+    /// Returns `true` if no reverts were found.
+    pub fn is_empty(&self) -> bool {
+        self.reverts.is_empty()
+    }
+
+    /// Short label for `pr_id`, e.g. `reverted by !12` or `reverts !10`.
+    pub fn label(&self, pr_id: i32) -> Option<String> {
+        match (self.reverts(pr_id), self.reverted_by(pr_id)) {
+            (Some(original), Some(revert)) => {
+                Some(format!("reverts !{}, reverted by !{}", original, revert))
+            }
+            (Some(original), None) => Some(format!("reverts !{}", original)),
+            (None, Some(revert)) => Some(format!("reverted by !{}", revert)),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Returns the id of the PR `revert` reverts, if it is a revert of one of `prs`.
+fn find_original(revert: &PullRequest, prs: &[&PullRequest]) -> Option<i32> {
+    let others = || prs.iter().filter(|pr| pr.id != revert.id);
+    let description = revert.description.as_deref().unwrap_or_default();
+
+    if let Some(id) = PR_REFERENCE
+        .captures_iter(description)
+        .filter_map(|captures| captures[1].parse::<i32>().ok())
+        .find(|id| others().any(|pr| pr.id == *id))
+    {
+        return Some(id);
+    }
+
+    for captures in COMMIT_REFERENCE.captures_iter(description) {
+        let sha = captures[1].to_lowercase();
+        if let Some(pr) = others().find(|pr| {
+            pr.last_merge_commit
+                .as_ref()
+                .is_some_and(|commit| commit.commit_id.to_lowercase().starts_with(&sha))
+        }) {
+            return Some(pr.id);
+        }
+    }
+
+    let title = revert
+        .title
+        .trim()
+        .strip_prefix("Revert \"")?
+        .strip_suffix('"')?;
+    others()
+        .filter(|pr| pr.id < revert.id && pr.title.trim() == title)
+        .map(|pr| pr.id)
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CreatedBy, MergeCommit};
+
+    fn pr(id: i32, title: &str, description: Option<&str>, commit: Option<&str>) -> PullRequest {
+        PullRequest {
+            id,
+            title: title.to_string(),
+            description: description.map(str::to_string),
+            closed_date: None,
+            created_by: CreatedBy {
+                display_name: "Dev".to_string(),
+            },
+            last_merge_commit: commit.map(|commit_id| MergeCommit {
+                commit_id: commit_id.to_string(),
+            }),
+            labels: None,
+            reviewers: Vec::new(),
+        }
+    }
+
+    /// # Revert Detection
+    ///
+    /// Tests recognizing revert PRs and the PRs they roll back.
+    ///
+    /// ## Test Scenario
+    /// - A revert referencing the original PR by id
+    /// - A `git revert` message referencing the original merge commit
+    /// - A revert recognized only by its `Revert "<title>"` title
+    /// - A revert of a revert, and a revert of a PR that was not fetched
+    ///
+    /// ## Expected Outcome
+    /// - Originals and reverts are linked in both directions
+    /// - A revert of a revert is flagged as both
+    /// - Reverts of unknown PRs and unrelated PRs are not flagged
+    #[test]
+    fn test_revert_index_detect() {
+        let prs = [
+            pr(10, "Add cache", None, Some("aaaaaaaa11112222")),
+            pr(11, "Revert \"Add cache\"", Some("Reverts !10"), None),
+            pr(
+                20,
+                "Tune retries",
+                None,
+                Some("bbbbbbbb33334444cccc5555dddd6666eeee7777"),
+            ),
+            pr(
+                21,
+                "Roll back retry tuning",
+                Some("This reverts commit BBBBBBBB33334444."),
+                None,
+            ),
+            pr(30, "Bump version", None, None),
+            pr(31, "Revert \"Bump version\"", None, None),
+            pr(32, "Revert \"Revert \"Bump version\"\"", None, None),
+            pr(40, "Revert \"Not fetched\"", Some("Reverts !5"), None),
+            pr(41, "Docs", Some("Preparing to revert later"), None),
+        ];
+
+        let index = RevertIndex::detect(&prs);
+
+        assert_eq!(index.reverted_by(10), Some(11));
+        assert_eq!(index.reverts(11), Some(10));
+        assert_eq!(index.reverted_by(20), Some(21));
+        assert_eq!(index.reverted_by(30), Some(31));
+        assert_eq!(index.reverts(32), Some(31));
+        assert_eq!(
+            index.label(31).as_deref(),
+            Some("reverts !30, reverted by !32")
+        );
+        assert_eq!(index.reverts(40), None);
+        assert_eq!(index.label(41), None);
+        assert_eq!(index.reverted_count(), 4);
+    }
+}
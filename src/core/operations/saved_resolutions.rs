@@ -0,0 +1,288 @@
+//! Conflict resolutions saved for re-use.
+//!
+//! When a conflicted cherry-pick is continued, the resolution of each
+//! conflicted file is saved in the state directory as a patch against the
+//! target branch, keyed by PR and file. When the same PR conflicts on the
+//! same file again, e.g. when it is cherry-picked to a second target
+//! branch, the patch can be re-applied before the user is asked to resolve
+//! the file.
+//!
+//! Patches are re-applied to the current target branch's version of the
+//! file. A patch that no longer applies leaves the conflict untouched.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+
+use crate::core::state::state_dir;
+use crate::git;
+
+/// Directory in the state directory holding saved resolutions.
+pub const RESOLUTIONS_DIR_NAME: &str = "resolutions";
+
+/// Saved conflict resolutions of one repository.
+#[derive(Debug, Clone)]
+pub struct SavedResolutions {
+    dir: PathBuf,
+}
+
+impl SavedResolutions {
+    /// Returns the saved resolutions of a repository.
+    ///
+    /// They live in the state directory (see [`state_dir`]), keyed by a hash
+    /// of the organization, project and repository.
+    pub fn for_repository(organization: &str, project: &str, repository: &str) -> Result<Self> {
+        let hash = short_hash(&format!("{}/{}/{}", organization, project, repository));
+        Ok(Self::in_dir(
+            state_dir()?.join(RESOLUTIONS_DIR_NAME).join(hash),
+        ))
+    }
+
+    /// Returns the saved resolutions in `dir`.
+    pub fn in_dir(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Path of the patch resolving `file` for a PR.
+    ///
+    /// File paths are hashed so nested paths map to flat file names.
+    fn patch_path(&self, pr_id: i32, file: &str) -> PathBuf {
+        self.dir
+            .join(pr_id.to_string())
+            .join(format!("{}.patch", short_hash(file)))
+    }
+
+    /// Saves the staged resolutions of `files` for a PR.
+    ///
+    /// Must be called before the cherry-pick is continued, while the
+    /// resolutions are staged on top of the target branch. Earlier
+    /// resolutions of the same files are replaced.
+    pub fn save(&self, repo_path: &Path, pr_id: i32, files: &[String]) -> Result<()> {
+        for file in files {
+            let patch = git::staged_diff(repo_path, file)?;
+            let path = self.patch_path(pr_id, file);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).with_context(|| {
+                    format!(
+                        "Failed to create resolutions directory: {}",
+                        parent.display()
+                    )
+                })?;
+            }
+            fs::write(&path, patch)
+                .with_context(|| format!("Failed to save resolution: {}", path.display()))?;
+        }
+        Ok(())
+    }
+
+    /// Returns the files among `files` with a saved resolution for a PR.
+    pub fn available(&self, pr_id: i32, files: &[String]) -> Vec<String> {
+        files
+            .iter()
+            .filter(|file| self.patch_path(pr_id, file).is_file())
+            .cloned()
+            .collect()
+    }
+
+    /// Re-applies the saved resolutions of a PR to its conflicted `files`.
+    ///
+    /// Each file with a saved resolution that still applies is resolved and
+    /// staged. Returns the resolved files; the others stay conflicted.
+    pub fn reapply(&self, repo_path: &Path, pr_id: i32, files: &[String]) -> Result<Vec<String>> {
+        let mut applied = Vec::new();
+        for file in self.available(pr_id, files) {
+            let path = self.patch_path(pr_id, &file);
+            let patch = fs::read(&path)
+                .with_context(|| format!("Failed to read resolution: {}", path.display()))?;
+            if git::apply_patch_to_head(repo_path, &file, &patch)? {
+                applied.push(file);
+            }
+        }
+        Ok(applied)
+    }
+}
+
+/// Returns the first 8 bytes of the SHA-256 of `text`, hex-encoded.
+fn short_hash(text: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(text);
+    hasher.finalize()[..8]
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(repo: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn cherry_pick_conflicts(repo: &Path, commit: &str) {
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(["cherry-pick", commit])
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "cherry-pick should conflict");
+    }
+
+    /// Creates a repository where cherry-picking `feature` onto `main`
+    /// conflicts in `notes.txt`, with a second target `release` holding the
+    /// same conflicting line and another line at the end, and a third target
+    /// `hotfix` changing the line differently.
+    fn setup_repo() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        git(repo, &["init", "-q", "-b", "main"]);
+        git(repo, &["config", "user.name", "Test User"]);
+        git(repo, &["config", "user.email", "test@example.com"]);
+        git(repo, &["config", "commit.gpgsign", "false"]);
+
+        std::fs::write(
+            repo.join("notes.txt"),
+            "a\nb\nc\nd\nvalue = 1\ne\nf\ng\nh\n",
+        )
+        .unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-q", "-m", "Initial"]);
+
+        git(repo, &["checkout", "-q", "-b", "feature"]);
+        std::fs::write(
+            repo.join("notes.txt"),
+            "a\nb\nc\nd\nvalue = 2\ne\nf\ng\nh\n",
+        )
+        .unwrap();
+        git(repo, &["commit", "-q", "-am", "Feature"]);
+
+        git(repo, &["checkout", "-q", "main"]);
+        git(repo, &["checkout", "-q", "-b", "release"]);
+        std::fs::write(
+            repo.join("notes.txt"),
+            "a\nb\nc\nd\nvalue = 3\ne\nf\ng\nh\ni\n",
+        )
+        .unwrap();
+        git(repo, &["commit", "-q", "-am", "Release"]);
+
+        git(repo, &["checkout", "-q", "main"]);
+        git(repo, &["checkout", "-q", "-b", "hotfix"]);
+        std::fs::write(
+            repo.join("notes.txt"),
+            "a\nb\nc\nd\nvalue = 4\ne\nf\ng\nh\n",
+        )
+        .unwrap();
+        git(repo, &["commit", "-q", "-am", "Hotfix"]);
+
+        git(repo, &["checkout", "-q", "main"]);
+        std::fs::write(
+            repo.join("notes.txt"),
+            "a\nb\nc\nd\nvalue = 3\ne\nf\ng\nh\n",
+        )
+        .unwrap();
+        git(repo, &["commit", "-q", "-am", "Main"]);
+        temp_dir
+    }
+
+    /// # Re-Applying a Saved Resolution
+    ///
+    /// Tests saving a resolution and re-applying it when the same commit
+    /// conflicts on another target branch.
+    ///
+    /// ## Test Scenario
+    /// - Resolves the conflict on `main`, saves it and continues
+    /// - Cherry-picks the same commit onto `release`, which conflicts the
+    ///   same way
+    /// - Re-applies the saved resolutions
+    ///
+    /// ## Expected Outcome
+    /// - The resolution is available for the PR only
+    /// - The file is resolved to the saved resolution, keeping the release
+    ///   branch's own changes, and staged
+    #[test]
+    fn test_reapply_on_second_target() {
+        let temp_dir = setup_repo();
+        let repo = temp_dir.path();
+        let store_dir = TempDir::new().unwrap();
+        let store = SavedResolutions::in_dir(store_dir.path().to_path_buf());
+        let files = vec!["notes.txt".to_string()];
+
+        cherry_pick_conflicts(repo, "feature");
+        std::fs::write(
+            repo.join("notes.txt"),
+            "a\nb\nc\nd\nvalue = 2 + 3\ne\nf\ng\nh\n",
+        )
+        .unwrap();
+        git(repo, &["add", "notes.txt"]);
+        store.save(repo, 42, &files).unwrap();
+        git(
+            repo,
+            &["-c", "core.editor=true", "cherry-pick", "--continue"],
+        );
+
+        assert_eq!(store.available(42, &files), files);
+        assert!(store.available(7, &files).is_empty());
+
+        git(repo, &["checkout", "-q", "release"]);
+        cherry_pick_conflicts(repo, "feature");
+        assert_eq!(store.reapply(repo, 42, &files).unwrap(), files);
+
+        let content = std::fs::read_to_string(repo.join("notes.txt")).unwrap();
+        assert_eq!(content, "a\nb\nc\nd\nvalue = 2 + 3\ne\nf\ng\nh\ni\n");
+        assert!(git::unresolved_files(repo).unwrap().is_empty());
+    }
+
+    /// # Saved Resolution No Longer Applies
+    ///
+    /// Tests re-applying a resolution to a target branch that changed the
+    /// conflicting lines differently.
+    ///
+    /// ## Test Scenario
+    /// - Saves a resolution on `main`
+    /// - Cherry-picks the same commit onto `hotfix` and re-applies
+    ///
+    /// ## Expected Outcome
+    /// - Nothing is re-applied
+    /// - The file keeps its conflict markers and stays unresolved
+    #[test]
+    fn test_reapply_leaves_conflict_when_patch_does_not_apply() {
+        let temp_dir = setup_repo();
+        let repo = temp_dir.path();
+        let store_dir = TempDir::new().unwrap();
+        let store = SavedResolutions::in_dir(store_dir.path().to_path_buf());
+        let files = vec!["notes.txt".to_string()];
+
+        cherry_pick_conflicts(repo, "feature");
+        std::fs::write(
+            repo.join("notes.txt"),
+            "a\nb\nc\nd\nvalue = 2 + 3\ne\nf\ng\nh\n",
+        )
+        .unwrap();
+        git(repo, &["add", "notes.txt"]);
+        store.save(repo, 42, &files).unwrap();
+        git(repo, &["cherry-pick", "--abort"]);
+
+        git(repo, &["checkout", "-q", "hotfix"]);
+        cherry_pick_conflicts(repo, "feature");
+        assert!(store.reapply(repo, 42, &files).unwrap().is_empty());
+
+        let content = std::fs::read_to_string(repo.join("notes.txt")).unwrap();
+        assert!(content.contains("<<<<<<<"));
+        assert_eq!(git::unresolved_files(repo).unwrap(), files);
+    }
+}
@@ -0,0 +1,258 @@
+//! Importing a PR selection from a text file.
+//!
+//! A selection file lists the PRs to select, one id per line, using the same
+//! shortcuts as the PR selection search: `!123` selects PR 123 and `#456`
+//! selects every PR linked to work item 456. A bare number is a PR id. Blank
+//! lines are skipped, and anything after the id (separated by whitespace) is
+//! ignored, so lists copied from release notes or chat can keep their titles:
+//!
+//! ```text
+//! !1234 Fix login timeout
+//! #5678
+//! 1240
+//! ```
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::core::operations::{SelectionFile, SelectionId};
+//!
+//! let file = SelectionFile::parse("!12 Fix login\n#34\n\n56").unwrap();
+//! assert_eq!(
+//!     file.ids(),
+//!     &[
+//!         SelectionId::PullRequest(12),
+//!         SelectionId::WorkItem(34),
+//!         SelectionId::PullRequest(56),
+//!     ]
+//! );
+//! ```
+
+use crate::models::PullRequestWithWorkItems;
+use anyhow::{Context, Result, bail};
+use std::collections::HashSet;
+use std::fmt;
+use std::path::Path;
+
+/// An id listed in a selection file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SelectionId {
+    /// A pull request id (`!123` or `123`).
+    PullRequest(i32),
+    /// A work item id (`#456`); selects every PR linked to it.
+    WorkItem(i32),
+}
+
+impl fmt::Display for SelectionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SelectionId::PullRequest(id) => write!(f, "!{}", id),
+            SelectionId::WorkItem(id) => write!(f, "#{}", id),
+        }
+    }
+}
+
+/// Parsed contents of a selection file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SelectionFile {
+    ids: Vec<SelectionId>,
+}
+
+/// Outcome of applying a selection file to the candidate PRs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SelectionImport {
+    /// Number of distinct PRs the file selected.
+    pub selected: usize,
+    /// Ids that matched no candidate PR, in file order.
+    pub not_found: Vec<SelectionId>,
+}
+
+impl SelectionImport {
+    /// One-line description of the import, e.g.
+    /// `Selected 3 PRs; not found: !12, #34`.
+    pub fn describe(&self) -> String {
+        let noun = if self.selected == 1 { "PR" } else { "PRs" };
+        let mut text = format!("Selected {} {}", self.selected, noun);
+        if !self.not_found.is_empty() {
+            let ids: Vec<String> = self.not_found.iter().map(ToString::to_string).collect();
+            text.push_str(&format!("; not found: {}", ids.join(", ")));
+        }
+        text
+    }
+}
+
+impl SelectionFile {
+    /// Parses selection file contents.
+    ///
+    /// Returns an error naming the offending line if an entry is not a valid id.
+    pub fn parse(contents: &str) -> Result<Self> {
+        let mut ids = Vec::new();
+
+        for (index, line) in contents.lines().enumerate() {
+            let Some(entry) = line.split_whitespace().next() else {
+                continue;
+            };
+
+            let (id_text, make_id): (&str, fn(i32) -> SelectionId) =
+                if let Some(rest) = entry.strip_prefix('!') {
+                    (rest, SelectionId::PullRequest)
+                } else if let Some(rest) = entry.strip_prefix('#') {
+                    (rest, SelectionId::WorkItem)
+                } else {
+                    (entry, SelectionId::PullRequest)
+                };
+
+            match id_text.parse::<i32>() {
+                Ok(id) => ids.push(make_id(id)),
+                Err(_) => bail!(
+                    "Invalid id on line {}: '{}' (expected !PR, #WORKITEM or a PR id)",
+                    index + 1,
+                    entry
+                ),
+            }
+        }
+
+        Ok(Self { ids })
+    }
+
+    /// Reads and parses a selection file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read selection file {}", path.display()))?;
+        Self::parse(&contents).with_context(|| format!("Invalid selection file {}", path.display()))
+    }
+
+    /// Returns the listed ids in file order.
+    pub fn ids(&self) -> &[SelectionId] {
+        &self.ids
+    }
+
+    /// Marks every PR matching a listed id as selected.
+    ///
+    /// Existing selections are kept; PRs not in the file are left unchanged.
+    pub fn apply(&self, prs: &mut [PullRequestWithWorkItems]) -> SelectionImport {
+        let mut selected = HashSet::new();
+        let mut not_found = Vec::new();
+
+        for id in &self.ids {
+            let mut found = false;
+            for pr in prs.iter_mut() {
+                let matches = match id {
+                    SelectionId::PullRequest(pr_id) => pr.pr.id == *pr_id,
+                    SelectionId::WorkItem(wi_id) => pr.work_items.iter().any(|wi| wi.id == *wi_id),
+                };
+                if matches {
+                    pr.selected = true;
+                    selected.insert(pr.pr.id);
+                    found = true;
+                }
+            }
+            if !found && !not_found.contains(id) {
+                not_found.push(*id);
+            }
+        }
+
+        SelectionImport {
+            selected: selected.len(),
+            not_found,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CreatedBy, PullRequest, WorkItem, WorkItemFields};
+
+    fn pr(id: i32, work_item_ids: &[i32]) -> PullRequestWithWorkItems {
+        PullRequestWithWorkItems {
+            pr: PullRequest {
+                id,
+                title: format!("PR {}", id),
+                closed_date: None,
+                created_by: CreatedBy {
+                    display_name: "Dev".to_string(),
+                },
+                last_merge_commit: None,
+                labels: None,
+                description: None,
+                reviewers: Vec::new(),
+            },
+            work_items: work_item_ids
+                .iter()
+                .map(|wi_id| WorkItem {
+                    id: *wi_id,
+                    fields: WorkItemFields {
+                        title: Some(format!("Work item {}", wi_id)),
+                        state: None,
+                        work_item_type: None,
+                        assigned_to: None,
+                        iteration_path: None,
+                        description: None,
+                        repro_steps: None,
+                        state_color: None,
+                        custom_fields: Default::default(),
+                    },
+                    history: Vec::new(),
+                })
+                .collect(),
+            selected: false,
+        }
+    }
+
+    /// # Parse Selection File
+    ///
+    /// Tests parsing PR and work item ids.
+    ///
+    /// ## Test Scenario
+    /// - Parses `!PR`, `#WI` and bare ids with trailing titles and blank lines
+    /// - Parses an entry that is not an id
+    ///
+    /// ## Expected Outcome
+    /// - Ids are returned in file order; trailing text is ignored
+    /// - The invalid entry is rejected with its line number
+    #[test]
+    fn test_parse() {
+        let file = SelectionFile::parse("  !12 Fix login\n\n#34\n56\t# comment\n").unwrap();
+        assert_eq!(
+            file.ids(),
+            &[
+                SelectionId::PullRequest(12),
+                SelectionId::WorkItem(34),
+                SelectionId::PullRequest(56),
+            ]
+        );
+
+        let err = SelectionFile::parse("!12\nfix-login\n").unwrap_err();
+        assert!(err.to_string().contains("line 2"));
+        assert!(SelectionFile::parse("#abc").is_err());
+    }
+
+    /// # Apply Selection File
+    ///
+    /// Tests selecting candidate PRs from a parsed file.
+    ///
+    /// ## Test Scenario
+    /// - Four PRs; PR 2 and PR 3 share work item 100; PR 4 is already selected
+    /// - Applies a file listing PR 1, work item 100, PR 99 and work item 999
+    ///
+    /// ## Expected Outcome
+    /// - PRs 1, 2 and 3 are selected and counted; PR 4 stays selected
+    /// - The ids without a matching PR are reported as not found
+    #[test]
+    fn test_apply() {
+        let mut prs = vec![pr(1, &[]), pr(2, &[100]), pr(3, &[100, 101]), pr(4, &[])];
+        prs[3].selected = true;
+
+        let file = SelectionFile::parse("!1\n#100\n!99\n#999\n!99").unwrap();
+        let import = file.apply(&mut prs);
+
+        assert_eq!(import.selected, 3);
+        assert_eq!(
+            import.not_found,
+            vec![SelectionId::PullRequest(99), SelectionId::WorkItem(999)]
+        );
+        assert!(prs.iter().all(|pr| pr.selected));
+        assert_eq!(import.describe(), "Selected 3 PRs; not found: !99, #999");
+    }
+}
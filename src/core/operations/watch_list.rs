@@ -0,0 +1,264 @@
+//! Persistent list of merge candidates accumulated by `mergers watch`.
+//!
+//! The watch command polls Azure DevOps for completed PRs that are not yet
+//! merged to the target branch. Every candidate it has seen is kept in a
+//! small JSON file in the state directory so that restarts do not re-announce
+//! old PRs. Candidates that appeared after the first poll are marked
+//! *pending* until the user opens the merge TUI with them.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chrono::Utc;
+//! use mergers::core::operations::watch_list::WatchList;
+//! use mergers::models::{CreatedBy, PullRequest};
+//!
+//! let pr = |id: i32| PullRequest {
+//!     id,
+//!     title: format!("PR {}", id),
+//!     description: None,
+//!     closed_date: None,
+//!     created_by: CreatedBy { display_name: "Dev".to_string() },
+//!     last_merge_commit: None,
+//!     labels: None,
+//!     reviewers: Vec::new(),
+//! };
+//!
+//! let mut list = WatchList::default();
+//! // The first poll only records the existing candidates
+//! assert!(list.update(&[pr(1)], Utc::now()).is_empty());
+//! // Later polls report PRs that were not seen before
+//! let new = list.update(&[pr(1), pr(2)], Utc::now());
+//! assert_eq!(new[0].pr_id, 2);
+//! assert_eq!(list.pending_ids().len(), 1);
+//! ```
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::core::state::state_dir;
+use crate::models::PullRequest;
+
+/// A PR the watch command considers a merge candidate.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchCandidate {
+    /// The PR ID.
+    pub pr_id: i32,
+    /// The PR title.
+    pub title: String,
+    /// Display name of the PR author.
+    pub author: String,
+    /// When the PR was completed, as reported by Azure DevOps.
+    pub closed_date: Option<String>,
+    /// When the watch command first saw the PR.
+    pub first_seen: DateTime<Utc>,
+    /// Whether the PR appeared after the first poll and has not been reviewed yet.
+    pub pending: bool,
+}
+
+impl WatchCandidate {
+    fn from_pr(pr: &PullRequest, now: DateTime<Utc>, pending: bool) -> Self {
+        Self {
+            pr_id: pr.id,
+            title: pr.title.clone(),
+            author: pr.created_by.display_name.clone(),
+            closed_date: pr.closed_date.clone(),
+            first_seen: now,
+            pending,
+        }
+    }
+}
+
+/// Candidates seen by the watch command for one repository and dev branch.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WatchList {
+    /// Whether the baseline poll has been recorded.
+    #[serde(default)]
+    initialized: bool,
+    /// Known candidates in the order they were first seen.
+    #[serde(default)]
+    candidates: Vec<WatchCandidate>,
+}
+
+impl WatchList {
+    /// Returns the watch list file for a repository and dev branch.
+    ///
+    /// The file lives in the state directory (see [`state_dir`]) and is
+    /// keyed by a hash of the organization, project, repository and branch.
+    pub fn path_for(
+        organization: &str,
+        project: &str,
+        repository: &str,
+        dev_branch: &str,
+    ) -> Result<PathBuf> {
+        let mut hasher = Sha256::new();
+        hasher.update(format!(
+            "{}/{}/{}/{}",
+            organization, project, repository, dev_branch
+        ));
+        let hash: String = hasher.finalize()[..8]
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        Ok(state_dir()?.join(format!("watch-{}.json", hash)))
+    }
+
+    /// Loads a watch list, returning an empty list if the file does not exist.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read watch list: {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse watch list: {}", path.display()))
+    }
+
+    /// Saves the watch list, creating the parent directory if needed.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!(
+                    "Failed to create watch list directory: {}",
+                    parent.display()
+                )
+            })?;
+        }
+        let contents = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, contents)
+            .with_context(|| format!("Failed to write watch list: {}", path.display()))
+    }
+
+    /// Updates the list with the candidates returned by a poll.
+    ///
+    /// Candidates that are no longer returned (merged, tagged or filtered
+    /// out) are dropped. Returns the candidates that were not known before.
+    /// The first update only records a baseline and returns nothing.
+    pub fn update(&mut self, prs: &[PullRequest], now: DateTime<Utc>) -> Vec<WatchCandidate> {
+        let current: HashSet<i32> = prs.iter().map(|pr| pr.id).collect();
+        self.candidates.retain(|c| current.contains(&c.pr_id));
+
+        let known: HashSet<i32> = self.candidates.iter().map(|c| c.pr_id).collect();
+        let baseline = !self.initialized;
+        self.initialized = true;
+
+        let new: Vec<WatchCandidate> = prs
+            .iter()
+            .filter(|pr| !known.contains(&pr.id))
+            .map(|pr| WatchCandidate::from_pr(pr, now, !baseline))
+            .collect();
+        self.candidates.extend(new.iter().cloned());
+
+        if baseline { Vec::new() } else { new }
+    }
+
+    /// All known candidates in the order they were first seen.
+    pub fn candidates(&self) -> &[WatchCandidate] {
+        &self.candidates
+    }
+
+    /// IDs of candidates that appeared since the last review.
+    pub fn pending_ids(&self) -> HashSet<i32> {
+        self.candidates
+            .iter()
+            .filter(|c| c.pending)
+            .map(|c| c.pr_id)
+            .collect()
+    }
+
+    /// Marks all pending candidates as reviewed.
+    pub fn acknowledge(&mut self) {
+        for candidate in &mut self.candidates {
+            candidate.pending = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CreatedBy;
+    use tempfile::TempDir;
+
+    fn pr(id: i32) -> PullRequest {
+        PullRequest {
+            id,
+            title: format!("PR {}", id),
+            description: None,
+            closed_date: Some("2026-01-01T00:00:00Z".to_string()),
+            created_by: CreatedBy {
+                display_name: "Test User".to_string(),
+            },
+            last_merge_commit: None,
+            labels: None,
+            reviewers: Vec::new(),
+        }
+    }
+
+    /// # Watch List Update
+    ///
+    /// Tests baseline recording, new candidate detection and pruning.
+    ///
+    /// ## Test Scenario
+    /// - Records a baseline poll with PRs 1 and 2
+    /// - Polls again with PR 1 merged away and PR 3 new
+    /// - Acknowledges the pending candidates
+    ///
+    /// ## Expected Outcome
+    /// - The baseline announces nothing and marks nothing pending
+    /// - The second poll returns only PR 3 and drops PR 1
+    /// - Acknowledging clears the pending set
+    #[test]
+    fn test_watch_list_update() {
+        let mut list = WatchList::default();
+        let now = Utc::now();
+
+        assert!(list.update(&[pr(1), pr(2)], now).is_empty());
+        assert_eq!(list.candidates().len(), 2);
+        assert!(list.pending_ids().is_empty());
+
+        let new = list.update(&[pr(2), pr(3)], now);
+        assert_eq!(new.len(), 1);
+        assert_eq!(new[0].pr_id, 3);
+        assert!(new[0].pending);
+        let ids: Vec<i32> = list.candidates().iter().map(|c| c.pr_id).collect();
+        assert_eq!(ids, vec![2, 3]);
+        assert_eq!(list.pending_ids(), HashSet::from([3]));
+
+        assert!(list.update(&[pr(2), pr(3)], now).is_empty());
+        list.acknowledge();
+        assert!(list.pending_ids().is_empty());
+    }
+
+    /// # Watch List Persistence
+    ///
+    /// Tests saving and loading a watch list.
+    ///
+    /// ## Test Scenario
+    /// - Loads from a missing file, updates twice, saves and reloads
+    ///
+    /// ## Expected Outcome
+    /// - A missing file yields an empty list
+    /// - The reloaded list keeps candidates, pending flags and the baseline
+    #[test]
+    fn test_watch_list_persistence() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("nested").join("watch.json");
+
+        let mut list = WatchList::load(&path).unwrap();
+        assert!(list.candidates().is_empty());
+        list.update(&[pr(1)], Utc::now());
+        list.update(&[pr(1), pr(2)], Utc::now());
+        list.save(&path).unwrap();
+
+        let mut restored = WatchList::load(&path).unwrap();
+        assert_eq!(restored.candidates(), list.candidates());
+        assert_eq!(restored.pending_ids(), HashSet::from([2]));
+        assert_eq!(restored.update(&[pr(1), pr(2), pr(4)], Utc::now()).len(), 1);
+    }
+}
@@ -0,0 +1,316 @@
+//! Templated work item field updates applied when a merge completes.
+//!
+//! Besides the state transition, post-merge tasks can write configured fields
+//! on every released work item: set a custom field to the version, append a
+//! tag or move the work item to an iteration. Values are templates using the
+//! placeholders of release comments: `{version}`, `{branch}`,
+//! `{target_branch}`, `{prs}`, `{work_item_id}` and `{work_item_title}`.
+//!
+//! # Config Format
+//!
+//! ```toml
+//! [[work_item_fields]]
+//! field = "Custom.ReleaseVersion"
+//! value = "{version}"
+//!
+//! [[work_item_fields]]
+//! field = "System.Tags"
+//! value = "released-{version}"
+//! mode = "append"
+//!
+//! [[work_item_fields]]
+//! field = "System.IterationPath"
+//! value = "Platform\\Release {version}"
+//! ```
+//!
+//! `set` (the default) replaces the field's value. `append` adds the value to
+//! a `;`-separated list such as `System.Tags`, unless it is already listed.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::core::operations::work_item_fields::{FieldUpdate, FieldUpdateMode};
+//!
+//! let update = FieldUpdate {
+//!     field: "System.Tags".to_string(),
+//!     value: "released-1.4".to_string(),
+//!     mode: FieldUpdateMode::Append,
+//! };
+//! assert_eq!(update.value_after(Some("backend; hotfix")), "backend; hotfix; released-1.4");
+//! assert_eq!(update.to_string(), "System.Tags += released-1.4");
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::api::AzureDevOpsClient;
+
+/// Placeholders that field value templates may use.
+pub const FIELD_TEMPLATE_PLACEHOLDERS: [&str; 6] = [
+    "{version}",
+    "{branch}",
+    "{target_branch}",
+    "{prs}",
+    "{work_item_id}",
+    "{work_item_title}",
+];
+
+/// How a field update changes the field.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldUpdateMode {
+    /// Replace the field's value.
+    #[default]
+    Set,
+    /// Add the value to a `;`-separated list, unless already listed.
+    Append,
+}
+
+impl fmt::Display for FieldUpdateMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldUpdateMode::Set => write!(f, "set"),
+            FieldUpdateMode::Append => write!(f, "append"),
+        }
+    }
+}
+
+/// A field update as written in the config file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkItemFieldRule {
+    /// Reference name of the field, e.g. `Custom.ReleaseVersion`.
+    pub field: String,
+    /// Template of the value written to the field.
+    pub value: String,
+    /// Whether the value replaces the field or is appended to it.
+    #[serde(default)]
+    pub mode: FieldUpdateMode,
+}
+
+/// Checks that each rule names a field and only uses known placeholders.
+pub fn validate_field_rules(rules: &[WorkItemFieldRule]) -> Result<()> {
+    for rule in rules {
+        if rule.field.trim().is_empty() || rule.field.contains(char::is_whitespace) {
+            anyhow::bail!(
+                "Invalid work item field '{}': expected a reference name like Custom.ReleaseVersion",
+                rule.field
+            );
+        }
+        let mut rest = rule.value.as_str();
+        while let Some(start) = rest.find('{') {
+            let end = rest[start..].find('}').with_context(|| {
+                format!(
+                    "Unclosed placeholder in the value of work item field {}: {}",
+                    rule.field, rule.value
+                )
+            })?;
+            let placeholder = &rest[start..start + end + 1];
+            if !FIELD_TEMPLATE_PLACEHOLDERS.contains(&placeholder) {
+                anyhow::bail!(
+                    "Unknown placeholder {} in the value of work item field {} (expected one of {})",
+                    placeholder,
+                    rule.field,
+                    FIELD_TEMPLATE_PLACEHOLDERS.join(", ")
+                );
+            }
+            rest = &rest[start + end + 1..];
+        }
+    }
+    Ok(())
+}
+
+/// A field update rendered for one work item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldUpdate {
+    /// Reference name of the field.
+    pub field: String,
+    /// Rendered value.
+    pub value: String,
+    /// Whether the value replaces the field or is appended to it.
+    pub mode: FieldUpdateMode,
+}
+
+impl FieldUpdate {
+    /// Returns the value the field has after the update, given its current
+    /// value.
+    pub fn value_after(&self, current: Option<&str>) -> String {
+        match self.mode {
+            FieldUpdateMode::Set => self.value.clone(),
+            FieldUpdateMode::Append => {
+                let mut items: Vec<&str> = current
+                    .unwrap_or_default()
+                    .split(';')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .collect();
+                if !items
+                    .iter()
+                    .any(|item| item.eq_ignore_ascii_case(self.value.trim()))
+                {
+                    items.push(self.value.trim());
+                }
+                items.join("; ")
+            }
+        }
+    }
+}
+
+impl fmt::Display for FieldUpdate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mode {
+            FieldUpdateMode::Set => write!(f, "{} = {}", self.field, self.value),
+            FieldUpdateMode::Append => write!(f, "{} += {}", self.field, self.value),
+        }
+    }
+}
+
+/// Writes rendered field updates to a work item in one request.
+///
+/// Fields with `append` updates are read first, so the appended value is
+/// added to what the field holds now. Updates of the same field are applied
+/// in order.
+pub async fn apply_field_updates(
+    client: &AzureDevOpsClient,
+    work_item_id: i32,
+    updates: &[FieldUpdate],
+) -> Result<()> {
+    let appended: Vec<&str> = updates
+        .iter()
+        .filter(|update| update.mode == FieldUpdateMode::Append)
+        .map(|update| update.field.as_str())
+        .collect();
+    let mut values: HashMap<String, String> = if appended.is_empty() {
+        HashMap::new()
+    } else {
+        client
+            .fetch_work_item_field_values(work_item_id, &appended)
+            .await?
+    };
+
+    let mut written: Vec<(String, String)> = Vec::new();
+    for update in updates {
+        let value = update.value_after(values.get(&update.field).map(String::as_str));
+        values.insert(update.field.clone(), value.clone());
+        match written.iter_mut().find(|(field, _)| *field == update.field) {
+            Some((_, existing)) => *existing = value,
+            None => written.push((update.field.clone(), value)),
+        }
+    }
+    client.update_work_item_fields(work_item_id, &written).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(field: &str, value: &str) -> WorkItemFieldRule {
+        WorkItemFieldRule {
+            field: field.to_string(),
+            value: value.to_string(),
+            mode: FieldUpdateMode::Set,
+        }
+    }
+
+    /// # Field Rule Validation
+    ///
+    /// Tests checking configured field updates.
+    ///
+    /// ## Test Scenario
+    /// - Validates rules with known placeholders, a blank field name, an
+    ///   unknown placeholder and an unclosed placeholder
+    ///
+    /// ## Expected Outcome
+    /// - Only the rules with known placeholders and a field name pass
+    #[test]
+    fn test_validate_field_rules() {
+        assert!(
+            validate_field_rules(&[
+                rule("Custom.ReleaseVersion", "{version}"),
+                rule("System.IterationPath", "Platform\\Release {version}"),
+            ])
+            .is_ok()
+        );
+
+        let error = validate_field_rules(&[rule(" ", "{version}")]).unwrap_err();
+        assert!(error.to_string().contains("Invalid work item field"));
+
+        let error = validate_field_rules(&[rule("Custom.Build", "{build}")]).unwrap_err();
+        assert!(error.to_string().contains("Unknown placeholder {build}"));
+
+        let error = validate_field_rules(&[rule("Custom.Build", "{version")]).unwrap_err();
+        assert!(error.to_string().contains("Unclosed placeholder"));
+    }
+
+    /// # Field Update Values
+    ///
+    /// Tests the value a field has after an update.
+    ///
+    /// ## Test Scenario
+    /// - Sets a field over an existing value
+    /// - Appends a tag to existing tags, to no tags and to tags already
+    ///   listing it in another case
+    ///
+    /// ## Expected Outcome
+    /// - `set` replaces the value
+    /// - `append` adds the tag once, separated by `; `
+    #[test]
+    fn test_field_update_value_after() {
+        let set = FieldUpdate {
+            field: "Custom.ReleaseVersion".to_string(),
+            value: "1.4".to_string(),
+            mode: FieldUpdateMode::Set,
+        };
+        assert_eq!(set.value_after(Some("1.3")), "1.4");
+        assert_eq!(set.to_string(), "Custom.ReleaseVersion = 1.4");
+
+        let append = FieldUpdate {
+            field: "System.Tags".to_string(),
+            value: "released-1.4".to_string(),
+            mode: FieldUpdateMode::Append,
+        };
+        assert_eq!(
+            append.value_after(Some("backend;hotfix")),
+            "backend; hotfix; released-1.4"
+        );
+        assert_eq!(append.value_after(None), "released-1.4");
+        assert_eq!(
+            append.value_after(Some("Released-1.4; backend")),
+            "Released-1.4; backend"
+        );
+    }
+
+    /// # Field Rule Parsing
+    ///
+    /// Tests reading field updates from the config file.
+    ///
+    /// ## Test Scenario
+    /// - Parses a rule without a mode and one appending
+    ///
+    /// ## Expected Outcome
+    /// - The mode defaults to `set`
+    #[test]
+    fn test_field_rule_parsing() {
+        #[derive(Deserialize)]
+        struct Config {
+            work_item_fields: Vec<WorkItemFieldRule>,
+        }
+        let config: Config = toml::from_str(
+            r#"
+            [[work_item_fields]]
+            field = "Custom.ReleaseVersion"
+            value = "{version}"
+
+            [[work_item_fields]]
+            field = "System.Tags"
+            value = "released-{version}"
+            mode = "append"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.work_item_fields[0].mode, FieldUpdateMode::Set);
+        assert_eq!(config.work_item_fields[1].mode, FieldUpdateMode::Append);
+    }
+}
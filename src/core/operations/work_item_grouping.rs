@@ -279,6 +279,7 @@ mod tests {
                     description: None,
                     repro_steps: None,
                     state_color: None,
+                    custom_fields: Default::default(),
                 },
                 history: Vec::new(),
             })
@@ -295,6 +296,7 @@ mod tests {
                 },
                 labels: None,
                 last_merge_commit: None,
+                reviewers: Vec::new(),
             },
             work_items,
             selected,
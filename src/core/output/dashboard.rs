@@ -0,0 +1,387 @@
+//! Read-only web dashboard for long non-interactive runs.
+//!
+//! With `--serve`, a merge run serves a small web page showing its progress,
+//! the merge state file and the dependency graph, so teammates can follow the
+//! run in a browser. The page is backed by a JSON API:
+//!
+//! - `GET /api/summary`: the current [`ProgressSummary`]
+//! - `GET /api/events`: the [`ProgressEvent`]s so far, then new ones as they
+//!   happen, as a server-sent event stream
+//! - `GET /api/history`: the events so far as a JSON array
+//! - `GET /api/state`: the merge state file, once the run has created it
+//! - `GET /api/graph`: the [`PRDependencyGraph`], once dependencies were
+//!   analyzed
+//!
+//! Nothing can be changed through the dashboard. It runs on a background
+//! thread with its own runtime, so it keeps answering while the run blocks on
+//! git, and stops when the [`Dashboard`] is dropped.
+
+use super::{ProgressEvent, ProgressSummary};
+use crate::core::operations::PRDependencyGraph;
+use anyhow::{Context, Result};
+use axum::{
+    Json, Router,
+    extract::State,
+    http::StatusCode,
+    response::{
+        Html, IntoResponse, Response,
+        sse::{Event, KeepAlive, Sse},
+    },
+    routing::get,
+};
+use futures::{Stream, StreamExt};
+use std::collections::VecDeque;
+use std::future::IntoFuture;
+use std::net::{SocketAddr, TcpListener};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use tokio::sync::{broadcast, oneshot};
+
+/// Events kept for clients connecting later; older ones are dropped.
+const HISTORY_LIMIT: usize = 1000;
+
+/// Events buffered for a slow live client before it skips ahead.
+const LIVE_BUFFER: usize = 256;
+
+/// The page served on `/`.
+const DASHBOARD_HTML: &str = include_str!("dashboard.html");
+
+/// What the dashboard shows, updated by the run.
+#[derive(Default)]
+struct RunView {
+    summary: ProgressSummary,
+    history: VecDeque<ProgressEvent>,
+    state_file: Option<PathBuf>,
+    graph: Option<PRDependencyGraph>,
+}
+
+struct Shared {
+    view: Mutex<RunView>,
+    live: broadcast::Sender<ProgressEvent>,
+}
+
+impl Shared {
+    fn view(&self) -> MutexGuard<'_, RunView> {
+        self.view.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Serves the read-only web dashboard of a run.
+pub struct Dashboard {
+    addr: SocketAddr,
+    shared: Arc<Shared>,
+    shutdown: Option<oneshot::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Dashboard {
+    /// Starts serving on `addr`; port `0` picks a free port.
+    pub fn start(addr: SocketAddr) -> Result<Self> {
+        let listener = TcpListener::bind(addr)
+            .with_context(|| format!("Failed to bind dashboard to {}", addr))?;
+        listener.set_nonblocking(true)?;
+        let addr = listener.local_addr()?;
+        let shared = Arc::new(Shared {
+            view: Mutex::new(RunView::default()),
+            live: broadcast::channel(LIVE_BUFFER).0,
+        });
+        let (shutdown, stopped) = oneshot::channel::<()>();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .context("Failed to create dashboard runtime")?;
+        let app = router(shared.clone());
+        let thread = std::thread::Builder::new()
+            .name("dashboard".to_string())
+            .spawn(move || {
+                runtime.block_on(async move {
+                    let listener = match tokio::net::TcpListener::from_std(listener) {
+                        Ok(listener) => listener,
+                        Err(e) => {
+                            tracing::warn!("Dashboard failed to listen: {}", e);
+                            return;
+                        }
+                    };
+                    // Open event streams never end, so the server is dropped
+                    // instead of shut down gracefully.
+                    tokio::select! {
+                        result = axum::serve(listener, app).into_future() => {
+                            if let Err(e) = result {
+                                tracing::warn!("Dashboard stopped: {}", e);
+                            }
+                        }
+                        _ = stopped => {}
+                    }
+                });
+            })
+            .context("Failed to start dashboard thread")?;
+
+        Ok(Self {
+            addr,
+            shared,
+            shutdown: Some(shutdown),
+            thread: Some(thread),
+        })
+    }
+
+    /// Returns the address the dashboard listens on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Records a progress event and passes it on to connected clients.
+    pub fn record(&self, event: &ProgressEvent) {
+        let mut view = self.shared.view();
+        view.summary.record(event);
+        if let ProgressEvent::Start {
+            state_file_path: Some(path),
+            ..
+        } = event
+        {
+            view.state_file = Some(path.clone());
+        }
+        if view.history.len() == HISTORY_LIMIT {
+            view.history.pop_front();
+        }
+        view.history.push_back(event.clone());
+        // Sent while holding the view, so a client subscribing sees every
+        // event exactly once: either in the history or live.
+        let _ = self.shared.live.send(event.clone());
+    }
+
+    /// Shows the dependency graph of the selected PRs.
+    pub fn set_dependency_graph(&self, graph: &PRDependencyGraph) {
+        self.shared.view().graph = Some(graph.clone());
+    }
+}
+
+impl Drop for Dashboard {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn router(shared: Arc<Shared>) -> Router {
+    Router::new()
+        .route("/", get(|| async { Html(DASHBOARD_HTML) }))
+        .route("/api/summary", get(summary))
+        .route("/api/events", get(events))
+        .route("/api/history", get(history))
+        .route("/api/state", get(state))
+        .route("/api/graph", get(graph))
+        .with_state(shared)
+}
+
+async fn summary(State(shared): State<Arc<Shared>>) -> Json<ProgressSummary> {
+    Json(shared.view().summary.clone())
+}
+
+async fn history(State(shared): State<Arc<Shared>>) -> Json<Vec<ProgressEvent>> {
+    Json(shared.view().history.iter().cloned().collect())
+}
+
+async fn events(
+    State(shared): State<Arc<Shared>>,
+) -> Sse<impl Stream<Item = Result<Event, axum::Error>>> {
+    let (history, live) = {
+        let view = shared.view();
+        (view.history.clone(), shared.live.subscribe())
+    };
+    let live = futures::stream::unfold(live, |mut live| async move {
+        loop {
+            match live.recv().await {
+                Ok(event) => return Some((event, live)),
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::debug!("Dashboard client skipped {} events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    });
+    let stream = futures::stream::iter(history)
+        .chain(live)
+        .map(|event| Event::default().json_data(event));
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+async fn state(State(shared): State<Arc<Shared>>) -> Response {
+    let Some(path) = shared.view().state_file.clone() else {
+        return not_found("The run has not created its state file yet");
+    };
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) => return not_found(&format!("Failed to read state file: {}", e)),
+    };
+    match serde_json::from_str::<serde_json::Value>(&contents) {
+        Ok(state) => Json(state).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Failed to parse state file: {}", e),
+        )
+            .into_response(),
+    }
+}
+
+async fn graph(State(shared): State<Arc<Shared>>) -> Response {
+    match shared.view().graph.clone() {
+        Some(graph) => Json(graph).into_response(),
+        None => not_found("Dependencies have not been analyzed yet"),
+    }
+}
+
+fn not_found(message: &str) -> Response {
+    (StatusCode::NOT_FOUND, message.to_string()).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::operations::PRDependencyNode;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpStream;
+
+    fn request(addr: SocketAddr, method: &str, path: &str) -> (String, String) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+            method, path
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        (head.lines().next().unwrap().to_string(), body.to_string())
+    }
+
+    fn get_json(addr: SocketAddr, path: &str) -> serde_json::Value {
+        let (status, body) = request(addr, "GET", path);
+        assert_eq!(status, "HTTP/1.1 200 OK", "{}", path);
+        serde_json::from_str(&body).unwrap()
+    }
+
+    fn start_event(state_file_path: Option<PathBuf>) -> ProgressEvent {
+        ProgressEvent::Start {
+            total_prs: 2,
+            version: "v1.0.0".to_string(),
+            target_branch: "main".to_string(),
+            state_file_path,
+        }
+    }
+
+    /// # Dashboard API
+    ///
+    /// Tests the read-only JSON API of the dashboard.
+    ///
+    /// ## Test Scenario
+    /// - Starts the dashboard on a free port and queries it before and after
+    ///   the run records events, creates its state file and analyzes
+    ///   dependencies
+    ///
+    /// ## Expected Outcome
+    /// - The page is served on `/`
+    /// - State file and graph are not found until they exist, then served
+    /// - Summary and history follow the recorded events
+    /// - Requests that would change anything are rejected
+    /// - Dropping the dashboard frees the port
+    #[test]
+    fn test_dashboard_api() {
+        let dir = tempfile::tempdir().unwrap();
+        let state_path = dir.path().join("merge-test.json");
+        std::fs::write(
+            &state_path,
+            r#"{"schema_version": 2, "phase": "cherry_picking"}"#,
+        )
+        .unwrap();
+
+        let dashboard = Dashboard::start(SocketAddr::from(([127, 0, 0, 1], 0))).unwrap();
+        let addr = dashboard.addr();
+
+        let (status, body) = request(addr, "GET", "/");
+        assert_eq!(status, "HTTP/1.1 200 OK");
+        assert!(body.contains("<title>mergers</title>"));
+        assert_eq!(
+            request(addr, "GET", "/api/state").0,
+            "HTTP/1.1 404 Not Found"
+        );
+        assert_eq!(
+            request(addr, "GET", "/api/graph").0,
+            "HTTP/1.1 404 Not Found"
+        );
+
+        dashboard.record(&start_event(Some(state_path)));
+        dashboard.record(&ProgressEvent::CherryPickSkipped {
+            pr_id: 1,
+            reason: None,
+        });
+        let mut graph = PRDependencyGraph::new();
+        graph.add_node(PRDependencyNode::new(1, "First".to_string(), true));
+        dashboard.set_dependency_graph(&graph);
+
+        let summary = get_json(addr, "/api/summary");
+        assert_eq!(summary["total"], 2);
+        assert_eq!(summary["completed"], 1);
+        let history = get_json(addr, "/api/history");
+        assert_eq!(history[0]["event"], "start");
+        assert_eq!(history[1]["event"], "cherry_pick_skipped");
+        assert_eq!(get_json(addr, "/api/state")["phase"], "cherry_picking");
+        assert_eq!(
+            get_json(addr, "/api/graph")["nodes"]["1"]["pr_title"],
+            "First"
+        );
+
+        assert_eq!(
+            request(addr, "POST", "/api/state").0,
+            "HTTP/1.1 405 Method Not Allowed"
+        );
+
+        drop(dashboard);
+        assert!(TcpListener::bind(addr).is_ok());
+    }
+
+    /// # Dashboard Event Stream
+    ///
+    /// Tests streaming progress events to a connected client.
+    ///
+    /// ## Test Scenario
+    /// - Records an event, connects to the event stream, then records
+    ///   another event
+    ///
+    /// ## Expected Outcome
+    /// - The client receives the earlier event first, then the new one
+    /// - Dropping the dashboard does not wait for the open stream
+    #[test]
+    fn test_dashboard_event_stream() {
+        let dashboard = Dashboard::start(SocketAddr::from(([127, 0, 0, 1], 0))).unwrap();
+        dashboard.record(&start_event(None));
+
+        let mut stream = TcpStream::connect(dashboard.addr()).unwrap();
+        stream
+            .write_all(b"GET /api/events HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut next_event = || loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if let Some(data) = line.trim_end().strip_prefix("data: ") {
+                return serde_json::from_str::<serde_json::Value>(data).unwrap();
+            }
+        };
+
+        assert_eq!(next_event()["event"], "start");
+        dashboard.record(&ProgressEvent::CherryPickSkipped {
+            pr_id: 7,
+            reason: None,
+        });
+        assert_eq!(next_event()["pr_id"], 7);
+
+        drop(dashboard);
+    }
+}
@@ -6,6 +6,10 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+use crate::core::operations::conflict_report::ConflictReport;
+use crate::core::operations::conflict_timeout::{ConflictDecision, ConflictTimeoutAction};
+use crate::notify::NotificationChannel;
+
 /// Progress events emitted during merge operations.
 ///
 /// Each variant represents a distinct stage or outcome that should be
@@ -44,6 +48,9 @@ pub enum ProgressEvent {
         pr_id: i32,
         /// Commit ID that was cherry-picked.
         commit_id: String,
+        /// Mainline parent used when the first parent did not apply.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        mainline_parent: Option<u8>,
     },
 
     /// Cherry-pick resulted in conflicts.
@@ -73,6 +80,26 @@ pub enum ProgressEvent {
         reason: Option<String>,
     },
 
+    /// PRs were selected from a selection file.
+    SelectionImported {
+        /// Path of the selection file.
+        path: PathBuf,
+        /// Number of PRs the file selected.
+        selected: usize,
+        /// Listed ids (`!PR` / `#WORKITEM`) that matched no candidate PR.
+        not_found: Vec<String>,
+    },
+
+    /// A setting differs from the previous run of the repository.
+    SettingChanged {
+        /// Display name of the setting.
+        setting: String,
+        /// Value in the previous run.
+        previous: String,
+        /// Value in this run.
+        current: String,
+    },
+
     /// Dependency analysis is starting.
     DependencyAnalysisStart {
         /// Number of PRs to analyze.
@@ -199,6 +226,58 @@ pub enum ProgressEvent {
         /// Error output from the command.
         error: String,
     },
+
+    /// A conflicted file is being opened for resolution.
+    ResolveFileStart {
+        /// Path of the file, relative to the worktree.
+        file: String,
+        /// Current index (0-based).
+        index: usize,
+        /// Total number of conflicted files.
+        total: usize,
+    },
+
+    /// A conflicted file was resolved and staged.
+    ResolveFileStaged {
+        /// Path of the file, relative to the worktree.
+        file: String,
+    },
+
+    /// A resolution saved from an earlier cherry-pick of the PR was
+    /// re-applied to a conflicted file, and the file was staged.
+    ResolveFileReused {
+        /// Path of the file, relative to the worktree.
+        file: String,
+    },
+
+    /// A conflict is held open for a human before the timeout action applies.
+    ConflictWaiting {
+        /// PR ID with conflicts.
+        pr_id: i32,
+        /// Seconds a human has to resolve and stage the files.
+        wait_seconds: u64,
+        /// Action taken if the conflict is still unresolved.
+        action: ConflictTimeoutAction,
+    },
+
+    /// A conflict held open by the timeout policy was settled.
+    ConflictSettled {
+        /// PR ID that had conflicts.
+        pr_id: i32,
+        /// How the conflict was settled.
+        decision: ConflictDecision,
+        /// Files resolved by keeping one side.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        files: Vec<String>,
+    },
+
+    /// The merge summary was posted to a chat webhook.
+    NotificationSent {
+        /// Chat service notified.
+        channel: NotificationChannel,
+        /// Whether the webhook accepted the message.
+        status: PostMergeStatus,
+    },
 }
 
 /// Status of a post-merge task.
@@ -244,6 +323,16 @@ pub struct ConflictInfo {
     pub repo_path: PathBuf,
     /// Instructions for resolution.
     pub resolution_instructions: Vec<String>,
+    /// Conflicted files with a resolution saved from an earlier cherry-pick
+    /// of the same PR.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub saved_resolutions: Vec<String>,
+    /// Structured report of the conflicted files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub report: Option<Box<ConflictReport>>,
+    /// Where the report was written.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub report_path: Option<PathBuf>,
 }
 
 impl ConflictInfo {
@@ -269,7 +358,31 @@ impl ConflictInfo {
             conflicted_files,
             repo_path,
             resolution_instructions: instructions,
+            saved_resolutions: Vec::new(),
+            report: None,
+            report_path: None,
+        }
+    }
+
+    /// Records the files with a saved resolution and adds a hint to
+    /// re-apply them.
+    pub fn with_saved_resolutions(mut self, files: Vec<String>) -> Self {
+        if !files.is_empty() {
+            self.resolution_instructions.push(format!(
+                "Tip: `mergers merge resolve` re-applies the resolutions of {} file(s) \
+                 saved from an earlier cherry-pick of this PR",
+                files.len()
+            ));
         }
+        self.saved_resolutions = files;
+        self
+    }
+
+    /// Attaches the conflict report and the file it was written to.
+    pub fn with_report(mut self, report: ConflictReport, path: Option<PathBuf>) -> Self {
+        self.report = Some(Box::new(report));
+        self.report_path = path;
+        self
     }
 }
 
@@ -296,8 +409,185 @@ pub struct StatusInfo {
     pub items: Option<Vec<SummaryItem>>,
 }
 
-/// Summary of cherry-pick progress.
+/// What `merge recover` found in an interrupted merge.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecoveryInfo {
+    /// Version being created.
+    pub version: String,
+    /// Target branch.
+    pub target_branch: String,
+    /// Phase recorded in the state file.
+    pub phase: String,
+    /// Worktree or clone the merge runs in.
+    pub repo_path: PathBuf,
+    /// Whether the worktree still exists on disk.
+    pub worktree_exists: bool,
+    /// Patch branch the picks were committed to.
+    pub branch: String,
+    /// Commit checked out in the worktree.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head: Option<String>,
+    /// Whether a cherry-pick was stopped half-way.
+    pub cherry_pick_in_progress: bool,
+    /// Whether tracked files have uncommitted changes.
+    pub uncommitted_changes: bool,
+    /// Files with unresolved conflicts.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conflicted_files: Vec<String>,
+    /// Index of the first item a resume would pick.
+    pub resume_index: usize,
+    /// Every item with its recorded status and the commit it was found as.
+    pub items: Vec<RecoveryItem>,
+    /// Branch the applied commits were exported to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rescue_branch: Option<String>,
+}
+
+impl RecoveryInfo {
+    /// Items applied on the branch but not recorded as picked.
+    pub fn unrecorded(&self) -> impl Iterator<Item = &RecoveryItem> {
+        self.items.iter().filter(|item| item.is_unrecorded())
+    }
+
+    /// Items recorded as picked but missing from the branch.
+    pub fn missing(&self) -> impl Iterator<Item = &RecoveryItem> {
+        self.items.iter().filter(|item| item.is_missing())
+    }
+}
+
+/// One cherry-pick item in a [`RecoveryInfo`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecoveryItem {
+    /// PR ID.
+    pub pr_id: i32,
+    /// PR title.
+    pub pr_title: String,
+    /// Commit ID being cherry-picked.
+    pub commit_id: String,
+    /// Status recorded in the state file.
+    pub recorded: ItemStatus,
+    /// Commit on the patch branch carrying this PR's change, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub applied_commit: Option<String>,
+}
+
+impl RecoveryItem {
+    /// Applied on the branch, but the state file does not know.
+    pub fn is_unrecorded(&self) -> bool {
+        self.applied_commit.is_some() && self.recorded != ItemStatus::Success
+    }
+
+    /// Recorded as picked, but not found on the branch.
+    pub fn is_missing(&self) -> bool {
+        self.applied_commit.is_none() && self.recorded == ItemStatus::Success
+    }
+}
+
+/// What `merge --dry-run` predicts a merge would do.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DryRunReport {
+    /// Version that would be created.
+    pub version: String,
+    /// Target branch.
+    pub target_branch: String,
+    /// Ref the cherry-picks were simulated onto.
+    pub base: String,
+    /// Label each applied PR would get on completion.
+    pub label: String,
+    /// State the work items of applied PRs would be moved to.
+    pub work_item_state: String,
+    /// Dependency relationships among the PRs, if the analysis succeeded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dependencies: Option<DryRunDependencies>,
+    /// Selected PRs in cherry-pick order with their predicted outcome.
+    pub items: Vec<DryRunItem>,
+    /// Selected PRs left out because they have no merge commit.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped_pr_ids: Vec<i32>,
+}
+
+impl DryRunReport {
+    /// Items that would be applied without stopping.
+    pub fn applied(&self) -> impl Iterator<Item = &DryRunItem> {
+        self.items.iter().filter(|item| item.outcome.is_applied())
+    }
+
+    /// Items whose cherry-pick would stop the merge.
+    pub fn stopping(&self) -> impl Iterator<Item = &DryRunItem> {
+        self.items.iter().filter(|item| !item.outcome.is_applied())
+    }
+
+    /// Work items that would be moved to `work_item_state`, without duplicates.
+    pub fn work_item_ids(&self) -> Vec<i32> {
+        let mut ids: Vec<i32> = self
+            .applied()
+            .flat_map(|item| item.work_item_ids.iter().copied())
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+}
+
+/// Dependency analysis counts in a [`DryRunReport`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct DryRunDependencies {
+    /// Number of independent PR pairs.
+    pub independent: usize,
+    /// Number of partially dependent PR pairs.
+    pub partial: usize,
+    /// Number of dependent PR pairs.
+    pub dependent: usize,
+    /// Selected PRs depending on PRs that are not selected.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// One selected PR in a [`DryRunReport`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DryRunItem {
+    /// PR ID.
+    pub pr_id: i32,
+    /// PR title.
+    pub pr_title: String,
+    /// Merge commit that would be cherry-picked.
+    pub commit_id: String,
+    /// Predicted outcome of the cherry-pick.
+    pub outcome: DryRunOutcome,
+    /// Files that would conflict (or be resolved by the binary policy).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conflicted_files: Vec<String>,
+    /// Why the simulation failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Linked work items.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub work_item_ids: Vec<i32>,
+}
+
+/// Predicted outcome of a simulated cherry-pick.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DryRunOutcome {
+    /// Applies cleanly.
+    Clean,
+    /// Conflicts only in files the binary conflict policy resolves.
+    ResolvedByPolicy,
+    /// Conflicts and would pause the merge for resolution.
+    Conflict,
+    /// Could not be simulated (e.g. the commit is not available locally).
+    Failed,
+}
+
+impl DryRunOutcome {
+    /// Whether the pick would be applied without stopping the merge.
+    pub fn is_applied(&self) -> bool {
+        matches!(self, DryRunOutcome::Clean | DryRunOutcome::ResolvedByPolicy)
+    }
+}
+
+/// Summary of cherry-pick progress.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct ProgressSummary {
     /// Total number of items.
     pub total: usize,
@@ -309,6 +599,36 @@ pub struct ProgressSummary {
     pub current_index: usize,
 }
 
+impl ProgressSummary {
+    /// Updates the summary from a progress event.
+    ///
+    /// Conflicts leave the counts unchanged: the PR stays pending until it is
+    /// resolved, skipped or aborted.
+    pub fn record(&mut self, event: &ProgressEvent) {
+        match event {
+            ProgressEvent::Start { total_prs, .. } => {
+                *self = ProgressSummary {
+                    total: *total_prs,
+                    completed: 0,
+                    pending: *total_prs,
+                    current_index: 0,
+                };
+            }
+            ProgressEvent::CherryPickStart { index, total, .. } => {
+                self.current_index = *index;
+                self.total = *total;
+            }
+            ProgressEvent::CherryPickSuccess { .. }
+            | ProgressEvent::CherryPickFailed { .. }
+            | ProgressEvent::CherryPickSkipped { .. } => {
+                self.completed = (self.completed + 1).min(self.total);
+                self.pending = self.total - self.completed;
+            }
+            _ => {}
+        }
+    }
+}
+
 /// Summary information for final output.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SummaryInfo {
@@ -393,6 +713,12 @@ pub struct SummaryItem {
     pub pr_title: String,
     /// Commit ID.
     pub commit_id: String,
+    /// Commit created on the patch branch, once picked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub picked_commit_id: Option<String>,
+    /// Mainline parent used when the first parent did not apply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mainline_parent: Option<u8>,
     /// Status of the item.
     pub status: ItemStatus,
     /// Error message if failed.
@@ -511,6 +837,7 @@ mod tests {
         let success = ProgressEvent::CherryPickSuccess {
             pr_id: 123,
             commit_id: "abc123".to_string(),
+            mainline_parent: None,
         };
         let json = serde_json::to_string(&success).unwrap();
         assert!(json.contains("\"event\":\"cherry_pick_success\""));
@@ -587,9 +914,11 @@ mod tests {
     ///
     /// ## Test Scenario
     /// - Creates ConflictInfo with basic data
+    /// - Adds a file with a saved resolution
     ///
     /// ## Expected Outcome
     /// - Default resolution instructions are set
+    /// - The saved resolution is recorded with a hint to re-apply it
     #[test]
     fn test_conflict_info_creation() {
         let info = ConflictInfo::new(
@@ -604,6 +933,12 @@ mod tests {
         assert_eq!(info.conflicted_files.len(), 1);
         assert_eq!(info.resolution_instructions.len(), 4);
         assert!(info.resolution_instructions[0].contains("/tmp/repo"));
+        assert!(info.saved_resolutions.is_empty());
+
+        let info = info.with_saved_resolutions(vec!["file1.rs".to_string()]);
+        assert_eq!(info.saved_resolutions, vec!["file1.rs".to_string()]);
+        assert_eq!(info.resolution_instructions.len(), 5);
+        assert!(info.resolution_instructions[4].contains("mergers merge resolve"));
     }
 
     /// # Summary Counts Creation
@@ -740,6 +1075,7 @@ mod tests {
             ProgressEvent::CherryPickSuccess {
                 pr_id: 1,
                 commit_id: "abc".to_string(),
+                mainline_parent: None,
             },
             ProgressEvent::CherryPickConflict {
                 pr_id: 1,
@@ -797,6 +1133,32 @@ mod tests {
                 command: "cargo test".to_string(),
                 error: "test failed".to_string(),
             },
+            ProgressEvent::ResolveFileStart {
+                file: "src/lib.rs".to_string(),
+                index: 0,
+                total: 1,
+            },
+            ProgressEvent::ResolveFileStaged {
+                file: "src/lib.rs".to_string(),
+            },
+            ProgressEvent::ResolveFileReused {
+                file: "src/lib.rs".to_string(),
+            },
+            ProgressEvent::ConflictWaiting {
+                pr_id: 1,
+                wait_seconds: 600,
+                action: ConflictTimeoutAction::Skip,
+            },
+            ProgressEvent::ConflictSettled {
+                pr_id: 1,
+                decision: ConflictDecision::KeptTheirs,
+                files: vec!["src/lib.rs".to_string()],
+            },
+            ProgressEvent::SelectionImported {
+                path: PathBuf::from("picks.txt"),
+                selected: 2,
+                not_found: vec!["!999".to_string()],
+            },
         ];
 
         for event in events {
@@ -899,4 +1261,65 @@ mod tests {
         assert!(json.contains("\"successful\":5"));
         assert!(json.contains("\"failed\":1"));
     }
+
+    /// # Progress Summary From Events
+    ///
+    /// Tests tracking progress from the event stream.
+    ///
+    /// ## Test Scenario
+    /// - Records a start event, cherry-pick starts and one of each outcome
+    ///
+    /// ## Expected Outcome
+    /// - Success, failure and skip complete a PR; a conflict does not
+    #[test]
+    fn test_progress_summary_record() {
+        let mut summary = ProgressSummary {
+            total: 0,
+            completed: 0,
+            pending: 0,
+            current_index: 0,
+        };
+        summary.record(&ProgressEvent::Start {
+            total_prs: 4,
+            version: "1.0.0".to_string(),
+            target_branch: "main".to_string(),
+            state_file_path: None,
+        });
+        summary.record(&ProgressEvent::CherryPickStart {
+            pr_id: 1,
+            commit_id: "abc".to_string(),
+            index: 0,
+            total: 4,
+        });
+        summary.record(&ProgressEvent::CherryPickSuccess {
+            pr_id: 1,
+            commit_id: "abc".to_string(),
+            mainline_parent: None,
+        });
+        summary.record(&ProgressEvent::CherryPickFailed {
+            pr_id: 2,
+            error: "boom".to_string(),
+        });
+        summary.record(&ProgressEvent::CherryPickStart {
+            pr_id: 3,
+            commit_id: "def".to_string(),
+            index: 2,
+            total: 4,
+        });
+        summary.record(&ProgressEvent::CherryPickConflict {
+            pr_id: 3,
+            conflicted_files: vec!["a.rs".to_string()],
+            repo_path: "/repo".into(),
+        });
+
+        assert_eq!(
+            summary,
+            ProgressSummary {
+                total: 4,
+                completed: 2,
+                pending: 2,
+                current_index: 2,
+            }
+        );
+    }
 }
@@ -4,8 +4,8 @@
 //! each implementing the `OutputFormatter` trait for consistent behavior.
 
 use super::events::{
-    ConflictInfo, ItemStatus, PostMergeStatus, ProgressEvent, StatusInfo, SummaryInfo,
-    SummaryResult,
+    ConflictInfo, DryRunOutcome, DryRunReport, ItemStatus, PostMergeStatus, ProgressEvent,
+    RecoveryInfo, StatusInfo, SummaryInfo, SummaryResult,
 };
 use crate::models::OutputFormat;
 use crate::utils::truncate_str;
@@ -25,6 +25,12 @@ pub trait OutputFormatter {
     /// Writes a final summary.
     fn write_summary(&mut self, summary: &SummaryInfo) -> io::Result<()>;
 
+    /// Writes the findings of `merge recover`.
+    fn write_recovery(&mut self, recovery: &RecoveryInfo) -> io::Result<()>;
+
+    /// Writes the predictions of `merge --dry-run`.
+    fn write_dry_run(&mut self, report: &DryRunReport) -> io::Result<()>;
+
     /// Flushes any buffered output.
     fn flush(&mut self) -> io::Result<()>;
 }
@@ -35,6 +41,8 @@ pub struct OutputWriter<W: Write> {
     format: OutputFormat,
     quiet: bool,
     events: Vec<ProgressEvent>,
+    /// Conflict of a JSON run, written with the summary or on flush.
+    conflict: Option<ConflictInfo>,
 }
 
 impl<W: Write> OutputWriter<W> {
@@ -45,6 +53,7 @@ impl<W: Write> OutputWriter<W> {
             format,
             quiet,
             events: Vec::new(),
+            conflict: None,
         }
     }
 
@@ -58,6 +67,11 @@ impl<W: Write> OutputWriter<W> {
         self.quiet
     }
 
+    /// Forgets the conflict buffered by a JSON run once it is settled.
+    pub fn clear_conflict(&mut self) {
+        self.conflict = None;
+    }
+
     /// Writes text with optional color support.
     fn write_text(&mut self, text: &str) -> io::Result<()> {
         write!(self.writer, "{}", text)
@@ -147,7 +161,28 @@ impl<W: Write> OutputFormatter for OutputWriter<W> {
                 self.writeln("")?;
                 self.writeln("Conflicted files:")?;
                 for file in &conflict.conflicted_files {
-                    self.writeln(&format!("  • {}", file))?;
+                    let overlapping: Vec<String> = conflict
+                        .report
+                        .iter()
+                        .flat_map(|report| &report.files)
+                        .filter(|f| &f.path == file)
+                        .flat_map(|f| &f.conflicting_prs)
+                        .filter(|pr| !pr.overlapping_lines.is_empty())
+                        .map(|pr| format!("#{}", pr.pr_id))
+                        .collect();
+                    if overlapping.is_empty() {
+                        self.writeln(&format!("  • {}", file))?;
+                    } else {
+                        self.writeln(&format!(
+                            "  • {} (overlaps picked PR {})",
+                            file,
+                            overlapping.join(", ")
+                        ))?;
+                    }
+                }
+                if let Some(path) = &conflict.report_path {
+                    self.writeln("")?;
+                    self.writeln(&format!("Conflict report: {}", path.display()))?;
                 }
                 self.writeln("")?;
                 self.writeln("To resolve:")?;
@@ -157,7 +192,8 @@ impl<W: Write> OutputFormatter for OutputWriter<W> {
                 self.writeln("")?;
             }
             OutputFormat::Json => {
-                // Include in final summary
+                // Included in the final summary, or written on flush
+                self.conflict = Some(conflict.clone());
             }
             OutputFormat::Ndjson => {
                 let event = ProgressEvent::CherryPickConflict {
@@ -228,6 +264,189 @@ impl<W: Write> OutputFormatter for OutputWriter<W> {
         Ok(())
     }
 
+    fn write_recovery(&mut self, recovery: &RecoveryInfo) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Text => {
+                let yes_no = |value: bool| if value { "yes" } else { "no" };
+                self.writeln("")?;
+                self.writeln("═══════════════════════════════════════════════════════════")?;
+                self.writeln("                     MERGE RECOVERY                         ")?;
+                self.writeln("═══════════════════════════════════════════════════════════")?;
+                self.writeln("")?;
+                self.writeln(&format!("Version:       {}", recovery.version))?;
+                self.writeln(&format!("Target Branch: {}", recovery.target_branch))?;
+                self.writeln(&format!("Phase:         {}", recovery.phase))?;
+                self.writeln(&format!(
+                    "Worktree:      {}{}",
+                    recovery.repo_path.display(),
+                    if recovery.worktree_exists {
+                        ""
+                    } else {
+                        " (missing)"
+                    }
+                ))?;
+                self.writeln(&format!("Branch:        {}", recovery.branch))?;
+                if let Some(head) = &recovery.head {
+                    self.writeln(&format!("HEAD:          {}", truncate_str(head, 8)))?;
+                }
+                self.writeln(&format!(
+                    "Cherry-pick in progress: {}",
+                    yes_no(recovery.cherry_pick_in_progress)
+                ))?;
+                self.writeln(&format!(
+                    "Uncommitted changes:     {}",
+                    yes_no(recovery.uncommitted_changes)
+                ))?;
+                for file in &recovery.conflicted_files {
+                    self.writeln(&format!("  conflict: {}", file))?;
+                }
+                self.writeln("")?;
+
+                self.writeln("Items:")?;
+                for item in &recovery.items {
+                    let (symbol, note) = match &item.applied_commit {
+                        Some(commit) if item.is_unrecorded() => (
+                            "✓",
+                            format!("applied as {}, not recorded", truncate_str(commit, 8)),
+                        ),
+                        Some(commit) => ("✓", format!("applied as {}", truncate_str(commit, 8))),
+                        None if item.is_missing() => {
+                            ("✗", "recorded as success, not on branch".to_string())
+                        }
+                        None => (
+                            Self::status_symbol(&item.recorded),
+                            item.recorded.to_string(),
+                        ),
+                    };
+                    self.writeln(&format!(
+                        "  {} PR #{}: {} [{}]",
+                        symbol,
+                        item.pr_id,
+                        truncate_string(&item.pr_title, 40),
+                        note
+                    ))?;
+                }
+                self.writeln("")?;
+
+                if let Some(branch) = &recovery.rescue_branch {
+                    self.writeln(&format!("Applied commits exported to {}", branch))?;
+                } else {
+                    let next = recovery
+                        .items
+                        .get(recovery.resume_index)
+                        .map(|item| format!("continue with PR #{}", item.pr_id))
+                        .unwrap_or_else(|| "mark the picks ready for completion".to_string());
+                    self.writeln("Options:")?;
+                    self.writeln(&format!("  mergers merge recover --resume   {}", next))?;
+                    self.writeln(
+                        "  mergers merge recover --abort    remove the worktree and patch branch",
+                    )?;
+                    self.writeln(
+                        "  mergers merge recover --export   keep the applied commits on a rescue branch",
+                    )?;
+                }
+                self.writeln("")?;
+            }
+            OutputFormat::Json | OutputFormat::Ndjson => {
+                let json = serde_json::to_string_pretty(recovery).map_err(io::Error::other)?;
+                self.writeln(&json)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_dry_run(&mut self, report: &DryRunReport) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Text => {
+                self.writeln("")?;
+                self.writeln("═══════════════════════════════════════════════════════════")?;
+                self.writeln("                         DRY RUN                           ")?;
+                self.writeln("═══════════════════════════════════════════════════════════")?;
+                self.writeln("")?;
+                self.writeln(&format!("Version:       {}", report.version))?;
+                self.writeln(&format!(
+                    "Target Branch: {} (simulated onto {})",
+                    report.target_branch, report.base
+                ))?;
+                if let Some(deps) = &report.dependencies {
+                    self.writeln(&format!(
+                        "Dependencies:  {} independent, {} partial, {} dependent",
+                        deps.independent, deps.partial, deps.dependent
+                    ))?;
+                    for warning in &deps.warnings {
+                        self.writeln(&format!("  ⚡ {}", warning))?;
+                    }
+                }
+                self.writeln("")?;
+
+                self.writeln("Picks:")?;
+                for item in &report.items {
+                    let (symbol, note) = match item.outcome {
+                        DryRunOutcome::Clean => ("✓", "clean".to_string()),
+                        DryRunOutcome::ResolvedByPolicy => {
+                            ("✓", "binary conflicts resolved by policy".to_string())
+                        }
+                        DryRunOutcome::Conflict => ("⚠", "conflict".to_string()),
+                        DryRunOutcome::Failed => (
+                            "✗",
+                            format!("failed: {}", item.error.as_deref().unwrap_or("unknown")),
+                        ),
+                    };
+                    self.writeln(&format!(
+                        "  {} PR #{}: {} [{}]",
+                        symbol,
+                        item.pr_id,
+                        truncate_string(&item.pr_title, 40),
+                        note
+                    ))?;
+                    for file in &item.conflicted_files {
+                        self.writeln(&format!("     • {}", file))?;
+                    }
+                }
+                for pr_id in &report.skipped_pr_ids {
+                    self.writeln(&format!("  ⊘ PR #{} [no merge commit]", pr_id))?;
+                }
+                self.writeln("")?;
+
+                let applied = report.applied().count();
+                let stopping = report.stopping().count();
+                let work_items = report.work_item_ids();
+                self.writeln(&format!(
+                    "Results: {} would apply, {} would stop the merge",
+                    applied, stopping
+                ))?;
+                self.writeln("On completion:")?;
+                self.writeln(&format!(
+                    "  Label {} PR{} with {}",
+                    applied,
+                    if applied == 1 { "" } else { "s" },
+                    report.label
+                ))?;
+                self.writeln(&format!(
+                    "  Move {} work item{} to {}",
+                    work_items.len(),
+                    if work_items.len() == 1 { "" } else { "s" },
+                    report.work_item_state
+                ))?;
+                self.writeln("")?;
+                self.writeln("Nothing was changed.")?;
+            }
+            OutputFormat::Json => {
+                let output = serde_json::json!({
+                    "dry_run": report,
+                    "events": self.events
+                });
+                let json = serde_json::to_string_pretty(&output).map_err(io::Error::other)?;
+                self.writeln(&json)?;
+            }
+            OutputFormat::Ndjson => {
+                let json = serde_json::to_string(report).map_err(io::Error::other)?;
+                self.writeln(&json)?;
+            }
+        }
+        Ok(())
+    }
+
     fn write_summary(&mut self, summary: &SummaryInfo) -> io::Result<()> {
         match self.format {
             OutputFormat::Text => {
@@ -258,6 +477,20 @@ impl<W: Write> OutputFormatter for OutputWriter<W> {
                 self.writeln(&format!("    Total:      {}", summary.counts.total))?;
                 self.writeln("")?;
 
+                let second_parent = summary
+                    .items
+                    .iter()
+                    .flatten()
+                    .filter_map(|item| Some((item.pr_id, item.mainline_parent?)))
+                    .collect::<Vec<_>>();
+                if !second_parent.is_empty() {
+                    self.writeln("Picked with a non-default mainline:")?;
+                    for (pr_id, parent) in second_parent {
+                        self.writeln(&format!("  PR #{} (-m {})", pr_id, parent))?;
+                    }
+                    self.writeln("")?;
+                }
+
                 if let Some(post_merge) = &summary.post_merge {
                     self.writeln("Post-merge tasks:")?;
                     self.writeln(&format!("  ✓ Successful: {}", post_merge.successful))?;
@@ -267,10 +500,14 @@ impl<W: Write> OutputFormatter for OutputWriter<W> {
             }
             OutputFormat::Json => {
                 // Write the full summary as JSON
-                let output = serde_json::json!({
+                let mut output = serde_json::json!({
                     "summary": summary,
                     "events": self.events
                 });
+                if let Some(conflict) = self.conflict.take() {
+                    output["conflict"] =
+                        serde_json::to_value(conflict).map_err(io::Error::other)?;
+                }
                 let json = serde_json::to_string_pretty(&output).map_err(io::Error::other)?;
                 self.writeln(&json)?;
             }
@@ -284,6 +521,15 @@ impl<W: Write> OutputFormatter for OutputWriter<W> {
     }
 
     fn flush(&mut self) -> io::Result<()> {
+        // A JSON run stopping at a conflict writes no summary
+        if let Some(conflict) = self.conflict.take() {
+            let output = serde_json::json!({
+                "conflict": conflict,
+                "events": self.events
+            });
+            let json = serde_json::to_string_pretty(&output).map_err(io::Error::other)?;
+            self.writeln(&json)?;
+        }
         self.writer.flush()
     }
 }
@@ -321,8 +567,15 @@ impl<W: Write> OutputWriter<W> {
                 ))?;
                 self.writer.flush()?;
             }
-            ProgressEvent::CherryPickSuccess { pr_id, .. } => {
-                self.writeln(&format!(" ✓ PR #{} applied", pr_id))?;
+            ProgressEvent::CherryPickSuccess {
+                pr_id,
+                mainline_parent,
+                ..
+            } => {
+                let parent_str = mainline_parent
+                    .map(|parent| format!(" (using parent {})", parent))
+                    .unwrap_or_default();
+                self.writeln(&format!(" ✓ PR #{} applied{}", pr_id, parent_str))?;
             }
             ProgressEvent::CherryPickConflict {
                 pr_id,
@@ -346,6 +599,31 @@ impl<W: Write> OutputWriter<W> {
                     .unwrap_or_default();
                 self.writeln(&format!(" ⊘ PR #{} skipped{}", pr_id, reason_str))?;
             }
+            ProgressEvent::SelectionImported {
+                path,
+                selected,
+                not_found,
+            } => {
+                self.writeln(&format!(
+                    "Selected {} PR{} from {}",
+                    selected,
+                    if *selected == 1 { "" } else { "s" },
+                    path.display()
+                ))?;
+                if !not_found.is_empty() {
+                    self.writeln(&format!("  ⚡ Not found: {}", not_found.join(", ")))?;
+                }
+            }
+            ProgressEvent::SettingChanged {
+                setting,
+                previous,
+                current,
+            } => {
+                self.writeln(&format!(
+                    "⚡ {} changed since the previous run: {} → {}",
+                    setting, previous, current
+                ))?;
+            }
             ProgressEvent::DependencyAnalysisStart { pr_count } => {
                 self.writeln(&format!("Analyzing dependencies for {} PRs...", pr_count))?;
             }
@@ -479,11 +757,59 @@ impl<W: Write> OutputWriter<W> {
                     self.writeln(&format!("  Error: {}", error))?;
                 }
             }
+            ProgressEvent::ResolveFileStart { file, index, total } => {
+                self.writeln(&format!("[{}/{}] Resolving {}...", index + 1, total, file))?;
+            }
+            ProgressEvent::ResolveFileStaged { file } => {
+                self.writeln(&format!(" ✓ {} staged", file))?;
+            }
+            ProgressEvent::ResolveFileReused { file } => {
+                self.writeln(&format!(" ✓ {} staged with its saved resolution", file))?;
+            }
+            ProgressEvent::ConflictWaiting {
+                pr_id,
+                wait_seconds,
+                action,
+            } => {
+                self.writeln(&format!(
+                    "  Waiting {} for PR #{}'s conflicts to be resolved and staged (then: {})",
+                    format_wait(*wait_seconds),
+                    pr_id,
+                    action
+                ))?;
+            }
+            ProgressEvent::ConflictSettled {
+                pr_id,
+                decision,
+                files,
+            } => {
+                self.writeln(&format!("  Conflict in PR #{}: {}", pr_id, decision))?;
+                for file in files {
+                    self.writeln(&format!("    ✓ {}", file))?;
+                }
+            }
+            ProgressEvent::NotificationSent { channel, status } => match status {
+                PostMergeStatus::Failed { error } => {
+                    self.writeln(&format!("  ✗ {} notification failed: {}", channel, error))?;
+                }
+                _ => {
+                    self.writeln(&format!("  ✓ {} notified", channel))?;
+                }
+            },
         }
         Ok(())
     }
 }
 
+/// Formats a wait as minutes, or seconds below a minute.
+fn format_wait(seconds: u64) -> String {
+    if seconds >= 60 && seconds % 60 == 0 {
+        format!("{}m", seconds / 60)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
 /// Truncates a string to a maximum length, adding ellipsis if needed.
 fn truncate_string(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
@@ -559,6 +885,7 @@ mod tests {
             .write_event(&ProgressEvent::CherryPickSuccess {
                 pr_id: 123,
                 commit_id: "abc".to_string(),
+                mainline_parent: None,
             })
             .unwrap();
 
@@ -635,6 +962,7 @@ mod tests {
             .write_event(&ProgressEvent::CherryPickSuccess {
                 pr_id: 123,
                 commit_id: "abc".to_string(),
+                mainline_parent: None,
             })
             .unwrap();
 
@@ -683,6 +1011,72 @@ mod tests {
         assert!(output.contains("To resolve"));
     }
 
+    /// # Conflict Report Output
+    ///
+    /// Verifies a conflict with a report is written in text and JSON mode.
+    ///
+    /// ## Test Scenario
+    /// - Writes a conflict whose report lists a picked PR overlapping one
+    ///   file, with the text formatter
+    /// - Writes the same conflict with the JSON formatter and flushes
+    ///
+    /// ## Expected Outcome
+    /// - Text marks the overlapping file and shows the report path
+    /// - JSON buffers the conflict until flush, then writes it with the
+    ///   report
+    #[test]
+    fn test_conflict_report_output() {
+        use crate::core::operations::{ConflictReport, ConflictedFile, ConflictingPr, LineRange};
+
+        let report = ConflictReport {
+            version: 1,
+            merge_version: "v1.0.0".to_string(),
+            target_branch: "main".to_string(),
+            pr_id: 123,
+            pr_title: "Test PR".to_string(),
+            commit_id: "abc123".to_string(),
+            files: vec![ConflictedFile {
+                path: "file1.rs".to_string(),
+                regions: vec![LineRange::new(4, 6)],
+                changed_lines: vec![LineRange::new(5, 6)],
+                conflicting_prs: vec![ConflictingPr {
+                    pr_id: 100,
+                    pr_title: "Earlier PR".to_string(),
+                    commit_id: "def456".to_string(),
+                    overlapping_lines: vec![LineRange::single(5)],
+                }],
+            }],
+            resolution_order: vec!["file1.rs".to_string()],
+        };
+        let conflict = ConflictInfo::new(
+            123,
+            "Test PR".to_string(),
+            "abc123".to_string(),
+            vec!["file1.rs".to_string(), "file2.rs".to_string()],
+            PathBuf::from("/tmp/repo"),
+        )
+        .with_report(report, Some(PathBuf::from("/state/conflicts-1.json")));
+
+        let mut buffer = Vec::new();
+        let mut writer = OutputWriter::new(&mut buffer, OutputFormat::Text, false);
+        writer.write_conflict(&conflict).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("file1.rs (overlaps picked PR #100)"));
+        assert!(output.contains("  • file2.rs\n"));
+        assert!(output.contains("Conflict report: /state/conflicts-1.json"));
+
+        let mut buffer = Vec::new();
+        let mut writer = OutputWriter::new(&mut buffer, OutputFormat::Json, false);
+        writer.write_conflict(&conflict).unwrap();
+        assert!(writer.conflict.is_some());
+        writer.flush().unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&String::from_utf8(buffer).unwrap()).unwrap();
+        let file = &json["conflict"]["report"]["files"][0];
+        assert_eq!(file["regions"][0]["start"], 4);
+        assert_eq!(file["conflicting_prs"][0]["overlapping_lines"][0]["end"], 5);
+    }
+
     /// # Progress Bar Formatting
     ///
     /// Verifies progress bar renders correctly.
@@ -852,6 +1246,7 @@ mod tests {
             .write_event(&ProgressEvent::CherryPickSuccess {
                 pr_id: 123,
                 commit_id: "abc123".to_string(),
+                mainline_parent: None,
             })
             .unwrap();
 
@@ -879,6 +1274,37 @@ mod tests {
         assert!(output.contains("already applied"));
     }
 
+    /// # Cherry Pick Success With Alternate Parent
+    ///
+    /// Verifies that a pick applied with the second parent says so.
+    ///
+    /// ## Test Scenario
+    /// - Writes a CherryPickSuccess event with mainline parent 2 in text and
+    ///   JSON-lines output
+    ///
+    /// ## Expected Outcome
+    /// - Text output names the parent; NDJSON carries `mainline_parent`
+    #[test]
+    fn test_cherry_pick_success_with_mainline_parent() {
+        let event = ProgressEvent::CherryPickSuccess {
+            pr_id: 123,
+            commit_id: "abc123".to_string(),
+            mainline_parent: Some(2),
+        };
+
+        let mut buffer = Vec::new();
+        let mut writer = OutputWriter::new(&mut buffer, OutputFormat::Text, false);
+        writer.write_event(&event).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("PR #123 applied (using parent 2)"));
+
+        let mut buffer = Vec::new();
+        let mut writer = OutputWriter::new(&mut buffer, OutputFormat::Ndjson, false);
+        writer.write_event(&event).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("\"mainline_parent\":2"));
+    }
+
     /// # Dependency Events Text Formatting
     ///
     /// Verifies dependency analysis events format correctly.
@@ -1010,6 +1436,49 @@ mod tests {
         }
     }
 
+    /// # Summary With Mainline Parent
+    ///
+    /// Verifies the text summary names the PRs picked with the second parent.
+    ///
+    /// ## Test Scenario
+    /// - Writes a summary with one item picked with `-m 1` and one with `-m 2`
+    ///
+    /// ## Expected Outcome
+    /// - Only the second item is listed, with its mainline
+    #[test]
+    fn test_summary_with_mainline_parent() {
+        use super::super::events::{
+            ItemStatus, SummaryCounts, SummaryInfo, SummaryItem, SummaryResult,
+        };
+
+        let item = |pr_id: i32, mainline_parent: Option<u8>| SummaryItem {
+            pr_id,
+            pr_title: format!("PR {}", pr_id),
+            commit_id: "abc123".to_string(),
+            picked_commit_id: None,
+            mainline_parent,
+            status: ItemStatus::Success,
+            error: None,
+        };
+        let summary = SummaryInfo {
+            result: SummaryResult::Success,
+            version: "v1.0.0".to_string(),
+            target_branch: "main".to_string(),
+            counts: SummaryCounts::new(2, 0, 0, 0),
+            items: Some(vec![item(1, None), item(2, Some(2))]),
+            post_merge: None,
+        };
+
+        let mut buffer = Vec::new();
+        let mut writer = OutputWriter::new(&mut buffer, OutputFormat::Text, false);
+        writer.write_summary(&summary).unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("Picked with a non-default mainline:"));
+        assert!(output.contains("PR #2 (-m 2)"));
+        assert!(!output.contains("PR #1 (-m"));
+    }
+
     /// # Summary With Post-Merge Tasks
     ///
     /// Verifies summary includes post-merge task counts.
@@ -1219,6 +1688,180 @@ mod tests {
         assert!(output.contains("tests failed"));
     }
 
+    /// # Resolve Events Text Formatting
+    ///
+    /// Verifies conflict resolution events format correctly.
+    ///
+    /// ## Test Scenario
+    /// - Writes a resolve start, a staged and a reused event
+    ///
+    /// ## Expected Outcome
+    /// - Output shows the file position and the staged files
+    #[test]
+    fn test_resolve_events_text_formatting() {
+        let mut buffer = Vec::new();
+        let mut writer = OutputWriter::new(&mut buffer, OutputFormat::Text, false);
+
+        writer
+            .write_event(&ProgressEvent::ResolveFileStart {
+                file: "src/lib.rs".to_string(),
+                index: 1,
+                total: 3,
+            })
+            .unwrap();
+        writer
+            .write_event(&ProgressEvent::ResolveFileStaged {
+                file: "src/lib.rs".to_string(),
+            })
+            .unwrap();
+        writer
+            .write_event(&ProgressEvent::ResolveFileReused {
+                file: "src/main.rs".to_string(),
+            })
+            .unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("[2/3] Resolving src/lib.rs..."));
+        assert!(output.contains("✓ src/lib.rs staged"));
+        assert!(output.contains("✓ src/main.rs staged with its saved resolution"));
+    }
+
+    /// # Conflict Timeout Events Text Formatting
+    ///
+    /// Verifies the unattended conflict policy's events format correctly.
+    ///
+    /// ## Test Scenario
+    /// - Writes a waiting event and a settled event with one resolved file
+    ///
+    /// ## Expected Outcome
+    /// - Output shows the wait, the timeout action, the decision and the file
+    #[test]
+    fn test_conflict_timeout_events_text_formatting() {
+        use crate::core::operations::conflict_timeout::{ConflictDecision, ConflictTimeoutAction};
+
+        let mut buffer = Vec::new();
+        let mut writer = OutputWriter::new(&mut buffer, OutputFormat::Text, false);
+
+        writer
+            .write_event(&ProgressEvent::ConflictWaiting {
+                pr_id: 42,
+                wait_seconds: 900,
+                action: ConflictTimeoutAction::Skip,
+            })
+            .unwrap();
+        writer
+            .write_event(&ProgressEvent::ConflictSettled {
+                pr_id: 42,
+                decision: ConflictDecision::KeptOurs,
+                files: vec!["src/lib.rs".to_string()],
+            })
+            .unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(
+            output.contains(
+                "Waiting 15m for PR #42's conflicts to be resolved and staged (then: skip)"
+            )
+        );
+        assert!(output.contains("Conflict in PR #42: kept ours"));
+        assert!(output.contains("✓ src/lib.rs"));
+    }
+
+    /// # Notification Events Text Formatting
+    ///
+    /// Verifies the chat notification events format correctly.
+    ///
+    /// ## Test Scenario
+    /// - Writes one delivered and one failed notification
+    ///
+    /// ## Expected Outcome
+    /// - Output names each channel and the failure's error
+    #[test]
+    fn test_notification_events_text_formatting() {
+        use crate::notify::NotificationChannel;
+
+        let mut buffer = Vec::new();
+        let mut writer = OutputWriter::new(&mut buffer, OutputFormat::Text, false);
+
+        writer
+            .write_event(&ProgressEvent::NotificationSent {
+                channel: NotificationChannel::Slack,
+                status: PostMergeStatus::Success,
+            })
+            .unwrap();
+        writer
+            .write_event(&ProgressEvent::NotificationSent {
+                channel: NotificationChannel::Teams,
+                status: PostMergeStatus::Failed {
+                    error: "Teams webhook returned HTTP 403".to_string(),
+                },
+            })
+            .unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("✓ Slack notified"));
+        assert!(output.contains("✗ Teams notification failed: Teams webhook returned HTTP 403"));
+    }
+
+    /// # Selection Imported Text Formatting
+    ///
+    /// Verifies the selection file event formats correctly.
+    ///
+    /// ## Test Scenario
+    /// - Writes a selection event with one id that was not found
+    ///
+    /// ## Expected Outcome
+    /// - Output shows the selected count, the file and the missing id
+    #[test]
+    fn test_selection_imported_text_formatting() {
+        let mut buffer = Vec::new();
+        let mut writer = OutputWriter::new(&mut buffer, OutputFormat::Text, false);
+
+        writer
+            .write_event(&ProgressEvent::SelectionImported {
+                path: PathBuf::from("picks.txt"),
+                selected: 2,
+                not_found: vec!["!999".to_string(), "#42".to_string()],
+            })
+            .unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("Selected 2 PRs from picks.txt"));
+        assert!(output.contains("Not found: !999, #42"));
+    }
+
+    /// # Setting Changed Formatting
+    ///
+    /// Verifies the text and NDJSON output of a changed setting.
+    ///
+    /// ## Test Scenario
+    /// - Writes a target branch change in both formats
+    ///
+    /// ## Expected Outcome
+    /// - Text shows the setting with both values
+    /// - NDJSON carries the `setting_changed` event with the same fields
+    #[test]
+    fn test_setting_changed_formatting() {
+        let event = ProgressEvent::SettingChanged {
+            setting: "Target Branch".to_string(),
+            previous: "next".to_string(),
+            current: "release".to_string(),
+        };
+
+        let mut buffer = Vec::new();
+        let mut writer = OutputWriter::new(&mut buffer, OutputFormat::Text, false);
+        writer.write_event(&event).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("Target Branch changed since the previous run: next → release"));
+
+        let mut buffer = Vec::new();
+        let mut writer = OutputWriter::new(&mut buffer, OutputFormat::Ndjson, false);
+        writer.write_event(&event).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("\"event\":\"setting_changed\""));
+        assert!(output.contains("\"previous\":\"next\""));
+    }
+
     /// # Flush Operation
     ///
     /// Verifies flush doesn't error.
@@ -1316,4 +1959,81 @@ mod tests {
         assert!(output.contains("PR #200"));
         assert!(output.contains("src/lib.rs"));
     }
+
+    fn sample_dry_run() -> DryRunReport {
+        use super::super::events::{DryRunDependencies, DryRunItem};
+        let item = |pr_id: i32, outcome: DryRunOutcome, work_item_ids: Vec<i32>| DryRunItem {
+            pr_id,
+            pr_title: format!("PR {}", pr_id),
+            commit_id: format!("{:040}", pr_id),
+            outcome,
+            conflicted_files: if outcome == DryRunOutcome::Conflict {
+                vec!["src/lib.rs".to_string()]
+            } else {
+                Vec::new()
+            },
+            error: None,
+            work_item_ids,
+        };
+        DryRunReport {
+            version: "v1.0.0".to_string(),
+            target_branch: "next".to_string(),
+            base: "origin/next".to_string(),
+            label: "merged-v1.0.0".to_string(),
+            work_item_state: "Next Merged".to_string(),
+            dependencies: Some(DryRunDependencies {
+                independent: 2,
+                partial: 1,
+                dependent: 0,
+                warnings: Vec::new(),
+            }),
+            items: vec![
+                item(1, DryRunOutcome::Clean, vec![10, 11]),
+                item(2, DryRunOutcome::Conflict, vec![12]),
+                item(3, DryRunOutcome::ResolvedByPolicy, vec![11]),
+            ],
+            skipped_pr_ids: vec![4],
+        }
+    }
+
+    /// # Dry Run Report Output
+    ///
+    /// Verifies the dry run report in text and JSON.
+    ///
+    /// ## Test Scenario
+    /// - Writes a report with a clean, a conflicting, a policy-resolved and a
+    ///   skipped PR in text and JSON format
+    ///
+    /// ## Expected Outcome
+    /// - Text lists each pick, the conflicted file and what completion would do
+    /// - Work items of the conflicting PR are not counted
+    /// - JSON round-trips the report
+    #[test]
+    fn test_dry_run_output() {
+        let report = sample_dry_run();
+        assert_eq!(report.work_item_ids(), vec![10, 11]);
+
+        let mut buffer = Vec::new();
+        let mut writer = OutputWriter::new(&mut buffer, OutputFormat::Text, false);
+        writer.write_dry_run(&report).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+
+        assert!(output.contains("DRY RUN"));
+        assert!(output.contains("next (simulated onto origin/next)"));
+        assert!(output.contains("✓ PR #1: PR 1 [clean]"));
+        assert!(output.contains("⚠ PR #2: PR 2 [conflict]"));
+        assert!(output.contains("• src/lib.rs"));
+        assert!(output.contains("⊘ PR #4 [no merge commit]"));
+        assert!(output.contains("2 would apply, 1 would stop the merge"));
+        assert!(output.contains("Label 2 PRs with merged-v1.0.0"));
+        assert!(output.contains("Move 2 work items to Next Merged"));
+
+        let mut buffer = Vec::new();
+        let mut writer = OutputWriter::new(&mut buffer, OutputFormat::Json, false);
+        writer.write_dry_run(&report).unwrap();
+        let output: serde_json::Value = serde_json::from_slice(&buffer).unwrap();
+        let parsed: DryRunReport = serde_json::from_value(output["dry_run"].clone()).unwrap();
+        assert_eq!(parsed, report);
+        assert_eq!(output["dry_run"]["items"][1]["outcome"], "conflict");
+    }
 }
@@ -4,11 +4,20 @@
 //! output formats (text, JSON, NDJSON). It enables consistent progress reporting
 //! and final summaries across all output modes.
 
+#[cfg(feature = "dashboard")]
+mod dashboard;
 mod events;
 mod format;
+mod report;
+mod status_server;
 
+#[cfg(feature = "dashboard")]
+pub use dashboard::Dashboard;
 pub use events::{
-    ConflictInfo, ItemStatus, PostMergeStatus, PostMergeSummary, ProgressEvent, ProgressSummary,
-    StatusInfo, SummaryCounts, SummaryInfo, SummaryItem, SummaryResult,
+    ConflictInfo, DryRunDependencies, DryRunItem, DryRunOutcome, DryRunReport, ItemStatus,
+    PostMergeStatus, PostMergeSummary, PostMergeTaskResult, ProgressEvent, ProgressSummary,
+    RecoveryInfo, RecoveryItem, StatusInfo, SummaryCounts, SummaryInfo, SummaryItem, SummaryResult,
 };
 pub use format::{OutputFormatter, OutputWriter};
+pub use report::{ReportTarget, junit_report};
+pub use status_server::StatusServer;
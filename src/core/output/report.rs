@@ -0,0 +1,285 @@
+//! Report files for CI systems.
+//!
+//! `--report FORMAT:PATH` writes a report of the cherry-picks each time a
+//! non-interactive merge stops, whether it finished, paused at a conflict or
+//! was aborted. The only format is `junit`: a JUnit XML file with one test
+//! case per PR, which Azure Pipelines (`PublishTestResults@2`) and Jenkins
+//! (`junit` step) show natively:
+//!
+//! | PR status | Test case |
+//! |-----------|-----------|
+//! | picked | passed |
+//! | conflict | failure, listing the conflicted files |
+//! | failed | failure, with the error |
+//! | skipped, or not picked yet | skipped |
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+
+use crate::core::state::{MergePhase, MergeStateFile, StateItemStatus};
+
+/// A report file to write, parsed from `FORMAT:PATH`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReportTarget {
+    /// JUnit XML file.
+    Junit(PathBuf),
+}
+
+impl FromStr for ReportTarget {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (format, path) = value
+            .split_once(':')
+            .ok_or_else(|| format!("expected FORMAT:PATH, got '{}'", value))?;
+        if path.is_empty() {
+            return Err(format!("missing path in '{}'", value));
+        }
+        match format {
+            "junit" => Ok(Self::Junit(PathBuf::from(path))),
+            _ => Err(format!(
+                "unknown report format '{}' (expected junit)",
+                format
+            )),
+        }
+    }
+}
+
+impl ReportTarget {
+    /// Returns the path the report is written to.
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Junit(path) => path,
+        }
+    }
+
+    /// Writes the report of `state`, replacing any earlier one.
+    pub fn write(&self, state: &MergeStateFile) -> Result<()> {
+        let content = match self {
+            Self::Junit(_) => junit_report(state),
+        };
+        let path = self.path();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create report directory: {}", parent.display())
+            })?;
+        }
+        std::fs::write(path, content)
+            .with_context(|| format!("Failed to write report: {}", path.display()))
+    }
+}
+
+/// Renders the cherry-picks of `state` as a JUnit XML report.
+pub fn junit_report(state: &MergeStateFile) -> String {
+    let suite = format!(
+        "cherry-pick {} onto {}",
+        state.merge_version, state.target_branch
+    );
+    let conflicted_files = match state.phase {
+        MergePhase::AwaitingConflictResolution => state.conflicted_files.as_deref(),
+        _ => None,
+    }
+    .unwrap_or_default();
+
+    let mut failures = 0;
+    let mut skipped = 0;
+    let mut cases = String::new();
+    for item in &state.cherry_pick_items {
+        let name = format!("PR #{}: {}", item.pr_id, item.pr_title);
+        let _ = write!(
+            cases,
+            "    <testcase classname=\"{}\" name=\"{}\"",
+            escape_xml(&suite),
+            escape_xml(&name)
+        );
+        let body = match &item.status {
+            StateItemStatus::Success => {
+                let picked = item.picked_commit_id.as_deref().unwrap_or(&item.commit_id);
+                let note = if item.had_conflict {
+                    " after resolving a conflict"
+                } else {
+                    ""
+                };
+                format!(
+                    "      <system-out>Picked {} as {}{}</system-out>\n",
+                    item.commit_id, picked, note
+                )
+            }
+            StateItemStatus::Conflict => {
+                failures += 1;
+                let files = if conflicted_files.is_empty() {
+                    String::new()
+                } else {
+                    format!("Conflicted files:\n{}", conflicted_files.join("\n"))
+                };
+                format!(
+                    "      <failure type=\"conflict\" message=\"Cherry-pick of {} conflicted\">{}</failure>\n",
+                    item.commit_id,
+                    escape_xml(&files)
+                )
+            }
+            StateItemStatus::Failed { message } => {
+                failures += 1;
+                format!(
+                    "      <failure type=\"error\" message=\"{}\"/>\n",
+                    escape_xml(message)
+                )
+            }
+            StateItemStatus::Skipped => {
+                skipped += 1;
+                "      <skipped message=\"Skipped\"/>\n".to_string()
+            }
+            StateItemStatus::Pending => {
+                skipped += 1;
+                "      <skipped message=\"Not cherry-picked yet\"/>\n".to_string()
+            }
+        };
+        let _ = write!(cases, ">\n{}    </testcase>\n", body);
+    }
+
+    let tests = state.cherry_pick_items.len();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"mergers\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">",
+        tests, failures, skipped
+    );
+    let _ = writeln!(
+        xml,
+        "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{}\" timestamp=\"{}\">",
+        escape_xml(&suite),
+        tests,
+        failures,
+        skipped,
+        state.created_at.format("%Y-%m-%dT%H:%M:%S")
+    );
+    xml.push_str(&cases);
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+/// Escapes text for use in XML content and attribute values.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::state::StateCherryPickItem;
+    use chrono::{TimeZone, Utc};
+
+    fn item(pr_id: i32, title: &str, status: StateItemStatus) -> StateCherryPickItem {
+        StateCherryPickItem {
+            commit_id: format!("c{}", pr_id),
+            pr_id,
+            pr_title: title.to_string(),
+            status,
+            work_item_ids: Vec::new(),
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
+        }
+    }
+
+    fn paused_state() -> MergeStateFile {
+        let mut state = MergeStateFile::new(
+            PathBuf::from("/repo"),
+            None,
+            false,
+            "org".to_string(),
+            "project".to_string(),
+            "repo".to_string(),
+            "dev".to_string(),
+            "release/1.2".to_string(),
+            "v1.2.0".to_string(),
+            "Done".to_string(),
+            "merged-".to_string(),
+            false,
+        );
+        state.created_at = Utc.with_ymd_and_hms(2026, 10, 1, 9, 30, 0).unwrap();
+        let mut picked = item(10, "Fix login", StateItemStatus::Success);
+        picked.picked_commit_id = Some("p10".to_string());
+        picked.had_conflict = true;
+        state.cherry_pick_items = vec![
+            picked,
+            item(11, "Skip <flaky> test", StateItemStatus::Skipped),
+            item(
+                12,
+                "Rework billing",
+                StateItemStatus::Failed {
+                    message: "Commit \"c12\" not found".to_string(),
+                },
+            ),
+            item(13, "Cache tokens & keys", StateItemStatus::Conflict),
+            item(14, "Update docs", StateItemStatus::Pending),
+        ];
+        state.current_index = 3;
+        state.phase = MergePhase::AwaitingConflictResolution;
+        state.conflicted_files = Some(vec!["src/cache.rs".to_string(), "README.md".to_string()]);
+        state
+    }
+
+    /// # JUnit Report
+    ///
+    /// Tests the JUnit XML report of a merge paused at a conflict.
+    ///
+    /// ## Test Scenario
+    /// - Renders a state with a picked, skipped, failed, conflicting and
+    ///   pending PR, with characters that need escaping
+    ///
+    /// ## Expected Outcome
+    /// - Each PR is a test case: picked passes, conflict and failure fail
+    ///   (the conflict lists its files), skipped and pending are skipped
+    /// - Counts and text are escaped XML
+    #[test]
+    fn test_junit_report() {
+        insta::assert_snapshot!(junit_report(&paused_state()));
+    }
+
+    /// # Report Targets
+    ///
+    /// Tests parsing `--report` values and writing the file.
+    ///
+    /// ## Test Scenario
+    /// - Parses valid and invalid `FORMAT:PATH` values
+    /// - Writes a JUnit report into a directory that does not exist yet
+    ///
+    /// ## Expected Outcome
+    /// - `junit:PATH` parses, unknown formats and missing paths are errors
+    /// - The report file is created with its directory
+    #[test]
+    fn test_report_targets() {
+        assert_eq!(
+            "junit:out/merge.xml".parse::<ReportTarget>(),
+            Ok(ReportTarget::Junit(PathBuf::from("out/merge.xml")))
+        );
+        assert!("junit:".parse::<ReportTarget>().is_err());
+        assert!("sarif:out.json".parse::<ReportTarget>().is_err());
+        assert!("merge.xml".parse::<ReportTarget>().is_err());
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("reports").join("merge.xml");
+        ReportTarget::Junit(path.clone())
+            .write(&paused_state())
+            .unwrap();
+        let xml = std::fs::read_to_string(path).unwrap();
+        assert!(xml.contains("tests=\"5\" failures=\"2\""));
+    }
+}
@@ -0,0 +1,163 @@
+//! Localhost status endpoint for long non-interactive runs.
+//!
+//! With `--status-port`, a merge run serves its current [`ProgressSummary`]
+//! as JSON on `http://127.0.0.1:<port>/`, so a wrapping pipeline can show
+//! live progress or enforce its own timeout without parsing the event stream.
+//!
+//! The server runs on a background thread and answers every request with the
+//! latest summary; it stops when the [`StatusServer`] is dropped.
+
+use super::{ProgressEvent, ProgressSummary};
+use anyhow::{Context, Result};
+use std::io::{Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// How long a client may take to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Serves the current [`ProgressSummary`] on a localhost port.
+pub struct StatusServer {
+    addr: SocketAddr,
+    summary: Arc<Mutex<ProgressSummary>>,
+    shutdown: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl StatusServer {
+    /// Starts serving on `127.0.0.1:port`; port `0` picks a free port.
+    pub fn start(port: u16) -> Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+            .with_context(|| format!("Failed to bind status endpoint to port {}", port))?;
+        let addr = listener.local_addr()?;
+        let summary = Arc::new(Mutex::new(ProgressSummary::default()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let thread = {
+            let summary = summary.clone();
+            let shutdown = shutdown.clone();
+            std::thread::Builder::new()
+                .name("status-endpoint".to_string())
+                .spawn(move || {
+                    for stream in listener.incoming() {
+                        if shutdown.load(Ordering::SeqCst) {
+                            break;
+                        }
+                        if let Ok(stream) = stream
+                            && let Err(e) = respond(stream, &summary)
+                        {
+                            tracing::debug!("Status endpoint request failed: {}", e);
+                        }
+                    }
+                })
+                .context("Failed to start status endpoint thread")?
+        };
+
+        Ok(Self {
+            addr,
+            summary,
+            shutdown,
+            thread: Some(thread),
+        })
+    }
+
+    /// Returns the address the endpoint listens on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Updates the served summary from a progress event.
+    pub fn record(&self, event: &ProgressEvent) {
+        self.summary
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .record(event);
+    }
+}
+
+impl Drop for StatusServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // Wake the blocking accept so the thread sees the shutdown flag.
+        let _ = TcpStream::connect(self.addr);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Answers one request with the current summary as JSON.
+fn respond(mut stream: TcpStream, summary: &Mutex<ProgressSummary>) -> Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    // The request itself does not matter; read its head so the client does
+    // not see a reset before the response.
+    let mut request = [0u8; 1024];
+    let _ = stream.read(&mut request)?;
+
+    let body = serde_json::to_string(&*summary.lock().unwrap_or_else(|e| e.into_inner()))?;
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get(addr: SocketAddr) -> ProgressSummary {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream
+            .write_all(b"GET / HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Type: application/json"));
+        let body = response.split("\r\n\r\n").nth(1).unwrap();
+        serde_json::from_str(body).unwrap()
+    }
+
+    /// # Status Endpoint
+    ///
+    /// Tests serving the summary over HTTP.
+    ///
+    /// ## Test Scenario
+    /// - Starts the endpoint on a free port and queries it before and after
+    ///   recording events
+    ///
+    /// ## Expected Outcome
+    /// - Each response is the latest summary as JSON
+    /// - Dropping the server frees the port
+    #[test]
+    fn test_status_endpoint() {
+        let server = StatusServer::start(0).unwrap();
+        let addr = server.addr();
+        assert_eq!(get(addr).total, 0);
+
+        server.record(&ProgressEvent::Start {
+            total_prs: 3,
+            version: "1.0.0".to_string(),
+            target_branch: "main".to_string(),
+            state_file_path: None,
+        });
+        server.record(&ProgressEvent::CherryPickSkipped {
+            pr_id: 1,
+            reason: None,
+        });
+        let summary = get(addr);
+        assert_eq!(summary.total, 3);
+        assert_eq!(summary.completed, 1);
+        assert_eq!(summary.pending, 2);
+
+        drop(server);
+        assert!(TcpListener::bind(addr).is_ok());
+    }
+}
@@ -0,0 +1,410 @@
+//! Dependency analysis runner for CLI usage.
+//!
+//! Builds the PR dependency graph from Azure DevOps PRs and a local
+//! repository and renders it as JSON or DOT for external planning tools,
+//! without entering a merge workflow. With `--plan`, the report also
+//! includes a [`MergePlan`] comparing cherry-pick orderings.
+
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::api::{AzureDevOpsClient, filter_prs_without_merged_tag};
+use crate::core::operations::dependency_analysis::GraphSummary;
+use crate::core::operations::ignore_list::apply_ignore_file;
+use crate::core::operations::pr_selection::{
+    parse_work_item_states, select_prs_by_work_item_states,
+};
+use crate::core::operations::{
+    DependencyAnalyzer, DependencyWarning, MergePlan, PRDependencyGraph, plan_merge,
+};
+use crate::git;
+use crate::models::{AnalyzeOutputFormat, PullRequestWithWorkItems, SinceField};
+
+/// Configuration for the analyze runner.
+pub struct AnalyzeRunnerConfig {
+    pub organization: String,
+    pub project: String,
+    pub repository: String,
+    pub pat: String,
+    pub dev_branch: String,
+    pub since: Option<String>,
+    /// Pull request date compared against `since`.
+    pub since_field: SinceField,
+    pub local_repo: Option<String>,
+    /// Branch the merge plan simulates cherry-picks onto.
+    pub target_branch: String,
+    pub select_by_state: Option<String>,
+    /// Whether to simulate cherry-pick orderings and include a merge plan.
+    pub plan: bool,
+    pub output_format: AnalyzeOutputFormat,
+    pub max_concurrent_network: usize,
+    pub max_concurrent_processing: usize,
+    /// Azure DevOps REST API version override.
+    pub api_version: Option<String>,
+}
+
+/// Dependency analysis report written by `mergers analyze`.
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyReport {
+    /// Branch the PRs were merged into.
+    pub dev_branch: String,
+    /// Date filter the PRs were fetched with, as given on the command line.
+    pub since: Option<String>,
+    /// Summary statistics for the graph.
+    pub summary: GraphSummary,
+    /// The full dependency graph, including overlapping files and line ranges.
+    pub graph: PRDependencyGraph,
+    /// Selected PRs that depend on unselected PRs.
+    pub warnings: Vec<DependencyWarning>,
+    /// Simulated cherry-pick orderings, present with `--plan`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<MergePlan>,
+}
+
+impl DependencyReport {
+    /// Renders the report in the requested format.
+    pub fn render(&self, format: AnalyzeOutputFormat) -> Result<String> {
+        match format {
+            AnalyzeOutputFormat::Json => {
+                serde_json::to_string_pretty(self).context("Failed to serialize dependency report")
+            }
+            AnalyzeOutputFormat::Dot if self.plan.is_some() => {
+                anyhow::bail!("The merge plan can only be written as JSON")
+            }
+            AnalyzeOutputFormat::Dot => Ok(self.graph.to_dot()),
+        }
+    }
+}
+
+/// Analyze runner.
+pub struct AnalyzeRunner {
+    config: AnalyzeRunnerConfig,
+}
+
+impl AnalyzeRunner {
+    pub fn new(config: AnalyzeRunnerConfig) -> Self {
+        Self { config }
+    }
+
+    /// Builds the dependency report and renders it in the configured format.
+    pub async fn run(&self) -> Result<String> {
+        let report = self.build_report().await?;
+        report.render(self.config.output_format)
+    }
+
+    /// Fetches PRs and analyzes their dependencies.
+    pub async fn build_report(&self) -> Result<DependencyReport> {
+        if self.config.plan && self.config.output_format == AnalyzeOutputFormat::Dot {
+            anyhow::bail!("--plan writes JSON; DOT output is only available without --plan");
+        }
+        let local_repo = self.config.local_repo.as_deref().context(
+            "Dependency analysis requires a local repository (pass a path or --local-repo)",
+        )?;
+        let repo_path = Path::new(local_repo);
+        if !repo_path.exists() {
+            anyhow::bail!("Local repository not found: {}", local_repo);
+        }
+
+        let client = AzureDevOpsClient::new_with_api_version(
+            self.config.organization.clone(),
+            self.config.project.clone(),
+            self.config.repository.clone(),
+            self.config.pat.clone().into(),
+            self.config.api_version.clone(),
+        )?
+        .with_since_field(self.config.since_field)
+        .with_max_concurrent_requests(self.config.max_concurrent_network);
+
+        tracing::info!("Fetching pull requests from Azure DevOps...");
+        let prs = client
+            .fetch_pull_requests(&self.config.dev_branch, self.config.since.as_deref())
+            .await?;
+        let prs = filter_prs_without_merged_tag(prs);
+        let (prs, ignored) = apply_ignore_file(repo_path, prs)?;
+        for ignored_pr in &ignored {
+            tracing::info!(
+                "Skipping PR #{} ({}): {}",
+                ignored_pr.pr.id,
+                ignored_pr.pr.title,
+                ignored_pr.reason
+            );
+        }
+
+        if prs.is_empty() {
+            anyhow::bail!(
+                "No pull requests found on '{}' matching the specified criteria",
+                self.config.dev_branch
+            );
+        }
+
+        let mut prs_with_wi = match self.config.select_by_state {
+            Some(ref states) => {
+                let mut prs_with_wi = client
+                    .fetch_work_items_for_prs_parallel(
+                        &prs,
+                        self.config.max_concurrent_network,
+                        self.config.max_concurrent_processing,
+                    )
+                    .await;
+                let selected = select_prs_by_work_item_states(
+                    &mut prs_with_wi,
+                    &parse_work_item_states(states),
+                );
+                tracing::info!("Selected {} PR(s) by work item state", selected);
+                prs_with_wi
+            }
+            None => prs
+                .into_iter()
+                .map(|pr| PullRequestWithWorkItems {
+                    pr,
+                    work_items: Vec::new(),
+                    selected: false,
+                })
+                .collect(),
+        };
+
+        for pr_with_wi in prs_with_wi
+            .iter_mut()
+            .filter(|p| p.pr.last_merge_commit.is_none())
+        {
+            match client.fetch_pr_commit(pr_with_wi.pr.id).await {
+                Ok(commit) => pr_with_wi.pr.last_merge_commit = Some(commit),
+                Err(e) => tracing::warn!(
+                    "No merge commit for PR #{}; analyzing without its changes: {}",
+                    pr_with_wi.pr.id,
+                    e
+                ),
+            }
+        }
+
+        tracing::info!("Analyzing dependencies of {} PR(s)...", prs_with_wi.len());
+        let result = DependencyAnalyzer::new()
+            .analyze_repository(
+                repo_path,
+                &prs_with_wi,
+                |analyzed, total, _| {
+                    if analyzed % 25 == 0 || analyzed == total {
+                        tracing::info!("Analyzed {}/{} PRs", analyzed, total);
+                    }
+                },
+                &AtomicBool::new(false),
+            )
+            .context("Dependency analysis was cancelled")?;
+
+        let plan = if self.config.plan {
+            Some(self.build_plan(repo_path, &prs_with_wi, &result.graph)?)
+        } else {
+            None
+        };
+
+        Ok(DependencyReport {
+            dev_branch: self.config.dev_branch.clone(),
+            since: self.config.since.clone(),
+            summary: result.graph.summary(),
+            graph: result.graph,
+            warnings: result.warnings,
+            plan,
+        })
+    }
+
+    /// Simulates cherry-pick orderings of the candidate PRs onto the target
+    /// branch.
+    ///
+    /// Candidates are the PRs selected by `--select-by-state`, or every
+    /// analyzed PR when no states were given.
+    fn build_plan(
+        &self,
+        repo_path: &Path,
+        prs: &[PullRequestWithWorkItems],
+        graph: &PRDependencyGraph,
+    ) -> Result<MergePlan> {
+        let candidates: Vec<PullRequestWithWorkItems> = if self.config.select_by_state.is_some() {
+            prs.iter().filter(|pr| pr.selected).cloned().collect()
+        } else {
+            prs.to_vec()
+        };
+
+        // Prefer the remote-tracking branch, which is what a merge branches from
+        let remote_target = format!("origin/{}", self.config.target_branch);
+        let target = if git::tree_id(repo_path, &remote_target).is_ok() {
+            remote_target
+        } else {
+            self.config.target_branch.clone()
+        };
+
+        tracing::info!(
+            "Simulating cherry-pick orderings of {} PR(s) onto {}...",
+            candidates.len(),
+            target
+        );
+        plan_merge(repo_path, &target, &candidates, graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::operations::{
+        DependencyCategory, OrderingSimulation, PRDependency, PRDependencyNode, PlanOrdering,
+        PredictedConflict,
+    };
+
+    fn sample_report() -> DependencyReport {
+        let mut graph = PRDependencyGraph::new();
+        let mut dependent = PRDependencyNode::new(2, "Second".to_string(), true);
+        dependent.dependencies.push(PRDependency {
+            from_pr_id: 2,
+            to_pr_id: 1,
+            category: DependencyCategory::PartiallyDependent {
+                shared_files: vec!["src/lib.rs".to_string()],
+            },
+        });
+        graph.add_node(PRDependencyNode::new(1, "First".to_string(), false));
+        graph.add_node(dependent);
+        graph.compute_topological_order();
+
+        DependencyReport {
+            dev_branch: "dev".to_string(),
+            since: Some("2w".to_string()),
+            summary: graph.summary(),
+            graph,
+            warnings: vec![DependencyWarning::UnselectedDependency {
+                selected_pr_id: 2,
+                selected_pr_title: "Second".to_string(),
+                unselected_pr_id: 1,
+                unselected_pr_title: "First".to_string(),
+                category: DependencyCategory::PartiallyDependent {
+                    shared_files: vec!["src/lib.rs".to_string()],
+                },
+            }],
+            plan: None,
+        }
+    }
+
+    /// # Render Report As JSON
+    ///
+    /// Tests the JSON rendering of a dependency report.
+    ///
+    /// ## Test Scenario
+    /// - Renders a report with one partial dependency and one warning
+    ///
+    /// ## Expected Outcome
+    /// - The JSON contains the summary, graph nodes, edges and warnings
+    #[test]
+    fn test_render_json() {
+        let json = sample_report().render(AnalyzeOutputFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["dev_branch"], "dev");
+        assert_eq!(value["summary"]["total_prs"], 2);
+        assert_eq!(value["summary"]["partial_relationships"], 1);
+        assert_eq!(
+            value["graph"]["nodes"]["2"]["dependencies"][0]["category"]["type"],
+            "partially_dependent"
+        );
+        assert_eq!(value["graph"]["topological_order"][0], 1);
+        assert_eq!(value["warnings"][0]["type"], "unselected_dependency");
+        assert!(value.get("plan").is_none());
+    }
+
+    /// # Render Report With Merge Plan
+    ///
+    /// Tests rendering a report that includes a merge plan.
+    ///
+    /// ## Test Scenario
+    /// - Adds a plan recommending the topological ordering to the sample report
+    /// - Renders it as JSON and as DOT
+    ///
+    /// ## Expected Outcome
+    /// - The JSON includes the plan with its simulations and recommendation
+    /// - DOT rendering is rejected
+    #[test]
+    fn test_render_plan() {
+        let mut report = sample_report();
+        report.plan = Some(MergePlan {
+            target: "origin/main".to_string(),
+            simulations: vec![
+                OrderingSimulation {
+                    ordering: PlanOrdering::ClosingDate,
+                    pr_ids: vec![2, 1],
+                    conflicts: vec![PredictedConflict {
+                        pr_id: 2,
+                        files: vec!["src/lib.rs".to_string()],
+                    }],
+                },
+                OrderingSimulation {
+                    ordering: PlanOrdering::Topological,
+                    pr_ids: vec![1, 2],
+                    conflicts: Vec::new(),
+                },
+            ],
+            recommended: PlanOrdering::Topological,
+            skipped_pr_ids: Vec::new(),
+        });
+
+        let json = report.render(AnalyzeOutputFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["plan"]["recommended"], "topological");
+        assert_eq!(value["plan"]["simulations"][0]["ordering"], "closing_date");
+        assert_eq!(
+            value["plan"]["simulations"][0]["conflicts"][0]["files"][0],
+            "src/lib.rs"
+        );
+
+        assert!(report.render(AnalyzeOutputFormat::Dot).is_err());
+    }
+
+    /// # Render Report As DOT
+    ///
+    /// Tests the DOT rendering of a dependency report.
+    ///
+    /// ## Test Scenario
+    /// - Renders the sample report as DOT
+    ///
+    /// ## Expected Outcome
+    /// - The output is the graph's DOT rendering
+    #[test]
+    fn test_render_dot() {
+        let report = sample_report();
+        let dot = report.render(AnalyzeOutputFormat::Dot).unwrap();
+
+        assert_eq!(dot, report.graph.to_dot());
+        assert!(dot.contains("pr2 -> pr1 [style=dashed"));
+    }
+
+    /// # Analyze Requires Local Repository
+    ///
+    /// Tests that the runner fails early without a local repository.
+    ///
+    /// ## Test Scenario
+    /// - Builds a report with no local repository configured
+    ///
+    /// ## Expected Outcome
+    /// - Returns an error mentioning the local repository
+    #[tokio::test]
+    async fn test_build_report_requires_local_repo() {
+        let runner = AnalyzeRunner::new(AnalyzeRunnerConfig {
+            organization: "org".to_string(),
+            project: "project".to_string(),
+            repository: "repo".to_string(),
+            pat: "pat".to_string(),
+            dev_branch: "dev".to_string(),
+            since: None,
+            since_field: Default::default(),
+            local_repo: None,
+            target_branch: "main".to_string(),
+            select_by_state: None,
+            plan: false,
+            output_format: AnalyzeOutputFormat::Json,
+            max_concurrent_network: 4,
+            max_concurrent_processing: 4,
+            api_version: None,
+        });
+
+        let err = runner.build_report().await.unwrap_err();
+        assert!(err.to_string().contains("local repository"));
+    }
+}
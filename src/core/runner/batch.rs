@@ -0,0 +1,624 @@
+//! Batch runner for CLI usage.
+//!
+//! Runs the non-interactive merge for each repository listed in the
+//! `[[repos]]` config section, one at a time or with a parallelism limit,
+//! and combines the per-repository results into one report and exit code.
+
+use std::collections::HashSet;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Result, bail};
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+
+use super::fan_out::FanOutRunner;
+use super::non_interactive::NonInteractiveRunner;
+use super::traits::{MergeRunnerConfig, RunResult};
+use crate::core::ExitCode;
+use crate::models::OutputFormat;
+
+/// A repository entry of the `[[repos]]` config section.
+///
+/// Unset organization, project and branches fall back to the regular
+/// configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchRepoConfig {
+    /// Name used in the report and with `--repos` [default: repository].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    pub repository: String,
+    /// Local clone to create the worktree in (`None` clones the repository).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local_repo: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dev_branch: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_branch: Option<String>,
+}
+
+impl BatchRepoConfig {
+    /// Returns the name of the entry.
+    pub fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.repository)
+    }
+}
+
+/// Validates the configured repositories and keeps the ones named in `only`
+/// (all of them when `only` is empty), in config order.
+pub fn select_batch_repos(
+    repos: &[BatchRepoConfig],
+    only: &[String],
+) -> Result<Vec<BatchRepoConfig>> {
+    if repos.is_empty() {
+        bail!("no repositories configured; add [[repos]] entries to the config file");
+    }
+
+    let mut names = HashSet::new();
+    for repo in repos {
+        if repo.repository.trim().is_empty() {
+            bail!("[[repos]] entry '{}' has an empty repository", repo.name());
+        }
+        if !names.insert(repo.name()) {
+            bail!(
+                "[[repos]] name '{}' is used more than once; set a unique name",
+                repo.name()
+            );
+        }
+    }
+
+    if let Some(unknown) = only.iter().find(|name| !names.contains(name.as_str())) {
+        let mut known = names.into_iter().collect::<Vec<_>>();
+        known.sort_unstable();
+        bail!(
+            "unknown repository '{}' (configured: {})",
+            unknown,
+            known.join(", ")
+        );
+    }
+
+    Ok(repos
+        .iter()
+        .filter(|repo| only.is_empty() || only.iter().any(|name| name == repo.name()))
+        .cloned()
+        .collect())
+}
+
+/// A repository to merge in a batch.
+pub struct BatchRepo {
+    /// Name of the `[[repos]]` entry.
+    pub name: String,
+    /// Azure DevOps repository.
+    pub repository: String,
+    /// Runner configuration, or why it could not be resolved.
+    pub config: Result<MergeRunnerConfig, String>,
+}
+
+/// Configuration for the batch runner.
+pub struct BatchRunnerConfig {
+    /// Repositories in run order.
+    pub repos: Vec<BatchRepo>,
+    /// Maximum number of repositories merged at the same time.
+    pub parallel: usize,
+    /// Output format (text, json, ndjson).
+    pub output_format: OutputFormat,
+    /// Whether to suppress progress output.
+    pub quiet: bool,
+}
+
+/// Outcome of the merge of one repository.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchRepoResult {
+    pub name: String,
+    pub repository: String,
+    /// Exit code of the repository's merge.
+    #[serde(serialize_with = "serialize_exit_code")]
+    pub exit_code: ExitCode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_file_path: Option<PathBuf>,
+    /// JSON output of the merge (`json` output format only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<serde_json::Value>,
+}
+
+/// Combined report of a batch run.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchReport {
+    /// Per-repository results, in run order.
+    pub repos: Vec<BatchRepoResult>,
+    /// Aggregated exit code.
+    #[serde(serialize_with = "serialize_exit_code")]
+    pub exit_code: ExitCode,
+}
+
+impl BatchReport {
+    /// Returns the number of repositories merged successfully.
+    pub fn succeeded(&self) -> usize {
+        self.repos
+            .iter()
+            .filter(|repo| repo.exit_code == ExitCode::Success)
+            .count()
+    }
+}
+
+/// Combines the exit codes of the merged repositories.
+///
+/// Returns the common code when all repositories ended the same way,
+/// `PartialSuccess` when only some succeeded and `GeneralError` when none did.
+pub fn aggregate_exit_code(codes: &[ExitCode]) -> ExitCode {
+    match codes.first() {
+        None => ExitCode::Success,
+        Some(first) if codes.iter().all(|code| code == first) => *first,
+        Some(_)
+            if codes
+                .iter()
+                .any(|code| matches!(code, ExitCode::Success | ExitCode::PartialSuccess)) =>
+        {
+            ExitCode::PartialSuccess
+        }
+        Some(_) => ExitCode::GeneralError,
+    }
+}
+
+/// Batch runner.
+pub struct BatchRunner<W: Write = io::Stdout> {
+    config: BatchRunnerConfig,
+    writer: W,
+}
+
+impl BatchRunner<io::Stdout> {
+    /// Creates a new batch runner with stdout output.
+    pub fn new(config: BatchRunnerConfig) -> Self {
+        Self::with_writer(config, io::stdout())
+    }
+}
+
+impl<W: Write> BatchRunner<W> {
+    /// Creates a new batch runner with a custom writer.
+    pub fn with_writer(config: BatchRunnerConfig, writer: W) -> Self {
+        Self { config, writer }
+    }
+
+    /// Merges every repository and writes the combined report.
+    ///
+    /// The output of each merge is written as soon as it finishes, so with
+    /// `parallel` above 1 repositories are reported in completion order.
+    pub async fn run(&mut self) -> Result<BatchReport> {
+        let repos = std::mem::take(&mut self.config.repos);
+        let order = repos
+            .iter()
+            .map(|repo| repo.name.clone())
+            .collect::<Vec<_>>();
+
+        let mut runs = stream::iter(repos)
+            .map(|repo| tokio::spawn(run_repo(repo)))
+            .buffer_unordered(self.config.parallel.max(1));
+
+        let mut finished = Vec::with_capacity(order.len());
+        while let Some(joined) = runs.next().await {
+            let (name, repository, result, output) = joined?;
+            let parsed = self.write_repo_output(&name, &repository, &result, &output)?;
+            finished.push(BatchRepoResult {
+                name,
+                repository,
+                exit_code: result.exit_code,
+                message: result.message,
+                state_file_path: result.state_file_path,
+                output: parsed,
+            });
+        }
+        finished.sort_by_key(|repo| order.iter().position(|name| *name == repo.name));
+
+        let codes = finished
+            .iter()
+            .map(|repo| repo.exit_code)
+            .collect::<Vec<_>>();
+        let report = BatchReport {
+            repos: finished,
+            exit_code: aggregate_exit_code(&codes),
+        };
+        self.write_report(&report)?;
+        Ok(report)
+    }
+
+    /// Writes the output of one merge, tagging it with the repository name.
+    ///
+    /// Returns the parsed output for the `json` format, which is only
+    /// written as part of the final report.
+    fn write_repo_output(
+        &mut self,
+        name: &str,
+        repository: &str,
+        result: &RunResult,
+        output: &[u8],
+    ) -> io::Result<Option<serde_json::Value>> {
+        write_captured_output(
+            &mut self.writer,
+            self.config.output_format,
+            self.config.quiet,
+            &format!("{} ({})", name, repository),
+            ("repo", name),
+            result,
+            output,
+        )
+    }
+
+    /// Writes the combined report.
+    fn write_report(&mut self, report: &BatchReport) -> io::Result<()> {
+        match self.config.output_format {
+            OutputFormat::Text => {
+                writeln!(
+                    self.writer,
+                    "═══════════════════════════════════════════════════════════"
+                )?;
+                writeln!(
+                    self.writer,
+                    "Batch: {} of {} repositories merged",
+                    report.succeeded(),
+                    report.repos.len()
+                )?;
+                writeln!(
+                    self.writer,
+                    "═══════════════════════════════════════════════════════════"
+                )?;
+                let rows = report
+                    .repos
+                    .iter()
+                    .map(|repo| {
+                        (
+                            repo.name.as_str(),
+                            repo.exit_code,
+                            repo.state_file_path.as_deref(),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                write_result_rows(&mut self.writer, &rows)?;
+            }
+            OutputFormat::Json => {
+                let json = serde_json::to_string_pretty(report).map_err(io::Error::other)?;
+                writeln!(self.writer, "{}", json)?;
+            }
+            OutputFormat::Ndjson => {
+                let mut event = serde_json::to_value(report).map_err(io::Error::other)?;
+                if let serde_json::Value::Object(object) = &mut event {
+                    object.insert("event".to_string(), "batch_complete".into());
+                }
+                writeln!(self.writer, "{}", event)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes the captured output of one merge, introduced by `heading`.
+///
+/// NDJSON events are tagged with the `tag` key and value. The `json` output
+/// is returned parsed instead, to be written as part of the final report.
+pub(super) fn write_captured_output<W: Write>(
+    writer: &mut W,
+    format: OutputFormat,
+    quiet: bool,
+    heading: &str,
+    tag: (&str, &str),
+    result: &RunResult,
+    output: &[u8],
+) -> io::Result<Option<serde_json::Value>> {
+    let output = String::from_utf8_lossy(output);
+    match format {
+        OutputFormat::Text => {
+            if !quiet {
+                writeln!(writer, "==> {}", heading)?;
+            }
+            write!(writer, "{}", output)?;
+            if let Some(message) = &result.message {
+                writeln!(writer, "{}", message)?;
+            }
+            if !quiet {
+                writeln!(writer)?;
+            }
+            Ok(None)
+        }
+        OutputFormat::Json => Ok(serde_json::from_str(&output).ok()),
+        OutputFormat::Ndjson => {
+            let (key, value) = tag;
+            for line in output.lines().filter(|line| !line.trim().is_empty()) {
+                let line = match serde_json::from_str::<serde_json::Value>(line) {
+                    Ok(serde_json::Value::Object(mut object)) => {
+                        object.insert(key.to_string(), value.into());
+                        serde_json::to_string(&object).map_err(io::Error::other)?
+                    }
+                    _ => line.to_string(),
+                };
+                writeln!(writer, "{}", line)?;
+            }
+            Ok(None)
+        }
+    }
+}
+
+/// Writes one line per merge with its exit code, followed by the state
+/// file of merges that did not succeed.
+pub(super) fn write_result_rows<W: Write>(
+    writer: &mut W,
+    rows: &[(&str, ExitCode, Option<&Path>)],
+) -> io::Result<()> {
+    let width = rows
+        .iter()
+        .map(|(name, _, _)| name.chars().count())
+        .max()
+        .unwrap_or(0);
+    for (name, code, state_file_path) in rows {
+        let marker = if *code == ExitCode::Success {
+            "✓"
+        } else {
+            "✗"
+        };
+        writeln!(
+            writer,
+            "  {} {:<width$}  {} ({})",
+            marker,
+            name,
+            code,
+            code.code()
+        )?;
+        if let Some(path) = state_file_path
+            && *code != ExitCode::Success
+        {
+            writeln!(writer, "    {:<width$}State file: {}", "", path.display())?;
+        }
+    }
+    Ok(())
+}
+
+/// Merges one repository, capturing its output.
+///
+/// A repository with several target branches fans out to each of them.
+async fn run_repo(repo: BatchRepo) -> (String, String, RunResult, Vec<u8>) {
+    let mut output = Vec::new();
+    let result = match repo.config {
+        Ok(config) if !config.fan_out.is_empty() => {
+            FanOutRunner::with_writer(config, &mut output)
+                .run_to_result()
+                .await
+        }
+        Ok(config) => {
+            let mut runner = NonInteractiveRunner::with_writer(config, &mut output);
+            runner.run().await
+        }
+        Err(message) => RunResult::error(
+            ExitCode::ConfigError,
+            format!("Configuration error: {}", message),
+        ),
+    };
+    (repo.name, repo.repository, result, output)
+}
+
+/// Serializes an exit code as its numeric value.
+pub(super) fn serialize_exit_code<S: serde::Serializer>(
+    code: &ExitCode,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(code.code())
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+    use crate::utils::SystemClock;
+    use std::sync::Arc;
+
+    fn repo(name: Option<&str>, repository: &str) -> BatchRepoConfig {
+        BatchRepoConfig {
+            name: name.map(String::from),
+            organization: None,
+            project: None,
+            repository: repository.to_string(),
+            local_repo: None,
+            dev_branch: None,
+            target_branch: None,
+        }
+    }
+
+    pub(crate) fn runner_config(repository: &str) -> MergeRunnerConfig {
+        MergeRunnerConfig {
+            organization: "test-org".to_string(),
+            project: "test-project".to_string(),
+            repository: repository.to_string(),
+            pat: "test-pat".to_string(),
+            write_pat: None,
+            dev_branch: "dev".to_string(),
+            target_branch: "main".to_string(),
+            // An empty version fails the merge before any network access
+            version: String::new(),
+            tag_prefix: "merged-".to_string(),
+            work_item_state: "Done".to_string(),
+            select_by_states: None,
+            select_file: None,
+            strict_states: None,
+            release_gate: None,
+            build_checks: Default::default(),
+            release_tag: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            url_templates: Default::default(),
+            release_notes_locale: Default::default(),
+            local_repo: None,
+            run_hooks: false,
+            output_format: OutputFormat::Text,
+            quiet: false,
+            hooks_config: None,
+            max_concurrent_network: 100,
+            max_concurrent_processing: 10,
+            since: None,
+            since_field: Default::default(),
+            api_version: None,
+            release_candidate: false,
+            status_port: None,
+            serve: None,
+            selection_hard_cap: None,
+            force: false,
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            notifications: None,
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            conflict_timeout: None,
+            dry_run: false,
+            reports: Vec::new(),
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    fn batch_config(output_format: OutputFormat) -> BatchRunnerConfig {
+        BatchRunnerConfig {
+            repos: vec![
+                BatchRepo {
+                    name: "api".to_string(),
+                    repository: "api-backend".to_string(),
+                    config: Err("pat is required".to_string()),
+                },
+                BatchRepo {
+                    name: "web".to_string(),
+                    repository: "web-frontend".to_string(),
+                    config: Ok(runner_config("web-frontend")),
+                },
+            ],
+            parallel: 2,
+            output_format,
+            quiet: false,
+        }
+    }
+
+    /// # Repository Selection Errors
+    ///
+    /// Tests the validation of the configured batch repositories.
+    ///
+    /// ## Test Scenario
+    /// - Selects from entries sharing a name, with an empty repository, and
+    ///   with a name filter naming an unknown entry
+    ///
+    /// ## Expected Outcome
+    /// - Each is an error naming the offending entry
+    #[test]
+    fn test_select_batch_repos_errors() {
+        let error = select_batch_repos(&[repo(None, "api"), repo(Some("api"), "web")], &[])
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("'api' is used more than once"), "{error}");
+
+        let error = select_batch_repos(&[repo(Some("api"), " ")], &[])
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("'api' has an empty repository"), "{error}");
+
+        let error = select_batch_repos(&[repo(None, "api")], &["web".to_string()])
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("unknown repository 'web'"), "{error}");
+        assert!(error.contains("configured: api"), "{error}");
+    }
+
+    /// # Exit Code Aggregation
+    ///
+    /// Tests combining the exit codes of the merged repositories.
+    ///
+    /// ## Test Scenario
+    /// - Aggregates equal codes, mixed codes with and without a success
+    ///
+    /// ## Expected Outcome
+    /// - Equal codes are kept, mixed codes with a success are a partial
+    ///   success and mixed failures a general error
+    #[test]
+    fn test_aggregate_exit_code() {
+        assert_eq!(aggregate_exit_code(&[]), ExitCode::Success);
+        assert_eq!(
+            aggregate_exit_code(&[ExitCode::Success, ExitCode::Success]),
+            ExitCode::Success
+        );
+        assert_eq!(
+            aggregate_exit_code(&[ExitCode::Conflict, ExitCode::Conflict]),
+            ExitCode::Conflict
+        );
+        assert_eq!(
+            aggregate_exit_code(&[ExitCode::Success, ExitCode::Conflict]),
+            ExitCode::PartialSuccess
+        );
+        assert_eq!(
+            aggregate_exit_code(&[ExitCode::AuthFailed, ExitCode::GitError]),
+            ExitCode::GeneralError
+        );
+    }
+
+    /// # JSON Batch Report
+    ///
+    /// Tests the combined report of a batch in the JSON format.
+    ///
+    /// ## Test Scenario
+    /// - Runs a batch of a repository without a configuration and one whose
+    ///   merge fails, two at a time
+    ///
+    /// ## Expected Outcome
+    /// - The report lists both repositories in config order with their exit
+    ///   codes and messages
+    /// - The aggregated exit code is a general error
+    #[tokio::test]
+    async fn test_batch_json_report() {
+        let mut buffer = Vec::new();
+        let report = BatchRunner::with_writer(batch_config(OutputFormat::Json), &mut buffer)
+            .run()
+            .await
+            .unwrap();
+        assert_eq!(report.exit_code, ExitCode::GeneralError);
+        assert_eq!(report.succeeded(), 0);
+
+        let json: serde_json::Value = serde_json::from_slice(&buffer).unwrap();
+        assert_eq!(json["exit_code"], 1);
+        assert_eq!(json["repos"][0]["name"], "api");
+        assert_eq!(json["repos"][0]["exit_code"], 11);
+        assert_eq!(
+            json["repos"][0]["message"],
+            "Configuration error: pat is required"
+        );
+        assert_eq!(json["repos"][1]["name"], "web");
+        assert_eq!(json["repos"][1]["repository"], "web-frontend");
+        assert_eq!(json["repos"][1]["exit_code"], 1);
+    }
+
+    /// # Text Batch Report
+    ///
+    /// Tests the combined report of a batch in the text format.
+    ///
+    /// ## Test Scenario
+    /// - Runs the same batch one repository at a time with text output
+    ///
+    /// ## Expected Outcome
+    /// - Each repository's output is introduced by its name
+    /// - The summary counts the merged repositories and lists each result
+    #[tokio::test]
+    async fn test_batch_text_report() {
+        let mut config = batch_config(OutputFormat::Text);
+        config.parallel = 1;
+        let mut buffer = Vec::new();
+        BatchRunner::with_writer(config, &mut buffer)
+            .run()
+            .await
+            .unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        let api = output.find("==> api (api-backend)").unwrap();
+        let web = output.find("==> web (web-frontend)").unwrap();
+        assert!(api < web);
+        assert!(output.contains("Batch: 0 of 2 repositories merged"));
+        assert!(output.contains("✗ api  Configuration is missing or invalid (11)"));
+        assert!(output.contains("✗ web  General error occurred (1)"));
+    }
+}
@@ -0,0 +1,286 @@
+//! Fan-out runner for CLI usage.
+//!
+//! Runs the non-interactive merge once per target branch of a fan-out, one
+//! target after the other, and combines the results into one report. The
+//! PRs picked into the first target are picked into every later target, so
+//! all release branches receive the same fixes.
+
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use super::batch::{
+    aggregate_exit_code, serialize_exit_code, write_captured_output, write_result_rows,
+};
+use super::non_interactive::NonInteractiveRunner;
+use super::traits::{MergeRunnerConfig, RunResult};
+use crate::core::ExitCode;
+use crate::core::state::MergeStateFile;
+use crate::models::OutputFormat;
+
+/// Outcome of the merge into one target branch.
+#[derive(Debug, Clone, Serialize)]
+pub struct FanOutTargetReport {
+    pub target_branch: String,
+    /// Exit code of the target's merge.
+    #[serde(serialize_with = "serialize_exit_code")]
+    pub exit_code: ExitCode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_file_path: Option<PathBuf>,
+    /// JSON output of the merge (`json` output format only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<serde_json::Value>,
+}
+
+/// Combined report of a fan-out.
+#[derive(Debug, Clone, Serialize)]
+pub struct FanOutReport {
+    /// Per-target results, in run order.
+    pub targets: Vec<FanOutTargetReport>,
+    /// Aggregated exit code.
+    #[serde(serialize_with = "serialize_exit_code")]
+    pub exit_code: ExitCode,
+}
+
+impl FanOutReport {
+    /// Returns the number of target branches merged successfully.
+    pub fn succeeded(&self) -> usize {
+        self.targets
+            .iter()
+            .filter(|target| target.exit_code == ExitCode::Success)
+            .count()
+    }
+}
+
+/// Fan-out runner.
+pub struct FanOutRunner<W: Write = io::Stdout> {
+    config: MergeRunnerConfig,
+    writer: W,
+}
+
+impl FanOutRunner<io::Stdout> {
+    /// Creates a new fan-out runner with stdout output.
+    pub fn new(config: MergeRunnerConfig) -> Self {
+        Self::with_writer(config, io::stdout())
+    }
+}
+
+impl<W: Write> FanOutRunner<W> {
+    /// Creates a new fan-out runner with a custom writer.
+    pub fn with_writer(config: MergeRunnerConfig, writer: W) -> Self {
+        Self { config, writer }
+    }
+
+    /// Merges into every target branch and writes the combined report.
+    ///
+    /// The first target selects the PRs as configured; later targets pick
+    /// the PRs recorded in its state file. Without a state file (e.g. when
+    /// the first merge failed early or was a dry run) each target selects
+    /// on its own.
+    pub async fn run(&mut self) -> Result<FanOutReport> {
+        let targets = self.config.fan_out.clone();
+        let mut selected_prs: Option<Vec<i32>> = None;
+
+        let mut finished = Vec::with_capacity(targets.len());
+        for target_branch in targets {
+            let config = MergeRunnerConfig {
+                target_branch: target_branch.clone(),
+                ..self.config.clone()
+            };
+            let mut output = Vec::new();
+            let result = {
+                let mut runner = NonInteractiveRunner::with_writer(config, &mut output);
+                if let Some(pr_ids) = &selected_prs {
+                    runner = runner.with_selected_prs(pr_ids.clone());
+                }
+                runner.run().await
+            };
+            if finished.is_empty() {
+                selected_prs = picked_prs(&result);
+            }
+
+            let parsed = write_captured_output(
+                &mut self.writer,
+                self.config.output_format,
+                self.config.quiet,
+                &target_branch,
+                ("target_branch", &target_branch),
+                &result,
+                &output,
+            )?;
+            finished.push(FanOutTargetReport {
+                target_branch,
+                exit_code: result.exit_code,
+                message: result.message,
+                state_file_path: result.state_file_path,
+                output: parsed,
+            });
+        }
+
+        let codes = finished
+            .iter()
+            .map(|target| target.exit_code)
+            .collect::<Vec<_>>();
+        let report = FanOutReport {
+            targets: finished,
+            exit_code: aggregate_exit_code(&codes),
+        };
+        self.write_report(&report)?;
+        Ok(report)
+    }
+
+    /// Runs the fan-out and reduces its report to the result of one run.
+    pub async fn run_to_result(&mut self) -> RunResult {
+        match self.run().await {
+            Ok(report) => RunResult {
+                exit_code: report.exit_code,
+                message: None,
+                state_file_path: None,
+            },
+            Err(e) => RunResult::error(ExitCode::GeneralError, format!("{:#}", e)),
+        }
+    }
+
+    /// Writes the combined report.
+    fn write_report(&mut self, report: &FanOutReport) -> io::Result<()> {
+        match self.config.output_format {
+            OutputFormat::Text => {
+                writeln!(
+                    self.writer,
+                    "═══════════════════════════════════════════════════════════"
+                )?;
+                writeln!(
+                    self.writer,
+                    "Fan-out: {} of {} target branches merged",
+                    report.succeeded(),
+                    report.targets.len()
+                )?;
+                writeln!(
+                    self.writer,
+                    "═══════════════════════════════════════════════════════════"
+                )?;
+                let rows = report
+                    .targets
+                    .iter()
+                    .map(|target| {
+                        (
+                            target.target_branch.as_str(),
+                            target.exit_code,
+                            target.state_file_path.as_deref(),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                write_result_rows(&mut self.writer, &rows)?;
+            }
+            OutputFormat::Json => {
+                let json = serde_json::to_string_pretty(report).map_err(io::Error::other)?;
+                writeln!(self.writer, "{}", json)?;
+            }
+            OutputFormat::Ndjson => {
+                let mut event = serde_json::to_value(report).map_err(io::Error::other)?;
+                if let serde_json::Value::Object(object) = &mut event {
+                    object.insert("event".to_string(), "fan_out_complete".into());
+                }
+                writeln!(self.writer, "{}", event)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the PRs recorded in the state file of a finished merge.
+fn picked_prs(result: &RunResult) -> Option<Vec<i32>> {
+    let path = result.state_file_path.as_ref()?;
+    match MergeStateFile::load(path) {
+        Ok(state) => Some(
+            state
+                .cherry_pick_items
+                .iter()
+                .map(|item| item.pr_id)
+                .collect(),
+        ),
+        Err(e) => {
+            tracing::warn!(
+                "Failed to read the PRs picked into the first target from {}: {:#}",
+                path.display(),
+                e
+            );
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::runner::batch::tests::runner_config;
+
+    fn fan_out_config(output_format: OutputFormat) -> MergeRunnerConfig {
+        MergeRunnerConfig {
+            target_branch: "release/6.6".to_string(),
+            fan_out: vec!["release/6.6".to_string(), "release/6.7".to_string()],
+            output_format,
+            ..runner_config("api-backend")
+        }
+    }
+
+    /// # JSON Fan-out Report
+    ///
+    /// Tests the combined report of a fan-out in the JSON format.
+    ///
+    /// ## Test Scenario
+    /// - Fans out to two release branches with a merge that fails before
+    ///   any network access
+    ///
+    /// ## Expected Outcome
+    /// - The report lists both targets in order with their exit codes
+    /// - The aggregated exit code is a general error
+    #[tokio::test]
+    async fn test_fan_out_json_report() {
+        let mut buffer = Vec::new();
+        let report = FanOutRunner::with_writer(fan_out_config(OutputFormat::Json), &mut buffer)
+            .run()
+            .await
+            .unwrap();
+        assert_eq!(report.exit_code, ExitCode::GeneralError);
+        assert_eq!(report.succeeded(), 0);
+
+        let json: serde_json::Value = serde_json::from_slice(&buffer).unwrap();
+        assert_eq!(json["exit_code"], 1);
+        assert_eq!(json["targets"][0]["target_branch"], "release/6.6");
+        assert_eq!(json["targets"][0]["exit_code"], 1);
+        assert_eq!(json["targets"][1]["target_branch"], "release/6.7");
+        assert_eq!(json["targets"][1]["exit_code"], 1);
+    }
+
+    /// # Text Fan-out Report
+    ///
+    /// Tests the combined report of a fan-out in the text format.
+    ///
+    /// ## Test Scenario
+    /// - Runs the same fan-out with text output
+    ///
+    /// ## Expected Outcome
+    /// - Each target's output is introduced by its branch, in order
+    /// - The summary counts the merged targets and lists each result
+    #[tokio::test]
+    async fn test_fan_out_text_report() {
+        let mut buffer = Vec::new();
+        let result = FanOutRunner::with_writer(fan_out_config(OutputFormat::Text), &mut buffer)
+            .run_to_result()
+            .await;
+        assert_eq!(result.exit_code, ExitCode::GeneralError);
+
+        let output = String::from_utf8(buffer).unwrap();
+        let first = output.find("==> release/6.6").unwrap();
+        let second = output.find("==> release/6.7").unwrap();
+        assert!(first < second);
+        assert!(output.contains("Fan-out: 0 of 2 target branches merged"));
+        assert!(output.contains("✗ release/6.6  General error occurred (1)"));
+        assert!(output.contains("✗ release/6.7  General error occurred (1)"));
+    }
+}
@@ -3,31 +3,46 @@
 //! This module provides the shared logic for running merge operations,
 //! independent of whether the runner is interactive or non-interactive.
 
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
 
-use crate::api::AzureDevOpsClient;
+use crate::api::{ArtifactsFeed, AzureDevOpsClient, UrlBuilder, UrlTemplates};
+use crate::cache::WorkItemCache;
+use crate::core::operations::PRDependencyGraph;
+use crate::core::operations::binary_conflicts::{BinaryConflictPolicy, apply_binary_policy};
+use crate::core::operations::build_checks::{BuildCheckIndex, check_pull_requests};
 use crate::core::operations::cherry_pick::{
-    CherryPickConfig, CherryPickOperation, CherryPickOutcome,
+    CherryPickConfig, CherryPickOperation, CherryPickOrder, CherryPickOutcome,
 };
+use crate::core::operations::fan_out;
 use crate::core::operations::hooks::{
     HookContext, HookExecutor, HookFailureMode, HookOutcome, HookProgress, HookTrigger, HooksConfig,
 };
 use crate::core::operations::post_merge::{
-    CompletedPRInfo, PostMergeConfig, PostMergeOperation, WorkItemInfo,
+    CompletedPRInfo, PostMergeConfig, PostMergeOperation, PostMergeProgress, PostMergeResult,
+    PostMergeTaskResult, WorkItemInfo,
 };
 use crate::core::operations::pr_selection::{
     parse_work_item_states, select_prs_by_work_item_states,
 };
-use crate::core::output::{ConflictInfo, ItemStatus, ProgressEvent, SummaryCounts, SummaryItem};
+use crate::core::operations::release_tag::{ReleaseTag, ReleaseTagMode};
+use crate::core::operations::saved_resolutions::SavedResolutions;
+use crate::core::operations::work_item_fields::WorkItemFieldRule;
+use crate::core::output::{
+    ConflictInfo, ItemStatus, PostMergeStatus, ProgressEvent, SummaryCounts, SummaryItem,
+};
 use crate::core::state::{
-    LockGuard, MergePhase, MergeStateFile, MergeStatus, StateCherryPickItem, StateCreateConfig,
-    StateItemStatus, StateManager,
+    LockGuard, LockKey, LockKind, MergePhase, MergeStateFile, MergeStatus, ReleaseLineage,
+    RunSettings, SettingChange, StateCherryPickItem, StateCreateConfig, StateItemStatus,
+    StateManager, create_run_dir, previous_run, run_clone_path,
 };
 use crate::git;
-use crate::models::PullRequestWithWorkItems;
+use crate::models::{PullRequest, PullRequestWithWorkItems};
+use crate::release_notes::{self, ReleaseNotesLocale};
+use crate::utils::{Clock, IdGen, SystemClock, TimestampIdGen};
 
 /// Result of processing cherry-picks.
 #[derive(Debug)]
@@ -64,6 +79,16 @@ impl CherryPickProcessResult {
     }
 }
 
+/// Maps a post-merge task result to its output status.
+pub fn post_merge_status(result: &PostMergeTaskResult) -> PostMergeStatus {
+    match result {
+        PostMergeTaskResult::Success => PostMergeStatus::Success,
+        PostMergeTaskResult::Failed { message } => PostMergeStatus::Failed {
+            error: message.clone(),
+        },
+    }
+}
+
 /// Core merge engine that orchestrates the merge workflow.
 ///
 /// This struct encapsulates the main merge logic and can be used by
@@ -87,8 +112,38 @@ pub struct MergeEngine {
     max_concurrent_processing: usize,
     /// Filter PRs by date (e.g., "1mo", "2w", "2025-01-15").
     since: Option<String>,
+    /// Template for release comments posted on completed work items.
+    work_item_comment: Option<String>,
+    /// Feed package whose version is stamped on completed work items.
+    artifacts_feed: Option<ArtifactsFeed>,
+    /// Templated fields written on completed work items.
+    work_item_fields: Vec<WorkItemFieldRule>,
+    /// Sides kept for conflicted binary files matching path globs.
+    binary_conflicts: BinaryConflictPolicy,
+    /// Order in which the selected PRs are cherry-picked.
+    cherry_pick_order: CherryPickOrder,
+    /// Whether cherry-picked commits and the release tag are signed.
+    sign_commits: bool,
+    /// Every target branch of the fan-out this merge is part of.
+    fan_out: Vec<String>,
+    squash_message: Option<String>,
+    /// Whether a merge commit whose first parent conflicts is retried with
+    /// its second parent.
+    mainline_fallback: bool,
+    /// Whether the patch branch is tagged `v<version>` on completion.
+    release_tag: ReleaseTagMode,
+    /// Link layouts of the release notes written into the release tag.
+    url_templates: UrlTemplates,
+    /// Headings of the release notes written into the release tag.
+    release_notes_locale: ReleaseNotesLocale,
+    /// Directory holding the artifacts of this run, once set up.
+    run_dir: Option<PathBuf>,
     /// State manager for state file operations.
     state_manager: StateManager,
+    /// Clock stamping the state file and dating the release notes.
+    clock: Arc<dyn Clock>,
+    /// Source of the run directory's id.
+    ids: Arc<dyn IdGen>,
 }
 
 impl MergeEngine {
@@ -111,6 +166,7 @@ impl MergeEngine {
         max_concurrent_processing: usize,
         since: Option<String>,
     ) -> Self {
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
         Self {
             client,
             organization,
@@ -127,10 +183,113 @@ impl MergeEngine {
             max_concurrent_network,
             max_concurrent_processing,
             since,
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            release_tag: ReleaseTagMode::Off,
+            url_templates: UrlTemplates::default(),
+            release_notes_locale: ReleaseNotesLocale::default(),
+            run_dir: None,
             state_manager: StateManager::new(),
+            clock: clock.clone(),
+            ids: Arc::new(TimestampIdGen::new(clock)),
         }
     }
 
+    /// Posts a comment rendered from `template` on each completed work item
+    /// during [`run_post_merge`](Self::run_post_merge).
+    pub fn with_work_item_comment(mut self, template: Option<String>) -> Self {
+        self.work_item_comment = template;
+        self
+    }
+
+    /// Looks up the version of the package in `feed` built for the merge and
+    /// writes it to each completed work item during
+    /// [`run_post_merge`](Self::run_post_merge).
+    pub fn with_artifacts_feed(mut self, feed: Option<ArtifactsFeed>) -> Self {
+        self.artifacts_feed = feed;
+        self
+    }
+
+    /// Writes the fields rendered from `rules` on each completed work item
+    /// during [`run_post_merge`](Self::run_post_merge).
+    pub fn with_work_item_fields(mut self, rules: Vec<WorkItemFieldRule>) -> Self {
+        self.work_item_fields = rules;
+        self
+    }
+
+    /// Resolves binary conflicts matching a policy without stopping the
+    /// cherry-pick.
+    pub fn with_binary_conflicts(mut self, policy: BinaryConflictPolicy) -> Self {
+        self.binary_conflicts = policy;
+        self
+    }
+
+    /// Cherry-picks the selected PRs in `order` (see
+    /// [`order_pull_requests`](Self::order_pull_requests)).
+    pub fn with_cherry_pick_order(mut self, order: CherryPickOrder) -> Self {
+        self.cherry_pick_order = order;
+        self
+    }
+
+    /// Signs cherry-picked commits and the release tag with the configured
+    /// GPG or SSH key.
+    pub fn with_sign_commits(mut self, sign_commits: bool) -> Self {
+        self.sign_commits = sign_commits;
+        self
+    }
+
+    /// Marks the merge as one target of a fan-out to `targets`: the worktree
+    /// is named after the target branch too, and the state file lists all
+    /// targets.
+    pub fn with_fan_out(mut self, targets: Vec<String>) -> Self {
+        self.fan_out = targets;
+        self
+    }
+
+    /// Squashes the commits picked for each PR into one commit with a
+    /// message rendered from `template` (`None` keeps the picked commits).
+    pub fn with_squash_message(mut self, template: Option<String>) -> Self {
+        self.squash_message = template;
+        self
+    }
+
+    /// Retries a two-parent merge commit whose first parent conflicts with
+    /// its second parent as mainline.
+    pub fn with_mainline_fallback(mut self, mainline_fallback: bool) -> Self {
+        self.mainline_fallback = mainline_fallback;
+        self
+    }
+
+    /// Reads the time from `clock` instead of the system clock.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.state_manager.set_clock(clock.clone());
+        self.ids = Arc::new(TimestampIdGen::new(clock.clone()));
+        self.clock = clock;
+        self
+    }
+
+    /// Tags the patch branch `v<version>` during
+    /// [`run_post_merge`](Self::run_post_merge), with release notes written
+    /// with `url_templates` and `locale` as the tag message.
+    pub fn with_release_tag(
+        mut self,
+        mode: ReleaseTagMode,
+        url_templates: UrlTemplates,
+        locale: ReleaseNotesLocale,
+    ) -> Self {
+        self.release_tag = mode;
+        self.url_templates = url_templates;
+        self.release_notes_locale = locale;
+        self
+    }
+
     /// Returns the hooks configuration.
     pub fn hooks_config(&self) -> &HooksConfig {
         &self.hooks_config
@@ -300,6 +459,30 @@ impl MergeEngine {
     }
 
     /// Loads pull requests from Azure DevOps.
+    /// Drops the PRs listed in the `.mergers-ignore` file of the local
+    /// repository.
+    ///
+    /// A fresh clone has no ignore file yet, so without a local repository
+    /// all PRs are kept.
+    fn skip_ignored_prs(&self, prs: Vec<PullRequest>) -> Result<Vec<PullRequest>> {
+        use crate::core::operations::ignore_list::{IGNORE_FILE_NAME, apply_ignore_file};
+
+        let Some(ref local_repo) = self.local_repo else {
+            tracing::debug!("No local repository, skipping {}", IGNORE_FILE_NAME);
+            return Ok(prs);
+        };
+        let (prs, ignored) = apply_ignore_file(local_repo, prs)?;
+        for ignored_pr in &ignored {
+            tracing::info!(
+                "Skipping PR #{} ({}): {}",
+                ignored_pr.pr.id,
+                ignored_pr.pr.title,
+                ignored_pr.reason
+            );
+        }
+        Ok(prs)
+    }
+
     pub async fn load_pull_requests(&self) -> Result<Vec<PullRequestWithWorkItems>> {
         use crate::api::filter_prs_without_merged_tag;
         use crate::utils::throttle::NetworkProcessor;
@@ -326,12 +509,15 @@ impl MergeEngine {
             prs.len()
         );
 
+        // Drop PRs listed in the .mergers-ignore skip list
+        let prs = self.skip_ignored_prs(prs)?;
+
         tracing::info!(
             "Fetching work items for PRs (max_concurrent_network={})",
             self.max_concurrent_network
         );
 
-        // Use NetworkProcessor to throttle work item fetching (same approach as TUI)
+        // Use NetworkProcessor to throttle listing the links (same approach as TUI)
         let network_processor = NetworkProcessor::new_with_limits(
             self.max_concurrent_network,
             self.max_concurrent_processing,
@@ -339,38 +525,48 @@ impl MergeEngine {
 
         let total = prs.len();
 
-        // Fetch work items for all PRs with proper throttling
-        let prs_with_work_items: Vec<PullRequestWithWorkItems> =
-            stream::iter(prs.into_iter().enumerate())
-                .map(|(index, pr)| {
-                    let client = self.client.clone();
-                    let processor = network_processor.clone();
-                    let pr_id = pr.id;
-                    async move {
-                        let work_items = processor
-                            .execute_network_operation(|| async {
-                                client.fetch_work_items_with_history_for_pr(pr_id).await
-                            })
-                            .await
-                            .unwrap_or_default();
-
-                        (index, pr, work_items)
-                    }
-                })
-                .buffer_unordered(self.max_concurrent_network)
-                .map(|(index, pr, work_items)| {
-                    // Log progress periodically
-                    if (index + 1) % 100 == 0 || index + 1 == total {
-                        tracing::info!("Fetched work items for {}/{} PRs", index + 1, total);
-                    }
-                    PullRequestWithWorkItems {
-                        pr,
-                        work_items,
-                        selected: false,
-                    }
-                })
-                .collect()
-                .await;
+        // List the work items linked to each PR with proper throttling
+        let pr_ids: Vec<i32> = prs.iter().map(|pr| pr.id).collect();
+        let links: Vec<(i32, Vec<i32>)> = stream::iter(pr_ids.into_iter().enumerate())
+            .map(|(index, pr_id)| {
+                let client = self.client.clone();
+                let processor = network_processor.clone();
+                async move {
+                    let ids = processor
+                        .execute_network_operation(|| async {
+                            client.fetch_work_item_ids_for_pr(pr_id).await
+                        })
+                        .await
+                        .unwrap_or_default();
+
+                    (index, pr_id, ids)
+                }
+            })
+            .buffer_unordered(self.max_concurrent_network)
+            .map(|(index, pr_id, ids)| {
+                // Log progress periodically
+                if (index + 1) % 100 == 0 || index + 1 == total {
+                    tracing::info!("Listed work items for {}/{} PRs", index + 1, total);
+                }
+                (pr_id, ids)
+            })
+            .collect()
+            .await;
+
+        // Fetch every linked work item once, in batches
+        let mut work_items = self
+            .client
+            .fetch_linked_work_items(links, self.max_concurrent_network)
+            .await
+            .context("Failed to fetch work items")?;
+        let prs_with_work_items: Vec<PullRequestWithWorkItems> = prs
+            .into_iter()
+            .map(|pr| PullRequestWithWorkItems {
+                work_items: work_items.remove(&pr.id).unwrap_or_default(),
+                pr,
+                selected: false,
+            })
+            .collect();
 
         tracing::info!(
             "Loaded {} PRs with work items successfully",
@@ -380,6 +576,23 @@ impl MergeEngine {
         Ok(prs_with_work_items)
     }
 
+    /// Checks the builds and branch policies of the selected PRs.
+    ///
+    /// PRs without a merge commit are not checked.
+    pub async fn check_builds(&self, prs: &[PullRequestWithWorkItems]) -> BuildCheckIndex {
+        let selected = prs
+            .iter()
+            .filter(|pr| pr.selected)
+            .filter_map(|pr| {
+                Some((
+                    pr.pr.id,
+                    pr.pr.last_merge_commit.as_ref()?.commit_id.clone(),
+                ))
+            })
+            .collect();
+        check_pull_requests(&self.client, selected, self.max_concurrent_network).await
+    }
+
     /// Selects PRs based on work item states.
     ///
     /// Returns the number of selected PRs.
@@ -394,8 +607,16 @@ impl MergeEngine {
 
     /// Sets up the repository for cherry-picking.
     ///
+    /// Creates the run directory recorded in the state file; without a local
+    /// repo, the repository is cloned into it.
+    ///
     /// Returns the path to the worktree/clone.
-    pub fn setup_repository(&self) -> Result<(PathBuf, bool)> {
+    pub fn setup_repository(&mut self) -> Result<(PathBuf, bool)> {
+        let run_dir = create_run_dir(self.clock.as_ref(), self.ids.as_ref())
+            .context("Failed to create run directory")?;
+        tracing::debug!("Run directory: {}", run_dir.display());
+        self.run_dir = Some(run_dir.clone());
+
         // Check if we have a local repo configured
         if let Some(ref local_repo) = self.local_repo {
             tracing::info!(
@@ -407,34 +628,51 @@ impl MergeEngine {
             let worktree_path = git::create_worktree(
                 local_repo,
                 &self.target_branch,
-                &self.version,
+                &self.worktree_version(),
                 !self.run_hooks,
             )
             .context("Failed to create worktree")?;
+            self.tag_base(&worktree_path);
 
             tracing::info!("Worktree setup complete");
             Ok((worktree_path, true))
         } else {
             tracing::info!("Cloning repository (no local repo configured)");
-            // Clone the repository
-            // shallow_clone_repo(ssh_url, target_branch, run_hooks) -> (PathBuf, TempDir)
-            let (clone_path, _temp_dir) = git::shallow_clone_repo(
-                &format!(
-                    "https://dev.azure.com/{}/{}/_git/{}",
-                    self.organization, self.project, self.repository
-                ),
+            // The clone lives in the run directory until `mergers gc` removes it
+            let clone_path = run_clone_path(&run_dir);
+            git::shallow_clone_repo_into(
+                &UrlBuilder::new(&self.organization, &self.project, &self.repository)
+                    .repository_url(),
                 &self.target_branch,
-                !self.run_hooks,
+                &clone_path,
             )
             .context("Failed to clone repository")?;
+            self.tag_base(&clone_path);
 
-            // Note: We intentionally drop _temp_dir which means the cloned repo
-            // will be deleted when this function returns. For persistent clones,
-            // use a worktree approach instead.
             Ok((clone_path, false))
         }
     }
 
+    /// Returns the version the worktree is named after (`next-<version>`),
+    /// which includes the target branch in a fan-out.
+    fn worktree_version(&self) -> String {
+        if self.fan_out.is_empty() {
+            self.version.clone()
+        } else {
+            fan_out::worktree_version(&self.version, &self.target_branch)
+        }
+    }
+
+    /// Tags the starting point of the release before anything is picked.
+    ///
+    /// Best effort: the tag only helps diffing the release afterwards.
+    fn tag_base(&self, repo_path: &Path) {
+        match git::create_base_tag(repo_path, &self.target_branch, &self.version) {
+            Ok(tag) => tracing::info!("Tagged the release base as {}", tag),
+            Err(e) => tracing::warn!("Failed to tag the release base: {:#}", e),
+        }
+    }
+
     /// Creates a new state file for a merge operation.
     ///
     /// This method delegates to the internal StateManager and returns the path
@@ -451,7 +689,74 @@ impl MergeEngine {
         is_worktree: bool,
         prs: &[PullRequestWithWorkItems],
     ) -> Result<PathBuf> {
-        let config = StateCreateConfig {
+        let config = self.state_create_config();
+
+        let items = Self::state_items_for(prs);
+
+        self.state_manager.create_state_file_with_items(
+            repo_path,
+            base_repo_path,
+            is_worktree,
+            &self.version,
+            &config,
+            items,
+        )
+    }
+
+    /// Creates the state file for a final release promoted from a release candidate.
+    ///
+    /// Items carried over from the RC keep their status and come first; newly
+    /// selected PRs are appended as pending items and cherry-picking resumes
+    /// right after the carried items.
+    ///
+    /// # Returns
+    ///
+    /// The path where the state file was saved.
+    pub fn create_promoted_state_file(
+        &mut self,
+        repo_path: PathBuf,
+        base_repo_path: Option<PathBuf>,
+        carried_items: Vec<StateCherryPickItem>,
+        new_prs: &[PullRequestWithWorkItems],
+        lineage: ReleaseLineage,
+    ) -> Result<PathBuf> {
+        let config = self.state_create_config();
+        let carried_count = carried_items.len();
+        let mut items = carried_items;
+        items.extend(Self::state_items_for(new_prs));
+
+        self.state_manager.create_state_file_with_items(
+            repo_path,
+            base_repo_path,
+            true,
+            &self.version,
+            &config,
+            items,
+        )?;
+        self.state_manager.sync_current_index(carried_count)?;
+        self.state_manager
+            .set_release_lineage(lineage)?
+            .ok_or_else(|| anyhow::anyhow!("State file was not created"))
+    }
+
+    /// Settings that differ from the previous run of the repository.
+    ///
+    /// Call this before [`Self::create_state_file`], which records this run
+    /// as the previous one.
+    pub fn changed_settings(&self) -> Vec<SettingChange> {
+        let config = self.state_create_config();
+        previous_run(&config)
+            .map(|previous| {
+                previous
+                    .settings
+                    .changes(&RunSettings::new(&config, Some(&self.version)))
+            })
+            .unwrap_or_default()
+    }
+
+    /// Builds the state file configuration from the engine settings.
+    fn state_create_config(&self) -> StateCreateConfig {
+        StateCreateConfig {
             organization: self.organization.clone(),
             project: self.project.clone(),
             repository: self.repository.clone(),
@@ -460,11 +765,45 @@ impl MergeEngine {
             tag_prefix: self.tag_prefix.clone(),
             work_item_state: self.work_item_state.clone(),
             run_hooks: self.run_hooks,
-        };
+            sign_commits: self.sign_commits,
+            fan_out: self.fan_out.clone(),
+            squash_message: self.squash_message.clone(),
+            mainline_fallback: self.mainline_fallback,
+            run_dir: self.run_dir.clone(),
+        }
+    }
 
-        // Convert selected PRs to cherry-pick items
-        let items: Vec<StateCherryPickItem> = prs
-            .iter()
+    /// Sorts `prs` into the configured cherry-pick order.
+    ///
+    /// Only [`CherryPickOrder::Topological`] changes the order: PRs are sorted
+    /// by closed date and then reordered by the dependency graph (see
+    /// [`CherryPickOperation::order`]).
+    pub fn order_pull_requests(
+        &self,
+        prs: &mut [PullRequestWithWorkItems],
+        graph: Option<&PRDependencyGraph>,
+    ) {
+        if self.cherry_pick_order == CherryPickOrder::ClosedDate {
+            return;
+        }
+        prs.sort_by_key(|pr| (pr.pr.closed_date.is_none(), pr.pr.closed_date.clone()));
+        let by_date: Vec<i32> = prs.iter().map(|pr| pr.pr.id).collect();
+        let operation = CherryPickOperation::new(CherryPickConfig {
+            order: self.cherry_pick_order,
+            ..Default::default()
+        });
+        let rank: HashMap<i32, usize> = operation
+            .order(&by_date, graph)
+            .into_iter()
+            .enumerate()
+            .map(|(rank, id)| (id, rank))
+            .collect();
+        prs.sort_by_key(|pr| rank[&pr.pr.id]);
+    }
+
+    /// Converts selected PRs with a merge commit to pending cherry-pick items.
+    fn state_items_for(prs: &[PullRequestWithWorkItems]) -> Vec<StateCherryPickItem> {
+        prs.iter()
             .filter(|pr| pr.selected)
             .filter_map(|pr| {
                 // Get the merge commit ID
@@ -477,42 +816,126 @@ impl MergeEngine {
                         pr_title: pr.pr.title.clone(),
                         status: StateItemStatus::Pending,
                         work_item_ids: pr.work_items.iter().map(|wi| wi.id).collect(),
+                        work_item_titles: pr.work_item_titles(),
+                        picked_commit_id: None,
+                        mainline_parent: None,
+                        pr_closed_at: pr.pr.closed_at(),
+                        had_conflict: false,
                     })
             })
-            .collect();
+            .collect()
+    }
 
-        self.state_manager.create_state_file_with_items(
+    /// Returns the conflicted files with a resolution saved for the PR.
+    fn saved_resolutions(&self, pr_id: i32, files: &[String]) -> Vec<String> {
+        SavedResolutions::for_repository(&self.organization, &self.project, &self.repository)
+            .map(|saved| saved.available(pr_id, files))
+            .unwrap_or_default()
+    }
+
+    /// Returns true if commits are signed, either as configured or because
+    /// the merge being resumed was started with signing.
+    fn signs_commits(&self) -> bool {
+        self.sign_commits
+            || self
+                .state_manager
+                .state_file()
+                .is_some_and(|state| state.sign_commits)
+    }
+
+    /// Returns the template PRs are squashed with, as configured or as
+    /// recorded by the merge being resumed.
+    fn squash_message(&self) -> Option<String> {
+        self.squash_message.clone().or_else(|| {
+            self.state_manager
+                .state_file()
+                .and_then(|state| state.squash_message.clone())
+        })
+    }
+
+    /// Returns true if merge commits are retried with their second parent,
+    /// either as configured or as recorded by the merge being resumed.
+    fn retries_second_parent(&self) -> bool {
+        self.mainline_fallback
+            || self
+                .state_manager
+                .state_file()
+                .is_some_and(|state| state.mainline_fallback)
+    }
+
+    /// Returns the cherry-pick operation configured for this merge.
+    fn cherry_pick_operation(&self) -> CherryPickOperation {
+        CherryPickOperation::new(CherryPickConfig {
+            run_hooks: self.run_hooks,
+            is_worktree: self.local_repo.is_some(),
+            order: self.cherry_pick_order,
+            sign_commits: self.signs_commits(),
+            squash_message: self.squash_message(),
+            mainline_fallback: self.retries_second_parent(),
+        })
+    }
+
+    /// Squashes the commits picked for the PR of `index` on top of `base`
+    /// into one commit (see [`CherryPickOperation::squash_pr`]).
+    pub fn squash_picked_pr(
+        &self,
+        repo_path: &Path,
+        base: &str,
+        index: usize,
+    ) -> CherryPickOutcome {
+        let Some(item) = self
+            .state_manager
+            .state_file()
+            .and_then(|state| state.cherry_pick_items.get(index))
+        else {
+            return CherryPickOutcome::Success;
+        };
+        self.cherry_pick_operation().squash_pr(
             repo_path,
-            base_repo_path,
-            is_worktree,
-            &self.version,
-            &config,
-            items,
+            base,
+            item.pr_id,
+            &item.pr_title,
+            &item.work_item_ids,
         )
     }
 
     /// Cherry-picks a single commit.
     ///
-    /// Returns the outcome and optionally the list of conflicted files.
+    /// Returns the outcome, optionally the list of conflicted files, and the
+    /// mainline parent the cherry-pick used.
     pub fn cherry_pick_commit(
         &self,
         repo_path: &Path,
         commit_id: &str,
-    ) -> (CherryPickOutcome, Option<Vec<String>>) {
-        let config = CherryPickConfig {
-            run_hooks: self.run_hooks,
-            is_worktree: self.local_repo.is_some(),
-        };
-        let operation = CherryPickOperation::new(config);
-
-        let outcome = operation.cherry_pick_commit(repo_path, commit_id);
+    ) -> (CherryPickOutcome, Option<Vec<String>>, u8) {
+        let operation = self.cherry_pick_operation();
+
+        let (mut outcome, mainline) = operation.cherry_pick_commit(repo_path, commit_id);
+
+        // If a policy cannot be applied the files stay conflicted for manual resolution
+        if let CherryPickOutcome::Conflict { conflicted_files } = &outcome
+            && let Ok(resolution) = apply_binary_policy(
+                repo_path,
+                conflicted_files,
+                &self.binary_conflicts,
+                self.signs_commits(),
+            )
+        {
+            outcome = if resolution.remaining.is_empty() {
+                CherryPickOutcome::Success
+            } else {
+                CherryPickOutcome::Conflict {
+                    conflicted_files: resolution.remaining,
+                }
+            };
+        }
 
         let conflicted_files = match &outcome {
             CherryPickOutcome::Conflict { conflicted_files } => Some(conflicted_files.clone()),
             _ => None,
         };
 
-        (outcome, conflicted_files)
+        (outcome, conflicted_files, mainline)
     }
 
     /// Processes cherry-pick items using the internal StateManager.
@@ -594,7 +1017,14 @@ impl MergeEngine {
             });
 
             // Perform cherry-pick (borrows self immutably)
-            let (outcome, _conflicted_files) = self.cherry_pick_commit(&repo_path, &commit_id);
+            let base = git::head_commit(&repo_path).ok();
+            let (mut outcome, _conflicted_files, mainline) =
+                self.cherry_pick_commit(&repo_path, &commit_id);
+            if let (CherryPickOutcome::Success, Some(base)) = (&outcome, &base) {
+                outcome = self.squash_picked_pr(&repo_path, base, current_index);
+            }
+            // Only non-default parents are recorded
+            let mainline_parent = (mainline != 1).then_some(mainline);
 
             // Update state based on outcome
             {
@@ -604,15 +1034,19 @@ impl MergeEngine {
                 match outcome {
                     CherryPickOutcome::Success => {
                         item.status = StateItemStatus::Success;
+                        item.picked_commit_id = git::head_commit(&repo_path).ok();
+                        item.mainline_parent = mainline_parent;
                         event_callback(ProgressEvent::CherryPickSuccess {
                             pr_id,
                             commit_id: commit_id.clone(),
+                            mainline_parent,
                         });
                     }
                     CherryPickOutcome::Conflict {
                         ref conflicted_files,
                     } => {
                         item.status = StateItemStatus::Conflict;
+                        item.had_conflict = true;
                         state_file.phase = MergePhase::AwaitingConflictResolution;
                         state_file.conflicted_files = Some(conflicted_files.clone());
 
@@ -637,13 +1071,17 @@ impl MergeEngine {
                             );
                         }
 
-                        return CherryPickProcessResult::Conflict(ConflictInfo::new(
-                            pr_id,
-                            pr_title,
-                            commit_id,
-                            conflicted_files.clone(),
-                            repo_path,
-                        ));
+                        let saved = self.saved_resolutions(pr_id, conflicted_files);
+                        return CherryPickProcessResult::Conflict(
+                            ConflictInfo::new(
+                                pr_id,
+                                pr_title,
+                                commit_id,
+                                conflicted_files.clone(),
+                                repo_path,
+                            )
+                            .with_saved_resolutions(saved),
+                        );
                     }
                     CherryPickOutcome::Skipped => {
                         item.status = StateItemStatus::Skipped;
@@ -703,13 +1141,14 @@ impl MergeEngine {
         CherryPickProcessResult::Complete
     }
 
-    /// Executes post-merge tasks (tagging PRs and updating work items).
+    /// Executes post-merge tasks (tagging PRs, updating work items and
+    /// posting release comments), emitting a progress event per task.
     pub async fn run_post_merge<F>(
         &self,
         state: &MergeStateFile,
         next_state: &str,
         mut event_callback: F,
-    ) -> Result<(usize, usize)>
+    ) -> Result<PostMergeResult>
     where
         F: FnMut(ProgressEvent),
     {
@@ -726,20 +1165,30 @@ impl MergeEngine {
                     .iter()
                     .map(|&id| WorkItemInfo {
                         id,
-                        title: String::new(), // Title not stored in state
+                        title: item.work_item_titles.get(&id).cloned().unwrap_or_default(),
                     })
                     .collect(),
             })
             .collect();
 
         if completed_prs.is_empty() {
-            return Ok((0, 0));
+            return Ok(PostMergeResult {
+                tasks: Vec::new(),
+                success_count: 0,
+                failed_count: 0,
+                package_version: None,
+            });
         }
 
         let config = PostMergeConfig {
             tag_prefix: state.tag_prefix.clone(),
             version: state.merge_version.clone(),
             work_item_state: next_state.to_string(),
+            target_branch: state.target_branch.clone(),
+            comment_template: self.work_item_comment.clone(),
+            artifacts_feed: self.artifacts_feed.clone(),
+            work_item_fields: self.work_item_fields.clone(),
+            release_tag: self.release_tag(state),
         };
 
         let operation = PostMergeOperation::new(Arc::clone(&self.client), config);
@@ -749,16 +1198,53 @@ impl MergeEngine {
             task_count: tasks.len(),
         });
 
+        let targets: Vec<(&'static str, i32)> = tasks
+            .iter()
+            .map(|t| (t.task.task_type(), t.task.target_id()))
+            .collect();
         let result = operation
             .execute_all(
                 &mut tasks,
-                Some(|_progress| {
-                    // Could emit more detailed progress events here
+                Some(|progress| {
+                    if let PostMergeProgress::TaskCompleted { index, result } = progress {
+                        let (task_type, target_id) = targets[index];
+                        event_callback(ProgressEvent::PostMergeProgress {
+                            task_type: task_type.to_string(),
+                            target_id,
+                            status: post_merge_status(&result),
+                        });
+                    }
                 }),
             )
             .await;
 
-        Ok((result.success_count, result.failed_count))
+        Ok(result)
+    }
+
+    /// The release tag of the merge in `state`, if enabled.
+    fn release_tag(&self, state: &MergeStateFile) -> Option<ReleaseTag> {
+        if !self.release_tag.is_enabled() {
+            return None;
+        }
+        let urls = UrlBuilder::new(&state.organization, &state.project, &state.repository)
+            .with_templates(self.url_templates.clone());
+        let cache = WorkItemCache::load().unwrap_or_default();
+        let message = release_notes::generate_from_state(
+            state,
+            &urls,
+            &cache,
+            self.clock.local_now().date_naive(),
+            &self.release_notes_locale,
+        );
+        Some(
+            ReleaseTag::new(
+                state.repo_path.clone(),
+                &state.merge_version,
+                message,
+                self.release_tag.pushes(),
+            )
+            .signed(self.sign_commits || state.sign_commits),
+        )
     }
 
     /// Creates summary items from the state file.
@@ -770,6 +1256,8 @@ impl MergeEngine {
                 pr_id: item.pr_id,
                 pr_title: item.pr_title.clone(),
                 commit_id: item.commit_id.clone(),
+                picked_commit_id: item.picked_commit_id.clone(),
+                mainline_parent: item.mainline_parent,
                 status: match &item.status {
                     StateItemStatus::Pending => ItemStatus::Pending,
                     StateItemStatus::Success => ItemStatus::Success,
@@ -914,9 +1402,9 @@ impl MergeEngine {
     }
 }
 
-/// Acquires a lock for a repository.
-pub fn acquire_lock(repo_path: &Path) -> Result<Option<LockGuard>> {
-    LockGuard::acquire(repo_path)
+/// Acquires the merge lock for a key.
+pub fn acquire_lock(key: &LockKey) -> Result<Option<LockGuard>> {
+    LockGuard::acquire(key, LockKind::Merge)
 }
 
 #[cfg(test)]
@@ -984,6 +1472,11 @@ mod tests {
                 pr_title: format!("PR #{}", pr_id),
                 status,
                 work_item_ids: vec![],
+                work_item_titles: Default::default(),
+                picked_commit_id: None,
+                mainline_parent: None,
+                pr_closed_at: None,
+                had_conflict: false,
             })
             .collect();
 
@@ -1284,12 +1777,54 @@ mod tests {
         unsafe { std::env::remove_var(crate::core::state::STATE_DIR_ENV) };
     }
 
+    /// # Changed Settings
+    ///
+    /// Verifies the engine compares its settings with the previous run.
+    ///
+    /// ## Test Scenario
+    /// - Lists changes with no previous run
+    /// - Creates a state file, then lists the changes of an engine with
+    ///   another version for the same repository
+    ///
+    /// ## Expected Outcome
+    /// - Nothing is listed without a previous run
+    /// - The version change is listed against the recorded run
+    #[test]
+    #[serial_test::file_serial(state_env)]
+    fn test_changed_settings() {
+        use tempfile::TempDir;
+
+        let temp_state_dir = TempDir::new().unwrap();
+        let temp_repo = TempDir::new().unwrap();
+        unsafe { std::env::set_var(crate::core::state::STATE_DIR_ENV, temp_state_dir.path()) };
+
+        let mut engine = create_test_engine();
+        assert!(engine.changed_settings().is_empty());
+        engine
+            .create_state_file(temp_repo.path().to_path_buf(), None, false, &[])
+            .unwrap();
+        drop(engine);
+
+        let mut engine = create_test_engine();
+        engine.version = "v1.1.0".to_string();
+        assert_eq!(
+            engine.changed_settings(),
+            vec![SettingChange {
+                name: "Version",
+                previous: "v1.0.0".to_string(),
+                current: "v1.1.0".to_string(),
+            }]
+        );
+
+        unsafe { std::env::remove_var(crate::core::state::STATE_DIR_ENV) };
+    }
+
     /// # Acquire Lock Function
     ///
     /// Verifies the acquire_lock convenience function works.
     ///
     /// ## Test Scenario
-    /// - Calls acquire_lock on a temp directory
+    /// - Calls acquire_lock for a repository and target branch
     ///
     /// ## Expected Outcome
     /// - Returns Ok with Some lock guard
@@ -1306,10 +1841,8 @@ mod tests {
             )
         };
 
-        let repo_dir = temp_dir.path().join("repo");
-        std::fs::create_dir_all(&repo_dir).unwrap();
-
-        let result = acquire_lock(&repo_dir);
+        let key = LockKey::for_repository("org", "project", "repo", "main");
+        let result = acquire_lock(&key);
         assert!(result.is_ok());
         assert!(result.unwrap().is_some());
 
@@ -1347,6 +1880,7 @@ mod tests {
                     description: None,
                     repro_steps: None,
                     state_color: None,
+                    custom_fields: Default::default(),
                 },
                 history: Vec::new(),
             }
@@ -1365,6 +1899,7 @@ mod tests {
                     commit_id: commit_id.to_string(),
                 }),
                 labels: None,
+                reviewers: Vec::new(),
             }
         }
 
@@ -1388,6 +1923,78 @@ mod tests {
         assert!(!prs[1].selected);
     }
 
+    /// # Order Pull Requests
+    ///
+    /// Verifies that the engine sorts PRs into the configured cherry-pick order.
+    ///
+    /// ## Test Scenario
+    /// - Loads three PRs newest first; the oldest depends on the newest
+    /// - Orders them with the default and the topological order
+    ///
+    /// ## Expected Outcome
+    /// - The closed-date order leaves the PRs untouched
+    /// - The topological order picks the dependency before its dependent and
+    ///   keeps the independent PR in its closed-date slot
+    #[test]
+    fn test_order_pull_requests() {
+        use crate::core::operations::{DependencyCategory, PRDependency, PRDependencyNode};
+        use crate::models::{CreatedBy, PullRequest};
+
+        let prs: Vec<PullRequestWithWorkItems> =
+            [(1, "2024-01-03"), (2, "2024-01-02"), (3, "2024-01-01")]
+                .into_iter()
+                .map(|(id, closed)| PullRequestWithWorkItems {
+                    pr: PullRequest {
+                        id,
+                        title: format!("PR {}", id),
+                        description: None,
+                        closed_date: Some(format!("{}T10:00:00Z", closed)),
+                        created_by: CreatedBy {
+                            display_name: "Test User".to_string(),
+                        },
+                        last_merge_commit: None,
+                        labels: None,
+                        reviewers: Vec::new(),
+                    },
+                    work_items: Vec::new(),
+                    selected: true,
+                })
+                .collect();
+
+        let mut graph = PRDependencyGraph::new();
+        for id in 1..=3 {
+            graph.add_node(PRDependencyNode::new(id, format!("PR {}", id), true));
+        }
+        graph
+            .get_node_mut(3)
+            .unwrap()
+            .dependencies
+            .push(PRDependency {
+                from_pr_id: 3,
+                to_pr_id: 1,
+                category: DependencyCategory::PartiallyDependent {
+                    shared_files: vec!["src/lib.rs".to_string()],
+                },
+            });
+        graph.get_node_mut(1).unwrap().dependents.push(3);
+        graph.topological_order = vec![1, 2, 3];
+        let ids =
+            |prs: &[PullRequestWithWorkItems]| prs.iter().map(|pr| pr.pr.id).collect::<Vec<_>>();
+
+        let mut unchanged = prs.clone();
+        create_test_engine().order_pull_requests(&mut unchanged, Some(&graph));
+        assert_eq!(ids(&unchanged), [1, 2, 3]);
+
+        let engine = create_test_engine().with_cherry_pick_order(CherryPickOrder::Topological);
+        let mut ordered = prs.clone();
+        engine.order_pull_requests(&mut ordered, None);
+        assert_eq!(ids(&ordered), [3, 2, 1]);
+
+        let mut ordered = prs;
+        engine.order_pull_requests(&mut ordered, Some(&graph));
+        assert_eq!(ids(&ordered), [1, 2, 3]);
+    }
+
     /// # Filter PRs Without Merged Tag Integration
     ///
     /// Verifies that the filter_prs_without_merged_tag function works correctly
@@ -1424,6 +2031,7 @@ mod tests {
                         })
                         .collect()
                 }),
+                reviewers: Vec::new(),
             }
         }
 
@@ -1443,6 +2051,48 @@ mod tests {
         assert_eq!(filtered[2].id, 5);
     }
 
+    /// # Skip Ignored PRs
+    ///
+    /// Verifies the `.mergers-ignore` file is only read from the local
+    /// repository.
+    ///
+    /// ## Test Scenario
+    /// - Writes an ignore file listing PR 2 into a repository directory
+    /// - Skips ignored PRs with an engine without a local repository
+    /// - Skips them again with that directory as local repository
+    ///
+    /// ## Expected Outcome
+    /// - Without a local repository every PR is kept
+    /// - With it, PR 2 is dropped
+    #[test]
+    fn test_skip_ignored_prs() {
+        use crate::core::operations::ignore_list::IGNORE_FILE_NAME;
+        use crate::models::CreatedBy;
+
+        let pr = |id: i32| PullRequest {
+            id,
+            title: format!("PR {}", id),
+            description: None,
+            closed_date: None,
+            created_by: CreatedBy {
+                display_name: "Test User".to_string(),
+            },
+            last_merge_commit: None,
+            labels: None,
+            reviewers: Vec::new(),
+        };
+        let repo = tempfile::TempDir::new().unwrap();
+        std::fs::write(repo.path().join(IGNORE_FILE_NAME), "2\n").unwrap();
+
+        let mut engine = create_test_engine();
+        let kept = engine.skip_ignored_prs(vec![pr(1), pr(2)]).unwrap();
+        assert_eq!(kept.iter().map(|pr| pr.id).collect::<Vec<_>>(), vec![1, 2]);
+
+        engine.local_repo = Some(repo.path().to_path_buf());
+        let kept = engine.skip_ignored_prs(vec![pr(1), pr(2)]).unwrap();
+        assert_eq!(kept.iter().map(|pr| pr.id).collect::<Vec<_>>(), vec![1]);
+    }
+
     /// # Filter PRs All Have Merged Tags
     ///
     /// Verifies behavior when all PRs have merged tags.
@@ -1472,6 +2122,7 @@ mod tests {
                 labels: Some(vec![Label {
                     name: "merged-v1.0.0".to_string(),
                 }]),
+                reviewers: Vec::new(),
             },
             PullRequest {
                 id: 2,
@@ -1487,6 +2138,7 @@ mod tests {
                 labels: Some(vec![Label {
                     name: "merged-v2.0.0".to_string(),
                 }]),
+                reviewers: Vec::new(),
             },
         ];
 
@@ -1524,6 +2176,7 @@ mod tests {
                 labels: Some(vec![Label {
                     name: "feature".to_string(),
                 }]),
+                reviewers: Vec::new(),
             },
             PullRequest {
                 id: 2,
@@ -1537,6 +2190,7 @@ mod tests {
                     commit_id: "b".to_string(),
                 }),
                 labels: None,
+                reviewers: Vec::new(),
             },
         ];
 
@@ -1585,6 +2239,7 @@ mod tests {
                 labels: Some(vec![Label {
                     name: "feature".to_string(),
                 }]),
+                reviewers: Vec::new(),
             },
             PullRequest {
                 id: 2,
@@ -1600,6 +2255,7 @@ mod tests {
                 labels: Some(vec![Label {
                     name: "merged-v1.0.0".to_string(),
                 }]),
+                reviewers: Vec::new(),
             },
         ];
 
@@ -1622,6 +2278,7 @@ mod tests {
                         description: None,
                         repro_steps: None,
                         state_color: None,
+                        custom_fields: Default::default(),
                     },
                     history: Vec::new(),
                 }],
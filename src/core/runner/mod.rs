@@ -8,16 +8,31 @@
 //! - `traits.rs` - Defines the `MergeRunner` trait and common types
 //! - `merge_engine.rs` - Core orchestration logic shared between runners
 //! - `non_interactive.rs` - CLI runner for non-interactive mode
+//! - `analyze.rs` - CLI runner exporting the PR dependency graph
+//! - `batch.rs` - CLI runner merging several repositories in one run
+//! - `fan_out.rs` - CLI runner picking the same PRs into several target branches
+//! - `watch.rs` - CLI runner polling for new merge candidates
 
+pub mod analyze;
+pub mod batch;
+pub mod fan_out;
 pub mod merge_engine;
 pub mod non_interactive;
 pub mod release_notes;
 pub mod traits;
+pub mod watch;
 
+pub use analyze::{AnalyzeRunner, AnalyzeRunnerConfig};
+pub use batch::{BatchRepo, BatchRepoConfig, BatchReport, BatchRunner, BatchRunnerConfig};
+pub use fan_out::{FanOutReport, FanOutRunner, FanOutTargetReport};
 pub use merge_engine::{CherryPickProcessResult, MergeEngine};
 pub use non_interactive::NonInteractiveRunner;
-pub use release_notes::{ReleaseNotesRunner, ReleaseNotesRunnerConfig};
+pub use release_notes::{
+    ReleaseNotesOutput, ReleaseNotesRunner, ReleaseNotesRunnerConfig, StateReleaseNotesConfig,
+    StateReleaseNotesRunner,
+};
 pub use traits::{MergeRunnerConfig, RunResult};
+pub use watch::{WatchRunner, WatchRunnerConfig};
 
 // Re-export OutputFormat from models for convenience
 pub use crate::models::OutputFormat;
@@ -4,21 +4,49 @@
 //! designed for use by AI agents and CI systems.
 
 use std::io::{self, Write};
+use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::{Context, Result, bail};
 
-use crate::api::AzureDevOpsClient;
+use crate::api::{AzureDevOpsClient, UrlBuilder};
 use crate::core::ExitCode;
+use crate::core::operations::binary_conflicts::resolve_binary_conflicts;
+use crate::core::operations::conflict_report::ConflictReport;
+use crate::core::operations::conflict_resolution::{ResolveTool, resolve_file};
+use crate::core::operations::conflict_timeout::{ConflictDecision, ConflictTimeout, keep_side};
+use crate::core::operations::dry_run::{clone_for_dry_run, simulate_merge};
+use crate::core::operations::pr_selection::{
+    describe_unknown_states, find_unknown_work_item_states,
+};
+use crate::core::operations::recovery::{self, RecoverAction};
+use crate::core::operations::release_candidate::{next_rc_number, rc_version};
+use crate::core::operations::release_history;
+use crate::core::operations::saved_resolutions::SavedResolutions;
+use crate::core::operations::selection_file::SelectionFile;
+use crate::core::operations::{
+    CherryPickConfig, CherryPickOperation, CherryPickOutcome, DependencyAnalysisResult,
+    DependencyWarning,
+};
+#[cfg(feature = "dashboard")]
+use crate::core::output::Dashboard;
 use crate::core::output::{
-    ConflictInfo, ItemStatus, OutputFormatter, OutputWriter, PostMergeSummary, ProgressEvent,
-    ProgressSummary, StatusInfo, SummaryCounts, SummaryInfo, SummaryItem, SummaryResult,
+    ConflictInfo, DryRunDependencies, DryRunOutcome, DryRunReport, ItemStatus, OutputFormatter,
+    OutputWriter, PostMergeStatus, PostMergeSummary, PostMergeTaskResult, ProgressEvent,
+    ProgressSummary, RecoveryInfo, StatusInfo, StatusServer, SummaryCounts, SummaryInfo,
+    SummaryItem, SummaryResult,
 };
-use crate::core::state::{LockGuard, MergePhase, MergeStateFile, MergeStatus, StateItemStatus};
-use crate::git;
+use crate::core::state::{
+    LockGuard, LockKey, MergePhase, MergeStateFile, MergeStatus, ReleaseLineage, StateItemStatus,
+    prune_expired_run_dirs,
+};
+use crate::error::{self, ConfigError, ConflictError, StateError};
+use crate::git::{self, GitOperations, SystemGit};
+use crate::models::PullRequestWithWorkItems;
+use crate::notify::{self, MergeNotification};
 
-use super::merge_engine::{CherryPickProcessResult, MergeEngine, acquire_lock};
+use super::merge_engine::{CherryPickProcessResult, MergeEngine, acquire_lock, post_merge_status};
 use super::traits::{MergeRunnerConfig, RunResult};
 use crate::core::operations::hooks::HookOutcome;
 
@@ -29,13 +57,30 @@ use crate::core::operations::hooks::HookOutcome;
 pub struct NonInteractiveRunner<W: Write = io::Stdout> {
     config: MergeRunnerConfig,
     output: OutputWriter<W>,
+    /// Git backend used to check and finalize conflict resolutions.
+    git: Arc<dyn GitOperations>,
+    /// Localhost status endpoint, started by `run` when a port is configured.
+    status_server: Option<StatusServer>,
+    /// Web dashboard, started by `run` when an address is configured.
+    #[cfg(feature = "dashboard")]
+    dashboard: Option<Dashboard>,
+    /// PRs `run` picks instead of selecting them by the configuration.
+    selected_prs: Option<Vec<i32>>,
 }
 
 impl NonInteractiveRunner<io::Stdout> {
     /// Creates a new non-interactive runner with stdout output.
     pub fn new(config: MergeRunnerConfig) -> Self {
         let output = OutputWriter::new(io::stdout(), config.output_format, config.quiet);
-        Self { config, output }
+        Self {
+            config,
+            output,
+            git: Arc::new(SystemGit),
+            status_server: None,
+            #[cfg(feature = "dashboard")]
+            dashboard: None,
+            selected_prs: None,
+        }
     }
 }
 
@@ -43,7 +88,29 @@ impl<W: Write> NonInteractiveRunner<W> {
     /// Creates a new runner with a custom writer.
     pub fn with_writer(config: MergeRunnerConfig, writer: W) -> Self {
         let output = OutputWriter::new(writer, config.output_format, config.quiet);
-        Self { config, output }
+        Self {
+            config,
+            output,
+            git: Arc::new(SystemGit),
+            status_server: None,
+            #[cfg(feature = "dashboard")]
+            dashboard: None,
+            selected_prs: None,
+        }
+    }
+
+    /// Uses `git` instead of the system git to check and finalize conflict
+    /// resolutions.
+    pub fn with_git(mut self, git: Arc<dyn GitOperations>) -> Self {
+        self.git = git;
+        self
+    }
+
+    /// Makes `run` pick exactly the PRs in `pr_ids`, ignoring the selection
+    /// settings, so a fan-out picks the same PRs into every target.
+    pub fn with_selected_prs(mut self, pr_ids: Vec<i32>) -> Self {
+        self.selected_prs = Some(pr_ids);
+        self
     }
 
     /// Runs a new merge operation.
@@ -67,6 +134,63 @@ impl<W: Write> NonInteractiveRunner<W> {
             );
         }
 
+        if let Some(port) = self.config.status_port {
+            match StatusServer::start(port) {
+                Ok(server) => {
+                    tracing::info!("Serving status on http://{}/", server.addr());
+                    self.status_server = Some(server);
+                }
+                Err(e) => {
+                    let message = format!("{:#}", e);
+                    self.emit_error(&message);
+                    return RunResult::error(ExitCode::GeneralError, message);
+                }
+            }
+        }
+
+        if let Some(addr) = self.config.serve
+            && let Err(e) = self.start_dashboard(addr)
+        {
+            let message = format!("{:#}", e);
+            self.emit_error(&message);
+            return RunResult::error(ExitCode::GeneralError, message);
+        }
+
+        // Release candidates get the next free -rcN suffix
+        let release = if self.config.release_candidate {
+            let release_version = self.config.version.clone();
+            let local_repo = self.config.local_repo.clone();
+            let target_branch = self.config.target_branch.clone();
+            // Without a local repository the remote is queried by URL
+            let remote = match &local_repo {
+                Some(_) => "origin".to_string(),
+                None => UrlBuilder::new(
+                    &self.config.organization,
+                    &self.config.project,
+                    &self.config.repository,
+                )
+                .repository_url(),
+            };
+            let number = next_rc_number(&release_version, |candidate| {
+                let branch = format!("patch/{}-{}", target_branch, candidate);
+                let repo = local_repo.as_deref();
+                let local = repo.is_some_and(|repo| {
+                    git::worktree_exists(repo, candidate).unwrap_or(false)
+                        || git::branch_exists(repo, &branch).unwrap_or(false)
+                });
+                local
+                    || git::remote_branch_exists(repo, &remote, &branch).unwrap_or_else(|e| {
+                        tracing::warn!("Could not check {} on the remote: {:#}", branch, e);
+                        false
+                    })
+            });
+            self.config.version = rc_version(&release_version, number);
+            tracing::info!("Creating release candidate {}", self.config.version);
+            Some(ReleaseLineage::candidate(release_version, number))
+        } else {
+            None
+        };
+
         // Create the API client
         tracing::debug!("Creating Azure DevOps API client");
         let client = match self.create_client() {
@@ -77,7 +201,7 @@ impl<W: Write> NonInteractiveRunner<W> {
             Err(e) => {
                 tracing::error!("Failed to create API client: {}", e);
                 return RunResult::error(
-                    ExitCode::GeneralError,
+                    error::exit_code(&e),
                     format!("Failed to create API client: {}", e),
                 );
             }
@@ -100,30 +224,21 @@ impl<W: Write> NonInteractiveRunner<W> {
             }
             Err(e) => {
                 tracing::error!("Failed to load PRs: {}", e);
-                self.emit_error(&format!("Failed to load PRs: {}", e));
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return self.fail_with_context("Failed to load PRs", e);
             }
         };
 
-        // Select PRs by work item states if configured
-        if let Some(ref states) = self.config.select_by_states {
-            tracing::info!("Selecting PRs by work item states: {:?}", states);
-            let count = engine.select_prs_by_states(&mut prs, states);
-            tracing::debug!("{} PRs matched the specified states", count);
-            if count == 0 {
-                tracing::warn!("No PRs matched the specified work item states");
-                self.emit_error("No PRs matched the specified work item states");
-                return RunResult::error(
-                    ExitCode::NoPRsMatched,
-                    "No PRs matched the specified work item states",
-                );
-            }
-        } else {
-            tracing::debug!("Selecting all PRs with merge commits");
-            // Select all PRs with merge commits
+        if let Some(result) = self.check_strict_states(&prs) {
+            return result;
+        }
+
+        if let Some(pr_ids) = &self.selected_prs {
+            tracing::info!("Selecting the {} given PRs", pr_ids.len());
             for pr in &mut prs {
-                pr.selected = pr.pr.last_merge_commit.is_some();
+                pr.selected = pr.pr.last_merge_commit.is_some() && pr_ids.contains(&pr.pr.id);
             }
+        } else if let Some(result) = self.select_pull_requests(&engine, &mut prs).await {
+            return result;
         }
 
         let selected_count = prs.iter().filter(|pr| pr.selected).count();
@@ -133,6 +248,22 @@ impl<W: Write> NonInteractiveRunner<W> {
             self.emit_error("No PRs selected for merge");
             return RunResult::error(ExitCode::NoPRsMatched, "No PRs selected for merge");
         }
+        if let Some(result) = self.check_selection_cap(selected_count) {
+            return result;
+        }
+
+        if self.config.dry_run {
+            return self.dry_run(&engine, &prs, selected_count);
+        }
+
+        // Best effort: remove run directories past the retention period
+        match prune_expired_run_dirs(self.config.clock.as_ref()) {
+            Ok(report) if !report.removed.is_empty() => {
+                tracing::info!("Pruned {} expired run directories", report.removed.len());
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Failed to prune run directories: {}", e),
+        }
 
         // Set up the repository
         tracing::info!("Setting up repository...");
@@ -148,21 +279,20 @@ impl<W: Write> NonInteractiveRunner<W> {
             }
             Err(e) => {
                 tracing::error!("Failed to set up repository: {}", e);
-                self.emit_error(&format!("Failed to set up repository: {}", e));
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return self.fail_with_context("Failed to set up repository", e);
             }
         };
 
         // Acquire lock
         tracing::debug!("Acquiring repository lock");
-        let _lock = match acquire_lock(&repo_path) {
+        let _lock = match acquire_lock(&self.config_lock_key()) {
             Ok(Some(lock)) => {
                 tracing::info!("Repository lock acquired");
                 lock
             }
             Ok(None) => {
                 tracing::warn!("Another merge operation is in progress");
-                self.emit_error_with_code("Another merge operation is in progress", Some("locked"));
+                self.emit_state_error(&StateError::Locked);
                 return RunResult::error(
                     ExitCode::Locked,
                     "Another merge operation is in progress",
@@ -170,8 +300,7 @@ impl<W: Write> NonInteractiveRunner<W> {
             }
             Err(e) => {
                 tracing::error!("Failed to acquire lock: {}", e);
-                self.emit_error(&format!("Failed to acquire lock: {}", e));
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return self.fail_with_context("Failed to acquire lock", e);
             }
         };
 
@@ -198,48 +327,9 @@ impl<W: Write> NonInteractiveRunner<W> {
             );
         }
 
-        // Run dependency analysis
-        tracing::info!("Starting dependency analysis for {} PRs", selected_count);
-        self.emit_event(ProgressEvent::DependencyAnalysisStart {
-            pr_count: selected_count,
-        });
-
-        match engine.analyze_dependencies(&prs, &repo_path) {
-            Ok(analysis_result) => {
-                // Emit summary
-                let summary = analysis_result.graph.summary();
-                self.emit_event(ProgressEvent::DependencyAnalysisComplete {
-                    independent: summary.independent_relationships,
-                    partial: summary.partial_relationships,
-                    dependent: summary.dependent_relationships,
-                });
-
-                // Emit warnings
-                for warning in &analysis_result.warnings {
-                    if let crate::core::operations::DependencyWarning::UnselectedDependency {
-                        selected_pr_id,
-                        selected_pr_title,
-                        unselected_pr_id,
-                        unselected_pr_title,
-                        category,
-                    } = warning
-                    {
-                        self.emit_event(ProgressEvent::DependencyWarning {
-                            selected_pr_id: *selected_pr_id,
-                            selected_pr_title: selected_pr_title.clone(),
-                            unselected_pr_id: *unselected_pr_id,
-                            unselected_pr_title: unselected_pr_title.clone(),
-                            is_critical: warning.is_critical(),
-                            shared_files: category.shared_files().to_vec(),
-                        });
-                    }
-                }
-            }
-            Err(e) => {
-                // Dependency analysis failure is non-fatal, just log a warning
-                tracing::warn!("Warning: Dependency analysis failed: {}", e);
-            }
-        }
+        // Run dependency analysis, which also decides a topological pick order
+        let analysis = self.analyze_dependencies(&engine, &prs, &repo_path, selected_count);
+        engine.order_pull_requests(&mut prs, analysis.as_ref().map(|result| &result.graph));
 
         // Create state file using StateManager-backed method
         let base_repo_path = if is_worktree {
@@ -248,16 +338,30 @@ impl<W: Write> NonInteractiveRunner<W> {
             None
         };
 
+        // Point out what changed since the previous run before this one replaces it
+        for change in engine.changed_settings() {
+            self.emit_event(ProgressEvent::SettingChanged {
+                setting: change.name.to_string(),
+                previous: change.previous,
+                current: change.current,
+            });
+        }
+
         // Create state file - this stores state in engine's internal StateManager
         let state_path =
             match engine.create_state_file(repo_path.clone(), base_repo_path, is_worktree, &prs) {
                 Ok(path) => path,
                 Err(e) => {
-                    self.emit_error(&format!("Failed to create state file: {}", e));
-                    return RunResult::error(ExitCode::GeneralError, e.to_string());
+                    return self.fail_with_context("Failed to create state file", e);
                 }
             };
 
+        if let Some(lineage) = release
+            && let Err(e) = engine.state_manager_mut().set_release_lineage(lineage)
+        {
+            return self.fail_with_context("Failed to save state", e);
+        }
+
         // Get total PRs from state manager for the start event
         let total_prs = engine
             .state_manager()
@@ -273,62 +377,7 @@ impl<W: Write> NonInteractiveRunner<W> {
             state_file_path: Some(state_path.clone()),
         });
 
-        // Process cherry-picks using internal state manager
-        let process_result = engine.process_cherry_picks(|event| {
-            self.emit_event(event);
-        });
-
-        // Save state after cherry-picks
-        if let Err(e) = engine.state_manager_mut().save() {
-            self.emit_error(&format!("Failed to save state: {}", e));
-            return RunResult::error(ExitCode::GeneralError, e.to_string());
-        }
-
-        // Handle process result
-        match process_result {
-            CherryPickProcessResult::Conflict(conflict) => {
-                // Output conflict info
-                if let Err(e) = self.output.write_conflict(&conflict) {
-                    tracing::warn!("Failed to write conflict info: {}", e);
-                }
-                return RunResult::conflict(state_path);
-            }
-            CherryPickProcessResult::HookAbort { command, error, .. } => {
-                self.emit_error(&format!("Hook aborted: {} - {}", command, error));
-                return RunResult::error(
-                    ExitCode::HookFailed,
-                    format!("Hook '{}' failed: {}", command, error),
-                )
-                .with_state_file(state_path);
-            }
-            CherryPickProcessResult::Complete => {
-                // Continue to completion
-            }
-        }
-
-        // All cherry-picks complete - get counts from state manager
-        let counts = engine
-            .state_manager()
-            .state_file()
-            .map(|state| engine.create_summary_counts(state))
-            .unwrap_or_else(|| SummaryCounts::new(0, 0, 0, 0));
-
-        self.emit_event(ProgressEvent::Complete {
-            successful: counts.successful,
-            failed: counts.failed,
-            skipped: counts.skipped,
-        });
-
-        // Determine result
-        if counts.failed > 0 {
-            RunResult::partial_success(format!(
-                "{} successful, {} failed, {} skipped",
-                counts.successful, counts.failed, counts.skipped
-            ))
-            .with_state_file(state_path)
-        } else {
-            RunResult::success().with_state_file(state_path)
-        }
+        self.process_and_finish(&mut engine, state_path)
     }
 
     /// Continues a merge operation after conflict resolution.
@@ -337,69 +386,78 @@ impl<W: Write> NonInteractiveRunner<W> {
         let repo_path = match self.find_repo_path(repo_path) {
             Ok(path) => path,
             Err(e) => {
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return error_result(e);
             }
         };
 
         // Early lock check (before loading state)
-        match LockGuard::is_locked(&repo_path) {
+        match LockGuard::is_locked(&self.state_lock_key(&repo_path)) {
             Ok(true) => {
-                self.emit_error_with_code("Another merge operation is in progress", Some("locked"));
+                self.emit_state_error(&StateError::Locked);
                 return RunResult::error(ExitCode::Locked, "Locked");
             }
             Err(e) => {
-                self.emit_error(&format!("Failed to check lock: {}", e));
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return self.fail_with_context("Failed to check lock", e);
             }
             Ok(false) => {}
         }
 
         // Load and validate state file
-        let mut state = match MergeStateFile::load_and_validate_for_repo(&repo_path) {
+        let mut state = match self.load_state(&repo_path) {
             Ok(Some(state)) => state,
             Ok(None) => {
-                self.emit_error_with_code(
-                    "No state file found for this repository",
-                    Some("no_state_file"),
-                );
+                self.emit_state_error(&StateError::NotFound);
                 return RunResult::error(ExitCode::NoStateFile, "No state file found");
             }
             Err(e) => {
-                self.emit_error(&format!("{}", e));
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return self.fail(e);
             }
         };
 
         // Validate phase
         if state.phase != MergePhase::AwaitingConflictResolution {
-            self.emit_error_with_code(
-                &format!("Cannot continue: merge is in '{}' phase", state.phase),
-                Some("invalid_phase"),
-            );
+            self.emit_state_error(&StateError::InvalidPhase {
+                operation: "continue".to_string(),
+                phase: state.phase.to_string(),
+            });
             return RunResult::error(ExitCode::InvalidPhase, "Invalid phase for continue");
         }
 
         // Acquire lock
-        let _lock = match acquire_lock(&repo_path) {
+        let _lock = match acquire_lock(&state.lock_key()) {
             Ok(Some(lock)) => lock,
             Ok(None) => {
-                self.emit_error_with_code("Another merge operation is in progress", Some("locked"));
+                self.emit_state_error(&StateError::Locked);
                 return RunResult::error(ExitCode::Locked, "Locked");
             }
             Err(e) => {
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return error_result(e);
             }
         };
 
         // Check if conflicts are resolved
         let conflicts_resolved = self.check_conflicts_resolved(&state.repo_path);
         if !conflicts_resolved {
-            self.emit_error_with_code("Conflicts are not fully resolved. Please resolve all conflicts and stage the files.", Some("conflicts_unresolved"));
+            let error = ConflictError::Unresolved;
+            self.emit_error_with_code(&error.to_string(), Some(error.code()));
+            self.write_unresolved_conflicts(&state);
             return RunResult::error(ExitCode::Conflict, "Conflicts not resolved");
         }
 
+        // Keep the resolutions for later cherry-picks of the same PR
+        if let Some(files) = &state.conflicted_files
+            && let Err(e) = saved_resolutions(&state).and_then(|saved| {
+                let pr_id = state.cherry_pick_items[state.current_index].pr_id;
+                saved.save(&state.repo_path, pr_id, files)
+            })
+        {
+            tracing::warn!("Failed to save conflict resolutions: {:#}", e);
+        }
+
         // Finalize the cherry-pick commit
-        if let Err(e) = git::continue_cherry_pick(&state.repo_path) {
+        let sign = state.sign_commits || self.config.sign_commits;
+        let base = git::head_commit(&state.repo_path).ok();
+        if let Err(e) = self.git.continue_cherry_pick(&state.repo_path, sign) {
             self.emit_error(&format!("Failed to finalize cherry-pick: {}", e));
             return RunResult::error(
                 ExitCode::GeneralError,
@@ -407,81 +465,173 @@ impl<W: Write> NonInteractiveRunner<W> {
             );
         }
 
-        // Mark current item as success and advance
-        state.cherry_pick_items[state.current_index].status = StateItemStatus::Success;
+        // Mark current item as success, record the finalized commit and advance
+        let squash_message = state
+            .squash_message
+            .clone()
+            .or_else(|| self.config.squash_message.clone());
+        if let Some(message) =
+            finish_current_item(&mut state, base.as_deref(), squash_message, sign)
+        {
+            self.emit_error(&format!("Failed to squash PR: {}", message));
+        }
         state.current_index += 1;
         state.phase = MergePhase::CherryPicking;
         state.conflicted_files = None;
 
-        // Create the engine
-        let client = match self.create_client() {
-            Ok(c) => c,
+        self.process_remaining(state)
+    }
+
+    /// Walks through the conflicted files of a paused merge.
+    ///
+    /// With `reuse_saved`, resolutions saved from an earlier cherry-pick of
+    /// the same PR are re-applied first. Each remaining file is opened with
+    /// `tool`, checked, and staged. Once no conflicts remain, the merge is
+    /// continued unless `continue_after` is false. Files that are still
+    /// conflicted are reported and left for the next run.
+    pub async fn resolve(
+        &mut self,
+        repo_path: Option<&Path>,
+        tool: &ResolveTool,
+        reuse_saved: bool,
+        continue_after: bool,
+    ) -> RunResult {
+        // Determine repo path
+        let repo_path = match self.find_repo_path(repo_path) {
+            Ok(path) => path,
             Err(e) => {
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return error_result(e);
             }
         };
-        let mut engine = self.create_engine(client);
-
-        // Set the loaded state file on the engine's state manager
-        // The lock guard remains local to ensure it stays alive for the operation
-        engine.state_manager_mut().set_state_file(state);
 
-        // Continue processing using internal state manager
-        let process_result = engine.process_cherry_picks(|event| {
-            self.emit_event(event);
-        });
+        // Early lock check (before loading state)
+        match LockGuard::is_locked(&self.state_lock_key(&repo_path)) {
+            Ok(true) => {
+                self.emit_state_error(&StateError::Locked);
+                return RunResult::error(ExitCode::Locked, "Locked");
+            }
+            Err(e) => {
+                return self.fail_with_context("Failed to check lock", e);
+            }
+            Ok(false) => {}
+        }
 
-        // Save state via state manager
-        let state_path = match engine.state_manager_mut().save() {
-            Ok(Some(path)) => path,
+        // Load and validate state file
+        let state = match self.load_state(&repo_path) {
+            Ok(Some(state)) => state,
             Ok(None) => {
-                self.emit_error("No state file to save");
-                return RunResult::error(ExitCode::GeneralError, "No state file to save");
+                self.emit_state_error(&StateError::NotFound);
+                return RunResult::error(ExitCode::NoStateFile, "No state file found");
             }
             Err(e) => {
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return self.fail(e);
             }
         };
 
-        // Handle process result
-        match process_result {
-            CherryPickProcessResult::Conflict(conflict) => {
-                if let Err(e) = self.output.write_conflict(&conflict) {
-                    tracing::warn!("Failed to write conflict info: {}", e);
-                }
-                return RunResult::conflict(state_path);
-            }
-            CherryPickProcessResult::HookAbort { command, error, .. } => {
-                self.emit_error(&format!("Hook aborted: {} - {}", command, error));
-                return RunResult::error(
-                    ExitCode::HookFailed,
-                    format!("Hook '{}' failed: {}", command, error),
-                )
-                .with_state_file(state_path);
-            }
-            CherryPickProcessResult::Complete => {
-                // Continue to completion
-            }
+        // Validate phase
+        if state.phase != MergePhase::AwaitingConflictResolution {
+            self.emit_state_error(&StateError::InvalidPhase {
+                operation: "resolve".to_string(),
+                phase: state.phase.to_string(),
+            });
+            return RunResult::error(ExitCode::InvalidPhase, "Invalid phase for resolve");
         }
 
-        // Get counts from state manager
-        let counts = engine
-            .state_manager()
-            .state_file()
-            .map(|state| engine.create_summary_counts(state))
-            .unwrap_or_else(|| SummaryCounts::new(0, 0, 0, 0));
+        let unresolved = {
+            // Hold the lock while files are edited; `continue_merge` takes its own
+            let _lock = match acquire_lock(&state.lock_key()) {
+                Ok(Some(lock)) => lock,
+                Ok(None) => {
+                    self.emit_state_error(&StateError::Locked);
+                    return RunResult::error(ExitCode::Locked, "Locked");
+                }
+                Err(e) => {
+                    return error_result(e);
+                }
+            };
 
-        self.emit_event(ProgressEvent::Complete {
-            successful: counts.successful,
-            failed: counts.failed,
-            skipped: counts.skipped,
-        });
+            let files = match git::unresolved_files(&state.repo_path) {
+                Ok(files) => files,
+                Err(e) => {
+                    return self.fail(e);
+                }
+            };
 
-        if counts.failed > 0 {
-            RunResult::partial_success("Completed with some failures").with_state_file(state_path)
-        } else {
-            RunResult::success().with_state_file(state_path)
+            // Binary files covered by a policy are resolved without an editor
+            let files = match resolve_binary_conflicts(
+                &state.repo_path,
+                &files,
+                &self.config.binary_conflicts,
+            ) {
+                Ok(resolution) => {
+                    for (file, _) in resolution.resolved {
+                        self.emit_event(ProgressEvent::ResolveFileStaged { file });
+                    }
+                    resolution.remaining
+                }
+                Err(e) => {
+                    return self.fail_with_context("Failed to resolve binary conflicts", e);
+                }
+            };
+
+            // Resolutions from an earlier cherry-pick of the PR are applied before prompting
+            let files = if reuse_saved {
+                let pr_id = state.cherry_pick_items[state.current_index].pr_id;
+                match saved_resolutions(&state)
+                    .and_then(|saved| saved.reapply(&state.repo_path, pr_id, &files))
+                {
+                    Ok(reused) => {
+                        for file in &reused {
+                            self.emit_event(ProgressEvent::ResolveFileReused {
+                                file: file.clone(),
+                            });
+                        }
+                        files.into_iter().filter(|f| !reused.contains(f)).collect()
+                    }
+                    Err(e) => {
+                        return self.fail_with_context("Failed to re-apply saved resolutions", e);
+                    }
+                }
+            } else {
+                files
+            };
+
+            let mut unresolved = Vec::new();
+            for (index, file) in files.iter().enumerate() {
+                self.emit_event(ProgressEvent::ResolveFileStart {
+                    file: file.clone(),
+                    index,
+                    total: files.len(),
+                });
+                match resolve_file(&state.repo_path, file, tool) {
+                    Ok(true) => {
+                        self.emit_event(ProgressEvent::ResolveFileStaged { file: file.clone() })
+                    }
+                    Ok(false) => unresolved.push(file.clone()),
+                    Err(e) => {
+                        return self.fail_with_context(&format!("Failed to resolve {}", file), e);
+                    }
+                }
+            }
+            unresolved
+        };
+
+        if !unresolved.is_empty() {
+            let error = ConflictError::Unresolved;
+            self.emit_error_with_code(
+                &format!("{}: {}", error, unresolved.join(", ")),
+                Some(error.code()),
+            );
+            return RunResult::error(ExitCode::Conflict, "Conflicts not resolved");
+        }
+
+        if !continue_after {
+            return RunResult::success_with_message(
+                "All conflicts resolved. Run `mergers merge continue` to continue.",
+            );
         }
+
+        self.continue_merge(Some(&repo_path)).await
     }
 
     /// Aborts the current merge operation.
@@ -490,57 +640,56 @@ impl<W: Write> NonInteractiveRunner<W> {
         let repo_path = match self.find_repo_path(repo_path) {
             Ok(path) => path,
             Err(e) => {
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return error_result(e);
             }
         };
 
         // Early lock check (before loading state)
-        match LockGuard::is_locked(&repo_path) {
+        match LockGuard::is_locked(&self.state_lock_key(&repo_path)) {
             Ok(true) => {
-                self.emit_error_with_code("Another merge operation is in progress", Some("locked"));
+                self.emit_state_error(&StateError::Locked);
                 return RunResult::error(ExitCode::Locked, "Locked");
             }
             Err(e) => {
-                self.emit_error(&format!("Failed to check lock: {}", e));
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return self.fail_with_context("Failed to check lock", e);
             }
             Ok(false) => {}
         }
 
         // Load and validate state file
-        let mut state = match MergeStateFile::load_and_validate_for_repo(&repo_path) {
+        let mut state = match self.load_state(&repo_path) {
             Ok(Some(state)) => state,
             Ok(None) => {
-                self.emit_error_with_code(
-                    "No state file found for this repository",
-                    Some("no_state_file"),
-                );
+                self.emit_state_error(&StateError::NotFound);
                 return RunResult::error(ExitCode::NoStateFile, "No state file found");
             }
             Err(e) => {
-                self.emit_error(&format!("{}", e));
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return self.fail(e);
             }
         };
 
         // Validate phase (can't abort if already completed)
         if state.phase.is_terminal() {
+            let error = StateError::InvalidPhase {
+                operation: "abort".to_string(),
+                phase: state.phase.to_string(),
+            };
             self.emit_error_with_code(
                 &format!("Cannot abort: merge is already '{}'", state.phase),
-                Some("invalid_phase"),
+                Some(error.code()),
             );
             return RunResult::error(ExitCode::InvalidPhase, "Invalid phase for abort");
         }
 
         // Acquire lock
-        let _lock = match acquire_lock(&repo_path) {
+        let _lock = match acquire_lock(&state.lock_key()) {
             Ok(Some(lock)) => lock,
             Ok(None) => {
-                self.emit_error_with_code("Another merge operation is in progress", Some("locked"));
+                self.emit_state_error(&StateError::Locked);
                 return RunResult::error(ExitCode::Locked, "Locked");
             }
             Err(e) => {
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return error_result(e);
             }
         };
 
@@ -548,7 +697,7 @@ impl<W: Write> NonInteractiveRunner<W> {
         let client = match self.create_client() {
             Ok(c) => c,
             Err(e) => {
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return error_result(e);
             }
         };
         let engine = self.create_engine(client);
@@ -570,67 +719,216 @@ impl<W: Write> NonInteractiveRunner<W> {
             success: true,
             message: None,
         });
+        self.write_state_summary(&engine, &state, SummaryResult::Aborted);
+        self.write_reports(&state);
 
         RunResult::success_with_message("Merge aborted")
     }
 
-    /// Skips the current conflicting PR and continues with remaining.
+    /// Inspects a merge interrupted by a crash and optionally recovers it.
+    ///
+    /// The report lists which PRs are actually on the patch branch. With
+    /// `Resume` those findings are written to the state file and the merge
+    /// continues; `Abort` cleans up like `merge abort`; `Export` keeps the
+    /// applied commits on `rescue_branch` (or a generated `rescue/` name).
+    pub async fn recover(
+        &mut self,
+        repo_path: Option<&Path>,
+        action: RecoverAction,
+        rescue_branch: Option<&str>,
+    ) -> RunResult {
+        if action == RecoverAction::Abort {
+            return self.abort(repo_path);
+        }
+
+        // Determine repo path
+        let repo_path = match self.find_repo_path(repo_path) {
+            Ok(path) => path,
+            Err(e) => {
+                return error_result(e);
+            }
+        };
+
+        // A live lock means the merge was not interrupted at all
+        match LockGuard::is_locked(&self.state_lock_key(&repo_path)) {
+            Ok(true) => {
+                self.emit_state_error(&StateError::Locked);
+                return RunResult::error(ExitCode::Locked, "Locked");
+            }
+            Err(e) => {
+                return self.fail_with_context("Failed to check lock", e);
+            }
+            Ok(false) => {}
+        }
+
+        let mut state = match self.load_state(&repo_path) {
+            Ok(Some(state)) => state,
+            Ok(None) => {
+                self.emit_state_error(&StateError::NotFound);
+                return RunResult::error(ExitCode::NoStateFile, "No state file found");
+            }
+            Err(e) => {
+                return self.fail(e);
+            }
+        };
+
+        if state.phase.is_terminal() {
+            let error = StateError::InvalidPhase {
+                operation: "recover".to_string(),
+                phase: state.phase.to_string(),
+            };
+            self.emit_error_with_code(
+                &format!("Nothing to recover: merge is already '{}'", state.phase),
+                Some(error.code()),
+            );
+            return RunResult::error(ExitCode::InvalidPhase, "Invalid phase for recover");
+        }
+
+        let mut info = match recovery::inspect(&state) {
+            Ok(info) => info,
+            Err(e) => {
+                return self.fail_with_context("Failed to inspect the worktree", e);
+            }
+        };
+
+        match action {
+            RecoverAction::Inspect | RecoverAction::Abort => {
+                self.write_recovery(&info);
+                RunResult::success()
+            }
+            RecoverAction::Export => {
+                let Some((repo, revision)) = recovery::picks_location(&state) else {
+                    self.write_recovery(&info);
+                    self.emit_error("Neither the worktree nor the patch branch exists");
+                    return RunResult::error(
+                        ExitCode::GeneralError,
+                        "Nothing to export: the worktree and patch branch are gone",
+                    );
+                };
+                let branch = rescue_branch.map(str::to_string).unwrap_or_else(|| {
+                    recovery::default_rescue_branch(&state, self.config.clock.as_ref())
+                });
+                if let Err(e) = git::create_branch_at(&repo, &branch, &revision) {
+                    return self.fail(e);
+                }
+                info.rescue_branch = Some(branch.clone());
+                self.write_recovery(&info);
+                RunResult::success_with_message(format!("Applied commits exported to {}", branch))
+            }
+            RecoverAction::Resume => {
+                if !info.worktree_exists {
+                    self.write_recovery(&info);
+                    self.emit_error("Cannot resume: the worktree no longer exists");
+                    return RunResult::error(ExitCode::GeneralError, "Worktree missing");
+                }
+                if info.uncommitted_changes && !info.cherry_pick_in_progress {
+                    self.write_recovery(&info);
+                    self.emit_error(
+                        "Cannot resume: the worktree has uncommitted changes; \
+                         commit or discard them first",
+                    );
+                    return RunResult::error(ExitCode::GeneralError, "Uncommitted changes");
+                }
+
+                let _lock = match acquire_lock(&state.lock_key()) {
+                    Ok(Some(lock)) => lock,
+                    Ok(None) => {
+                        self.emit_state_error(&StateError::Locked);
+                        return RunResult::error(ExitCode::Locked, "Locked");
+                    }
+                    Err(e) => {
+                        return error_result(e);
+                    }
+                };
+
+                recovery::reconcile(&mut state, &info);
+                self.write_recovery(&info);
+
+                match state.phase {
+                    MergePhase::CherryPicking => self.process_remaining(state),
+                    MergePhase::AwaitingConflictResolution => {
+                        let state_path = match state.save_for_repo() {
+                            Ok(path) => path,
+                            Err(e) => return self.fail(e),
+                        };
+                        let item = &state.cherry_pick_items[state.current_index];
+                        let saved = saved_resolutions(&state)
+                            .map(|saved| saved.available(item.pr_id, &info.conflicted_files))
+                            .unwrap_or_default();
+                        let conflict = ConflictInfo::new(
+                            item.pr_id,
+                            item.pr_title.clone(),
+                            item.commit_id.clone(),
+                            info.conflicted_files.clone(),
+                            state.repo_path.clone(),
+                        )
+                        .with_saved_resolutions(saved);
+                        self.write_conflict(&conflict, Some(&state));
+                        self.flush_output();
+                        RunResult::conflict(state_path)
+                    }
+                    _ => match state.save_for_repo() {
+                        Ok(path) => RunResult::success_with_message("All picks are applied")
+                            .with_state_file(path),
+                        Err(e) => self.fail(e),
+                    },
+                }
+            }
+        }
+    }
+
+    /// Skips the current conflicting PR and continues with remaining.
     pub async fn skip(&mut self, repo_path: Option<&Path>) -> RunResult {
         // Determine repo path
         let repo_path = match self.find_repo_path(repo_path) {
             Ok(path) => path,
             Err(e) => {
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return error_result(e);
             }
         };
 
         // Early lock check
-        match LockGuard::is_locked(&repo_path) {
+        match LockGuard::is_locked(&self.state_lock_key(&repo_path)) {
             Ok(true) => {
-                self.emit_error_with_code("Another merge operation is in progress", Some("locked"));
+                self.emit_state_error(&StateError::Locked);
                 return RunResult::error(ExitCode::Locked, "Locked");
             }
             Err(e) => {
-                self.emit_error(&format!("Failed to check lock: {}", e));
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return self.fail_with_context("Failed to check lock", e);
             }
             Ok(false) => {}
         }
 
         // Load and validate state file
-        let mut state = match MergeStateFile::load_and_validate_for_repo(&repo_path) {
+        let mut state = match self.load_state(&repo_path) {
             Ok(Some(state)) => state,
             Ok(None) => {
-                self.emit_error_with_code(
-                    "No state file found for this repository",
-                    Some("no_state_file"),
-                );
+                self.emit_state_error(&StateError::NotFound);
                 return RunResult::error(ExitCode::NoStateFile, "No state file found");
             }
             Err(e) => {
-                self.emit_error(&format!("{}", e));
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return self.fail(e);
             }
         };
 
         // Validate phase
         if state.phase != MergePhase::AwaitingConflictResolution {
-            self.emit_error_with_code(
-                &format!("Cannot skip: merge is in '{}' phase", state.phase),
-                Some("invalid_phase"),
-            );
+            self.emit_state_error(&StateError::InvalidPhase {
+                operation: "skip".to_string(),
+                phase: state.phase.to_string(),
+            });
             return RunResult::error(ExitCode::InvalidPhase, "Invalid phase for skip");
         }
 
         // Acquire lock
-        let _lock = match acquire_lock(&repo_path) {
+        let _lock = match acquire_lock(&state.lock_key()) {
             Ok(Some(lock)) => lock,
             Ok(None) => {
-                self.emit_error_with_code("Another merge operation is in progress", Some("locked"));
+                self.emit_state_error(&StateError::Locked);
                 return RunResult::error(ExitCode::Locked, "Locked");
             }
             Err(e) => {
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return error_result(e);
             }
         };
 
@@ -660,7 +958,7 @@ impl<W: Write> NonInteractiveRunner<W> {
         let client = match self.create_client() {
             Ok(c) => c,
             Err(e) => {
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return error_result(e);
             }
         };
         let mut engine = self.create_engine(client);
@@ -681,16 +979,18 @@ impl<W: Write> NonInteractiveRunner<W> {
                 return RunResult::error(ExitCode::GeneralError, "No state file to save");
             }
             Err(e) => {
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return error_result(e);
             }
         };
+        if let Some(state) = engine.state_manager().state_file() {
+            self.write_reports(state);
+        }
 
         // Handle process result
         match conflict_info {
             CherryPickProcessResult::Conflict(conflict) => {
-                if let Err(e) = self.output.write_conflict(&conflict) {
-                    tracing::warn!("Warning: Failed to write conflict info: {}", e);
-                }
+                self.write_conflict(&conflict, engine.state_manager().state_file());
+                self.flush_output();
                 return RunResult::conflict(state_path);
             }
             CherryPickProcessResult::HookAbort { command, error, .. } => {
@@ -732,12 +1032,12 @@ impl<W: Write> NonInteractiveRunner<W> {
         let repo_path = match self.find_repo_path(repo_path) {
             Ok(path) => path,
             Err(e) => {
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return error_result(e);
             }
         };
 
         // Load and validate state file
-        let state = match MergeStateFile::load_and_validate_for_repo(&repo_path) {
+        let state = match self.load_state(&repo_path) {
             Ok(Some(state)) => state,
             Ok(None) => {
                 // No merge in progress - return idle status (not an error)
@@ -764,8 +1064,7 @@ impl<W: Write> NonInteractiveRunner<W> {
                 return RunResult::success();
             }
             Err(e) => {
-                self.emit_error(&format!("{}", e));
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return self.fail(e);
             }
         };
 
@@ -778,6 +1077,8 @@ impl<W: Write> NonInteractiveRunner<W> {
                 pr_id: item.pr_id,
                 pr_title: item.pr_title.clone(),
                 commit_id: item.commit_id.clone(),
+                picked_commit_id: item.picked_commit_id.clone(),
+                mainline_parent: item.mainline_parent,
                 status: match &item.status {
                     StateItemStatus::Pending => ItemStatus::Pending,
                     StateItemStatus::Success => ItemStatus::Success,
@@ -797,13 +1098,24 @@ impl<W: Write> NonInteractiveRunner<W> {
                 .cherry_pick_items
                 .get(state.current_index)
                 .map(|item| {
-                    ConflictInfo::new(
+                    let files = state.conflicted_files.clone().unwrap_or_default();
+                    let saved = saved_resolutions(&state)
+                        .map(|saved| saved.available(item.pr_id, &files))
+                        .unwrap_or_default();
+                    let conflict = ConflictInfo::new(
                         item.pr_id,
                         item.pr_title.clone(),
                         item.commit_id.clone(),
-                        state.conflicted_files.clone().unwrap_or_default(),
+                        files,
                         state.repo_path.clone(),
                     )
+                    .with_saved_resolutions(saved);
+                    match ConflictReport::load_for_repo(&state.repo_path) {
+                        Some((report, path)) if report.pr_id == item.pr_id => {
+                            conflict.with_report(report, Some(path))
+                        }
+                        _ => conflict,
+                    }
                 })
         } else {
             None
@@ -848,86 +1160,80 @@ impl<W: Write> NonInteractiveRunner<W> {
         let repo_path = match self.find_repo_path(repo_path) {
             Ok(path) => path,
             Err(e) => {
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return error_result(e);
             }
         };
 
         // Early lock check (before loading state)
-        match LockGuard::is_locked(&repo_path) {
+        match LockGuard::is_locked(&self.state_lock_key(&repo_path)) {
             Ok(true) => {
-                self.emit_error_with_code("Another merge operation is in progress", Some("locked"));
+                self.emit_state_error(&StateError::Locked);
                 return RunResult::error(ExitCode::Locked, "Locked");
             }
             Err(e) => {
-                self.emit_error(&format!("Failed to check lock: {}", e));
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return self.fail_with_context("Failed to check lock", e);
             }
             Ok(false) => {}
         }
 
         // Load and validate state file
-        let mut state = match MergeStateFile::load_and_validate_for_repo(&repo_path) {
+        let mut state = match self.load_state(&repo_path) {
             Ok(Some(state)) => state,
             Ok(None) => {
-                self.emit_error_with_code(
-                    "No state file found for this repository",
-                    Some("no_state_file"),
-                );
+                self.emit_state_error(&StateError::NotFound);
                 return RunResult::error(ExitCode::NoStateFile, "No state file found");
             }
             Err(e) => {
-                self.emit_error(&format!("{}", e));
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return self.fail(e);
             }
         };
 
         // Validate phase
         if state.phase != MergePhase::ReadyForCompletion {
-            self.emit_error_with_code(
-                &format!("Cannot complete: merge is in '{}' phase", state.phase),
-                Some("invalid_phase"),
-            );
+            self.emit_state_error(&StateError::InvalidPhase {
+                operation: "complete".to_string(),
+                phase: state.phase.to_string(),
+            });
             return RunResult::error(ExitCode::InvalidPhase, "Invalid phase for complete");
         }
 
         // Acquire lock
-        let _lock = match acquire_lock(&repo_path) {
+        let _lock = match acquire_lock(&state.lock_key()) {
             Ok(Some(lock)) => lock,
             Ok(None) => {
-                self.emit_error_with_code("Another merge operation is in progress", Some("locked"));
+                self.emit_state_error(&StateError::Locked);
                 return RunResult::error(ExitCode::Locked, "Locked");
             }
             Err(e) => {
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return error_result(e);
             }
         };
 
         // Update phase
         state.phase = MergePhase::Completing;
         if let Err(e) = state.save_for_repo() {
-            return RunResult::error(ExitCode::GeneralError, e.to_string());
+            return error_result(e);
         }
 
         // Create engine
         let client = match self.create_client() {
             Ok(c) => c,
             Err(e) => {
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return error_result(e);
             }
         };
         let engine = self.create_engine(client);
 
         // Run post-merge tasks
-        let (success_count, failed_count) = match engine
+        let post_merge = match engine
             .run_post_merge(&state, next_state, |event| {
                 self.emit_event(event);
             })
             .await
         {
-            Ok((s, f)) => (s, f),
+            Ok(result) => result,
             Err(e) => {
-                self.emit_error(&format!("Post-merge failed: {}", e));
-                return RunResult::error(ExitCode::GeneralError, e.to_string());
+                return self.fail_with_context("Post-merge failed", e);
             }
         };
 
@@ -943,10 +1249,22 @@ impl<W: Write> NonInteractiveRunner<W> {
             &mut |event| self.emit_event(event),
         );
 
+        let failed_count = post_merge.failed_count;
+        if let Some(package) = &post_merge.package_version {
+            tracing::info!("Found package version {}", package);
+            state.package_version = Some(package.clone());
+        }
+
         // Mark as completed
         let final_status = engine.determine_final_status(&state);
         if let Err(e) = state.mark_completed(final_status) {
-            return RunResult::error(ExitCode::GeneralError, e.to_string());
+            return error_result(e);
+        }
+        if let Err(e) = release_history::record_release(&state) {
+            tracing::warn!("Warning: Failed to record release history: {}", e);
+        }
+        if let Err(e) = state.save_run_snapshot() {
+            tracing::warn!("Warning: Failed to save run snapshot: {}", e);
         }
 
         // Build summary
@@ -964,10 +1282,24 @@ impl<W: Write> NonInteractiveRunner<W> {
             counts,
             items: Some(items),
             post_merge: Some(PostMergeSummary {
-                total_tasks: success_count + failed_count,
-                successful: success_count,
-                failed: failed_count,
-                tasks: None, // Individual task details not tracked at this level
+                total_tasks: post_merge.tasks.len(),
+                successful: post_merge.success_count,
+                failed: post_merge.failed_count,
+                tasks: Some(
+                    post_merge
+                        .tasks
+                        .iter()
+                        .map(|t| PostMergeTaskResult {
+                            task_type: t.task.task_type().to_string(),
+                            target_id: t.task.target_id(),
+                            status: t
+                                .result
+                                .as_ref()
+                                .map(post_merge_status)
+                                .unwrap_or(PostMergeStatus::Pending),
+                        })
+                        .collect(),
+                ),
             }),
         };
 
@@ -975,6 +1307,8 @@ impl<W: Write> NonInteractiveRunner<W> {
             tracing::warn!("Warning: Failed to write summary: {}", e);
         }
 
+        self.send_notifications(&state).await;
+
         if failed_count > 0 {
             RunResult::partial_success(format!("Completed with {} task failures", failed_count))
         } else {
@@ -982,1250 +1316,2651 @@ impl<W: Write> NonInteractiveRunner<W> {
         }
     }
 
-    // Helper methods
-
-    fn create_client(&self) -> Result<Arc<AzureDevOpsClient>> {
-        let client = AzureDevOpsClient::new(
-            self.config.organization.clone(),
-            self.config.project.clone(),
-            self.config.repository.clone(),
-            self.config.pat.clone(),
-        )?;
-        Ok(Arc::new(client))
-    }
-
-    fn create_engine(&self, client: Arc<AzureDevOpsClient>) -> MergeEngine {
-        MergeEngine::new(
-            client,
-            self.config.organization.clone(),
-            self.config.project.clone(),
-            self.config.repository.clone(),
-            self.config.dev_branch.clone(),
-            self.config.target_branch.clone(),
-            self.config.version.clone(),
-            self.config.tag_prefix.clone(),
-            self.config.work_item_state.clone(),
-            self.config.run_hooks,
-            self.config.local_repo.clone(),
-            self.config.hooks_config.clone(),
-            self.config.max_concurrent_network,
-            self.config.max_concurrent_processing,
-            self.config.since.clone(),
-        )
-    }
-
-    fn emit_event(&mut self, event: ProgressEvent) {
-        if let Err(e) = self.output.write_event(&event) {
-            tracing::warn!("Warning: Failed to write event: {}", e);
+    /// Posts the summary of a finished merge to the configured chat webhooks.
+    ///
+    /// A webhook that cannot be reached is reported but does not fail the run.
+    async fn send_notifications(&mut self, state: &MergeStateFile) {
+        let Some(config) = self.config.notifications.clone() else {
+            return;
+        };
+        let notification = MergeNotification::from_state(state, &config);
+        for (channel, webhook) in config.webhooks() {
+            let status = match notify::send(channel, webhook, &notification).await {
+                Ok(()) => PostMergeStatus::Success,
+                Err(e) => {
+                    tracing::warn!("Failed to notify {}: {:#}", channel, e);
+                    PostMergeStatus::Failed {
+                        error: format!("{:#}", e),
+                    }
+                }
+            };
+            self.emit_event(ProgressEvent::NotificationSent { channel, status });
         }
     }
 
-    fn emit_error_with_code(&mut self, message: &str, code: Option<&str>) {
-        let event = ProgressEvent::Error {
-            message: message.to_string(),
-            code: code.map(|c| c.to_string()),
+    /// Promotes a release candidate to the final release.
+    ///
+    /// Creates the `next-<version>` worktree on branch `patch/<target>-<version>`
+    /// starting from the RC worktree's HEAD, carries over the RC's cherry-pick
+    /// items, optionally adds newly selected PRs (`select_by_states`) that are
+    /// not part of the RC, and cherry-picks them. The new state file records
+    /// the RC it was promoted from; run `merge complete` afterwards to tag PRs
+    /// with the final version.
+    pub async fn promote(&mut self, rc_version: &str) -> RunResult {
+        tracing::info!(
+            "Promoting release candidate {} to {}",
+            rc_version,
+            self.config.version
+        );
+
+        let Some(local_repo) = self.config.local_repo.clone() else {
+            self.emit_error("Promoting a release candidate requires a local repository");
+            return RunResult::error(
+                ExitCode::GeneralError,
+                "Promoting a release candidate requires a local repository (--local-repo)",
+            );
         };
-        if let Err(e) = self.output.write_event(&event) {
-            tracing::warn!("Warning: Failed to write error: {}", e);
-        }
-    }
 
-    fn emit_error(&mut self, message: &str) {
-        self.emit_error_with_code(message, None);
-    }
+        // Load the release candidate state
+        let rc_repo = local_repo.join(format!("next-{}", rc_version));
+        let rc_state = match MergeStateFile::load_for_repo(&rc_repo) {
+            Ok(Some(state)) => state,
+            // A missing worktree cannot be resolved to a state path either
+            _ if !rc_repo.exists() => {
+                let msg = format!(
+                    "Release candidate {} not found at {}",
+                    rc_version,
+                    rc_repo.display()
+                );
+                self.emit_error_with_code(&msg, Some(StateError::NotFound.code()));
+                return RunResult::error(ExitCode::NoStateFile, msg);
+            }
+            Ok(None) => {
+                let msg = format!(
+                    "No state file found for release candidate {} at {}",
+                    rc_version,
+                    rc_repo.display()
+                );
+                self.emit_error_with_code(&msg, Some(StateError::NotFound.code()));
+                return RunResult::error(ExitCode::NoStateFile, msg);
+            }
+            Err(e) => {
+                return self.fail_with_context("Failed to load release candidate state", e);
+            }
+        };
 
-    fn find_repo_path(&self, provided: Option<&Path>) -> Result<PathBuf> {
-        if let Some(path) = provided {
-            return Ok(path.to_path_buf());
+        if !matches!(
+            rc_state.phase,
+            MergePhase::ReadyForCompletion | MergePhase::Completed
+        ) {
+            let msg = format!(
+                "Release candidate {} is in phase '{}'; finish it before promoting",
+                rc_version, rc_state.phase
+            );
+            let error = StateError::InvalidPhase {
+                operation: "promote".to_string(),
+                phase: rc_state.phase.to_string(),
+            };
+            self.emit_error_with_code(&msg, Some(error.code()));
+            return RunResult::error(ExitCode::InvalidPhase, msg);
         }
 
-        // Try to find repo from current directory
-        let current_dir = std::env::current_dir().context("Failed to get current directory")?;
+        // Hold the lock until the promoted release is processed
+        let _lock = match acquire_lock(&self.config_lock_key()) {
+            Ok(Some(lock)) => lock,
+            Ok(None) => {
+                self.emit_state_error(&StateError::Locked);
+                return RunResult::error(
+                    ExitCode::Locked,
+                    "Another merge operation is in progress",
+                );
+            }
+            Err(e) => return self.fail_with_context("Failed to acquire lock", e),
+        };
 
-        // Check if we're in a git repo
-        let output = std::process::Command::new("git")
-            .args(["rev-parse", "--show-toplevel"])
-            .current_dir(&current_dir)
-            .output()
-            .context("Failed to run git")?;
+        let client = match self.create_client() {
+            Ok(c) => c,
+            Err(e) => {
+                return RunResult::error(
+                    error::exit_code(&e),
+                    format!("Failed to create API client: {}", e),
+                );
+            }
+        };
+        let mut engine = self.create_engine(client);
 
-        if output.status.success() {
-            let path = String::from_utf8_lossy(&output.stdout);
-            return Ok(PathBuf::from(path.trim()));
+        // Optionally add PRs that were not part of the release candidate
+        let mut new_prs = Vec::new();
+        if let Some(states) = self.config.select_by_states.clone() {
+            let mut prs = match engine.load_pull_requests().await {
+                Ok(prs) => prs,
+                Err(e) => {
+                    return self.fail_with_context("Failed to load PRs", e);
+                }
+            };
+            if let Some(result) = self.check_strict_states(&prs) {
+                return result;
+            }
+            engine.select_prs_by_states(&mut prs, &states);
+            self.apply_release_gate(&mut prs);
+            self.apply_build_checks(&engine, &mut prs).await;
+            for pr in &mut prs {
+                if rc_state
+                    .cherry_pick_items
+                    .iter()
+                    .any(|item| item.pr_id == pr.pr.id)
+                {
+                    pr.selected = false;
+                }
+            }
+            new_prs = prs;
         }
+        let added = new_prs.iter().filter(|pr| pr.selected).count();
+        tracing::info!("{} new PRs selected for the final release", added);
 
-        bail!("Not in a git repository. Specify --repo path.")
-    }
+        // Create the release worktree from the RC's HEAD
+        let start_point = match git::head_commit(&rc_repo) {
+            Ok(commit) => commit,
+            Err(e) => {
+                return self.fail_with_context("Failed to read release candidate HEAD", e);
+            }
+        };
+        let branch_name = format!(
+            "patch/{}-{}",
+            self.config.target_branch, self.config.version
+        );
+        let repo_path = match git::create_worktree_from_ref(
+            &local_repo,
+            &self.config.version,
+            &branch_name,
+            &start_point,
+        ) {
+            Ok(path) => path,
+            Err(e) => {
+                return self.fail_with_context("Failed to set up repository", e);
+            }
+        };
 
-    fn check_conflicts_resolved(&self, repo_path: &Path) -> bool {
-        // Delegate to the git module's implementation which uses `git ls-files -u`
-        git::check_conflicts_resolved(repo_path).unwrap_or(false)
-    }
-}
+        let carried_items = rc_state.cherry_pick_items.clone();
+        let lineage = ReleaseLineage::promoted(
+            self.config.version.clone(),
+            rc_version,
+            rc_repo,
+            carried_items.iter().map(|item| item.pr_id).collect(),
+        );
+        let state_path = match engine.create_promoted_state_file(
+            repo_path,
+            Some(local_repo),
+            carried_items,
+            &new_prs,
+            lineage,
+        ) {
+            Ok(path) => path,
+            Err(e) => {
+                return self.fail_with_context("Failed to create state file", e);
+            }
+        };
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::models::OutputFormat;
+        let total_prs = engine
+            .state_manager()
+            .state_file()
+            .map(|s| s.cherry_pick_items.len())
+            .unwrap_or(0);
+        self.emit_event(ProgressEvent::Start {
+            total_prs,
+            version: self.config.version.clone(),
+            target_branch: self.config.target_branch.clone(),
+            state_file_path: Some(state_path.clone()),
+        });
 
-    fn create_test_config() -> MergeRunnerConfig {
-        MergeRunnerConfig {
-            organization: "test-org".to_string(),
-            project: "test-project".to_string(),
-            repository: "test-repo".to_string(),
-            pat: "test-pat".to_string(),
-            dev_branch: "dev".to_string(),
-            target_branch: "main".to_string(),
-            version: "v1.0.0".to_string(),
-            tag_prefix: "merged-".to_string(),
-            work_item_state: "Done".to_string(),
-            select_by_states: None,
-            local_repo: None,
-            run_hooks: false,
-            output_format: OutputFormat::Text,
-            quiet: false,
-            hooks_config: None,
-            max_concurrent_network: 100,
-            max_concurrent_processing: 10,
-            since: None,
-        }
+        self.process_and_finish(&mut engine, state_path)
     }
 
-    /// # Runner Creation
-    ///
-    /// Verifies that the runner can be created with a config.
-    ///
-    /// ## Test Scenario
-    /// - Creates a runner with test config
-    ///
-    /// ## Expected Outcome
-    /// - Runner is created without error
-    #[test]
-    fn test_runner_creation() {
-        let config = create_test_config();
-        let mut buffer = Vec::new();
-        let _runner = NonInteractiveRunner::with_writer(config, &mut buffer);
-        // Runner created successfully
-    }
+    // Helper methods
 
-    /// # Runner With Custom Writer
-    ///
-    /// Verifies that the runner can use a custom writer.
-    ///
-    /// ## Test Scenario
-    /// - Creates runner with Vec<u8> writer
-    /// - Emits an event
+    /// Processes pending cherry-picks and builds the run result.
     ///
-    /// ## Expected Outcome
-    /// - Event is written to the buffer
-    #[test]
-    fn test_runner_with_custom_writer() {
-        let config = create_test_config();
+    /// Shared by [`run`](Self::run) and [`promote`](Self::promote) once the
+    /// state file has been created. With a conflict timeout, conflicts are
+    /// settled by [`Self::settle_conflict`] and processing goes on.
+    fn process_and_finish(&mut self, engine: &mut MergeEngine, state_path: PathBuf) -> RunResult {
+        // Process cherry-picks using internal state manager
+        let process_result = loop {
+            let result = engine.process_cherry_picks(|event| {
+                self.emit_event(event);
+            });
+            let (Some(policy), CherryPickProcessResult::Conflict(conflict)) =
+                (self.config.conflict_timeout, &result)
+            else {
+                break result;
+            };
+
+            // The paused merge is on disk while a human may be looking at it
+            if let Err(e) = engine.state_manager_mut().save() {
+                return self.fail_with_context("Failed to save state", e);
+            }
+            self.write_conflict(conflict, engine.state_manager().state_file());
+            match self.settle_conflict(engine, conflict, policy) {
+                Ok(ConflictDecision::Aborted) => {
+                    if let Err(e) = engine.state_manager_mut().save() {
+                        return self.fail_with_context("Failed to save state", e);
+                    }
+                    if let Some(state) = engine.state_manager().state_file() {
+                        self.write_reports(state);
+                    }
+                    self.flush_output();
+                    return RunResult::error(
+                        ExitCode::Conflict,
+                        format!(
+                            "Merge aborted: conflict in PR #{} not resolved in time",
+                            conflict.pr_id
+                        ),
+                    )
+                    .with_state_file(state_path);
+                }
+                Ok(_) => self.output.clear_conflict(),
+                Err(e) => {
+                    let _ = engine.state_manager_mut().save();
+                    return self.fail_with_context("Failed to settle conflict", e);
+                }
+            }
+        };
+
+        // Save state after cherry-picks
+        if let Err(e) = engine.state_manager_mut().save() {
+            return self.fail_with_context("Failed to save state", e);
+        }
+        if let Some(state) = engine.state_manager().state_file() {
+            self.write_reports(state);
+        }
+
+        // Handle process result
+        match process_result {
+            CherryPickProcessResult::Conflict(conflict) => {
+                self.write_conflict(&conflict, engine.state_manager().state_file());
+                self.flush_output();
+                return RunResult::conflict(state_path);
+            }
+            CherryPickProcessResult::HookAbort { command, error, .. } => {
+                self.emit_error(&format!("Hook aborted: {} - {}", command, error));
+                return RunResult::error(
+                    ExitCode::HookFailed,
+                    format!("Hook '{}' failed: {}", command, error),
+                )
+                .with_state_file(state_path);
+            }
+            CherryPickProcessResult::Complete => {
+                // Continue to completion
+            }
+        }
+
+        // All cherry-picks complete - get counts from state manager
+        let counts = engine
+            .state_manager()
+            .state_file()
+            .map(|state| engine.create_summary_counts(state))
+            .unwrap_or_else(|| SummaryCounts::new(0, 0, 0, 0));
+
+        self.emit_event(ProgressEvent::Complete {
+            successful: counts.successful,
+            failed: counts.failed,
+            skipped: counts.skipped,
+        });
+
+        // Determine result
+        if counts.failed > 0 {
+            RunResult::partial_success(format!(
+                "{} successful, {} failed, {} skipped",
+                counts.successful, counts.failed, counts.skipped
+            ))
+            .with_state_file(state_path)
+        } else {
+            RunResult::success().with_state_file(state_path)
+        }
+    }
+
+    /// Selects PRs by the configured work item states, selection file,
+    /// release gate and build checks.
+    ///
+    /// Returns the failed result when the selection cannot be made.
+    async fn select_pull_requests(
+        &mut self,
+        engine: &MergeEngine,
+        prs: &mut [PullRequestWithWorkItems],
+    ) -> Option<RunResult> {
+        // Select PRs by work item states if configured
+        if let Some(ref states) = self.config.select_by_states {
+            tracing::info!("Selecting PRs by work item states: {:?}", states);
+            let count = engine.select_prs_by_states(prs, states);
+            tracing::debug!("{} PRs matched the specified states", count);
+            if count == 0 {
+                tracing::warn!("No PRs matched the specified work item states");
+                self.emit_error("No PRs matched the specified work item states");
+                return Some(RunResult::error(
+                    ExitCode::NoPRsMatched,
+                    "No PRs matched the specified work item states",
+                ));
+            }
+        } else if self.config.select_file.is_none() {
+            tracing::debug!("Selecting all PRs with merge commits");
+            // Select all PRs with merge commits
+            for pr in prs.iter_mut() {
+                pr.selected = pr.pr.last_merge_commit.is_some();
+            }
+        }
+        if let Some(path) = self.config.select_file.clone() {
+            tracing::info!("Selecting PRs listed in {}", path.display());
+            let file = match SelectionFile::load(&path) {
+                Ok(file) => file,
+                Err(e) => return Some(self.fail_with_context("Failed to load selection file", e)),
+            };
+            let import = file.apply(prs);
+            self.emit_event(ProgressEvent::SelectionImported {
+                path,
+                selected: import.selected,
+                not_found: import.not_found.iter().map(ToString::to_string).collect(),
+            });
+        }
+        self.apply_release_gate(prs);
+        self.apply_build_checks(engine, prs).await;
+        None
+    }
+
+    /// Holds a conflict open for a human, then applies the timeout action.
+    ///
+    /// Unless the merge is aborted, the conflicting item is finished (picked
+    /// or skipped) and the state is ready for the next cherry-pick.
+    fn settle_conflict(
+        &mut self,
+        engine: &mut MergeEngine,
+        conflict: &ConflictInfo,
+        policy: ConflictTimeout,
+    ) -> Result<ConflictDecision> {
+        let pr_id = conflict.pr_id;
+        let repo_path = &conflict.repo_path;
+        self.emit_event(ProgressEvent::ConflictWaiting {
+            pr_id,
+            wait_seconds: policy.wait.as_secs(),
+            action: policy.action,
+        });
+
+        let (decision, files) = if policy.wait_for_resolution(repo_path) {
+            (ConflictDecision::Resolved, Vec::new())
+        } else if let Some(side) = policy.action.side() {
+            let files = keep_side(repo_path, side)?;
+            (ConflictDecision::from(policy.action), files)
+        } else {
+            (ConflictDecision::from(policy.action), Vec::new())
+        };
+
+        if decision == ConflictDecision::Aborted {
+            self.emit_event(ProgressEvent::ConflictSettled {
+                pr_id,
+                decision,
+                files,
+            });
+            if let Some(state) = engine.state_manager().state_file() {
+                engine.cleanup(state)?;
+            }
+            let state = engine
+                .state_manager_mut()
+                .state_file_mut()
+                .context("No state file")?;
+            state.phase = MergePhase::Aborted;
+            state.final_status = Some(MergeStatus::Aborted);
+            self.emit_event(ProgressEvent::Aborted {
+                success: true,
+                message: Some(format!(
+                    "Conflict in PR #{} not resolved within {}s",
+                    pr_id,
+                    policy.wait.as_secs()
+                )),
+            });
+            return Ok(decision);
+        }
+
+        let state = engine
+            .state_manager_mut()
+            .state_file_mut()
+            .context("No state file")?;
+        let mut squash_error = None;
+        if decision == ConflictDecision::Skipped {
+            git::abort_cherry_pick(repo_path)?;
+            state.cherry_pick_items[state.current_index].status = StateItemStatus::Skipped;
+        } else {
+            // Only a human's resolutions are worth re-applying later
+            if decision == ConflictDecision::Resolved
+                && let Err(e) = saved_resolutions(state)
+                    .and_then(|saved| saved.save(repo_path, pr_id, &conflict.conflicted_files))
+            {
+                tracing::warn!("Failed to save conflict resolutions: {:#}", e);
+            }
+            let base = git::head_commit(repo_path).ok();
+            git::continue_cherry_pick(repo_path, state.sign_commits)?;
+            let squash_message = state.squash_message.clone();
+            let sign = state.sign_commits;
+            squash_error = finish_current_item(state, base.as_deref(), squash_message, sign);
+        }
+        state.current_index += 1;
+        state.phase = MergePhase::CherryPicking;
+        state.conflicted_files = None;
+
+        self.emit_event(ProgressEvent::ConflictSettled {
+            pr_id,
+            decision,
+            files,
+        });
+        self.emit_event(if decision == ConflictDecision::Skipped {
+            ProgressEvent::CherryPickSkipped {
+                pr_id,
+                reason: Some("Conflict not resolved in time".to_string()),
+            }
+        } else if let Some(error) = squash_error {
+            ProgressEvent::CherryPickFailed { pr_id, error }
+        } else {
+            ProgressEvent::CherryPickSuccess {
+                pr_id,
+                commit_id: conflict.commit_id.clone(),
+                mainline_parent: None,
+            }
+        });
+        Ok(decision)
+    }
+
+    fn create_client(&self) -> Result<Arc<AzureDevOpsClient>> {
+        let client = AzureDevOpsClient::new_with_api_version(
+            self.config.organization.clone(),
+            self.config.project.clone(),
+            self.config.repository.clone(),
+            self.config.pat.clone().into(),
+            self.config.api_version.clone(),
+        )?
+        .with_max_concurrent_requests(self.config.max_concurrent_network)
+        .with_disk_cache();
+        let client = match &self.config.write_pat {
+            Some(write_pat) => client.with_write_pat(write_pat.clone().into()),
+            None => client,
+        }
+        .with_extra_work_item_fields(
+            self.config
+                .release_gate
+                .iter()
+                .map(|gate| gate.field.clone())
+                .collect(),
+        )
+        .with_since_field(self.config.since_field)
+        .with_clock(self.config.clock.clone());
+        Ok(Arc::new(client))
+    }
+
+    /// Cherry-picks the items left in `state`, saves it and reports the outcome.
+    ///
+    /// Shared by `continue` and `recover --resume`; the caller holds the lock.
+    fn process_remaining(&mut self, state: MergeStateFile) -> RunResult {
+        // Create the engine
+        let client = match self.create_client() {
+            Ok(c) => c,
+            Err(e) => {
+                return error_result(e);
+            }
+        };
+        let mut engine = self.create_engine(client);
+
+        // Set the loaded state file on the engine's state manager
+        // The lock guard remains local to ensure it stays alive for the operation
+        engine.state_manager_mut().set_state_file(state);
+
+        // Continue processing using internal state manager
+        let process_result = engine.process_cherry_picks(|event| {
+            self.emit_event(event);
+        });
+
+        // Save state via state manager
+        let state_path = match engine.state_manager_mut().save() {
+            Ok(Some(path)) => path,
+            Ok(None) => {
+                self.emit_error("No state file to save");
+                return RunResult::error(ExitCode::GeneralError, "No state file to save");
+            }
+            Err(e) => {
+                return error_result(e);
+            }
+        };
+        if let Some(state) = engine.state_manager().state_file() {
+            self.write_reports(state);
+        }
+
+        // Handle process result
+        match process_result {
+            CherryPickProcessResult::Conflict(conflict) => {
+                self.write_conflict(&conflict, engine.state_manager().state_file());
+                if let Some(state) = engine.state_manager().state_file() {
+                    self.write_state_summary(&engine, state, SummaryResult::Conflict);
+                }
+                return RunResult::conflict(state_path);
+            }
+            CherryPickProcessResult::HookAbort { command, error, .. } => {
+                self.emit_error(&format!("Hook aborted: {} - {}", command, error));
+                return RunResult::error(
+                    ExitCode::HookFailed,
+                    format!("Hook '{}' failed: {}", command, error),
+                )
+                .with_state_file(state_path);
+            }
+            CherryPickProcessResult::Complete => {
+                // Continue to completion
+            }
+        }
+
+        // Get counts from state manager
+        let counts = engine
+            .state_manager()
+            .state_file()
+            .map(|state| engine.create_summary_counts(state))
+            .unwrap_or_else(|| SummaryCounts::new(0, 0, 0, 0));
+
+        self.emit_event(ProgressEvent::Complete {
+            successful: counts.successful,
+            failed: counts.failed,
+            skipped: counts.skipped,
+        });
+        if let Some(state) = engine.state_manager().state_file() {
+            let result = if counts.failed > 0 {
+                SummaryResult::PartialSuccess
+            } else {
+                SummaryResult::Success
+            };
+            self.write_state_summary(&engine, state, result);
+        }
+
+        if counts.failed > 0 {
+            RunResult::partial_success("Completed with some failures").with_state_file(state_path)
+        } else {
+            RunResult::success().with_state_file(state_path)
+        }
+    }
+
+    /// Writes the summary of a merge after `continue` or `abort`, listing
+    /// every PR with its status so scripts see where the merge stands.
+    fn write_state_summary(
+        &mut self,
+        engine: &MergeEngine,
+        state: &MergeStateFile,
+        result: SummaryResult,
+    ) {
+        let summary = SummaryInfo {
+            result,
+            version: state.merge_version.clone(),
+            target_branch: state.target_branch.clone(),
+            counts: engine.create_summary_counts(state),
+            items: Some(engine.create_summary_items(state)),
+            post_merge: None,
+        };
+        if let Err(e) = self.output.write_summary(&summary) {
+            tracing::warn!("Failed to write summary: {}", e);
+        }
+    }
+
+    /// Writes the configured `--report` files for `state`, and removes the
+    /// conflict report once the merge is past its conflict.
+    ///
+    /// A report that cannot be written is logged but does not fail the run.
+    fn write_reports(&self, state: &MergeStateFile) {
+        for report in &self.config.reports {
+            if let Err(e) = report.write(state) {
+                tracing::warn!("Failed to write report: {:#}", e);
+            }
+        }
+        if state.phase != MergePhase::AwaitingConflictResolution
+            && let Err(e) = ConflictReport::remove_for_repo(&state.repo_path)
+        {
+            tracing::warn!("{:#}", e);
+        }
+    }
+
+    /// Writes a conflict with its conflict report, which is also saved next
+    /// to the state file.
+    ///
+    /// A report that cannot be saved is logged but does not fail the run.
+    fn write_conflict(&mut self, conflict: &ConflictInfo, state: Option<&MergeStateFile>) {
+        let mut conflict = conflict.clone();
+        if let Some(state) = state
+            && let Some(report) = ConflictReport::collect(&state.repo_path, state)
+        {
+            let path = ConflictReport::path_for_repo(&state.repo_path)
+                .and_then(|path| report.write(&path).map(|()| path))
+                .inspect_err(|e| tracing::warn!("Failed to save conflict report: {:#}", e))
+                .ok();
+            conflict = conflict.with_report(report, path);
+        }
+        if let Err(e) = self.output.write_conflict(&conflict) {
+            tracing::warn!("Failed to write conflict info: {}", e);
+        }
+    }
+
+    /// Writes out buffered output before the run stops without a summary.
+    fn flush_output(&mut self) {
+        if let Err(e) = self.output.flush() {
+            tracing::warn!("Failed to write output: {}", e);
+        }
+    }
+
+    /// Lists the files of the paused cherry-pick that are still conflicted.
+    fn write_unresolved_conflicts(&mut self, state: &MergeStateFile) {
+        let Some(item) = state.cherry_pick_items.get(state.current_index) else {
+            return;
+        };
+        let files = git::unresolved_files(&state.repo_path)
+            .unwrap_or_else(|_| state.conflicted_files.clone().unwrap_or_default());
+        let conflict = ConflictInfo::new(
+            item.pr_id,
+            item.pr_title.clone(),
+            item.commit_id.clone(),
+            files,
+            state.repo_path.clone(),
+        );
+        if let Err(e) = self.output.write_conflict(&conflict) {
+            tracing::warn!("Failed to write conflict info: {}", e);
+        }
+    }
+
+    /// Analyzes dependencies between the PRs and emits the findings.
+    ///
+    /// A failed analysis is non-fatal and only logged.
+    fn analyze_dependencies(
+        &mut self,
+        engine: &MergeEngine,
+        prs: &[PullRequestWithWorkItems],
+        repo_path: &Path,
+        selected_count: usize,
+    ) -> Option<DependencyAnalysisResult> {
+        tracing::info!("Starting dependency analysis for {} PRs", selected_count);
+        self.emit_event(ProgressEvent::DependencyAnalysisStart {
+            pr_count: selected_count,
+        });
+
+        match engine.analyze_dependencies(prs, repo_path) {
+            Ok(analysis_result) => {
+                #[cfg(feature = "dashboard")]
+                if let Some(dashboard) = &self.dashboard {
+                    dashboard.set_dependency_graph(&analysis_result.graph);
+                }
+
+                // Emit summary
+                let summary = analysis_result.graph.summary();
+                self.emit_event(ProgressEvent::DependencyAnalysisComplete {
+                    independent: summary.independent_relationships,
+                    partial: summary.partial_relationships,
+                    dependent: summary.dependent_relationships,
+                });
+
+                // Emit warnings
+                for warning in &analysis_result.warnings {
+                    if let DependencyWarning::UnselectedDependency {
+                        selected_pr_id,
+                        selected_pr_title,
+                        unselected_pr_id,
+                        unselected_pr_title,
+                        category,
+                    } = warning
+                    {
+                        self.emit_event(ProgressEvent::DependencyWarning {
+                            selected_pr_id: *selected_pr_id,
+                            selected_pr_title: selected_pr_title.clone(),
+                            unselected_pr_id: *unselected_pr_id,
+                            unselected_pr_title: unselected_pr_title.clone(),
+                            is_critical: warning.is_critical(),
+                            shared_files: category.shared_files().to_vec(),
+                        });
+                    }
+                }
+                Some(analysis_result)
+            }
+            Err(e) => {
+                tracing::warn!("Warning: Dependency analysis failed: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Simulates the merge of the selected PRs and reports what would happen.
+    ///
+    /// The picks are simulated in the local repository, or in a throwaway
+    /// clone without one. No worktree, branch, tag or state file is created,
+    /// no hooks run, and PRs and work items are left untouched.
+    fn dry_run(
+        &mut self,
+        engine: &MergeEngine,
+        prs: &[PullRequestWithWorkItems],
+        selected_count: usize,
+    ) -> RunResult {
+        tracing::info!("Dry run: simulating the merge of {} PRs", selected_count);
+        let target_branch = self.config.target_branch.clone();
+
+        // The clone is removed when the dry run ends
+        let mut _clone_dir = None;
+        let repo_path = match &self.config.local_repo {
+            Some(path) => path.clone(),
+            None => {
+                let clone_dir = match tempfile::TempDir::new() {
+                    Ok(dir) => dir,
+                    Err(e) => return self.fail_with_context("Failed to create clone directory", e),
+                };
+                let path = clone_dir.path().join("repo");
+                let url = UrlBuilder::new(
+                    &self.config.organization,
+                    &self.config.project,
+                    &self.config.repository,
+                )
+                .repository_url();
+                if let Err(e) = clone_for_dry_run(&url, &target_branch, &path, prs) {
+                    return self.fail_with_context("Failed to clone repository", e);
+                }
+                _clone_dir = Some(clone_dir);
+                path
+            }
+        };
+
+        // Prefer the remote-tracking branch, which is what a merge branches from
+        let base = match git::fetch_remote_tracking_ref(&repo_path, &target_branch) {
+            Ok(Some(remote_ref)) => remote_ref,
+            Ok(None) => target_branch.clone(),
+            Err(e) => return self.fail_with_context("Failed to resolve the target branch", e),
+        };
+
+        let analysis = self.analyze_dependencies(engine, prs, &repo_path, selected_count);
+        let mut prs = prs.to_vec();
+        engine.order_pull_requests(&mut prs, analysis.as_ref().map(|result| &result.graph));
+        let dependencies = analysis.map(|result| {
+            let summary = result.graph.summary();
+            DryRunDependencies {
+                independent: summary.independent_relationships,
+                partial: summary.partial_relationships,
+                dependent: summary.dependent_relationships,
+                warnings: result.warnings.iter().map(|w| w.message()).collect(),
+            }
+        });
+
+        let merge = match simulate_merge(&repo_path, &base, &prs, &self.config.binary_conflicts) {
+            Ok(merge) => merge,
+            Err(e) => return self.fail_with_context("Failed to simulate the merge", e),
+        };
+
+        let report = DryRunReport {
+            version: self.config.version.clone(),
+            target_branch,
+            base,
+            label: format!("{}{}", self.config.tag_prefix, self.config.version),
+            work_item_state: self.config.work_item_state.clone(),
+            dependencies,
+            items: merge.items,
+            skipped_pr_ids: merge.skipped_pr_ids,
+        };
+        if let Err(e) = self.output.write_dry_run(&report) {
+            tracing::warn!("Warning: Failed to write dry run report: {}", e);
+        }
+
+        let conflicts = report
+            .items
+            .iter()
+            .filter(|item| item.outcome == DryRunOutcome::Conflict)
+            .count();
+        let stopping = report.stopping().count();
+        if conflicts > 0 {
+            RunResult::error(
+                ExitCode::Conflict,
+                format!("Dry run predicts conflicts in {} PR(s)", conflicts),
+            )
+        } else if stopping > 0 {
+            RunResult::partial_success(format!("Dry run could not simulate {} PR(s)", stopping))
+        } else {
+            RunResult::success_with_message("Dry run predicts a clean merge")
+        }
+    }
+
+    fn write_recovery(&mut self, info: &RecoveryInfo) {
+        if let Err(e) = self.output.write_recovery(info) {
+            tracing::warn!("Warning: Failed to write recovery report: {}", e);
+        }
+    }
+
+    fn create_engine(&self, client: Arc<AzureDevOpsClient>) -> MergeEngine {
+        MergeEngine::new(
+            client,
+            self.config.organization.clone(),
+            self.config.project.clone(),
+            self.config.repository.clone(),
+            self.config.dev_branch.clone(),
+            self.config.target_branch.clone(),
+            self.config.version.clone(),
+            self.config.tag_prefix.clone(),
+            self.config.work_item_state.clone(),
+            self.config.run_hooks,
+            self.config.local_repo.clone(),
+            self.config.hooks_config.clone(),
+            self.config.max_concurrent_network,
+            self.config.max_concurrent_processing,
+            self.config.since.clone(),
+        )
+        .with_work_item_comment(self.config.work_item_comment.clone())
+        .with_artifacts_feed(self.config.artifacts_feed.clone())
+        .with_work_item_fields(self.config.work_item_fields.clone())
+        .with_binary_conflicts(self.config.binary_conflicts.clone())
+        .with_cherry_pick_order(self.config.cherry_pick_order)
+        .with_sign_commits(self.config.sign_commits)
+        .with_squash_message(self.config.squash_message.clone())
+        .with_mainline_fallback(self.config.mainline_fallback)
+        .with_fan_out(self.config.fan_out.clone())
+        .with_release_tag(
+            self.config.release_tag,
+            self.config.url_templates.clone(),
+            self.config.release_notes_locale.clone(),
+        )
+        .with_clock(self.config.clock.clone())
+    }
+
+    /// Loads and validates the state file of `repo_path`, stamped by the
+    /// runner's clock.
+    fn load_state(&self, repo_path: &Path) -> Result<Option<MergeStateFile>> {
+        let mut state = MergeStateFile::load_and_validate_for_repo(repo_path)?;
+        if let Some(state) = &mut state {
+            state.set_clock(self.config.clock.clone());
+        }
+        Ok(state)
+    }
+
+    /// Starts the web dashboard on `addr`.
+    #[cfg(feature = "dashboard")]
+    fn start_dashboard(&mut self, addr: SocketAddr) -> anyhow::Result<()> {
+        let dashboard = Dashboard::start(addr)?;
+        tracing::info!("Serving dashboard on http://{}/", dashboard.addr());
+        self.dashboard = Some(dashboard);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "dashboard"))]
+    fn start_dashboard(&mut self, _addr: SocketAddr) -> anyhow::Result<()> {
+        anyhow::bail!("--serve needs mergers built with the `dashboard` feature")
+    }
+
+    fn emit_event(&mut self, event: ProgressEvent) {
+        if let Some(server) = &self.status_server {
+            server.record(&event);
+        }
+        #[cfg(feature = "dashboard")]
+        if let Some(dashboard) = &self.dashboard {
+            dashboard.record(&event);
+        }
+        if let Err(e) = self.output.write_event(&event) {
+            tracing::warn!("Warning: Failed to write event: {}", e);
+        }
+    }
+
+    fn emit_error_with_code(&mut self, message: &str, code: Option<&str>) {
+        let event = ProgressEvent::Error {
+            message: message.to_string(),
+            code: code.map(|c| c.to_string()),
+        };
+        if let Err(e) = self.output.write_event(&event) {
+            tracing::warn!("Warning: Failed to write error: {}", e);
+        }
+    }
+
+    fn emit_error(&mut self, message: &str) {
+        self.emit_error_with_code(message, None);
+    }
+
+    fn emit_state_error(&mut self, error: &StateError) {
+        self.emit_error_with_code(&error.to_string(), Some(error.code()));
+    }
+
+    /// Emits a classified error event and returns the matching failed result.
+    fn fail(&mut self, error: impl Into<anyhow::Error>) -> RunResult {
+        let error = error.into();
+        self.emit_error_with_code(&error.to_string(), Some(error::error_code(&error)));
+        error_result(error)
+    }
+
+    /// Like [`Self::fail`], prefixing the emitted message with `context`.
+    fn fail_with_context(&mut self, context: &str, error: impl Into<anyhow::Error>) -> RunResult {
+        let error = error.into();
+        self.emit_error_with_code(
+            &format!("{}: {}", context, error),
+            Some(error::error_code(&error)),
+        );
+        error_result(error)
+    }
+
+    /// Fails the run if strict state checking finds work items in unknown states.
+    ///
+    /// The target `work_item_state` always counts as known.
+    fn check_strict_states(&mut self, prs: &[PullRequestWithWorkItems]) -> Option<RunResult> {
+        let mut known_states = self.config.strict_states.clone()?;
+        known_states.push(self.config.work_item_state.clone());
+
+        let unknown = find_unknown_work_item_states(prs, &known_states);
+        if unknown.is_empty() {
+            return None;
+        }
+
+        let error = ConfigError::UnknownWorkItemStates {
+            states: describe_unknown_states(&unknown),
+        };
+        tracing::error!("{}", error);
+        self.emit_error_with_code(&error.to_string(), Some(error.code()));
+        Some(RunResult::error(ExitCode::ConfigError, error.to_string()))
+    }
+
+    /// Refuses selections above the hard cap unless `--force` was given.
+    fn check_selection_cap(&mut self, selected_count: usize) -> Option<RunResult> {
+        let cap = self.config.selection_hard_cap?;
+        if self.config.force || selected_count <= cap {
+            return None;
+        }
+
+        let message = format!(
+            "{} PRs selected, more than selection_hard_cap ({}); use --force to merge them anyway",
+            selected_count, cap
+        );
+        tracing::warn!("{}", message);
+        self.emit_error(&message);
+        Some(RunResult::error(ExitCode::NoPRsMatched, message))
+    }
+
+    /// Deselects PRs whose work items fail the configured release gate.
+    fn apply_release_gate(&self, prs: &mut [PullRequestWithWorkItems]) {
+        let Some(ref gate) = self.config.release_gate else {
+            return;
+        };
+
+        for pr in prs.iter_mut().filter(|pr| pr.selected) {
+            let failing = gate.failing_work_items(pr);
+            if !failing.is_empty() {
+                tracing::warn!(
+                    "Skipping PR #{}: work items {:?} fail release gate '{}'",
+                    pr.pr.id,
+                    failing,
+                    gate
+                );
+                pr.selected = false;
+            }
+        }
+    }
+
+    /// Checks the builds and branch policies of the selected PRs, deselecting
+    /// failing PRs when green builds are required.
+    async fn apply_build_checks(&self, engine: &MergeEngine, prs: &mut [PullRequestWithWorkItems]) {
+        let mode = self.config.build_checks;
+        if !mode.is_enabled() {
+            return;
+        }
+
+        tracing::info!("Checking builds and branch policies of selected PRs...");
+        let checks = engine.check_builds(prs).await;
+        for pr in prs.iter_mut().filter(|pr| pr.selected) {
+            let Some(failures) = checks.failures(pr.pr.id) else {
+                continue;
+            };
+            if mode.excludes_failing() {
+                tracing::warn!(
+                    "Skipping PR #{}: green build required ({})",
+                    pr.pr.id,
+                    failures.join(", ")
+                );
+                pr.selected = false;
+            } else {
+                tracing::warn!(
+                    "PR #{} failed build checks: {}",
+                    pr.pr.id,
+                    failures.join(", ")
+                );
+            }
+        }
+    }
+
+    /// Returns the key of the lock protecting merges into the configured target.
+    fn config_lock_key(&self) -> LockKey {
+        LockKey::for_repository(
+            &self.config.organization,
+            &self.config.project,
+            &self.config.repository,
+            &self.config.target_branch,
+        )
+    }
+
+    /// Returns the key of the lock protecting the merge recorded in the state
+    /// file of `repo_path`, falling back to the configured target.
+    fn state_lock_key(&self, repo_path: &Path) -> LockKey {
+        match MergeStateFile::load_for_repo(repo_path) {
+            Ok(Some(state)) => state.lock_key(),
+            _ => self.config_lock_key(),
+        }
+    }
+
+    fn find_repo_path(&self, provided: Option<&Path>) -> Result<PathBuf> {
+        if let Some(path) = provided {
+            return Ok(path.to_path_buf());
+        }
+
+        // Try to find repo from current directory
+        let current_dir = std::env::current_dir().context("Failed to get current directory")?;
+
+        // Check if we're in a git repo
+        let output = std::process::Command::new("git")
+            .args(["rev-parse", "--show-toplevel"])
+            .current_dir(&current_dir)
+            .output()
+            .context("Failed to run git")?;
+
+        if output.status.success() {
+            let path = String::from_utf8_lossy(&output.stdout);
+            return Ok(PathBuf::from(path.trim()));
+        }
+
+        bail!("Not in a git repository. Specify --repo path.")
+    }
+
+    fn check_conflicts_resolved(&self, repo_path: &Path) -> bool {
+        self.git
+            .check_conflicts_resolved(repo_path)
+            .unwrap_or(false)
+    }
+}
+
+/// Returns the conflict resolutions saved for the repository of a merge.
+fn saved_resolutions(state: &MergeStateFile) -> Result<SavedResolutions> {
+    SavedResolutions::for_repository(&state.organization, &state.project, &state.repository)
+}
+
+/// Marks the current item of a paused merge as picked once its commit was
+/// finalized on top of `base`, squashing the PR's commits in squash mode.
+///
+/// Returns the error of a failed squash, which leaves the item failed.
+fn finish_current_item(
+    state: &mut MergeStateFile,
+    base: Option<&str>,
+    squash_message: Option<String>,
+    sign: bool,
+) -> Option<String> {
+    let repo_path = state.repo_path.clone();
+    let item = &mut state.cherry_pick_items[state.current_index];
+    let operation = CherryPickOperation::new(CherryPickConfig {
+        sign_commits: sign,
+        squash_message,
+        ..Default::default()
+    });
+    let outcome = match base {
+        Some(base) => operation.squash_pr(
+            &repo_path,
+            base,
+            item.pr_id,
+            &item.pr_title,
+            &item.work_item_ids,
+        ),
+        None => CherryPickOutcome::Success,
+    };
+    if let CherryPickOutcome::Failed { message } = outcome {
+        item.status = StateItemStatus::Failed {
+            message: message.clone(),
+        };
+        return Some(message);
+    }
+    item.status = StateItemStatus::Success;
+    item.picked_commit_id = git::head_commit(&repo_path).ok();
+    None
+}
+
+/// Builds a failed result whose exit code reflects the error's category.
+fn error_result(error: impl Into<anyhow::Error>) -> RunResult {
+    let error = error.into();
+    RunResult::error(error::exit_code(&error), error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::state::LockKind;
+    use crate::models::OutputFormat;
+    use crate::utils::SystemClock;
+
+    fn create_test_config() -> MergeRunnerConfig {
+        MergeRunnerConfig {
+            organization: "test-org".to_string(),
+            project: "test-project".to_string(),
+            repository: "test-repo".to_string(),
+            pat: "test-pat".to_string(),
+            write_pat: None,
+            dev_branch: "dev".to_string(),
+            target_branch: "main".to_string(),
+            version: "v1.0.0".to_string(),
+            tag_prefix: "merged-".to_string(),
+            work_item_state: "Done".to_string(),
+            select_by_states: None,
+            select_file: None,
+            strict_states: None,
+            release_gate: None,
+            build_checks: Default::default(),
+            release_tag: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            url_templates: Default::default(),
+            release_notes_locale: Default::default(),
+            local_repo: None,
+            run_hooks: false,
+            output_format: OutputFormat::Text,
+            quiet: false,
+            hooks_config: None,
+            max_concurrent_network: 100,
+            max_concurrent_processing: 10,
+            since: None,
+            since_field: Default::default(),
+            api_version: None,
+            release_candidate: false,
+            status_port: None,
+            serve: None,
+            selection_hard_cap: None,
+            force: false,
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            notifications: None,
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            conflict_timeout: None,
+            dry_run: false,
+            reports: Vec::new(),
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// # Runner Creation
+    ///
+    /// Verifies that the runner can be created with a config.
+    ///
+    /// ## Test Scenario
+    /// - Creates a runner with test config
+    ///
+    /// ## Expected Outcome
+    /// - Runner is created without error
+    #[test]
+    fn test_runner_creation() {
+        let config = create_test_config();
+        let mut buffer = Vec::new();
+        let _runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+        // Runner created successfully
+    }
+
+    /// # Runner With Custom Writer
+    ///
+    /// Verifies that the runner can use a custom writer.
+    ///
+    /// ## Test Scenario
+    /// - Creates runner with Vec<u8> writer
+    /// - Emits an event
+    ///
+    /// ## Expected Outcome
+    /// - Event is written to the buffer
+    #[test]
+    fn test_runner_with_custom_writer() {
+        let config = create_test_config();
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+
+        runner.emit_event(ProgressEvent::Start {
+            total_prs: 5,
+            version: "v1.0.0".to_string(),
+            target_branch: "main".to_string(),
+            state_file_path: None,
+        });
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("Starting merge"));
+    }
+
+    /// # Output Format Handling
+    ///
+    /// Verifies different output formats work correctly.
+    ///
+    /// ## Test Scenario
+    /// - Creates runners with different output formats
+    ///
+    /// ## Expected Outcome
+    /// - All formats are accepted
+    #[test]
+    fn test_output_format_variations() {
+        for format in [OutputFormat::Text, OutputFormat::Json, OutputFormat::Ndjson] {
+            let mut config = create_test_config();
+            config.output_format = format;
+
+            let mut buffer = Vec::new();
+            let _runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+        }
+    }
+
+    /// # Find Repo Path With Provided Path
+    ///
+    /// Verifies find_repo_path returns the provided path.
+    ///
+    /// ## Test Scenario
+    /// - Calls find_repo_path with a specific path
+    ///
+    /// ## Expected Outcome
+    /// - Returns the provided path
+    #[test]
+    fn test_find_repo_path_with_provided() {
+        let config = create_test_config();
+        let mut buffer = Vec::new();
+        let runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+
+        let path = PathBuf::from("/test/repo");
+        let result = runner.find_repo_path(Some(&path));
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), path);
+    }
+
+    /// # Error Emission
+    ///
+    /// Verifies that errors are emitted correctly.
+    ///
+    /// ## Test Scenario
+    /// - Emits an error message
+    ///
+    /// ## Expected Outcome
+    /// - Error appears in output
+    #[test]
+    fn test_error_emission() {
+        let config = create_test_config();
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+
+        runner.emit_error("Test error message");
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("error") || output.contains("Error"));
+    }
+
+    /// # Selection Hard Cap
+    ///
+    /// Verifies that large selections are refused unless forced.
+    ///
+    /// ## Test Scenario
+    /// - Checks counts below, at and above a hard cap of 2
+    /// - Repeats the check above the cap with `force` set
+    ///
+    /// ## Expected Outcome
+    /// - Only the count above the cap fails, with `NoPRsMatched`
+    /// - The error mentions `--force`; forcing skips the check
+    #[test]
+    fn test_selection_hard_cap() {
+        let mut config = create_test_config();
+        config.selection_hard_cap = Some(2);
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config.clone(), &mut buffer);
+
+        assert!(runner.check_selection_cap(2).is_none());
+        let result = runner.check_selection_cap(3).unwrap();
+        assert_eq!(result.exit_code, ExitCode::NoPRsMatched);
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("--force"));
+
+        config.force = true;
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+        assert!(runner.check_selection_cap(3).is_none());
+    }
+
+    /// # JSON Output Format Buffers Events
+    ///
+    /// Verifies that JSON output format buffers events instead of writing
+    /// them immediately, since JSON mode collects all events and outputs
+    /// a single JSON object at the end via `write_summary`.
+    ///
+    /// ## Test Scenario
+    /// - Creates runner with JSON output format
+    /// - Emits a Start event
+    ///
+    /// ## Expected Outcome
+    /// - Buffer remains empty after emitting events (events are buffered internally)
+    #[test]
+    fn test_json_output_format_buffers_events() {
+        let mut config = create_test_config();
+        config.output_format = OutputFormat::Json;
+
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+
+        runner.emit_event(ProgressEvent::Start {
+            total_prs: 3,
+            version: "v2.0.0".to_string(),
+            target_branch: "release".to_string(),
+            state_file_path: None,
+        });
+
+        // JSON format collects events internally and only writes output
+        // when write_summary is called, so the buffer must be empty here.
+        assert!(
+            buffer.is_empty(),
+            "JSON format should buffer events, not write them immediately"
+        );
+    }
+
+    /// # NDJSON Output Format
+    ///
+    /// Verifies NDJSON output format produces valid newline-delimited JSON.
+    ///
+    /// ## Test Scenario
+    /// - Creates runner with NDJSON format
+    /// - Emits progress events
+    ///
+    /// ## Expected Outcome
+    /// - Each line is valid JSON
+    #[test]
+    fn test_ndjson_output_format() {
+        let mut config = create_test_config();
+        config.output_format = OutputFormat::Ndjson;
+
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
         runner.emit_event(ProgressEvent::Start {
-            total_prs: 5,
+            total_prs: 2,
             version: "v1.0.0".to_string(),
             target_branch: "main".to_string(),
             state_file_path: None,
         });
 
+        runner.emit_event(ProgressEvent::CherryPickStart {
+            pr_id: 123,
+            commit_id: "abc123".to_string(),
+            index: 0,
+            total: 2,
+        });
+
         let output = String::from_utf8(buffer).unwrap();
-        assert!(output.contains("Starting merge"));
+        // NDJSON should have one JSON object per line
+        for line in output.lines() {
+            if !line.is_empty() {
+                assert!(
+                    serde_json::from_str::<serde_json::Value>(line).is_ok(),
+                    "Line should be valid JSON: {}",
+                    line
+                );
+            }
+        }
     }
 
-    /// # Output Format Handling
+    /// # Quiet Mode Suppresses Output
     ///
-    /// Verifies different output formats work correctly.
+    /// Verifies quiet mode suppresses regular output.
     ///
     /// ## Test Scenario
-    /// - Creates runners with different output formats
+    /// - Creates runner with quiet=true
+    /// - Emits events
     ///
     /// ## Expected Outcome
-    /// - All formats are accepted
+    /// - Output is empty or minimal
     #[test]
-    fn test_output_format_variations() {
-        for format in [OutputFormat::Text, OutputFormat::Json, OutputFormat::Ndjson] {
-            let mut config = create_test_config();
-            config.output_format = format;
+    fn test_quiet_mode() {
+        let mut config = create_test_config();
+        config.quiet = true;
 
-            let mut buffer = Vec::new();
-            let _runner = NonInteractiveRunner::with_writer(config, &mut buffer);
-        }
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+
+        runner.emit_event(ProgressEvent::CherryPickStart {
+            pr_id: 1,
+            commit_id: "abc".to_string(),
+            index: 0,
+            total: 1,
+        });
+
+        // In quiet mode, output should be suppressed
+        let output = String::from_utf8(buffer).unwrap();
+        // Either empty or contains only essential info
+        assert!(
+            output.is_empty() || output.len() < 100,
+            "Quiet mode should produce minimal output"
+        );
+    }
+
+    /// # Status Info Construction
+    ///
+    /// Verifies StatusInfo can be constructed with proper values.
+    ///
+    /// ## Test Scenario
+    /// - Manually constructs StatusInfo
+    /// - Verifies all fields
+    ///
+    /// ## Expected Outcome
+    /// - Status info contains correct values
+    #[test]
+    fn test_status_info_construction() {
+        let status = StatusInfo {
+            phase: "cherry_picking".to_string(),
+            status: "in_progress".to_string(),
+            version: "v1.0.0".to_string(),
+            target_branch: "main".to_string(),
+            repo_path: PathBuf::from("/test/repo"),
+            progress: ProgressSummary {
+                total: 5,
+                completed: 2,
+                pending: 3,
+                current_index: 2,
+            },
+            conflict: None,
+            items: None,
+        };
+
+        assert_eq!(status.version, "v1.0.0");
+        assert_eq!(status.target_branch, "main");
+        assert_eq!(status.progress.total, 5);
+        assert_eq!(status.progress.completed, 2);
+        assert_eq!(status.progress.pending, 3);
+    }
+
+    /// # Multiple Cherry-Pick Events
+    ///
+    /// Verifies multiple cherry-pick events are emitted correctly.
+    ///
+    /// ## Test Scenario
+    /// - Emits a series of cherry-pick events
+    ///
+    /// ## Expected Outcome
+    /// - All events are recorded in output
+    #[test]
+    fn test_multiple_cherry_pick_events() {
+        let config = create_test_config();
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+
+        runner.emit_event(ProgressEvent::CherryPickStart {
+            pr_id: 1,
+            commit_id: "abc".to_string(),
+            index: 0,
+            total: 3,
+        });
+        runner.emit_event(ProgressEvent::CherryPickSuccess {
+            pr_id: 1,
+            commit_id: "abc".to_string(),
+            mainline_parent: None,
+        });
+        runner.emit_event(ProgressEvent::CherryPickStart {
+            pr_id: 2,
+            commit_id: "def".to_string(),
+            index: 1,
+            total: 3,
+        });
+        runner.emit_event(ProgressEvent::CherryPickFailed {
+            pr_id: 2,
+            error: "Commit not found".to_string(),
+        });
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("PR #1") || output.contains("[1/3]"));
+        assert!(output.contains("PR #2") || output.contains("[2/3]"));
+    }
+
+    /// # Complete Event With Counts
+    ///
+    /// Verifies the complete event shows all three counts (successful, failed,
+    /// skipped) in the text output so operators can see the merge outcome.
+    ///
+    /// ## Test Scenario
+    /// - Emits a Complete event with 5 successful, 2 failed, 1 skipped
+    ///
+    /// ## Expected Outcome
+    /// - Output contains all three numeric counts with their labels
+    #[test]
+    fn test_complete_event_with_counts() {
+        let config = create_test_config();
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+
+        runner.emit_event(ProgressEvent::Complete {
+            successful: 5,
+            failed: 2,
+            skipped: 1,
+        });
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(
+            output.contains("5 successful"),
+            "Should show successful count: got '{output}'"
+        );
+        assert!(
+            output.contains("2 failed"),
+            "Should show failed count: got '{output}'"
+        );
+        assert!(
+            output.contains("1 skipped"),
+            "Should show skipped count: got '{output}'"
+        );
+    }
+
+    /// # Conflict Event Details
+    ///
+    /// Verifies conflict events include the PR ID, conflicted file names,
+    /// and repository path in the text output.
+    ///
+    /// ## Test Scenario
+    /// - Emits a cherry-pick conflict event with PR #42 and two conflicted files
+    ///
+    /// ## Expected Outcome
+    /// - Output contains the PR ID, each conflicted file name, and the repo path
+    #[test]
+    fn test_conflict_event_details() {
+        let config = create_test_config();
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+
+        runner.emit_event(ProgressEvent::CherryPickConflict {
+            pr_id: 42,
+            conflicted_files: vec!["src/main.rs".to_string(), "Cargo.toml".to_string()],
+            repo_path: PathBuf::from("/test/repo"),
+        });
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("42"), "Should include the PR ID");
+        assert!(
+            output.contains("src/main.rs"),
+            "Should list the first conflicted file"
+        );
+        assert!(
+            output.contains("Cargo.toml"),
+            "Should list the second conflicted file"
+        );
+        assert!(
+            output.contains("/test/repo"),
+            "Should include the repository path"
+        );
+    }
+
+    /// # Run Result Success
+    ///
+    /// Verifies RunResult::success() creates correct result.
+    ///
+    /// ## Test Scenario
+    /// - Creates a success result
+    ///
+    /// ## Expected Outcome
+    /// - Exit code is Success, is_success() returns true
+    #[test]
+    fn test_run_result_success() {
+        let result = RunResult::success();
+        assert!(result.is_success());
+        assert_eq!(result.exit_code, crate::core::ExitCode::Success);
+        assert!(result.message.is_none());
+    }
+
+    /// # Run Result Error
+    ///
+    /// Verifies RunResult::error() creates correct result.
+    ///
+    /// ## Test Scenario
+    /// - Creates an error result
+    ///
+    /// ## Expected Outcome
+    /// - Exit code matches, message is set
+    #[test]
+    fn test_run_result_error() {
+        let result = RunResult::error(crate::core::ExitCode::GeneralError, "Something went wrong");
+        assert!(!result.is_success());
+        assert_eq!(result.exit_code, crate::core::ExitCode::GeneralError);
+        assert_eq!(result.message, Some("Something went wrong".to_string()));
+    }
+
+    /// # Run Result Conflict
+    ///
+    /// Verifies RunResult::conflict() creates correct result.
+    ///
+    /// ## Test Scenario
+    /// - Creates a conflict result
+    ///
+    /// ## Expected Outcome
+    /// - Exit code is Conflict, state file path is set
+    #[test]
+    fn test_run_result_conflict() {
+        let result = RunResult::conflict(PathBuf::from("/state/file.json"));
+        assert!(!result.is_success());
+        assert_eq!(result.exit_code, crate::core::ExitCode::Conflict);
+        assert_eq!(
+            result.state_file_path,
+            Some(PathBuf::from("/state/file.json"))
+        );
     }
 
-    /// # Find Repo Path With Provided Path
+    /// # Run Result Partial Success
     ///
-    /// Verifies find_repo_path returns the provided path.
+    /// Verifies RunResult::partial_success() creates correct result.
     ///
     /// ## Test Scenario
-    /// - Calls find_repo_path with a specific path
+    /// - Creates a partial success result
     ///
     /// ## Expected Outcome
-    /// - Returns the provided path
+    /// - Exit code is PartialSuccess
     #[test]
-    fn test_find_repo_path_with_provided() {
-        let config = create_test_config();
-        let mut buffer = Vec::new();
-        let runner = NonInteractiveRunner::with_writer(config, &mut buffer);
-
-        let path = PathBuf::from("/test/repo");
-        let result = runner.find_repo_path(Some(&path));
-
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), path);
+    fn test_run_result_partial_success() {
+        let result = RunResult::partial_success("3 of 5 succeeded");
+        assert!(!result.is_success());
+        assert_eq!(result.exit_code, crate::core::ExitCode::PartialSuccess);
+        assert_eq!(result.message, Some("3 of 5 succeeded".to_string()));
     }
 
-    /// # Error Emission
+    /// # Status Returns Idle When No State File
     ///
-    /// Verifies that errors are emitted correctly.
+    /// Verifies that status returns idle status (not error) when no state file exists.
     ///
     /// ## Test Scenario
-    /// - Emits an error message
+    /// - Creates a runner with a temporary directory (no state file)
+    /// - Calls status method
     ///
     /// ## Expected Outcome
-    /// - Error appears in output
+    /// - Returns success (exit code 0)
+    /// - Output contains "idle" phase and status
     #[test]
-    fn test_error_emission() {
+    fn test_status_returns_idle_when_no_state_file() {
         let config = create_test_config();
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_error("Test error message");
+        // Use a temp directory that has no state file
+        let temp_dir = tempfile::tempdir().unwrap();
+        let result = runner.status(Some(temp_dir.path()));
+
+        // Should return success, not an error
+        assert!(result.is_success());
+        assert_eq!(result.exit_code, crate::core::ExitCode::Success);
 
+        // Output should contain idle status
         let output = String::from_utf8(buffer).unwrap();
-        assert!(output.contains("error") || output.contains("Error"));
+        assert!(
+            output.contains("idle"),
+            "Output should contain 'idle' status: {}",
+            output
+        );
     }
 
-    /// # JSON Output Format Buffers Events
+    /// # Status Idle Output JSON Format
     ///
-    /// Verifies that JSON output format buffers events instead of writing
-    /// them immediately, since JSON mode collects all events and outputs
-    /// a single JSON object at the end via `write_summary`.
+    /// Verifies that idle status is properly formatted in JSON output.
     ///
     /// ## Test Scenario
-    /// - Creates runner with JSON output format
-    /// - Emits a Start event
+    /// - Creates a runner with JSON output format
+    /// - Calls status with no state file
     ///
     /// ## Expected Outcome
-    /// - Buffer remains empty after emitting events (events are buffered internally)
+    /// - JSON output contains phase: "idle" and status: "idle"
     #[test]
-    fn test_json_output_format_buffers_events() {
+    fn test_status_idle_json_format() {
         let mut config = create_test_config();
         config.output_format = OutputFormat::Json;
-
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_event(ProgressEvent::Start {
-            total_prs: 3,
-            version: "v2.0.0".to_string(),
-            target_branch: "release".to_string(),
-            state_file_path: None,
-        });
+        let temp_dir = tempfile::tempdir().unwrap();
+        let result = runner.status(Some(temp_dir.path()));
 
-        // JSON format collects events internally and only writes output
-        // when write_summary is called, so the buffer must be empty here.
-        assert!(
-            buffer.is_empty(),
-            "JSON format should buffer events, not write them immediately"
-        );
+        assert!(result.is_success());
+
+        let output = String::from_utf8(buffer).unwrap();
+        // Parse as JSON and verify structure
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["phase"], "idle");
+        assert_eq!(json["status"], "idle");
+        assert_eq!(json["progress"]["total"], 0);
+        assert_eq!(json["progress"]["completed"], 0);
     }
 
-    /// # NDJSON Output Format
+    /// # Error Emission With Code
     ///
-    /// Verifies NDJSON output format produces valid newline-delimited JSON.
+    /// Verifies that errors with codes are emitted correctly in NDJSON.
     ///
     /// ## Test Scenario
-    /// - Creates runner with NDJSON format
-    /// - Emits progress events
+    /// - Emits an error with a code using NDJSON format
     ///
     /// ## Expected Outcome
-    /// - Each line is valid JSON
+    /// - Error contains both message and code in JSON output
     #[test]
-    fn test_ndjson_output_format() {
+    fn test_error_emission_with_code() {
         let mut config = create_test_config();
         config.output_format = OutputFormat::Ndjson;
 
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_event(ProgressEvent::Start {
-            total_prs: 2,
-            version: "v1.0.0".to_string(),
-            target_branch: "main".to_string(),
-            state_file_path: None,
-        });
-
-        runner.emit_event(ProgressEvent::CherryPickStart {
-            pr_id: 123,
-            commit_id: "abc123".to_string(),
-            index: 0,
-            total: 2,
-        });
+        runner.emit_error_with_code("Test locked error", Some(StateError::Locked.code()));
 
         let output = String::from_utf8(buffer).unwrap();
-        // NDJSON should have one JSON object per line
-        for line in output.lines() {
-            if !line.is_empty() {
-                assert!(
-                    serde_json::from_str::<serde_json::Value>(line).is_ok(),
-                    "Line should be valid JSON: {}",
-                    line
-                );
-            }
-        }
+        assert!(output.contains("\"code\":\"locked\""));
+        assert!(output.contains("Test locked error"));
     }
 
-    /// # Quiet Mode Suppresses Output
+    /// # Error Emission Without Code
     ///
-    /// Verifies quiet mode suppresses regular output.
+    /// Verifies that errors without codes omit the code field in NDJSON.
     ///
     /// ## Test Scenario
-    /// - Creates runner with quiet=true
-    /// - Emits events
+    /// - Emits an error without a code using NDJSON format
     ///
     /// ## Expected Outcome
-    /// - Output is empty or minimal
+    /// - Error contains message but no code field
     #[test]
-    fn test_quiet_mode() {
+    fn test_error_emission_without_code() {
         let mut config = create_test_config();
-        config.quiet = true;
+        config.output_format = OutputFormat::Ndjson;
 
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_event(ProgressEvent::CherryPickStart {
-            pr_id: 1,
-            commit_id: "abc".to_string(),
-            index: 0,
-            total: 1,
-        });
+        runner.emit_error("Generic error");
 
-        // In quiet mode, output should be suppressed
         let output = String::from_utf8(buffer).unwrap();
-        // Either empty or contains only essential info
-        assert!(
-            output.is_empty() || output.len() < 100,
-            "Quiet mode should produce minimal output"
-        );
+        assert!(output.contains("Generic error"));
+        assert!(!output.contains("\"code\""));
     }
 
-    /// # Status Info Construction
+    /// # Error Emission With All Code Variants
     ///
-    /// Verifies StatusInfo can be constructed with proper values.
+    /// Verifies all error code strings used across the runner
+    /// are correctly emitted in NDJSON output.
     ///
     /// ## Test Scenario
-    /// - Manually constructs StatusInfo
-    /// - Verifies all fields
+    /// - Emits errors with each code used in the codebase:
+    ///   locked, no_state_file, invalid_phase, conflicts_unresolved
     ///
     /// ## Expected Outcome
-    /// - Status info contains correct values
+    /// - Each NDJSON line contains the correct code field
     #[test]
-    fn test_status_info_construction() {
-        let status = StatusInfo {
-            phase: "cherry_picking".to_string(),
-            status: "in_progress".to_string(),
-            version: "v1.0.0".to_string(),
-            target_branch: "main".to_string(),
-            repo_path: PathBuf::from("/test/repo"),
-            progress: ProgressSummary {
-                total: 5,
-                completed: 2,
-                pending: 3,
-                current_index: 2,
-            },
-            conflict: None,
-            items: None,
-        };
+    fn test_error_emission_all_code_variants() {
+        let codes = [
+            "locked",
+            "no_state_file",
+            "invalid_phase",
+            "conflicts_unresolved",
+        ];
 
-        assert_eq!(status.version, "v1.0.0");
-        assert_eq!(status.target_branch, "main");
-        assert_eq!(status.progress.total, 5);
-        assert_eq!(status.progress.completed, 2);
-        assert_eq!(status.progress.pending, 3);
-    }
+        for code in &codes {
+            let mut config = create_test_config();
+            config.output_format = OutputFormat::Ndjson;
 
-    /// # Multiple Cherry-Pick Events
-    ///
-    /// Verifies multiple cherry-pick events are emitted correctly.
-    ///
-    /// ## Test Scenario
-    /// - Emits a series of cherry-pick events
-    ///
-    /// ## Expected Outcome
-    /// - All events are recorded in output
-    #[test]
-    fn test_multiple_cherry_pick_events() {
-        let config = create_test_config();
-        let mut buffer = Vec::new();
-        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+            let mut buffer = Vec::new();
+            let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_event(ProgressEvent::CherryPickStart {
-            pr_id: 1,
-            commit_id: "abc".to_string(),
-            index: 0,
-            total: 3,
-        });
-        runner.emit_event(ProgressEvent::CherryPickSuccess {
-            pr_id: 1,
-            commit_id: "abc".to_string(),
-        });
-        runner.emit_event(ProgressEvent::CherryPickStart {
-            pr_id: 2,
-            commit_id: "def".to_string(),
-            index: 1,
-            total: 3,
-        });
-        runner.emit_event(ProgressEvent::CherryPickFailed {
-            pr_id: 2,
-            error: "Commit not found".to_string(),
-        });
+            runner.emit_error_with_code(&format!("Error: {}", code), Some(code));
 
-        let output = String::from_utf8(buffer).unwrap();
-        assert!(output.contains("PR #1") || output.contains("[1/3]"));
-        assert!(output.contains("PR #2") || output.contains("[2/3]"));
+            let output = String::from_utf8(buffer).unwrap();
+            let json: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
+            assert_eq!(json["event"], "error", "event type should be error");
+            assert_eq!(
+                json["code"], *code,
+                "code should be '{}' but got '{}'",
+                code, json["code"]
+            );
+            assert!(
+                json["message"].as_str().unwrap().contains(code),
+                "message should contain code context"
+            );
+        }
     }
 
-    /// # Complete Event With Counts
+    /// # Error Emission in Text Format With Code
     ///
-    /// Verifies the complete event shows all three counts (successful, failed,
-    /// skipped) in the text output so operators can see the merge outcome.
+    /// Verifies that error codes are rendered in text output too.
     ///
     /// ## Test Scenario
-    /// - Emits a Complete event with 5 successful, 2 failed, 1 skipped
+    /// - Emits an error with code "locked" in Text format
     ///
     /// ## Expected Outcome
-    /// - Output contains all three numeric counts with their labels
+    /// - Text output contains the error message
     #[test]
-    fn test_complete_event_with_counts() {
+    fn test_error_emission_text_format_with_code() {
         let config = create_test_config();
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_event(ProgressEvent::Complete {
-            successful: 5,
-            failed: 2,
-            skipped: 1,
-        });
+        runner.emit_error_with_code("Another merge operation is in progress", Some("locked"));
 
         let output = String::from_utf8(buffer).unwrap();
         assert!(
-            output.contains("5 successful"),
-            "Should show successful count: got '{output}'"
-        );
-        assert!(
-            output.contains("2 failed"),
-            "Should show failed count: got '{output}'"
-        );
-        assert!(
-            output.contains("1 skipped"),
-            "Should show skipped count: got '{output}'"
+            output.contains("Another merge operation is in progress"),
+            "Text format should display the error message: got '{output}'"
         );
     }
 
-    /// # Conflict Event Details
+    /// # Start Event With State File Path
     ///
-    /// Verifies conflict events include the PR ID, conflicted file names,
-    /// and repository path in the text output.
+    /// Verifies the Start event correctly includes state_file_path in NDJSON output.
     ///
     /// ## Test Scenario
-    /// - Emits a cherry-pick conflict event with PR #42 and two conflicted files
+    /// - Emits a Start event with a state file path set
     ///
     /// ## Expected Outcome
-    /// - Output contains the PR ID, each conflicted file name, and the repo path
+    /// - NDJSON output includes the state_file_path field
     #[test]
-    fn test_conflict_event_details() {
-        let config = create_test_config();
+    fn test_start_event_with_state_file_path() {
+        let mut config = create_test_config();
+        config.output_format = OutputFormat::Ndjson;
+
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_event(ProgressEvent::CherryPickConflict {
-            pr_id: 42,
-            conflicted_files: vec!["src/main.rs".to_string(), "Cargo.toml".to_string()],
-            repo_path: PathBuf::from("/test/repo"),
+        runner.emit_event(ProgressEvent::Start {
+            total_prs: 3,
+            version: "v2.0.0".to_string(),
+            target_branch: "release".to_string(),
+            state_file_path: Some(PathBuf::from("/tmp/state/merge.json")),
         });
 
         let output = String::from_utf8(buffer).unwrap();
-        assert!(output.contains("42"), "Should include the PR ID");
-        assert!(
-            output.contains("src/main.rs"),
-            "Should list the first conflicted file"
-        );
-        assert!(
-            output.contains("Cargo.toml"),
-            "Should list the second conflicted file"
-        );
+        let json: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
+        assert_eq!(json["event"], "start");
+        assert_eq!(json["total_prs"], 3);
+        assert_eq!(json["state_file_path"], "/tmp/state/merge.json");
+    }
+
+    /// # Start Event Without State File Path Omits Field
+    ///
+    /// Verifies the Start event omits state_file_path when None in NDJSON output.
+    ///
+    /// ## Test Scenario
+    /// - Emits a Start event with state_file_path = None
+    ///
+    /// ## Expected Outcome
+    /// - NDJSON output does not include state_file_path field
+    #[test]
+    fn test_start_event_without_state_file_path() {
+        let mut config = create_test_config();
+        config.output_format = OutputFormat::Ndjson;
+
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+
+        runner.emit_event(ProgressEvent::Start {
+            total_prs: 1,
+            version: "v1.0.0".to_string(),
+            target_branch: "main".to_string(),
+            state_file_path: None,
+        });
+
+        let output = String::from_utf8(buffer).unwrap();
+        let json: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
+        assert_eq!(json["event"], "start");
         assert!(
-            output.contains("/test/repo"),
-            "Should include the repository path"
+            json.get("state_file_path").is_none(),
+            "state_file_path should be omitted when None"
         );
     }
 
-    /// # Run Result Success
+    /// # RunResult Success With Message
     ///
-    /// Verifies RunResult::success() creates correct result.
+    /// Verifies RunResult::success_with_message() sets exit code and message.
     ///
     /// ## Test Scenario
-    /// - Creates a success result
+    /// - Creates a success result with a message
     ///
     /// ## Expected Outcome
-    /// - Exit code is Success, is_success() returns true
+    /// - Exit code is Success, message is set
     #[test]
-    fn test_run_result_success() {
-        let result = RunResult::success();
+    fn test_run_result_success_with_message() {
+        let result = RunResult::success_with_message("Merge aborted");
         assert!(result.is_success());
         assert_eq!(result.exit_code, crate::core::ExitCode::Success);
-        assert!(result.message.is_none());
+        assert_eq!(result.message, Some("Merge aborted".to_string()));
     }
 
-    /// # Run Result Error
+    /// # RunResult With State File Builder
     ///
-    /// Verifies RunResult::error() creates correct result.
+    /// Verifies RunResult::with_state_file() builder method chains correctly.
     ///
     /// ## Test Scenario
-    /// - Creates an error result
+    /// - Creates a partial success result and chains with_state_file
     ///
     /// ## Expected Outcome
-    /// - Exit code matches, message is set
+    /// - State file path is set, original fields preserved
     #[test]
-    fn test_run_result_error() {
-        let result = RunResult::error(crate::core::ExitCode::GeneralError, "Something went wrong");
-        assert!(!result.is_success());
-        assert_eq!(result.exit_code, crate::core::ExitCode::GeneralError);
-        assert_eq!(result.message, Some("Something went wrong".to_string()));
+    fn test_run_result_with_state_file() {
+        let result = RunResult::partial_success("3 of 5 succeeded")
+            .with_state_file(PathBuf::from("/state/merge.json"));
+        assert_eq!(result.exit_code, crate::core::ExitCode::PartialSuccess);
+        assert_eq!(result.message, Some("3 of 5 succeeded".to_string()));
+        assert_eq!(
+            result.state_file_path,
+            Some(PathBuf::from("/state/merge.json"))
+        );
     }
 
-    /// # Run Result Conflict
+    /// # Quiet Mode Still Shows Errors
     ///
-    /// Verifies RunResult::conflict() creates correct result.
+    /// Verifies quiet mode shows errors (since errors and conflicts
+    /// are always displayed even in quiet mode).
     ///
     /// ## Test Scenario
-    /// - Creates a conflict result
+    /// - Creates runner with quiet=true
+    /// - Emits error events with and without codes
     ///
     /// ## Expected Outcome
-    /// - Exit code is Conflict, state file path is set
+    /// - Error output is still present (quiet only suppresses progress)
     #[test]
-    fn test_run_result_conflict() {
-        let result = RunResult::conflict(PathBuf::from("/state/file.json"));
-        assert!(!result.is_success());
-        assert_eq!(result.exit_code, crate::core::ExitCode::Conflict);
-        assert_eq!(
-            result.state_file_path,
-            Some(PathBuf::from("/state/file.json"))
+    fn test_quiet_mode_shows_errors() {
+        let mut config = create_test_config();
+        config.quiet = true;
+
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+
+        runner.emit_error_with_code("Something locked", Some("locked"));
+        runner.emit_error("Generic failure");
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(
+            output.contains("Something locked"),
+            "Quiet mode should still show errors: got '{output}'"
+        );
+        assert!(
+            output.contains("Generic failure"),
+            "Quiet mode should still show errors: got '{output}'"
         );
     }
 
-    /// # Run Result Partial Success
+    /// # Full Merge Event Flow in NDJSON
     ///
-    /// Verifies RunResult::partial_success() creates correct result.
+    /// Simulates a realistic merge flow: start → cherry-pick → conflict →
+    /// skip → cherry-pick → success → complete. Validates all events appear
+    /// as valid NDJSON and in correct order.
     ///
     /// ## Test Scenario
-    /// - Creates a partial success result
+    /// - Emits the sequence of events a real merge + skip flow would produce
     ///
     /// ## Expected Outcome
-    /// - Exit code is PartialSuccess
+    /// - Each line is valid JSON with the expected event type
+    /// - Events appear in the correct order
     #[test]
-    fn test_run_result_partial_success() {
-        let result = RunResult::partial_success("3 of 5 succeeded");
-        assert!(!result.is_success());
-        assert_eq!(result.exit_code, crate::core::ExitCode::PartialSuccess);
-        assert_eq!(result.message, Some("3 of 5 succeeded".to_string()));
+    fn test_full_merge_flow_ndjson() {
+        let mut config = create_test_config();
+        config.output_format = OutputFormat::Ndjson;
+
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+
+        // Start
+        runner.emit_event(ProgressEvent::Start {
+            total_prs: 2,
+            version: "v1.0.0".to_string(),
+            target_branch: "main".to_string(),
+            state_file_path: Some(PathBuf::from("/tmp/state.json")),
+        });
+
+        // First PR conflicts
+        runner.emit_event(ProgressEvent::CherryPickStart {
+            pr_id: 100,
+            commit_id: "aaa111".to_string(),
+            index: 0,
+            total: 2,
+        });
+        runner.emit_event(ProgressEvent::CherryPickSkipped {
+            pr_id: 100,
+            reason: Some("Skipped by user due to unresolvable conflict".to_string()),
+        });
+
+        // Second PR succeeds
+        runner.emit_event(ProgressEvent::CherryPickStart {
+            pr_id: 200,
+            commit_id: "bbb222".to_string(),
+            index: 1,
+            total: 2,
+        });
+        runner.emit_event(ProgressEvent::CherryPickSuccess {
+            pr_id: 200,
+            commit_id: "bbb222".to_string(),
+            mainline_parent: None,
+        });
+
+        // Complete
+        runner.emit_event(ProgressEvent::Complete {
+            successful: 1,
+            failed: 0,
+            skipped: 1,
+        });
+
+        let output = String::from_utf8(buffer).unwrap();
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| serde_json::from_str(l).unwrap_or_else(|_| panic!("Invalid JSON line: {}", l)))
+            .collect();
+
+        assert_eq!(lines.len(), 6, "Should have 6 NDJSON events");
+        assert_eq!(lines[0]["event"], "start");
+        assert_eq!(lines[0]["state_file_path"], "/tmp/state.json");
+        assert_eq!(lines[1]["event"], "cherry_pick_start");
+        assert_eq!(lines[1]["pr_id"], 100);
+        assert_eq!(lines[2]["event"], "cherry_pick_skipped");
+        assert_eq!(lines[2]["pr_id"], 100);
+        assert_eq!(lines[3]["event"], "cherry_pick_start");
+        assert_eq!(lines[3]["pr_id"], 200);
+        assert_eq!(lines[4]["event"], "cherry_pick_success");
+        assert_eq!(lines[4]["pr_id"], 200);
+        assert_eq!(lines[5]["event"], "complete");
+        assert_eq!(lines[5]["successful"], 1);
+        assert_eq!(lines[5]["skipped"], 1);
     }
 
-    /// # Status Returns Idle When No State File
+    /// # Error Event in JSON Format Is Buffered
     ///
-    /// Verifies that status returns idle status (not error) when no state file exists.
+    /// Verifies that errors are also buffered in JSON mode (not written immediately).
     ///
     /// ## Test Scenario
-    /// - Creates a runner with a temporary directory (no state file)
-    /// - Calls status method
+    /// - Emits error events in JSON format
     ///
     /// ## Expected Outcome
-    /// - Returns success (exit code 0)
-    /// - Output contains "idle" phase and status
+    /// - Buffer remains empty since JSON buffers all events
     #[test]
-    fn test_status_returns_idle_when_no_state_file() {
-        let config = create_test_config();
+    fn test_error_event_buffered_in_json_mode() {
+        let mut config = create_test_config();
+        config.output_format = OutputFormat::Json;
+
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        // Use a temp directory that has no state file
-        let temp_dir = tempfile::tempdir().unwrap();
-        let result = runner.status(Some(temp_dir.path()));
-
-        // Should return success, not an error
-        assert!(result.is_success());
-        assert_eq!(result.exit_code, crate::core::ExitCode::Success);
+        runner.emit_error_with_code("Locked", Some("locked"));
+        runner.emit_error("Generic error");
 
-        // Output should contain idle status
-        let output = String::from_utf8(buffer).unwrap();
         assert!(
-            output.contains("idle"),
-            "Output should contain 'idle' status: {}",
-            output
+            buffer.is_empty(),
+            "JSON format should buffer error events too"
         );
     }
 
-    /// # Status Idle Output JSON Format
+    /// # Skip Event in Text Format
     ///
-    /// Verifies that idle status is properly formatted in JSON output.
+    /// Verifies CherryPickSkipped events display correctly in text output
+    /// including the PR ID and skip reason.
     ///
     /// ## Test Scenario
-    /// - Creates a runner with JSON output format
-    /// - Calls status with no state file
+    /// - Emits a CherryPickSkipped event with a reason
     ///
     /// ## Expected Outcome
-    /// - JSON output contains phase: "idle" and status: "idle"
+    /// - Output mentions the PR being skipped
     #[test]
-    fn test_status_idle_json_format() {
-        let mut config = create_test_config();
-        config.output_format = OutputFormat::Json;
+    fn test_skip_event_text_format() {
+        let config = create_test_config();
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        let temp_dir = tempfile::tempdir().unwrap();
-        let result = runner.status(Some(temp_dir.path()));
-
-        assert!(result.is_success());
+        runner.emit_event(ProgressEvent::CherryPickSkipped {
+            pr_id: 42,
+            reason: Some("Skipped by user due to unresolvable conflict".to_string()),
+        });
 
         let output = String::from_utf8(buffer).unwrap();
-        // Parse as JSON and verify structure
-        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
-        assert_eq!(json["phase"], "idle");
-        assert_eq!(json["status"], "idle");
-        assert_eq!(json["progress"]["total"], 0);
-        assert_eq!(json["progress"]["completed"], 0);
+        assert!(
+            output.contains("42"),
+            "Should mention the PR ID: got '{output}'"
+        );
     }
 
-    /// # Error Emission With Code
+    /// # Multiple Errors With Different Codes in NDJSON
     ///
-    /// Verifies that errors with codes are emitted correctly in NDJSON.
+    /// Verifies that emitting multiple errors produces separate NDJSON lines,
+    /// each with the correct code.
     ///
     /// ## Test Scenario
-    /// - Emits an error with a code using NDJSON format
+    /// - Emits three errors: locked (with code), generic (no code), invalid_phase (with code)
     ///
     /// ## Expected Outcome
-    /// - Error contains both message and code in JSON output
+    /// - Three NDJSON lines, each valid JSON with correct fields
     #[test]
-    fn test_error_emission_with_code() {
+    fn test_multiple_errors_ndjson() {
         let mut config = create_test_config();
         config.output_format = OutputFormat::Ndjson;
 
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_error_with_code("Test locked error", Some("locked"));
+        runner.emit_error_with_code("Locked", Some("locked"));
+        runner.emit_error("Something went wrong");
+        runner.emit_error_with_code("Wrong phase", Some("invalid_phase"));
 
         let output = String::from_utf8(buffer).unwrap();
-        assert!(output.contains("\"code\":\"locked\""));
-        assert!(output.contains("Test locked error"));
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0]["code"], "locked");
+        assert!(lines[1].get("code").is_none());
+        assert_eq!(lines[2]["code"], "invalid_phase");
     }
 
-    /// # Error Emission Without Code
+    /// # Status Idle in NDJSON Format
     ///
-    /// Verifies that errors without codes omit the code field in NDJSON.
+    /// Verifies that idle status is properly formatted in NDJSON output.
     ///
     /// ## Test Scenario
-    /// - Emits an error without a code using NDJSON format
+    /// - Creates a runner with NDJSON output format
+    /// - Calls status with no state file
     ///
     /// ## Expected Outcome
-    /// - Error contains message but no code field
+    /// - NDJSON output is valid JSON with idle fields
     #[test]
-    fn test_error_emission_without_code() {
+    fn test_status_idle_ndjson_format() {
         let mut config = create_test_config();
         config.output_format = OutputFormat::Ndjson;
-
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_error("Generic error");
+        let temp_dir = tempfile::tempdir().unwrap();
+        let result = runner.status(Some(temp_dir.path()));
+
+        assert!(result.is_success());
 
         let output = String::from_utf8(buffer).unwrap();
-        assert!(output.contains("Generic error"));
-        assert!(!output.contains("\"code\""));
+        let json: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
+        assert_eq!(json["phase"], "idle");
+        assert_eq!(json["status"], "idle");
     }
 
-    /// # Error Emission With All Code Variants
+    /// # Dependency Analysis Events in NDJSON
     ///
-    /// Verifies all error code strings used across the runner
-    /// are correctly emitted in NDJSON output.
+    /// Verifies dependency analysis events serialize correctly.
     ///
     /// ## Test Scenario
-    /// - Emits errors with each code used in the codebase:
-    ///   locked, no_state_file, invalid_phase, conflicts_unresolved
+    /// - Emits DependencyAnalysisStart, DependencyAnalysisComplete,
+    ///   and DependencyWarning events
     ///
     /// ## Expected Outcome
-    /// - Each NDJSON line contains the correct code field
+    /// - All events produce valid NDJSON with correct fields
     #[test]
-    fn test_error_emission_all_code_variants() {
-        let codes = [
-            "locked",
-            "no_state_file",
-            "invalid_phase",
-            "conflicts_unresolved",
-        ];
-
-        for code in &codes {
-            let mut config = create_test_config();
-            config.output_format = OutputFormat::Ndjson;
-
-            let mut buffer = Vec::new();
-            let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
-
-            runner.emit_error_with_code(&format!("Error: {}", code), Some(code));
-
-            let output = String::from_utf8(buffer).unwrap();
-            let json: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
-            assert_eq!(json["event"], "error", "event type should be error");
-            assert_eq!(
-                json["code"], *code,
-                "code should be '{}' but got '{}'",
-                code, json["code"]
-            );
-            assert!(
-                json["message"].as_str().unwrap().contains(code),
-                "message should contain code context"
-            );
-        }
-    }
+    fn test_dependency_analysis_events_ndjson() {
+        let mut config = create_test_config();
+        config.output_format = OutputFormat::Ndjson;
 
-    /// # Error Emission in Text Format With Code
-    ///
-    /// Verifies that error codes are rendered in text output too.
-    ///
-    /// ## Test Scenario
-    /// - Emits an error with code "locked" in Text format
-    ///
-    /// ## Expected Outcome
-    /// - Text output contains the error message
-    #[test]
-    fn test_error_emission_text_format_with_code() {
-        let config = create_test_config();
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_error_with_code("Another merge operation is in progress", Some("locked"));
+        runner.emit_event(ProgressEvent::DependencyAnalysisStart { pr_count: 5 });
+        runner.emit_event(ProgressEvent::DependencyAnalysisComplete {
+            independent: 3,
+            partial: 1,
+            dependent: 1,
+        });
+        runner.emit_event(ProgressEvent::DependencyWarning {
+            selected_pr_id: 100,
+            selected_pr_title: "Feature A".to_string(),
+            unselected_pr_id: 200,
+            unselected_pr_title: "Feature B".to_string(),
+            is_critical: true,
+            shared_files: vec!["src/lib.rs".to_string()],
+        });
 
         let output = String::from_utf8(buffer).unwrap();
-        assert!(
-            output.contains("Another merge operation is in progress"),
-            "Text format should display the error message: got '{output}'"
-        );
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0]["event"], "dependency_analysis_start");
+        assert_eq!(lines[0]["pr_count"], 5);
+        assert_eq!(lines[1]["event"], "dependency_analysis_complete");
+        assert_eq!(lines[1]["independent"], 3);
+        assert_eq!(lines[2]["event"], "dependency_warning");
+        assert!(lines[2]["is_critical"].as_bool().unwrap());
     }
 
-    /// # Start Event With State File Path
+    /// # Aborted Event in NDJSON
     ///
-    /// Verifies the Start event correctly includes state_file_path in NDJSON output.
+    /// Verifies Aborted event serializes correctly with and without message.
     ///
     /// ## Test Scenario
-    /// - Emits a Start event with a state file path set
+    /// - Emits Aborted events with success=true and success=false
     ///
     /// ## Expected Outcome
-    /// - NDJSON output includes the state_file_path field
+    /// - NDJSON contains correct success and message fields
     #[test]
-    fn test_start_event_with_state_file_path() {
+    fn test_aborted_event_ndjson() {
         let mut config = create_test_config();
         config.output_format = OutputFormat::Ndjson;
 
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_event(ProgressEvent::Start {
-            total_prs: 3,
-            version: "v2.0.0".to_string(),
-            target_branch: "release".to_string(),
-            state_file_path: Some(PathBuf::from("/tmp/state/merge.json")),
+        runner.emit_event(ProgressEvent::Aborted {
+            success: true,
+            message: None,
+        });
+        runner.emit_event(ProgressEvent::Aborted {
+            success: false,
+            message: Some("Failed to clean up".to_string()),
         });
 
         let output = String::from_utf8(buffer).unwrap();
-        let json: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
-        assert_eq!(json["event"], "start");
-        assert_eq!(json["total_prs"], 3);
-        assert_eq!(json["state_file_path"], "/tmp/state/merge.json");
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["event"], "aborted");
+        assert!(lines[0]["success"].as_bool().unwrap());
+        assert!(lines[0].get("message").is_none());
+        assert_eq!(lines[1]["event"], "aborted");
+        assert!(!lines[1]["success"].as_bool().unwrap());
+        assert_eq!(lines[1]["message"], "Failed to clean up");
     }
 
-    /// # Start Event Without State File Path Omits Field
+    /// # Cherry-pick Failed Event in Text Format
     ///
-    /// Verifies the Start event omits state_file_path when None in NDJSON output.
+    /// Verifies CherryPickFailed events display the error in text output.
     ///
     /// ## Test Scenario
-    /// - Emits a Start event with state_file_path = None
+    /// - Emits a CherryPickFailed event
     ///
-    /// ## Expected Outcome
-    /// - NDJSON output does not include state_file_path field
-    #[test]
-    fn test_start_event_without_state_file_path() {
-        let mut config = create_test_config();
-        config.output_format = OutputFormat::Ndjson;
-
+    /// ## Expected Outcome
+    /// - Output includes the PR ID and error message
+    #[test]
+    fn test_cherry_pick_failed_event_text() {
+        let config = create_test_config();
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_event(ProgressEvent::Start {
-            total_prs: 1,
-            version: "v1.0.0".to_string(),
-            target_branch: "main".to_string(),
-            state_file_path: None,
+        runner.emit_event(ProgressEvent::CherryPickFailed {
+            pr_id: 99,
+            error: "Commit abc123 not found".to_string(),
         });
 
         let output = String::from_utf8(buffer).unwrap();
-        let json: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
-        assert_eq!(json["event"], "start");
+        assert!(output.contains("99"), "Should include PR ID");
         assert!(
-            json.get("state_file_path").is_none(),
-            "state_file_path should be omitted when None"
+            output.contains("not found") || output.contains("abc123"),
+            "Should include error details: got '{output}'"
         );
     }
 
-    /// # RunResult Success With Message
+    /// # Status Port In Use
     ///
-    /// Verifies RunResult::success_with_message() sets exit code and message.
+    /// Verifies that a run fails early when the status endpoint cannot bind.
     ///
     /// ## Test Scenario
-    /// - Creates a success result with a message
+    /// - Occupies a localhost port and configures it as the status port
+    /// - Starts a run
     ///
     /// ## Expected Outcome
-    /// - Exit code is Success, message is set
-    #[test]
-    fn test_run_result_success_with_message() {
-        let result = RunResult::success_with_message("Merge aborted");
-        assert!(result.is_success());
-        assert_eq!(result.exit_code, crate::core::ExitCode::Success);
-        assert_eq!(result.message, Some("Merge aborted".to_string()));
-    }
+    /// - The run fails with a general error before any merge work
+    /// - The error names the status endpoint
+    #[tokio::test]
+    async fn test_run_fails_when_status_port_in_use() {
+        let occupied = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut config = create_test_config();
+        config.status_port = Some(occupied.local_addr().unwrap().port());
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-    /// # RunResult With State File Builder
-    ///
-    /// Verifies RunResult::with_state_file() builder method chains correctly.
-    ///
-    /// ## Test Scenario
-    /// - Creates a partial success result and chains with_state_file
-    ///
-    /// ## Expected Outcome
-    /// - State file path is set, original fields preserved
-    #[test]
-    fn test_run_result_with_state_file() {
-        let result = RunResult::partial_success("3 of 5 succeeded")
-            .with_state_file(PathBuf::from("/state/merge.json"));
-        assert_eq!(result.exit_code, crate::core::ExitCode::PartialSuccess);
-        assert_eq!(result.message, Some("3 of 5 succeeded".to_string()));
-        assert_eq!(
-            result.state_file_path,
-            Some(PathBuf::from("/state/merge.json"))
-        );
+        let result = runner.run().await;
+
+        assert_eq!(result.exit_code, ExitCode::GeneralError);
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("Failed to bind status endpoint"));
     }
 
-    /// # Quiet Mode Still Shows Errors
+    /// # Dashboard Address In Use
     ///
-    /// Verifies quiet mode shows errors (since errors and conflicts
-    /// are always displayed even in quiet mode).
+    /// Verifies that a run fails early when the dashboard cannot be served.
     ///
     /// ## Test Scenario
-    /// - Creates runner with quiet=true
-    /// - Emits error events with and without codes
+    /// - Occupies a localhost port and configures it as the dashboard address
+    /// - Starts a run
     ///
     /// ## Expected Outcome
-    /// - Error output is still present (quiet only suppresses progress)
-    #[test]
-    fn test_quiet_mode_shows_errors() {
+    /// - The run fails with a general error before any merge work
+    /// - The error names the dashboard, or the missing `dashboard` feature
+    #[tokio::test]
+    async fn test_run_fails_when_dashboard_cannot_start() {
+        let occupied = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
         let mut config = create_test_config();
-        config.quiet = true;
-
+        config.serve = Some(occupied.local_addr().unwrap());
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_error_with_code("Something locked", Some("locked"));
-        runner.emit_error("Generic failure");
+        let result = runner.run().await;
 
+        assert_eq!(result.exit_code, ExitCode::GeneralError);
         let output = String::from_utf8(buffer).unwrap();
-        assert!(
-            output.contains("Something locked"),
-            "Quiet mode should still show errors: got '{output}'"
-        );
-        assert!(
-            output.contains("Generic failure"),
-            "Quiet mode should still show errors: got '{output}'"
+        if cfg!(feature = "dashboard") {
+            assert!(output.contains("Failed to bind dashboard"), "{}", output);
+        } else {
+            assert!(output.contains("`dashboard` feature"), "{}", output);
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Stateful tests (require MERGERS_STATE_DIR env, serialized execution)
+    // -----------------------------------------------------------------------
+
+    use crate::core::state::{LockGuard, MergePhase, MergeStateFile, STATE_DIR_ENV};
+    use serial_test::file_serial;
+    use std::fs;
+
+    /// Helper: sets up a temp state dir + repo dir, sets MERGERS_STATE_DIR env var.
+    /// Returns (state_dir, repo_dir) as TempDir handles (kept alive for RAII).
+    fn setup_state_env() -> (tempfile::TempDir, PathBuf) {
+        let temp = tempfile::tempdir().unwrap();
+        let state_dir = temp.path().join("state");
+        let repo_dir = temp.path().join("repo");
+        fs::create_dir_all(&state_dir).unwrap();
+        fs::create_dir_all(&repo_dir).unwrap();
+        unsafe { std::env::set_var(STATE_DIR_ENV, &state_dir) };
+        (temp, repo_dir)
+    }
+
+    /// Key of the merge lock for [`create_test_config`].
+    fn test_lock_key() -> LockKey {
+        LockKey::for_repository("test-org", "test-project", "test-repo", "main")
+    }
+
+    fn teardown_state_env() {
+        unsafe { std::env::remove_var(STATE_DIR_ENV) };
+    }
+
+    /// Creates and saves a state file for the given repo_dir with the given phase.
+    fn create_state_file_with_phase(repo_dir: &Path, phase: MergePhase) {
+        let mut state = MergeStateFile::new(
+            repo_dir.to_path_buf(),
+            None,
+            false,
+            "org".to_string(),
+            "project".to_string(),
+            "repo".to_string(),
+            "dev".to_string(),
+            "main".to_string(),
+            "v1.0.0".to_string(),
+            "Done".to_string(),
+            "merged-".to_string(),
+            false,
         );
+        state.phase = phase;
+        state.save_for_repo().unwrap();
     }
 
-    /// # Full Merge Event Flow in NDJSON
+    /// # Abort Returns NoStateFile When No State Exists
     ///
-    /// Simulates a realistic merge flow: start → cherry-pick → conflict →
-    /// skip → cherry-pick → success → complete. Validates all events appear
-    /// as valid NDJSON and in correct order.
+    /// Verifies abort returns the correct error when no state file is found.
     ///
     /// ## Test Scenario
-    /// - Emits the sequence of events a real merge + skip flow would produce
+    /// - Sets up temp state dir with no state file
+    /// - Calls abort with a repo path
     ///
     /// ## Expected Outcome
-    /// - Each line is valid JSON with the expected event type
-    /// - Events appear in the correct order
+    /// - Exit code is NoStateFile
+    /// - NDJSON output contains "no_state_file" code
     #[test]
-    fn test_full_merge_flow_ndjson() {
+    #[file_serial(state_env)]
+    fn test_abort_no_state_file() {
+        let (_temp, repo_dir) = setup_state_env();
+
         let mut config = create_test_config();
         config.output_format = OutputFormat::Ndjson;
-
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        // Start
-        runner.emit_event(ProgressEvent::Start {
-            total_prs: 2,
-            version: "v1.0.0".to_string(),
-            target_branch: "main".to_string(),
-            state_file_path: Some(PathBuf::from("/tmp/state.json")),
-        });
-
-        // First PR conflicts
-        runner.emit_event(ProgressEvent::CherryPickStart {
-            pr_id: 100,
-            commit_id: "aaa111".to_string(),
-            index: 0,
-            total: 2,
-        });
-        runner.emit_event(ProgressEvent::CherryPickSkipped {
-            pr_id: 100,
-            reason: Some("Skipped by user due to unresolvable conflict".to_string()),
-        });
-
-        // Second PR succeeds
-        runner.emit_event(ProgressEvent::CherryPickStart {
-            pr_id: 200,
-            commit_id: "bbb222".to_string(),
-            index: 1,
-            total: 2,
-        });
-        runner.emit_event(ProgressEvent::CherryPickSuccess {
-            pr_id: 200,
-            commit_id: "bbb222".to_string(),
-        });
-
-        // Complete
-        runner.emit_event(ProgressEvent::Complete {
-            successful: 1,
-            failed: 0,
-            skipped: 1,
-        });
+        let result = runner.abort(Some(&repo_dir));
 
+        assert_eq!(result.exit_code, ExitCode::NoStateFile);
         let output = String::from_utf8(buffer).unwrap();
-        let lines: Vec<serde_json::Value> = output
-            .lines()
-            .filter(|l| !l.is_empty())
-            .map(|l| serde_json::from_str(l).unwrap_or_else(|_| panic!("Invalid JSON line: {}", l)))
-            .collect();
+        assert!(output.contains("\"code\":\"no_state_file\""));
 
-        assert_eq!(lines.len(), 6, "Should have 6 NDJSON events");
-        assert_eq!(lines[0]["event"], "start");
-        assert_eq!(lines[0]["state_file_path"], "/tmp/state.json");
-        assert_eq!(lines[1]["event"], "cherry_pick_start");
-        assert_eq!(lines[1]["pr_id"], 100);
-        assert_eq!(lines[2]["event"], "cherry_pick_skipped");
-        assert_eq!(lines[2]["pr_id"], 100);
-        assert_eq!(lines[3]["event"], "cherry_pick_start");
-        assert_eq!(lines[3]["pr_id"], 200);
-        assert_eq!(lines[4]["event"], "cherry_pick_success");
-        assert_eq!(lines[4]["pr_id"], 200);
-        assert_eq!(lines[5]["event"], "complete");
-        assert_eq!(lines[5]["successful"], 1);
-        assert_eq!(lines[5]["skipped"], 1);
+        teardown_state_env();
     }
 
-    /// # Error Event in JSON Format Is Buffered
+    /// # Abort Returns InvalidPhase When Already Completed
     ///
-    /// Verifies that errors are also buffered in JSON mode (not written immediately).
+    /// Verifies abort returns invalid_phase when the merge is already terminal.
     ///
     /// ## Test Scenario
-    /// - Emits error events in JSON format
+    /// - Creates a state file with Completed phase
+    /// - Calls abort
     ///
     /// ## Expected Outcome
-    /// - Buffer remains empty since JSON buffers all events
+    /// - Exit code is InvalidPhase
+    /// - NDJSON output contains "invalid_phase" code
     #[test]
-    fn test_error_event_buffered_in_json_mode() {
-        let mut config = create_test_config();
-        config.output_format = OutputFormat::Json;
+    #[file_serial(state_env)]
+    fn test_abort_invalid_phase() {
+        let (_temp, repo_dir) = setup_state_env();
+        create_state_file_with_phase(&repo_dir, MergePhase::Completed);
 
+        let mut config = create_test_config();
+        config.output_format = OutputFormat::Ndjson;
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_error_with_code("Locked", Some("locked"));
-        runner.emit_error("Generic error");
+        let result = runner.abort(Some(&repo_dir));
 
-        assert!(
-            buffer.is_empty(),
-            "JSON format should buffer error events too"
-        );
+        assert_eq!(result.exit_code, ExitCode::InvalidPhase);
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("\"code\":\"invalid_phase\""));
+        assert!(output.contains("Completed"));
+
+        teardown_state_env();
     }
 
-    /// # Skip Event in Text Format
+    /// # Abort Returns Locked When Lock Is Held
     ///
-    /// Verifies CherryPickSkipped events display correctly in text output
-    /// including the PR ID and skip reason.
+    /// Verifies abort returns locked error when another operation holds the lock.
     ///
     /// ## Test Scenario
-    /// - Emits a CherryPickSkipped event with a reason
+    /// - Acquires a lock on the repo
+    /// - Calls abort
     ///
     /// ## Expected Outcome
-    /// - Output mentions the PR being skipped
+    /// - Exit code is Locked
+    /// - NDJSON output contains "locked" code
     #[test]
-    fn test_skip_event_text_format() {
-        let config = create_test_config();
+    #[file_serial(state_env)]
+    fn test_abort_locked() {
+        let (_temp, repo_dir) = setup_state_env();
+
+        // Acquire lock before calling abort
+        let _lock = LockGuard::acquire(&test_lock_key(), LockKind::Merge).unwrap();
+
+        let mut config = create_test_config();
+        config.output_format = OutputFormat::Ndjson;
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_event(ProgressEvent::CherryPickSkipped {
-            pr_id: 42,
-            reason: Some("Skipped by user due to unresolvable conflict".to_string()),
-        });
+        let result = runner.abort(Some(&repo_dir));
 
+        assert_eq!(result.exit_code, ExitCode::Locked);
         let output = String::from_utf8(buffer).unwrap();
-        assert!(
-            output.contains("42"),
-            "Should mention the PR ID: got '{output}'"
-        );
+        assert!(output.contains("\"code\":\"locked\""));
+
+        teardown_state_env();
     }
 
-    /// # Multiple Errors With Different Codes in NDJSON
+    /// # Abort Lock Is Keyed By The Merge Target
     ///
-    /// Verifies that emitting multiple errors produces separate NDJSON lines,
-    /// each with the correct code.
+    /// Verifies the lock checked by abort comes from the state file's
+    /// repository and target branch rather than the checkout path.
     ///
     /// ## Test Scenario
-    /// - Emits three errors: locked (with code), generic (no code), invalid_phase (with code)
+    /// - Creates a state file for merging into `main`
+    /// - Holds a merge lock on another target, then one on `main` spelled
+    ///   with a `.git` remote URL as another checkout would
     ///
     /// ## Expected Outcome
-    /// - Three NDJSON lines, each valid JSON with correct fields
+    /// - The lock on another target does not block abort
+    /// - The lock on the same target does
     #[test]
-    fn test_multiple_errors_ndjson() {
-        let mut config = create_test_config();
-        config.output_format = OutputFormat::Ndjson;
-
-        let mut buffer = Vec::new();
-        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+    #[file_serial(state_env)]
+    fn test_abort_lock_keyed_by_target() {
+        let (_temp, repo_dir) = setup_state_env();
 
-        runner.emit_error_with_code("Locked", Some("locked"));
-        runner.emit_error("Something went wrong");
-        runner.emit_error_with_code("Wrong phase", Some("invalid_phase"));
+        create_state_file_with_phase(&repo_dir, MergePhase::ReadyForCompletion);
+        let other_target = LockKey::for_repository("org", "project", "repo", "release");
+        let lock = LockGuard::acquire(&other_target, LockKind::Merge).unwrap();
+        assert!(lock.is_some());
+        let mut runner = NonInteractiveRunner::with_writer(create_test_config(), Vec::new());
+        assert_ne!(runner.abort(Some(&repo_dir)).exit_code, ExitCode::Locked);
+        drop(lock);
 
-        let output = String::from_utf8(buffer).unwrap();
-        let lines: Vec<serde_json::Value> = output
-            .lines()
-            .filter(|l| !l.is_empty())
-            .map(|l| serde_json::from_str(l).unwrap())
-            .collect();
+        create_state_file_with_phase(&repo_dir, MergePhase::ReadyForCompletion);
+        let same_target = LockKey::new("https://dev.azure.com/org/project/_git/repo.git", "main");
+        let _lock = LockGuard::acquire(&same_target, LockKind::Merge).unwrap();
+        let mut runner = NonInteractiveRunner::with_writer(create_test_config(), Vec::new());
+        assert_eq!(runner.abort(Some(&repo_dir)).exit_code, ExitCode::Locked);
 
-        assert_eq!(lines.len(), 3);
-        assert_eq!(lines[0]["code"], "locked");
-        assert!(lines[1].get("code").is_none());
-        assert_eq!(lines[2]["code"], "invalid_phase");
+        teardown_state_env();
     }
 
-    /// # Status Idle in NDJSON Format
+    /// # Skip Returns NoStateFile When No State Exists
     ///
-    /// Verifies that idle status is properly formatted in NDJSON output.
+    /// Verifies skip returns the correct error when no state file is found.
     ///
     /// ## Test Scenario
-    /// - Creates a runner with NDJSON output format
-    /// - Calls status with no state file
+    /// - Sets up temp state dir with no state file
+    /// - Calls skip with a repo path
     ///
     /// ## Expected Outcome
-    /// - NDJSON output is valid JSON with idle fields
-    #[test]
-    fn test_status_idle_ndjson_format() {
+    /// - Exit code is NoStateFile
+    /// - NDJSON output contains "no_state_file" code
+    #[tokio::test]
+    #[file_serial(state_env)]
+    async fn test_skip_no_state_file() {
+        let (_temp, repo_dir) = setup_state_env();
+
         let mut config = create_test_config();
         config.output_format = OutputFormat::Ndjson;
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        let temp_dir = tempfile::tempdir().unwrap();
-        let result = runner.status(Some(temp_dir.path()));
-
-        assert!(result.is_success());
+        let result = runner.skip(Some(&repo_dir)).await;
 
+        assert_eq!(result.exit_code, ExitCode::NoStateFile);
         let output = String::from_utf8(buffer).unwrap();
-        let json: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
-        assert_eq!(json["phase"], "idle");
-        assert_eq!(json["status"], "idle");
+        assert!(output.contains("\"code\":\"no_state_file\""));
+
+        teardown_state_env();
     }
 
-    /// # Dependency Analysis Events in NDJSON
+    /// # Skip Returns InvalidPhase When Not Awaiting Conflict
     ///
-    /// Verifies dependency analysis events serialize correctly.
+    /// Verifies skip returns invalid_phase when the merge is not in
+    /// AwaitingConflictResolution phase.
     ///
     /// ## Test Scenario
-    /// - Emits DependencyAnalysisStart, DependencyAnalysisComplete,
-    ///   and DependencyWarning events
+    /// - Creates a state file with CherryPicking phase
+    /// - Calls skip
     ///
     /// ## Expected Outcome
-    /// - All events produce valid NDJSON with correct fields
-    #[test]
-    fn test_dependency_analysis_events_ndjson() {
+    /// - Exit code is InvalidPhase
+    /// - NDJSON output contains "invalid_phase" code
+    #[tokio::test]
+    #[file_serial(state_env)]
+    async fn test_skip_invalid_phase() {
+        let (_temp, repo_dir) = setup_state_env();
+        create_state_file_with_phase(&repo_dir, MergePhase::CherryPicking);
+
         let mut config = create_test_config();
         config.output_format = OutputFormat::Ndjson;
-
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_event(ProgressEvent::DependencyAnalysisStart { pr_count: 5 });
-        runner.emit_event(ProgressEvent::DependencyAnalysisComplete {
-            independent: 3,
-            partial: 1,
-            dependent: 1,
-        });
-        runner.emit_event(ProgressEvent::DependencyWarning {
-            selected_pr_id: 100,
-            selected_pr_title: "Feature A".to_string(),
-            unselected_pr_id: 200,
-            unselected_pr_title: "Feature B".to_string(),
-            is_critical: true,
-            shared_files: vec!["src/lib.rs".to_string()],
-        });
+        let result = runner.skip(Some(&repo_dir)).await;
 
+        assert_eq!(result.exit_code, ExitCode::InvalidPhase);
         let output = String::from_utf8(buffer).unwrap();
-        let lines: Vec<serde_json::Value> = output
-            .lines()
-            .filter(|l| !l.is_empty())
-            .map(|l| serde_json::from_str(l).unwrap())
-            .collect();
+        assert!(output.contains("\"code\":\"invalid_phase\""));
 
-        assert_eq!(lines.len(), 3);
-        assert_eq!(lines[0]["event"], "dependency_analysis_start");
-        assert_eq!(lines[0]["pr_count"], 5);
-        assert_eq!(lines[1]["event"], "dependency_analysis_complete");
-        assert_eq!(lines[1]["independent"], 3);
-        assert_eq!(lines[2]["event"], "dependency_warning");
-        assert!(lines[2]["is_critical"].as_bool().unwrap());
+        teardown_state_env();
     }
 
-    /// # Aborted Event in NDJSON
+    /// # Skip Returns Locked When Lock Is Held
     ///
-    /// Verifies Aborted event serializes correctly with and without message.
+    /// Verifies skip returns locked error when another operation holds the lock.
     ///
     /// ## Test Scenario
-    /// - Emits Aborted events with success=true and success=false
+    /// - Acquires a lock on the repo
+    /// - Calls skip
     ///
     /// ## Expected Outcome
-    /// - NDJSON contains correct success and message fields
-    #[test]
-    fn test_aborted_event_ndjson() {
+    /// - Exit code is Locked
+    /// - NDJSON output contains "locked" code
+    #[tokio::test]
+    #[file_serial(state_env)]
+    async fn test_skip_locked() {
+        let (_temp, repo_dir) = setup_state_env();
+
+        let _lock = LockGuard::acquire(&test_lock_key(), LockKind::Merge).unwrap();
+
         let mut config = create_test_config();
         config.output_format = OutputFormat::Ndjson;
-
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_event(ProgressEvent::Aborted {
-            success: true,
-            message: None,
-        });
-        runner.emit_event(ProgressEvent::Aborted {
-            success: false,
-            message: Some("Failed to clean up".to_string()),
-        });
+        let result = runner.skip(Some(&repo_dir)).await;
 
+        assert_eq!(result.exit_code, ExitCode::Locked);
         let output = String::from_utf8(buffer).unwrap();
-        let lines: Vec<serde_json::Value> = output
-            .lines()
-            .filter(|l| !l.is_empty())
-            .map(|l| serde_json::from_str(l).unwrap())
-            .collect();
+        assert!(output.contains("\"code\":\"locked\""));
 
-        assert_eq!(lines.len(), 2);
-        assert_eq!(lines[0]["event"], "aborted");
-        assert!(lines[0]["success"].as_bool().unwrap());
-        assert!(lines[0].get("message").is_none());
-        assert_eq!(lines[1]["event"], "aborted");
-        assert!(!lines[1]["success"].as_bool().unwrap());
-        assert_eq!(lines[1]["message"], "Failed to clean up");
+        teardown_state_env();
     }
 
-    /// # Cherry-pick Failed Event in Text Format
+    /// # Resolve Returns InvalidPhase When Not Awaiting Conflict
     ///
-    /// Verifies CherryPickFailed events display the error in text output.
+    /// Verifies resolve refuses to run when the merge is not paused on a
+    /// conflict.
     ///
     /// ## Test Scenario
-    /// - Emits a CherryPickFailed event
+    /// - Creates a state file with CherryPicking phase
+    /// - Calls resolve
     ///
     /// ## Expected Outcome
-    /// - Output includes the PR ID and error message
-    #[test]
-    fn test_cherry_pick_failed_event_text() {
-        let config = create_test_config();
+    /// - Exit code is InvalidPhase
+    /// - NDJSON output contains "invalid_phase" code
+    #[tokio::test]
+    #[file_serial(state_env)]
+    async fn test_resolve_invalid_phase() {
+        let (_temp, repo_dir) = setup_state_env();
+        create_state_file_with_phase(&repo_dir, MergePhase::CherryPicking);
+
+        let mut config = create_test_config();
+        config.output_format = OutputFormat::Ndjson;
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        runner.emit_event(ProgressEvent::CherryPickFailed {
-            pr_id: 99,
-            error: "Commit abc123 not found".to_string(),
-        });
+        let tool = ResolveTool::Editor("true".to_string());
+        let result = runner.resolve(Some(&repo_dir), &tool, true, true).await;
 
+        assert_eq!(result.exit_code, ExitCode::InvalidPhase);
         let output = String::from_utf8(buffer).unwrap();
-        assert!(output.contains("99"), "Should include PR ID");
-        assert!(
-            output.contains("not found") || output.contains("abc123"),
-            "Should include error details: got '{output}'"
-        );
-    }
-
-    // -----------------------------------------------------------------------
-    // Stateful tests (require MERGERS_STATE_DIR env, serialized execution)
-    // -----------------------------------------------------------------------
-
-    use crate::core::state::{LockGuard, MergePhase, MergeStateFile, STATE_DIR_ENV};
-    use serial_test::file_serial;
-    use std::fs;
-
-    /// Helper: sets up a temp state dir + repo dir, sets MERGERS_STATE_DIR env var.
-    /// Returns (state_dir, repo_dir) as TempDir handles (kept alive for RAII).
-    fn setup_state_env() -> (tempfile::TempDir, PathBuf) {
-        let temp = tempfile::tempdir().unwrap();
-        let state_dir = temp.path().join("state");
-        let repo_dir = temp.path().join("repo");
-        fs::create_dir_all(&state_dir).unwrap();
-        fs::create_dir_all(&repo_dir).unwrap();
-        unsafe { std::env::set_var(STATE_DIR_ENV, &state_dir) };
-        (temp, repo_dir)
-    }
-
-    fn teardown_state_env() {
-        unsafe { std::env::remove_var(STATE_DIR_ENV) };
-    }
+        assert!(output.contains("\"code\":\"invalid_phase\""));
+        assert!(output.contains("Cannot resolve"));
 
-    /// Creates and saves a state file for the given repo_dir with the given phase.
-    fn create_state_file_with_phase(repo_dir: &Path, phase: MergePhase) {
-        let mut state = MergeStateFile::new(
-            repo_dir.to_path_buf(),
-            None,
-            false,
-            "org".to_string(),
-            "project".to_string(),
-            "repo".to_string(),
-            "dev".to_string(),
-            "main".to_string(),
-            "v1.0.0".to_string(),
-            "Done".to_string(),
-            "merged-".to_string(),
-            false,
-        );
-        state.phase = phase;
-        state.save_for_repo().unwrap();
+        teardown_state_env();
     }
 
-    /// # Abort Returns NoStateFile When No State Exists
+    /// # Continue Returns NoStateFile When No State Exists
     ///
-    /// Verifies abort returns the correct error when no state file is found.
+    /// Verifies continue_merge returns the correct error when no state file is found.
     ///
     /// ## Test Scenario
     /// - Sets up temp state dir with no state file
-    /// - Calls abort with a repo path
+    /// - Calls continue_merge with a repo path
     ///
     /// ## Expected Outcome
     /// - Exit code is NoStateFile
     /// - NDJSON output contains "no_state_file" code
-    #[test]
+    #[tokio::test]
     #[file_serial(state_env)]
-    fn test_abort_no_state_file() {
+    async fn test_continue_no_state_file() {
         let (_temp, repo_dir) = setup_state_env();
 
         let mut config = create_test_config();
@@ -2233,7 +3968,7 @@ mod tests {
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        let result = runner.abort(Some(&repo_dir));
+        let result = runner.continue_merge(Some(&repo_dir)).await;
 
         assert_eq!(result.exit_code, ExitCode::NoStateFile);
         let output = String::from_utf8(buffer).unwrap();
@@ -2242,63 +3977,63 @@ mod tests {
         teardown_state_env();
     }
 
-    /// # Abort Returns InvalidPhase When Already Completed
+    /// # Continue Returns InvalidPhase When Not Awaiting Conflict
     ///
-    /// Verifies abort returns invalid_phase when the merge is already terminal.
+    /// Verifies continue_merge returns invalid_phase when the merge is not in
+    /// AwaitingConflictResolution phase.
     ///
-    /// ## Test Scenario
-    /// - Creates a state file with Completed phase
-    /// - Calls abort
+    /// ## Test Scenario
+    /// - Creates a state file with CherryPicking phase
+    /// - Calls continue_merge
     ///
     /// ## Expected Outcome
     /// - Exit code is InvalidPhase
     /// - NDJSON output contains "invalid_phase" code
-    #[test]
+    #[tokio::test]
     #[file_serial(state_env)]
-    fn test_abort_invalid_phase() {
+    async fn test_continue_invalid_phase() {
         let (_temp, repo_dir) = setup_state_env();
-        create_state_file_with_phase(&repo_dir, MergePhase::Completed);
+        create_state_file_with_phase(&repo_dir, MergePhase::CherryPicking);
 
         let mut config = create_test_config();
         config.output_format = OutputFormat::Ndjson;
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        let result = runner.abort(Some(&repo_dir));
+        let result = runner.continue_merge(Some(&repo_dir)).await;
 
         assert_eq!(result.exit_code, ExitCode::InvalidPhase);
         let output = String::from_utf8(buffer).unwrap();
         assert!(output.contains("\"code\":\"invalid_phase\""));
-        assert!(output.contains("Completed"));
 
         teardown_state_env();
     }
 
-    /// # Abort Returns Locked When Lock Is Held
+    /// # Continue Returns Locked When Lock Is Held
     ///
-    /// Verifies abort returns locked error when another operation holds the lock.
+    /// Verifies continue_merge returns locked error when another operation
+    /// holds the lock.
     ///
     /// ## Test Scenario
     /// - Acquires a lock on the repo
-    /// - Calls abort
+    /// - Calls continue_merge
     ///
     /// ## Expected Outcome
     /// - Exit code is Locked
     /// - NDJSON output contains "locked" code
-    #[test]
+    #[tokio::test]
     #[file_serial(state_env)]
-    fn test_abort_locked() {
+    async fn test_continue_locked() {
         let (_temp, repo_dir) = setup_state_env();
 
-        // Acquire lock before calling abort
-        let _lock = LockGuard::acquire(&repo_dir).unwrap();
+        let _lock = LockGuard::acquire(&test_lock_key(), LockKind::Merge).unwrap();
 
         let mut config = create_test_config();
         config.output_format = OutputFormat::Ndjson;
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        let result = runner.abort(Some(&repo_dir));
+        let result = runner.continue_merge(Some(&repo_dir)).await;
 
         assert_eq!(result.exit_code, ExitCode::Locked);
         let output = String::from_utf8(buffer).unwrap();
@@ -2307,114 +4042,253 @@ mod tests {
         teardown_state_env();
     }
 
-    /// # Skip Returns NoStateFile When No State Exists
+    /// Git backend whose conflicts are resolved or not, as a test needs.
+    struct ResolvedGit(bool);
+
+    impl GitOperations for ResolvedGit {
+        fn cherry_pick(&self, _: &Path, _: &str, _: bool) -> Result<git::CherryPickResult> {
+            bail!("not used")
+        }
+
+        fn get_commit_info(&self, _: &Path, _: &str) -> Result<git::CommitInfo> {
+            bail!("not used")
+        }
+
+        fn check_conflicts_resolved(&self, _: &Path) -> Result<bool> {
+            Ok(self.0)
+        }
+
+        fn continue_cherry_pick(&self, _: &Path, _: bool) -> Result<()> {
+            Ok(())
+        }
+
+        fn abort_cherry_pick(&self, _: &Path) -> Result<()> {
+            Ok(())
+        }
+
+        fn create_branch(&self, _: &Path, _: &str) -> Result<()> {
+            bail!("not used")
+        }
+
+        fn fetch_commits(&self, _: &Path, _: &[String]) -> Result<()> {
+            bail!("not used")
+        }
+
+        fn get_branch_history(&self, _: &Path, _: &str) -> Result<git::CommitHistory> {
+            bail!("not used")
+        }
+    }
+
+    /// Saves a merge paused on a conflict in `f.txt` of PR 7.
+    fn create_paused_state_file(repo_dir: &Path) {
+        create_state_file_with_phase(repo_dir, MergePhase::CherryPicking);
+        let mut state = MergeStateFile::load_and_validate_for_repo(repo_dir)
+            .unwrap()
+            .unwrap();
+        state.cherry_pick_items = vec![crate::core::state::StateCherryPickItem {
+            commit_id: "abc123".to_string(),
+            pr_id: 7,
+            pr_title: "Feature".to_string(),
+            status: StateItemStatus::Conflict,
+            work_item_ids: Vec::new(),
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: true,
+        }];
+        state.phase = MergePhase::AwaitingConflictResolution;
+        state.conflicted_files = Some(vec!["f.txt".to_string()]);
+        state.save_for_repo().unwrap();
+    }
+
+    /// # Continue Lists Unresolved Conflicts
     ///
-    /// Verifies skip returns the correct error when no state file is found.
+    /// Verifies continue_merge reports the files that are still conflicted.
     ///
     /// ## Test Scenario
-    /// - Sets up temp state dir with no state file
-    /// - Calls skip with a repo path
+    /// - Creates a merge paused on a conflict in `f.txt`
+    /// - Calls continue_merge with a git backend reporting unresolved conflicts
     ///
     /// ## Expected Outcome
-    /// - Exit code is NoStateFile
-    /// - NDJSON output contains "no_state_file" code
+    /// - Exit code is Conflict
+    /// - NDJSON output lists `f.txt` as conflicted
+    /// - The merge stays paused
     #[tokio::test]
     #[file_serial(state_env)]
-    async fn test_skip_no_state_file() {
+    async fn test_continue_unresolved_conflicts() {
         let (_temp, repo_dir) = setup_state_env();
+        create_paused_state_file(&repo_dir);
 
         let mut config = create_test_config();
         config.output_format = OutputFormat::Ndjson;
         let mut buffer = Vec::new();
-        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer)
+            .with_git(Arc::new(ResolvedGit(false)));
 
-        let result = runner.skip(Some(&repo_dir)).await;
+        let result = runner.continue_merge(Some(&repo_dir)).await;
 
-        assert_eq!(result.exit_code, ExitCode::NoStateFile);
+        assert_eq!(result.exit_code, ExitCode::Conflict);
         let output = String::from_utf8(buffer).unwrap();
-        assert!(output.contains("\"code\":\"no_state_file\""));
+        assert!(
+            output.contains("\"code\":\"conflicts_unresolved\""),
+            "{}",
+            output
+        );
+        assert!(
+            output.contains("\"conflicted_files\":[\"f.txt\"]"),
+            "{}",
+            output
+        );
+        let state = MergeStateFile::load_and_validate_for_repo(&repo_dir)
+            .unwrap()
+            .unwrap();
+        assert_eq!(state.phase, MergePhase::AwaitingConflictResolution);
 
         teardown_state_env();
     }
 
-    /// # Skip Returns InvalidPhase When Not Awaiting Conflict
+    /// # Continue Prints A Summary
     ///
-    /// Verifies skip returns invalid_phase when the merge is not in
-    /// AwaitingConflictResolution phase.
+    /// Verifies continue_merge finishes the paused cherry-pick and summarizes
+    /// the merge.
     ///
     /// ## Test Scenario
-    /// - Creates a state file with CherryPicking phase
-    /// - Calls skip
+    /// - Creates a merge paused on the conflict of its only PR
+    /// - Calls continue_merge with a git backend reporting resolved conflicts
     ///
     /// ## Expected Outcome
-    /// - Exit code is InvalidPhase
-    /// - NDJSON output contains "invalid_phase" code
+    /// - Exit code is Success
+    /// - The NDJSON summary reports success with PR 7 picked
     #[tokio::test]
     #[file_serial(state_env)]
-    async fn test_skip_invalid_phase() {
+    async fn test_continue_summary() {
         let (_temp, repo_dir) = setup_state_env();
-        create_state_file_with_phase(&repo_dir, MergePhase::CherryPicking);
+        create_paused_state_file(&repo_dir);
 
         let mut config = create_test_config();
         config.output_format = OutputFormat::Ndjson;
         let mut buffer = Vec::new();
-        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer)
+            .with_git(Arc::new(ResolvedGit(true)));
 
-        let result = runner.skip(Some(&repo_dir)).await;
+        let result = runner.continue_merge(Some(&repo_dir)).await;
 
-        assert_eq!(result.exit_code, ExitCode::InvalidPhase);
+        assert_eq!(result.exit_code, ExitCode::Success);
         let output = String::from_utf8(buffer).unwrap();
-        assert!(output.contains("\"code\":\"invalid_phase\""));
+        let summary: SummaryInfo = serde_json::from_str(output.lines().last().unwrap()).unwrap();
+        assert_eq!(summary.result, SummaryResult::Success);
+        assert_eq!(summary.counts, SummaryCounts::new(1, 0, 0, 0));
+        let items = summary.items.unwrap();
+        assert_eq!(
+            (items[0].pr_id, &items[0].status),
+            (7, &ItemStatus::Success)
+        );
 
         teardown_state_env();
     }
 
-    /// # Skip Returns Locked When Lock Is Held
+    /// # Continue Writes A JUnit Report
     ///
-    /// Verifies skip returns locked error when another operation holds the lock.
+    /// Verifies `--report junit:PATH` is written when the cherry-picks stop.
     ///
     /// ## Test Scenario
-    /// - Acquires a lock on the repo
-    /// - Calls skip
+    /// - Creates a merge paused on the conflict of its only PR
+    /// - Calls continue_merge with a JUnit report configured and a git
+    ///   backend reporting resolved conflicts
     ///
     /// ## Expected Outcome
-    /// - Exit code is Locked
-    /// - NDJSON output contains "locked" code
+    /// - The report lists PR 7 as a passed test case
     #[tokio::test]
     #[file_serial(state_env)]
-    async fn test_skip_locked() {
-        let (_temp, repo_dir) = setup_state_env();
+    async fn test_continue_junit_report() {
+        let (temp, repo_dir) = setup_state_env();
+        create_paused_state_file(&repo_dir);
+        let report_path = temp.path().join("reports").join("merge.xml");
+
+        let mut config = create_test_config();
+        config.quiet = true;
+        config.reports = vec![crate::core::output::ReportTarget::Junit(
+            report_path.clone(),
+        )];
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer)
+            .with_git(Arc::new(ResolvedGit(true)));
+
+        let result = runner.continue_merge(Some(&repo_dir)).await;
 
-        let _lock = LockGuard::acquire(&repo_dir).unwrap();
+        assert_eq!(result.exit_code, ExitCode::Success);
+        let report = fs::read_to_string(report_path).unwrap();
+        assert!(report.contains("tests=\"1\" failures=\"0\" skipped=\"0\""));
+        assert!(report.contains("name=\"PR #7: Feature\""));
+
+        teardown_state_env();
+    }
+
+    /// # Conflict Report Lifecycle
+    ///
+    /// Verifies the conflict report saved next to the state file is shown
+    /// by status and removed once the merge is past the conflict.
+    ///
+    /// ## Test Scenario
+    /// - Creates a merge paused on the conflict of PR 7 with its report saved
+    /// - Reads the status as JSON, then continues with a git backend
+    ///   reporting resolved conflicts
+    ///
+    /// ## Expected Outcome
+    /// - The status conflict carries the report and its path
+    /// - The report file is removed after continuing
+    #[tokio::test]
+    #[file_serial(state_env)]
+    async fn test_conflict_report_lifecycle() {
+        let (_temp, repo_dir) = setup_state_env();
+        create_paused_state_file(&repo_dir);
+        let state = MergeStateFile::load_for_repo(&repo_dir).unwrap().unwrap();
+        let report = ConflictReport::from_changes(&state, &Default::default(), &Default::default());
+        let report_path = ConflictReport::path_for_repo(&repo_dir).unwrap();
+        report.write(&report_path).unwrap();
 
         let mut config = create_test_config();
-        config.output_format = OutputFormat::Ndjson;
+        config.output_format = OutputFormat::Json;
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+        let result = runner.status(Some(&repo_dir));
+        assert_eq!(result.exit_code, ExitCode::Conflict);
+        let json: serde_json::Value =
+            serde_json::from_str(&String::from_utf8(buffer).unwrap()).unwrap();
+        assert_eq!(json["conflict"]["report"]["pr_id"], 7);
+        assert_eq!(
+            json["conflict"]["report_path"],
+            report_path.display().to_string()
+        );
 
-        let result = runner.skip(Some(&repo_dir)).await;
+        let mut config = create_test_config();
+        config.quiet = true;
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer)
+            .with_git(Arc::new(ResolvedGit(true)));
+        let result = runner.continue_merge(Some(&repo_dir)).await;
 
-        assert_eq!(result.exit_code, ExitCode::Locked);
-        let output = String::from_utf8(buffer).unwrap();
-        assert!(output.contains("\"code\":\"locked\""));
+        assert_eq!(result.exit_code, ExitCode::Success);
+        assert!(!report_path.exists());
 
         teardown_state_env();
     }
 
-    /// # Continue Returns NoStateFile When No State Exists
+    /// # Complete Returns NoStateFile When No State Exists
     ///
-    /// Verifies continue_merge returns the correct error when no state file is found.
+    /// Verifies complete returns the correct error when no state file is found.
     ///
     /// ## Test Scenario
     /// - Sets up temp state dir with no state file
-    /// - Calls continue_merge with a repo path
+    /// - Calls complete with a repo path
     ///
     /// ## Expected Outcome
     /// - Exit code is NoStateFile
     /// - NDJSON output contains "no_state_file" code
     #[tokio::test]
     #[file_serial(state_env)]
-    async fn test_continue_no_state_file() {
+    async fn test_complete_no_state_file() {
         let (_temp, repo_dir) = setup_state_env();
 
         let mut config = create_test_config();
@@ -2422,7 +4296,7 @@ mod tests {
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        let result = runner.continue_merge(Some(&repo_dir)).await;
+        let result = runner.complete(Some(&repo_dir), "Done").await;
 
         assert_eq!(result.exit_code, ExitCode::NoStateFile);
         let output = String::from_utf8(buffer).unwrap();
@@ -2431,21 +4305,21 @@ mod tests {
         teardown_state_env();
     }
 
-    /// # Continue Returns InvalidPhase When Not Awaiting Conflict
+    /// # Complete Returns InvalidPhase When Not Ready
     ///
-    /// Verifies continue_merge returns invalid_phase when the merge is not in
-    /// AwaitingConflictResolution phase.
+    /// Verifies complete returns invalid_phase when the merge is not in
+    /// ReadyForCompletion phase.
     ///
     /// ## Test Scenario
     /// - Creates a state file with CherryPicking phase
-    /// - Calls continue_merge
+    /// - Calls complete
     ///
     /// ## Expected Outcome
     /// - Exit code is InvalidPhase
     /// - NDJSON output contains "invalid_phase" code
     #[tokio::test]
     #[file_serial(state_env)]
-    async fn test_continue_invalid_phase() {
+    async fn test_complete_invalid_phase() {
         let (_temp, repo_dir) = setup_state_env();
         create_state_file_with_phase(&repo_dir, MergePhase::CherryPicking);
 
@@ -2454,7 +4328,7 @@ mod tests {
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        let result = runner.continue_merge(Some(&repo_dir)).await;
+        let result = runner.complete(Some(&repo_dir), "Done").await;
 
         assert_eq!(result.exit_code, ExitCode::InvalidPhase);
         let output = String::from_utf8(buffer).unwrap();
@@ -2463,31 +4337,30 @@ mod tests {
         teardown_state_env();
     }
 
-    /// # Continue Returns Locked When Lock Is Held
+    /// # Complete Returns Locked When Lock Is Held
     ///
-    /// Verifies continue_merge returns locked error when another operation
-    /// holds the lock.
+    /// Verifies complete returns locked error when another operation holds the lock.
     ///
     /// ## Test Scenario
     /// - Acquires a lock on the repo
-    /// - Calls continue_merge
+    /// - Calls complete
     ///
     /// ## Expected Outcome
     /// - Exit code is Locked
     /// - NDJSON output contains "locked" code
     #[tokio::test]
     #[file_serial(state_env)]
-    async fn test_continue_locked() {
+    async fn test_complete_locked() {
         let (_temp, repo_dir) = setup_state_env();
 
-        let _lock = LockGuard::acquire(&repo_dir).unwrap();
+        let _lock = LockGuard::acquire(&test_lock_key(), LockKind::Merge).unwrap();
 
         let mut config = create_test_config();
         config.output_format = OutputFormat::Ndjson;
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        let result = runner.continue_merge(Some(&repo_dir)).await;
+        let result = runner.complete(Some(&repo_dir), "Done").await;
 
         assert_eq!(result.exit_code, ExitCode::Locked);
         let output = String::from_utf8(buffer).unwrap();
@@ -2496,28 +4369,51 @@ mod tests {
         teardown_state_env();
     }
 
-    /// # Complete Returns NoStateFile When No State Exists
+    /// # Promote Requires A Local Repository
     ///
-    /// Verifies complete returns the correct error when no state file is found.
+    /// Verifies promote fails without a local repository.
     ///
     /// ## Test Scenario
-    /// - Sets up temp state dir with no state file
-    /// - Calls complete with a repo path
+    /// - Calls promote with no `local_repo` configured
     ///
     /// ## Expected Outcome
-    /// - Exit code is NoStateFile
-    /// - NDJSON output contains "no_state_file" code
+    /// - Exit code is GeneralError
+    #[tokio::test]
+    async fn test_promote_requires_local_repo() {
+        let mut config = create_test_config();
+        config.local_repo = None;
+        config.output_format = OutputFormat::Ndjson;
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+
+        let result = runner.promote("1.0.0-rc1").await;
+
+        assert_eq!(result.exit_code, ExitCode::GeneralError);
+        assert!(result.message.unwrap().contains("local repository"));
+    }
+
+    /// # Promote Returns NoStateFile For Unknown Release Candidate
+    ///
+    /// Verifies promote fails when the RC worktree has no state file.
+    ///
+    /// ## Test Scenario
+    /// - Configures a local repo without any RC state
+    /// - Calls promote
+    ///
+    /// ## Expected Outcome
+    /// - Exit code is NoStateFile and the NDJSON output has the matching code
     #[tokio::test]
     #[file_serial(state_env)]
-    async fn test_complete_no_state_file() {
+    async fn test_promote_no_state_file() {
         let (_temp, repo_dir) = setup_state_env();
 
         let mut config = create_test_config();
+        config.local_repo = Some(repo_dir);
         config.output_format = OutputFormat::Ndjson;
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        let result = runner.complete(Some(&repo_dir), "Done").await;
+        let result = runner.promote("1.0.0-rc1").await;
 
         assert_eq!(result.exit_code, ExitCode::NoStateFile);
         let output = String::from_utf8(buffer).unwrap();
@@ -2526,30 +4422,31 @@ mod tests {
         teardown_state_env();
     }
 
-    /// # Complete Returns InvalidPhase When Not Ready
+    /// # Promote Rejects Unfinished Release Candidate
     ///
-    /// Verifies complete returns invalid_phase when the merge is not in
-    /// ReadyForCompletion phase.
+    /// Verifies promote refuses an RC that is still cherry-picking.
     ///
     /// ## Test Scenario
-    /// - Creates a state file with CherryPicking phase
-    /// - Calls complete
+    /// - Saves an RC state file in the CherryPicking phase
+    /// - Calls promote
     ///
     /// ## Expected Outcome
     /// - Exit code is InvalidPhase
-    /// - NDJSON output contains "invalid_phase" code
     #[tokio::test]
     #[file_serial(state_env)]
-    async fn test_complete_invalid_phase() {
+    async fn test_promote_invalid_phase() {
         let (_temp, repo_dir) = setup_state_env();
-        create_state_file_with_phase(&repo_dir, MergePhase::CherryPicking);
+        let rc_repo = repo_dir.join("next-1.0.0-rc1");
+        fs::create_dir_all(&rc_repo).unwrap();
+        create_state_file_with_phase(&rc_repo, MergePhase::CherryPicking);
 
         let mut config = create_test_config();
+        config.local_repo = Some(repo_dir);
         config.output_format = OutputFormat::Ndjson;
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        let result = runner.complete(Some(&repo_dir), "Done").await;
+        let result = runner.promote("1.0.0-rc1").await;
 
         assert_eq!(result.exit_code, ExitCode::InvalidPhase);
         let output = String::from_utf8(buffer).unwrap();
@@ -2558,30 +4455,34 @@ mod tests {
         teardown_state_env();
     }
 
-    /// # Complete Returns Locked When Lock Is Held
+    /// # Promote Returns Locked When Lock Is Held
     ///
-    /// Verifies complete returns locked error when another operation holds the lock.
+    /// Verifies promote does not run alongside another merge operation.
     ///
     /// ## Test Scenario
-    /// - Acquires a lock on the repo
-    /// - Calls complete
+    /// - Saves a finished RC state file
+    /// - Acquires the merge lock of the target, then calls promote
     ///
     /// ## Expected Outcome
     /// - Exit code is Locked
     /// - NDJSON output contains "locked" code
     #[tokio::test]
     #[file_serial(state_env)]
-    async fn test_complete_locked() {
+    async fn test_promote_locked() {
         let (_temp, repo_dir) = setup_state_env();
+        let rc_repo = repo_dir.join("next-1.0.0-rc1");
+        fs::create_dir_all(&rc_repo).unwrap();
+        create_state_file_with_phase(&rc_repo, MergePhase::ReadyForCompletion);
 
-        let _lock = LockGuard::acquire(&repo_dir).unwrap();
+        let _lock = LockGuard::acquire(&test_lock_key(), LockKind::Merge).unwrap();
 
         let mut config = create_test_config();
+        config.local_repo = Some(repo_dir);
         config.output_format = OutputFormat::Ndjson;
         let mut buffer = Vec::new();
         let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
 
-        let result = runner.complete(Some(&repo_dir), "Done").await;
+        let result = runner.promote("1.0.0-rc1").await;
 
         assert_eq!(result.exit_code, ExitCode::Locked);
         let output = String::from_utf8(buffer).unwrap();
@@ -2589,4 +4490,181 @@ mod tests {
 
         teardown_state_env();
     }
+
+    /// Creates a repository paused in a conflicting cherry-pick of `f.txt`,
+    /// with a state file awaiting its resolution.
+    fn conflicted_merge(repo: &Path) -> MergeStateFile {
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .current_dir(repo)
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(output.status.success() || args[0] == "cherry-pick");
+        };
+        git(&["init", "-q", "-b", "main"]);
+        git(&["config", "user.name", "Test User"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "commit.gpgsign", "false"]);
+        fs::write(repo.join("f.txt"), "base\n").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "Initial"]);
+        git(&["checkout", "-q", "-b", "feature"]);
+        fs::write(repo.join("f.txt"), "feature\n").unwrap();
+        git(&["commit", "-q", "-am", "Feature"]);
+        git(&["checkout", "-q", "main"]);
+        fs::write(repo.join("f.txt"), "main\n").unwrap();
+        git(&["commit", "-q", "-am", "Main"]);
+        git(&["cherry-pick", "feature"]);
+
+        let mut state = MergeStateFile::new(
+            repo.to_path_buf(),
+            None,
+            false,
+            "org".to_string(),
+            "project".to_string(),
+            "repo".to_string(),
+            "dev".to_string(),
+            "main".to_string(),
+            "v1.0.0".to_string(),
+            "Done".to_string(),
+            "merged-".to_string(),
+            false,
+        );
+        state.cherry_pick_items = vec![crate::core::state::StateCherryPickItem {
+            commit_id: "feature".to_string(),
+            pr_id: 7,
+            pr_title: "Feature".to_string(),
+            status: StateItemStatus::Conflict,
+            work_item_ids: Vec::new(),
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: true,
+        }];
+        state.phase = MergePhase::AwaitingConflictResolution;
+        state.conflicted_files = Some(vec!["f.txt".to_string()]);
+        state
+    }
+
+    /// # Settling Conflicts After the Timeout
+    ///
+    /// Verifies the unattended conflict policy's actions on a paused merge.
+    ///
+    /// ## Test Scenario
+    /// - Pauses a cherry-pick on a conflict nobody resolves
+    /// - Settles it with a short wait, once keeping theirs and once skipping
+    ///
+    /// ## Expected Outcome
+    /// - Keeping theirs commits the picked version and marks the PR picked
+    /// - Skipping drops the cherry-pick and marks the PR skipped
+    /// - Waiting and settled events are emitted for each decision
+    #[test]
+    fn test_settle_conflict_after_timeout() {
+        use crate::core::operations::ConflictTimeoutAction;
+        use std::time::Duration;
+
+        for (action, expected) in [
+            (ConflictTimeoutAction::Theirs, ConflictDecision::KeptTheirs),
+            (ConflictTimeoutAction::Skip, ConflictDecision::Skipped),
+        ] {
+            let temp = tempfile::tempdir().unwrap();
+            let repo = temp.path();
+            let state = conflicted_merge(repo);
+            let policy = ConflictTimeout::new(Duration::from_millis(30), action);
+
+            let mut config = create_test_config();
+            config.output_format = OutputFormat::Ndjson;
+            config.conflict_timeout = Some(policy);
+            let mut buffer = Vec::new();
+            let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+            let mut engine = runner.create_engine(runner.create_client().unwrap());
+            engine.state_manager_mut().set_state_file(state);
+            let conflict = ConflictInfo::new(
+                7,
+                "Feature".to_string(),
+                "feature".to_string(),
+                vec!["f.txt".to_string()],
+                repo.to_path_buf(),
+            );
+
+            let decision = runner
+                .settle_conflict(&mut engine, &conflict, policy)
+                .unwrap();
+            assert_eq!(decision, expected);
+
+            let state = engine.state_manager().state_file().unwrap();
+            assert_eq!(state.current_index, 1);
+            assert_eq!(state.phase, MergePhase::CherryPicking);
+            assert!(state.conflicted_files.is_none());
+            assert!(!git::cherry_pick_in_progress(repo));
+            let content = fs::read_to_string(repo.join("f.txt")).unwrap();
+            if action == ConflictTimeoutAction::Theirs {
+                assert_eq!(state.cherry_pick_items[0].status, StateItemStatus::Success);
+                assert!(state.cherry_pick_items[0].picked_commit_id.is_some());
+                assert_eq!(content, "feature\n");
+            } else {
+                assert_eq!(state.cherry_pick_items[0].status, StateItemStatus::Skipped);
+                assert_eq!(content, "main\n");
+            }
+
+            drop(runner);
+            let output = String::from_utf8(buffer).unwrap();
+            assert!(output.contains("\"event\":\"conflict_waiting\""));
+            assert!(output.contains(&format!("\"action\":\"{}\"", action)));
+            assert!(output.contains("\"event\":\"conflict_settled\""));
+        }
+    }
+
+    /// # Chat Notifications On Completion
+    ///
+    /// Tests posting the summary of a finished merge to the webhooks.
+    ///
+    /// ## Test Scenario
+    /// - Sends without notifications configured
+    /// - Sends with a Slack webhook that is not a valid URL
+    ///
+    /// ## Expected Outcome
+    /// - Nothing is emitted without notifications
+    /// - A failed notification event names the channel and the error
+    #[tokio::test]
+    async fn test_send_notifications() {
+        let temp = tempfile::tempdir().unwrap();
+        let state = MergeStateFile::new(
+            temp.path().to_path_buf(),
+            None,
+            false,
+            "org".to_string(),
+            "project".to_string(),
+            "repo".to_string(),
+            "dev".to_string(),
+            "main".to_string(),
+            "v1.0.0".to_string(),
+            "Done".to_string(),
+            "merged-".to_string(),
+            false,
+        );
+
+        let mut config = create_test_config();
+        config.output_format = OutputFormat::Ndjson;
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config.clone(), &mut buffer);
+        runner.send_notifications(&state).await;
+        drop(runner);
+        assert!(buffer.is_empty());
+
+        config.notifications = Some(crate::notify::NotificationsConfig {
+            slack_webhook: Some("not a url".to_string()),
+            ..Default::default()
+        });
+        let mut buffer = Vec::new();
+        let mut runner = NonInteractiveRunner::with_writer(config, &mut buffer);
+        runner.send_notifications(&state).await;
+        drop(runner);
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("\"event\":\"notification_sent\""));
+        assert!(output.contains("\"channel\":\"slack\""));
+        assert!(output.contains("Invalid Slack webhook URL"));
+    }
 }
@@ -1,13 +1,25 @@
 //! Release notes runner for CLI usage.
 //!
-//! Generates release notes from Azure DevOps PR labels and work items.
+//! Generates release notes from Azure DevOps PR labels and work items, or
+//! offline from the state file of a finished merge.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 use anyhow::{Context, Result};
 
-use crate::api::{AzureDevOpsClient, extract_merged_tags, filter_prs_with_tag};
-use crate::models::ReleaseNotesOutputFormat;
+use crate::api::{
+    AzureDevOpsClient, PublishedWikiPage, UrlBuilder, UrlTemplates, WikiPageTarget,
+    extract_merged_tags, filter_prs_with_tag,
+};
+use crate::cache::WorkItemCache;
+use crate::core::state::MergeStateFile;
+use crate::models::{
+    PreviouslyReleased, PullRequestWithWorkItems, ReleaseNotesOutputFormat, TaskGroup,
+};
 use crate::release_notes;
-use crate::release_notes::cache::WorkItemCache;
+use crate::release_notes::{PublishedTasks, ReleaseNotesLocale, TaskPatterns};
+use crate::utils::{ClipboardOptions, CopyDestination, copy_text};
 
 /// Configuration for the release notes runner.
 pub struct ReleaseNotesRunnerConfig {
@@ -22,10 +34,40 @@ pub struct ReleaseNotesRunnerConfig {
     pub output_format: ReleaseNotesOutputFormat,
     pub grouped: bool,
     pub include_prs: bool,
+    /// List PRs without work items in an "Unreferenced changes" section.
+    pub include_unreferenced: bool,
     pub copy_to_clipboard: bool,
+    /// Clipboard method and file fallback used when copying.
+    pub clipboard: ClipboardOptions,
     pub no_cache: bool,
     pub max_concurrent_network: usize,
     pub max_concurrent_processing: usize,
+    /// Azure DevOps REST API version override.
+    pub api_version: Option<String>,
+    /// Custom pull request and work item link layouts.
+    pub url_templates: UrlTemplates,
+    /// Patterns of work item references in PR titles and descriptions.
+    pub task_patterns: TaskPatterns,
+    /// Headings of markdown and plain output.
+    pub locale: ReleaseNotesLocale,
+    /// Existing changelog whose work items are treated as previously released.
+    pub changelog: Option<PathBuf>,
+    /// Whether previously released work items are left out or marked.
+    pub previously_released: PreviouslyReleased,
+    /// Separate PAT for publishing to the wiki (`None` to use `pat`).
+    pub write_pat: Option<String>,
+    /// Wiki page the markdown notes are published to.
+    pub wiki: Option<WikiPageTarget>,
+}
+
+/// Release notes generated by [`ReleaseNotesRunner::run`].
+pub struct ReleaseNotesOutput {
+    /// Version the notes are for, without the tag prefix.
+    pub version: String,
+    /// Notes in the configured output format.
+    pub output: String,
+    /// Notes as markdown, the format published to the wiki.
+    pub markdown: String,
 }
 
 /// Release notes runner.
@@ -38,13 +80,8 @@ impl ReleaseNotesRunner {
         Self { config }
     }
 
-    pub async fn run(&self) -> Result<String> {
-        let client = AzureDevOpsClient::new(
-            self.config.organization.clone(),
-            self.config.project.clone(),
-            self.config.repository.clone(),
-            self.config.pat.clone(),
-        )?;
+    pub async fn run(&self) -> Result<ReleaseNotesOutput> {
+        let client = self.create_client()?;
 
         tracing::info!("Fetching pull requests from Azure DevOps...");
         let all_prs = client
@@ -60,7 +97,7 @@ impl ReleaseNotesRunner {
             );
         }
 
-        let (target_tag, _version_label) = self.resolve_target_tag(&all_tags)?;
+        let (target_tag, version) = self.resolve_target_tag(&all_tags)?;
 
         let tagged_prs = filter_prs_with_tag(&all_prs, &target_tag);
 
@@ -71,33 +108,142 @@ impl ReleaseNotesRunner {
         tracing::info!("Found {} PR(s) with tag '{}'", tagged_prs.len(), target_tag);
 
         let owned_prs: Vec<_> = tagged_prs.into_iter().cloned().collect();
-        let prs_with_wi = client
+        let mut prs_with_wi = client
             .fetch_work_items_for_prs_parallel(
                 &owned_prs,
                 self.config.max_concurrent_network,
                 self.config.max_concurrent_processing,
             )
             .await;
+        let sections = self.link_task_references(&client, &mut prs_with_wi).await?;
 
         if !self.config.no_cache {
             self.update_cache(&prs_with_wi);
         }
 
-        let entries = release_notes::build_entries_from_prs(
-            &prs_with_wi,
+        let urls = UrlBuilder::new(
             &self.config.organization,
             &self.config.project,
-        );
+            &self.config.repository,
+        )
+        .with_templates(self.config.url_templates.clone());
+        let mut entries = release_notes::build_entries_from_prs(&prs_with_wi, &urls);
+        release_notes::route_to_sections(&mut entries, &sections);
+        if let Some(changelog) = &self.config.changelog {
+            let published = PublishedTasks::load(changelog, &version)?;
+            let count = published.apply(&mut entries, self.config.previously_released);
+            if count > 0 {
+                tracing::info!(
+                    "{} work item(s) already listed in {} ({})",
+                    count,
+                    changelog.display(),
+                    self.config.previously_released
+                );
+            }
+        }
+        let unreferenced = self
+            .config
+            .include_unreferenced
+            .then(|| release_notes::collect_unreferenced(&prs_with_wi, &urls));
+
+        let format = |output_format| {
+            release_notes::format_output(
+                &entries,
+                unreferenced.as_deref(),
+                output_format,
+                self.config.grouped,
+                self.config.include_prs,
+                &self.config.locale,
+            )
+        };
+        let output = format(self.config.output_format)?;
+        let markdown = match self.config.output_format {
+            ReleaseNotesOutputFormat::Markdown => output.clone(),
+            _ => format(ReleaseNotesOutputFormat::Markdown)?,
+        };
+        Ok(ReleaseNotesOutput {
+            version,
+            output,
+            markdown,
+        })
+    }
+
+    /// Publishes the markdown notes to the configured wiki page.
+    ///
+    /// Returns `None` when no wiki page is configured.
+    pub async fn publish_to_wiki(
+        &self,
+        notes: &ReleaseNotesOutput,
+    ) -> Result<Option<PublishedWikiPage>> {
+        let Some(target) = &self.config.wiki else {
+            return Ok(None);
+        };
+        let path = target.page_path(&notes.version);
+        let page = self
+            .create_client()?
+            .publish_wiki_page(
+                target,
+                &path,
+                &notes.markdown,
+                &format!("Release notes for {}", notes.version),
+            )
+            .await?;
+        tracing::info!("Release notes published to {}", page);
+        Ok(Some(page))
+    }
 
-        let formatted =
-            release_notes::format_output(&entries, self.config.output_format, self.config.grouped)?;
+    fn create_client(&self) -> Result<AzureDevOpsClient> {
+        let client = AzureDevOpsClient::new_with_api_version(
+            self.config.organization.clone(),
+            self.config.project.clone(),
+            self.config.repository.clone(),
+            self.config.pat.clone().into(),
+            self.config.api_version.clone(),
+        )?
+        .with_max_concurrent_requests(self.config.max_concurrent_network);
+        Ok(match &self.config.write_pat {
+            Some(write_pat) => client.with_write_pat(write_pat.clone().into()),
+            None => client,
+        })
+    }
 
-        if self.config.copy_to_clipboard {
-            release_notes::copy_to_clipboard(&formatted)?;
-            tracing::info!("Output copied to clipboard");
+    /// Adds the work items referenced in PR text by the configured patterns,
+    /// returning the sections they are routed to.
+    async fn link_task_references(
+        &self,
+        client: &AzureDevOpsClient,
+        prs_with_wi: &mut [PullRequestWithWorkItems],
+    ) -> Result<HashMap<i32, TaskGroup>> {
+        let patterns = &self.config.task_patterns;
+        if patterns.is_empty() {
+            return Ok(HashMap::new());
         }
+        let unlinked = release_notes::unlinked_task_references(prs_with_wi, patterns);
+        if !unlinked.is_empty() {
+            tracing::info!(
+                "Fetching {} work item(s) referenced in PR text",
+                unlinked.len()
+            );
+        }
+        let fetched = client
+            .fetch_work_items_by_ids(&unlinked)
+            .await
+            .context("Failed to fetch work items referenced in PR text")?;
+        Ok(release_notes::link_task_references(
+            prs_with_wi,
+            patterns,
+            &fetched,
+        ))
+    }
 
-        Ok(formatted)
+    /// Copies the output if `copy_to_clipboard` is set, returning where it went.
+    pub fn copy_output(&self, output: &str) -> Result<Option<CopyDestination>> {
+        if !self.config.copy_to_clipboard {
+            return Ok(None);
+        }
+        let destination = copy_text(output, &self.config.clipboard)?;
+        tracing::info!("Output copied to {}", destination);
+        Ok(Some(destination))
     }
 
     fn resolve_target_tag(&self, all_tags: &[String]) -> Result<(String, String)> {
@@ -173,3 +319,75 @@ impl ReleaseNotesRunner {
         }
     }
 }
+
+/// Configuration for release notes generated from a state file.
+pub struct StateReleaseNotesConfig {
+    /// State file of the merge, usually the snapshot in its run directory.
+    pub state_path: PathBuf,
+    pub output_format: ReleaseNotesOutputFormat,
+    pub grouped: bool,
+    pub include_prs: bool,
+    pub copy_to_clipboard: bool,
+    /// Clipboard method and file fallback used when copying.
+    pub clipboard: ClipboardOptions,
+    /// Custom pull request and work item link layouts.
+    pub url_templates: UrlTemplates,
+    /// Headings of markdown, plain and HTML output.
+    pub locale: ReleaseNotesLocale,
+}
+
+/// Release notes runner reading a state file instead of the API.
+///
+/// The PRs and work items are the cherry-pick items the merge recorded, so
+/// the notes match what was actually picked and need no network access.
+pub struct StateReleaseNotesRunner {
+    config: StateReleaseNotesConfig,
+}
+
+impl StateReleaseNotesRunner {
+    pub fn new(config: StateReleaseNotesConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn run(&self) -> Result<String> {
+        let state = MergeStateFile::load(&self.config.state_path)?;
+        tracing::info!(
+            "Generating release notes for {} from {}",
+            state.merge_version,
+            self.config.state_path.display()
+        );
+
+        let urls = UrlBuilder::new(&state.organization, &state.project, &state.repository)
+            .with_templates(self.config.url_templates.clone());
+        let cache = WorkItemCache::load().unwrap_or_default();
+        let entries = release_notes::build_entries_from_state(&state, &urls, &cache);
+
+        let output = release_notes::format_output(
+            &entries,
+            None,
+            self.config.output_format,
+            self.config.grouped,
+            self.config.include_prs,
+            &self.config.locale,
+        )?;
+        match &state.package_version {
+            Some(package) => release_notes::with_package_version(
+                output,
+                package,
+                self.config.output_format,
+                &self.config.locale,
+            ),
+            None => Ok(output),
+        }
+    }
+
+    /// Copies the output if `copy_to_clipboard` is set, returning where it went.
+    pub fn copy_output(&self, output: &str) -> Result<Option<CopyDestination>> {
+        if !self.config.copy_to_clipboard {
+            return Ok(None);
+        }
+        let destination = copy_text(output, &self.config.clipboard)?;
+        tracing::info!("Output copied to {}", destination);
+        Ok(Some(destination))
+    }
+}
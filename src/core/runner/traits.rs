@@ -3,11 +3,14 @@
 //! This module defines the interfaces that both interactive and
 //! non-interactive runners implement.
 
+use std::net::SocketAddr;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use crate::core::ExitCode;
-use crate::core::operations::HooksConfig;
-use crate::models::OutputFormat;
+use crate::core::operations::{BinaryConflictPolicy, ConflictTimeout, HooksConfig};
+use crate::models::{OutputFormat, SinceField};
+use crate::utils::Clock;
 
 /// Configuration for a merge runner.
 #[derive(Debug, Clone)]
@@ -20,6 +23,8 @@ pub struct MergeRunnerConfig {
     pub repository: String,
     /// Personal access token for Azure DevOps.
     pub pat: String,
+    /// Separate personal access token for mutating requests (`None` to use `pat`).
+    pub write_pat: Option<String>,
     /// Development branch (source of PRs).
     pub dev_branch: String,
     /// Target branch for cherry-picks.
@@ -32,6 +37,31 @@ pub struct MergeRunnerConfig {
     pub work_item_state: String,
     /// Work item states for PR selection (comma-separated).
     pub select_by_states: Option<String>,
+    /// File listing PR and work item ids to select.
+    pub select_file: Option<PathBuf>,
+    /// Known work item states; loading fails if work items are in other states.
+    pub strict_states: Option<Vec<String>>,
+    /// Release gate on a custom work item field (`None` when not configured).
+    pub release_gate: Option<crate::core::operations::ReleaseGate>,
+    /// Whether builds and branch policies of selected PRs are checked, and
+    /// whether failing PRs are deselected.
+    pub build_checks: crate::core::operations::BuildCheckMode,
+    /// Whether the patch branch is tagged `v<version>` on completion.
+    pub release_tag: crate::core::operations::ReleaseTagMode,
+    /// Whether cherry-picked commits and the release tag are signed.
+    pub sign_commits: bool,
+    /// Every target branch of a fan-out, `target_branch` among them (empty
+    /// for a single target).
+    pub fan_out: Vec<String>,
+    /// Commit message template of squashed PRs (`None` when squash mode is off).
+    pub squash_message: Option<String>,
+    /// Whether a merge commit whose first parent conflicts is retried with
+    /// its second parent as mainline (`mainline_fallback`).
+    pub mainline_fallback: bool,
+    /// Link layouts of the release notes written into the release tag.
+    pub url_templates: crate::api::UrlTemplates,
+    /// Headings of the release notes written into the release tag.
+    pub release_notes_locale: crate::release_notes::ReleaseNotesLocale,
     /// Local repository path for worktree creation.
     pub local_repo: Option<PathBuf>,
     /// Whether to run git hooks.
@@ -48,6 +78,44 @@ pub struct MergeRunnerConfig {
     pub max_concurrent_processing: usize,
     /// Filter PRs by date (e.g., "1mo", "2w", "2025-01-15").
     pub since: Option<String>,
+    /// Pull request date compared against `since`.
+    pub since_field: SinceField,
+    /// Azure DevOps REST API version override.
+    pub api_version: Option<String>,
+    /// Create a release candidate: `version` gets the next free `-rcN` suffix.
+    pub release_candidate: bool,
+    /// Port of the localhost status endpoint (`None` to not serve one).
+    pub status_port: Option<u16>,
+    /// Address of the web dashboard (`None` to not serve one).
+    pub serve: Option<SocketAddr>,
+    /// Selected PR count above which the run fails (`None` for no limit).
+    pub selection_hard_cap: Option<usize>,
+    /// Merge even if the selection exceeds `selection_hard_cap`.
+    pub force: bool,
+    /// Comment template posted on each released work item on completion
+    /// (`None` to post no comments).
+    pub work_item_comment: Option<String>,
+    /// Feed package whose version is stamped on released work items on
+    /// completion (`None` when not configured).
+    pub artifacts_feed: Option<crate::api::ArtifactsFeed>,
+    /// Templated fields written on released work items on completion
+    /// (empty when not configured).
+    pub work_item_fields: Vec<crate::core::operations::WorkItemFieldRule>,
+    /// Chat webhooks notified when the merge finishes (`None` when not configured).
+    pub notifications: Option<crate::notify::NotificationsConfig>,
+    /// Sides kept for conflicted binary files matching path globs.
+    pub binary_conflicts: BinaryConflictPolicy,
+    /// Order in which the selected PRs are cherry-picked (default: closed date).
+    pub cherry_pick_order: crate::core::operations::CherryPickOrder,
+    /// How long a conflict waits for a human before it is settled
+    /// automatically (`None` to stop at the conflict).
+    pub conflict_timeout: Option<ConflictTimeout>,
+    /// Simulate the merge and report what would happen without changing anything.
+    pub dry_run: bool,
+    /// Report files written whenever the cherry-picks stop (`--report`).
+    pub reports: Vec<crate::core::output::ReportTarget>,
+    /// Clock stamping the state file (see [`default_clock`](crate::utils::default_clock)).
+    pub clock: Arc<dyn Clock>,
 }
 
 /// Result of a merge operation.
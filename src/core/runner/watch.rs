@@ -0,0 +1,274 @@
+//! Watch runner for CLI usage.
+//!
+//! Polls Azure DevOps for completed PRs that are ready to merge, keeps the
+//! accumulated candidates in a [`WatchList`], and announces new candidates
+//! with a terminal bell and/or desktop notification.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::api::{AzureDevOpsClient, filter_prs_without_merged_tag};
+use crate::core::operations::ignore_list::apply_ignore_file;
+use crate::core::operations::pr_selection::{
+    filter_prs_by_work_item_states, parse_work_item_states,
+};
+use crate::core::operations::watch_list::{WatchCandidate, WatchList};
+use crate::models::{SharedConfig, SinceField, WatchNotify};
+use crate::utils::{Clock, SystemClock};
+
+/// Configuration for the watch runner.
+pub struct WatchRunnerConfig {
+    pub organization: String,
+    pub project: String,
+    pub repository: String,
+    pub pat: String,
+    pub dev_branch: String,
+    pub since: Option<String>,
+    /// Pull request date compared against `since`.
+    pub since_field: SinceField,
+    pub local_repo: Option<String>,
+    pub select_by_state: Option<String>,
+    pub max_concurrent_network: usize,
+    pub max_concurrent_processing: usize,
+    /// Azure DevOps REST API version override.
+    pub api_version: Option<String>,
+}
+
+impl WatchRunnerConfig {
+    /// Builds the runner configuration from resolved shared settings.
+    pub fn from_shared(shared: &SharedConfig, select_by_state: Option<String>) -> Self {
+        Self {
+            organization: shared.organization.value().clone(),
+            project: shared.project.value().clone(),
+            repository: shared.repository.value().clone(),
+            pat: shared.pat.value().clone(),
+            dev_branch: shared.dev_branch.value().clone(),
+            since: shared
+                .since
+                .as_ref()
+                .and_then(|d| d.original())
+                .map(String::from),
+            since_field: shared.since_field,
+            local_repo: shared.local_repo.as_ref().map(|p| p.value().clone()),
+            select_by_state,
+            max_concurrent_network: *shared.max_concurrent_network.value(),
+            max_concurrent_processing: *shared.max_concurrent_processing.value(),
+            api_version: shared.api_version.as_ref().map(|p| p.value().clone()),
+        }
+    }
+}
+
+/// Result of a single poll.
+#[derive(Debug, Clone)]
+pub struct PollOutcome {
+    /// Candidates that appeared since the previous poll.
+    pub new_candidates: Vec<WatchCandidate>,
+    /// Number of candidates currently known.
+    pub total_candidates: usize,
+    /// Number of candidates waiting to be reviewed.
+    pub pending_candidates: usize,
+}
+
+/// Watch runner.
+pub struct WatchRunner {
+    config: WatchRunnerConfig,
+    client: AzureDevOpsClient,
+    list: WatchList,
+    list_path: PathBuf,
+    /// Clock stamping when candidates were first seen.
+    clock: Arc<dyn Clock>,
+}
+
+impl WatchRunner {
+    /// Creates a runner and loads the persisted watch list.
+    pub fn new(config: WatchRunnerConfig) -> Result<Self> {
+        let client = AzureDevOpsClient::new_with_api_version(
+            config.organization.clone(),
+            config.project.clone(),
+            config.repository.clone(),
+            config.pat.clone().into(),
+            config.api_version.clone(),
+        )?
+        .with_since_field(config.since_field)
+        .with_max_concurrent_requests(config.max_concurrent_network);
+        let list_path = WatchList::path_for(
+            &config.organization,
+            &config.project,
+            &config.repository,
+            &config.dev_branch,
+        )?;
+        let list = WatchList::load(&list_path)?;
+
+        Ok(Self {
+            config,
+            client,
+            list,
+            list_path,
+            clock: Arc::new(SystemClock),
+        })
+    }
+
+    /// Reads the time from `clock` instead of the system clock.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.client = self.client.with_clock(clock.clone());
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the clock stamping the watch list.
+    pub fn clock(&self) -> &Arc<dyn Clock> {
+        &self.clock
+    }
+
+    /// Returns the accumulated watch list.
+    pub fn list(&self) -> &WatchList {
+        &self.list
+    }
+
+    /// Fetches the current candidates and updates the watch list.
+    pub async fn poll(&mut self) -> Result<PollOutcome> {
+        let prs = self
+            .client
+            .fetch_pull_requests(&self.config.dev_branch, self.config.since.as_deref())
+            .await?;
+        let prs = filter_prs_without_merged_tag(prs);
+        let prs = match self.config.local_repo.as_deref() {
+            Some(repo) => apply_ignore_file(Path::new(repo), prs)?.0,
+            None => prs,
+        };
+
+        let prs = match self.config.select_by_state {
+            Some(ref states) => {
+                let prs_with_wi = self
+                    .client
+                    .fetch_work_items_for_prs_parallel(
+                        &prs,
+                        self.config.max_concurrent_network,
+                        self.config.max_concurrent_processing,
+                    )
+                    .await;
+                filter_prs_by_work_item_states(&prs_with_wi, &parse_work_item_states(states))
+                    .into_iter()
+                    .map(|p| p.pr.clone())
+                    .collect()
+            }
+            None => prs,
+        };
+
+        let new_candidates = self.list.update(&prs, self.clock.now());
+        self.list.save(&self.list_path)?;
+
+        Ok(PollOutcome {
+            new_candidates,
+            total_candidates: self.list.candidates().len(),
+            pending_candidates: self.list.pending_ids().len(),
+        })
+    }
+
+    /// Marks all pending candidates as reviewed and saves the list.
+    pub fn acknowledge(&mut self) -> Result<()> {
+        self.list.acknowledge();
+        self.list.save(&self.list_path)
+    }
+}
+
+/// Builds the notification title and body for new candidates.
+pub fn notification_text(new_candidates: &[WatchCandidate]) -> (String, String) {
+    let title = match new_candidates.len() {
+        1 => "mergers: 1 new merge candidate".to_string(),
+        n => format!("mergers: {} new merge candidates", n),
+    };
+    let body = new_candidates
+        .iter()
+        .map(|c| format!("#{} {}", c.pr_id, c.title))
+        .collect::<Vec<_>>()
+        .join("\n");
+    (title, body)
+}
+
+/// Announces new candidates using the configured notification mode.
+///
+/// Desktop notifications use `notify-send` on Linux and `osascript` on
+/// macOS. Failures are logged and otherwise ignored.
+pub fn notify_new_candidates(mode: WatchNotify, new_candidates: &[WatchCandidate]) {
+    if new_candidates.is_empty() {
+        return;
+    }
+
+    if mode.bell() {
+        let mut stderr = std::io::stderr();
+        let _ = stderr.write_all(b"\x07");
+        let _ = stderr.flush();
+    }
+
+    if mode.desktop() {
+        let (title, body) = notification_text(new_candidates);
+        if let Err(e) = show_desktop_notification(&title, &body) {
+            tracing::debug!("Desktop notification failed: {}", e);
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn show_desktop_notification(title: &str, body: &str) -> std::io::Result<()> {
+    let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+    let script = format!(
+        "display notification \"{}\" with title \"{}\"",
+        escape(body),
+        escape(title)
+    );
+    std::process::Command::new("osascript")
+        .args(["-e", &script])
+        .spawn()
+        .map(|_| ())
+}
+
+#[cfg(not(target_os = "macos"))]
+fn show_desktop_notification(title: &str, body: &str) -> std::io::Result<()> {
+    std::process::Command::new("notify-send")
+        .args(["--app-name=mergers", title, body])
+        .spawn()
+        .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn candidate(pr_id: i32, title: &str) -> WatchCandidate {
+        WatchCandidate {
+            pr_id,
+            title: title.to_string(),
+            author: "Test User".to_string(),
+            closed_date: None,
+            first_seen: Utc::now(),
+            pending: true,
+        }
+    }
+
+    /// # Notification Text
+    ///
+    /// Tests the notification title and body for new candidates.
+    ///
+    /// ## Test Scenario
+    /// - Builds the text for one and for two candidates
+    ///
+    /// ## Expected Outcome
+    /// - The title uses the right count and plural
+    /// - The body lists each PR on its own line
+    #[test]
+    fn test_notification_text() {
+        let (title, body) = notification_text(&[candidate(1, "Fix login")]);
+        assert_eq!(title, "mergers: 1 new merge candidate");
+        assert_eq!(body, "#1 Fix login");
+
+        let (title, body) =
+            notification_text(&[candidate(1, "Fix login"), candidate(2, "Add search")]);
+        assert_eq!(title, "mergers: 2 new merge candidates");
+        assert_eq!(body, "#1 Fix login\n#2 Add search");
+    }
+}
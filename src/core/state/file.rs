@@ -3,17 +3,19 @@
 //! This module provides persistent state storage for merge operations,
 //! enabling resume after conflicts and cross-mode (TUI ↔ CLI) handoffs.
 
+use super::run_dir::run_state_path;
+use super::schema::{SCHEMA_VERSION, migrate_state};
+use crate::api::PackageVersion;
+use crate::utils::{Clock, SystemClock, native_path, path_key};
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
-
-/// Current schema version for state files.
-/// Increment when making breaking changes to the state file format.
-pub const SCHEMA_VERSION: u32 = 1;
+use std::sync::Arc;
 
 /// Environment variable to override the state directory.
 pub const STATE_DIR_ENV: &str = "MERGERS_STATE_DIR";
@@ -134,6 +136,79 @@ pub struct StateCherryPickItem {
     /// Work item IDs associated with this PR.
     #[serde(default)]
     pub work_item_ids: Vec<i32>,
+    /// Titles of the work items, for release notes generated from the state.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub work_item_titles: BTreeMap<i32, String>,
+    /// Commit created on the patch branch when the cherry-pick succeeded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub picked_commit_id: Option<String>,
+    /// Mainline parent the cherry-pick used when the first parent did not
+    /// apply (reversed merge commit parents).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mainline_parent: Option<u8>,
+    /// When the PR was completed on the dev branch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pr_closed_at: Option<DateTime<Utc>>,
+    /// Whether the cherry-pick hit a conflict, kept after it was resolved.
+    #[serde(default)]
+    pub had_conflict: bool,
+}
+
+/// Release candidate lineage of a merge operation.
+///
+/// Set on state files created with `--rc` and on state files created by
+/// `mergers promote`, linking the final release back to the RC it was
+/// promoted from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseLineage {
+    /// Final release version this merge belongs to (e.g., "1.2.0").
+    pub release_version: String,
+    /// Release candidate number, if this merge produced an RC.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rc_number: Option<u32>,
+    /// Version of the release candidate this merge was promoted from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub promoted_from: Option<String>,
+    /// Repository path of the promoted release candidate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub promoted_from_repo: Option<PathBuf>,
+    /// PR IDs carried over from the release candidate.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub carried_pr_ids: Vec<i32>,
+}
+
+impl ReleaseLineage {
+    /// Creates the lineage for a release candidate.
+    pub fn candidate(release_version: impl Into<String>, rc_number: u32) -> Self {
+        Self {
+            release_version: release_version.into(),
+            rc_number: Some(rc_number),
+            promoted_from: None,
+            promoted_from_repo: None,
+            carried_pr_ids: Vec::new(),
+        }
+    }
+
+    /// Creates the lineage for a final release promoted from a release candidate.
+    pub fn promoted(
+        release_version: impl Into<String>,
+        rc_version: impl Into<String>,
+        rc_repo: PathBuf,
+        carried_pr_ids: Vec<i32>,
+    ) -> Self {
+        Self {
+            release_version: release_version.into(),
+            rc_number: None,
+            promoted_from: Some(rc_version.into()),
+            promoted_from_repo: Some(rc_repo),
+            carried_pr_ids,
+        }
+    }
+
+    /// Returns true if this lineage describes a release candidate.
+    pub fn is_candidate(&self) -> bool {
+        self.rc_number.is_some()
+    }
 }
 
 /// Persistent state file for merge operations.
@@ -144,6 +219,9 @@ pub struct StateCherryPickItem {
 pub struct MergeStateFile {
     /// Schema version for forward compatibility.
     pub schema_version: u32,
+    /// Version of mergers that last wrote the file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub written_by: Option<String>,
 
     // Timestamps
     /// When the merge operation was started.
@@ -173,6 +251,10 @@ pub struct MergeStateFile {
     pub dev_branch: String,
     /// Target branch for cherry-picks.
     pub target_branch: String,
+    /// Every target branch of a fan-out, in run order (empty for a single
+    /// target). Each target has a state file of its own.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fan_out: Vec<String>,
     /// Merge version string (e.g., "v1.2.3").
     pub merge_version: String,
 
@@ -197,6 +279,17 @@ pub struct MergeStateFile {
     /// Whether git hooks are enabled for this merge.
     #[serde(default)]
     pub run_hooks: bool,
+    /// Whether the commits and tags of this merge are signed.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub sign_commits: bool,
+    /// Commit message template the PRs of this merge are squashed with
+    /// (`None` when squash mode is off).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub squash_message: Option<String>,
+    /// Whether merge commits whose first parent conflicts are retried with
+    /// their second parent.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub mainline_fallback: bool,
 
     // Completion Info
     /// When the merge was completed (if completed).
@@ -205,6 +298,29 @@ pub struct MergeStateFile {
     /// Final status of the merge (if completed).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub final_status: Option<MergeStatus>,
+
+    // Release Candidate Lineage
+    /// RC/promotion lineage (set for `--rc` merges and `mergers promote`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release: Option<ReleaseLineage>,
+
+    // Artifacts Feed
+    /// Package version found in the artifacts feed on completion.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package_version: Option<PackageVersion>,
+
+    // Run Directory
+    /// Directory holding the artifacts of this run, see `mergers gc`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_dir: Option<PathBuf>,
+
+    /// Clock stamping `updated_at` and `completed_at`.
+    #[serde(skip, default = "system_clock")]
+    clock: Arc<dyn Clock>,
+}
+
+fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
 }
 
 /// Builder for creating `MergeStateFile` instances.
@@ -241,6 +357,12 @@ pub struct MergeStateFileBuilder {
     work_item_state: Option<String>,
     tag_prefix: Option<String>,
     run_hooks: bool,
+    sign_commits: bool,
+    fan_out: Vec<String>,
+    squash_message: Option<String>,
+    mainline_fallback: bool,
+    run_dir: Option<PathBuf>,
+    clock: Option<Arc<dyn Clock>>,
 }
 
 impl MergeStateFileBuilder {
@@ -321,6 +443,43 @@ impl MergeStateFileBuilder {
         self
     }
 
+    /// Sets whether the commits and tags of this merge are signed.
+    pub fn sign_commits(mut self, sign_commits: bool) -> Self {
+        self.sign_commits = sign_commits;
+        self
+    }
+
+    /// Sets every target branch of the fan-out this merge is part of.
+    pub fn fan_out(mut self, targets: Vec<String>) -> Self {
+        self.fan_out = targets;
+        self
+    }
+
+    /// Sets the template the PRs of this merge are squashed with.
+    pub fn squash_message(mut self, template: Option<String>) -> Self {
+        self.squash_message = template;
+        self
+    }
+
+    /// Sets whether merge commits whose first parent conflicts are retried
+    /// with their second parent.
+    pub fn mainline_fallback(mut self, mainline_fallback: bool) -> Self {
+        self.mainline_fallback = mainline_fallback;
+        self
+    }
+
+    /// Sets the directory holding the artifacts of this run.
+    pub fn run_dir<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.run_dir = Some(path.into());
+        self
+    }
+
+    /// Sets the clock stamping the state file (the system clock by default).
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
     /// Builds the `MergeStateFile`.
     ///
     /// # Panics
@@ -336,9 +495,11 @@ impl MergeStateFileBuilder {
     /// - `work_item_state`
     /// - `tag_prefix`
     pub fn build(self) -> MergeStateFile {
-        let now = Utc::now();
+        let clock = self.clock.unwrap_or_else(system_clock);
+        let now = clock.now();
         MergeStateFile {
             schema_version: SCHEMA_VERSION,
+            written_by: None,
             created_at: now,
             updated_at: now,
             repo_path: self.repo_path.expect("repo_path is required"),
@@ -357,8 +518,16 @@ impl MergeStateFileBuilder {
             work_item_state: self.work_item_state.expect("work_item_state is required"),
             tag_prefix: self.tag_prefix.expect("tag_prefix is required"),
             run_hooks: self.run_hooks,
+            sign_commits: self.sign_commits,
+            fan_out: self.fan_out,
+            squash_message: self.squash_message,
+            mainline_fallback: self.mainline_fallback,
             completed_at: None,
             final_status: None,
+            release: None,
+            package_version: None,
+            run_dir: self.run_dir,
+            clock,
         }
     }
 
@@ -368,9 +537,11 @@ impl MergeStateFileBuilder {
     ///
     /// Returns an error if any required field is not set.
     pub fn try_build(self) -> Result<MergeStateFile> {
-        let now = Utc::now();
+        let clock = self.clock.unwrap_or_else(system_clock);
+        let now = clock.now();
         Ok(MergeStateFile {
             schema_version: SCHEMA_VERSION,
+            written_by: None,
             created_at: now,
             updated_at: now,
             repo_path: self
@@ -407,8 +578,16 @@ impl MergeStateFileBuilder {
                 .tag_prefix
                 .ok_or_else(|| anyhow::anyhow!("tag_prefix is required"))?,
             run_hooks: self.run_hooks,
+            sign_commits: self.sign_commits,
+            fan_out: self.fan_out,
+            squash_message: self.squash_message,
+            mainline_fallback: self.mainline_fallback,
             completed_at: None,
             final_status: None,
+            release: None,
+            package_version: None,
+            run_dir: self.run_dir,
+            clock,
         })
     }
 }
@@ -439,9 +618,11 @@ impl MergeStateFile {
         tag_prefix: String,
         run_hooks: bool,
     ) -> Self {
-        let now = Utc::now();
+        let clock = system_clock();
+        let now = clock.now();
         Self {
             schema_version: SCHEMA_VERSION,
+            written_by: None,
             created_at: now,
             updated_at: now,
             repo_path,
@@ -452,6 +633,9 @@ impl MergeStateFile {
             repository,
             dev_branch,
             target_branch,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
             merge_version,
             cherry_pick_items: Vec::new(),
             current_index: 0,
@@ -460,27 +644,69 @@ impl MergeStateFile {
             work_item_state,
             tag_prefix,
             run_hooks,
+            sign_commits: false,
             completed_at: None,
             final_status: None,
+            release: None,
+            package_version: None,
+            run_dir: None,
+            clock,
         }
     }
 
+    /// Sets the clock stamping `updated_at` and `completed_at`.
+    ///
+    /// Loaded state files use the system clock until this is called.
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
     /// Loads a state file from disk.
+    ///
+    /// State files of an older schema version are migrated in memory; the
+    /// next save writes them in the current format.
     pub fn load(path: &Path) -> Result<Self> {
+        Ok(Self::load_migrated(path)?.0)
+    }
+
+    /// Loads a state file, returning the schema version it was migrated from.
+    fn load_migrated(path: &Path) -> Result<(Self, Option<u32>)> {
         let content = fs::read_to_string(path)
             .with_context(|| format!("Failed to read state file: {}", path.display()))?;
-        let state: Self = serde_json::from_str(&content)
+        let mut value: serde_json::Value = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse state file: {}", path.display()))?;
+        let migrated_from = migrate_state(&mut value)?;
+        if let Some(version) = migrated_from {
+            tracing::info!(
+                "Migrated state file {} from schema version {} to {}",
+                path.display(),
+                version,
+                SCHEMA_VERSION
+            );
+        }
+        let state: Self = serde_json::from_value(value)
             .with_context(|| format!("Failed to parse state file: {}", path.display()))?;
-        Ok(state)
+        Ok((state, migrated_from))
+    }
+
+    /// Rewrites a state file in the current schema version.
+    ///
+    /// Returns the version it was upgraded from, or `None` if it already was
+    /// current. With `dry_run`, the file is only checked.
+    pub fn upgrade(path: &Path, dry_run: bool) -> Result<Option<u32>> {
+        let (mut state, migrated_from) = Self::load_migrated(path)?;
+        if migrated_from.is_some() && !dry_run {
+            // Keeps updated_at, since the merge itself did not change
+            state.write(path)?;
+        }
+        Ok(migrated_from)
     }
 
     /// Loads a state file for a repository, if it exists.
     pub fn load_for_repo(repo_path: &Path) -> Result<Option<Self>> {
-        let state_path = path_for_repo(repo_path)?;
-        if state_path.exists() {
-            Ok(Some(Self::load(&state_path)?))
-        } else {
-            Ok(None)
+        match existing_path_for_repo(repo_path)? {
+            Some(state_path) => Ok(Some(Self::load(&state_path)?)),
+            None => Ok(None),
         }
     }
 
@@ -489,10 +715,9 @@ impl MergeStateFile {
     /// Returns a detailed error if the state file is corrupted or invalid.
     /// The error message includes suggestions for recovery.
     pub fn load_and_validate_for_repo(repo_path: &Path) -> Result<Option<Self>> {
-        let state_path = path_for_repo(repo_path)?;
-        if !state_path.exists() {
+        let Some(state_path) = existing_path_for_repo(repo_path)? else {
             return Ok(None);
-        }
+        };
 
         // Try to load the file
         let state = match Self::load(&state_path) {
@@ -529,7 +754,13 @@ impl MergeStateFile {
     ///
     /// Uses write-to-temp-then-rename pattern for atomicity.
     pub fn save(&mut self, path: &Path) -> Result<()> {
-        self.updated_at = Utc::now();
+        self.updated_at = self.clock.now();
+        self.write(path)
+    }
+
+    /// Writes the state file atomically, recording this mergers version.
+    fn write(&mut self, path: &Path) -> Result<()> {
+        self.written_by = Some(env!("CARGO_PKG_VERSION").to_string());
 
         // Ensure parent directory exists
         if let Some(parent) = path.parent() {
@@ -558,12 +789,30 @@ impl MergeStateFile {
     }
 
     /// Saves the state file to the default location for this repository.
+    ///
+    /// A state file left at the legacy location is removed, so the merge
+    /// moves to the current location on its next save.
     pub fn save_for_repo(&mut self) -> Result<PathBuf> {
         let path = path_for_repo(&self.repo_path)?;
         self.save(&path)?;
+        remove_legacy_state(&path, &legacy_path_for_repo(&self.repo_path)?)?;
         Ok(path)
     }
 
+    /// Writes a snapshot of the state file to the run directory.
+    ///
+    /// Called when the merge completes, so the run can be looked at after
+    /// the state file is removed or replaced by the next merge. Returns the
+    /// snapshot path, or `None` when the merge has no run directory.
+    pub fn save_run_snapshot(&mut self) -> Result<Option<PathBuf>> {
+        let Some(run_dir) = self.run_dir.clone().filter(|dir| dir.is_dir()) else {
+            return Ok(None);
+        };
+        let path = run_state_path(&run_dir);
+        self.write(&path)?;
+        Ok(Some(path))
+    }
+
     /// Updates the phase and saves.
     pub fn set_phase(&mut self, phase: MergePhase) -> Result<PathBuf> {
         self.phase = phase;
@@ -573,11 +822,21 @@ impl MergeStateFile {
     /// Marks the merge as completed with the given status.
     pub fn mark_completed(&mut self, status: MergeStatus) -> Result<PathBuf> {
         self.phase = MergePhase::Completed;
-        self.completed_at = Some(Utc::now());
+        self.completed_at = Some(self.clock.now());
         self.final_status = Some(status);
         self.save_for_repo()
     }
 
+    /// Returns the key of the lock protecting this merge.
+    pub fn lock_key(&self) -> LockKey {
+        LockKey::for_repository(
+            &self.organization,
+            &self.project,
+            &self.repository,
+            &self.target_branch,
+        )
+    }
+
     /// Returns the count of items by status.
     pub fn status_counts(&self) -> StatusCounts {
         let mut counts = StatusCounts::default();
@@ -676,7 +935,7 @@ impl StatusCounts {
 /// otherwise uses the XDG state directory.
 pub fn state_dir() -> Result<PathBuf> {
     if let Ok(dir) = std::env::var(STATE_DIR_ENV) {
-        return Ok(PathBuf::from(dir));
+        return Ok(native_path(&dir));
     }
 
     // Use XDG state directory
@@ -700,19 +959,22 @@ pub fn state_dir() -> Result<PathBuf> {
 /// Computes a hash of the repository path for unique file naming.
 ///
 /// Returns the first 16 characters of the SHA-256 hash of the
-/// canonicalized path.
+/// canonicalized path's [`path_key`], so `C:\src\app` from Windows and
+/// `/mnt/c/src/app` from WSL share a state file.
 pub fn compute_repo_hash(repo_path: &Path) -> Result<String> {
     let canonical = repo_path
         .canonicalize()
         .with_context(|| format!("Failed to canonicalize path: {}", repo_path.display()))?;
 
-    let path_str = canonical.to_string_lossy();
+    Ok(hash_path_key(&path_key(&canonical)))
+}
+
+/// Hashes a path key, keeping the first 16 characters of the hex encoding.
+fn hash_path_key(key: &str) -> String {
     let mut hasher = Sha256::new();
-    hasher.update(path_str.as_bytes());
+    hasher.update(key.as_bytes());
     let result = hasher.finalize();
-
-    // Take first 16 characters of hex encoding
-    Ok(hex::encode(&result[..8]))
+    hex::encode(&result[..8])
 }
 
 /// Returns the state file path for a repository.
@@ -722,53 +984,199 @@ pub fn path_for_repo(repo_path: &Path) -> Result<PathBuf> {
     Ok(dir.join(format!("merge-{}.json", hash)))
 }
 
-/// Returns the lock file path for a repository.
-pub fn lock_path_for_repo(repo_path: &Path) -> Result<PathBuf> {
-    let hash = compute_repo_hash(repo_path)?;
+/// Returns the state file path older versions used for a repository.
+///
+/// They hashed the canonicalized path as is instead of its [`path_key`], so
+/// for Windows drive paths and WSL mount paths the two locations differ.
+fn legacy_path_for_repo(repo_path: &Path) -> Result<PathBuf> {
+    let canonical = repo_path
+        .canonicalize()
+        .with_context(|| format!("Failed to canonicalize path: {}", repo_path.display()))?;
+    let hash = hash_path_key(&canonical.to_string_lossy());
+    Ok(state_dir()?.join(format!("merge-{}.json", hash)))
+}
+
+/// Returns the path of the state file a repository has, if any.
+///
+/// Falls back to the legacy location when there is no state file at the
+/// current one, so merges started by older versions can be resumed.
+pub fn existing_path_for_repo(repo_path: &Path) -> Result<Option<PathBuf>> {
+    let path = path_for_repo(repo_path)?;
+    if path.exists() {
+        return Ok(Some(path));
+    }
+    let legacy = legacy_path_for_repo(repo_path)?;
+    Ok(legacy.exists().then_some(legacy))
+}
+
+/// Removes the state file at `legacy` once it was saved at `path`.
+fn remove_legacy_state(path: &Path, legacy: &Path) -> Result<()> {
+    if legacy == path || !legacy.exists() {
+        return Ok(());
+    }
+    fs::remove_file(legacy)
+        .with_context(|| format!("Failed to remove legacy state file: {}", legacy.display()))
+}
+
+/// Paths of the state files in `state_dir`, sorted.
+pub fn state_files(state_dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(state_dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("merge-") && name.ends_with(".json"))
+        })
+        .collect();
+    paths.sort();
+    paths
+}
+
+/// What a lock is keyed by: the remote repository and the target branch.
+///
+/// Checkouts of the same repository share keys, so two merges into one target
+/// from different clones exclude each other, while operations on different
+/// target branches never do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockKey {
+    remote_url: String,
+    target_branch: String,
+}
+
+impl LockKey {
+    /// Creates a key, ignoring a trailing `/` or `.git` on the remote URL,
+    /// its case, and a `refs/heads/` prefix on the branch.
+    pub fn new(remote_url: &str, target_branch: &str) -> Self {
+        let remote_url = remote_url.trim().trim_end_matches('/');
+        let remote_url = remote_url.strip_suffix(".git").unwrap_or(remote_url);
+        let target_branch = target_branch.trim();
+        let target_branch = target_branch
+            .strip_prefix("refs/heads/")
+            .unwrap_or(target_branch);
+        Self {
+            remote_url: remote_url.to_lowercase(),
+            target_branch: target_branch.to_string(),
+        }
+    }
+
+    /// Creates the key for a target branch of an Azure DevOps repository.
+    pub fn for_repository(
+        organization: &str,
+        project: &str,
+        repository: &str,
+        target_branch: &str,
+    ) -> Self {
+        let remote_url =
+            crate::api::UrlBuilder::new(organization, project, repository).repository_url();
+        Self::new(&remote_url, target_branch)
+    }
+
+    /// Returns the normalized remote URL.
+    pub fn remote_url(&self) -> &str {
+        &self.remote_url
+    }
+
+    /// Returns the target branch.
+    pub fn target_branch(&self) -> &str {
+        &self.target_branch
+    }
+
+    /// Returns the first 16 characters of the SHA-256 hash of the key.
+    fn hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.remote_url.as_bytes());
+        hasher.update(b"\n");
+        hasher.update(self.target_branch.as_bytes());
+        hex::encode(&hasher.finalize()[..8])
+    }
+}
+
+/// The kind of operation holding a lock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockKind {
+    /// A merge creating a patch branch.
+    Merge,
+    /// A cleanup deleting merged patch branches.
+    Cleanup,
+}
+
+impl LockKind {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Merge => "merge",
+            Self::Cleanup => "cleanup",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "merge" => Some(Self::Merge),
+            "cleanup" => Some(Self::Cleanup),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for LockKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Returns the lock file path for a key.
+///
+/// Merges and cleanups share the file, so no two operations ever run on the
+/// same target at once.
+pub fn lock_path(key: &LockKey) -> Result<PathBuf> {
     let dir = state_dir()?;
-    Ok(dir.join(format!("merge-{}.lock", hash)))
+    Ok(dir.join(format!("lock-{}.lock", key.hash())))
 }
 
-/// A lock guard that holds a lock on a merge operation.
+/// How long a lock file without a readable holder is assumed to still be
+/// written by the process that just created it.
+const LOCK_WRITE_GRACE: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// A lock guard that holds a lock on a merge or cleanup operation.
 ///
 /// The lock is automatically released when the guard is dropped.
-/// Uses a simple PID-based locking mechanism with stale lock detection.
+/// The lock file is created atomically and holds the PID and kind of the
+/// holder; locks of processes that are gone are stale and taken over.
 #[derive(Debug)]
 pub struct LockGuard {
     path: PathBuf,
 }
 
 impl LockGuard {
-    /// Checks if a lock exists for the given repository without acquiring it.
+    /// Returns the kind of operation a live process holds the lock on `key`
+    /// for, without acquiring it.
+    ///
+    /// Locks written by older versions, which do not record a kind, are
+    /// reported as merges.
+    pub fn holder(key: &LockKey) -> Result<Option<LockKind>> {
+        Ok(read_holder(&lock_path(key)?)
+            .filter(|holder| is_process_alive(holder.pid))
+            .map(|holder| holder.kind))
+    }
+
+    /// Checks if another operation holds a lock on `key`, without acquiring it.
     ///
     /// Returns `true` if another process holds the lock, `false` otherwise.
     /// This is useful for early detection before loading state files.
-    pub fn is_locked(repo_path: &Path) -> Result<bool> {
-        let lock_path = lock_path_for_repo(repo_path)?;
-
-        if !lock_path.exists() {
-            return Ok(false);
-        }
-
-        // Check if the process holding the lock is still alive
-        if let Ok(content) = fs::read_to_string(&lock_path)
-            && let Ok(pid) = content.trim().parse::<u32>()
-            && is_process_alive(pid)
-        {
-            return Ok(true);
-        }
-
-        // Lock file exists but is stale
-        Ok(false)
+    pub fn is_locked(key: &LockKey) -> Result<bool> {
+        Ok(Self::holder(key)?.is_some())
     }
 
-    /// Attempts to acquire a lock for the given repository.
+    /// Attempts to acquire a lock of `kind` on `key`.
     ///
     /// Returns `Ok(Some(guard))` if the lock was acquired,
-    /// `Ok(None)` if another process holds the lock,
+    /// `Ok(None)` if another operation holds the lock,
     /// or `Err` if an error occurred.
-    pub fn acquire(repo_path: &Path) -> Result<Option<Self>> {
-        let lock_path = lock_path_for_repo(repo_path)?;
+    pub fn acquire(key: &LockKey, kind: LockKind) -> Result<Option<Self>> {
+        let lock_path = lock_path(key)?;
 
         // Ensure parent directory exists
         if let Some(parent) = lock_path.parent() {
@@ -777,29 +1185,35 @@ impl LockGuard {
             })?;
         }
 
-        // Check if lock file exists and if the process is still alive
-        if lock_path.exists() {
-            if let Ok(content) = fs::read_to_string(&lock_path)
-                && let Ok(pid) = content.trim().parse::<u32>()
-                && is_process_alive(pid)
+        // A stale lock is removed once, then creation is retried
+        for _ in 0..2 {
+            match fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&lock_path)
             {
-                // Another process holds the lock
-                return Ok(None);
+                Ok(mut file) => {
+                    let guard = LockGuard { path: lock_path };
+                    write!(file, "{}\n{}\n", std::process::id(), kind.name()).with_context(
+                        || format!("Failed to write lock file: {}", guard.path.display()),
+                    )?;
+                    return Ok(Some(guard));
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                    let Some(content) = stale_lock_content(&lock_path) else {
+                        return Ok(None);
+                    };
+                    // Only remove the lock we judged stale, not one taken since
+                    if fs::read_to_string(&lock_path).ok() == Some(content) {
+                        let _ = fs::remove_file(&lock_path);
+                    }
+                }
+                Err(e) => {
+                    return Err(e).with_context(|| {
+                        format!("Failed to create lock file: {}", lock_path.display())
+                    });
+                }
             }
-            // Lock is stale or unreadable, remove it
-            let _ = fs::remove_file(&lock_path);
-        }
-
-        // Try to create the lock file
-        let pid = std::process::id();
-        fs::write(&lock_path, pid.to_string())
-            .with_context(|| format!("Failed to create lock file: {}", lock_path.display()))?;
-
-        // Verify we own the lock (handle race condition)
-        if let Ok(content) = fs::read_to_string(&lock_path)
-            && content.trim() == pid.to_string()
-        {
-            return Ok(Some(LockGuard { path: lock_path }));
         }
 
         // Someone else won the race
@@ -817,6 +1231,43 @@ impl LockGuard {
     }
 }
 
+/// The process holding a lock, as recorded in its lock file.
+struct LockHolder {
+    pid: u32,
+    kind: LockKind,
+}
+
+/// Parses lock file content: the PID, then the kind on the next line.
+fn parse_holder(content: &str) -> Option<LockHolder> {
+    let mut lines = content.lines();
+    let pid = lines.next()?.trim().parse().ok()?;
+    let kind = lines
+        .next()
+        .and_then(|name| LockKind::from_name(name.trim()))
+        .unwrap_or(LockKind::Merge);
+    Some(LockHolder { pid, kind })
+}
+
+fn read_holder(path: &Path) -> Option<LockHolder> {
+    parse_holder(&fs::read_to_string(path).ok()?)
+}
+
+/// Returns the content of the lock file at `path` if the lock is stale.
+///
+/// A lock is stale when its holder is gone. A file without a readable holder
+/// is only stale once it is older than [`LOCK_WRITE_GRACE`], since its
+/// creator may still be writing it.
+fn stale_lock_content(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    match parse_holder(&content) {
+        Some(holder) => (!is_process_alive(holder.pid)).then_some(content),
+        None => {
+            let age = fs::metadata(path).ok()?.modified().ok()?.elapsed().ok()?;
+            (age > LOCK_WRITE_GRACE).then_some(content)
+        }
+    }
+}
+
 impl Drop for LockGuard {
     fn drop(&mut self) {
         self.release();
@@ -896,7 +1347,7 @@ mod tests {
         );
 
         let json = serde_json::to_string_pretty(&state).unwrap();
-        assert!(json.contains("\"schema_version\": 1"));
+        assert!(json.contains("\"schema_version\": 2"));
         assert!(json.contains("\"organization\": \"org\""));
         assert!(json.contains("\"merge_version\": \"v1.0.0\""));
         assert!(json.contains("\"run_hooks\": false"));
@@ -939,6 +1390,55 @@ mod tests {
         assert_eq!(state.organization, "org");
         assert_eq!(state.phase, MergePhase::CherryPicking);
         assert!(state.run_hooks);
+        assert!(state.release.is_none());
+    }
+
+    /// # Release Lineage Round Trip
+    ///
+    /// Verifies that RC lineage survives serialization.
+    ///
+    /// ## Test Scenario
+    /// - Sets a promoted lineage on a state file and round-trips it through JSON
+    /// - Serializes a state file without lineage
+    ///
+    /// ## Expected Outcome
+    /// - The lineage is preserved
+    /// - State files without lineage omit the `release` key
+    #[test]
+    fn test_release_lineage_round_trip() {
+        let mut state = MergeStateFile::new(
+            PathBuf::from("/test/repo/next-1.2.0"),
+            Some(PathBuf::from("/test/repo")),
+            true,
+            "org".to_string(),
+            "project".to_string(),
+            "repo".to_string(),
+            "dev".to_string(),
+            "next".to_string(),
+            "1.2.0".to_string(),
+            "Done".to_string(),
+            "merged-".to_string(),
+            false,
+        );
+        assert!(
+            !serde_json::to_string(&state)
+                .unwrap()
+                .contains("\"release\"")
+        );
+
+        let lineage = ReleaseLineage::promoted(
+            "1.2.0",
+            "1.2.0-rc2",
+            PathBuf::from("/test/repo/next-1.2.0-rc2"),
+            vec![1, 2],
+        );
+        assert!(!lineage.is_candidate());
+        state.release = Some(lineage.clone());
+
+        let json = serde_json::to_string(&state).unwrap();
+        let restored: MergeStateFile = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.release, Some(lineage));
+        assert!(ReleaseLineage::candidate("1.2.0", 1).is_candidate());
     }
 
     /// # State File Round Trip
@@ -974,6 +1474,11 @@ mod tests {
             pr_title: "Test PR".to_string(),
             status: StateItemStatus::Success,
             work_item_ids: vec![1, 2, 3],
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
         });
         state.phase = MergePhase::ReadyForCompletion;
 
@@ -1239,14 +1744,13 @@ mod tests {
         // SAFETY: Tests are run single-threaded
         unsafe { std::env::set_var(STATE_DIR_ENV, temp_dir.path()) };
 
-        let repo_path = temp_dir.path().join("repo");
-        fs::create_dir(&repo_path).unwrap();
+        let key = LockKey::for_repository("org", "project", "repo", "main");
 
-        let lock_path = lock_path_for_repo(&repo_path).unwrap();
+        let lock_path = lock_path(&key).unwrap();
 
         // Acquire lock
         {
-            let guard = LockGuard::acquire(&repo_path).unwrap();
+            let guard = LockGuard::acquire(&key, LockKind::Merge).unwrap();
             assert!(guard.is_some());
             assert!(lock_path.exists());
         }
@@ -1275,21 +1779,90 @@ mod tests {
         // SAFETY: Tests are run single-threaded
         unsafe { std::env::set_var(STATE_DIR_ENV, temp_dir.path()) };
 
-        let repo_path = temp_dir.path().join("repo");
-        fs::create_dir(&repo_path).unwrap();
+        let key = LockKey::for_repository("org", "project", "repo", "main");
 
         // Acquire first lock
-        let guard1 = LockGuard::acquire(&repo_path).unwrap();
+        let guard1 = LockGuard::acquire(&key, LockKind::Merge).unwrap();
         assert!(guard1.is_some());
 
         // Second acquisition should fail
-        let guard2 = LockGuard::acquire(&repo_path).unwrap();
+        let guard2 = LockGuard::acquire(&key, LockKind::Merge).unwrap();
         assert!(guard2.is_none());
 
         // SAFETY: Tests are run single-threaded
         unsafe { std::env::remove_var(STATE_DIR_ENV) };
     }
 
+    /// # Lock Keys And Kinds
+    ///
+    /// Verifies locks are keyed by remote and target branch, and that any
+    /// two operations on a key exclude each other.
+    ///
+    /// ## Test Scenario
+    /// - Builds keys from differently spelled remote URLs and branch refs
+    /// - Holds a merge lock and acquires other locks around it
+    ///
+    /// ## Expected Outcome
+    /// - Spellings of the same remote and branch give the same key
+    /// - The lock records the kind of operation holding it
+    /// - A cleanup of the target is blocked while a merge into it runs, and
+    ///   a merge while a cleanup runs
+    /// - A second merge into that target is blocked, one into another is not
+    /// - A second cleanup of the target is blocked
+    #[test]
+    #[serial]
+    fn test_lock_keys_and_kinds() {
+        let temp_dir = TempDir::new().unwrap();
+        // SAFETY: Tests are run single-threaded
+        unsafe { std::env::set_var(STATE_DIR_ENV, temp_dir.path()) };
+
+        let key = LockKey::for_repository("Org", "project", "repo", "main");
+        assert_eq!(
+            key,
+            LockKey::new(
+                "https://dev.azure.com/org/project/_git/repo.git/",
+                "refs/heads/main"
+            )
+        );
+        assert_eq!(key.target_branch(), "main");
+        assert_ne!(
+            key,
+            LockKey::for_repository("org", "project", "repo", "next")
+        );
+
+        let merge = LockGuard::acquire(&key, LockKind::Merge).unwrap().unwrap();
+        assert!(LockGuard::is_locked(&key).unwrap());
+        assert_eq!(LockGuard::holder(&key).unwrap(), Some(LockKind::Merge));
+        assert!(
+            LockGuard::acquire(&key, LockKind::Cleanup)
+                .unwrap()
+                .is_none()
+        );
+        assert!(LockGuard::acquire(&key, LockKind::Merge).unwrap().is_none());
+        drop(merge);
+
+        let _cleanup = LockGuard::acquire(&key, LockKind::Cleanup)
+            .unwrap()
+            .unwrap();
+        assert_eq!(LockGuard::holder(&key).unwrap(), Some(LockKind::Cleanup));
+        assert!(LockGuard::acquire(&key, LockKind::Merge).unwrap().is_none());
+        assert!(
+            LockGuard::acquire(&key, LockKind::Cleanup)
+                .unwrap()
+                .is_none()
+        );
+
+        let next = LockKey::for_repository("org", "project", "repo", "next");
+        assert!(
+            LockGuard::acquire(&next, LockKind::Merge)
+                .unwrap()
+                .is_some()
+        );
+
+        // SAFETY: Tests are run single-threaded
+        unsafe { std::env::remove_var(STATE_DIR_ENV) };
+    }
+
     /// # Run Hooks Defaults False
     ///
     /// Verifies that run_hooks defaults to false when missing from JSON.
@@ -1358,6 +1931,11 @@ mod tests {
                 pr_title: "PR 1".to_string(),
                 status: StateItemStatus::Pending,
                 work_item_ids: vec![],
+                work_item_titles: Default::default(),
+                picked_commit_id: None,
+                mainline_parent: None,
+                pr_closed_at: None,
+                had_conflict: false,
             },
             StateCherryPickItem {
                 commit_id: "b".to_string(),
@@ -1365,6 +1943,11 @@ mod tests {
                 pr_title: "PR 2".to_string(),
                 status: StateItemStatus::Success,
                 work_item_ids: vec![],
+                work_item_titles: Default::default(),
+                picked_commit_id: None,
+                mainline_parent: None,
+                pr_closed_at: None,
+                had_conflict: false,
             },
             StateCherryPickItem {
                 commit_id: "c".to_string(),
@@ -1372,6 +1955,11 @@ mod tests {
                 pr_title: "PR 3".to_string(),
                 status: StateItemStatus::Success,
                 work_item_ids: vec![],
+                work_item_titles: Default::default(),
+                picked_commit_id: None,
+                mainline_parent: None,
+                pr_closed_at: None,
+                had_conflict: false,
             },
             StateCherryPickItem {
                 commit_id: "d".to_string(),
@@ -1379,6 +1967,11 @@ mod tests {
                 pr_title: "PR 4".to_string(),
                 status: StateItemStatus::Skipped,
                 work_item_ids: vec![],
+                work_item_titles: Default::default(),
+                picked_commit_id: None,
+                mainline_parent: None,
+                pr_closed_at: None,
+                had_conflict: false,
             },
             StateCherryPickItem {
                 commit_id: "e".to_string(),
@@ -1388,6 +1981,11 @@ mod tests {
                     message: "error".to_string(),
                 },
                 work_item_ids: vec![],
+                work_item_titles: Default::default(),
+                picked_commit_id: None,
+                mainline_parent: None,
+                pr_closed_at: None,
+                had_conflict: false,
             },
         ];
 
@@ -1449,7 +2047,67 @@ mod tests {
             false,
         );
         assert_eq!(state.schema_version, SCHEMA_VERSION);
-        assert_eq!(state.schema_version, 1);
+        assert_eq!(state.schema_version, 2);
+    }
+
+    /// # Upgrade State File
+    ///
+    /// Verifies that a version 1 state file is migrated on load and
+    /// rewritten by an upgrade.
+    ///
+    /// ## Test Scenario
+    /// - Writes a version 1 state file, loads it, then upgrades it with and
+    ///   without dry run, and again once current
+    ///
+    /// ## Expected Outcome
+    /// - Loading migrates in memory without touching the file
+    /// - A dry run reports version 1 and leaves the file alone
+    /// - The upgrade rewrites it as version 2, keeping updated_at and
+    ///   recording the mergers version; a second upgrade reports nothing
+    #[test]
+    fn test_upgrade_state_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("merge-0123456789abcdef.json");
+        let v1 = r#"{
+            "schema_version": 1,
+            "created_at": "2024-01-15T10:00:00Z",
+            "updated_at": "2024-01-15T10:30:00Z",
+            "repo_path": "/test/repo",
+            "is_worktree": false,
+            "organization": "org",
+            "project": "project",
+            "repository": "repo",
+            "dev_branch": "dev",
+            "target_branch": "next",
+            "merge_version": "v1.0.0",
+            "cherry_pick_items": [
+                { "commit_id": "abc", "pr_id": 1, "pr_title": "PR 1", "status": "conflict" }
+            ],
+            "current_index": 0,
+            "phase": "awaiting_conflict_resolution",
+            "conflicted_files": ["src/lib.rs"],
+            "work_item_state": "Done",
+            "tag_prefix": "merged-"
+        }"#;
+        fs::write(&path, v1).unwrap();
+
+        let loaded = MergeStateFile::load(&path).unwrap();
+        assert_eq!(loaded.schema_version, SCHEMA_VERSION);
+        assert!(loaded.cherry_pick_items[0].had_conflict);
+        loaded.validate().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), v1);
+
+        assert_eq!(MergeStateFile::upgrade(&path, true).unwrap(), Some(1));
+        assert_eq!(fs::read_to_string(&path).unwrap(), v1);
+
+        assert_eq!(MergeStateFile::upgrade(&path, false).unwrap(), Some(1));
+        let upgraded: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(upgraded["schema_version"], 2);
+        assert_eq!(upgraded["updated_at"], "2024-01-15T10:30:00Z");
+        assert_eq!(upgraded["written_by"], env!("CARGO_PKG_VERSION"));
+
+        assert_eq!(MergeStateFile::upgrade(&path, false).unwrap(), None);
     }
 
     /// # Path For Repo Generation
@@ -1496,27 +2154,83 @@ mod tests {
         unsafe { std::env::remove_var(STATE_DIR_ENV) };
     }
 
-    /// # Lock Path For Repo Generation
+    /// # Legacy State File Location
     ///
-    /// Verifies lock file path is generated correctly for a repo.
+    /// Verifies state files at the legacy location are found and moved.
     ///
     /// ## Test Scenario
-    /// - Creates temp dir and sets as state dir
-    /// - Gets lock path for a repo
+    /// - Looks up the state file of a repo without one
+    /// - Saves a state file at the legacy location and looks it up again
+    /// - Removes the legacy file after saving at a different path
     ///
     /// ## Expected Outcome
-    /// - Lock path is in state dir with merge- prefix and .lock suffix
+    /// - No state file is found before saving
+    /// - The legacy file is found, and removed once saved elsewhere
+    /// - A legacy path equal to the current path is kept
     #[test]
     #[serial]
-    fn test_lock_path_for_repo() {
+    fn test_legacy_state_path() {
         let temp_dir = TempDir::new().unwrap();
         // SAFETY: Tests are run single-threaded
         unsafe { std::env::set_var(STATE_DIR_ENV, temp_dir.path()) };
 
         let repo_path = temp_dir.path().join("my-repo");
         fs::create_dir(&repo_path).unwrap();
+        assert_eq!(existing_path_for_repo(&repo_path).unwrap(), None);
 
-        let lock_path = lock_path_for_repo(&repo_path).unwrap();
+        let legacy = legacy_path_for_repo(&repo_path).unwrap();
+        let mut state = MergeStateFile::new(
+            repo_path.clone(),
+            None,
+            false,
+            "org".to_string(),
+            "project".to_string(),
+            "repo".to_string(),
+            "dev".to_string(),
+            "next".to_string(),
+            "v1.0.0".to_string(),
+            "Done".to_string(),
+            "merged-".to_string(),
+            false,
+        );
+        state.save(&legacy).unwrap();
+        assert_eq!(
+            existing_path_for_repo(&repo_path).unwrap(),
+            Some(legacy.clone())
+        );
+        assert!(MergeStateFile::load_for_repo(&repo_path).unwrap().is_some());
+
+        remove_legacy_state(&legacy, &legacy).unwrap();
+        assert!(legacy.exists());
+        let current = temp_dir.path().join("merge-current.json");
+        state.save(&current).unwrap();
+        remove_legacy_state(&current, &legacy).unwrap();
+        assert!(!legacy.exists());
+        assert!(current.exists());
+
+        // SAFETY: Tests are run single-threaded
+        unsafe { std::env::remove_var(STATE_DIR_ENV) };
+    }
+
+    /// # Lock Path Generation
+    ///
+    /// Verifies lock file path is generated correctly for a key.
+    ///
+    /// ## Test Scenario
+    /// - Creates temp dir and sets as state dir
+    /// - Gets the lock path for a repository and target branch
+    ///
+    /// ## Expected Outcome
+    /// - Lock path is in state dir with lock- prefix and .lock suffix
+    #[test]
+    #[serial]
+    fn test_lock_path() {
+        let temp_dir = TempDir::new().unwrap();
+        // SAFETY: Tests are run single-threaded
+        unsafe { std::env::set_var(STATE_DIR_ENV, temp_dir.path()) };
+
+        let key = LockKey::for_repository("org", "project", "repo", "main");
+        let lock_path = lock_path(&key).unwrap();
 
         assert!(lock_path.starts_with(temp_dir.path()));
         assert!(
@@ -1525,7 +2239,7 @@ mod tests {
                 .unwrap()
                 .to_str()
                 .unwrap()
-                .starts_with("merge-")
+                .starts_with("lock-")
         );
         assert!(
             lock_path
@@ -1575,16 +2289,19 @@ mod tests {
         assert!(deserialized.run_hooks);
     }
 
-    /// # Lock Content Is PID
+    /// # Lock Content Is PID And Kind
     ///
-    /// Verifies that lock file contains the current process PID.
+    /// Verifies that lock file records the current process PID and the kind
+    /// of operation holding it.
     ///
     /// ## Test Scenario
-    /// - Acquires a lock
+    /// - Acquires a cleanup lock
     /// - Reads lock file content
+    /// - Replaces it with a lock from an older version holding only a PID
     ///
     /// ## Expected Outcome
-    /// - Lock file contains current PID as text
+    /// - Lock file contains current PID and `cleanup` on separate lines
+    /// - A lock without a kind is reported as held by a merge
     #[test]
     #[serial]
     fn test_lock_content_is_pid() {
@@ -1592,18 +2309,21 @@ mod tests {
         // SAFETY: Tests are run single-threaded
         unsafe { std::env::set_var(STATE_DIR_ENV, temp_dir.path()) };
 
-        let repo_path = temp_dir.path().join("repo");
-        fs::create_dir(&repo_path).unwrap();
+        let key = LockKey::for_repository("org", "project", "repo", "main");
 
-        let lock_path = lock_path_for_repo(&repo_path).unwrap();
+        let lock_path = lock_path(&key).unwrap();
         let expected_pid = std::process::id().to_string();
 
         // Acquire lock
-        let _guard = LockGuard::acquire(&repo_path).unwrap();
+        let guard = LockGuard::acquire(&key, LockKind::Cleanup).unwrap();
 
         // Read lock file content
         let content = fs::read_to_string(&lock_path).unwrap();
-        assert_eq!(content.trim(), expected_pid);
+        assert_eq!(content, format!("{}\ncleanup\n", expected_pid));
+
+        fs::write(&lock_path, &expected_pid).unwrap();
+        assert_eq!(LockGuard::holder(&key).unwrap(), Some(LockKind::Merge));
+        drop(guard);
 
         // SAFETY: Tests are run single-threaded
         unsafe { std::env::remove_var(STATE_DIR_ENV) };
@@ -0,0 +1,274 @@
+//! Settings of the previous merge run of each repository.
+//!
+//! Whenever a merge creates its state file, the settings it ran with are
+//! saved per Azure DevOps repository to `last-runs.json` in the state
+//! directory. The next run compares its settings against them, so the TUI
+//! can point out a changed target branch or tag prefix before anything is
+//! cherry-picked.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::file::state_dir;
+use super::manager::StateCreateConfig;
+
+/// File name of the last run settings in the state directory.
+pub const LAST_RUNS_FILE_NAME: &str = "last-runs.json";
+
+/// Settings a merge ran with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunSettings {
+    /// Source branch for PRs.
+    pub dev_branch: String,
+    /// Target branch for cherry-picks.
+    pub target_branch: String,
+    /// Merge version, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// State to set work items to after completion.
+    pub work_item_state: String,
+    /// Prefix for PR tags.
+    pub tag_prefix: String,
+    /// Whether git hooks were enabled.
+    pub run_hooks: bool,
+}
+
+impl RunSettings {
+    /// Settings of a merge about to create its state file.
+    pub fn new(config: &StateCreateConfig, version: Option<&str>) -> Self {
+        Self {
+            dev_branch: config.dev_branch.clone(),
+            target_branch: config.target_branch.clone(),
+            version: version.map(str::to_string),
+            work_item_state: config.work_item_state.clone(),
+            tag_prefix: config.tag_prefix.clone(),
+            run_hooks: config.run_hooks,
+        }
+    }
+
+    /// Settings that differ from `current`, in display order.
+    ///
+    /// The version is only compared when both runs know it.
+    pub fn changes(&self, current: &RunSettings) -> Vec<SettingChange> {
+        let mut changes = Vec::new();
+        let mut compare = |name: &'static str, previous: String, current: String| {
+            if previous != current {
+                changes.push(SettingChange {
+                    name,
+                    previous,
+                    current,
+                });
+            }
+        };
+        compare(
+            "Dev Branch",
+            self.dev_branch.clone(),
+            current.dev_branch.clone(),
+        );
+        compare(
+            "Target Branch",
+            self.target_branch.clone(),
+            current.target_branch.clone(),
+        );
+        if let (Some(previous), Some(version)) = (&self.version, &current.version) {
+            compare("Version", previous.clone(), version.clone());
+        }
+        compare(
+            "Work Item State",
+            self.work_item_state.clone(),
+            current.work_item_state.clone(),
+        );
+        compare(
+            "Tag Prefix",
+            self.tag_prefix.clone(),
+            current.tag_prefix.clone(),
+        );
+        compare(
+            "Run Hooks",
+            self.run_hooks.to_string(),
+            current.run_hooks.to_string(),
+        );
+        changes
+    }
+}
+
+/// One setting that changed since the previous run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingChange {
+    /// Display name of the setting.
+    pub name: &'static str,
+    /// Value in the previous run.
+    pub previous: String,
+    /// Value in this run.
+    pub current: String,
+}
+
+/// The previous run of a repository.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LastRun {
+    /// When the run created its state file.
+    pub recorded_at: DateTime<Utc>,
+    /// Settings the run used.
+    #[serde(flatten)]
+    pub settings: RunSettings,
+}
+
+/// Key of a repository in the last runs file.
+pub fn last_run_key(organization: &str, project: &str, repository: &str) -> String {
+    format!("{}/{}/{}", organization, project, repository).to_lowercase()
+}
+
+/// Path of the last runs file in the state directory.
+pub fn last_runs_path() -> Result<PathBuf> {
+    Ok(state_dir()?.join(LAST_RUNS_FILE_NAME))
+}
+
+fn load_all(path: &Path) -> Result<BTreeMap<String, LastRun>> {
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read last runs: {}", path.display()))?;
+    serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to parse last runs: {}", path.display()))
+}
+
+/// Loads the previous run stored under `key`, if any.
+pub fn load_last_run(path: &Path, key: &str) -> Result<Option<LastRun>> {
+    Ok(load_all(path)?.remove(key))
+}
+
+/// Stores `run` under `key`, replacing the previous run of that repository.
+pub fn save_last_run(path: &Path, key: &str, run: LastRun) -> Result<()> {
+    let mut runs = load_all(path).unwrap_or_default();
+    runs.insert(key.to_string(), run);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create state directory: {}", parent.display()))?;
+    }
+    let contents = serde_json::to_string_pretty(&runs)?;
+    std::fs::write(path, contents)
+        .with_context(|| format!("Failed to write last runs: {}", path.display()))
+}
+
+/// Records the settings of a merge starting at `recorded_at`.
+pub fn record_last_run(
+    config: &StateCreateConfig,
+    version: &str,
+    recorded_at: DateTime<Utc>,
+) -> Result<()> {
+    save_last_run(
+        &last_runs_path()?,
+        &last_run_key(&config.organization, &config.project, &config.repository),
+        LastRun {
+            recorded_at,
+            settings: RunSettings::new(config, Some(version)),
+        },
+    )
+}
+
+/// The previous run of the repository a merge is configured for.
+///
+/// Returns `None` if there is no previous run or it cannot be read.
+pub fn previous_run(config: &StateCreateConfig) -> Option<LastRun> {
+    let path = last_runs_path().ok()?;
+    load_last_run(
+        &path,
+        &last_run_key(&config.organization, &config.project, &config.repository),
+    )
+    .ok()
+    .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn settings(target_branch: &str, version: Option<&str>) -> RunSettings {
+        RunSettings {
+            dev_branch: "dev".to_string(),
+            target_branch: target_branch.to_string(),
+            version: version.map(str::to_string),
+            work_item_state: "Next Merged".to_string(),
+            tag_prefix: "merged-".to_string(),
+            run_hooks: false,
+        }
+    }
+
+    /// # Setting Changes
+    ///
+    /// Tests listing the settings that differ between two runs.
+    ///
+    /// ## Test Scenario
+    /// - Compares runs with a different target branch and version
+    /// - Compares a run against one without a version
+    ///
+    /// ## Expected Outcome
+    /// - Changed settings are listed with both values, in display order
+    /// - The version is skipped when one run does not know it
+    #[test]
+    fn test_setting_changes() {
+        let previous = settings("next", Some("1.2.0"));
+
+        assert_eq!(
+            previous.changes(&settings("release", Some("1.3.0"))),
+            vec![
+                SettingChange {
+                    name: "Target Branch",
+                    previous: "next".to_string(),
+                    current: "release".to_string(),
+                },
+                SettingChange {
+                    name: "Version",
+                    previous: "1.2.0".to_string(),
+                    current: "1.3.0".to_string(),
+                },
+            ]
+        );
+        assert!(previous.changes(&settings("next", None)).is_empty());
+    }
+
+    /// # Last Run Round Trip
+    ///
+    /// Tests saving and loading the previous run of several repositories.
+    ///
+    /// ## Test Scenario
+    /// - Saves runs for two repositories, then replaces the first
+    ///
+    /// ## Expected Outcome
+    /// - Each repository keeps its own latest run
+    /// - Unknown repositories and a missing file have no previous run
+    #[test]
+    fn test_last_run_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("state").join(LAST_RUNS_FILE_NAME);
+        assert_eq!(load_last_run(&path, "org/proj/repo").unwrap(), None);
+
+        let run = |target: &str| LastRun {
+            recorded_at: Utc::now(),
+            settings: settings(target, Some("1.0.0")),
+        };
+        let key = last_run_key("Org", "Proj", "Repo");
+        save_last_run(&path, &key, run("next")).unwrap();
+        save_last_run(&path, "org/proj/other", run("main")).unwrap();
+        let latest = run("release");
+        save_last_run(&path, &key, latest.clone()).unwrap();
+
+        assert_eq!(key, "org/proj/repo");
+        assert_eq!(load_last_run(&path, &key).unwrap(), Some(latest));
+        assert_eq!(
+            load_last_run(&path, "org/proj/other")
+                .unwrap()
+                .unwrap()
+                .settings
+                .target_branch,
+            "main"
+        );
+        assert_eq!(load_last_run(&path, "org/proj/unknown").unwrap(), None);
+    }
+}
@@ -4,12 +4,14 @@
 //! and lock handling that can be shared across components via `Arc<Mutex<StateManager>>`.
 
 use crate::core::state::{
-    LockGuard, MergePhase, MergeStateFile, MergeStateFileBuilder, MergeStatus, StateCherryPickItem,
-    StateItemStatus,
+    LockGuard, LockKey, LockKind, MergePhase, MergeStateFile, MergeStateFileBuilder, MergeStatus,
+    ReleaseLineage, StateCherryPickItem, StateItemStatus, record_last_run,
 };
+use crate::utils::{Clock, SystemClock};
 use anyhow::Result;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 /// Configuration for state file creation.
 ///
@@ -34,6 +36,30 @@ pub struct StateCreateConfig {
     pub work_item_state: String,
     /// Whether git hooks are enabled for this merge.
     pub run_hooks: bool,
+    /// Whether the commits and tags of this merge are signed.
+    pub sign_commits: bool,
+    /// Every target branch of the fan-out this merge is part of (empty for
+    /// a single target).
+    pub fan_out: Vec<String>,
+    /// Commit message template of squashed PRs (`None` when squash mode is off).
+    pub squash_message: Option<String>,
+    /// Whether a merge commit whose first parent conflicts is retried with
+    /// its second parent as mainline (`mainline_fallback`).
+    pub mainline_fallback: bool,
+    /// Directory holding the artifacts of the run, if one was created.
+    pub run_dir: Option<PathBuf>,
+}
+
+impl StateCreateConfig {
+    /// Returns the key of the lock protecting the merge.
+    pub fn lock_key(&self) -> LockKey {
+        LockKey::for_repository(
+            &self.organization,
+            &self.project,
+            &self.repository,
+            &self.target_branch,
+        )
+    }
 }
 
 /// Manages state file and lock for merge operations.
@@ -68,6 +94,8 @@ pub struct StateManager {
     state_file: Option<MergeStateFile>,
     /// Lock guard for exclusive merge access.
     lock_guard: Option<LockGuard>,
+    /// Clock stamping the state file.
+    clock: Arc<dyn Clock>,
 }
 
 impl Default for StateManager {
@@ -90,9 +118,18 @@ impl StateManager {
         Self {
             state_file: None,
             lock_guard: None,
+            clock: Arc::new(SystemClock),
         }
     }
 
+    /// Sets the clock stamping the state file, including one already set.
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        if let Some(state_file) = &mut self.state_file {
+            state_file.set_clock(clock.clone());
+        }
+        self.clock = clock;
+    }
+
     /// Creates a new state file and acquires a lock.
     ///
     /// This method:
@@ -115,7 +152,7 @@ impl StateManager {
     /// # Errors
     ///
     /// Returns an error if:
-    /// - Another merge operation is in progress (lock acquisition fails)
+    /// - Another merge into the same target is in progress (lock acquisition fails)
     /// - Failed to create or save the state file
     pub fn create_state_file(
         &mut self,
@@ -126,11 +163,12 @@ impl StateManager {
         config: &StateCreateConfig,
     ) -> Result<PathBuf> {
         // Acquire lock first to ensure exclusive access
-        let guard = match LockGuard::acquire(&repo_path) {
+        let guard = match LockGuard::acquire(&config.lock_key(), LockKind::Merge) {
             Ok(Some(guard)) => guard,
             Ok(None) => {
                 return Err(anyhow::anyhow!(
-                    "Another merge operation is in progress for this repository"
+                    "Another merge operation into {} is in progress for this repository",
+                    config.target_branch
                 ));
             }
             Err(e) => {
@@ -150,11 +188,19 @@ impl StateManager {
             .merge_version(version)
             .work_item_state(&config.work_item_state)
             .tag_prefix(&config.tag_prefix)
-            .run_hooks(config.run_hooks);
+            .run_hooks(config.run_hooks)
+            .sign_commits(config.sign_commits)
+            .fan_out(config.fan_out.clone())
+            .squash_message(config.squash_message.clone())
+            .mainline_fallback(config.mainline_fallback)
+            .clock(self.clock.clone());
 
         if let Some(base_path) = base_repo_path {
             builder = builder.base_repo_path(base_path);
         }
+        if let Some(run_dir) = &config.run_dir {
+            builder = builder.run_dir(run_dir);
+        }
 
         let state_file = builder.build();
         self.state_file = Some(state_file);
@@ -164,6 +210,8 @@ impl StateManager {
             Ok(path) => {
                 // Only store lock guard after successful save
                 self.lock_guard = Some(guard);
+                // Best effort: only used to point out changed settings next time
+                let _ = record_last_run(config, version, self.clock.now());
                 Ok(path)
             }
             Err(e) => {
@@ -177,7 +225,8 @@ impl StateManager {
     /// Sets the state file (for resuming from existing state).
     ///
     /// Use this when loading a state file from disk to continue a merge operation.
-    pub fn set_state_file(&mut self, state_file: MergeStateFile) {
+    pub fn set_state_file(&mut self, mut state_file: MergeStateFile) {
+        state_file.set_clock(self.clock.clone());
         self.state_file = Some(state_file);
     }
 
@@ -240,6 +289,7 @@ impl StateManager {
     ) -> Result<Option<PathBuf>> {
         if let Some(ref mut state_file) = self.state_file {
             if let Some(item) = state_file.cherry_pick_items.get_mut(index) {
+                item.had_conflict |= status == StateItemStatus::Conflict;
                 item.status = status;
             }
             state_file.current_index = current_index;
@@ -250,6 +300,52 @@ impl StateManager {
         }
     }
 
+    /// Records the commit a successful cherry-pick created on the patch branch.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(path))` - The path where the state file was saved
+    /// * `Ok(None)` - No state file is set (operation is a no-op)
+    /// * `Err` - Failed to save the state file
+    pub fn set_item_picked_commit(
+        &mut self,
+        index: usize,
+        commit_id: String,
+    ) -> Result<Option<PathBuf>> {
+        if let Some(ref mut state_file) = self.state_file {
+            if let Some(item) = state_file.cherry_pick_items.get_mut(index) {
+                item.picked_commit_id = Some(commit_id);
+            }
+            let path = state_file.save_for_repo()?;
+            Ok(Some(path))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Records the mainline parent a cherry-pick used instead of the first.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(path))` - The path where the state file was saved
+    /// * `Ok(None)` - No state file is set (operation is a no-op)
+    /// * `Err` - Failed to save the state file
+    pub fn set_item_mainline_parent(
+        &mut self,
+        index: usize,
+        mainline_parent: u8,
+    ) -> Result<Option<PathBuf>> {
+        if let Some(ref mut state_file) = self.state_file {
+            if let Some(item) = state_file.cherry_pick_items.get_mut(index) {
+                item.mainline_parent = Some(mainline_parent);
+            }
+            let path = state_file.save_for_repo()?;
+            Ok(Some(path))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Syncs the current cherry-pick index to the state file.
     ///
     /// # Returns
@@ -325,6 +421,23 @@ impl StateManager {
         }
     }
 
+    /// Sets the release candidate lineage in the state file.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(path))` - The path where the state file was saved
+    /// * `Ok(None)` - No state file is set (operation is a no-op)
+    /// * `Err` - Failed to save the state file
+    pub fn set_release_lineage(&mut self, lineage: ReleaseLineage) -> Result<Option<PathBuf>> {
+        if let Some(ref mut state_file) = self.state_file {
+            state_file.release = Some(lineage);
+            let path = state_file.save_for_repo()?;
+            Ok(Some(path))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Clears conflicted files in the state file.
     ///
     /// # Returns
@@ -348,8 +461,8 @@ impl StateManager {
     /// and the state file is no longer needed.
     pub fn cleanup(&mut self) -> Result<()> {
         if let Some(ref state_file) = self.state_file {
-            let path = crate::core::state::path_for_repo(&state_file.repo_path)?;
-            if path.exists() {
+            let path = crate::core::state::existing_path_for_repo(&state_file.repo_path)?;
+            if let Some(path) = path {
                 std::fs::remove_file(&path)?;
             }
         }
@@ -419,7 +532,7 @@ impl StateManager {
     pub fn set_final_status(&mut self, status: MergeStatus) -> Result<Option<PathBuf>> {
         if let Some(ref mut state_file) = self.state_file {
             state_file.final_status = Some(status);
-            state_file.completed_at = Some(chrono::Utc::now());
+            state_file.completed_at = Some(self.clock.now());
             let path = state_file.save_for_repo()?;
             Ok(Some(path))
         } else {
@@ -473,6 +586,11 @@ mod tests {
             tag_prefix: "merged/".to_string(),
             work_item_state: "Next Merged".to_string(),
             run_hooks: false,
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            run_dir: None,
         }
     }
 
@@ -558,6 +676,59 @@ mod tests {
         unsafe { std::env::remove_var(STATE_DIR_ENV) };
     }
 
+    /// # State File Clock
+    ///
+    /// Verifies that the state file is stamped by the manager's clock.
+    ///
+    /// ## Test Scenario
+    /// - Creates a state file with a fixed clock
+    /// - Moves the clock forward and sets the final status
+    ///
+    /// ## Expected Outcome
+    /// - Creation and completion carry the clock's times, also on disk
+    #[test]
+    #[serial]
+    fn test_state_file_clock() {
+        use crate::utils::FixedClock;
+        use chrono::TimeZone;
+
+        let temp_state_dir = TempDir::new().unwrap();
+        let temp_repo = TempDir::new().unwrap();
+
+        // SAFETY: Tests are run single-threaded
+        unsafe { std::env::set_var(STATE_DIR_ENV, temp_state_dir.path()) };
+
+        let created = chrono::Utc.with_ymd_and_hms(2025, 1, 15, 12, 0, 0).unwrap();
+        let completed = chrono::Utc.with_ymd_and_hms(2025, 1, 15, 13, 0, 0).unwrap();
+        let mut manager = StateManager::new();
+        manager.set_clock(Arc::new(FixedClock::new(created)));
+        manager
+            .create_state_file(
+                temp_repo.path().to_path_buf(),
+                None,
+                false,
+                "v1.0.0",
+                &create_test_config(),
+            )
+            .unwrap();
+        let state = manager.state_file().unwrap();
+        assert_eq!(state.created_at, created);
+        assert_eq!(state.updated_at, created);
+
+        manager.set_clock(Arc::new(FixedClock::new(completed)));
+        let path = manager
+            .set_final_status(MergeStatus::Success)
+            .unwrap()
+            .unwrap();
+        let saved = MergeStateFile::load(&path).unwrap();
+        assert_eq!(saved.created_at, created);
+        assert_eq!(saved.updated_at, completed);
+        assert_eq!(saved.completed_at, Some(completed));
+
+        // SAFETY: Tests are run single-threaded
+        unsafe { std::env::remove_var(STATE_DIR_ENV) };
+    }
+
     /// # Update Phase
     ///
     /// Verifies that update_phase updates and saves the state file.
@@ -663,6 +834,11 @@ mod tests {
                 pr_title: "Test PR".to_string(),
                 status: StateItemStatus::Pending,
                 work_item_ids: vec![],
+                work_item_titles: Default::default(),
+                picked_commit_id: None,
+                mainline_parent: None,
+                pr_closed_at: None,
+                had_conflict: false,
             });
 
         let result = manager.update_item_status(0, StateItemStatus::Success, 1);
@@ -676,6 +852,76 @@ mod tests {
         unsafe { std::env::remove_var(STATE_DIR_ENV) };
     }
 
+    /// # Set Item Picked Commit
+    ///
+    /// Verifies that the commit created by a cherry-pick is saved with the item.
+    ///
+    /// ## Test Scenario
+    /// - Creates a state file with one item
+    /// - Records the picked commit and reloads the saved file
+    ///
+    /// ## Expected Outcome
+    /// - The reloaded item carries the picked commit
+    /// - Items without one omit the field when serialized
+    #[test]
+    #[serial]
+    fn test_set_item_picked_commit() {
+        let temp_state_dir = TempDir::new().unwrap();
+        let temp_repo = TempDir::new().unwrap();
+
+        // SAFETY: Tests are run single-threaded
+        unsafe { std::env::set_var(STATE_DIR_ENV, temp_state_dir.path()) };
+
+        let mut manager = StateManager::new();
+        let config = create_test_config();
+        manager
+            .create_state_file(
+                temp_repo.path().to_path_buf(),
+                None,
+                false,
+                "v1.0.0",
+                &config,
+            )
+            .unwrap();
+        let item = StateCherryPickItem {
+            commit_id: "abc123".to_string(),
+            pr_id: 42,
+            pr_title: "Test PR".to_string(),
+            status: StateItemStatus::Success,
+            work_item_ids: vec![],
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
+        };
+        assert!(
+            !serde_json::to_string(&item)
+                .unwrap()
+                .contains("picked_commit_id")
+        );
+        manager
+            .state_file_mut()
+            .unwrap()
+            .cherry_pick_items
+            .push(item);
+
+        let path = manager
+            .set_item_picked_commit(0, "def456".to_string())
+            .unwrap()
+            .unwrap();
+
+        let saved: MergeStateFile =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(
+            saved.cherry_pick_items[0].picked_commit_id.as_deref(),
+            Some("def456")
+        );
+
+        // SAFETY: Tests are run single-threaded
+        unsafe { std::env::remove_var(STATE_DIR_ENV) };
+    }
+
     /// # Cleanup
     ///
     /// Verifies that cleanup removes the state file and releases the lock.
@@ -1063,6 +1309,11 @@ mod tests {
                 pr_title: "PR 1".to_string(),
                 status: StateItemStatus::Pending,
                 work_item_ids: vec![100],
+                work_item_titles: Default::default(),
+                picked_commit_id: None,
+                mainline_parent: None,
+                pr_closed_at: None,
+                had_conflict: false,
             },
             StateCherryPickItem {
                 commit_id: "def456".to_string(),
@@ -1070,6 +1321,11 @@ mod tests {
                 pr_title: "PR 2".to_string(),
                 status: StateItemStatus::Pending,
                 work_item_ids: vec![101, 102],
+                work_item_titles: Default::default(),
+                picked_commit_id: None,
+                mainline_parent: None,
+                pr_closed_at: None,
+                had_conflict: false,
             },
         ];
 
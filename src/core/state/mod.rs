@@ -16,13 +16,67 @@
 //! ```
 //!
 //! Where `{hash}` is the first 16 characters of SHA-256 of the canonical repository path.
+//!
+//! # Schema Versions
+//!
+//! State files record the schema version they were written with. Older
+//! versions are migrated when loaded, see [`migrate_state`], and can be
+//! rewritten ahead of time with `mergers state upgrade`.
+//!
+//! # Locks
+//!
+//! Locks are keyed by the remote repository URL and the target branch, so
+//! they cover every checkout of a repository:
+//!
+//! ```text
+//! $MERGERS_STATE_DIR/lock-{hash}.lock
+//! ```
+//!
+//! Merges and cleanups share the one lock file of a target, so no two of them
+//! run on it at once. The file is created atomically and records the pid and
+//! [`LockKind`] of its holder.
+//!
+//! # Last Runs
+//!
+//! The settings of the latest merge of each repository are kept in
+//! `$MERGERS_STATE_DIR/last-runs.json`, see [`LastRun`].
+//!
+//! # Selection Sessions
+//!
+//! PR selections started with `--session NAME` are kept in
+//! `$MERGERS_STATE_DIR/sessions/{name}.json`, see [`SelectionSession`].
+//!
+//! # Run Directories
+//!
+//! Artifacts of each merge are written to its own directory, recorded in
+//! the state file and removed by `mergers gc`:
+//!
+//! ```text
+//! $MERGERS_STATE_DIR/runs/{timestamp}-{pid}/
+//! ```
 
 mod file;
+mod last_run;
 mod manager;
+mod run_dir;
+mod schema;
+mod session;
 
 pub use file::{
-    LockGuard, MergePhase, MergeStateFile, MergeStateFileBuilder, MergeStatus, STATE_DIR_ENV,
-    StateCherryPickItem, StateItemStatus, compute_repo_hash, lock_path_for_repo, path_for_repo,
-    state_dir,
+    LockGuard, LockKey, LockKind, MergePhase, MergeStateFile, MergeStateFileBuilder, MergeStatus,
+    ReleaseLineage, STATE_DIR_ENV, StateCherryPickItem, StateItemStatus, compute_repo_hash,
+    existing_path_for_repo, lock_path, path_for_repo, state_dir, state_files,
+};
+pub use last_run::{
+    LAST_RUNS_FILE_NAME, LastRun, RunSettings, SettingChange, last_run_key, last_runs_path,
+    load_last_run, previous_run, record_last_run, save_last_run,
 };
 pub use manager::{StateCreateConfig, StateManager};
+pub use run_dir::{
+    DEFAULT_RUN_RETENTION_DAYS, GcReport, RUN_GC_GRACE_HOURS, RUN_RETENTION_ENV,
+    RUN_STATE_FILE_NAME, RUNS_DIR_NAME, RunDirEntry, active_run_dirs, create_run_dir,
+    create_run_dir_in, find_run_state, gc_run_dirs, list_run_dirs, prune_expired_run_dirs,
+    run_clone_path, run_retention_days, run_state_path, runs_dir,
+};
+pub use schema::{SCHEMA_VERSION, migrate_state};
+pub use session::{SESSIONS_DIR_NAME, SelectionSession, session_path};
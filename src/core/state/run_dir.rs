@@ -0,0 +1,447 @@
+//! Per-run working directories.
+//!
+//! Every merge gets its own directory under `runs/` in the state directory.
+//! Artifacts of the run, such as the repository clone when no local
+//! repository is configured, are written there, and the state file records
+//! the directory in [`MergeStateFile::run_dir`].
+//!
+//! When a merge completes, a snapshot of its state file is written to the
+//! run directory, so `mergers release-notes from-state` can regenerate the
+//! release notes of the run after the state file itself is gone.
+//!
+//! Run directories are removed by `mergers gc`, and directories older than
+//! the retention period are pruned whenever a merge starts. Directories
+//! referenced by a merge that is still in progress are never removed.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+
+use super::file::{MergeStateFile, state_dir, state_files};
+use crate::utils::{Clock, IdGen};
+
+/// Name of the directory holding run directories in the state directory.
+pub const RUNS_DIR_NAME: &str = "runs";
+
+/// Environment variable overriding the retention period in days.
+///
+/// `0` disables pruning when a merge starts.
+pub const RUN_RETENTION_ENV: &str = "MERGERS_RUN_RETENTION_DAYS";
+
+/// Days a finished run directory is kept before it is pruned.
+pub const DEFAULT_RUN_RETENTION_DAYS: u32 = 14;
+
+/// Name of the repository clone inside a run directory.
+const CLONE_DIR_NAME: &str = "clone";
+
+/// Name of the state file snapshot inside a run directory.
+pub const RUN_STATE_FILE_NAME: &str = "state.json";
+
+/// Hours a run directory is kept even without a maximum age.
+///
+/// A starting merge creates its run directory before the state file that
+/// marks it as active, so a concurrent `mergers gc` must not take it.
+pub const RUN_GC_GRACE_HOURS: i64 = 1;
+
+/// Path of the directory holding run directories.
+pub fn runs_dir() -> Result<PathBuf> {
+    Ok(state_dir()?.join(RUNS_DIR_NAME))
+}
+
+/// Creates a new run directory in the state directory.
+pub fn create_run_dir(clock: &dyn Clock, ids: &dyn IdGen) -> Result<PathBuf> {
+    create_run_dir_in(&runs_dir()?, clock, ids)
+}
+
+/// Creates a new run directory in `root`.
+///
+/// The directory is named after the current time of `clock` and an id from
+/// `ids`, with a counter appended if that name is taken.
+pub fn create_run_dir_in(root: &Path, clock: &dyn Clock, ids: &dyn IdGen) -> Result<PathBuf> {
+    std::fs::create_dir_all(root)
+        .with_context(|| format!("Failed to create runs directory: {}", root.display()))?;
+    let base = ids.next_id(&clock.now().format("%Y%m%dT%H%M%SZ").to_string());
+    let mut path = root.join(&base);
+    let mut counter = 1;
+    while path.exists() {
+        path = root.join(format!("{}-{}", base, counter));
+        counter += 1;
+    }
+    std::fs::create_dir(&path)
+        .with_context(|| format!("Failed to create run directory: {}", path.display()))?;
+    Ok(path)
+}
+
+/// Path of the repository clone in a run directory.
+pub fn run_clone_path(run_dir: &Path) -> PathBuf {
+    run_dir.join(CLONE_DIR_NAME)
+}
+
+/// Path of the state file snapshot in a run directory.
+pub fn run_state_path(run_dir: &Path) -> PathBuf {
+    run_dir.join(RUN_STATE_FILE_NAME)
+}
+
+/// Finds the state file snapshot of run `id` in `root`, or of the most
+/// recent run that has one.
+pub fn find_run_state(root: &Path, id: Option<&str>) -> Result<PathBuf> {
+    if let Some(id) = id {
+        let path = run_state_path(&root.join(id));
+        if !path.is_file() {
+            anyhow::bail!(
+                "Run '{}' has no saved state in {}; only completed merges keep one",
+                id,
+                root.display()
+            );
+        }
+        return Ok(path);
+    }
+    list_run_dirs(root)?
+        .iter()
+        .rev()
+        .map(|entry| run_state_path(&entry.path))
+        .find(|path| path.is_file())
+        .with_context(|| {
+            format!(
+                "No completed merge with a saved state in {}",
+                root.display()
+            )
+        })
+}
+
+/// A run directory on disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunDirEntry {
+    /// Directory name.
+    pub id: String,
+    /// Path of the directory.
+    pub path: PathBuf,
+    /// When the directory was last modified.
+    pub modified: DateTime<Utc>,
+    /// Total size of the files in the directory, in bytes.
+    pub size: u64,
+}
+
+/// Lists the run directories in `root`, oldest first.
+pub fn list_run_dirs(root: &Path) -> Result<Vec<RunDirEntry>> {
+    if !root.exists() {
+        return Ok(Vec::new());
+    }
+    let mut entries = Vec::new();
+    for entry in std::fs::read_dir(root)
+        .with_context(|| format!("Failed to read runs directory: {}", root.display()))?
+    {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if !metadata.is_dir() {
+            continue;
+        }
+        let path = entry.path();
+        entries.push(RunDirEntry {
+            id: entry.file_name().to_string_lossy().into_owned(),
+            // Without a modification time a directory is never old enough
+            // to be removed
+            modified: metadata
+                .modified()
+                .map(DateTime::<Utc>::from)
+                .unwrap_or(DateTime::<Utc>::MAX_UTC),
+            size: dir_size(&path),
+            path,
+        });
+    }
+    entries.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(entries)
+}
+
+fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let metadata = entry.path().symlink_metadata().ok()?;
+            Some(if metadata.is_dir() {
+                dir_size(&entry.path())
+            } else {
+                metadata.len()
+            })
+        })
+        .sum()
+}
+
+/// Names of the run directories referenced by merges still in progress.
+///
+/// Reads every state file in `state_dir`; unreadable files are skipped.
+pub fn active_run_dirs(state_dir: &Path) -> HashSet<String> {
+    state_files(state_dir)
+        .into_iter()
+        .filter_map(|path| MergeStateFile::load(&path).ok())
+        .filter(|state| !state.phase.is_terminal())
+        .filter_map(|state| {
+            state
+                .run_dir?
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+        })
+        .collect()
+}
+
+/// Outcome of a garbage collection of run directories.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GcReport {
+    /// Directories removed, or that would be removed in a dry run.
+    pub removed: Vec<RunDirEntry>,
+    /// Directories kept because a merge in progress references them.
+    pub active: Vec<RunDirEntry>,
+    /// Directories kept because they are younger than the maximum age.
+    pub recent: Vec<RunDirEntry>,
+}
+
+impl GcReport {
+    /// Total size of the removed directories, in bytes.
+    pub fn freed(&self) -> u64 {
+        self.removed.iter().map(|entry| entry.size).sum()
+    }
+}
+
+/// Removes the run directories in `root` that no merge in progress
+/// references.
+///
+/// Only directories last modified longer ago than `max_age` are removed,
+/// and never those modified in the last [`RUN_GC_GRACE_HOURS`]. With
+/// `dry_run`, nothing is removed but the report is the same.
+pub fn gc_run_dirs(
+    root: &Path,
+    active: &HashSet<String>,
+    max_age: Option<Duration>,
+    now: DateTime<Utc>,
+    dry_run: bool,
+) -> Result<GcReport> {
+    let min_age = max_age
+        .unwrap_or_else(Duration::zero)
+        .max(Duration::hours(RUN_GC_GRACE_HOURS));
+    let mut report = GcReport::default();
+    for entry in list_run_dirs(root)? {
+        if active.contains(&entry.id) {
+            report.active.push(entry);
+        } else if now - entry.modified < min_age {
+            report.recent.push(entry);
+        } else {
+            if !dry_run {
+                std::fs::remove_dir_all(&entry.path).with_context(|| {
+                    format!("Failed to remove run directory: {}", entry.path.display())
+                })?;
+            }
+            report.removed.push(entry);
+        }
+    }
+    Ok(report)
+}
+
+/// Retention period in days, from [`RUN_RETENTION_ENV`] or the default.
+pub fn run_retention_days() -> u32 {
+    std::env::var(RUN_RETENTION_ENV)
+        .ok()
+        .and_then(|days| days.trim().parse().ok())
+        .unwrap_or(DEFAULT_RUN_RETENTION_DAYS)
+}
+
+/// Prunes run directories older than the retention period at the current
+/// time of `clock`.
+///
+/// Called when a merge starts; does nothing if the retention is `0`.
+pub fn prune_expired_run_dirs(clock: &dyn Clock) -> Result<GcReport> {
+    let days = run_retention_days();
+    if days == 0 {
+        return Ok(GcReport::default());
+    }
+    let state_dir = state_dir()?;
+    gc_run_dirs(
+        &state_dir.join(RUNS_DIR_NAME),
+        &active_run_dirs(&state_dir),
+        Some(Duration::days(days.into())),
+        clock.now(),
+        false,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::state::MergePhase;
+    use crate::utils::{FixedClock, SequentialIdGen};
+    use chrono::TimeZone;
+    use tempfile::TempDir;
+
+    /// # Run Directory Creation
+    ///
+    /// Tests creating run directories for runs started in the same second.
+    ///
+    /// ## Test Scenario
+    /// - Creates two run directories with a fixed clock and sequential ids
+    /// - Creates a third with a new id generator, repeating the first name
+    ///
+    /// ## Expected Outcome
+    /// - Directories are named after the clock's time and the next id
+    /// - A taken name gets a counter appended
+    /// - The clone lives inside the run directory
+    #[test]
+    fn test_create_run_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().join(RUNS_DIR_NAME);
+        let clock = FixedClock::new(Utc.with_ymd_and_hms(2025, 3, 1, 12, 30, 0).unwrap());
+        let ids = SequentialIdGen::new();
+
+        let first = create_run_dir_in(&root, &clock, &ids).unwrap();
+        let second = create_run_dir_in(&root, &clock, &ids).unwrap();
+        let third = create_run_dir_in(&root, &clock, &SequentialIdGen::new()).unwrap();
+
+        let names: Vec<_> = [&first, &second, &third]
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "20250301T123000Z-1",
+                "20250301T123000Z-2",
+                "20250301T123000Z-1-1"
+            ]
+        );
+        assert!(first.is_dir());
+        assert_eq!(run_clone_path(&first).parent(), Some(first.as_path()));
+        assert_eq!(list_run_dirs(&root).unwrap().len(), 3);
+    }
+
+    /// # Run Directory Garbage Collection
+    ///
+    /// Tests which run directories are removed.
+    ///
+    /// ## Test Scenario
+    /// - Creates a run referenced by a merge in progress, one referenced by
+    ///   a completed merge, and one without a state file
+    /// - Collects with a maximum age, as a dry run, and without a maximum age
+    ///
+    /// ## Expected Outcome
+    /// - Runs of merges in progress are always kept
+    /// - Runs younger than the maximum age are kept, and without a maximum
+    ///   age those younger than the grace period
+    /// - A dry run reports but does not remove
+    #[test]
+    fn test_gc_run_dirs() {
+        let temp_dir = TempDir::new().unwrap();
+        let state_dir = temp_dir.path();
+        let root = state_dir.join(RUNS_DIR_NAME);
+        let now = Utc::now();
+        let ids = SequentialIdGen::new();
+        let runs: Vec<_> = (0..3)
+            .map(|_| create_run_dir_in(&root, &FixedClock::new(now), &ids).unwrap())
+            .collect();
+        std::fs::write(run_clone_path(&runs[1]), "x".repeat(10)).unwrap();
+
+        for (index, phase) in [(0, MergePhase::CherryPicking), (1, MergePhase::Completed)] {
+            let mut state = MergeStateFile::builder()
+                .repo_path("/work/repo")
+                .organization("org")
+                .project("project")
+                .repository("repo")
+                .dev_branch("dev")
+                .target_branch("main")
+                .merge_version("v1.0.0")
+                .work_item_state("Done")
+                .tag_prefix("merged-")
+                .run_dir(&runs[index])
+                .build();
+            state.phase = phase;
+            std::fs::write(
+                state_dir.join(format!("merge-{}.json", index)),
+                serde_json::to_string(&state).unwrap(),
+            )
+            .unwrap();
+        }
+        let active = active_run_dirs(state_dir);
+        assert_eq!(active.len(), 1);
+
+        let report = gc_run_dirs(&root, &active, Some(Duration::days(7)), now, false).unwrap();
+        assert_eq!(report.active.len(), 1);
+        assert_eq!(report.recent.len(), 2);
+        assert!(report.removed.is_empty());
+
+        let later = now + Duration::days(8);
+        let report = gc_run_dirs(&root, &active, Some(Duration::days(7)), later, true).unwrap();
+        assert_eq!(report.removed.len(), 2);
+        assert_eq!(report.freed(), 10);
+        assert!(runs.iter().all(|run| run.exists()));
+
+        let report = gc_run_dirs(&root, &active, None, now, false).unwrap();
+        assert_eq!(report.recent.len(), 2);
+        assert!(runs.iter().all(|run| run.exists()));
+
+        let report = gc_run_dirs(&root, &active, None, later, false).unwrap();
+        assert_eq!(report.removed.len(), 2);
+        assert!(runs[0].exists());
+        assert!(!runs[1].exists());
+        assert!(!runs[2].exists());
+    }
+
+    /// # Run State Snapshots
+    ///
+    /// Tests snapshotting state files to run directories and finding them.
+    ///
+    /// ## Test Scenario
+    /// - Creates three runs; the first two complete and snapshot their state
+    /// - Finds a snapshot by run id, the most recent one, and one of a run
+    ///   without a snapshot
+    ///
+    /// ## Expected Outcome
+    /// - The snapshot is written to the run directory
+    /// - Without an id, the newest run with a snapshot is found
+    /// - Runs without a snapshot and unknown runs are errors
+    #[test]
+    fn test_find_run_state() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().join(RUNS_DIR_NAME);
+        let now = Utc::now();
+        let ids = SequentialIdGen::new();
+        let runs: Vec<_> = (0..3)
+            .map(|offset| {
+                let clock = FixedClock::new(now + Duration::seconds(offset));
+                create_run_dir_in(&root, &clock, &ids).unwrap()
+            })
+            .collect();
+        for (run, version) in runs.iter().zip(["v1.0.0", "v1.1.0"]) {
+            let mut state = MergeStateFile::builder()
+                .repo_path("/work/repo")
+                .organization("org")
+                .project("project")
+                .repository("repo")
+                .dev_branch("dev")
+                .target_branch("main")
+                .merge_version(version)
+                .work_item_state("Done")
+                .tag_prefix("merged-")
+                .run_dir(run)
+                .build();
+            let path = state.save_run_snapshot().unwrap();
+            assert_eq!(path, Some(run_state_path(run)));
+        }
+        let id = |run: &PathBuf| run.file_name().unwrap().to_string_lossy().into_owned();
+
+        let first = find_run_state(&root, Some(&id(&runs[0]))).unwrap();
+        assert_eq!(
+            MergeStateFile::load(&first).unwrap().merge_version,
+            "v1.0.0"
+        );
+        let latest = find_run_state(&root, None).unwrap();
+        assert_eq!(
+            MergeStateFile::load(&latest).unwrap().merge_version,
+            "v1.1.0"
+        );
+        assert!(find_run_state(&root, Some(&id(&runs[2]))).is_err());
+        assert!(find_run_state(&root, Some("unknown")).is_err());
+        assert!(find_run_state(&temp_dir.path().join("missing"), None).is_err());
+    }
+}
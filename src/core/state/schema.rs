@@ -0,0 +1,185 @@
+//! Schema versions of state files and the migrations between them.
+//!
+//! Every state file records the `schema_version` it was written with. When
+//! the format changes, [`SCHEMA_VERSION`] is incremented and a migration from
+//! the previous version is appended to `MIGRATIONS`. Loading a state file
+//! applies the migrations from its version on, so a merge started with an
+//! older mergers can be continued after upgrading the binary; `mergers state
+//! upgrade` rewrites state files in the current format ahead of time.
+//!
+//! # Versions
+//!
+//! - 1: initial format
+//! - 2: records the mergers version that last wrote the file (`written_by`)
+//!   and always writes the cherry-pick item fields version 1 could omit
+
+use anyhow::{Context, Result};
+use serde_json::{Map, Value};
+
+/// Current schema version for state files.
+/// Increment when making breaking changes to the state file format, and add
+/// a migration from the previous version.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// A migration of a state file object to the next schema version.
+type Migration = fn(&mut Map<String, Value>) -> Result<()>;
+
+/// Migrations in order; the first one migrates version 1 to version 2.
+const MIGRATIONS: &[Migration] = &[migrate_v1_to_v2];
+
+/// Migrates a parsed state file to [`SCHEMA_VERSION`] in place.
+///
+/// Returns the version the file had if it was migrated, or `None` if it
+/// already was current. State files written by a newer mergers are
+/// rejected, since this binary cannot know their format.
+pub fn migrate_state(value: &mut Value) -> Result<Option<u32>> {
+    let object = value
+        .as_object_mut()
+        .context("State file is not a JSON object")?;
+    let version = object
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .context("State file has no schema_version")?;
+
+    if version > u64::from(SCHEMA_VERSION) {
+        anyhow::bail!(
+            "Unsupported schema version: {} (this mergers supports up to {}). \
+             The state file was created by a newer version of mergers; upgrade mergers \
+             to continue this merge.",
+            version,
+            SCHEMA_VERSION
+        );
+    }
+    let version = match u32::try_from(version) {
+        Ok(version) if version > 0 => version,
+        _ => anyhow::bail!("Unsupported schema version: {}", version),
+    };
+    if version == SCHEMA_VERSION {
+        return Ok(None);
+    }
+
+    for from in version..SCHEMA_VERSION {
+        let migration = MIGRATIONS[(from - 1) as usize];
+        migration(object).with_context(|| {
+            format!(
+                "Failed to migrate state file from schema version {} to {}",
+                from,
+                from + 1
+            )
+        })?;
+        object.insert("schema_version".to_string(), Value::from(from + 1));
+    }
+
+    Ok(Some(version))
+}
+
+/// Version 2 writes `run_hooks` and each item's `work_item_ids` and
+/// `had_conflict` explicitly. Version 1 files from before these fields
+/// existed lack them; an item still in conflict did have one.
+fn migrate_v1_to_v2(object: &mut Map<String, Value>) -> Result<()> {
+    object
+        .entry("run_hooks")
+        .or_insert_with(|| Value::Bool(false));
+
+    let items = object
+        .get_mut("cherry_pick_items")
+        .and_then(Value::as_array_mut)
+        .context("missing cherry_pick_items")?;
+    for item in items {
+        let item = item
+            .as_object_mut()
+            .context("cherry-pick item is not an object")?;
+        let in_conflict = item.get("status").and_then(Value::as_str) == Some("conflict");
+        item.entry("work_item_ids")
+            .or_insert_with(|| Value::Array(Vec::new()));
+        item.entry("had_conflict")
+            .or_insert_with(|| Value::Bool(in_conflict));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn v1_state() -> Value {
+        json!({
+            "schema_version": 1,
+            "created_at": "2024-01-15T10:00:00Z",
+            "updated_at": "2024-01-15T10:30:00Z",
+            "repo_path": "/test/repo",
+            "is_worktree": false,
+            "organization": "org",
+            "project": "project",
+            "repository": "repo",
+            "dev_branch": "dev",
+            "target_branch": "next",
+            "merge_version": "v1.0.0",
+            "cherry_pick_items": [
+                { "commit_id": "abc", "pr_id": 1, "pr_title": "PR 1", "status": "success" },
+                { "commit_id": "def", "pr_id": 2, "pr_title": "PR 2", "status": "conflict" }
+            ],
+            "current_index": 1,
+            "phase": "awaiting_conflict_resolution",
+            "conflicted_files": ["src/lib.rs"],
+            "work_item_state": "Done",
+            "tag_prefix": "merged-"
+        })
+    }
+
+    /// # State File Migration From Version 1
+    ///
+    /// Tests migrating a version 1 state file written before the optional
+    /// item fields existed.
+    ///
+    /// ## Test Scenario
+    /// - A version 1 file without `run_hooks`, `work_item_ids` and
+    ///   `had_conflict` is migrated, then migrated again
+    ///
+    /// ## Expected Outcome
+    /// - The file is reported as migrated from version 1 and is now current
+    /// - Missing fields are filled in; the item in conflict had a conflict
+    /// - Migrating a current file changes nothing
+    #[test]
+    fn test_migrate_v1_state() {
+        let mut state = v1_state();
+
+        assert_eq!(migrate_state(&mut state).unwrap(), Some(1));
+        assert_eq!(state["schema_version"], SCHEMA_VERSION);
+        assert_eq!(state["run_hooks"], false);
+        assert_eq!(state["cherry_pick_items"][0]["work_item_ids"], json!([]));
+        assert_eq!(state["cherry_pick_items"][0]["had_conflict"], false);
+        assert_eq!(state["cherry_pick_items"][1]["had_conflict"], true);
+
+        let migrated = state.clone();
+        assert_eq!(migrate_state(&mut state).unwrap(), None);
+        assert_eq!(state, migrated);
+    }
+
+    /// # Unsupported State File Versions
+    ///
+    /// Tests rejecting state files that cannot be migrated.
+    ///
+    /// ## Test Scenario
+    /// - Files with a newer version, version 0 and no version
+    ///
+    /// ## Expected Outcome
+    /// - Each is rejected; the newer version asks to upgrade mergers
+    #[test]
+    fn test_migrate_unsupported_versions() {
+        let mut newer = v1_state();
+        newer["schema_version"] = json!(SCHEMA_VERSION + 1);
+        let error = migrate_state(&mut newer).unwrap_err().to_string();
+        assert!(error.contains("upgrade mergers"), "{}", error);
+
+        let mut zero = v1_state();
+        zero["schema_version"] = json!(0);
+        assert!(migrate_state(&mut zero).is_err());
+
+        let mut missing = v1_state();
+        missing.as_object_mut().unwrap().remove("schema_version");
+        assert!(migrate_state(&mut missing).is_err());
+    }
+}
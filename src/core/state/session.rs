@@ -0,0 +1,172 @@
+//! Named PR selection sessions.
+//!
+//! Picking PRs for a big release can take several sittings. Started with
+//! `--session NAME`, the TUI keeps the PR selection (selected PRs, manual
+//! pick order, search query, marked work item states and PR filter) in a
+//! session file and restores it on the next run with the same name:
+//!
+//! ```text
+//! $MERGERS_STATE_DIR/sessions/{name}.json
+//! ```
+//!
+//! Sessions remember the repository they were saved for and are not
+//! restored into another one.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::file::state_dir;
+
+/// Directory of the session files in the state directory.
+pub const SESSIONS_DIR_NAME: &str = "sessions";
+
+/// Snapshot of a PR selection.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SelectionSession {
+    /// Repository the selection was made in, see
+    /// [`last_run_key`](super::last_run_key).
+    pub repository: String,
+    /// When the selection was saved.
+    pub saved_at: DateTime<Utc>,
+    /// Selected PRs, by id.
+    pub selected_pr_ids: Vec<i32>,
+    /// Manual cherry-pick order by PR id; empty for closed-date order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pick_order: Vec<i32>,
+    /// Last search query.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub search_query: String,
+    /// Work item states marked in the state selection dialog.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filter_states: Vec<String>,
+    /// Author, reviewer and path filter, in the filter prompt syntax.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub pr_filter: String,
+}
+
+impl SelectionSession {
+    /// Returns true if both snapshots hold the same selection, whenever
+    /// they were saved.
+    pub fn same_selection(&self, other: &Self) -> bool {
+        Self {
+            saved_at: other.saved_at,
+            ..self.clone()
+        } == *other
+    }
+
+    /// Loads the session saved at `path`, if any.
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read session: {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .map(Some)
+            .with_context(|| format!("Failed to parse session: {}", path.display()))
+    }
+
+    /// Saves the session to `path`, replacing any previous one.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create sessions directory: {}", parent.display())
+            })?;
+        }
+        let contents = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, contents)
+            .with_context(|| format!("Failed to write session: {}", path.display()))
+    }
+}
+
+/// Path of the session file named `name` in the state directory.
+///
+/// Names may contain letters, digits, `.`, `-` and `_`, and must not start
+/// with a `.`.
+pub fn session_path(name: &str) -> Result<PathBuf> {
+    let valid = !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'));
+    if !valid {
+        bail!(
+            "Invalid session name '{}' (use letters, digits, '.', '-' and '_')",
+            name
+        );
+    }
+    Ok(state_dir()?
+        .join(SESSIONS_DIR_NAME)
+        .join(format!("{}.json", name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// # Session Round Trip
+    ///
+    /// Tests saving a selection session and loading it back.
+    ///
+    /// ## Test Scenario
+    /// - Loads a session that was never saved
+    /// - Saves a session into a missing directory and loads it
+    ///
+    /// ## Expected Outcome
+    /// - A missing session loads as `None`
+    /// - The loaded session equals the saved one
+    /// - Snapshots saved at different times hold the same selection
+    #[test]
+    fn test_session_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(SESSIONS_DIR_NAME).join("release.json");
+        assert_eq!(SelectionSession::load(&path).unwrap(), None);
+
+        let session = SelectionSession {
+            repository: "org/proj/repo".to_string(),
+            saved_at: Utc::now(),
+            selected_pr_ids: vec![101, 100],
+            pick_order: vec![101, 100],
+            search_query: "login".to_string(),
+            filter_states: vec!["Resolved".to_string()],
+            pr_filter: "author:alice".to_string(),
+        };
+        session.save(&path).unwrap();
+        let loaded = SelectionSession::load(&path).unwrap().unwrap();
+        assert_eq!(loaded, session);
+
+        let later = SelectionSession {
+            saved_at: session.saved_at + chrono::Duration::minutes(5),
+            ..session.clone()
+        };
+        assert!(later.same_selection(&session));
+        assert!(!later.same_selection(&SelectionSession {
+            selected_pr_ids: vec![100],
+            ..session
+        }));
+    }
+
+    /// # Session Names
+    ///
+    /// Tests validating session names.
+    ///
+    /// ## Test Scenario
+    /// - Builds session paths for valid and invalid names
+    ///
+    /// ## Expected Outcome
+    /// - Valid names map to `sessions/{name}.json`
+    /// - Empty names, path separators and leading dots are rejected
+    #[test]
+    fn test_session_path() {
+        let path = session_path("release-6.7").unwrap();
+        assert!(path.ends_with("sessions/release-6.7.json"));
+
+        for name in ["", "../release", "a/b", ".hidden", "release 6.7"] {
+            assert!(session_path(name).is_err(), "{}", name);
+        }
+    }
+}
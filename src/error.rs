@@ -8,8 +8,21 @@
 //! - [`ApiError`]: Errors from Azure DevOps API interactions
 //! - [`GitError`]: Errors from git operations
 //! - [`ConfigError`]: Errors from configuration loading and validation
+//! - [`StateError`]: Errors from merge state files and locking
+//! - [`ConflictError`]: Merge conflicts that need manual resolution
 //! - [`UiError`]: Errors from terminal UI operations
 //!
+//! ## Error Codes
+//!
+//! Every variant has a stable, machine-readable code such as `api.unauthorized`
+//! or `conflict.cherry_pick` (see [`MergersError::code`]), reported in the
+//! `code` field of JSON/NDJSON error events. Errors that had a code before the
+//! categories were introduced keep it (`locked`, `no_state_file`,
+//! `invalid_phase`, `conflicts_unresolved`, `unknown_work_item_states`) so
+//! existing consumers keep working. [`error_code`] and [`exit_code`]
+//! classify any `anyhow::Error` by looking for these types, and for Azure
+//! DevOps HTTP errors, in its cause chain.
+//!
 //! ## Example
 //!
 //! ```rust,no_run
@@ -22,9 +35,13 @@
 //! }
 //! ```
 
+use crate::core::ExitCode;
 use std::path::PathBuf;
 use thiserror::Error;
 
+/// Code for errors that do not belong to any category.
+pub const GENERAL_ERROR_CODE: &str = "general";
+
 /// The main error type for the mergers library.
 ///
 /// This enum encompasses all possible errors that can occur during
@@ -43,6 +60,14 @@ pub enum MergersError {
     #[error("Configuration error: {0}")]
     Config(#[from] ConfigError),
 
+    /// An error occurred while reading or writing merge state.
+    #[error("State error: {0}")]
+    State(#[from] StateError),
+
+    /// A merge conflict needs manual resolution.
+    #[error("Conflict: {0}")]
+    Conflict(#[from] ConflictError),
+
     /// An error occurred in the terminal UI.
     #[error("UI error: {0}")]
     Ui(#[from] UiError),
@@ -52,6 +77,34 @@ pub enum MergersError {
     Other(#[from] anyhow::Error),
 }
 
+impl MergersError {
+    /// Returns the stable machine-readable code for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            MergersError::Api(e) => e.code(),
+            MergersError::Git(e) => e.code(),
+            MergersError::Config(e) => e.code(),
+            MergersError::State(e) => e.code(),
+            MergersError::Conflict(e) => e.code(),
+            MergersError::Ui(e) => e.code(),
+            MergersError::Other(e) => error_code(e),
+        }
+    }
+
+    /// Returns the process exit code this error maps to.
+    pub fn exit_code(&self) -> ExitCode {
+        match self {
+            MergersError::Api(e) => e.exit_code(),
+            MergersError::Git(e) => e.exit_code(),
+            MergersError::Config(_) => ExitCode::ConfigError,
+            MergersError::State(e) => e.exit_code(),
+            MergersError::Conflict(_) => ExitCode::Conflict,
+            MergersError::Ui(_) => ExitCode::GeneralError,
+            MergersError::Other(e) => exit_code(e),
+        }
+    }
+}
+
 /// Errors that can occur when interacting with the Azure DevOps API.
 #[derive(Error, Debug)]
 pub enum ApiError {
@@ -106,6 +159,46 @@ pub enum ApiError {
     },
 }
 
+impl ApiError {
+    /// Returns the stable machine-readable code for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ApiError::Unauthorized => "api.unauthorized",
+            ApiError::NotFound { .. } => "api.not_found",
+            ApiError::RateLimited { .. } => "api.rate_limited",
+            ApiError::RequestFailed { .. } => "api.request_failed",
+            ApiError::ParseError { .. } => "api.parse_error",
+            ApiError::NoMergeCommit { .. } => "api.no_merge_commit",
+            ApiError::PaginationLimitExceeded { .. } => "api.pagination_limit",
+        }
+    }
+
+    /// Returns the process exit code this error maps to.
+    pub fn exit_code(&self) -> ExitCode {
+        match self {
+            ApiError::Unauthorized => ExitCode::AuthFailed,
+            _ => ExitCode::ApiError,
+        }
+    }
+
+    /// Classifies an HTTP error status returned by Azure DevOps.
+    pub fn from_status(status: u16, message: impl Into<String>) -> Self {
+        match status {
+            401 | 403 => ApiError::Unauthorized,
+            404 => ApiError::NotFound {
+                resource: message.into(),
+            },
+            429 => ApiError::RateLimited {
+                retry_after_seconds: 0,
+            },
+            _ => ApiError::RequestFailed {
+                status,
+                message: message.into(),
+            },
+        }
+    }
+}
+
 /// Errors that can occur during git operations.
 #[derive(Error, Debug, Clone)]
 pub enum GitError {
@@ -176,6 +269,13 @@ pub enum GitError {
         message: String,
     },
 
+    /// A commit or tag could not be signed with the configured GPG or SSH key.
+    #[error("Signing failed: {message}")]
+    SigningFailed {
+        /// Error message from git.
+        message: String,
+    },
+
     /// Invalid git reference (contains invalid characters).
     #[error("Invalid git reference '{reference}': contains forbidden characters")]
     InvalidReference {
@@ -188,6 +288,34 @@ pub enum GitError {
     Other(String),
 }
 
+impl GitError {
+    /// Returns the stable machine-readable code for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            GitError::BranchExists { .. } => "git.branch_exists",
+            GitError::WorktreeExists { .. } => "git.worktree_exists",
+            GitError::NotARepository { .. } => "git.not_a_repository",
+            GitError::PathNotFound { .. } => "git.path_not_found",
+            GitError::CloneFailed { .. } => "git.clone_failed",
+            GitError::CherryPickConflict { .. } => "conflict.cherry_pick",
+            GitError::CherryPickFailed { .. } => "git.cherry_pick_failed",
+            GitError::FetchFailed { .. } => "git.fetch_failed",
+            GitError::CommandFailed { .. } => "git.command_failed",
+            GitError::SigningFailed { .. } => "git.signing_failed",
+            GitError::InvalidReference { .. } => "git.invalid_reference",
+            GitError::Other(_) => "git.other",
+        }
+    }
+
+    /// Returns the process exit code this error maps to.
+    pub fn exit_code(&self) -> ExitCode {
+        match self {
+            GitError::CherryPickConflict { .. } => ExitCode::Conflict,
+            _ => ExitCode::GitError,
+        }
+    }
+}
+
 /// Errors that can occur during configuration loading and validation.
 #[derive(Error, Debug)]
 pub enum ConfigError {
@@ -244,6 +372,111 @@ pub enum ConfigError {
         /// Error message.
         message: String,
     },
+
+    /// Work items are in states missing from `known_work_item_states`.
+    #[error("Work items are in states not listed in known_work_item_states: {states}")]
+    UnknownWorkItemStates {
+        /// Description of the unknown states and the PRs using them.
+        states: String,
+    },
+}
+
+impl ConfigError {
+    /// Returns the stable machine-readable code for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ConfigError::MissingRequired { .. } => "config.missing_required",
+            ConfigError::FileReadError { .. } => "config.file_read",
+            ConfigError::ParseError { .. } => "config.parse_error",
+            ConfigError::InvalidValue { .. } => "config.invalid_value",
+            ConfigError::DateParseError { .. } => "config.invalid_date",
+            ConfigError::DirectoryCreationError { .. } => "config.directory_creation",
+            // Legacy code, kept for existing consumers
+            ConfigError::UnknownWorkItemStates { .. } => "unknown_work_item_states",
+        }
+    }
+}
+
+/// Errors related to the persisted merge state and repository lock.
+#[derive(Error, Debug, Clone)]
+pub enum StateError {
+    /// No state file exists for the repository.
+    #[error("No state file found for this repository")]
+    NotFound,
+
+    /// Another merge operation holds the repository lock.
+    #[error("Another merge operation is in progress")]
+    Locked,
+
+    /// The operation is not valid in the current merge phase.
+    #[error("Cannot {operation}: merge is in '{phase}' phase")]
+    InvalidPhase {
+        /// The attempted operation (e.g. "continue").
+        operation: String,
+        /// The current merge phase.
+        phase: String,
+    },
+
+    /// The state file could not be read or written.
+    #[error("State file error at {path}: {message}")]
+    Io {
+        /// Path to the state file.
+        path: PathBuf,
+        /// Error message.
+        message: String,
+    },
+}
+
+impl StateError {
+    /// Returns the stable machine-readable code for this error.
+    ///
+    /// All but [`StateError::Io`] keep their legacy, unprefixed codes.
+    pub fn code(&self) -> &'static str {
+        match self {
+            StateError::NotFound => "no_state_file",
+            StateError::Locked => "locked",
+            StateError::InvalidPhase { .. } => "invalid_phase",
+            StateError::Io { .. } => "state.io",
+        }
+    }
+
+    /// Returns the process exit code this error maps to.
+    pub fn exit_code(&self) -> ExitCode {
+        match self {
+            StateError::NotFound => ExitCode::NoStateFile,
+            StateError::Locked => ExitCode::Locked,
+            StateError::InvalidPhase { .. } => ExitCode::InvalidPhase,
+            StateError::Io { .. } => ExitCode::GeneralError,
+        }
+    }
+}
+
+/// Merge conflicts that need manual resolution.
+#[derive(Error, Debug, Clone)]
+pub enum ConflictError {
+    /// Cherry-picking a pull request produced conflicts.
+    #[error("Cherry-pick of PR #{pr_id} conflicts in {} file(s)", files.len())]
+    CherryPick {
+        /// The PR whose cherry-pick conflicted.
+        pr_id: i32,
+        /// Conflicted file paths.
+        files: Vec<String>,
+    },
+
+    /// Conflicts are still present when trying to continue.
+    #[error("Conflicts are not fully resolved. Please resolve all conflicts and stage the files.")]
+    Unresolved,
+}
+
+impl ConflictError {
+    /// Returns the stable machine-readable code for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ConflictError::CherryPick { .. } => "conflict.cherry_pick",
+            // Legacy code, kept for existing consumers
+            ConflictError::Unresolved => "conflicts_unresolved",
+        }
+    }
 }
 
 /// Errors that can occur in the terminal UI.
@@ -266,6 +499,85 @@ pub enum UiError {
     Cancelled,
 }
 
+impl UiError {
+    /// Returns the stable machine-readable code for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            UiError::TerminalInitError(_) => "ui.terminal_init",
+            UiError::RenderError(_) => "ui.render",
+            UiError::IoError(_) => "ui.io",
+            UiError::Cancelled => "ui.cancelled",
+        }
+    }
+}
+
+/// Finds the first classified error in an `anyhow` cause chain.
+///
+/// Azure DevOps HTTP errors are classified by status code, so a 401 deep
+/// inside a `.context(...)` chain still reports as `api.unauthorized`.
+fn classify<T>(
+    error: &anyhow::Error,
+    mergers: impl Fn(&MergersError) -> T,
+    api: impl Fn(&ApiError) -> T,
+    git: impl Fn(&GitError) -> T,
+    config: impl Fn(&ConfigError) -> T,
+    state: impl Fn(&StateError) -> T,
+    conflict: impl Fn(&ConflictError) -> T,
+) -> Option<T> {
+    error.chain().find_map(|cause| {
+        if let Some(e) = cause.downcast_ref::<MergersError>() {
+            Some(mergers(e))
+        } else if let Some(e) = cause.downcast_ref::<ApiError>() {
+            Some(api(e))
+        } else if let Some(e) = cause.downcast_ref::<GitError>() {
+            Some(git(e))
+        } else if let Some(e) = cause.downcast_ref::<ConfigError>() {
+            Some(config(e))
+        } else if let Some(e) = cause.downcast_ref::<StateError>() {
+            Some(state(e))
+        } else if let Some(e) = cause.downcast_ref::<ConflictError>() {
+            Some(conflict(e))
+        } else {
+            cause
+                .downcast_ref::<azure_core::Error>()
+                .and_then(|e| e.http_status())
+                .map(|status| api(&ApiError::from_status(u16::from(status), cause.to_string())))
+        }
+    })
+}
+
+/// Returns the stable machine-readable code for any error.
+///
+/// Falls back to [`GENERAL_ERROR_CODE`] when no classified error is found.
+pub fn error_code(error: &anyhow::Error) -> &'static str {
+    classify(
+        error,
+        MergersError::code,
+        ApiError::code,
+        GitError::code,
+        ConfigError::code,
+        StateError::code,
+        ConflictError::code,
+    )
+    .unwrap_or(GENERAL_ERROR_CODE)
+}
+
+/// Returns the process exit code for any error.
+///
+/// Falls back to [`ExitCode::GeneralError`] when no classified error is found.
+pub fn exit_code(error: &anyhow::Error) -> ExitCode {
+    classify(
+        error,
+        MergersError::exit_code,
+        ApiError::exit_code,
+        GitError::exit_code,
+        |_| ExitCode::ConfigError,
+        StateError::exit_code,
+        |_| ExitCode::Conflict,
+    )
+    .unwrap_or(ExitCode::GeneralError)
+}
+
 /// Type alias for Results using MergersError.
 ///
 /// Note: This is not re-exported from the crate root to avoid shadowing `anyhow::Result`.
@@ -336,6 +648,12 @@ mod tests {
             files: vec!["a.rs".to_string(), "b.rs".to_string(), "c.rs".to_string()],
         };
         assert!(conflict.to_string().contains("3 file(s)"));
+
+        let signing = GitError::SigningFailed {
+            message: "error: gpg failed to sign the data".to_string(),
+        };
+        assert!(signing.to_string().starts_with("Signing failed"));
+        assert_eq!(signing.code(), "git.signing_failed");
     }
 
     /// # Config Error Display
@@ -431,4 +749,93 @@ mod tests {
             _ => panic!("Clone produced different variant"),
         }
     }
+
+    /// # Error Codes and Exit Codes
+    ///
+    /// Tests the stable codes and exit codes of each error category.
+    ///
+    /// ## Test Scenario
+    /// - Gets codes and exit codes for API, git, config, state and conflict errors
+    /// - Wraps them in `MergersError`
+    ///
+    /// ## Expected Outcome
+    /// - Codes are prefixed with their category
+    /// - Auth failures and conflicts map to distinct exit codes
+    #[test]
+    fn test_error_codes() {
+        assert_eq!(ApiError::Unauthorized.code(), "api.unauthorized");
+        assert_eq!(ApiError::Unauthorized.exit_code(), ExitCode::AuthFailed);
+        assert_eq!(
+            ApiError::from_status(429, "throttled").code(),
+            "api.rate_limited"
+        );
+        assert_eq!(
+            ApiError::from_status(500, "boom").exit_code(),
+            ExitCode::ApiError
+        );
+
+        let conflict = GitError::CherryPickConflict {
+            file_count: 1,
+            files: vec!["a.rs".to_string()],
+        };
+        assert_eq!(conflict.code(), "conflict.cherry_pick");
+        assert_eq!(conflict.exit_code(), ExitCode::Conflict);
+        assert_eq!(
+            GitError::FetchFailed {
+                message: "offline".to_string()
+            }
+            .exit_code(),
+            ExitCode::GitError
+        );
+
+        let state: MergersError = StateError::Locked.into();
+        assert_eq!(state.code(), "locked");
+        assert_eq!(state.exit_code(), ExitCode::Locked);
+
+        let config: MergersError = ConfigError::MissingRequired {
+            field: "organization".to_string(),
+            env_var: "MERGERS_ORGANIZATION".to_string(),
+        }
+        .into();
+        assert_eq!(config.code(), "config.missing_required");
+        assert_eq!(config.exit_code(), ExitCode::ConfigError);
+
+        let unresolved: MergersError = ConflictError::Unresolved.into();
+        assert_eq!(unresolved.code(), "conflicts_unresolved");
+        assert_eq!(unresolved.exit_code(), ExitCode::Conflict);
+    }
+
+    /// # Classifying anyhow Errors
+    ///
+    /// Tests classification of errors wrapped in `anyhow` context chains.
+    ///
+    /// ## Test Scenario
+    /// - Wraps typed errors and an Azure DevOps 401 response in context
+    /// - Classifies a plain string error
+    ///
+    /// ## Expected Outcome
+    /// - The first typed cause determines the code and exit code
+    /// - Unclassified errors fall back to `general` and `GeneralError`
+    #[test]
+    fn test_classify_anyhow_errors() {
+        let err = anyhow::Error::new(StateError::NotFound).context("Failed to continue");
+        assert_eq!(error_code(&err), "no_state_file");
+        assert_eq!(exit_code(&err), ExitCode::NoStateFile);
+
+        let http = azure_core::Error::with_message(
+            azure_core::error::ErrorKind::HttpResponse {
+                status: azure_core::http::StatusCode::Unauthorized,
+                error_code: None,
+                raw_response: None,
+            },
+            "TF400813: not authorized",
+        );
+        let err = anyhow::Error::new(http).context("Failed to fetch pull requests");
+        assert_eq!(error_code(&err), "api.unauthorized");
+        assert_eq!(exit_code(&err), ExitCode::AuthFailed);
+
+        let err = anyhow::anyhow!("something odd");
+        assert_eq!(error_code(&err), GENERAL_ERROR_CODE);
+        assert_eq!(exit_code(&err), ExitCode::GeneralError);
+    }
 }
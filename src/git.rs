@@ -11,12 +11,15 @@
 use anyhow::{Context, Result};
 use std::{
     collections::HashSet,
+    io::{BufRead, BufReader, Read},
     path::{Path, PathBuf},
-    process::Command,
+    process::{Command, Stdio},
 };
 use tempfile::TempDir;
 
+use crate::core::operations::binary_conflicts::ConflictSide;
 use crate::error::GitError;
+use crate::profiling;
 
 /// Creates a git command with non-interactive settings.
 ///
@@ -48,8 +51,13 @@ fn git_command() -> Command {
 /// # Ok::<(), anyhow::Error>(())
 /// ```
 pub trait GitOperations: Send + Sync {
-    /// Cherry-pick a commit into the current branch.
-    fn cherry_pick(&self, repo_path: &Path, commit_id: &str) -> Result<CherryPickResult>;
+    /// Cherry-pick a commit into the current branch, signing it if `sign`.
+    fn cherry_pick(
+        &self,
+        repo_path: &Path,
+        commit_id: &str,
+        sign: bool,
+    ) -> Result<CherryPickResult>;
 
     /// Get information about a specific commit.
     fn get_commit_info(&self, repo_path: &Path, commit_id: &str) -> Result<CommitInfo>;
@@ -57,8 +65,8 @@ pub trait GitOperations: Send + Sync {
     /// Check if conflicts have been resolved.
     fn check_conflicts_resolved(&self, repo_path: &Path) -> Result<bool>;
 
-    /// Continue a paused cherry-pick operation.
-    fn continue_cherry_pick(&self, repo_path: &Path) -> Result<()>;
+    /// Continue a paused cherry-pick operation, signing the commit if `sign`.
+    fn continue_cherry_pick(&self, repo_path: &Path, sign: bool) -> Result<()>;
 
     /// Abort a cherry-pick operation.
     fn abort_cherry_pick(&self, repo_path: &Path) -> Result<()>;
@@ -80,8 +88,13 @@ pub trait GitOperations: Send + Sync {
 pub struct SystemGit;
 
 impl GitOperations for SystemGit {
-    fn cherry_pick(&self, repo_path: &Path, commit_id: &str) -> Result<CherryPickResult> {
-        cherry_pick_commit(repo_path, commit_id)
+    fn cherry_pick(
+        &self,
+        repo_path: &Path,
+        commit_id: &str,
+        sign: bool,
+    ) -> Result<CherryPickResult> {
+        cherry_pick_commit(repo_path, commit_id, sign)
     }
 
     fn get_commit_info(&self, repo_path: &Path, commit_id: &str) -> Result<CommitInfo> {
@@ -92,8 +105,8 @@ impl GitOperations for SystemGit {
         check_conflicts_resolved(repo_path)
     }
 
-    fn continue_cherry_pick(&self, repo_path: &Path) -> Result<()> {
-        continue_cherry_pick(repo_path)
+    fn continue_cherry_pick(&self, repo_path: &Path, sign: bool) -> Result<()> {
+        continue_cherry_pick(repo_path, sign)
     }
 
     fn abort_cherry_pick(&self, repo_path: &Path) -> Result<()> {
@@ -213,13 +226,24 @@ pub fn shallow_clone_repo(
     target_branch: &str,
     run_hooks: bool,
 ) -> Result<(PathBuf, TempDir)> {
-    tracing::info!("Cloning repository: {} -> temporary directory", ssh_url);
-    tracing::debug!("Clone args: depth=1, branch={}", target_branch);
-
     let temp_dir = TempDir::new().context("Failed to create temporary directory")?;
     let repo_path = temp_dir.path().to_path_buf();
+    shallow_clone_repo_into(ssh_url, target_branch, &repo_path)?;
+
+    // Note: Hook configuration is now handled by the ConfigureRepository wizard step
+    let _ = run_hooks; // Acknowledge parameter (used by wizard step)
+
+    Ok((repo_path, temp_dir))
+}
 
-    tracing::debug!("Repository will be cloned to: {}", repo_path.display());
+/// Shallow-clones `target_branch` into `repo_path`, which must not exist or
+/// be empty.
+///
+/// Unlike [`shallow_clone_repo`], the clone outlives the call; it is used
+/// to clone into a run directory.
+pub fn shallow_clone_repo_into(ssh_url: &str, target_branch: &str, repo_path: &Path) -> Result<()> {
+    tracing::info!("Cloning repository: {} -> {}", ssh_url, repo_path.display());
+    tracing::debug!("Clone args: depth=1, branch={}", target_branch);
 
     let output = git_command()
         .args([
@@ -231,8 +255,8 @@ pub fn shallow_clone_repo(
             target_branch,
             "--no-tags",
             ssh_url,
-            repo_path.to_str().unwrap(),
         ])
+        .arg(repo_path)
         .output()
         .context("Failed to clone repository")?;
 
@@ -245,11 +269,7 @@ pub fn shallow_clone_repo(
     }
 
     tracing::info!("Repository cloned successfully");
-
-    // Note: Hook configuration is now handled by the ConfigureRepository wizard step
-    let _ = run_hooks; // Acknowledge parameter (used by wizard step)
-
-    Ok((repo_path, temp_dir))
+    Ok(())
 }
 
 #[allow(deprecated)]
@@ -347,6 +367,85 @@ pub fn create_worktree(
     Ok(worktree_path)
 }
 
+/// Create a worktree for `version` on a new branch starting at `start_point`.
+///
+/// Used to promote a release candidate: the final release worktree
+/// (`next-{version}`) is created on `branch_name`, starting from the RC
+/// worktree's HEAD so every commit picked into the RC is kept.
+///
+/// # Arguments
+///
+/// * `base_repo_path` - Path to the base repository
+/// * `version` - Version string used to name the worktree (`next-{version}`)
+/// * `branch_name` - Branch to create for the worktree (e.g., `patch/next-1.2.0`)
+/// * `start_point` - Commit or ref the new branch starts from
+pub fn create_worktree_from_ref(
+    base_repo_path: &Path,
+    version: &str,
+    branch_name: &str,
+    start_point: &str,
+) -> std::result::Result<PathBuf, GitError> {
+    let worktree_path = base_repo_path.join(format!("next-{}", version));
+
+    tracing::info!(
+        "Creating worktree at {} on {} from {}",
+        worktree_path.display(),
+        branch_name,
+        start_point
+    );
+
+    let output = git_command()
+        .current_dir(base_repo_path)
+        .args([
+            "worktree",
+            "add",
+            "-b",
+            branch_name,
+            &worktree_path.to_string_lossy(),
+            start_point,
+        ])
+        .output()
+        .map_err(|e| GitError::Other(format!("Failed to create worktree: {}", e)))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("already exists") && stderr.contains("branch") {
+            return Err(GitError::BranchExists {
+                branch: branch_name.to_string(),
+            });
+        }
+        if stderr.contains("already exists") {
+            return Err(GitError::WorktreeExists {
+                path: worktree_path.display().to_string(),
+            });
+        }
+        return Err(GitError::Other(format!(
+            "Failed to create worktree: {}",
+            stderr
+        )));
+    }
+
+    Ok(worktree_path)
+}
+
+/// Returns the commit ID checked out in `repo_path`.
+pub fn head_commit(repo_path: &Path) -> Result<String> {
+    let output = git_command()
+        .current_dir(repo_path)
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .context("Failed to resolve HEAD")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to resolve HEAD: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
 /// Check if a worktree with the given version already exists.
 ///
 /// This checks both the filesystem and git's worktree list to detect
@@ -414,15 +513,71 @@ pub fn branch_exists(repo_path: &Path, branch_name: &str) -> Result<bool> {
     Ok(output.status.success())
 }
 
+/// Check if a branch with the given name exists on a remote.
+///
+/// `remote` is a remote name resolved in `repo_path` (e.g. `origin`) or a
+/// repository URL, in which case `repo_path` may be `None`.
+///
+/// # Returns
+///
+/// * `Ok(true)` if the remote has the branch
+/// * `Ok(false)` if it does not
+/// * `Err` if the remote could not be queried
+#[must_use = "this returns whether the branch exists"]
+pub fn remote_branch_exists(
+    repo_path: Option<&Path>,
+    remote: &str,
+    branch_name: &str,
+) -> Result<bool> {
+    let mut command = git_command();
+    if let Some(repo_path) = repo_path {
+        command.current_dir(repo_path);
+    }
+    let output = command
+        .args([
+            "ls-remote",
+            "--exit-code",
+            "--heads",
+            remote,
+            &format!("refs/heads/{}", branch_name),
+        ])
+        .output()
+        .context("Failed to query remote branches")?;
+
+    // ls-remote exits with 2 when no matching refs were found
+    match output.status.code() {
+        Some(0) => Ok(true),
+        Some(2) => Ok(false),
+        _ => anyhow::bail!(
+            "Failed to query branch {} on {}: {}",
+            branch_name,
+            remote,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+    }
+}
+
 #[must_use = "this operation can fail and the result should be checked"]
 pub fn force_remove_worktree(base_repo_path: &Path, version: &str) -> Result<()> {
-    let worktree_name = format!("next-{}", version);
-    let worktree_path = base_repo_path.join(&worktree_name);
+    force_remove_worktree_at(
+        base_repo_path,
+        &base_repo_path.join(format!("next-{}", version)),
+    )
+}
 
-    // Remove from git worktree list
+/// Force-removes the worktree at `worktree_path` from `base_repo_path`,
+/// whatever it is named.
+#[must_use = "this operation can fail and the result should be checked"]
+pub fn force_remove_worktree_at(base_repo_path: &Path, worktree_path: &Path) -> Result<()> {
+    // Remove from git worktree list; git resolves the path from the base repo
     let _remove_output = Command::new("git")
         .current_dir(base_repo_path)
-        .args(["worktree", "remove", "--force", &worktree_name])
+        .args(["worktree", "remove", "--force"])
+        .arg(
+            worktree_path
+                .strip_prefix(base_repo_path)
+                .unwrap_or(worktree_path),
+        )
         .output();
 
     // Prune worktrees
@@ -433,7 +588,7 @@ pub fn force_remove_worktree(base_repo_path: &Path, version: &str) -> Result<()>
 
     // Remove directory if it exists
     if worktree_path.exists() {
-        std::fs::remove_dir_all(&worktree_path)
+        std::fs::remove_dir_all(worktree_path)
             .context("Failed to remove existing worktree directory")?;
     }
 
@@ -451,6 +606,45 @@ pub fn force_delete_branch(repo_path: &Path, branch_name: &str) -> Result<()> {
     Ok(())
 }
 
+/// Deletes a local branch and returns the commit it pointed to.
+///
+/// The returned tip is enough to restore the branch with
+/// `git branch <name> <sha>`. A branch that does not exist counts as already
+/// deleted and returns `None`. Unlike [`force_delete_branch`], a branch that
+/// cannot be deleted is an error.
+#[must_use = "this returns the deleted branch tip, which is needed to restore it"]
+pub fn delete_branch(repo_path: &Path, branch_name: &str) -> Result<Option<String>> {
+    let output = git_command()
+        .current_dir(repo_path)
+        .args([
+            "rev-parse",
+            "--verify",
+            &format!("refs/heads/{}", branch_name),
+        ])
+        .output()
+        .context("Failed to resolve branch tip")?;
+
+    if !output.status.success() {
+        return Ok(None);
+    }
+    let tip = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    let output = git_command()
+        .current_dir(repo_path)
+        .args(["branch", "-D", branch_name])
+        .output()
+        .context("Failed to delete branch")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to delete branch: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(Some(tip))
+}
+
 /// Clean up a cherry-pick operation by removing the worktree and branch.
 /// This is used when aborting the entire cherry-pick process.
 ///
@@ -480,10 +674,11 @@ pub fn cleanup_cherry_pick(
             .output();
 
         // Remove the worktree
-        let _ = force_remove_worktree(base_path, version);
+        let _ = force_remove_worktree_at(base_path, worktree_path);
 
-        // Delete the branch from the base repo
+        // Delete the branch and its base tag from the base repo
         let _ = force_delete_branch(base_path, &branch_name);
+        let _ = delete_base_tag(base_path, target_branch, version);
     } else {
         // For cloned repos, just delete the branch (temp dir will be cleaned up automatically)
         let _ = force_delete_branch(worktree_path, &branch_name);
@@ -492,6 +687,70 @@ pub fn cleanup_cherry_pick(
     Ok(())
 }
 
+/// Name of the branch a selective abort keeps the applied commits on.
+pub fn rescue_branch_name(target_branch: &str, version: &str) -> String {
+    format!("patch/{}-{}-partial", target_branch, version)
+}
+
+/// Abort a cherry-pick process but keep the commits applied so far.
+///
+/// Only the cherry-pick in conflict is rolled back. The patch branch is
+/// saved as `patch/<target>-<version>-partial`, then cleaned up like
+/// [`cleanup_cherry_pick`]. An existing rescue branch is never overwritten:
+/// if it exists, nothing is changed, not even the cherry-pick in conflict.
+/// In a cloned repo, the clone is left on the rescue branch.
+///
+/// Returns the commit the rescue branch points to.
+pub fn abort_keeping_applied(
+    base_repo_path: Option<&Path>,
+    worktree_path: &Path,
+    version: &str,
+    target_branch: &str,
+) -> Result<String> {
+    let rescue_branch = rescue_branch_name(target_branch, version);
+    validate_git_ref(&rescue_branch)?;
+    if branch_exists(worktree_path, &rescue_branch)? {
+        anyhow::bail!(
+            "Branch {} already exists; delete or rename it to keep the applied commits",
+            rescue_branch
+        );
+    }
+
+    let _ = abort_cherry_pick(worktree_path);
+    let tip = head_commit(worktree_path)?;
+
+    let output = Command::new("git")
+        .current_dir(worktree_path)
+        .args(["branch", &rescue_branch, &tip])
+        .output()
+        .context("Failed to execute git branch")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to create branch {}: {}",
+            rescue_branch,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    if base_repo_path.is_none() {
+        let output = Command::new("git")
+            .current_dir(worktree_path)
+            .args(["checkout", &rescue_branch])
+            .output()
+            .context("Failed to execute git checkout")?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "Failed to check out {}: {}",
+                rescue_branch,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+    }
+
+    cleanup_cherry_pick(base_repo_path, worktree_path, version, target_branch)?;
+    Ok(tip)
+}
+
 pub enum RepositorySetup {
     Local(PathBuf),
     Clone(PathBuf, TempDir),
@@ -550,14 +809,77 @@ pub enum CherryPickResult {
 
 #[must_use = "this returns the cherry-pick result which must be handled"]
 #[tracing::instrument(skip(repo_path), fields(repo = ?repo_path))]
-pub fn cherry_pick_commit(repo_path: &Path, commit_id: &str) -> Result<CherryPickResult> {
+pub fn cherry_pick_commit(
+    repo_path: &Path,
+    commit_id: &str,
+    sign: bool,
+) -> Result<CherryPickResult> {
+    let _phase = profiling::phase("cherry-pick");
     // Always use -m 1 to handle both regular and merge commits:
     // - For merge commits: selects the first parent (the branch that was merged into)
     // - For regular commits: git uses the single parent, -m 1 has no negative effect
+    cherry_pick_commit_with_mainline(repo_path, commit_id, 1, sign)
+}
+
+/// Cherry-pick a commit with its first parent as mainline, and with
+/// `mainline_fallback` retry a two-parent merge commit whose first parent
+/// does not apply with its second parent as mainline.
+///
+/// Some merge commits have their parents reversed, so only `-m 2` yields the
+/// PR's changes. For a regular merge commit, however, `-m 2` applies the
+/// target line's changes instead of the PR's, so the retry is opt-in; without
+/// it the `-m 1` conflict is returned as is. If the retry fails too, the
+/// `-m 1` attempt is redone so its conflicts can be resolved as usual.
+///
+/// Returns the result together with the mainline parent (1 or 2) it came from.
+/// With `sign`, the picked commit is signed; a signing failure aborts the
+/// cherry-pick and is returned as [`GitError::SigningFailed`].
+#[must_use = "this returns the cherry-pick result which must be handled"]
+#[tracing::instrument(skip(repo_path), fields(repo = ?repo_path))]
+pub fn cherry_pick_with_parent_fallback(
+    repo_path: &Path,
+    commit_id: &str,
+    sign: bool,
+    mainline_fallback: bool,
+) -> Result<(CherryPickResult, u8)> {
+    let result = cherry_pick_commit_with_mainline(repo_path, commit_id, 1, sign)?;
+    if !mainline_fallback
+        || matches!(result, CherryPickResult::Success)
+        || parent_count(repo_path, commit_id)? != 2
+    {
+        return Ok((result, 1));
+    }
+
+    tracing::info!("Retrying {} with the second parent as mainline", commit_id);
+    let _ = abort_cherry_pick(repo_path);
+    match cherry_pick_commit_with_mainline(repo_path, commit_id, 2, sign)? {
+        CherryPickResult::Success => Ok((CherryPickResult::Success, 2)),
+        _ => {
+            let _ = abort_cherry_pick(repo_path);
+            Ok((
+                cherry_pick_commit_with_mainline(repo_path, commit_id, 1, sign)?,
+                1,
+            ))
+        }
+    }
+}
+
+fn cherry_pick_commit_with_mainline(
+    repo_path: &Path,
+    commit_id: &str,
+    mainline: u8,
+    sign: bool,
+) -> Result<CherryPickResult> {
     // Use --allow-empty to handle commits that may result in no changes (already applied)
+    let mainline = mainline.to_string();
+    let mut args = vec!["cherry-pick", "-m", &mainline, "--allow-empty"];
+    if sign {
+        args.push("-S");
+    }
+    args.push(commit_id);
     let output = Command::new("git")
         .current_dir(repo_path)
-        .args(["cherry-pick", "-m", "1", "--allow-empty", commit_id])
+        .args(&args)
         .output()
         .context("Failed to execute cherry-pick command")?;
 
@@ -567,6 +889,12 @@ pub fn cherry_pick_commit(repo_path: &Path, commit_id: &str) -> Result<CherryPic
 
     let stderr = String::from_utf8_lossy(&output.stderr);
 
+    if let Some(error) = signing_error(sign, &stderr) {
+        // The changes were applied but could not be committed
+        let _ = abort_cherry_pick(repo_path);
+        return Err(error.into());
+    }
+
     if stderr.contains("conflict") || stderr.contains("CONFLICT") {
         let status_output = Command::new("git")
             .current_dir(repo_path)
@@ -584,6 +912,26 @@ pub fn cherry_pick_commit(repo_path: &Path, commit_id: &str) -> Result<CherryPic
     }
 }
 
+/// Number of parents of a commit (2 for a regular merge commit).
+fn parent_count(repo_path: &Path, commit_id: &str) -> Result<usize> {
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(["rev-list", "--parents", "-n", "1", commit_id])
+        .output()
+        .context("Failed to execute git rev-list")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to read parents of {}: {}",
+            commit_id,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .split_whitespace()
+        .count()
+        .saturating_sub(1))
+}
+
 #[must_use = "this operation can fail and the result should be checked"]
 pub fn create_branch(repo_path: &Path, branch_name: &str) -> Result<()> {
     let output = Command::new("git")
@@ -627,161 +975,630 @@ pub fn check_conflicts_resolved(repo_path: &Path) -> Result<bool> {
     Ok(output.stdout.is_empty())
 }
 
-#[must_use = "this operation can fail and the result should be checked"]
-pub fn continue_cherry_pick(repo_path: &Path) -> Result<()> {
-    // Check if the commit would be empty by checking staged changes
-    // git diff --cached --quiet exits with 1 if there are changes, 0 if empty
-    let is_empty_commit = Command::new("git")
+/// Lists files that still have unmerged entries in the index.
+#[must_use = "this returns the unresolved files which should be used"]
+pub fn unresolved_files(repo_path: &Path) -> Result<Vec<String>> {
+    let output = git_command()
         .current_dir(repo_path)
-        .args(["diff", "--cached", "--quiet"])
-        .status()
-        .map(|s| s.success()) // success (exit 0) means no staged changes = empty
-        .unwrap_or(false);
-
-    // For empty commits, use git commit --allow-empty directly
-    // because git cherry-pick --continue doesn't support --keep-redundant-commits
-    let output = if is_empty_commit {
-        Command::new("git")
-            .current_dir(repo_path)
-            .args(["commit", "--allow-empty", "--no-edit"])
-            .output()?
-    } else {
-        Command::new("git")
-            .current_dir(repo_path)
-            .args(["cherry-pick", "--continue", "--no-edit"])
-            .output()?
-    };
+        .args(["diff", "--name-only", "--diff-filter=U"])
+        .output()
+        .context("Failed to list unresolved files")?;
 
-    if output.status.success() {
-        return Ok(());
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to list unresolved files: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
     }
 
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    anyhow::bail!("Failed to continue cherry-pick: {}", stderr);
+    let mut files: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(String::from)
+        .collect();
+    files.dedup();
+    Ok(files)
 }
 
+/// Stages a file, marking its conflict as resolved.
 #[must_use = "this operation can fail and the result should be checked"]
-pub fn abort_cherry_pick(repo_path: &Path) -> Result<()> {
-    Command::new("git")
-        .current_dir(repo_path)
-        .args(["cherry-pick", "--abort"])
-        .output()?;
-
-    Ok(())
-}
-
-#[derive(Debug, Clone)]
-pub struct CommitInfo {
-    pub hash: String,
-    pub date: String,
-    pub title: String,
-    pub author: String,
-}
-
-#[must_use = "this returns commit information which should be used"]
-pub fn get_commit_info(repo_path: &Path, commit_id: &str) -> Result<CommitInfo> {
-    let output = Command::new("git")
+pub fn stage_file(repo_path: &Path, file: &str) -> Result<()> {
+    let output = git_command()
         .current_dir(repo_path)
-        .args(["show", "--no-patch", "--format=%H|%ci|%s|%an", commit_id])
-        .output()?;
+        .args(["add", "--", file])
+        .output()
+        .context("Failed to execute add command")?;
 
     if !output.status.success() {
         anyhow::bail!(
-            "Failed to get commit info: {}",
-            String::from_utf8_lossy(&output.stderr)
+            "Failed to stage {}: {}",
+            file,
+            String::from_utf8_lossy(&output.stderr).trim()
         );
     }
 
-    let output_str = String::from_utf8_lossy(&output.stdout);
-    let parts: Vec<&str> = output_str.trim().split('|').collect();
-
-    if parts.len() >= 4 {
-        Ok(CommitInfo {
-            hash: parts[0].to_string(),
-            date: parts[1].to_string(),
-            title: parts[2].to_string(),
-            author: parts[3].to_string(),
-        })
-    } else {
-        anyhow::bail!("Unexpected git show output format");
-    }
-}
-
-/// Structure to hold pre-fetched commit history for optimized PR analysis
-#[derive(Debug, Clone)]
-pub struct CommitHistory {
-    pub commit_hashes: HashSet<String>, // All commit hashes in target branch
-    pub commit_messages: Vec<String>,   // All commit messages in target branch
-    pub commit_bodies: Vec<String>,     // All commit bodies in target branch
+    Ok(())
 }
 
-/// Get complete commit history for target branch once to avoid repeated git calls
-#[must_use = "this returns the commit history which should be used"]
-pub fn get_target_branch_history(repo_path: &Path, target_branch: &str) -> Result<CommitHistory> {
-    // Get all commit hashes in target branch
-    let hash_output = Command::new("git")
+/// Returns `true` if a conflicted file holds binary content.
+///
+/// A file is binary when its `diff` attribute is unset (e.g. `*.png -diff`
+/// or `binary` in `.gitattributes`), or when the target branch's or the
+/// picked commit's version contains a NUL byte in its first 8000 bytes,
+/// the heuristic git itself uses.
+pub fn is_binary_conflict(repo_path: &Path, file: &str) -> Result<bool> {
+    let output = git_command()
         .current_dir(repo_path)
-        .args(["log", "--format=%H", target_branch])
+        .args(["check-attr", "diff", "--", file])
         .output()
-        .context("Failed to get commit hashes from target branch")?;
+        .context("Failed to execute check-attr command")?;
+    if output.status.success()
+        && String::from_utf8_lossy(&output.stdout)
+            .trim_end()
+            .ends_with(": unset")
+    {
+        return Ok(true);
+    }
 
-    if !hash_output.status.success() {
-        let stderr = String::from_utf8_lossy(&hash_output.stderr);
-        anyhow::bail!("Failed to get commit hashes from target branch: {}", stderr);
+    for stage in [2, 3] {
+        let output = git_command()
+            .current_dir(repo_path)
+            .args(["cat-file", "blob", &format!(":{}:{}", stage, file)])
+            .output()
+            .context("Failed to execute cat-file command")?;
+        // A missing stage means the file was deleted on that side
+        if output.status.success() && output.stdout.iter().take(8000).any(|b| *b == 0) {
+            return Ok(true);
+        }
     }
+    Ok(false)
+}
 
-    let commit_hashes: HashSet<String> = String::from_utf8_lossy(&hash_output.stdout)
-        .lines()
-        .map(|line| line.trim().to_string())
-        .filter(|line| !line.is_empty())
-        .collect();
+/// Resolves a conflicted file by keeping one side's version and staging it.
+///
+/// If the file was deleted on the chosen side, it is removed instead.
+#[must_use = "this operation can fail and the result should be checked"]
+pub fn checkout_conflict_side(repo_path: &Path, file: &str, side: ConflictSide) -> Result<()> {
+    let stage = match side {
+        ConflictSide::Ours => "2",
+        ConflictSide::Theirs => "3",
+    };
+    let output = git_command()
+        .current_dir(repo_path)
+        .args(["ls-files", "-u", "--", file])
+        .output()
+        .context("Failed to execute ls-files command")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to list conflict stages of {}: {}",
+            file,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    // Lines look like "<mode> <object> <stage>\t<path>"
+    let has_stage = String::from_utf8_lossy(&output.stdout).lines().any(|line| {
+        line.split('\t')
+            .next()
+            .and_then(|info| info.split_whitespace().nth(2))
+            == Some(stage)
+    });
+
+    let args: Vec<&str> = if has_stage {
+        let flag = match side {
+            ConflictSide::Ours => "--ours",
+            ConflictSide::Theirs => "--theirs",
+        };
+        vec!["checkout", flag, "--", file]
+    } else {
+        vec!["rm", "-f", "--quiet", "--", file]
+    };
+    let output = git_command()
+        .current_dir(repo_path)
+        .args(&args)
+        .output()
+        .with_context(|| format!("Failed to execute {} command", args[0]))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to keep {} version of {}: {}",
+            side,
+            file,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    if has_stage {
+        stage_file(repo_path, file)?;
+    }
+    Ok(())
+}
+
+/// Renders a conflicted file as a three-way merge with diff3-style markers.
+///
+/// The target branch's (`ours`), common ancestor's (`base`) and picked
+/// commit's (`theirs`) versions are read from the index and merged again with
+/// `git merge-file`, so the result does not depend on edits to the working
+/// tree file or on `merge.conflictStyle`. A side on which the file was added
+/// or deleted is merged as an empty file.
+pub fn conflict_diff3(repo_path: &Path, file: &str) -> Result<String> {
+    let temp_dir = TempDir::new().context("Failed to create temporary directory")?;
+    let mut paths = Vec::new();
+    for (stage, label) in [(2, "ours"), (1, "base"), (3, "theirs")] {
+        let output = git_command()
+            .current_dir(repo_path)
+            .args(["cat-file", "blob", &format!(":{}:{}", stage, file)])
+            .output()
+            .context("Failed to execute cat-file command")?;
+        // A missing stage means the file was added or deleted on that side
+        let content = if output.status.success() {
+            output.stdout
+        } else {
+            Vec::new()
+        };
+        let path = temp_dir.path().join(label);
+        std::fs::write(&path, content)
+            .with_context(|| format!("Failed to write {} version of {}", label, file))?;
+        paths.push(path);
+    }
+
+    let output = git_command()
+        .current_dir(repo_path)
+        .args([
+            "merge-file",
+            "-p",
+            "--diff3",
+            "-L",
+            "ours",
+            "-L",
+            "base",
+            "-L",
+            "theirs",
+        ])
+        .args(&paths)
+        .output()
+        .context("Failed to execute merge-file command")?;
+
+    // The exit code is the number of conflicts; errors are reported as -1
+    match output.status.code() {
+        Some(code) if (0..128).contains(&code) => {
+            Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+        }
+        _ => anyhow::bail!(
+            "Failed to merge the versions of {}: {}",
+            file,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+    }
+}
 
-    // Get all commit messages in target branch
-    let message_output = Command::new("git")
+/// Returns the staged changes to `file` against `HEAD` as a patch.
+///
+/// The patch has full object IDs and binary hunks, so it can be applied to
+/// binary files too. It is empty when the staged file matches `HEAD`.
+pub fn staged_diff(repo_path: &Path, file: &str) -> Result<Vec<u8>> {
+    let output = git_command()
         .current_dir(repo_path)
-        .args(["log", "--format=%s", target_branch])
+        .args([
+            "diff",
+            "--cached",
+            "--binary",
+            "--full-index",
+            "--no-color",
+            "--no-ext-diff",
+            "HEAD",
+            "--",
+            file,
+        ])
         .output()
-        .context("Failed to get commit messages from target branch")?;
+        .context("Failed to execute diff command")?;
 
-    if !message_output.status.success() {
-        let stderr = String::from_utf8_lossy(&message_output.stderr);
+    if !output.status.success() {
         anyhow::bail!(
-            "Failed to get commit messages from target branch: {}",
-            stderr
+            "Failed to diff {}: {}",
+            file,
+            String::from_utf8_lossy(&output.stderr).trim()
         );
     }
 
-    let commit_messages: Vec<String> = String::from_utf8_lossy(&message_output.stdout)
-        .lines()
-        .map(|line| line.trim().to_string())
-        .filter(|line| !line.is_empty())
-        .collect();
+    Ok(output.stdout)
+}
+
+/// Resolves `file` to `HEAD`'s version with `patch` applied, and stages it.
+///
+/// The patch is applied to a throwaway index holding `HEAD`'s tree, so when
+/// it does not apply, `false` is returned and neither the worktree nor the
+/// real index is touched. An empty patch keeps `HEAD`'s version.
+pub fn apply_patch_to_head(repo_path: &Path, file: &str, patch: &[u8]) -> Result<bool> {
+    let temp_dir = TempDir::new().context("Failed to create temporary directory")?;
+    let index = temp_dir.path().join("index");
+    let with_index = || {
+        let mut command = git_command();
+        command.current_dir(repo_path).env("GIT_INDEX_FILE", &index);
+        command
+    };
+
+    let output = with_index()
+        .args(["read-tree", "HEAD"])
+        .output()
+        .context("Failed to execute read-tree command")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to read HEAD into a temporary index: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    if !patch.is_empty() {
+        let mut child = with_index()
+            .args(["apply", "--cached", "-"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .context("Failed to execute apply command")?;
+        if let Some(mut stdin) = child.stdin.take() {
+            std::io::Write::write_all(&mut stdin, patch)
+                .context("Failed to write patch to apply command")?;
+        }
+        if !child.wait().context("Failed to wait for apply")?.success() {
+            return Ok(false);
+        }
+    }
+
+    let output = with_index()
+        .args(["ls-files", "--", file])
+        .output()
+        .context("Failed to execute ls-files command")?;
+    let args: &[&str] = if output.stdout.is_empty() {
+        // The resolution deletes the file
+        &["rm", "--quiet", "--force", "--ignore-unmatch", "--"]
+    } else {
+        // Write the result with the repository's filters, then stage it
+        let output = with_index()
+            .args(["checkout-index", "--force", "--", file])
+            .output()
+            .context("Failed to execute checkout-index command")?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "Failed to write {}: {}",
+                file,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        &["add", "--"]
+    };
 
-    // Get all commit bodies in target branch (full message including body)
-    let body_output = Command::new("git")
+    let output = git_command()
         .current_dir(repo_path)
-        .args(["log", "--format=%b", target_branch])
+        .args(args)
+        .arg(file)
         .output()
-        .context("Failed to get commit bodies from target branch")?;
+        .context("Failed to stage file")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to stage {}: {}",
+            file,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(true)
+}
 
-    if !body_output.status.success() {
-        let stderr = String::from_utf8_lossy(&body_output.stderr);
-        anyhow::bail!("Failed to get commit bodies from target branch: {}", stderr);
+/// Runs `git mergetool` on a single conflicted file.
+///
+/// Uses `tool` when given, otherwise git's `merge.tool` setting. The tool
+/// inherits the terminal. git stages the file itself when the tool reports
+/// success, so callers should check [`unresolved_files`] afterwards.
+#[must_use = "this operation can fail and the result should be checked"]
+pub fn run_mergetool(repo_path: &Path, tool: Option<&str>, file: &str) -> Result<()> {
+    let mut command = git_command();
+    command
+        .current_dir(repo_path)
+        .args(["mergetool", "--no-prompt"]);
+    if let Some(tool) = tool {
+        command.arg(format!("--tool={}", tool));
     }
+    command
+        .args(["--", file])
+        .status()
+        .context("Failed to execute mergetool command")?;
 
-    let commit_bodies: Vec<String> = String::from_utf8_lossy(&body_output.stdout)
-        .lines()
-        .map(|line| line.trim().to_string())
-        .filter(|line| !line.is_empty())
-        .collect();
+    Ok(())
+}
+
+/// Commits a paused cherry-pick once its conflicts are resolved.
+///
+/// With `sign`, the commit is signed; a signing failure is returned as
+/// [`GitError::SigningFailed`] and leaves the cherry-pick paused.
+#[must_use = "this operation can fail and the result should be checked"]
+pub fn continue_cherry_pick(repo_path: &Path, sign: bool) -> Result<()> {
+    // Check if the commit would be empty by checking staged changes
+    // git diff --cached --quiet exits with 1 if there are changes, 0 if empty
+    let is_empty_commit = Command::new("git")
+        .current_dir(repo_path)
+        .args(["diff", "--cached", "--quiet"])
+        .status()
+        .map(|s| s.success()) // success (exit 0) means no staged changes = empty
+        .unwrap_or(false);
+
+    // For empty commits, use git commit --allow-empty directly
+    // because git cherry-pick --continue doesn't support --keep-redundant-commits
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(continue_cherry_pick_args(is_empty_commit, sign))
+        .output()?;
+
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if let Some(error) = signing_error(sign, &stderr) {
+        return Err(error.into());
+    }
+    anyhow::bail!("Failed to continue cherry-pick: {}", stderr);
+}
 
-    Ok(CommitHistory {
-        commit_hashes,
-        commit_messages,
-        commit_bodies,
+/// Arguments of the git command committing a paused cherry-pick.
+///
+/// `git cherry-pick --continue` ignores `-S` for a single pick, so signing
+/// is switched on through `commit.gpgSign` instead.
+pub fn continue_cherry_pick_args(is_empty_commit: bool, sign: bool) -> Vec<&'static str> {
+    match (is_empty_commit, sign) {
+        (true, false) => vec!["commit", "--allow-empty", "--no-edit"],
+        (true, true) => vec!["commit", "--allow-empty", "--no-edit", "-S"],
+        (false, false) => vec!["cherry-pick", "--continue", "--no-edit"],
+        (false, true) => vec![
+            "-c",
+            "commit.gpgSign=true",
+            "cherry-pick",
+            "--continue",
+            "--no-edit",
+        ],
+    }
+}
+
+/// Returns [`GitError::SigningFailed`] if `stderr` of a git command run with
+/// signing enabled reports that the GPG or SSH signature could not be made.
+fn signing_error(sign: bool, stderr: &str) -> Option<GitError> {
+    const SIGNING_FAILURES: [&str; 4] = [
+        "failed to sign",
+        "unable to sign",
+        "couldn't load public key",
+        "failed to write commit object",
+    ];
+    let lower = stderr.to_lowercase();
+    (sign
+        && SIGNING_FAILURES
+            .iter()
+            .any(|failure| lower.contains(failure)))
+    .then(|| GitError::SigningFailed {
+        message: stderr.trim().to_string(),
     })
 }
 
+/// Squashes the commits on top of `base` into one commit with `message`.
+///
+/// The squashed commit keeps the author and author date of the picked commit,
+/// the first one after `base`; later commits are conflict resolutions. With
+/// `sign`, the commit is signed; a signing failure is returned as
+/// [`GitError::SigningFailed`]. On any failure the branch is reset to `base`.
+#[must_use = "this operation can fail and the result should be checked"]
+pub fn squash_commits(repo_path: &Path, base: &str, message: &str, sign: bool) -> Result<()> {
+    let reset_hard = || {
+        Command::new("git")
+            .current_dir(repo_path)
+            .args(["reset", "--hard", base])
+            .output()
+    };
+
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args([
+            "log",
+            "--format=%an <%ae>%x00%aI",
+            &format!("{}..HEAD", base),
+        ])
+        .output()
+        .context("Failed to read the author of the picked commit")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to read the author of the picked commit: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    // Commits are listed newest first; an empty pick leaves none
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let author_args: Vec<String> = stdout
+        .lines()
+        .last()
+        .and_then(|line| line.split_once('\0'))
+        .map(|(author, date)| vec![format!("--author={}", author), format!("--date={}", date)])
+        .unwrap_or_default();
+
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(["reset", "--soft", base])
+        .output()
+        .context("Failed to execute reset command")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to squash onto {}: {}",
+            base,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let mut args = vec!["commit", "--allow-empty", "-m", message];
+    args.extend(author_args.iter().map(String::as_str));
+    if sign {
+        args.push("-S");
+    }
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(&args)
+        .output()
+        .context("Failed to execute commit command")?;
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let _ = reset_hard();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if let Some(error) = signing_error(sign, &stderr) {
+        return Err(error.into());
+    }
+    anyhow::bail!("Failed to commit squashed PR: {}", stderr.trim());
+}
+
+#[must_use = "this operation can fail and the result should be checked"]
+pub fn abort_cherry_pick(repo_path: &Path) -> Result<()> {
+    Command::new("git")
+        .current_dir(repo_path)
+        .args(["cherry-pick", "--abort"])
+        .output()?;
+
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub struct CommitInfo {
+    pub hash: String,
+    pub date: String,
+    pub title: String,
+    pub author: String,
+}
+
+#[must_use = "this returns commit information which should be used"]
+pub fn get_commit_info(repo_path: &Path, commit_id: &str) -> Result<CommitInfo> {
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(["show", "--no-patch", "--format=%H|%ci|%s|%an", commit_id])
+        .output()?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to get commit info: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let output_str = String::from_utf8_lossy(&output.stdout);
+    let parts: Vec<&str> = output_str.trim().split('|').collect();
+
+    if parts.len() >= 4 {
+        Ok(CommitInfo {
+            hash: parts[0].to_string(),
+            date: parts[1].to_string(),
+            title: parts[2].to_string(),
+            author: parts[3].to_string(),
+        })
+    } else {
+        anyhow::bail!("Unexpected git show output format");
+    }
+}
+
+/// Structure to hold pre-fetched commit history for optimized PR analysis
+#[derive(Debug, Clone)]
+pub struct CommitHistory {
+    pub commit_hashes: HashSet<String>, // All commit hashes in target branch
+    pub commit_messages: Vec<String>,   // All commit subjects in target branch
+    pub commit_bodies: Vec<String>,     // Body lines referencing cherry-picked commits
+}
+
+/// Get complete commit history for target branch once to avoid repeated git calls
+#[must_use = "this returns the commit history which should be used"]
+pub fn get_target_branch_history(repo_path: &Path, target_branch: &str) -> Result<CommitHistory> {
+    get_target_branch_history_with_depth(repo_path, target_branch, None)
+}
+
+/// Get commit history for target branch, looking back at most `depth` commits.
+///
+/// The log is streamed from a single `git log` process, so memory grows with
+/// what is kept rather than with git's full output: hashes, subjects, and only
+/// those body lines that reference a cherry-picked commit. With `depth` set,
+/// commits older than the newest `depth` are not considered at all.
+#[must_use = "this returns the commit history which should be used"]
+pub fn get_target_branch_history_with_depth(
+    repo_path: &Path,
+    target_branch: &str,
+    depth: Option<usize>,
+) -> Result<CommitHistory> {
+    let _phase = profiling::phase("read target history");
+    let mut command = Command::new("git");
+    command
+        .current_dir(repo_path)
+        .args(["log", "-z", "--format=%H%n%s%n%b"]);
+    if let Some(depth) = depth {
+        command.arg(format!("--max-count={}", depth));
+    }
+    let mut child = command
+        .arg(target_branch)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to get commit history from target branch")?;
+
+    let mut history = CommitHistory {
+        commit_hashes: HashSet::new(),
+        commit_messages: Vec::new(),
+        commit_bodies: Vec::new(),
+    };
+
+    // Commits are NUL-separated: hash, subject, then body lines
+    let mut reader = BufReader::new(child.stdout.take().expect("stdout is piped"));
+    let mut record = Vec::new();
+    loop {
+        record.clear();
+        let read = reader
+            .read_until(b'\0', &mut record)
+            .context("Failed to read commit history from target branch")?;
+        if read == 0 {
+            break;
+        }
+        if record.last() == Some(&b'\0') {
+            record.pop();
+        }
+
+        let text = String::from_utf8_lossy(&record);
+        let mut lines = text.lines().map(str::trim);
+        if let Some(hash) = lines.next().filter(|hash| !hash.is_empty()) {
+            history.commit_hashes.insert(hash.to_string());
+        }
+        if let Some(subject) = lines.next().filter(|subject| !subject.is_empty()) {
+            history.commit_messages.push(subject.to_string());
+        }
+        history.commit_bodies.extend(
+            lines
+                .filter(|line| is_cherry_pick_reference(line))
+                .map(str::to_string),
+        );
+    }
+
+    let mut stderr = String::new();
+    if let Some(mut pipe) = child.stderr.take() {
+        let _ = pipe.read_to_string(&mut stderr);
+    }
+    let status = child
+        .wait()
+        .context("Failed to get commit history from target branch")?;
+    if !status.success() {
+        anyhow::bail!(
+            "Failed to get commit history from target branch: {}",
+            stderr
+        );
+    }
+
+    history.commit_hashes.shrink_to_fit();
+    history.commit_messages.shrink_to_fit();
+    history.commit_bodies.shrink_to_fit();
+    Ok(history)
+}
+
+/// Returns `true` for commit body lines that record a cherry-pick source.
+fn is_cherry_pick_reference(line: &str) -> bool {
+    line.contains("cherry-picked from") || line.contains("cherry picked from commit")
+}
+
 /// Check if a commit exists in the pre-fetched commit history
 #[must_use]
 pub fn check_commit_in_history(commit_id: &str, history: &CommitHistory) -> bool {
@@ -791,6 +1608,7 @@ pub fn check_commit_in_history(commit_id: &str, history: &CommitHistory) -> bool
 /// Check if a PR is merged using pre-fetched commit history
 #[must_use]
 pub fn check_pr_merged_in_history(pr_id: i32, pr_title: &str, history: &CommitHistory) -> bool {
+    let _phase = profiling::phase("match merge history");
     // Strategy 1: Check for Azure DevOps merge pattern (most common)
     if check_azure_devops_merge_pattern_in_history(pr_id, pr_title, history) {
         return true;
@@ -1163,8 +1981,10 @@ pub fn list_patch_branches_detailed(repo_path: &Path) -> Result<PatchBranchListR
                 target: target.clone(),
                 version: version.clone(),
                 is_merged: false, // Will be determined later
+                is_merged_remote: None,
                 selected: false,
                 status: crate::models::CleanupStatus::Pending,
+                tip: None,
             });
         } else {
             skipped_branches.push(branch);
@@ -1216,10 +2036,18 @@ fn resolve_git_repo_path(repo_path: &Path) -> Result<PathBuf> {
 }
 
 /// Get all commit hashes from a specific branch (excluding those already on the base branch)
-fn get_branch_commits(repo_path: &Path, branch_name: &str) -> Result<Vec<String>> {
+///
+/// The base is `base_ref` if given, otherwise it is derived from the branch name.
+fn get_branch_commits(
+    repo_path: &Path,
+    branch_name: &str,
+    base_ref: Option<&str>,
+) -> Result<Vec<String>> {
     // Use ^main to exclude commits already on main
     // This gets only commits unique to the branch
-    let range_arg = if branch_name.contains('/') {
+    let range_arg = if let Some(base) = base_ref {
+        format!("{}..{}", base, branch_name)
+    } else if branch_name.contains('/') {
         // For patch branches like "patch/main-6.6.2", exclude commits from main
         let base = branch_name
             .split('/')
@@ -1286,13 +2114,48 @@ pub fn check_patch_merged(
 ) -> Result<bool> {
     // Get commit history from target branch
     let target_history = get_target_branch_history(repo_path, target_branch)?;
+    check_patch_merged_in_history(repo_path, patch_branch, &target_history)
+}
 
-    // Get all commits from the patch branch
-    let patch_commits = get_branch_commits(repo_path, patch_branch)?;
-
-    // Strategy 1: Check if all patch commit hashes are in target (for regular merges)
-    let all_hashes_found = patch_commits
-        .iter()
+/// Check if a patch branch is merged using pre-fetched target branch history
+///
+/// Use this when checking several patch branches against the same target so
+/// the history is only read once.
+pub fn check_patch_merged_in_history(
+    repo_path: &Path,
+    patch_branch: &str,
+    target_history: &CommitHistory,
+) -> Result<bool> {
+    check_patch_merged_with_base(repo_path, patch_branch, None, target_history)
+}
+
+/// Check if a patch branch is merged into a remote-tracking ref
+///
+/// `remote_history` must be the history of `remote_ref`. The patch commits are
+/// taken relative to `remote_ref` rather than the local base branch, so a
+/// merge that only exists locally is not counted.
+pub fn check_patch_merged_on_remote(
+    repo_path: &Path,
+    patch_branch: &str,
+    remote_ref: &str,
+    remote_history: &CommitHistory,
+) -> Result<bool> {
+    validate_git_ref(remote_ref)?;
+    check_patch_merged_with_base(repo_path, patch_branch, Some(remote_ref), remote_history)
+}
+
+fn check_patch_merged_with_base(
+    repo_path: &Path,
+    patch_branch: &str,
+    base_ref: Option<&str>,
+    target_history: &CommitHistory,
+) -> Result<bool> {
+    // Get all commits from the patch branch
+    let patch_commits = get_branch_commits(repo_path, patch_branch, base_ref)?;
+
+    // Strategy 1: Check if all patch commit hashes are in target (for regular merges)
+    let all_hashes_found = patch_commits
+        .iter()
         .all(|commit| target_history.commit_hashes.contains(commit));
 
     if all_hashes_found {
@@ -1301,7 +2164,7 @@ pub fn check_patch_merged(
 
     // Strategy 2: Check for cherry-pick references in commit bodies
     // Look for "cherry-picked from <hash>" or "(cherry picked from commit <hash>)" patterns
-    // Each line in commit_bodies is a separate line from all commit bodies
+    // Each line in commit_bodies is a cherry-pick reference line from a commit body
     let cherry_pick_found_count = patch_commits
         .iter()
         .filter(|commit_hash| {
@@ -1343,6 +2206,545 @@ pub fn check_patch_merged(
     Ok(found_count >= threshold)
 }
 
+/// Fetch a branch from `origin` and return its remote-tracking ref.
+///
+/// Cleanup mode checks patch branches against this ref as well as the local
+/// branch, since a stale local target can make a branch look merged when it
+/// is not on the server. Returns `None` if there is no `origin` remote or the
+/// branch does not exist there. A failed fetch (e.g. offline) is only logged;
+/// an existing, possibly stale, remote-tracking ref is still returned.
+pub fn fetch_remote_tracking_ref(repo_path: &Path, branch: &str) -> Result<Option<String>> {
+    validate_git_ref(branch)?;
+
+    let remotes = Command::new("git")
+        .current_dir(repo_path)
+        .args(["remote"])
+        .output()
+        .context("Failed to list git remotes")?;
+    if !String::from_utf8_lossy(&remotes.stdout)
+        .lines()
+        .any(|remote| remote.trim() == "origin")
+    {
+        return Ok(None);
+    }
+
+    let fetch = Command::new("git")
+        .current_dir(repo_path)
+        .args(["fetch", "origin", branch])
+        .output()
+        .context("Failed to execute git fetch")?;
+    if !fetch.status.success() {
+        tracing::warn!(
+            "Failed to fetch origin/{}: {}",
+            branch,
+            String::from_utf8_lossy(&fetch.stderr).trim()
+        );
+    }
+
+    let remote_ref = format!("origin/{}", branch);
+    let exists = Command::new("git")
+        .current_dir(repo_path)
+        .args([
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/remotes/{}", remote_ref),
+        ])
+        .output()
+        .context("Failed to resolve remote-tracking ref")?
+        .status
+        .success();
+
+    Ok(exists.then_some(remote_ref))
+}
+
+/// How far the remote target branch has moved past a worktree's base.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetDivergence {
+    /// Remote-tracking ref of the target branch (e.g. `origin/next`).
+    pub remote_ref: String,
+    /// Commits on the remote target that `HEAD` does not contain.
+    pub behind: usize,
+}
+
+/// Check whether `origin/<target_branch>` moved past the worktree's base.
+///
+/// Fetches the target branch and counts the commits on it that are not in
+/// `HEAD`. A patch branch created from the target tip has none, so any count
+/// means the target advanced while the merge session was running. Returns
+/// `None` if the target did not move or there is no `origin` remote.
+pub fn check_target_divergence(
+    repo_path: &Path,
+    target_branch: &str,
+) -> Result<Option<TargetDivergence>> {
+    let Some(remote_ref) = fetch_remote_tracking_ref(repo_path, target_branch)? else {
+        return Ok(None);
+    };
+
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(["rev-list", "--count", &format!("HEAD..{}", remote_ref)])
+        .output()
+        .context("Failed to execute git rev-list")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to compare HEAD with {}: {}",
+            remote_ref,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let behind: usize = String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse()
+        .context("Failed to parse commit count")?;
+    Ok((behind > 0).then_some(TargetDivergence { remote_ref, behind }))
+}
+
+/// Rebase the branch checked out in `repo_path` onto `onto`.
+///
+/// If the rebase stops on a conflict it is aborted, leaving the branch as it
+/// was, and an error is returned.
+pub fn rebase_onto(repo_path: &Path, onto: &str) -> Result<()> {
+    validate_git_ref(onto)?;
+
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(["rebase", onto])
+        .output()
+        .context("Failed to execute git rebase")?;
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let _ = Command::new("git")
+        .current_dir(repo_path)
+        .args(["rebase", "--abort"])
+        .output();
+    anyhow::bail!(
+        "Rebase onto {} failed and was aborted: {}",
+        onto,
+        String::from_utf8_lossy(&output.stderr).trim()
+    )
+}
+
+// ==================== Branch Logs ====================
+
+/// One commit of a branch log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    pub commit_id: String,
+    pub subject: String,
+    /// Stable patch-id of the commit's change; `None` for empty commits.
+    pub patch_id: Option<String>,
+}
+
+/// List the newest `limit` first-parent commits of `revision` with their
+/// patch-ids.
+pub fn branch_log(repo_path: &Path, revision: &str, limit: usize) -> Result<Vec<LogEntry>> {
+    validate_git_ref(revision)?;
+    log_entries(
+        repo_path,
+        revision,
+        &[&format!("--max-count={}", limit), revision],
+    )
+}
+
+/// List the first-parent commits of `revision` that are not reachable from
+/// `base`, newest first, with their patch-ids.
+pub fn branch_log_since(repo_path: &Path, revision: &str, base: &str) -> Result<Vec<LogEntry>> {
+    validate_git_ref(revision)?;
+    validate_git_ref(base)?;
+    log_entries(repo_path, revision, &[revision, &format!("^{}", base)])
+}
+
+fn log_entries(repo_path: &Path, revision: &str, args: &[&str]) -> Result<Vec<LogEntry>> {
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(["log", "--first-parent", "--format=%H%x1f%s"])
+        .args(args)
+        .output()
+        .context("Failed to execute git log")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to list commits of {}: {}",
+            revision,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let commits: Vec<(String, String)> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.split_once('\x1f'))
+        .map(|(id, subject)| (id.to_string(), subject.to_string()))
+        .collect();
+    let ids: Vec<String> = commits.iter().map(|(id, _)| id.clone()).collect();
+    let mut patch_ids = patch_ids(repo_path, &ids)?;
+
+    Ok(commits
+        .into_iter()
+        .map(|(commit_id, subject)| LogEntry {
+            patch_id: patch_ids.remove(&commit_id),
+            commit_id,
+            subject,
+        })
+        .collect())
+}
+
+/// Returns `true` if `path` is the top level of a git work tree.
+pub fn is_repository(path: &Path) -> bool {
+    let Ok(output) = git_command()
+        .current_dir(path)
+        .args(["rev-parse", "--show-toplevel"])
+        .output()
+    else {
+        return false;
+    };
+    output.status.success()
+        && match (
+            Path::new(String::from_utf8_lossy(&output.stdout).trim()).canonicalize(),
+            path.canonicalize(),
+        ) {
+            (Ok(top_level), Ok(path)) => top_level == path,
+            _ => false,
+        }
+}
+
+/// Returns `true` if a cherry-pick is stopped in `repo_path`.
+pub fn cherry_pick_in_progress(repo_path: &Path) -> bool {
+    Command::new("git")
+        .current_dir(repo_path)
+        .args(["rev-parse", "-q", "--verify", "CHERRY_PICK_HEAD"])
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// Returns `true` if tracked files in `repo_path` have uncommitted changes.
+pub fn has_uncommitted_changes(repo_path: &Path) -> Result<bool> {
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .context("Failed to execute git status")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to read worktree status: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(!output.stdout.is_empty())
+}
+
+/// Create `branch_name` at `revision` without checking it out.
+///
+/// Fails if the branch already exists.
+pub fn create_branch_at(repo_path: &Path, branch_name: &str, revision: &str) -> Result<()> {
+    validate_git_ref(branch_name)?;
+    validate_git_ref(revision)?;
+
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(["branch", branch_name, revision])
+        .output()
+        .context("Failed to execute git branch")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to create branch {}: {}",
+            branch_name,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+/// Name of the tag marking where the patch branch of `version` into
+/// `target_branch` started.
+///
+/// The target is part of the name so a release picked into several targets
+/// gets one tag per patch branch.
+pub fn base_tag_name(target_branch: &str, version: &str) -> String {
+    format!("mergers/base-{}-{}", target_branch, version)
+}
+
+/// Tags the current HEAD of `repo_path` as the base of `version` into
+/// `target_branch`.
+///
+/// The lightweight tag is created before any cherry-pick, so
+/// `git diff mergers/base-<target>-<version>..patch/<target>-<version>` shows
+/// exactly what the release adds. A tag left over from an earlier run of the
+/// same version is moved.
+pub fn create_base_tag(repo_path: &Path, target_branch: &str, version: &str) -> Result<String> {
+    let tag = base_tag_name(target_branch, version);
+    validate_git_ref(&tag)?;
+
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(["tag", "--force", &tag, "HEAD"])
+        .output()
+        .context("Failed to execute git tag")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to create tag {}: {}",
+            tag,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(tag)
+}
+
+/// Deletes the base tag of `version` into `target_branch`, if it exists.
+///
+/// Returns whether a tag was deleted.
+pub fn delete_base_tag(repo_path: &Path, target_branch: &str, version: &str) -> Result<bool> {
+    let tag = base_tag_name(target_branch, version);
+    let exists = Command::new("git")
+        .current_dir(repo_path)
+        .args([
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/tags/{}", tag),
+        ])
+        .output()
+        .context("Failed to check tag existence")?
+        .status
+        .success();
+    if !exists {
+        return Ok(false);
+    }
+
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(["tag", "--delete", &tag])
+        .output()
+        .context("Failed to execute git tag")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to delete tag {}: {}",
+            tag,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(true)
+}
+
+/// Name of the release tag of `version`, e.g. `v1.2.3`.
+///
+/// A leading `v` in the version is not doubled.
+pub fn release_tag_name(version: &str) -> String {
+    format!("v{}", version.strip_prefix('v').unwrap_or(version))
+}
+
+/// Creates the annotated tag `tag` on the current HEAD of `repo_path`.
+///
+/// The message is kept verbatim, so markdown headings in release notes
+/// survive. An existing tag is not moved. With `sign`, the tag is signed and
+/// a signing failure is returned as [`GitError::SigningFailed`].
+pub fn create_annotated_tag(repo_path: &Path, tag: &str, message: &str, sign: bool) -> Result<()> {
+    validate_git_ref(tag)?;
+
+    let mut child = Command::new("git")
+        .current_dir(repo_path)
+        .args([
+            "tag",
+            if sign { "--sign" } else { "--annotate" },
+            "--cleanup=verbatim",
+            "--file=-",
+            tag,
+            "HEAD",
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to execute git tag")?;
+    if let Some(mut stdin) = child.stdin.take() {
+        std::io::Write::write_all(&mut stdin, message.as_bytes())
+            .context("Failed to write tag message")?;
+    }
+    let output = child
+        .wait_with_output()
+        .context("Failed to execute git tag")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if let Some(error) = signing_error(sign, &stderr) {
+            return Err(error.into());
+        }
+        anyhow::bail!("Failed to create tag {}: {}", tag, stderr.trim());
+    }
+    Ok(())
+}
+
+/// Pushes the tag `tag` of `repo_path` to `origin`.
+pub fn push_tag(repo_path: &Path, tag: &str) -> Result<()> {
+    validate_git_ref(tag)?;
+
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(["push", "origin", &format!("refs/tags/{}", tag)])
+        .output()
+        .context("Failed to execute git push")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to push tag {}: {}",
+            tag,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+/// Compute the stable patch-id of each commit, keyed by commit id.
+///
+/// Merge commits are diffed against their first parent, which is what
+/// `git cherry-pick -m 1` applies, so a merged PR and its cherry-pick share
+/// a patch-id unless the pick needed conflict resolution. Commits without
+/// changes are left out.
+pub fn patch_ids(
+    repo_path: &Path,
+    commits: &[String],
+) -> Result<std::collections::HashMap<String, String>> {
+    if commits.is_empty() {
+        return Ok(std::collections::HashMap::new());
+    }
+
+    let mut log = Command::new("git")
+        .current_dir(repo_path)
+        .args([
+            "log",
+            "--no-walk=unsorted",
+            "-p",
+            "-m",
+            "--first-parent",
+            "--no-color",
+            "--format=commit %H",
+        ])
+        .args(commits)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to execute git log")?;
+    let diff = log
+        .stdout
+        .take()
+        .context("Failed to capture git log output")?;
+
+    let patch_id = Command::new("git")
+        .current_dir(repo_path)
+        .args(["patch-id", "--stable"])
+        .stdin(Stdio::from(diff))
+        .output()
+        .context("Failed to execute git patch-id")?;
+    let log = log
+        .wait_with_output()
+        .context("Failed to wait for git log")?;
+    if !log.status.success() {
+        anyhow::bail!(
+            "Failed to read commit diffs: {}",
+            String::from_utf8_lossy(&log.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&patch_id.stdout)
+        .lines()
+        .filter_map(|line| line.split_once(' '))
+        .map(|(patch_id, commit_id)| (commit_id.to_string(), patch_id.to_string()))
+        .collect())
+}
+
+/// Find the best common ancestor of two revisions.
+///
+/// Returns `None` if the histories are unrelated.
+pub fn merge_base(repo_path: &Path, a: &str, b: &str) -> Result<Option<String>> {
+    validate_git_ref(a)?;
+    validate_git_ref(b)?;
+
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(["merge-base", a, b])
+        .output()
+        .context("Failed to execute git merge-base")?;
+    match output.status.code() {
+        Some(0) => Ok(Some(
+            String::from_utf8_lossy(&output.stdout).trim().to_string(),
+        )),
+        Some(1) => Ok(None),
+        _ => anyhow::bail!(
+            "Failed to find merge base of {} and {}: {}",
+            a,
+            b,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+    }
+}
+
+// ==================== Diff Statistics ====================
+
+/// Total size of a set of changes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiffStat {
+    /// Changed files, counted once per commit that changes them.
+    pub files: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+impl std::ops::AddAssign for DiffStat {
+    fn add_assign(&mut self, other: Self) {
+        self.files += other.files;
+        self.insertions += other.insertions;
+        self.deletions += other.deletions;
+    }
+}
+
+/// Sum the diff size of the given commits.
+///
+/// Merge commits are diffed against their first parent, like
+/// [`patch_ids`]. Binary files count as changed files without lines.
+pub fn diff_stat(repo_path: &Path, commits: &[String]) -> Result<DiffStat> {
+    if commits.is_empty() {
+        return Ok(DiffStat::default());
+    }
+    for commit in commits {
+        validate_git_ref(commit)?;
+    }
+
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args([
+            "log",
+            "--no-walk=unsorted",
+            "--numstat",
+            "-m",
+            "--first-parent",
+            "--format=",
+        ])
+        .args(commits)
+        .output()
+        .context("Failed to execute git log --numstat")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to read commit diff sizes: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    // Format: INSERTIONS<tab>DELETIONS<tab>PATH, with "-" counts for binaries
+    let mut stat = DiffStat::default();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let mut parts = line.splitn(3, '\t');
+        let (Some(insertions), Some(deletions), Some(_)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        stat.files += 1;
+        stat.insertions += insertions.parse::<usize>().unwrap_or(0);
+        stat.deletions += deletions.parse::<usize>().unwrap_or(0);
+    }
+    Ok(stat)
+}
+
 // ==================== Commit Change Analysis ====================
 
 use crate::core::operations::dependency_analysis::{ChangeType, FileChange, LineRange};
@@ -1429,6 +2831,7 @@ pub fn get_commit_changes_with_ranges(
     repo_path: &Path,
     commit_id: &str,
 ) -> Result<Vec<FileChange>> {
+    let _phase = profiling::phase("read PR changes");
     validate_git_ref(commit_id)?;
 
     // First get the basic file changes
@@ -1578,15 +2981,184 @@ pub fn fetch_commits_for_analysis(repo_path: &Path, commit_ids: &[String]) -> Re
     Ok(())
 }
 
+/// Fetches commits from origin together with their parents, as simulating
+/// a cherry-pick needs the first parent's tree.
+///
+/// Commits whose first parent is already available are not fetched. Fetch
+/// failures are only logged; the callers report commits that stay missing.
+#[must_use = "this operation can fail and the result should be checked"]
+pub fn fetch_commits_with_parents(repo_path: &Path, commit_ids: &[String]) -> Result<()> {
+    for commit_id in commit_ids {
+        if commit_exists(repo_path, &format!("{}^1", commit_id)) {
+            continue;
+        }
+        let output = Command::new("git")
+            .current_dir(repo_path)
+            .args(["fetch", "--depth=2", "origin", commit_id])
+            .output()
+            .context("Failed to execute fetch command")?;
+        if !output.status.success() {
+            tracing::warn!(
+                "Failed to fetch {}: {}",
+                commit_id,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+    }
+    Ok(())
+}
+
 /// Checks if a commit exists in the local repository.
 #[must_use]
 pub fn commit_exists(repo_path: &Path, commit_id: &str) -> bool {
     Command::new("git")
         .current_dir(repo_path)
-        .args(["cat-file", "-t", commit_id])
+        .args(["cat-file", "-t", commit_id])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+/// Outcome of a cherry-pick simulated with `git merge-tree`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimulatedCherryPick {
+    /// The commit applies cleanly, producing this tree.
+    Clean { tree: String },
+    /// The commit conflicts in these files.
+    Conflict { files: Vec<String> },
+}
+
+/// Resolves a revision to the ID of its tree object.
+pub fn tree_id(repo_path: &Path, revision: &str) -> Result<String> {
+    let output = git_command()
+        .current_dir(repo_path)
+        .args(["rev-parse", "--verify", &format!("{}^{{tree}}", revision)])
+        .output()
+        .context("Failed to execute rev-parse command")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to resolve tree of '{}': {}",
+            revision,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Creates a throwaway commit for `tree`, used to steer `git merge-tree`.
+fn synthetic_commit(repo_path: &Path, tree: &str, parent: Option<&str>) -> Result<String> {
+    let mut cmd = git_command();
+    cmd.current_dir(repo_path)
+        .env("GIT_AUTHOR_NAME", "mergers")
+        .env("GIT_AUTHOR_EMAIL", "mergers@localhost")
+        .env("GIT_COMMITTER_NAME", "mergers")
+        .env("GIT_COMMITTER_EMAIL", "mergers@localhost")
+        .args(["commit-tree", tree, "-m", "mergers simulation"]);
+    if let Some(parent) = parent {
+        cmd.args(["-p", parent]);
+    }
+    let output = cmd
+        .output()
+        .context("Failed to execute commit-tree command")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to create simulation commit: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Simulates cherry-picking `commit_id` onto `onto_tree` without touching
+/// the worktree, the index or any branch.
+///
+/// Like [`cherry_pick_commit`], the commit's first parent is the merge base.
+/// `git merge-tree` picks the merge base itself, so both sides are wrapped in
+/// throwaway commits whose only common ancestor holds the first parent's
+/// tree. This needs git 2.38 or later; the unreferenced objects are removed
+/// by the next `git gc`.
+pub fn simulate_cherry_pick(
+    repo_path: &Path,
+    onto_tree: &str,
+    commit_id: &str,
+) -> Result<SimulatedCherryPick> {
+    let base_tree = tree_id(repo_path, &format!("{}^1", commit_id))?;
+    let their_tree = tree_id(repo_path, commit_id)?;
+
+    let base = synthetic_commit(repo_path, &base_tree, None)?;
+    let ours = synthetic_commit(repo_path, onto_tree, Some(&base))?;
+    let theirs = synthetic_commit(repo_path, &their_tree, Some(&base))?;
+
+    let output = git_command()
+        .current_dir(repo_path)
+        .args([
+            "merge-tree",
+            "--write-tree",
+            "--name-only",
+            "--no-messages",
+            &ours,
+            &theirs,
+        ])
+        .output()
+        .context("Failed to execute merge-tree command")?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines();
+    let tree = lines.next().unwrap_or_default().trim().to_string();
+
+    match output.status.code() {
+        Some(0) => Ok(SimulatedCherryPick::Clean { tree }),
+        Some(1) => {
+            let mut files: Vec<String> = lines
+                .take_while(|line| !line.is_empty())
+                .map(String::from)
+                .collect();
+            files.dedup();
+            Ok(SimulatedCherryPick::Conflict { files })
+        }
+        _ => anyhow::bail!(
+            "git merge-tree failed (git 2.38 or later is required): {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+    }
+}
+
+/// Returns whether `commit_id` changes `file` as a binary file.
+///
+/// Used where no conflicted index exists to check, e.g. for conflicts
+/// predicted by [`simulate_cherry_pick`]. Like cherry-picks, the commit is
+/// compared with its first parent.
+pub fn is_binary_change(repo_path: &Path, commit_id: &str, file: &str) -> Result<bool> {
+    let output = git_command()
+        .current_dir(repo_path)
+        .args([
+            "diff",
+            "--numstat",
+            &format!("{}^1", commit_id),
+            commit_id,
+            "--",
+            file,
+        ])
         .output()
-        .map(|o| o.status.success())
-        .unwrap_or(false)
+        .context("Failed to execute diff command")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to diff '{}' in {}: {}",
+            file,
+            commit_id,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    // Binary files are listed with "-" instead of line counts
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .any(|line| line.starts_with("-\t-\t")))
 }
 
 #[cfg(test)]
@@ -1874,6 +3446,46 @@ mod tests {
         assert!(!history.commit_hashes.is_empty());
     }
 
+    /// # Target Branch History Depth
+    ///
+    /// Tests limiting the history read and which body lines are kept.
+    ///
+    /// ## Test Scenario
+    /// - Creates commits, one with a multi-line body and a cherry-pick reference
+    /// - Reads the history with a depth of 2 and without a limit
+    ///
+    /// ## Expected Outcome
+    /// - With depth 2 only the two newest commits are kept
+    /// - Only cherry-pick reference lines are kept from commit bodies
+    #[test]
+    fn test_get_target_branch_history_with_depth() {
+        let (_temp_dir, repo_path) = setup_test_repo();
+
+        create_commit_with_message(&repo_path, "Oldest commit");
+        create_commit_with_message(
+            &repo_path,
+            "Cherry-picked fix\n\nLong description\nspanning lines\n\n(cherry picked from commit abc123)",
+        );
+        create_commit_with_message(&repo_path, "Newest commit");
+
+        let limited = get_target_branch_history_with_depth(&repo_path, "main", Some(2)).unwrap();
+        assert_eq!(limited.commit_hashes.len(), 2);
+        assert_eq!(
+            limited.commit_messages,
+            vec!["Newest commit".to_string(), "Cherry-picked fix".to_string()]
+        );
+        assert_eq!(
+            limited.commit_bodies,
+            vec!["(cherry picked from commit abc123)".to_string()]
+        );
+
+        let full = get_target_branch_history_with_depth(&repo_path, "main", None).unwrap();
+        assert!(full.commit_messages.contains(&"Oldest commit".to_string()));
+        assert!(full.commit_hashes.len() > limited.commit_hashes.len());
+
+        assert!(get_target_branch_history_with_depth(&repo_path, "missing", None).is_err());
+    }
+
     /// # Check Commit in History
     ///
     /// Tests checking whether a specific commit exists in branch history.
@@ -2569,7 +4181,7 @@ mod tests {
             .output()
             .unwrap();
 
-        let result = cherry_pick_commit(&repo_path, &commit_hash);
+        let result = cherry_pick_commit(&repo_path, &commit_hash, false);
 
         // Cherry-pick should succeed
         assert!(result.is_ok());
@@ -2630,7 +4242,7 @@ mod tests {
         create_commit_with_message(&repo_path, "Main commit");
 
         // Try to cherry-pick - should detect conflict
-        let result = cherry_pick_commit(&repo_path, &feature_hash);
+        let result = cherry_pick_commit(&repo_path, &feature_hash, false);
         assert!(result.is_ok()); // cherry_pick_commit returns CherryPickResult, not error
 
         // Check that it detected conflict
@@ -2642,6 +4254,61 @@ mod tests {
         }
     }
 
+    /// # Simulate Cherry Pick
+    ///
+    /// Tests predicting cherry-pick outcomes without touching the worktree.
+    ///
+    /// ## Test Scenario
+    /// - A feature commit and a main commit change the same file differently
+    /// - Simulates picking the feature commit onto its own base and onto main
+    ///
+    /// ## Expected Outcome
+    /// - Onto the base, the pick is clean and yields the feature commit's tree
+    /// - Onto main, the pick conflicts on the shared file
+    /// - The checked-out branch is left unchanged
+    #[test]
+    fn test_simulate_cherry_pick() {
+        let (_temp_dir, repo_path) = setup_test_repo();
+
+        std::fs::write(repo_path.join("conflict.txt"), "original content").unwrap();
+        create_commit_with_message(&repo_path, "Initial commit with file");
+        let base_tree = tree_id(&repo_path, "HEAD").unwrap();
+
+        Command::new("git")
+            .current_dir(&repo_path)
+            .args(["checkout", "-b", "feature"])
+            .output()
+            .unwrap();
+        std::fs::write(repo_path.join("conflict.txt"), "feature content").unwrap();
+        create_commit_with_message(&repo_path, "Feature commit");
+        let feature_hash = head_commit(&repo_path).unwrap();
+
+        Command::new("git")
+            .current_dir(&repo_path)
+            .args(["checkout", "main"])
+            .output()
+            .unwrap();
+        std::fs::write(repo_path.join("conflict.txt"), "main content").unwrap();
+        create_commit_with_message(&repo_path, "Main commit");
+        let main_head = head_commit(&repo_path).unwrap();
+
+        assert_eq!(
+            simulate_cherry_pick(&repo_path, &base_tree, &feature_hash).unwrap(),
+            SimulatedCherryPick::Clean {
+                tree: tree_id(&repo_path, &feature_hash).unwrap()
+            }
+        );
+
+        let main_tree = tree_id(&repo_path, "main").unwrap();
+        assert_eq!(
+            simulate_cherry_pick(&repo_path, &main_tree, &feature_hash).unwrap(),
+            SimulatedCherryPick::Conflict {
+                files: vec!["conflict.txt".to_string()]
+            }
+        );
+        assert_eq!(head_commit(&repo_path).unwrap(), main_head);
+    }
+
     /// # Cherry Pick Merge Commit Success
     ///
     /// Tests that cherry-picking a merge commit works correctly with the -m flag.
@@ -2716,7 +4383,7 @@ mod tests {
         );
 
         // Cherry-pick the merge commit (this should use -m 1 internally)
-        let result = cherry_pick_commit(&repo_path, &merge_hash);
+        let result = cherry_pick_commit(&repo_path, &merge_hash, false);
         assert!(result.is_ok(), "Cherry-pick should not error");
 
         match result.unwrap() {
@@ -2744,6 +4411,78 @@ mod tests {
         );
     }
 
+    /// # Cherry Pick With Parent Fallback
+    ///
+    /// Tests retrying a merge commit with reversed parents.
+    ///
+    /// ## Test Scenario
+    /// - Merges `dev` into `feature`, so the merge commit's first parent is
+    ///   the feature branch and `-m 1` applies the dev changes
+    /// - Cherry-picks it onto a branch whose `dev.txt` conflicts with them,
+    ///   first without and then with the fallback
+    /// - Cherry-picks a regular commit onto the same conflict
+    ///
+    /// ## Expected Outcome
+    /// - Without the fallback the conflict of parent 1 is reported
+    /// - With it the merge commit applies with parent 2, bringing in
+    ///   `feature.txt`
+    /// - The regular commit is not retried and reports the conflict
+    #[test]
+    fn test_cherry_pick_with_parent_fallback() {
+        let (_temp_dir, repo_path) = setup_test_repo();
+        let git = |args: &[&str]| {
+            let output = Command::new("git")
+                .current_dir(&repo_path)
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(
+                output.status.success(),
+                "git {:?} failed: {}",
+                args,
+                String::from_utf8_lossy(&output.stderr)
+            );
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+        let commit_file = |name: &str, content: &str| {
+            std::fs::write(repo_path.join(name), content).unwrap();
+            git(&["add", name]);
+            git(&["commit", "-m", name]);
+            git(&["rev-parse", "HEAD"])
+        };
+
+        commit_file("base.txt", "base");
+        git(&["checkout", "-b", "dev"]);
+        let dev_commit = commit_file("dev.txt", "dev");
+        git(&["checkout", "-b", "feature", "main"]);
+        commit_file("feature.txt", "feature");
+        git(&["merge", "--no-ff", "-m", "Merged PR 9: Feature", "dev"]);
+        let merge = git(&["rev-parse", "HEAD"]);
+
+        git(&["checkout", "-b", "target", "main"]);
+        commit_file("dev.txt", "conflicting");
+
+        let (result, mainline) =
+            cherry_pick_with_parent_fallback(&repo_path, &merge, false, false).unwrap();
+        assert!(matches!(result, CherryPickResult::Conflict(_)));
+        assert_eq!(mainline, 1);
+        abort_cherry_pick(&repo_path).unwrap();
+
+        let (result, mainline) =
+            cherry_pick_with_parent_fallback(&repo_path, &merge, false, true).unwrap();
+        assert!(matches!(result, CherryPickResult::Success));
+        assert_eq!(mainline, 2);
+        assert_eq!(
+            std::fs::read_to_string(repo_path.join("feature.txt")).unwrap(),
+            "feature"
+        );
+
+        let (result, mainline) =
+            cherry_pick_with_parent_fallback(&repo_path, &dev_commit, false, true).unwrap();
+        assert!(matches!(result, CherryPickResult::Conflict(_)));
+        assert_eq!(mainline, 1);
+    }
+
     /// # Cherry Pick Merge Commit With Conflict
     ///
     /// Tests that cherry-picking a merge commit correctly detects conflicts.
@@ -2815,7 +4554,7 @@ mod tests {
         create_commit_with_message(&repo_path, "Target conflicting commit");
 
         // Try to cherry-pick the merge commit - should detect conflict
-        let result = cherry_pick_commit(&repo_path, &merge_hash);
+        let result = cherry_pick_commit(&repo_path, &merge_hash, false);
         assert!(result.is_ok(), "Cherry-pick should not error");
 
         match result.unwrap() {
@@ -3061,73 +4800,357 @@ mod tests {
             commit_bodies: vec![],
         };
 
-        // Test various PR ID formats
-        assert!(search_pr_id_in_history(123, &history));
-        assert!(search_pr_id_in_history(456, &history));
-        assert!(search_pr_id_in_history(789, &history));
-        assert!(search_pr_id_in_history(321, &history));
-        assert!(search_pr_id_in_history(654, &history));
+        // Test various PR ID formats
+        assert!(search_pr_id_in_history(123, &history));
+        assert!(search_pr_id_in_history(456, &history));
+        assert!(search_pr_id_in_history(789, &history));
+        assert!(search_pr_id_in_history(321, &history));
+        assert!(search_pr_id_in_history(654, &history));
+
+        // Test non-existent PR ID
+        assert!(!search_pr_id_in_history(999, &history));
+    }
+
+    /// # List Local Branches
+    ///
+    /// Tests listing local branches matching a pattern.
+    ///
+    /// ## Test Scenario
+    /// - Creates repository with various branches
+    /// - Tests pattern matching for branch listing
+    ///
+    /// ## Expected Outcome
+    /// - Correctly lists branches matching the pattern
+    /// - Filters out non-matching branches
+    #[test]
+    fn test_list_local_branches() {
+        let (_temp_dir, repo_path) = setup_test_repo();
+
+        // Create initial commit
+        fs::write(repo_path.join("test.txt"), "initial").unwrap();
+        Command::new("git")
+            .current_dir(&repo_path)
+            .args(["add", "."])
+            .output()
+            .unwrap();
+        Command::new("git")
+            .current_dir(&repo_path)
+            .args(["commit", "-m", "Initial commit"])
+            .output()
+            .unwrap();
+
+        // Create various branches
+        for branch in &[
+            "feature/test",
+            "patch/next-1.0.0",
+            "patch/main-2.0.0",
+            "bugfix/issue",
+        ] {
+            Command::new("git")
+                .current_dir(&repo_path)
+                .args(["branch", branch])
+                .output()
+                .unwrap();
+        }
+
+        // Test listing all branches
+        let all_branches = list_local_branches(&repo_path, "*").unwrap();
+        assert!(all_branches.len() >= 5); // main + 4 created branches
+
+        // Test listing patch branches only
+        let patch_branches = list_local_branches(&repo_path, "patch/*").unwrap();
+        assert_eq!(patch_branches.len(), 2);
+        assert!(patch_branches.contains(&"patch/next-1.0.0".to_string()));
+        assert!(patch_branches.contains(&"patch/main-2.0.0".to_string()));
+
+        // Test listing feature branches
+        let feature_branches = list_local_branches(&repo_path, "feature/*").unwrap();
+        assert_eq!(feature_branches.len(), 1);
+        assert!(feature_branches.contains(&"feature/test".to_string()));
+    }
+
+    /// # Delete Branch
+    ///
+    /// Tests deleting a branch and recording its tip.
+    ///
+    /// ## Test Scenario
+    /// - Creates a patch branch and deletes it
+    /// - Restores it from the returned tip
+    /// - Deletes a branch that does not exist
+    ///
+    /// ## Expected Outcome
+    /// - The returned tip is the commit the branch pointed to
+    /// - `git branch <name> <sha>` restores the deleted branch
+    /// - A missing branch counts as already deleted, without a tip
+    #[test]
+    fn test_delete_branch() {
+        let (_temp_dir, repo_path) = setup_test_repo();
+
+        fs::write(repo_path.join("test.txt"), "initial").unwrap();
+        Command::new("git")
+            .current_dir(&repo_path)
+            .args(["add", "."])
+            .output()
+            .unwrap();
+        Command::new("git")
+            .current_dir(&repo_path)
+            .args(["commit", "-m", "Initial commit"])
+            .output()
+            .unwrap();
+        Command::new("git")
+            .current_dir(&repo_path)
+            .args(["branch", "patch/main-1.0.0"])
+            .output()
+            .unwrap();
+
+        let head = head_commit(&repo_path).unwrap();
+        let tip = delete_branch(&repo_path, "patch/main-1.0.0")
+            .unwrap()
+            .unwrap();
+        assert_eq!(tip, head);
+        assert!(!branch_exists(&repo_path, "patch/main-1.0.0").unwrap());
+
+        Command::new("git")
+            .current_dir(&repo_path)
+            .args(["branch", "patch/main-1.0.0", &tip])
+            .output()
+            .unwrap();
+        assert!(branch_exists(&repo_path, "patch/main-1.0.0").unwrap());
+
+        assert_eq!(delete_branch(&repo_path, "patch/main-9.9.9").unwrap(), None);
+    }
+
+    /// # Base Tag
+    ///
+    /// Tests tagging the starting point of a patch branch.
+    ///
+    /// ## Test Scenario
+    /// - Tags HEAD as the base of a version, commits, and tags again
+    /// - Tags the same version into another target
+    /// - Deletes the first tag twice
+    ///
+    /// ## Expected Outcome
+    /// - The tag is `mergers/base-<target>-<version>` at HEAD and is moved by
+    ///   a later run
+    /// - Each target of the same version has its own tag
+    /// - Deleting reports whether a tag existed
+    #[test]
+    fn test_base_tag() {
+        let (_temp_dir, repo_path) = setup_test_repo();
+        let commit = |message: &str| {
+            fs::write(repo_path.join("test.txt"), message).unwrap();
+            Command::new("git")
+                .current_dir(&repo_path)
+                .args(["commit", "-am", message])
+                .output()
+                .unwrap();
+        };
+        fs::write(repo_path.join("test.txt"), "initial").unwrap();
+        Command::new("git")
+            .current_dir(&repo_path)
+            .args(["add", "."])
+            .output()
+            .unwrap();
+        commit("Initial commit");
+
+        let tag_commit = |tag: &str| {
+            let output = Command::new("git")
+                .current_dir(&repo_path)
+                .args(["rev-parse", &format!("{}^{{commit}}", tag)])
+                .output()
+                .unwrap();
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+
+        let tag = create_base_tag(&repo_path, "main", "1.2.3").unwrap();
+        assert_eq!(tag, "mergers/base-main-1.2.3");
+        assert_eq!(tag_commit(&tag), head_commit(&repo_path).unwrap());
+
+        commit("Second commit");
+        create_base_tag(&repo_path, "main", "1.2.3").unwrap();
+        assert_eq!(tag_commit(&tag), head_commit(&repo_path).unwrap());
+
+        // The same version into another target gets its own tag
+        let next = create_base_tag(&repo_path, "next", "1.2.3").unwrap();
+        assert_eq!(next, "mergers/base-next-1.2.3");
+        assert!(delete_base_tag(&repo_path, "main", "1.2.3").unwrap());
+        assert!(!delete_base_tag(&repo_path, "main", "1.2.3").unwrap());
+        assert_eq!(tag_commit(&next), head_commit(&repo_path).unwrap());
+        assert!(delete_base_tag(&repo_path, "next", "1.2.3").unwrap());
+    }
+
+    /// # Release Tag
+    ///
+    /// Tests creating and pushing the annotated release tag of a version.
+    ///
+    /// ## Test Scenario
+    /// - Tags HEAD as `v1.2.3` with a markdown message and pushes it
+    /// - Tags the same version again
+    ///
+    /// ## Expected Outcome
+    /// - The tag is annotated, keeps the message verbatim and reaches origin
+    /// - An existing tag is not moved
+    #[test]
+    fn test_release_tag() {
+        let (_test_dir, repo_path, _origin_dir, origin_path) = setup_test_repo_with_origin();
+        assert_eq!(release_tag_name("1.2.3"), "v1.2.3");
+        assert_eq!(release_tag_name("v1.2.3"), "v1.2.3");
+        Command::new("git")
+            .current_dir(&repo_path)
+            .args(["checkout", "-b", "patch/main-1.2.3", "origin/main"])
+            .output()
+            .unwrap();
+
+        let message = "# Release Notes - 1.2.3\n\n- Fix login bug\n";
+        create_annotated_tag(&repo_path, "v1.2.3", message, false).unwrap();
+        let output = Command::new("git")
+            .current_dir(&repo_path)
+            .args([
+                "tag",
+                "--list",
+                "--format=%(objecttype)%0a%(contents)",
+                "v1.2.3",
+            ])
+            .output()
+            .unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            format!("tag\n{}\n", message)
+        );
+
+        push_tag(&repo_path, "v1.2.3").unwrap();
+        let output = Command::new("git")
+            .current_dir(&origin_path)
+            .args(["tag", "--list", "v1.2.3"])
+            .output()
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "v1.2.3");
+
+        let error = create_annotated_tag(&repo_path, "v1.2.3", message, false).unwrap_err();
+        assert!(error.to_string().contains("already exists"));
+    }
+
+    /// # Signing Failure
+    ///
+    /// Tests cherry-picking and tagging with signing enabled when git cannot
+    /// sign.
+    ///
+    /// ## Test Scenario
+    /// - Points `gpg.program` at a command that always fails
+    /// - Cherry-picks a feature commit and creates a release tag, both signed
+    /// - Cherry-picks the commit again without signing
+    ///
+    /// ## Expected Outcome
+    /// - Both signed operations fail with `GitError::SigningFailed`
+    /// - The failed cherry-pick is aborted rather than left half-applied
+    /// - Without signing the same commit is picked
+    #[test]
+    fn test_signing_failure() {
+        let (_temp_dir, repo_path) = setup_test_repo();
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .current_dir(&repo_path)
+                .args(args)
+                .output()
+                .unwrap()
+        };
+        create_commit_with_message(&repo_path, "Initial commit");
+        git(&["checkout", "-b", "feature"]);
+        create_commit_with_message(&repo_path, "Feature commit");
+        let commit_hash = head_commit(&repo_path).unwrap();
+        git(&["checkout", "main"]);
+        git(&["config", "gpg.program", "false"]);
+
+        let error = cherry_pick_commit(&repo_path, &commit_hash, true).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<GitError>(),
+            Some(GitError::SigningFailed { .. })
+        ));
+        assert!(!cherry_pick_in_progress(&repo_path));
+        assert!(!has_uncommitted_changes(&repo_path).unwrap());
+
+        let error = create_annotated_tag(&repo_path, "v1.2.3", "notes", true).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<GitError>(),
+            Some(GitError::SigningFailed { .. })
+        ));
 
-        // Test non-existent PR ID
-        assert!(!search_pr_id_in_history(999, &history));
+        assert!(matches!(
+            cherry_pick_commit(&repo_path, &commit_hash, false).unwrap(),
+            CherryPickResult::Success
+        ));
+        assert_eq!(
+            continue_cherry_pick_args(false, true),
+            [
+                "-c",
+                "commit.gpgSign=true",
+                "cherry-pick",
+                "--continue",
+                "--no-edit"
+            ]
+        );
     }
 
-    /// # List Local Branches
+    /// # Squash Commits
     ///
-    /// Tests listing local branches matching a pattern.
+    /// Tests squashing the commits on top of a base into one commit.
     ///
     /// ## Test Scenario
-    /// - Creates repository with various branches
-    /// - Tests pattern matching for branch listing
+    /// - Creates two commits on top of the initial commit, the first one by
+    ///   another author at a fixed date
+    /// - Squashes them onto the initial commit
     ///
     /// ## Expected Outcome
-    /// - Correctly lists branches matching the pattern
-    /// - Filters out non-matching branches
+    /// - One commit with the given message sits on top of the base
+    /// - It keeps the author and author date of the first commit
+    /// - The working tree is clean
     #[test]
-    fn test_list_local_branches() {
+    fn test_squash_commits() {
         let (_temp_dir, repo_path) = setup_test_repo();
-
-        // Create initial commit
-        fs::write(repo_path.join("test.txt"), "initial").unwrap();
-        Command::new("git")
+        create_commit_with_message(&repo_path, "Initial commit");
+        let base = head_commit(&repo_path).unwrap();
+        fs::write(repo_path.join("fix.txt"), "fix").unwrap();
+        let output = Command::new("git")
             .current_dir(&repo_path)
             .args(["add", "."])
             .output()
             .unwrap();
-        Command::new("git")
+        assert!(output.status.success());
+        let output = Command::new("git")
             .current_dir(&repo_path)
-            .args(["commit", "-m", "Initial commit"])
+            .args([
+                "commit",
+                "--author=Jane Doe <jane@example.com>",
+                "--date=2024-03-01T10:00:00+01:00",
+                "-m",
+                "Fix login",
+            ])
             .output()
             .unwrap();
+        assert!(output.status.success());
+        create_commit_with_message(&repo_path, "Resolve conflicts");
 
-        // Create various branches
-        for branch in &[
-            "feature/test",
-            "patch/next-1.0.0",
-            "patch/main-2.0.0",
-            "bugfix/issue",
-        ] {
-            Command::new("git")
-                .current_dir(&repo_path)
-                .args(["branch", branch])
-                .output()
-                .unwrap();
-        }
-
-        // Test listing all branches
-        let all_branches = list_local_branches(&repo_path, "*").unwrap();
-        assert!(all_branches.len() >= 5); // main + 4 created branches
-
-        // Test listing patch branches only
-        let patch_branches = list_local_branches(&repo_path, "patch/*").unwrap();
-        assert_eq!(patch_branches.len(), 2);
-        assert!(patch_branches.contains(&"patch/next-1.0.0".to_string()));
-        assert!(patch_branches.contains(&"patch/main-2.0.0".to_string()));
+        squash_commits(&repo_path, &base, "Merged PR 42: Fix login", false).unwrap();
 
-        // Test listing feature branches
-        let feature_branches = list_local_branches(&repo_path, "feature/*").unwrap();
-        assert_eq!(feature_branches.len(), 1);
-        assert!(feature_branches.contains(&"feature/test".to_string()));
+        let output = Command::new("git")
+            .current_dir(&repo_path)
+            .args(["log", "--format=%s", &format!("{}..HEAD", base)])
+            .output()
+            .unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout).trim(),
+            "Merged PR 42: Fix login"
+        );
+        let output = Command::new("git")
+            .current_dir(&repo_path)
+            .args(["log", "-1", "--format=%an <%ae> %aI"])
+            .output()
+            .unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout).trim(),
+            "Jane Doe <jane@example.com> 2024-03-01T10:00:00+01:00"
+        );
+        assert!(!has_uncommitted_changes(&repo_path).unwrap());
     }
 
     /// # List Patch Branches
@@ -3841,6 +5864,182 @@ mod tests {
         );
     }
 
+    /// # Check Patch Merged Against Origin
+    ///
+    /// Tests that a stale or unpushed local target is caught by checking the
+    /// remote-tracking ref.
+    ///
+    /// ## Test Scenario
+    /// - Merges a patch branch into local main without pushing
+    /// - Checks it against local main and origin/main, then pushes and checks
+    ///   again
+    /// - Asks for the remote ref in a repository without origin
+    ///
+    /// ## Expected Outcome
+    /// - Merged locally but not on origin until main is pushed
+    /// - No remote ref without an origin remote
+    #[test]
+    fn test_check_patch_merged_on_origin() {
+        let (_test_dir, repo_path, _origin_dir, _origin_path) = setup_test_repo_with_origin();
+        let git = |args: &[&str]| {
+            let output = Command::new("git")
+                .current_dir(&repo_path)
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(
+                output.status.success(),
+                "git {:?} failed: {}",
+                args,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        };
+
+        git(&["checkout", "main"]);
+        git(&["checkout", "-b", "patch/main-1.0.0"]);
+        create_commit_with_message(&repo_path, "Patch commit");
+        git(&["checkout", "main"]);
+        git(&["merge", "patch/main-1.0.0", "--no-ff", "-m", "Merge patch"]);
+
+        assert!(check_patch_merged(&repo_path, "patch/main-1.0.0", "main").unwrap());
+        let remote_ref = fetch_remote_tracking_ref(&repo_path, "main")
+            .unwrap()
+            .expect("origin/main exists");
+        assert_eq!(remote_ref, "origin/main");
+        let remote_merged = |remote_ref: &str| {
+            let history = get_target_branch_history(&repo_path, remote_ref).unwrap();
+            check_patch_merged_on_remote(&repo_path, "patch/main-1.0.0", remote_ref, &history)
+                .unwrap()
+        };
+        assert!(!remote_merged(&remote_ref));
+
+        git(&["push", "origin", "main"]);
+        let remote_ref = fetch_remote_tracking_ref(&repo_path, "main")
+            .unwrap()
+            .unwrap();
+        assert!(remote_merged(&remote_ref));
+
+        let (_temp_dir, local_only) = setup_test_repo();
+        assert_eq!(
+            fetch_remote_tracking_ref(&local_only, "main").unwrap(),
+            None
+        );
+    }
+
+    /// # Target Divergence and Rebase
+    ///
+    /// Tests detecting that the remote target moved during a session and
+    /// rebasing the patch branch onto its new tip.
+    ///
+    /// ## Test Scenario
+    /// - Creates a patch branch from main with one commit
+    /// - Pushes a new commit to origin/main, then rebases the patch branch
+    ///
+    /// ## Expected Outcome
+    /// - No divergence before the push, one commit behind after it
+    /// - No divergence after the rebase, with the patch commit kept on top
+    #[test]
+    fn test_check_target_divergence_and_rebase() {
+        let (_test_dir, repo_path, _origin_dir, _origin_path) = setup_test_repo_with_origin();
+        let git = |args: &[&str]| {
+            let output = Command::new("git")
+                .current_dir(&repo_path)
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(
+                output.status.success(),
+                "git {:?} failed: {}",
+                args,
+                String::from_utf8_lossy(&output.stderr)
+            );
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+
+        git(&["checkout", "main"]);
+        git(&["checkout", "-b", "patch/main-1.0.0"]);
+        create_commit_with_message(&repo_path, "Patch commit");
+        assert_eq!(check_target_divergence(&repo_path, "main").unwrap(), None);
+
+        git(&["checkout", "main"]);
+        create_commit_with_message(&repo_path, "Landed meanwhile");
+        git(&["push", "origin", "main"]);
+        git(&["checkout", "patch/main-1.0.0"]);
+
+        let divergence = check_target_divergence(&repo_path, "main")
+            .unwrap()
+            .expect("origin/main moved");
+        assert_eq!(divergence.remote_ref, "origin/main");
+        assert_eq!(divergence.behind, 1);
+
+        rebase_onto(&repo_path, &divergence.remote_ref).unwrap();
+        assert_eq!(check_target_divergence(&repo_path, "main").unwrap(), None);
+        assert_eq!(git(&["log", "-1", "--format=%s"]), "Patch commit");
+    }
+
+    /// # Branch Log Patch-Ids
+    ///
+    /// Tests matching a cherry-picked PR merge to its source via patch-id.
+    ///
+    /// ## Test Scenario
+    /// - Merges a feature branch into dev with a merge commit
+    /// - Cherry-picks the merge with `-m 1` onto a patch branch from main
+    ///
+    /// ## Expected Outcome
+    /// - The merge commit and the pick share a patch-id
+    /// - The branch log lists the pick first and ends at the merge base
+    #[test]
+    fn test_branch_log_patch_ids() {
+        let (_temp_dir, repo_path) = setup_test_repo();
+        let git = |args: &[&str]| {
+            let output = Command::new("git")
+                .current_dir(&repo_path)
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(
+                output.status.success(),
+                "git {:?} failed: {}",
+                args,
+                String::from_utf8_lossy(&output.stderr)
+            );
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+
+        create_commit_with_message(&repo_path, "Initial commit");
+        let base = git(&["rev-parse", "HEAD"]);
+        git(&["checkout", "-b", "dev"]);
+        git(&["checkout", "-b", "feature"]);
+        create_commit_with_message(&repo_path, "Feature work");
+        git(&["checkout", "dev"]);
+        git(&["merge", "--no-ff", "-m", "Merged PR 7: Feature", "feature"]);
+        let merge = git(&["rev-parse", "HEAD"]);
+
+        git(&["checkout", "-b", "patch", "main"]);
+        git(&["cherry-pick", "-m", "1", &merge]);
+        let pick = git(&["rev-parse", "HEAD"]);
+
+        let ids = patch_ids(&repo_path, &[merge.clone(), pick.clone()]).unwrap();
+        assert!(ids.contains_key(&merge));
+        assert_eq!(ids.get(&merge), ids.get(&pick));
+
+        assert_eq!(
+            merge_base(&repo_path, "patch", "main").unwrap(),
+            Some(base.clone())
+        );
+        let log = branch_log(&repo_path, "patch", 10).unwrap();
+        assert_eq!(
+            log.first().map(|e| e.commit_id.as_str()),
+            Some(pick.as_str())
+        );
+        assert_eq!(log[0].subject, "Merged PR 7: Feature");
+        assert_eq!(log[0].patch_id, ids.get(&merge).cloned());
+        assert_eq!(
+            log.last().map(|e| e.commit_id.as_str()),
+            Some(base.as_str())
+        );
+    }
+
     /// # Validate Git Reference (Valid References)
     ///
     /// Tests that valid git references pass validation.
@@ -4054,6 +6253,51 @@ mod tests {
         );
     }
 
+    /// # Cleanup Cherry-Pick of a Fan-out Target
+    ///
+    /// Tests cleanup of a worktree named after its target branch.
+    ///
+    /// ## Test Scenario
+    /// - Creates worktrees of the same version for two target branches,
+    ///   named as in a fan-out
+    /// - Runs cleanup_cherry_pick for the first one
+    ///
+    /// ## Expected Outcome
+    /// - Only the cleaned up target's worktree and patch branch are removed
+    #[test]
+    fn test_cleanup_cherry_pick_fan_out_worktree() {
+        let (_test_dir, repo_path, _origin_dir, _origin_path) = setup_test_repo_with_origin();
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .current_dir(&repo_path)
+                .args(args)
+                .output()
+                .unwrap();
+        };
+        for target in ["release-a", "release-b"] {
+            git(&["checkout", "-b", target]);
+            create_commit_with_message(&repo_path, &format!("{} commit", target));
+            git(&["push", "-u", "origin", target]);
+            git(&["checkout", "main"]);
+        }
+
+        let mut worktrees = Vec::new();
+        for target in ["release-a", "release-b"] {
+            let version = crate::core::operations::fan_out::worktree_version("1.0.0", target);
+            let worktree_path = create_worktree(&repo_path, target, &version, false).unwrap();
+            create_branch(&worktree_path, &format!("patch/{}-1.0.0", target)).unwrap();
+            worktrees.push(worktree_path);
+        }
+        assert!(worktrees[0].ends_with("next-1.0.0-release-a"));
+
+        cleanup_cherry_pick(Some(&repo_path), &worktrees[0], "1.0.0", "release-a").unwrap();
+
+        assert!(!worktrees[0].exists(), "Worktree should be removed");
+        assert!(!branch_exists(&repo_path, "patch/release-a-1.0.0").unwrap());
+        assert!(worktrees[1].exists(), "Other target should be kept");
+        assert!(branch_exists(&repo_path, "patch/release-b-1.0.0").unwrap());
+    }
+
     /// # Cleanup Cherry-Pick Without Worktree
     ///
     /// Tests cleanup of a cherry-pick operation in a cloned repository (no worktree).
@@ -4200,6 +6444,109 @@ mod tests {
         );
     }
 
+    /// # Abort Keeping Applied Commits
+    ///
+    /// Tests aborting a cherry-pick process without losing finished picks.
+    ///
+    /// ## Test Scenario
+    /// - Commits a resolved pick on the patch branch of a cloned repo
+    /// - Starts a cherry-pick that conflicts
+    /// - Aborts, keeping the applied commits
+    ///
+    /// ## Expected Outcome
+    /// - The conflicting cherry-pick is rolled back
+    /// - `patch/main-v1.0.0-partial` points to the last applied commit and is
+    ///   checked out
+    /// - The patch branch is deleted
+    #[test]
+    fn test_abort_keeping_applied() {
+        let (_temp_dir, repo_path) = setup_test_repo();
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .current_dir(&repo_path)
+                .args(args)
+                .output()
+                .unwrap()
+        };
+
+        std::fs::write(repo_path.join("conflict.txt"), "original content").unwrap();
+        create_commit_with_message(&repo_path, "Initial commit with file");
+        git(&["checkout", "-b", "feature"]);
+        std::fs::write(repo_path.join("conflict.txt"), "feature content").unwrap();
+        create_commit_with_message(&repo_path, "Feature commit");
+        let feature_hash = head_commit(&repo_path).unwrap();
+
+        git(&["checkout", "main"]);
+        git(&["checkout", "-b", "patch/main-v1.0.0"]);
+        std::fs::write(repo_path.join("conflict.txt"), "resolved content").unwrap();
+        create_commit_with_message(&repo_path, "Resolved pick");
+        let applied = head_commit(&repo_path).unwrap();
+        git(&["cherry-pick", &feature_hash]);
+        assert!(repo_path.join(".git/CHERRY_PICK_HEAD").exists());
+
+        let tip = abort_keeping_applied(None, &repo_path, "v1.0.0", "main").unwrap();
+
+        assert_eq!(tip, applied);
+        assert!(!repo_path.join(".git/CHERRY_PICK_HEAD").exists());
+        let current = git(&["branch", "--show-current"]);
+        assert_eq!(
+            String::from_utf8_lossy(&current.stdout).trim(),
+            "patch/main-v1.0.0-partial"
+        );
+        assert_eq!(head_commit(&repo_path).unwrap(), applied);
+        assert!(!branch_exists(&repo_path, "patch/main-v1.0.0").unwrap());
+    }
+
+    /// # Abort Keeping Applied Commits With Existing Rescue Branch
+    ///
+    /// Tests that a selective abort never overwrites an earlier rescue branch.
+    ///
+    /// ## Test Scenario
+    /// - Creates `patch/main-v1.0.0-partial` from an earlier abort
+    /// - Starts a cherry-pick that conflicts on the patch branch
+    /// - Aborts, keeping the applied commits
+    ///
+    /// ## Expected Outcome
+    /// - The abort fails, naming the existing branch
+    /// - The rescue branch, the patch branch and the cherry-pick are untouched
+    #[test]
+    fn test_abort_keeping_applied_existing_branch() {
+        let (_temp_dir, repo_path) = setup_test_repo();
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .current_dir(&repo_path)
+                .args(args)
+                .output()
+                .unwrap()
+        };
+
+        std::fs::write(repo_path.join("conflict.txt"), "original content").unwrap();
+        create_commit_with_message(&repo_path, "Initial commit with file");
+        let earlier = head_commit(&repo_path).unwrap();
+        git(&["branch", "patch/main-v1.0.0-partial"]);
+        git(&["checkout", "-b", "feature"]);
+        std::fs::write(repo_path.join("conflict.txt"), "feature content").unwrap();
+        create_commit_with_message(&repo_path, "Feature commit");
+        let feature_hash = head_commit(&repo_path).unwrap();
+
+        git(&["checkout", "main"]);
+        git(&["checkout", "-b", "patch/main-v1.0.0"]);
+        std::fs::write(repo_path.join("conflict.txt"), "resolved content").unwrap();
+        create_commit_with_message(&repo_path, "Resolved pick");
+        git(&["cherry-pick", &feature_hash]);
+
+        let err = abort_keeping_applied(None, &repo_path, "v1.0.0", "main").unwrap_err();
+
+        assert!(
+            err.to_string()
+                .contains("patch/main-v1.0.0-partial already exists")
+        );
+        assert!(repo_path.join(".git/CHERRY_PICK_HEAD").exists());
+        assert!(branch_exists(&repo_path, "patch/main-v1.0.0").unwrap());
+        let partial = git(&["rev-parse", "patch/main-v1.0.0-partial"]);
+        assert_eq!(String::from_utf8_lossy(&partial.stdout).trim(), earlier);
+    }
+
     /// # Continue Cherry Pick With Empty Commit
     ///
     /// Tests that continue_cherry_pick handles empty commits correctly.
@@ -4251,7 +6598,7 @@ mod tests {
 
         // Try to cherry-pick - this will conflict because both modified the same file
         // even though they have the same content
-        let result = cherry_pick_commit(&repo_path, &feature_hash);
+        let result = cherry_pick_commit(&repo_path, &feature_hash, false);
         assert!(result.is_ok());
 
         // Check what kind of result we got
@@ -4273,7 +6620,7 @@ mod tests {
                     .unwrap();
 
                 // Now continue - this is where we test the empty commit handling
-                let continue_result = continue_cherry_pick(&repo_path);
+                let continue_result = continue_cherry_pick(&repo_path, false);
                 assert!(
                     continue_result.is_ok(),
                     "continue_cherry_pick should succeed with empty commit: {:?}",
@@ -4347,7 +6694,7 @@ mod tests {
         create_commit_with_message(&repo_path, "Also update to version 2");
 
         // Cherry-pick should succeed with --allow-empty (already in cherry_pick_commit)
-        let result = cherry_pick_commit(&repo_path, &feature_hash);
+        let result = cherry_pick_commit(&repo_path, &feature_hash, false);
         assert!(result.is_ok(), "Cherry-pick should not error: {:?}", result);
 
         // The result should be Success (--allow-empty allows the empty commit)
@@ -4571,4 +6918,104 @@ mod tests {
         // Verify non-existent branches are not detected
         assert!(!branch_exists(&repo_path, "patch/nonexistent-v1.0.0").unwrap());
     }
+
+    /// # Remote Branch Exists
+    ///
+    /// Tests looking up branches on a remote by name and by URL.
+    ///
+    /// ## Test Scenario
+    /// - Pushes `patch/main-v1.0.0-rc1` to origin
+    /// - Queries it and a missing branch through `origin` and the origin path
+    /// - Queries an unreachable remote
+    ///
+    /// ## Expected Outcome
+    /// - The pushed branch exists, the missing one does not
+    /// - The unreachable remote returns an error
+    #[test]
+    fn test_remote_branch_exists() {
+        let (_test_dir, repo_path, _origin_dir, origin_path) = setup_test_repo_with_origin();
+        let push = Command::new("git")
+            .current_dir(&repo_path)
+            .args([
+                "push",
+                "origin",
+                "origin/main:refs/heads/patch/main-v1.0.0-rc1",
+            ])
+            .output()
+            .unwrap();
+        assert!(
+            push.status.success(),
+            "Git push failed: {}",
+            String::from_utf8_lossy(&push.stderr)
+        );
+
+        let origin = origin_path.to_str().unwrap();
+        assert!(remote_branch_exists(Some(&repo_path), "origin", "patch/main-v1.0.0-rc1").unwrap());
+        assert!(
+            !remote_branch_exists(Some(&repo_path), "origin", "patch/main-v1.0.0-rc2").unwrap()
+        );
+        assert!(remote_branch_exists(None, origin, "patch/main-v1.0.0-rc1").unwrap());
+        assert!(!remote_branch_exists(None, origin, "patch/main-v1.0.0-rc2").unwrap());
+
+        let missing = origin_path.join("missing");
+        assert!(remote_branch_exists(None, missing.to_str().unwrap(), "main").is_err());
+    }
+
+    /// # Diff Statistics
+    ///
+    /// Tests summing the diff size of several commits.
+    ///
+    /// ## Test Scenario
+    /// - Creates a commit adding a two-line file and a `--no-ff` merge of a
+    ///   branch adding a one-line file
+    /// - Sums both commits, then no commits
+    ///
+    /// ## Expected Outcome
+    /// - The merge counts only its first-parent diff
+    /// - Totals cover both commits; no commits give an empty stat
+    #[test]
+    fn test_diff_stat() {
+        let (_temp_dir, repo_path) = setup_test_repo();
+        let git = |args: &[&str]| {
+            let output = Command::new("git")
+                .current_dir(&repo_path)
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(
+                output.status.success(),
+                "git {:?} failed: {}",
+                args,
+                String::from_utf8_lossy(&output.stderr)
+            );
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+
+        create_commit_with_message(&repo_path, "Initial commit");
+        fs::write(repo_path.join("two.txt"), "one\ntwo\n").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-m", "Two lines"]);
+        let two_lines = git(&["rev-parse", "HEAD"]);
+
+        git(&["checkout", "-b", "feature"]);
+        create_commit_with_message(&repo_path, "Feature work");
+        git(&["checkout", "-"]);
+        git(&["merge", "--no-ff", "-m", "Merged PR 7: Feature", "feature"]);
+        let merge = git(&["rev-parse", "HEAD"]);
+
+        let stat = diff_stat(&repo_path, std::slice::from_ref(&merge)).unwrap();
+        assert_eq!(
+            stat,
+            DiffStat {
+                files: 1,
+                insertions: 1,
+                deletions: 0
+            }
+        );
+
+        let stat = diff_stat(&repo_path, &[two_lines, merge]).unwrap();
+        assert_eq!(stat.files, 2);
+        assert_eq!(stat.insertions, 3);
+        assert_eq!(diff_stat(&repo_path, &[]).unwrap(), DiffStat::default());
+    }
 }
@@ -7,7 +7,7 @@
 //! - Configuration management
 //! - Git operations and analysis
 //! - Pull request migration analysis
-//! - Terminal UI for interactive workflows
+//! - Terminal UI for interactive workflows (`tui` feature)
 //!
 //! ## Quick Start
 //!
@@ -30,30 +30,56 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Cargo Features
+//!
+//! All features are enabled by default. Crates embedding only the API
+//! client, configuration or release notes logic can turn them off:
+//!
+//! - `cli`: command-line arguments ([`Args`], [`Commands`]) and
+//!   [`logging`] setup; pulls in `clap` and `tracing-subscriber`
+//! - `tui`: the terminal UI ([`ui`]); pulls in `ratatui` and `crossterm`
+//! - `clipboard`: the system clipboard for `--copy`; without it, copies
+//!   fall back to OSC 52 or a file
+//! - `testing`: test helpers for embedded UI states (off by default)
+//!
+//! ```toml
+//! mergers = { version = "1", default-features = false }
+//! ```
 
 pub mod api;
+pub mod cache;
 pub mod config;
 pub mod core;
 pub mod error;
 pub mod git;
 pub mod git_config;
+#[cfg(feature = "cli")]
 pub mod logging;
 pub mod migration;
 pub mod models;
+pub mod notify;
 pub mod parsed_property;
+pub mod profiling;
 pub mod release_notes;
+#[cfg(feature = "tui")]
 pub mod ui;
 pub mod utils;
 
 // Re-export commonly used types for convenience
 pub use api::AzureDevOpsClient;
 pub use config::Config;
-pub use error::{ApiError, ConfigError, GitError, MergersError, UiError};
+pub use error::{
+    ApiError, ConfigError, ConflictError, GitError, MergersError, StateError, UiError,
+};
+pub use models::{
+    AppConfig, DefaultModeConfig, MigrationModeConfig, OutputFormat, ReleaseNotesOutputFormat,
+    SharedConfig, TaskGroup,
+};
+#[cfg(feature = "cli")]
 pub use models::{
-    AppConfig,
     Args,
     Commands,
-    DefaultModeConfig,
     // Non-interactive mode types
     MergeAbortArgs,
     MergeArgs,
@@ -63,15 +89,10 @@ pub use models::{
     MergeStatusArgs,
     MergeSubcommand,
     MigrateArgs,
-    MigrationModeConfig,
     NonInteractiveArgs,
-    OutputFormat,
     // Release notes types
     ReleaseNotesArgs,
-    ReleaseNotesOutputFormat,
     SharedArgs,
-    SharedConfig,
-    TaskGroup,
 };
 pub use parsed_property::ParsedProperty;
 
@@ -1,4 +1,5 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use rayon::prelude::*;
 
 use crate::{
     api::AzureDevOpsClient,
@@ -28,19 +29,58 @@ impl MigrationAnalyzer {
         pr_with_work_items: &PullRequestWithWorkItems,
         commit_history: &CommitHistory,
     ) -> Result<PRAnalysisResult> {
+        Ok(self.analyze_pr(pr_with_work_items, commit_history))
+    }
+
+    /// Analyzes pull requests on at most `max_concurrency` threads.
+    ///
+    /// `on_result` is called from the worker threads as each PR finishes, in
+    /// completion order. The returned results keep the order of `prs`.
+    pub fn analyze_prs<F>(
+        &self,
+        prs: &[PullRequestWithWorkItems],
+        commit_history: &CommitHistory,
+        max_concurrency: usize,
+        on_result: F,
+    ) -> Result<Vec<PRAnalysisResult>>
+    where
+        F: Fn(&PRAnalysisResult) + Sync,
+    {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(max_concurrency.max(1))
+            .build()
+            .context("Failed to start analysis threads")?;
+
+        Ok(pool.install(|| {
+            prs.par_iter()
+                .map(|pr_with_work_items| {
+                    let analysis = self.analyze_pr(pr_with_work_items, commit_history);
+                    on_result(&analysis);
+                    analysis
+                })
+                .collect()
+        }))
+    }
+
+    /// Checks one pull request against the pre-fetched target history.
+    fn analyze_pr(
+        &self,
+        pr_with_work_items: &PullRequestWithWorkItems,
+        commit_history: &CommitHistory,
+    ) -> PRAnalysisResult {
         // Get commit ID from PR
         let commit_id = if let Some(last_merge_commit) = &pr_with_work_items.pr.last_merge_commit {
             last_merge_commit.commit_id.clone()
         } else {
             // If no lastMergeCommit, we can't analyze this PR
-            return Ok(PRAnalysisResult {
+            return PRAnalysisResult {
                 pr: pr_with_work_items.clone(),
                 all_work_items_terminal: false,
                 commit_in_target: false,
                 commit_title_in_target: false,
                 unsure_reason: Some("No lastMergeCommit available".to_string()),
                 reason: Some("No lastMergeCommit available".to_string()),
-            });
+            };
         };
 
         // Check if commit exists in target branch using pre-fetched history
@@ -154,14 +194,14 @@ impl MigrationAnalyzer {
             ),
         };
 
-        Ok(PRAnalysisResult {
+        PRAnalysisResult {
             pr: pr_with_work_items.clone(),
             all_work_items_terminal: work_items_requirement_met,
             commit_in_target,
             commit_title_in_target,
             unsure_reason,
             reason,
-        })
+        }
     }
 
     fn generate_pr_detection_details(
@@ -270,6 +310,7 @@ mod tests {
             },
             last_merge_commit: commit_id.map(|id| MergeCommit { commit_id: id }),
             labels: None,
+            reviewers: Vec::new(),
         }
     }
 
@@ -285,6 +326,7 @@ mod tests {
                 description: None,
                 repro_steps: None,
                 state_color: None,
+                custom_fields: Default::default(),
             },
             history: Vec::new(),
         }
@@ -942,4 +984,66 @@ mod tests {
         assert_eq!(result.manual_overrides.marked_as_not_eligible.len(), 1);
         assert_eq!(result.manual_overrides.marked_as_eligible.len(), 1);
     }
+
+    /// # Analyze PRs in Parallel
+    ///
+    /// Tests checking many PRs against the target history on a bounded pool.
+    ///
+    /// ## Test Scenario
+    /// - Analyzes 50 PRs on 4 threads; every third one is in the history
+    /// - Counts the PRs reported through the result callback
+    ///
+    /// ## Expected Outcome
+    /// - Every PR is reported exactly once through the callback
+    /// - Results keep the input order and match the sequential analysis
+    #[tokio::test]
+    async fn test_analyze_prs_parallel() {
+        use std::sync::Mutex;
+
+        let client = AzureDevOpsClient::new(
+            "test_org".to_string(),
+            "test_project".to_string(),
+            "test_repo".to_string(),
+            "test_pat".to_string(),
+        )
+        .unwrap();
+        let analyzer = MigrationAnalyzer::new(client, vec!["Closed".to_string()]);
+
+        let prs: Vec<PullRequestWithWorkItems> = (1..=50)
+            .map(|id| PullRequestWithWorkItems {
+                pr: create_test_pr(id, &format!("Change {}", id), Some(format!("c{:06}", id))),
+                work_items: vec![create_test_work_item(id, "Closed")],
+                selected: false,
+            })
+            .collect();
+        let history = CommitHistory {
+            commit_hashes: (1..=50)
+                .step_by(3)
+                .map(|id| format!("c{:06}", id))
+                .collect(),
+            commit_messages: Vec::new(),
+            commit_bodies: Vec::new(),
+        };
+
+        let reported = Mutex::new(Vec::new());
+        let results = analyzer
+            .analyze_prs(&prs, &history, 4, |analysis| {
+                reported.lock().unwrap().push(analysis.pr.pr.id);
+            })
+            .unwrap();
+
+        let mut reported = reported.into_inner().unwrap();
+        reported.sort_unstable();
+        assert_eq!(reported, (1..=50).collect::<Vec<_>>());
+        assert_eq!(
+            results.iter().map(|r| r.pr.pr.id).collect::<Vec<_>>(),
+            (1..=50).collect::<Vec<_>>()
+        );
+        for (pr, result) in prs.iter().zip(&results) {
+            let sequential = analyzer.analyze_single_pr(pr, &history).await.unwrap();
+            assert_eq!(result.commit_in_target, sequential.commit_in_target);
+            assert_eq!(result.reason, sequential.reason);
+            assert_eq!(result.commit_in_target, pr.pr.id % 3 == 1);
+        }
+    }
 }
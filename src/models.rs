@@ -1,366 +1,51 @@
-use crate::{config::Config, parsed_property::ParsedProperty, utils::parse_since_date};
-use anyhow::{Context, Result};
+use crate::parsed_property::ParsedProperty;
+use anyhow::Result;
 use chrono::{DateTime, Utc};
-use clap::{
-    Args as ClapArgs, Parser, Subcommand,
-    builder::{Styles, styling::AnsiColor},
-};
 use serde::Deserialize;
 
-/// Build a version string that includes the git commit hash
-fn build_version() -> &'static str {
-    // Use concat! with env! to create a compile-time constant string
-    concat!(env!("CARGO_PKG_VERSION"), " (", env!("GIT_HASH"), ")")
+#[cfg(feature = "cli")]
+mod cli;
+#[cfg(feature = "cli")]
+pub use cli::*;
+
+/// Pull request date compared against `--since`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, Deserialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "kebab-case")]
+pub enum SinceField {
+    /// When the PR was completed.
+    #[default]
+    Closed,
+    /// When the PR's last merge commit was created.
+    MergeCommit,
+    /// When the target branch commit the PR last merged onto was created.
+    TargetCommit,
 }
 
-/// Define custom styles for colorized help output
-fn help_styles() -> Styles {
-    Styles::styled()
-        .header(AnsiColor::Yellow.on_default().bold())
-        .usage(AnsiColor::Yellow.on_default().bold())
-        .literal(AnsiColor::Green.on_default().bold())
-        .placeholder(AnsiColor::Cyan.on_default())
-        .valid(AnsiColor::Green.on_default())
-        .invalid(AnsiColor::Red.on_default())
-        .error(AnsiColor::Red.on_default().bold())
-}
+impl std::str::FromStr for SinceField {
+    type Err = anyhow::Error;
 
-/// Apply syntax highlighting to shell examples
-fn highlight_shell(content: &str) -> String {
-    use clap::builder::styling::AnsiColor;
-
-    let comment_style = AnsiColor::BrightBlack.on_default();
-    let command_style = AnsiColor::Green.on_default().bold();
-    let flag_style = AnsiColor::Cyan.on_default();
-    let string_style = AnsiColor::Yellow.on_default();
-    let reset = AnsiColor::White.on_default();
-
-    let mut result = String::new();
-    let mut in_command_block = false;
-
-    for line in content.lines() {
-        let trimmed = line.trim_start();
-
-        // Handle comment lines
-        if trimmed.starts_with('#') {
-            result.push_str(&format!("{comment_style}{line}{reset:#}\n"));
-            in_command_block = false;
-            continue;
-        }
-
-        // Handle empty lines
-        if trimmed.is_empty() {
-            result.push('\n');
-            in_command_block = false;
-            continue;
-        }
-
-        // Check if this is a shell command line (starts with known command or continuation)
-        let is_command_line = trimmed.starts_with("mergers")
-            || (in_command_block
-                && trimmed
-                    .chars()
-                    .next()
-                    .is_some_and(|c| c == '-' || c.is_whitespace()));
-
-        // Non-command lines (like "For more information...") - just output as-is
-        if !is_command_line && !in_command_block {
-            result.push_str(line);
-            result.push('\n');
-            continue;
-        }
-
-        // Track if line ends with continuation
-        let has_continuation = line.trim_end().ends_with('\\');
-        in_command_block = has_continuation;
-
-        // Preserve leading whitespace
-        let leading_spaces = line.len() - trimmed.len();
-        result.push_str(&" ".repeat(leading_spaces));
-
-        // Simple tokenization for shell highlighting
-        let mut chars = trimmed.chars().peekable();
-        let mut current_token = String::new();
-        let mut in_string = false;
-        let mut string_char = ' ';
-        let mut is_first_token = trimmed.starts_with("mergers");
-
-        while let Some(ch) = chars.next() {
-            match ch {
-                '"' | '\'' if !in_string => {
-                    // Flush current token
-                    if !current_token.is_empty() {
-                        if is_first_token {
-                            result.push_str(&format!("{command_style}{current_token}{reset:#}"));
-                            is_first_token = false;
-                        } else if current_token.starts_with('-') {
-                            result.push_str(&format!("{flag_style}{current_token}{reset:#}"));
-                        } else {
-                            result.push_str(&current_token);
-                        }
-                        current_token.clear();
-                    }
-                    // Start string
-                    in_string = true;
-                    string_char = ch;
-                    current_token.push(ch);
-                }
-                c if c == string_char && in_string => {
-                    // End string
-                    current_token.push(ch);
-                    result.push_str(&format!("{string_style}{current_token}{reset:#}"));
-                    current_token.clear();
-                    in_string = false;
-                }
-                ' ' | '\t' if !in_string => {
-                    // Token boundary
-                    if !current_token.is_empty() {
-                        if is_first_token {
-                            result.push_str(&format!("{command_style}{current_token}{reset:#}"));
-                            is_first_token = false;
-                        } else if current_token.starts_with('-') {
-                            result.push_str(&format!("{flag_style}{current_token}{reset:#}"));
-                        } else if current_token.starts_with('<') && current_token.ends_with('>') {
-                            result.push_str(&format!("{string_style}{current_token}{reset:#}"));
-                        } else {
-                            result.push_str(&current_token);
-                        }
-                        current_token.clear();
-                    }
-                    result.push(ch);
-                }
-                '\\' if chars.peek() == Some(&'\n') => {
-                    // Line continuation
-                    if !current_token.is_empty() {
-                        if is_first_token {
-                            result.push_str(&format!("{command_style}{current_token}{reset:#}"));
-                            is_first_token = false;
-                        } else if current_token.starts_with('-') {
-                            result.push_str(&format!("{flag_style}{current_token}{reset:#}"));
-                        } else {
-                            result.push_str(&current_token);
-                        }
-                        current_token.clear();
-                    }
-                    result.push_str("\\\n");
-                    chars.next(); // consume the newline
-                }
-                _ => {
-                    current_token.push(ch);
-                }
-            }
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "closed" => Ok(SinceField::Closed),
+            "merge-commit" => Ok(SinceField::MergeCommit),
+            "target-commit" => Ok(SinceField::TargetCommit),
+            other => anyhow::bail!(
+                "Invalid since field '{}' (expected closed, merge-commit or target-commit)",
+                other
+            ),
         }
-
-        // Flush remaining token
-        if !current_token.is_empty() {
-            if is_first_token {
-                result.push_str(&format!("{command_style}{current_token}{reset:#}"));
-            } else if current_token.starts_with('-') {
-                result.push_str(&format!("{flag_style}{current_token}{reset:#}"));
-            } else if current_token.starts_with('<') && current_token.ends_with('>') {
-                result.push_str(&format!("{string_style}{current_token}{reset:#}"));
-            } else {
-                result.push_str(&current_token);
-            }
-        }
-
-        result.push('\n');
     }
-
-    result
-}
-
-/// Build styled after_help text with colorized EXAMPLES header and syntax highlighting
-fn styled_examples(content: &str) -> String {
-    let header_style = AnsiColor::Yellow.on_default().bold();
-    let highlighted = highlight_shell(content);
-    format!("{header_style}EXAMPLES:{header_style:#}\n{highlighted}")
-}
-
-/// Main command examples
-fn main_examples() -> &'static str {
-    use std::sync::OnceLock;
-    static EXAMPLES: OnceLock<String> = OnceLock::new();
-    EXAMPLES.get_or_init(|| styled_examples(include_str!("../docs/examples/main.txt")))
-}
-
-/// Merge command examples
-fn merge_examples() -> &'static str {
-    use std::sync::OnceLock;
-    static EXAMPLES: OnceLock<String> = OnceLock::new();
-    EXAMPLES.get_or_init(|| styled_examples(include_str!("../docs/examples/merge.txt")))
-}
-
-/// Migrate command examples
-fn migrate_examples() -> &'static str {
-    use std::sync::OnceLock;
-    static EXAMPLES: OnceLock<String> = OnceLock::new();
-    EXAMPLES.get_or_init(|| styled_examples(include_str!("../docs/examples/migrate.txt")))
-}
-
-/// Cleanup command examples
-fn cleanup_examples() -> &'static str {
-    use std::sync::OnceLock;
-    static EXAMPLES: OnceLock<String> = OnceLock::new();
-    EXAMPLES.get_or_init(|| styled_examples(include_str!("../docs/examples/cleanup.txt")))
 }
 
-/// Release-notes command examples
-fn release_notes_examples() -> &'static str {
-    use std::sync::OnceLock;
-    static EXAMPLES: OnceLock<String> = OnceLock::new();
-    EXAMPLES.get_or_init(|| styled_examples(include_str!("../docs/examples/release-notes.txt")))
-}
-
-/// Shared arguments used by all commands
-#[derive(ClapArgs, Clone, Default, Debug)]
-pub struct SharedArgs {
-    /// Local repository path or alias (positional argument, takes precedence over --local-repo)
-    pub path: Option<String>,
-
-    // Azure DevOps Connection
-    /// Azure DevOps organization name
-    #[arg(short, long, help_heading = "Azure DevOps Connection")]
-    pub organization: Option<String>,
-
-    /// Azure DevOps project name
-    #[arg(short, long, help_heading = "Azure DevOps Connection")]
-    pub project: Option<String>,
-
-    /// Azure DevOps repository name
-    #[arg(short, long, help_heading = "Azure DevOps Connection")]
-    pub repository: Option<String>,
-
-    /// Personal Access Token for Azure DevOps API authentication
-    #[arg(short = 't', long, help_heading = "Azure DevOps Connection")]
-    pub pat: Option<String>,
-
-    // Branch Configuration
-    /// Source branch to fetch PRs from [default: dev]
-    #[arg(long, help_heading = "Branch Configuration")]
-    pub dev_branch: Option<String>,
-
-    /// Target branch for cherry-picks [default: next]
-    #[arg(long, help_heading = "Branch Configuration")]
-    pub target_branch: Option<String>,
-
-    // Repository Options
-    /// Local repository path (alternative to positional argument)
-    #[arg(long, help_heading = "Repository Options")]
-    pub local_repo: Option<String>,
-
-    /// Prefix for tagging processed PRs
-    #[arg(long, default_value = "merged-", help_heading = "Repository Options")]
-    pub tag_prefix: Option<String>,
-
-    // Performance Tuning
-    /// Maximum parallel API requests [default: 300]
-    #[arg(long, help_heading = "Performance Tuning")]
-    pub parallel_limit: Option<usize>,
-
-    /// Maximum concurrent network operations [default: 100]
-    #[arg(long, help_heading = "Performance Tuning")]
-    pub max_concurrent_network: Option<usize>,
-
-    /// Maximum concurrent processing operations [default: 10]
-    #[arg(long, help_heading = "Performance Tuning")]
-    pub max_concurrent_processing: Option<usize>,
-
-    // Filtering
-    /// Only fetch items created after this date (e.g., "1mo", "2w", "2025-01-15")
-    #[arg(long, help_heading = "Filtering")]
-    pub since: Option<String>,
-
-    // Behavior
-    /// Skip the settings confirmation screen and proceed directly
-    #[arg(long, help_heading = "Behavior")]
-    pub skip_confirmation: bool,
-
-    // Logging
-    /// Log level (trace, debug, info, warn, error)
-    #[arg(long, help_heading = "Logging")]
-    pub log_level: Option<String>,
-
-    /// Log file path (logs to file instead of stderr)
-    #[arg(long, help_heading = "Logging")]
-    pub log_file: Option<String>,
-
-    /// Log format (text, json) [default: text]
-    #[arg(long, help_heading = "Logging", value_parser = ["text", "json"])]
-    pub log_format: Option<String>,
-}
-
-/// Arguments specific to non-interactive mode.
-/// Flattened into MergeArgs so these flags are available on `mergers merge` directly.
-#[derive(ClapArgs, Clone, Default, Debug)]
-pub struct NonInteractiveArgs {
-    /// Run in non-interactive mode (for CI/AI agents)
-    #[arg(short = 'n', long, help_heading = "Non-Interactive Mode")]
-    pub non_interactive: bool,
-
-    /// Merge branch version (required with --non-interactive)
-    #[arg(long, help_heading = "Non-Interactive Mode")]
-    pub version: Option<String>,
-
-    /// Comma-separated work item states for PR filtering
-    #[arg(long, help_heading = "Non-Interactive Mode")]
-    pub select_by_state: Option<String>,
-
-    /// Output format: text, json, ndjson
-    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help_heading = "Output Options")]
-    pub output: OutputFormat,
-
-    /// Suppress progress output
-    #[arg(short, long, help_heading = "Output Options")]
-    pub quiet: bool,
-}
-
-/// Arguments specific to merge mode
-#[derive(ClapArgs, Clone)]
-pub struct MergeArgs {
-    #[command(flatten)]
-    pub shared: SharedArgs,
-
-    #[command(flatten)]
-    pub ni: NonInteractiveArgs,
-
-    /// State to set work items to after successful merge [default: Next Merged]
-    #[arg(long, help_heading = "Merge Options")]
-    pub work_item_state: Option<String>,
-
-    /// Run git hooks during cherry-pick operations (hooks are skipped by default)
-    #[arg(long, help_heading = "Merge Options")]
-    pub run_hooks: bool,
-
-    /// Subcommand for non-interactive operations
-    #[command(subcommand)]
-    pub subcommand: Option<MergeSubcommand>,
-}
-
-/// Arguments specific to migration mode
-#[derive(ClapArgs, Clone)]
-pub struct MigrateArgs {
-    #[command(flatten)]
-    pub shared: SharedArgs,
-
-    /// Comma-separated list of work item states considered terminal
-    #[arg(
-        long,
-        default_value = "Closed,Next Closed,Next Merged",
-        help_heading = "Migration Options"
-    )]
-    pub terminal_states: String,
-}
-
-/// Arguments specific to cleanup mode
-#[derive(ClapArgs, Clone)]
-pub struct CleanupArgs {
-    #[command(flatten)]
-    pub shared: SharedArgs,
-
-    /// Target branch to check for merged patches (defaults to --target-branch)
-    #[arg(long, help_heading = "Cleanup Options")]
-    pub target: Option<String>,
+impl std::fmt::Display for SinceField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SinceField::Closed => write!(f, "closed"),
+            SinceField::MergeCommit => write!(f, "merge-commit"),
+            SinceField::TargetCommit => write!(f, "target-commit"),
+        }
+    }
 }
 
 // ============================================================================
@@ -368,7 +53,8 @@ pub struct CleanupArgs {
 // ============================================================================
 
 /// Output format for non-interactive mode.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
 pub enum OutputFormat {
     /// Human-readable text output.
     #[default]
@@ -394,7 +80,8 @@ impl std::fmt::Display for OutputFormat {
 // ============================================================================
 
 /// Output format for release-notes command.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum, serde::Serialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
 pub enum ReleaseNotesOutputFormat {
     /// Markdown table format.
     #[default]
@@ -403,6 +90,8 @@ pub enum ReleaseNotesOutputFormat {
     Json,
     /// Plain text list.
     Plain,
+    /// HTML fragment with a list of tasks per group.
+    Html,
 }
 
 impl std::fmt::Display for ReleaseNotesOutputFormat {
@@ -411,6 +100,27 @@ impl std::fmt::Display for ReleaseNotesOutputFormat {
             ReleaseNotesOutputFormat::Markdown => write!(f, "markdown"),
             ReleaseNotesOutputFormat::Json => write!(f, "json"),
             ReleaseNotesOutputFormat::Plain => write!(f, "plain"),
+            ReleaseNotesOutputFormat::Html => write!(f, "html"),
+        }
+    }
+}
+
+/// How release notes treat work items an existing changelog already lists.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum PreviouslyReleased {
+    /// Leave them out of the release notes.
+    #[default]
+    Exclude,
+    /// List them, marked as previously released.
+    Mark,
+}
+
+impl std::fmt::Display for PreviouslyReleased {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PreviouslyReleased::Exclude => write!(f, "exclude"),
+            PreviouslyReleased::Mark => write!(f, "mark"),
         }
     }
 }
@@ -442,377 +152,101 @@ impl std::fmt::Display for TaskGroup {
     }
 }
 
-/// Arguments for the release-notes command.
-#[derive(ClapArgs, Clone, Debug)]
-pub struct ReleaseNotesArgs {
-    #[command(flatten)]
-    pub shared: SharedArgs,
-
-    /// Output format: markdown, json, plain
-    #[arg(long, value_enum, default_value_t = ReleaseNotesOutputFormat::Markdown, help_heading = "Output Options")]
-    pub output: ReleaseNotesOutputFormat,
-
-    /// Copy output to clipboard
-    #[arg(long, help_heading = "Output Options")]
-    pub copy: bool,
-
-    /// Group tasks by commit type (feat, fix, refactor)
-    #[arg(long, help_heading = "Output Options")]
-    pub group: bool,
-
-    /// Include PR links in output
-    #[arg(long, help_heading = "Output Options")]
-    pub include_prs: bool,
-
-    /// Starting version/tag for range (inclusive)
-    #[arg(long, help_heading = "Version Range")]
-    pub from: Option<String>,
-
-    /// Ending version/tag for range (inclusive, defaults to HEAD)
-    #[arg(long, help_heading = "Version Range")]
-    pub to: Option<String>,
-
-    /// Skip cache and fetch fresh data from API
-    #[arg(long, help_heading = "Cache Options")]
-    pub no_cache: bool,
-}
-
-/// Arguments for the `merge continue` subcommand.
-#[derive(ClapArgs, Clone, Debug)]
-pub struct MergeContinueArgs {
-    /// Repository path (auto-detected if in repo)
-    #[arg(long, help_heading = "Repository")]
-    pub repo: Option<String>,
-
-    /// Output format: text, json, ndjson
-    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help_heading = "Output Options")]
-    pub output: OutputFormat,
-
-    /// Suppress progress output
-    #[arg(short, long, help_heading = "Output Options")]
-    pub quiet: bool,
-}
-
-/// Arguments for the `merge abort` subcommand.
-#[derive(ClapArgs, Clone, Debug)]
-pub struct MergeAbortArgs {
-    /// Repository path (auto-detected if in repo)
-    #[arg(long, help_heading = "Repository")]
-    pub repo: Option<String>,
-
-    /// Output format: text, json, ndjson
-    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help_heading = "Output Options")]
-    pub output: OutputFormat,
-
-    /// Suppress progress output
-    #[arg(short, long, help_heading = "Output Options")]
-    pub quiet: bool,
-}
-
-/// Arguments for the `merge skip` subcommand.
-#[derive(ClapArgs, Clone, Debug)]
-pub struct MergeSkipArgs {
-    /// Repository path (auto-detected if in repo)
-    #[arg(long, help_heading = "Repository")]
-    pub repo: Option<String>,
-
-    /// Output format: text, json, ndjson
-    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help_heading = "Output Options")]
-    pub output: OutputFormat,
-
-    /// Suppress progress output
-    #[arg(short, long, help_heading = "Output Options")]
-    pub quiet: bool,
-}
-
-/// Arguments for the `merge status` subcommand.
-#[derive(ClapArgs, Clone, Debug)]
-pub struct MergeStatusArgs {
-    /// Repository path (auto-detected if in repo)
-    #[arg(long, help_heading = "Repository")]
-    pub repo: Option<String>,
-
-    /// Output format: text, json, ndjson
-    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help_heading = "Output Options")]
-    pub output: OutputFormat,
-}
-
-/// Arguments for the `merge complete` subcommand.
-#[derive(ClapArgs, Clone, Debug)]
-pub struct MergeCompleteArgs {
-    /// Repository path (auto-detected if in repo)
-    #[arg(long, help_heading = "Repository")]
-    pub repo: Option<String>,
-
-    /// State to set work items to (required)
-    #[arg(long, help_heading = "Completion Options")]
-    pub next_state: String,
-
-    /// Output format: text, json, ndjson
-    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help_heading = "Output Options")]
-    pub output: OutputFormat,
-
-    /// Suppress progress output
-    #[arg(short, long, help_heading = "Output Options")]
-    pub quiet: bool,
-}
-
-/// Subcommands for the merge mode.
-#[derive(Subcommand, Clone, Debug)]
-pub enum MergeSubcommand {
-    /// Continue merge after resolving conflicts
-    #[command(
-        about = "Continue merge after resolving conflicts",
-        long_about = "Continue a merge operation that was paused due to conflicts.\n\n\
-            This command reads the state file, verifies conflicts are resolved,\n\
-            and continues cherry-picking remaining commits."
-    )]
-    Continue(MergeContinueArgs),
-
-    /// Abort and clean up an in-progress merge
-    #[command(
-        about = "Abort and clean up an in-progress merge",
-        long_about = "Abort an in-progress merge operation and clean up.\n\n\
-            This removes the worktree, deletes the working branch, and aborts\n\
-            any in-progress cherry-pick."
-    )]
-    Abort(MergeAbortArgs),
-
-    /// Show status of current merge operation
-    #[command(
-        about = "Show status of current merge operation",
-        long_about = "Show the current status of an in-progress merge operation.\n\n\
-            Displays the current phase, progress, and any conflicts."
-    )]
-    Status(MergeStatusArgs),
-
-    /// Complete merge by tagging PRs and updating work items
-    #[command(
-        about = "Complete merge by tagging PRs and updating work items",
-        long_about = "Complete a merge operation after all cherry-picks are done.\n\n\
-            This tags successful PRs in Azure DevOps and updates work items\n\
-            to the specified next state."
-    )]
-    Complete(MergeCompleteArgs),
-
-    /// Skip the current conflicting PR and continue with remaining
-    #[command(
-        about = "Skip the current conflicting PR and continue",
-        long_about = "Skip the currently conflicting PR in a merge operation.\n\n\
-            This aborts the current cherry-pick, marks the PR as skipped,\n\
-            and continues processing the remaining PRs."
-    )]
-    Skip(MergeSkipArgs),
-}
+// ============================================================================
+// Analyze CLI Arguments
+// ============================================================================
 
-/// Trait to extract shared arguments from command-specific argument structs
-pub trait HasSharedArgs {
-    fn shared_args(&self) -> &SharedArgs;
-    fn shared_args_mut(&mut self) -> &mut SharedArgs;
+/// Output format for the analyze command.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum AnalyzeOutputFormat {
+    /// JSON report with the full dependency graph and warnings.
+    #[default]
+    Json,
+    /// Graphviz DOT graph.
+    Dot,
 }
 
-impl HasSharedArgs for MergeArgs {
-    fn shared_args(&self) -> &SharedArgs {
-        &self.shared
-    }
-
-    fn shared_args_mut(&mut self) -> &mut SharedArgs {
-        &mut self.shared
+impl AnalyzeOutputFormat {
+    /// Infers the format from an output file extension (`.dot`/`.gv` → DOT).
+    pub fn from_path(path: &std::path::Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("dot" | "gv") => AnalyzeOutputFormat::Dot,
+            _ => AnalyzeOutputFormat::Json,
+        }
     }
 }
 
-impl HasSharedArgs for MigrateArgs {
-    fn shared_args(&self) -> &SharedArgs {
-        &self.shared
-    }
-
-    fn shared_args_mut(&mut self) -> &mut SharedArgs {
-        &mut self.shared
+impl std::fmt::Display for AnalyzeOutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnalyzeOutputFormat::Json => write!(f, "json"),
+            AnalyzeOutputFormat::Dot => write!(f, "dot"),
+        }
     }
 }
 
-impl HasSharedArgs for CleanupArgs {
-    fn shared_args(&self) -> &SharedArgs {
-        &self.shared
-    }
+// ============================================================================
+// Watch CLI Arguments
+// ============================================================================
 
-    fn shared_args_mut(&mut self) -> &mut SharedArgs {
-        &mut self.shared
-    }
+/// How `mergers watch` announces new merge candidates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum WatchNotify {
+    /// Terminal bell only.
+    Bell,
+    /// Desktop notification only.
+    Desktop,
+    /// Terminal bell and desktop notification.
+    #[default]
+    Both,
+    /// Print new candidates without notifying.
+    None,
 }
 
-impl HasSharedArgs for ReleaseNotesArgs {
-    fn shared_args(&self) -> &SharedArgs {
-        &self.shared
+impl WatchNotify {
+    /// Returns `true` if the terminal bell should ring.
+    pub fn bell(self) -> bool {
+        matches!(self, WatchNotify::Bell | WatchNotify::Both)
     }
 
-    fn shared_args_mut(&mut self) -> &mut SharedArgs {
-        &mut self.shared
+    /// Returns `true` if a desktop notification should be shown.
+    pub fn desktop(self) -> bool {
+        matches!(self, WatchNotify::Desktop | WatchNotify::Both)
     }
 }
 
-/// Available commands
-#[derive(Subcommand, Clone)]
-pub enum Commands {
-    /// Cherry-pick merged PRs from dev branch to target branch
-    #[command(
-        visible_alias = "m",
-        long_about = "Cherry-pick merged PRs from the dev branch to a target branch.\n\n\
-            This mode fetches completed PRs from Azure DevOps, displays them in an interactive\n\
-            TUI for selection, and cherry-picks the selected commits to your target branch.\n\
-            Work items can be automatically transitioned to a specified state after merge.",
-        after_help = merge_examples()
-    )]
-    Merge(MergeArgs),
-
-    /// Analyze PRs to determine migration eligibility
-    #[command(
-        visible_alias = "mi",
-        long_about = "Analyze pull requests to determine which ones are eligible for migration.\n\n\
-            This mode examines PRs and their associated work items to categorize them as:\n  \
-            • Eligible: All work items in terminal states, commit found in target\n  \
-            • Unsure: Mixed signals requiring manual review\n  \
-            • Not merged: PR commits not present in target branch\n\n\
-            Results are displayed in an interactive TUI for review and manual override.",
-        after_help = migrate_examples()
-    )]
-    Migrate(MigrateArgs),
-
-    /// Clean up merged patch branches from the repository
-    #[command(
-        visible_alias = "c",
-        long_about = "Clean up patch branches that have been merged to the target branch.\n\n\
-            This mode identifies local branches matching the tag prefix pattern (default: merged-*)\n\
-            that have been fully merged into the target branch, and offers to delete them.\n\
-            Useful for maintaining a clean repository after completing merge operations.",
-        after_help = cleanup_examples()
-    )]
-    Cleanup(CleanupArgs),
-
-    /// Generate release notes from version commits
-    #[command(
-        visible_alias = "rn",
-        long_about = "Generate release notes from git tags and pull requests.\n\n\
-            Discovers PRs tagged with a configurable prefix (tag_prefix) and fetches\n\
-            associated work items from Azure DevOps to build formatted release notes.\n\n\
-            Features:\n  \
-            • Supports version ranges (--from / --to)\n  \
-            • Groups entries by type (feat, fix, refactor)\n  \
-            • Caches work item titles locally\n  \
-            • Multiple output formats (markdown, json, plain)\n  \
-            • Clipboard support (--copy)",
-        after_help = release_notes_examples()
-    )]
-    ReleaseNotes(ReleaseNotesArgs),
-}
+// ============================================================================
+// Metrics CLI Arguments
+// ============================================================================
 
-impl Commands {
-    /// Extract shared arguments from any command variant.
-    pub fn shared_args(&self) -> &SharedArgs {
-        match self {
-            Commands::Merge(args) => args.shared_args(),
-            Commands::Migrate(args) => args.shared_args(),
-            Commands::Cleanup(args) => args.shared_args(),
-            Commands::ReleaseNotes(args) => args.shared_args(),
-        }
-    }
+/// Output format for the metrics command.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum MetricsOutputFormat {
+    /// JSON with per-release metrics and repeatedly deferred PRs.
+    #[default]
+    Json,
+    /// CSV with one row per release.
+    Csv,
+}
 
-    /// Extract mutable shared arguments from any command variant.
-    pub fn shared_args_mut(&mut self) -> &mut SharedArgs {
-        match self {
-            Commands::Merge(args) => args.shared_args_mut(),
-            Commands::Migrate(args) => args.shared_args_mut(),
-            Commands::Cleanup(args) => args.shared_args_mut(),
-            Commands::ReleaseNotes(args) => args.shared_args_mut(),
+impl MetricsOutputFormat {
+    /// Infers the format from an output file extension (`.csv` → CSV).
+    pub fn from_path(path: &std::path::Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("csv") => MetricsOutputFormat::Csv,
+            _ => MetricsOutputFormat::Json,
         }
     }
-
-    /// Check if this command is the ReleaseNotes command.
-    #[must_use]
-    pub fn is_release_notes(&self) -> bool {
-        matches!(self, Commands::ReleaseNotes(_))
-    }
 }
 
-#[derive(Parser, Clone)]
-#[command(
-    name = "mergers",
-    author,
-    version,
-    long_version = build_version(),
-    about = "Manage Azure DevOps pull request merging and migration workflows",
-    long_about = "A CLI/TUI tool for managing Azure DevOps pull request merging and migration workflows.\n\n\
-        Mergers helps you:\n  \
-        • Cherry-pick merged PRs from dev to target branches\n  \
-        • Analyze PRs for migration eligibility\n  \
-        • Clean up merged patch branches\n\n\
-        Configuration can be provided via CLI arguments, environment variables (MERGERS_*),\n\
-        config file (~/.config/mergers/config.toml), or auto-detected from git remotes.",
-    before_help = concat!("mergers ", env!("CARGO_PKG_VERSION"), " (", env!("GIT_HASH"), ")"),
-    after_help = main_examples(),
-    styles = help_styles()
-)]
-pub struct Args {
-    #[command(subcommand)]
-    pub command: Option<Commands>,
-
-    /// Create a sample configuration file at ~/.config/mergers/config.toml
-    #[arg(long)]
-    pub create_config: bool,
-}
-
-/// Temporary wrapper to parse MergeArgs as if they were top-level
-#[derive(Parser, Clone)]
-#[command(
-    name = "mergers",
-    about = None,
-    long_about = None,
-    before_help = concat!("mergers ", env!("CARGO_PKG_VERSION"), " (", env!("GIT_HASH"), ")"),
-    styles = help_styles()
-)]
-pub struct MergeArgsParser {
-    #[command(flatten)]
-    pub merge_args: MergeArgs,
-}
-
-impl Args {
-    /// Parse arguments with default mode fallback.
-    /// If no subcommand is provided, attempts to parse args as MergeArgs.
-    pub fn parse_with_default_mode() -> Self {
-        use clap::error::ErrorKind;
-
-        // First try normal parsing
-        match Args::try_parse() {
-            Ok(args) => {
-                // Successfully parsed as Args, check if command is present
-                if args.command.is_some() || args.create_config {
-                    return args;
-                }
-                // No command and no create_config, fall through to try merge mode
-            }
-            Err(e) => {
-                // If it's a help or version display, show it and exit
-                match e.kind() {
-                    ErrorKind::DisplayHelp | ErrorKind::DisplayVersion => {
-                        e.exit();
-                    }
-                    _ => {
-                        // Other errors: fall through to try merge mode
-                    }
-                }
-            }
-        }
-
-        // Try to parse as MergeArgs using the wrapper
-        match MergeArgsParser::try_parse() {
-            Ok(parser) => Args {
-                command: Some(Commands::Merge(parser.merge_args)),
-                create_config: false,
-            },
-            Err(e) => {
-                // If MergeArgs parsing also fails, show the error and exit
-                e.exit();
-            }
+impl std::fmt::Display for MetricsOutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MetricsOutputFormat::Json => write!(f, "json"),
+            MetricsOutputFormat::Csv => write!(f, "csv"),
         }
     }
 }
@@ -824,6 +258,8 @@ pub struct SharedConfig {
     pub project: ParsedProperty<String>,
     pub repository: ParsedProperty<String>,
     pub pat: ParsedProperty<String>,
+    /// PAT used for mutating requests; `pat` is used when unset.
+    pub write_pat: Option<ParsedProperty<String>>,
     pub dev_branch: ParsedProperty<String>,
     pub target_branch: ParsedProperty<String>,
     pub local_repo: Option<ParsedProperty<String>>,
@@ -832,13 +268,74 @@ pub struct SharedConfig {
     pub max_concurrent_processing: ParsedProperty<usize>,
     pub tag_prefix: ParsedProperty<String>,
     pub since: Option<ParsedProperty<DateTime<Utc>>>,
+    /// Pull request date compared against `since`.
+    pub since_field: SinceField,
     pub skip_confirmation: bool,
+    /// Azure DevOps REST API version override, if configured.
+    pub api_version: Option<ParsedProperty<String>>,
+    /// Maximum number of target branch commits read when checking what is merged.
+    pub history_depth: Option<ParsedProperty<usize>>,
+    /// Custom pull request and work item link layouts.
+    pub url_templates: crate::api::UrlTemplates,
+    /// Headings and date format of release notes written by the TUI.
+    pub release_notes_locale: crate::release_notes::ReleaseNotesLocale,
 }
 
 /// Configuration specific to default mode
 #[derive(Debug, Clone)]
 pub struct DefaultModeConfig {
     pub work_item_state: ParsedProperty<String>,
+    /// Known work item states enforced by strict state checking
+    /// (`None` when strict checking is off).
+    pub strict_states: Option<Vec<String>>,
+    /// Release gate on a custom work item field (`None` when not configured).
+    pub release_gate: Option<crate::core::operations::ReleaseGate>,
+    /// Whether PR builds and branch policies are checked (default: off).
+    pub build_checks: crate::core::operations::BuildCheckMode,
+    /// Whether the patch branch is tagged `v<version>` on completion (default: off).
+    pub release_tag: crate::core::operations::ReleaseTagMode,
+    /// Whether cherry-picked commits and the release tag are signed.
+    pub sign_commits: bool,
+    /// Target branches the merge fans out to (empty for a single target).
+    pub fan_out: Vec<String>,
+    /// Commit message template of squashed PRs (`None` when squash mode is off).
+    pub squash_message: Option<String>,
+    /// Whether a merge commit whose first parent conflicts is retried with
+    /// its second parent as mainline (`mainline_fallback`).
+    pub mainline_fallback: bool,
+    /// Interval for refreshing the PR list in the background (`None` when off).
+    pub auto_refresh: Option<std::time::Duration>,
+    /// File listing PR and work item ids to select (`None` when not given).
+    pub select_file: Option<std::path::PathBuf>,
+    /// PR grouping tabs for the selection screen (empty when not configured).
+    pub pr_tabs: Vec<crate::core::operations::PrTab>,
+    /// Selected PR count above which cherry-picking needs confirmation (`None` when off).
+    pub selection_soft_cap: Option<usize>,
+    /// Work item states whose PRs are hidden from PR selection and bulk
+    /// selection (empty when not configured).
+    pub terminal_states: Vec<String>,
+    /// Comment template posted on each released work item on completion
+    /// (`None` to post no comments).
+    pub work_item_comment: Option<String>,
+    /// Feed package whose version is stamped on released work items on
+    /// completion (`None` when not configured).
+    pub artifacts_feed: Option<crate::api::ArtifactsFeed>,
+    /// Templated fields written on released work items on completion
+    /// (empty when not configured).
+    pub work_item_fields: Vec<crate::core::operations::WorkItemFieldRule>,
+    /// Chat webhooks notified when the merge finishes (`None` when not configured).
+    pub notifications: Option<crate::notify::NotificationsConfig>,
+    /// Sides kept for conflicted binary files matching path globs
+    /// (empty when not configured).
+    pub binary_conflicts: crate::core::operations::BinaryConflictPolicy,
+    /// Order in which the selected PRs are cherry-picked (default: closed date).
+    pub cherry_pick_order: crate::core::operations::CherryPickOrder,
+    /// Author, reviewer and path filter of the PR list (empty when not given).
+    pub pr_filter: crate::core::operations::PrFilter,
+    /// Name of the selection session to restore and keep (`None` without `--session`).
+    pub session: Option<String>,
+    /// How text is copied by quick actions (defaults to auto-detection).
+    pub clipboard: crate::utils::ClipboardOptions,
     /// Whether to run git hooks during cherry-pick operations (default: false).
     /// When false, hooks are disabled at repo initialization by setting core.hooksPath=/dev/null.
     pub run_hooks: ParsedProperty<bool>,
@@ -864,8 +361,30 @@ pub struct ReleaseNotesModeConfig {
     pub output_format: ReleaseNotesOutputFormat,
     pub grouped: bool,
     pub include_prs: bool,
+    /// List PRs without work items in an "Unreferenced changes" section.
+    pub include_unreferenced: bool,
     pub copy_to_clipboard: bool,
+    pub clipboard: crate::utils::ClipboardOptions,
     pub no_cache: bool,
+    /// Work item reference patterns applied to PR text (empty when not configured).
+    pub task_patterns: crate::release_notes::TaskPatterns,
+    /// Headings of markdown and plain output.
+    pub locale: crate::release_notes::ReleaseNotesLocale,
+    /// Existing changelog whose work items are treated as previously released.
+    pub changelog: Option<std::path::PathBuf>,
+    /// Whether previously released work items are left out or marked.
+    pub previously_released: PreviouslyReleased,
+    /// Wiki page the markdown notes are published to.
+    pub wiki: Option<crate::api::WikiPageTarget>,
+}
+
+/// Configuration specific to analyze mode
+#[derive(Debug, Clone)]
+pub struct AnalyzeModeConfig {
+    pub output_path: Option<std::path::PathBuf>,
+    pub output_format: AnalyzeOutputFormat,
+    pub select_by_state: Option<String>,
+    pub plan: bool,
 }
 
 // ============================================================================
@@ -901,6 +420,57 @@ pub struct MergeConfig {
     pub shared: SharedConfig,
     /// State to set work items to after successful merge.
     pub work_item_state: ParsedProperty<String>,
+    /// Known work item states enforced by strict state checking
+    /// (`None` when strict checking is off).
+    pub strict_states: Option<Vec<String>>,
+    /// Release gate on a custom work item field (`None` when not configured).
+    pub release_gate: Option<crate::core::operations::ReleaseGate>,
+    /// Whether PR builds and branch policies are checked (default: off).
+    pub build_checks: crate::core::operations::BuildCheckMode,
+    /// Whether the patch branch is tagged `v<version>` on completion (default: off).
+    pub release_tag: crate::core::operations::ReleaseTagMode,
+    /// Whether cherry-picked commits and the release tag are signed.
+    pub sign_commits: bool,
+    /// Target branches the merge fans out to (empty for a single target).
+    pub fan_out: Vec<String>,
+    /// Commit message template of squashed PRs (`None` when squash mode is off).
+    pub squash_message: Option<String>,
+    /// Whether a merge commit whose first parent conflicts is retried with
+    /// its second parent as mainline (`mainline_fallback`).
+    pub mainline_fallback: bool,
+    /// Interval for refreshing the PR list in the background (`None` when off).
+    pub auto_refresh: Option<std::time::Duration>,
+    /// File listing PR and work item ids to select (`None` when not given).
+    pub select_file: Option<std::path::PathBuf>,
+    /// PR grouping tabs for the selection screen (empty when not configured).
+    pub pr_tabs: Vec<crate::core::operations::PrTab>,
+    /// Selected PR count above which cherry-picking needs confirmation (`None` when off).
+    pub selection_soft_cap: Option<usize>,
+    /// Work item states whose PRs are hidden from PR selection and bulk
+    /// selection (empty when not configured).
+    pub terminal_states: Vec<String>,
+    /// Comment template posted on each released work item on completion
+    /// (`None` to post no comments).
+    pub work_item_comment: Option<String>,
+    /// Feed package whose version is stamped on released work items on
+    /// completion (`None` when not configured).
+    pub artifacts_feed: Option<crate::api::ArtifactsFeed>,
+    /// Templated fields written on released work items on completion
+    /// (empty when not configured).
+    pub work_item_fields: Vec<crate::core::operations::WorkItemFieldRule>,
+    /// Chat webhooks notified when the merge finishes (`None` when not configured).
+    pub notifications: Option<crate::notify::NotificationsConfig>,
+    /// Sides kept for conflicted binary files matching path globs
+    /// (empty when not configured).
+    pub binary_conflicts: crate::core::operations::BinaryConflictPolicy,
+    /// Order in which the selected PRs are cherry-picked (default: closed date).
+    pub cherry_pick_order: crate::core::operations::CherryPickOrder,
+    /// Author, reviewer and path filter of the PR list (empty when not given).
+    pub pr_filter: crate::core::operations::PrFilter,
+    /// Name of the selection session to restore and keep (`None` without `--session`).
+    pub session: Option<String>,
+    /// How text is copied by quick actions (defaults to auto-detection).
+    pub clipboard: crate::utils::ClipboardOptions,
     /// Whether to run git hooks during cherry-pick operations (default: false).
     pub run_hooks: ParsedProperty<bool>,
 }
@@ -918,6 +488,28 @@ impl MergeConfig {
             shared: self.shared.clone(),
             default: DefaultModeConfig {
                 work_item_state: self.work_item_state.clone(),
+                strict_states: self.strict_states.clone(),
+                release_gate: self.release_gate.clone(),
+                build_checks: self.build_checks,
+                release_tag: self.release_tag,
+                sign_commits: self.sign_commits,
+                fan_out: self.fan_out.clone(),
+                squash_message: self.squash_message.clone(),
+                mainline_fallback: self.mainline_fallback,
+                auto_refresh: self.auto_refresh,
+                select_file: self.select_file.clone(),
+                pr_tabs: self.pr_tabs.clone(),
+                selection_soft_cap: self.selection_soft_cap,
+                terminal_states: self.terminal_states.clone(),
+                work_item_comment: self.work_item_comment.clone(),
+                artifacts_feed: self.artifacts_feed.clone(),
+                work_item_fields: self.work_item_fields.clone(),
+                notifications: self.notifications.clone(),
+                binary_conflicts: self.binary_conflicts.clone(),
+                cherry_pick_order: self.cherry_pick_order,
+                pr_filter: self.pr_filter.clone(),
+                session: self.session.clone(),
+                clipboard: self.clipboard.clone(),
                 run_hooks: self.run_hooks.clone(),
             },
         }
@@ -993,6 +585,7 @@ impl CleanupConfig {
 /// This enum is maintained for backward compatibility. New code should prefer
 /// the type-safe config structs ([`MergeConfig`], [`MigrationConfig`], [`CleanupConfig`]).
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum AppConfig {
     Default {
         shared: SharedConfig,
@@ -1010,6 +603,10 @@ pub enum AppConfig {
         shared: SharedConfig,
         release_notes: ReleaseNotesModeConfig,
     },
+    Analyze {
+        shared: SharedConfig,
+        analyze: AnalyzeModeConfig,
+    },
 }
 
 impl AppConfig {
@@ -1018,7 +615,8 @@ impl AppConfig {
             AppConfig::Default { shared, .. }
             | AppConfig::Migration { shared, .. }
             | AppConfig::Cleanup { shared, .. }
-            | AppConfig::ReleaseNotes { shared, .. } => shared,
+            | AppConfig::ReleaseNotes { shared, .. }
+            | AppConfig::Analyze { shared, .. } => shared,
         }
     }
 
@@ -1040,6 +638,28 @@ impl AppConfig {
             AppConfig::Default { shared, default } => MergeConfig {
                 shared,
                 work_item_state: default.work_item_state,
+                strict_states: default.strict_states,
+                release_gate: default.release_gate,
+                build_checks: default.build_checks,
+                release_tag: default.release_tag,
+                sign_commits: default.sign_commits,
+                fan_out: default.fan_out,
+                squash_message: default.squash_message,
+                mainline_fallback: default.mainline_fallback,
+                auto_refresh: default.auto_refresh,
+                select_file: default.select_file,
+                pr_tabs: default.pr_tabs,
+                selection_soft_cap: default.selection_soft_cap,
+                terminal_states: default.terminal_states,
+                work_item_comment: default.work_item_comment,
+                artifacts_feed: default.artifacts_feed,
+                work_item_fields: default.work_item_fields,
+                notifications: default.notifications,
+                binary_conflicts: default.binary_conflicts,
+                cherry_pick_order: default.cherry_pick_order,
+                pr_filter: default.pr_filter,
+                session: default.session,
+                clipboard: default.clipboard,
                 run_hooks: default.run_hooks,
             },
             _ => panic!("into_merge_config called on non-Default variant"),
@@ -1105,21 +725,87 @@ impl AppConfig {
                 output_format: release_notes.output_format,
                 grouped: release_notes.grouped,
                 include_prs: release_notes.include_prs,
+                include_unreferenced: release_notes.include_unreferenced,
                 copy_to_clipboard: release_notes.copy_to_clipboard,
+                clipboard: release_notes.clipboard,
                 no_cache: release_notes.no_cache,
                 max_concurrent_network: *shared.max_concurrent_network.value(),
                 max_concurrent_processing: *shared.max_concurrent_processing.value(),
+                api_version: shared.api_version.map(|p| p.value().clone()),
+                url_templates: shared.url_templates,
+                task_patterns: release_notes.task_patterns,
+                locale: release_notes.locale,
+                changelog: release_notes.changelog,
+                previously_released: release_notes.previously_released,
+                write_pat: shared.write_pat.map(|p| p.value().clone()),
+                wiki: release_notes.wiki,
             },
             _ => panic!("into_release_notes_runner_config called on non-ReleaseNotes variant"),
         }
     }
 
+    /// Converts to AnalyzeRunnerConfig if this is an Analyze variant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called on a non-Analyze variant.
+    pub fn into_analyze_runner_config(self) -> crate::core::runner::analyze::AnalyzeRunnerConfig {
+        match self {
+            AppConfig::Analyze { shared, analyze } => {
+                crate::core::runner::analyze::AnalyzeRunnerConfig {
+                    organization: shared.organization.value().clone(),
+                    project: shared.project.value().clone(),
+                    repository: shared.repository.value().clone(),
+                    pat: shared.pat.value().clone(),
+                    dev_branch: shared.dev_branch.value().clone(),
+                    since: shared
+                        .since
+                        .as_ref()
+                        .and_then(|d| d.original())
+                        .map(String::from),
+                    since_field: shared.since_field,
+                    local_repo: shared.local_repo.map(|p| p.value().clone()),
+                    target_branch: shared.target_branch.value().clone(),
+                    select_by_state: analyze.select_by_state,
+                    plan: analyze.plan,
+                    output_format: analyze.output_format,
+                    max_concurrent_network: *shared.max_concurrent_network.value(),
+                    max_concurrent_processing: *shared.max_concurrent_processing.value(),
+                    api_version: shared.api_version.map(|p| p.value().clone()),
+                }
+            }
+            _ => panic!("into_analyze_runner_config called on non-Analyze variant"),
+        }
+    }
+
     /// Tries to convert to MergeConfig, returning None if not a Default variant.
     pub fn try_into_merge_config(self) -> Option<MergeConfig> {
         match self {
             AppConfig::Default { shared, default } => Some(MergeConfig {
                 shared,
                 work_item_state: default.work_item_state,
+                strict_states: default.strict_states,
+                release_gate: default.release_gate,
+                build_checks: default.build_checks,
+                release_tag: default.release_tag,
+                sign_commits: default.sign_commits,
+                fan_out: default.fan_out,
+                squash_message: default.squash_message,
+                mainline_fallback: default.mainline_fallback,
+                auto_refresh: default.auto_refresh,
+                select_file: default.select_file,
+                pr_tabs: default.pr_tabs,
+                selection_soft_cap: default.selection_soft_cap,
+                terminal_states: default.terminal_states,
+                work_item_comment: default.work_item_comment,
+                artifacts_feed: default.artifacts_feed,
+                work_item_fields: default.work_item_fields,
+                notifications: default.notifications,
+                binary_conflicts: default.binary_conflicts,
+                cherry_pick_order: default.cherry_pick_order,
+                pr_filter: default.pr_filter,
+                session: default.session,
+                clipboard: default.clipboard,
                 run_hooks: default.run_hooks,
             }),
             _ => None,
@@ -1159,180 +845,8 @@ impl AppModeConfig for AppConfig {
             AppConfig::Default { shared, .. }
             | AppConfig::Migration { shared, .. }
             | AppConfig::Cleanup { shared, .. }
-            | AppConfig::ReleaseNotes { shared, .. } => shared,
-        }
-    }
-}
-
-impl Args {
-    /// Resolve configuration from CLI args, environment variables, config file, and git remote
-    /// Priority: CLI args > environment variables > git remote > config file > defaults
-    pub fn resolve_config(self) -> Result<AppConfig> {
-        // Destructure self to extract command
-        let Args {
-            command,
-            create_config: _,
-        } = self;
-
-        // Use command or default to merge mode
-        let mode_command = command.unwrap_or_else(|| {
-            Commands::Merge(MergeArgs {
-                shared: SharedArgs::default(),
-                ni: NonInteractiveArgs::default(),
-                work_item_state: None,
-                run_hooks: false,
-                subcommand: None,
-            })
-        });
-
-        // Access shared args through the command using the trait
-        let shared = mode_command.shared_args();
-
-        // Determine local_repo path from CLI (positional arg takes precedence over --local-repo flag)
-        let cli_local_repo = shared.path.as_ref().or(shared.local_repo.as_ref());
-
-        // Load from config file (lowest priority)
-        let file_config = Config::load_from_file()?;
-
-        // Load from environment variables
-        let env_config = Config::load_from_env();
-
-        // Resolve repo aliases for all commands (supports path or alias via SharedArgs.path)
-        let repo_aliases = file_config.repo_aliases.as_ref().map(|p| p.value().clone());
-        let resolved_local_repo = cli_local_repo.and_then(|path| {
-            crate::config::resolve_repo_path(Some(path), &repo_aliases)
-                .ok()
-                .map(|p| p.to_string_lossy().to_string())
-        });
-
-        // Determine effective local_repo path for git detection
-        // CLI (resolved via aliases) takes precedence, then env var, then config file
-        let effective_local_repo = resolved_local_repo.or_else(|| {
-            env_config
-                .local_repo
-                .as_ref()
-                .map(|p| p.value().clone())
-                .or_else(|| file_config.local_repo.as_ref().map(|p| p.value().clone()))
-        });
-
-        // Try to detect from git remote if we have a local repo path from any source
-        let git_config = if let Some(ref repo_path) = effective_local_repo {
-            Config::detect_from_git_remote(repo_path)
-        } else {
-            Config::default()
-        };
-
-        let cli_config = Config::from_shared_args(shared);
-
-        // Merge configs: file < git_remote < env < cli
-        let merged_config = file_config
-            .merge(git_config)
-            .merge(env_config)
-            .merge(cli_config);
-
-        // Validate required shared fields
-        let organization = merged_config.organization
-            .ok_or_else(|| anyhow::anyhow!("organization is required (use --organization, MERGERS_ORGANIZATION env var, or config file)"))?;
-        let project = merged_config.project.ok_or_else(|| {
-            anyhow::anyhow!(
-                "project is required (use --project, MERGERS_PROJECT env var, or config file)"
-            )
-        })?;
-        let repository = merged_config.repository
-            .ok_or_else(|| anyhow::anyhow!("repository is required (use --repository, MERGERS_REPOSITORY env var, or config file)"))?;
-        let pat = merged_config.pat.ok_or_else(|| {
-            anyhow::anyhow!("pat is required (use --pat, MERGERS_PAT env var, or config file)")
-        })?;
-
-        // Handle since field parsing
-        let since = if let Some(since_str) = &shared.since {
-            let parsed_date = parse_since_date(since_str)
-                .with_context(|| format!("Failed to parse since date: {}", since_str))?;
-            Some(ParsedProperty::Cli(parsed_date, since_str.clone()))
-        } else {
-            None
-        };
-
-        let shared_config = SharedConfig {
-            organization,
-            project,
-            repository,
-            pat,
-            dev_branch: merged_config
-                .dev_branch
-                .unwrap_or_else(|| "dev".to_string().into()),
-            target_branch: merged_config
-                .target_branch
-                .unwrap_or_else(|| "next".to_string().into()),
-            local_repo: merged_config.local_repo,
-            parallel_limit: merged_config.parallel_limit.unwrap_or(300.into()),
-            max_concurrent_network: merged_config.max_concurrent_network.unwrap_or(100.into()),
-            max_concurrent_processing: merged_config.max_concurrent_processing.unwrap_or(10.into()),
-            tag_prefix: merged_config
-                .tag_prefix
-                .unwrap_or_else(|| "merged-".to_string().into()),
-            since,
-            skip_confirmation: shared.skip_confirmation,
-        };
-
-        // Return appropriate configuration based on command
-        match mode_command {
-            Commands::Migrate(migrate_args) => {
-                // Parse terminal states from CLI
-                let terminal_states_parsed = crate::api::AzureDevOpsClient::parse_terminal_states(
-                    &migrate_args.terminal_states,
-                );
-                Ok(AppConfig::Migration {
-                    shared: shared_config,
-                    migration: MigrationModeConfig {
-                        terminal_states: ParsedProperty::Cli(
-                            terminal_states_parsed,
-                            migrate_args.terminal_states,
-                        ),
-                    },
-                })
-            }
-            Commands::Merge(merge_args) => Ok(AppConfig::Default {
-                shared: shared_config,
-                default: DefaultModeConfig {
-                    work_item_state: match merge_args.work_item_state {
-                        Some(state) => ParsedProperty::Cli(state.clone(), state),
-                        None => merged_config
-                            .work_item_state
-                            .unwrap_or_else(|| ParsedProperty::Default("Next Merged".to_string())),
-                    },
-                    run_hooks: if merge_args.run_hooks {
-                        ParsedProperty::Cli(true, "true".to_string())
-                    } else {
-                        merged_config
-                            .run_hooks
-                            .unwrap_or(ParsedProperty::Default(false))
-                    },
-                },
-            }),
-            Commands::Cleanup(cleanup_args) => {
-                let target = cleanup_args
-                    .target
-                    .map(|t| ParsedProperty::Cli(t.clone(), t))
-                    .or_else(|| Some(shared_config.target_branch.clone()))
-                    .unwrap();
-                Ok(AppConfig::Cleanup {
-                    shared: shared_config,
-                    cleanup: CleanupModeConfig { target },
-                })
-            }
-            Commands::ReleaseNotes(rn_args) => Ok(AppConfig::ReleaseNotes {
-                shared: shared_config,
-                release_notes: ReleaseNotesModeConfig {
-                    from_version: rn_args.from.clone(),
-                    to_version: rn_args.to.clone(),
-                    output_format: rn_args.output,
-                    grouped: rn_args.group,
-                    include_prs: rn_args.include_prs,
-                    copy_to_clipboard: rn_args.copy,
-                    no_cache: rn_args.no_cache,
-                },
-            }),
+            | AppConfig::ReleaseNotes { shared, .. }
+            | AppConfig::Analyze { shared, .. } => shared,
         }
     }
 }
@@ -1351,9 +865,21 @@ pub struct PullRequest {
     #[serde(rename = "lastMergeCommit")]
     pub last_merge_commit: Option<MergeCommit>,
     pub labels: Option<Vec<Label>>,
+    #[serde(default)]
+    pub reviewers: Vec<CreatedBy>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+impl PullRequest {
+    /// Returns when the PR was completed, if the date is known and valid.
+    pub fn closed_at(&self) -> Option<DateTime<Utc>> {
+        self.closed_date
+            .as_deref()
+            .and_then(|date| DateTime::parse_from_rfc3339(date).ok())
+            .map(|date| date.with_timezone(&Utc))
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, Deserialize)]
 pub struct CreatedBy {
     #[serde(rename = "displayName")]
     pub display_name: String,
@@ -1384,7 +910,7 @@ pub struct WorkItem {
     pub history: Vec<WorkItemHistory>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, Deserialize)]
 pub struct WorkItemFields {
     #[serde(rename = "System.Title")]
     pub title: Option<String>,
@@ -1401,11 +927,14 @@ pub struct WorkItemFields {
     #[serde(rename = "Microsoft.VSTS.TCM.ReproSteps", default)]
     pub repro_steps: Option<String>,
     /// State color as RGB tuple (r, g, b), populated from Azure DevOps API
-    #[serde(skip_deserializing, default)]
+    #[serde(skip)]
     pub state_color: Option<(u8, u8, u8)>,
+    /// Additional requested fields (e.g. a release gate field), keyed by reference name
+    #[serde(flatten, default)]
+    pub custom_fields: std::collections::HashMap<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, Deserialize)]
 pub struct WorkItemHistory {
     pub rev: i32,
     #[serde(rename = "revisedDate")]
@@ -1414,7 +943,7 @@ pub struct WorkItemHistory {
     pub fields: Option<WorkItemHistoryFields>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, Deserialize)]
 pub struct WorkItemHistoryFields {
     #[serde(rename = "System.State")]
     pub state: Option<WorkItemFieldChange>,
@@ -1422,7 +951,7 @@ pub struct WorkItemHistoryFields {
     pub changed_date: Option<WorkItemFieldChange>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, Deserialize)]
 pub struct WorkItemFieldChange {
     #[serde(rename = "newValue")]
     pub new_value: Option<String>,
@@ -1441,6 +970,16 @@ pub struct PullRequestWithWorkItems {
     pub selected: bool,
 }
 
+impl PullRequestWithWorkItems {
+    /// Titles of the linked work items that have one, by work item ID.
+    pub fn work_item_titles(&self) -> std::collections::BTreeMap<i32, String> {
+        self.work_items
+            .iter()
+            .filter_map(|wi| Some((wi.id, wi.fields.title.clone()?)))
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum CherryPickStatus {
     Pending,
@@ -1491,9 +1030,22 @@ pub struct CleanupBranch {
     pub name: String,
     pub target: String,
     pub version: String,
+    /// Merged into the local target branch.
     pub is_merged: bool,
+    /// Merged into `origin/<target>`; `None` if there is no remote-tracking ref.
+    pub is_merged_remote: Option<bool>,
     pub selected: bool,
     pub status: CleanupStatus,
+    /// Commit the branch pointed to when it was deleted, for restoring it.
+    pub tip: Option<String>,
+}
+
+impl CleanupBranch {
+    /// Returns `true` if the branch is merged locally and, when the remote
+    /// target is known, on origin too.
+    pub fn is_safe_to_delete(&self) -> bool {
+        self.is_merged && self.is_merged_remote != Some(false)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1504,9 +1056,10 @@ pub enum CleanupStatus {
     Failed(String),
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "cli"))]
 mod tests {
     use super::*;
+    use clap::Parser;
     use tempfile::TempDir;
 
     fn create_sample_args() -> Args {
@@ -1518,6 +1071,7 @@ mod tests {
                     project: Some("test-project".to_string()),
                     repository: Some("test-repo".to_string()),
                     pat: Some("test-pat".to_string()),
+                    write_pat: None,
                     dev_branch: Some("dev".to_string()),
                     target_branch: Some("main".to_string()),
                     local_repo: None,
@@ -1526,14 +1080,33 @@ mod tests {
                     max_concurrent_network: Some(20),
                     max_concurrent_processing: Some(5),
                     since: Some("1w".to_string()),
+                    since_field: None,
                     skip_confirmation: true,
                     log_level: None,
                     log_file: None,
                     log_format: None,
+                    profile_run: false,
+                    dump_raw_response: None,
                 },
                 ni: NonInteractiveArgs::default(),
                 work_item_state: Some("Done".to_string()),
                 run_hooks: false,
+                strict_states: false,
+                check_builds: false,
+                require_green_build: false,
+                release_tag: false,
+                push_release_tag: false,
+                sign_commits: false,
+                squash: false,
+                squash_message: None,
+                mainline_fallback: false,
+                auto_refresh: None,
+                select_file: None,
+                author: None,
+                reviewer: None,
+                path_glob: Vec::new(),
+                session: None,
+                cherry_pick_order: None,
                 subcommand: None,
             })),
             create_config: false,
@@ -1549,6 +1122,7 @@ mod tests {
                     project: Some("test-project".to_string()),
                     repository: Some("test-repo".to_string()),
                     pat: Some("test-pat".to_string()),
+                    write_pat: None,
                     dev_branch: Some("dev".to_string()),
                     target_branch: Some("main".to_string()),
                     local_repo: None,
@@ -1557,10 +1131,13 @@ mod tests {
                     max_concurrent_network: Some(20),
                     max_concurrent_processing: Some(5),
                     since: Some("1w".to_string()),
+                    since_field: None,
                     skip_confirmation: true,
                     log_level: None,
                     log_file: None,
                     log_format: None,
+                    profile_run: false,
+                    dump_raw_response: None,
                 },
                 terminal_states: "Closed,Done".to_string(),
             })),
@@ -1577,6 +1154,7 @@ mod tests {
                     project: Some("test-project".to_string()),
                     repository: Some("test-repo".to_string()),
                     pat: Some("test-pat".to_string()),
+                    write_pat: None,
                     dev_branch: Some("dev".to_string()),
                     target_branch: Some("main".to_string()),
                     local_repo: None,
@@ -1585,18 +1163,28 @@ mod tests {
                     max_concurrent_network: Some(20),
                     max_concurrent_processing: Some(5),
                     since: Some("1w".to_string()),
+                    since_field: None,
                     skip_confirmation: true,
                     log_level: None,
                     log_file: None,
                     log_format: None,
+                    profile_run: false,
+                    dump_raw_response: None,
                 },
                 output: ReleaseNotesOutputFormat::Markdown,
                 copy: false,
                 group: false,
                 include_prs: false,
+                include_unreferenced: false,
+                locale: None,
+                changelog: None,
+                previously_released: PreviouslyReleased::Exclude,
+                wiki: None,
+                wiki_page: None,
                 from: Some("v1.0.0".to_string()),
                 to: Some("v2.0.0".to_string()),
                 no_cache: false,
+                subcommand: None,
             })),
             create_config: false,
         }
@@ -1617,6 +1205,7 @@ mod tests {
             labels: Some(vec![Label {
                 name: "feature".to_string(),
             }]),
+            reviewers: Vec::new(),
         }
     }
 
@@ -1634,6 +1223,7 @@ mod tests {
                 description: Some("Test description".to_string()),
                 repro_steps: Some("Steps to reproduce".to_string()),
                 state_color: None,
+                custom_fields: Default::default(),
             },
             history: vec![],
         }
@@ -1688,6 +1278,7 @@ mod tests {
             project: ParsedProperty::Default("test-project".to_string()),
             repository: ParsedProperty::Default("test-repo".to_string()),
             pat: ParsedProperty::Default("test-pat".to_string()),
+            write_pat: None,
             dev_branch: ParsedProperty::Default("dev".to_string()),
             target_branch: ParsedProperty::Default("main".to_string()),
             local_repo: Some(ParsedProperty::Default("/test/repo".to_string())),
@@ -1696,7 +1287,12 @@ mod tests {
             max_concurrent_processing: ParsedProperty::Default(10),
             tag_prefix: ParsedProperty::Default("merged-".to_string()),
             since: None,
+            since_field: Default::default(),
             skip_confirmation: false,
+            api_version: None,
+            history_depth: None,
+            url_templates: Default::default(),
+            release_notes_locale: Default::default(),
         };
 
         assert_eq!(
@@ -1726,6 +1322,28 @@ mod tests {
     fn test_default_config_creation() {
         let default_config = DefaultModeConfig {
             work_item_state: ParsedProperty::Default("Done".to_string()),
+            strict_states: None,
+            release_gate: None,
+            build_checks: Default::default(),
+            release_tag: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            pr_tabs: Vec::new(),
+            selection_soft_cap: None,
+            terminal_states: Vec::new(),
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            notifications: None,
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            pr_filter: Default::default(),
+            session: None,
+            clipboard: Default::default(),
             run_hooks: ParsedProperty::Default(false),
         };
 
@@ -1784,6 +1402,7 @@ mod tests {
             project: ParsedProperty::Default("test-project".to_string()),
             repository: ParsedProperty::Default("test-repo".to_string()),
             pat: ParsedProperty::Default("test-pat".to_string()),
+            write_pat: None,
             dev_branch: ParsedProperty::Default("dev".to_string()),
             target_branch: ParsedProperty::Default("main".to_string()),
             local_repo: None,
@@ -1792,13 +1411,40 @@ mod tests {
             max_concurrent_processing: ParsedProperty::Default(10),
             tag_prefix: ParsedProperty::Default("merged-".to_string()),
             since: None,
+            since_field: Default::default(),
             skip_confirmation: false,
+            api_version: None,
+            history_depth: None,
+            url_templates: Default::default(),
+            release_notes_locale: Default::default(),
         };
 
         let config = AppConfig::Default {
             shared: shared.clone(),
             default: DefaultModeConfig {
                 work_item_state: ParsedProperty::Default("Done".to_string()),
+                strict_states: None,
+                release_gate: None,
+                build_checks: Default::default(),
+                release_tag: Default::default(),
+                sign_commits: false,
+                fan_out: Vec::new(),
+                squash_message: None,
+                mainline_fallback: false,
+                auto_refresh: None,
+                select_file: None,
+                pr_tabs: Vec::new(),
+                selection_soft_cap: None,
+                terminal_states: Vec::new(),
+                work_item_comment: None,
+                artifacts_feed: None,
+                work_item_fields: Vec::new(),
+                notifications: None,
+                binary_conflicts: Default::default(),
+                cherry_pick_order: Default::default(),
+                pr_filter: Default::default(),
+                session: None,
+                clipboard: Default::default(),
                 run_hooks: ParsedProperty::Default(false),
             },
         };
@@ -1828,6 +1474,7 @@ mod tests {
             project: ParsedProperty::Default("test-project".to_string()),
             repository: ParsedProperty::Default("test-repo".to_string()),
             pat: ParsedProperty::Default("test-pat".to_string()),
+            write_pat: None,
             dev_branch: ParsedProperty::Default("dev".to_string()),
             target_branch: ParsedProperty::Default("main".to_string()),
             local_repo: None,
@@ -1836,7 +1483,12 @@ mod tests {
             max_concurrent_processing: ParsedProperty::Default(10),
             tag_prefix: ParsedProperty::Default("merged-".to_string()),
             since: None,
+            since_field: Default::default(),
             skip_confirmation: false,
+            api_version: None,
+            history_depth: None,
+            url_templates: Default::default(),
+            release_notes_locale: Default::default(),
         };
 
         let config = AppConfig::Migration {
@@ -2246,6 +1898,42 @@ mod tests {
         }
     }
 
+    /// # Args Resolve Config (Auto-Refresh)
+    ///
+    /// Tests resolving the PR selection auto-refresh interval.
+    ///
+    /// ## Test Scenario
+    /// - Resolves merge args with `--auto-refresh 3`
+    /// - Resolves the same args in non-interactive mode
+    ///
+    /// ## Expected Outcome
+    /// - The interval is converted from minutes
+    /// - Non-interactive runs have no auto-refresh
+    #[test]
+    fn test_args_resolve_config_auto_refresh() {
+        let mut args = create_sample_args();
+        let Some(Commands::Merge(merge_args)) = args.command.as_mut() else {
+            unreachable!();
+        };
+        merge_args.auto_refresh = Some(std::time::Duration::from_secs(180));
+
+        let AppConfig::Default { default, .. } = args.clone().resolve_config().unwrap() else {
+            panic!("Expected default config");
+        };
+        assert_eq!(
+            default.auto_refresh,
+            Some(std::time::Duration::from_secs(180))
+        );
+
+        if let Some(Commands::Merge(merge_args)) = args.command.as_mut() {
+            merge_args.ni.non_interactive = true;
+        }
+        let AppConfig::Default { default, .. } = args.resolve_config().unwrap() else {
+            panic!("Expected default config");
+        };
+        assert_eq!(default.auto_refresh, None);
+    }
+
     /// # Cherry Pick Status Variants
     ///
     /// Tests all possible cherry pick status enumeration values.
@@ -2548,11 +2236,28 @@ mod tests {
                 project: Some("test-proj".to_string()),
                 repository: Some("test-repo".to_string()),
                 pat: Some("test-pat".to_string()),
+                write_pat: None,
                 ..Default::default()
             },
             ni: NonInteractiveArgs::default(),
             work_item_state: None,
             run_hooks: false,
+            strict_states: false,
+            check_builds: false,
+            require_green_build: false,
+            release_tag: false,
+            push_release_tag: false,
+            sign_commits: false,
+            squash: false,
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            author: None,
+            reviewer: None,
+            path_glob: Vec::new(),
+            session: None,
+            cherry_pick_order: None,
             subcommand: None,
         };
 
@@ -2580,6 +2285,7 @@ mod tests {
                 project: Some("test-proj".to_string()),
                 repository: Some("test-repo".to_string()),
                 pat: Some("test-pat".to_string()),
+                write_pat: None,
                 ..Default::default()
             },
             terminal_states: "Closed,Done".to_string(),
@@ -2612,6 +2318,22 @@ mod tests {
             ni: NonInteractiveArgs::default(),
             work_item_state: None,
             run_hooks: false,
+            strict_states: false,
+            check_builds: false,
+            require_green_build: false,
+            release_tag: false,
+            push_release_tag: false,
+            sign_commits: false,
+            squash: false,
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            author: None,
+            reviewer: None,
+            path_glob: Vec::new(),
+            session: None,
+            cherry_pick_order: None,
             subcommand: None,
         });
 
@@ -2954,6 +2676,42 @@ mod tests {
         }
     }
 
+    /// # Merge with --since-field Parameter
+    ///
+    /// Tests that --since-field selects the date compared against --since.
+    ///
+    /// ## Test Scenario
+    /// - Parses `merge --since 2w --since-field merge-commit`
+    /// - Parses since field names as the config file and environment give them
+    ///
+    /// ## Expected Outcome
+    /// - The field is captured in MergeArgs.shared.since_field
+    /// - Names parse case-insensitively and unknown names are rejected
+    #[test]
+    fn test_merge_with_since_field_parameter() {
+        let args = Args::parse_from([
+            "mergers",
+            "merge",
+            "--since",
+            "2w",
+            "--since-field",
+            "merge-commit",
+        ]);
+
+        if let Some(Commands::Merge(merge_args)) = args.command {
+            assert_eq!(merge_args.shared.since_field, Some(SinceField::MergeCommit));
+        } else {
+            panic!("Expected Merge command");
+        }
+
+        assert_eq!(
+            "Target-Commit".parse::<SinceField>().unwrap(),
+            SinceField::TargetCommit
+        );
+        assert_eq!(SinceField::default().to_string(), "closed");
+        assert!("merged".parse::<SinceField>().is_err());
+    }
+
     /// # Merge with All Non-Interactive Parameters
     ///
     /// Tests that all parameters specific to non-interactive mode are parsed.
@@ -3000,6 +2758,7 @@ mod tests {
             "--output",
             "json",
             "--quiet",
+            "--dry-run",
             "/path/to/repo",
         ]);
 
@@ -3029,6 +2788,7 @@ mod tests {
             assert_eq!(merge_args.ni.version, Some("v2.0.0".to_string()));
             assert!(merge_args.ni.non_interactive);
             assert!(merge_args.ni.quiet);
+            assert!(merge_args.ni.dry_run);
             assert_eq!(merge_args.ni.output, OutputFormat::Json);
         } else {
             panic!("Expected Merge command");
@@ -3449,6 +3209,42 @@ mod tests {
         }
     }
 
+    /// # Dashboard Address
+    ///
+    /// Tests parsing the `--serve` address of the web dashboard.
+    ///
+    /// ## Test Scenario
+    /// - Parses `merge -n --serve` with a bare port, a host and port, and an
+    ///   invalid value
+    ///
+    /// ## Expected Outcome
+    /// - A bare port listens on localhost only
+    /// - A host and port are used as given
+    /// - Invalid values are rejected
+    #[test]
+    fn test_serve_address() {
+        let serve = |value: &str| {
+            Args::try_parse_from([
+                "mergers", "merge", "-n", "-o", "org", "-p", "proj", "-r", "repo", "-t", "pat",
+                "--serve", value,
+            ])
+            .map(|args| match args.command {
+                Some(Commands::Merge(merge_args)) => merge_args.ni.serve,
+                _ => panic!("Expected Merge command"),
+            })
+        };
+
+        assert_eq!(
+            serve("8080").unwrap(),
+            Some("127.0.0.1:8080".parse().unwrap())
+        );
+        assert_eq!(
+            serve("0.0.0.0:9000").unwrap(),
+            Some("0.0.0.0:9000".parse().unwrap())
+        );
+        assert!(serve("localhost").is_err());
+    }
+
     // ========================================================================
     // Default value tests
     // ========================================================================
@@ -3512,6 +3308,9 @@ mod tests {
         assert_eq!(ni.select_by_state, None);
         assert_eq!(ni.output, OutputFormat::Text);
         assert!(!ni.quiet);
+        assert!(!ni.force);
+        assert!(!ni.dry_run);
+        assert_eq!(ni.serve, None);
     }
 
     /// # Clap Default Values Applied at Parse Time
@@ -3851,6 +3650,43 @@ mod tests {
         }
     }
 
+    /// # Top-Level Continue And Abort Commands
+    ///
+    /// Tests that `continue` and `abort` parse like their `merge` subcommands.
+    ///
+    /// ## Test Scenario
+    /// - Parses `mergers continue /path/to/repo --output json -q`
+    /// - Parses `mergers abort --repo /other /path/to/repo`
+    ///
+    /// ## Expected Outcome
+    /// - The positional path is used as the repository
+    /// - `--repo` takes precedence over the positional path
+    #[test]
+    fn test_top_level_continue_and_abort() {
+        let args = Args::parse_from([
+            "mergers",
+            "continue",
+            "/path/to/repo",
+            "--output",
+            "json",
+            "-q",
+        ]);
+        let Some(Commands::Continue(continue_args)) = args.command else {
+            panic!("Expected Continue command");
+        };
+        let merge_args = continue_args.merge_args();
+        assert_eq!(merge_args.repo, Some("/path/to/repo".to_string()));
+        assert_eq!(merge_args.output, OutputFormat::Json);
+        assert!(merge_args.quiet);
+
+        let args = Args::parse_from(["mergers", "abort", "--repo", "/other", "/path/to/repo"]);
+        let Some(Commands::Abort(abort_args)) = args.command else {
+            panic!("Expected Abort command");
+        };
+        assert_eq!(abort_args.merge_args().repo, Some("/other".to_string()));
+        assert_eq!(abort_args.merge_args().output, OutputFormat::Text);
+    }
+
     /// # Merge Status Subcommand Parsing
     ///
     /// Tests that `merge status` subcommand parses correctly with all its flags.
@@ -3907,8 +3743,62 @@ mod tests {
             } else {
                 panic!("Expected Status subcommand");
             }
-        } else {
-            panic!("Expected Merge command");
+        } else {
+            panic!("Expected Merge command");
+        }
+    }
+
+    /// # Merge Recover Subcommand Parsing
+    ///
+    /// Tests that `merge recover` maps its flags to a recovery action.
+    ///
+    /// ## Test Scenario
+    /// - Parses `merge recover` with no flags, `--resume`, and `--export --rescue-branch`
+    /// - Parses conflicting flags and `--rescue-branch` without `--export`
+    ///
+    /// ## Expected Outcome
+    /// - The action is Inspect, Resume, and Export respectively
+    /// - Conflicting flags and a lone `--rescue-branch` are rejected
+    #[test]
+    fn test_merge_recover_subcommand_parsing() {
+        use crate::core::operations::RecoverAction;
+
+        let parse = |extra: &[&str]| {
+            let args = Args::parse_from(["mergers", "merge", "recover"].iter().chain(extra));
+            match args.command {
+                Some(Commands::Merge(MergeArgs {
+                    subcommand: Some(MergeSubcommand::Recover(recover_args)),
+                    ..
+                })) => recover_args,
+                _ => panic!("Expected Recover subcommand"),
+            }
+        };
+
+        let inspect = parse(&[]);
+        assert_eq!(inspect.action(), RecoverAction::Inspect);
+        assert_eq!(inspect.output, OutputFormat::Text);
+
+        assert_eq!(parse(&["--resume"]).action(), RecoverAction::Resume);
+        assert_eq!(parse(&["--abort"]).action(), RecoverAction::Abort);
+
+        let export = parse(&[
+            "--export",
+            "--rescue-branch",
+            "rescue/x",
+            "--output",
+            "json",
+        ]);
+        assert_eq!(export.action(), RecoverAction::Export);
+        assert_eq!(export.rescue_branch, Some("rescue/x".to_string()));
+        assert_eq!(export.output, OutputFormat::Json);
+
+        for invalid in [
+            &["--resume", "--abort"][..],
+            &["--abort", "--export"][..],
+            &["--rescue-branch", "rescue/x"][..],
+        ] {
+            let argv = ["mergers", "merge", "recover"].iter().chain(invalid);
+            assert!(Args::try_parse_from(argv).is_err(), "{:?}", invalid);
         }
     }
 
@@ -4974,6 +4864,50 @@ mod tests {
         }
     }
 
+    /// # Select File Parsing
+    ///
+    /// Tests that --select-file is parsed and carried into the merge config.
+    ///
+    /// ## Test Scenario
+    /// - Parses merge with --select-file and required shared args
+    /// - Resolves the configuration
+    ///
+    /// ## Expected Outcome
+    /// - The path is kept in the merge args and in the resolved config
+    #[test]
+    fn test_select_file_parsing() {
+        let args = Args::parse_from([
+            "mergers",
+            "merge",
+            "--organization",
+            "org",
+            "--project",
+            "proj",
+            "--repository",
+            "repo",
+            "--pat",
+            "pat",
+            "--select-file",
+            "picks.txt",
+        ]);
+
+        let Some(Commands::Merge(merge_args)) = &args.command else {
+            panic!("Expected Merge command");
+        };
+        assert_eq!(
+            merge_args.select_file,
+            Some(std::path::PathBuf::from("picks.txt"))
+        );
+
+        let AppConfig::Default { default, .. } = args.resolve_config().unwrap() else {
+            panic!("Expected default config");
+        };
+        assert_eq!(
+            default.select_file,
+            Some(std::path::PathBuf::from("picks.txt"))
+        );
+    }
+
     /// # Version Flag Parsing
     ///
     /// Tests that --version on merge (not the binary --version) is parsed.
@@ -5068,6 +5002,22 @@ mod tests {
             ni: NonInteractiveArgs::default(),
             work_item_state: None,
             run_hooks: false,
+            strict_states: false,
+            check_builds: false,
+            require_green_build: false,
+            release_tag: false,
+            push_release_tag: false,
+            sign_commits: false,
+            squash: false,
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            author: None,
+            reviewer: None,
+            path_glob: Vec::new(),
+            session: None,
+            cherry_pick_order: None,
             subcommand: None,
         });
         merge_cmd.shared_args_mut().organization = Some("mutated".to_string());
@@ -5120,6 +5070,7 @@ mod tests {
                     project: Some("proj".to_string()),
                     repository: Some("repo".to_string()),
                     pat: Some("pat".to_string()),
+                    write_pat: None,
                     ..Default::default()
                 },
                 target: Some("main".to_string()),
@@ -5450,6 +5401,400 @@ mod tests {
         }
     }
 
+    // ========================================================================
+    // Analyze command parsing tests
+    // ========================================================================
+
+    /// # Analyze Command Parsing
+    ///
+    /// Tests parsing `mergers analyze` and resolving the report format.
+    ///
+    /// ## Test Scenario
+    /// - Parses analyze with a `.json` output, a `.dot` output, an explicit `--format`,
+    ///   and `--plan`
+    ///
+    /// ## Expected Outcome
+    /// - The format is inferred from the output extension unless `--format` is given
+    /// - `--plan` is off unless given
+    #[test]
+    fn test_analyze_command_parsing() {
+        let parse = |args: &[&str]| {
+            let args = Args::parse_from(args);
+            let Some(Commands::Analyze(analyze_args)) = args.command else {
+                panic!("Expected Analyze command");
+            };
+            analyze_args
+        };
+
+        let args = parse(&[
+            "mergers",
+            "analyze",
+            "--since",
+            "2w",
+            "--output",
+            "graph.json",
+        ]);
+        assert_eq!(args.shared.since.as_deref(), Some("2w"));
+        assert_eq!(args.output_format(), AnalyzeOutputFormat::Json);
+
+        let args = parse(&["mergers", "analyze", "--output", "graph.dot"]);
+        assert_eq!(args.output_format(), AnalyzeOutputFormat::Dot);
+
+        let args = parse(&[
+            "mergers",
+            "analyze",
+            "--output",
+            "graph.dot",
+            "--format",
+            "json",
+        ]);
+        assert_eq!(args.output_format(), AnalyzeOutputFormat::Json);
+        assert_eq!(
+            parse(&["mergers", "analyze"]).output_format(),
+            AnalyzeOutputFormat::Json
+        );
+        assert!(!parse(&["mergers", "analyze"]).plan);
+        assert!(parse(&["mergers", "analyze", "--plan"]).plan);
+    }
+
+    // ========================================================================
+    // Watch command parsing tests
+    // ========================================================================
+
+    /// # Watch Command Parsing
+    ///
+    /// Tests parsing `mergers watch` options.
+    ///
+    /// ## Test Scenario
+    /// - Parses watch with defaults, with explicit options, and with a zero interval
+    ///
+    /// ## Expected Outcome
+    /// - Defaults to a 5 minute interval with bell and desktop notifications
+    /// - Explicit options are applied
+    /// - A zero interval is rejected
+    #[test]
+    fn test_watch_command_parsing() {
+        let args = Args::parse_from(["mergers", "watch"]);
+        let Some(Commands::Watch(watch_args)) = args.command else {
+            panic!("Expected Watch command");
+        };
+        assert_eq!(watch_args.interval, std::time::Duration::from_secs(300));
+        assert_eq!(watch_args.notify, WatchNotify::Both);
+        assert!(watch_args.notify.bell() && watch_args.notify.desktop());
+
+        let args = Args::parse_from([
+            "mergers",
+            "watch",
+            "--interval",
+            "90s",
+            "--notify",
+            "bell",
+            "--select-by-state",
+            "Ready for Next",
+        ]);
+        let Some(Commands::Watch(watch_args)) = args.command else {
+            panic!("Expected Watch command");
+        };
+        assert_eq!(watch_args.interval, std::time::Duration::from_secs(90));
+        assert!(watch_args.notify.bell());
+        assert!(!watch_args.notify.desktop());
+        assert_eq!(
+            watch_args.select_by_state.as_deref(),
+            Some("Ready for Next")
+        );
+
+        assert!(Args::try_parse_from(["mergers", "watch", "--interval", "0"]).is_err());
+    }
+
+    // ========================================================================
+    // Metrics command parsing tests
+    // ========================================================================
+
+    /// # Metrics Command Parsing
+    ///
+    /// Tests parsing `mergers metrics` and resolving the export format.
+    ///
+    /// ## Test Scenario
+    /// - Parses metrics with defaults, a `.csv` output, and an explicit `--format`
+    ///
+    /// ## Expected Outcome
+    /// - Defaults to JSON for the configured repository only
+    /// - The format is inferred from the output extension unless `--format` is given
+    #[test]
+    fn test_metrics_command_parsing() {
+        let parse = |args: &[&str]| {
+            let args = Args::parse_from(args);
+            let Some(Commands::Metrics(metrics_args)) = args.command else {
+                panic!("Expected Metrics command");
+            };
+            metrics_args
+        };
+
+        let args = parse(&["mergers", "metrics"]);
+        assert_eq!(args.output_format(), MetricsOutputFormat::Json);
+        assert!(!args.all_repositories);
+
+        let args = parse(&["mergers", "metrics", "--output", "metrics.csv"]);
+        assert_eq!(args.output_format(), MetricsOutputFormat::Csv);
+
+        let args = parse(&[
+            "mergers",
+            "metrics",
+            "--output",
+            "metrics.csv",
+            "--format",
+            "json",
+            "--all-repositories",
+        ]);
+        assert_eq!(args.output_format(), MetricsOutputFormat::Json);
+        assert!(args.all_repositories);
+    }
+
+    /// # Gc Command Parsing
+    ///
+    /// Tests parsing `mergers gc` with and without its options.
+    ///
+    /// ## Test Scenario
+    /// - Parses gc with defaults, then with `--max-age` and `--dry-run`
+    /// - Parses `--max-age` with an explicit unit
+    ///
+    /// ## Expected Outcome
+    /// - By default every finished run is removed for real
+    /// - The options are captured, a bare age is read in days
+    #[test]
+    fn test_gc_command_parsing() {
+        let parse = |args: &[&str]| {
+            let args = Args::parse_from(args);
+            let Some(Commands::Gc(gc_args)) = args.command else {
+                panic!("Expected Gc command");
+            };
+            gc_args
+        };
+
+        let args = parse(&["mergers", "gc"]);
+        assert_eq!(args.max_age, None);
+        assert!(!args.dry_run);
+
+        let args = parse(&["mergers", "gc", "--max-age", "7", "--dry-run"]);
+        assert_eq!(
+            args.max_age,
+            Some(std::time::Duration::from_secs(7 * 86_400))
+        );
+        assert!(args.dry_run);
+
+        let args = parse(&["mergers", "gc", "--max-age", "36h"]);
+        assert_eq!(
+            args.max_age,
+            Some(std::time::Duration::from_secs(36 * 3600))
+        );
+    }
+
+    /// # State Upgrade Command Parsing
+    ///
+    /// Tests parsing `mergers state upgrade` with and without its options.
+    ///
+    /// ## Test Scenario
+    /// - Parses state upgrade with defaults, then with `--repo` and `--dry-run`
+    ///
+    /// ## Expected Outcome
+    /// - By default every state file is upgraded for real
+    /// - The options are captured
+    #[test]
+    fn test_state_upgrade_command_parsing() {
+        let parse = |args: &[&str]| {
+            let args = Args::parse_from(args);
+            let Some(Commands::State(StateArgs {
+                subcommand: StateSubcommand::Upgrade(upgrade_args),
+                ..
+            })) = args.command
+            else {
+                panic!("Expected State Upgrade command");
+            };
+            upgrade_args
+        };
+
+        let args = parse(&["mergers", "state", "upgrade"]);
+        assert_eq!(args.repo, None);
+        assert!(!args.dry_run);
+
+        let args = parse(&[
+            "mergers",
+            "state",
+            "upgrade",
+            "--repo",
+            "/repo",
+            "--dry-run",
+        ]);
+        assert_eq!(args.repo.as_deref(), Some("/repo"));
+        assert!(args.dry_run);
+    }
+
+    // ========================================================================
+    // Promote command parsing tests
+    // ========================================================================
+
+    /// # Promote Command Parsing
+    ///
+    /// Tests parsing `mergers promote` and deriving the release version.
+    ///
+    /// ## Test Scenario
+    /// - Parses `promote --from 1.2.0-rc2` with and without `--version`
+    ///
+    /// ## Expected Outcome
+    /// - The release version defaults to the RC version without its suffix
+    /// - `--version` overrides the derived version
+    #[test]
+    fn test_promote_command_parsing() {
+        let args = Args::parse_from(["mergers", "promote", "--from", "1.2.0-rc2"]);
+        let Some(Commands::Promote(promote_args)) = args.command else {
+            panic!("Expected Promote command");
+        };
+        assert_eq!(promote_args.release_version(), Some("1.2.0".to_string()));
+        let merge_args = promote_args.to_merge_args("1.2.0".to_string());
+        assert!(merge_args.ni.non_interactive);
+        assert_eq!(merge_args.ni.version.as_deref(), Some("1.2.0"));
+
+        let args = Args::parse_from([
+            "mergers",
+            "promote",
+            "--from",
+            "1.2.0-rc2",
+            "--version",
+            "1.2.1",
+        ]);
+        let Some(Commands::Promote(promote_args)) = args.command else {
+            panic!("Expected Promote command");
+        };
+        assert_eq!(promote_args.release_version(), Some("1.2.1".to_string()));
+    }
+
+    // ========================================================================
+    // Batch command parsing tests
+    // ========================================================================
+
+    /// # Release Notes From State Parsing
+    ///
+    /// Tests parsing `mergers release-notes from-state`.
+    ///
+    /// ## Test Scenario
+    /// - Parses `rn from-state` with a run, HTML format and PR links
+    /// - Parses `release-notes` with a repository alias and no subcommand
+    /// - Parses `from-state` with both a run and a state file
+    ///
+    /// ## Expected Outcome
+    /// - The subcommand carries its options
+    /// - The alias is still a positional argument, not a subcommand
+    /// - A run and a state file are rejected together
+    #[test]
+    fn test_release_notes_from_state_parsing() {
+        let args = Args::parse_from([
+            "mergers",
+            "rn",
+            "from-state",
+            "--run",
+            "20260101T000000Z-1",
+            "--format",
+            "html",
+            "--include-prs",
+        ]);
+        let Some(Commands::ReleaseNotes(rn_args)) = args.command else {
+            panic!("Expected ReleaseNotes command");
+        };
+        let Some(ReleaseNotesSubcommand::FromState(from_state)) = rn_args.subcommand else {
+            panic!("Expected from-state subcommand");
+        };
+        assert_eq!(from_state.run.as_deref(), Some("20260101T000000Z-1"));
+        assert_eq!(from_state.format, ReleaseNotesOutputFormat::Html);
+        assert!(from_state.include_prs);
+        assert!(!from_state.group);
+
+        let args = Args::parse_from(["mergers", "release-notes", "th"]);
+        let Some(Commands::ReleaseNotes(rn_args)) = args.command else {
+            panic!("Expected ReleaseNotes command");
+        };
+        assert!(rn_args.subcommand.is_none());
+        assert_eq!(rn_args.shared.path.as_deref(), Some("th"));
+
+        assert!(
+            Args::try_parse_from([
+                "mergers",
+                "rn",
+                "from-state",
+                "--run",
+                "x",
+                "--state-file",
+                "state.json",
+            ])
+            .is_err()
+        );
+    }
+
+    /// # Batch Command Parsing
+    ///
+    /// Tests parsing `mergers batch` and building the merge arguments of a
+    /// `[[repos]]` entry.
+    ///
+    /// ## Test Scenario
+    /// - Parses `batch` with a version, repository filter, parallelism and
+    ///   shared organization and project
+    /// - Builds the merge arguments for an entry overriding the project
+    /// - Parses `batch --parallel 0`
+    ///
+    /// ## Expected Outcome
+    /// - The filter is split on commas
+    /// - The entry's settings win over the shared arguments, the rest are kept
+    /// - A parallelism of 0 is rejected
+    #[test]
+    fn test_batch_command_parsing() {
+        let args = Args::parse_from([
+            "mergers",
+            "batch",
+            "--version",
+            "v1.2.0",
+            "--repos",
+            "api,web",
+            "--parallel",
+            "2",
+            "-o",
+            "contoso",
+            "-p",
+            "Platform",
+            "--local-repo",
+            "/src/shared",
+        ]);
+        let Some(Commands::Batch(batch_args)) = args.command else {
+            panic!("Expected Batch command");
+        };
+        assert_eq!(batch_args.repos, vec!["api", "web"]);
+        assert_eq!(batch_args.parallel, 2);
+
+        let repo = crate::core::runner::BatchRepoConfig {
+            name: Some("web".to_string()),
+            organization: None,
+            project: Some("Web".to_string()),
+            repository: "web-frontend".to_string(),
+            local_repo: None,
+            dev_branch: None,
+            target_branch: Some("release".to_string()),
+        };
+        let merge_args = batch_args.to_merge_args(&repo);
+        assert!(merge_args.ni.non_interactive);
+        assert_eq!(merge_args.ni.version.as_deref(), Some("v1.2.0"));
+        assert_eq!(merge_args.shared.organization.as_deref(), Some("contoso"));
+        assert_eq!(merge_args.shared.project.as_deref(), Some("Web"));
+        assert_eq!(
+            merge_args.shared.repository.as_deref(),
+            Some("web-frontend")
+        );
+        assert_eq!(merge_args.shared.target_branch.as_deref(), Some("release"));
+        assert_eq!(merge_args.shared.local_repo, None);
+
+        assert!(
+            Args::try_parse_from(["mergers", "batch", "--version", "v1", "--parallel", "0"])
+                .is_err()
+        );
+    }
+
     // ========================================================================
     // ReleaseNotes command parsing tests
     // ========================================================================
@@ -5666,7 +6011,7 @@ mod tests {
     /// - Parses release-notes with all boolean flags
     ///
     /// ## Expected Outcome
-    /// - copy, group, include_prs, no_cache are all true
+    /// - copy, group, include_prs, include_unreferenced, no_cache are all true
     #[test]
     fn test_release_notes_boolean_flags_activated() {
         let args = Args::parse_from([
@@ -5675,6 +6020,7 @@ mod tests {
             "--copy",
             "--group",
             "--include-prs",
+            "--include-unreferenced",
             "--no-cache",
         ]);
 
@@ -5682,6 +6028,7 @@ mod tests {
             assert!(rn_args.copy);
             assert!(rn_args.group);
             assert!(rn_args.include_prs);
+            assert!(rn_args.include_unreferenced);
             assert!(rn_args.no_cache);
         } else {
             panic!("Expected ReleaseNotes command");
@@ -5791,7 +6138,7 @@ mod tests {
     /// Tests that all ReleaseNotesOutputFormat values parse correctly.
     ///
     /// ## Test Scenario
-    /// - Parses --output with each valid value (markdown, json, plain)
+    /// - Parses --output with each valid value (markdown, json, plain, html)
     ///
     /// ## Expected Outcome
     /// - Each string maps to the correct enum variant
@@ -5801,6 +6148,7 @@ mod tests {
             ("markdown", ReleaseNotesOutputFormat::Markdown),
             ("json", ReleaseNotesOutputFormat::Json),
             ("plain", ReleaseNotesOutputFormat::Plain),
+            ("html", ReleaseNotesOutputFormat::Html),
         ] {
             let args = Args::parse_from(["mergers", "rn", "--output", input]);
 
@@ -5859,12 +6207,13 @@ mod tests {
     /// - Calls to_string() on each variant
     ///
     /// ## Expected Outcome
-    /// - Markdown -> "markdown", Json -> "json", Plain -> "plain"
+    /// - Markdown -> "markdown", Json -> "json", Plain -> "plain", Html -> "html"
     #[test]
     fn test_release_notes_output_format_display() {
         assert_eq!(ReleaseNotesOutputFormat::Markdown.to_string(), "markdown");
         assert_eq!(ReleaseNotesOutputFormat::Json.to_string(), "json");
         assert_eq!(ReleaseNotesOutputFormat::Plain.to_string(), "plain");
+        assert_eq!(ReleaseNotesOutputFormat::Html.to_string(), "html");
     }
 
     // ========================================================================
@@ -5949,9 +6298,16 @@ mod tests {
             copy: false,
             group: false,
             include_prs: false,
+            include_unreferenced: false,
+            locale: None,
+            changelog: None,
+            previously_released: PreviouslyReleased::Exclude,
+            wiki: None,
+            wiki_page: None,
             from: None,
             to: None,
             no_cache: false,
+            subcommand: None,
         });
 
         assert!(cmd.is_release_notes());
@@ -5974,6 +6330,22 @@ mod tests {
             ni: NonInteractiveArgs::default(),
             work_item_state: None,
             run_hooks: false,
+            strict_states: false,
+            check_builds: false,
+            require_green_build: false,
+            release_tag: false,
+            push_release_tag: false,
+            sign_commits: false,
+            squash: false,
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            author: None,
+            reviewer: None,
+            path_glob: Vec::new(),
+            session: None,
+            cherry_pick_order: None,
             subcommand: None,
         });
         let migrate_cmd = Commands::Migrate(MigrateArgs {
@@ -6011,9 +6383,16 @@ mod tests {
             copy: false,
             group: false,
             include_prs: false,
+            include_unreferenced: false,
+            locale: None,
+            changelog: None,
+            previously_released: PreviouslyReleased::Exclude,
+            wiki: None,
+            wiki_page: None,
             from: None,
             to: None,
             no_cache: false,
+            subcommand: None,
         });
 
         assert_eq!(
@@ -6040,9 +6419,16 @@ mod tests {
             copy: false,
             group: false,
             include_prs: false,
+            include_unreferenced: false,
+            locale: None,
+            changelog: None,
+            previously_released: PreviouslyReleased::Exclude,
+            wiki: None,
+            wiki_page: None,
             from: None,
             to: None,
             no_cache: false,
+            subcommand: None,
         });
 
         rn_cmd.shared_args_mut().organization = Some("mutated-org".to_string());
@@ -6075,9 +6461,16 @@ mod tests {
             copy: false,
             group: false,
             include_prs: false,
+            include_unreferenced: false,
+            locale: None,
+            changelog: None,
+            previously_released: PreviouslyReleased::Exclude,
+            wiki: None,
+            wiki_page: None,
             from: None,
             to: None,
             no_cache: false,
+            subcommand: None,
         };
 
         assert_eq!(
@@ -6093,6 +6486,58 @@ mod tests {
         );
     }
 
+    /// # Release Notes Wiki Target
+    ///
+    /// Tests combining the wiki flags with the `[release_notes_wiki]` table.
+    ///
+    /// ## Test Scenario
+    /// - Resolves the target with and without the table and with one or both flags
+    ///
+    /// ## Expected Outcome
+    /// - Flags override the table's fields and keep its project
+    /// - Both flags are needed without the table
+    /// - No target without flags or table
+    #[test]
+    fn test_release_notes_wiki_target() {
+        use crate::api::WikiPageTarget;
+
+        let Some(Commands::ReleaseNotes(mut rn_args)) = create_sample_release_notes_args().command
+        else {
+            panic!("Expected ReleaseNotes command");
+        };
+        let configured = WikiPageTarget {
+            wiki: "Team.wiki".to_string(),
+            page: "/Releases/{version}".to_string(),
+            project: Some("Platform".to_string()),
+        };
+
+        assert_eq!(rn_args.wiki_target(None).unwrap(), None);
+        assert_eq!(
+            rn_args.wiki_target(Some(configured.clone())).unwrap(),
+            Some(configured.clone())
+        );
+
+        rn_args.wiki_page = Some("/Notes/{version}".to_string());
+        assert_eq!(
+            rn_args.wiki_target(Some(configured.clone())).unwrap(),
+            Some(WikiPageTarget {
+                page: "/Notes/{version}".to_string(),
+                ..configured.clone()
+            })
+        );
+        assert!(rn_args.wiki_target(None).is_err());
+
+        rn_args.wiki = Some("Other.wiki".to_string());
+        assert_eq!(
+            rn_args.wiki_target(None).unwrap(),
+            Some(WikiPageTarget {
+                wiki: "Other.wiki".to_string(),
+                page: "/Notes/{version}".to_string(),
+                project: None,
+            })
+        );
+    }
+
     // ========================================================================
     // Release-notes config resolution tests
     // ========================================================================
@@ -6168,6 +6613,7 @@ mod tests {
             );
             assert!(!release_notes.grouped);
             assert!(!release_notes.include_prs);
+            assert!(!release_notes.include_unreferenced);
             assert!(!release_notes.copy_to_clipboard);
             assert!(!release_notes.no_cache);
         } else {
@@ -6751,6 +7197,50 @@ mod tests {
         assert!(!skip_args.quiet);
     }
 
+    /// # Merge Resolve Subcommand Parsing
+    ///
+    /// Tests that `merge resolve` parses and picks the resolution tool.
+    ///
+    /// ## Test Scenario
+    /// - Parses `mergers merge resolve --tool meld --no-continue`
+    /// - Parses `mergers merge resolve --mergetool`
+    ///
+    /// ## Expected Outcome
+    /// - `--tool` selects git mergetool with that tool and implies `--mergetool`
+    /// - `--mergetool` alone uses git's configured merge tool
+    #[test]
+    fn test_merge_resolve_subcommand_parsing() {
+        use crate::core::operations::ResolveTool;
+
+        let parse = |args: &[&str]| {
+            let Some(Commands::Merge(merge_args)) = Args::parse_from(args).command else {
+                panic!("Expected Merge command");
+            };
+            let Some(MergeSubcommand::Resolve(resolve_args)) = merge_args.subcommand else {
+                panic!("Expected Resolve subcommand");
+            };
+            resolve_args
+        };
+
+        let args = parse(&[
+            "mergers",
+            "merge",
+            "resolve",
+            "--tool",
+            "meld",
+            "--no-continue",
+        ]);
+        assert!(args.no_continue);
+        assert_eq!(
+            args.resolve_tool(),
+            ResolveTool::MergeTool(Some("meld".to_string()))
+        );
+
+        let args = parse(&["mergers", "merge", "resolve", "--mergetool"]);
+        assert!(!args.no_continue);
+        assert_eq!(args.resolve_tool(), ResolveTool::MergeTool(None));
+    }
+
     /// # Quiet Flag on Abort Subcommand
     ///
     /// Tests that -q works on merge abort subcommand.
@@ -0,0 +1,2308 @@
+//! Command-line arguments of the `mergers` binary.
+//!
+//! Only compiled with the `cli` feature. The argument structs are
+//! re-exported from [`crate::models`], and [`Args::resolve_config`] turns
+//! them into an [`AppConfig`] using the config file, environment variables
+//! and git remote as fallbacks.
+
+use super::{
+    AnalyzeModeConfig, AnalyzeOutputFormat, AppConfig, CleanupModeConfig, DefaultModeConfig,
+    MetricsOutputFormat, MigrationModeConfig, OutputFormat, PreviouslyReleased,
+    ReleaseNotesModeConfig, ReleaseNotesOutputFormat, SharedConfig, SinceField, WatchNotify,
+};
+use crate::{
+    config::Config,
+    core::operations::resolve_target_branches,
+    core::output::ReportTarget,
+    parsed_property::ParsedProperty,
+    utils::{TimeUnit, parse_duration_in, parse_since_date},
+};
+use anyhow::{Context, Result};
+use clap::{
+    Args as ClapArgs, Parser, Subcommand,
+    builder::{Styles, styling::AnsiColor},
+};
+use std::net::{Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+/// Build a version string that includes the git commit hash
+fn build_version() -> &'static str {
+    // Use concat! with env! to create a compile-time constant string
+    concat!(env!("CARGO_PKG_VERSION"), " (", env!("GIT_HASH"), ")")
+}
+
+/// Define custom styles for colorized help output
+fn help_styles() -> Styles {
+    Styles::styled()
+        .header(AnsiColor::Yellow.on_default().bold())
+        .usage(AnsiColor::Yellow.on_default().bold())
+        .literal(AnsiColor::Green.on_default().bold())
+        .placeholder(AnsiColor::Cyan.on_default())
+        .valid(AnsiColor::Green.on_default())
+        .invalid(AnsiColor::Red.on_default())
+        .error(AnsiColor::Red.on_default().bold())
+}
+
+/// Apply syntax highlighting to shell examples
+fn highlight_shell(content: &str) -> String {
+    use clap::builder::styling::AnsiColor;
+
+    let comment_style = AnsiColor::BrightBlack.on_default();
+    let command_style = AnsiColor::Green.on_default().bold();
+    let flag_style = AnsiColor::Cyan.on_default();
+    let string_style = AnsiColor::Yellow.on_default();
+    let reset = AnsiColor::White.on_default();
+
+    let mut result = String::new();
+    let mut in_command_block = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+
+        // Handle comment lines
+        if trimmed.starts_with('#') {
+            result.push_str(&format!("{comment_style}{line}{reset:#}\n"));
+            in_command_block = false;
+            continue;
+        }
+
+        // Handle empty lines
+        if trimmed.is_empty() {
+            result.push('\n');
+            in_command_block = false;
+            continue;
+        }
+
+        // Check if this is a shell command line (starts with known command or continuation)
+        let is_command_line = trimmed.starts_with("mergers")
+            || (in_command_block
+                && trimmed
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c == '-' || c.is_whitespace()));
+
+        // Non-command lines (like "For more information...") - just output as-is
+        if !is_command_line && !in_command_block {
+            result.push_str(line);
+            result.push('\n');
+            continue;
+        }
+
+        // Track if line ends with continuation
+        let has_continuation = line.trim_end().ends_with('\\');
+        in_command_block = has_continuation;
+
+        // Preserve leading whitespace
+        let leading_spaces = line.len() - trimmed.len();
+        result.push_str(&" ".repeat(leading_spaces));
+
+        // Simple tokenization for shell highlighting
+        let mut chars = trimmed.chars().peekable();
+        let mut current_token = String::new();
+        let mut in_string = false;
+        let mut string_char = ' ';
+        let mut is_first_token = trimmed.starts_with("mergers");
+
+        while let Some(ch) = chars.next() {
+            match ch {
+                '"' | '\'' if !in_string => {
+                    // Flush current token
+                    if !current_token.is_empty() {
+                        if is_first_token {
+                            result.push_str(&format!("{command_style}{current_token}{reset:#}"));
+                            is_first_token = false;
+                        } else if current_token.starts_with('-') {
+                            result.push_str(&format!("{flag_style}{current_token}{reset:#}"));
+                        } else {
+                            result.push_str(&current_token);
+                        }
+                        current_token.clear();
+                    }
+                    // Start string
+                    in_string = true;
+                    string_char = ch;
+                    current_token.push(ch);
+                }
+                c if c == string_char && in_string => {
+                    // End string
+                    current_token.push(ch);
+                    result.push_str(&format!("{string_style}{current_token}{reset:#}"));
+                    current_token.clear();
+                    in_string = false;
+                }
+                ' ' | '\t' if !in_string => {
+                    // Token boundary
+                    if !current_token.is_empty() {
+                        if is_first_token {
+                            result.push_str(&format!("{command_style}{current_token}{reset:#}"));
+                            is_first_token = false;
+                        } else if current_token.starts_with('-') {
+                            result.push_str(&format!("{flag_style}{current_token}{reset:#}"));
+                        } else if current_token.starts_with('<') && current_token.ends_with('>') {
+                            result.push_str(&format!("{string_style}{current_token}{reset:#}"));
+                        } else {
+                            result.push_str(&current_token);
+                        }
+                        current_token.clear();
+                    }
+                    result.push(ch);
+                }
+                '\\' if chars.peek() == Some(&'\n') => {
+                    // Line continuation
+                    if !current_token.is_empty() {
+                        if is_first_token {
+                            result.push_str(&format!("{command_style}{current_token}{reset:#}"));
+                            is_first_token = false;
+                        } else if current_token.starts_with('-') {
+                            result.push_str(&format!("{flag_style}{current_token}{reset:#}"));
+                        } else {
+                            result.push_str(&current_token);
+                        }
+                        current_token.clear();
+                    }
+                    result.push_str("\\\n");
+                    chars.next(); // consume the newline
+                }
+                _ => {
+                    current_token.push(ch);
+                }
+            }
+        }
+
+        // Flush remaining token
+        if !current_token.is_empty() {
+            if is_first_token {
+                result.push_str(&format!("{command_style}{current_token}{reset:#}"));
+            } else if current_token.starts_with('-') {
+                result.push_str(&format!("{flag_style}{current_token}{reset:#}"));
+            } else if current_token.starts_with('<') && current_token.ends_with('>') {
+                result.push_str(&format!("{string_style}{current_token}{reset:#}"));
+            } else {
+                result.push_str(&current_token);
+            }
+        }
+
+        result.push('\n');
+    }
+
+    result
+}
+
+/// Build styled after_help text with colorized EXAMPLES header and syntax highlighting
+fn styled_examples(content: &str) -> String {
+    let header_style = AnsiColor::Yellow.on_default().bold();
+    let highlighted = highlight_shell(content);
+    format!("{header_style}EXAMPLES:{header_style:#}\n{highlighted}")
+}
+
+/// Main command examples
+fn main_examples() -> &'static str {
+    use std::sync::OnceLock;
+    static EXAMPLES: OnceLock<String> = OnceLock::new();
+    EXAMPLES.get_or_init(|| styled_examples(include_str!("../../docs/examples/main.txt")))
+}
+
+/// Merge command examples
+fn merge_examples() -> &'static str {
+    use std::sync::OnceLock;
+    static EXAMPLES: OnceLock<String> = OnceLock::new();
+    EXAMPLES.get_or_init(|| styled_examples(include_str!("../../docs/examples/merge.txt")))
+}
+
+/// Migrate command examples
+fn migrate_examples() -> &'static str {
+    use std::sync::OnceLock;
+    static EXAMPLES: OnceLock<String> = OnceLock::new();
+    EXAMPLES.get_or_init(|| styled_examples(include_str!("../../docs/examples/migrate.txt")))
+}
+
+/// Cleanup command examples
+fn cleanup_examples() -> &'static str {
+    use std::sync::OnceLock;
+    static EXAMPLES: OnceLock<String> = OnceLock::new();
+    EXAMPLES.get_or_init(|| styled_examples(include_str!("../../docs/examples/cleanup.txt")))
+}
+
+/// Release-notes command examples
+fn release_notes_examples() -> &'static str {
+    use std::sync::OnceLock;
+    static EXAMPLES: OnceLock<String> = OnceLock::new();
+    EXAMPLES.get_or_init(|| styled_examples(include_str!("../../docs/examples/release-notes.txt")))
+}
+
+/// Release-notes from-state subcommand examples
+fn release_notes_from_state_examples() -> &'static str {
+    use std::sync::OnceLock;
+    static EXAMPLES: OnceLock<String> = OnceLock::new();
+    EXAMPLES.get_or_init(|| {
+        styled_examples(include_str!(
+            "../../docs/examples/release-notes-from-state.txt"
+        ))
+    })
+}
+
+/// Promote command examples
+fn promote_examples() -> &'static str {
+    use std::sync::OnceLock;
+    static EXAMPLES: OnceLock<String> = OnceLock::new();
+    EXAMPLES.get_or_init(|| styled_examples(include_str!("../../docs/examples/promote.txt")))
+}
+
+/// Returns styled examples for the analyze command.
+fn analyze_examples() -> &'static str {
+    use std::sync::OnceLock;
+    static EXAMPLES: OnceLock<String> = OnceLock::new();
+    EXAMPLES.get_or_init(|| styled_examples(include_str!("../../docs/examples/analyze.txt")))
+}
+
+/// Returns styled examples for the watch command.
+fn watch_examples() -> &'static str {
+    use std::sync::OnceLock;
+    static EXAMPLES: OnceLock<String> = OnceLock::new();
+    EXAMPLES.get_or_init(|| styled_examples(include_str!("../../docs/examples/watch.txt")))
+}
+
+/// Returns styled examples for the metrics command.
+fn metrics_examples() -> &'static str {
+    use std::sync::OnceLock;
+    static EXAMPLES: OnceLock<String> = OnceLock::new();
+    EXAMPLES.get_or_init(|| styled_examples(include_str!("../../docs/examples/metrics.txt")))
+}
+
+/// Returns styled examples for the gc command.
+fn gc_examples() -> &'static str {
+    use std::sync::OnceLock;
+    static EXAMPLES: OnceLock<String> = OnceLock::new();
+    EXAMPLES.get_or_init(|| styled_examples(include_str!("../../docs/examples/gc.txt")))
+}
+
+/// Returns styled examples for the batch command.
+fn batch_examples() -> &'static str {
+    use std::sync::OnceLock;
+    static EXAMPLES: OnceLock<String> = OnceLock::new();
+    EXAMPLES.get_or_init(|| styled_examples(include_str!("../../docs/examples/batch.txt")))
+}
+
+/// Returns styled examples for the continue and abort commands.
+fn continue_examples() -> &'static str {
+    use std::sync::OnceLock;
+    static EXAMPLES: OnceLock<String> = OnceLock::new();
+    EXAMPLES.get_or_init(|| styled_examples(include_str!("../../docs/examples/continue.txt")))
+}
+
+/// Returns styled examples for the state command.
+fn state_examples() -> &'static str {
+    use std::sync::OnceLock;
+    static EXAMPLES: OnceLock<String> = OnceLock::new();
+    EXAMPLES.get_or_init(|| styled_examples(include_str!("../../docs/examples/state.txt")))
+}
+
+/// Shared arguments used by all commands
+#[derive(ClapArgs, Clone, Default, Debug)]
+pub struct SharedArgs {
+    /// Local repository path or alias (positional argument, takes precedence over --local-repo)
+    pub path: Option<String>,
+
+    // Azure DevOps Connection
+    /// Azure DevOps organization name
+    #[arg(short, long, help_heading = "Azure DevOps Connection")]
+    pub organization: Option<String>,
+
+    /// Azure DevOps project name
+    #[arg(short, long, help_heading = "Azure DevOps Connection")]
+    pub project: Option<String>,
+
+    /// Azure DevOps repository name
+    #[arg(short, long, help_heading = "Azure DevOps Connection")]
+    pub repository: Option<String>,
+
+    /// Personal Access Token for Azure DevOps API authentication
+    #[arg(short = 't', long, help_heading = "Azure DevOps Connection")]
+    pub pat: Option<String>,
+
+    /// Separate Personal Access Token for mutating requests (labels, work item states)
+    #[arg(long, help_heading = "Azure DevOps Connection")]
+    pub write_pat: Option<String>,
+
+    // Branch Configuration
+    /// Source branch to fetch PRs from [default: dev]
+    #[arg(long, help_heading = "Branch Configuration")]
+    pub dev_branch: Option<String>,
+
+    /// Target branch for cherry-picks; a comma-separated list picks into
+    /// each branch [default: next]
+    #[arg(long, help_heading = "Branch Configuration")]
+    pub target_branch: Option<String>,
+
+    // Repository Options
+    /// Local repository path (alternative to positional argument)
+    #[arg(long, help_heading = "Repository Options")]
+    pub local_repo: Option<String>,
+
+    /// Prefix for tagging processed PRs
+    #[arg(long, default_value = "merged-", help_heading = "Repository Options")]
+    pub tag_prefix: Option<String>,
+
+    // Performance Tuning
+    /// Maximum parallel API requests [default: 300]
+    #[arg(long, help_heading = "Performance Tuning")]
+    pub parallel_limit: Option<usize>,
+
+    /// Maximum concurrent network operations [default: 100]
+    #[arg(long, help_heading = "Performance Tuning")]
+    pub max_concurrent_network: Option<usize>,
+
+    /// Maximum concurrent processing operations [default: 10]
+    #[arg(long, help_heading = "Performance Tuning")]
+    pub max_concurrent_processing: Option<usize>,
+
+    // Filtering
+    /// Only fetch items created after this date (e.g., "1mo", "3 weeks ago", "last monday", "2024-W32", "2025-01-15")
+    #[arg(long, help_heading = "Filtering")]
+    pub since: Option<String>,
+
+    /// Date compared against --since: closed, merge-commit or target-commit [default: closed]
+    #[arg(long, value_enum, help_heading = "Filtering")]
+    pub since_field: Option<SinceField>,
+
+    // Behavior
+    /// Skip the settings confirmation screen and proceed directly
+    #[arg(long, help_heading = "Behavior")]
+    pub skip_confirmation: bool,
+
+    // Logging
+    /// Log level (trace, debug, info, warn, error)
+    #[arg(long, help_heading = "Logging")]
+    pub log_level: Option<String>,
+
+    /// Log file path (logs to file instead of stderr)
+    #[arg(long, help_heading = "Logging")]
+    pub log_file: Option<String>,
+
+    /// Log format (text, json) [default: text]
+    #[arg(long, help_heading = "Logging", value_parser = ["text", "json"])]
+    pub log_format: Option<String>,
+
+    /// Print how long each phase took (fetching, history matching, analysis) when the run ends
+    #[arg(long, help_heading = "Logging")]
+    pub profile_run: bool,
+
+    /// Store Azure DevOps responses that do not match the expected schema in this directory
+    #[arg(long, value_name = "DIR", help_heading = "Logging")]
+    pub dump_raw_response: Option<std::path::PathBuf>,
+}
+
+/// Parses the `--serve` address: a bare port listens on localhost only.
+fn parse_serve_addr(value: &str) -> Result<SocketAddr, String> {
+    if let Ok(port) = value.parse::<u16>() {
+        return Ok(SocketAddr::from((Ipv4Addr::LOCALHOST, port)));
+    }
+    value
+        .parse()
+        .map_err(|_| format!("expected PORT or HOST:PORT, got '{}'", value))
+}
+
+/// Parses a duration flag such as `90s` or `1h30m`; a bare number is minutes.
+fn parse_minutes(value: &str) -> Result<Duration, String> {
+    parse_duration_in(value, TimeUnit::Minutes).map_err(|e| e.to_string())
+}
+
+/// Parses an interval flag like [`parse_minutes`], rejecting zero.
+fn parse_interval(value: &str) -> Result<Duration, String> {
+    match parse_minutes(value)? {
+        Duration::ZERO => Err("interval must be greater than zero".to_string()),
+        interval => Ok(interval),
+    }
+}
+
+/// Parses an age flag such as `2w` or `36h`; a bare number is days.
+fn parse_days(value: &str) -> Result<Duration, String> {
+    parse_duration_in(value, TimeUnit::Days).map_err(|e| e.to_string())
+}
+
+/// Arguments specific to non-interactive mode.
+/// Flattened into MergeArgs so these flags are available on `mergers merge` directly.
+#[derive(ClapArgs, Clone, Default, Debug)]
+pub struct NonInteractiveArgs {
+    /// Run in non-interactive mode (for CI/AI agents)
+    #[arg(short = 'n', long, help_heading = "Non-Interactive Mode")]
+    pub non_interactive: bool,
+
+    /// Merge branch version (required with --non-interactive)
+    #[arg(long, help_heading = "Non-Interactive Mode")]
+    pub version: Option<String>,
+
+    /// Comma-separated work item states for PR filtering
+    #[arg(long, help_heading = "Non-Interactive Mode")]
+    pub select_by_state: Option<String>,
+
+    /// Create a release candidate (the version gets the next free -rcN suffix)
+    #[arg(long, help_heading = "Non-Interactive Mode")]
+    pub rc: bool,
+
+    /// Output format: text, json, ndjson
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help_heading = "Output Options")]
+    pub output: OutputFormat,
+
+    /// Suppress progress output
+    #[arg(short, long, help_heading = "Output Options")]
+    pub quiet: bool,
+
+    /// Serve live progress as JSON on http://127.0.0.1:PORT/ during the run
+    #[arg(long, value_name = "PORT", help_heading = "Output Options")]
+    pub status_port: Option<u16>,
+
+    /// Serve a read-only web dashboard of the run on PORT (localhost) or HOST:PORT
+    #[arg(long, value_name = "ADDR", value_parser = parse_serve_addr, help_heading = "Output Options")]
+    pub serve: Option<SocketAddr>,
+
+    /// Merge even if more PRs are selected than selection_hard_cap allows
+    #[arg(long, help_heading = "Non-Interactive Mode")]
+    pub force: bool,
+
+    /// Simulate the merge and report what would happen, without changing anything
+    #[arg(long, help_heading = "Non-Interactive Mode")]
+    pub dry_run: bool,
+
+    /// Wait this long for a human to resolve a conflict, then apply --on-conflict-timeout (e.g., "15", "90s", "1h")
+    #[arg(
+        long,
+        value_name = "DURATION",
+        value_parser = parse_minutes,
+        help_heading = "Non-Interactive Mode"
+    )]
+    pub conflict_timeout: Option<Duration>,
+
+    /// What to do with a conflict still unresolved after --conflict-timeout [default: skip]
+    #[arg(
+        long,
+        value_enum,
+        value_name = "ACTION",
+        requires = "conflict_timeout",
+        help_heading = "Non-Interactive Mode"
+    )]
+    pub on_conflict_timeout: Option<crate::core::operations::ConflictTimeoutAction>,
+
+    /// Write a report whenever the cherry-picks stop (e.g., "junit:merge.xml"); repeatable
+    #[arg(long, value_name = "FORMAT:PATH", help_heading = "Output Options")]
+    pub report: Vec<ReportTarget>,
+}
+
+/// Arguments specific to merge mode
+#[derive(ClapArgs, Clone)]
+pub struct MergeArgs {
+    #[command(flatten)]
+    pub shared: SharedArgs,
+
+    #[command(flatten)]
+    pub ni: NonInteractiveArgs,
+
+    /// State to set work items to after successful merge [default: Next Merged]
+    #[arg(long, help_heading = "Merge Options")]
+    pub work_item_state: Option<String>,
+
+    /// Run git hooks during cherry-pick operations (hooks are skipped by default)
+    #[arg(long, help_heading = "Merge Options")]
+    pub run_hooks: bool,
+
+    /// Fail data loading if work items are in states not listed in known_work_item_states
+    #[arg(long, help_heading = "Merge Options")]
+    pub strict_states: bool,
+
+    /// Flag PRs whose last merge commit did not build or that fail a branch policy
+    #[arg(long, help_heading = "Merge Options")]
+    pub check_builds: bool,
+
+    /// Like --check-builds, and leave failing PRs out of the merge
+    #[arg(long, help_heading = "Merge Options")]
+    pub require_green_build: bool,
+
+    /// Tag the patch branch v<version> with the release notes when the merge completes
+    #[arg(long, help_heading = "Merge Options")]
+    pub release_tag: bool,
+
+    /// Like --release-tag, and push the tag to origin
+    #[arg(long, help_heading = "Merge Options")]
+    pub push_release_tag: bool,
+
+    /// Sign cherry-picked commits and the release tag with the git signing key (GPG or SSH)
+    #[arg(long, help_heading = "Merge Options")]
+    pub sign_commits: bool,
+
+    /// Squash the commits picked for each PR into one commit
+    #[arg(long, help_heading = "Merge Options")]
+    pub squash: bool,
+
+    /// Commit message of squashed PRs; {id}, {title} and {task_ids} are replaced [default: "Merged PR {id}: {title} (rwi:#{task_ids})"]
+    #[arg(long, value_name = "TEMPLATE", help_heading = "Merge Options")]
+    pub squash_message: Option<String>,
+
+    /// Retry a merge commit whose first parent conflicts with its second parent as mainline (-m 2)
+    #[arg(long, help_heading = "Merge Options")]
+    pub mainline_fallback: bool,
+
+    /// Refresh the PR list in the background during PR selection (minutes, or e.g. "90s", "1h")
+    #[arg(
+        long,
+        value_name = "DURATION",
+        value_parser = parse_interval,
+        help_heading = "Merge Options"
+    )]
+    pub auto_refresh: Option<Duration>,
+
+    /// Select the PRs listed in a file (one `!PR`, `#WORKITEM` or PR id per line)
+    #[arg(long, value_name = "PATH", help_heading = "Merge Options")]
+    pub select_file: Option<std::path::PathBuf>,
+
+    /// Only list PRs created by this author (name or identity id) in PR selection
+    #[arg(long, value_name = "NAME", help_heading = "Merge Options")]
+    pub author: Option<String>,
+
+    /// Only list PRs with this reviewer (name or identity id) in PR selection
+    #[arg(long, value_name = "NAME", help_heading = "Merge Options")]
+    pub reviewer: Option<String>,
+
+    /// Only list PRs changing files that match this glob in PR selection (repeatable)
+    #[arg(long, value_name = "GLOB", help_heading = "Merge Options")]
+    pub path_glob: Vec<String>,
+
+    /// Restore the PR selection saved under this name and keep saving it
+    #[arg(long, value_name = "NAME", help_heading = "Merge Options")]
+    pub session: Option<String>,
+
+    /// Order in which selected PRs are cherry-picked [default: closed-date]
+    #[arg(long, value_enum, help_heading = "Merge Options")]
+    pub cherry_pick_order: Option<crate::core::operations::CherryPickOrder>,
+
+    /// Subcommand for non-interactive operations
+    #[command(subcommand)]
+    pub subcommand: Option<MergeSubcommand>,
+}
+
+/// Arguments specific to migration mode
+#[derive(ClapArgs, Clone)]
+pub struct MigrateArgs {
+    #[command(flatten)]
+    pub shared: SharedArgs,
+
+    /// Comma-separated list of work item states considered terminal
+    #[arg(
+        long,
+        default_value = "Closed,Next Closed,Next Merged",
+        help_heading = "Migration Options"
+    )]
+    pub terminal_states: String,
+}
+
+/// Arguments specific to cleanup mode
+#[derive(ClapArgs, Clone)]
+pub struct CleanupArgs {
+    #[command(flatten)]
+    pub shared: SharedArgs,
+
+    /// Target branch to check for merged patches (defaults to --target-branch)
+    #[arg(long, help_heading = "Cleanup Options")]
+    pub target: Option<String>,
+}
+
+/// Arguments for the release-notes command.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct ReleaseNotesArgs {
+    #[command(flatten)]
+    pub shared: SharedArgs,
+
+    /// Output format: markdown, json, plain, html
+    #[arg(long, value_enum, default_value_t = ReleaseNotesOutputFormat::Markdown, help_heading = "Output Options")]
+    pub output: ReleaseNotesOutputFormat,
+
+    /// Copy output to clipboard
+    #[arg(long, help_heading = "Output Options")]
+    pub copy: bool,
+
+    /// Group tasks by commit type (feat, fix, refactor)
+    #[arg(long, help_heading = "Output Options")]
+    pub group: bool,
+
+    /// Include PR links in output
+    #[arg(long, help_heading = "Output Options")]
+    pub include_prs: bool,
+
+    /// List PRs without linked work items in an "Unreferenced changes" section
+    #[arg(long, help_heading = "Output Options")]
+    pub include_unreferenced: bool,
+
+    /// Language of headings: a locale profile from the config file or a
+    /// built-in locale (en, de, fr)
+    #[arg(long, help_heading = "Output Options")]
+    pub locale: Option<String>,
+
+    /// Existing changelog; work items it already lists are treated as
+    /// previously released
+    #[arg(long, value_name = "PATH", help_heading = "Output Options")]
+    pub changelog: Option<std::path::PathBuf>,
+
+    /// What to do with work items the changelog already lists: exclude, mark
+    #[arg(long, value_enum, default_value_t = PreviouslyReleased::Exclude, requires = "changelog", help_heading = "Output Options")]
+    pub previously_released: PreviouslyReleased,
+
+    /// Publish the markdown notes to this Azure DevOps wiki (name or ID)
+    #[arg(long, value_name = "WIKI", help_heading = "Output Options")]
+    pub wiki: Option<String>,
+
+    /// Wiki page to create or replace; {version} is the release version
+    #[arg(long, value_name = "PATH", help_heading = "Output Options")]
+    pub wiki_page: Option<String>,
+
+    /// Starting version/tag for range (inclusive)
+    #[arg(long, help_heading = "Version Range")]
+    pub from: Option<String>,
+
+    /// Ending version/tag for range (inclusive, defaults to HEAD)
+    #[arg(long, help_heading = "Version Range")]
+    pub to: Option<String>,
+
+    /// Skip cache and fetch fresh data from API
+    #[arg(long, help_heading = "Cache Options")]
+    pub no_cache: bool,
+
+    /// Subcommand generating the notes from other sources
+    #[command(subcommand)]
+    pub subcommand: Option<ReleaseNotesSubcommand>,
+}
+
+/// Subcommands for the release-notes command.
+#[derive(Subcommand, Clone, Debug)]
+pub enum ReleaseNotesSubcommand {
+    /// Generate release notes from the state file of a finished merge
+    #[command(
+        about = "Generate release notes from the state file of a finished merge",
+        long_about = "Generate release notes from the cherry-pick items a merge recorded, without\n\
+            fetching PRs or work items from Azure DevOps or reading git.\n\n\
+            Completed merges keep a snapshot of their state file in their run directory\n\
+            (see `mergers gc`). Without --run, the most recent one is used. Only PRs that\n\
+            were cherry-picked successfully are listed; JSON output includes the commits\n\
+            they were picked as.",
+        after_help = release_notes_from_state_examples()
+    )]
+    FromState(ReleaseNotesFromStateArgs),
+}
+
+/// Arguments for the `release-notes from-state` subcommand.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct ReleaseNotesFromStateArgs {
+    /// Run directory of the merge [default: the most recent completed merge]
+    #[arg(long, value_name = "ID", help_heading = "Input Options")]
+    pub run: Option<String>,
+
+    /// Read this state file instead of a run's snapshot
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with = "run",
+        help_heading = "Input Options"
+    )]
+    pub state_file: Option<std::path::PathBuf>,
+
+    /// Output format: markdown, json, plain, html
+    #[arg(long, value_enum, default_value_t = ReleaseNotesOutputFormat::Markdown, help_heading = "Output Options")]
+    pub format: ReleaseNotesOutputFormat,
+
+    /// Group tasks by commit type (feat, fix, refactor)
+    #[arg(long, help_heading = "Output Options")]
+    pub group: bool,
+
+    /// Include PR links in output
+    #[arg(long, help_heading = "Output Options")]
+    pub include_prs: bool,
+
+    /// Language of headings: a locale profile from the config file or a
+    /// built-in locale (en, de, fr)
+    #[arg(long, help_heading = "Output Options")]
+    pub locale: Option<String>,
+
+    /// Copy output to clipboard
+    #[arg(long, help_heading = "Output Options")]
+    pub copy: bool,
+}
+
+/// Arguments for the analyze command.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct AnalyzeArgs {
+    #[command(flatten)]
+    pub shared: SharedArgs,
+
+    /// Write the report to this file instead of stdout
+    #[arg(long, help_heading = "Output Options")]
+    pub output: Option<std::path::PathBuf>,
+
+    /// Report format: json, dot [default: from --output extension, else json]
+    #[arg(long, value_enum, help_heading = "Output Options")]
+    pub format: Option<AnalyzeOutputFormat>,
+
+    /// Comma-separated work item states; matching PRs are marked selected and
+    /// their unselected dependencies are reported as warnings
+    #[arg(long, help_heading = "Analysis Options")]
+    pub select_by_state: Option<String>,
+
+    /// Simulate cherry-pick orderings onto the target branch and recommend
+    /// the one with the fewest predicted conflicts (JSON output only)
+    #[arg(long, help_heading = "Analysis Options")]
+    pub plan: bool,
+}
+
+impl AnalyzeArgs {
+    /// Returns the report format, inferring it from `--output` when not given.
+    pub fn output_format(&self) -> AnalyzeOutputFormat {
+        self.format.unwrap_or_else(|| {
+            self.output
+                .as_deref()
+                .map(AnalyzeOutputFormat::from_path)
+                .unwrap_or_default()
+        })
+    }
+}
+
+/// Arguments for the watch command.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct WatchArgs {
+    #[command(flatten)]
+    pub shared: SharedArgs,
+
+    /// Time between polls (minutes, or e.g. "90s", "1h")
+    #[arg(
+        long,
+        value_name = "DURATION",
+        default_value = "5",
+        value_parser = parse_interval,
+        help_heading = "Watch Options"
+    )]
+    pub interval: Duration,
+
+    /// Comma-separated work item states; only PRs with all work items in
+    /// these states are treated as candidates
+    #[arg(long, help_heading = "Watch Options")]
+    pub select_by_state: Option<String>,
+
+    /// How to announce new candidates: bell, desktop, both, none
+    #[arg(long, value_enum, default_value_t = WatchNotify::Both, help_heading = "Watch Options")]
+    pub notify: WatchNotify,
+
+    /// State to set work items to after successful merge [default: Next Merged]
+    #[arg(long, help_heading = "Merge Options")]
+    pub work_item_state: Option<String>,
+
+    /// Run git hooks during cherry-pick operations (hooks are skipped by default)
+    #[arg(long, help_heading = "Merge Options")]
+    pub run_hooks: bool,
+}
+
+/// Arguments for the metrics command.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct MetricsArgs {
+    #[command(flatten)]
+    pub shared: SharedArgs,
+
+    /// Write the metrics to this file instead of stdout
+    #[arg(long, help_heading = "Output Options")]
+    pub output: Option<std::path::PathBuf>,
+
+    /// Metrics format: json, csv [default: from --output extension, else json]
+    #[arg(long, value_enum, help_heading = "Output Options")]
+    pub format: Option<MetricsOutputFormat>,
+
+    /// Include releases of every repository, not just the configured one
+    #[arg(long, help_heading = "Filtering")]
+    pub all_repositories: bool,
+}
+
+impl MetricsArgs {
+    /// Returns the metrics format, inferring it from `--output` when not given.
+    pub fn output_format(&self) -> MetricsOutputFormat {
+        self.format.unwrap_or_else(|| {
+            self.output
+                .as_deref()
+                .map(MetricsOutputFormat::from_path)
+                .unwrap_or_default()
+        })
+    }
+}
+
+// ============================================================================
+// Gc CLI Arguments
+// ============================================================================
+
+/// Arguments for the gc command.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct GcArgs {
+    #[command(flatten)]
+    pub shared: SharedArgs,
+
+    /// Only remove run directories not modified for this long (days, or e.g. "2w", "36h")
+    #[arg(
+        long,
+        value_name = "DURATION",
+        value_parser = parse_days,
+        help_heading = "Filtering"
+    )]
+    pub max_age: Option<Duration>,
+
+    /// List the run directories that would be removed without removing them
+    #[arg(long, help_heading = "Output Options")]
+    pub dry_run: bool,
+}
+
+// ============================================================================
+// State CLI Arguments
+// ============================================================================
+
+/// Arguments for the state command.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct StateArgs {
+    #[command(flatten)]
+    pub shared: SharedArgs,
+
+    #[command(subcommand)]
+    pub subcommand: StateSubcommand,
+}
+
+/// Subcommands for the state command.
+#[derive(Subcommand, Clone, Debug)]
+pub enum StateSubcommand {
+    /// Rewrite state files in the current schema version
+    #[command(
+        about = "Rewrite state files in the current schema version",
+        long_about = "Rewrite merge state files written by an older version of mergers in the\n\
+            current schema version.\n\n\
+            Older state files are also migrated when a merge is continued, so this is only\n\
+            needed to convert them ahead of time, e.g. before older mergers binaries are\n\
+            removed. Files already in the current version are left untouched."
+    )]
+    Upgrade(StateUpgradeArgs),
+}
+
+/// Arguments for the `state upgrade` subcommand.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct StateUpgradeArgs {
+    /// Only upgrade the state file of this repository (default: all state files)
+    #[arg(long, help_heading = "Repository")]
+    pub repo: Option<String>,
+
+    /// List the state files that would be upgraded without rewriting them
+    #[arg(long, help_heading = "Output Options")]
+    pub dry_run: bool,
+}
+
+/// Arguments for the `merge continue` subcommand.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct MergeContinueArgs {
+    /// Repository path (auto-detected if in repo)
+    #[arg(long, help_heading = "Repository")]
+    pub repo: Option<String>,
+
+    /// Output format: text, json, ndjson
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help_heading = "Output Options")]
+    pub output: OutputFormat,
+
+    /// Suppress progress output
+    #[arg(short, long, help_heading = "Output Options")]
+    pub quiet: bool,
+
+    /// Write a report when the cherry-picks stop (e.g., "junit:merge.xml"); repeatable
+    #[arg(long, value_name = "FORMAT:PATH", help_heading = "Output Options")]
+    pub report: Vec<ReportTarget>,
+}
+
+/// Arguments for the `merge abort` subcommand.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct MergeAbortArgs {
+    /// Repository path (auto-detected if in repo)
+    #[arg(long, help_heading = "Repository")]
+    pub repo: Option<String>,
+
+    /// Output format: text, json, ndjson
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help_heading = "Output Options")]
+    pub output: OutputFormat,
+
+    /// Suppress progress output
+    #[arg(short, long, help_heading = "Output Options")]
+    pub quiet: bool,
+
+    /// Write a report when the cherry-picks stop (e.g., "junit:merge.xml"); repeatable
+    #[arg(long, value_name = "FORMAT:PATH", help_heading = "Output Options")]
+    pub report: Vec<ReportTarget>,
+}
+
+/// Arguments for the top-level `continue` command.
+///
+/// Same as `merge continue`; the positional path is used when `--repo` is
+/// not given.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct ContinueArgs {
+    #[command(flatten)]
+    pub shared: SharedArgs,
+
+    #[command(flatten)]
+    pub merge: MergeContinueArgs,
+}
+
+impl ContinueArgs {
+    /// Returns the `merge continue` arguments, taking the repository from
+    /// the positional path if `--repo` is missing.
+    pub fn merge_args(&self) -> MergeContinueArgs {
+        MergeContinueArgs {
+            repo: self.merge.repo.clone().or_else(|| self.shared.path.clone()),
+            ..self.merge.clone()
+        }
+    }
+}
+
+/// Arguments for the top-level `abort` command.
+///
+/// Same as `merge abort`; the positional path is used when `--repo` is not
+/// given.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct AbortArgs {
+    #[command(flatten)]
+    pub shared: SharedArgs,
+
+    #[command(flatten)]
+    pub merge: MergeAbortArgs,
+}
+
+impl AbortArgs {
+    /// Returns the `merge abort` arguments, taking the repository from the
+    /// positional path if `--repo` is missing.
+    pub fn merge_args(&self) -> MergeAbortArgs {
+        MergeAbortArgs {
+            repo: self.merge.repo.clone().or_else(|| self.shared.path.clone()),
+            ..self.merge.clone()
+        }
+    }
+}
+
+/// Arguments for the `merge skip` subcommand.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct MergeSkipArgs {
+    /// Repository path (auto-detected if in repo)
+    #[arg(long, help_heading = "Repository")]
+    pub repo: Option<String>,
+
+    /// Output format: text, json, ndjson
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help_heading = "Output Options")]
+    pub output: OutputFormat,
+
+    /// Suppress progress output
+    #[arg(short, long, help_heading = "Output Options")]
+    pub quiet: bool,
+
+    /// Write a report when the cherry-picks stop (e.g., "junit:merge.xml"); repeatable
+    #[arg(long, value_name = "FORMAT:PATH", help_heading = "Output Options")]
+    pub report: Vec<ReportTarget>,
+}
+
+/// Arguments for the `merge resolve` subcommand.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct MergeResolveArgs {
+    /// Repository path (auto-detected if in repo)
+    #[arg(long, help_heading = "Repository")]
+    pub repo: Option<String>,
+
+    /// Open files with `git mergetool` (git's merge.tool) instead of $EDITOR
+    #[arg(long, help_heading = "Resolve Options")]
+    pub mergetool: bool,
+
+    /// Merge tool to use with `git mergetool` (implies --mergetool)
+    #[arg(long, value_name = "TOOL", help_heading = "Resolve Options")]
+    pub tool: Option<String>,
+
+    /// Do not re-apply resolutions saved from an earlier cherry-pick of the PR
+    #[arg(long, help_heading = "Resolve Options")]
+    pub no_reuse: bool,
+
+    /// Stage resolved files but do not continue the merge
+    #[arg(long, help_heading = "Resolve Options")]
+    pub no_continue: bool,
+
+    /// Output format: text, json, ndjson
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help_heading = "Output Options")]
+    pub output: OutputFormat,
+
+    /// Suppress progress output
+    #[arg(short, long, help_heading = "Output Options")]
+    pub quiet: bool,
+}
+
+impl MergeResolveArgs {
+    /// Returns how conflicted files should be opened.
+    pub fn resolve_tool(&self) -> crate::core::operations::ResolveTool {
+        use crate::core::operations::ResolveTool;
+
+        if self.mergetool || self.tool.is_some() {
+            ResolveTool::MergeTool(self.tool.clone())
+        } else {
+            ResolveTool::from_env()
+        }
+    }
+}
+
+/// Arguments for the `merge recover` subcommand.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct MergeRecoverArgs {
+    /// Repository path (auto-detected if in repo)
+    #[arg(long, help_heading = "Repository")]
+    pub repo: Option<String>,
+
+    /// Record the applied picks and continue with the remaining PRs
+    #[arg(long, conflicts_with_all = ["abort", "export"], help_heading = "Recovery Options")]
+    pub resume: bool,
+
+    /// Remove the worktree and patch branch, like `merge abort`
+    #[arg(long, conflicts_with = "export", help_heading = "Recovery Options")]
+    pub abort: bool,
+
+    /// Keep the applied commits on a rescue branch
+    #[arg(long, help_heading = "Recovery Options")]
+    pub export: bool,
+
+    /// Name of the rescue branch (default: rescue/<target>-<version>-<timestamp>)
+    #[arg(
+        long,
+        value_name = "BRANCH",
+        requires = "export",
+        help_heading = "Recovery Options"
+    )]
+    pub rescue_branch: Option<String>,
+
+    /// Output format: text, json, ndjson
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help_heading = "Output Options")]
+    pub output: OutputFormat,
+
+    /// Suppress progress output
+    #[arg(short, long, help_heading = "Output Options")]
+    pub quiet: bool,
+}
+
+impl MergeRecoverArgs {
+    /// Returns the action selected by the flags.
+    pub fn action(&self) -> crate::core::operations::RecoverAction {
+        use crate::core::operations::RecoverAction;
+
+        if self.resume {
+            RecoverAction::Resume
+        } else if self.abort {
+            RecoverAction::Abort
+        } else if self.export {
+            RecoverAction::Export
+        } else {
+            RecoverAction::Inspect
+        }
+    }
+}
+
+/// Arguments for the `merge status` subcommand.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct MergeStatusArgs {
+    /// Repository path (auto-detected if in repo)
+    #[arg(long, help_heading = "Repository")]
+    pub repo: Option<String>,
+
+    /// Output format: text, json, ndjson
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help_heading = "Output Options")]
+    pub output: OutputFormat,
+}
+
+/// Arguments for the `merge complete` subcommand.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct MergeCompleteArgs {
+    /// Repository path (auto-detected if in repo)
+    #[arg(long, help_heading = "Repository")]
+    pub repo: Option<String>,
+
+    /// State to set work items to (required)
+    #[arg(long, help_heading = "Completion Options")]
+    pub next_state: String,
+
+    /// Tag the patch branch v<version> with the release notes
+    #[arg(long, help_heading = "Completion Options")]
+    pub release_tag: bool,
+
+    /// Like --release-tag, and push the tag to origin
+    #[arg(long, help_heading = "Completion Options")]
+    pub push_release_tag: bool,
+
+    /// Output format: text, json, ndjson
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help_heading = "Output Options")]
+    pub output: OutputFormat,
+
+    /// Suppress progress output
+    #[arg(short, long, help_heading = "Output Options")]
+    pub quiet: bool,
+}
+
+/// Arguments for the `promote` command.
+#[derive(ClapArgs, Clone)]
+pub struct PromoteArgs {
+    #[command(flatten)]
+    pub shared: SharedArgs,
+
+    /// Release candidate version to promote (e.g., 1.2.0-rc2)
+    #[arg(long, help_heading = "Promotion Options")]
+    pub from: String,
+
+    /// Final release version [default: RC version without its -rcN suffix]
+    #[arg(long, help_heading = "Promotion Options")]
+    pub version: Option<String>,
+
+    /// Comma-separated work item states; matching PRs not in the RC are added
+    #[arg(long, help_heading = "Promotion Options")]
+    pub select_by_state: Option<String>,
+
+    /// State to set work items to after successful merge [default: Next Merged]
+    #[arg(long, help_heading = "Merge Options")]
+    pub work_item_state: Option<String>,
+
+    /// Run git hooks during cherry-pick operations (hooks are skipped by default)
+    #[arg(long, help_heading = "Merge Options")]
+    pub run_hooks: bool,
+
+    /// Output format: text, json, ndjson
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help_heading = "Output Options")]
+    pub output: OutputFormat,
+
+    /// Suppress progress output
+    #[arg(short, long, help_heading = "Output Options")]
+    pub quiet: bool,
+}
+
+impl ReleaseNotesArgs {
+    /// Returns the wiki page to publish to.
+    ///
+    /// `--wiki` and `--wiki-page` override the fields of `[release_notes_wiki]`;
+    /// without that table both have to be given.
+    pub fn wiki_target(
+        &self,
+        configured: Option<crate::api::WikiPageTarget>,
+    ) -> Result<Option<crate::api::WikiPageTarget>> {
+        match (configured, &self.wiki, &self.wiki_page) {
+            (configured, None, None) => Ok(configured),
+            (Some(configured), wiki, page) => Ok(Some(crate::api::WikiPageTarget {
+                wiki: wiki.clone().unwrap_or(configured.wiki),
+                page: page.clone().unwrap_or(configured.page),
+                project: configured.project,
+            })),
+            (None, Some(wiki), Some(page)) => Ok(Some(crate::api::WikiPageTarget {
+                wiki: wiki.clone(),
+                page: page.clone(),
+                project: None,
+            })),
+            (None, _, _) => anyhow::bail!(
+                "--wiki and --wiki-page must be given together unless [release_notes_wiki] is configured"
+            ),
+        }
+    }
+}
+
+impl PromoteArgs {
+    /// Returns the final release version.
+    ///
+    /// Uses `--version` if given, otherwise strips the `-rcN` suffix from `--from`.
+    pub fn release_version(&self) -> Option<String> {
+        self.version.clone().or_else(|| {
+            crate::core::operations::release_candidate::parse_rc_version(&self.from)
+                .map(|(version, _)| version.to_string())
+        })
+    }
+
+    /// Converts the promote arguments into non-interactive merge arguments for
+    /// configuration resolution.
+    pub fn to_merge_args(&self, version: String) -> MergeArgs {
+        MergeArgs {
+            shared: self.shared.clone(),
+            ni: NonInteractiveArgs {
+                non_interactive: true,
+                version: Some(version),
+                select_by_state: self.select_by_state.clone(),
+                rc: false,
+                output: self.output,
+                quiet: self.quiet,
+                status_port: None,
+                serve: None,
+                // The PRs were already accepted into the release candidate
+                force: true,
+                dry_run: false,
+                conflict_timeout: None,
+                on_conflict_timeout: None,
+                report: Vec::new(),
+            },
+            work_item_state: self.work_item_state.clone(),
+            run_hooks: self.run_hooks,
+            strict_states: false,
+            check_builds: false,
+            require_green_build: false,
+            release_tag: false,
+            push_release_tag: false,
+            sign_commits: false,
+            squash: false,
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            author: None,
+            reviewer: None,
+            path_glob: Vec::new(),
+            session: None,
+            cherry_pick_order: None,
+            subcommand: None,
+        }
+    }
+}
+
+// ============================================================================
+// Batch CLI Arguments
+// ============================================================================
+
+/// Arguments for the batch command.
+#[derive(ClapArgs, Clone, Debug)]
+pub struct BatchArgs {
+    #[command(flatten)]
+    pub shared: SharedArgs,
+
+    /// Version for the merge of every repository (e.g., v1.2.0)
+    #[arg(long, help_heading = "Batch Options")]
+    pub version: String,
+
+    /// Only merge these repositories (comma-separated [[repos]] names)
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "NAMES",
+        help_heading = "Batch Options"
+    )]
+    pub repos: Vec<String>,
+
+    /// Maximum number of repositories merged at the same time
+    #[arg(
+        long,
+        default_value_t = 1,
+        value_parser = clap::value_parser!(u16).range(1..),
+        help_heading = "Batch Options"
+    )]
+    pub parallel: u16,
+
+    /// Comma-separated work item states for PR selection
+    #[arg(long, help_heading = "Batch Options")]
+    pub select_by_state: Option<String>,
+
+    /// Merge even if a selection exceeds the hard cap
+    #[arg(long, help_heading = "Batch Options")]
+    pub force: bool,
+
+    /// Simulate the merges and report what would happen without changing anything
+    #[arg(long, help_heading = "Batch Options")]
+    pub dry_run: bool,
+
+    /// State to set work items to after successful merge [default: Next Merged]
+    #[arg(long, help_heading = "Merge Options")]
+    pub work_item_state: Option<String>,
+
+    /// Run git hooks during cherry-pick operations (hooks are skipped by default)
+    #[arg(long, help_heading = "Merge Options")]
+    pub run_hooks: bool,
+
+    /// Output format: text, json, ndjson
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help_heading = "Output Options")]
+    pub output: OutputFormat,
+
+    /// Suppress progress output
+    #[arg(short, long, help_heading = "Output Options")]
+    pub quiet: bool,
+}
+
+impl BatchArgs {
+    /// Builds the non-interactive merge arguments for one `[[repos]]` entry.
+    ///
+    /// The entry's repository settings take precedence over the shared
+    /// arguments.
+    pub fn to_merge_args(&self, repo: &crate::core::runner::BatchRepoConfig) -> MergeArgs {
+        let mut shared = self.shared.clone();
+        shared.path = None;
+        shared.repository = Some(repo.repository.clone());
+        shared.local_repo = repo.local_repo.clone();
+        shared.organization = repo.organization.clone().or(shared.organization);
+        shared.project = repo.project.clone().or(shared.project);
+        shared.dev_branch = repo.dev_branch.clone().or(shared.dev_branch);
+        shared.target_branch = repo.target_branch.clone().or(shared.target_branch);
+
+        MergeArgs {
+            shared,
+            ni: NonInteractiveArgs {
+                non_interactive: true,
+                version: Some(self.version.clone()),
+                select_by_state: self.select_by_state.clone(),
+                rc: false,
+                output: self.output,
+                quiet: self.quiet,
+                status_port: None,
+                serve: None,
+                force: self.force,
+                dry_run: self.dry_run,
+                conflict_timeout: None,
+                on_conflict_timeout: None,
+                report: Vec::new(),
+            },
+            work_item_state: self.work_item_state.clone(),
+            run_hooks: self.run_hooks,
+            strict_states: false,
+            check_builds: false,
+            require_green_build: false,
+            release_tag: false,
+            push_release_tag: false,
+            sign_commits: false,
+            squash: false,
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            author: None,
+            reviewer: None,
+            path_glob: Vec::new(),
+            session: None,
+            cherry_pick_order: None,
+            subcommand: None,
+        }
+    }
+}
+
+/// Subcommands for the merge mode.
+#[derive(Subcommand, Clone, Debug)]
+pub enum MergeSubcommand {
+    /// Continue merge after resolving conflicts
+    #[command(
+        about = "Continue merge after resolving conflicts",
+        long_about = "Continue a merge operation that was paused due to conflicts.\n\n\
+            This command reads the state file, verifies conflicts are resolved,\n\
+            and continues cherry-picking remaining commits."
+    )]
+    Continue(MergeContinueArgs),
+
+    /// Abort and clean up an in-progress merge
+    #[command(
+        about = "Abort and clean up an in-progress merge",
+        long_about = "Abort an in-progress merge operation and clean up.\n\n\
+            This removes the worktree, deletes the working branch, and aborts\n\
+            any in-progress cherry-pick."
+    )]
+    Abort(MergeAbortArgs),
+
+    /// Show status of current merge operation
+    #[command(
+        about = "Show status of current merge operation",
+        long_about = "Show the current status of an in-progress merge operation.\n\n\
+            Displays the current phase, progress, and any conflicts."
+    )]
+    Status(MergeStatusArgs),
+
+    /// Complete merge by tagging PRs and updating work items
+    #[command(
+        about = "Complete merge by tagging PRs and updating work items",
+        long_about = "Complete a merge operation after all cherry-picks are done.\n\n\
+            This tags successful PRs in Azure DevOps and updates work items\n\
+            to the specified next state."
+    )]
+    Complete(MergeCompleteArgs),
+
+    /// Skip the current conflicting PR and continue with remaining
+    #[command(
+        about = "Skip the current conflicting PR and continue",
+        long_about = "Skip the currently conflicting PR in a merge operation.\n\n\
+            This aborts the current cherry-pick, marks the PR as skipped,\n\
+            and continues processing the remaining PRs."
+    )]
+    Skip(MergeSkipArgs),
+
+    /// Resolve conflicts file by file in an editor or merge tool
+    #[command(
+        about = "Resolve conflicts in an editor or merge tool, then continue",
+        long_about = "Walk through the conflicted files of a paused merge operation.\n\n\
+            Each conflicted file is opened in $VISUAL/$EDITOR (or `git mergetool` with\n\
+            --mergetool/--tool). Files without remaining conflict markers are staged, and\n\
+            once all conflicts are resolved the merge continues as with `merge continue`."
+    )]
+    Resolve(MergeResolveArgs),
+
+    /// Inspect and recover a merge interrupted by a crash
+    #[command(
+        about = "Inspect and recover a merge interrupted by a crash",
+        long_about = "Inspect a merge whose process died before it finished.\n\n\
+            Compares the state file with the patch branch and reports exactly which PRs\n\
+            were applied, including picks committed after the state file was last saved\n\
+            and a cherry-pick stopped half-way. Then, optionally:\n  \
+            • --resume: record the applied picks and continue with the remaining PRs\n  \
+            • --abort: remove the worktree and patch branch, like `merge abort`\n  \
+            • --export: keep the applied commits on a rescue branch"
+    )]
+    Recover(MergeRecoverArgs),
+}
+
+/// Trait to extract shared arguments from command-specific argument structs
+pub trait HasSharedArgs {
+    fn shared_args(&self) -> &SharedArgs;
+    fn shared_args_mut(&mut self) -> &mut SharedArgs;
+}
+
+impl HasSharedArgs for MergeArgs {
+    fn shared_args(&self) -> &SharedArgs {
+        &self.shared
+    }
+
+    fn shared_args_mut(&mut self) -> &mut SharedArgs {
+        &mut self.shared
+    }
+}
+
+impl HasSharedArgs for MigrateArgs {
+    fn shared_args(&self) -> &SharedArgs {
+        &self.shared
+    }
+
+    fn shared_args_mut(&mut self) -> &mut SharedArgs {
+        &mut self.shared
+    }
+}
+
+impl HasSharedArgs for CleanupArgs {
+    fn shared_args(&self) -> &SharedArgs {
+        &self.shared
+    }
+
+    fn shared_args_mut(&mut self) -> &mut SharedArgs {
+        &mut self.shared
+    }
+}
+
+impl HasSharedArgs for PromoteArgs {
+    fn shared_args(&self) -> &SharedArgs {
+        &self.shared
+    }
+
+    fn shared_args_mut(&mut self) -> &mut SharedArgs {
+        &mut self.shared
+    }
+}
+
+impl HasSharedArgs for AnalyzeArgs {
+    fn shared_args(&self) -> &SharedArgs {
+        &self.shared
+    }
+
+    fn shared_args_mut(&mut self) -> &mut SharedArgs {
+        &mut self.shared
+    }
+}
+
+impl HasSharedArgs for WatchArgs {
+    fn shared_args(&self) -> &SharedArgs {
+        &self.shared
+    }
+
+    fn shared_args_mut(&mut self) -> &mut SharedArgs {
+        &mut self.shared
+    }
+}
+
+impl HasSharedArgs for MetricsArgs {
+    fn shared_args(&self) -> &SharedArgs {
+        &self.shared
+    }
+
+    fn shared_args_mut(&mut self) -> &mut SharedArgs {
+        &mut self.shared
+    }
+}
+
+impl HasSharedArgs for ContinueArgs {
+    fn shared_args(&self) -> &SharedArgs {
+        &self.shared
+    }
+
+    fn shared_args_mut(&mut self) -> &mut SharedArgs {
+        &mut self.shared
+    }
+}
+
+impl HasSharedArgs for AbortArgs {
+    fn shared_args(&self) -> &SharedArgs {
+        &self.shared
+    }
+
+    fn shared_args_mut(&mut self) -> &mut SharedArgs {
+        &mut self.shared
+    }
+}
+
+impl HasSharedArgs for GcArgs {
+    fn shared_args(&self) -> &SharedArgs {
+        &self.shared
+    }
+
+    fn shared_args_mut(&mut self) -> &mut SharedArgs {
+        &mut self.shared
+    }
+}
+
+impl HasSharedArgs for StateArgs {
+    fn shared_args(&self) -> &SharedArgs {
+        &self.shared
+    }
+
+    fn shared_args_mut(&mut self) -> &mut SharedArgs {
+        &mut self.shared
+    }
+}
+
+impl HasSharedArgs for BatchArgs {
+    fn shared_args(&self) -> &SharedArgs {
+        &self.shared
+    }
+
+    fn shared_args_mut(&mut self) -> &mut SharedArgs {
+        &mut self.shared
+    }
+}
+
+impl HasSharedArgs for ReleaseNotesArgs {
+    fn shared_args(&self) -> &SharedArgs {
+        &self.shared
+    }
+
+    fn shared_args_mut(&mut self) -> &mut SharedArgs {
+        &mut self.shared
+    }
+}
+
+/// Available commands
+#[derive(Subcommand, Clone)]
+pub enum Commands {
+    /// Cherry-pick merged PRs from dev branch to target branch
+    #[command(
+        visible_alias = "m",
+        long_about = "Cherry-pick merged PRs from the dev branch to a target branch.\n\n\
+            This mode fetches completed PRs from Azure DevOps, displays them in an interactive\n\
+            TUI for selection, and cherry-picks the selected commits to your target branch.\n\
+            Work items can be automatically transitioned to a specified state after merge.",
+        after_help = merge_examples()
+    )]
+    Merge(MergeArgs),
+
+    /// Continue a merge paused by conflicts
+    #[command(
+        long_about = "Continue a non-interactive merge that was paused due to conflicts.\n\n\
+            Loads the state file of the repository, verifies that every conflict is resolved\n\
+            and staged, finalizes the cherry-pick and picks the remaining PRs. A summary of\n\
+            every PR's status is printed at the end; files that are still conflicted are\n\
+            listed instead when the conflicts are not resolved yet.\n\n\
+            Same as `merge continue`. Exit codes: 0 success, 2 conflict, 3 partial success,\n\
+            4 no state file, 5 invalid phase, 7 locked.",
+        after_help = continue_examples()
+    )]
+    Continue(ContinueArgs),
+
+    /// Abort a merge in progress and clean up
+    #[command(
+        long_about = "Abort an in-progress non-interactive merge and clean up.\n\n\
+            Aborts any cherry-pick in progress, removes the worktree and deletes the patch\n\
+            branch, then prints a summary of every PR's status at the time of the abort.\n\n\
+            Same as `merge abort`. Exit codes: 0 aborted, 4 no state file, 5 invalid phase\n\
+            (merge already finished), 7 locked.",
+        after_help = continue_examples()
+    )]
+    Abort(AbortArgs),
+
+    /// Analyze PRs to determine migration eligibility
+    #[command(
+        visible_alias = "mi",
+        long_about = "Analyze pull requests to determine which ones are eligible for migration.\n\n\
+            This mode examines PRs and their associated work items to categorize them as:\n  \
+            • Eligible: All work items in terminal states, commit found in target\n  \
+            • Unsure: Mixed signals requiring manual review\n  \
+            • Not merged: PR commits not present in target branch\n\n\
+            Results are displayed in an interactive TUI for review and manual override.",
+        after_help = migrate_examples()
+    )]
+    Migrate(MigrateArgs),
+
+    /// Clean up merged patch branches from the repository
+    #[command(
+        visible_alias = "c",
+        long_about = "Clean up patch branches that have been merged to the target branch.\n\n\
+            This mode identifies local branches matching the tag prefix pattern (default: merged-*)\n\
+            that have been fully merged into the target branch, and offers to delete them.\n\
+            Useful for maintaining a clean repository after completing merge operations.",
+        after_help = cleanup_examples()
+    )]
+    Cleanup(CleanupArgs),
+
+    /// Generate release notes from version commits
+    #[command(
+        visible_alias = "rn",
+        long_about = "Generate release notes from git tags and pull requests.\n\n\
+            Discovers PRs tagged with a configurable prefix (tag_prefix) and fetches\n\
+            associated work items from Azure DevOps to build formatted release notes.\n\n\
+            Features:\n  \
+            • Supports version ranges (--from / --to)\n  \
+            • Groups entries by type (feat, fix, refactor)\n  \
+            • Caches work item titles locally\n  \
+            • Multiple output formats (markdown, json, plain)\n  \
+            • Clipboard support (--copy)",
+        after_help = release_notes_examples()
+    )]
+    ReleaseNotes(ReleaseNotesArgs),
+
+    /// Promote a release candidate to the final release
+    #[command(
+        long_about = "Promote a release candidate created with `merge -n --rc` to the final release.\n\n\
+            Creates the release worktree and patch branch from the RC's HEAD, carries over\n\
+            the RC's cherry-picked PRs, optionally adds newly selected PRs (--select-by-state),\n\
+            and records the RC in the new state file. Run `merge complete` afterwards to tag\n\
+            PRs with the final version.",
+        after_help = promote_examples()
+    )]
+    Promote(PromoteArgs),
+
+    /// Merge several repositories in one run
+    #[command(
+        long_about = "Run the non-interactive merge for each repository listed in the [[repos]]\n\
+            section of the config file.\n\n\
+            Repositories are merged one at a time, or --parallel at a time, each in its own\n\
+            worktree. A repository that conflicts or fails does not stop the others; the\n\
+            combined report lists the result of each, and conflicts are resolved per\n\
+            repository with `merge continue`.\n\n\
+            The exit code is the repositories' common exit code, 3 (partial success) when\n\
+            only some succeeded, or 1 when none did.",
+        after_help = batch_examples()
+    )]
+    Batch(BatchArgs),
+
+    /// Export the PR dependency graph without starting a merge
+    #[command(
+        long_about = "Analyze file-level dependencies between pull requests without entering a merge workflow.\n\n\
+            Fetches completed PRs from the dev branch, reads each PR's merge commit from the\n\
+            local repository, and writes the dependency graph for external planning tools:\n  \
+            • JSON: full graph with overlapping files and line ranges, summary, and warnings\n  \
+            • DOT: Graphviz graph (dependent edges red, partially dependent edges dashed)\n\n\
+            With --plan, the JSON report also simulates cherry-picking the PRs onto the target\n\
+            branch in several orderings (closing date, topological, risk) and recommends the\n\
+            one with the fewest predicted conflicts. Requires a local repository clone.",
+        after_help = analyze_examples()
+    )]
+    Analyze(AnalyzeArgs),
+
+    /// Watch for new merge candidates and notify when they appear
+    #[command(
+        long_about = "Poll Azure DevOps for newly completed pull requests and notify when new merge candidates appear.\n\n\
+            Candidates are completed PRs on the dev branch that are not yet tagged as merged,\n\
+            not excluded by .mergers-ignore, and (with --select-by-state) whose work items\n\
+            are all in the given states. The list is kept in the state directory so that\n\
+            restarts do not announce old PRs again.\n\n\
+            While waiting, press Enter or 'm' to open the merge TUI with the new candidates\n\
+            preselected, or 'q' to quit.",
+        after_help = watch_examples()
+    )]
+    Watch(WatchArgs),
+
+    /// Export time-to-release metrics from the release history
+    #[command(
+        long_about = "Export release metrics for engineering dashboards.\n\n\
+            Every completed merge is recorded in the release history in the state directory.\n\
+            This command summarizes it per release:\n  \
+            • Median time from PR completion on the dev branch to the release\n  \
+            • PRs released, deferred (skipped or never picked) and failed\n  \
+            • Cherry-pick conflicts\n  \
+            • PRs deferred more than once\n\n\
+            Releases of the configured repository are included unless --all-repositories is given.",
+        after_help = metrics_examples()
+    )]
+    Metrics(MetricsArgs),
+
+    /// Remove run directories of finished merges
+    #[command(
+        long_about = "Remove run directories from the state directory.\n\n\
+            Each merge writes its artifacts, such as the repository clone when no local\n\
+            repository is configured, to its own directory under runs/ in the state directory.\n\
+            This command removes the directories of finished merges; directories of merges\n\
+            still in progress are always kept. Starting a merge also prunes directories\n\
+            older than MERGERS_RUN_RETENTION_DAYS (default 14).",
+        after_help = gc_examples()
+    )]
+    Gc(GcArgs),
+
+    /// Manage merge state files
+    #[command(
+        long_about = "Manage the merge state files in the state directory.\n\n\
+            State files record the schema version they were written with. When mergers is\n\
+            upgraded mid-merge, older state files are migrated as they are loaded;\n\
+            `state upgrade` rewrites them in the current version ahead of time.",
+        after_help = state_examples()
+    )]
+    State(StateArgs),
+}
+
+impl Commands {
+    /// Extract shared arguments from any command variant.
+    pub fn shared_args(&self) -> &SharedArgs {
+        match self {
+            Commands::Merge(args) => args.shared_args(),
+            Commands::Continue(args) => args.shared_args(),
+            Commands::Abort(args) => args.shared_args(),
+            Commands::Migrate(args) => args.shared_args(),
+            Commands::Cleanup(args) => args.shared_args(),
+            Commands::ReleaseNotes(args) => args.shared_args(),
+            Commands::Promote(args) => args.shared_args(),
+            Commands::Batch(args) => args.shared_args(),
+            Commands::Analyze(args) => args.shared_args(),
+            Commands::Watch(args) => args.shared_args(),
+            Commands::Metrics(args) => args.shared_args(),
+            Commands::Gc(args) => args.shared_args(),
+            Commands::State(args) => args.shared_args(),
+        }
+    }
+
+    /// Extract mutable shared arguments from any command variant.
+    pub fn shared_args_mut(&mut self) -> &mut SharedArgs {
+        match self {
+            Commands::Merge(args) => args.shared_args_mut(),
+            Commands::Continue(args) => args.shared_args_mut(),
+            Commands::Abort(args) => args.shared_args_mut(),
+            Commands::Migrate(args) => args.shared_args_mut(),
+            Commands::Cleanup(args) => args.shared_args_mut(),
+            Commands::ReleaseNotes(args) => args.shared_args_mut(),
+            Commands::Promote(args) => args.shared_args_mut(),
+            Commands::Batch(args) => args.shared_args_mut(),
+            Commands::Analyze(args) => args.shared_args_mut(),
+            Commands::Watch(args) => args.shared_args_mut(),
+            Commands::Metrics(args) => args.shared_args_mut(),
+            Commands::Gc(args) => args.shared_args_mut(),
+            Commands::State(args) => args.shared_args_mut(),
+        }
+    }
+
+    /// Check if this command is the ReleaseNotes command.
+    #[must_use]
+    pub fn is_release_notes(&self) -> bool {
+        matches!(self, Commands::ReleaseNotes(_))
+    }
+}
+
+#[derive(Parser, Clone)]
+#[command(
+    name = "mergers",
+    author,
+    version,
+    long_version = build_version(),
+    about = "Manage Azure DevOps pull request merging and migration workflows",
+    long_about = "A CLI/TUI tool for managing Azure DevOps pull request merging and migration workflows.\n\n\
+        Mergers helps you:\n  \
+        • Cherry-pick merged PRs from dev to target branches\n  \
+        • Analyze PRs for migration eligibility\n  \
+        • Clean up merged patch branches\n\n\
+        Configuration can be provided via CLI arguments, environment variables (MERGERS_*),\n\
+        config file (~/.config/mergers/config.toml), or auto-detected from git remotes.",
+    before_help = concat!("mergers ", env!("CARGO_PKG_VERSION"), " (", env!("GIT_HASH"), ")"),
+    after_help = main_examples(),
+    styles = help_styles()
+)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+
+    /// Create a sample configuration file at ~/.config/mergers/config.toml
+    #[arg(long)]
+    pub create_config: bool,
+}
+
+/// Temporary wrapper to parse MergeArgs as if they were top-level
+#[derive(Parser, Clone)]
+#[command(
+    name = "mergers",
+    about = None,
+    long_about = None,
+    before_help = concat!("mergers ", env!("CARGO_PKG_VERSION"), " (", env!("GIT_HASH"), ")"),
+    styles = help_styles()
+)]
+pub struct MergeArgsParser {
+    #[command(flatten)]
+    pub merge_args: MergeArgs,
+}
+
+impl Args {
+    /// Parse arguments with default mode fallback.
+    /// If no subcommand is provided, attempts to parse args as MergeArgs.
+    pub fn parse_with_default_mode() -> Self {
+        use clap::error::ErrorKind;
+
+        // First try normal parsing
+        match Args::try_parse() {
+            Ok(args) => {
+                // Successfully parsed as Args, check if command is present
+                if args.command.is_some() || args.create_config {
+                    return args;
+                }
+                // No command and no create_config, fall through to try merge mode
+            }
+            Err(e) => {
+                // If it's a help or version display, show it and exit
+                match e.kind() {
+                    ErrorKind::DisplayHelp | ErrorKind::DisplayVersion => {
+                        e.exit();
+                    }
+                    _ => {
+                        // Other errors: fall through to try merge mode
+                    }
+                }
+            }
+        }
+
+        // Try to parse as MergeArgs using the wrapper
+        match MergeArgsParser::try_parse() {
+            Ok(parser) => Args {
+                command: Some(Commands::Merge(parser.merge_args)),
+                create_config: false,
+            },
+            Err(e) => {
+                // If MergeArgs parsing also fails, show the error and exit
+                e.exit();
+            }
+        }
+    }
+}
+
+impl Args {
+    /// Resolve configuration from CLI args, environment variables, config file, and git remote
+    /// Priority: CLI args > environment variables > git remote > config file > defaults
+    pub fn resolve_config(self) -> Result<AppConfig> {
+        // Destructure self to extract command
+        let Args {
+            command,
+            create_config: _,
+        } = self;
+
+        // Use command or default to merge mode
+        let mode_command = command.unwrap_or_else(|| {
+            Commands::Merge(MergeArgs {
+                shared: SharedArgs::default(),
+                ni: NonInteractiveArgs::default(),
+                work_item_state: None,
+                run_hooks: false,
+                strict_states: false,
+                check_builds: false,
+                require_green_build: false,
+                release_tag: false,
+                push_release_tag: false,
+                sign_commits: false,
+                squash: false,
+                squash_message: None,
+                mainline_fallback: false,
+                auto_refresh: None,
+                select_file: None,
+                author: None,
+                reviewer: None,
+                path_glob: Vec::new(),
+                session: None,
+                cherry_pick_order: None,
+                subcommand: None,
+            })
+        });
+
+        // Access shared args through the command using the trait
+        let shared = mode_command.shared_args();
+
+        // Determine local_repo path from CLI (positional arg takes precedence over --local-repo flag)
+        let cli_local_repo = shared.path.as_ref().or(shared.local_repo.as_ref());
+
+        // Load from config file (lowest priority)
+        let file_config = Config::load_from_file()?;
+
+        // Load from environment variables
+        let env_config = Config::load_from_env();
+
+        // Resolve repo aliases for all commands (supports path or alias via SharedArgs.path)
+        let repo_aliases = file_config.repo_aliases.as_ref().map(|p| p.value().clone());
+        let resolved_local_repo = cli_local_repo.and_then(|path| {
+            crate::config::resolve_repo_path(Some(path), &repo_aliases)
+                .ok()
+                .map(|p| p.to_string_lossy().to_string())
+        });
+
+        // Determine effective local_repo path for git detection
+        // CLI (resolved via aliases) takes precedence, then env var, then config file
+        let effective_local_repo = resolved_local_repo.or_else(|| {
+            env_config
+                .local_repo
+                .as_ref()
+                .map(|p| p.value().clone())
+                .or_else(|| file_config.local_repo.as_ref().map(|p| p.value().clone()))
+        });
+
+        // Try to detect from git remote if we have a local repo path from any source
+        let git_config = if let Some(ref repo_path) = effective_local_repo {
+            Config::detect_from_git_remote(repo_path)
+        } else {
+            Config::default()
+        };
+
+        let cli_config = Config::from_shared_args(shared);
+
+        // Merge configs: file < git_remote < env < cli
+        let merged_config = file_config
+            .merge(git_config)
+            .merge(env_config)
+            .merge(cli_config);
+
+        // Strict state checking applies to merge workflows only
+        let strict_states = match &mode_command {
+            Commands::Merge(merge_args) => {
+                merged_config.strict_work_item_states(merge_args.strict_states)?
+            }
+            Commands::Promote(_) | Commands::Watch(_) => {
+                merged_config.strict_work_item_states(false)?
+            }
+            _ => None,
+        };
+
+        let release_gate = merged_config.release_gate()?;
+        let build_checks = match &mode_command {
+            Commands::Merge(merge_args) => merged_config
+                .build_check_mode(merge_args.check_builds, merge_args.require_green_build),
+            _ => merged_config.build_check_mode(false, false),
+        };
+        let release_tag = match &mode_command {
+            Commands::Merge(merge_args) => {
+                merged_config.release_tag_mode(merge_args.release_tag, merge_args.push_release_tag)
+            }
+            _ => merged_config.release_tag_mode(false, false),
+        };
+        let sign_commits = match &mode_command {
+            Commands::Merge(merge_args) => merged_config.sign_commits(merge_args.sign_commits),
+            _ => merged_config.sign_commits(false),
+        };
+        let squash_message = match &mode_command {
+            Commands::Merge(merge_args) => merged_config
+                .squash_message(merge_args.squash, merge_args.squash_message.as_deref()),
+            _ => merged_config.squash_message(false, None),
+        };
+        let mainline_fallback = match &mode_command {
+            Commands::Merge(merge_args) => {
+                merged_config.mainline_fallback(merge_args.mainline_fallback)
+            }
+            _ => merged_config.mainline_fallback(false),
+        };
+        let url_templates = merged_config.url_templates()?;
+        let pr_tabs = merged_config.pr_tabs()?;
+        let selection_caps = merged_config.selection_caps();
+        let terminal_states = merged_config.terminal_work_item_states();
+        let work_item_comment = merged_config.work_item_comment();
+        let artifacts_feed = merged_config.artifacts_feed();
+        let work_item_fields = merged_config.work_item_field_rules()?;
+        let notifications = merged_config.notifications();
+        let binary_conflicts = merged_config.binary_conflict_policy()?;
+        let cherry_pick_order = merged_config.cherry_pick_order(match &mode_command {
+            Commands::Merge(merge_args) => merge_args.cherry_pick_order,
+            _ => None,
+        });
+        let task_patterns = match &mode_command {
+            Commands::ReleaseNotes(_) => merged_config.task_patterns(
+                merged_config
+                    .repository
+                    .as_ref()
+                    .map(|p| p.value().as_str())
+                    .unwrap_or_default(),
+            )?,
+            _ => Default::default(),
+        };
+        let release_notes_wiki = match &mode_command {
+            Commands::ReleaseNotes(rn_args) => {
+                rn_args.wiki_target(merged_config.release_notes_wiki())?
+            }
+            _ => None,
+        };
+        let release_notes_locale = merged_config.release_notes_locale(match &mode_command {
+            Commands::ReleaseNotes(rn_args) => rn_args.locale.as_deref(),
+            _ => None,
+        })?;
+        // Auto-refresh only applies to the PR selection TUI; 0 in config disables it
+        let auto_refresh = match &mode_command {
+            Commands::Merge(merge_args) if !merge_args.ni.non_interactive => merge_args
+                .auto_refresh
+                .or_else(|| merged_config.auto_refresh.as_ref().map(|p| *p.value())),
+            Commands::Watch(_) => merged_config.auto_refresh.as_ref().map(|p| *p.value()),
+            _ => None,
+        }
+        .filter(|interval| !interval.is_zero());
+        let clipboard_options = merged_config.clipboard_options();
+
+        // Validate required shared fields
+        let organization = merged_config.organization
+            .ok_or_else(|| anyhow::anyhow!("organization is required (use --organization, MERGERS_ORGANIZATION env var, or config file)"))?;
+        let project = merged_config.project.ok_or_else(|| {
+            anyhow::anyhow!(
+                "project is required (use --project, MERGERS_PROJECT env var, or config file)"
+            )
+        })?;
+        let repository = merged_config.repository
+            .ok_or_else(|| anyhow::anyhow!("repository is required (use --repository, MERGERS_REPOSITORY env var, or config file)"))?;
+        let pat = merged_config.pat.ok_or_else(|| {
+            anyhow::anyhow!("pat is required (use --pat, MERGERS_PAT env var, or config file)")
+        })?;
+
+        // Handle since field parsing
+        let since = if let Some(since_str) = &shared.since {
+            let parsed_date = parse_since_date(since_str)
+                .with_context(|| format!("Failed to parse since date: {}", since_str))?;
+            Some(ParsedProperty::Cli(parsed_date, since_str.clone()))
+        } else {
+            None
+        };
+
+        // A list of target branches fans the merge out; everything else uses the first
+        let target_branch = merged_config.target_branch;
+        let fan_out = target_branch
+            .as_ref()
+            .map(|targets| resolve_target_branches(targets.value()).1)
+            .unwrap_or_default();
+
+        let shared_config = SharedConfig {
+            organization,
+            project,
+            repository,
+            pat,
+            write_pat: merged_config.write_pat,
+            dev_branch: merged_config
+                .dev_branch
+                .unwrap_or_else(|| "dev".to_string().into()),
+            target_branch: target_branch
+                .map(|targets| targets.map(|value| resolve_target_branches(&value).0))
+                .unwrap_or_else(|| "next".to_string().into()),
+            local_repo: merged_config.local_repo,
+            parallel_limit: merged_config.parallel_limit.unwrap_or(300.into()),
+            max_concurrent_network: merged_config.max_concurrent_network.unwrap_or(100.into()),
+            max_concurrent_processing: merged_config.max_concurrent_processing.unwrap_or(10.into()),
+            tag_prefix: merged_config
+                .tag_prefix
+                .unwrap_or_else(|| "merged-".to_string().into()),
+            since,
+            since_field: merged_config
+                .since_field
+                .map(|p| *p.value())
+                .unwrap_or_default(),
+            skip_confirmation: shared.skip_confirmation,
+            api_version: merged_config.api_version,
+            history_depth: merged_config.history_depth,
+            url_templates,
+            release_notes_locale: release_notes_locale.clone(),
+        };
+
+        // Return appropriate configuration based on command
+        match mode_command {
+            Commands::Migrate(migrate_args) => {
+                // Parse terminal states from CLI
+                let terminal_states_parsed = crate::api::AzureDevOpsClient::parse_terminal_states(
+                    &migrate_args.terminal_states,
+                );
+                Ok(AppConfig::Migration {
+                    shared: shared_config,
+                    migration: MigrationModeConfig {
+                        terminal_states: ParsedProperty::Cli(
+                            terminal_states_parsed,
+                            migrate_args.terminal_states,
+                        ),
+                    },
+                })
+            }
+            Commands::Promote(promote_args) => Ok(AppConfig::Default {
+                shared: shared_config,
+                default: DefaultModeConfig {
+                    strict_states: strict_states.clone(),
+                    release_gate: release_gate.clone(),
+                    build_checks,
+                    release_tag,
+                    sign_commits,
+                    fan_out,
+                    squash_message: squash_message.clone(),
+                    mainline_fallback,
+                    auto_refresh: None,
+                    select_file: None,
+                    pr_tabs: Vec::new(),
+                    selection_soft_cap: None,
+                    terminal_states: Vec::new(),
+                    work_item_comment: work_item_comment.clone(),
+                    artifacts_feed: artifacts_feed.clone(),
+                    work_item_fields: work_item_fields.clone(),
+                    notifications: notifications.clone(),
+                    binary_conflicts: binary_conflicts.clone(),
+                    cherry_pick_order,
+                    pr_filter: Default::default(),
+                    session: None,
+                    clipboard: clipboard_options.clone(),
+                    work_item_state: match promote_args.work_item_state {
+                        Some(state) => ParsedProperty::Cli(state.clone(), state),
+                        None => merged_config
+                            .work_item_state
+                            .unwrap_or_else(|| ParsedProperty::Default("Next Merged".to_string())),
+                    },
+                    run_hooks: if promote_args.run_hooks {
+                        ParsedProperty::Cli(true, "true".to_string())
+                    } else {
+                        merged_config
+                            .run_hooks
+                            .unwrap_or(ParsedProperty::Default(false))
+                    },
+                },
+            }),
+            Commands::Watch(watch_args) => Ok(AppConfig::Default {
+                shared: shared_config,
+                default: DefaultModeConfig {
+                    strict_states: strict_states.clone(),
+                    release_gate: release_gate.clone(),
+                    build_checks,
+                    release_tag,
+                    sign_commits,
+                    fan_out,
+                    squash_message: squash_message.clone(),
+                    mainline_fallback,
+                    auto_refresh,
+                    select_file: None,
+                    pr_tabs: pr_tabs.clone(),
+                    selection_soft_cap: selection_caps.soft,
+                    terminal_states: terminal_states.clone(),
+                    work_item_comment: work_item_comment.clone(),
+                    artifacts_feed: artifacts_feed.clone(),
+                    work_item_fields: work_item_fields.clone(),
+                    notifications: notifications.clone(),
+                    binary_conflicts: binary_conflicts.clone(),
+                    cherry_pick_order,
+                    pr_filter: Default::default(),
+                    session: None,
+                    clipboard: clipboard_options.clone(),
+                    work_item_state: match watch_args.work_item_state {
+                        Some(state) => ParsedProperty::Cli(state.clone(), state),
+                        None => merged_config
+                            .work_item_state
+                            .unwrap_or_else(|| ParsedProperty::Default("Next Merged".to_string())),
+                    },
+                    run_hooks: if watch_args.run_hooks {
+                        ParsedProperty::Cli(true, "true".to_string())
+                    } else {
+                        merged_config
+                            .run_hooks
+                            .unwrap_or(ParsedProperty::Default(false))
+                    },
+                },
+            }),
+            Commands::Merge(merge_args) => Ok(AppConfig::Default {
+                shared: shared_config,
+                default: DefaultModeConfig {
+                    strict_states,
+                    release_gate,
+                    build_checks,
+                    release_tag,
+                    sign_commits,
+                    fan_out,
+                    squash_message: squash_message.clone(),
+                    mainline_fallback,
+                    auto_refresh,
+                    select_file: merge_args.select_file,
+                    pr_tabs,
+                    selection_soft_cap: selection_caps.soft,
+                    terminal_states: terminal_states.clone(),
+                    work_item_comment: work_item_comment.clone(),
+                    artifacts_feed: artifacts_feed.clone(),
+                    work_item_fields: work_item_fields.clone(),
+                    notifications: notifications.clone(),
+                    binary_conflicts: binary_conflicts.clone(),
+                    cherry_pick_order,
+                    pr_filter: crate::core::operations::PrFilter::new(
+                        merge_args.author,
+                        merge_args.reviewer,
+                        merge_args.path_glob,
+                    ),
+                    session: merge_args.session,
+                    clipboard: clipboard_options.clone(),
+                    work_item_state: match merge_args.work_item_state {
+                        Some(state) => ParsedProperty::Cli(state.clone(), state),
+                        None => merged_config
+                            .work_item_state
+                            .unwrap_or_else(|| ParsedProperty::Default("Next Merged".to_string())),
+                    },
+                    run_hooks: if merge_args.run_hooks {
+                        ParsedProperty::Cli(true, "true".to_string())
+                    } else {
+                        merged_config
+                            .run_hooks
+                            .unwrap_or(ParsedProperty::Default(false))
+                    },
+                },
+            }),
+            Commands::Cleanup(cleanup_args) => {
+                let target = cleanup_args
+                    .target
+                    .map(|t| ParsedProperty::Cli(t.clone(), t))
+                    .or_else(|| Some(shared_config.target_branch.clone()))
+                    .unwrap();
+                Ok(AppConfig::Cleanup {
+                    shared: shared_config,
+                    cleanup: CleanupModeConfig { target },
+                })
+            }
+            Commands::ReleaseNotes(rn_args) => Ok(AppConfig::ReleaseNotes {
+                shared: shared_config,
+                release_notes: ReleaseNotesModeConfig {
+                    from_version: rn_args.from.clone(),
+                    to_version: rn_args.to.clone(),
+                    output_format: rn_args.output,
+                    grouped: rn_args.group,
+                    include_prs: rn_args.include_prs,
+                    include_unreferenced: rn_args.include_unreferenced,
+                    copy_to_clipboard: rn_args.copy,
+                    clipboard: clipboard_options,
+                    no_cache: rn_args.no_cache,
+                    task_patterns,
+                    locale: release_notes_locale,
+                    changelog: rn_args.changelog.clone(),
+                    previously_released: rn_args.previously_released,
+                    wiki: release_notes_wiki,
+                },
+            }),
+            Commands::Analyze(analyze_args) => Ok(AppConfig::Analyze {
+                shared: shared_config,
+                analyze: AnalyzeModeConfig {
+                    output_format: analyze_args.output_format(),
+                    output_path: analyze_args.output,
+                    select_by_state: analyze_args.select_by_state,
+                    plan: analyze_args.plan,
+                },
+            }),
+            // Metrics only reads the release history in the state directory
+            Commands::Metrics(_) => {
+                anyhow::bail!("the metrics command does not use an application configuration")
+            }
+            // Continue and abort only need the state file of the paused merge
+            Commands::Continue(_) | Commands::Abort(_) => {
+                anyhow::bail!(
+                    "the continue and abort commands do not use an application configuration"
+                )
+            }
+            // Gc only removes run directories in the state directory
+            Commands::Gc(_) => {
+                anyhow::bail!("the gc command does not use an application configuration")
+            }
+            // State only rewrites state files in the state directory
+            Commands::State(_) => {
+                anyhow::bail!("the state command does not use an application configuration")
+            }
+            // Batch resolves a runner configuration per repository
+            Commands::Batch(_) => {
+                anyhow::bail!("the batch command does not use an application configuration")
+            }
+        }
+    }
+}
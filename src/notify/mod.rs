@@ -0,0 +1,479 @@
+//! Chat notifications when a merge run finishes.
+//!
+//! Once the post-merge tasks of a merge are done, in the TUI or in
+//! non-interactive mode, a summary of the run is posted to each configured
+//! incoming webhook: the release version, the merged PRs, the PRs that hit a
+//! conflict, the PRs left out and a link to the release notes.
+//!
+//! # Config Format
+//!
+//! ```toml
+//! [notifications]
+//! slack_webhook = "https://hooks.slack.com/services/T000/B000/XXXX"
+//! teams_webhook = "https://contoso.webhook.office.com/webhookb2/..."
+//! release_notes_url = "https://dev.azure.com/contoso/Platform/_wiki/wikis/Contoso.wiki?pagePath=/Releases/{version}"
+//! ```
+//!
+//! `{version}` in `release_notes_url` is replaced with the release version.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::notify::{MergeNotification, NotificationChannel, NotifiedPr, payload};
+//!
+//! let notification = MergeNotification {
+//!     version: "v1.4.0".to_string(),
+//!     repository: "web".to_string(),
+//!     target_branch: "release".to_string(),
+//!     merged: vec![NotifiedPr { id: 12, title: "Fix login".to_string(), had_conflict: false }],
+//!     not_merged: Vec::new(),
+//!     release_notes_url: None,
+//! };
+//! assert_eq!(notification.headline(), "Merged 1 PR into release for v1.4.0 (web)");
+//! assert!(payload(NotificationChannel::Slack, &notification)["text"].is_string());
+//! ```
+
+mod slack;
+mod teams;
+
+use std::fmt;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use azure_core::http::{HttpClient, Method, Request, Url, new_http_client};
+use serde::{Deserialize, Serialize};
+
+use crate::core::state::{MergeStateFile, StateItemStatus};
+
+/// Most PRs listed per section; the rest are counted.
+pub const MAX_LISTED_PRS: usize = 25;
+
+/// Webhooks notified when a merge finishes, as configured in `[notifications]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationsConfig {
+    /// Slack incoming webhook URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_webhook: Option<String>,
+    /// Microsoft Teams incoming webhook URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub teams_webhook: Option<String>,
+    /// Link to the release notes; `{version}` is replaced with the release version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_notes_url: Option<String>,
+}
+
+impl NotificationsConfig {
+    /// Returns the configured channels with their webhook URLs.
+    pub fn webhooks(&self) -> Vec<(NotificationChannel, &str)> {
+        [
+            (NotificationChannel::Slack, &self.slack_webhook),
+            (NotificationChannel::Teams, &self.teams_webhook),
+        ]
+        .into_iter()
+        .filter_map(|(channel, url)| Some((channel, url.as_deref()?)))
+        .collect()
+    }
+
+    /// Returns the webhook URL of `channel`, if configured.
+    pub fn webhook(&self, channel: NotificationChannel) -> Option<&str> {
+        match channel {
+            NotificationChannel::Slack => self.slack_webhook.as_deref(),
+            NotificationChannel::Teams => self.teams_webhook.as_deref(),
+        }
+    }
+
+    /// Returns the release notes link of a release.
+    pub fn release_notes_url(&self, version: &str) -> Option<String> {
+        self.release_notes_url
+            .as_ref()
+            .map(|url| url.replace("{version}", version))
+    }
+}
+
+/// A chat service notified through an incoming webhook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationChannel {
+    Slack,
+    Teams,
+}
+
+impl fmt::Display for NotificationChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotificationChannel::Slack => write!(f, "Slack"),
+            NotificationChannel::Teams => write!(f, "Teams"),
+        }
+    }
+}
+
+/// A PR listed in a notification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotifiedPr {
+    /// PR ID.
+    pub id: i32,
+    /// PR title.
+    pub title: String,
+    /// Whether the cherry-pick hit a conflict.
+    pub had_conflict: bool,
+}
+
+/// Summary of a finished merge, posted to every configured webhook.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeNotification {
+    /// Release version of the merge.
+    pub version: String,
+    /// Repository the merge ran in.
+    pub repository: String,
+    /// Branch the PRs were merged into.
+    pub target_branch: String,
+    /// PRs cherry-picked onto the patch branch.
+    pub merged: Vec<NotifiedPr>,
+    /// PRs skipped, failed or never picked.
+    pub not_merged: Vec<NotifiedPr>,
+    /// Link to the release notes.
+    pub release_notes_url: Option<String>,
+}
+
+impl MergeNotification {
+    /// Summarizes the merge recorded in a state file.
+    pub fn from_state(state: &MergeStateFile, config: &NotificationsConfig) -> Self {
+        let (merged, not_merged) = state
+            .cherry_pick_items
+            .iter()
+            .map(|item| {
+                let pr = NotifiedPr {
+                    id: item.pr_id,
+                    title: item.pr_title.clone(),
+                    had_conflict: item.had_conflict,
+                };
+                (item.status == StateItemStatus::Success, pr)
+            })
+            .partition::<Vec<_>, _>(|(success, _)| *success);
+        Self {
+            version: state.merge_version.clone(),
+            repository: state.repository.clone(),
+            target_branch: state.target_branch.clone(),
+            merged: merged.into_iter().map(|(_, pr)| pr).collect(),
+            not_merged: not_merged.into_iter().map(|(_, pr)| pr).collect(),
+            release_notes_url: config.release_notes_url(&state.merge_version),
+        }
+    }
+
+    /// One-line summary of the merge.
+    pub fn headline(&self) -> String {
+        format!(
+            "Merged {} PR{} into {} for {} ({})",
+            self.merged.len(),
+            if self.merged.len() == 1 { "" } else { "s" },
+            self.target_branch,
+            self.version,
+            self.repository
+        )
+    }
+
+    /// PRs that hit a conflict, merged or not.
+    pub fn conflicts(&self) -> Vec<&NotifiedPr> {
+        self.merged
+            .iter()
+            .chain(&self.not_merged)
+            .filter(|pr| pr.had_conflict)
+            .collect()
+    }
+}
+
+/// Returns the lines listing `prs`, capped at [`MAX_LISTED_PRS`].
+fn pr_lines<'a>(
+    prs: impl IntoIterator<Item = &'a NotifiedPr>,
+    format: impl Fn(&NotifiedPr) -> String,
+) -> Vec<String> {
+    let prs: Vec<&NotifiedPr> = prs.into_iter().collect();
+    let mut lines: Vec<String> = prs
+        .iter()
+        .take(MAX_LISTED_PRS)
+        .map(|pr| format(pr))
+        .collect();
+    if prs.len() > MAX_LISTED_PRS {
+        lines.push(format!("…and {} more", prs.len() - MAX_LISTED_PRS));
+    }
+    lines
+}
+
+/// Builds the webhook payload of `channel`.
+pub fn payload(
+    channel: NotificationChannel,
+    notification: &MergeNotification,
+) -> serde_json::Value {
+    match channel {
+        NotificationChannel::Slack => slack::payload(notification),
+        NotificationChannel::Teams => teams::payload(notification),
+    }
+}
+
+/// Posts a notification to a webhook.
+pub async fn send(
+    channel: NotificationChannel,
+    webhook: &str,
+    notification: &MergeNotification,
+) -> Result<()> {
+    send_with(&new_http_client(), channel, webhook, notification).await
+}
+
+/// Posts a notification to a webhook with the given HTTP client.
+pub async fn send_with(
+    http: &Arc<dyn HttpClient>,
+    channel: NotificationChannel,
+    webhook: &str,
+    notification: &MergeNotification,
+) -> Result<()> {
+    let url = Url::parse(webhook).with_context(|| format!("Invalid {} webhook URL", channel))?;
+    let mut request = Request::new(url, Method::Post);
+    request.insert_header("content-type", "application/json");
+    request.set_json(&payload(channel, notification))?;
+
+    let response = http
+        .execute_request(&request)
+        .await
+        .with_context(|| format!("Failed to reach the {} webhook", channel))?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response
+            .into_body()
+            .collect_string()
+            .await
+            .unwrap_or_default();
+        anyhow::bail!(
+            "{} webhook returned HTTP {}: {}",
+            channel,
+            u16::from(status),
+            body.trim()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::state::{MergeStateFileBuilder, StateCherryPickItem};
+    use async_trait::async_trait;
+    use azure_core::http::{AsyncRawResponse, StatusCode, headers::Headers};
+    use std::sync::Mutex;
+
+    pub(super) fn sample_notification() -> MergeNotification {
+        MergeNotification {
+            version: "v1.4.0".to_string(),
+            repository: "web".to_string(),
+            target_branch: "release".to_string(),
+            merged: vec![
+                NotifiedPr {
+                    id: 12,
+                    title: "Fix login".to_string(),
+                    had_conflict: false,
+                },
+                NotifiedPr {
+                    id: 13,
+                    title: "Cache <tokens> & keys".to_string(),
+                    had_conflict: true,
+                },
+            ],
+            not_merged: vec![NotifiedPr {
+                id: 14,
+                title: "Rework billing".to_string(),
+                had_conflict: true,
+            }],
+            release_notes_url: Some("https://example.com/notes/v1.4.0".to_string()),
+        }
+    }
+
+    /// Transport answering every request with one status, recording the bodies.
+    #[derive(Debug)]
+    struct RecordingTransport {
+        status: u16,
+        bodies: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl HttpClient for RecordingTransport {
+        async fn execute_request(&self, request: &Request) -> azure_core::Result<AsyncRawResponse> {
+            let body: &[u8] = match request.body() {
+                azure_core::http::Body::Bytes(bytes) => bytes,
+                _ => &[],
+            };
+            self.bodies
+                .lock()
+                .unwrap()
+                .push(String::from_utf8_lossy(body).into_owned());
+            Ok(AsyncRawResponse::from_bytes(
+                StatusCode::from(self.status),
+                Headers::new(),
+                "invalid_token",
+            ))
+        }
+    }
+
+    /// # Notification From State
+    ///
+    /// Tests summarizing a finished merge from its state file.
+    ///
+    /// ## Test Scenario
+    /// - Builds a state file with merged, conflicted and skipped PRs
+    /// - Summarizes it with a release notes link template
+    ///
+    /// ## Expected Outcome
+    /// - PRs are split into merged and not merged, conflicts are kept
+    /// - The link and headline name the version
+    #[test]
+    fn test_notification_from_state() {
+        let mut state = MergeStateFileBuilder::new()
+            .repo_path("/work/web")
+            .organization("contoso")
+            .project("Platform")
+            .repository("web")
+            .dev_branch("dev")
+            .target_branch("release")
+            .merge_version("v1.4.0")
+            .work_item_state("Done")
+            .tag_prefix("merged-")
+            .build();
+        let item = |pr_id, status, had_conflict| StateCherryPickItem {
+            commit_id: format!("c{}", pr_id),
+            pr_id,
+            pr_title: format!("PR {}", pr_id),
+            status,
+            work_item_ids: Vec::new(),
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict,
+        };
+        state.cherry_pick_items = vec![
+            item(1, StateItemStatus::Success, false),
+            item(2, StateItemStatus::Success, true),
+            item(3, StateItemStatus::Skipped, true),
+        ];
+        let config = NotificationsConfig {
+            release_notes_url: Some("https://example.com/notes/{version}".to_string()),
+            ..Default::default()
+        };
+
+        let notification = MergeNotification::from_state(&state, &config);
+        assert_eq!(
+            notification
+                .merged
+                .iter()
+                .map(|pr| pr.id)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            notification
+                .not_merged
+                .iter()
+                .map(|pr| pr.id)
+                .collect::<Vec<_>>(),
+            vec![3]
+        );
+        assert_eq!(
+            notification
+                .conflicts()
+                .iter()
+                .map(|pr| pr.id)
+                .collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        assert_eq!(
+            notification.release_notes_url.as_deref(),
+            Some("https://example.com/notes/v1.4.0")
+        );
+        assert_eq!(
+            notification.headline(),
+            "Merged 2 PRs into release for v1.4.0 (web)"
+        );
+        assert!(config.webhooks().is_empty());
+    }
+
+    /// # Long PR Lists
+    ///
+    /// Tests capping the PRs listed in a section.
+    ///
+    /// ## Test Scenario
+    /// - Lists more PRs than the cap
+    ///
+    /// ## Expected Outcome
+    /// - The cap is listed and the rest counted on one line
+    #[test]
+    fn test_pr_lines_capped() {
+        let prs: Vec<NotifiedPr> = (0..MAX_LISTED_PRS as i32 + 3)
+            .map(|id| NotifiedPr {
+                id,
+                title: String::new(),
+                had_conflict: false,
+            })
+            .collect();
+        let lines = pr_lines(&prs, |pr| pr.id.to_string());
+        assert_eq!(lines.len(), MAX_LISTED_PRS + 1);
+        assert_eq!(lines.last().unwrap(), "…and 3 more");
+    }
+
+    /// # Sending To a Webhook
+    ///
+    /// Tests posting a notification and reporting webhook errors.
+    ///
+    /// ## Test Scenario
+    /// - Posts to a webhook answering 200, then to one answering 403
+    /// - Posts to a malformed URL
+    ///
+    /// ## Expected Outcome
+    /// - The JSON payload is posted and a success is reported
+    /// - The error names the channel, the status and the response body
+    /// - The malformed URL is rejected before sending
+    #[tokio::test]
+    async fn test_send_with() {
+        let notification = sample_notification();
+        let ok = Arc::new(RecordingTransport {
+            status: 200,
+            bodies: Mutex::new(Vec::new()),
+        });
+        let http: Arc<dyn HttpClient> = ok.clone();
+        send_with(
+            &http,
+            NotificationChannel::Slack,
+            "https://hooks.slack.com/services/T/B/X",
+            &notification,
+        )
+        .await
+        .unwrap();
+        let body = ok.bodies.lock().unwrap()[0].clone();
+        let posted: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(posted, payload(NotificationChannel::Slack, &notification));
+
+        let http: Arc<dyn HttpClient> = Arc::new(RecordingTransport {
+            status: 403,
+            bodies: Mutex::new(Vec::new()),
+        });
+        let error = send_with(
+            &http,
+            NotificationChannel::Teams,
+            "https://contoso.webhook.office.com/x",
+            &notification,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Teams webhook returned HTTP 403: invalid_token"
+        );
+
+        let error = send_with(
+            &http,
+            NotificationChannel::Slack,
+            "not a url",
+            &notification,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(error.to_string(), "Invalid Slack webhook URL");
+    }
+}
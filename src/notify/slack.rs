@@ -0,0 +1,72 @@
+//! Slack incoming webhook messages.
+//!
+//! The summary is sent as `mrkdwn` text, which incoming webhooks render
+//! without any extra setup.
+
+use serde_json::json;
+
+use super::{MergeNotification, NotifiedPr, pr_lines};
+
+/// Escapes the characters Slack treats as markup.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn pr_line(pr: &NotifiedPr) -> String {
+    format!("• #{} {}", pr.id, escape(&pr.title))
+}
+
+/// Builds the webhook payload of a notification.
+pub(super) fn payload(notification: &MergeNotification) -> serde_json::Value {
+    let mut text = format!("*{}*", escape(&notification.headline()));
+
+    let sections = [
+        ("Merged", notification.merged.iter().collect::<Vec<_>>()),
+        ("Conflicts", notification.conflicts()),
+        ("Not merged", notification.not_merged.iter().collect()),
+    ];
+    for (title, prs) in sections {
+        if prs.is_empty() {
+            continue;
+        }
+        text.push_str(&format!("\n\n*{}*\n", title));
+        text.push_str(&pr_lines(prs, pr_line).join("\n"));
+    }
+
+    if let Some(url) = &notification.release_notes_url {
+        text.push_str(&format!("\n\n<{}|Release notes>", url));
+    }
+
+    json!({ "text": text })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notify::tests::sample_notification;
+
+    /// # Slack Payload
+    ///
+    /// Tests the message posted to a Slack webhook.
+    ///
+    /// ## Test Scenario
+    /// - Renders a notification with merged, conflicted and skipped PRs
+    ///
+    /// ## Expected Outcome
+    /// - Every section is listed, titles are escaped
+    /// - The release notes are linked
+    #[test]
+    fn test_slack_payload() {
+        let payload = payload(&sample_notification());
+        assert_eq!(
+            payload["text"],
+            "*Merged 2 PRs into release for v1.4.0 (web)*\n\n\
+             *Merged*\n• #12 Fix login\n• #13 Cache &lt;tokens&gt; &amp; keys\n\n\
+             *Conflicts*\n• #13 Cache &lt;tokens&gt; &amp; keys\n• #14 Rework billing\n\n\
+             *Not merged*\n• #14 Rework billing\n\n\
+             <https://example.com/notes/v1.4.0|Release notes>"
+        );
+    }
+}
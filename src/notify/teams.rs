@@ -0,0 +1,125 @@
+//! Microsoft Teams incoming webhook messages.
+//!
+//! The summary is sent as an Adaptive Card, the format accepted by Teams
+//! workflow webhooks.
+
+use serde_json::json;
+
+use super::{MergeNotification, NotifiedPr, pr_lines};
+
+fn pr_line(pr: &NotifiedPr) -> String {
+    format!("- #{} {}", pr.id, pr.title)
+}
+
+/// Builds the webhook payload of a notification.
+pub(super) fn payload(notification: &MergeNotification) -> serde_json::Value {
+    let mut body = vec![json!({
+        "type": "TextBlock",
+        "text": notification.headline(),
+        "weight": "Bolder",
+        "size": "Medium",
+        "wrap": true,
+    })];
+
+    let sections = [
+        ("Merged", notification.merged.iter().collect::<Vec<_>>()),
+        ("Conflicts", notification.conflicts()),
+        ("Not merged", notification.not_merged.iter().collect()),
+    ];
+    for (title, prs) in sections {
+        if prs.is_empty() {
+            continue;
+        }
+        body.push(json!({
+            "type": "TextBlock",
+            "text": title,
+            "weight": "Bolder",
+            "spacing": "Medium",
+        }));
+        body.push(json!({
+            "type": "TextBlock",
+            "text": pr_lines(prs, pr_line).join("\r"),
+            "wrap": true,
+        }));
+    }
+
+    let actions: Vec<serde_json::Value> = notification
+        .release_notes_url
+        .iter()
+        .map(|url| {
+            json!({
+                "type": "Action.OpenUrl",
+                "title": "Release notes",
+                "url": url,
+            })
+        })
+        .collect();
+
+    json!({
+        "type": "message",
+        "attachments": [{
+            "contentType": "application/vnd.microsoft.card.adaptive",
+            "content": {
+                "$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+                "type": "AdaptiveCard",
+                "version": "1.4",
+                "body": body,
+                "actions": actions,
+            },
+        }],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notify::tests::sample_notification;
+
+    /// # Teams Payload
+    ///
+    /// Tests the Adaptive Card posted to a Teams webhook.
+    ///
+    /// ## Test Scenario
+    /// - Renders a notification with merged, conflicted and skipped PRs
+    /// - Renders it again without a release notes link
+    ///
+    /// ## Expected Outcome
+    /// - The card has the headline and one title and list per section
+    /// - The release notes are an action, dropped without a link
+    #[test]
+    fn test_teams_payload() {
+        let mut notification = sample_notification();
+        let payload = payload(&notification);
+        let card = &payload["attachments"][0]["content"];
+        assert_eq!(card["type"], "AdaptiveCard");
+        let texts: Vec<&str> = card["body"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|block| block["text"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                "Merged 2 PRs into release for v1.4.0 (web)",
+                "Merged",
+                "- #12 Fix login\r- #13 Cache <tokens> & keys",
+                "Conflicts",
+                "- #13 Cache <tokens> & keys\r- #14 Rework billing",
+                "Not merged",
+                "- #14 Rework billing",
+            ]
+        );
+        assert_eq!(
+            card["actions"][0]["url"],
+            "https://example.com/notes/v1.4.0"
+        );
+
+        notification.release_notes_url = None;
+        let payload = super::payload(&notification);
+        assert_eq!(
+            payload["attachments"][0]["content"]["actions"],
+            serde_json::json!([])
+        );
+    }
+}
@@ -57,6 +57,20 @@ impl<T> ParsedProperty<T> {
     pub fn is_from_source(&self, source: &str) -> bool {
         self.source_name() == source
     }
+
+    /// Converts the parsed value, keeping the source and original value
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> ParsedProperty<U> {
+        match self {
+            ParsedProperty::Cli(value, original) => ParsedProperty::Cli(f(value), original),
+            ParsedProperty::Env(value, original) => ParsedProperty::Env(f(value), original),
+            ParsedProperty::Git(value, original) => ParsedProperty::Git(f(value), original),
+            ParsedProperty::File(value, path, original) => {
+                ParsedProperty::File(f(value), path, original)
+            }
+            ParsedProperty::Default(value) => ParsedProperty::Default(f(value)),
+        }
+    }
 }
 
 impl<T> Deref for ParsedProperty<T> {
@@ -656,4 +670,29 @@ mod tests {
         assert_eq!(&*string_prop, "test");
         assert_eq!(&*vec_prop, &vec![1, 2, 3]);
     }
+
+    /// # ParsedProperty Map
+    ///
+    /// Tests converting the parsed value of a property.
+    ///
+    /// ## Test Scenario
+    /// - Maps file and default properties to another value
+    ///
+    /// ## Expected Outcome
+    /// - The value is converted while the source and original value are kept
+    #[test]
+    fn test_parsed_property_map() {
+        let file_prop = ParsedProperty::File(
+            "a,b".to_string(),
+            PathBuf::from("config.toml"),
+            "a,b".to_string(),
+        );
+        let mapped = file_prop.map(|v| v.split(',').count());
+        assert_eq!(*mapped.value(), 2);
+        assert_eq!(mapped.source_name(), "file");
+        assert_eq!(mapped.original(), Some("a,b"));
+
+        let default_prop = ParsedProperty::Default(2).map(|v| v * 2);
+        assert_eq!(default_prop, ParsedProperty::Default(4));
+    }
 }
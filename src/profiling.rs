@@ -0,0 +1,202 @@
+//! Per-phase timings for `--profile-run`.
+//!
+//! Hot paths such as fetching pull requests, reading the target branch
+//! history and dependency analysis wrap their work in [`phase`]. While
+//! profiling is disabled the guard does nothing; once [`enable`] has been
+//! called every phase adds its elapsed time to a process-wide [`Profile`],
+//! which the binary prints with [`report`] when the run ends.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use mergers::profiling::Profile;
+//!
+//! let mut profile = Profile::default();
+//! profile.record("fetch pull requests", Duration::from_millis(1200));
+//! profile.record("match merge history", Duration::from_micros(40));
+//! profile.record("match merge history", Duration::from_micros(60));
+//!
+//! assert_eq!(profile.phases()[1].calls, 2);
+//! assert!(profile.format().contains("fetch pull requests"));
+//! ```
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static PROFILE: Mutex<Profile> = Mutex::new(Profile { phases: Vec::new() });
+
+/// Total time spent in one phase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhaseTiming {
+    /// Phase name, as passed to [`phase`].
+    pub name: &'static str,
+    /// Time summed over all calls.
+    pub total: Duration,
+    /// Number of times the phase ran.
+    pub calls: u32,
+}
+
+/// Phase timings in the order the phases first ran.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile {
+    phases: Vec<PhaseTiming>,
+}
+
+impl Profile {
+    /// Adds one call of a phase.
+    pub fn record(&mut self, name: &'static str, elapsed: Duration) {
+        match self.phases.iter_mut().find(|phase| phase.name == name) {
+            Some(phase) => {
+                phase.total += elapsed;
+                phase.calls += 1;
+            }
+            None => self.phases.push(PhaseTiming {
+                name,
+                total: elapsed,
+                calls: 1,
+            }),
+        }
+    }
+
+    /// The recorded phases.
+    pub fn phases(&self) -> &[PhaseTiming] {
+        &self.phases
+    }
+
+    /// Formats the timings as an aligned table.
+    pub fn format(&self) -> String {
+        let width = self
+            .phases
+            .iter()
+            .map(|phase| phase.name.len())
+            .max()
+            .unwrap_or_default();
+        let mut output = String::from("Run profile:\n");
+        if self.phases.is_empty() {
+            output.push_str("  (no phases recorded)\n");
+        }
+        for phase in &self.phases {
+            output.push_str(&format!(
+                "  {:<width$}  {:>10.3}s  {:>6} call{}\n",
+                phase.name,
+                phase.total.as_secs_f64(),
+                phase.calls,
+                if phase.calls == 1 { "" } else { "s" },
+            ));
+        }
+        output
+    }
+}
+
+/// Starts recording phase timings for the rest of the process.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Whether phase timings are being recorded.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Times a phase until the returned guard is dropped.
+#[must_use = "the phase ends when the guard is dropped"]
+pub fn phase(name: &'static str) -> PhaseGuard {
+    PhaseGuard {
+        name,
+        start: is_enabled().then(Instant::now),
+    }
+}
+
+/// The timings recorded so far, or `None` if profiling is disabled.
+pub fn report() -> Option<String> {
+    if !is_enabled() {
+        return None;
+    }
+    let profile = PROFILE.lock().unwrap_or_else(|e| e.into_inner());
+    Some(profile.format())
+}
+
+/// Records a phase's elapsed time when dropped.
+#[derive(Debug)]
+pub struct PhaseGuard {
+    name: &'static str,
+    start: Option<Instant>,
+}
+
+impl Drop for PhaseGuard {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            PROFILE
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .record(self.name, start.elapsed());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// # Phase Aggregation
+    ///
+    /// Tests summing repeated phases and formatting the report.
+    ///
+    /// ## Test Scenario
+    /// - Records two phases, one of them twice
+    /// - Formats the profile
+    ///
+    /// ## Expected Outcome
+    /// - Phases keep the order they first ran in
+    /// - Repeated phases sum their time and count their calls
+    /// - The report lists every phase with its total and call count
+    #[test]
+    fn test_profile_record_and_format() {
+        let mut profile = Profile::default();
+        profile.record("read target history", Duration::from_millis(250));
+        profile.record("match merge history", Duration::from_millis(1));
+        profile.record("match merge history", Duration::from_millis(2));
+
+        assert_eq!(
+            profile.phases(),
+            &[
+                PhaseTiming {
+                    name: "read target history",
+                    total: Duration::from_millis(250),
+                    calls: 1,
+                },
+                PhaseTiming {
+                    name: "match merge history",
+                    total: Duration::from_millis(3),
+                    calls: 2,
+                },
+            ]
+        );
+        assert_eq!(
+            profile.format(),
+            "Run profile:\n\
+             \x20 read target history       0.250s       1 call\n\
+             \x20 match merge history       0.003s       2 calls\n"
+        );
+        assert!(Profile::default().format().contains("no phases recorded"));
+    }
+
+    /// # Disabled Profiling
+    ///
+    /// Tests that phase guards are free while profiling is off.
+    ///
+    /// ## Test Scenario
+    /// - Creates a phase guard without enabling profiling
+    ///
+    /// ## Expected Outcome
+    /// - The guard does not start a timer
+    #[test]
+    fn test_phase_disabled() {
+        assert!(!is_enabled());
+        let guard = phase("unused");
+        assert!(guard.start.is_none());
+    }
+}
@@ -0,0 +1,254 @@
+//! Work items already published in an existing changelog.
+//!
+//! Hotfix chains ship the same work item in several versions, and notes for a
+//! version that partially shipped before would list it again. Passing the
+//! existing changelog with `--changelog` collects the work item IDs it
+//! already lists, so they are left out of the new notes or marked as
+//! previously released.
+//!
+//! IDs are read from work item links (`[123](...)`, as written by the
+//! markdown formats) and `#123` references (as written by the plain format
+//! or by hand). Sections whose heading names the version being generated are
+//! skipped, so regenerating notes for a version does not drop its own work
+//! items.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::release_notes::PublishedTasks;
+//!
+//! let changelog = "\
+//! ## v1.2.1
+//! - [101](https://dev.azure.com/org/proj/_workitems/edit/101) Fix login
+//!
+//! ## v1.2.0
+//! - Add export (#99)
+//! ";
+//! let published = PublishedTasks::parse(changelog, "v1.2.1");
+//! assert!(published.contains(99));
+//! assert!(!published.contains(101));
+//! ```
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use anyhow::{Context, Result};
+use regex::Regex;
+
+use super::ReleaseNoteEntry;
+use crate::models::PreviouslyReleased;
+
+static TASK_REFERENCE_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// Matches work item links and `#123` references.
+fn get_task_reference_regex() -> &'static Regex {
+    TASK_REFERENCE_REGEX.get_or_init(|| {
+        Regex::new(r"\[(\d+)\]\(|#(\d+)\b").expect("Failed to compile task reference regex")
+    })
+}
+
+/// IDs of the work items an existing changelog lists.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PublishedTasks {
+    ids: HashSet<i32>,
+}
+
+impl PublishedTasks {
+    /// Reads the changelog at `path`, skipping the sections of `version`.
+    ///
+    /// A missing file lists no work items, so the first run against a new
+    /// changelog behaves like a run without one.
+    pub fn load(path: &Path, version: &str) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Ok(Self::parse(&text, version)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                tracing::debug!("Changelog {} does not exist yet", path.display());
+                Ok(Self::default())
+            }
+            Err(e) => Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        }
+    }
+
+    /// Collects the work item IDs in `text`, skipping the sections of `version`.
+    pub fn parse(text: &str, version: &str) -> Self {
+        let mut ids = HashSet::new();
+        // Level of the heading of the skipped section, while in one
+        let mut skipped_level: Option<usize> = None;
+
+        for line in text.lines() {
+            if let Some(level) = heading_level(line) {
+                if skipped_level.is_some_and(|skipped| level <= skipped) {
+                    skipped_level = None;
+                }
+                if skipped_level.is_none() && names_version(line, version) {
+                    skipped_level = Some(level);
+                }
+                continue;
+            }
+            if skipped_level.is_some() {
+                continue;
+            }
+            for captures in get_task_reference_regex().captures_iter(line) {
+                if let Some(id) = captures
+                    .get(1)
+                    .or_else(|| captures.get(2))
+                    .and_then(|id| id.as_str().parse().ok())
+                {
+                    ids.insert(id);
+                }
+            }
+        }
+
+        Self { ids }
+    }
+
+    /// Whether the changelog lists work item `id`.
+    pub fn contains(&self, id: i32) -> bool {
+        self.ids.contains(&id)
+    }
+
+    /// Number of work items the changelog lists.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Whether the changelog lists no work items.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Removes or marks the entries the changelog already lists, returning
+    /// how many there were.
+    pub fn apply(&self, entries: &mut Vec<ReleaseNoteEntry>, mode: PreviouslyReleased) -> usize {
+        let before = entries.len();
+        match mode {
+            PreviouslyReleased::Exclude => {
+                entries.retain(|entry| !self.contains(entry.task_id));
+                before - entries.len()
+            }
+            PreviouslyReleased::Mark => {
+                let mut marked = 0;
+                for entry in entries.iter_mut().filter(|e| self.contains(e.task_id)) {
+                    entry.previously_released = true;
+                    marked += 1;
+                }
+                marked
+            }
+        }
+    }
+}
+
+/// Level of a markdown heading line, e.g. 2 for `## Fixes`.
+fn heading_level(line: &str) -> Option<usize> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    let rest = &line[level..];
+    (level > 0 && (rest.is_empty() || rest.starts_with(' '))).then_some(level)
+}
+
+/// Whether a heading names `version`, ignoring a leading `v` on either side.
+fn names_version(heading: &str, version: &str) -> bool {
+    let version = version.trim_start_matches(['v', 'V']);
+    !version.is_empty()
+        && heading
+            .split(|c: char| !(c.is_alphanumeric() || matches!(c, '.' | '-' | '_' | '+')))
+            .any(|token| token.trim_start_matches(['v', 'V']) == version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(task_id: i32) -> ReleaseNoteEntry {
+        ReleaseNoteEntry {
+            task_id,
+            title: format!("Task {}", task_id),
+            url: format!("https://example.com/{}", task_id),
+            group: Default::default(),
+            pr_id: None,
+            pr_url: None,
+            prs: Vec::new(),
+            previously_released: false,
+        }
+    }
+
+    /// # Published Tasks From A Changelog
+    ///
+    /// Tests collecting the work items an existing changelog lists.
+    ///
+    /// ## Test Scenario
+    /// - A changelog has a document for the version being generated, with
+    ///   a nested section, followed by older versions in markdown table,
+    ///   plain and hand-written formats
+    ///
+    /// ## Expected Outcome
+    /// - Work items of older versions are collected from links and `#` references
+    /// - Work items under the generated version's heading are not collected
+    /// - Version headings match with and without a `v` prefix
+    #[test]
+    fn test_parse_published_tasks() {
+        let changelog = "\
+# Release Notes - v1.2.1
+
+## Fixes
+
+- [101](https://example.com/101) Fix login
+
+# Release Notes - 1.2.0
+
+| Task ID | Title |
+|---------|-------|
+| [90](https://example.com/90) | Export |
+
+## [1.1.0] - 2024-01-01
+#80: Import
+- Faster search (#81, AB#82)
+";
+
+        let published = PublishedTasks::parse(changelog, "1.2.1");
+        for id in [90, 80, 81, 82] {
+            assert!(published.contains(id), "missing {}", id);
+        }
+        assert!(!published.contains(101));
+        assert_eq!(published.len(), 4);
+
+        let regenerated = PublishedTasks::parse(changelog, "v1.1.0");
+        assert!(regenerated.contains(101));
+        assert!(!regenerated.contains(80));
+        assert!(!regenerated.contains(81));
+    }
+
+    /// # Previously Released Entries
+    ///
+    /// Tests excluding and marking entries a changelog already lists.
+    ///
+    /// ## Test Scenario
+    /// - Entries 1 to 3 where the changelog lists 2
+    ///
+    /// ## Expected Outcome
+    /// - Exclude removes entry 2, mark flags it; both report one entry
+    #[test]
+    fn test_apply_previously_released() {
+        let published = PublishedTasks::parse("- Shipped (#2)", "v2.0.0");
+
+        let mut excluded = vec![entry(1), entry(2), entry(3)];
+        assert_eq!(
+            published.apply(&mut excluded, PreviouslyReleased::Exclude),
+            1
+        );
+        assert_eq!(
+            excluded.iter().map(|e| e.task_id).collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+
+        let mut marked = vec![entry(1), entry(2), entry(3)];
+        assert_eq!(published.apply(&mut marked, PreviouslyReleased::Mark), 1);
+        assert_eq!(
+            marked
+                .iter()
+                .map(|e| e.previously_released)
+                .collect::<Vec<_>>(),
+            vec![false, true, false]
+        );
+    }
+}
@@ -0,0 +1,490 @@
+//! Language of release notes headings and dates.
+//!
+//! Release notes are written in English with ISO dates by default. A locale
+//! switches the section headings, the document text and the month and
+//! weekday names of dates to another language; `en`, `de` and `fr` are
+//! built in. Named profiles in the config file pick a locale and override
+//! its date format and individual headings, so customer-facing notes can
+//! use their own wording without post-editing.
+//!
+//! Heading keys are `features`, `fixes`, `refactors`, `other`,
+//! `unreferenced`, `title`, `release_date`, `summary`, `no_changes`,
+//! `previously_released` and `package`. `summary` is written after the work item list,
+//! with `{count}` replaced by the number of work items.
+//!
+//! # Config Format
+//!
+//! ```toml
+//! release_notes_locale = "customer"
+//!
+//! [release_notes_locales.customer]
+//! locale = "de"
+//! date_format = "%-d. %B %Y"
+//!
+//! [release_notes_locales.customer.headings]
+//! features = "Neuerungen"
+//! ```
+//!
+//! # Example
+//!
+//! ```rust
+//! use chrono::NaiveDate;
+//! use mergers::models::TaskGroup;
+//! use mergers::release_notes::ReleaseNotesLocale;
+//!
+//! let locale = ReleaseNotesLocale::builtin("de").unwrap();
+//! assert_eq!(locale.heading(TaskGroup::Fix), "Fehlerbehebungen");
+//! let date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+//! assert_eq!(locale.format_date(date), "05.03.2024");
+//! ```
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::models::TaskGroup;
+
+/// Heading keys accepted in a profile's `headings` table.
+const HEADING_KEYS: &[&str] = &[
+    "features",
+    "fixes",
+    "refactors",
+    "other",
+    "unreferenced",
+    "title",
+    "release_date",
+    "summary",
+    "no_changes",
+    "previously_released",
+    "package",
+];
+
+/// A release notes locale profile as written in the config file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseNotesLocaleConfig {
+    /// Built-in locale the profile starts from: en, de or fr. Defaults to en.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// strftime format of the release date. Defaults to the locale's format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_format: Option<String>,
+    /// Headings overriding the locale's, by heading key.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headings: HashMap<String, String>,
+}
+
+/// Built-in texts of a locale.
+struct Builtin {
+    name: &'static str,
+    date_format: &'static str,
+    /// Headings in the order of [`HEADING_KEYS`].
+    headings: [&'static str; 11],
+    months: [&'static str; 12],
+    short_months: [&'static str; 12],
+    /// Weekdays starting on Monday.
+    weekdays: [&'static str; 7],
+    short_weekdays: [&'static str; 7],
+}
+
+const BUILTINS: &[Builtin] = &[
+    Builtin {
+        name: "en",
+        date_format: "%Y-%m-%d",
+        headings: [
+            "Features",
+            "Fixes",
+            "Refactors",
+            "Other",
+            "Unreferenced changes",
+            "Release Notes",
+            "Release Date",
+            "{count} work item(s) included in this release.",
+            "No changes included in this release.",
+            "previously released",
+            "Package",
+        ],
+        months: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        short_months: [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+        weekdays: [
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+            "Sunday",
+        ],
+        short_weekdays: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    },
+    Builtin {
+        name: "de",
+        date_format: "%d.%m.%Y",
+        headings: [
+            "Neue Funktionen",
+            "Fehlerbehebungen",
+            "Überarbeitungen",
+            "Sonstiges",
+            "Änderungen ohne Arbeitselement",
+            "Versionshinweise",
+            "Veröffentlichungsdatum",
+            "{count} Arbeitselement(e) in dieser Version enthalten.",
+            "Diese Version enthält keine Änderungen.",
+            "bereits veröffentlicht",
+            "Paket",
+        ],
+        months: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        short_months: [
+            "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+        ],
+        weekdays: [
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+            "Sonntag",
+        ],
+        short_weekdays: ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+    },
+    Builtin {
+        name: "fr",
+        date_format: "%d/%m/%Y",
+        headings: [
+            "Nouvelles fonctionnalités",
+            "Corrections",
+            "Refactorisations",
+            "Autres",
+            "Modifications sans élément de travail",
+            "Notes de version",
+            "Date de publication",
+            "{count} élément(s) de travail inclus dans cette version.",
+            "Cette version ne contient aucune modification.",
+            "déjà publié",
+            "Paquet",
+        ],
+        months: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        short_months: [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        weekdays: [
+            "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+        ],
+        short_weekdays: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+    },
+];
+
+/// Headings and date format of generated release notes.
+#[derive(Debug, Clone)]
+pub struct ReleaseNotesLocale {
+    builtin: &'static Builtin,
+    date_format: String,
+    headings: HashMap<&'static str, String>,
+}
+
+impl std::fmt::Debug for Builtin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl Default for ReleaseNotesLocale {
+    /// English headings with ISO dates.
+    fn default() -> Self {
+        Self::from_builtin(&BUILTINS[0])
+    }
+}
+
+impl ReleaseNotesLocale {
+    /// Names of the built-in locales.
+    pub fn builtin_names() -> impl Iterator<Item = &'static str> {
+        BUILTINS.iter().map(|builtin| builtin.name)
+    }
+
+    /// The built-in locale `name`, e.g. `de`.
+    ///
+    /// Region suffixes are ignored, so `de-AT` and `de_CH` select `de`.
+    pub fn builtin(name: &str) -> Result<Self> {
+        let language = name
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        let builtin = BUILTINS
+            .iter()
+            .find(|builtin| builtin.name == language)
+            .with_context(|| {
+                format!(
+                    "Unknown release notes locale '{}' (expected {})",
+                    name,
+                    Self::builtin_names().collect::<Vec<_>>().join(", ")
+                )
+            })?;
+        Ok(Self::from_builtin(builtin))
+    }
+
+    /// Builds the locale of a config file profile.
+    ///
+    /// Returns an error if the profile names an unknown locale or heading
+    /// key, or has an invalid date format.
+    pub fn from_config(name: &str, config: &ReleaseNotesLocaleConfig) -> Result<Self> {
+        let mut locale = match config.locale.as_deref() {
+            Some(locale) => Self::builtin(locale)
+                .with_context(|| format!("Invalid release notes locale profile '{}'", name))?,
+            None => Self::default(),
+        };
+
+        if let Some(date_format) = &config.date_format {
+            if StrftimeItems::new(date_format).any(|item| item == Item::Error) {
+                anyhow::bail!(
+                    "Invalid date format '{}' in release notes locale profile '{}'",
+                    date_format,
+                    name
+                );
+            }
+            locale.date_format = date_format.clone();
+        }
+
+        for (key, heading) in &config.headings {
+            let key = HEADING_KEYS
+                .iter()
+                .find(|known| known.eq_ignore_ascii_case(key))
+                .with_context(|| {
+                    format!(
+                        "Unknown heading '{}' in release notes locale profile '{}' (expected {})",
+                        key,
+                        name,
+                        HEADING_KEYS.join(", ")
+                    )
+                })?;
+            locale.headings.insert(key, heading.clone());
+        }
+
+        Ok(locale)
+    }
+
+    fn from_builtin(builtin: &'static Builtin) -> Self {
+        Self {
+            builtin,
+            date_format: builtin.date_format.to_string(),
+            headings: HEADING_KEYS
+                .iter()
+                .copied()
+                .zip(builtin.headings.iter().map(|heading| heading.to_string()))
+                .collect(),
+        }
+    }
+
+    fn text(&self, key: &str) -> &str {
+        self.headings
+            .get(key)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+
+    /// Heading of a task group section.
+    pub fn heading(&self, group: TaskGroup) -> &str {
+        self.text(match group {
+            TaskGroup::Feature => "features",
+            TaskGroup::Fix => "fixes",
+            TaskGroup::Refactor => "refactors",
+            TaskGroup::Other => "other",
+        })
+    }
+
+    /// Heading of the section listing PRs without work items.
+    pub fn unreferenced_heading(&self) -> &str {
+        self.text("unreferenced")
+    }
+
+    /// Title of a release notes document, before the version.
+    pub fn title(&self) -> &str {
+        self.text("title")
+    }
+
+    /// Label of the release date in a release notes document.
+    pub fn release_date_label(&self) -> &str {
+        self.text("release_date")
+    }
+
+    /// Line ending a release notes document with `count` work items.
+    pub fn summary(&self, count: usize) -> String {
+        self.text("summary").replace("{count}", &count.to_string())
+    }
+
+    /// Text of a release notes document without changes.
+    pub fn no_changes(&self) -> &str {
+        self.text("no_changes")
+    }
+
+    /// Mark after the title of a work item an existing changelog lists.
+    pub fn previously_released(&self) -> &str {
+        self.text("previously_released")
+    }
+
+    /// Label of the package version built for a release.
+    pub fn package_label(&self) -> &str {
+        self.text("package")
+    }
+
+    /// Formats `date` with the date format, using the locale's month and
+    /// weekday names for `%B`, `%b`, `%h`, `%A` and `%a`.
+    pub fn format_date(&self, date: NaiveDate) -> String {
+        let month = date.month0() as usize;
+        let weekday = date.weekday().num_days_from_monday() as usize;
+
+        let mut format = String::with_capacity(self.date_format.len());
+        let mut chars = self.date_format.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                format.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('B') => format.push_str(self.builtin.months[month]),
+                Some('b' | 'h') => format.push_str(self.builtin.short_months[month]),
+                Some('A') => format.push_str(self.builtin.weekdays[weekday]),
+                Some('a') => format.push_str(self.builtin.short_weekdays[weekday]),
+                Some(other) => {
+                    format.push('%');
+                    format.push(other);
+                }
+                None => format.push('%'),
+            }
+        }
+
+        date.format(&format).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// # Release Notes Locale
+    ///
+    /// Tests building localized headings and dates from a profile.
+    ///
+    /// ## Test Scenario
+    /// - A profile starts from `de`, sets a long date format and overrides
+    ///   the features heading
+    /// - The built-in `fr` locale is selected with a region suffix
+    ///
+    /// ## Expected Outcome
+    /// - Overridden headings replace the locale's, others keep it
+    /// - Month and weekday names are localized, `%%` stays a literal percent
+    /// - The default locale writes English headings and ISO dates
+    #[test]
+    fn test_release_notes_locale() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        let config = ReleaseNotesLocaleConfig {
+            locale: Some("de".to_string()),
+            date_format: Some("%A, %-d. %B %Y (%a %b) 100%%".to_string()),
+            headings: HashMap::from([("Features".to_string(), "Neuerungen".to_string())]),
+        };
+
+        let locale = ReleaseNotesLocale::from_config("customer", &config).unwrap();
+        assert_eq!(locale.heading(TaskGroup::Feature), "Neuerungen");
+        assert_eq!(locale.heading(TaskGroup::Fix), "Fehlerbehebungen");
+        assert_eq!(locale.title(), "Versionshinweise");
+        assert_eq!(
+            locale.summary(3),
+            "3 Arbeitselement(e) in dieser Version enthalten."
+        );
+        assert_eq!(
+            locale.format_date(date),
+            "Dienstag, 5. März 2024 (Di Mär) 100%"
+        );
+
+        let french = ReleaseNotesLocale::builtin("fr_CA").unwrap();
+        assert_eq!(french.heading(TaskGroup::Other), "Autres");
+        assert_eq!(french.format_date(date), "05/03/2024");
+
+        let english = ReleaseNotesLocale::default();
+        assert_eq!(english.heading(TaskGroup::Refactor), "Refactors");
+        assert_eq!(english.unreferenced_heading(), "Unreferenced changes");
+        assert_eq!(english.format_date(date), "2024-03-05");
+    }
+
+    /// # Invalid Release Notes Locale
+    ///
+    /// Tests rejecting profiles that cannot be applied.
+    ///
+    /// ## Test Scenario
+    /// - Profiles with an unknown locale, an unknown heading key and an
+    ///   invalid date format
+    ///
+    /// ## Expected Outcome
+    /// - Each profile is rejected with an error naming the profile
+    #[test]
+    fn test_invalid_release_notes_locale() {
+        let profiles = [
+            ReleaseNotesLocaleConfig {
+                locale: Some("xx".to_string()),
+                ..Default::default()
+            },
+            ReleaseNotesLocaleConfig {
+                headings: HashMap::from([("chores".to_string(), "Wartung".to_string())]),
+                ..Default::default()
+            },
+            ReleaseNotesLocaleConfig {
+                date_format: Some("%Y-%Q".to_string()),
+                ..Default::default()
+            },
+        ];
+
+        for profile in &profiles {
+            let error = ReleaseNotesLocale::from_config("customer", profile).unwrap_err();
+            assert!(
+                format!("{:#}", error).contains("'customer'"),
+                "unexpected error: {:#}",
+                error
+            );
+        }
+    }
+}
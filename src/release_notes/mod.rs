@@ -8,15 +8,31 @@
 //!
 //! - PR label/tag-based version tracking
 //! - Group tasks by type (feat, fix, refactor)
-//! - Multiple output formats (markdown, json, plain)
+//! - Multiple output formats (markdown, json, plain, html)
 //! - Work item caching
+//! - Work item references in PR text via configurable patterns
+//! - Localized headings and dates via locale profiles
+//! - Work items already listed in an existing changelog left out or marked
+//! - Regeneration from the state file of a finished merge, without the API
 
-pub mod cache;
+mod changelog;
+mod locale;
+mod task_refs;
 
+pub use changelog::PublishedTasks;
+pub use locale::{ReleaseNotesLocale, ReleaseNotesLocaleConfig};
+pub use task_refs::{TaskPatternConfig, TaskPatterns, TaskReference};
+
+use crate::api::{PackageVersion, UrlBuilder};
+use crate::cache::WorkItemCache;
+use crate::core::state::{MergeStateFile, StateItemStatus};
 use crate::models::{
-    CherryPickItem, CherryPickStatus, PullRequestWithWorkItems, ReleaseNotesOutputFormat, TaskGroup,
+    CherryPickItem, CherryPickStatus, PullRequestWithWorkItems, ReleaseNotesOutputFormat,
+    TaskGroup, WorkItem,
 };
+use crate::profiling;
 use anyhow::{Context, Result};
+use chrono::NaiveDate;
 use std::collections::{HashMap, HashSet};
 
 /// Represents a release note entry with task ID, title, and optional PR info.
@@ -26,8 +42,40 @@ pub struct ReleaseNoteEntry {
     pub title: String,
     pub url: String,
     pub group: TaskGroup,
+    /// First PR that referenced the task.
     pub pr_id: Option<i32>,
     pub pr_url: Option<String>,
+    /// Every PR that referenced the task, in the order they were seen.
+    pub prs: Vec<ReleaseNotePr>,
+    /// Whether an existing changelog already lists the task.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub previously_released: bool,
+}
+
+/// A pull request linked to a release note entry.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ReleaseNotePr {
+    pub id: i32,
+    pub url: String,
+    /// Commit the PR was cherry-picked as on the patch branch, when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_id: Option<String>,
+}
+
+/// A pull request in the release that references no work item.
+///
+/// Such PRs produce no task entry, so they are listed separately when
+/// requested to keep every change in the release visible.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct UnreferencedChange {
+    pub pr_id: i32,
+    pub pr_url: String,
+    /// Subject of the merge commit, i.e. the PR title.
+    pub subject: String,
+    pub author: String,
+    /// Merge commit of the PR, when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_id: Option<String>,
 }
 
 /// Determine task group based on commit message prefix.
@@ -52,25 +100,18 @@ pub fn determine_task_group(commit_message: &str) -> TaskGroup {
     }
 }
 
-/// Build Azure DevOps base URL with properly encoded org/project names.
-///
-/// Uses `url::Url` path segment encoding to handle spaces, parentheses, brackets,
-/// and any other special characters that could break markdown link syntax or URL validity.
-fn build_base_url(organization: &str, project: &str) -> String {
-    let mut url = url::Url::parse("https://dev.azure.com").expect("valid base URL");
-    url.path_segments_mut()
-        .expect("valid base URL")
-        .push(organization)
-        .push(project);
-    // Remove trailing slash added by push
-    let result = url.to_string();
-    result.trim_end_matches('/').to_string()
-}
-
 /// Format entries as a markdown table.
-pub fn format_markdown(entries: &[ReleaseNoteEntry], grouped: bool) -> String {
+///
+/// With `include_prs`, a column links the PRs that referenced each task.
+/// Group headings are taken from `locale`.
+pub fn format_markdown(
+    entries: &[ReleaseNoteEntry],
+    grouped: bool,
+    include_prs: bool,
+    locale: &ReleaseNotesLocale,
+) -> String {
     if !grouped {
-        return format_markdown_flat(entries);
+        return format_markdown_table(entries.iter(), include_prs, locale);
     }
 
     let mut output = String::new();
@@ -90,33 +131,55 @@ pub fn format_markdown(entries: &[ReleaseNoteEntry], grouped: bool) -> String {
         if let Some(group_entries) = groups.get(&group)
             && !group_entries.is_empty()
         {
-            output.push_str(&format!("\n## {}\n\n", group));
-            output.push_str("| Task ID | Title |\n");
-            output.push_str("|---------|-------|\n");
-
-            for entry in group_entries {
-                output.push_str(&format!(
-                    "| [{}]({}) | {} |\n",
-                    entry.task_id, entry.url, entry.title
-                ));
-            }
+            output.push_str(&format!("\n## {}\n\n", locale.heading(group)));
+            output.push_str(&format_markdown_table(
+                group_entries.iter().copied(),
+                include_prs,
+                locale,
+            ));
         }
     }
 
     output
 }
 
-/// Format entries as a flat markdown table (no grouping).
-fn format_markdown_flat(entries: &[ReleaseNoteEntry]) -> String {
+/// Format entries as a single markdown table.
+fn format_markdown_table<'a>(
+    entries: impl Iterator<Item = &'a ReleaseNoteEntry>,
+    include_prs: bool,
+    locale: &ReleaseNotesLocale,
+) -> String {
     let mut output = String::new();
-    output.push_str("| Task ID | Title |\n");
-    output.push_str("|---------|-------|\n");
+    if include_prs {
+        output.push_str("| Task ID | Title | PRs |\n");
+        output.push_str("|---------|-------|-----|\n");
+    } else {
+        output.push_str("| Task ID | Title |\n");
+        output.push_str("|---------|-------|\n");
+    }
 
     for entry in entries {
-        output.push_str(&format!(
-            "| [{}]({}) | {} |\n",
-            entry.task_id, entry.url, entry.title
-        ));
+        if include_prs {
+            let prs: Vec<String> = entry
+                .prs
+                .iter()
+                .map(|pr| format!("[!{}]({})", pr.id, pr.url))
+                .collect();
+            output.push_str(&format!(
+                "| [{}]({}) | {} | {} |\n",
+                entry.task_id,
+                entry.url,
+                entry_title(entry, locale),
+                prs.join(", ")
+            ));
+        } else {
+            output.push_str(&format!(
+                "| [{}]({}) | {} |\n",
+                entry.task_id,
+                entry.url,
+                entry_title(entry, locale)
+            ));
+        }
     }
 
     output
@@ -149,11 +212,19 @@ pub fn format_json(entries: &[ReleaseNoteEntry], grouped: bool) -> Result<String
 }
 
 /// Format entries as plain text.
-pub fn format_plain(entries: &[ReleaseNoteEntry], grouped: bool) -> String {
+///
+/// With `include_prs`, each line ends with the referencing PRs, e.g. `(!42, !57)`.
+/// Group headings are taken from `locale`.
+pub fn format_plain(
+    entries: &[ReleaseNoteEntry],
+    grouped: bool,
+    include_prs: bool,
+    locale: &ReleaseNotesLocale,
+) -> String {
     if !grouped {
         return entries
             .iter()
-            .map(|e| format!("#{}: {}", e.task_id, e.title))
+            .map(|e| format_plain_line(e, include_prs, locale))
             .collect::<Vec<_>>()
             .join("\n");
     }
@@ -174,9 +245,10 @@ pub fn format_plain(entries: &[ReleaseNoteEntry], grouped: bool) -> String {
         if let Some(group_entries) = groups.get(&group)
             && !group_entries.is_empty()
         {
-            output.push_str(&format!("\n# {}\n", group));
+            output.push_str(&format!("\n# {}\n", locale.heading(group)));
             for entry in group_entries {
-                output.push_str(&format!("#{}: {}\n", entry.task_id, entry.title));
+                output.push_str(&format_plain_line(entry, include_prs, locale));
+                output.push('\n');
             }
         }
     }
@@ -184,29 +256,292 @@ pub fn format_plain(entries: &[ReleaseNoteEntry], grouped: bool) -> String {
     output
 }
 
+/// Format entries as an HTML fragment.
+///
+/// Each group becomes an `<h2>` heading with a `<ul>` of tasks; ungrouped
+/// output is a single list. With `include_prs`, each item ends with links to
+/// the referencing PRs. Group headings are taken from `locale`.
+pub fn format_html(
+    entries: &[ReleaseNoteEntry],
+    grouped: bool,
+    include_prs: bool,
+    locale: &ReleaseNotesLocale,
+) -> String {
+    if !grouped {
+        return format_html_list(entries.iter(), include_prs, locale);
+    }
+
+    let mut output = String::new();
+    let mut groups: HashMap<TaskGroup, Vec<&ReleaseNoteEntry>> = HashMap::new();
+
+    for entry in entries {
+        groups.entry(entry.group).or_default().push(entry);
+    }
+
+    for group in [
+        TaskGroup::Feature,
+        TaskGroup::Fix,
+        TaskGroup::Refactor,
+        TaskGroup::Other,
+    ] {
+        if let Some(group_entries) = groups.get(&group)
+            && !group_entries.is_empty()
+        {
+            output.push_str(&format!(
+                "<h2>{}</h2>\n",
+                escape_html(locale.heading(group))
+            ));
+            output.push_str(&format_html_list(
+                group_entries.iter().copied(),
+                include_prs,
+                locale,
+            ));
+        }
+    }
+
+    output
+}
+
+/// Format entries as a single HTML list.
+fn format_html_list<'a>(
+    entries: impl Iterator<Item = &'a ReleaseNoteEntry>,
+    include_prs: bool,
+    locale: &ReleaseNotesLocale,
+) -> String {
+    let mut output = String::from("<ul>\n");
+    for entry in entries {
+        let mut item = format!(
+            "<a href=\"{}\">#{}</a> {}",
+            escape_html(&entry.url),
+            entry.task_id,
+            escape_html(&entry_title(entry, locale))
+        );
+        if include_prs && !entry.prs.is_empty() {
+            let prs: Vec<String> = entry
+                .prs
+                .iter()
+                .map(|pr| format!("<a href=\"{}\">!{}</a>", escape_html(&pr.url), pr.id))
+                .collect();
+            item.push_str(&format!(" ({})", prs.join(", ")));
+        }
+        output.push_str(&format!("  <li>{}</li>\n", item));
+    }
+    output.push_str("</ul>\n");
+    output
+}
+
+/// Escapes text for use in HTML content and attribute values.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Format one entry as a plain text line.
+fn format_plain_line(
+    entry: &ReleaseNoteEntry,
+    include_prs: bool,
+    locale: &ReleaseNotesLocale,
+) -> String {
+    let mut line = format!("#{}: {}", entry.task_id, entry_title(entry, locale));
+    if include_prs && !entry.prs.is_empty() {
+        let prs: Vec<String> = entry.prs.iter().map(|pr| format!("!{}", pr.id)).collect();
+        line.push_str(&format!(" ({})", prs.join(", ")));
+    }
+    line
+}
+
+/// Title of an entry, marked when an existing changelog already lists it.
+fn entry_title(entry: &ReleaseNoteEntry, locale: &ReleaseNotesLocale) -> String {
+    if entry.previously_released {
+        format!("{} ({})", entry.title, locale.previously_released())
+    } else {
+        entry.title.clone()
+    }
+}
+
 /// Format entries based on output format.
+///
+/// `include_prs` adds PR links to markdown, plain and HTML output; JSON
+/// always carries them. With `unreferenced`, markdown, plain and HTML output
+/// end with an "Unreferenced changes" section listing PRs without work
+/// items, and JSON becomes an object with the tasks and an `unreferenced`
+/// array.
+///
+/// Markdown, plain and HTML headings are taken from `locale`; JSON keys are
+/// not localized.
 pub fn format_output(
     entries: &[ReleaseNoteEntry],
+    unreferenced: Option<&[UnreferencedChange]>,
     format: ReleaseNotesOutputFormat,
     grouped: bool,
+    include_prs: bool,
+    locale: &ReleaseNotesLocale,
 ) -> Result<String> {
+    let mut output = match format {
+        ReleaseNotesOutputFormat::Markdown => {
+            format_markdown(entries, grouped, include_prs, locale)
+        }
+        ReleaseNotesOutputFormat::Json => format_json(entries, grouped)?,
+        ReleaseNotesOutputFormat::Plain => format_plain(entries, grouped, include_prs, locale),
+        ReleaseNotesOutputFormat::Html => format_html(entries, grouped, include_prs, locale),
+    };
+    let Some(unreferenced) = unreferenced else {
+        return Ok(output);
+    };
+
     match format {
-        ReleaseNotesOutputFormat::Markdown => Ok(format_markdown(entries, grouped)),
-        ReleaseNotesOutputFormat::Json => format_json(entries, grouped),
-        ReleaseNotesOutputFormat::Plain => Ok(format_plain(entries, grouped)),
+        ReleaseNotesOutputFormat::Json => {
+            let tasks: serde_json::Value =
+                serde_json::from_str(&output).context("Failed to serialize to JSON")?;
+            let mut object = match tasks {
+                serde_json::Value::Object(groups) => groups,
+                tasks => serde_json::Map::from_iter([("tasks".to_string(), tasks)]),
+            };
+            object.insert(
+                "unreferenced".to_string(),
+                serde_json::to_value(unreferenced).context("Failed to serialize to JSON")?,
+            );
+            serde_json::to_string_pretty(&object).context("Failed to serialize to JSON")
+        }
+        _ if unreferenced.is_empty() => Ok(output),
+        ReleaseNotesOutputFormat::Markdown => {
+            output.push_str(&format_unreferenced_markdown(unreferenced, locale));
+            Ok(output)
+        }
+        ReleaseNotesOutputFormat::Plain => {
+            if !output.is_empty() && !output.ends_with('\n') {
+                output.push('\n');
+            }
+            output.push_str(&format_unreferenced_plain(unreferenced, locale));
+            Ok(output)
+        }
+        ReleaseNotesOutputFormat::Html => {
+            output.push_str(&format_unreferenced_html(unreferenced, locale));
+            Ok(output)
+        }
     }
 }
 
-/// Copy text to system clipboard.
-pub fn copy_to_clipboard(text: &str) -> Result<()> {
-    use arboard::Clipboard;
+/// Adds the package version built for a release to formatted release notes.
+///
+/// Markdown, plain and HTML output start with a labeled line; JSON output
+/// gets a `package` object next to the tasks.
+pub fn with_package_version(
+    output: String,
+    package: &PackageVersion,
+    format: ReleaseNotesOutputFormat,
+    locale: &ReleaseNotesLocale,
+) -> Result<String> {
+    let label = locale.package_label();
+    match format {
+        ReleaseNotesOutputFormat::Markdown => {
+            Ok(format!("**{}:** {}\n\n{}", label, package, output))
+        }
+        ReleaseNotesOutputFormat::Plain => Ok(format!("{}: {}\n\n{}", label, package, output)),
+        ReleaseNotesOutputFormat::Html => Ok(format!(
+            "<p><strong>{}:</strong> {}</p>\n{}",
+            escape_html(label),
+            escape_html(&package.to_string()),
+            output
+        )),
+        ReleaseNotesOutputFormat::Json => {
+            let tasks: serde_json::Value =
+                serde_json::from_str(&output).context("Failed to serialize to JSON")?;
+            let mut object = match tasks {
+                serde_json::Value::Object(groups) => groups,
+                tasks => serde_json::Map::from_iter([("tasks".to_string(), tasks)]),
+            };
+            object.insert(
+                "package".to_string(),
+                serde_json::to_value(package).context("Failed to serialize to JSON")?,
+            );
+            serde_json::to_string_pretty(&object).context("Failed to serialize to JSON")
+        }
+    }
+}
 
-    let mut clipboard = Clipboard::new().context("Failed to access clipboard")?;
-    clipboard
-        .set_text(text)
-        .context("Failed to copy to clipboard")?;
+/// Format unreferenced changes as a markdown section.
+fn format_unreferenced_markdown(
+    changes: &[UnreferencedChange],
+    locale: &ReleaseNotesLocale,
+) -> String {
+    let mut output = format!("\n## {}\n\n", locale.unreferenced_heading());
+    output.push_str("| PR | Subject | Author | Commit |\n");
+    output.push_str("|----|---------|--------|--------|\n");
+    for change in changes {
+        let commit = change
+            .commit_id
+            .as_deref()
+            .map(|commit_id| format!("`{}`", short_hash(commit_id)))
+            .unwrap_or_default();
+        output.push_str(&format!(
+            "| [!{}]({}) | {} | {} | {} |\n",
+            change.pr_id, change.pr_url, change.subject, change.author, commit
+        ));
+    }
+    output
+}
 
-    Ok(())
+/// Format unreferenced changes as a plain text section.
+fn format_unreferenced_plain(
+    changes: &[UnreferencedChange],
+    locale: &ReleaseNotesLocale,
+) -> String {
+    let mut output = format!("\n# {}\n", locale.unreferenced_heading());
+    for change in changes {
+        let mut details = vec![change.author.as_str()];
+        if let Some(commit_id) = change.commit_id.as_deref() {
+            details.push(short_hash(commit_id));
+        }
+        output.push_str(&format!(
+            "!{}: {} ({})\n",
+            change.pr_id,
+            change.subject,
+            details.join(", ")
+        ));
+    }
+    output
+}
+
+/// Format unreferenced changes as an HTML section.
+fn format_unreferenced_html(changes: &[UnreferencedChange], locale: &ReleaseNotesLocale) -> String {
+    let mut output = format!(
+        "<h2>{}</h2>\n<ul>\n",
+        escape_html(locale.unreferenced_heading())
+    );
+    for change in changes {
+        let mut details = vec![escape_html(&change.author)];
+        if let Some(commit_id) = change.commit_id.as_deref() {
+            details.push(format!(
+                "<code>{}</code>",
+                escape_html(short_hash(commit_id))
+            ));
+        }
+        output.push_str(&format!(
+            "  <li><a href=\"{}\">!{}</a> {} ({})</li>\n",
+            escape_html(&change.pr_url),
+            change.pr_id,
+            escape_html(&change.subject),
+            details.join(", ")
+        ));
+    }
+    output.push_str("</ul>\n");
+    output
+}
+
+/// The first eight characters of a commit hash.
+fn short_hash(commit_id: &str) -> &str {
+    &commit_id[..commit_id.len().min(8)]
 }
 
 /// Generate release notes markdown from TUI merge data.
@@ -220,14 +555,21 @@ pub fn copy_to_clipboard(text: &str) -> Result<()> {
 /// * `version` - Version string (e.g., "v1.0.0")
 /// * `cherry_pick_items` - All cherry-pick items with their statuses
 /// * `pull_requests` - All PRs with associated work items
-/// * `organization` - Azure DevOps organization name
-/// * `project` - Azure DevOps project name
+/// * `urls` - URL builder for the work item and PR links
+/// * `picked_commits` - Commit each PR was cherry-picked as, by PR id
+/// * `package` - Package version found in the artifacts feed, if any
+/// * `release_date` - Date shown in the document header
+/// * `locale` - Headings and date format of the document
+#[allow(clippy::too_many_arguments)]
 pub fn generate_from_merge_data(
     version: &str,
     cherry_pick_items: &[CherryPickItem],
     pull_requests: &[PullRequestWithWorkItems],
-    organization: &str,
-    project: &str,
+    urls: &UrlBuilder,
+    picked_commits: &HashMap<i32, String>,
+    package: Option<&PackageVersion>,
+    release_date: NaiveDate,
+    locale: &ReleaseNotesLocale,
 ) -> String {
     let successful_pr_ids: HashSet<i32> = cherry_pick_items
         .iter()
@@ -241,25 +583,38 @@ pub fn generate_from_merge_data(
         .cloned()
         .collect();
 
-    let entries = build_entries_from_prs(&filtered, organization, project);
-    format_release_notes_document(version, &entries)
+    let mut entries = build_entries_from_prs(&filtered, urls);
+    for pr in entries.iter_mut().flat_map(|entry| entry.prs.iter_mut()) {
+        pr.commit_id = picked_commits.get(&pr.id).cloned();
+    }
+    format_release_notes_document(version, &entries, package, release_date, locale)
 }
 
 /// Build release note entries from PR + work item data.
+///
+/// Each work item becomes one entry, linked to every PR that referenced it.
 pub fn build_entries_from_prs(
     prs: &[PullRequestWithWorkItems],
-    organization: &str,
-    project: &str,
+    urls: &UrlBuilder,
 ) -> Vec<ReleaseNoteEntry> {
-    let base_url = build_base_url(organization, project);
+    let _phase = profiling::phase("build release notes");
     let mut entries: Vec<ReleaseNoteEntry> = Vec::new();
-    let mut seen_task_ids = HashSet::new();
+    let mut entry_index: HashMap<i32, usize> = HashMap::new();
 
     for pr_with_wi in prs {
         let group = determine_task_group(&pr_with_wi.pr.title);
+        let pr = ReleaseNotePr {
+            id: pr_with_wi.pr.id,
+            url: urls.pull_request_url(pr_with_wi.pr.id),
+            commit_id: None,
+        };
 
         for wi in &pr_with_wi.work_items {
-            if !seen_task_ids.insert(wi.id) {
+            if let Some(&index) = entry_index.get(&wi.id) {
+                let entry = &mut entries[index];
+                if !entry.prs.contains(&pr) {
+                    entry.prs.push(pr.clone());
+                }
                 continue;
             }
 
@@ -268,18 +623,73 @@ pub fn build_entries_from_prs(
                 .title
                 .clone()
                 .unwrap_or_else(|| "(Title not found)".to_string());
-            let url = format!("{}/_workitems/edit/{}", base_url, wi.id);
-
+            entry_index.insert(wi.id, entries.len());
             entries.push(ReleaseNoteEntry {
                 task_id: wi.id,
                 title,
-                url,
+                url: urls.work_item_url(wi.id),
+                group,
+                pr_id: Some(pr.id),
+                pr_url: Some(pr.url.clone()),
+                prs: vec![pr.clone()],
+                previously_released: false,
+            });
+        }
+    }
+
+    entries
+}
+
+/// Build release note entries from the cherry-picked items of a state file.
+///
+/// Only successfully cherry-picked PRs are included, each linked to the
+/// commit it was picked as. Work item titles come from the state file,
+/// falling back to `cache` for state files written before titles were
+/// recorded.
+pub fn build_entries_from_state(
+    state: &MergeStateFile,
+    urls: &UrlBuilder,
+    cache: &WorkItemCache,
+) -> Vec<ReleaseNoteEntry> {
+    let mut entries: Vec<ReleaseNoteEntry> = Vec::new();
+    let mut entry_index: HashMap<i32, usize> = HashMap::new();
+
+    for item in &state.cherry_pick_items {
+        if item.status != StateItemStatus::Success {
+            continue;
+        }
+        let group = determine_task_group(&item.pr_title);
+        let pr = ReleaseNotePr {
+            id: item.pr_id,
+            url: urls.pull_request_url(item.pr_id),
+            commit_id: item.picked_commit_id.clone(),
+        };
+
+        for &id in &item.work_item_ids {
+            if let Some(&index) = entry_index.get(&id) {
+                let entry = &mut entries[index];
+                if !entry.prs.contains(&pr) {
+                    entry.prs.push(pr.clone());
+                }
+                continue;
+            }
+
+            let title = item
+                .work_item_titles
+                .get(&id)
+                .cloned()
+                .or_else(|| cache.get(id).map(|cached| cached.title.clone()))
+                .unwrap_or_else(|| "(Title not found)".to_string());
+            entry_index.insert(id, entries.len());
+            entries.push(ReleaseNoteEntry {
+                task_id: id,
+                title,
+                url: urls.work_item_url(id),
                 group,
-                pr_id: Some(pr_with_wi.pr.id),
-                pr_url: Some(format!(
-                    "{}/_git/pullrequest/{}",
-                    base_url, pr_with_wi.pr.id
-                )),
+                pr_id: Some(pr.id),
+                pr_url: Some(pr.url.clone()),
+                prs: vec![pr.clone()],
+                previously_released: false,
             });
         }
     }
@@ -287,24 +697,150 @@ pub fn build_entries_from_prs(
     entries
 }
 
+/// Generate the release notes markdown document of a state file's merge.
+///
+/// The state file counterpart of [`generate_from_merge_data`], used as the
+/// message of the release tag when a merge is completed without the TUI.
+pub fn generate_from_state(
+    state: &MergeStateFile,
+    urls: &UrlBuilder,
+    cache: &WorkItemCache,
+    release_date: NaiveDate,
+    locale: &ReleaseNotesLocale,
+) -> String {
+    let entries = build_entries_from_state(state, urls, cache);
+    format_release_notes_document(
+        &state.merge_version,
+        &entries,
+        state.package_version.as_ref(),
+        release_date,
+        locale,
+    )
+}
+
+/// IDs of work items referenced in PR text that no PR links, sorted.
+///
+/// These have to be fetched before [`link_task_references`].
+pub fn unlinked_task_references(
+    prs: &[PullRequestWithWorkItems],
+    patterns: &TaskPatterns,
+) -> Vec<i32> {
+    let linked: HashSet<i32> = prs
+        .iter()
+        .flat_map(|pr| pr.work_items.iter().map(|wi| wi.id))
+        .collect();
+    let mut ids: Vec<i32> = prs
+        .iter()
+        .flat_map(|pr| patterns.extract_from_pr(&pr.pr))
+        .map(|reference| reference.id)
+        .filter(|id| !linked.contains(id))
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
+
+/// Links the work items referenced in each PR's text to the PR.
+///
+/// `fetched` holds the work items of [`unlinked_task_references`];
+/// references to work items that could not be fetched are dropped. Returns
+/// the section each routed work item goes to, for [`route_to_sections`].
+pub fn link_task_references(
+    prs: &mut [PullRequestWithWorkItems],
+    patterns: &TaskPatterns,
+    fetched: &[WorkItem],
+) -> HashMap<i32, TaskGroup> {
+    let mut known: HashMap<i32, WorkItem> = fetched.iter().map(|wi| (wi.id, wi.clone())).collect();
+    for wi in prs.iter().flat_map(|pr| pr.work_items.iter()) {
+        known.entry(wi.id).or_insert_with(|| wi.clone());
+    }
+
+    let mut sections = HashMap::new();
+    for pr in prs.iter_mut() {
+        for reference in patterns.extract_from_pr(&pr.pr) {
+            let Some(work_item) = known.get(&reference.id) else {
+                tracing::warn!(
+                    "Work item {} referenced by PR {} ({}) was not found",
+                    reference.id,
+                    pr.pr.id,
+                    reference.pattern
+                );
+                continue;
+            };
+            if !pr.work_items.iter().any(|wi| wi.id == reference.id) {
+                pr.work_items.push(work_item.clone());
+            }
+            if let Some(section) = reference.section {
+                sections.entry(reference.id).or_insert(section);
+            }
+        }
+    }
+    sections
+}
+
+/// Moves entries to the sections their reference patterns route them to.
+pub fn route_to_sections(entries: &mut [ReleaseNoteEntry], sections: &HashMap<i32, TaskGroup>) {
+    for entry in entries {
+        if let Some(&section) = sections.get(&entry.task_id) {
+            entry.group = section;
+        }
+    }
+}
+
+/// Collect the PRs that reference no work item, in their original order.
+pub fn collect_unreferenced(
+    prs: &[PullRequestWithWorkItems],
+    urls: &UrlBuilder,
+) -> Vec<UnreferencedChange> {
+    prs.iter()
+        .filter(|pr_with_wi| pr_with_wi.work_items.is_empty())
+        .map(|pr_with_wi| UnreferencedChange {
+            pr_id: pr_with_wi.pr.id,
+            pr_url: urls.pull_request_url(pr_with_wi.pr.id),
+            subject: pr_with_wi.pr.title.clone(),
+            author: pr_with_wi.pr.created_by.display_name.clone(),
+            commit_id: pr_with_wi
+                .pr
+                .last_merge_commit
+                .as_ref()
+                .map(|commit| commit.commit_id.clone()),
+        })
+        .collect()
+}
+
 /// Generate full release notes markdown from PR + work item data.
 pub fn generate_from_prs(
     version: &str,
     prs: &[PullRequestWithWorkItems],
-    organization: &str,
-    project: &str,
+    urls: &UrlBuilder,
+    release_date: NaiveDate,
+    locale: &ReleaseNotesLocale,
 ) -> String {
-    let entries = build_entries_from_prs(prs, organization, project);
-    format_release_notes_document(version, &entries)
+    let entries = build_entries_from_prs(prs, urls);
+    format_release_notes_document(version, &entries, None, release_date, locale)
 }
 
 /// Format entries into a full release notes document with header, grouped sections, and summary.
-fn format_release_notes_document(version: &str, entries: &[ReleaseNoteEntry]) -> String {
-    let today = chrono::Local::now().format("%Y-%m-%d");
-    let mut output = format!("# Release Notes - {version}\n\n**Release Date:** {today}\n");
+fn format_release_notes_document(
+    version: &str,
+    entries: &[ReleaseNoteEntry],
+    package: Option<&PackageVersion>,
+    release_date: NaiveDate,
+    locale: &ReleaseNotesLocale,
+) -> String {
+    let mut output = format!(
+        "# {} - {}\n\n**{}:** {}\n",
+        locale.title(),
+        version,
+        locale.release_date_label(),
+        locale.format_date(release_date)
+    );
+    if let Some(package) = package {
+        output.push_str(&format!("**{}:** {}\n", locale.package_label(), package));
+    }
 
     if entries.is_empty() {
-        output.push_str("\nNo changes included in this release.\n");
+        output.push_str(&format!("\n{}\n", locale.no_changes()));
         return output;
     }
 
@@ -322,20 +858,31 @@ fn format_release_notes_document(version: &str, entries: &[ReleaseNoteEntry]) ->
         if let Some(group_entries) = groups.get(&group)
             && !group_entries.is_empty()
         {
-            output.push_str(&format!("\n## {}\n\n", group));
+            output.push_str(&format!("\n## {}\n\n", locale.heading(group)));
             for entry in group_entries {
+                let commits: Vec<String> = entry
+                    .prs
+                    .iter()
+                    .filter_map(|pr| pr.commit_id.as_deref())
+                    .map(|commit_id| format!("`{}`", short_hash(commit_id)))
+                    .collect();
+                let commits = if commits.is_empty() {
+                    String::new()
+                } else {
+                    format!("({})", commits.join(", "))
+                };
                 output.push_str(&format!(
-                    "- [{}]({}) {} \n",
-                    entry.task_id, entry.url, entry.title
+                    "- [{}]({}) {} {}\n",
+                    entry.task_id,
+                    entry.url,
+                    entry_title(entry, locale),
+                    commits
                 ));
             }
         }
     }
 
-    output.push_str(&format!(
-        "\n---\n\n*{} work item(s) included in this release.*\n",
-        entries.len()
-    ));
+    output.push_str(&format!("\n---\n\n*{}*\n", locale.summary(entries.len())));
 
     output
 }
@@ -344,6 +891,31 @@ fn format_release_notes_document(version: &str, entries: &[ReleaseNoteEntry]) ->
 mod tests {
     use super::*;
 
+    fn pr(id: i32, work_item_ids: &[i32]) -> PullRequestWithWorkItems {
+        PullRequestWithWorkItems {
+            pr: serde_json::from_value(serde_json::json!({
+                "pullRequestId": id,
+                "title": format!("fix: PR {}", id),
+                "closedDate": null,
+                "createdBy": { "displayName": "Dev" },
+                "lastMergeCommit": null,
+                "labels": null
+            }))
+            .unwrap(),
+            work_items: work_item_ids
+                .iter()
+                .map(|wi_id| {
+                    serde_json::from_value(serde_json::json!({
+                        "id": wi_id,
+                        "fields": { "System.Title": format!("Task {}", wi_id) }
+                    }))
+                    .unwrap()
+                })
+                .collect(),
+            selected: true,
+        }
+    }
+
     #[test]
     fn test_determine_task_group_feature() {
         assert_eq!(
@@ -395,9 +967,11 @@ mod tests {
             group: TaskGroup::Feature,
             pr_id: None,
             pr_url: None,
+            prs: Vec::new(),
+            previously_released: false,
         }];
 
-        let output = format_markdown(&entries, false);
+        let output = format_markdown(&entries, false, false, &ReleaseNotesLocale::default());
         assert!(output.contains("| Task ID | Title |"));
         assert!(output.contains("[123](https://example.com/123)"));
         assert!(output.contains("Test task"));
@@ -412,9 +986,536 @@ mod tests {
             group: TaskGroup::Fix,
             pr_id: None,
             pr_url: None,
+            prs: Vec::new(),
+            previously_released: false,
         }];
 
-        let output = format_plain(&entries, false);
+        let output = format_plain(&entries, false, false, &ReleaseNotesLocale::default());
         assert_eq!(output, "#456: Another task");
     }
+
+    #[test]
+    fn test_format_previously_released() {
+        let entries = vec![ReleaseNoteEntry {
+            task_id: 456,
+            title: "Another task".to_string(),
+            url: "https://example.com/456".to_string(),
+            group: TaskGroup::Fix,
+            pr_id: None,
+            pr_url: None,
+            prs: Vec::new(),
+            previously_released: true,
+        }];
+
+        let locale = ReleaseNotesLocale::default();
+        assert_eq!(
+            format_plain(&entries, false, false, &locale),
+            "#456: Another task (previously released)"
+        );
+        assert!(
+            format_markdown(&entries, false, false, &locale).contains(
+                "| [456](https://example.com/456) | Another task (previously released) |"
+            )
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&format_json(&entries, false).unwrap()).unwrap();
+        assert_eq!(json[0]["previously_released"], true);
+        assert_eq!(json[0]["title"], "Another task");
+    }
+
+    #[test]
+    fn test_build_entries_from_prs_urls() {
+        let prs = vec![PullRequestWithWorkItems {
+            pr: serde_json::from_value(serde_json::json!({
+                "pullRequestId": 42,
+                "title": "feat: Add export",
+                "closedDate": null,
+                "createdBy": { "displayName": "Dev" },
+                "lastMergeCommit": null,
+                "labels": null
+            }))
+            .unwrap(),
+            work_items: vec![
+                serde_json::from_value(serde_json::json!({
+                    "id": 7,
+                    "fields": { "System.Title": "Export data" }
+                }))
+                .unwrap(),
+            ],
+            selected: true,
+        }];
+
+        let urls = UrlBuilder::new("org", "My Project", "repo");
+        let entries = build_entries_from_prs(&prs, &urls);
+
+        assert_eq!(
+            entries[0].url,
+            "https://dev.azure.com/org/My%20Project/_workitems/edit/7"
+        );
+        assert_eq!(
+            entries[0].pr_url.as_deref(),
+            Some("https://dev.azure.com/org/My%20Project/_git/repo/pullrequest/42")
+        );
+    }
+
+    /// # PR Links For Shared Work Items
+    ///
+    /// Tests linking each task to every PR that referenced it.
+    ///
+    /// ## Test Scenario
+    /// - PRs 42 and 57 both reference work item 7; PR 57 also references 8
+    /// - Formats the entries as markdown, plain text and JSON with `include_prs`
+    ///
+    /// ## Expected Outcome
+    /// - Work item 7 lists both PRs; work item 8 lists PR 57
+    /// - Markdown and plain output show the PR links; JSON carries them
+    /// - Without `include_prs`, markdown keeps the two-column table
+    #[test]
+    fn test_entries_link_all_prs() {
+        let urls = UrlBuilder::new("org", "proj", "repo");
+        let entries = build_entries_from_prs(&[pr(42, &[7]), pr(57, &[7, 8])], &urls);
+
+        assert_eq!(entries.len(), 2);
+        let pr_ids: Vec<i32> = entries[0].prs.iter().map(|pr| pr.id).collect();
+        assert_eq!(pr_ids, vec![42, 57]);
+        assert_eq!(entries[0].pr_id, Some(42));
+        assert_eq!(entries[1].prs[0].id, 57);
+
+        let markdown = format_markdown(&entries, false, true, &ReleaseNotesLocale::default());
+        assert!(markdown.contains("| Task ID | Title | PRs |"));
+        assert!(markdown.contains(
+            "| [7](https://dev.azure.com/org/proj/_workitems/edit/7) | Task 7 | \
+             [!42](https://dev.azure.com/org/proj/_git/repo/pullrequest/42), \
+             [!57](https://dev.azure.com/org/proj/_git/repo/pullrequest/57) |"
+        ));
+        assert!(
+            !format_markdown(&entries, true, false, &ReleaseNotesLocale::default()).contains("PRs")
+        );
+
+        assert_eq!(
+            format_plain(&entries, false, true, &ReleaseNotesLocale::default()),
+            "#7: Task 7 (!42, !57)\n#8: Task 8 (!57)"
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&format_json(&entries, false).unwrap()).unwrap();
+        assert_eq!(json[0]["prs"][1]["id"], 57);
+        assert_eq!(
+            json[0]["prs"][1]["url"],
+            "https://dev.azure.com/org/proj/_git/repo/pullrequest/57"
+        );
+    }
+
+    /// # Picked Commits in Release Notes
+    ///
+    /// Tests that release notes from a merge session name the commits the
+    /// PRs were cherry-picked as.
+    ///
+    /// ## Test Scenario
+    /// - Generates notes for two picked PRs, one with a recorded commit
+    ///
+    /// ## Expected Outcome
+    /// - The work item of the recorded PR shows the short commit hash
+    /// - The other work item has no commit suffix
+    #[test]
+    fn test_generate_from_merge_data_picked_commits() {
+        let items: Vec<CherryPickItem> = [42, 57]
+            .into_iter()
+            .map(|pr_id| CherryPickItem {
+                commit_id: format!("source{}", pr_id),
+                pr_id,
+                pr_title: format!("fix: PR {}", pr_id),
+                status: CherryPickStatus::Success,
+            })
+            .collect();
+        let picked = HashMap::from([(42, "0123456789abcdef".to_string())]);
+
+        let notes = generate_from_merge_data(
+            "v1.0.0",
+            &items,
+            &[pr(42, &[7]), pr(57, &[8])],
+            &UrlBuilder::new("org", "proj", "repo"),
+            &picked,
+            None,
+            NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
+            &ReleaseNotesLocale::default(),
+        );
+
+        assert!(notes.contains(
+            "- [7](https://dev.azure.com/org/proj/_workitems/edit/7) Task 7 (`01234567`)\n"
+        ));
+        assert!(
+            notes.contains("- [8](https://dev.azure.com/org/proj/_workitems/edit/8) Task 8 \n")
+        );
+    }
+
+    /// # Unreferenced Changes
+    ///
+    /// Tests listing PRs that reference no work item.
+    ///
+    /// ## Test Scenario
+    /// - PR 42 references work item 7; PR 57 references none and has a merge commit
+    /// - Formats the notes as markdown, plain text and JSON with and without
+    ///   unreferenced changes
+    ///
+    /// ## Expected Outcome
+    /// - Only PR 57 is unreferenced, with its title, author and commit
+    /// - Markdown and plain output end with an "Unreferenced changes" section
+    /// - JSON wraps the tasks in an object with an `unreferenced` array
+    /// - Without the option, output is unchanged
+    #[test]
+    fn test_unreferenced_changes() {
+        let urls = UrlBuilder::new("org", "proj", "repo");
+        let mut unreferenced_pr = pr(57, &[]);
+        unreferenced_pr.pr.last_merge_commit = Some(crate::models::MergeCommit {
+            commit_id: "0123456789abcdef".to_string(),
+        });
+        let prs = [pr(42, &[7]), unreferenced_pr];
+        let entries = build_entries_from_prs(&prs, &urls);
+
+        let unreferenced = collect_unreferenced(&prs, &urls);
+        assert_eq!(
+            unreferenced,
+            vec![UnreferencedChange {
+                pr_id: 57,
+                pr_url: "https://dev.azure.com/org/proj/_git/repo/pullrequest/57".to_string(),
+                subject: "fix: PR 57".to_string(),
+                author: "Dev".to_string(),
+                commit_id: Some("0123456789abcdef".to_string()),
+            }]
+        );
+
+        let markdown = format_output(
+            &entries,
+            Some(&unreferenced),
+            ReleaseNotesOutputFormat::Markdown,
+            false,
+            false,
+            &ReleaseNotesLocale::default(),
+        )
+        .unwrap();
+        assert!(markdown.ends_with(
+            "\n## Unreferenced changes\n\n\
+             | PR | Subject | Author | Commit |\n\
+             |----|---------|--------|--------|\n\
+             | [!57](https://dev.azure.com/org/proj/_git/repo/pullrequest/57) | fix: PR 57 | Dev | `01234567` |\n"
+        ));
+
+        let plain = format_output(
+            &entries,
+            Some(&unreferenced),
+            ReleaseNotesOutputFormat::Plain,
+            false,
+            false,
+            &ReleaseNotesLocale::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            plain,
+            "#7: Task 7\n\n# Unreferenced changes\n!57: fix: PR 57 (Dev, 01234567)\n"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(
+            &format_output(
+                &entries,
+                Some(&unreferenced),
+                ReleaseNotesOutputFormat::Json,
+                false,
+                false,
+                &ReleaseNotesLocale::default(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(json["tasks"][0]["task_id"], 7);
+        assert_eq!(json["unreferenced"][0]["pr_id"], 57);
+        assert_eq!(json["unreferenced"][0]["commit_id"], "0123456789abcdef");
+
+        let grouped: serde_json::Value = serde_json::from_str(
+            &format_output(
+                &entries,
+                Some(&[]),
+                ReleaseNotesOutputFormat::Json,
+                true,
+                false,
+                &ReleaseNotesLocale::default(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(grouped["fixes"][0]["task_id"], 7);
+        assert_eq!(grouped["unreferenced"], serde_json::json!([]));
+
+        assert_eq!(
+            format_output(
+                &entries,
+                None,
+                ReleaseNotesOutputFormat::Plain,
+                false,
+                false,
+                &ReleaseNotesLocale::default(),
+            )
+            .unwrap(),
+            "#7: Task 7"
+        );
+    }
+
+    /// # Package Version
+    ///
+    /// Tests listing the package version built for a release.
+    ///
+    /// ## Test Scenario
+    /// - Generates a release notes document with a package version
+    /// - Adds the package version to markdown and JSON output
+    ///
+    /// ## Expected Outcome
+    /// - The document lists the package under the release date
+    /// - Markdown output starts with the labeled package line
+    /// - JSON output gets a `package` object next to the tasks
+    #[test]
+    fn test_with_package_version() {
+        let package = PackageVersion {
+            package: "Contoso.Service".to_string(),
+            version: "1.4.1207".to_string(),
+        };
+        let locale = ReleaseNotesLocale::default();
+
+        let notes = generate_from_merge_data(
+            "v1.4",
+            &[],
+            &[],
+            &UrlBuilder::new("org", "proj", "repo"),
+            &HashMap::new(),
+            Some(&package),
+            NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
+            &locale,
+        );
+        assert!(
+            notes.contains("**Release Date:** 2025-01-15\n**Package:** Contoso.Service 1.4.1207\n")
+        );
+
+        let markdown = with_package_version(
+            "## Fixes\n".to_string(),
+            &package,
+            ReleaseNotesOutputFormat::Markdown,
+            &locale,
+        )
+        .unwrap();
+        assert_eq!(
+            markdown,
+            "**Package:** Contoso.Service 1.4.1207\n\n## Fixes\n"
+        );
+
+        let json = with_package_version(
+            "[]".to_string(),
+            &package,
+            ReleaseNotesOutputFormat::Json,
+            &locale,
+        )
+        .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(json["package"]["version"], "1.4.1207");
+        assert_eq!(json["tasks"], serde_json::json!([]));
+    }
+
+    /// # HTML Output
+    ///
+    /// Tests the HTML fragment of grouped entries and unreferenced changes.
+    ///
+    /// ## Test Scenario
+    /// - Formats a feature whose title needs escaping, with PR links
+    /// - Adds an unreferenced change with a merge commit
+    ///
+    /// ## Expected Outcome
+    /// - Each group is an `<h2>` with a `<ul>` of linked, escaped tasks
+    /// - Unreferenced changes end the fragment as their own list
+    #[test]
+    fn test_format_html() {
+        let urls = UrlBuilder::new("org", "proj", "repo");
+        let mut feature = pr(42, &[7]);
+        feature.pr.title = "feat: PR 42".to_string();
+        feature.work_items[0].fields.title = Some("Export <csv> & \"xlsx\"".to_string());
+        let mut unreferenced_pr = pr(57, &[]);
+        unreferenced_pr.pr.last_merge_commit = Some(crate::models::MergeCommit {
+            commit_id: "0123456789abcdef".to_string(),
+        });
+        let prs = [feature, unreferenced_pr];
+        let entries = build_entries_from_prs(&prs, &urls);
+        let unreferenced = collect_unreferenced(&prs, &urls);
+
+        let html = format_output(
+            &entries,
+            Some(&unreferenced),
+            ReleaseNotesOutputFormat::Html,
+            true,
+            true,
+            &ReleaseNotesLocale::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            html,
+            "<h2>Features</h2>\n\
+             <ul>\n  \
+             <li><a href=\"https://dev.azure.com/org/proj/_workitems/edit/7\">#7</a> \
+             Export &lt;csv&gt; &amp; &quot;xlsx&quot; \
+             (<a href=\"https://dev.azure.com/org/proj/_git/repo/pullrequest/42\">!42</a>)</li>\n\
+             </ul>\n\
+             <h2>Unreferenced changes</h2>\n\
+             <ul>\n  \
+             <li><a href=\"https://dev.azure.com/org/proj/_git/repo/pullrequest/57\">!57</a> \
+             fix: PR 57 (Dev, <code>01234567</code>)</li>\n\
+             </ul>\n"
+        );
+
+        let flat = format_html(&entries, false, false, &ReleaseNotesLocale::default());
+        assert!(flat.starts_with("<ul>\n  <li><a href="));
+        assert!(!flat.contains("<h2>"));
+        assert!(!flat.contains("pullrequest"));
+    }
+
+    /// # Entries From a State File
+    ///
+    /// Tests building release notes from the cherry-pick items of a merge.
+    ///
+    /// ## Test Scenario
+    /// - PR 42 was picked and records the title of work item 7
+    /// - PR 43 was picked and links work items 7 and 8; 8 has no recorded
+    ///   title but is in the work item cache
+    /// - PR 44 was skipped
+    ///
+    /// ## Expected Outcome
+    /// - Only picked PRs contribute entries, grouped by PR title
+    /// - Titles come from the state file, then the cache
+    /// - Each PR link carries the commit it was picked as
+    #[test]
+    fn test_build_entries_from_state() {
+        let state: MergeStateFile = serde_json::from_value(serde_json::json!({
+            "schema_version": crate::core::state::SCHEMA_VERSION,
+            "created_at": "2026-01-01T00:00:00Z",
+            "updated_at": "2026-01-01T00:00:00Z",
+            "repo_path": "/repo",
+            "base_repo_path": null,
+            "is_worktree": false,
+            "organization": "org",
+            "project": "proj",
+            "repository": "repo",
+            "dev_branch": "dev",
+            "target_branch": "main",
+            "merge_version": "1.2.0",
+            "cherry_pick_items": [
+                {
+                    "commit_id": "aaa", "pr_id": 42, "pr_title": "feat: Export",
+                    "status": "success", "work_item_ids": [7],
+                    "work_item_titles": { "7": "Export data" },
+                    "picked_commit_id": "0123456789abcdef", "had_conflict": false
+                },
+                {
+                    "commit_id": "bbb", "pr_id": 43, "pr_title": "fix: Export crash",
+                    "status": "success", "work_item_ids": [7, 8],
+                    "picked_commit_id": "fedcba9876543210", "had_conflict": true
+                },
+                {
+                    "commit_id": "ccc", "pr_id": 44, "pr_title": "fix: Later",
+                    "status": "skipped", "work_item_ids": [9], "had_conflict": false
+                }
+            ],
+            "current_index": 3,
+            "phase": "completed",
+            "conflicted_files": null,
+            "work_item_state": "Done",
+            "tag_prefix": "merged-",
+            "run_hooks": false
+        }))
+        .unwrap();
+        let mut cache = WorkItemCache::default();
+        cache.set(8, "Crash on export", None, None);
+        let urls = UrlBuilder::new("org", "proj", "repo");
+
+        let entries = build_entries_from_state(&state, &urls, &cache);
+
+        let summary: Vec<(i32, &str, TaskGroup)> = entries
+            .iter()
+            .map(|entry| (entry.task_id, entry.title.as_str(), entry.group))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (7, "Export data", TaskGroup::Feature),
+                (8, "Crash on export", TaskGroup::Fix),
+            ]
+        );
+        let commits: Vec<_> = entries[0]
+            .prs
+            .iter()
+            .map(|pr| (pr.id, pr.commit_id.as_deref()))
+            .collect();
+        assert_eq!(
+            commits,
+            vec![
+                (42, Some("0123456789abcdef")),
+                (43, Some("fedcba9876543210"))
+            ]
+        );
+    }
+
+    /// # Work Items Referenced in PR Text
+    ///
+    /// Tests adding work items referenced by patterns to the release notes.
+    ///
+    /// ## Test Scenario
+    /// - PR 42 links work item 7 and references `AB#7` and `AB#9` in its description
+    /// - PR 57 references `WI-8` in its title, routed to the features section
+    /// - Work items 8 and 9 are fetched; `AB#5` in PR 57 is not found
+    ///
+    /// ## Expected Outcome
+    /// - Only unlinked references are fetched
+    /// - Referenced work items become entries linked to their PRs
+    /// - Routed work items move to their section; others keep the PR title's
+    #[test]
+    fn test_task_references() {
+        let patterns = TaskPatterns::new(
+            &[
+                TaskPatternConfig {
+                    name: "boards".to_string(),
+                    pattern: r"AB#(\d+)".to_string(),
+                    section: None,
+                    repositories: Vec::new(),
+                },
+                TaskPatternConfig {
+                    name: "wi".to_string(),
+                    pattern: r"WI-(\d+)".to_string(),
+                    section: Some("features".to_string()),
+                    repositories: Vec::new(),
+                },
+            ],
+            "repo",
+        )
+        .unwrap();
+        let mut first = pr(42, &[7]);
+        first.pr.description = Some("Fixes AB#7 and AB#9".to_string());
+        let mut second = pr(57, &[]);
+        second.pr.title = "fix: WI-8 login timeout, AB#5".to_string();
+        let mut prs = vec![first, second];
+
+        assert_eq!(unlinked_task_references(&prs, &patterns), vec![5, 8, 9]);
+
+        let fetched = pr(0, &[8, 9]).work_items;
+        let sections = link_task_references(&mut prs, &patterns, &fetched);
+        let urls = UrlBuilder::new("org", "proj", "repo");
+        let mut entries = build_entries_from_prs(&prs, &urls);
+        route_to_sections(&mut entries, &sections);
+
+        let found: Vec<_> = entries
+            .iter()
+            .map(|entry| (entry.task_id, entry.prs[0].id, entry.group))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (7, 42, TaskGroup::Fix),
+                (9, 42, TaskGroup::Fix),
+                (8, 57, TaskGroup::Feature),
+            ]
+        );
+    }
 }
@@ -0,0 +1,278 @@
+//! Work item references written in pull request titles and descriptions.
+//!
+//! Release notes list the work items linked to each PR. Teams that write
+//! references like `AB#123` or `WI-123` into the PR instead of linking the
+//! work item configure patterns for them; the referenced work items are
+//! added to the release notes as if they were linked. A pattern may route
+//! its matches to a release notes section instead of the one derived from
+//! the PR title.
+//!
+//! The work item ID is the pattern's `id` group, or its first group if it
+//! has no group of that name. The regex crate has no look-behind, so guards
+//! are written as non-capturing groups.
+//!
+//! # Config Format
+//!
+//! ```toml
+//! [[task_patterns]]
+//! name = "boards"
+//! pattern = "AB#(\\d+)"
+//!
+//! [[task_patterns]]
+//! name = "hotfix"
+//! pattern = "(?:^|\\s)#(?P<id>\\d+)\\b"
+//! section = "fixes"
+//! repositories = ["app"]
+//! ```
+//!
+//! # Example
+//!
+//! ```rust
+//! use mergers::models::TaskGroup;
+//! use mergers::release_notes::{TaskPatternConfig, TaskPatterns};
+//!
+//! let patterns = TaskPatterns::new(
+//!     &[TaskPatternConfig {
+//!         name: "boards".to_string(),
+//!         pattern: r"AB#(\d+)".to_string(),
+//!         section: Some("fixes".to_string()),
+//!         repositories: Vec::new(),
+//!     }],
+//!     "app",
+//! )
+//! .unwrap();
+//! let references = patterns.extract("Fix login timeout (AB#123)");
+//! assert_eq!(references[0].id, 123);
+//! assert_eq!(references[0].section, Some(TaskGroup::Fix));
+//! ```
+
+use std::collections::HashSet;
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{PullRequest, TaskGroup};
+
+/// A work item reference pattern as written in the config file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskPatternConfig {
+    /// Name shown in errors.
+    pub name: String,
+    /// Regex matching a reference; captures the work item ID.
+    pub pattern: String,
+    /// Section matched work items are listed in: features, fixes,
+    /// refactors or other. Defaults to the section of the PR title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
+    /// Repositories the pattern applies to; all when empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repositories: Vec<String>,
+}
+
+/// A work item referenced by a pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskReference {
+    /// Work item ID.
+    pub id: i32,
+    /// Name of the pattern that matched.
+    pub pattern: String,
+    /// Section the pattern routes the work item to, if any.
+    pub section: Option<TaskGroup>,
+}
+
+#[derive(Debug, Clone)]
+struct TaskPattern {
+    name: String,
+    regex: Regex,
+    section: Option<TaskGroup>,
+}
+
+/// Compiled work item reference patterns, checked in config order.
+#[derive(Debug, Clone, Default)]
+pub struct TaskPatterns {
+    patterns: Vec<TaskPattern>,
+}
+
+/// Parses a section name of the config file.
+fn parse_section(section: &str) -> Option<TaskGroup> {
+    match section.trim().to_lowercase().as_str() {
+        "feature" | "features" => Some(TaskGroup::Feature),
+        "fix" | "fixes" => Some(TaskGroup::Fix),
+        "refactor" | "refactors" => Some(TaskGroup::Refactor),
+        "other" => Some(TaskGroup::Other),
+        _ => None,
+    }
+}
+
+impl TaskPatterns {
+    /// Compiles the patterns that apply to `repository`.
+    ///
+    /// Returns an error if a pattern is not a valid regex, has no capture
+    /// group, or names an unknown section.
+    pub fn new(configs: &[TaskPatternConfig], repository: &str) -> Result<Self> {
+        let patterns = configs
+            .iter()
+            .filter(|config| {
+                config.repositories.is_empty()
+                    || config
+                        .repositories
+                        .iter()
+                        .any(|name| name.eq_ignore_ascii_case(repository))
+            })
+            .map(|config| {
+                let regex = Regex::new(&config.pattern).with_context(|| {
+                    format!("Invalid regex in task pattern '{}'", config.name)
+                })?;
+                if regex.captures_len() < 2 {
+                    anyhow::bail!(
+                        "task pattern '{}' needs a capture group for the work item ID",
+                        config.name
+                    );
+                }
+                let section = config
+                    .section
+                    .as_deref()
+                    .map(|section| {
+                        parse_section(section).with_context(|| {
+                            format!(
+                                "Unknown section '{}' in task pattern '{}' (expected features, fixes, refactors or other)",
+                                section, config.name
+                            )
+                        })
+                    })
+                    .transpose()?;
+                Ok(TaskPattern {
+                    name: config.name.clone(),
+                    regex,
+                    section,
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { patterns })
+    }
+
+    /// Returns `true` if no patterns apply.
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Work items referenced in `text`, in pattern order.
+    ///
+    /// A work item matched by several patterns is reported once, for the
+    /// first of them.
+    pub fn extract(&self, text: &str) -> Vec<TaskReference> {
+        let mut seen = HashSet::new();
+        let mut references = Vec::new();
+        for pattern in &self.patterns {
+            for captures in pattern.regex.captures_iter(text) {
+                let Some(id) = captures
+                    .name("id")
+                    .or_else(|| captures.get(1))
+                    .and_then(|id| id.as_str().parse::<i32>().ok())
+                else {
+                    continue;
+                };
+                if seen.insert(id) {
+                    references.push(TaskReference {
+                        id,
+                        pattern: pattern.name.clone(),
+                        section: pattern.section,
+                    });
+                }
+            }
+        }
+        references
+    }
+
+    /// Work items referenced in a PR's title and description.
+    pub fn extract_from_pr(&self, pr: &PullRequest) -> Vec<TaskReference> {
+        let text = match &pr.description {
+            Some(description) => format!("{}\n{}", pr.title, description),
+            None => pr.title.clone(),
+        };
+        self.extract(&text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(name: &str, pattern: &str, section: Option<&str>) -> TaskPatternConfig {
+        TaskPatternConfig {
+            name: name.to_string(),
+            pattern: pattern.to_string(),
+            section: section.map(str::to_string),
+            repositories: Vec::new(),
+        }
+    }
+
+    /// # Reference Extraction
+    ///
+    /// Tests extracting work item references with several patterns.
+    ///
+    /// ## Test Scenario
+    /// - Configures `AB#123`, `WI-123` and a guarded `#123` pattern
+    /// - Extracts from text with each kind, a repeated ID and a non-reference
+    ///
+    /// ## Expected Outcome
+    /// - Each ID is reported once, for the first pattern that matches it
+    /// - The guard skips `#` inside words, and the `id` group wins over others
+    /// - Sections are carried over from the pattern
+    #[test]
+    fn test_extract_references() {
+        let patterns = TaskPatterns::new(
+            &[
+                config("boards", r"AB#(\d+)", None),
+                config("wi", r"\bWI-(\d+)\b", Some("Features")),
+                config("hash", r"(^|\s)#(?P<id>\d+)\b", Some("fix")),
+            ],
+            "app",
+        )
+        .unwrap();
+
+        let references = patterns.extract("Add export (WI-7, AB#12) #31 and #12, see issue#99");
+        let found: Vec<_> = references
+            .iter()
+            .map(|r| (r.id, r.pattern.as_str(), r.section))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (12, "boards", None),
+                (7, "wi", Some(TaskGroup::Feature)),
+                (31, "hash", Some(TaskGroup::Fix)),
+            ]
+        );
+        assert!(patterns.extract("No references").is_empty());
+    }
+
+    /// # Pattern Validation
+    ///
+    /// Tests compiling invalid and repository-scoped patterns.
+    ///
+    /// ## Test Scenario
+    /// - Compiles a pattern without a capture group, one with an invalid
+    ///   regex, one with an unknown section, and one for another repository
+    ///
+    /// ## Expected Outcome
+    /// - The invalid patterns are rejected naming the pattern
+    /// - Patterns for other repositories are skipped
+    #[test]
+    fn test_pattern_validation() {
+        let error = |config| TaskPatterns::new(&[config], "app").unwrap_err().to_string();
+        assert!(error(config("plain", r"AB#\d+", None)).contains("'plain'"));
+        assert!(error(config("broken", r"AB#(\d+", None)).contains("'broken'"));
+        assert!(error(config("section", r"AB#(\d+)", Some("bugs"))).contains("'bugs'"));
+
+        let mut scoped = config("scoped", r"AB#(\d+)", None);
+        scoped.repositories = vec!["App".to_string()];
+        assert!(
+            !TaskPatterns::new(&[scoped.clone()], "app")
+                .unwrap()
+                .is_empty()
+        );
+        assert!(TaskPatterns::new(&[scoped], "other").unwrap().is_empty());
+    }
+}
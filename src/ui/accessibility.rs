@@ -0,0 +1,373 @@
+//! Screen-reader friendly output for the TUI.
+//!
+//! The interactive screens signal a lot through color alone: selected rows
+//! turn green, missing dependencies amber, search hits blue. Accessible mode
+//! makes that information available without color:
+//!
+//! - `MERGERS_ACCESSIBLE=true` replaces color-only and emoji status signals
+//!   with textual markers such as `[x]`, `[d]` or `[conflict]`.
+//! - `MERGERS_HIGH_CONTRAST=true` redraws every frame in a high-contrast
+//!   palette: bright foregrounds on black, highlighted cells in inverse video.
+//! - `MERGERS_PLAIN_UPDATES=<file>` appends one plain line per screen change
+//!   and progress update to `<file>`. The TUI itself runs in the alternate
+//!   screen, so follow the file with `tail -f` in a second terminal to let a
+//!   screen reader track progress.
+
+use crate::models::CherryPickStatus;
+use crate::ui::state::typed::AppState;
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Environment variable enabling textual status markers.
+pub const ACCESSIBLE_ENV: &str = "MERGERS_ACCESSIBLE";
+
+/// Environment variable enabling the high-contrast palette.
+pub const HIGH_CONTRAST_ENV: &str = "MERGERS_HIGH_CONTRAST";
+
+/// Environment variable naming the file plain-line updates are appended to.
+pub const PLAIN_UPDATES_ENV: &str = "MERGERS_PLAIN_UPDATES";
+
+/// Accessibility settings for the interactive screens.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Accessibility {
+    /// Show textual markers instead of color-only signals.
+    pub markers: bool,
+    /// Redraw frames in the high-contrast palette.
+    pub high_contrast: bool,
+    /// File plain-line updates are appended to.
+    pub plain_updates: Option<PathBuf>,
+}
+
+impl Accessibility {
+    /// Reads the settings from `MERGERS_ACCESSIBLE`, `MERGERS_HIGH_CONTRAST`
+    /// and `MERGERS_PLAIN_UPDATES`.
+    pub fn from_env() -> Self {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Self {
+        let flag = |name: &str| {
+            var(name).is_some_and(|v| {
+                matches!(
+                    v.trim().to_ascii_lowercase().as_str(),
+                    "1" | "true" | "yes" | "on"
+                )
+            })
+        };
+        Self {
+            markers: flag(ACCESSIBLE_ENV),
+            high_contrast: flag(HIGH_CONTRAST_ENV),
+            plain_updates: var(PLAIN_UPDATES_ENV)
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+                .map(PathBuf::from),
+        }
+    }
+
+    /// Returns the selection column text for a PR row.
+    ///
+    /// Without markers this is the usual check mark or cross; with markers
+    /// every state the row background would signal gets its own text.
+    pub fn selection_marker(
+        &self,
+        selected: bool,
+        fails_gate: bool,
+        missing_dependency: bool,
+        search_match: bool,
+    ) -> &'static str {
+        match (self.markers, selected, fails_gate) {
+            (false, true, _) => "✓",
+            (false, false, true) => "✗",
+            (false, false, false) => " ",
+            (true, true, _) => "[x]",
+            (true, false, true) => "[!]",
+            (true, false, false) if missing_dependency => "[d]",
+            (true, false, false) if search_match => "[/]",
+            (true, false, false) => "[ ]",
+        }
+    }
+
+    /// Returns the symbol shown before a cherry-pick item.
+    pub fn status_marker(&self, status: &CherryPickStatus) -> &'static str {
+        if self.markers {
+            match status {
+                CherryPickStatus::Pending => "[pending]",
+                CherryPickStatus::InProgress => "[running]",
+                CherryPickStatus::Success => "[ok]",
+                CherryPickStatus::Conflict => "[conflict]",
+                CherryPickStatus::Skipped => "[skipped]",
+                CherryPickStatus::Failed(_) => "[failed]",
+            }
+        } else {
+            match status {
+                CherryPickStatus::Pending => "⏸",
+                CherryPickStatus::InProgress => "⏳",
+                CherryPickStatus::Success => "✅",
+                CherryPickStatus::Conflict => "⚠️",
+                CherryPickStatus::Skipped => "⏭",
+                CherryPickStatus::Failed(_) => "❌",
+            }
+        }
+    }
+
+    /// Applies the high-contrast palette to a rendered frame, if enabled.
+    pub fn apply(&self, buffer: &mut Buffer) {
+        if self.high_contrast {
+            apply_high_contrast(buffer);
+        }
+    }
+}
+
+/// Rewrites every cell of `buffer` into the high-contrast palette.
+///
+/// Cells with a background color become black on white, so highlighted
+/// rows stay distinguishable. Other cells get a bright foreground on black,
+/// and dimmed text is brightened.
+pub fn apply_high_contrast(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        if matches!(cell.bg, Color::Reset | Color::Black) {
+            cell.bg = Color::Black;
+            cell.fg = bright(cell.fg);
+        } else {
+            cell.bg = Color::White;
+            cell.fg = Color::Black;
+        }
+        cell.modifier.remove(Modifier::DIM);
+    }
+}
+
+fn bright(color: Color) -> Color {
+    match color {
+        Color::Red | Color::LightRed => Color::LightRed,
+        Color::Green | Color::LightGreen => Color::LightGreen,
+        Color::Yellow | Color::LightYellow => Color::LightYellow,
+        Color::Blue | Color::LightBlue | Color::Cyan | Color::LightCyan => Color::LightCyan,
+        Color::Magenta | Color::LightMagenta => Color::LightMagenta,
+        _ => Color::White,
+    }
+}
+
+/// Appends plain-line updates for screen readers.
+///
+/// Consecutive duplicates are dropped, so the run loop can offer the
+/// current status on every frame.
+#[derive(Debug, Default)]
+pub struct Announcer {
+    file: Option<File>,
+    last: Option<String>,
+}
+
+impl Announcer {
+    /// Creates an announcer appending to `path`.
+    ///
+    /// Returns a disabled announcer if the file cannot be opened; accessible
+    /// output must never stop the TUI from starting.
+    pub fn new(path: Option<&Path>) -> Self {
+        let file =
+            path.and_then(|path| OpenOptions::new().create(true).append(true).open(path).ok());
+        Self { file, last: None }
+    }
+
+    /// Returns `true` if updates are written anywhere.
+    pub fn is_enabled(&self) -> bool {
+        self.file.is_some()
+    }
+
+    /// Writes `line` unless it repeats the previous line.
+    pub fn announce(&mut self, line: &str) {
+        let Some(file) = self.file.as_mut() else {
+            return;
+        };
+        if self.last.as_deref() == Some(line) {
+            return;
+        }
+        if writeln!(file, "{}", line)
+            .and_then(|_| file.flush())
+            .is_ok()
+        {
+            self.last = Some(line.to_string());
+        }
+    }
+
+    /// Announces the screen name of `state` and its progress line, if any.
+    pub fn announce_state<S: AppState>(&mut self, state: &S, app: &S::App) {
+        if !self.is_enabled() {
+            return;
+        }
+        match state.announcement(app) {
+            Some(text) => self.announce(&format!("{}: {}", state.name(), text)),
+            None => self.announce(state.name()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+    use std::collections::HashMap;
+
+    fn settings(vars: &[(&str, &str)]) -> Accessibility {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        Accessibility::from_vars(|name| vars.get(name).cloned())
+    }
+
+    /// # Settings From Environment
+    ///
+    /// Tests reading the accessibility settings from environment variables.
+    ///
+    /// ## Test Scenario
+    /// - Reads settings with no variables, with all of them set, and with
+    ///   invalid values
+    ///
+    /// ## Expected Outcome
+    /// - Everything is off by default
+    /// - Flags accept `1`, `true`, `yes` and `on`; anything else is off
+    /// - An empty plain-updates path is ignored
+    #[test]
+    fn test_from_env() {
+        assert_eq!(settings(&[]), Accessibility::default());
+
+        let all = settings(&[
+            (ACCESSIBLE_ENV, "1"),
+            (HIGH_CONTRAST_ENV, " ON "),
+            (PLAIN_UPDATES_ENV, "/tmp/mergers.log"),
+        ]);
+        assert!(all.markers);
+        assert!(all.high_contrast);
+        assert_eq!(all.plain_updates, Some(PathBuf::from("/tmp/mergers.log")));
+
+        let invalid = settings(&[
+            (ACCESSIBLE_ENV, "maybe"),
+            (HIGH_CONTRAST_ENV, "0"),
+            (PLAIN_UPDATES_ENV, "  "),
+        ]);
+        assert_eq!(invalid, Accessibility::default());
+    }
+
+    /// # Textual Markers
+    ///
+    /// Tests the selection and cherry-pick status markers.
+    ///
+    /// ## Test Scenario
+    /// - Requests markers with accessible mode off and on
+    ///
+    /// ## Expected Outcome
+    /// - Off: the usual symbols
+    /// - On: a distinct bracketed marker for every state
+    #[test]
+    fn test_markers() {
+        let plain = Accessibility::default();
+        assert_eq!(plain.selection_marker(true, false, false, false), "✓");
+        assert_eq!(plain.selection_marker(false, true, false, false), "✗");
+        assert_eq!(plain.selection_marker(false, false, true, true), " ");
+        assert_eq!(plain.status_marker(&CherryPickStatus::Success), "✅");
+
+        let accessible = Accessibility {
+            markers: true,
+            ..Default::default()
+        };
+        assert_eq!(accessible.selection_marker(true, true, true, true), "[x]");
+        assert_eq!(accessible.selection_marker(false, true, true, true), "[!]");
+        assert_eq!(accessible.selection_marker(false, false, true, true), "[d]");
+        assert_eq!(
+            accessible.selection_marker(false, false, false, true),
+            "[/]"
+        );
+        assert_eq!(
+            accessible.selection_marker(false, false, false, false),
+            "[ ]"
+        );
+        assert_eq!(
+            accessible.status_marker(&CherryPickStatus::Conflict),
+            "[conflict]"
+        );
+        assert_eq!(
+            accessible.status_marker(&CherryPickStatus::Failed("x".into())),
+            "[failed]"
+        );
+    }
+
+    /// # High-Contrast Palette
+    ///
+    /// Tests rewriting a frame buffer into the high-contrast palette.
+    ///
+    /// ## Test Scenario
+    /// - Styles cells with a dim foreground, a colored background and a
+    ///   plain foreground, then applies the palette
+    ///
+    /// ## Expected Outcome
+    /// - Highlighted cells become black on white
+    /// - Other cells get bright foregrounds on black without `DIM`
+    /// - Nothing changes while high contrast is off
+    #[test]
+    fn test_high_contrast() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        buffer.set_string(
+            0,
+            0,
+            "a",
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::DIM),
+        );
+        buffer.set_string(1, 0, "b", Style::default().bg(Color::Rgb(0, 60, 0)));
+        buffer.set_string(2, 0, "c", Style::default().fg(Color::Blue));
+
+        let untouched = buffer.clone();
+        Accessibility::default().apply(&mut buffer);
+        assert_eq!(buffer, untouched);
+
+        Accessibility {
+            high_contrast: true,
+            ..Default::default()
+        }
+        .apply(&mut buffer);
+        assert_eq!(buffer[(0, 0)].fg, Color::White);
+        assert_eq!(buffer[(0, 0)].bg, Color::Black);
+        assert!(!buffer[(0, 0)].modifier.contains(Modifier::DIM));
+        assert_eq!(buffer[(1, 0)].fg, Color::Black);
+        assert_eq!(buffer[(1, 0)].bg, Color::White);
+        assert_eq!(buffer[(2, 0)].fg, Color::LightCyan);
+    }
+
+    /// # Plain-Line Announcements
+    ///
+    /// Tests appending plain-line updates to a file.
+    ///
+    /// ## Test Scenario
+    /// - Announces lines, repeating one of them back to back
+    /// - Announces through a disabled announcer
+    ///
+    /// ## Expected Outcome
+    /// - Lines are appended in order without consecutive duplicates
+    /// - A disabled announcer writes nothing
+    #[test]
+    fn test_announcer() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("updates.log");
+
+        let mut announcer = Announcer::new(Some(&path));
+        assert!(announcer.is_enabled());
+        announcer.announce("SetupRepo");
+        announcer.announce("CherryPick: Cherry-picking PR #1 (1 of 2)");
+        announcer.announce("CherryPick: Cherry-picking PR #1 (1 of 2)");
+        announcer.announce("SetupRepo");
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "SetupRepo\nCherryPick: Cherry-picking PR #1 (1 of 2)\nSetupRepo\n"
+        );
+
+        let mut disabled = Announcer::new(None);
+        assert!(!disabled.is_enabled());
+        disabled.announce("ignored");
+    }
+}
@@ -10,9 +10,10 @@ use crate::{
         AppConfig, AppModeConfig, CherryPickItem, CleanupBranch, CleanupConfig, MergeConfig,
         MigrationAnalysis, MigrationConfig, PullRequestWithWorkItems, SharedConfig, WorkItem,
     },
-    ui::AppMode,
     ui::apps::{CleanupApp, MergeApp, MigrationApp},
     ui::browser::SystemBrowserOpener,
+    ui::{Accessibility, AppMode, Hyperlinks},
+    utils::Clock,
 };
 use std::sync::Arc;
 use tempfile::TempDir;
@@ -124,6 +125,28 @@ impl App {
                 let typed_config = Arc::new(MergeConfig {
                     shared,
                     work_item_state: default.work_item_state,
+                    strict_states: default.strict_states,
+                    release_gate: default.release_gate,
+                    build_checks: default.build_checks,
+                    release_tag: default.release_tag,
+                    sign_commits: default.sign_commits,
+                    fan_out: default.fan_out,
+                    squash_message: default.squash_message,
+                    mainline_fallback: default.mainline_fallback,
+                    auto_refresh: default.auto_refresh,
+                    select_file: default.select_file,
+                    pr_tabs: default.pr_tabs,
+                    selection_soft_cap: default.selection_soft_cap,
+                    terminal_states: default.terminal_states,
+                    work_item_comment: default.work_item_comment,
+                    artifacts_feed: default.artifacts_feed,
+                    work_item_fields: default.work_item_fields,
+                    notifications: default.notifications,
+                    binary_conflicts: default.binary_conflicts,
+                    cherry_pick_order: default.cherry_pick_order,
+                    pr_filter: default.pr_filter,
+                    session: default.session,
+                    clipboard: default.clipboard,
                     run_hooks: default.run_hooks,
                 });
                 App::new_merge(typed_config, client)
@@ -131,6 +154,9 @@ impl App {
             AppConfig::ReleaseNotes { .. } => {
                 unreachable!("ReleaseNotes uses its own CLI runner, not the TUI")
             }
+            AppConfig::Analyze { .. } => {
+                unreachable!("Analyze uses its own CLI runner, not the TUI")
+            }
         }
     }
 
@@ -138,7 +164,7 @@ impl App {
     ///
     /// This constructor is primarily used for testing to inject a mock browser
     /// opener instead of the real system browser.
-    #[cfg(test)]
+    #[cfg(any(test, feature = "testing"))]
     pub fn new_with_browser(
         pull_requests: Vec<PullRequestWithWorkItems>,
         config: Arc<AppConfig>,
@@ -167,6 +193,28 @@ impl App {
                 let typed_config = Arc::new(MergeConfig {
                     shared,
                     work_item_state: default.work_item_state,
+                    strict_states: default.strict_states,
+                    release_gate: default.release_gate,
+                    build_checks: default.build_checks,
+                    release_tag: default.release_tag,
+                    sign_commits: default.sign_commits,
+                    fan_out: default.fan_out,
+                    squash_message: default.squash_message,
+                    mainline_fallback: default.mainline_fallback,
+                    auto_refresh: default.auto_refresh,
+                    select_file: default.select_file,
+                    pr_tabs: default.pr_tabs,
+                    selection_soft_cap: default.selection_soft_cap,
+                    terminal_states: default.terminal_states,
+                    work_item_comment: default.work_item_comment,
+                    artifacts_feed: default.artifacts_feed,
+                    work_item_fields: default.work_item_fields,
+                    notifications: default.notifications,
+                    binary_conflicts: default.binary_conflicts,
+                    cherry_pick_order: default.cherry_pick_order,
+                    pr_filter: default.pr_filter,
+                    session: default.session,
+                    clipboard: default.clipboard,
                     run_hooks: default.run_hooks,
                 });
                 App::Merge(MergeApp::new(typed_config, client, browser))
@@ -174,6 +222,9 @@ impl App {
             AppConfig::ReleaseNotes { .. } => {
                 unreachable!("ReleaseNotes uses its own CLI runner, not the TUI")
             }
+            AppConfig::Analyze { .. } => {
+                unreachable!("Analyze uses its own CLI runner, not the TUI")
+            }
         };
 
         *app.pull_requests_mut() = pull_requests;
@@ -245,6 +296,33 @@ impl App {
         }
     }
 
+    /// Sets the clock used for timestamps and run ids.
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        match self {
+            App::Merge(app) => app.set_clock(clock),
+            App::Migration(app) => app.set_clock(clock),
+            App::Cleanup(app) => app.set_clock(clock),
+        }
+    }
+
+    /// Sets the screen-reader and high-contrast output settings.
+    pub fn set_accessibility(&mut self, accessibility: Accessibility) {
+        match self {
+            App::Merge(app) => app.set_accessibility(accessibility),
+            App::Migration(app) => app.set_accessibility(accessibility),
+            App::Cleanup(app) => app.set_accessibility(accessibility),
+        }
+    }
+
+    /// Sets the terminal hyperlink settings.
+    pub fn set_hyperlinks(&mut self, hyperlinks: Hyperlinks) {
+        match self {
+            App::Merge(app) => app.set_hyperlinks(hyperlinks),
+            App::Migration(app) => app.set_hyperlinks(hyperlinks),
+            App::Cleanup(app) => app.set_hyperlinks(hyperlinks),
+        }
+    }
+
     /// Returns the error message if set.
     pub fn error_message(&self) -> Option<&str> {
         match self {
@@ -571,6 +649,7 @@ mod tests {
             project: ParsedProperty::Default("test_project".to_string()),
             repository: ParsedProperty::Default("test_repo".to_string()),
             pat: ParsedProperty::Default("test_pat".to_string()),
+            write_pat: None,
             dev_branch: ParsedProperty::Default("dev".to_string()),
             target_branch: ParsedProperty::Default("next".to_string()),
             local_repo: None,
@@ -579,7 +658,12 @@ mod tests {
             max_concurrent_processing: ParsedProperty::Default(10),
             tag_prefix: ParsedProperty::Default("merged-".to_string()),
             since: None,
+            since_field: Default::default(),
             skip_confirmation: false,
+            api_version: None,
+            history_depth: None,
+            url_templates: Default::default(),
+            release_notes_locale: Default::default(),
         }
     }
 
@@ -587,6 +671,28 @@ mod tests {
         Arc::new(MergeConfig {
             shared: create_shared_config(),
             work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+            strict_states: None,
+            release_gate: None,
+            build_checks: Default::default(),
+            release_tag: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            pr_tabs: Vec::new(),
+            selection_soft_cap: None,
+            terminal_states: Vec::new(),
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            notifications: None,
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            pr_filter: Default::default(),
+            session: None,
+            clipboard: Default::default(),
             run_hooks: ParsedProperty::Default(false),
         })
     }
@@ -710,6 +816,28 @@ mod tests {
             shared: create_shared_config(),
             default: DefaultModeConfig {
                 work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+                strict_states: None,
+                release_gate: None,
+                build_checks: Default::default(),
+                release_tag: Default::default(),
+                sign_commits: false,
+                fan_out: Vec::new(),
+                squash_message: None,
+                mainline_fallback: false,
+                auto_refresh: None,
+                select_file: None,
+                pr_tabs: Vec::new(),
+                selection_soft_cap: None,
+                terminal_states: Vec::new(),
+                work_item_comment: None,
+                artifacts_feed: None,
+                work_item_fields: Vec::new(),
+                notifications: None,
+                binary_conflicts: Default::default(),
+                cherry_pick_order: Default::default(),
+                pr_filter: Default::default(),
+                session: None,
+                clipboard: Default::default(),
                 run_hooks: ParsedProperty::Default(false),
             },
         });
@@ -869,8 +997,10 @@ mod tests {
             target: "main".to_string(),
             version: "1.0".to_string(),
             is_merged: true,
+            is_merged_remote: Some(true),
             selected: false,
             status: CleanupStatus::Pending,
+            tip: None,
         });
 
         assert_eq!(app.cleanup_branches().len(), 1);
@@ -916,6 +1046,28 @@ mod tests {
         let merge_config = Arc::new(MergeConfig {
             shared: create_shared_config(),
             work_item_state: ParsedProperty::Default("Custom State".to_string()),
+            strict_states: None,
+            release_gate: None,
+            build_checks: Default::default(),
+            release_tag: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            pr_tabs: Vec::new(),
+            selection_soft_cap: None,
+            terminal_states: Vec::new(),
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            notifications: None,
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            pr_filter: Default::default(),
+            session: None,
+            clipboard: Default::default(),
             run_hooks: ParsedProperty::Default(false),
         });
         let merge_app = App::new_merge(merge_config, client.clone());
@@ -13,11 +13,12 @@
 //! - `CleanupApp` uses `AppBase<CleanupConfig>`
 
 use crate::{
-    api::AzureDevOpsClient,
+    api::{AzureDevOpsClient, UrlBuilder},
     models::{AppModeConfig, PullRequestWithWorkItems, WorkItem},
-    ui::{WorktreeContext, browser::BrowserOpener},
+    ui::{Accessibility, Hyperlinks, WorktreeContext, browser::BrowserOpener},
+    utils::{Clock, IdGen, SystemClock, TimestampIdGen},
 };
-use std::{path::Path, sync::Arc};
+use std::{collections::HashMap, path::Path, sync::Arc};
 
 /// Shared state common to all app modes.
 ///
@@ -66,11 +67,34 @@ pub struct AppBase<C: AppModeConfig> {
 
     /// Browser opener for opening URLs (trait object for testing).
     browser: Box<dyn BrowserOpener>,
+
+    /// Source of the current time (trait object for testing).
+    clock: Arc<dyn Clock>,
+
+    /// Source of run ids (trait object for testing).
+    ids: Arc<dyn IdGen>,
+
+    /// Screen-reader and high-contrast output settings.
+    accessibility: Accessibility,
+
+    /// Terminal hyperlink settings.
+    hyperlinks: Hyperlinks,
+
+    /// Manual cherry-pick order by PR id; empty keeps closed-date order.
+    pick_order: Vec<i32>,
+
+    /// Cherry-pick order from the dependency graph by PR id; empty keeps
+    /// closed-date order.
+    dependency_order: Vec<i32>,
 }
 
 impl<C: AppModeConfig> AppBase<C> {
     /// Creates a new AppBase with the given configuration, client, and browser opener.
+    ///
+    /// Time comes from the system clock; use [`set_clock`](Self::set_clock)
+    /// to run from another clock.
     pub fn new(config: Arc<C>, client: AzureDevOpsClient, browser: Box<dyn BrowserOpener>) -> Self {
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
         Self {
             config,
             pull_requests: Vec::new(),
@@ -79,6 +103,12 @@ impl<C: AppModeConfig> AppBase<C> {
             worktree: WorktreeContext::new(),
             error_message: None,
             browser,
+            ids: Arc::new(TimestampIdGen::new(clock.clone())),
+            clock,
+            accessibility: Accessibility::default(),
+            hyperlinks: Hyperlinks::default(),
+            pick_order: Vec::new(),
+            dependency_order: Vec::new(),
         }
     }
 
@@ -131,6 +161,50 @@ impl<C: AppModeConfig> AppBase<C> {
         self.worktree.set_repo_path(path);
     }
 
+    /// Returns the clock used for timestamps.
+    pub fn clock(&self) -> &Arc<dyn Clock> {
+        &self.clock
+    }
+
+    /// Sets the clock used for timestamps.
+    ///
+    /// Run ids are then derived from the new clock as well, unless replaced
+    /// again with [`set_id_gen`](Self::set_id_gen).
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.ids = Arc::new(TimestampIdGen::new(clock.clone()));
+        self.clock = clock;
+    }
+
+    /// Returns the accessibility settings.
+    pub fn accessibility(&self) -> &Accessibility {
+        &self.accessibility
+    }
+
+    /// Sets the accessibility settings.
+    pub fn set_accessibility(&mut self, accessibility: Accessibility) {
+        self.accessibility = accessibility;
+    }
+
+    /// Returns the terminal hyperlink settings.
+    pub fn hyperlinks(&self) -> Hyperlinks {
+        self.hyperlinks
+    }
+
+    /// Sets the terminal hyperlink settings.
+    pub fn set_hyperlinks(&mut self, hyperlinks: Hyperlinks) {
+        self.hyperlinks = hyperlinks;
+    }
+
+    /// Returns the generator used for run ids.
+    pub fn ids(&self) -> &Arc<dyn IdGen> {
+        &self.ids
+    }
+
+    /// Sets the generator used for run ids.
+    pub fn set_id_gen(&mut self, ids: Arc<dyn IdGen>) {
+        self.ids = ids;
+    }
+
     // ========================================================================
     // Configuration Getters
     // ========================================================================
@@ -184,6 +258,15 @@ impl<C: AppModeConfig> AppBase<C> {
         self.config.shared().tag_prefix.value()
     }
 
+    /// Returns the configured limit on target branch history, if any.
+    pub fn history_depth(&self) -> Option<usize> {
+        self.config
+            .shared()
+            .history_depth
+            .as_ref()
+            .map(|p| *p.value())
+    }
+
     /// Returns the "since" date filter as originally specified.
     pub fn since(&self) -> Option<&str> {
         self.config
@@ -197,7 +280,12 @@ impl<C: AppModeConfig> AppBase<C> {
     // Pull Request Helpers
     // ========================================================================
 
-    /// Returns all selected pull requests, sorted by closed date.
+    /// Returns all selected pull requests in cherry-pick order.
+    ///
+    /// PRs are sorted by closed date, or by the
+    /// [dependency order](Self::set_dependency_order) when one is set, until
+    /// reordered with [`move_in_pick_order`](Self::move_in_pick_order). After
+    /// that, PRs keep their manual order and PRs selected later follow.
     pub fn get_selected_prs(&self) -> Vec<&PullRequestWithWorkItems> {
         let mut prs = self
             .pull_requests
@@ -205,37 +293,130 @@ impl<C: AppModeConfig> AppBase<C> {
             .filter(|pr| pr.selected)
             .collect::<Vec<_>>();
         prs.sort_by_key(|pr| pr.pr.closed_date.as_ref().unwrap());
+        if !self.dependency_order.is_empty() {
+            prs.sort_by_key(|pr| {
+                self.dependency_order
+                    .iter()
+                    .position(|&id| id == pr.pr.id)
+                    .unwrap_or(usize::MAX)
+            });
+        }
+        if !self.pick_order.is_empty() {
+            prs.sort_by_key(|pr| {
+                self.pick_order
+                    .iter()
+                    .position(|&id| id == pr.pr.id)
+                    .unwrap_or(usize::MAX)
+            });
+        }
         prs
     }
 
+    /// Returns the 1-based cherry-pick position of each selected PR by id.
+    pub fn pick_positions(&self) -> HashMap<i32, usize> {
+        self.get_selected_prs()
+            .iter()
+            .enumerate()
+            .map(|(index, pr)| (pr.pr.id, index + 1))
+            .collect()
+    }
+
+    /// Returns true if the cherry-pick order was changed by hand.
+    pub fn has_manual_pick_order(&self) -> bool {
+        !self.pick_order.is_empty()
+    }
+
+    /// Moves a selected PR one place earlier or later in the cherry-pick
+    /// order.
+    ///
+    /// Returns false if the PR is not selected or already first or last.
+    pub fn move_in_pick_order(&mut self, pr_id: i32, earlier: bool) -> bool {
+        let mut order: Vec<i32> = self.get_selected_prs().iter().map(|pr| pr.pr.id).collect();
+        let Some(position) = order.iter().position(|&id| id == pr_id) else {
+            return false;
+        };
+        let target = if earlier {
+            position.checked_sub(1)
+        } else {
+            Some(position + 1).filter(|&target| target < order.len())
+        };
+        let Some(target) = target else {
+            return false;
+        };
+        order.swap(position, target);
+        self.pick_order = order;
+        true
+    }
+
+    /// Returns the manual cherry-pick order by PR id; empty for the
+    /// closed-date order.
+    pub fn pick_order(&self) -> &[i32] {
+        &self.pick_order
+    }
+
+    /// Replaces the manual cherry-pick order, e.g. from a saved session.
+    pub fn set_pick_order(&mut self, order: Vec<i32>) {
+        self.pick_order = order;
+    }
+
+    /// Restores the closed-date cherry-pick order.
+    pub fn reset_pick_order(&mut self) {
+        self.pick_order.clear();
+    }
+
+    /// Sets the cherry-pick order derived from the dependency graph.
+    ///
+    /// PRs missing from `order` follow by closed date; an empty order
+    /// restores the closed-date order.
+    pub fn set_dependency_order(&mut self, order: Vec<i32>) {
+        self.dependency_order = order;
+    }
+
     // ========================================================================
     // Browser Helpers
     // ========================================================================
 
+    /// Returns the URL builder for the configured repository.
+    pub fn urls(&self) -> UrlBuilder {
+        UrlBuilder::new(self.organization(), self.project(), self.repository())
+            .with_templates(self.config.shared().url_templates.clone())
+    }
+
+    /// Returns the headings and date format of generated release notes.
+    pub fn release_notes_locale(&self) -> &crate::release_notes::ReleaseNotesLocale {
+        &self.config.shared().release_notes_locale
+    }
+
     /// Opens a pull request in the default browser.
     pub fn open_pr_in_browser(&self, pr_id: i32) {
-        let url = format!(
-            "https://dev.azure.com/{}/{}/_git/{}/pullrequest/{}",
-            self.organization(),
-            self.project(),
-            self.repository(),
-            pr_id
+        self.browser.open_url(&self.urls().pull_request_url(pr_id));
+    }
+
+    /// Opens the repository file browser on `branch` in the default browser.
+    pub fn open_branch_in_browser(&self, branch: &str) {
+        self.browser.open_url(&self.urls().branch_url(branch));
+    }
+
+    /// Opens the page creating a pull request from `source_branch` into
+    /// `target_branch` in the default browser.
+    pub fn open_create_pull_request(&self, source_branch: &str, target_branch: &str) {
+        self.browser.open_url(
+            &self
+                .urls()
+                .create_pull_request_url(source_branch, target_branch),
         );
+    }
 
-        self.browser.open_url(&url);
+    /// Opens a local folder in the system file manager.
+    pub fn open_folder(&self, path: &std::path::Path) {
+        self.browser.open_url(&path.display().to_string());
     }
 
     /// Opens work items in the default browser.
     pub fn open_work_items_in_browser(&self, work_items: &[WorkItem]) {
+        let urls = self.urls();
         for wi in work_items {
-            let url = format!(
-                "https://dev.azure.com/{}/{}/_workitems/edit/{}",
-                self.organization(),
-                self.project(),
-                wi.id
-            );
-
-            self.browser.open_url(&url);
+            self.browser.open_url(&urls.work_item_url(wi.id));
         }
     }
 }
@@ -255,6 +436,7 @@ mod tests {
             project: ParsedProperty::Default("test_project".to_string()),
             repository: ParsedProperty::Default("test_repo".to_string()),
             pat: ParsedProperty::Default("test_pat".to_string()),
+            write_pat: None,
             dev_branch: ParsedProperty::Default("develop".to_string()),
             target_branch: ParsedProperty::Default("main".to_string()),
             local_repo: Some(ParsedProperty::Default("/path/to/repo".to_string())),
@@ -263,7 +445,12 @@ mod tests {
             max_concurrent_processing: ParsedProperty::Default(10),
             tag_prefix: ParsedProperty::Default("merged/".to_string()),
             since: None,
+            since_field: Default::default(),
             skip_confirmation: false,
+            api_version: None,
+            history_depth: None,
+            url_templates: Default::default(),
+            release_notes_locale: Default::default(),
         }
     }
 
@@ -294,6 +481,28 @@ mod tests {
             shared: create_test_shared_config(),
             default: DefaultModeConfig {
                 work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+                strict_states: None,
+                release_gate: None,
+                build_checks: Default::default(),
+                release_tag: Default::default(),
+                sign_commits: false,
+                fan_out: Vec::new(),
+                squash_message: None,
+                mainline_fallback: false,
+                auto_refresh: None,
+                select_file: None,
+                pr_tabs: Vec::new(),
+                selection_soft_cap: None,
+                terminal_states: Vec::new(),
+                work_item_comment: None,
+                artifacts_feed: None,
+                work_item_fields: Vec::new(),
+                notifications: None,
+                binary_conflicts: Default::default(),
+                cherry_pick_order: Default::default(),
+                pr_filter: Default::default(),
+                session: None,
+                clipboard: Default::default(),
                 run_hooks: ParsedProperty::Default(false),
             },
         });
@@ -324,6 +533,28 @@ mod tests {
             shared: create_test_shared_config(),
             default: DefaultModeConfig {
                 work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+                strict_states: None,
+                release_gate: None,
+                build_checks: Default::default(),
+                release_tag: Default::default(),
+                sign_commits: false,
+                fan_out: Vec::new(),
+                squash_message: None,
+                mainline_fallback: false,
+                auto_refresh: None,
+                select_file: None,
+                pr_tabs: Vec::new(),
+                selection_soft_cap: None,
+                terminal_states: Vec::new(),
+                work_item_comment: None,
+                artifacts_feed: None,
+                work_item_fields: Vec::new(),
+                notifications: None,
+                binary_conflicts: Default::default(),
+                cherry_pick_order: Default::default(),
+                pr_filter: Default::default(),
+                session: None,
+                clipboard: Default::default(),
                 run_hooks: ParsedProperty::Default(false),
             },
         });
@@ -362,6 +593,28 @@ mod tests {
             shared,
             default: DefaultModeConfig {
                 work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+                strict_states: None,
+                release_gate: None,
+                build_checks: Default::default(),
+                release_tag: Default::default(),
+                sign_commits: false,
+                fan_out: Vec::new(),
+                squash_message: None,
+                mainline_fallback: false,
+                auto_refresh: None,
+                select_file: None,
+                pr_tabs: Vec::new(),
+                selection_soft_cap: None,
+                terminal_states: Vec::new(),
+                work_item_comment: None,
+                artifacts_feed: None,
+                work_item_fields: Vec::new(),
+                notifications: None,
+                binary_conflicts: Default::default(),
+                cherry_pick_order: Default::default(),
+                pr_filter: Default::default(),
+                session: None,
+                clipboard: Default::default(),
                 run_hooks: ParsedProperty::Default(false),
             },
         });
@@ -379,10 +632,13 @@ mod tests {
     /// ## Test Scenario
     /// - Creates AppBase with mix of selected and unselected PRs
     /// - Calls get_selected_prs()
+    /// - Moves the newest PR first, selects another PR, resets the order
     ///
     /// ## Expected Outcome
     /// - Only selected PRs are returned
     /// - PRs are sorted by closed date
+    /// - A manual order wins; PRs selected later follow it
+    /// - Moving past either end is refused
     #[test]
     fn test_get_selected_prs() {
         use crate::models::{CreatedBy, PullRequest, PullRequestWithWorkItems};
@@ -391,6 +647,28 @@ mod tests {
             shared: create_test_shared_config(),
             default: DefaultModeConfig {
                 work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+                strict_states: None,
+                release_gate: None,
+                build_checks: Default::default(),
+                release_tag: Default::default(),
+                sign_commits: false,
+                fan_out: Vec::new(),
+                squash_message: None,
+                mainline_fallback: false,
+                auto_refresh: None,
+                select_file: None,
+                pr_tabs: Vec::new(),
+                selection_soft_cap: None,
+                terminal_states: Vec::new(),
+                work_item_comment: None,
+                artifacts_feed: None,
+                work_item_fields: Vec::new(),
+                notifications: None,
+                binary_conflicts: Default::default(),
+                cherry_pick_order: Default::default(),
+                pr_filter: Default::default(),
+                session: None,
+                clipboard: Default::default(),
                 run_hooks: ParsedProperty::Default(false),
             },
         });
@@ -411,6 +689,7 @@ mod tests {
                 created_by: created_by.clone(),
                 last_merge_commit: None,
                 labels: None,
+                reviewers: Vec::new(),
             },
             work_items: vec![],
             selected: true,
@@ -424,6 +703,7 @@ mod tests {
                 created_by: created_by.clone(),
                 last_merge_commit: None,
                 labels: None,
+                reviewers: Vec::new(),
             },
             work_items: vec![],
             selected: false, // Not selected
@@ -437,6 +717,7 @@ mod tests {
                 created_by,
                 last_merge_commit: None,
                 labels: None,
+                reviewers: Vec::new(),
             },
             work_items: vec![],
             selected: true,
@@ -450,5 +731,18 @@ mod tests {
         assert_eq!(selected.len(), 2);
         assert_eq!(selected[0].pr.id, 1); // Oldest first
         assert_eq!(selected[1].pr.id, 3); // Newest last
+
+        assert!(base.move_in_pick_order(3, true));
+        assert!(!base.move_in_pick_order(3, true));
+        assert!(!base.move_in_pick_order(2, false));
+        base.pull_requests[2].selected = true;
+        let order: Vec<i32> = base.get_selected_prs().iter().map(|pr| pr.pr.id).collect();
+        assert_eq!(order, vec![3, 1, 2]);
+        assert_eq!(base.pick_positions()[&2], 3);
+        assert!(base.has_manual_pick_order());
+
+        base.reset_pick_order();
+        let order: Vec<i32> = base.get_selected_prs().iter().map(|pr| pr.pr.id).collect();
+        assert_eq!(order, vec![1, 2, 3]);
     }
 }
@@ -5,6 +5,7 @@
 
 use crate::{
     api::AzureDevOpsClient,
+    core::operations::ReleaseLabel,
     models::{CleanupBranch, CleanupConfig},
     ui::{AppBase, AppMode, browser::BrowserOpener},
 };
@@ -46,6 +47,11 @@ pub struct CleanupApp {
 
     /// Branches that are candidates for cleanup.
     pub cleanup_branches: Vec<CleanupBranch>,
+
+    /// Release labels found on PRs, grouped by version.
+    ///
+    /// `None` until the release labels tab has loaded them.
+    pub release_labels: Option<Vec<ReleaseLabel>>,
 }
 
 impl CleanupApp {
@@ -58,6 +64,7 @@ impl CleanupApp {
         Self {
             base: AppBase::new(config, client, browser),
             cleanup_branches: Vec::new(),
+            release_labels: None,
         }
     }
 
@@ -121,6 +128,16 @@ impl CleanupApp {
     pub fn cleanup_branches_mut(&mut self) -> &mut Vec<CleanupBranch> {
         &mut self.cleanup_branches
     }
+
+    /// Returns the loaded release labels, or an empty slice if not loaded.
+    pub fn release_labels(&self) -> &[ReleaseLabel] {
+        self.release_labels.as_deref().unwrap_or_default()
+    }
+
+    /// Returns a mutable reference to the loaded release labels.
+    pub fn release_labels_mut(&mut self) -> &mut Vec<ReleaseLabel> {
+        self.release_labels.get_or_insert_with(Vec::new)
+    }
 }
 
 impl Deref for CleanupApp {
@@ -163,6 +180,7 @@ mod tests {
                 project: ParsedProperty::Default("test_project".to_string()),
                 repository: ParsedProperty::Default("test_repo".to_string()),
                 pat: ParsedProperty::Default("test_pat".to_string()),
+                write_pat: None,
                 dev_branch: ParsedProperty::Default("develop".to_string()),
                 target_branch: ParsedProperty::Default("main".to_string()),
                 local_repo: None,
@@ -171,7 +189,12 @@ mod tests {
                 max_concurrent_processing: ParsedProperty::Default(10),
                 tag_prefix: ParsedProperty::Default("merged/".to_string()),
                 since: None,
+                since_field: Default::default(),
                 skip_confirmation: false,
+                api_version: None,
+                history_depth: None,
+                url_templates: Default::default(),
+                release_notes_locale: Default::default(),
             },
             target: ParsedProperty::Default("release/1.0".to_string()),
         })
@@ -280,24 +303,30 @@ mod tests {
                 target: "main".to_string(),
                 version: "1.0".to_string(),
                 is_merged: true,
+                is_merged_remote: Some(true),
                 selected: false,
                 status: CleanupStatus::Pending,
+                tip: None,
             },
             CleanupBranch {
                 name: "feature/b".to_string(),
                 target: "main".to_string(),
                 version: "1.0".to_string(),
                 is_merged: true,
+                is_merged_remote: Some(true),
                 selected: false,
                 status: CleanupStatus::Pending,
+                tip: None,
             },
             CleanupBranch {
                 name: "feature/c".to_string(),
                 target: "main".to_string(),
                 version: "1.0".to_string(),
                 is_merged: false,
+                is_merged_remote: Some(false),
                 selected: false,
                 status: CleanupStatus::Pending,
+                tip: None,
             },
         ];
 
@@ -344,24 +373,30 @@ mod tests {
                 target: "main".to_string(),
                 version: "1.0".to_string(),
                 is_merged: true,
+                is_merged_remote: Some(true),
                 selected: true,
                 status: CleanupStatus::Pending,
+                tip: None,
             },
             CleanupBranch {
                 name: "feature/b".to_string(),
                 target: "main".to_string(),
                 version: "1.0".to_string(),
                 is_merged: true,
+                is_merged_remote: Some(true),
                 selected: false,
                 status: CleanupStatus::Pending,
+                tip: None,
             },
             CleanupBranch {
                 name: "feature/c".to_string(),
                 target: "main".to_string(),
                 version: "1.0".to_string(),
                 is_merged: false,
+                is_merged_remote: Some(false),
                 selected: true,
                 status: CleanupStatus::Pending,
+                tip: None,
             },
         ];
 
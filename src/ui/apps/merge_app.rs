@@ -5,20 +5,55 @@
 
 use crate::{
     Config,
-    api::AzureDevOpsClient,
-    core::operations::PRDependencyGraph,
+    api::{ArtifactsFeed, AzureDevOpsClient, PackageVersion, SchemaWarning},
+    core::operations::{
+        BuildCheckIndex, BuildCheckMode, CherryPickConfig, CherryPickOperation, CherryPickOrder,
+        CherryPickOutcome, DependencyAnalyzer, FanOutTargetResult, IgnoredPullRequest,
+        PRDependencyGraph, PrFilter, PrTableLayout, ReleaseGate, ReleaseTagMode, RevertIndex,
+        fan_out, release_history,
+    },
     core::state::{
-        LockGuard, MergePhase, MergeStateFile, StateCreateConfig, StateItemStatus, StateManager,
+        LockGuard, MergePhase, MergeStateFile, MergeStatus, StateCreateConfig, StateItemStatus,
+        StateManager,
     },
-    models::{CherryPickItem, CherryPickStatus, MergeConfig},
+    git,
+    models::{CherryPickItem, CherryPickStatus, MergeConfig, PullRequestWithWorkItems},
+    parsed_property::ParsedProperty,
     ui::{AppBase, AppMode, browser::BrowserOpener},
+    utils::Clock,
 };
 use anyhow::Result;
 use std::{
+    collections::{HashMap, HashSet},
     ops::{Deref, DerefMut},
-    path::PathBuf,
-    sync::{Arc, Mutex},
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
 };
+use tokio::task::JoinHandle;
+
+/// Features that need `local_repo` and are off without it.
+pub const LOCAL_REPO_FEATURES: [&str; 4] = [
+    "dependency analysis",
+    "conflict risk",
+    "file-based PR tabs",
+    "selection diff size",
+];
+
+/// Line-range dependency analysis refining a file-level graph in the background.
+struct DependencyRefinement {
+    task: JoinHandle<Option<PRDependencyGraph>>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl Drop for DependencyRefinement {
+    fn drop(&mut self) {
+        // Blocking tasks can't be aborted; ask the analysis to stop instead
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
 
 /// Application state for merge (default) mode.
 ///
@@ -64,6 +99,30 @@ pub struct MergeApp {
     /// Populated during data loading, before PR selection.
     dependency_graph: Option<PRDependencyGraph>,
 
+    /// Line-range analysis that replaces the file-level graph once finished.
+    dependency_refinement: Option<DependencyRefinement>,
+
+    /// PRs excluded by the `.mergers-ignore` skip list.
+    /// Kept for display only; they are never selectable.
+    ignored_pull_requests: Vec<IgnoredPullRequest>,
+
+    /// Reverts found among the fetched PRs, flagged in the selection table.
+    revert_index: RevertIndex,
+
+    /// PRs that failed their build or branch policy checks.
+    build_checks: BuildCheckIndex,
+
+    /// Mismatches between Azure DevOps responses and the expected schema.
+    schema_warnings: Vec<SchemaWarning>,
+
+    /// PRs to mark as selected once the PR list is loaded.
+    /// Set by `mergers watch` when opening the TUI with accumulated candidates.
+    preselected_pr_ids: HashSet<i32>,
+
+    /// Author, reviewer and path filter of the PR selection table.
+    /// Starts from `--author`, `--reviewer` and `--path-glob`.
+    pr_filter: PrFilter,
+
     // ==========================================================================
     // UI Settings (runtime-modifiable, persisted to config file)
     // ==========================================================================
@@ -73,8 +132,27 @@ pub struct MergeApp {
     /// Whether to show work item relationship highlighting in PR selection.
     show_work_item_highlights: bool,
 
+    /// Column widths and sort order of the PR selection table.
+    pr_table_layout: PrTableLayout,
+
+    /// File the table layout is saved to (`None` keeps it in memory only).
+    pr_table_layout_path: Option<PathBuf>,
+
+    /// Session file the PR selection is restored from and saved to
+    /// (`None` without `--session`).
+    session_path: Option<PathBuf>,
+
+    /// Directory to open an interactive shell in, taken by the run loop.
+    shell_request: Option<PathBuf>,
+
     /// Whether tagging (post-completion) has been completed.
     pub tagging_completed: bool,
+
+    /// Outcomes of the fan-out targets finished before the current one.
+    fan_out_results: Vec<FanOutTargetResult>,
+
+    /// Package version found in the artifacts feed during post-completion.
+    package_version: Option<PackageVersion>,
 }
 
 impl MergeApp {
@@ -120,15 +198,29 @@ impl MergeApp {
         show_dependency_highlights: bool,
         show_work_item_highlights: bool,
     ) -> Self {
+        let pr_filter = config.pr_filter.clone();
         Self {
             base: AppBase::new(config, client, browser),
             cherry_pick_items: Vec::new(),
             current_cherry_pick_index: 0,
             state_manager: Arc::new(Mutex::new(StateManager::new())),
             dependency_graph: None,
+            dependency_refinement: None,
+            ignored_pull_requests: Vec::new(),
+            revert_index: RevertIndex::default(),
+            build_checks: BuildCheckIndex::default(),
+            schema_warnings: Vec::new(),
+            preselected_pr_ids: HashSet::new(),
+            pr_filter,
             show_dependency_highlights,
             show_work_item_highlights,
+            pr_table_layout: PrTableLayout::default(),
+            pr_table_layout_path: None,
+            session_path: None,
+            shell_request: None,
             tagging_completed: false,
+            fan_out_results: Vec::new(),
+            package_version: None,
         }
     }
 
@@ -149,6 +241,246 @@ impl MergeApp {
         *self.config().run_hooks.value()
     }
 
+    /// Returns whether cherry-picked commits and the release tag are signed.
+    pub fn sign_commits(&self) -> bool {
+        self.config().sign_commits
+    }
+
+    /// Returns whether a merge commit whose first parent conflicts is retried
+    /// with its second parent.
+    pub fn mainline_fallback(&self) -> bool {
+        self.config().mainline_fallback
+    }
+
+    /// Returns the commit message template of squashed PRs, if squash mode is on.
+    pub fn squash_message(&self) -> Option<&str> {
+        self.config().squash_message.as_deref()
+    }
+
+    /// Squashes the commits picked for the cherry-pick item at `index` on
+    /// top of `base` into one commit (see [`CherryPickOperation::squash_pr`]).
+    pub fn squash_picked_pr(
+        &self,
+        repo_path: &Path,
+        base: &str,
+        index: usize,
+    ) -> CherryPickOutcome {
+        let Some(item) = self.cherry_pick_items().get(index) else {
+            return CherryPickOutcome::Success;
+        };
+        let work_item_ids: Vec<i32> = self
+            .pull_requests()
+            .iter()
+            .find(|pr| pr.pr.id == item.pr_id)
+            .map(|pr| pr.work_items.iter().map(|wi| wi.id).collect())
+            .unwrap_or_default();
+        CherryPickOperation::new(CherryPickConfig {
+            sign_commits: self.sign_commits(),
+            squash_message: self.squash_message().map(str::to_string),
+            ..Default::default()
+        })
+        .squash_pr(repo_path, base, item.pr_id, &item.pr_title, &work_item_ids)
+    }
+
+    /// Returns every target branch of a fan-out (empty for a single target).
+    pub fn fan_out(&self) -> &[String] {
+        &self.config().fan_out
+    }
+
+    /// Returns the version the worktree is named after, which in a fan-out
+    /// also names the current target branch.
+    pub fn worktree_version(&self) -> Option<String> {
+        let version = self.version()?;
+        Some(if self.fan_out().is_empty() {
+            version.to_string()
+        } else {
+            fan_out::worktree_version(version, self.target_branch())
+        })
+    }
+
+    /// Returns the fan-out target picked into after the current one, if any.
+    pub fn next_fan_out_target(&self) -> Option<&str> {
+        let fan_out = self.fan_out();
+        let current = fan_out
+            .iter()
+            .position(|target| target == self.target_branch())?;
+        fan_out.get(current + 1).map(String::as_str)
+    }
+
+    /// Returns the outcomes of the fan-out targets finished so far.
+    pub fn fan_out_results(&self) -> &[FanOutTargetResult] {
+        &self.fan_out_results
+    }
+
+    /// Finishes the merge into the current target and switches to `target`.
+    ///
+    /// The outcome of the current target is recorded and its worktree kept;
+    /// the cherry-pick queue is rebuilt by the next repository setup from the
+    /// same selected PRs.
+    pub fn advance_fan_out(&mut self, target: &str) {
+        let count = |matches: fn(&CherryPickStatus) -> bool| {
+            self.cherry_pick_items
+                .iter()
+                .filter(|item| matches(&item.status))
+                .count()
+        };
+        let result = FanOutTargetResult {
+            target_branch: self.target_branch().to_string(),
+            patch_branch: format!(
+                "patch/{}-{}",
+                self.target_branch(),
+                self.version().unwrap_or_default()
+            ),
+            picked: count(|status| matches!(status, CherryPickStatus::Success)),
+            failed: count(|status| {
+                matches!(
+                    status,
+                    CherryPickStatus::Failed(_) | CherryPickStatus::Conflict
+                )
+            }),
+            skipped: count(|status| matches!(status, CherryPickStatus::Skipped)),
+        };
+        self.fan_out_results.push(result);
+        self.finish_merge();
+
+        let mut config = MergeConfig::clone(self.config());
+        config.shared.target_branch = ParsedProperty::Default(target.to_string());
+        self.config = Arc::new(config);
+        self.cherry_pick_items.clear();
+        self.current_cherry_pick_index = 0;
+        self.set_repo_path(None);
+        self.worktree.base_repo_path = None;
+    }
+
+    /// Returns the known work item states when strict state checking is on.
+    ///
+    /// The target work item state is not included; callers treat it as known.
+    pub fn strict_states(&self) -> Option<&[String]> {
+        self.config().strict_states.as_deref()
+    }
+
+    /// Returns the release gate work items must pass to be bulk-selected.
+    pub fn release_gate(&self) -> Option<&ReleaseGate> {
+        self.config().release_gate.as_ref()
+    }
+
+    /// Returns whether all of the PR's work items pass the release gate.
+    ///
+    /// Always true when no gate is configured.
+    pub fn passes_release_gate(&self, pr: &PullRequestWithWorkItems) -> bool {
+        self.release_gate().is_none_or(|gate| gate.pr_passes(pr))
+    }
+
+    /// Returns whether PR builds and branch policies are checked.
+    pub fn build_check_mode(&self) -> BuildCheckMode {
+        self.config().build_checks
+    }
+
+    /// Returns whether the patch branch is tagged `v<version>` on completion.
+    pub fn release_tag_mode(&self) -> ReleaseTagMode {
+        self.config().release_tag
+    }
+
+    /// Returns whether the PR failed its build or branch policy checks.
+    pub fn fails_build_checks(&self, pr: &PullRequestWithWorkItems) -> bool {
+        !self.build_checks.passes(pr.pr.id)
+    }
+
+    /// Returns whether the PR is left out of the merge because green builds
+    /// are required and it failed its checks.
+    pub fn excluded_by_build_checks(&self, pr: &PullRequestWithWorkItems) -> bool {
+        self.build_check_mode().excludes_failing() && self.fails_build_checks(pr)
+    }
+
+    /// Returns the template for release comments on completed work items.
+    pub fn work_item_comment(&self) -> Option<&str> {
+        self.config().work_item_comment.as_deref()
+    }
+
+    /// Returns the templated fields written on released work items.
+    pub fn work_item_fields(&self) -> &[crate::core::operations::WorkItemFieldRule] {
+        &self.config().work_item_fields
+    }
+
+    /// Returns the chat webhooks notified when the merge finishes, if any.
+    pub fn notifications(&self) -> Option<&crate::notify::NotificationsConfig> {
+        self.config().notifications.as_ref()
+    }
+
+    /// Returns the feed package whose version is stamped on released work
+    /// items, if configured.
+    pub fn artifacts_feed(&self) -> Option<&ArtifactsFeed> {
+        self.config().artifacts_feed.as_ref()
+    }
+
+    /// Returns the policies resolving binary conflicts by path.
+    pub fn binary_conflicts(&self) -> &crate::core::operations::BinaryConflictPolicy {
+        &self.config().binary_conflicts
+    }
+
+    /// Returns the work item states whose PRs are hidden in PR selection.
+    pub fn terminal_states(&self) -> &[String] {
+        &self.config().terminal_states
+    }
+
+    /// Returns whether all of the PR's work items are in a terminal state.
+    ///
+    /// Always false when no terminal states are configured.
+    pub fn is_terminal(&self, pr: &PullRequestWithWorkItems) -> bool {
+        crate::core::operations::all_work_items_in_states(pr, self.terminal_states())
+    }
+
+    /// Returns the interval for refreshing the PR list during PR selection.
+    pub fn auto_refresh_interval(&self) -> Option<std::time::Duration> {
+        self.config().auto_refresh
+    }
+
+    /// Returns the `--select-file` path applied when the PR list is loaded.
+    pub fn select_file(&self) -> Option<&std::path::Path> {
+        self.config().select_file.as_deref()
+    }
+
+    /// Returns the name of the selection session, if one was given.
+    pub fn session_name(&self) -> Option<&str> {
+        self.config().session.as_deref()
+    }
+
+    /// Returns the session file of the PR selection, if any.
+    pub fn session_path(&self) -> Option<&Path> {
+        self.session_path.as_deref()
+    }
+
+    /// Restores the PR selection from `path` and keeps saving it there
+    /// (`None` stops saving).
+    pub fn set_session_path(&mut self, path: Option<PathBuf>) {
+        self.session_path = path;
+    }
+
+    /// Asks the run loop to suspend the TUI and open a shell in `dir`.
+    pub fn request_shell(&mut self, dir: PathBuf) {
+        self.shell_request = Some(dir);
+    }
+
+    /// Takes the directory of a requested shell, if any.
+    pub fn take_shell_request(&mut self) -> Option<PathBuf> {
+        self.shell_request.take()
+    }
+
+    /// Returns the configured PR grouping tabs.
+    pub fn pr_tabs(&self) -> &[crate::core::operations::PrTab] {
+        &self.config().pr_tabs
+    }
+
+    /// Returns the selected PR count above which cherry-picking needs confirmation.
+    pub fn selection_soft_cap(&self) -> Option<usize> {
+        self.config().selection_soft_cap
+    }
+
+    /// Returns how quick actions copy text.
+    pub fn clipboard_options(&self) -> &crate::utils::ClipboardOptions {
+        &self.config().clipboard
+    }
+
     /// Returns the current cherry-pick item, if any.
     pub fn current_cherry_pick(&self) -> Option<&CherryPickItem> {
         self.cherry_pick_items.get(self.current_cherry_pick_index)
@@ -204,6 +536,12 @@ impl MergeApp {
         Arc::clone(&self.state_manager)
     }
 
+    /// Sets the clock used for timestamps, including those of the state file.
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.state_manager.lock().unwrap().set_clock(clock.clone());
+        self.base.set_clock(clock);
+    }
+
     /// Creates a StateCreateConfig from the current app configuration.
     ///
     /// This extracts the configuration needed for state file creation from
@@ -219,6 +557,12 @@ impl MergeApp {
             tag_prefix: config.shared.tag_prefix.value().clone(),
             work_item_state: config.work_item_state.value().clone(),
             run_hooks: *config.run_hooks.value(),
+            sign_commits: config.sign_commits,
+            fan_out: config.fan_out.clone(),
+            squash_message: config.squash_message.clone(),
+            mainline_fallback: config.mainline_fallback,
+            // Created by the repository setup
+            run_dir: None,
         }
     }
 
@@ -307,6 +651,20 @@ impl MergeApp {
                 pr_title: item.pr_title.clone(),
                 status: cherry_pick_status_to_state(&item.status),
                 work_item_ids: vec![], // Work item IDs will be added by set_cherry_pick_items
+                work_item_titles: self
+                    .pull_requests()
+                    .iter()
+                    .find(|pr| pr.pr.id == item.pr_id)
+                    .map(PullRequestWithWorkItems::work_item_titles)
+                    .unwrap_or_default(),
+                picked_commit_id: None,
+                mainline_parent: None,
+                pr_closed_at: self
+                    .pull_requests()
+                    .iter()
+                    .find(|pr| pr.pr.id == item.pr_id)
+                    .and_then(|pr| pr.pr.closed_at()),
+                had_conflict: false,
             })
             .collect();
 
@@ -324,6 +682,77 @@ impl MergeApp {
         manager.update_item_status(index, status, self.current_cherry_pick_index)
     }
 
+    /// Records the patch branch `HEAD` as the commit picked for an item.
+    ///
+    /// Called right after a cherry-pick of the item succeeded.
+    pub fn record_state_picked_commit(&mut self, index: usize) -> Result<Option<PathBuf>> {
+        let Some(repo_path) = self.repo_path() else {
+            return Ok(None);
+        };
+        let commit_id = git::head_commit(repo_path)?;
+        let mut manager = self.state_manager.lock().unwrap();
+        manager.set_item_picked_commit(index, commit_id)
+    }
+
+    /// Returns the commit picked onto the patch branch for each PR, as
+    /// recorded in the state file.
+    pub fn picked_commits(&self) -> HashMap<i32, String> {
+        let manager = self.state_manager.lock().unwrap();
+        manager
+            .state_file()
+            .map(|state_file| {
+                state_file
+                    .cherry_pick_items
+                    .iter()
+                    .filter_map(|item| Some((item.pr_id, item.picked_commit_id.clone()?)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the package version found in the artifacts feed, if any.
+    pub fn package_version(&self) -> Option<&PackageVersion> {
+        self.package_version.as_ref()
+    }
+
+    /// Records the package version found in the artifacts feed, also in the
+    /// state file so release notes generated from it list the package.
+    pub fn set_package_version(&mut self, package: PackageVersion) {
+        self.with_state_file_mut(|state_file| {
+            state_file.package_version = Some(package.clone());
+            if let Err(e) = state_file.save_for_repo() {
+                tracing::warn!("Failed to save package version: {}", e);
+            }
+        });
+        self.package_version = Some(package);
+    }
+
+    /// Records the mainline parent a cherry-pick used instead of the first.
+    pub fn record_state_mainline_parent(
+        &mut self,
+        index: usize,
+        mainline_parent: u8,
+    ) -> Result<Option<PathBuf>> {
+        let mut manager = self.state_manager.lock().unwrap();
+        manager.set_item_mainline_parent(index, mainline_parent)
+    }
+
+    /// Returns the mainline parent used for each PR whose merge commit only
+    /// applied with its second parent, as recorded in the state file.
+    pub fn mainline_parents(&self) -> HashMap<i32, u8> {
+        let manager = self.state_manager.lock().unwrap();
+        manager
+            .state_file()
+            .map(|state_file| {
+                state_file
+                    .cherry_pick_items
+                    .iter()
+                    .filter_map(|item| Some((item.pr_id, item.mainline_parent?)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Syncs the current cherry-pick index to the state file.
     pub fn sync_state_current_index(&mut self) -> Result<Option<PathBuf>> {
         let mut manager = self.state_manager.lock().unwrap();
@@ -348,6 +777,24 @@ impl MergeApp {
         manager.cleanup()
     }
 
+    /// Marks the merge as completed, records it in the release history,
+    /// snapshots the state file to the run directory and removes it.
+    pub fn finish_merge(&mut self) {
+        let now = self.clock().now();
+        self.with_state_file_mut(|state_file| {
+            state_file.final_status = Some(MergeStatus::Success);
+            state_file.completed_at = Some(now);
+            let _ = state_file.save_for_repo();
+            if let Err(e) = release_history::record_release(state_file) {
+                tracing::warn!("Failed to record release history: {}", e);
+            }
+            if let Err(e) = state_file.save_run_snapshot() {
+                tracing::warn!("Failed to save run snapshot: {}", e);
+            }
+        });
+        let _ = self.cleanup_state_file();
+    }
+
     /// Returns the repo path from the state file, if any.
     pub fn state_repo_path(&self) -> Option<PathBuf> {
         let manager = self.state_manager.lock().unwrap();
@@ -369,15 +816,202 @@ impl MergeApp {
         self.dependency_graph.as_ref()
     }
 
+    /// Returns the PRs excluded by the `.mergers-ignore` skip list.
+    pub fn ignored_pull_requests(&self) -> &[IgnoredPullRequest] {
+        &self.ignored_pull_requests
+    }
+
+    /// Sets the PRs excluded by the `.mergers-ignore` skip list.
+    pub fn set_ignored_pull_requests(&mut self, ignored: Vec<IgnoredPullRequest>) {
+        self.ignored_pull_requests = ignored;
+    }
+
+    /// Returns the reverts found among the fetched PRs.
+    pub fn revert_index(&self) -> &RevertIndex {
+        &self.revert_index
+    }
+
+    /// Sets the reverts found among the fetched PRs.
+    pub fn set_revert_index(&mut self, reverts: RevertIndex) {
+        self.revert_index = reverts;
+    }
+
+    /// Returns the build and branch policy check results.
+    pub fn build_checks(&self) -> &BuildCheckIndex {
+        &self.build_checks
+    }
+
+    /// Adds build and branch policy check results, deselecting PRs they
+    /// exclude from the merge.
+    pub fn extend_build_checks(&mut self, checks: BuildCheckIndex) {
+        self.build_checks.extend(checks);
+        self.deselect_excluded_prs();
+    }
+
+    /// Deselects PRs excluded by the build checks (e.g. preselected ones).
+    pub fn deselect_excluded_prs(&mut self) {
+        if !self.build_check_mode().excludes_failing() {
+            return;
+        }
+        let checks = &self.build_checks;
+        for pr in self.base.pull_requests.iter_mut() {
+            if !checks.passes(pr.pr.id) {
+                pr.selected = false;
+            }
+        }
+    }
+
+    /// Returns the schema mismatches found while loading.
+    pub fn schema_warnings(&self) -> &[SchemaWarning] {
+        &self.schema_warnings
+    }
+
+    /// Sets the schema mismatches found while loading.
+    pub fn set_schema_warnings(&mut self, warnings: Vec<SchemaWarning>) {
+        self.schema_warnings = warnings;
+    }
+
+    /// Returns the PRs to mark as selected once the PR list is loaded.
+    pub fn preselected_pr_ids(&self) -> &HashSet<i32> {
+        &self.preselected_pr_ids
+    }
+
+    /// Sets the PRs to mark as selected once the PR list is loaded.
+    pub fn set_preselected_pr_ids(&mut self, ids: HashSet<i32>) {
+        self.preselected_pr_ids = ids;
+    }
+
+    /// Returns the author, reviewer and path filter of the PR list.
+    pub fn pr_filter(&self) -> &PrFilter {
+        &self.pr_filter
+    }
+
+    /// Replaces the PR list filter.
+    ///
+    /// Identity ids are filtered by Azure DevOps, so the client lists PRs
+    /// with the new ones from now on. Returns `true` if they changed and the
+    /// PR list must be reloaded.
+    pub fn set_pr_filter(&mut self, filter: PrFilter) -> bool {
+        let ids_changed = filter.creator_id() != self.pr_filter.creator_id()
+            || filter.reviewer_id() != self.pr_filter.reviewer_id();
+        if ids_changed {
+            self.base.client = self.base.client.clone().with_pull_request_identities(
+                filter.creator_id().map(str::to_string),
+                filter.reviewer_id().map(str::to_string),
+            );
+        }
+        self.pr_filter = filter;
+        ids_changed
+    }
+
     /// Sets the dependency graph after analysis.
+    ///
+    /// Cancels any refinement of the previous graph.
     pub fn set_dependency_graph(&mut self, graph: PRDependencyGraph) {
         self.dependency_graph = Some(graph);
+        self.dependency_refinement = None;
+        self.update_dependency_order();
+    }
+
+    /// Recomputes the cherry-pick order from the dependency graph.
+    ///
+    /// Only applies with the topological cherry-pick order; otherwise, or
+    /// without a graph, selected PRs stay in closed-date order.
+    fn update_dependency_order(&mut self) {
+        let order = match (self.config().cherry_pick_order, &self.dependency_graph) {
+            (CherryPickOrder::Topological, Some(graph)) => {
+                let mut prs: Vec<&PullRequestWithWorkItems> = self
+                    .pull_requests()
+                    .iter()
+                    .filter(|pr| pr.pr.closed_date.is_some())
+                    .collect();
+                prs.sort_by_key(|pr| pr.pr.closed_date.clone());
+                let by_date: Vec<i32> = prs.iter().map(|pr| pr.pr.id).collect();
+                CherryPickOperation::new(CherryPickConfig {
+                    order: CherryPickOrder::Topological,
+                    ..Default::default()
+                })
+                .order(&by_date, Some(graph))
+            }
+            _ => Vec::new(),
+        };
+        self.base.set_dependency_order(order);
+    }
+
+    /// Returns whether the merge runs without a local repository.
+    ///
+    /// The features in [`LOCAL_REPO_FEATURES`] are off in this mode.
+    pub fn is_degraded(&self) -> bool {
+        self.local_repo().is_none()
+    }
+
+    /// Uses `path` as the local repository from now on and analyzes the
+    /// dependencies of the loaded PRs with it.
+    ///
+    /// Leaves degraded mode without restarting; the merge then creates its
+    /// worktree in `path` instead of cloning.
+    pub fn set_local_repo(&mut self, path: &Path) {
+        let value = path.display().to_string();
+        Arc::make_mut(&mut self.base.config).shared.local_repo =
+            Some(ParsedProperty::Cli(value.clone(), value));
+        self.refine_dependency_graph();
+    }
+
+    /// Starts refining the dependency graph with line-range analysis.
+    ///
+    /// The graph from data loading only compares changed files, so the PR
+    /// list is usable right away. The refined graph replaces it once
+    /// [`Self::poll_dependency_refinement`] finds the analysis finished.
+    /// Does nothing without a local repository.
+    pub fn refine_dependency_graph(&mut self) {
+        let Some(repo_path) = self.local_repo().map(PathBuf::from) else {
+            return;
+        };
+        let prs = self.pull_requests().clone();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let task_cancel = Arc::clone(&cancel);
+        let task = tokio::task::spawn_blocking(move || {
+            DependencyAnalyzer::new()
+                .analyze_repository(&repo_path, &prs, |_, _, _| {}, &task_cancel)
+                .map(|result| result.graph)
+        });
+        self.dependency_refinement = Some(DependencyRefinement { task, cancel });
+    }
+
+    /// Returns whether line-range dependency analysis is still running.
+    pub fn is_refining_dependencies(&self) -> bool {
+        self.dependency_refinement.is_some()
+    }
+
+    /// Applies the refined dependency graph once its analysis has finished.
+    ///
+    /// Called on every tick. Returns `true` if the graph was replaced.
+    pub async fn poll_dependency_refinement(&mut self) -> bool {
+        let Some(mut refinement) = self
+            .dependency_refinement
+            .take_if(|refinement| refinement.task.is_finished())
+        else {
+            return false;
+        };
+        match (&mut refinement.task).await {
+            Ok(Some(graph)) => {
+                self.dependency_graph = Some(graph);
+                self.update_dependency_order();
+                true
+            }
+            Ok(None) => false,
+            Err(e) => {
+                tracing::warn!("Line-range dependency analysis failed: {}", e);
+                false
+            }
+        }
     }
 
     /// Clears the cached dependency graph.
     #[allow(dead_code)]
     pub fn clear_dependency_graph(&mut self) {
         self.dependency_graph = None;
+        self.update_dependency_order();
     }
 
     // ==========================================================================
@@ -416,6 +1050,37 @@ impl MergeApp {
         self.show_work_item_highlights
     }
 
+    /// Returns the column widths and sort order of the PR selection table.
+    pub fn pr_table_layout(&self) -> &PrTableLayout {
+        &self.pr_table_layout
+    }
+
+    /// Returns the PR table layout for changing widths or sort keys.
+    ///
+    /// Call [`Self::save_pr_table_layout`] afterwards to persist the change.
+    pub fn pr_table_layout_mut(&mut self) -> &mut PrTableLayout {
+        &mut self.pr_table_layout
+    }
+
+    /// Loads the PR table layout from `path` and saves later changes there.
+    ///
+    /// A file that cannot be read is logged and replaced by the default layout.
+    pub fn load_pr_table_layout(&mut self, path: PathBuf) {
+        self.pr_table_layout = PrTableLayout::load(&path).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load PR table layout: {:#}", e);
+            PrTableLayout::default()
+        });
+        self.pr_table_layout_path = Some(path);
+    }
+
+    /// Saves the PR table layout, if a layout file was loaded.
+    pub fn save_pr_table_layout(&self) -> Result<()> {
+        match &self.pr_table_layout_path {
+            Some(path) => self.pr_table_layout.save(path),
+            None => Ok(()),
+        }
+    }
+
     /// Saves the current UI settings to the config file.
     ///
     /// This persists the highlight settings so they are restored on next run.
@@ -485,6 +1150,7 @@ mod tests {
                 project: ParsedProperty::Default("test_project".to_string()),
                 repository: ParsedProperty::Default("test_repo".to_string()),
                 pat: ParsedProperty::Default("test_pat".to_string()),
+                write_pat: None,
                 dev_branch: ParsedProperty::Default("develop".to_string()),
                 target_branch: ParsedProperty::Default("main".to_string()),
                 local_repo: None,
@@ -493,9 +1159,36 @@ mod tests {
                 max_concurrent_processing: ParsedProperty::Default(10),
                 tag_prefix: ParsedProperty::Default("merged/".to_string()),
                 since: None,
+                since_field: Default::default(),
                 skip_confirmation: false,
+                api_version: None,
+                history_depth: None,
+                url_templates: Default::default(),
+                release_notes_locale: Default::default(),
             },
             work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+            strict_states: None,
+            release_gate: None,
+            build_checks: Default::default(),
+            release_tag: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            pr_tabs: Vec::new(),
+            selection_soft_cap: None,
+            terminal_states: Vec::new(),
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            notifications: None,
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            pr_filter: Default::default(),
+            session: None,
+            clipboard: Default::default(),
             run_hooks: ParsedProperty::Default(false),
         })
     }
@@ -574,6 +1267,7 @@ mod tests {
                 project: ParsedProperty::Default("test_project".to_string()),
                 repository: ParsedProperty::Default("test_repo".to_string()),
                 pat: ParsedProperty::Default("test_pat".to_string()),
+                write_pat: None,
                 dev_branch: ParsedProperty::Default("develop".to_string()),
                 target_branch: ParsedProperty::Default("main".to_string()),
                 local_repo: None,
@@ -582,9 +1276,36 @@ mod tests {
                 max_concurrent_processing: ParsedProperty::Default(10),
                 tag_prefix: ParsedProperty::Default("merged/".to_string()),
                 since: None,
+                since_field: Default::default(),
                 skip_confirmation: false,
+                api_version: None,
+                history_depth: None,
+                url_templates: Default::default(),
+                release_notes_locale: Default::default(),
             },
             work_item_state: ParsedProperty::Default("Custom State".to_string()),
+            strict_states: None,
+            release_gate: None,
+            build_checks: Default::default(),
+            release_tag: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            pr_tabs: Vec::new(),
+            selection_soft_cap: None,
+            terminal_states: Vec::new(),
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            notifications: None,
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            pr_filter: Default::default(),
+            session: None,
+            clipboard: Default::default(),
             run_hooks: ParsedProperty::Default(false),
         });
 
@@ -801,4 +1522,78 @@ mod tests {
         assert!(app.show_dependency_highlights());
         assert!(app.show_work_item_highlights());
     }
+
+    /// # Topological Cherry-Pick Order
+    ///
+    /// Tests that the dependency graph decides the cherry-pick order when the
+    /// topological order is configured.
+    ///
+    /// ## Test Scenario
+    /// - Loads three selected PRs; the oldest depends on the newest
+    /// - Sets and clears the dependency graph
+    ///
+    /// ## Expected Outcome
+    /// - With the graph, the dependency is picked before its dependent
+    /// - Without it, the PRs are picked by closed date
+    #[test]
+    fn test_topological_cherry_pick_order() {
+        use crate::core::operations::{DependencyCategory, PRDependency, PRDependencyNode};
+        use crate::models::{CreatedBy, PullRequest};
+
+        let mut config = (*create_test_config()).clone();
+        config.cherry_pick_order = CherryPickOrder::Topological;
+        let mut app = MergeApp::new(
+            Arc::new(config),
+            create_test_client(),
+            Box::new(MockBrowserOpener::new()),
+        );
+        *app.pull_requests_mut() = (1..=3)
+            .map(|id| PullRequestWithWorkItems {
+                pr: PullRequest {
+                    id,
+                    title: format!("PR {}", id),
+                    description: None,
+                    closed_date: Some(format!("2024-01-0{}T10:00:00Z", id)),
+                    created_by: CreatedBy {
+                        display_name: "Test User".to_string(),
+                    },
+                    last_merge_commit: None,
+                    labels: None,
+                    reviewers: Vec::new(),
+                },
+                work_items: Vec::new(),
+                selected: true,
+            })
+            .collect();
+
+        let mut graph = PRDependencyGraph::new();
+        for id in 1..=3 {
+            graph.add_node(PRDependencyNode::new(id, format!("PR {}", id), true));
+        }
+        graph
+            .get_node_mut(1)
+            .unwrap()
+            .dependencies
+            .push(PRDependency {
+                from_pr_id: 1,
+                to_pr_id: 3,
+                category: DependencyCategory::PartiallyDependent {
+                    shared_files: vec!["src/lib.rs".to_string()],
+                },
+            });
+        graph.get_node_mut(3).unwrap().dependents.push(1);
+        graph.topological_order = vec![3, 2, 1];
+        let order = |app: &MergeApp| {
+            app.get_selected_prs()
+                .iter()
+                .map(|pr| pr.pr.id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(order(&app), [1, 2, 3]);
+        app.set_dependency_graph(graph);
+        assert_eq!(order(&app), [3, 2, 1]);
+        app.clear_dependency_graph();
+        assert_eq!(order(&app), [1, 2, 3]);
+    }
 }
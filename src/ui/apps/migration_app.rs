@@ -242,6 +242,7 @@ mod tests {
                 project: ParsedProperty::Default("test_project".to_string()),
                 repository: ParsedProperty::Default("test_repo".to_string()),
                 pat: ParsedProperty::Default("test_pat".to_string()),
+                write_pat: None,
                 dev_branch: ParsedProperty::Default("develop".to_string()),
                 target_branch: ParsedProperty::Default("main".to_string()),
                 local_repo: None,
@@ -250,7 +251,12 @@ mod tests {
                 max_concurrent_processing: ParsedProperty::Default(10),
                 tag_prefix: ParsedProperty::Default("merged/".to_string()),
                 since: None,
+                since_field: Default::default(),
                 skip_confirmation: false,
+                api_version: None,
+                history_depth: None,
+                url_templates: Default::default(),
+                release_notes_locale: Default::default(),
             },
             terminal_states: ParsedProperty::Default(vec![
                 "Closed".to_string(),
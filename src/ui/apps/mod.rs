@@ -14,5 +14,5 @@ mod merge_app;
 mod migration_app;
 
 pub use cleanup_app::CleanupApp;
-pub use merge_app::MergeApp;
+pub use merge_app::{LOCAL_REPO_FEATURES, MergeApp};
 pub use migration_app::MigrationApp;
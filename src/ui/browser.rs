@@ -29,12 +29,12 @@ impl BrowserOpener for SystemBrowserOpener {
 }
 
 /// Mock implementation for tests that tracks opened URLs without launching browsers.
-#[cfg(test)]
+#[cfg(any(test, feature = "testing"))]
 pub struct MockBrowserOpener {
     pub opened_urls: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "testing"))]
 impl Default for MockBrowserOpener {
     fn default() -> Self {
         Self {
@@ -43,7 +43,7 @@ impl Default for MockBrowserOpener {
     }
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "testing"))]
 impl MockBrowserOpener {
     pub fn new() -> Self {
         Self::default()
@@ -54,7 +54,7 @@ impl MockBrowserOpener {
     }
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "testing"))]
 impl BrowserOpener for MockBrowserOpener {
     fn open_url(&self, url: &str) {
         self.opened_urls.lock().unwrap().push(url.to_string());
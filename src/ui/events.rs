@@ -51,7 +51,7 @@ impl EventSource for CrosstermEventSource {
     }
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "testing"))]
 pub mod testing {
     //! Test utilities for mocking terminal events.
 
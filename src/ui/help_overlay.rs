@@ -0,0 +1,244 @@
+//! Contextual `?` help overlay.
+//!
+//! The overlay is owned by the typed run loops and drawn on top of whatever
+//! state is active. Its contents are generated from the state's
+//! [`ActionMap`], so it lists exactly the keys the state currently handles.
+
+use crate::ui::keymap::{ActionMap, HELP_KEY, format_key};
+use crossterm::event::KeyCode;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+/// Visibility and scroll state of the help overlay.
+#[derive(Debug, Default, Clone)]
+pub struct HelpOverlay {
+    visible: bool,
+    scroll: u16,
+}
+
+impl HelpOverlay {
+    /// Creates a hidden overlay.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the overlay is currently shown.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Handles a key press, returning `true` if the overlay consumed it.
+    ///
+    /// While visible, `↑`/`k` and `↓`/`j` scroll and every other key closes
+    /// the overlay. While hidden, only [`HELP_KEY`] is consumed, and only
+    /// when the active state is not capturing text input.
+    pub fn handle_key(&mut self, code: KeyCode, map: &ActionMap) -> bool {
+        if self.visible {
+            match code {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.scroll = self.scroll.saturating_sub(1);
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.scroll = self.scroll.saturating_add(1);
+                }
+                _ => {
+                    self.visible = false;
+                }
+            }
+            return true;
+        }
+
+        if code == HELP_KEY && !map.captures_text_input() {
+            self.visible = true;
+            self.scroll = 0;
+            return true;
+        }
+
+        false
+    }
+
+    /// Builds the overlay lines for an action map.
+    pub fn lines(map: &ActionMap) -> Vec<Line<'static>> {
+        let key_style = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let header_style = Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD);
+
+        let key_width = map
+            .actions()
+            .iter()
+            .map(|a| a.key_label().chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(format_key(HELP_KEY).len());
+
+        let mut lines = Vec::new();
+        for (category, actions) in map.grouped() {
+            lines.push(Line::from(Span::styled(category.title(), header_style)));
+            for action in actions {
+                lines.push(Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled(
+                        format!("{:<width$}", action.key_label(), width = key_width),
+                        key_style,
+                    ),
+                    Span::raw("  "),
+                    Span::raw(action.description),
+                ]));
+            }
+            lines.push(Line::from(""));
+        }
+
+        if map.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No keys are active in this screen.",
+                Style::default().fg(Color::DarkGray),
+            )));
+            lines.push(Line::from(""));
+        }
+
+        lines.push(Line::from(vec![
+            Span::raw("  "),
+            Span::styled(
+                format!("{:<width$}", format_key(HELP_KEY), width = key_width),
+                key_style,
+            ),
+            Span::raw("  Show/hide this help"),
+        ]));
+
+        lines
+    }
+
+    /// Renders the overlay if it is visible.
+    pub fn render(&self, f: &mut Frame, map: &ActionMap) {
+        if !self.visible {
+            return;
+        }
+
+        let area = f.area();
+        let lines = Self::lines(map);
+        let popup_width = 64u16.min(area.width.saturating_sub(4));
+        let popup_height = (lines.len() as u16 + 2).min(area.height.saturating_sub(2));
+        let popup_area = Rect::new(
+            area.x + (area.width.saturating_sub(popup_width)) / 2,
+            area.y + (area.height.saturating_sub(popup_height)) / 2,
+            popup_width,
+            popup_height,
+        );
+
+        f.render_widget(Clear, popup_area);
+
+        let title = if map.title().is_empty() {
+            " Help ".to_string()
+        } else {
+            format!(" Help: {} ", map.title())
+        };
+        let help = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan))
+                    .title(title)
+                    .title_bottom(
+                        Line::from(" Esc/? close, ↑/↓ scroll ").alignment(Alignment::Right),
+                    ),
+            )
+            .style(Style::default().bg(Color::Black))
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll, 0));
+        f.render_widget(help, popup_area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::keymap::ActionCategory;
+    use crate::ui::snapshot_testing::with_settings_and_module_path;
+    use insta::assert_snapshot;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    fn sample_map() -> ActionMap {
+        ActionMap::new("Sample")
+            .bind(
+                ActionCategory::Navigation,
+                &[KeyCode::Up, KeyCode::Down],
+                "Move highlight",
+            )
+            .bind(ActionCategory::Selection, &[KeyCode::Char(' ')], "Toggle")
+            .bind(ActionCategory::General, &[KeyCode::Char('q')], "Quit")
+    }
+
+    /// # Help Overlay Toggle
+    ///
+    /// Tests opening, scrolling and closing the overlay.
+    ///
+    /// ## Test Scenario
+    /// - Presses '?' to open, ↓ to scroll, Esc to close
+    /// - Presses an unrelated key while hidden
+    ///
+    /// ## Expected Outcome
+    /// - '?' opens, all keys are consumed while visible, Esc closes
+    /// - Unrelated keys are not consumed while hidden
+    #[test]
+    fn test_help_overlay_toggle() {
+        let map = sample_map();
+        let mut overlay = HelpOverlay::new();
+
+        assert!(!overlay.handle_key(KeyCode::Char('q'), &map));
+        assert!(overlay.handle_key(KeyCode::Char('?'), &map));
+        assert!(overlay.is_visible());
+        assert!(overlay.handle_key(KeyCode::Down, &map));
+        assert!(overlay.is_visible());
+        assert!(overlay.handle_key(KeyCode::Esc, &map));
+        assert!(!overlay.is_visible());
+    }
+
+    /// # Help Overlay Ignored During Text Input
+    ///
+    /// Tests that '?' is passed through when the state captures text.
+    ///
+    /// ## Test Scenario
+    /// - Presses '?' with a text-input action map
+    ///
+    /// ## Expected Outcome
+    /// - The key is not consumed and the overlay stays hidden
+    #[test]
+    fn test_help_overlay_text_input() {
+        let map = ActionMap::new("Input").with_text_input();
+        let mut overlay = HelpOverlay::new();
+
+        assert!(!overlay.handle_key(KeyCode::Char('?'), &map));
+        assert!(!overlay.is_visible());
+    }
+
+    /// # Help Overlay Rendering
+    ///
+    /// Tests the rendered overlay for a sample action map.
+    ///
+    /// ## Test Scenario
+    /// - Opens the overlay and renders it into an 80x20 terminal
+    ///
+    /// ## Expected Outcome
+    /// - Categories are listed in order with aligned key labels
+    #[test]
+    fn test_help_overlay_render() {
+        with_settings_and_module_path(module_path!(), || {
+            let map = sample_map();
+            let mut overlay = HelpOverlay::new();
+            overlay.handle_key(KeyCode::Char('?'), &map);
+
+            let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+            terminal.draw(|f| overlay.render(f, &map)).unwrap();
+
+            assert_snapshot!("sample", terminal.backend());
+        });
+    }
+}
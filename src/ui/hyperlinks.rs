@@ -0,0 +1,335 @@
+//! Clickable terminal hyperlinks (OSC 8) for PR and work item ids.
+//!
+//! Terminals supporting the OSC 8 escape sequence show text wrapped in it as
+//! a link, so PR numbers and work item ids can be opened with a click instead
+//! of the `p`/`w` keys. Support is detected from the environment; terminals
+//! that do not understand the sequence would print it literally, so links are
+//! only written where support is known. `MERGERS_HYPERLINKS` overrides the
+//! detection:
+//!
+//! - `auto` (default): detect from `TERM_PROGRAM`, `VTE_VERSION` and the
+//!   variables terminals set for their sessions
+//! - `always`: write links in any terminal
+//! - `never`: never write links
+//!
+//! # Rendering
+//!
+//! Ratatui has no notion of links, so they are added to the rendered frame:
+//! the first cell of the linked text holds the whole text wrapped in the
+//! escape sequence and the cells it covers are skipped when the frame is
+//! drawn.
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use std::borrow::Cow;
+
+/// Environment variable overriding hyperlink detection.
+pub const HYPERLINKS_ENV: &str = "MERGERS_HYPERLINKS";
+
+/// Starts a link to the URL that follows; `OSC_END` ends it.
+const OSC_START: &str = "\x1B]8;;";
+const OSC_END: &str = "\x1B\\";
+
+/// `TERM_PROGRAM` values of terminals supporting OSC 8.
+const SUPPORTING_PROGRAMS: &[&str] = &[
+    "iTerm.app",
+    "WezTerm",
+    "vscode",
+    "ghostty",
+    "Hyper",
+    "Tabby",
+    "rio",
+];
+
+/// Variables set by terminals supporting OSC 8 for their sessions.
+const SUPPORTING_SESSION_VARS: &[&str] = &[
+    "WT_SESSION",
+    "KITTY_WINDOW_ID",
+    "KONSOLE_VERSION",
+    "ALACRITTY_WINDOW_ID",
+    "WEZTERM_PANE",
+];
+
+/// `TERM` values of terminals supporting OSC 8.
+const SUPPORTING_TERMS: &[&str] = &[
+    "xterm-kitty",
+    "xterm-ghostty",
+    "wezterm",
+    "foot",
+    "alacritty",
+];
+
+/// First VTE version (0.50) supporting OSC 8.
+const MIN_VTE_VERSION: u32 = 5000;
+
+/// Hyperlink settings for the interactive screens.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Hyperlinks {
+    /// Write PR and work item ids as terminal hyperlinks.
+    pub enabled: bool,
+}
+
+impl Hyperlinks {
+    /// Reads the setting from `MERGERS_HYPERLINKS`, detecting support from
+    /// the terminal when it is unset or `auto`.
+    pub fn from_env() -> Self {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Self {
+        let setting = var(HYPERLINKS_ENV).map(|v| v.trim().to_ascii_lowercase());
+        let enabled = match setting.as_deref() {
+            Some("always" | "1" | "true" | "yes" | "on") => true,
+            Some("never" | "0" | "false" | "no" | "off") => false,
+            _ => terminal_supports_hyperlinks(&var),
+        };
+        Self { enabled }
+    }
+
+    /// Links the first occurrence of `text` in `area` to `url`.
+    ///
+    /// Only whole tokens match: `#12` is not found in `#123`. Returns where
+    /// the linked text starts, or `None` if links are disabled or the text is
+    /// not shown in `area`.
+    pub fn link(&self, buffer: &mut Buffer, area: Rect, text: &str, url: &str) -> Option<Position> {
+        if !self.enabled {
+            return None;
+        }
+        let position = find_text(buffer, area, text)?;
+        let width = text.chars().count() as u16;
+        link_cells(buffer, position, width, url);
+        Some(position)
+    }
+}
+
+/// Returns true if the terminal described by `var` is known to support
+/// OSC 8 hyperlinks.
+///
+/// Multiplexers are left out: tmux and screen only pass the sequence on when
+/// configured to, so `MERGERS_HYPERLINKS=always` has to be set there.
+fn terminal_supports_hyperlinks(var: &impl Fn(&str) -> Option<String>) -> bool {
+    let term = var("TERM").unwrap_or_default();
+    if term == "dumb" || term.starts_with("screen") || term.starts_with("tmux") {
+        return false;
+    }
+    if var("TMUX").is_some() || var("STY").is_some() {
+        return false;
+    }
+    if var("TERM_PROGRAM").is_some_and(|program| SUPPORTING_PROGRAMS.contains(&program.as_str())) {
+        return true;
+    }
+    if SUPPORTING_SESSION_VARS
+        .iter()
+        .any(|name| var(name).is_some_and(|v| !v.is_empty()))
+    {
+        return true;
+    }
+    if var("VTE_VERSION")
+        .and_then(|v| v.trim().parse::<u32>().ok())
+        .is_some_and(|version| version >= MIN_VTE_VERSION)
+    {
+        return true;
+    }
+    SUPPORTING_TERMS.contains(&term.as_str())
+}
+
+/// Finds the first whole-token occurrence of `text` in `area`, row by row.
+///
+/// A token is not preceded or followed by a letter or digit. Cells already
+/// holding a link never match.
+pub fn find_text(buffer: &Buffer, area: Rect, text: &str) -> Option<Position> {
+    let area = area.intersection(buffer.area);
+    let chars: Vec<char> = text.chars().collect();
+    let width = chars.len() as u16;
+    if chars.is_empty() || width > area.width {
+        return None;
+    }
+    let is_word = |x: u16, y: u16| {
+        buffer[(x, y)]
+            .symbol()
+            .chars()
+            .last()
+            .is_some_and(char::is_alphanumeric)
+    };
+    for y in area.top()..area.bottom() {
+        for x in area.left()..=area.right() - width {
+            let matches = chars.iter().enumerate().all(|(i, &c)| {
+                let cell = &buffer[(x + i as u16, y)];
+                !cell.skip && cell.symbol().chars().eq(std::iter::once(c))
+            });
+            let bounded = (x == area.left() || !is_word(x - 1, y))
+                && (x + width == area.right() || !is_word(x + width, y));
+            if matches && bounded {
+                return Some(Position::new(x, y));
+            }
+        }
+    }
+    None
+}
+
+/// Turns `width` cells starting at `position` into a link to `url`.
+///
+/// The first cell holds the text of all cells; the others are skipped when
+/// drawing. When a frame is drawn, only the cell right after a symbol wider
+/// than one column is left out, so single-cell text takes the following
+/// cell into the link; at the end of a row it stays unlinked.
+fn link_cells(buffer: &mut Buffer, position: Position, width: u16, url: &str) {
+    let mut width = width;
+    if width == 1 {
+        if position.x + 1 >= buffer.area.right() {
+            return;
+        }
+        width = 2;
+    }
+    let text: String = (0..width)
+        .map(|i| buffer[(position.x + i, position.y)].symbol())
+        .collect();
+    buffer[position].set_symbol(&format!(
+        "{OSC_START}{url}{OSC_END}{text}{OSC_START}{OSC_END}"
+    ));
+    for i in 1..width {
+        buffer[(position.x + i, position.y)].set_skip(true);
+    }
+}
+
+/// Removes hyperlink escape sequences from a cell symbol, leaving the text.
+pub fn strip_hyperlinks(symbol: &str) -> Cow<'_, str> {
+    if !symbol.contains(OSC_START) {
+        return Cow::Borrowed(symbol);
+    }
+    let mut text = String::new();
+    let mut rest = symbol;
+    while let Some(start) = rest.find(OSC_START) {
+        text.push_str(&rest[..start]);
+        let after = &rest[start + OSC_START.len()..];
+        rest = match after.find(OSC_END) {
+            Some(end) => &after[end + OSC_END.len()..],
+            None => "",
+        };
+    }
+    text.push_str(rest);
+    Cow::Owned(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Style;
+    use std::collections::HashMap;
+
+    fn settings(vars: &[(&str, &str)]) -> Hyperlinks {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        Hyperlinks::from_vars(|name| vars.get(name).cloned())
+    }
+
+    fn buffer(line: &str) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 2));
+        buffer.set_string(0, 0, line, Style::default());
+        buffer
+    }
+
+    const ENABLED: Hyperlinks = Hyperlinks { enabled: true };
+
+    /// # Hyperlink Detection
+    ///
+    /// Tests detecting OSC 8 support from environment variables.
+    ///
+    /// ## Test Scenario
+    /// - Known terminals, unknown terminals, multiplexers, and the
+    ///   `MERGERS_HYPERLINKS` override
+    ///
+    /// ## Expected Outcome
+    /// - Known terminals and recent VTE enable links
+    /// - Unknown terminals, old VTE, tmux and dumb terminals do not
+    /// - `always` and `never` override the detection
+    #[test]
+    fn test_hyperlinks_from_vars() {
+        assert!(!settings(&[]).enabled);
+        assert!(!settings(&[("TERM", "xterm-256color")]).enabled);
+        assert!(settings(&[("TERM_PROGRAM", "iTerm.app")]).enabled);
+        assert!(settings(&[("TERM_PROGRAM", "WezTerm")]).enabled);
+        assert!(settings(&[("WT_SESSION", "abc")]).enabled);
+        assert!(settings(&[("TERM", "xterm-kitty")]).enabled);
+        assert!(settings(&[("VTE_VERSION", "7600")]).enabled);
+        assert!(!settings(&[("VTE_VERSION", "4600")]).enabled);
+        assert!(!settings(&[("TERM_PROGRAM", "vscode"), ("TMUX", "/tmp/tmux")]).enabled);
+        assert!(!settings(&[("TERM_PROGRAM", "iTerm.app"), ("TERM", "dumb")]).enabled);
+
+        assert!(settings(&[(HYPERLINKS_ENV, "always"), ("TMUX", "/tmp/tmux")]).enabled);
+        assert!(settings(&[(HYPERLINKS_ENV, "1")]).enabled);
+        assert!(!settings(&[(HYPERLINKS_ENV, "never"), ("TERM_PROGRAM", "WezTerm")]).enabled);
+        assert!(settings(&[(HYPERLINKS_ENV, "auto"), ("TERM_PROGRAM", "WezTerm")]).enabled);
+    }
+
+    /// # Finding Link Text
+    ///
+    /// Tests locating whole tokens in a rendered buffer.
+    ///
+    /// ## Test Scenario
+    /// - Searches for ids that are part of longer numbers and ids that stand
+    ///   alone, inside and outside the searched area
+    ///
+    /// ## Expected Outcome
+    /// - Only whole tokens inside the area are found
+    #[test]
+    fn test_find_text() {
+        let buffer = buffer("PR #1234: fix 123");
+
+        assert_eq!(
+            find_text(&buffer, buffer.area, "#1234"),
+            Some(Position::new(3, 0))
+        );
+        assert_eq!(
+            find_text(&buffer, buffer.area, "123"),
+            Some(Position::new(14, 0))
+        );
+        assert_eq!(find_text(&buffer, buffer.area, "#123"), None);
+        assert_eq!(find_text(&buffer, Rect::new(0, 0, 10, 2), "123"), None);
+        assert_eq!(find_text(&buffer, buffer.area, ""), None);
+    }
+
+    /// # Linking Cells
+    ///
+    /// Tests wrapping rendered text in an OSC 8 hyperlink.
+    ///
+    /// ## Test Scenario
+    /// - Links an id with links disabled, then enabled, then a single-cell id
+    ///
+    /// ## Expected Outcome
+    /// - Disabled links leave the buffer unchanged
+    /// - The first cell holds the linked text, the covered cells are skipped
+    /// - Single-cell text takes the following cell into the link
+    /// - Stripping the escape sequences gives back the text
+    #[test]
+    fn test_link() {
+        let url = "https://example.com/pr/1234";
+        let mut buffer = buffer("PR #1234: x 7");
+        let original = buffer.clone();
+
+        assert_eq!(
+            Hyperlinks::default().link(&mut buffer, original.area, "#1234", url),
+            None
+        );
+        assert_eq!(buffer, original);
+
+        let position = ENABLED.link(&mut buffer, original.area, "#1234", url);
+        assert_eq!(position, Some(Position::new(3, 0)));
+        assert_eq!(
+            buffer[(3, 0)].symbol(),
+            "\x1B]8;;https://example.com/pr/1234\x1B\\#1234\x1B]8;;\x1B\\"
+        );
+        assert!((4..8).all(|x| buffer[(x, 0)].skip));
+        assert!(!buffer[(8, 0)].skip);
+        assert_eq!(strip_hyperlinks(buffer[(3, 0)].symbol()), "#1234");
+
+        // Already linked text is not found again
+        assert_eq!(ENABLED.link(&mut buffer, original.area, "#1234", url), None);
+
+        ENABLED.link(&mut buffer, original.area, "7", url);
+        assert_eq!(strip_hyperlinks(buffer[(12, 0)].symbol()), "7 ");
+        assert!(buffer[(13, 0)].skip);
+    }
+}
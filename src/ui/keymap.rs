@@ -0,0 +1,342 @@
+//! Key action maps describing the bindings available in each UI state.
+//!
+//! Every state exposes an [`ActionMap`] through
+//! [`ModeState::action_map`](crate::ui::state::ModeState::action_map). The map
+//! lists the keys the state currently reacts to, grouped into
+//! [`ActionCategory`] sections. The `?` help overlay is generated from these
+//! maps, so the key reference always reflects the state (and sub-mode, such as
+//! search or a dialog) the user is looking at.
+//!
+//! The run loops only pass a key press on to the state when the map
+//! [handles](ActionMap::handles) it, so a key missing from the map does
+//! nothing.
+//!
+//! # Example
+//!
+//! ```rust
+//! use crossterm::event::KeyCode;
+//! use mergers::ui::keymap::{ActionCategory, ActionMap};
+//!
+//! let map = ActionMap::new("Pull Request Selection")
+//!     .bind(ActionCategory::Navigation, &[KeyCode::Up, KeyCode::Down], "Move highlight")
+//!     .bind(ActionCategory::General, &[KeyCode::Char('q')], "Quit");
+//!
+//! assert_eq!(map.lookup(KeyCode::Down).unwrap().description, "Move highlight");
+//! assert_eq!(map.actions()[0].key_label(), "↑/↓");
+//! ```
+
+use crossterm::event::KeyCode;
+
+/// Key that toggles the help overlay in every state.
+pub const HELP_KEY: KeyCode = KeyCode::Char('?');
+
+/// Section of the help overlay an action is listed under.
+///
+/// Categories are rendered in declaration order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ActionCategory {
+    /// Moving the highlight, scrolling and switching tabs.
+    Navigation,
+    /// Selecting and deselecting items.
+    Selection,
+    /// Toggling panes, dialogs and other presentation changes.
+    View,
+    /// Actions that change application state or open external resources.
+    Actions,
+    /// Confirming, going back and quitting.
+    General,
+}
+
+impl ActionCategory {
+    /// All categories in display order.
+    pub const ALL: [ActionCategory; 5] = [
+        ActionCategory::Navigation,
+        ActionCategory::Selection,
+        ActionCategory::View,
+        ActionCategory::Actions,
+        ActionCategory::General,
+    ];
+
+    /// Section title shown in the help overlay.
+    pub fn title(self) -> &'static str {
+        match self {
+            ActionCategory::Navigation => "Navigation",
+            ActionCategory::Selection => "Selection",
+            ActionCategory::View => "View",
+            ActionCategory::Actions => "Actions",
+            ActionCategory::General => "General",
+        }
+    }
+}
+
+/// A single user action and the keys bound to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyAction {
+    /// Section this action belongs to.
+    pub category: ActionCategory,
+    /// Keys that trigger the action. The first key is the primary binding.
+    ///
+    /// Empty for an action triggered by any key, see
+    /// [`ActionMap::bind_any_key`].
+    pub keys: Vec<KeyCode>,
+    /// Human readable description of the action.
+    pub description: &'static str,
+}
+
+impl KeyAction {
+    /// Returns `true` if `code` triggers this action.
+    pub fn matches(&self, code: KeyCode) -> bool {
+        self.keys.is_empty() || self.keys.contains(&code)
+    }
+
+    /// Formats all keys of this action for display, e.g. `↑/k`.
+    pub fn key_label(&self) -> String {
+        if self.keys.is_empty() {
+            return "Any key".to_string();
+        }
+        self.keys
+            .iter()
+            .map(|k| format_key(*k))
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+/// The set of actions available in a state.
+///
+/// Built with [`ActionMap::new`] and [`ActionMap::bind`]. States that accept
+/// free-form text (version input, search input, path input) should call
+/// [`ActionMap::with_text_input`] so that `?` is typed instead of opening the
+/// help overlay.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActionMap {
+    title: &'static str,
+    actions: Vec<KeyAction>,
+    text_input: bool,
+}
+
+impl ActionMap {
+    /// Creates an empty action map with the given overlay title.
+    pub fn new(title: &'static str) -> Self {
+        Self {
+            title,
+            actions: Vec::new(),
+            text_input: false,
+        }
+    }
+
+    /// Adds an action bound to one or more keys.
+    pub fn bind(
+        mut self,
+        category: ActionCategory,
+        keys: &[KeyCode],
+        description: &'static str,
+    ) -> Self {
+        self.actions.push(KeyAction {
+            category,
+            keys: keys.to_vec(),
+            description,
+        });
+        self
+    }
+
+    /// Adds an action triggered by every key not bound before it.
+    ///
+    /// Used for "press any key to continue" prompts; bind it last so the
+    /// other actions keep their keys.
+    pub fn bind_any_key(self, category: ActionCategory, description: &'static str) -> Self {
+        self.bind(category, &[], description)
+    }
+
+    /// Marks the state as capturing text input.
+    pub fn with_text_input(mut self) -> Self {
+        self.text_input = true;
+        self
+    }
+
+    /// Title shown in the help overlay.
+    pub fn title(&self) -> &'static str {
+        self.title
+    }
+
+    /// All actions in insertion order.
+    pub fn actions(&self) -> &[KeyAction] {
+        &self.actions
+    }
+
+    /// Returns `true` if no actions are bound.
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// Returns `true` if the state is currently capturing text input.
+    pub fn captures_text_input(&self) -> bool {
+        self.text_input
+    }
+
+    /// Finds the first action bound to `code`.
+    pub fn lookup(&self, code: KeyCode) -> Option<&KeyAction> {
+        self.actions.iter().find(|a| a.matches(code))
+    }
+
+    /// Returns `true` if a press of `code` should reach the state.
+    ///
+    /// States capturing text input receive every key; otherwise the key must
+    /// be bound to an action.
+    pub fn handles(&self, code: KeyCode) -> bool {
+        self.text_input || self.lookup(code).is_some()
+    }
+
+    /// Groups actions by category in display order, skipping empty categories.
+    pub fn grouped(&self) -> Vec<(ActionCategory, Vec<&KeyAction>)> {
+        ActionCategory::ALL
+            .iter()
+            .filter_map(|category| {
+                let actions: Vec<&KeyAction> = self
+                    .actions
+                    .iter()
+                    .filter(|a| a.category == *category)
+                    .collect();
+                (!actions.is_empty()).then_some((*category, actions))
+            })
+            .collect()
+    }
+}
+
+/// Formats a key code the way it is shown in help text.
+pub fn format_key(code: KeyCode) -> String {
+    match code {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::BackTab => "Shift+Tab".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::Delete => "Del".to_string(),
+        KeyCode::Home => "Home".to_string(),
+        KeyCode::End => "End".to_string(),
+        KeyCode::PageUp => "PgUp".to_string(),
+        KeyCode::PageDown => "PgDn".to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        other => format!("{:?}", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// # Key Formatting
+    ///
+    /// Tests the display labels of common key codes.
+    ///
+    /// ## Test Scenario
+    /// - Formats characters, space, arrows and named keys
+    ///
+    /// ## Expected Outcome
+    /// - Labels match those used in the single-line help bars
+    #[test]
+    fn test_format_key() {
+        assert_eq!(format_key(KeyCode::Char('q')), "q");
+        assert_eq!(format_key(KeyCode::Char(' ')), "Space");
+        assert_eq!(format_key(KeyCode::Up), "↑");
+        assert_eq!(format_key(KeyCode::BackTab), "Shift+Tab");
+        assert_eq!(format_key(KeyCode::F(5)), "F5");
+    }
+
+    /// # Action Map Lookup And Grouping
+    ///
+    /// Tests key lookup and category grouping of an action map.
+    ///
+    /// ## Test Scenario
+    /// - Builds a map with actions in mixed category order
+    /// - Looks up bound and unbound keys
+    /// - Groups the actions
+    ///
+    /// ## Expected Outcome
+    /// - Bound keys resolve to their action, unbound keys to `None`
+    /// - Groups follow category display order and skip empty categories
+    #[test]
+    fn test_action_map_lookup_and_grouping() {
+        let map = ActionMap::new("Test")
+            .bind(ActionCategory::General, &[KeyCode::Char('q')], "Quit")
+            .bind(
+                ActionCategory::Navigation,
+                &[KeyCode::Up, KeyCode::Char('k')],
+                "Move up",
+            )
+            .bind(ActionCategory::Selection, &[KeyCode::Char(' ')], "Toggle");
+
+        assert_eq!(map.title(), "Test");
+        assert!(!map.captures_text_input());
+        assert_eq!(
+            map.lookup(KeyCode::Char('k')).unwrap().description,
+            "Move up"
+        );
+        assert!(map.lookup(KeyCode::Char('x')).is_none());
+        assert_eq!(map.actions()[1].key_label(), "↑/k");
+
+        let grouped = map.grouped();
+        let categories: Vec<ActionCategory> = grouped.iter().map(|(c, _)| *c).collect();
+        assert_eq!(
+            categories,
+            vec![
+                ActionCategory::Navigation,
+                ActionCategory::Selection,
+                ActionCategory::General
+            ]
+        );
+    }
+
+    /// # Text Input Flag
+    ///
+    /// Tests that text-input maps are flagged.
+    ///
+    /// ## Test Scenario
+    /// - Builds a map with `with_text_input`
+    ///
+    /// ## Expected Outcome
+    /// - `captures_text_input` returns true
+    #[test]
+    fn test_text_input_flag() {
+        let map = ActionMap::new("Input").with_text_input();
+        assert!(map.captures_text_input());
+        assert!(map.is_empty());
+        assert!(map.handles(KeyCode::Char('x')));
+    }
+
+    /// # Handled Keys
+    ///
+    /// Tests which key presses a map lets through to its state.
+    ///
+    /// ## Test Scenario
+    /// - Builds a map with a bound key, then adds an any-key action
+    ///
+    /// ## Expected Outcome
+    /// - Only the bound key is handled without the any-key action
+    /// - With it every key is handled, bound keys still resolve to their own
+    ///   action and the any-key action is labelled "Any key"
+    #[test]
+    fn test_handles() {
+        let map =
+            ActionMap::new("Test").bind(ActionCategory::General, &[KeyCode::Char('q')], "Quit");
+        assert!(map.handles(KeyCode::Char('q')));
+        assert!(!map.handles(KeyCode::Char('x')));
+        assert!(!map.handles(KeyCode::Enter));
+
+        let map = map.bind_any_key(ActionCategory::General, "Continue");
+        assert!(map.handles(KeyCode::Char('x')));
+        assert!(map.handles(KeyCode::Enter));
+        assert_eq!(map.lookup(KeyCode::Char('q')).unwrap().description, "Quit");
+        assert_eq!(
+            map.lookup(KeyCode::Char('x')).unwrap().description,
+            "Continue"
+        );
+        assert_eq!(map.actions()[1].key_label(), "Any key");
+    }
+}
@@ -5,27 +5,39 @@ use state::{
     MigrationDataLoadingState, MigrationModeState, SettingsConfirmationState,
 };
 
+mod accessibility;
 mod app;
 mod app_base;
 mod app_mode;
 pub mod apps;
 pub mod browser;
 mod events;
-#[cfg(test)]
+mod help_overlay;
+mod hyperlinks;
+pub mod keymap;
+mod render_throttle;
+mod screen_dump;
+mod shell;
+#[cfg(any(test, feature = "testing"))]
 pub mod snapshot_testing;
 pub mod state;
-#[cfg(test)]
+#[cfg(any(test, feature = "testing"))]
 pub mod testing;
 pub mod typed_run;
 mod worktree_context;
 
+pub use accessibility::{Accessibility, Announcer};
 pub use app::App;
 pub use app_base::AppBase;
 pub use app_mode::AppMode;
 pub use apps::{CleanupApp, MergeApp, MigrationApp};
-#[cfg(test)]
+#[cfg(any(test, feature = "testing"))]
 pub use events::testing::MockEventSource;
 pub use events::{CrosstermEventSource, EventSource};
+pub use help_overlay::HelpOverlay;
+pub use hyperlinks::Hyperlinks;
+pub use render_throttle::RenderThrottle;
+pub use screen_dump::ScreenDump;
 pub use worktree_context::WorktreeContext;
 
 /// Run the application loop with an injectable event source.
@@ -66,9 +78,13 @@ where
                 MergeState::DataLoading(DataLoadingState::new())
             } else {
                 let app_config = typed_config.to_app_config();
-                MergeState::SettingsConfirmation(Box::new(SettingsConfirmationState::new(
-                    app_config,
-                )))
+                let state_config = merge_app.state_create_config();
+                MergeState::SettingsConfirmation(Box::new(
+                    SettingsConfirmationState::new(app_config).with_previous_run(
+                        crate::core::state::previous_run(&state_config),
+                        &crate::core::state::RunSettings::new(&state_config, merge_app.version()),
+                    ),
+                ))
             };
             typed_run::run_merge_mode(terminal, merge_app, event_source, initial_state).await
         }
@@ -0,0 +1,169 @@
+//! Render coalescing for the TUI run loops.
+//!
+//! The run loops used to redraw on every event and every poll tick. With a
+//! [`RenderThrottle`] they only redraw when something changed — an input
+//! event or a state transition — at most `max_fps` times per second, plus a
+//! slow idle refresh so progress that advances without a state change (a
+//! gauge fed by a background task, a notice timing out) still shows up.
+//!
+//! The frame cap is read from `MERGERS_MAX_FPS` (default 30).
+
+use std::time::{Duration, Instant};
+
+/// Environment variable overriding the frame cap.
+pub const MAX_FPS_ENV: &str = "MERGERS_MAX_FPS";
+
+/// Frame cap used when `MERGERS_MAX_FPS` is unset or invalid.
+pub const DEFAULT_MAX_FPS: u32 = 30;
+
+/// Longest time between two frames when nothing is marked dirty.
+const IDLE_REFRESH: Duration = Duration::from_millis(200);
+
+/// Decides when the run loop redraws.
+#[derive(Debug, Clone)]
+pub struct RenderThrottle {
+    min_frame: Duration,
+    dirty: bool,
+    last_draw: Option<Instant>,
+}
+
+impl RenderThrottle {
+    /// Creates a throttle drawing at most `max_fps` frames per second.
+    ///
+    /// The first frame is always drawn.
+    pub fn new(max_fps: u32) -> Self {
+        Self {
+            min_frame: Duration::from_secs(1) / max_fps.max(1),
+            dirty: true,
+            last_draw: None,
+        }
+    }
+
+    /// Creates a throttle capped at `MERGERS_MAX_FPS`, or [`DEFAULT_MAX_FPS`].
+    pub fn from_env() -> Self {
+        let max_fps = std::env::var(MAX_FPS_ENV)
+            .ok()
+            .and_then(|v| v.trim().parse::<u32>().ok())
+            .filter(|fps| (1..=240).contains(fps))
+            .unwrap_or(DEFAULT_MAX_FPS);
+        Self::new(max_fps)
+    }
+
+    /// Records that the screen content may have changed.
+    pub fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Returns `true` if a frame should be drawn now.
+    pub fn should_draw(&self) -> bool {
+        self.should_draw_at(Instant::now())
+    }
+
+    /// Records that a frame was just drawn.
+    pub fn drew(&mut self) {
+        self.drew_at(Instant::now());
+    }
+
+    /// Returns how long to wait for input before the next tick.
+    ///
+    /// This is `tick`, shortened so a pending frame is not delayed.
+    pub fn poll_timeout(&self, tick: Duration) -> Duration {
+        self.poll_timeout_at(Instant::now(), tick)
+    }
+
+    fn should_draw_at(&self, now: Instant) -> bool {
+        match self.last_draw {
+            None => true,
+            Some(last) => {
+                let elapsed = now.saturating_duration_since(last);
+                (self.dirty && elapsed >= self.min_frame) || elapsed >= IDLE_REFRESH
+            }
+        }
+    }
+
+    fn drew_at(&mut self, now: Instant) {
+        self.dirty = false;
+        self.last_draw = Some(now);
+    }
+
+    fn poll_timeout_at(&self, now: Instant, tick: Duration) -> Duration {
+        let Some(last) = self.last_draw else {
+            return Duration::ZERO;
+        };
+        let next_frame = if self.dirty {
+            self.min_frame
+        } else {
+            IDLE_REFRESH
+        };
+        tick.min(next_frame.saturating_sub(now.saturating_duration_since(last)))
+    }
+}
+
+impl Default for RenderThrottle {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_FPS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// # Frame Coalescing
+    ///
+    /// Tests when the throttle allows a frame.
+    ///
+    /// ## Test Scenario
+    /// - Draws a first frame, then checks clean and dirty states at several
+    ///   times after it with a 10 FPS cap
+    ///
+    /// ## Expected Outcome
+    /// - The first frame is always drawn
+    /// - A dirty screen is drawn once the frame interval passed, not before
+    /// - A clean screen is only redrawn after the idle refresh interval
+    #[test]
+    fn test_frame_coalescing() {
+        let start = Instant::now();
+        let mut throttle = RenderThrottle::new(10);
+        assert!(throttle.should_draw_at(start));
+        throttle.drew_at(start);
+
+        let ms = |n| start + Duration::from_millis(n);
+        assert!(!throttle.should_draw_at(ms(150)));
+        assert!(throttle.should_draw_at(ms(200)));
+
+        throttle.mark_dirty();
+        assert!(!throttle.should_draw_at(ms(50)));
+        assert!(throttle.should_draw_at(ms(100)));
+        throttle.drew_at(ms(100));
+        assert!(!throttle.should_draw_at(ms(250)));
+    }
+
+    /// # Poll Timeout
+    ///
+    /// Tests that input polling wakes up in time for the next frame.
+    ///
+    /// ## Test Scenario
+    /// - Asks for the poll timeout before any frame, while dirty and while
+    ///   clean, with a 50ms tick and a 30 FPS cap
+    ///
+    /// ## Expected Outcome
+    /// - No wait before the first frame
+    /// - A dirty screen waits only until the next allowed frame
+    /// - A clean screen waits a full tick
+    #[test]
+    fn test_poll_timeout() {
+        let start = Instant::now();
+        let tick = Duration::from_millis(50);
+        let mut throttle = RenderThrottle::new(30);
+        assert_eq!(throttle.poll_timeout_at(start, tick), Duration::ZERO);
+
+        throttle.drew_at(start);
+        throttle.mark_dirty();
+        let timeout = throttle.poll_timeout_at(start + Duration::from_millis(10), tick);
+        assert!(timeout > Duration::from_millis(20) && timeout < Duration::from_millis(25));
+
+        throttle.drew_at(start);
+        assert_eq!(throttle.poll_timeout_at(start, tick), tick);
+    }
+}
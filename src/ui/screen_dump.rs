@@ -0,0 +1,347 @@
+//! `Ctrl+S` plain-text snapshot of the terminal UI.
+//!
+//! The snapshot holds the rendered frame as plain text, without colors or
+//! styles, followed by a short summary of the app state. It is written to a
+//! file in the state directory (see [`state_dir`]) so it can be attached to a
+//! bug report instead of a screenshot. The PAT is never included.
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    Frame,
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::Span,
+    widgets::{Clear, Paragraph},
+};
+
+use crate::core::state::state_dir;
+use crate::models::AppModeConfig;
+use crate::ui::AppBase;
+use crate::ui::hyperlinks::strip_hyperlinks;
+
+/// How long the confirmation line stays on screen.
+const NOTICE_DURATION: Duration = Duration::from_secs(4);
+
+/// Handles `Ctrl+S` snapshots and shows where the last one was written.
+#[derive(Debug, Default)]
+pub struct ScreenDump {
+    notice: Option<(String, bool, Instant)>,
+}
+
+impl ScreenDump {
+    /// Creates a dump handler with no notice shown.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if `key` is `Ctrl+S`.
+    pub fn is_dump_key(key: &KeyEvent) -> bool {
+        key.code == KeyCode::Char('s') && key.modifiers.contains(KeyModifiers::CONTROL)
+    }
+
+    /// Writes a snapshot of `buffer` and the app state, then shows the file
+    /// path (or the error) at the bottom of the screen.
+    pub fn save<C: AppModeConfig>(&mut self, buffer: &Buffer, state_name: &str, app: &AppBase<C>) {
+        let contents = format!(
+            "{}\n{}",
+            buffer_text(buffer),
+            summary(state_name, app, buffer.area)
+        );
+        let file_name = format!(
+            "ui-snapshot-{}.txt",
+            app.clock().local_now().format("%Y%m%d-%H%M%S")
+        );
+        self.notice = Some(match write_snapshot(&file_name, &contents) {
+            Ok(path) => (
+                format!(" UI snapshot saved to {} ", path.display()),
+                true,
+                Instant::now(),
+            ),
+            Err(e) => (
+                format!(" Failed to save UI snapshot: {:#} ", e),
+                false,
+                Instant::now(),
+            ),
+        });
+    }
+
+    /// Renders the notice on the last line while it is recent.
+    pub fn render(&self, f: &mut Frame) {
+        let Some((message, ok, shown_at)) = &self.notice else {
+            return;
+        };
+        if shown_at.elapsed() > NOTICE_DURATION {
+            return;
+        }
+
+        let area = f.area();
+        if area.height == 0 {
+            return;
+        }
+        let line_area = Rect::new(area.x, area.y + area.height - 1, area.width, 1);
+        let color = if *ok { Color::Green } else { Color::Red };
+
+        f.render_widget(Clear, line_area);
+        f.render_widget(
+            Paragraph::new(message.as_str()).style(Style::default().fg(Color::Black).bg(color)),
+            line_area,
+        );
+    }
+}
+
+/// Converts a rendered buffer to plain text, one line per row.
+///
+/// Cells covered by a wide character are skipped, hyperlinks are reduced to
+/// their text, and trailing spaces are trimmed.
+pub fn buffer_text(buffer: &Buffer) -> String {
+    let mut text = String::new();
+    for row in buffer.content.chunks(buffer.area.width.max(1) as usize) {
+        let mut line = String::new();
+        let mut skip = 0usize;
+        for cell in row {
+            let symbol = strip_hyperlinks(cell.symbol());
+            if skip == 0 {
+                line.push_str(&symbol);
+            }
+            skip = skip.max(Span::raw(symbol).width()).saturating_sub(1);
+        }
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+    text
+}
+
+/// Summarizes the app state for a snapshot.
+pub fn summary<C: AppModeConfig>(state_name: &str, app: &AppBase<C>, area: Rect) -> String {
+    let selected = app.get_selected_prs().len();
+    let mut out = String::from("--- mergers UI snapshot ---\n");
+    let _ = writeln!(out, "mergers: {}", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(out, "time: {}", app.clock().local_now().to_rfc3339());
+    let _ = writeln!(out, "terminal: {}x{}", area.width, area.height);
+    let _ = writeln!(out, "screen: {}", state_name);
+    let _ = writeln!(
+        out,
+        "repository: {}/{}/{}",
+        app.organization(),
+        app.project(),
+        app.repository()
+    );
+    let _ = writeln!(
+        out,
+        "branches: {} -> {}",
+        app.dev_branch(),
+        app.target_branch()
+    );
+    if let Some(version) = app.version() {
+        let _ = writeln!(out, "version: {}", version);
+    }
+    if let Some(path) = app.repo_path() {
+        let _ = writeln!(out, "local repo: {}", path.display());
+    }
+    let _ = writeln!(
+        out,
+        "pull requests: {} loaded, {} selected",
+        app.pull_requests().len(),
+        selected
+    );
+    if let Some(error) = app.error_message() {
+        let _ = writeln!(out, "error: {}", error);
+    }
+    out
+}
+
+/// Writes `contents` to `file_name` in the state directory.
+fn write_snapshot(file_name: &str, contents: &str) -> Result<PathBuf> {
+    let dir = state_dir()?;
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create state directory {}", dir.display()))?;
+    let path = dir.join(file_name);
+    std::fs::write(&path, contents)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::AzureDevOpsClient;
+    use crate::core::state::STATE_DIR_ENV;
+    use crate::models::{MergeConfig, SharedConfig};
+    use crate::parsed_property::ParsedProperty;
+    use crate::ui::browser::MockBrowserOpener;
+    use crate::utils::{Clock, FixedClock};
+    use chrono::{TimeZone, Utc};
+    use ratatui::{
+        Terminal,
+        backend::TestBackend,
+        widgets::{Block, Borders},
+    };
+    use serial_test::file_serial;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    fn create_app() -> AppBase<MergeConfig> {
+        let config = Arc::new(MergeConfig {
+            shared: SharedConfig {
+                organization: ParsedProperty::Default("test_org".to_string()),
+                project: ParsedProperty::Default("test_project".to_string()),
+                repository: ParsedProperty::Default("test_repo".to_string()),
+                pat: ParsedProperty::Default("secret_pat".to_string()),
+                write_pat: None,
+                dev_branch: ParsedProperty::Default("dev".to_string()),
+                target_branch: ParsedProperty::Default("main".to_string()),
+                local_repo: None,
+                parallel_limit: ParsedProperty::Default(4),
+                max_concurrent_network: ParsedProperty::Default(10),
+                max_concurrent_processing: ParsedProperty::Default(5),
+                tag_prefix: ParsedProperty::Default("merged/".to_string()),
+                since: None,
+                since_field: Default::default(),
+                skip_confirmation: false,
+                api_version: None,
+                history_depth: None,
+                url_templates: Default::default(),
+                release_notes_locale: Default::default(),
+            },
+            work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+            strict_states: None,
+            release_gate: None,
+            build_checks: Default::default(),
+            release_tag: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            pr_tabs: Vec::new(),
+            selection_soft_cap: None,
+            terminal_states: Vec::new(),
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            notifications: None,
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            pr_filter: Default::default(),
+            session: None,
+            clipboard: Default::default(),
+            run_hooks: ParsedProperty::Default(false),
+        });
+        let client = AzureDevOpsClient::new(
+            "test_org".to_string(),
+            "test_project".to_string(),
+            "test_repo".to_string(),
+            "secret_pat".to_string(),
+        )
+        .unwrap();
+        AppBase::new(config, client, Box::new(MockBrowserOpener::new()))
+    }
+
+    /// # Dump Key
+    ///
+    /// Tests recognizing the snapshot key.
+    ///
+    /// ## Test Scenario
+    /// - Checks `Ctrl+S`, plain `s` and `Ctrl+Q`
+    ///
+    /// ## Expected Outcome
+    /// - Only `Ctrl+S` is the dump key
+    #[test]
+    fn test_is_dump_key() {
+        assert!(ScreenDump::is_dump_key(&KeyEvent::new(
+            KeyCode::Char('s'),
+            KeyModifiers::CONTROL
+        )));
+        assert!(!ScreenDump::is_dump_key(&KeyEvent::new(
+            KeyCode::Char('s'),
+            KeyModifiers::NONE
+        )));
+        assert!(!ScreenDump::is_dump_key(&KeyEvent::new(
+            KeyCode::Char('q'),
+            KeyModifiers::CONTROL
+        )));
+    }
+
+    /// # Buffer Text
+    ///
+    /// Tests converting a rendered frame to plain text.
+    ///
+    /// ## Test Scenario
+    /// - Renders a bordered block with a title and a wide character
+    ///
+    /// ## Expected Outcome
+    /// - One line per row, trailing spaces trimmed, wide characters not padded
+    #[test]
+    fn test_buffer_text() {
+        let mut terminal = Terminal::new(TestBackend::new(12, 3)).unwrap();
+        let frame = terminal
+            .draw(|f| {
+                f.render_widget(
+                    Block::default().borders(Borders::ALL).title("日本"),
+                    f.area(),
+                );
+            })
+            .unwrap();
+
+        assert_eq!(
+            buffer_text(frame.buffer),
+            "┌日本──────┐\n│          │\n└──────────┘\n"
+        );
+    }
+
+    /// # Snapshot File
+    ///
+    /// Tests writing a snapshot to the state directory.
+    ///
+    /// ## Test Scenario
+    /// - Saves a snapshot of a rendered frame with the state dir overridden
+    ///
+    /// ## Expected Outcome
+    /// - One file is written containing the frame and the state summary
+    /// - The file name and time come from the app's clock
+    /// - The PAT does not appear in the file
+    #[test]
+    #[file_serial(state_env)]
+    fn test_save_snapshot() {
+        let temp_dir = TempDir::new().unwrap();
+        unsafe { std::env::set_var(STATE_DIR_ENV, temp_dir.path()) };
+
+        let clock = FixedClock::new(Utc.with_ymd_and_hms(2025, 1, 15, 12, 0, 0).unwrap());
+        let mut app = create_app();
+        app.set_clock(Arc::new(clock));
+        let mut terminal = Terminal::new(TestBackend::new(20, 3)).unwrap();
+        let frame = terminal
+            .draw(|f| f.render_widget(Paragraph::new("PR list"), f.area()))
+            .unwrap();
+        let mut dump = ScreenDump::new();
+        dump.save(frame.buffer, "PullRequestSelection", &app);
+
+        unsafe { std::env::remove_var(STATE_DIR_ENV) };
+
+        let files: Vec<_> = std::fs::read_dir(temp_dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        assert_eq!(files.len(), 1);
+        let local_now = clock.local_now();
+        assert_eq!(
+            files[0].file_name().unwrap().to_string_lossy(),
+            format!("ui-snapshot-{}.txt", local_now.format("%Y%m%d-%H%M%S"))
+        );
+        let contents = std::fs::read_to_string(&files[0]).unwrap();
+        assert!(contents.starts_with("PR list\n"));
+        assert!(contents.contains(&format!("time: {}", local_now.to_rfc3339())));
+        assert!(contents.contains("screen: PullRequestSelection"));
+        assert!(contents.contains("repository: test_org/test_project/test_repo"));
+        assert!(contents.contains("pull requests: 0 loaded, 0 selected"));
+        assert!(!contents.contains("secret_pat"));
+        assert!(matches!(dump.notice, Some((_, true, _))));
+    }
+}
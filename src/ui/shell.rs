@@ -0,0 +1,55 @@
+//! Dropping to an interactive shell from the TUI.
+//!
+//! The terminal is handed back to the shell while it runs: raw mode, the
+//! alternate screen and mouse capture are turned off, and turned on again
+//! once the shell exits.
+
+use std::ffi::OsString;
+use std::io;
+use std::path::Path;
+use std::process::{Command, ExitStatus};
+
+use anyhow::{Context, Result};
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+
+/// Returns the shell to start: `$SHELL` (`%COMSPEC%` on Windows), or the
+/// platform's default shell.
+fn shell_program() -> OsString {
+    let (var, default) = if cfg!(windows) {
+        ("COMSPEC", "cmd")
+    } else {
+        ("SHELL", "sh")
+    };
+    std::env::var_os(var)
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| default.into())
+}
+
+/// Suspends the TUI, runs an interactive shell in `dir` and restores the TUI
+/// once the shell exits.
+///
+/// The caller must clear the terminal afterwards so the next frame is drawn
+/// in full.
+pub fn run_shell(dir: &Path) -> Result<ExitStatus> {
+    let mut stdout = io::stdout();
+    disable_raw_mode()?;
+    execute!(stdout, LeaveAlternateScreen, DisableMouseCapture)?;
+    println!(
+        "Starting a shell in {}. Exit it to return to mergers.",
+        dir.display()
+    );
+
+    let program = shell_program();
+    let status = Command::new(&program)
+        .current_dir(dir)
+        .status()
+        .with_context(|| format!("Failed to start shell {}", program.to_string_lossy()));
+
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    enable_raw_mode()?;
+    status
+}
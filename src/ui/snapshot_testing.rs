@@ -1,3 +1,8 @@
+//! Snapshot test configuration for TUI states.
+//!
+//! Stores insta snapshots in a directory tree mirroring the module path of
+//! the test. Available to other crates with the `testing` feature.
+
 use insta::Settings;
 use std::path::Path;
 
@@ -21,6 +26,19 @@ where
 
 /// Internal function that handles the actual configuration
 pub fn with_settings_and_module_path<F>(module_path: &str, f: F)
+where
+    F: FnOnce(),
+{
+    let snapshot_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/ui/snapshots");
+    with_settings_in_dir(&snapshot_root, module_path, f);
+}
+
+/// Configure snapshot path under a caller-provided snapshot root.
+///
+/// Crates using the `testing` feature call this with their own directory,
+/// e.g. `Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshots")`, so that
+/// snapshots of their states are stored in their own tree.
+pub fn with_settings_in_dir<F>(snapshot_root: &Path, module_path: &str, f: F)
 where
     F: FnOnce(),
 {
@@ -31,11 +49,7 @@ where
     // becomes "state/shared/settings_confirmation"
     let path = module_path_to_snapshot_dir(module_path);
 
-    let snapshot_path = Path::new(env!("CARGO_MANIFEST_DIR"))
-        .join("src/ui/snapshots")
-        .join(path);
-
-    settings.set_snapshot_path(snapshot_path);
+    settings.set_snapshot_path(snapshot_root.join(path));
 
     // Disable module prefix since directory structure provides context
     settings.set_prepend_module_to_snapshot(false);
@@ -101,4 +115,27 @@ mod tests {
             assert_eq!(result, expected, "Failed for input: {}", input);
         }
     }
+
+    /// # Snapshot Settings In Custom Directory
+    ///
+    /// Tests configuring snapshots under a caller-provided root.
+    ///
+    /// ## Test Scenario
+    /// - Applies settings for a downstream module path under a custom root
+    ///
+    /// ## Expected Outcome
+    /// - The snapshot path is the root joined with the module directory
+    /// - Module prefixes are disabled
+    #[test]
+    fn test_with_settings_in_dir() {
+        let root = Path::new("/tmp/plugin-snapshots");
+        with_settings_in_dir(root, "my_plugin::states::review::tests", || {
+            let settings = Settings::clone_current();
+            assert_eq!(
+                settings.snapshot_path(),
+                root.join("my_plugin/states/review")
+            );
+            assert!(!settings.prepend_module_to_snapshot());
+        });
+    }
 }
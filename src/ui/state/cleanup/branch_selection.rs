@@ -1,8 +1,9 @@
 use super::CleanupModeState;
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::{
     ui::apps::CleanupApp,
-    ui::state::CleanupExecutionState,
     ui::state::typed::{ModeState, StateChange},
+    ui::state::{CleanupExecutionState, CleanupReleaseLabelsState},
 };
 use async_trait::async_trait;
 use crossterm::event::KeyCode;
@@ -71,7 +72,7 @@ impl CleanupBranchSelectionState {
 
     fn select_all_merged(&mut self, app: &mut CleanupApp) {
         for branch in app.cleanup_branches_mut() {
-            if branch.is_merged {
+            if branch.is_safe_to_delete() {
                 branch.selected = true;
             }
         }
@@ -88,6 +89,15 @@ impl CleanupBranchSelectionState {
     }
 }
 
+/// Renders a merge check result; `None` means the check could not be made.
+fn merge_status_span(merged: Option<bool>) -> Span<'static> {
+    match merged {
+        Some(true) => Span::styled("Merged", Style::default().fg(Color::Green)),
+        Some(false) => Span::styled("Not Merged", Style::default().fg(Color::Yellow)),
+        None => Span::styled("Unknown", Style::default().fg(Color::DarkGray)),
+    }
+}
+
 // ============================================================================
 // ModeState Implementation
 // ============================================================================
@@ -123,7 +133,7 @@ impl ModeState for CleanupBranchSelectionState {
         f.render_widget(title, chunks[0]);
 
         // Branch table
-        let header_cells = ["", "Branch", "Target", "Version", "Status"]
+        let header_cells = ["", "Branch", "Target", "Version", "Local", "Origin"]
             .iter()
             .map(|h| {
                 Cell::from(*h).style(
@@ -136,18 +146,16 @@ impl ModeState for CleanupBranchSelectionState {
 
         let rows = app.cleanup_branches().iter().map(|branch| {
             let checkbox = if branch.selected { "☑" } else { "☐" };
-            let status = if branch.is_merged {
-                Span::styled("Merged", Style::default().fg(Color::Green))
-            } else {
-                Span::styled("Not Merged", Style::default().fg(Color::Yellow))
-            };
+            let local_status = merge_status_span(Some(branch.is_merged));
+            let remote_status = merge_status_span(branch.is_merged_remote);
 
             let cells = vec![
                 Cell::from(checkbox),
                 Cell::from(branch.name.as_str()),
                 Cell::from(branch.target.as_str()),
                 Cell::from(branch.version.as_str()),
-                Cell::from(status),
+                Cell::from(local_status),
+                Cell::from(remote_status),
             ];
 
             Row::new(cells).height(1)
@@ -160,7 +168,8 @@ impl ModeState for CleanupBranchSelectionState {
                 Constraint::Min(30),
                 Constraint::Length(15),
                 Constraint::Length(15),
-                Constraint::Length(15),
+                Constraint::Length(12),
+                Constraint::Length(12),
             ],
         )
         .header(header)
@@ -214,6 +223,13 @@ impl ModeState for CleanupBranchSelectionState {
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::raw(": Proceed to cleanup  "),
+                Span::styled(
+                    "Tab",
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(": Release labels  "),
                 Span::styled(
                     "q",
                     Style::default()
@@ -237,6 +253,9 @@ impl ModeState for CleanupBranchSelectionState {
     ) -> StateChange<CleanupModeState> {
         match code {
             KeyCode::Char('q') => StateChange::Exit,
+            KeyCode::Tab => StateChange::Change(CleanupModeState::ReleaseLabels(
+                CleanupReleaseLabelsState::new(),
+            )),
             KeyCode::Up => {
                 self.previous(app);
                 StateChange::Keep
@@ -271,6 +290,41 @@ impl ModeState for CleanupBranchSelectionState {
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        ActionMap::new("Branch Selection")
+            .bind(
+                ActionCategory::Navigation,
+                &[KeyCode::Up, KeyCode::Down],
+                "Move highlight",
+            )
+            .bind(
+                ActionCategory::Navigation,
+                &[KeyCode::Tab],
+                "Release labels tab",
+            )
+            .bind(
+                ActionCategory::Selection,
+                &[KeyCode::Char(' ')],
+                "Toggle branch",
+            )
+            .bind(
+                ActionCategory::Selection,
+                &[KeyCode::Char('a')],
+                "Select all branches merged locally and on origin",
+            )
+            .bind(
+                ActionCategory::Selection,
+                &[KeyCode::Char('d')],
+                "Deselect all",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Enter],
+                "Delete selected branches",
+            )
+            .bind(ActionCategory::General, &[KeyCode::Char('q')], "Quit")
+    }
+
     fn name(&self) -> &'static str {
         "CleanupBranchSelection"
     }
@@ -315,12 +369,12 @@ mod tests {
     ///
     /// ## Test Scenario
     /// - Creates a cleanup mode configuration
-    /// - Adds several patch branches (merged and not merged)
+    /// - Adds several patch branches (merged, merged only locally, not merged)
     /// - Renders the branch selection screen
     ///
     /// ## Expected Outcome
     /// - Should display branch table with checkboxes
-    /// - Should show branch metadata (target, version, status)
+    /// - Should show branch metadata (target, version, local and origin status)
     /// - Should highlight current selection
     /// - Should display help text with all available actions
     #[test]
@@ -338,24 +392,30 @@ mod tests {
                     target: "main".to_string(),
                     version: "6.6.2".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: false,
                     status: CleanupStatus::Pending,
+                    tip: None,
                 },
                 CleanupBranch {
                     name: "patch/next-6.6.1".to_string(),
                     target: "next".to_string(),
                     version: "6.6.1".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(false),
                     selected: false,
                     status: CleanupStatus::Pending,
+                    tip: None,
                 },
                 CleanupBranch {
                     name: "patch/main-6.6.0".to_string(),
                     target: "main".to_string(),
                     version: "6.6.0".to_string(),
                     is_merged: false,
+                    is_merged_remote: Some(false),
                     selected: false,
                     status: CleanupStatus::Pending,
+                    tip: None,
                 },
             ];
 
@@ -393,24 +453,30 @@ mod tests {
                     target: "main".to_string(),
                     version: "6.6.2".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Pending,
+                    tip: None,
                 },
                 CleanupBranch {
                     name: "patch/next-6.6.1".to_string(),
                     target: "next".to_string(),
                     version: "6.6.1".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Pending,
+                    tip: None,
                 },
                 CleanupBranch {
                     name: "patch/main-6.6.0".to_string(),
                     target: "main".to_string(),
                     version: "6.6.0".to_string(),
                     is_merged: false,
+                    is_merged_remote: Some(false),
                     selected: false,
                     status: CleanupStatus::Pending,
+                    tip: None,
                 },
             ];
 
@@ -419,4 +485,49 @@ mod tests {
             assert_snapshot!("with_selections", harness.backend());
         });
     }
+
+    /// # Select All Merged Skips Branches Not Merged on Origin
+    ///
+    /// Tests that 'a' only selects branches that are safe to delete.
+    ///
+    /// ## Test Scenario
+    /// - Adds branches merged everywhere, merged only locally, and merged
+    ///   locally without a known remote state
+    /// - Presses 'a'
+    ///
+    /// ## Expected Outcome
+    /// - The branch merged only into the stale local target stays unselected
+    /// - A branch without a remote-tracking ref falls back to the local check
+    #[tokio::test]
+    async fn test_select_all_merged_requires_origin() {
+        let config = create_test_config_cleanup();
+        let mut harness = TuiTestHarness::with_config(config);
+
+        let branch = |version: &str, is_merged_remote| CleanupBranch {
+            name: format!("patch/main-{}", version),
+            target: "main".to_string(),
+            version: version.to_string(),
+            is_merged: true,
+            is_merged_remote,
+            selected: false,
+            status: CleanupStatus::Pending,
+            tip: None,
+        };
+        *harness.app.cleanup_branches_mut() = vec![
+            branch("1.0.0", Some(true)),
+            branch("1.0.1", Some(false)),
+            branch("1.0.2", None),
+        ];
+
+        let mut state = CleanupBranchSelectionState::new();
+        ModeState::process_key(&mut state, KeyCode::Char('a'), harness.cleanup_app_mut()).await;
+
+        let selected: Vec<bool> = harness
+            .app
+            .cleanup_branches()
+            .iter()
+            .map(|b| b.selected)
+            .collect();
+        assert_eq!(selected, vec![true, false, true]);
+    }
 }
@@ -1,6 +1,9 @@
 use super::CleanupModeState;
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::{
-    git::force_delete_branch,
+    api::AUDIT_LOG_TARGET,
+    core::state::{LockGuard, LockKey, LockKind},
+    git::{delete_base_tag, delete_branch},
     models::CleanupStatus,
     ui::apps::CleanupApp,
     ui::state::CleanupResultsState,
@@ -15,14 +18,18 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Gauge, List, ListItem, Paragraph},
 };
+use std::collections::BTreeSet;
 use std::time::Instant;
 
-type DeletionTask = tokio::task::JoinHandle<(usize, Result<(), String>)>;
+type DeletionTask = tokio::task::JoinHandle<(usize, Result<Option<String>, String>)>;
 
 pub struct CleanupExecutionState {
     is_complete: bool,
     start_time: Option<Instant>,
     deletion_tasks: Option<Vec<DeletionTask>>,
+    /// Cleanup locks on the targets of the selected branches, held while
+    /// branches are deleted.
+    locks: Vec<LockGuard>,
 }
 
 impl Default for CleanupExecutionState {
@@ -37,9 +44,20 @@ impl CleanupExecutionState {
             is_complete: false,
             start_time: None,
             deletion_tasks: None,
+            locks: Vec::new(),
         }
     }
 
+    /// Marks every selected branch as failed and finishes the cleanup.
+    fn fail_selected(&mut self, app: &mut CleanupApp, message: &str) {
+        for branch in app.cleanup_branches_mut() {
+            if branch.selected {
+                branch.status = CleanupStatus::Failed(message.to_string());
+            }
+        }
+        self.is_complete = true;
+    }
+
     fn start_cleanup(&mut self, app: &mut CleanupApp) {
         if self.deletion_tasks.is_some() {
             return;
@@ -54,28 +72,69 @@ impl CleanupExecutionState {
             Some(path) => path,
             None => {
                 // This shouldn't happen, but handle it gracefully
-                for branch in app.cleanup_branches_mut() {
-                    if branch.selected {
-                        branch.status =
-                            CleanupStatus::Failed("No repository path available".to_string());
-                    }
-                }
-                self.is_complete = true;
+                self.fail_selected(app, "No repository path available");
                 return;
             }
         };
 
+        // Neither a merge into any of the targets nor another cleanup may be
+        // running, so every target is locked before anything is deleted
+        let targets: BTreeSet<String> = app
+            .cleanup_branches()
+            .iter()
+            .filter(|branch| branch.selected)
+            .map(|branch| branch.target.clone())
+            .collect();
+        let mut locks = Vec::with_capacity(targets.len());
+        for target in &targets {
+            let key = LockKey::for_repository(
+                app.organization(),
+                app.project(),
+                app.repository(),
+                target,
+            );
+            match LockGuard::acquire(&key, LockKind::Cleanup) {
+                Ok(Some(lock)) => locks.push(lock),
+                Ok(None) => {
+                    let message = match LockGuard::holder(&key) {
+                        Ok(Some(LockKind::Merge)) => {
+                            format!("A merge into {} is in progress", target)
+                        }
+                        Ok(Some(LockKind::Cleanup)) => {
+                            format!("Another cleanup of {} is in progress", target)
+                        }
+                        _ => format!("Another merge or cleanup of {} is in progress", target),
+                    };
+                    self.fail_selected(app, &message);
+                    return;
+                }
+                Err(e) => {
+                    self.fail_selected(app, &format!("Failed to acquire lock: {:#}", e));
+                    return;
+                }
+            }
+        }
+        self.locks = locks;
+
         // Spawn deletion tasks for selected branches
         let mut tasks = Vec::new();
         for (idx, branch) in app.cleanup_branches_mut().iter_mut().enumerate() {
             if branch.selected {
                 branch.status = CleanupStatus::InProgress;
                 let branch_name = branch.name.clone();
+                let target = branch.target.clone();
+                let version = branch.version.clone();
                 let repo_path_clone = repo_path.clone();
 
                 let task = tokio::spawn(async move {
-                    let result = force_delete_branch(&repo_path_clone, &branch_name)
-                        .map_err(|e| e.to_string());
+                    let result = delete_branch(&repo_path_clone, &branch_name)
+                        .map_err(|e| format!("{:#}", e));
+                    // The base tag is only useful while the branch exists
+                    if result.is_ok()
+                        && let Err(e) = delete_base_tag(&repo_path_clone, &target, &version)
+                    {
+                        tracing::warn!("Failed to delete the base tag of {}: {:#}", version, e);
+                    }
                     (idx, result)
                 });
 
@@ -102,15 +161,47 @@ impl CleanupExecutionState {
                 if let Ok((idx, result)) = task.await
                     && idx < app.cleanup_branches().len()
                 {
-                    app.cleanup_branches_mut()[idx].status = match result {
-                        Ok(_) => CleanupStatus::Success,
-                        Err(e) => CleanupStatus::Failed(e),
-                    };
+                    let branch = &mut app.cleanup_branches_mut()[idx];
+                    match result {
+                        Ok(Some(tip)) => {
+                            tracing::info!(
+                                target: AUDIT_LOG_TARGET,
+                                action = "delete branch",
+                                target = branch.name.as_str(),
+                                tip = tip.as_str(),
+                                "branch deleted; restore with `git branch {} {}`",
+                                branch.name,
+                                tip
+                            );
+                            branch.status = CleanupStatus::Success;
+                            branch.tip = Some(tip);
+                        }
+                        Ok(None) => {
+                            tracing::info!(
+                                target: AUDIT_LOG_TARGET,
+                                action = "delete branch",
+                                target = branch.name.as_str(),
+                                "branch was already deleted"
+                            );
+                            branch.status = CleanupStatus::Success;
+                        }
+                        Err(e) => {
+                            tracing::warn!(
+                                target: AUDIT_LOG_TARGET,
+                                action = "delete branch",
+                                target = branch.name.as_str(),
+                                error = e.as_str(),
+                                "branch deletion failed"
+                            );
+                            branch.status = CleanupStatus::Failed(e);
+                        }
+                    }
                 }
             }
 
             if pending_tasks.is_empty() {
                 self.is_complete = true;
+                self.locks.clear();
                 return true;
             }
 
@@ -271,6 +362,14 @@ impl ModeState for CleanupExecutionState {
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        let mut map = ActionMap::new("Cleanup");
+        if self.is_complete {
+            map = map.bind(ActionCategory::General, &[KeyCode::Enter], "View results");
+        }
+        map.bind(ActionCategory::General, &[KeyCode::Char('q')], "Quit")
+    }
+
     fn name(&self) -> &'static str {
         "CleanupExecution"
     }
@@ -312,16 +411,20 @@ mod tests {
                 target: "main".to_string(),
                 version: "1.0.0".to_string(),
                 is_merged: true,
+                is_merged_remote: Some(true),
                 selected: true,
                 status: CleanupStatus::InProgress,
+                tip: None,
             },
             CleanupBranch {
                 name: "branch-2".to_string(),
                 target: "main".to_string(),
                 version: "1.0.1".to_string(),
                 is_merged: true,
+                is_merged_remote: Some(true),
                 selected: true,
                 status: CleanupStatus::InProgress,
+                tip: None,
             },
         ];
 
@@ -329,8 +432,8 @@ mod tests {
 
         // Manually set up tasks that complete immediately
         let tasks: Vec<DeletionTask> = vec![
-            tokio::spawn(async { (0, Ok(())) }),
-            tokio::spawn(async { (1, Ok(())) }),
+            tokio::spawn(async { (0, Ok(Some("a1b2c3d".to_string()))) }),
+            tokio::spawn(async { (1, Ok(Some("e4f5a6b".to_string()))) }),
         ];
         state.deletion_tasks = Some(tasks);
 
@@ -384,16 +487,20 @@ mod tests {
                 target: "main".to_string(),
                 version: "1.0.0".to_string(),
                 is_merged: true,
+                is_merged_remote: Some(true),
                 selected: true,
                 status: CleanupStatus::InProgress,
+                tip: None,
             },
             CleanupBranch {
                 name: "slow-branch".to_string(),
                 target: "main".to_string(),
                 version: "1.0.1".to_string(),
                 is_merged: true,
+                is_merged_remote: Some(true),
                 selected: true,
                 status: CleanupStatus::InProgress,
+                tip: None,
             },
         ];
 
@@ -401,10 +508,10 @@ mod tests {
 
         // Create tasks: one completes immediately, one takes longer
         let tasks: Vec<DeletionTask> = vec![
-            tokio::spawn(async { (0, Ok(())) }), // Completes immediately
+            tokio::spawn(async { (0, Ok(Some("a1b2c3d".to_string()))) }), // Completes immediately
             tokio::spawn(async {
                 tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-                (1, Ok(()))
+                (1, Ok(Some("e4f5a6b".to_string())))
             }), // Takes longer
         ];
         state.deletion_tasks = Some(tasks);
@@ -456,6 +563,8 @@ mod tests {
     ///
     /// ## Expected Outcome
     /// - Failed tasks should update branch status to Failed
+    /// - Deleted branches record their tip, failed ones do not
+    /// - A branch that was already gone succeeds without a tip
     /// - No panic should occur on subsequent calls
     #[tokio::test]
     async fn test_check_progress_with_failed_tasks() {
@@ -468,24 +577,39 @@ mod tests {
                 target: "main".to_string(),
                 version: "1.0.0".to_string(),
                 is_merged: true,
+                is_merged_remote: Some(true),
                 selected: true,
                 status: CleanupStatus::InProgress,
+                tip: None,
             },
             CleanupBranch {
                 name: "fail-branch".to_string(),
                 target: "main".to_string(),
                 version: "1.0.1".to_string(),
                 is_merged: true,
+                is_merged_remote: Some(true),
                 selected: true,
                 status: CleanupStatus::InProgress,
+                tip: None,
+            },
+            CleanupBranch {
+                name: "gone-branch".to_string(),
+                target: "main".to_string(),
+                version: "1.0.2".to_string(),
+                is_merged: true,
+                is_merged_remote: Some(true),
+                selected: true,
+                status: CleanupStatus::InProgress,
+                tip: None,
             },
         ];
 
         let mut state = CleanupExecutionState::new();
 
         let tasks: Vec<DeletionTask> = vec![
-            tokio::spawn(async { (0, Ok(())) }),
+            tokio::spawn(async { (0, Ok(Some("a1b2c3d".to_string()))) }),
             tokio::spawn(async { (1, Err("Branch is protected".to_string())) }),
+            tokio::spawn(async { (2, Ok(None)) }),
         ];
         state.deletion_tasks = Some(tasks);
 
@@ -504,12 +628,168 @@ mod tests {
             &harness.app.cleanup_branches()[1].status,
             CleanupStatus::Failed(msg) if msg == "Branch is protected"
         ));
+        assert_eq!(
+            harness.app.cleanup_branches()[0].tip.as_deref(),
+            Some("a1b2c3d")
+        );
+        assert_eq!(harness.app.cleanup_branches()[1].tip, None);
+        assert!(matches!(
+            harness.app.cleanup_branches()[2].status,
+            CleanupStatus::Success
+        ));
+        assert_eq!(harness.app.cleanup_branches()[2].tip, None);
 
         // Second call - should not panic
         let completed = state.check_progress(harness.cleanup_app_mut()).await;
         assert!(!completed);
     }
 
+    /// # Start Cleanup While Another Operation Runs
+    ///
+    /// Tests that a cleanup does not start while a merge into the same target
+    /// or another cleanup of it holds a lock.
+    ///
+    /// ## Test Scenario
+    /// - Holds a merge lock on the cleanup target and starts the cleanup
+    /// - Releases it, starts the cleanup, then starts another cleanup
+    ///
+    /// ## Expected Outcome
+    /// - The cleanup blocked by the merge fails every selected branch
+    /// - The unblocked cleanup starts deleting and takes the cleanup lock
+    /// - The second cleanup fails every selected branch and completes
+    #[tokio::test]
+    #[serial_test::file_serial(state_env)]
+    async fn test_start_cleanup_lock() {
+        let dir = tempfile::TempDir::new().unwrap();
+        unsafe { std::env::set_var(crate::core::state::STATE_DIR_ENV, dir.path()) };
+
+        let mut harness = TuiTestHarness::with_config(create_test_config_cleanup());
+        harness.app.set_repo_path(Some(dir.path().join("repo")));
+        let branch = CleanupBranch {
+            name: "patch/main-1.0.0".to_string(),
+            target: "main".to_string(),
+            version: "1.0.0".to_string(),
+            is_merged: true,
+            is_merged_remote: Some(true),
+            selected: true,
+            status: CleanupStatus::Pending,
+            tip: None,
+        };
+        *harness.app.cleanup_branches_mut() = vec![branch.clone()];
+        let app = harness.cleanup_app_mut();
+        let key = LockKey::for_repository(
+            app.organization(),
+            app.project(),
+            app.repository(),
+            app.cleanup_target(),
+        );
+
+        let merge = LockGuard::acquire(&key, LockKind::Merge).unwrap().unwrap();
+        let mut blocked = CleanupExecutionState::new();
+        blocked.start_cleanup(app);
+        assert!(blocked.is_complete);
+        assert!(blocked.locks.is_empty());
+        assert!(matches!(
+            &app.cleanup_branches()[0].status,
+            CleanupStatus::Failed(msg) if msg == "A merge into main is in progress"
+        ));
+        drop(merge);
+
+        *app.cleanup_branches_mut() = vec![branch.clone()];
+        let mut state = CleanupExecutionState::new();
+        state.start_cleanup(app);
+        assert_eq!(state.locks.len(), 1);
+        assert!(state.deletion_tasks.is_some());
+
+        let mut other = CleanupExecutionState::new();
+        *app.cleanup_branches_mut() = vec![branch];
+        other.start_cleanup(app);
+        assert!(other.is_complete);
+        assert!(matches!(
+            &app.cleanup_branches()[0].status,
+            CleanupStatus::Failed(msg) if msg == "Another cleanup of main is in progress"
+        ));
+
+        unsafe { std::env::remove_var(crate::core::state::STATE_DIR_ENV) };
+    }
+
+    /// # Start Cleanup Locks Every Target
+    ///
+    /// Tests that a cleanup of branches of several targets locks each of
+    /// them before deleting anything.
+    ///
+    /// ## Test Scenario
+    /// - Selects patch branches of `main` and `release/1.0`, and an
+    ///   unselected one of `release/2.0`
+    /// - Holds a merge lock on `release/1.0` and starts the cleanup
+    /// - Releases it and starts the cleanup again
+    ///
+    /// ## Expected Outcome
+    /// - The blocked cleanup fails every selected branch, spawns no deletion
+    ///   and holds no lock, so `main` stays free
+    /// - The unblocked cleanup holds a cleanup lock on `main` and
+    ///   `release/1.0` but not on `release/2.0`
+    #[tokio::test]
+    #[serial_test::file_serial(state_env)]
+    async fn test_start_cleanup_locks_every_target() {
+        let dir = tempfile::TempDir::new().unwrap();
+        unsafe { std::env::set_var(crate::core::state::STATE_DIR_ENV, dir.path()) };
+
+        let mut harness = TuiTestHarness::with_config(create_test_config_cleanup());
+        harness.app.set_repo_path(Some(dir.path().join("repo")));
+        let branch = |target: &str, selected: bool| CleanupBranch {
+            name: format!("patch/{}-1.0.0", target),
+            target: target.to_string(),
+            version: "1.0.0".to_string(),
+            is_merged: true,
+            is_merged_remote: Some(true),
+            selected,
+            status: CleanupStatus::Pending,
+            tip: None,
+        };
+        let branches = vec![
+            branch("main", true),
+            branch("release/1.0", true),
+            branch("release/2.0", false),
+        ];
+        *harness.app.cleanup_branches_mut() = branches.clone();
+        let app = harness.cleanup_app_mut();
+        let key = |target: &str| {
+            LockKey::for_repository(app.organization(), app.project(), app.repository(), target)
+        };
+        let (main, release, other) = (key("main"), key("release/1.0"), key("release/2.0"));
+
+        let merge = LockGuard::acquire(&release, LockKind::Merge)
+            .unwrap()
+            .unwrap();
+        let mut blocked = CleanupExecutionState::new();
+        blocked.start_cleanup(app);
+        assert!(blocked.is_complete);
+        assert!(blocked.deletion_tasks.is_none());
+        assert!(blocked.locks.is_empty());
+        assert!(!LockGuard::is_locked(&main).unwrap());
+        for status in app.cleanup_branches()[..2].iter().map(|b| &b.status) {
+            assert!(matches!(
+                status,
+                CleanupStatus::Failed(msg) if msg == "A merge into release/1.0 is in progress"
+            ));
+        }
+        drop(merge);
+
+        *app.cleanup_branches_mut() = branches;
+        let mut state = CleanupExecutionState::new();
+        state.start_cleanup(app);
+        assert_eq!(state.locks.len(), 2);
+        assert_eq!(LockGuard::holder(&main).unwrap(), Some(LockKind::Cleanup));
+        assert_eq!(
+            LockGuard::holder(&release).unwrap(),
+            Some(LockKind::Cleanup)
+        );
+        assert!(!LockGuard::is_locked(&other).unwrap());
+
+        unsafe { std::env::remove_var(crate::core::state::STATE_DIR_ENV) };
+    }
+
     /// # Cleanup Execution Initial State Test
     ///
     /// Tests the cleanup execution screen at start.
@@ -539,16 +819,20 @@ mod tests {
                     target: "main".to_string(),
                     version: "6.6.2".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Pending,
+                    tip: None,
                 },
                 CleanupBranch {
                     name: "patch/next-6.6.1".to_string(),
                     target: "next".to_string(),
                     version: "6.6.1".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Pending,
+                    tip: None,
                 },
             ];
 
@@ -587,24 +871,30 @@ mod tests {
                     target: "main".to_string(),
                     version: "6.6.3".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Success,
+                    tip: None,
                 },
                 CleanupBranch {
                     name: "patch/main-6.6.2".to_string(),
                     target: "main".to_string(),
                     version: "6.6.2".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::InProgress,
+                    tip: None,
                 },
                 CleanupBranch {
                     name: "patch/next-6.6.1".to_string(),
                     target: "next".to_string(),
                     version: "6.6.1".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Pending,
+                    tip: None,
                 },
             ];
 
@@ -645,24 +935,30 @@ mod tests {
                     target: "main".to_string(),
                     version: "6.6.3".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Success,
+                    tip: None,
                 },
                 CleanupBranch {
                     name: "patch/main-6.6.2".to_string(),
                     target: "main".to_string(),
                     version: "6.6.2".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Success,
+                    tip: None,
                 },
                 CleanupBranch {
                     name: "patch/next-6.6.1".to_string(),
                     target: "next".to_string(),
                     version: "6.6.1".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Failed("Branch is checked out".to_string()),
+                    tip: None,
                 },
             ];
 
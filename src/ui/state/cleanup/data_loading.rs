@@ -1,6 +1,10 @@
 use super::CleanupModeState;
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::{
-    git::{check_patch_merged, list_patch_branches_detailed},
+    git::{
+        check_patch_merged_in_history, check_patch_merged_on_remote, fetch_remote_tracking_ref,
+        get_target_branch_history_with_depth, list_patch_branches_detailed,
+    },
     models::AppConfig,
     ui::apps::CleanupApp,
     ui::state::CleanupBranchSelectionState,
@@ -43,6 +47,7 @@ impl Default for CleanupDataLoadingState {
                 project: "default".to_string().into(),
                 repository: "default".to_string().into(),
                 pat: "default".to_string().into(),
+                write_pat: None,
                 dev_branch: "dev".to_string().into(),
                 target_branch: "next".to_string().into(),
                 local_repo: None,
@@ -51,7 +56,12 @@ impl Default for CleanupDataLoadingState {
                 max_concurrent_processing: 10.into(),
                 tag_prefix: "merged-".to_string().into(),
                 since: None,
+                since_field: Default::default(),
                 skip_confirmation: false,
+                api_version: None,
+                history_depth: None,
+                url_templates: Default::default(),
+                release_notes_locale: Default::default(),
             },
             cleanup: crate::models::CleanupModeConfig {
                 target: "next".to_string().into(),
@@ -89,14 +99,14 @@ impl CleanupDataLoadingState {
         let repo_path = local_repo.unwrap().to_string();
         // With typed configs, we can directly access the cleanup target
         let target_branch = app.cleanup_target().to_string();
+        let history_depth = app.history_depth();
 
         self.status = "Loading patch branches...".to_string();
         self.progress = 0.1;
 
-        let task =
-            tokio::spawn(
-                async move { load_and_analyze_branches(&repo_path, &target_branch).await },
-            );
+        let task = tokio::spawn(async move {
+            load_and_analyze_branches(&repo_path, &target_branch, history_depth).await
+        });
 
         self.loading_task = Some(task);
     }
@@ -176,17 +186,36 @@ fn build_no_branches_error(result: &LoadBranchesResult) -> String {
 async fn load_and_analyze_branches(
     repo_path: &str,
     target_branch: &str,
+    history_depth: Option<usize>,
 ) -> Result<LoadBranchesResult> {
     let path = Path::new(repo_path);
 
     // List all patch branches with detailed information
     let result = list_patch_branches_detailed(path)?;
 
-    // Check which branches are merged
+    // Check which branches are merged, reading each target history only once.
+    // The local target may be stale, so origin's copy is checked as well.
     let mut branches = result.branches;
-    for branch in &mut branches {
-        let is_merged = check_patch_merged(path, &branch.name, target_branch)?;
-        branch.is_merged = is_merged;
+    if !branches.is_empty() {
+        let target_history =
+            get_target_branch_history_with_depth(path, target_branch, history_depth)?;
+        let remote = match fetch_remote_tracking_ref(path, target_branch)? {
+            Some(remote_ref) => {
+                let history =
+                    get_target_branch_history_with_depth(path, &remote_ref, history_depth)?;
+                Some((remote_ref, history))
+            }
+            None => None,
+        };
+        for branch in &mut branches {
+            branch.is_merged = check_patch_merged_in_history(path, &branch.name, &target_history)?;
+            branch.is_merged_remote = remote
+                .as_ref()
+                .map(|(remote_ref, history)| {
+                    check_patch_merged_on_remote(path, &branch.name, remote_ref, history)
+                })
+                .transpose()?;
+        }
     }
 
     Ok(LoadBranchesResult {
@@ -333,6 +362,14 @@ impl ModeState for CleanupDataLoadingState {
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        ActionMap::new("Loading Branches").bind(
+            ActionCategory::General,
+            &[KeyCode::Char('q')],
+            "Quit",
+        )
+    }
+
     fn name(&self) -> &'static str {
         "CleanupDataLoading"
     }
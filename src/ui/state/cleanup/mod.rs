@@ -1,11 +1,13 @@
 mod branch_selection;
 mod cleanup_execution;
 mod data_loading;
+mod release_labels;
 mod results;
 mod state_enum;
 
 pub use branch_selection::CleanupBranchSelectionState;
 pub use cleanup_execution::CleanupExecutionState;
 pub use data_loading::CleanupDataLoadingState;
+pub use release_labels::CleanupReleaseLabelsState;
 pub use results::CleanupResultsState;
 pub use state_enum::CleanupModeState;
@@ -0,0 +1,625 @@
+use super::CleanupModeState;
+use crate::ui::keymap::{ActionCategory, ActionMap};
+use crate::{
+    core::operations::{
+        LabelRemovalResult, ReleaseLabel, apply_removal_results, group_release_labels,
+        remove_labels, selected_removals,
+    },
+    ui::apps::CleanupApp,
+    ui::state::CleanupBranchSelectionState,
+    ui::state::typed::{ModeState, StateChange},
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use crossterm::event::KeyCode;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Gauge, Paragraph, Row, Table, TableState},
+};
+use tokio::sync::mpsc;
+
+/// Number of PR ids shown per label before the list is shortened.
+const MAX_LISTED_PRS: usize = 8;
+
+/// Labels removal in flight.
+struct Removal {
+    total: usize,
+    results: Vec<LabelRemovalResult>,
+    receiver: mpsc::UnboundedReceiver<LabelRemovalResult>,
+}
+
+/// Release labels tab of cleanup mode.
+///
+/// Lists the `tag_prefix` labels found on PRs, grouped by version, and
+/// removes the selected labels from all of their PRs in one batch. This is
+/// used to clean up after a release is withdrawn.
+pub struct CleanupReleaseLabelsState {
+    table_state: TableState,
+    loading_task: Option<tokio::task::JoinHandle<Result<Vec<ReleaseLabel>>>>,
+    confirming: bool,
+    removal: Option<Removal>,
+    message: Option<(String, Color)>,
+}
+
+impl Default for CleanupReleaseLabelsState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CleanupReleaseLabelsState {
+    pub fn new() -> Self {
+        let mut state = Self {
+            table_state: TableState::default(),
+            loading_task: None,
+            confirming: false,
+            removal: None,
+            message: None,
+        };
+        state.table_state.select(Some(0));
+        state
+    }
+
+    fn start_loading(&mut self, app: &CleanupApp) {
+        if self.loading_task.is_some() {
+            return;
+        }
+
+        let client = app.client().clone();
+        let dev_branch = app.dev_branch().to_string();
+        let since = app.since().map(str::to_string);
+        let tag_prefix = app.tag_prefix().to_string();
+
+        self.message = Some(("Loading release labels...".to_string(), Color::Yellow));
+        self.loading_task = Some(tokio::spawn(async move {
+            let prs = client
+                .fetch_pull_requests(&dev_branch, since.as_deref())
+                .await?;
+            Ok(group_release_labels(&prs, &tag_prefix))
+        }));
+    }
+
+    async fn check_loading(&mut self, app: &mut CleanupApp) {
+        let Some(task) = self.loading_task.take_if(|task| task.is_finished()) else {
+            return;
+        };
+
+        match task.await {
+            Ok(Ok(labels)) => {
+                self.message = Some((
+                    format!("Found {} release label(s)", labels.len()),
+                    Color::Green,
+                ));
+                app.release_labels = Some(labels);
+                self.table_state.select(Some(0));
+            }
+            Ok(Err(e)) => {
+                self.message = Some((format!("Failed to load labels: {:#}", e), Color::Red));
+                app.release_labels = Some(Vec::new());
+            }
+            Err(e) => {
+                self.message = Some((format!("Task error: {}", e), Color::Red));
+                app.release_labels = Some(Vec::new());
+            }
+        }
+    }
+
+    fn start_removal(&mut self, app: &CleanupApp) {
+        let removals = selected_removals(app.release_labels());
+        if removals.is_empty() {
+            return;
+        }
+
+        let (tx, receiver) = mpsc::unbounded_channel();
+        let client = app.client().clone();
+        let max_concurrent = app.max_concurrent_network();
+
+        self.removal = Some(Removal {
+            total: removals.len(),
+            results: Vec::new(),
+            receiver,
+        });
+        self.message = None;
+
+        tokio::spawn(async move {
+            remove_labels(&client, removals, max_concurrent, |result| {
+                let _ = tx.send(result);
+            })
+            .await;
+        });
+    }
+
+    fn check_removal(&mut self, app: &mut CleanupApp) {
+        let Some(removal) = self.removal.as_mut() else {
+            return;
+        };
+
+        while let Ok(result) = removal.receiver.try_recv() {
+            removal.results.push(result);
+        }
+        if removal.results.len() < removal.total {
+            return;
+        }
+
+        let removal = self.removal.take().expect("removal in progress");
+        apply_removal_results(app.release_labels_mut(), &removal.results);
+        self.clamp_selection(app);
+
+        let failed: Vec<String> = removal
+            .results
+            .iter()
+            .filter(|r| r.error.is_some())
+            .map(|r| format!("{} on PR {}", r.removal.label, r.removal.pr_id))
+            .collect();
+        let removed = removal.total - failed.len();
+        self.message = Some(if failed.is_empty() {
+            (format!("Removed {} label(s)", removed), Color::Green)
+        } else {
+            let shown: Vec<&str> = failed.iter().take(3).map(String::as_str).collect();
+            let more = if failed.len() > 3 {
+                format!(" and {} more", failed.len() - 3)
+            } else {
+                String::new()
+            };
+            (
+                format!(
+                    "Removed {} label(s); {} failed: {}{}",
+                    removed,
+                    failed.len(),
+                    shown.join(", "),
+                    more
+                ),
+                Color::Red,
+            )
+        });
+    }
+
+    fn clamp_selection(&mut self, app: &CleanupApp) {
+        let len = app.release_labels().len();
+        let selected = self.table_state.selected().unwrap_or(0);
+        self.table_state
+            .select(Some(selected.min(len.saturating_sub(1))));
+    }
+
+    fn next(&mut self, app: &CleanupApp) {
+        let len = app.release_labels().len();
+        if len == 0 {
+            return;
+        }
+        let i = match self.table_state.selected() {
+            Some(i) if i + 1 < len => i + 1,
+            _ => 0,
+        };
+        self.table_state.select(Some(i));
+    }
+
+    fn previous(&mut self, app: &CleanupApp) {
+        let len = app.release_labels().len();
+        if len == 0 {
+            return;
+        }
+        let i = match self.table_state.selected() {
+            Some(0) | None => len - 1,
+            Some(i) => i - 1,
+        };
+        self.table_state.select(Some(i));
+    }
+
+    fn toggle_selection(&mut self, app: &mut CleanupApp) {
+        if let Some(i) = self.table_state.selected()
+            && let Some(label) = app.release_labels_mut().get_mut(i)
+        {
+            label.selected = !label.selected;
+        }
+    }
+
+    fn deselect_all(&mut self, app: &mut CleanupApp) {
+        for label in app.release_labels_mut() {
+            label.selected = false;
+        }
+    }
+
+    /// Returns the number of selected labels and the removals they need.
+    fn selected_counts(&self, app: &CleanupApp) -> (usize, usize) {
+        app.release_labels()
+            .iter()
+            .filter(|l| l.selected)
+            .fold((0, 0), |(labels, prs), l| {
+                (labels + 1, prs + l.pr_ids.len())
+            })
+    }
+
+    fn is_busy(&self) -> bool {
+        self.loading_task.is_some() || self.removal.is_some()
+    }
+}
+
+fn key_span(key: &str) -> Span<'_> {
+    Span::styled(
+        key,
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )
+}
+
+// ============================================================================
+// ModeState Implementation
+// ============================================================================
+
+#[async_trait]
+impl ModeState for CleanupReleaseLabelsState {
+    type Mode = CleanupModeState;
+
+    fn ui(&mut self, f: &mut Frame, app: &CleanupApp) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(10),
+                Constraint::Length(3),
+                Constraint::Length(5),
+            ])
+            .split(f.area());
+
+        // Title
+        let (selected_labels, _) = self.selected_counts(app);
+        let title = Paragraph::new(format!(
+            "Cleanup Mode - Release Labels '{}*' ({} selected)",
+            app.tag_prefix(),
+            selected_labels
+        ))
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+        f.render_widget(title, chunks[0]);
+
+        // Label table
+        let header_cells = ["", "Version", "Label", "PRs", "Pull Requests"]
+            .iter()
+            .map(|h| {
+                Cell::from(*h).style(
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                )
+            });
+        let header = Row::new(header_cells).height(1).bottom_margin(1);
+
+        let rows = app.release_labels().iter().map(|label| {
+            let checkbox = if label.selected { "☑" } else { "☐" };
+            let mut pr_list: Vec<String> = label
+                .pr_ids
+                .iter()
+                .take(MAX_LISTED_PRS)
+                .map(|id| format!("!{}", id))
+                .collect();
+            if label.pr_ids.len() > MAX_LISTED_PRS {
+                pr_list.push("…".to_string());
+            }
+
+            Row::new(vec![
+                Cell::from(checkbox),
+                Cell::from(label.version.as_str()),
+                Cell::from(label.name.as_str()),
+                Cell::from(label.pr_ids.len().to_string()),
+                Cell::from(pr_list.join(" ")),
+            ])
+            .height(1)
+        });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(3),
+                Constraint::Length(15),
+                Constraint::Length(25),
+                Constraint::Length(5),
+                Constraint::Min(20),
+            ],
+        )
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Release Labels"),
+        )
+        .row_highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("→ ");
+
+        f.render_stateful_widget(table, chunks[1], &mut self.table_state);
+
+        // Status: removal progress, confirmation prompt or last message
+        let status_block = Block::default().borders(Borders::ALL).title("Status");
+        if let Some(removal) = &self.removal {
+            let done = removal.results.len();
+            let percent = (done * 100)
+                .checked_div(removal.total)
+                .unwrap_or(0)
+                .min(100) as u16;
+            let gauge = Gauge::default()
+                .block(status_block)
+                .gauge_style(
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .percent(percent)
+                .label(format!("Removed {}/{} labels", done, removal.total));
+            f.render_widget(gauge, chunks[2]);
+        } else if self.confirming {
+            let (labels, prs) = self.selected_counts(app);
+            let prompt = Paragraph::new(Line::from(vec![
+                Span::styled(
+                    format!("Remove {} label(s) from {} PR(s)? ", labels, prs),
+                    Style::default().fg(Color::Yellow),
+                ),
+                key_span("y"),
+                Span::raw(": Remove  "),
+                key_span("n"),
+                Span::raw(": Cancel"),
+            ]))
+            .block(status_block);
+            f.render_widget(prompt, chunks[2]);
+        } else {
+            let line = match &self.message {
+                Some((text, color)) => {
+                    Line::from(Span::styled(text.as_str(), Style::default().fg(*color)))
+                }
+                None => Line::from(""),
+            };
+            f.render_widget(Paragraph::new(line).block(status_block), chunks[2]);
+        }
+
+        // Help text
+        let help_lines = vec![
+            Line::from(vec![
+                key_span("↑/↓"),
+                Span::raw(": Navigate  "),
+                key_span("Space"),
+                Span::raw(": Toggle selection  "),
+                key_span("d"),
+                Span::raw(": Deselect all  "),
+                key_span("r"),
+                Span::raw(": Reload"),
+            ]),
+            Line::from(vec![
+                key_span("Enter"),
+                Span::raw(": Remove selected labels  "),
+                key_span("Tab"),
+                Span::raw(": Branches  "),
+                key_span("q"),
+                Span::raw(": Exit"),
+            ]),
+        ];
+
+        let help = Paragraph::new(help_lines)
+            .style(Style::default().fg(Color::DarkGray))
+            .block(Block::default().borders(Borders::ALL).title("Help"));
+        f.render_widget(help, chunks[3]);
+    }
+
+    async fn process_key(
+        &mut self,
+        code: KeyCode,
+        app: &mut CleanupApp,
+    ) -> StateChange<CleanupModeState> {
+        if code == KeyCode::Null {
+            if app.release_labels.is_none() {
+                self.start_loading(app);
+            }
+            self.check_loading(app).await;
+            self.check_removal(app);
+            return StateChange::Keep;
+        }
+
+        if self.confirming {
+            match code {
+                KeyCode::Char('y') => {
+                    self.confirming = false;
+                    self.start_removal(app);
+                }
+                KeyCode::Char('n') | KeyCode::Esc => self.confirming = false,
+                _ => {}
+            }
+            return StateChange::Keep;
+        }
+
+        match code {
+            KeyCode::Char('q') => StateChange::Exit,
+            // Leaving mid-removal would drop the progress view; finish first
+            _ if self.is_busy() => StateChange::Keep,
+            KeyCode::Tab => StateChange::Change(CleanupModeState::BranchSelection(
+                CleanupBranchSelectionState::new(),
+            )),
+            KeyCode::Up => {
+                self.previous(app);
+                StateChange::Keep
+            }
+            KeyCode::Down => {
+                self.next(app);
+                StateChange::Keep
+            }
+            KeyCode::Char(' ') => {
+                self.toggle_selection(app);
+                StateChange::Keep
+            }
+            KeyCode::Char('d') => {
+                self.deselect_all(app);
+                StateChange::Keep
+            }
+            KeyCode::Char('r') => {
+                app.release_labels = None;
+                self.start_loading(app);
+                StateChange::Keep
+            }
+            KeyCode::Enter => {
+                if self.selected_counts(app).0 > 0 {
+                    self.confirming = true;
+                }
+                StateChange::Keep
+            }
+            _ => StateChange::Keep,
+        }
+    }
+
+    fn action_map(&self) -> ActionMap {
+        if self.confirming {
+            return ActionMap::new("Remove Release Labels")
+                .bind(
+                    ActionCategory::Actions,
+                    &[KeyCode::Char('y')],
+                    "Remove selected labels",
+                )
+                .bind(
+                    ActionCategory::General,
+                    &[KeyCode::Char('n'), KeyCode::Esc],
+                    "Cancel",
+                );
+        }
+
+        ActionMap::new("Release Labels")
+            .bind(
+                ActionCategory::Navigation,
+                &[KeyCode::Up, KeyCode::Down],
+                "Move highlight",
+            )
+            .bind(ActionCategory::Navigation, &[KeyCode::Tab], "Branches tab")
+            .bind(
+                ActionCategory::Selection,
+                &[KeyCode::Char(' ')],
+                "Toggle label",
+            )
+            .bind(
+                ActionCategory::Selection,
+                &[KeyCode::Char('d')],
+                "Deselect all",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Enter],
+                "Remove selected labels from their PRs",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('r')],
+                "Reload labels",
+            )
+            .bind(ActionCategory::General, &[KeyCode::Char('q')], "Quit")
+    }
+
+    fn name(&self) -> &'static str {
+        "CleanupReleaseLabels"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::testing::*;
+    use insta::assert_snapshot;
+
+    fn sample_labels() -> Vec<ReleaseLabel> {
+        vec![
+            ReleaseLabel {
+                name: "merged-6.6.2".to_string(),
+                version: "6.6.2".to_string(),
+                pr_ids: vec![101, 102, 105],
+                selected: true,
+            },
+            ReleaseLabel {
+                name: "merged-6.6.1".to_string(),
+                version: "6.6.1".to_string(),
+                pr_ids: (1..=10).collect(),
+                selected: false,
+            },
+        ]
+    }
+
+    /// # Release Labels Tab
+    ///
+    /// Tests the release labels tab with loaded labels.
+    ///
+    /// ## Test Scenario
+    /// - Loads two release labels, one selected and one on many PRs
+    /// - Renders the tab
+    ///
+    /// ## Expected Outcome
+    /// - Labels are listed with version, PR count and shortened PR list
+    /// - The title shows the tag prefix and the selected count
+    #[test]
+    fn test_release_labels_list() {
+        use crate::ui::snapshot_testing::with_settings_and_module_path;
+
+        with_settings_and_module_path(module_path!(), || {
+            let mut harness = TuiTestHarness::with_config(create_test_config_cleanup());
+            harness.cleanup_app_mut().release_labels = Some(sample_labels());
+
+            let state = CleanupReleaseLabelsState::new();
+            harness.render_cleanup_state(&mut CleanupModeState::ReleaseLabels(state));
+            assert_snapshot!("list", harness.backend());
+        });
+    }
+
+    /// # Release Labels Removal Confirmation
+    ///
+    /// Tests confirming and cancelling a bulk removal.
+    ///
+    /// ## Test Scenario
+    /// - Presses Enter with a label selected, renders the prompt
+    /// - Presses `n`, then Tab
+    ///
+    /// ## Expected Outcome
+    /// - The prompt counts the selected labels and their PRs
+    /// - `n` cancels without removing anything; Tab switches to the branches tab
+    #[tokio::test]
+    async fn test_release_labels_confirm() {
+        use crate::ui::snapshot_testing::with_settings_and_module_path;
+
+        let mut harness = TuiTestHarness::with_config(create_test_config_cleanup());
+        harness.cleanup_app_mut().release_labels = Some(sample_labels());
+        let mut mode = CleanupModeState::ReleaseLabels(CleanupReleaseLabelsState::new());
+        let CleanupModeState::ReleaseLabels(state) = &mut mode else {
+            unreachable!()
+        };
+
+        state
+            .process_key(KeyCode::Enter, harness.cleanup_app_mut())
+            .await;
+        assert!(state.confirming);
+
+        with_settings_and_module_path(module_path!(), || {
+            harness.render_cleanup_state(&mut mode);
+            assert_snapshot!("confirm", harness.backend());
+        });
+
+        let CleanupModeState::ReleaseLabels(state) = &mut mode else {
+            unreachable!()
+        };
+        state
+            .process_key(KeyCode::Char('n'), harness.cleanup_app_mut())
+            .await;
+        assert!(!state.confirming);
+        assert!(state.removal.is_none());
+        assert_eq!(harness.cleanup_app().release_labels().len(), 2);
+
+        let change = state
+            .process_key(KeyCode::Tab, harness.cleanup_app_mut())
+            .await;
+        assert!(matches!(
+            change,
+            StateChange::Change(CleanupModeState::BranchSelection(_))
+        ));
+    }
+}
@@ -1,5 +1,7 @@
 use super::CleanupModeState;
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::{
+    core::operations::CleanupReport,
     models::CleanupStatus,
     ui::apps::CleanupApp,
     ui::state::typed::{ModeState, StateChange},
@@ -13,6 +15,7 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Tabs},
 };
+use std::path::PathBuf;
 
 #[derive(Debug, Clone, PartialEq)]
 enum ResultTab {
@@ -24,6 +27,10 @@ pub struct CleanupResultsState {
     current_tab: ResultTab,
     success_list_state: ListState,
     failed_list_state: ListState,
+    /// Directory the cleanup report is exported to.
+    export_dir: PathBuf,
+    /// Outcome of the last export: the written files or the error.
+    export_status: Option<Result<String, String>>,
 }
 
 impl Default for CleanupResultsState {
@@ -44,9 +51,24 @@ impl CleanupResultsState {
             current_tab: ResultTab::Success,
             success_list_state,
             failed_list_state,
+            export_dir: std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")),
+            export_status: None,
         }
     }
 
+    /// Writes the JSON and markdown report of the cleanup run.
+    fn export_report(&mut self, app: &CleanupApp) {
+        let report = CleanupReport::new(app.cleanup_target(), app.cleanup_branches());
+        self.export_status = Some(
+            report
+                .write_to(&self.export_dir)
+                .map(|(json, markdown)| {
+                    format!("Exported {} and {}", json.display(), markdown.display())
+                })
+                .map_err(|e| format!("Export failed: {:#}", e)),
+        );
+    }
+
     fn switch_tab(&mut self) {
         self.current_tab = match self.current_tab {
             ResultTab::Success => ResultTab::Failed,
@@ -146,7 +168,7 @@ impl ModeState for CleanupResultsState {
                 Constraint::Length(3),
                 Constraint::Length(3),
                 Constraint::Min(10),
-                Constraint::Length(3),
+                Constraint::Length(if self.export_status.is_some() { 4 } else { 3 }),
             ])
             .split(f.area());
 
@@ -258,7 +280,7 @@ impl ModeState for CleanupResultsState {
         }
 
         // Help text
-        let help_lines = vec![Line::from(vec![
+        let mut help_lines = vec![Line::from(vec![
             Span::styled(
                 "Tab",
                 Style::default()
@@ -273,6 +295,13 @@ impl ModeState for CleanupResultsState {
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw(": Navigate  "),
+            Span::styled(
+                "e",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(": Export report  "),
             Span::styled(
                 "q",
                 Style::default()
@@ -281,6 +310,17 @@ impl ModeState for CleanupResultsState {
             ),
             Span::raw(": Exit"),
         ])];
+        match &self.export_status {
+            Some(Ok(message)) => help_lines.push(Line::styled(
+                message.as_str(),
+                Style::default().fg(Color::Green),
+            )),
+            Some(Err(message)) => help_lines.push(Line::styled(
+                message.as_str(),
+                Style::default().fg(Color::Red),
+            )),
+            None => {}
+        }
 
         let help = Paragraph::new(help_lines)
             .style(Style::default().fg(Color::DarkGray))
@@ -307,10 +347,30 @@ impl ModeState for CleanupResultsState {
                 self.next(app);
                 StateChange::Keep
             }
+            KeyCode::Char('e') => {
+                self.export_report(app);
+                StateChange::Keep
+            }
             _ => StateChange::Keep,
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        ActionMap::new("Cleanup Results")
+            .bind(
+                ActionCategory::Navigation,
+                &[KeyCode::Up, KeyCode::Down],
+                "Move highlight",
+            )
+            .bind(ActionCategory::Navigation, &[KeyCode::Tab], "Switch tab")
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('e')],
+                "Export report with restorable branch tips",
+            )
+            .bind(ActionCategory::General, &[KeyCode::Char('q')], "Quit")
+    }
+
     fn name(&self) -> &'static str {
         "CleanupResults"
     }
@@ -351,24 +411,30 @@ mod tests {
                     target: "main".to_string(),
                     version: "6.6.3".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Success,
+                    tip: None,
                 },
                 CleanupBranch {
                     name: "patch/main-6.6.2".to_string(),
                     target: "main".to_string(),
                     version: "6.6.2".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Success,
+                    tip: None,
                 },
                 CleanupBranch {
                     name: "patch/next-6.6.1".to_string(),
                     target: "next".to_string(),
                     version: "6.6.1".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: false, // Not selected, should not appear
                     status: CleanupStatus::Success,
+                    tip: None,
                 },
             ];
 
@@ -408,16 +474,20 @@ mod tests {
                     target: "main".to_string(),
                     version: "6.6.2".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Failed("Branch is checked out".to_string()),
+                    tip: None,
                 },
                 CleanupBranch {
                     name: "patch/next-6.6.1".to_string(),
                     target: "next".to_string(),
                     version: "6.6.1".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Failed("Protected branch".to_string()),
+                    tip: None,
                 },
             ];
 
@@ -457,24 +527,30 @@ mod tests {
                     target: "main".to_string(),
                     version: "6.6.3".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Success,
+                    tip: None,
                 },
                 CleanupBranch {
                     name: "patch/main-6.6.2".to_string(),
                     target: "main".to_string(),
                     version: "6.6.2".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Failed("Branch is checked out".to_string()),
+                    tip: None,
                 },
                 CleanupBranch {
                     name: "patch/next-6.6.1".to_string(),
                     target: "next".to_string(),
                     version: "6.6.1".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Success,
+                    tip: None,
                 },
             ];
 
@@ -512,16 +588,20 @@ mod tests {
                     target: "main".to_string(),
                     version: "6.6.3".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Success,
+                    tip: None,
                 },
                 CleanupBranch {
                     name: "patch/main-6.6.2".to_string(),
                     target: "main".to_string(),
                     version: "6.6.2".to_string(),
                     is_merged: true,
+                    is_merged_remote: Some(true),
                     selected: true,
                     status: CleanupStatus::Success,
+                    tip: None,
                 },
             ];
 
@@ -532,4 +612,64 @@ mod tests {
             assert_snapshot!("no_failures", harness.backend());
         });
     }
+
+    /// # Cleanup Results Export Test
+    ///
+    /// Tests exporting the cleanup report from the results screen.
+    ///
+    /// ## Test Scenario
+    /// - Sets up a deleted branch with a recorded tip and an unselected branch
+    /// - Presses 'e' with the export directory set to a temporary directory
+    ///
+    /// ## Expected Outcome
+    /// - The JSON and markdown reports are written
+    /// - The markdown contains the restore command for the deleted branch
+    /// - The screen reports the written files
+    #[tokio::test]
+    async fn test_results_export_report() {
+        let config = create_test_config_cleanup();
+        let mut harness = TuiTestHarness::with_config(config);
+
+        *harness.app.cleanup_branches_mut() = vec![
+            CleanupBranch {
+                name: "patch/main-6.6.3".to_string(),
+                target: "main".to_string(),
+                version: "6.6.3".to_string(),
+                is_merged: true,
+                is_merged_remote: Some(true),
+                selected: true,
+                status: CleanupStatus::Success,
+                tip: Some("a1b2c3d".to_string()),
+            },
+            CleanupBranch {
+                name: "patch/main-6.6.4".to_string(),
+                target: "main".to_string(),
+                version: "6.6.4".to_string(),
+                is_merged: false,
+                is_merged_remote: Some(false),
+                selected: false,
+                status: CleanupStatus::Pending,
+                tip: None,
+            },
+        ];
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut state = CleanupResultsState::new();
+        state.export_dir = temp_dir.path().to_path_buf();
+
+        let result = state
+            .process_key(KeyCode::Char('e'), harness.cleanup_app_mut())
+            .await;
+        assert!(matches!(result, StateChange::Keep));
+
+        let markdown =
+            std::fs::read_to_string(temp_dir.path().join("cleanup-report-main.md")).unwrap();
+        assert!(markdown.contains("`git branch patch/main-6.6.3 a1b2c3d`"));
+        assert!(markdown.contains("Not merged into main"));
+        assert!(temp_dir.path().join("cleanup-report-main.json").exists());
+        assert!(matches!(
+            &state.export_status,
+            Some(Ok(message)) if message.starts_with("Exported ")
+        ));
+    }
 }
@@ -6,9 +6,10 @@
 
 use super::{
     CleanupBranchSelectionState, CleanupDataLoadingState, CleanupExecutionState,
-    CleanupResultsState,
+    CleanupReleaseLabelsState, CleanupResultsState,
 };
 use crate::ui::apps::CleanupApp;
+use crate::ui::keymap::ActionMap;
 use crate::ui::state::shared::{ErrorState, SettingsConfirmationState};
 use crate::ui::state::typed::{AppState, ModeState, StateChange};
 use async_trait::async_trait;
@@ -26,7 +27,8 @@ use ratatui::Frame;
 /// The cleanup workflow progresses through these states:
 /// 1. `SettingsConfirmation` - Confirm settings before starting
 /// 2. `DataLoading` - Fetch branch information and determine cleanup candidates
-/// 3. `BranchSelection` - User selects branches to clean up
+/// 3. `BranchSelection` - User selects branches to clean up; `Tab` switches to
+///    `ReleaseLabels` to remove the labels of a withdrawn release
 /// 4. `Execution` - Delete selected branches
 /// 5. `Results` - Display cleanup results
 /// 6. `Error` - Display error messages
@@ -53,6 +55,8 @@ pub enum CleanupModeState {
     DataLoading(CleanupDataLoadingState),
     /// Branch selection screen.
     BranchSelection(CleanupBranchSelectionState),
+    /// Release labels tab.
+    ReleaseLabels(CleanupReleaseLabelsState),
     /// Executing cleanup operations.
     Execution(CleanupExecutionState),
     /// Cleanup results display.
@@ -94,6 +98,7 @@ impl CleanupModeState {
             CleanupModeState::SettingsConfirmation(_) => "SettingsConfirmation",
             CleanupModeState::DataLoading(_) => "DataLoading",
             CleanupModeState::BranchSelection(_) => "BranchSelection",
+            CleanupModeState::ReleaseLabels(_) => "ReleaseLabels",
             CleanupModeState::Execution(_) => "Execution",
             CleanupModeState::Results(_) => "Results",
             CleanupModeState::Error(_) => "Error",
@@ -117,6 +122,7 @@ impl AppState for CleanupModeState {
             CleanupModeState::SettingsConfirmation(state) => state.render(f),
             CleanupModeState::DataLoading(state) => ModeState::ui(state, f, app),
             CleanupModeState::BranchSelection(state) => ModeState::ui(state, f, app),
+            CleanupModeState::ReleaseLabels(state) => ModeState::ui(state, f, app),
             CleanupModeState::Execution(state) => ModeState::ui(state, f, app),
             CleanupModeState::Results(state) => ModeState::ui(state, f, app),
             CleanupModeState::Error(state) => state.render(f, app.error_message()),
@@ -132,6 +138,9 @@ impl AppState for CleanupModeState {
             CleanupModeState::BranchSelection(state) => {
                 ModeState::process_key(state, code, app).await
             }
+            CleanupModeState::ReleaseLabels(state) => {
+                ModeState::process_key(state, code, app).await
+            }
             CleanupModeState::Execution(state) => ModeState::process_key(state, code, app).await,
             CleanupModeState::Results(state) => ModeState::process_key(state, code, app).await,
             CleanupModeState::Error(state) => state.handle_key(code),
@@ -151,12 +160,27 @@ impl AppState for CleanupModeState {
             CleanupModeState::BranchSelection(state) => {
                 ModeState::process_mouse(state, event, app).await
             }
+            CleanupModeState::ReleaseLabels(state) => {
+                ModeState::process_mouse(state, event, app).await
+            }
             CleanupModeState::Execution(state) => ModeState::process_mouse(state, event, app).await,
             CleanupModeState::Results(state) => ModeState::process_mouse(state, event, app).await,
             CleanupModeState::Error(_) => StateChange::Keep,
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        match self {
+            CleanupModeState::SettingsConfirmation(state) => state.action_map(),
+            CleanupModeState::DataLoading(state) => ModeState::action_map(state),
+            CleanupModeState::BranchSelection(state) => ModeState::action_map(state),
+            CleanupModeState::ReleaseLabels(state) => ModeState::action_map(state),
+            CleanupModeState::Execution(state) => ModeState::action_map(state),
+            CleanupModeState::Results(state) => ModeState::action_map(state),
+            CleanupModeState::Error(state) => state.action_map(),
+        }
+    }
+
     fn name(&self) -> &'static str {
         CleanupModeState::name(self)
     }
@@ -175,6 +199,7 @@ mod tests {
                 project: ParsedProperty::Default("test-project".to_string()),
                 repository: ParsedProperty::Default("test-repo".to_string()),
                 pat: ParsedProperty::Default("test-pat".to_string()),
+                write_pat: None,
                 dev_branch: ParsedProperty::Default("develop".to_string()),
                 target_branch: ParsedProperty::Default("main".to_string()),
                 local_repo: None,
@@ -183,7 +208,12 @@ mod tests {
                 max_concurrent_processing: ParsedProperty::Default(5),
                 tag_prefix: ParsedProperty::Default("merged/".to_string()),
                 since: None,
+                since_field: Default::default(),
                 skip_confirmation: false,
+                api_version: None,
+                history_depth: None,
+                url_templates: Default::default(),
+                release_notes_locale: Default::default(),
             },
             cleanup: CleanupModeConfig {
                 target: ParsedProperty::Default("main".to_string()),
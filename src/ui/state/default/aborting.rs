@@ -1,4 +1,5 @@
 use super::MergeState;
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::{
     git,
     ui::apps::MergeApp,
@@ -23,9 +24,14 @@ use std::{
 ///
 /// This state provides immediate UI feedback while running cleanup operations
 /// (git cherry-pick --abort, worktree removal, branch deletion) in a background thread.
+/// A selective abort first keeps the commits applied so far on a rescue branch.
 pub struct AbortingState {
     is_complete: Arc<Mutex<bool>>,
     cleanup_result: Arc<Mutex<Option<Result<(), String>>>>,
+    /// Commit the rescue branch points to, once a selective abort created it.
+    rescue_tip: Arc<Mutex<Option<String>>>,
+    keep_applied: bool,
+    is_clone: bool,
     repo_path: PathBuf,
     version: String,
     target_branch: String,
@@ -44,24 +50,63 @@ impl AbortingState {
         repo_path: PathBuf,
         version: String,
         target_branch: String,
+    ) -> Self {
+        Self::start(base_repo_path, repo_path, version, target_branch, false)
+    }
+
+    /// Create an aborting state that keeps the commits applied so far.
+    ///
+    /// Only the cherry-pick in conflict is rolled back; the patch branch is
+    /// saved as `patch/<target>-<version>-partial` before cleanup. A cloned
+    /// repository (no `base_repo_path`) must be kept on disk by the caller.
+    pub fn keeping_applied(
+        base_repo_path: Option<PathBuf>,
+        repo_path: PathBuf,
+        version: String,
+        target_branch: String,
+    ) -> Self {
+        Self::start(base_repo_path, repo_path, version, target_branch, true)
+    }
+
+    fn start(
+        base_repo_path: Option<PathBuf>,
+        repo_path: PathBuf,
+        version: String,
+        target_branch: String,
+        keep_applied: bool,
     ) -> Self {
         let is_complete = Arc::new(Mutex::new(false));
         let cleanup_result = Arc::new(Mutex::new(None));
+        let rescue_tip = Arc::new(Mutex::new(None));
+        let is_clone = base_repo_path.is_none();
 
         let is_complete_clone = is_complete.clone();
         let cleanup_result_clone = cleanup_result.clone();
+        let rescue_tip_clone = rescue_tip.clone();
         let repo_path_clone = repo_path.clone();
         let version_clone = version.clone();
         let target_branch_clone = target_branch.clone();
 
         // Spawn a thread to run the cleanup in the background
         thread::spawn(move || {
-            let result = git::cleanup_cherry_pick(
-                base_repo_path.as_deref(),
-                &repo_path_clone,
-                &version_clone,
-                &target_branch_clone,
-            );
+            let result = if keep_applied {
+                git::abort_keeping_applied(
+                    base_repo_path.as_deref(),
+                    &repo_path_clone,
+                    &version_clone,
+                    &target_branch_clone,
+                )
+                .map(|tip| {
+                    *rescue_tip_clone.lock().unwrap() = Some(tip);
+                })
+            } else {
+                git::cleanup_cherry_pick(
+                    base_repo_path.as_deref(),
+                    &repo_path_clone,
+                    &version_clone,
+                    &target_branch_clone,
+                )
+            };
 
             // Store the result
             *cleanup_result_clone.lock().unwrap() = Some(result.map_err(|e| e.to_string()));
@@ -71,6 +116,9 @@ impl AbortingState {
         Self {
             is_complete,
             cleanup_result,
+            rescue_tip,
+            keep_applied,
+            is_clone,
             repo_path,
             version,
             target_branch,
@@ -88,6 +136,9 @@ impl AbortingState {
         Self {
             is_complete: Arc::new(Mutex::new(is_complete)),
             cleanup_result: Arc::new(Mutex::new(cleanup_result)),
+            rescue_tip: Arc::new(Mutex::new(None)),
+            keep_applied: false,
+            is_clone: false,
             repo_path,
             version,
             target_branch,
@@ -145,15 +196,30 @@ impl ModeState for AbortingState {
         )]));
         content_text.push(Line::from(""));
 
+        let rescue_branch = git::rescue_branch_name(&self.target_branch, &self.version);
+        let rescue_tip = self.rescue_tip.lock().unwrap().clone();
         if is_complete {
             content_text.push(Line::from(vec![
                 Span::styled("✓ ", Style::default().fg(Color::Green)),
                 Span::raw("Aborted cherry-pick in progress"),
             ]));
-            content_text.push(Line::from(vec![
-                Span::styled("✓ ", Style::default().fg(Color::Green)),
-                Span::raw("Cleaned up patch branch"),
-            ]));
+            if let Some(tip) = &rescue_tip {
+                content_text.push(Line::from(vec![
+                    Span::styled("✓ ", Style::default().fg(Color::Green)),
+                    Span::raw("Kept applied commits on "),
+                    Span::styled(rescue_branch.clone(), Style::default().fg(Color::Cyan)),
+                    Span::styled(
+                        format!(" ({})", &tip[..tip.len().min(8)]),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ]));
+            }
+            if !self.keep_applied || rescue_tip.is_some() {
+                content_text.push(Line::from(vec![
+                    Span::styled("✓ ", Style::default().fg(Color::Green)),
+                    Span::raw("Cleaned up patch branch"),
+                ]));
+            }
 
             // Check if there was an error
             let cleanup_result = self.cleanup_result.lock().unwrap();
@@ -169,6 +235,12 @@ impl ModeState for AbortingState {
                 Span::styled("⏳ ", Style::default().fg(Color::Yellow)),
                 Span::raw("Aborting cherry-pick in progress..."),
             ]));
+            if self.keep_applied {
+                content_text.push(Line::from(vec![
+                    Span::styled("⏳ ", Style::default().fg(Color::Yellow)),
+                    Span::raw(format!("Keeping applied commits on {}...", rescue_branch)),
+                ]));
+            }
             content_text.push(Line::from(vec![
                 Span::styled("⏳ ", Style::default().fg(Color::Yellow)),
                 Span::raw("Cleaning up patch branch..."),
@@ -200,6 +272,18 @@ impl ModeState for AbortingState {
                 Style::default().fg(Color::Cyan),
             ),
         ]));
+        if rescue_tip.is_some() {
+            content_text.push(Line::from(vec![
+                Span::raw("Rescue branch: "),
+                Span::styled(rescue_branch, Style::default().fg(Color::Cyan)),
+            ]));
+            if self.is_clone {
+                content_text.push(Line::from(Span::styled(
+                    "The cloned repository is kept so the rescue branch is not lost.",
+                    Style::default().fg(Color::Gray),
+                )));
+            }
+        }
 
         let content = Paragraph::new(content_text)
             .block(
@@ -246,6 +330,15 @@ impl ModeState for AbortingState {
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        let map = ActionMap::new("Aborting");
+        if *self.is_complete.lock().unwrap() {
+            map.bind_any_key(ActionCategory::General, "Continue to results")
+        } else {
+            map
+        }
+    }
+
     fn name(&self) -> &'static str {
         "Aborting"
     }
@@ -349,6 +442,40 @@ mod tests {
         });
     }
 
+    /// # Aborting State - Complete Keeping Applied Commits
+    ///
+    /// Tests the aborting screen after a selective abort of a cloned repo.
+    ///
+    /// ## Test Scenario
+    /// - Creates a completed selective abort with a rescue branch tip
+    /// - Renders the aborting screen
+    ///
+    /// ## Expected Outcome
+    /// - Should show the rescue branch with its tip
+    /// - Should note that the clone is kept
+    #[test]
+    fn test_aborting_complete_keeping_applied() {
+        with_settings_and_module_path(module_path!(), || {
+            let config = create_test_config_default();
+            let mut harness = TuiTestHarness::with_config(config);
+
+            let mut state = AbortingState::new_test(
+                PathBuf::from("/path/to/repo"),
+                "v1.0.0".to_string(),
+                "main".to_string(),
+                true, // Complete
+                Some(Ok(())),
+            );
+            state.keep_applied = true;
+            state.is_clone = true;
+            *state.rescue_tip.lock().unwrap() =
+                Some("a1b2c3d4e5f60718293a4b5c6d7e8f9012345678".to_string());
+
+            harness.render_state(&mut state);
+            assert_snapshot!("complete_keeping_applied", harness.backend());
+        });
+    }
+
     /// # Aborting State - Key Press During Processing
     ///
     /// Tests that key presses are ignored during cleanup.
@@ -0,0 +1,826 @@
+//! Side-by-side log of the target branch and the patch branch.
+//!
+//! Before pushing, the patch branch can be checked against the target: each
+//! row pairs a commit on the target with one on the patch branch. Picks are
+//! matched to the PR they came from by patch-id, falling back to the PR
+//! title when the diff changed (e.g. after conflict resolution), and a pick
+//! whose change already landed on the target is shown next to that commit.
+
+use crate::ui::keymap::{ActionCategory, ActionMap};
+use crate::{
+    git::LogEntry,
+    ui::apps::MergeApp,
+    ui::state::default::{CompletionState, MergeState},
+    ui::state::typed::{ModeState, StateChange},
+    utils::truncate_str,
+};
+use async_trait::async_trait;
+use crossterm::event::KeyCode;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tokio::task::JoinHandle;
+
+/// Commits listed per branch.
+const LOG_LIMIT: usize = 200;
+
+/// How a pick on the patch branch was matched to its PR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SourceMatch {
+    /// Same patch-id as the PR's merge commit.
+    PatchId(i32),
+    /// Same PR title, but the diff differs.
+    Title(i32),
+}
+
+/// A commit that was cherry-picked, as recorded in the merge session.
+struct PickSource {
+    pr_id: i32,
+    pr_title: String,
+    patch_id: Option<String>,
+}
+
+/// One aligned row of the two logs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LogRow {
+    target: Option<LogEntry>,
+    patch: Option<LogEntry>,
+    source: Option<SourceMatch>,
+}
+
+impl LogRow {
+    fn is_shared(&self) -> bool {
+        matches!((&self.target, &self.patch), (Some(t), Some(p)) if t.commit_id == p.commit_id)
+    }
+
+    fn is_duplicate(&self) -> bool {
+        self.target.is_some() && self.patch.is_some() && !self.is_shared()
+    }
+
+    fn matches(&self, query: &str) -> bool {
+        let entry_matches = |entry: &Option<LogEntry>| {
+            entry.as_ref().is_some_and(|e| {
+                e.commit_id.starts_with(query) || e.subject.to_lowercase().contains(query)
+            })
+        };
+        let pr_matches = match self.source {
+            Some(SourceMatch::PatchId(pr_id) | SourceMatch::Title(pr_id)) => {
+                format!("#{}", pr_id) == query
+            }
+            None => false,
+        };
+        entry_matches(&self.target) || entry_matches(&self.patch) || pr_matches
+    }
+}
+
+/// Both logs, aligned row by row.
+struct BranchLogs {
+    target_ref: String,
+    rows: Vec<LogRow>,
+}
+
+/// Pair the commits of the target and patch branches.
+///
+/// Commits above the merge base are the picks (patch side) and the commits
+/// the target gained meanwhile (target side). A pick with the same patch-id
+/// as one of those target commits is placed on its row; the rest get a row
+/// of their own, in log order. The shared history below follows.
+fn align(
+    target: Vec<LogEntry>,
+    patch: Vec<LogEntry>,
+    base: Option<&str>,
+    sources: &[PickSource],
+) -> Vec<LogRow> {
+    let is_base = |entry: &LogEntry| Some(entry.commit_id.as_str()) == base;
+    let split = target.iter().position(is_base).unwrap_or(target.len());
+    let mut target = target.into_iter();
+    let target_new: Vec<LogEntry> = target.by_ref().take(split).collect();
+    let picks = patch.into_iter().take_while(|entry| !is_base(entry));
+
+    let source_of = |pick: &LogEntry| {
+        sources
+            .iter()
+            .find(|s| s.patch_id.is_some() && s.patch_id == pick.patch_id)
+            .map(|s| SourceMatch::PatchId(s.pr_id))
+            .or_else(|| {
+                sources
+                    .iter()
+                    .find(|s| !s.pr_title.is_empty() && pick.subject.contains(&s.pr_title))
+                    .map(|s| SourceMatch::Title(s.pr_id))
+            })
+    };
+    let landed: HashMap<&str, usize> = target_new
+        .iter()
+        .enumerate()
+        .filter_map(|(i, entry)| entry.patch_id.as_deref().map(|id| (id, i)))
+        .collect();
+
+    let mut rows = Vec::new();
+    let mut next_target = 0;
+    let mut paired = vec![false; target_new.len()];
+    for pick in picks {
+        let source = source_of(&pick);
+        let duplicate = pick
+            .patch_id
+            .as_deref()
+            .and_then(|id| landed.get(id).copied())
+            .filter(|&i| !paired[i]);
+        match duplicate {
+            Some(i) => {
+                for entry in &target_new[next_target.min(i)..i] {
+                    rows.push(LogRow {
+                        target: Some(entry.clone()),
+                        patch: None,
+                        source: None,
+                    });
+                }
+                paired[next_target.min(i)..=i].fill(true);
+                next_target = next_target.max(i + 1);
+                rows.push(LogRow {
+                    target: Some(target_new[i].clone()),
+                    patch: Some(pick),
+                    source,
+                });
+            }
+            None => rows.push(LogRow {
+                target: None,
+                patch: Some(pick),
+                source,
+            }),
+        }
+    }
+    for (i, entry) in target_new.iter().enumerate() {
+        if !paired[i] {
+            rows.push(LogRow {
+                target: Some(entry.clone()),
+                patch: None,
+                source: None,
+            });
+        }
+    }
+    rows.extend(target.map(|entry| LogRow {
+        target: Some(entry.clone()),
+        patch: Some(entry),
+        source: None,
+    }));
+    rows
+}
+
+/// Read both logs from the worktree and align them.
+fn load_logs(
+    repo_path: PathBuf,
+    target_branch: String,
+    sources: Vec<(String, i32, String)>,
+) -> anyhow::Result<BranchLogs> {
+    let target_ref =
+        crate::git::fetch_remote_tracking_ref(&repo_path, &target_branch)?.unwrap_or(target_branch);
+    let target = crate::git::branch_log(&repo_path, &target_ref, LOG_LIMIT)?;
+    let patch = crate::git::branch_log(&repo_path, "HEAD", LOG_LIMIT)?;
+    let base = crate::git::merge_base(&repo_path, "HEAD", &target_ref)?;
+
+    let commit_ids: Vec<String> = sources.iter().map(|(id, _, _)| id.clone()).collect();
+    let mut patch_ids = crate::git::patch_ids(&repo_path, &commit_ids).unwrap_or_else(|e| {
+        tracing::warn!("Failed to compute patch-ids of PR commits: {:#}", e);
+        HashMap::new()
+    });
+    let sources: Vec<PickSource> = sources
+        .into_iter()
+        .map(|(commit_id, pr_id, pr_title)| PickSource {
+            pr_id,
+            pr_title,
+            patch_id: patch_ids.remove(&commit_id),
+        })
+        .collect();
+
+    Ok(BranchLogs {
+        rows: align(target, patch, base.as_deref(), &sources),
+        target_ref,
+    })
+}
+
+pub struct BranchLogState {
+    load_task: Option<JoinHandle<anyhow::Result<BranchLogs>>>,
+    logs: Option<BranchLogs>,
+    error: Option<String>,
+    list_state: ListState,
+    /// Rows visible in the last render, for page scrolling.
+    page_height: usize,
+    search_mode: bool,
+    search_input: String,
+    /// Lowercased query whose matches `n`/`N` step through.
+    search_query: String,
+    search_results: Vec<usize>,
+}
+
+impl Default for BranchLogState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BranchLogState {
+    pub fn new() -> Self {
+        Self {
+            load_task: None,
+            logs: None,
+            error: None,
+            list_state: ListState::default(),
+            page_height: 10,
+            search_mode: false,
+            search_input: String::new(),
+            search_query: String::new(),
+            search_results: Vec::new(),
+        }
+    }
+
+    /// Starts loading the logs, or collects them once loaded.
+    async fn poll_load(&mut self, app: &MergeApp) {
+        if let Some(task) = self.load_task.take_if(|task| task.is_finished()) {
+            match task.await {
+                Ok(Ok(logs)) => {
+                    self.list_state.select((!logs.rows.is_empty()).then_some(0));
+                    self.logs = Some(logs);
+                }
+                Ok(Err(e)) => self.error = Some(format!("{:#}", e)),
+                Err(e) => self.error = Some(format!("Task error: {}", e)),
+            }
+        }
+        if self.logs.is_some() || self.error.is_some() || self.load_task.is_some() {
+            return;
+        }
+
+        let Some(repo_path) = app.repo_path().map(|path| path.to_path_buf()) else {
+            self.error = Some("No repository to read the branch logs from".to_string());
+            return;
+        };
+        let target_branch = app.target_branch().to_string();
+        let sources = app
+            .cherry_pick_items
+            .iter()
+            .map(|item| (item.commit_id.clone(), item.pr_id, item.pr_title.clone()))
+            .collect();
+        self.load_task = Some(tokio::task::spawn_blocking(move || {
+            load_logs(repo_path, target_branch, sources)
+        }));
+    }
+
+    fn row_count(&self) -> usize {
+        self.logs.as_ref().map_or(0, |logs| logs.rows.len())
+    }
+
+    fn scroll_by(&mut self, delta: isize) {
+        let count = self.row_count();
+        if count == 0 {
+            return;
+        }
+        let current = self.list_state.selected().unwrap_or(0);
+        let next = current.saturating_add_signed(delta).min(count - 1);
+        self.list_state.select(Some(next));
+    }
+
+    fn execute_search(&mut self) {
+        self.search_query = self.search_input.trim().to_lowercase();
+        self.search_results = match &self.logs {
+            Some(logs) if !self.search_query.is_empty() => logs
+                .rows
+                .iter()
+                .enumerate()
+                .filter(|(_, row)| row.matches(&self.search_query))
+                .map(|(i, _)| i)
+                .collect(),
+            _ => Vec::new(),
+        };
+        let current = self.list_state.selected().unwrap_or(0);
+        if let Some(&first) = self
+            .search_results
+            .iter()
+            .find(|&&i| i >= current)
+            .or(self.search_results.first())
+        {
+            self.list_state.select(Some(first));
+        }
+    }
+
+    fn next_match(&mut self, forward: bool) {
+        let current = self.list_state.selected().unwrap_or(0);
+        let found = if forward {
+            self.search_results
+                .iter()
+                .find(|&&i| i > current)
+                .or(self.search_results.first())
+        } else {
+            self.search_results
+                .iter()
+                .rev()
+                .find(|&&i| i < current)
+                .or(self.search_results.last())
+        };
+        if let Some(&i) = found {
+            self.list_state.select(Some(i));
+        }
+    }
+
+    fn clear_search(&mut self) {
+        self.search_input.clear();
+        self.search_query.clear();
+        self.search_results.clear();
+    }
+
+    fn render_entry(entry: Option<&LogEntry>, width: usize, style: Style) -> Vec<Span<'static>> {
+        let Some(entry) = entry else {
+            return vec![Span::raw("")];
+        };
+        let short = truncate_str(&entry.commit_id, 8).to_string();
+        let subject_width = width.saturating_sub(short.len() + 1);
+        vec![
+            Span::styled(short, Style::default().fg(Color::DarkGray)),
+            Span::raw(" "),
+            Span::styled(
+                truncate_str(&entry.subject, subject_width).to_string(),
+                style,
+            ),
+        ]
+    }
+}
+
+#[async_trait]
+impl ModeState for BranchLogState {
+    type Mode = MergeState;
+
+    fn ui(&mut self, f: &mut Frame, app: &MergeApp) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(5),
+                Constraint::Length(4),
+            ])
+            .split(f.area());
+
+        let target_ref = self
+            .logs
+            .as_ref()
+            .map_or(app.target_branch(), |logs| logs.target_ref.as_str());
+        let title = Paragraph::new(Line::from(vec![
+            Span::styled(
+                target_ref.to_string(),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("  vs  "),
+            Span::styled(
+                "patch branch (HEAD)",
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]))
+        .block(Block::default().borders(Borders::ALL).title("Branch Log"))
+        .alignment(ratatui::layout::Alignment::Center);
+        f.render_widget(title, chunks[0]);
+
+        let Some(logs) = &self.logs else {
+            let (text, color) = match &self.error {
+                Some(error) => (format!("Failed to read branch logs: {}", error), Color::Red),
+                None => ("Loading branch logs...".to_string(), Color::Yellow),
+            };
+            let body = Paragraph::new(text)
+                .style(Style::default().fg(color))
+                .block(Block::default().borders(Borders::ALL));
+            f.render_widget(body, chunks[1]);
+            f.render_widget(
+                Paragraph::new("Esc: Back").block(Block::default().borders(Borders::ALL)),
+                chunks[2],
+            );
+            return;
+        };
+
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[1]);
+        let pane_width = panes[0].width.saturating_sub(2) as usize;
+        self.page_height = panes[0].height.saturating_sub(2).max(1) as usize;
+
+        let mut target_items = Vec::with_capacity(logs.rows.len());
+        let mut patch_items = Vec::with_capacity(logs.rows.len());
+        for (i, row) in logs.rows.iter().enumerate() {
+            let (target_style, patch_style, marker) = if row.is_shared() {
+                let style = Style::default().fg(Color::DarkGray);
+                (style, style, None)
+            } else if row.is_duplicate() {
+                let style = Style::default().fg(Color::Yellow);
+                (style, style, Some(("⚠ on target ", Color::Yellow)))
+            } else {
+                let marker = match row.source {
+                    Some(SourceMatch::PatchId(_)) => ("✓ ", Color::Green),
+                    Some(SourceMatch::Title(_)) => ("~ ", Color::Yellow),
+                    None => ("? ", Color::Red),
+                };
+                (
+                    Style::default().fg(Color::Cyan),
+                    Style::default().fg(Color::White),
+                    row.patch.is_some().then_some(marker),
+                )
+            };
+            let (target_style, patch_style) = if self.search_results.contains(&i) {
+                (
+                    target_style.add_modifier(Modifier::UNDERLINED),
+                    patch_style.add_modifier(Modifier::UNDERLINED),
+                )
+            } else {
+                (target_style, patch_style)
+            };
+
+            target_items.push(ListItem::new(Line::from(Self::render_entry(
+                row.target.as_ref(),
+                pane_width,
+                target_style,
+            ))));
+
+            let mut spans = Vec::new();
+            let mut width = pane_width;
+            if let Some((marker, color)) = marker {
+                spans.push(Span::styled(marker, Style::default().fg(color)));
+                width = width.saturating_sub(marker.chars().count());
+            }
+            if let Some(SourceMatch::PatchId(pr_id) | SourceMatch::Title(pr_id)) = row.source {
+                let label = format!("#{} ", pr_id);
+                width = width.saturating_sub(label.len());
+                spans.push(Span::styled(label, Style::default().fg(Color::Magenta)));
+            }
+            spans.extend(Self::render_entry(row.patch.as_ref(), width, patch_style));
+            patch_items.push(ListItem::new(Line::from(spans)));
+        }
+
+        let highlight = Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD);
+        let target_list = List::new(target_items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("Target ({})", logs.target_ref)),
+            )
+            .highlight_style(highlight);
+        f.render_stateful_widget(target_list, panes[0], &mut self.list_state);
+        let patch_list = List::new(patch_items)
+            .block(Block::default().borders(Borders::ALL).title("Patch branch"))
+            .highlight_style(highlight);
+        // Same selection and offset as the target pane, so rows stay aligned
+        f.render_stateful_widget(patch_list, panes[1], &mut self.list_state.clone());
+
+        let key_style = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let status = if self.search_mode {
+            Line::from(vec![
+                Span::styled("/", key_style),
+                Span::raw(self.search_input.clone()),
+                Span::styled("█", Style::default().fg(Color::Gray)),
+            ])
+        } else if !self.search_query.is_empty() {
+            let position = self
+                .list_state
+                .selected()
+                .and_then(|selected| self.search_results.iter().position(|&i| i == selected))
+                .map_or("-".to_string(), |i| (i + 1).to_string());
+            Line::from(format!(
+                "Match {}/{} for '{}'",
+                position,
+                self.search_results.len(),
+                self.search_query
+            ))
+        } else {
+            Line::from(vec![
+                Span::styled("✓", Style::default().fg(Color::Green)),
+                Span::raw(" same change as PR  "),
+                Span::styled("~", Style::default().fg(Color::Yellow)),
+                Span::raw(" PR title only, diff changed  "),
+                Span::styled("?", Style::default().fg(Color::Red)),
+                Span::raw(" no PR  "),
+                Span::styled("⚠", Style::default().fg(Color::Yellow)),
+                Span::raw(" already on target"),
+            ])
+        };
+        let help = Line::from(vec![
+            Span::styled("↑/↓ PgUp/PgDn", key_style),
+            Span::raw(": Scroll  "),
+            Span::styled("/", key_style),
+            Span::raw(": Search  "),
+            Span::styled("n/N", key_style),
+            Span::raw(": Next/previous match  "),
+            Span::styled("Esc", key_style),
+            Span::raw(": Back"),
+        ]);
+        f.render_widget(
+            Paragraph::new(vec![status, help]).block(Block::default().borders(Borders::ALL)),
+            chunks[2],
+        );
+    }
+
+    async fn process_key(&mut self, code: KeyCode, app: &mut MergeApp) -> StateChange<MergeState> {
+        if self.search_mode {
+            match code {
+                KeyCode::Null => self.poll_load(app).await,
+                KeyCode::Char(c) => self.search_input.push(c),
+                KeyCode::Backspace => {
+                    self.search_input.pop();
+                }
+                KeyCode::Enter => {
+                    self.search_mode = false;
+                    self.execute_search();
+                }
+                KeyCode::Esc => {
+                    self.search_mode = false;
+                    self.search_input.clear();
+                }
+                _ => {}
+            }
+            return StateChange::Keep;
+        }
+
+        match code {
+            KeyCode::Null => self.poll_load(app).await,
+            KeyCode::Up | KeyCode::Char('k') => self.scroll_by(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_by(1),
+            KeyCode::PageUp => self.scroll_by(-(self.page_height as isize)),
+            KeyCode::PageDown => self.scroll_by(self.page_height as isize),
+            KeyCode::Home => self.scroll_by(isize::MIN),
+            KeyCode::End => self.scroll_by(isize::MAX),
+            KeyCode::Char('/') => {
+                self.search_mode = true;
+                self.search_input.clear();
+            }
+            KeyCode::Char('n') => self.next_match(true),
+            KeyCode::Char('N') => self.next_match(false),
+            KeyCode::Esc if !self.search_query.is_empty() => self.clear_search(),
+            KeyCode::Esc => {
+                return StateChange::Change(MergeState::Completion(CompletionState::new()));
+            }
+            _ => {}
+        }
+        StateChange::Keep
+    }
+
+    fn action_map(&self) -> ActionMap {
+        let map = ActionMap::new("Branch Log");
+        if self.search_mode {
+            return map
+                .with_text_input()
+                .bind(ActionCategory::Actions, &[KeyCode::Enter], "Search")
+                .bind(ActionCategory::General, &[KeyCode::Esc], "Cancel search");
+        }
+        map.bind(
+            ActionCategory::Navigation,
+            &[
+                KeyCode::Up,
+                KeyCode::Down,
+                KeyCode::Char('k'),
+                KeyCode::Char('j'),
+            ],
+            "Scroll",
+        )
+        .bind(
+            ActionCategory::Navigation,
+            &[
+                KeyCode::PageUp,
+                KeyCode::PageDown,
+                KeyCode::Home,
+                KeyCode::End,
+            ],
+            "Scroll by page / to either end",
+        )
+        .bind(ActionCategory::View, &[KeyCode::Char('/')], "Search")
+        .bind(
+            ActionCategory::Navigation,
+            &[KeyCode::Char('n'), KeyCode::Char('N')],
+            "Next/previous match",
+        )
+        .bind(
+            ActionCategory::General,
+            &[KeyCode::Esc],
+            "Clear search / back to completion summary",
+        )
+    }
+
+    fn name(&self) -> &'static str {
+        "BranchLog"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{
+        snapshot_testing::with_settings_and_module_path,
+        testing::{TuiTestHarness, create_test_config_default},
+    };
+    use insta::assert_snapshot;
+
+    fn entry(commit_id: &str, subject: &str, patch_id: Option<&str>) -> LogEntry {
+        LogEntry {
+            commit_id: commit_id.to_string(),
+            subject: subject.to_string(),
+            patch_id: patch_id.map(String::from),
+        }
+    }
+
+    /// Target moved by two commits, one of which is also picked.
+    fn sample_logs() -> BranchLogs {
+        let target = vec![
+            entry("aaaa0003", "Hotfix login", Some("p-hotfix")),
+            entry("aaaa0002", "Merged PR 12: Fix crash", Some("p-12")),
+            entry("bbbb0001", "Release 1.0", Some("p-base")),
+            entry("bbbb0000", "Initial commit", Some("p-init")),
+        ];
+        let patch = vec![
+            entry(
+                "cccc0003",
+                "Merged PR 14: Manual change",
+                Some("p-14-edited"),
+            ),
+            entry("cccc0002", "Merged PR 12: Fix crash", Some("p-12")),
+            entry("cccc0001", "Merged PR 10: Add search", Some("p-10")),
+            entry("dddd0000", "Unrelated commit", Some("p-other")),
+            entry("bbbb0001", "Release 1.0", Some("p-base")),
+            entry("bbbb0000", "Initial commit", Some("p-init")),
+        ];
+        let sources = vec![
+            PickSource {
+                pr_id: 10,
+                pr_title: "Add search".to_string(),
+                patch_id: Some("p-10".to_string()),
+            },
+            PickSource {
+                pr_id: 12,
+                pr_title: "Fix crash".to_string(),
+                patch_id: Some("p-12".to_string()),
+            },
+            PickSource {
+                pr_id: 14,
+                pr_title: "Manual change".to_string(),
+                patch_id: Some("p-14".to_string()),
+            },
+        ];
+        BranchLogs {
+            target_ref: "origin/main".to_string(),
+            rows: align(target, patch, Some("bbbb0001"), &sources),
+        }
+    }
+
+    fn loaded_state() -> BranchLogState {
+        let mut state = BranchLogState::new();
+        state.logs = Some(sample_logs());
+        state.list_state.select(Some(0));
+        state
+    }
+
+    /// # Log Alignment
+    ///
+    /// Tests pairing the target and patch branch logs.
+    ///
+    /// ## Test Scenario
+    /// - The target gained two commits; the patch branch has four picks
+    /// - One pick has the same patch-id as a new target commit
+    ///
+    /// ## Expected Outcome
+    /// - Picks are matched to their PRs by patch-id, then by title
+    /// - The duplicate pick shares a row with the target commit
+    /// - Unmatched target commits and the shared history follow
+    #[test]
+    fn test_align_rows() {
+        let rows = sample_logs().rows;
+        let summary: Vec<(Option<&str>, Option<&str>, Option<SourceMatch>)> = rows
+            .iter()
+            .map(|row| {
+                (
+                    row.target.as_ref().map(|e| e.commit_id.as_str()),
+                    row.patch.as_ref().map(|e| e.commit_id.as_str()),
+                    row.source,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                (None, Some("cccc0003"), Some(SourceMatch::Title(14))),
+                (Some("aaaa0003"), None, None),
+                (
+                    Some("aaaa0002"),
+                    Some("cccc0002"),
+                    Some(SourceMatch::PatchId(12))
+                ),
+                (None, Some("cccc0001"), Some(SourceMatch::PatchId(10))),
+                (None, Some("dddd0000"), None),
+                (Some("bbbb0001"), Some("bbbb0001"), None),
+                (Some("bbbb0000"), Some("bbbb0000"), None),
+            ]
+        );
+        assert!(rows[2].is_duplicate());
+        assert!(rows[5].is_shared());
+        assert!(!rows[0].is_duplicate() && !rows[0].is_shared());
+    }
+
+    /// # Branch Log - Display
+    ///
+    /// Tests the side-by-side rendering of both logs.
+    ///
+    /// ## Test Scenario
+    /// - Renders the aligned sample logs
+    ///
+    /// ## Expected Outcome
+    /// - Both panes list their commits on matching rows with PR markers
+    #[test]
+    fn test_branch_log_display() {
+        with_settings_and_module_path(module_path!(), || {
+            let mut harness = TuiTestHarness::with_config(create_test_config_default());
+            let mut state = loaded_state();
+            harness.render_state(&mut state);
+            assert_snapshot!("display", harness.backend());
+        });
+    }
+
+    /// # Branch Log - Search
+    ///
+    /// Tests searching the logs and stepping through matches.
+    ///
+    /// ## Test Scenario
+    /// - Searches for "merged pr 1", then presses `n` and `N`
+    /// - Presses Esc twice
+    ///
+    /// ## Expected Outcome
+    /// - The highlight jumps between the matching rows, wrapping around
+    /// - The first Esc clears the search, the second returns to completion
+    #[tokio::test]
+    async fn test_branch_log_search() {
+        let mut harness = TuiTestHarness::with_config(create_test_config_default());
+        let mut state = loaded_state();
+        state.list_state.select(Some(1));
+
+        for key in "/merged pr 1"
+            .chars()
+            .map(KeyCode::Char)
+            .chain([KeyCode::Enter])
+        {
+            ModeState::process_key(&mut state, key, harness.merge_app_mut()).await;
+        }
+        assert!(!state.search_mode);
+        assert_eq!(state.search_results, vec![0, 2, 3]);
+        assert_eq!(state.list_state.selected(), Some(2));
+
+        ModeState::process_key(&mut state, KeyCode::Char('n'), harness.merge_app_mut()).await;
+        assert_eq!(state.list_state.selected(), Some(3));
+        ModeState::process_key(&mut state, KeyCode::Char('n'), harness.merge_app_mut()).await;
+        assert_eq!(state.list_state.selected(), Some(0));
+        ModeState::process_key(&mut state, KeyCode::Char('N'), harness.merge_app_mut()).await;
+        assert_eq!(state.list_state.selected(), Some(3));
+
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Esc, harness.merge_app_mut()).await;
+        assert!(matches!(result, StateChange::Keep));
+        assert!(state.search_results.is_empty());
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Esc, harness.merge_app_mut()).await;
+        assert!(matches!(
+            result,
+            StateChange::Change(MergeState::Completion(_))
+        ));
+    }
+
+    /// # Branch Log - Scrolling
+    ///
+    /// Tests line and page scrolling.
+    ///
+    /// ## Test Scenario
+    /// - Presses Down, End, Up, PageUp and PageDown
+    ///
+    /// ## Expected Outcome
+    /// - The highlight moves and stays within the rows
+    #[tokio::test]
+    async fn test_branch_log_scrolling() {
+        let mut harness = TuiTestHarness::with_config(create_test_config_default());
+        let mut state = loaded_state();
+        state.page_height = 3;
+
+        let mut press = async |state: &mut BranchLogState, key| {
+            ModeState::process_key(state, key, harness.merge_app_mut()).await;
+            state.list_state.selected()
+        };
+        assert_eq!(press(&mut state, KeyCode::Down).await, Some(1));
+        assert_eq!(press(&mut state, KeyCode::End).await, Some(6));
+        assert_eq!(press(&mut state, KeyCode::Down).await, Some(6));
+        assert_eq!(press(&mut state, KeyCode::Up).await, Some(5));
+        assert_eq!(press(&mut state, KeyCode::PageUp).await, Some(2));
+        assert_eq!(press(&mut state, KeyCode::PageUp).await, Some(0));
+        assert_eq!(press(&mut state, KeyCode::PageDown).await, Some(3));
+    }
+}
@@ -1,8 +1,10 @@
 use super::MergeState;
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::{
+    core::operations::{CherryPickOutcome, apply_binary_policy},
     core::state::{MergePhase, StateItemStatus},
     git,
-    models::CherryPickStatus,
+    models::{CherryPickItem, CherryPickStatus},
     ui::apps::MergeApp,
     ui::state::typed::{ModeState, StateChange},
     ui::state::{CompletionState, ConflictResolutionState, ErrorState},
@@ -12,7 +14,7 @@ use async_trait::async_trait;
 use crossterm::event::KeyCode;
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
@@ -20,6 +22,10 @@ use ratatui::{
 
 pub struct CherryPickState {
     processing: bool,
+    /// Whether picking is paused; ticks leave the queue alone meanwhile.
+    paused: bool,
+    /// Index of the failed pick the pipeline paused at.
+    failed_index: Option<usize>,
 }
 
 impl Default for CherryPickState {
@@ -30,11 +36,120 @@ impl Default for CherryPickState {
 
 impl CherryPickState {
     pub fn new() -> Self {
-        Self { processing: true }
+        Self {
+            processing: true,
+            paused: false,
+            failed_index: None,
+        }
     }
 
     pub fn continue_after_conflict() -> Self {
-        Self { processing: false }
+        Self {
+            processing: false,
+            paused: false,
+            failed_index: None,
+        }
+    }
+
+    /// Pauses after the pick at `index` failed, so it can be retried or
+    /// skipped.
+    pub fn paused_after_failure(index: usize) -> Self {
+        Self {
+            processing: false,
+            paused: true,
+            failed_index: Some(index),
+        }
+    }
+
+    /// Returns whether picking is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Index of the PR `s` skips: the failed pick, or else the next one.
+    fn skip_target(&self, app: &MergeApp) -> Option<usize> {
+        self.failed_index.or_else(|| {
+            (app.current_cherry_pick_index()..app.cherry_pick_items().len())
+                .find(|&i| matches!(app.cherry_pick_items()[i].status, CherryPickStatus::Pending))
+        })
+    }
+
+    /// Marks the failed or next PR as skipped. Skipping a failed pick
+    /// resumes picking.
+    fn skip(&mut self, app: &mut MergeApp) {
+        let Some(index) = self.skip_target(app) else {
+            return;
+        };
+        app.cherry_pick_items_mut()[index].status = CherryPickStatus::Skipped;
+        let _ = app.update_state_item_status(index, StateItemStatus::Skipped);
+        if self.failed_index.take().is_some() {
+            self.paused = false;
+        }
+    }
+
+    /// Queues the failed pick again and resumes picking with it.
+    fn retry(&mut self, app: &mut MergeApp) {
+        let Some(index) = self.failed_index.take() else {
+            return;
+        };
+        app.cherry_pick_items_mut()[index].status = CherryPickStatus::Pending;
+        let _ = app.update_state_item_status(index, StateItemStatus::Pending);
+        app.set_current_cherry_pick_index(index);
+        let _ = app.sync_state_current_index();
+        self.paused = false;
+    }
+
+    /// Key hints of the status bar.
+    fn status_line(&self, app: &MergeApp) -> Line<'static> {
+        let key_style = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let hint = |key: &'static str, action: String| {
+            vec![
+                Span::styled(key, key_style),
+                Span::raw(format!(" {}  ", action)),
+            ]
+        };
+        let skip_label = |app: &MergeApp| {
+            self.skip_target(app)
+                .map(|i| format!("skip #{}", app.cherry_pick_items()[i].pr_id))
+        };
+
+        let mut spans = Vec::new();
+        if let Some(index) = self.failed_index {
+            spans.push(Span::styled(
+                format!("PR #{} failed  ", app.cherry_pick_items()[index].pr_id),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ));
+            spans.extend(hint("r", "retry".to_string()));
+            spans.extend(hint("s", skip_label(app).unwrap_or_default()));
+            spans.extend(hint("!", "shell".to_string()));
+            spans.extend(hint("p", "continue".to_string()));
+        } else if self.paused {
+            spans.push(Span::styled(
+                "Paused  ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            spans.extend(hint("p", "resume".to_string()));
+            if let Some(label) = skip_label(app) {
+                spans.extend(hint("s", label));
+            }
+            spans.extend(hint("!", "shell".to_string()));
+        } else {
+            if self.processing {
+                spans.push(Span::raw("Processing cherry-picks...  "));
+            } else {
+                spans.push(Span::raw("Press "));
+                spans.push(Span::styled("any key", key_style));
+                spans.push(Span::raw(" to continue  "));
+            }
+            spans.extend(hint("p", "pause".to_string()));
+            spans.extend(hint("s", "skip next".to_string()));
+            spans.extend(hint("!", "shell".to_string()));
+        }
+        Line::from(spans)
     }
 }
 
@@ -66,94 +181,35 @@ impl ModeState for CherryPickState {
             .block(Block::default().borders(Borders::ALL));
         f.render_widget(title, chunks[0]);
 
-        // Split the main area horizontally
         let main_chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(6)])
             .split(chunks[1]);
 
-        // Left side: Commit list
-        let items: Vec<ListItem> = app
-            .cherry_pick_items()
-            .iter()
-            .enumerate()
-            .map(|(i, item)| {
-                let mut spans = vec![];
-
-                let (symbol, color) = match &item.status {
-                    CherryPickStatus::Pending => ("⏸", Color::Gray),
-                    CherryPickStatus::InProgress => ("⏳", Color::Yellow),
-                    CherryPickStatus::Success => ("✅", Color::Green),
-                    CherryPickStatus::Conflict => ("⚠️", Color::Yellow),
-                    CherryPickStatus::Skipped => ("⏭", Color::Gray),
-                    CherryPickStatus::Failed(_) => ("❌", Color::Red),
-                };
-
-                spans.push(Span::styled(
-                    format!("{} ", symbol),
-                    Style::default().fg(color),
-                ));
-                spans.push(Span::raw(format!(
-                    "[{}/{}] ",
-                    i + 1,
-                    app.cherry_pick_items().len()
-                )));
-                spans.push(Span::styled(
-                    format!("PR #{}: ", item.pr_id),
-                    Style::default().fg(Color::Cyan),
-                ));
-
-                // Truncate title if too long
-                let title = if item.pr_title.len() > 40 {
-                    format!("{}...", truncate_str(&item.pr_title, 37))
-                } else {
-                    item.pr_title.clone()
-                };
-                spans.push(Span::raw(title));
-
-                ListItem::new(Line::from(spans))
-            })
-            .collect();
-
-        let list = List::new(items)
-            .block(Block::default().borders(Borders::ALL).title("Commits"))
-            .highlight_style(Style::default().bg(Color::DarkGray));
-        f.render_widget(list, main_chunks[0]);
+        render_board(f, main_chunks[0], app);
 
-        // Right side: Details
+        // Bottom: Details of the current commit
+        let label = Style::default().fg(Color::Gray);
         let mut details_text = vec![];
 
-        if app.current_cherry_pick_index() < app.cherry_pick_items().len() {
-            let current_item = &app.cherry_pick_items()[app.current_cherry_pick_index()];
+        let shown_index = self.failed_index.unwrap_or(app.current_cherry_pick_index());
+        if shown_index < app.cherry_pick_items().len() {
+            let current_item = &app.cherry_pick_items()[shown_index];
 
-            details_text.push(Line::from(vec![
-                Span::raw("Current PR: "),
+            let mut spans = vec![
+                Span::styled("Current PR: ", label),
                 Span::styled(
                     format!("#{}", current_item.pr_id),
                     Style::default()
                         .fg(Color::Cyan)
                         .add_modifier(Modifier::BOLD),
                 ),
-            ]));
-
-            details_text.push(Line::from(""));
-            details_text.push(Line::from(vec![
-                Span::raw("Title: "),
-                Span::raw(&current_item.pr_title),
-            ]));
-
-            details_text.push(Line::from(""));
-            details_text.push(Line::from(vec![
-                Span::raw("Commit: "),
+                Span::styled("  Commit: ", label),
                 Span::styled(
-                    &current_item.commit_id[..8],
+                    truncate_str(&current_item.commit_id, 8),
                     Style::default().fg(Color::Yellow),
                 ),
-            ]));
-
-            details_text.push(Line::from(""));
-            details_text.push(Line::from(vec![
-                Span::raw("Status: "),
+                Span::styled("  Status: ", label),
                 Span::styled(
                     match &current_item.status {
                         CherryPickStatus::Pending => "Pending",
@@ -163,48 +219,49 @@ impl ModeState for CherryPickState {
                         CherryPickStatus::Skipped => "Skipped",
                         CherryPickStatus::Failed(_) => "Failed",
                     },
-                    Style::default().fg(match &current_item.status {
-                        CherryPickStatus::Success => Color::Green,
-                        CherryPickStatus::Failed(_) => Color::Red,
-                        CherryPickStatus::Conflict => Color::Yellow,
-                        CherryPickStatus::InProgress => Color::Yellow,
-                        CherryPickStatus::Skipped => Color::Gray,
-                        _ => Color::White,
-                    }),
+                    Style::default().fg(status_color(&current_item.status)),
                 ),
-            ]));
-
-            if let CherryPickStatus::Failed(msg) = &current_item.status {
-                details_text.push(Line::from(""));
-                details_text.push(Line::from(vec![
-                    Span::raw("Error: "),
-                    Span::styled(msg, Style::default().fg(Color::Red)),
-                ]));
+            ];
+            if let Some(parent) = app.mainline_parents().get(&current_item.pr_id) {
+                spans.push(Span::styled("  Parent: ", label));
+                spans.push(Span::styled(
+                    parent.to_string(),
+                    Style::default().fg(Color::Magenta),
+                ));
             }
+            details_text.push(Line::from(spans));
+            details_text.push(Line::from(vec![
+                Span::styled("Title: ", label),
+                Span::raw(&current_item.pr_title),
+            ]));
         }
 
-        details_text.push(Line::from(""));
-        details_text.push(Line::from("─────────────────────"));
-        details_text.push(Line::from(""));
-
         let branch_name = format!(
             "patch/{}-{}",
             app.target_branch(),
             app.version().as_ref().unwrap()
         );
-
-        details_text.push(Line::from(vec![
-            Span::raw("Branch: "),
+        let mut spans = vec![
+            Span::styled("Branch: ", label),
             Span::styled(branch_name, Style::default().fg(Color::Cyan)),
-        ]));
-
+        ];
         if let Some(repo_path) = &app.repo_path() {
+            spans.push(Span::styled("  Location: ", label));
+            spans.push(Span::styled(
+                format!("{}", repo_path.display()),
+                Style::default().fg(Color::Blue),
+            ));
+        }
+        details_text.push(Line::from(spans));
+
+        if let Some(CherryPickStatus::Failed(msg)) = app
+            .cherry_pick_items()
+            .get(shown_index)
+            .map(|item| &item.status)
+        {
             details_text.push(Line::from(vec![
-                Span::raw("Location: "),
-                Span::styled(
-                    format!("{}", repo_path.display()),
-                    Style::default().fg(Color::Blue),
-                ),
+                Span::styled("Error: ", label),
+                Span::styled(msg, Style::default().fg(Color::Red)),
             ]));
         }
 
@@ -213,25 +270,39 @@ impl ModeState for CherryPickState {
             .wrap(Wrap { trim: true });
         f.render_widget(details, main_chunks[1]);
 
-        let key_style = Style::default()
-            .fg(Color::Yellow)
-            .add_modifier(Modifier::BOLD);
-        let status_lines = if self.processing {
-            vec![Line::from("Processing cherry-picks...")]
-        } else {
-            vec![Line::from(vec![
-                Span::raw("Press "),
-                Span::styled("any key", key_style),
-                Span::raw(" to continue"),
-            ])]
-        };
-        let status_widget = Paragraph::new(status_lines)
+        let status_widget = Paragraph::new(self.status_line(app))
             .style(Style::default().fg(Color::Gray))
             .block(Block::default().borders(Borders::ALL));
         f.render_widget(status_widget, chunks[2]);
     }
 
-    async fn process_key(&mut self, _code: KeyCode, app: &mut MergeApp) -> StateChange<MergeState> {
+    async fn process_key(&mut self, code: KeyCode, app: &mut MergeApp) -> StateChange<MergeState> {
+        match code {
+            KeyCode::Char('p') => {
+                self.paused = !self.paused;
+                self.failed_index = None;
+                return StateChange::Keep;
+            }
+            KeyCode::Char('s') => {
+                self.skip(app);
+                return StateChange::Keep;
+            }
+            KeyCode::Char('r') => {
+                self.retry(app);
+                return StateChange::Keep;
+            }
+            KeyCode::Char('!') => {
+                if let Some(repo_path) = app.repo_path() {
+                    self.paused = true;
+                    let repo_path = repo_path.to_path_buf();
+                    app.request_shell(repo_path);
+                }
+                return StateChange::Keep;
+            }
+            _ if self.paused => return StateChange::Keep,
+            _ => {}
+        }
+
         if self.processing {
             // First time processing - fetch commits if needed
             self.processing = false;
@@ -258,11 +329,166 @@ impl ModeState for CherryPickState {
         process_next_commit(app)
     }
 
+    fn action_map(&self) -> ActionMap {
+        let map = ActionMap::new("Cherry-picking")
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('p')],
+                "Pause or resume cherry-picking",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('s')],
+                "Skip the failed PR, or else the next one",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('r')],
+                "Retry the failed pick",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('!')],
+                "Pause and open a shell in the worktree",
+            );
+        if self.paused || self.processing {
+            map
+        } else {
+            map.bind_any_key(ActionCategory::General, "Continue")
+        }
+    }
+
     fn name(&self) -> &'static str {
         "CherryPick"
     }
 }
 
+/// A column of the cherry-pick board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BoardColumn {
+    Pending,
+    InProgress,
+    Conflict,
+    Done,
+    Skipped,
+}
+
+impl BoardColumn {
+    /// Columns in display order.
+    const ALL: [BoardColumn; 5] = [
+        BoardColumn::Pending,
+        BoardColumn::InProgress,
+        BoardColumn::Conflict,
+        BoardColumn::Done,
+        BoardColumn::Skipped,
+    ];
+
+    /// Column of an item; failed picks need attention like conflicts.
+    fn for_status(status: &CherryPickStatus) -> Self {
+        match status {
+            CherryPickStatus::Pending => BoardColumn::Pending,
+            CherryPickStatus::InProgress => BoardColumn::InProgress,
+            CherryPickStatus::Conflict | CherryPickStatus::Failed(_) => BoardColumn::Conflict,
+            CherryPickStatus::Success => BoardColumn::Done,
+            CherryPickStatus::Skipped => BoardColumn::Skipped,
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            BoardColumn::Pending => "Pending",
+            BoardColumn::InProgress => "In Progress",
+            BoardColumn::Conflict => "Conflict",
+            BoardColumn::Done => "Done",
+            BoardColumn::Skipped => "Skipped",
+        }
+    }
+
+    /// Whether the column lists the most recently processed item first.
+    fn newest_first(self) -> bool {
+        matches!(self, BoardColumn::Done | BoardColumn::Skipped)
+    }
+}
+
+/// Color of a cherry-pick status.
+fn status_color(status: &CherryPickStatus) -> Color {
+    match status {
+        CherryPickStatus::Pending => Color::Gray,
+        CherryPickStatus::InProgress => Color::Yellow,
+        CherryPickStatus::Success => Color::Green,
+        CherryPickStatus::Conflict => Color::Yellow,
+        CherryPickStatus::Skipped => Color::Gray,
+        CherryPickStatus::Failed(_) => Color::Red,
+    }
+}
+
+/// Sorts the items into the board columns, in display order.
+///
+/// Pending items keep the queue order; finished columns list the most
+/// recently processed item first, so it stays visible in long merges.
+fn board_columns(items: &[CherryPickItem]) -> Vec<(BoardColumn, Vec<&CherryPickItem>)> {
+    BoardColumn::ALL
+        .iter()
+        .map(|&column| {
+            let mut column_items: Vec<_> = items
+                .iter()
+                .filter(|item| BoardColumn::for_status(&item.status) == column)
+                .collect();
+            if column.newest_first() {
+                column_items.reverse();
+            }
+            (column, column_items)
+        })
+        .collect()
+}
+
+/// Renders the cherry-pick queue as a board with one column per status.
+fn render_board(f: &mut Frame, area: Rect, app: &MergeApp) {
+    let columns = board_columns(app.cherry_pick_items());
+    let areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![
+            Constraint::Ratio(1, columns.len() as u32);
+            columns.len()
+        ])
+        .split(area);
+
+    for ((column, items), area) in columns.into_iter().zip(areas.iter()) {
+        let width = area.width.saturating_sub(2) as usize;
+        let cards: Vec<ListItem> = items
+            .iter()
+            .map(|item| {
+                let marker = Span::styled(
+                    format!("{} ", app.accessibility().status_marker(&item.status)),
+                    Style::default().fg(status_color(&item.status)),
+                );
+                let id = format!("#{} ", item.pr_id);
+                let title_width = width.saturating_sub(marker.width() + id.len());
+                let title = if item.pr_title.chars().count() > title_width {
+                    format!(
+                        "{}…",
+                        truncate_str(&item.pr_title, title_width.saturating_sub(1))
+                    )
+                } else {
+                    item.pr_title.clone()
+                };
+                ListItem::new(Line::from(vec![
+                    marker,
+                    Span::styled(id, Style::default().fg(Color::Cyan)),
+                    Span::raw(title),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(cards).block(Block::default().borders(Borders::ALL).title(format!(
+            "{} ({})",
+            column.title(),
+            items.len()
+        )));
+        f.render_widget(list, *area);
+    }
+}
+
 pub fn process_next_commit(app: &mut MergeApp) -> StateChange<MergeState> {
     // Skip already processed commits
     while app.current_cherry_pick_index() < app.cherry_pick_items().len() {
@@ -294,21 +520,64 @@ pub fn process_next_commit(app: &mut MergeApp) -> StateChange<MergeState> {
     // Sync current index to state file
     let _ = app.sync_state_current_index();
 
-    match git::cherry_pick_commit(&repo_path, &commit_id) {
-        Ok(git::CherryPickResult::Success) => {
+    // Binary conflicts covered by a policy are resolved without stopping;
+    // if that fails the files stay conflicted for the chooser
+    let sign = app.sign_commits();
+    let base = git::head_commit(&repo_path).ok();
+    let result = git::cherry_pick_with_parent_fallback(
+        &repo_path,
+        &commit_id,
+        sign,
+        app.mainline_fallback(),
+    )
+    .map(|(result, mainline)| match result {
+        git::CherryPickResult::Conflict(files) => {
+            match apply_binary_policy(&repo_path, &files, app.binary_conflicts(), sign) {
+                Ok(resolution) if resolution.remaining.is_empty() => {
+                    (git::CherryPickResult::Success, mainline)
+                }
+                Ok(resolution) => (
+                    git::CherryPickResult::Conflict(resolution.remaining),
+                    mainline,
+                ),
+                Err(_) => (git::CherryPickResult::Conflict(files), mainline),
+            }
+        }
+        result => (result, mainline),
+    });
+
+    // In squash mode the PR's commits become one commit with the templated message
+    let result = match (result, &base) {
+        (Ok((git::CherryPickResult::Success, mainline)), Some(base)) => {
+            match app.squash_picked_pr(&repo_path, base, current_index) {
+                CherryPickOutcome::Failed { message } => {
+                    Ok((git::CherryPickResult::Failed(message), mainline))
+                }
+                _ => Ok((git::CherryPickResult::Success, mainline)),
+            }
+        }
+        (result, _) => result,
+    };
+
+    match result {
+        Ok((git::CherryPickResult::Success, mainline)) => {
             let item = &mut app.cherry_pick_items_mut()[current_index];
             item.status = CherryPickStatus::Success;
             app.set_current_cherry_pick_index(app.current_cherry_pick_index() + 1);
 
-            // Update state file with success status
+            // Update state file with success status and the picked commit
             let _ = app.update_state_item_status(current_index, StateItemStatus::Success);
+            let _ = app.record_state_picked_commit(current_index);
+            if mainline != 1 {
+                let _ = app.record_state_mainline_parent(current_index, mainline);
+            }
 
             // Return to the same state to continue processing and show UI update
             StateChange::Change(MergeState::CherryPick(
                 CherryPickState::continue_after_conflict(),
             ))
         }
-        Ok(git::CherryPickResult::Conflict(files)) => {
+        Ok((git::CherryPickResult::Conflict(files), _)) => {
             let item = &mut app.cherry_pick_items_mut()[current_index];
             item.status = CherryPickStatus::Conflict;
 
@@ -321,7 +590,7 @@ pub fn process_next_commit(app: &mut MergeApp) -> StateChange<MergeState> {
                 ConflictResolutionState::new(files),
             ))
         }
-        Ok(git::CherryPickResult::Failed(msg)) => {
+        Ok((git::CherryPickResult::Failed(msg), _)) => {
             let item = &mut app.cherry_pick_items_mut()[current_index];
             item.status = CherryPickStatus::Failed(msg.clone());
             app.set_current_cherry_pick_index(app.current_cherry_pick_index() + 1);
@@ -330,9 +599,9 @@ pub fn process_next_commit(app: &mut MergeApp) -> StateChange<MergeState> {
             let _ = app
                 .update_state_item_status(current_index, StateItemStatus::Failed { message: msg });
 
-            // Return to the same state to continue processing and show UI update
+            // Pause so the pick can be retried or skipped
             StateChange::Change(MergeState::CherryPick(
-                CherryPickState::continue_after_conflict(),
+                CherryPickState::paused_after_failure(current_index),
             ))
         }
         Err(e) => {
@@ -347,9 +616,9 @@ pub fn process_next_commit(app: &mut MergeApp) -> StateChange<MergeState> {
                 StateItemStatus::Failed { message: err_msg },
             );
 
-            // Return to the same state to continue processing and show UI update
+            // Pause so the pick can be retried or skipped
             StateChange::Change(MergeState::CherryPick(
-                CherryPickState::continue_after_conflict(),
+                CherryPickState::paused_after_failure(current_index),
             ))
         }
     }
@@ -645,4 +914,197 @@ mod tests {
             assert_snapshot!("mixed_end", harness.backend());
         });
     }
+
+    /// # Cherry Pick State - Paused After Failure
+    ///
+    /// Tests the cherry-pick screen paused at a failed pick.
+    ///
+    /// ## Test Scenario
+    /// - Marks the second item failed with the queue past it
+    /// - Renders the state paused after that failure
+    ///
+    /// ## Expected Outcome
+    /// - Details show the failed PR and its error
+    /// - The status bar offers retry, skip, shell and continue
+    #[test]
+    fn test_cherry_pick_paused_after_failure() {
+        with_settings_and_module_path(module_path!(), || {
+            let config = create_test_config_default();
+            let mut harness = TuiTestHarness::with_config(config);
+
+            let mut items = create_test_cherry_pick_items();
+            items[1].status = CherryPickStatus::Failed("Local changes would be overwritten".into());
+            *harness.app.cherry_pick_items_mut() = items;
+            harness.app.set_version(Some("v1.0.0".to_string()));
+            harness
+                .app
+                .set_repo_path(Some(PathBuf::from("/path/to/repo")));
+            harness.app.set_current_cherry_pick_index(2);
+
+            let mut state = CherryPickState::paused_after_failure(1);
+            harness.render_state(&mut state);
+
+            assert_snapshot!("paused_after_failure", harness.backend());
+        });
+    }
+
+    /// # Pausing and Skipping
+    ///
+    /// Tests pausing the pipeline and skipping the next PR.
+    ///
+    /// ## Test Scenario
+    /// - Presses `p`, then sends a tick and `s`
+    /// - Presses `p` again
+    ///
+    /// ## Expected Outcome
+    /// - While paused, ticks pick nothing
+    /// - `s` marks the next pending PR skipped and stays paused
+    /// - The second `p` resumes
+    #[tokio::test]
+    async fn test_pause_and_skip() {
+        let mut harness = TuiTestHarness::with_config(create_test_config_default());
+        *harness.app.cherry_pick_items_mut() = create_test_cherry_pick_items();
+        harness
+            .app
+            .set_repo_path(Some(PathBuf::from("/nonexistent/repo/path")));
+        harness.app.set_current_cherry_pick_index(1);
+        let app = harness.merge_app_mut();
+        let mut state = CherryPickState::continue_after_conflict();
+
+        let result = ModeState::process_key(&mut state, KeyCode::Char('p'), app).await;
+        assert!(matches!(result, StateChange::Keep));
+        assert!(state.is_paused());
+
+        let result = ModeState::process_key(&mut state, KeyCode::Null, app).await;
+        assert!(matches!(result, StateChange::Keep));
+        assert_eq!(app.current_cherry_pick_index(), 1);
+
+        ModeState::process_key(&mut state, KeyCode::Char('s'), app).await;
+        assert!(matches!(
+            app.cherry_pick_items()[2].status,
+            CherryPickStatus::Skipped
+        ));
+        assert!(state.is_paused());
+
+        ModeState::process_key(&mut state, KeyCode::Char('p'), app).await;
+        assert!(!state.is_paused());
+    }
+
+    /// # Retrying and Skipping a Failed Pick
+    ///
+    /// Tests the keys offered after a pick failed.
+    ///
+    /// ## Test Scenario
+    /// - Pauses after PR 101 failed and presses `r`
+    /// - Pauses after the same failure again and presses `s`
+    ///
+    /// ## Expected Outcome
+    /// - `r` queues PR 101 again, moves back to it and resumes
+    /// - `s` marks PR 101 skipped and resumes
+    #[tokio::test]
+    async fn test_failed_pick_retry_and_skip() {
+        let mut harness = TuiTestHarness::with_config(create_test_config_default());
+        let mut items = create_test_cherry_pick_items();
+        items[1].status = CherryPickStatus::Failed("error".to_string());
+        *harness.app.cherry_pick_items_mut() = items;
+        harness.app.set_current_cherry_pick_index(2);
+        let app = harness.merge_app_mut();
+
+        let mut state = CherryPickState::paused_after_failure(1);
+        ModeState::process_key(&mut state, KeyCode::Char('r'), app).await;
+        assert!(matches!(
+            app.cherry_pick_items()[1].status,
+            CherryPickStatus::Pending
+        ));
+        assert_eq!(app.current_cherry_pick_index(), 1);
+        assert!(!state.is_paused());
+
+        app.cherry_pick_items_mut()[1].status = CherryPickStatus::Failed("error".to_string());
+        app.set_current_cherry_pick_index(2);
+        let mut state = CherryPickState::paused_after_failure(1);
+        ModeState::process_key(&mut state, KeyCode::Char('s'), app).await;
+        assert!(matches!(
+            app.cherry_pick_items()[1].status,
+            CherryPickStatus::Skipped
+        ));
+        assert!(matches!(
+            app.cherry_pick_items()[2].status,
+            CherryPickStatus::Pending
+        ));
+        assert!(!state.is_paused());
+    }
+
+    /// # Opening a Shell
+    ///
+    /// Tests requesting a shell in the worktree.
+    ///
+    /// ## Test Scenario
+    /// - Presses `!` while picking
+    ///
+    /// ## Expected Outcome
+    /// - Picking pauses and a shell is requested in the repository path
+    #[tokio::test]
+    async fn test_shell_request() {
+        let mut harness = TuiTestHarness::with_config(create_test_config_default());
+        *harness.app.cherry_pick_items_mut() = create_test_cherry_pick_items();
+        harness
+            .app
+            .set_repo_path(Some(PathBuf::from("/path/to/repo")));
+        let app = harness.merge_app_mut();
+        let mut state = CherryPickState::continue_after_conflict();
+
+        let result = ModeState::process_key(&mut state, KeyCode::Char('!'), app).await;
+
+        assert!(matches!(result, StateChange::Keep));
+        assert!(state.is_paused());
+        assert_eq!(
+            app.take_shell_request(),
+            Some(PathBuf::from("/path/to/repo"))
+        );
+        assert!(app.take_shell_request().is_none());
+    }
+
+    /// # Cherry Pick Board Columns
+    ///
+    /// Tests sorting cherry-pick items into the board columns.
+    ///
+    /// ## Test Scenario
+    /// - Sorts items that succeeded, were skipped, failed and are pending
+    ///
+    /// ## Expected Outcome
+    /// - Every column is present in display order, even when empty
+    /// - Failed items join conflicts in the column needing attention
+    /// - Done lists the most recently processed item first
+    #[test]
+    fn test_board_columns() {
+        let mut items = create_test_cherry_pick_items();
+        items[1].status = CherryPickStatus::Success;
+        items[2].status = CherryPickStatus::Failed("error".to_string());
+        items.push(CherryPickItem {
+            commit_id: "pending000".to_string(),
+            pr_id: 104,
+            pr_title: "Pending".to_string(),
+            status: CherryPickStatus::Pending,
+        });
+
+        let columns: Vec<_> = board_columns(&items)
+            .into_iter()
+            .map(|(column, items)| {
+                (
+                    column.title(),
+                    items.iter().map(|item| item.pr_id).collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            columns,
+            vec![
+                ("Pending", vec![104]),
+                ("In Progress", vec![]),
+                ("Conflict", vec![102, 103]),
+                ("Done", vec![101, 100]),
+                ("Skipped", vec![]),
+            ]
+        );
+    }
 }
@@ -1,5 +1,7 @@
 use super::MergeState;
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::{
+    core::operations::CherryPickOutcome,
     core::state::{MergePhase, StateItemStatus},
     models::CherryPickStatus,
     ui::apps::MergeApp,
@@ -27,14 +29,20 @@ pub struct CherryPickContinueState {
     success: Arc<Mutex<Option<bool>>>,
     error_message: Arc<Mutex<Option<String>>>,
     conflicted_files: Vec<String>,
+    /// Commit the PR was picked onto, squashed onto in squash mode.
+    base: Option<String>,
+    repo_path: std::path::PathBuf,
 }
 
 impl CherryPickContinueState {
-    pub fn new(conflicted_files: Vec<String>, repo_path: std::path::PathBuf) -> Self {
+    pub fn new(conflicted_files: Vec<String>, repo_path: std::path::PathBuf, sign: bool) -> Self {
         let output = Arc::new(Mutex::new(Vec::new()));
         let is_complete = Arc::new(Mutex::new(false));
         let success = Arc::new(Mutex::new(None));
         let error_message = Arc::new(Mutex::new(None));
+        // A conflicted cherry-pick has not committed yet, so HEAD is still the base
+        let base = crate::git::head_commit(&repo_path).ok();
+        let thread_repo_path = repo_path.clone();
 
         let output_clone = output.clone();
         let is_complete_clone = is_complete.clone();
@@ -43,6 +51,7 @@ impl CherryPickContinueState {
 
         // Spawn a thread to run the git cherry-pick --continue command
         thread::spawn(move || {
+            let repo_path = thread_repo_path;
             // Check if the commit would be empty by checking staged changes
             // git diff --cached --quiet exits with 1 if there are changes, 0 if empty
             let is_empty_commit = Command::new("git")
@@ -54,15 +63,11 @@ impl CherryPickContinueState {
 
             // For empty commits, use git commit --allow-empty directly
             // because git cherry-pick --continue doesn't support --keep-redundant-commits
-            let command_args: &[&str] = if is_empty_commit {
-                &["commit", "--allow-empty", "--no-edit"]
-            } else {
-                &["cherry-pick", "--continue", "--no-edit"]
-            };
+            let command_args = crate::git::continue_cherry_pick_args(is_empty_commit, sign);
 
             let mut child = match Command::new("git")
                 .current_dir(&repo_path)
-                .args(command_args)
+                .args(&command_args)
                 .stdout(Stdio::piped())
                 .stderr(Stdio::piped())
                 .spawn()
@@ -136,6 +141,8 @@ impl CherryPickContinueState {
             success,
             error_message,
             conflicted_files,
+            base,
+            repo_path,
         }
     }
 }
@@ -315,7 +322,9 @@ impl ModeState for CherryPickContinueState {
                     Span::styled("s", key_style),
                     Span::raw(": Skip commit | "),
                     Span::styled("a", key_style),
-                    Span::raw(": Abort (cleanup)"),
+                    Span::raw(": Abort (cleanup) | "),
+                    Span::styled("K", key_style),
+                    Span::raw(": Abort, keep applied"),
                 ])],
                 None => vec![Line::from(vec![
                     Span::raw("Press "),
@@ -349,11 +358,31 @@ impl ModeState for CherryPickContinueState {
             Some(true) => {
                 // Success - mark as successful and continue to next commit
                 let current_index = app.current_cherry_pick_index();
+                if let Some(base) = &self.base
+                    && let CherryPickOutcome::Failed { message } =
+                        app.squash_picked_pr(&self.repo_path, base, current_index)
+                {
+                    app.cherry_pick_items_mut()[current_index].status =
+                        CherryPickStatus::Failed(message.clone());
+                    app.set_current_cherry_pick_index(current_index + 1);
+                    let _ = app.update_state_item_status(
+                        current_index,
+                        StateItemStatus::Failed { message },
+                    );
+                    let _ = app.clear_state_conflicted_files();
+                    let _ = app.update_state_phase(MergePhase::CherryPicking);
+
+                    // Pause so the pick can be retried or skipped
+                    return StateChange::Change(MergeState::CherryPick(
+                        CherryPickState::paused_after_failure(current_index),
+                    ));
+                }
                 app.cherry_pick_items_mut()[current_index].status = CherryPickStatus::Success;
                 app.set_current_cherry_pick_index(current_index + 1);
 
                 // Update state file with success status and resume cherry-picking phase
                 let _ = app.update_state_item_status(current_index, StateItemStatus::Success);
+                let _ = app.record_state_picked_commit(current_index);
                 let _ = app.clear_state_conflicted_files();
                 let _ = app.update_state_phase(MergePhase::CherryPicking);
 
@@ -403,6 +432,26 @@ impl ModeState for CherryPickContinueState {
                             target_branch,
                         )))
                     }
+                    KeyCode::Char('K') => {
+                        // Abort but keep the commits applied so far on a rescue branch
+                        let repo_path_opt = app.repo_path();
+                        let repo_path = repo_path_opt.as_ref().unwrap().to_path_buf();
+                        let version_opt = app.version();
+                        let version = version_opt.as_ref().unwrap().to_string();
+                        let target_branch = app.target_branch().to_string();
+                        let base_repo_path =
+                            app.state_file().and_then(|sf| sf.base_repo_path.clone());
+                        if base_repo_path.is_none() {
+                            // A cloned repo would be deleted on exit, taking the rescue branch with it
+                            app.worktree.keep_temp_dir();
+                        }
+                        StateChange::Change(MergeState::Aborting(AbortingState::keeping_applied(
+                            base_repo_path,
+                            repo_path,
+                            version,
+                            target_branch,
+                        )))
+                    }
                     _ => StateChange::Keep,
                 }
             }
@@ -413,6 +462,37 @@ impl ModeState for CherryPickContinueState {
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        let map = ActionMap::new("Continue Cherry-pick");
+        if !*self.is_complete.lock().unwrap() {
+            return map;
+        }
+        if *self.success.lock().unwrap() == Some(false) {
+            map.bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('r')],
+                "Retry conflict resolution",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('s')],
+                "Skip this commit",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('a')],
+                "Abort the merge and clean up",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('K')],
+                "Abort, keeping applied commits on a rescue branch",
+            )
+        } else {
+            map.bind_any_key(ActionCategory::General, "Continue to the next commit")
+        }
+    }
+
     fn name(&self) -> &'static str {
         "CherryPickContinue"
     }
@@ -433,6 +513,8 @@ impl CherryPickContinueState {
             success: Arc::new(Mutex::new(success)),
             error_message: Arc::new(Mutex::new(error_message)),
             conflicted_files,
+            base: None,
+            repo_path: std::path::PathBuf::new(),
         }
     }
 }
@@ -497,6 +579,7 @@ mod tests {
                         commit_id: "abc123def456".to_string(),
                     }),
                     labels: None,
+                    reviewers: Vec::new(),
                 },
                 work_items: vec![],
                 selected: false,
@@ -571,6 +654,7 @@ mod tests {
                         commit_id: "abc123def456".to_string(),
                     }),
                     labels: None,
+                    reviewers: Vec::new(),
                 },
                 work_items: vec![],
                 selected: false,
@@ -645,6 +729,7 @@ mod tests {
                         commit_id: "def456ghi789".to_string(),
                     }),
                     labels: None,
+                    reviewers: Vec::new(),
                 },
                 work_items: vec![],
                 selected: false,
@@ -825,7 +910,7 @@ mod tests {
 
         // Now create the CherryPickContinueState which will run git cherry-pick --continue --no-edit
         let conflicted_files = vec!["conflict.txt".to_string()];
-        let state = CherryPickContinueState::new(conflicted_files, repo_path.clone());
+        let state = CherryPickContinueState::new(conflicted_files, repo_path.clone(), false);
 
         // Wait for the command to complete (with timeout)
         let start = std::time::Instant::now();
@@ -1296,7 +1381,7 @@ mod tests {
 
             // Now create the CherryPickContinueState which should handle empty commit
             let conflicted_files = vec!["file.txt".to_string()];
-            let state = CherryPickContinueState::new(conflicted_files, repo_path.clone());
+            let state = CherryPickContinueState::new(conflicted_files, repo_path.clone(), false);
 
             // Wait for the command to complete (with timeout)
             let start = std::time::Instant::now();
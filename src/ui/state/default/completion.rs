@@ -1,24 +1,43 @@
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::{
-    core::state::MergeStatus,
+    git::TargetDivergence,
     models::CherryPickStatus,
     ui::apps::MergeApp,
+    ui::hyperlinks::find_text,
     ui::state::default::MergeState,
     ui::state::typed::{ModeState, StateChange},
-    utils::truncate_str,
+    utils::{copy_text, truncate_str},
 };
 use async_trait::async_trait;
 use crossterm::event::KeyCode;
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
 };
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+
+/// How often the remote target branch is checked for new commits.
+const DIVERGENCE_POLL_INTERVAL: Duration = Duration::from_secs(60);
 
 pub struct CompletionState {
     list_state: ListState,
     show_tagging_warning: bool,
+    /// Set when tagging was requested while the remote target had moved.
+    show_divergence_warning: bool,
+    /// Commits the remote target gained since the patch branch was created.
+    divergence: Option<TargetDivergence>,
+    divergence_task: Option<JoinHandle<anyhow::Result<Option<TargetDivergence>>>>,
+    last_divergence_check: Option<Instant>,
+    rebase_task: Option<JoinHandle<anyhow::Result<()>>>,
+    rebase_message: Option<(String, Color)>,
+    /// Result of the last quick action.
+    action_message: Option<(String, Color)>,
+    /// Set after the first 'x', which only asks to press it again.
+    remove_worktree_pending: bool,
 }
 
 impl Default for CompletionState {
@@ -32,11 +51,171 @@ impl CompletionState {
         let mut state = Self {
             list_state: ListState::default(),
             show_tagging_warning: false,
+            show_divergence_warning: false,
+            divergence: None,
+            divergence_task: None,
+            last_divergence_check: None,
+            rebase_task: None,
+            rebase_message: None,
+            action_message: None,
+            remove_worktree_pending: false,
         };
         state.list_state.select(Some(0));
         state
     }
 
+    /// Collects finished divergence checks and rebases, and starts a new
+    /// check when the last one is older than [`DIVERGENCE_POLL_INTERVAL`].
+    async fn poll_target(&mut self, app: &MergeApp) {
+        if let Some(task) = self.rebase_task.take_if(|task| task.is_finished()) {
+            match task.await {
+                Ok(Ok(())) => {
+                    let remote_ref = self
+                        .divergence
+                        .take()
+                        .map(|divergence| divergence.remote_ref)
+                        .unwrap_or_default();
+                    self.rebase_message =
+                        Some((format!("Rebased onto {}", remote_ref), Color::Green));
+                    // Confirm the new base on the next tick
+                    self.last_divergence_check = None;
+                }
+                Ok(Err(e)) => self.rebase_message = Some((format!("{:#}", e), Color::Red)),
+                Err(e) => self.rebase_message = Some((format!("Task error: {}", e), Color::Red)),
+            }
+        }
+
+        if let Some(task) = self.divergence_task.take_if(|task| task.is_finished()) {
+            match task.await {
+                Ok(Ok(divergence)) => self.divergence = divergence,
+                Ok(Err(e)) => tracing::warn!("Failed to check target branch: {:#}", e),
+                Err(e) => tracing::warn!("Target branch check task failed: {}", e),
+            }
+        }
+
+        let due = self
+            .last_divergence_check
+            .is_none_or(|checked| checked.elapsed() >= DIVERGENCE_POLL_INTERVAL);
+        if !due || self.divergence_task.is_some() || self.rebase_task.is_some() {
+            return;
+        }
+        let Some(repo_path) = app.repo_path().map(|path| path.to_path_buf()) else {
+            return;
+        };
+        let target_branch = app.target_branch().to_string();
+        self.last_divergence_check = Some(Instant::now());
+        self.divergence_task = Some(tokio::task::spawn_blocking(move || {
+            crate::git::check_target_divergence(&repo_path, &target_branch)
+        }));
+    }
+
+    /// Rebases the patch branch onto the new remote target tip.
+    fn start_rebase(&mut self, app: &MergeApp) {
+        let (Some(divergence), Some(repo_path)) = (&self.divergence, app.repo_path()) else {
+            return;
+        };
+        if self.rebase_task.is_some() {
+            return;
+        }
+        let repo_path = repo_path.to_path_buf();
+        let onto = divergence.remote_ref.clone();
+        self.rebase_message = Some((format!("Rebasing onto {}...", onto), Color::Yellow));
+        self.rebase_task = Some(tokio::task::spawn_blocking(move || {
+            crate::git::rebase_onto(&repo_path, &onto)
+        }));
+    }
+
+    /// Copies `text` and reports where it went.
+    fn copy(&mut self, app: &MergeApp, what: &str, text: &str) {
+        self.action_message = Some(match copy_text(text, app.clipboard_options()) {
+            Ok(destination) => (format!("Copied {} to {}", what, destination), Color::Green),
+            Err(e) => (format!("Failed to copy {}: {:#}", what, e), Color::Red),
+        });
+    }
+
+    /// Copies the command pushing the patch branch to the remote.
+    fn copy_push_command(&mut self, app: &MergeApp) {
+        let Some(repo_path) = app.repo_path() else {
+            self.action_message = Some(("No local repository to push from".into(), Color::Red));
+            return;
+        };
+        let command = format!(
+            "git -C {} push -u origin {}",
+            shell_quote(&repo_path.display().to_string()),
+            patch_branch(app)
+        );
+        self.copy(app, "push command", &command);
+    }
+
+    /// Copies the release notes of the cherry-picked PRs.
+    fn copy_release_notes(&mut self, app: &MergeApp) {
+        let notes = crate::release_notes::generate_from_merge_data(
+            app.version.as_deref().unwrap_or("unknown"),
+            &app.cherry_pick_items,
+            &app.pull_requests,
+            &app.urls(),
+            &app.picked_commits(),
+            app.package_version(),
+            app.clock().local_now().date_naive(),
+            app.release_notes_locale(),
+        );
+        self.copy(app, "release notes", &notes);
+    }
+
+    /// Marks the merge as finished and removes its state file.
+    fn finish(app: &mut MergeApp) -> StateChange<MergeState> {
+        app.finish_merge();
+        StateChange::Exit
+    }
+
+    /// Removes the worktree holding the patch branch and exits.
+    ///
+    /// The worktree is removed with any uncommitted changes, so the first
+    /// press only asks to press 'x' again. The branch itself stays in the
+    /// base repository.
+    fn finish_and_remove_worktree(
+        &mut self,
+        app: &mut MergeApp,
+        confirmed: bool,
+    ) -> StateChange<MergeState> {
+        let (Some(base_repo_path), Some(worktree_path)) =
+            (&app.worktree.base_repo_path, app.repo_path())
+        else {
+            self.action_message = Some((
+                "The patch branch is not in a worktree".into(),
+                Color::Yellow,
+            ));
+            return StateChange::Keep;
+        };
+        if !confirmed {
+            self.remove_worktree_pending = true;
+            self.action_message = Some((
+                format!(
+                    "Press 'x' again to remove {} and discard its uncommitted changes",
+                    worktree_path.display()
+                ),
+                Color::Yellow,
+            ));
+            return StateChange::Keep;
+        }
+        if let Err(e) = crate::git::force_remove_worktree_at(base_repo_path, worktree_path) {
+            self.action_message = Some((format!("Failed to remove worktree: {:#}", e), Color::Red));
+            return StateChange::Keep;
+        }
+        Self::finish(app)
+    }
+
+    /// Finishes the current fan-out target and sets up the next one.
+    fn next_fan_out_target(&mut self, app: &mut MergeApp) -> StateChange<MergeState> {
+        let Some(target) = app.next_fan_out_target().map(String::from) else {
+            return StateChange::Keep;
+        };
+        app.advance_fan_out(&target);
+        StateChange::Change(MergeState::SetupRepo(
+            crate::ui::state::SetupRepoState::new(),
+        ))
+    }
+
     fn next(&mut self, app: &MergeApp) {
         if app.cherry_pick_items.is_empty() {
             return;
@@ -70,6 +249,74 @@ impl CompletionState {
         };
         self.list_state.select(Some(i));
     }
+
+    /// Links the PR numbers and work item ids of the listed items to their
+    /// pages.
+    fn link_items(&self, f: &mut Frame, app: &MergeApp, area: Rect) {
+        let hyperlinks = app.hyperlinks();
+        if !hyperlinks.enabled {
+            return;
+        }
+        let urls = app.urls();
+        let buffer = f.buffer_mut();
+        let rows = area.height.saturating_sub(2) as usize;
+        let items = app
+            .cherry_pick_items
+            .iter()
+            .skip(self.list_state.offset())
+            .take(rows);
+        for (row, item) in items.enumerate() {
+            let row_area = Rect::new(
+                area.x + 1,
+                area.y + 1 + row as u16,
+                area.width.saturating_sub(2),
+                1,
+            );
+            hyperlinks.link(
+                buffer,
+                row_area,
+                &format!("#{}", item.pr_id),
+                &urls.pull_request_url(item.pr_id),
+            );
+            let Some(start) = find_text(buffer, row_area, "WI:") else {
+                continue;
+            };
+            let work_items_area = Rect::new(start.x, start.y, row_area.right() - start.x, 1);
+            let work_items = app
+                .pull_requests
+                .iter()
+                .find(|pr| pr.pr.id == item.pr_id)
+                .map(|pr| pr.work_items.as_slice())
+                .unwrap_or_default();
+            for work_item in work_items {
+                hyperlinks.link(
+                    buffer,
+                    work_items_area,
+                    &work_item.id.to_string(),
+                    &urls.work_item_url(work_item.id),
+                );
+            }
+        }
+    }
+}
+
+/// Name of the branch holding the cherry-picked commits.
+pub(super) fn patch_branch(app: &MergeApp) -> String {
+    format!(
+        "patch/{}-{}",
+        app.target_branch(),
+        app.version.as_deref().unwrap_or_default()
+    )
+}
+
+/// Quotes `value` for a POSIX shell when it contains special characters.
+fn shell_quote(value: &str) -> String {
+    let safe = |c: char| c.is_ascii_alphanumeric() || "/._-+:@%=,".contains(c);
+    if !value.is_empty() && value.chars().all(safe) {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
 }
 
 #[async_trait]
@@ -107,12 +354,20 @@ impl ModeState for CompletionState {
             .map(|item| {
                 let mut spans = vec![];
 
-                let (symbol, color) = match &item.status {
-                    CherryPickStatus::Success => ("✅", Color::Green),
-                    CherryPickStatus::Failed(_) => ("❌", Color::Red),
-                    CherryPickStatus::Conflict => ("⚠️", Color::Yellow),
-                    CherryPickStatus::Skipped => ("⏭", Color::Gray),
-                    _ => ("❓", Color::White),
+                let color = match &item.status {
+                    CherryPickStatus::Success => Color::Green,
+                    CherryPickStatus::Failed(_) => Color::Red,
+                    CherryPickStatus::Conflict => Color::Yellow,
+                    CherryPickStatus::Skipped => Color::Gray,
+                    _ => Color::White,
+                };
+                let symbol = match &item.status {
+                    CherryPickStatus::Pending | CherryPickStatus::InProgress
+                        if !app.accessibility().markers =>
+                    {
+                        "❓"
+                    }
+                    status => app.accessibility().status_marker(status),
                 };
 
                 spans.push(Span::styled(
@@ -211,6 +466,7 @@ impl ModeState for CompletionState {
                     .fg(Color::Yellow),
             );
         f.render_stateful_widget(list, content_chunks[0], &mut self.list_state);
+        self.link_items(f, app, content_chunks[0]);
 
         // Right side: Summary and info
         let mut summary_text = vec![];
@@ -262,14 +518,9 @@ impl ModeState for CompletionState {
         )]));
         summary_text.push(Line::from(""));
 
-        let branch_name = format!(
-            "patch/{}-{}",
-            app.target_branch(),
-            app.version.as_ref().unwrap()
-        );
         summary_text.push(Line::from(vec![
             Span::raw("Branch: "),
-            Span::styled(branch_name, Style::default().fg(Color::Cyan)),
+            Span::styled(patch_branch(app), Style::default().fg(Color::Cyan)),
         ]));
 
         if let Some(repo_path) = app.repo_path() {
@@ -282,6 +533,70 @@ impl ModeState for CompletionState {
             ]));
         }
 
+        if !app.fan_out().is_empty() {
+            summary_text.push(Line::from(""));
+            summary_text.push(Line::from(vec![Span::styled(
+                "Fan-out",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )]));
+            for target in app.fan_out() {
+                let finished = app
+                    .fan_out_results()
+                    .iter()
+                    .find(|result| result.target_branch == *target);
+                let line = match finished {
+                    Some(result) => Line::from(vec![
+                        Span::styled("✓ ", Style::default().fg(Color::Green)),
+                        Span::raw(format!(
+                            "{}: {} picked, {} failed, {} skipped",
+                            target, result.picked, result.failed, result.skipped
+                        )),
+                    ]),
+                    None if target == app.target_branch() => Line::from(vec![
+                        Span::styled("▶ ", Style::default().fg(Color::Cyan)),
+                        Span::styled(
+                            format!("{} (current)", target),
+                            Style::default().fg(Color::Cyan),
+                        ),
+                    ]),
+                    None => Line::from(vec![
+                        Span::styled("· ", Style::default().fg(Color::Gray)),
+                        Span::styled(target.clone(), Style::default().fg(Color::Gray)),
+                    ]),
+                };
+                summary_text.push(line);
+            }
+        }
+
+        if let Some(divergence) = &self.divergence {
+            summary_text.push(Line::from(""));
+            summary_text.push(Line::from(Span::styled(
+                format!(
+                    "⚠ {} moved {} commit(s) since the patch branch was created",
+                    divergence.remote_ref, divergence.behind
+                ),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )));
+        }
+        if let Some((message, color)) = &self.rebase_message {
+            summary_text.push(Line::from(""));
+            summary_text.push(Line::from(Span::styled(
+                message.clone(),
+                Style::default().fg(*color),
+            )));
+        }
+        if let Some((message, color)) = &self.action_message {
+            summary_text.push(Line::from(""));
+            summary_text.push(Line::from(Span::styled(
+                message.clone(),
+                Style::default().fg(*color),
+            )));
+        }
+
         summary_text.push(Line::from(""));
         summary_text.push(Line::from("─────────────────────"));
         summary_text.push(Line::from(""));
@@ -297,10 +612,24 @@ impl ModeState for CompletionState {
         summary_text.push(Line::from("'p' Open PR in browser"));
         summary_text.push(Line::from("'w' Open work items"));
         summary_text.push(Line::from("'r' Export release notes"));
+        summary_text.push(Line::from("'l' Compare branch logs"));
         summary_text.push(Line::from(format!(
             "'t' Tag PRs & update work items to '{}'",
             app.work_item_state()
         )));
+        if self.divergence.is_some() {
+            summary_text.push(Line::from("'b' Rebase onto new target tip"));
+        }
+        summary_text.push(Line::from("'o' Open patch branch"));
+        summary_text.push(Line::from("'P' Create pull request"));
+        summary_text.push(Line::from("'c' Copy push command"));
+        summary_text.push(Line::from("'n' Copy release notes"));
+        if let Some(target) = app.next_fan_out_target() {
+            summary_text.push(Line::from(format!("'f' Pick into {}", target)));
+        }
+        if app.worktree.base_repo_path.is_some() {
+            summary_text.push(Line::from("'x' Remove worktree & exit"));
+        }
         summary_text.push(Line::from("'q' Exit"));
 
         let summary = Paragraph::new(summary_text)
@@ -362,6 +691,60 @@ impl ModeState for CompletionState {
                 .alignment(ratatui::layout::Alignment::Center);
             f.render_widget(popup, popup_area);
         }
+
+        if self.show_divergence_warning
+            && let Some(divergence) = &self.divergence
+        {
+            use ratatui::layout::Rect;
+            use ratatui::widgets::Clear;
+
+            let area = f.area();
+            let popup_width = 64u16.min(area.width.saturating_sub(4));
+            let popup_height = 9u16;
+            let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+            let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+            let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+            f.render_widget(Clear, popup_area);
+
+            let key = |key: &'static str, color: Color| {
+                Span::styled(key, Style::default().fg(color).add_modifier(Modifier::BOLD))
+            };
+            let warning_text = vec![
+                Line::from(""),
+                Line::from(Span::styled(
+                    format!(
+                        "{} moved {} commit(s) since the patch branch was created.",
+                        divergence.remote_ref, divergence.behind
+                    ),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                )),
+                Line::from(""),
+                Line::from("Rebase the patch branch onto the new tip before tagging?"),
+                Line::from(""),
+                Line::from(vec![
+                    key("b", Color::Green),
+                    Span::raw(": Rebase  "),
+                    key("Enter", Color::Yellow),
+                    Span::raw(": Tag anyway  "),
+                    key("Esc", Color::Red),
+                    Span::raw(": Cancel"),
+                ]),
+            ];
+
+            let popup = Paragraph::new(warning_text)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Target Branch Moved")
+                        .border_style(Style::default().fg(Color::Yellow)),
+                )
+                .alignment(ratatui::layout::Alignment::Center)
+                .wrap(Wrap { trim: true });
+            f.render_widget(popup, popup_area);
+        }
     }
 
     async fn process_key(&mut self, code: KeyCode, app: &mut MergeApp) -> StateChange<MergeState> {
@@ -381,15 +764,61 @@ impl ModeState for CompletionState {
             };
         }
 
+        if self.show_divergence_warning {
+            return match code {
+                KeyCode::Char('b') => {
+                    self.show_divergence_warning = false;
+                    self.start_rebase(app);
+                    StateChange::Keep
+                }
+                KeyCode::Enter => {
+                    self.show_divergence_warning = false;
+                    StateChange::Change(MergeState::PostCompletion(
+                        crate::ui::state::PostCompletionState::new(),
+                    ))
+                }
+                KeyCode::Esc => {
+                    self.show_divergence_warning = false;
+                    StateChange::Keep
+                }
+                _ => StateChange::Keep,
+            };
+        }
+
+        // Any other key cancels a pending worktree removal
+        let remove_confirmed = std::mem::take(&mut self.remove_worktree_pending);
+        if remove_confirmed && !matches!(code, KeyCode::Char('x') | KeyCode::Null) {
+            self.action_message = None;
+        }
+
         match code {
-            KeyCode::Char('q') => {
-                app.with_state_file_mut(|state_file| {
-                    state_file.final_status = Some(MergeStatus::Success);
-                    state_file.completed_at = Some(chrono::Utc::now());
-                    let _ = state_file.save_for_repo();
-                });
-                let _ = app.cleanup_state_file();
-                StateChange::Exit
+            KeyCode::Null => {
+                self.remove_worktree_pending = remove_confirmed;
+                self.poll_target(app).await;
+                StateChange::Keep
+            }
+            KeyCode::Char('b') => {
+                self.start_rebase(app);
+                StateChange::Keep
+            }
+            KeyCode::Char('q') => Self::finish(app),
+            KeyCode::Char('x') => self.finish_and_remove_worktree(app, remove_confirmed),
+            KeyCode::Char('f') => self.next_fan_out_target(app),
+            KeyCode::Char('o') => {
+                app.open_branch_in_browser(&patch_branch(app));
+                StateChange::Keep
+            }
+            KeyCode::Char('P') => {
+                app.open_create_pull_request(&patch_branch(app), app.target_branch());
+                StateChange::Keep
+            }
+            KeyCode::Char('c') => {
+                self.copy_push_command(app);
+                StateChange::Keep
+            }
+            KeyCode::Char('n') => {
+                self.copy_release_notes(app);
+                StateChange::Keep
             }
             KeyCode::Up => {
                 self.previous(app);
@@ -427,13 +856,128 @@ impl ModeState for CompletionState {
                     StateChange::Keep
                 }
             }
-            KeyCode::Char('t') => StateChange::Change(MergeState::PostCompletion(
-                crate::ui::state::PostCompletionState::new(),
+            KeyCode::Char('l') => StateChange::Change(MergeState::BranchLog(
+                crate::ui::state::BranchLogState::new(),
             )),
+            KeyCode::Char('t') => {
+                if self.divergence.is_some() {
+                    self.show_divergence_warning = true;
+                    StateChange::Keep
+                } else {
+                    StateChange::Change(MergeState::PostCompletion(
+                        crate::ui::state::PostCompletionState::new(),
+                    ))
+                }
+            }
             _ => StateChange::Keep,
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        let map = ActionMap::new("Completion");
+        if self.show_tagging_warning {
+            return map
+                .bind(
+                    ActionCategory::General,
+                    &[KeyCode::Enter],
+                    "Continue to release notes export",
+                )
+                .bind(ActionCategory::General, &[KeyCode::Esc], "Cancel");
+        }
+        if self.show_divergence_warning {
+            return map
+                .bind(
+                    ActionCategory::Actions,
+                    &[KeyCode::Char('b')],
+                    "Rebase onto new target tip",
+                )
+                .bind(ActionCategory::General, &[KeyCode::Enter], "Tag anyway")
+                .bind(ActionCategory::General, &[KeyCode::Esc], "Cancel");
+        }
+
+        let map = if self.divergence.is_some() {
+            map.bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('b')],
+                "Rebase onto new target tip",
+            )
+        } else {
+            map
+        };
+        let map = map
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('o')],
+                "Open patch branch in browser",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('P')],
+                "Create pull request in browser",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('c')],
+                "Copy push command",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('n')],
+                "Copy release notes",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('f')],
+                "Pick into the next fan-out target",
+            )
+            .bind(
+                ActionCategory::General,
+                &[KeyCode::Char('x')],
+                "Remove worktree and exit",
+            );
+        let map = map
+            .bind(
+                ActionCategory::Navigation,
+                &[KeyCode::Up, KeyCode::Down],
+                "Move highlight",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('p')],
+                "Open pull request in browser",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('w')],
+                "Open work items in browser",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('t')],
+                "Tag PRs and update work items",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('r')],
+                "Export release notes",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('l')],
+                "Compare target and patch branch logs",
+            )
+            .bind(
+                ActionCategory::General,
+                &[KeyCode::Char('q')],
+                "Finish and exit",
+            );
+        if self.remove_worktree_pending {
+            map.bind_any_key(ActionCategory::General, "Cancel worktree removal")
+        } else {
+            map
+        }
+    }
+
     fn name(&self) -> &'static str {
         "Completion"
     }
@@ -443,7 +987,8 @@ impl ModeState for CompletionState {
 mod tests {
     use super::*;
     use crate::{
-        models::CherryPickStatus,
+        models::{AppConfig, CherryPickStatus},
+        parsed_property::ParsedProperty,
         ui::{
             snapshot_testing::with_settings_and_module_path,
             testing::{TuiTestHarness, create_test_cherry_pick_items, create_test_config_default},
@@ -488,6 +1033,107 @@ mod tests {
         });
     }
 
+    /// # Completion State - Fan-out
+    ///
+    /// Tests moving on to the next target branch of a fan-out.
+    ///
+    /// ## Test Scenario
+    /// - Completes the picks into the first of two target branches
+    /// - Processes 'f', then completes the second target and processes 'f'
+    ///   again
+    ///
+    /// ## Expected Outcome
+    /// - 'f' records the first target's outcome and sets up the second
+    /// - The summary lists the finished and the current target
+    /// - On the last target 'f' does nothing
+    #[tokio::test]
+    async fn test_completion_fan_out() {
+        let mut config = create_test_config_default();
+        if let AppConfig::Default { shared, default } = &mut config {
+            shared.target_branch = ParsedProperty::Default("release/6.6".to_string());
+            default.fan_out = vec!["release/6.6".to_string(), "release/6.7".to_string()];
+        }
+        let mut harness = TuiTestHarness::with_config(config);
+        let mut items = create_test_cherry_pick_items();
+        items[0].status = CherryPickStatus::Success;
+        items[1].status = CherryPickStatus::Skipped;
+        *harness.app.cherry_pick_items_mut() = items;
+        harness.app.set_version(Some("v1.0.0".to_string()));
+
+        let mut state = CompletionState::new();
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Char('f'), harness.merge_app_mut()).await;
+        assert!(matches!(
+            result,
+            StateChange::Change(MergeState::SetupRepo(_))
+        ));
+        assert_eq!(harness.app.target_branch(), "release/6.7");
+        assert!(harness.app.cherry_pick_items().is_empty());
+        assert_eq!(
+            harness.merge_app().fan_out_results()[0].patch_branch,
+            "patch/release/6.6-v1.0.0"
+        );
+        assert_eq!(harness.merge_app().fan_out_results()[0].picked, 1);
+        assert_eq!(harness.merge_app().fan_out_results()[0].skipped, 1);
+        assert_eq!(
+            harness.merge_app().worktree_version().as_deref(),
+            Some("v1.0.0-release-6.7")
+        );
+
+        let mut items = create_test_cherry_pick_items();
+        for item in &mut items {
+            item.status = CherryPickStatus::Success;
+        }
+        *harness.app.cherry_pick_items_mut() = items;
+        with_settings_and_module_path(module_path!(), || {
+            let mut state = CompletionState::new();
+            harness.render_state(&mut state);
+            assert_snapshot!("fan_out", harness.backend());
+        });
+
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Char('f'), harness.merge_app_mut()).await;
+        assert!(matches!(result, StateChange::Keep));
+        assert_eq!(harness.merge_app().fan_out_results().len(), 1);
+    }
+
+    /// # Completion State - Terminal Hyperlinks
+    ///
+    /// Tests linking the PR numbers of the cherry-pick results.
+    ///
+    /// ## Test Scenario
+    /// - Renders the completion summary with hyperlinks enabled
+    ///
+    /// ## Expected Outcome
+    /// - Every listed PR number links to its pull request page
+    /// - The plain text still shows the PR numbers
+    #[test]
+    fn test_completion_hyperlinks() {
+        let mut harness = TuiTestHarness::with_config(create_test_config_default());
+        *harness.app.cherry_pick_items_mut() = create_test_cherry_pick_items();
+        harness
+            .merge_app_mut()
+            .set_hyperlinks(crate::ui::Hyperlinks { enabled: true });
+
+        let mut state = CompletionState::new();
+        harness.render_state(&mut state);
+
+        let buffer = harness.backend().buffer();
+        let urls = harness.merge_app().urls();
+        for item in create_test_cherry_pick_items() {
+            let link = format!("\x1B]8;;{}\x1B\\", urls.pull_request_url(item.pr_id));
+            assert!(
+                buffer
+                    .content
+                    .iter()
+                    .any(|cell| cell.symbol().starts_with(&link)),
+                "PR {} is not linked",
+                item.pr_id
+            );
+        }
+        assert!(crate::ui::screen_dump::buffer_text(buffer).contains("PR #103: "));
+    }
+
     /// # Completion State - With Conflicts
     ///
     /// Tests the completion screen with some conflicts.
@@ -624,6 +1270,86 @@ mod tests {
         assert!(matches!(result, StateChange::Change(_)));
     }
 
+    /// # Completion State - Branch Log Key
+    ///
+    /// Tests 'l' key to open the branch log comparison.
+    ///
+    /// ## Test Scenario
+    /// - Processes 'l' key
+    ///
+    /// ## Expected Outcome
+    /// - Should return StateChange::Change to BranchLogState
+    #[tokio::test]
+    async fn test_completion_branch_log() {
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+        let mut state = CompletionState::new();
+
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Char('l'), harness.merge_app_mut()).await;
+        assert!(matches!(
+            result,
+            StateChange::Change(MergeState::BranchLog(_))
+        ));
+    }
+
+    /// # Completion State - Target Branch Moved
+    ///
+    /// Tests the warning shown when the remote target advanced during the
+    /// session.
+    ///
+    /// ## Test Scenario
+    /// - Sets a detected divergence of origin/main and renders the screen
+    /// - Presses 't', renders the prompt, then presses Esc, 't' and Enter
+    ///
+    /// ## Expected Outcome
+    /// - The summary warns and offers 'b' to rebase
+    /// - Tagging asks first; Esc stays, Enter proceeds to tagging
+    #[tokio::test]
+    async fn test_completion_target_moved() {
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+        let mut items = create_test_cherry_pick_items();
+        for item in &mut items {
+            item.status = CherryPickStatus::Success;
+        }
+        *harness.app.cherry_pick_items_mut() = items;
+        harness.app.set_version(Some("v1.0.0".to_string()));
+
+        let mut state = CompletionState::new();
+        state.divergence = Some(TargetDivergence {
+            remote_ref: "origin/main".to_string(),
+            behind: 3,
+        });
+
+        with_settings_and_module_path(module_path!(), || {
+            harness.render_state(&mut state);
+            assert_snapshot!("target_moved", harness.backend());
+        });
+
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Char('t'), harness.merge_app_mut()).await;
+        assert!(matches!(result, StateChange::Keep));
+        assert!(state.show_divergence_warning);
+        with_settings_and_module_path(module_path!(), || {
+            harness.render_state(&mut state);
+            assert_snapshot!("target_moved_prompt", harness.backend());
+        });
+
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Esc, harness.merge_app_mut()).await;
+        assert!(matches!(result, StateChange::Keep));
+        assert!(!state.show_divergence_warning);
+
+        ModeState::process_key(&mut state, KeyCode::Char('t'), harness.merge_app_mut()).await;
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Enter, harness.merge_app_mut()).await;
+        assert!(matches!(
+            result,
+            StateChange::Change(MergeState::PostCompletion(_))
+        ));
+    }
+
     /// # Completion State - Open PR Key
     ///
     /// Tests 'p' key to open PR in browser.
@@ -675,6 +1401,172 @@ mod tests {
         assert!(matches!(result, StateChange::Keep));
     }
 
+    /// # Completion State - Browser Quick Actions
+    ///
+    /// Tests opening the patch branch and the pull request creation page.
+    ///
+    /// ## Test Scenario
+    /// - Processes 'o' and 'P' with a recording browser opener
+    ///
+    /// ## Expected Outcome
+    /// - The patch branch page and the create page targeting the target
+    ///   branch are opened
+    #[tokio::test]
+    async fn test_completion_browser_quick_actions() {
+        use crate::ui::browser::MockBrowserOpener;
+
+        let opener = MockBrowserOpener::new();
+        let opened_urls = opener.opened_urls.clone();
+        let mut harness =
+            TuiTestHarness::with_config_and_browser(create_test_config_default(), Box::new(opener));
+        harness.app.set_version(Some("v1.0.0".to_string()));
+
+        let mut state = CompletionState::new();
+        for key in [KeyCode::Char('o'), KeyCode::Char('P')] {
+            let result = ModeState::process_key(&mut state, key, harness.merge_app_mut()).await;
+            assert!(matches!(result, StateChange::Keep));
+        }
+
+        let urls = opened_urls.lock().unwrap().clone();
+        assert_eq!(urls.len(), 2);
+        assert!(urls[0].ends_with("_git/test-repo?version=GBpatch%2Fmain-v1.0.0"));
+        assert!(urls[1].ends_with(
+            "_git/test-repo/pullrequestcreate?sourceRef=patch%2Fmain-v1.0.0&targetRef=main"
+        ));
+    }
+
+    /// # Completion State - Copy Quick Actions
+    ///
+    /// Tests copying the push command and the release notes.
+    ///
+    /// ## Test Scenario
+    /// - Configures the file clipboard method
+    /// - Processes 'c' with a repository path containing a space, then 'n'
+    ///
+    /// ## Expected Outcome
+    /// - The push command quotes the path and pushes the patch branch
+    /// - The release notes for the version replace it in the clipboard file
+    /// - The destination is reported on screen
+    #[tokio::test]
+    async fn test_completion_copy_quick_actions() {
+        use crate::utils::{ClipboardMethod, ClipboardOptions};
+
+        let dir = tempfile::tempdir().unwrap();
+        let clipboard_file = dir.path().join("clipboard.txt");
+        let mut config = create_test_config_default();
+        if let AppConfig::Default { default, .. } = &mut config {
+            default.clipboard = ClipboardOptions {
+                method: ClipboardMethod::File,
+                file: Some(clipboard_file.clone()),
+            };
+        }
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.cherry_pick_items_mut() = create_test_cherry_pick_items();
+        harness.app.set_version(Some("v1.0.0".to_string()));
+        harness
+            .app
+            .set_repo_path(Some(PathBuf::from("/tmp/my repo")));
+
+        let mut state = CompletionState::new();
+        ModeState::process_key(&mut state, KeyCode::Char('c'), harness.merge_app_mut()).await;
+        assert_eq!(
+            std::fs::read_to_string(&clipboard_file).unwrap(),
+            "git -C '/tmp/my repo' push -u origin patch/main-v1.0.0"
+        );
+        let (message, color) = state.action_message.clone().unwrap();
+        assert!(message.starts_with("Copied push command to "));
+        assert_eq!(color, Color::Green);
+
+        ModeState::process_key(&mut state, KeyCode::Char('n'), harness.merge_app_mut()).await;
+        assert!(
+            std::fs::read_to_string(&clipboard_file)
+                .unwrap()
+                .contains("v1.0.0")
+        );
+        assert!(
+            state
+                .action_message
+                .as_ref()
+                .unwrap()
+                .0
+                .starts_with("Copied release notes to ")
+        );
+    }
+
+    /// # Completion State - Remove Worktree
+    ///
+    /// Tests finishing the merge and removing its worktree with 'x'.
+    ///
+    /// ## Test Scenario
+    /// - Processes 'x' without a worktree
+    /// - Creates a worktree for the version, processes 'x' and then 'Esc'
+    /// - Processes 'x' twice
+    ///
+    /// ## Expected Outcome
+    /// - Without a worktree a message is shown and the screen stays
+    /// - A single 'x' only asks for a second one; other keys cancel it
+    /// - Pressing 'x' twice removes the worktree and exits
+    #[tokio::test]
+    async fn test_completion_remove_worktree() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo_path = dir.path().to_path_buf();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .current_dir(&repo_path)
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(
+                output.status.success(),
+                "{}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        };
+        git(&["init", "-b", "main"]);
+        git(&["config", "user.name", "Test User"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "commit.gpgsign", "false"]);
+        git(&["commit", "--allow-empty", "-m", "Initial commit"]);
+
+        let mut harness = TuiTestHarness::with_config(create_test_config_default());
+        harness.app.set_version(Some("v1.0.0".to_string()));
+
+        let mut state = CompletionState::new();
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Char('x'), harness.merge_app_mut()).await;
+        assert!(matches!(result, StateChange::Keep));
+        assert_eq!(state.action_message.as_ref().unwrap().1, Color::Yellow);
+
+        let worktree = repo_path.join("next-v1.0.0");
+        git(&[
+            "worktree",
+            "add",
+            "-b",
+            "patch/main-v1.0.0",
+            worktree.to_str().unwrap(),
+        ]);
+        harness.merge_app_mut().worktree.base_repo_path = Some(repo_path.clone());
+        harness.app.set_repo_path(Some(worktree.clone()));
+
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Char('x'), harness.merge_app_mut()).await;
+        assert!(matches!(result, StateChange::Keep));
+        assert!(state.action_message.as_ref().unwrap().0.contains("again"));
+        assert!(ModeState::action_map(&state).handles(KeyCode::Esc));
+        ModeState::process_key(&mut state, KeyCode::Esc, harness.merge_app_mut()).await;
+        assert!(state.action_message.is_none());
+        assert!(!ModeState::action_map(&state).handles(KeyCode::Esc));
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Char('x'), harness.merge_app_mut()).await;
+        assert!(matches!(result, StateChange::Keep));
+        assert!(worktree.exists());
+
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Char('x'), harness.merge_app_mut()).await;
+        assert!(matches!(result, StateChange::Exit));
+        assert!(!worktree.exists());
+    }
+
     /// # Completion State - Other Keys Ignored
     ///
     /// Tests that other keys are ignored.
@@ -694,7 +1586,7 @@ mod tests {
 
         let mut state = CompletionState::new();
 
-        for key in [KeyCode::Char('x'), KeyCode::Esc, KeyCode::Enter] {
+        for key in [KeyCode::Char('z'), KeyCode::Esc, KeyCode::Enter] {
             let result = ModeState::process_key(&mut state, key, harness.merge_app_mut()).await;
             assert!(matches!(result, StateChange::Keep));
         }
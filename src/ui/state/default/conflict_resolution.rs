@@ -1,5 +1,7 @@
 use super::MergeState;
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::{
+    core::operations::{ConflictHunk, ConflictSide, SavedResolutions, load_conflict_hunks},
     core::state::{MergePhase, StateItemStatus},
     git,
     models::CherryPickStatus,
@@ -17,14 +19,280 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
 };
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 
 pub struct ConflictResolutionState {
     conflicted_files: Vec<String>,
+    /// Index of the highlighted file in `conflicted_files`.
+    selected: usize,
+    /// Conflicted files holding binary content, detected on first use.
+    binary_files: Option<HashSet<String>>,
+    /// Binary files resolved with the chooser, and the side that was kept.
+    resolved: HashMap<String, ConflictSide>,
+    /// Conflicted files with a resolution saved from an earlier cherry-pick
+    /// of the PR, detected on first use.
+    saved_files: Option<HashSet<String>>,
+    /// Files resolved by re-applying their saved resolution.
+    reused: HashSet<String>,
+    /// Three-way diff of the highlighted file, when open.
+    diff_view: Option<DiffView>,
+}
+
+/// Three-way diff of one conflicted file.
+struct DiffView {
+    file: String,
+    /// The file's conflict hunks, or why they could not be loaded.
+    hunks: Result<Vec<ConflictHunk>, String>,
+    /// Index of the shown hunk.
+    hunk: usize,
 }
 
 impl ConflictResolutionState {
     pub fn new(conflicted_files: Vec<String>) -> Self {
-        Self { conflicted_files }
+        Self {
+            conflicted_files,
+            selected: 0,
+            binary_files: None,
+            resolved: HashMap::new(),
+            saved_files: None,
+            reused: HashSet::new(),
+            diff_view: None,
+        }
+    }
+
+    /// Whether the three-way diff viewer is open.
+    pub fn is_viewing_diff(&self) -> bool {
+        self.diff_view.is_some()
+    }
+
+    /// Index of the hunk shown in the diff viewer.
+    pub fn diff_hunk(&self) -> Option<usize> {
+        self.diff_view.as_ref().map(|view| view.hunk)
+    }
+
+    /// Files with unresolved conflicts.
+    pub fn conflicted_files(&self) -> &[String] {
+        &self.conflicted_files
+    }
+
+    /// The highlighted file.
+    pub fn selected_file(&self) -> Option<&str> {
+        self.conflicted_files.get(self.selected).map(String::as_str)
+    }
+
+    /// Side kept for a binary file resolved with the chooser.
+    pub fn resolution(&self, file: &str) -> Option<ConflictSide> {
+        self.resolved.get(file).copied()
+    }
+
+    fn detect_binary_files(&mut self, repo_path: &Path) -> &HashSet<String> {
+        self.binary_files.get_or_insert_with(|| {
+            self.conflicted_files
+                .iter()
+                .filter(|file| git::is_binary_conflict(repo_path, file).unwrap_or(false))
+                .cloned()
+                .collect()
+        })
+    }
+
+    fn detect_saved_files(&mut self, app: &MergeApp) {
+        if self.saved_files.is_some() {
+            return;
+        }
+        let saved = match saved_resolutions(app) {
+            Some((store, pr_id)) => store.available(pr_id, &self.conflicted_files),
+            None => Vec::new(),
+        };
+        self.saved_files = Some(saved.into_iter().collect());
+    }
+
+    /// Saved resolutions that have not been re-applied yet.
+    fn pending_saved_files(&self) -> Vec<String> {
+        self.conflicted_files
+            .iter()
+            .filter(|file| self.has_saved_resolution(file) && !self.reused.contains(*file))
+            .cloned()
+            .collect()
+    }
+
+    fn has_saved_resolution(&self, file: &str) -> bool {
+        self.saved_files
+            .as_ref()
+            .is_some_and(|files| files.contains(file))
+    }
+
+    /// Re-applies the saved resolutions of files that are still conflicted.
+    fn reapply_saved(&mut self, repo_path: &Path, app: &MergeApp) {
+        self.detect_saved_files(app);
+        let Some((store, pr_id)) = saved_resolutions(app) else {
+            return;
+        };
+        let unresolved = git::unresolved_files(repo_path).unwrap_or_default();
+        let files: Vec<String> = self
+            .pending_saved_files()
+            .into_iter()
+            .filter(|file| unresolved.contains(file))
+            .collect();
+        match store.reapply(repo_path, pr_id, &files) {
+            Ok(applied) => self.reused.extend(applied),
+            Err(e) => tracing::warn!("Failed to re-apply saved resolutions: {}", e),
+        }
+    }
+
+    fn is_binary(&self, file: &str) -> bool {
+        self.binary_files
+            .as_ref()
+            .is_some_and(|files| files.contains(file))
+    }
+
+    /// Keeps one side of the highlighted file and stages it.
+    fn choose_side(&mut self, repo_path: &Path, side: ConflictSide) {
+        let Some(file) = self.selected_file().map(str::to_string) else {
+            return;
+        };
+        if git::checkout_conflict_side(repo_path, &file, side).is_ok() {
+            self.resolved.insert(file, side);
+        }
+    }
+
+    /// Opens the diff viewer on the highlighted file, or reloads it after
+    /// another file was highlighted.
+    fn load_diff(&mut self, repo_path: &Path) {
+        let Some(file) = self.selected_file().map(str::to_string) else {
+            return;
+        };
+        if self
+            .diff_view
+            .as_ref()
+            .is_some_and(|view| view.file == file)
+        {
+            return;
+        }
+        let hunks = if self.detect_binary_files(repo_path).contains(&file) {
+            Err("Binary file: pick the version to keep.".to_string())
+        } else {
+            load_conflict_hunks(repo_path, &file).map_err(|e| e.to_string())
+        };
+        self.diff_view = Some(DiffView {
+            file,
+            hunks,
+            hunk: 0,
+        });
+    }
+
+    /// Moves the diff viewer to the next (`1`) or previous (`-1`) hunk.
+    fn move_hunk(&mut self, delta: isize) {
+        if let Some(view) = &mut self.diff_view
+            && let Ok(hunks) = &view.hunks
+            && !hunks.is_empty()
+        {
+            view.hunk = view.hunk.saturating_add_signed(delta).min(hunks.len() - 1);
+        }
+    }
+
+    fn render_diff_view(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let Some(view) = &self.diff_view else {
+            return;
+        };
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+
+        let hunks = match (&view.hunks, self.resolved.get(&view.file)) {
+            (_, Some(side)) => Err(format!("Resolved: kept {}.", side_label(*side))),
+            (_, None) if self.reused.contains(&view.file) => {
+                Err("Resolved with the saved resolution.".to_string())
+            }
+            (Ok(hunks), None) if hunks.is_empty() => Err(
+                "No conflicting lines: the versions merge cleanly. Keep one side or stage the file."
+                    .to_string(),
+            ),
+            (Ok(hunks), None) => Ok(hunks),
+            (Err(message), None) => Err(message.clone()),
+        };
+
+        let header = match &hunks {
+            Ok(hunks) => Line::from(vec![
+                Span::styled(
+                    format!("Conflict {} of {}", view.hunk + 1, hunks.len()),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!(" at line {}", hunks[view.hunk].ours_line)),
+            ]),
+            Err(_) => Line::from(""),
+        };
+        let header = Paragraph::new(header).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("3-Way Diff: {}", view.file)),
+        );
+        f.render_widget(header, chunks[0]);
+
+        let hunk = match hunks {
+            Ok(hunks) => &hunks[view.hunk],
+            Err(message) => {
+                let message = Paragraph::new(message)
+                    .block(Block::default().borders(Borders::ALL))
+                    .style(Style::default().fg(Color::White))
+                    .wrap(Wrap { trim: true });
+                f.render_widget(message, chunks[1]);
+                return;
+            }
+        };
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Ratio(1, 3),
+                Constraint::Ratio(1, 3),
+                Constraint::Ratio(1, 3),
+            ])
+            .split(chunks[1]);
+        let sides = [
+            ("Ours (target)", &hunk.ours, Color::Cyan),
+            ("Base", &hunk.base, Color::Gray),
+            ("Theirs (PR)", &hunk.theirs, Color::Green),
+        ];
+        for ((title, lines, color), column) in sides.into_iter().zip(columns.iter()) {
+            f.render_widget(hunk_side(hunk, title, lines, color), *column);
+        }
+    }
+
+    fn render_diff_instructions(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let key_style = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let instructions = vec![
+            Line::from(vec![
+                Span::styled("n", key_style),
+                Span::raw("/"),
+                Span::styled("N", key_style),
+                Span::raw(": Next/previous conflict | "),
+                Span::styled("↑↓", key_style),
+                Span::raw(": Select file | "),
+                Span::styled("d", key_style),
+                Span::raw("/"),
+                Span::styled("Esc", key_style),
+                Span::raw(": Close diff"),
+            ]),
+            Line::from(vec![
+                Span::styled("o", key_style),
+                Span::raw(": Keep ours (target) | "),
+                Span::styled("t", key_style),
+                Span::raw(": Take theirs (PR) | "),
+                Span::styled("c", key_style),
+                Span::raw(": Continue (after resolving)"),
+            ]),
+            Line::from("Keeping a side writes that version of the whole file and stages it."),
+        ];
+        let instructions_widget = Paragraph::new(instructions)
+            .block(Block::default().borders(Borders::ALL).title("Instructions"))
+            .style(Style::default().fg(Color::White));
+        f.render_widget(instructions_widget, area);
     }
 
     fn render_commit_info(
@@ -122,17 +390,82 @@ impl ConflictResolutionState {
         let files: Vec<ListItem> = self
             .conflicted_files
             .iter()
-            .map(|file| ListItem::new(format!("  • {}", file)))
+            .enumerate()
+            .map(|(index, file)| {
+                let marker = if index == self.selected { "▶ " } else { "  " };
+                let mut spans = vec![Span::raw(format!("{}• {}", marker, file))];
+                match self.resolved.get(file) {
+                    Some(side) => spans.push(Span::styled(
+                        format!("  ✓ kept {}", side_label(*side)),
+                        Style::default().fg(Color::Green),
+                    )),
+                    None if self.reused.contains(file) => spans.push(Span::styled(
+                        "  ✓ saved resolution",
+                        Style::default().fg(Color::Green),
+                    )),
+                    None if self.has_saved_resolution(file) => spans.push(Span::styled(
+                        "  [saved resolution]",
+                        Style::default().fg(Color::Cyan),
+                    )),
+                    None if self.is_binary(file) => spans.push(Span::styled(
+                        "  [binary]",
+                        Style::default().fg(Color::Magenta),
+                    )),
+                    None => {}
+                }
+                ListItem::new(Line::from(spans))
+            })
             .collect();
 
+        let binary_selected = self
+            .selected_file()
+            .is_some_and(|file| self.is_binary(file));
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(0),
+                Constraint::Length(if binary_selected { 4 } else { 0 }),
+            ])
+            .split(area);
+
+        let pending = self.pending_saved_files().len();
+        let title = if pending > 0 {
+            format!(
+                "Conflicted Files (r: re-apply {} saved resolution{})",
+                pending,
+                if pending == 1 { "" } else { "s" }
+            )
+        } else {
+            "Conflicted Files".to_string()
+        };
         let file_list = List::new(files)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .style(Style::default().fg(Color::Red));
+        f.render_widget(file_list, chunks[0]);
+
+        if binary_selected {
+            let key_style = Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD);
+            let chooser = Paragraph::new(vec![
+                Line::from("Binary file: pick the version to keep."),
+                Line::from(vec![
+                    Span::styled("o", key_style),
+                    Span::raw(": Ours (target) | "),
+                    Span::styled("t", key_style),
+                    Span::raw(": Theirs (PR) | "),
+                    Span::styled("f", key_style),
+                    Span::raw(": Open folder"),
+                ]),
+            ])
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title("Conflicted Files"),
+                    .title("Binary Conflict"),
             )
-            .style(Style::default().fg(Color::Red));
-        f.render_widget(file_list, area);
+            .style(Style::default().fg(Color::White));
+            f.render_widget(chooser, chunks[1]);
+        }
     }
 
     fn render_pr_details(
@@ -306,6 +639,11 @@ impl ModeState for ConflictResolutionState {
     type Mode = MergeState;
 
     fn ui(&mut self, f: &mut Frame, app: &MergeApp) {
+        if let Some(repo_path) = app.repo_path() {
+            self.detect_binary_files(repo_path);
+        }
+        self.detect_saved_files(app);
+
         // Main layout: Title at top, content in middle, help at bottom
         let main_chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -327,6 +665,17 @@ impl ModeState for ConflictResolutionState {
             .block(Block::default().borders(Borders::ALL));
         f.render_widget(title, main_chunks[0]);
 
+        if self.diff_view.is_some() {
+            let content_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+                .split(main_chunks[1]);
+            self.render_conflicted_files(f, content_chunks[0]);
+            self.render_diff_view(f, content_chunks[1]);
+            self.render_diff_instructions(f, main_chunks[2]);
+            return;
+        }
+
         // Split content horizontally: Left and Right panes
         let content_chunks = Layout::default()
             .direction(Direction::Horizontal)
@@ -379,7 +728,11 @@ impl ModeState for ConflictResolutionState {
                 Span::raw("Repository: "),
                 Span::styled(format!("{}", repo_path), Style::default().fg(Color::Cyan)),
             ]),
-            Line::from("Please resolve conflicts in another terminal and stage the changes."),
+            Line::from(vec![
+                Span::raw("Please resolve conflicts in another terminal and stage the changes. "),
+                Span::styled("K", key_style),
+                Span::raw(": Abort, keeping applied commits"),
+            ]),
             Line::from(vec![
                 Span::styled("c", key_style),
                 Span::raw(": Continue (after resolving) | "),
@@ -390,7 +743,9 @@ impl ModeState for ConflictResolutionState {
                 Span::styled("p", key_style),
                 Span::raw(": Open PR | "),
                 Span::styled("w", key_style),
-                Span::raw(": Open Work Item"),
+                Span::raw(": Open Work Item | "),
+                Span::styled("d", key_style),
+                Span::raw(": 3-way diff"),
             ]),
         ];
 
@@ -411,11 +766,18 @@ impl ModeState for ConflictResolutionState {
                 // Check if conflicts are resolved
                 match git::check_conflicts_resolved(&repo_path) {
                     Ok(true) => {
+                        // Save the resolutions for later cherry-picks of the same PR
+                        if let Some((store, pr_id)) = saved_resolutions(app)
+                            && let Err(e) = store.save(&repo_path, pr_id, &self.conflicted_files)
+                        {
+                            tracing::warn!("Failed to save conflict resolutions: {}", e);
+                        }
                         // Transition to CherryPickContinueState to process the commit with feedback
                         StateChange::Change(MergeState::CherryPickContinue(
                             CherryPickContinueState::new(
                                 self.conflicted_files.clone(),
                                 repo_path.clone(),
+                                app.sign_commits(),
                             ),
                         ))
                     }
@@ -452,6 +814,80 @@ impl ModeState for ConflictResolutionState {
                     target_branch,
                 )))
             }
+            KeyCode::Char('K') => {
+                // Abort but keep the commits applied so far on a rescue branch
+                let version_opt = app.version();
+                let version = version_opt.as_ref().unwrap().to_string();
+                let target_branch = app.target_branch().to_string();
+                let base_repo_path = app.state_file().and_then(|sf| sf.base_repo_path.clone());
+                if base_repo_path.is_none() {
+                    // A cloned repo would be deleted on exit, taking the rescue branch with it
+                    app.worktree.keep_temp_dir();
+                }
+                StateChange::Change(MergeState::Aborting(AbortingState::keeping_applied(
+                    base_repo_path,
+                    repo_path.clone(),
+                    version,
+                    target_branch,
+                )))
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = self.selected.saturating_sub(1);
+                if self.is_viewing_diff() {
+                    self.load_diff(&repo_path);
+                }
+                StateChange::Keep
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                if self.selected + 1 < self.conflicted_files.len() {
+                    self.selected += 1;
+                }
+                if self.is_viewing_diff() {
+                    self.load_diff(&repo_path);
+                }
+                StateChange::Keep
+            }
+            KeyCode::Char('d') => {
+                if self.is_viewing_diff() {
+                    self.diff_view = None;
+                } else {
+                    self.load_diff(&repo_path);
+                }
+                StateChange::Keep
+            }
+            KeyCode::Esc if self.is_viewing_diff() => {
+                self.diff_view = None;
+                StateChange::Keep
+            }
+            KeyCode::Char('n') => {
+                self.move_hunk(1);
+                StateChange::Keep
+            }
+            KeyCode::Char('N') => {
+                self.move_hunk(-1);
+                StateChange::Keep
+            }
+            KeyCode::Char('o') => {
+                self.choose_side(&repo_path, ConflictSide::Ours);
+                StateChange::Keep
+            }
+            KeyCode::Char('t') => {
+                self.choose_side(&repo_path, ConflictSide::Theirs);
+                StateChange::Keep
+            }
+            KeyCode::Char('r') => {
+                self.reapply_saved(&repo_path, app);
+                StateChange::Keep
+            }
+            KeyCode::Char('f') => {
+                // Open the folder containing the highlighted file
+                if let Some(file) = self.selected_file() {
+                    let folder = repo_path.join(file);
+                    let folder = folder.parent().unwrap_or(&repo_path);
+                    app.open_folder(folder);
+                }
+                StateChange::Keep
+            }
             KeyCode::Char('p') => {
                 // Open current PR in browser
                 let current_item = &app.cherry_pick_items()[app.current_cherry_pick_index()];
@@ -476,11 +912,140 @@ impl ModeState for ConflictResolutionState {
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        let map = ActionMap::new("Conflict Resolution")
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('c')],
+                "Continue after resolving conflicts",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('s')],
+                "Skip this commit",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('a')],
+                "Abort the merge and clean up",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('K')],
+                "Abort, keeping applied commits on a rescue branch",
+            )
+            .bind(
+                ActionCategory::Navigation,
+                &[KeyCode::Up, KeyCode::Char('k')],
+                "Select previous file",
+            )
+            .bind(
+                ActionCategory::Navigation,
+                &[KeyCode::Down, KeyCode::Char('j')],
+                "Select next file",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('o')],
+                "Keep target branch version of file",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('t')],
+                "Take PR version of file",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('d')],
+                "Toggle three-way diff of file",
+            );
+        let map = if self.is_viewing_diff() {
+            map.bind(ActionCategory::View, &[KeyCode::Esc], "Close diff")
+        } else {
+            map
+        };
+        map.bind(
+            ActionCategory::Navigation,
+            &[KeyCode::Char('n'), KeyCode::Char('N')],
+            "Next / previous conflict in diff",
+        )
+        .bind(
+            ActionCategory::Actions,
+            &[KeyCode::Char('r')],
+            "Re-apply saved resolutions",
+        )
+        .bind(
+            ActionCategory::View,
+            &[KeyCode::Char('f')],
+            "Open folder containing file",
+        )
+        .bind(
+            ActionCategory::View,
+            &[KeyCode::Char('p')],
+            "Open pull request in browser",
+        )
+        .bind(
+            ActionCategory::View,
+            &[KeyCode::Char('w')],
+            "Open work items in browser",
+        )
+    }
+
     fn name(&self) -> &'static str {
         "ConflictResolution"
     }
 }
 
+/// Saved resolutions of the repository and the PR being cherry-picked.
+fn saved_resolutions(app: &MergeApp) -> Option<(SavedResolutions, i32)> {
+    let item = app
+        .cherry_pick_items()
+        .get(app.current_cherry_pick_index())?;
+    let store =
+        SavedResolutions::for_repository(app.organization(), app.project(), app.repository())
+            .ok()?;
+    Some((store, item.pr_id))
+}
+
+/// One side of a conflict hunk between its context lines.
+fn hunk_side<'a>(
+    hunk: &'a ConflictHunk,
+    title: &'a str,
+    lines: &'a [String],
+    color: Color,
+) -> Paragraph<'a> {
+    let context = Style::default().fg(Color::DarkGray);
+    let mut text: Vec<Line> = hunk
+        .before
+        .iter()
+        .map(|line| Line::styled(line.as_str(), context))
+        .collect();
+    if lines.is_empty() {
+        text.push(Line::styled(
+            "(no lines)",
+            context.add_modifier(Modifier::ITALIC),
+        ));
+    }
+    text.extend(
+        lines
+            .iter()
+            .map(|line| Line::styled(line.as_str(), Style::default().fg(color))),
+    );
+    text.extend(
+        hunk.after
+            .iter()
+            .map(|line| Line::styled(line.as_str(), context)),
+    );
+    Paragraph::new(text).block(Block::default().borders(Borders::ALL).title(title))
+}
+
+fn side_label(side: ConflictSide) -> &'static str {
+    match side {
+        ConflictSide::Ours => "ours (target)",
+        ConflictSide::Theirs => "theirs (PR)",
+    }
+}
+
 fn get_work_item_type_color(work_item_type: &str) -> Color {
     match work_item_type.to_lowercase().as_str() {
         "task" => Color::Yellow,
@@ -644,6 +1209,53 @@ mod tests {
         assert!(matches!(result, StateChange::Change(_)));
     }
 
+    /// # Conflict Resolution - Abort Keeping Applied Commits
+    ///
+    /// Tests behavior when user presses 'K' to abort selectively.
+    ///
+    /// ## Test Scenario
+    /// - Creates a conflict resolution state for a cloned repo
+    /// - Simulates pressing 'K'
+    ///
+    /// ## Expected Outcome
+    /// - Should transition to the aborting state
+    /// - The cloned repository is no longer removed on exit
+    #[tokio::test]
+    async fn test_conflict_resolution_abort_keeping_applied() {
+        use crossterm::event::KeyCode;
+
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+
+        let clone_dir = tempfile::TempDir::new().unwrap();
+        let clone_path = clone_dir.path().to_path_buf();
+        harness
+            .merge_app_mut()
+            .worktree
+            .set_temp_dir(Some(clone_dir));
+        harness.app.set_repo_path(Some(clone_path.join("missing")));
+        harness.app.set_version(Some("v1.0.0".to_string()));
+        *harness.app.cherry_pick_items_mut() = vec![CherryPickItem {
+            commit_id: "abc123".to_string(),
+            pr_id: 100,
+            pr_title: "Test PR".to_string(),
+            status: CherryPickStatus::Conflict,
+        }];
+        harness.app.set_current_cherry_pick_index(0);
+
+        let mut state = ConflictResolutionState::new(vec!["test.rs".to_string()]);
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Char('K'), harness.merge_app_mut()).await;
+
+        assert!(matches!(
+            result,
+            StateChange::Change(MergeState::Aborting(_))
+        ));
+        assert!(harness.merge_app().worktree._temp_dir.is_none());
+        assert!(clone_path.exists());
+        std::fs::remove_dir_all(clone_path).unwrap();
+    }
+
     /// # Conflict Resolution - Other Key Press
     ///
     /// Tests behavior when user presses other keys.
@@ -908,6 +1520,7 @@ mod tests {
                         commit_id: "abc123".to_string(),
                     }),
                     labels: None,
+                    reviewers: Vec::new(),
                 },
                 work_items: vec![],
                 selected: false,
@@ -962,6 +1575,7 @@ mod tests {
                         commit_id: "abc123".to_string(),
                     }),
                     labels: None,
+                    reviewers: Vec::new(),
                 },
                 work_items: vec![WorkItem {
                     id: 1001,
@@ -978,6 +1592,7 @@ mod tests {
                         ),
                         repro_steps: Some("<div>1. Navigate to login page<br>2. Click login button<br>3. Nothing happens</div>".to_string()),
                         state_color: None,
+                        custom_fields: Default::default(),
                     },
                     history: vec![],
                 }],
@@ -1035,6 +1650,7 @@ mod tests {
                         commit_id: "def456".to_string(),
                     }),
                     labels: None,
+                    reviewers: Vec::new(),
                 },
                 work_items: vec![WorkItem {
                     id: 2001,
@@ -1052,6 +1668,7 @@ mod tests {
                         ),
                         repro_steps: None,
                         state_color: None,
+                        custom_fields: Default::default(),
                     },
                     history: vec![],
                 }],
@@ -1072,4 +1689,284 @@ mod tests {
             assert_snapshot!("task_work_item", harness.backend());
         });
     }
+
+    /// # Conflict Resolution - Side Chooser
+    ///
+    /// Tests keeping one side of a conflicted file from the conflict screen.
+    ///
+    /// ## Test Scenario
+    /// - Pauses a cherry-pick on a binary `logo.png` and a text `notes.txt`
+    /// - Renders the screen, presses 'o' on the binary file
+    /// - Moves down and presses 't' on the text file
+    ///
+    /// ## Expected Outcome
+    /// - The binary file is resolved to the target branch's version and staged
+    /// - The text file is resolved to the PR's version and staged
+    #[tokio::test]
+    async fn test_conflict_resolution_binary_chooser() {
+        use std::process::Command;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        let git = |args: &[&str]| {
+            let output = Command::new("git")
+                .current_dir(repo)
+                .args(args)
+                .output()
+                .unwrap();
+            output.status.success()
+        };
+        git(&["init", "-q", "-b", "main"]);
+        git(&["config", "user.name", "Test User"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "commit.gpgsign", "false"]);
+        for (branch, content) in [("base", "base"), ("feature", "feature"), ("main", "main")] {
+            if branch == "feature" {
+                git(&["checkout", "-q", "-b", "feature"]);
+            } else if branch == "main" {
+                git(&["checkout", "-q", "main"]);
+            }
+            std::fs::write(repo.join("logo.png"), format!("PNG\0{}", content)).unwrap();
+            std::fs::write(repo.join("notes.txt"), format!("{}\n", content)).unwrap();
+            git(&["add", "."]);
+            git(&["commit", "-q", "-m", branch]);
+        }
+        assert!(
+            !git(&["cherry-pick", "feature"]),
+            "cherry-pick should conflict"
+        );
+
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+        harness.app.set_repo_path(Some(repo.to_path_buf()));
+        *harness.app.cherry_pick_items_mut() = vec![CherryPickItem {
+            commit_id: "abc123".to_string(),
+            pr_id: 100,
+            pr_title: "Update logo".to_string(),
+            status: CherryPickStatus::Conflict,
+        }];
+        harness.app.set_current_cherry_pick_index(0);
+
+        let mut state = ConflictResolutionState::new(git::unresolved_files(repo).unwrap());
+        harness.render_state(&mut state);
+        assert_eq!(state.selected_file(), Some("logo.png"));
+
+        for key in [KeyCode::Char('o'), KeyCode::Down, KeyCode::Char('t')] {
+            let result = ModeState::process_key(&mut state, key, harness.merge_app_mut()).await;
+            assert!(matches!(result, StateChange::Keep));
+        }
+
+        assert_eq!(state.resolution("logo.png"), Some(ConflictSide::Ours));
+        assert_eq!(state.resolution("notes.txt"), Some(ConflictSide::Theirs));
+        assert!(git::unresolved_files(repo).unwrap().is_empty());
+        assert_eq!(std::fs::read(repo.join("logo.png")).unwrap(), b"PNG\0main");
+        assert_eq!(
+            std::fs::read_to_string(repo.join("notes.txt")).unwrap(),
+            "feature\n"
+        );
+    }
+
+    /// # Conflict Resolution - Three-Way Diff
+    ///
+    /// Tests the three-way diff viewer of a conflicted text file.
+    ///
+    /// ## Test Scenario
+    /// - Pauses a cherry-pick on a text file with two conflicting lines
+    /// - Presses 'd' and renders the viewer
+    /// - Moves between conflicts with 'n' and 'N', then closes with Esc
+    ///
+    /// ## Expected Outcome
+    /// - The first conflict shows the target's, base's and PR's lines with
+    ///   context
+    /// - Hunk navigation stops at the first and last conflict
+    /// - Esc is only in the action map while the diff is shown
+    #[tokio::test]
+    async fn test_conflict_resolution_diff_view() {
+        use std::process::Command;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        let git = |args: &[&str]| {
+            let output = Command::new("git")
+                .current_dir(repo)
+                .args(args)
+                .output()
+                .unwrap();
+            output.status.success()
+        };
+        git(&["init", "-q", "-b", "main"]);
+        git(&["config", "user.name", "Test User"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "commit.gpgsign", "false"]);
+        for (branch, content) in [
+            (
+                "base",
+                "timeout = 30\nretries = 3\nmode = fast\nlog = info\n",
+            ),
+            (
+                "feature",
+                "timeout = 60\nretries = 3\nmode = fast\nlog = debug\n",
+            ),
+            (
+                "main",
+                "timeout = 45\nretries = 3\nmode = fast\nlog = warn\n",
+            ),
+        ] {
+            if branch == "feature" {
+                git(&["checkout", "-q", "-b", "feature"]);
+            } else if branch == "main" {
+                git(&["checkout", "-q", "main"]);
+            }
+            std::fs::write(repo.join("settings.ini"), content).unwrap();
+            git(&["add", "."]);
+            git(&["commit", "-q", "-m", branch]);
+        }
+        assert!(
+            !git(&["cherry-pick", "feature"]),
+            "cherry-pick should conflict"
+        );
+
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+        harness.app.set_repo_path(Some(repo.to_path_buf()));
+        *harness.app.cherry_pick_items_mut() = vec![CherryPickItem {
+            commit_id: "abc123".to_string(),
+            pr_id: 100,
+            pr_title: "Tune settings".to_string(),
+            status: CherryPickStatus::Conflict,
+        }];
+        harness.app.set_current_cherry_pick_index(0);
+
+        let mut state = ConflictResolutionState::new(git::unresolved_files(repo).unwrap());
+        assert!(!ModeState::action_map(&state).handles(KeyCode::Esc));
+        ModeState::process_key(&mut state, KeyCode::Char('d'), harness.merge_app_mut()).await;
+        assert!(state.is_viewing_diff());
+        assert_eq!(state.diff_hunk(), Some(0));
+        assert!(ModeState::action_map(&state).handles(KeyCode::Esc));
+
+        with_settings_and_module_path(module_path!(), || {
+            harness.render_state(&mut state);
+            assert_snapshot!("diff_view", harness.backend());
+        });
+
+        let mut hunks = Vec::new();
+        for key in [KeyCode::Char('n'), KeyCode::Char('n'), KeyCode::Char('N')] {
+            ModeState::process_key(&mut state, key, harness.merge_app_mut()).await;
+            hunks.push(state.diff_hunk());
+        }
+        assert_eq!(hunks, vec![Some(1), Some(1), Some(0)]);
+
+        ModeState::process_key(&mut state, KeyCode::Esc, harness.merge_app_mut()).await;
+        assert!(!state.is_viewing_diff());
+        assert_eq!(git::unresolved_files(repo).unwrap(), vec!["settings.ini"]);
+    }
+
+    /// # Conflict Resolution - Saved Resolutions
+    ///
+    /// Tests saving a resolution on continue and re-applying it when the
+    /// same PR conflicts on another target branch.
+    ///
+    /// ## Test Scenario
+    /// - Resolves a conflict on `main` and presses 'c'
+    /// - Cherry-picks the same commit onto `release`, which conflicts the
+    ///   same way, and presses 'r'
+    ///
+    /// ## Expected Outcome
+    /// - Continuing saves the resolution and commits the pick
+    /// - The file on `release` is resolved to the saved resolution and staged
+    #[tokio::test]
+    #[serial_test::file_serial(state_env)]
+    async fn test_conflict_resolution_reapply_saved() {
+        use crate::core::state::STATE_DIR_ENV;
+        use std::process::Command;
+
+        let state_dir = tempfile::TempDir::new().unwrap();
+        // SAFETY: state_env tests are serialized
+        unsafe { std::env::set_var(STATE_DIR_ENV, state_dir.path()) };
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        let git = |args: &[&str]| {
+            let output = Command::new("git")
+                .current_dir(repo)
+                .args(args)
+                .output()
+                .unwrap();
+            output.status.success()
+        };
+        git(&["init", "-q", "-b", "main"]);
+        git(&["config", "user.name", "Test User"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "commit.gpgsign", "false"]);
+        let write_value = |value: &str| {
+            let content = format!("a\nb\nc\nd\nvalue = {}\ne\nf\ng\nh\n", value);
+            std::fs::write(repo.join("notes.txt"), content).unwrap();
+        };
+        write_value("1");
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "Initial"]);
+        // `feature` changes the value; `main` and `release` both change it to 3
+        for (branch, value) in [("feature", "2"), ("release", "3")] {
+            git(&["checkout", "-q", "-b", branch, "main"]);
+            write_value(value);
+            git(&["commit", "-q", "-am", branch]);
+        }
+        git(&["checkout", "-q", "main"]);
+        write_value("3");
+        git(&["commit", "-q", "-am", "main"]);
+        assert!(
+            !git(&["cherry-pick", "feature"]),
+            "cherry-pick should conflict"
+        );
+
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+        harness.app.set_repo_path(Some(repo.to_path_buf()));
+        *harness.app.cherry_pick_items_mut() = vec![CherryPickItem {
+            commit_id: "abc123".to_string(),
+            pr_id: 100,
+            pr_title: "Update value".to_string(),
+            status: CherryPickStatus::Conflict,
+        }];
+        harness.app.set_current_cherry_pick_index(0);
+
+        let resolved = "a\nb\nc\nd\nvalue = 2 + 3\ne\nf\ng\nh\n";
+        std::fs::write(repo.join("notes.txt"), resolved).unwrap();
+        git(&["add", "notes.txt"]);
+        let mut state = ConflictResolutionState::new(vec!["notes.txt".to_string()]);
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Char('c'), harness.merge_app_mut()).await;
+        assert!(matches!(result, StateChange::Change(_)));
+        // The continue state commits the pick in the background
+        let git_dir = repo.join(".git");
+        for _ in 0..200 {
+            if !git_dir.join("CHERRY_PICK_HEAD").exists() && !git_dir.join("index.lock").exists() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+        assert!(!git_dir.join("CHERRY_PICK_HEAD").exists());
+
+        git(&["checkout", "-q", "release"]);
+        assert!(
+            !git(&["cherry-pick", "feature"]),
+            "cherry-pick should conflict"
+        );
+        let mut state = ConflictResolutionState::new(git::unresolved_files(repo).unwrap());
+        harness.render_state(&mut state);
+        assert_eq!(state.pending_saved_files(), vec!["notes.txt"]);
+
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Char('r'), harness.merge_app_mut()).await;
+        assert!(matches!(result, StateChange::Keep));
+        assert!(state.pending_saved_files().is_empty());
+        assert!(git::unresolved_files(repo).unwrap().is_empty());
+        assert_eq!(
+            std::fs::read_to_string(repo.join("notes.txt")).unwrap(),
+            resolved
+        );
+
+        // SAFETY: state_env tests are serialized
+        unsafe { std::env::remove_var(STATE_DIR_ENV) };
+    }
 }
@@ -1,9 +1,13 @@
 use super::PullRequestSelectionState;
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::{
     api,
-    core::operations::{DependencyAnalyzer, FileChange, PRDependencyGraph, PRInfo},
-    git,
-    models::PullRequestWithWorkItems,
+    core::operations::{
+        BuildCheckIndex, BuildCheckMode, DependencyAnalysisConfig, DependencyAnalyzer,
+        IGNORE_FILE_NAME, IgnoredPullRequest, PRDependencyGraph, RevertIndex, apply_ignore_file,
+        check_pull_requests, describe_unknown_states, find_unknown_work_item_states,
+    },
+    models::{PullRequest, PullRequestWithWorkItems},
     ui::apps::MergeApp,
     ui::state::default::MergeState,
     ui::state::typed::{ModeState, StateChange},
@@ -18,9 +22,10 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
 };
-use rayon::prelude::*;
-use std::collections::HashMap;
+use std::collections::HashSet;
 use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::sync::mpsc;
 
 // ============================================================================
@@ -36,6 +41,8 @@ pub enum LoadingProgressMessage {
     StepCompleted(LoadingStep, LoadingStepResult),
     /// Progress update within a step (for parallel operations like work items fetch)
     StepProgress(LoadingStep, usize, usize),
+    /// Per-PR dependency analysis progress: (analyzed, total, PR just analyzed)
+    AnalysisProgress(usize, usize, i32),
     /// All steps completed successfully
     AllComplete,
     /// An error occurred during loading
@@ -47,10 +54,16 @@ pub enum LoadingProgressMessage {
 pub struct LoadingStepResult {
     /// Fetched PR list (FetchPullRequests step)
     pub prs: Option<Vec<PullRequestWithWorkItems>>,
+    /// PRs excluded by `.mergers-ignore` (FetchPullRequests step)
+    pub ignored_prs: Option<Vec<IgnoredPullRequest>>,
+    /// Reverts among all fetched PRs (FetchPullRequests step)
+    pub reverts: Option<Box<RevertIndex>>,
     /// Work items update for a specific PR (FetchWorkItems step)
     pub work_items_update: Option<WorkItemsResult>,
     /// Commit info update for a specific PR (FetchCommitInfo step)
     pub commit_info_update: Option<CommitInfoResult>,
+    /// Build and branch policy check results (FetchCommitInfo step)
+    pub build_checks: Option<Box<BuildCheckIndex>>,
     /// Dependency graph result (AnalyzeDependencies step)
     pub dependency_graph: Option<PRDependencyGraph>,
 }
@@ -67,6 +80,8 @@ pub enum LoadingError {
     NetworkTimeout(String),
     /// Local repository not found for dependency analysis (skippable)
     LocalRepoNotFound(String),
+    /// Work items are in states outside `known_work_item_states` (strict mode)
+    UnknownWorkItemStates(String),
     /// Generic error
     Other(String),
 }
@@ -127,6 +142,16 @@ impl LoadingError {
                     path
                 )
             }
+            LoadingError::UnknownWorkItemStates(states) => {
+                format!(
+                    "Work items are in states not listed in known_work_item_states:\n{}\n\n\
+                     The process template may have changed. Update known_work_item_states\n\
+                     in the config file, or run without --strict-states.\n\n\
+                     Options:\n\
+                       • Press 'Esc' to go back",
+                    states
+                )
+            }
             LoadingError::Other(msg) => {
                 format!(
                     "Error: {}\n\n\
@@ -171,7 +196,17 @@ impl LoadingStep {
     /// Returns the progress message for this step
     pub fn progress_message(&self, fetched: usize, total: usize) -> String {
         match self {
-            LoadingStep::FetchPullRequests => "Fetching pull requests...".to_string(),
+            // For pull requests the counters are PRs fetched and pages received
+            LoadingStep::FetchPullRequests => {
+                if total > 0 {
+                    format!(
+                        "Fetching pull requests ({} PRs, page {})...",
+                        fetched, total
+                    )
+                } else {
+                    "Fetching pull requests...".to_string()
+                }
+            }
             LoadingStep::FetchWorkItems => {
                 if total > 0 {
                     format!("Fetching work items ({}/{})...", fetched, total)
@@ -187,7 +222,9 @@ impl LoadingStep {
                 }
             }
             LoadingStep::AnalyzeDependencies => {
-                if total > 0 {
+                if fetched > 0 && total > 0 {
+                    format!("Analyzing dependencies ({}/{} PRs)...", fetched, total)
+                } else if total > 0 {
                     format!("Analyzing dependencies ({} PRs)...", total)
                 } else {
                     "Analyzing dependencies...".to_string()
@@ -226,11 +263,16 @@ pub struct LoadingProgress {
     current_step: Option<LoadingStep>,
 
     /// Progress counters for parallel operations
+    prs_fetched: usize,
+    pr_pages_fetched: usize,
     work_items_fetched: usize,
     work_items_total: usize,
     commits_fetched: usize,
     commits_total: usize,
     prs_for_analysis: usize,
+    prs_analyzed: usize,
+    /// PR most recently analyzed during dependency analysis
+    analyzing_pr: Option<i32>,
 
     /// Whether dependency analysis is available (local repo exists)
     /// Reserved for future use in dynamic step configuration
@@ -251,11 +293,15 @@ impl LoadingProgress {
                 StepStatus::Skipped
             },
             current_step: None,
+            prs_fetched: 0,
+            pr_pages_fetched: 0,
             work_items_fetched: 0,
             work_items_total: 0,
             commits_fetched: 0,
             commits_total: 0,
             prs_for_analysis: 0,
+            prs_analyzed: 0,
+            analyzing_pr: None,
             dependency_analysis_available,
         }
     }
@@ -303,6 +349,10 @@ impl LoadingProgress {
     /// Updates progress counters for a step
     pub fn update_progress(&mut self, step: LoadingStep, fetched: usize, total: usize) {
         match step {
+            LoadingStep::FetchPullRequests => {
+                self.prs_fetched = fetched;
+                self.pr_pages_fetched = total;
+            }
             LoadingStep::FetchWorkItems => {
                 self.work_items_fetched = fetched;
                 self.work_items_total = total;
@@ -314,23 +364,38 @@ impl LoadingProgress {
             LoadingStep::AnalyzeDependencies => {
                 self.prs_for_analysis = total;
             }
-            _ => {}
         }
     }
 
+    /// Records per-PR dependency analysis progress
+    pub fn update_analysis_progress(&mut self, analyzed: usize, total: usize, pr_id: i32) {
+        self.prs_analyzed = analyzed;
+        self.prs_for_analysis = total;
+        self.analyzing_pr = Some(pr_id);
+    }
+
+    /// Returns `true` while the dependency analysis step is running
+    pub fn is_analyzing_dependencies(&self) -> bool {
+        self.current_step == Some(LoadingStep::AnalyzeDependencies)
+    }
+
     /// Returns the current step's progress message
     pub fn current_message(&self) -> String {
         match self.current_step {
-            Some(LoadingStep::FetchPullRequests) => {
-                LoadingStep::FetchPullRequests.progress_message(0, 0)
-            }
+            Some(LoadingStep::FetchPullRequests) => LoadingStep::FetchPullRequests
+                .progress_message(self.prs_fetched, self.pr_pages_fetched),
             Some(LoadingStep::FetchWorkItems) => LoadingStep::FetchWorkItems
                 .progress_message(self.work_items_fetched, self.work_items_total),
             Some(LoadingStep::FetchCommitInfo) => LoadingStep::FetchCommitInfo
                 .progress_message(self.commits_fetched, self.commits_total),
-            Some(LoadingStep::AnalyzeDependencies) => {
-                LoadingStep::AnalyzeDependencies.progress_message(0, self.prs_for_analysis)
-            }
+            Some(LoadingStep::AnalyzeDependencies) => match self.analyzing_pr {
+                Some(pr_id) => format!(
+                    "Analyzing dependencies ({}/{} PRs, PR #{})...",
+                    self.prs_analyzed, self.prs_for_analysis, pr_id
+                ),
+                None => LoadingStep::AnalyzeDependencies
+                    .progress_message(self.prs_analyzed, self.prs_for_analysis),
+            },
             None => "Initializing...".to_string(),
         }
     }
@@ -433,6 +498,8 @@ pub struct LoadingContext {
     pub max_concurrent_network: usize,
     /// Network throttling: max concurrent processing operations
     pub max_concurrent_processing: usize,
+    /// Whether PR builds and branch policies are checked
+    pub build_checks: BuildCheckMode,
 }
 
 impl LoadingContext {
@@ -445,6 +512,7 @@ impl LoadingContext {
             local_repo: app.local_repo().map(String::from),
             max_concurrent_network: app.max_concurrent_network(),
             max_concurrent_processing: app.max_concurrent_processing(),
+            build_checks: app.build_check_mode(),
         }
     }
 
@@ -455,6 +523,23 @@ impl LoadingContext {
     pub fn has_local_repo_configured(&self) -> bool {
         self.local_repo.is_some()
     }
+
+    /// Applies the `.mergers-ignore` skip list of the configured local
+    /// repository.
+    ///
+    /// Without a local repository all PRs are kept.
+    pub fn skip_ignored_prs(
+        &self,
+        prs: Vec<PullRequest>,
+    ) -> Result<(Vec<PullRequest>, Vec<IgnoredPullRequest>)> {
+        match self.local_repo.as_deref() {
+            Some(repo) => apply_ignore_file(Path::new(repo), prs),
+            None => {
+                tracing::debug!("No local repository, skipping {}", IGNORE_FILE_NAME);
+                Ok((prs, Vec::new()))
+            }
+        }
+    }
 }
 
 /// Channel receiver wrapper that allows Debug implementation
@@ -495,6 +580,8 @@ pub struct DataLoadingState {
     receiver: Option<LoadingProgressReceiver>,
     /// Cached: whether local repo is available for dependency analysis
     has_local_repo: Option<bool>,
+    /// Set to stop a running dependency analysis
+    analysis_cancel: Arc<AtomicBool>,
 }
 
 impl std::fmt::Debug for DataLoadingState {
@@ -518,6 +605,7 @@ impl DataLoadingState {
             state: LoadingState::Initializing,
             receiver: None,
             has_local_repo: None,
+            analysis_cancel: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -589,6 +677,7 @@ impl DataLoadingState {
 
         let (tx, rx) = mpsc::channel::<LoadingProgressMessage>(32);
         self.receiver = Some(LoadingProgressReceiver(rx));
+        self.analysis_cancel = Arc::new(AtomicBool::new(false));
 
         // Initialize the Running state
         self.state = LoadingState::Running {
@@ -597,7 +686,7 @@ impl DataLoadingState {
         };
 
         // Spawn the background task
-        tokio::spawn(run_loading_task(ctx, tx));
+        tokio::spawn(run_loading_task(ctx, tx, Arc::clone(&self.analysis_cancel)));
     }
 
     /// Process a message received from the background task
@@ -612,6 +701,21 @@ impl DataLoadingState {
                 // Apply PR list to app when fetched
                 if let Some(ref prs) = result.prs {
                     *app.pull_requests_mut() = prs.clone();
+                    let preselected = app.preselected_pr_ids().clone();
+                    for pr_with_wi in app.pull_requests_mut().iter_mut() {
+                        if preselected.contains(&pr_with_wi.pr.id) {
+                            pr_with_wi.selected = true;
+                        }
+                    }
+                }
+                if let Some(ref ignored) = result.ignored_prs {
+                    app.set_ignored_pull_requests(ignored.clone());
+                }
+                if let Some(ref reverts) = result.reverts {
+                    app.set_revert_index(RevertIndex::clone(reverts));
+                }
+                if let Some(ref checks) = result.build_checks {
+                    app.extend_build_checks(BuildCheckIndex::clone(checks));
                 }
 
                 // Apply work items updates to app immediately
@@ -628,16 +732,31 @@ impl DataLoadingState {
                     pr_with_wi.pr.last_merge_commit = Some(ci_result.merge_commit.clone());
                 }
 
-                // Apply dependency graph to app
+                // Apply the file-level dependency graph, then refine it with
+                // line ranges while the PR list is in use
                 if let Some(ref graph) = result.dependency_graph {
                     app.set_dependency_graph(graph.clone());
+                    app.refine_dependency_graph();
                 }
 
                 self.merge_step_result(&result);
+
+                // Strict mode: all work items are known once their step completes
+                if step == LoadingStep::FetchWorkItems
+                    && let Some(error) = check_strict_states(app)
+                {
+                    self.receiver = None;
+                    self.set_error(error);
+                }
             }
             LoadingProgressMessage::StepProgress(step, fetched, total) => {
                 self.update_step_progress(step, fetched, total);
             }
+            LoadingProgressMessage::AnalysisProgress(analyzed, total, pr_id) => {
+                if let Some(progress) = self.progress_mut() {
+                    progress.update_analysis_progress(analyzed, total, pr_id);
+                }
+            }
             LoadingProgressMessage::AllComplete => {
                 // Extract the accumulated data and transition to Complete state
                 if let LoadingState::Running { step_data, .. } = &self.state {
@@ -674,6 +793,34 @@ impl DataLoadingState {
         self.start_background_task(app);
     }
 
+    /// Cancels a running dependency analysis and continues without dependency data.
+    ///
+    /// The background task is told to stop and its channel is dropped, so a
+    /// late result cannot overwrite the no-deps mode.
+    fn skip_dependency_analysis(&mut self) {
+        let LoadingState::Running {
+            progress,
+            step_data,
+        } = &self.state
+        else {
+            return;
+        };
+        if !progress.is_analyzing_dependencies() {
+            return;
+        }
+
+        tracing::info!(
+            "Dependency analysis skipped after {}/{} PRs; continuing without dependency data",
+            progress.prs_analyzed,
+            progress.prs_for_analysis
+        );
+        self.analysis_cancel.store(true, Ordering::Relaxed);
+        self.receiver = None;
+        self.state = LoadingState::Complete {
+            step_data: step_data.clone(),
+        };
+    }
+
     /// Skip the current step (for optional steps like dependency analysis)
     fn skip_current_step(&mut self) {
         if let LoadingState::Error {
@@ -692,6 +839,23 @@ impl DataLoadingState {
     }
 }
 
+/// Checks loaded work items against the known states when strict mode is on.
+///
+/// The target work item state always counts as known.
+fn check_strict_states(app: &MergeApp) -> Option<LoadingError> {
+    let mut known_states = app.strict_states()?.to_vec();
+    known_states.push(app.work_item_state().to_string());
+
+    let unknown = find_unknown_work_item_states(app.pull_requests(), &known_states);
+    if unknown.is_empty() {
+        return None;
+    }
+
+    let description = describe_unknown_states(&unknown);
+    tracing::error!("Unknown work item states: {}", description);
+    Some(LoadingError::UnknownWorkItemStates(description))
+}
+
 // ============================================================================
 // Background Task Implementation
 // ============================================================================
@@ -701,7 +865,13 @@ impl DataLoadingState {
 /// This function executes all loading steps sequentially, sending progress messages
 /// to the UI through the provided channel. The UI can then update the display
 /// as each step starts and completes.
-async fn run_loading_task(ctx: LoadingContext, tx: mpsc::Sender<LoadingProgressMessage>) {
+///
+/// Dependency analysis stops early once `analysis_cancel` is set.
+async fn run_loading_task(
+    ctx: LoadingContext,
+    tx: mpsc::Sender<LoadingProgressMessage>,
+    analysis_cancel: Arc<AtomicBool>,
+) {
     // Helper macro to send a message or return if channel is closed
     macro_rules! send_or_return {
         ($tx:expr, $msg:expr) => {
@@ -717,8 +887,8 @@ async fn run_loading_task(ctx: LoadingContext, tx: mpsc::Sender<LoadingProgressM
         LoadingProgressMessage::StepStarted(LoadingStep::FetchPullRequests)
     );
 
-    let prs = match fetch_pull_requests_impl(&ctx).await {
-        Ok(prs) => prs,
+    let (prs, ignored_prs, reverts) = match fetch_pull_requests_impl(&ctx, &tx).await {
+        Ok(result) => result,
         Err(e) => {
             let _ = tx.send(LoadingProgressMessage::Error(e)).await;
             return;
@@ -732,6 +902,8 @@ async fn run_loading_task(ctx: LoadingContext, tx: mpsc::Sender<LoadingProgressM
             LoadingStep::FetchPullRequests,
             LoadingStepResult {
                 prs: Some(prs.clone()),
+                ignored_prs: Some(ignored_prs),
+                reverts: Some(Box::new(reverts)),
                 ..Default::default()
             }
         )
@@ -781,19 +953,31 @@ async fn run_loading_task(ctx: LoadingContext, tx: mpsc::Sender<LoadingProgressM
         LoadingProgressMessage::StepProgress(LoadingStep::FetchCommitInfo, 0, commits_needed)
     );
 
-    match fetch_commit_info_impl(&ctx, &prs, &tx).await {
-        Ok(_) => {}
+    let merge_commits = match fetch_commit_info_impl(&ctx, &prs, &tx).await {
+        Ok(merge_commits) => merge_commits,
         Err(e) => {
             let _ = tx.send(LoadingProgressMessage::Error(e)).await;
             return;
         }
-    }
+    };
+
+    // Check builds and branch policies once every PR has its merge commit
+    let build_checks = if ctx.build_checks.is_enabled() {
+        Some(Box::new(
+            check_pull_requests(&ctx.client, merge_commits, ctx.max_concurrent_network).await,
+        ))
+    } else {
+        None
+    };
 
     send_or_return!(
         tx,
         LoadingProgressMessage::StepCompleted(
             LoadingStep::FetchCommitInfo,
-            LoadingStepResult::default()
+            LoadingStepResult {
+                build_checks,
+                ..Default::default()
+            }
         )
     );
 
@@ -809,7 +993,20 @@ async fn run_loading_task(ctx: LoadingContext, tx: mpsc::Sender<LoadingProgressM
             LoadingProgressMessage::StepProgress(LoadingStep::AnalyzeDependencies, 0, pr_count)
         );
 
-        match analyze_dependencies_impl(&ctx, &prs) {
+        // Analysis shells out to git per PR; keep it off the async worker threads
+        let analysis_tx = tx.clone();
+        let analysis = tokio::task::spawn_blocking(move || {
+            analyze_dependencies_impl(&ctx, &prs, &analysis_tx, &analysis_cancel)
+        })
+        .await
+        .unwrap_or_else(|e| {
+            Err(LoadingError::Other(format!(
+                "Dependency analysis failed: {}",
+                e
+            )))
+        });
+
+        match analysis {
             Ok(graph) => {
                 send_or_return!(
                     tx,
@@ -835,33 +1032,61 @@ async fn run_loading_task(ctx: LoadingContext, tx: mpsc::Sender<LoadingProgressM
 }
 
 /// Fetch pull requests from Azure DevOps API
+///
+/// PRs listed in `.mergers-ignore` are returned separately so they can be
+/// shown greyed out without being selectable. Reverts are detected before
+/// filtering, so a revert that is already merged still flags its original.
 async fn fetch_pull_requests_impl(
     ctx: &LoadingContext,
-) -> Result<Vec<PullRequestWithWorkItems>, LoadingError> {
+    tx: &mpsc::Sender<LoadingProgressMessage>,
+) -> Result<
+    (
+        Vec<PullRequestWithWorkItems>,
+        Vec<IgnoredPullRequest>,
+        RevertIndex,
+    ),
+    LoadingError,
+> {
     let prs = ctx
         .client
-        .fetch_pull_requests(&ctx.dev_branch, ctx.since.as_deref())
+        .fetch_pull_requests_with_progress(&ctx.dev_branch, ctx.since.as_deref(), |page| {
+            // Progress is best effort; a full channel just skips an update
+            let _ = tx.try_send(LoadingProgressMessage::StepProgress(
+                LoadingStep::FetchPullRequests,
+                page.items,
+                page.pages,
+            ));
+        })
         .await
         .map_err(|e| LoadingError::ApiError(format!("Failed to fetch pull requests: {}", e)))?;
 
+    let reverts = RevertIndex::detect(&prs);
     let filtered_prs = api::filter_prs_without_merged_tag(prs);
+    let (filtered_prs, ignored_prs) = ctx
+        .skip_ignored_prs(filtered_prs)
+        .map_err(|e| LoadingError::Other(format!("{:#}", e)))?;
 
     if filtered_prs.is_empty() {
         return Err(LoadingError::NoPullRequestsFound);
     }
 
     // Initialize PRs with empty work items
-    Ok(filtered_prs
+    let prs = filtered_prs
         .into_iter()
         .map(|pr| PullRequestWithWorkItems {
             pr,
             work_items: Vec::new(),
             selected: false,
         })
-        .collect())
+        .collect();
+    Ok((prs, ignored_prs, reverts))
 }
 
-/// Fetch work items for all PRs in parallel with throttling
+/// Fetch work items for all PRs.
+///
+/// The work item links of each PR are listed in parallel with throttling,
+/// reporting progress per PR. Every linked work item is then fetched once,
+/// in batches, and sent to its PRs.
 async fn fetch_work_items_impl(
     ctx: &LoadingContext,
     prs: &[PullRequestWithWorkItems],
@@ -881,7 +1106,7 @@ async fn fetch_work_items_impl(
 
     let mut tasks = Vec::new();
 
-    for (index, pr_with_wi) in prs.iter().enumerate() {
+    for pr_with_wi in prs {
         let client = ctx.client.clone();
         let pr_id = pr_with_wi.pr.id;
         let processor = network_processor.clone();
@@ -889,55 +1114,34 @@ async fn fetch_work_items_impl(
         let completed = completed.clone();
 
         let task = tokio::spawn(async move {
-            let result = processor
+            let ids = processor
                 .execute_network_operation(|| async {
                     client
-                        .fetch_work_items_with_history_for_pr(pr_id)
+                        .fetch_work_item_ids_for_pr(pr_id)
                         .await
                         .context("Failed to fetch work items")
                 })
-                .await;
+                .await?;
 
-            match result {
-                Ok(work_items) => {
-                    let count = completed.fetch_add(1, Ordering::SeqCst) + 1;
-
-                    // Send progress update
-                    let _ = tx
-                        .send(LoadingProgressMessage::StepProgress(
-                            LoadingStep::FetchWorkItems,
-                            count,
-                            total,
-                        ))
-                        .await;
-
-                    // Send individual work item result
-                    let _ = tx
-                        .send(LoadingProgressMessage::StepCompleted(
-                            LoadingStep::FetchWorkItems,
-                            LoadingStepResult {
-                                work_items_update: Some(WorkItemsResult {
-                                    pr_index: index,
-                                    work_items,
-                                }),
-                                ..Default::default()
-                            },
-                        ))
-                        .await;
-
-                    Ok(())
-                }
-                Err(e) => Err(e),
-            }
+            let count = completed.fetch_add(1, Ordering::SeqCst) + 1;
+            let _ = tx
+                .send(LoadingProgressMessage::StepProgress(
+                    LoadingStep::FetchWorkItems,
+                    count,
+                    total,
+                ))
+                .await;
+            anyhow::Ok((pr_id, ids))
         });
 
         tasks.push(task);
     }
 
-    // Wait for all tasks to complete
+    // Wait for all links to be listed
+    let mut links = Vec::with_capacity(tasks.len());
     for task in tasks {
         match task.await {
-            Ok(Ok(())) => {}
+            Ok(Ok(link)) => links.push(link),
             Ok(Err(e)) => {
                 return Err(LoadingError::ApiError(format!(
                     "Failed to fetch work items: {}",
@@ -950,15 +1154,39 @@ async fn fetch_work_items_impl(
         }
     }
 
+    let mut work_items = ctx
+        .client
+        .fetch_linked_work_items(links, ctx.max_concurrent_network)
+        .await
+        .map_err(|e| LoadingError::ApiError(format!("Failed to fetch work items: {}", e)))?;
+
+    for (index, pr_with_wi) in prs.iter().enumerate() {
+        let _ = tx
+            .send(LoadingProgressMessage::StepCompleted(
+                LoadingStep::FetchWorkItems,
+                LoadingStepResult {
+                    work_items_update: Some(WorkItemsResult {
+                        pr_index: index,
+                        work_items: work_items.remove(&pr_with_wi.pr.id).unwrap_or_default(),
+                    }),
+                    ..Default::default()
+                },
+            ))
+            .await;
+    }
+
     Ok(())
 }
 
 /// Fetch commit information for PRs that don't have it
+///
+/// Returns the id and merge commit of every PR.
 async fn fetch_commit_info_impl(
     ctx: &LoadingContext,
     prs: &[PullRequestWithWorkItems],
     tx: &mpsc::Sender<LoadingProgressMessage>,
-) -> Result<(), LoadingError> {
+) -> Result<Vec<(i32, String)>, LoadingError> {
+    let mut merge_commits = Vec::with_capacity(prs.len());
     let mut fetched = 0;
     let total = prs
         .iter()
@@ -966,10 +1194,13 @@ async fn fetch_commit_info_impl(
         .count();
 
     for (index, pr_with_wi) in prs.iter().enumerate() {
-        if pr_with_wi.pr.last_merge_commit.is_none() {
+        if let Some(commit) = &pr_with_wi.pr.last_merge_commit {
+            merge_commits.push((pr_with_wi.pr.id, commit.commit_id.clone()));
+        } else {
             match ctx.client.fetch_pr_commit(pr_with_wi.pr.id).await {
                 Ok(commit_info) => {
                     fetched += 1;
+                    merge_commits.push((pr_with_wi.pr.id, commit_info.commit_id.clone()));
 
                     // Send individual commit info result for UI to apply
                     let _ = tx
@@ -1003,14 +1234,83 @@ async fn fetch_commit_info_impl(
             }
         }
     }
+    ctx.client.save_cache();
 
-    Ok(())
+    Ok(merge_commits)
+}
+
+/// PRs found by auto-refresh, with the build checks of those PRs.
+pub(super) type NewPullRequests = (Vec<PullRequestWithWorkItems>, BuildCheckIndex);
+
+/// Fetch PRs that appeared since the PR list was loaded.
+///
+/// Used by PR selection auto-refresh. PRs in `known_ids` (already listed or
+/// ignored) are skipped; new PRs come back with their work items, merge
+/// commit and, when enabled, build checks so they can be appended to the
+/// table as-is.
+pub(super) async fn fetch_new_pull_requests(
+    ctx: LoadingContext,
+    known_ids: HashSet<i32>,
+) -> Result<NewPullRequests> {
+    let prs = ctx
+        .client
+        .fetch_pull_requests(&ctx.dev_branch, ctx.since.as_deref())
+        .await
+        .context("Failed to fetch pull requests")?;
+    let prs = api::filter_prs_without_merged_tag(prs)
+        .into_iter()
+        .filter(|pr| !known_ids.contains(&pr.id))
+        .collect();
+    let (prs, _) = ctx.skip_ignored_prs(prs)?;
+
+    let mut new_prs = Vec::with_capacity(prs.len());
+    for mut pr in prs {
+        let work_items = ctx
+            .client
+            .fetch_work_items_with_history_for_pr(pr.id)
+            .await
+            .with_context(|| format!("Failed to fetch work items for PR #{}", pr.id))?;
+        if pr.last_merge_commit.is_none() {
+            pr.last_merge_commit = Some(
+                ctx.client
+                    .fetch_pr_commit(pr.id)
+                    .await
+                    .with_context(|| format!("Failed to fetch commit for PR #{}", pr.id))?,
+            );
+        }
+        new_prs.push(PullRequestWithWorkItems {
+            pr,
+            work_items,
+            selected: false,
+        });
+    }
+
+    let build_checks = if ctx.build_checks.is_enabled() {
+        let merge_commits = new_prs
+            .iter()
+            .filter_map(|pr| {
+                let commit = pr.pr.last_merge_commit.as_ref()?;
+                Some((pr.pr.id, commit.commit_id.clone()))
+            })
+            .collect();
+        check_pull_requests(&ctx.client, merge_commits, ctx.max_concurrent_network).await
+    } else {
+        BuildCheckIndex::default()
+    };
+    Ok((new_prs, build_checks))
 }
 
 /// Analyze file dependencies using local repository
+///
+/// Only changed files are compared; line ranges are analyzed afterwards in
+/// the background (see [`MergeApp::refine_dependency_graph`]). Sends an
+/// `AnalysisProgress` message after each PR's changes are read.
+/// Returns `Ok(None)` if `cancel` is set before the analysis finishes.
 fn analyze_dependencies_impl(
     ctx: &LoadingContext,
     prs: &[PullRequestWithWorkItems],
+    tx: &mpsc::Sender<LoadingProgressMessage>,
+    cancel: &AtomicBool,
 ) -> Result<Option<PRDependencyGraph>, LoadingError> {
     let local_repo = match &ctx.local_repo {
         Some(path) => path,
@@ -1022,58 +1322,25 @@ fn analyze_dependencies_impl(
         return Err(LoadingError::LocalRepoNotFound(local_repo.clone()));
     }
 
-    // Build PRInfo list and sort by closed date (oldest first)
-    let mut pr_infos: Vec<PRInfo> = prs
-        .iter()
-        .map(|pr_with_wi| {
-            PRInfo::new(
-                pr_with_wi.pr.id,
-                pr_with_wi.pr.title.clone(),
-                pr_with_wi.selected,
-                pr_with_wi
-                    .pr
-                    .last_merge_commit
-                    .as_ref()
-                    .map(|c| c.commit_id.clone()),
-            )
-        })
-        .collect();
-
-    // Sort PRs by closed date (oldest first) for correct dependency analysis
-    let pr_dates: HashMap<i32, Option<String>> = prs
-        .iter()
-        .map(|pr| (pr.pr.id, pr.pr.closed_date.clone()))
-        .collect();
-
-    pr_infos.sort_by(|a, b| {
-        let date_a = pr_dates.get(&a.id).and_then(|d| d.as_ref());
-        let date_b = pr_dates.get(&b.id).and_then(|d| d.as_ref());
-
-        match (date_a, date_b) {
-            (Some(da), Some(db)) => da.cmp(db),
-            (Some(_), None) => std::cmp::Ordering::Less,
-            (None, Some(_)) => std::cmp::Ordering::Greater,
-            (None, None) => a.id.cmp(&b.id),
-        }
-    });
-
-    // Parallel fetch of file changes for each PR
-    let pr_changes: HashMap<i32, Vec<FileChange>> = pr_infos
-        .par_iter()
-        .filter_map(|pr_info| {
-            let commit_id = pr_info.commit_id.as_ref()?;
-            match git::get_commit_changes_with_ranges(repo_path, commit_id) {
-                Ok(changes) => Some((pr_info.id, changes)),
-                Err(_) => Some((pr_info.id, Vec::new())),
-            }
-        })
-        .collect();
-
-    // Run parallel dependency analysis
-    let analyzer = DependencyAnalyzer::new();
-    let result = analyzer.analyze_parallel(&pr_infos, &pr_changes);
+    // Progress is best-effort; each message carries absolute counts
+    let analyzer = DependencyAnalyzer::with_config(DependencyAnalysisConfig {
+        line_ranges: false,
+        ..Default::default()
+    })
+    .with_cache(ctx.client.cache().cloned());
+    let result = analyzer.analyze_repository(
+        repo_path,
+        prs,
+        |analyzed, total, pr_id| {
+            let _ = tx.try_send(LoadingProgressMessage::AnalysisProgress(
+                analyzed, total, pr_id,
+            ));
+        },
+        cancel,
+    );
+    ctx.client.save_cache();
 
-    Ok(Some(result.graph))
+    Ok(result.map(|result| result.graph))
 }
 
 // ============================================================================
@@ -1151,7 +1418,7 @@ fn render_current_step_progress(f: &mut Frame, area: Rect, progress: &LoadingPro
     let message = progress.current_message();
 
     // Build content with current step message
-    let lines = vec![
+    let mut lines = vec![
         Line::from(""),
         Line::from(Span::styled(
             message,
@@ -1165,6 +1432,14 @@ fn render_current_step_progress(f: &mut Frame, area: Rect, progress: &LoadingPro
             Style::default().fg(Color::DarkGray),
         )),
     ];
+    if progress.is_analyzing_dependencies() {
+        lines.push(style_hotkey_line(
+            "Press 's' to skip and continue without dependency data",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
 
     let paragraph = Paragraph::new(lines).alignment(Alignment::Center).block(
         Block::default()
@@ -1413,18 +1688,30 @@ impl ModeState for DataLoadingState {
                     return StateChange::Keep;
                 }
             }
-            LoadingState::Running { .. } => {
-                // Only 'q' to quit during loading
+            LoadingState::Running { progress, .. } => {
+                // 'q' quits; 's' skips a running dependency analysis
                 if code == KeyCode::Char('q') {
                     return StateChange::Exit;
                 }
+                if code == KeyCode::Char('s') && progress.is_analyzing_dependencies() {
+                    self.skip_dependency_analysis();
+                    return StateChange::Keep;
+                }
             }
             LoadingState::Complete { .. } => {
                 // All data has been applied to app in handle_progress_message.
                 // Automatically transition to PR selection.
-                return StateChange::Change(MergeState::PullRequestSelection(
-                    PullRequestSelectionState::new(),
-                ));
+                let schema_warnings = app.client().schema_warnings();
+                app.set_schema_warnings(schema_warnings);
+                let mut selection = PullRequestSelectionState::new();
+                if selection.restore_session(app) {
+                    // The session's PR filter lists PRs by other identities
+                    return StateChange::Change(MergeState::DataLoading(DataLoadingState::new()));
+                }
+                if let Some(path) = app.select_file().map(Path::to_path_buf) {
+                    selection.import_selection(app, &path);
+                }
+                return StateChange::Change(MergeState::PullRequestSelection(selection));
             }
             LoadingState::Error { error, .. } => match code {
                 KeyCode::Char('r') if error.can_retry() => {
@@ -1450,6 +1737,38 @@ impl ModeState for DataLoadingState {
         StateChange::Keep
     }
 
+    fn action_map(&self) -> ActionMap {
+        let map = ActionMap::new("Loading Data");
+        match &self.state {
+            LoadingState::Error { error, .. } => {
+                let mut map = map;
+                if error.can_retry() {
+                    map = map.bind(ActionCategory::Actions, &[KeyCode::Char('r')], "Retry");
+                }
+                if error.can_skip() {
+                    map = map.bind(
+                        ActionCategory::Actions,
+                        &[KeyCode::Char('s')],
+                        "Skip this step",
+                    );
+                }
+                map.bind(
+                    ActionCategory::General,
+                    &[KeyCode::Esc, KeyCode::Char('q')],
+                    "Quit",
+                )
+            }
+            LoadingState::Running { progress, .. } if progress.is_analyzing_dependencies() => map
+                .bind(
+                    ActionCategory::Actions,
+                    &[KeyCode::Char('s')],
+                    "Skip dependency analysis",
+                )
+                .bind(ActionCategory::General, &[KeyCode::Char('q')], "Quit"),
+            _ => map.bind(ActionCategory::General, &[KeyCode::Char('q')], "Quit"),
+        }
+    }
+
     fn name(&self) -> &'static str {
         "DataLoading"
     }
@@ -1458,11 +1777,13 @@ impl ModeState for DataLoadingState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::AppConfig;
     use crate::ui::{
         snapshot_testing::with_settings_and_module_path,
-        testing::{TuiTestHarness, create_test_config_default},
+        testing::{TuiTestHarness, create_test_config_default, create_test_pull_requests},
     };
     use insta::assert_snapshot;
+    use std::collections::HashSet;
 
     // ========================================================================
     // Helper functions for creating test states
@@ -1483,6 +1804,7 @@ mod tests {
             },
             receiver: None,
             has_local_repo: Some(has_local_repo),
+            analysis_cancel: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -1514,6 +1836,7 @@ mod tests {
             },
             receiver: None,
             has_local_repo: Some(has_local_repo),
+            analysis_cancel: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -1541,6 +1864,7 @@ mod tests {
             },
             receiver: None,
             has_local_repo: Some(has_local_repo),
+            analysis_cancel: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -1551,6 +1875,7 @@ mod tests {
             },
             receiver: None,
             has_local_repo: Some(has_local_repo),
+            analysis_cancel: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -1603,6 +1928,7 @@ mod tests {
                 state: LoadingState::Initializing,
                 receiver: None,
                 has_local_repo: Some(true),
+                analysis_cancel: Arc::new(AtomicBool::new(false)),
             };
             harness.render_state(&mut state);
 
@@ -1631,6 +1957,7 @@ mod tests {
                 state: LoadingState::Initializing,
                 receiver: None,
                 has_local_repo: Some(false),
+                analysis_cancel: Arc::new(AtomicBool::new(false)),
             };
             harness.render_state(&mut state);
 
@@ -1918,6 +2245,42 @@ mod tests {
         });
     }
 
+    /// # Data Loading State - Analyze Dependencies Progress
+    ///
+    /// Tests the per-PR progress display during dependency analysis.
+    ///
+    /// ## Test Scenario
+    /// - Renders the analysis step after 12 of 200 PRs were analyzed
+    ///
+    /// ## Expected Outcome
+    /// - Shows the n/m counter with the current PR id
+    /// - Shows the hint for skipping the analysis
+    #[test]
+    fn test_loading_step_analyze_deps_progress() {
+        with_settings_and_module_path(module_path!(), || {
+            let config = create_test_config_default();
+            let mut harness = TuiTestHarness::with_config(config);
+
+            let mut state = create_running_state_with_progress(
+                true,
+                &[
+                    LoadingStep::FetchPullRequests,
+                    LoadingStep::FetchWorkItems,
+                    LoadingStep::FetchCommitInfo,
+                ],
+                Some(LoadingStep::AnalyzeDependencies),
+                None,
+                None,
+            );
+            if let Some(progress) = state.progress_mut() {
+                progress.update_analysis_progress(12, 200, 1234);
+            }
+            harness.render_state(&mut state);
+
+            assert_snapshot!("step_analyze_deps_progress", harness.backend());
+        });
+    }
+
     /// # Data Loading State - Dependencies Skipped (No Local Repo)
     ///
     /// Tests that dependency analysis shows as skipped when no local repo.
@@ -2039,6 +2402,29 @@ mod tests {
         });
     }
 
+    /// # Data Loading State - Error Unknown Work Item States
+    ///
+    /// Tests the display when strict mode finds unknown work item states.
+    #[test]
+    fn test_loading_error_unknown_work_item_states() {
+        with_settings_and_module_path(module_path!(), || {
+            let config = create_test_config_default();
+            let mut harness = TuiTestHarness::with_config(config);
+
+            let mut state = create_error_state(
+                true,
+                LoadingError::UnknownWorkItemStates(
+                    "'Blocked' (PR #3), 'In Review' (PR #1, #2)".to_string(),
+                ),
+                &[LoadingStep::FetchPullRequests, LoadingStep::FetchWorkItems],
+                None,
+            );
+            harness.render_state(&mut state);
+
+            assert_snapshot!("error_unknown_work_item_states", harness.backend());
+        });
+    }
+
     /// # Data Loading State - Error API Error on Step 1
     ///
     /// Tests the display when API error occurs during PR fetch.
@@ -2315,6 +2701,141 @@ mod tests {
         assert!(matches!(result, StateChange::Keep));
     }
 
+    /// # Data Loading State - Skip Running Dependency Analysis
+    ///
+    /// Tests cancelling the dependency analysis while it is running.
+    ///
+    /// ## Test Scenario
+    /// - Presses 's' while fetching commit info, then during analysis
+    /// - Presses another key after the skip
+    ///
+    /// ## Expected Outcome
+    /// - 's' is ignored outside the analysis step
+    /// - During analysis, the cancel flag is set and loading completes
+    /// - The next key moves to PR selection without a dependency graph
+    #[tokio::test]
+    async fn test_data_loading_skip_running_analysis() {
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+
+        let mut state = create_running_state(true, Some(LoadingStep::FetchCommitInfo));
+        ModeState::process_key(&mut state, KeyCode::Char('s'), harness.merge_app_mut()).await;
+        assert!(matches!(state.state, LoadingState::Running { .. }));
+        assert!(!state.analysis_cancel.load(Ordering::Relaxed));
+
+        let mut state = create_running_state(true, Some(LoadingStep::AnalyzeDependencies));
+        assert!(
+            ModeState::action_map(&state)
+                .lookup(KeyCode::Char('s'))
+                .is_some()
+        );
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Char('s'), harness.merge_app_mut()).await;
+        assert!(matches!(result, StateChange::Keep));
+        assert!(matches!(state.state, LoadingState::Complete { .. }));
+        assert!(state.analysis_cancel.load(Ordering::Relaxed));
+
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Null, harness.merge_app_mut()).await;
+        assert!(matches!(
+            result,
+            StateChange::Change(MergeState::PullRequestSelection(_))
+        ));
+        assert!(harness.merge_app().dependency_graph().is_none());
+    }
+
+    /// # Data Loading State - Preselected PRs
+    ///
+    /// Tests that PRs handed over by `mergers watch` are selected on load.
+    ///
+    /// ## Test Scenario
+    /// - Sets preselected PR ids on the app
+    /// - Delivers the fetched PR list to a running loading state
+    ///
+    /// ## Expected Outcome
+    /// - Only the preselected PRs are marked as selected
+    #[tokio::test]
+    async fn test_data_loading_applies_preselected_prs() {
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+        harness
+            .merge_app_mut()
+            .set_preselected_pr_ids(HashSet::from([2]));
+
+        let mut state = create_running_state(false, Some(LoadingStep::FetchPullRequests));
+        state.handle_progress_message(
+            LoadingProgressMessage::StepCompleted(
+                LoadingStep::FetchPullRequests,
+                LoadingStepResult {
+                    prs: Some(vec![make_test_pr(1), make_test_pr(2)]),
+                    ..Default::default()
+                },
+            ),
+            harness.merge_app_mut(),
+        );
+
+        let selected: Vec<i32> = harness
+            .merge_app()
+            .pull_requests()
+            .iter()
+            .filter(|pr| pr.selected)
+            .map(|pr| pr.pr.id)
+            .collect();
+        assert_eq!(selected, vec![2]);
+    }
+
+    /// # Data Loading State - Strict Work Item States
+    ///
+    /// Tests that strict mode fails loading on unknown work item states.
+    ///
+    /// ## Test Scenario
+    /// - Configures strict states without "Active"
+    /// - Completes the work item step with PRs whose work items are "Active"
+    ///
+    /// ## Expected Outcome
+    /// - Loading fails with the unknown state and the affected PRs
+    /// - The error cannot be retried or skipped
+    /// - Without strict mode the same PRs load normally
+    #[tokio::test]
+    async fn test_data_loading_strict_states() {
+        let work_items_done = |state: &mut DataLoadingState, app: &mut MergeApp| {
+            state.handle_progress_message(
+                LoadingProgressMessage::StepCompleted(
+                    LoadingStep::FetchWorkItems,
+                    LoadingStepResult::default(),
+                ),
+                app,
+            );
+        };
+
+        let mut config = create_test_config_default();
+        if let AppConfig::Default { default, .. } = &mut config {
+            default.strict_states = Some(vec!["Closed".to_string(), "Resolved".to_string()]);
+        }
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.merge_app_mut().pull_requests_mut() = create_test_pull_requests();
+
+        let mut state = create_running_state(true, Some(LoadingStep::FetchWorkItems));
+        work_items_done(&mut state, harness.merge_app_mut());
+        match &state.state {
+            LoadingState::Error { error, .. } => {
+                assert!(matches!(
+                    error,
+                    LoadingError::UnknownWorkItemStates(states) if states == "'Active' (PR #101, #102)"
+                ));
+                assert!(!error.can_retry());
+                assert!(!error.can_skip());
+            }
+            _ => panic!("Expected error state"),
+        }
+
+        let mut harness = TuiTestHarness::with_config(create_test_config_default());
+        *harness.merge_app_mut().pull_requests_mut() = create_test_pull_requests();
+        let mut state = create_running_state(true, Some(LoadingStep::FetchWorkItems));
+        work_items_done(&mut state, harness.merge_app_mut());
+        assert!(matches!(state.state, LoadingState::Running { .. }));
+    }
+
     /// # Data Loading State - Error State Escape
     ///
     /// Tests that Esc key exits on error.
@@ -2373,6 +2894,17 @@ mod tests {
             LoadingStep::FetchPullRequests.progress_message(0, 0),
             "Fetching pull requests..."
         );
+        assert_eq!(
+            LoadingStep::FetchPullRequests.progress_message(250, 3),
+            "Fetching pull requests (250 PRs, page 3)..."
+        );
+        let mut progress = LoadingProgress::new(false);
+        progress.start_step(LoadingStep::FetchPullRequests);
+        progress.update_progress(LoadingStep::FetchPullRequests, 100, 1);
+        assert_eq!(
+            progress.current_message(),
+            "Fetching pull requests (100 PRs, page 1)..."
+        );
         assert_eq!(
             LoadingStep::FetchWorkItems.progress_message(5, 10),
             "Fetching work items (5/10)..."
@@ -2391,6 +2923,34 @@ mod tests {
         );
     }
 
+    /// # LoadingProgress - Analysis Progress Message
+    ///
+    /// Tests the dependency analysis message as per-PR progress arrives.
+    ///
+    /// ## Test Scenario
+    /// - Starts the analysis step and records progress for a PR
+    ///
+    /// ## Expected Outcome
+    /// - Before progress, the PR total is shown
+    /// - After progress, the n/m counter and current PR id are shown
+    #[test]
+    fn test_loading_progress_analysis_message() {
+        let mut progress = LoadingProgress::new(true);
+        progress.start_step(LoadingStep::AnalyzeDependencies);
+        progress.update_progress(LoadingStep::AnalyzeDependencies, 0, 200);
+        assert!(progress.is_analyzing_dependencies());
+        assert_eq!(
+            progress.current_message(),
+            "Analyzing dependencies (200 PRs)..."
+        );
+
+        progress.update_analysis_progress(12, 200, 1234);
+        assert_eq!(
+            progress.current_message(),
+            "Analyzing dependencies (12/200 PRs, PR #1234)..."
+        );
+    }
+
     /// # LoadingError - Can Retry
     ///
     /// Tests that error types correctly report retry capability.
@@ -2465,6 +3025,7 @@ mod tests {
                 },
                 last_merge_commit: None,
                 labels: None,
+                reviewers: Vec::new(),
             },
             work_items: Vec::new(),
             selected: false,
@@ -2595,6 +3156,7 @@ mod tests {
             },
             receiver: None,
             has_local_repo: Some(true),
+            analysis_cancel: Arc::new(AtomicBool::new(false)),
         };
 
         state.skip_current_step();
@@ -2628,6 +3190,7 @@ mod tests {
             },
             receiver: None,
             has_local_repo: Some(true),
+            analysis_cancel: Arc::new(AtomicBool::new(false)),
         };
 
         state.skip_current_step();
@@ -2767,10 +3330,48 @@ mod tests {
             local_repo: None,
             max_concurrent_network: 4,
             max_concurrent_processing: 4,
+            build_checks: BuildCheckMode::Off,
         };
         assert!(!ctx.has_local_repo_configured());
     }
 
+    /// # Test: cancelled dependency analysis
+    ///
+    /// Verifies that analysis stops without a graph once cancelled.
+    ///
+    /// ## Test Scenario
+    /// - Runs the analysis against an existing directory with the cancel flag set
+    ///
+    /// ## Expected Outcome
+    /// - Returns `Ok(None)` and sends no progress
+    #[test]
+    fn test_analyze_dependencies_cancelled() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let ctx = LoadingContext {
+            client: crate::api::AzureDevOpsClient::new(
+                "test_org".to_string(),
+                "test_project".to_string(),
+                "test_repo".to_string(),
+                "test_pat".to_string(),
+            )
+            .unwrap(),
+            dev_branch: "main".to_string(),
+            since: None,
+            local_repo: Some(dir.path().to_string_lossy().to_string()),
+            max_concurrent_network: 4,
+            max_concurrent_processing: 4,
+            build_checks: BuildCheckMode::Off,
+        };
+        let (tx, mut rx) = mpsc::channel(8);
+        let cancel = AtomicBool::new(true);
+
+        let result =
+            analyze_dependencies_impl(&ctx, &[make_test_pr(1), make_test_pr(2)], &tx, &cancel);
+
+        assert!(matches!(result, Ok(None)));
+        assert!(rx.try_recv().is_err());
+    }
+
     /// # Test: has_local_repo_configured returns true for any Some value
     ///
     /// Verifies that `has_local_repo_configured()` returns true when
@@ -2795,6 +3396,7 @@ mod tests {
             local_repo: Some("/nonexistent/path/to/repo".to_string()),
             max_concurrent_network: 4,
             max_concurrent_processing: 4,
+            build_checks: BuildCheckMode::Off,
         };
         assert!(ctx.has_local_repo_configured());
     }
@@ -1,4 +1,5 @@
 mod aborting;
+mod branch_log;
 mod cherry_pick;
 mod cherry_pick_continue;
 mod completion;
@@ -12,6 +13,7 @@ mod state_enum;
 mod version_input;
 
 pub use aborting::AbortingState;
+pub use branch_log::BranchLogState;
 pub use cherry_pick::CherryPickState;
 pub use cherry_pick_continue::CherryPickContinueState;
 pub use completion::CompletionState;
@@ -1,10 +1,20 @@
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::{
-    core::state::MergeStatus,
+    api::{INTEGRATION_BUILD_FIELD, PackageVersion},
+    core::operations::post_merge::{
+        PostMergeConfig, PostMergeTask, WorkItemTransition, extract_completed_pr_info,
+        work_item_comments, work_item_field_updates, work_item_transitions,
+    },
+    core::operations::release_tag::ReleaseTag,
+    core::operations::work_item_fields::{FieldUpdate, apply_field_updates},
     models::CherryPickStatus,
+    notify::{self, MergeNotification, NotificationChannel},
     ui::apps::MergeApp,
     ui::state::default::MergeState,
+    ui::state::default::completion::patch_branch,
     ui::state::typed::{ModeState, StateChange},
 };
+use anyhow::Context;
 use async_trait::async_trait;
 use crossterm::event::KeyCode;
 use ratatui::{
@@ -12,8 +22,9 @@ use ratatui::{
     layout::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Gauge, Paragraph, Wrap},
+    widgets::{Block, Borders, Cell, Gauge, Paragraph, Row, Table, TableState, Wrap},
 };
+use std::collections::HashSet;
 
 #[derive(Debug, Clone)]
 pub enum PostCompletionTask {
@@ -25,6 +36,37 @@ pub enum PostCompletionTask {
         work_item_id: i32,
         work_item_title: String,
     },
+    CommentingWorkItem {
+        work_item_id: i32,
+        comment: String,
+    },
+    /// Writes the configured field updates.
+    UpdatingWorkItemFields {
+        work_item_id: i32,
+        updates: Vec<FieldUpdate>,
+    },
+    /// Looks up the version of the package built for the merge.
+    ResolvingPackageVersion {
+        package: String,
+    },
+    /// Writes the package version to the "Integrated in build" field.
+    StampingWorkItem {
+        work_item_id: i32,
+    },
+    /// Tags the patch branch with the release notes as message.
+    CreatingReleaseTag {
+        tag: String,
+        push: bool,
+    },
+    /// Links the work items to the PR opened from the patch branch.
+    LinkingReleasePR {
+        source_branch: String,
+        work_item_ids: Vec<i32>,
+    },
+    /// Posts the merge summary to a chat webhook.
+    SendingNotification {
+        channel: NotificationChannel,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -33,6 +75,8 @@ pub enum TaskStatus {
     InProgress,
     Success,
     Failed(String),
+    /// Nothing to do yet, with the reason; retried like a failed task.
+    Skipped(String),
 }
 
 #[derive(Debug, Clone)]
@@ -41,11 +85,29 @@ pub struct PostCompletionTaskItem {
     pub status: TaskStatus,
 }
 
+/// Review of the work item changes before any task runs.
+enum Review {
+    /// Not shown yet; opened when the tasks are first initialized.
+    Pending,
+    /// Waiting for the listed changes to be confirmed.
+    Open {
+        transitions: Vec<WorkItemTransition>,
+        table_state: TableState,
+    },
+    /// Confirmed, or there was nothing to review.
+    Done,
+}
+
 pub struct PostCompletionState {
     tasks: Vec<PostCompletionTaskItem>,
     current_task_index: usize,
     completed: bool,
     total_tasks: usize,
+    review: Review,
+    /// Work items excluded from state updates and comments.
+    excluded: HashSet<i32>,
+    /// Whether the work items are linked to the PR of the patch branch.
+    link_release_pr: bool,
 }
 
 impl Default for PostCompletionState {
@@ -61,12 +123,82 @@ impl PostCompletionState {
             current_task_index: 0,
             completed: false,
             total_tasks: 0,
+            review: Review::Pending,
+            excluded: HashSet::new(),
+            link_release_pr: true,
+        }
+    }
+
+    fn is_reviewing(&self) -> bool {
+        matches!(self.review, Review::Open { .. })
+    }
+
+    /// Moves the review highlight by `delta` rows, wrapping around.
+    fn move_review_selection(&mut self, delta: isize) {
+        if let Review::Open {
+            transitions,
+            table_state,
+        } = &mut self.review
+        {
+            let len = transitions.len() as isize;
+            let current = table_state.selected().unwrap_or(0) as isize;
+            table_state.select(Some((current + delta).rem_euclid(len) as usize));
+        }
+    }
+
+    /// Excludes the highlighted work item, or includes it again.
+    fn toggle_excluded(&mut self) {
+        if let Review::Open {
+            transitions,
+            table_state,
+        } = &self.review
+            && let Some(transition) = table_state.selected().and_then(|i| transitions.get(i))
+            && !self.excluded.remove(&transition.work_item_id)
+        {
+            self.excluded.insert(transition.work_item_id);
+        }
+    }
+
+    /// Work items of the successful PRs that were not excluded, in order.
+    fn included_work_item_ids(&self, app: &MergeApp) -> Vec<i32> {
+        let successful = Self::successful_pr_ids(app);
+        let mut seen = HashSet::new();
+        app.pull_requests()
+            .iter()
+            .filter(|pr| successful.contains(&pr.pr.id))
+            .flat_map(|pr| &pr.work_items)
+            .map(|work_item| work_item.id)
+            .filter(|id| !self.excluded.contains(id) && seen.insert(*id))
+            .collect()
+    }
+
+    fn successful_pr_ids(app: &MergeApp) -> Vec<i32> {
+        app.cherry_pick_items()
+            .iter()
+            .filter(|item| matches!(item.status, CherryPickStatus::Success))
+            .map(|item| item.pr_id)
+            .collect()
+    }
+
+    fn post_merge_config(app: &MergeApp) -> PostMergeConfig {
+        PostMergeConfig {
+            tag_prefix: app.tag_prefix().to_string(),
+            version: app.version().unwrap().to_string(),
+            work_item_state: app.work_item_state().to_string(),
+            target_branch: app.target_branch().to_string(),
+            comment_template: app.work_item_comment().map(str::to_string),
+            work_item_fields: app.work_item_fields().to_vec(),
+            artifacts_feed: app.artifacts_feed().cloned(),
+            release_tag: None,
         }
     }
 
     fn retry_failed_tasks(&mut self) {
         for task_item in &mut self.tasks {
-            if matches!(task_item.status, TaskStatus::Failed(_)) {
+            if matches!(
+                task_item.status,
+                TaskStatus::Failed(_) | TaskStatus::Skipped(_)
+            ) {
                 task_item.status = TaskStatus::Pending;
             }
         }
@@ -87,12 +219,43 @@ impl PostCompletionState {
             .any(|task| matches!(task.status, TaskStatus::Failed(_)))
     }
 
+    fn has_skipped_tasks(&self) -> bool {
+        self.tasks
+            .iter()
+            .any(|task| matches!(task.status, TaskStatus::Skipped(_)))
+    }
+
+    fn has_retryable_tasks(&self) -> bool {
+        self.has_failed_tasks() || self.has_skipped_tasks()
+    }
+
     fn initialize_tasks(&mut self, app: &MergeApp) {
         if !self.tasks.is_empty() {
             return; // Already initialized
         }
 
-        let _version = app.version().as_ref().unwrap();
+        match self.review {
+            Review::Pending => {
+                let transitions = work_item_transitions(
+                    app.pull_requests(),
+                    &Self::successful_pr_ids(app),
+                    &Self::post_merge_config(app),
+                );
+                if transitions.is_empty() {
+                    self.review = Review::Done;
+                } else {
+                    let mut table_state = TableState::default();
+                    table_state.select(Some(0));
+                    self.review = Review::Open {
+                        transitions,
+                        table_state,
+                    };
+                    return;
+                }
+            }
+            Review::Open { .. } => return,
+            Review::Done => {}
+        }
 
         // Add tasks for tagging successful PRs
         for item in app.cherry_pick_items() {
@@ -109,6 +272,9 @@ impl PostCompletionState {
                 if let Some(pr_data) = app.pull_requests().iter().find(|pr| pr.pr.id == item.pr_id)
                 {
                     for work_item in &pr_data.work_items {
+                        if self.excluded.contains(&work_item.id) {
+                            continue;
+                        }
                         if let Some(title) = &work_item.fields.title {
                             self.tasks.push(PostCompletionTaskItem {
                                 task: PostCompletionTask::UpdatingWorkItem {
@@ -123,10 +289,105 @@ impl PostCompletionState {
             }
         }
 
+        // Add one release comment per work item when a template is configured
+        if let Some(template) = app.work_item_comment() {
+            let config = Self::post_merge_config(app);
+            let completed =
+                extract_completed_pr_info(app.pull_requests(), &Self::successful_pr_ids(app));
+            for task in work_item_comments(template, &config, &completed) {
+                if let PostMergeTask::CommentWorkItem {
+                    work_item_id,
+                    comment,
+                    ..
+                } = task
+                    && !self.excluded.contains(&work_item_id)
+                {
+                    self.tasks.push(PostCompletionTaskItem {
+                        task: PostCompletionTask::CommentingWorkItem {
+                            work_item_id,
+                            comment,
+                        },
+                        status: TaskStatus::Pending,
+                    });
+                }
+            }
+        }
+
+        // Write the configured fields on each work item
+        let completed =
+            extract_completed_pr_info(app.pull_requests(), &Self::successful_pr_ids(app));
+        for task in work_item_field_updates(&Self::post_merge_config(app), &completed) {
+            if let PostMergeTask::UpdateWorkItemFields {
+                work_item_id,
+                updates,
+                ..
+            } = task
+                && !self.excluded.contains(&work_item_id)
+            {
+                self.tasks.push(PostCompletionTaskItem {
+                    task: PostCompletionTask::UpdatingWorkItemFields {
+                        work_item_id,
+                        updates,
+                    },
+                    status: TaskStatus::Pending,
+                });
+            }
+        }
+
+        // Look up the package version and stamp it on the work items
+        let work_item_ids = self.included_work_item_ids(app);
+        if let Some(feed) = app.artifacts_feed() {
+            self.tasks.push(PostCompletionTaskItem {
+                task: PostCompletionTask::ResolvingPackageVersion {
+                    package: feed.package.clone(),
+                },
+                status: TaskStatus::Pending,
+            });
+            for &work_item_id in &work_item_ids {
+                self.tasks.push(PostCompletionTaskItem {
+                    task: PostCompletionTask::StampingWorkItem { work_item_id },
+                    status: TaskStatus::Pending,
+                });
+            }
+        }
+
+        // Tag the patch branch once the package version is known
+        let mode = app.release_tag_mode();
+        if mode.is_enabled() {
+            self.tasks.push(PostCompletionTaskItem {
+                task: PostCompletionTask::CreatingReleaseTag {
+                    tag: crate::git::release_tag_name(app.version().unwrap_or_default()),
+                    push: mode.pushes(),
+                },
+                status: TaskStatus::Pending,
+            });
+        }
+
+        // Link the work items to the release PR so it lists them too
+        if self.link_release_pr && !work_item_ids.is_empty() {
+            self.tasks.push(PostCompletionTaskItem {
+                task: PostCompletionTask::LinkingReleasePR {
+                    source_branch: patch_branch(app),
+                    work_item_ids,
+                },
+                status: TaskStatus::Pending,
+            });
+        }
+
+        // Announce the merge last, once everything else is done
+        if let Some(notifications) = app.notifications() {
+            for (channel, _) in notifications.webhooks() {
+                self.tasks.push(PostCompletionTaskItem {
+                    task: PostCompletionTask::SendingNotification { channel },
+                    status: TaskStatus::Pending,
+                });
+            }
+        }
+
         self.total_tasks = self.tasks.len();
     }
 
-    async fn process_current_task(&mut self, app: &MergeApp) -> bool {
+    async fn process_current_task(&mut self, app: &mut MergeApp) -> bool {
         if self.current_task_index >= self.tasks.len() {
             self.completed = true;
             return true;
@@ -141,6 +402,7 @@ impl PostCompletionState {
 
         task_item.status = TaskStatus::InProgress;
 
+        let mut skipped = None;
         let result = match &task_item.task {
             PostCompletionTask::TaggingPR { pr_id, .. } => {
                 let version = app.version().unwrap();
@@ -152,11 +414,65 @@ impl PostCompletionState {
                     .update_work_item_state(*work_item_id, app.work_item_state())
                     .await
             }
+            PostCompletionTask::CommentingWorkItem {
+                work_item_id,
+                comment,
+            } => {
+                app.client()
+                    .add_work_item_comment(*work_item_id, comment)
+                    .await
+            }
+            PostCompletionTask::UpdatingWorkItemFields {
+                work_item_id,
+                updates,
+            } => apply_field_updates(app.client(), *work_item_id, updates).await,
+            PostCompletionTask::ResolvingPackageVersion { .. } => {
+                match Self::resolve_package_version(app).await {
+                    Ok(package) => {
+                        app.set_package_version(package);
+                        Ok(())
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+            PostCompletionTask::StampingWorkItem { work_item_id } => match app.package_version() {
+                Some(package) => {
+                    app.client()
+                        .update_work_item_field(
+                            *work_item_id,
+                            INTEGRATION_BUILD_FIELD,
+                            &package.version,
+                        )
+                        .await
+                }
+                None => Err(anyhow::anyhow!("Package version was not found")),
+            },
+            PostCompletionTask::CreatingReleaseTag { push, .. } => {
+                Self::create_release_tag(app, *push).await
+            }
+            PostCompletionTask::LinkingReleasePR {
+                source_branch,
+                work_item_ids,
+            } => app
+                .client()
+                .link_work_items_to_pull_request(source_branch, app.target_branch(), work_item_ids)
+                .await
+                .map(|linked| {
+                    if linked.is_none() {
+                        skipped = Some("No release PR yet".to_string());
+                    }
+                }),
+            PostCompletionTask::SendingNotification { channel } => {
+                Self::send_notification(app, *channel).await
+            }
         };
 
         match result {
             Ok(()) => {
-                task_item.status = TaskStatus::Success;
+                task_item.status = match skipped {
+                    Some(reason) => TaskStatus::Skipped(reason),
+                    None => TaskStatus::Success,
+                };
             }
             Err(e) => {
                 task_item.status = TaskStatus::Failed(e.to_string());
@@ -166,6 +482,210 @@ impl PostCompletionState {
         self.current_task_index += 1;
         false
     }
+
+    /// Posts the summary of the merge recorded in the state file to a webhook.
+    async fn send_notification(app: &MergeApp, channel: NotificationChannel) -> anyhow::Result<()> {
+        let config = app.notifications().context("No notifications configured")?;
+        let webhook = config
+            .webhook(channel)
+            .with_context(|| format!("No {} webhook configured", channel))?;
+        let state = app.state_file().context("No merge state to summarize")?;
+        let notification = MergeNotification::from_state(&state, config);
+        notify::send(channel, webhook, &notification).await
+    }
+
+    /// Tags the last cherry-picked commit with the release notes as message.
+    async fn create_release_tag(app: &MergeApp, push: bool) -> anyhow::Result<()> {
+        let repo_path = app
+            .repo_path()
+            .context("No local repository to tag")?
+            .to_path_buf();
+        let version = app.version().context("No version to tag")?;
+        let message = crate::release_notes::generate_from_merge_data(
+            version,
+            app.cherry_pick_items(),
+            app.pull_requests(),
+            &app.urls(),
+            &app.picked_commits(),
+            app.package_version(),
+            app.clock().local_now().date_naive(),
+            app.release_notes_locale(),
+        );
+        let release_tag =
+            ReleaseTag::new(repo_path, version, message, push).signed(app.sign_commits());
+        tokio::task::spawn_blocking(move || release_tag.create()).await?
+    }
+
+    /// Looks up the version of the configured package built for the merge.
+    async fn resolve_package_version(app: &MergeApp) -> anyhow::Result<PackageVersion> {
+        let feed = app
+            .artifacts_feed()
+            .context("No artifacts feed configured")?;
+        app.client()
+            .resolve_package_version(feed, app.version().unwrap())
+            .await
+    }
+}
+
+impl PostCompletionState {
+    /// Renders the work item changes awaiting confirmation.
+    fn render_review(&mut self, f: &mut Frame) {
+        let Review::Open {
+            transitions,
+            table_state,
+        } = &mut self.review
+        else {
+            return;
+        };
+
+        // Field updates are previewed for the highlighted work item only
+        // when field updates are configured.
+        let preview_height = transitions
+            .iter()
+            .map(|t| t.field_updates.len())
+            .max()
+            .filter(|&count| count > 0)
+            .map_or(0, |count| count as u16 + 2);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(preview_height),
+                Constraint::Length(6),
+            ])
+            .split(f.area());
+
+        let title = Paragraph::new("🏷️  Review Work Item Changes")
+            .style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .block(Block::default().borders(Borders::ALL));
+        f.render_widget(title, chunks[0]);
+
+        let header_cells = [
+            "",
+            "Work Item",
+            "Title",
+            "Current State",
+            "Target State",
+            "Fields",
+        ]
+        .iter()
+        .map(|h| {
+            Cell::from(*h).style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )
+        });
+        let header = Row::new(header_cells).height(1).bottom_margin(1);
+
+        let rows = transitions.iter().map(|transition| {
+            let excluded = self.excluded.contains(&transition.work_item_id);
+            let checkbox = if excluded { "☐" } else { "☑" };
+            let target = if transition.is_unchanged() {
+                format!("{} (unchanged)", transition.target_state)
+            } else {
+                transition.target_state.clone()
+            };
+            let style = if excluded {
+                Style::default().fg(Color::DarkGray)
+            } else {
+                Style::default()
+            };
+            Row::new(vec![
+                Cell::from(checkbox),
+                Cell::from(format!("#{}", transition.work_item_id)),
+                Cell::from(transition.title.clone()),
+                Cell::from(transition.current_state.clone().unwrap_or_default()),
+                Cell::from(target),
+                Cell::from(transition.fields.join(", ")),
+            ])
+            .style(style)
+            .height(1)
+        });
+
+        let included = transitions
+            .iter()
+            .filter(|t| !self.excluded.contains(&t.work_item_id))
+            .count();
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(3),
+                Constraint::Length(10),
+                Constraint::Min(20),
+                Constraint::Length(15),
+                Constraint::Length(25),
+                Constraint::Length(22),
+            ],
+        )
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            "Work Items ({} of {} will be updated)",
+            included,
+            transitions.len()
+        )))
+        .row_highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("→ ");
+        f.render_stateful_widget(table, chunks[1], table_state);
+
+        if preview_height > 0
+            && let Some(transition) = table_state.selected().and_then(|i| transitions.get(i))
+        {
+            let lines: Vec<Line> = transition
+                .field_updates
+                .iter()
+                .map(|update| Line::from(update.to_string()))
+                .collect();
+            let preview = Paragraph::new(lines).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("Field Updates (#{})", transition.work_item_id)),
+            );
+            f.render_widget(preview, chunks[2]);
+        }
+
+        let key_style = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let link = if self.link_release_pr {
+            Span::styled("on", Style::default().fg(Color::Green))
+        } else {
+            Span::styled("off", Style::default().fg(Color::DarkGray))
+        };
+        let instructions = vec![
+            Line::from("Nothing is written to Azure DevOps until the changes are confirmed."),
+            Line::from(vec![
+                Span::raw("Link work items to the release PR: "),
+                link,
+                Span::raw(" (press "),
+                Span::styled("l", key_style),
+                Span::raw(" to toggle)"),
+            ]),
+            Line::from(vec![
+                Span::raw("Press "),
+                Span::styled("Space", key_style),
+                Span::raw(" to exclude or include a work item, "),
+                Span::styled("Enter", key_style),
+                Span::raw(" to start, "),
+                Span::styled("Esc", key_style),
+                Span::raw(" to go back"),
+            ]),
+        ];
+        let instructions_widget = Paragraph::new(instructions)
+            .block(Block::default().borders(Borders::ALL).title("Instructions"))
+            .wrap(Wrap { trim: true });
+        f.render_widget(instructions_widget, chunks[3]);
+    }
 }
 
 // ============================================================================
@@ -178,6 +698,10 @@ impl ModeState for PostCompletionState {
 
     fn ui(&mut self, f: &mut Frame, app: &MergeApp) {
         self.initialize_tasks(app);
+        if self.is_reviewing() {
+            self.render_review(f);
+            return;
+        }
 
         let main_chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -235,6 +759,7 @@ impl ModeState for PostCompletionState {
                 TaskStatus::InProgress => ("⚡", Color::Yellow),
                 TaskStatus::Success => ("✅", Color::Green),
                 TaskStatus::Failed(_) => ("❌", Color::Red),
+                TaskStatus::Skipped(_) => ("➖", Color::DarkGray),
             };
 
             let task_description = match &task_item.task {
@@ -252,6 +777,48 @@ impl ModeState for PostCompletionState {
                         work_item_title
                     )
                 }
+                PostCompletionTask::CommentingWorkItem {
+                    work_item_id,
+                    comment,
+                } => {
+                    format!("Comment on WI #{}: {}", work_item_id, comment)
+                }
+                PostCompletionTask::UpdatingWorkItemFields {
+                    work_item_id,
+                    updates,
+                } => {
+                    let updates: Vec<String> = updates.iter().map(ToString::to_string).collect();
+                    format!("Update WI #{}: {}", work_item_id, updates.join(", "))
+                }
+                PostCompletionTask::ResolvingPackageVersion { package } => {
+                    match app.package_version() {
+                        Some(found) => format!("Find package version: {}", found),
+                        None => format!("Find package version: {}", package),
+                    }
+                }
+                PostCompletionTask::StampingWorkItem { work_item_id } => {
+                    format!("Stamp WI #{} with the package version", work_item_id)
+                }
+                PostCompletionTask::CreatingReleaseTag { tag, push: false } => {
+                    format!("Create release tag {}", tag)
+                }
+                PostCompletionTask::CreatingReleaseTag { tag, push: true } => {
+                    format!("Create and push release tag {}", tag)
+                }
+                PostCompletionTask::LinkingReleasePR {
+                    source_branch,
+                    work_item_ids,
+                } => {
+                    format!(
+                        "Link {} work item(s) to PR {} → {}",
+                        work_item_ids.len(),
+                        source_branch,
+                        app.target_branch()
+                    )
+                }
+                PostCompletionTask::SendingNotification { channel } => {
+                    format!("Notify {}", channel)
+                }
             };
 
             let mut spans = vec![
@@ -259,11 +826,16 @@ impl ModeState for PostCompletionState {
                 Span::raw(task_description),
             ];
 
-            if let TaskStatus::Failed(error) = &task_item.status {
-                spans.push(Span::styled(
+            match &task_item.status {
+                TaskStatus::Failed(error) => spans.push(Span::styled(
                     format!(" - Error: {}", error),
                     Style::default().fg(Color::Red),
-                ));
+                )),
+                TaskStatus::Skipped(reason) => spans.push(Span::styled(
+                    format!(" - {}", reason),
+                    Style::default().fg(Color::DarkGray),
+                )),
+                _ => {}
             }
 
             // Highlight current task
@@ -305,7 +877,12 @@ impl ModeState for PostCompletionState {
                 Line::from(""),
             ];
 
-            if self.has_failed_tasks() {
+            if self.has_retryable_tasks() {
+                let retry = if self.has_failed_tasks() {
+                    " to retry failed tasks"
+                } else {
+                    " to retry skipped tasks"
+                };
                 lines.extend(vec![
                     Line::from(vec![
                         Span::raw("Press "),
@@ -315,7 +892,7 @@ impl ModeState for PostCompletionState {
                     Line::from(vec![
                         Span::raw("Press "),
                         Span::styled("r", key_style),
-                        Span::raw(" to retry failed tasks"),
+                        Span::raw(retry),
                     ]),
                     Line::from(vec![
                         Span::raw("Press "),
@@ -371,14 +948,36 @@ impl ModeState for PostCompletionState {
         match code {
             KeyCode::Char('q') => {
                 // Mark state file as completed and clean up before exit
-                app.with_state_file_mut(|state_file| {
-                    state_file.final_status = Some(MergeStatus::Success);
-                    state_file.completed_at = Some(chrono::Utc::now());
-                    let _ = state_file.save_for_repo();
-                });
-                let _ = app.cleanup_state_file();
+                app.finish_merge();
                 StateChange::Exit
             }
+            _ if self.is_reviewing() => match code {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.move_review_selection(-1);
+                    StateChange::Keep
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.move_review_selection(1);
+                    StateChange::Keep
+                }
+                KeyCode::Char(' ') => {
+                    self.toggle_excluded();
+                    StateChange::Keep
+                }
+                KeyCode::Char('l') => {
+                    self.link_release_pr = !self.link_release_pr;
+                    StateChange::Keep
+                }
+                KeyCode::Enter => {
+                    self.review = Review::Done;
+                    self.initialize_tasks(app);
+                    StateChange::Keep
+                }
+                KeyCode::Esc => StateChange::Change(MergeState::Completion(
+                    crate::ui::state::CompletionState::new(),
+                )),
+                _ => StateChange::Keep,
+            },
             KeyCode::Null if !self.completed => {
                 // Auto-process tasks
                 if self.process_current_task(app).await {
@@ -392,7 +991,7 @@ impl ModeState for PostCompletionState {
                     crate::ui::state::CompletionState::new(),
                 ))
             }
-            KeyCode::Char('r') if self.completed && self.has_failed_tasks() => {
+            KeyCode::Char('r') if self.completed && self.has_retryable_tasks() => {
                 // Retry failed tasks
                 self.retry_failed_tasks();
                 StateChange::Keep
@@ -401,6 +1000,68 @@ impl ModeState for PostCompletionState {
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        if self.is_reviewing() {
+            return ActionMap::new("Review Work Item Changes")
+                .bind(
+                    ActionCategory::Navigation,
+                    &[
+                        KeyCode::Up,
+                        KeyCode::Down,
+                        KeyCode::Char('k'),
+                        KeyCode::Char('j'),
+                    ],
+                    "Move highlight",
+                )
+                .bind(
+                    ActionCategory::Selection,
+                    &[KeyCode::Char(' ')],
+                    "Exclude or include work item",
+                )
+                .bind(
+                    ActionCategory::Selection,
+                    &[KeyCode::Char('l')],
+                    "Link work items to release PR",
+                )
+                .bind(
+                    ActionCategory::Actions,
+                    &[KeyCode::Enter],
+                    "Start post-completion tasks",
+                )
+                .bind(
+                    ActionCategory::General,
+                    &[KeyCode::Esc],
+                    "Back to completion summary",
+                )
+                .bind(
+                    ActionCategory::General,
+                    &[KeyCode::Char('q')],
+                    "Finish and exit",
+                );
+        }
+
+        let mut map = ActionMap::new("Post-Completion Tasks");
+        if self.completed {
+            map = map.bind(
+                ActionCategory::General,
+                &[KeyCode::Enter],
+                "Back to completion summary",
+            );
+            if self.has_retryable_tasks() {
+                map = map.bind(
+                    ActionCategory::Actions,
+                    &[KeyCode::Char('r')],
+                    "Retry failed tasks",
+                );
+            }
+        }
+        map.bind(
+            ActionCategory::General,
+            &[KeyCode::Char('q')],
+            "Finish and exit",
+        )
+    }
+
     fn name(&self) -> &'static str {
         "PostCompletion"
     }
@@ -409,9 +1070,13 @@ impl ModeState for PostCompletionState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::AppConfig;
     use crate::ui::{
         snapshot_testing::with_settings_and_module_path,
-        testing::{TuiTestHarness, create_test_config_default, create_test_pull_requests},
+        testing::{
+            TuiTestHarness, create_test_cherry_pick_items, create_test_config_default,
+            create_test_pull_requests,
+        },
     };
     use insta::assert_snapshot;
 
@@ -446,6 +1111,479 @@ mod tests {
         });
     }
 
+    /// # Post Completion State - Work Item Comments
+    ///
+    /// Tests that a configured comment template adds comment tasks.
+    ///
+    /// ## Test Scenario
+    /// - Configures a work item comment template
+    /// - PR #100 (work item #1001) was cherry-picked successfully
+    /// - Confirms the work item review and initializes the task list
+    ///
+    /// ## Expected Outcome
+    /// - The tag and state update tasks come first
+    /// - A rendered comment task follows for work item #1001
+    /// - Linking the release PR comes last
+    #[test]
+    fn test_post_completion_work_item_comments() {
+        let mut config = create_test_config_default();
+        if let AppConfig::Default { default, .. } = &mut config {
+            default.work_item_comment = Some("Shipped in {version}, PR {prs}".to_string());
+        }
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+        *harness.app.cherry_pick_items_mut() = create_test_cherry_pick_items();
+        harness.app.set_version(Some("v1.0.0".to_string()));
+
+        let mut state = PostCompletionState::new();
+        state.initialize_tasks(harness.merge_app());
+        assert!(state.is_reviewing());
+        assert!(ModeState::action_map(&state).handles(KeyCode::Char('j')));
+        state.review = Review::Done;
+        state.initialize_tasks(harness.merge_app());
+
+        assert_eq!(state.total_tasks, 4);
+        assert!(matches!(
+            state.tasks[2].task,
+            PostCompletionTask::CommentingWorkItem {
+                work_item_id: 1001,
+                ref comment,
+            } if comment == "Shipped in v1.0.0, PR !100"
+        ));
+    }
+
+    /// # Post Completion State - Work Item Review
+    ///
+    /// Tests the review of work item changes shown before any task runs.
+    ///
+    /// ## Test Scenario
+    /// - PRs #100 and #101 were cherry-picked successfully
+    /// - Configures a work item comment template
+    /// - Excludes work item #1002 and renders the review
+    ///
+    /// ## Expected Outcome
+    /// - Each work item is listed with its current and target state and fields
+    /// - The excluded work item is unchecked and the title counts 1 of 2
+    #[test]
+    fn test_post_completion_work_item_review() {
+        with_settings_and_module_path(module_path!(), || {
+            let mut config = create_test_config_default();
+            if let AppConfig::Default { default, .. } = &mut config {
+                default.work_item_comment = Some("Shipped in {version}".to_string());
+            }
+            let mut harness = TuiTestHarness::with_config(config);
+            *harness.app.pull_requests_mut() = create_test_pull_requests();
+            let mut items = create_test_cherry_pick_items();
+            items[1].status = CherryPickStatus::Success;
+            *harness.app.cherry_pick_items_mut() = items;
+            harness.app.set_version(Some("v1.0.0".to_string()));
+
+            let mut inner_state = PostCompletionState::new();
+            inner_state.initialize_tasks(harness.merge_app());
+            inner_state.move_review_selection(1);
+            inner_state.toggle_excluded();
+            let mut state = MergeState::PostCompletion(inner_state);
+
+            harness.render_merge_state(&mut state);
+
+            assert_snapshot!("work_item_review", harness.backend());
+        });
+    }
+
+    fn field_rules() -> Vec<crate::core::operations::WorkItemFieldRule> {
+        use crate::core::operations::{FieldUpdateMode, WorkItemFieldRule};
+        vec![
+            WorkItemFieldRule {
+                field: "Custom.ReleaseVersion".to_string(),
+                value: "{version}".to_string(),
+                mode: FieldUpdateMode::Set,
+            },
+            WorkItemFieldRule {
+                field: "System.Tags".to_string(),
+                value: "released-{version}".to_string(),
+                mode: FieldUpdateMode::Append,
+            },
+        ]
+    }
+
+    /// # Post Completion State - Work Item Field Update Preview
+    ///
+    /// Tests the preview of field updates in the work item review.
+    ///
+    /// ## Test Scenario
+    /// - Configures a set and an append field update
+    /// - PRs #100 and #101 were cherry-picked successfully
+    /// - Renders the review with work item #1001 highlighted
+    ///
+    /// ## Expected Outcome
+    /// - The configured fields are listed with the work item changes
+    /// - The rendered field updates of #1001 are shown below the table
+    #[test]
+    fn test_post_completion_field_update_preview() {
+        with_settings_and_module_path(module_path!(), || {
+            let mut config = create_test_config_default();
+            if let AppConfig::Default { default, .. } = &mut config {
+                default.work_item_fields = field_rules();
+            }
+            let mut harness = TuiTestHarness::with_config(config);
+            *harness.app.pull_requests_mut() = create_test_pull_requests();
+            let mut items = create_test_cherry_pick_items();
+            items[1].status = CherryPickStatus::Success;
+            *harness.app.cherry_pick_items_mut() = items;
+            harness.app.set_version(Some("v1.0.0".to_string()));
+
+            let mut inner_state = PostCompletionState::new();
+            inner_state.initialize_tasks(harness.merge_app());
+            let mut state = MergeState::PostCompletion(inner_state);
+
+            harness.render_merge_state(&mut state);
+
+            assert_snapshot!("field_update_preview", harness.backend());
+        });
+    }
+
+    /// # Post Completion State - Work Item Field Updates
+    ///
+    /// Tests that configured field updates add one task per work item.
+    ///
+    /// ## Test Scenario
+    /// - Configures a set and an append field update
+    /// - PRs #100 and #101 were cherry-picked successfully
+    /// - Excludes work item #1002 and confirms the review
+    ///
+    /// ## Expected Outcome
+    /// - A field update task with the rendered values follows the state
+    ///   updates for work item #1001 only
+    #[test]
+    fn test_post_completion_field_updates() {
+        let mut config = create_test_config_default();
+        if let AppConfig::Default { default, .. } = &mut config {
+            default.work_item_fields = field_rules();
+        }
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+        let mut items = create_test_cherry_pick_items();
+        items[1].status = CherryPickStatus::Success;
+        *harness.app.cherry_pick_items_mut() = items;
+        harness.app.set_version(Some("v1.0.0".to_string()));
+
+        let mut state = PostCompletionState::new();
+        state.initialize_tasks(harness.merge_app());
+        state.move_review_selection(1);
+        state.toggle_excluded();
+        state.review = Review::Done;
+        state.initialize_tasks(harness.merge_app());
+
+        let field_tasks: Vec<_> = state
+            .tasks
+            .iter()
+            .filter_map(|item| match &item.task {
+                PostCompletionTask::UpdatingWorkItemFields {
+                    work_item_id,
+                    updates,
+                } => Some((*work_item_id, updates.clone())),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(field_tasks.len(), 1);
+        assert_eq!(field_tasks[0].0, 1001);
+        let updates: Vec<String> = field_tasks[0].1.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            updates,
+            vec![
+                "Custom.ReleaseVersion = v1.0.0",
+                "System.Tags += released-v1.0.0"
+            ]
+        );
+    }
+
+    /// # Post Completion State - Excluded Work Items
+    ///
+    /// Tests that excluded work items are left untouched.
+    ///
+    /// ## Test Scenario
+    /// - PRs #100 and #101 were cherry-picked successfully
+    /// - Configures a work item comment template
+    /// - Excludes work item #1001 in the review and confirms with Enter
+    ///
+    /// ## Expected Outcome
+    /// - Both PRs are still tagged
+    /// - Only work item #1002 is updated and commented on
+    #[tokio::test]
+    async fn test_post_completion_excluded_work_items() {
+        let mut config = create_test_config_default();
+        if let AppConfig::Default { default, .. } = &mut config {
+            default.work_item_comment = Some("Shipped in {version}".to_string());
+        }
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+        let mut items = create_test_cherry_pick_items();
+        items[1].status = CherryPickStatus::Success;
+        *harness.app.cherry_pick_items_mut() = items;
+        harness.app.set_version(Some("v1.0.0".to_string()));
+
+        let mut state = PostCompletionState::new();
+        state.initialize_tasks(harness.merge_app());
+        for key in [KeyCode::Char(' '), KeyCode::Enter] {
+            let result = ModeState::process_key(&mut state, key, harness.merge_app_mut()).await;
+            assert!(matches!(result, StateChange::Keep));
+        }
+
+        assert!(!state.is_reviewing());
+        let work_item_ids: Vec<i32> = state
+            .tasks
+            .iter()
+            .filter_map(|item| match &item.task {
+                PostCompletionTask::TaggingPR { .. }
+                | PostCompletionTask::ResolvingPackageVersion { .. }
+                | PostCompletionTask::CreatingReleaseTag { .. }
+                | PostCompletionTask::LinkingReleasePR { .. }
+                | PostCompletionTask::SendingNotification { .. } => None,
+                PostCompletionTask::UpdatingWorkItem { work_item_id, .. }
+                | PostCompletionTask::CommentingWorkItem { work_item_id, .. }
+                | PostCompletionTask::UpdatingWorkItemFields { work_item_id, .. }
+                | PostCompletionTask::StampingWorkItem { work_item_id } => Some(*work_item_id),
+            })
+            .collect();
+        assert_eq!(state.total_tasks, 5);
+        assert_eq!(work_item_ids, vec![1002, 1002]);
+    }
+
+    /// # Post Completion State - Release PR Linking
+    ///
+    /// Tests linking the work items to the PR of the patch branch.
+    ///
+    /// ## Test Scenario
+    /// - PRs #100 and #101 were cherry-picked successfully
+    /// - Excludes work item #1001 and confirms the review
+    /// - Repeats with linking toggled off with `l`
+    ///
+    /// ## Expected Outcome
+    /// - The last task links only work item #1002, from the patch branch
+    /// - Without linking, no link task is added
+    #[tokio::test]
+    async fn test_post_completion_release_pr_linking() {
+        let mut harness = TuiTestHarness::new();
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+        let mut items = create_test_cherry_pick_items();
+        items[1].status = CherryPickStatus::Success;
+        *harness.app.cherry_pick_items_mut() = items;
+        harness.app.set_version(Some("v1.0.0".to_string()));
+
+        let mut state = PostCompletionState::new();
+        state.initialize_tasks(harness.merge_app());
+        for key in [KeyCode::Char(' '), KeyCode::Enter] {
+            ModeState::process_key(&mut state, key, harness.merge_app_mut()).await;
+        }
+        assert!(matches!(
+            &state.tasks.last().unwrap().task,
+            PostCompletionTask::LinkingReleasePR {
+                source_branch,
+                work_item_ids,
+            } if source_branch == &patch_branch(harness.merge_app()) && work_item_ids == &[1002]
+        ));
+
+        let mut state = PostCompletionState::new();
+        state.initialize_tasks(harness.merge_app());
+        for key in [KeyCode::Char('l'), KeyCode::Enter] {
+            ModeState::process_key(&mut state, key, harness.merge_app_mut()).await;
+        }
+        assert!(!state.tasks.is_empty());
+        assert!(
+            !state
+                .tasks
+                .iter()
+                .any(|item| matches!(item.task, PostCompletionTask::LinkingReleasePR { .. }))
+        );
+    }
+
+    /// # Post Completion State - Package Version
+    ///
+    /// Tests the tasks stamping the package version on the work items.
+    ///
+    /// ## Test Scenario
+    /// - Configures an artifacts feed
+    /// - PRs #100 and #101 were cherry-picked successfully
+    /// - Runs a stamp task before the package version was looked up
+    ///
+    /// ## Expected Outcome
+    /// - The lookup comes after the work item updates, followed by one
+    ///   stamp task per work item and the release PR link
+    /// - Stamping fails while no package version was found
+    #[tokio::test]
+    async fn test_post_completion_package_version() {
+        let mut config = create_test_config_default();
+        if let AppConfig::Default { default, .. } = &mut config {
+            default.artifacts_feed = Some(crate::api::ArtifactsFeed {
+                feed: "releases".to_string(),
+                package: "Contoso.Service".to_string(),
+                project: None,
+            });
+        }
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+        let mut items = create_test_cherry_pick_items();
+        items[1].status = CherryPickStatus::Success;
+        *harness.app.cherry_pick_items_mut() = items;
+        harness.app.set_version(Some("v1.0.0".to_string()));
+
+        let mut state = PostCompletionState::new();
+        state.review = Review::Done;
+        state.initialize_tasks(harness.merge_app());
+
+        assert_eq!(state.total_tasks, 8);
+        assert!(matches!(
+            &state.tasks[4].task,
+            PostCompletionTask::ResolvingPackageVersion { package } if package == "Contoso.Service"
+        ));
+        assert!(matches!(
+            state.tasks[5].task,
+            PostCompletionTask::StampingWorkItem { work_item_id: 1001 }
+        ));
+        assert!(matches!(
+            state.tasks[6].task,
+            PostCompletionTask::StampingWorkItem { work_item_id: 1002 }
+        ));
+        assert!(matches!(
+            state.tasks[7].task,
+            PostCompletionTask::LinkingReleasePR { .. }
+        ));
+
+        state.current_task_index = 5;
+        state.process_current_task(harness.merge_app_mut()).await;
+        assert!(matches!(
+            &state.tasks[5].status,
+            TaskStatus::Failed(message) if message == "Package version was not found"
+        ));
+    }
+
+    /// # Post Completion State - Release Tag
+    ///
+    /// Tests the task tagging the patch branch with the release notes.
+    ///
+    /// ## Test Scenario
+    /// - Configures a pushed release tag
+    /// - PRs #100 and #101 were cherry-picked successfully
+    /// - Runs the tag task without a local repository
+    ///
+    /// ## Expected Outcome
+    /// - Pushing `v1.0.0` comes after the work item updates, right before
+    ///   the release PR link
+    /// - The task fails as there is no repository to tag
+    #[tokio::test]
+    async fn test_post_completion_release_tag() {
+        let mut config = create_test_config_default();
+        if let AppConfig::Default { default, .. } = &mut config {
+            default.release_tag = crate::core::operations::ReleaseTagMode::Push;
+        }
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+        let mut items = create_test_cherry_pick_items();
+        items[1].status = CherryPickStatus::Success;
+        *harness.app.cherry_pick_items_mut() = items;
+        harness.app.set_version(Some("v1.0.0".to_string()));
+
+        let mut state = PostCompletionState::new();
+        state.review = Review::Done;
+        state.initialize_tasks(harness.merge_app());
+
+        assert_eq!(state.total_tasks, 6);
+        assert!(matches!(
+            &state.tasks[4].task,
+            PostCompletionTask::CreatingReleaseTag { tag, push: true } if tag == "v1.0.0"
+        ));
+        assert!(matches!(
+            state.tasks[5].task,
+            PostCompletionTask::LinkingReleasePR { .. }
+        ));
+
+        state.current_task_index = 4;
+        state.process_current_task(harness.merge_app_mut()).await;
+        assert!(matches!(
+            &state.tasks[4].status,
+            TaskStatus::Failed(message) if message == "No local repository to tag"
+        ));
+    }
+
+    /// # Post Completion State - Chat Notifications
+    ///
+    /// Tests the tasks posting the merge summary to chat webhooks.
+    ///
+    /// ## Test Scenario
+    /// - Configures Slack and Teams webhooks, the Slack one malformed
+    /// - Runs the Slack task with and without a state file
+    ///
+    /// ## Expected Outcome
+    /// - One notification task per webhook comes last
+    /// - The task fails without a state file, then on the malformed URL
+    #[tokio::test]
+    async fn test_post_completion_notifications() {
+        let mut config = create_test_config_default();
+        if let AppConfig::Default { default, .. } = &mut config {
+            default.notifications = Some(crate::notify::NotificationsConfig {
+                slack_webhook: Some("not a url".to_string()),
+                teams_webhook: Some("https://contoso.webhook.office.com/x".to_string()),
+                release_notes_url: None,
+            });
+        }
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+        *harness.app.cherry_pick_items_mut() = create_test_cherry_pick_items();
+        harness.app.set_version(Some("v1.0.0".to_string()));
+
+        let mut state = PostCompletionState::new();
+        state.review = Review::Done;
+        state.initialize_tasks(harness.merge_app());
+
+        let channels: Vec<NotificationChannel> = state
+            .tasks
+            .iter()
+            .filter_map(|item| match item.task {
+                PostCompletionTask::SendingNotification { channel } => Some(channel),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            channels,
+            vec![NotificationChannel::Slack, NotificationChannel::Teams]
+        );
+        let slack = state.total_tasks - 2;
+        assert!(matches!(
+            state.tasks[slack].task,
+            PostCompletionTask::SendingNotification {
+                channel: NotificationChannel::Slack
+            }
+        ));
+
+        state.current_task_index = slack;
+        state.process_current_task(harness.merge_app_mut()).await;
+        assert!(matches!(
+            &state.tasks[slack].status,
+            TaskStatus::Failed(message) if message == "No merge state to summarize"
+        ));
+
+        harness.merge_app_mut().set_state_file(
+            crate::core::state::MergeStateFile::builder()
+                .repo_path("/work/repo")
+                .organization("org")
+                .project("project")
+                .repository("repo")
+                .dev_branch("dev")
+                .target_branch("main")
+                .merge_version("v1.0.0")
+                .work_item_state("Done")
+                .tag_prefix("merged-")
+                .build(),
+        );
+        state.tasks[slack].status = TaskStatus::Pending;
+        state.current_task_index = slack;
+        state.process_current_task(harness.merge_app_mut()).await;
+        assert!(matches!(
+            &state.tasks[slack].status,
+            TaskStatus::Failed(message) if message == "Invalid Slack webhook URL"
+        ));
+    }
+
     /// # Post Completion State - Partially Updated
     ///
     /// Tests the post-completion screen with tasks in various states of completion.
@@ -540,6 +1678,55 @@ mod tests {
         });
     }
 
+    /// # Post Completion State - Release PR Missing
+    ///
+    /// Tests the post-completion screen when the release PR does not exist.
+    ///
+    /// ## Test Scenario
+    /// - Completes two tasks, with linking the release PR skipped
+    /// - Renders the screen and presses `r`
+    ///
+    /// ## Expected Outcome
+    /// - The link task is shown as skipped with its reason, not as an error
+    /// - Retrying makes the skipped task pending again
+    #[test]
+    fn test_post_completion_release_pr_missing() {
+        with_settings_and_module_path(module_path!(), || {
+            let config = create_test_config_default();
+            let mut harness = TuiTestHarness::with_config(config);
+            harness.app.set_version(Some("v1.0.0".to_string()));
+
+            let mut tasks = crate::ui::testing::create_test_post_completion_tasks();
+            tasks.truncate(1);
+            tasks[0].status = TaskStatus::Success;
+            tasks.push(PostCompletionTaskItem {
+                task: PostCompletionTask::LinkingReleasePR {
+                    source_branch: "patch/main-v1.0.0".to_string(),
+                    work_item_ids: vec![1001],
+                },
+                status: TaskStatus::Skipped("No release PR yet".to_string()),
+            });
+
+            let mut inner_state = PostCompletionState::new();
+            inner_state.tasks = tasks;
+            inner_state.current_task_index = 2;
+            inner_state.total_tasks = 2;
+            inner_state.completed = true;
+            assert!(!inner_state.has_failed_tasks());
+            let mut state = MergeState::PostCompletion(inner_state);
+
+            harness.render_merge_state(&mut state);
+            assert_snapshot!("release_pr_missing", harness.backend());
+
+            let MergeState::PostCompletion(inner_state) = &mut state else {
+                unreachable!()
+            };
+            inner_state.retry_failed_tasks();
+            assert!(matches!(inner_state.tasks[1].status, TaskStatus::Pending));
+            assert_eq!(inner_state.current_task_index, 1);
+        });
+    }
+
     /// # Post Completion State - Mixed Errors
     ///
     /// Tests the post-completion screen with various types of errors.
@@ -1,28 +1,44 @@
+use super::data_loading::{LoadingContext, NewPullRequests, fetch_new_pull_requests};
 use super::{DataLoadingState, VersionInputState};
 use crate::{
-    core::operations::{DependencyCategory, WorkItemPrIndex},
-    models::WorkItemHistory,
-    ui::apps::MergeApp,
+    core::operations::{
+        BuildCheckIndex, ConflictRisk, DependencyCategory, PrColumn, PrFilter, PrTab,
+        PrTableLayout, SelectionFile, WorkItemPrIndex, summarize_selection,
+    },
+    core::state::{SelectionSession, last_run_key},
+    git::{self, DiffStat},
+    models::{PullRequestWithWorkItems, WorkItemHistory},
+    ui::apps::{LOCAL_REPO_FEATURES, MergeApp},
+    ui::keymap::{ActionCategory, ActionMap},
     ui::state::default::MergeState,
     ui::state::typed::{ModeState, StateChange},
-    utils::{html_to_lines, truncate_str},
+    utils::{html_to_lines, markdown_to_lines, truncate_str},
 };
 use anyhow::{Result, bail};
 use async_trait::async_trait;
 use chrono::DateTime;
-use crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Flex, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{
         Block, Borders, Cell, List, ListItem, Paragraph, Row, Scrollbar, ScrollbarOrientation,
-        ScrollbarState, Table, TableState,
+        ScrollbarState, Table, TableState, Tabs, Wrap,
     },
 };
+use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::time::Instant;
+use tokio::sync::oneshot;
+
+/// Width of the selection summary side panel.
+const SUMMARY_PANEL_WIDTH: u16 = 34;
+
+/// Characters a column grows or shrinks by per Alt+←/→.
+const COLUMN_RESIZE_STEP: i16 = 2;
 
 #[derive(Debug, Clone)]
 enum SearchQuery {
@@ -32,6 +48,24 @@ enum SearchQuery {
     WorkItemId(i32),
 }
 
+/// Total diff size shown when confirming a selection above the soft cap.
+enum SelectionDiffSize {
+    /// Still being summed in the background.
+    Loading(oneshot::Receiver<Result<DiffStat>>),
+    Ready(DiffStat),
+    /// No local repository, or git failed.
+    Unavailable(String),
+}
+
+/// Content shown in the details pane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DetailsTab {
+    /// Work items linked to the selected PR.
+    WorkItems,
+    /// The selected PR's own description.
+    PullRequest,
+}
+
 pub struct PullRequestSelectionState {
     table_state: TableState,
     scrollbar_state: ScrollbarState,
@@ -58,11 +92,54 @@ pub struct PullRequestSelectionState {
     dependency_dialog_scroll: usize,
     // Details pane toggle
     show_details: bool,
+    details_tab: DetailsTab,
+    // Selection summary side panel toggle
+    show_summary: bool,
+    // API schema diagnostics side panel toggle
+    show_diagnostics: bool,
+    // Show PRs excluded by .mergers-ignore (greyed out, not selectable)
+    show_ignored: bool,
+    // Show PRs whose work items are all in a configured terminal state
+    show_terminal: bool,
     // Work item grouping index (for highlighting and hotkeys)
     work_item_pr_index: Option<WorkItemPrIndex>,
     // Settings dialog
     show_settings_dialog: bool,
     settings_selection: usize,
+    // Background auto-refresh
+    last_refresh: Instant,
+    refresh_task: Option<oneshot::Receiver<Result<NewPullRequests>>>,
+    new_prs: Vec<PullRequestWithWorkItems>,
+    new_build_checks: BuildCheckIndex,
+    refresh_error: Option<String>,
+    // Selection import from a file of PR / work item ids
+    import_mode: bool,
+    import_input: String,
+    import_message: Option<(String, Color)>,
+    // Author, reviewer and path filter prompt
+    filter_mode: bool,
+    filter_input: String,
+    filter_error: Option<String>,
+    // Selection last written to the session file
+    saved_session: Option<SelectionSession>,
+    // User-defined PR tabs; 0 is "All", tab `n` is `app.pr_tabs()[n - 1]`
+    active_tab: usize,
+    tab_count: usize,
+    // Table rows of the active tab (selection and offset by row, not PR index)
+    tab_table_state: TableState,
+    // Whether the last render showed only some PRs or a sorted order
+    rows_by_position: bool,
+    // Column targeted by resizing (Alt+←/→) and sorting (o / O)
+    focused_column: PrColumn,
+    // Column widths of the last render, the starting point for resizing
+    column_widths: Vec<u16>,
+    // Confirmation before cherry-picking more PRs than the soft cap
+    large_selection_confirm: Option<SelectionDiffSize>,
+    // Local repository prompt, shown from the degraded-mode banner
+    local_repo_mode: bool,
+    local_repo_input: String,
+    local_repo_message: Option<(String, Color)>,
+    clone_task: Option<oneshot::Receiver<Result<PathBuf>>>,
 }
 
 impl Default for PullRequestSelectionState {
@@ -99,11 +176,44 @@ impl PullRequestSelectionState {
             table_area: None,
             // Details pane toggle
             show_details: true,
+            details_tab: DetailsTab::WorkItems,
+            show_summary: false,
+            show_diagnostics: false,
+            show_ignored: false,
+            show_terminal: false,
             // Work item grouping index (for highlighting and hotkeys)
             work_item_pr_index: None,
             // Settings dialog
             show_settings_dialog: false,
             settings_selection: 0,
+            // Background auto-refresh
+            last_refresh: Instant::now(),
+            refresh_task: None,
+            new_build_checks: BuildCheckIndex::default(),
+            new_prs: Vec::new(),
+            refresh_error: None,
+            // Selection import
+            import_mode: false,
+            import_input: String::new(),
+            import_message: None,
+            // PR filter prompt
+            filter_mode: false,
+            filter_input: String::new(),
+            filter_error: None,
+            saved_session: None,
+            // PR tabs
+            active_tab: 0,
+            tab_count: 0,
+            tab_table_state: TableState::default(),
+            rows_by_position: false,
+            focused_column: PrColumn::Title,
+            column_widths: Vec::new(),
+            large_selection_confirm: None,
+            // Local repository prompt
+            local_repo_mode: false,
+            local_repo_input: String::new(),
+            local_repo_message: None,
+            clone_task: None,
         }
     }
 
@@ -113,6 +223,375 @@ impl PullRequestSelectionState {
         self.work_item_pr_index = Some(WorkItemPrIndex::build(prs));
     }
 
+    /// Starts a background PR refresh when the auto-refresh interval has
+    /// elapsed, and collects its result once finished.
+    ///
+    /// Called on every tick. Newly found PRs are held back until the user
+    /// adds them with [`Self::add_new_prs`].
+    fn poll_auto_refresh(&mut self, app: &MergeApp) {
+        if let Some(receiver) = self.refresh_task.as_mut() {
+            let result = match receiver.try_recv() {
+                Ok(result) => result,
+                Err(oneshot::error::TryRecvError::Empty) => return,
+                Err(oneshot::error::TryRecvError::Closed) => {
+                    Err(anyhow::anyhow!("Refresh task stopped unexpectedly"))
+                }
+            };
+            self.refresh_task = None;
+            self.last_refresh = Instant::now();
+            match result {
+                Ok((prs, build_checks)) => {
+                    self.refresh_error = None;
+                    self.new_prs.extend(prs);
+                    self.new_build_checks.extend(build_checks);
+                }
+                Err(e) => self.refresh_error = Some(format!("{:#}", e)),
+            }
+            return;
+        }
+
+        let Some(interval) = app.auto_refresh_interval() else {
+            return;
+        };
+        if self.last_refresh.elapsed() < interval {
+            return;
+        }
+
+        // Skip PRs already listed, ignored, or waiting to be added
+        let known_ids: HashSet<i32> = app
+            .pull_requests()
+            .iter()
+            .map(|pr| pr.pr.id)
+            .chain(
+                app.ignored_pull_requests()
+                    .iter()
+                    .map(|ignored| ignored.pr.id),
+            )
+            .chain(self.new_prs.iter().map(|pr| pr.pr.id))
+            .collect();
+        let ctx = LoadingContext::from_app(app);
+        let (tx, rx) = oneshot::channel();
+        tokio::spawn(async move {
+            let _ = tx.send(fetch_new_pull_requests(ctx, known_ids).await);
+        });
+        self.refresh_task = Some(rx);
+    }
+
+    /// Opens the large selection confirmation and sums the selected PRs'
+    /// diff size in the background.
+    fn open_large_selection_confirm(&mut self, app: &MergeApp) {
+        let Some(repo) = app.local_repo().map(std::path::PathBuf::from) else {
+            self.large_selection_confirm = Some(SelectionDiffSize::Unavailable(
+                "needs local_repo".to_string(),
+            ));
+            return;
+        };
+        let commits: Vec<String> = app
+            .get_selected_prs()
+            .iter()
+            .filter_map(|pr| pr.pr.last_merge_commit.as_ref())
+            .map(|commit| commit.commit_id.clone())
+            .collect();
+        let (tx, rx) = oneshot::channel();
+        tokio::task::spawn_blocking(move || {
+            let _ = tx.send(git::diff_stat(&repo, &commits));
+        });
+        self.large_selection_confirm = Some(SelectionDiffSize::Loading(rx));
+    }
+
+    /// Collects the diff size for the large selection confirmation once
+    /// summed. Called on every tick.
+    fn poll_selection_diff_size(&mut self) {
+        let Some(SelectionDiffSize::Loading(receiver)) = self.large_selection_confirm.as_mut()
+        else {
+            return;
+        };
+        let size = match receiver.try_recv() {
+            Ok(Ok(stat)) => SelectionDiffSize::Ready(stat),
+            Ok(Err(e)) => SelectionDiffSize::Unavailable(format!("{:#}", e)),
+            Err(oneshot::error::TryRecvError::Empty) => return,
+            Err(oneshot::error::TryRecvError::Closed) => {
+                SelectionDiffSize::Unavailable("diff size task stopped".to_string())
+            }
+        };
+        self.large_selection_confirm = Some(size);
+    }
+
+    /// Appends PRs found by auto-refresh to the end of the table.
+    ///
+    /// Existing rows keep their positions, so selections, the cursor and
+    /// search results stay valid.
+    fn add_new_prs(&mut self, app: &mut MergeApp) {
+        if self.new_prs.is_empty() {
+            return;
+        }
+        app.pull_requests_mut().append(&mut self.new_prs);
+        app.extend_build_checks(std::mem::take(&mut self.new_build_checks));
+        self.init_work_item_index(app);
+        self.initialize_selection(app);
+        self.update_scrollbar_state(app.pull_requests().len());
+    }
+
+    /// Selects the PRs listed in a selection file (see [`SelectionFile`]).
+    ///
+    /// The outcome, including ids not found among the candidates, is shown
+    /// on the PR table's bottom border.
+    pub fn import_selection(&mut self, app: &mut MergeApp, path: &Path) {
+        self.import_input = path.display().to_string();
+        self.import_message = Some(match SelectionFile::load(path) {
+            Ok(file) => {
+                let import = file.apply(app.pull_requests_mut());
+                app.deselect_excluded_prs();
+                let color = if import.not_found.is_empty() {
+                    Color::Green
+                } else {
+                    Color::Yellow
+                };
+                (import.describe(), color)
+            }
+            Err(e) => (format!("{:#}", e), Color::Red),
+        });
+    }
+
+    /// Restores the PR selection saved in the session file (see
+    /// [`SelectionSession`]).
+    ///
+    /// A saved PR filter replaces the configured one unless `--author`,
+    /// `--reviewer` or `--path-glob` were given. Returns `true` if it has
+    /// new identity ids, in which case the PR list must be reloaded before
+    /// the rest is restored. Sessions of another repository or that cannot
+    /// be read are reported on the table border and not saved over.
+    pub fn restore_session(&mut self, app: &mut MergeApp) -> bool {
+        let Some(path) = app.session_path().map(Path::to_path_buf) else {
+            return false;
+        };
+        let name = app.session_name().unwrap_or_default().to_string();
+        let session = match SelectionSession::load(&path) {
+            Ok(Some(session)) => session,
+            Ok(None) => return false,
+            Err(e) => {
+                self.import_message = Some((format!("{:#}", e), Color::Red));
+                app.set_session_path(None);
+                return false;
+            }
+        };
+        let repository = last_run_key(app.organization(), app.project(), app.repository());
+        if session.repository != repository {
+            self.import_message = Some((
+                format!(
+                    "Session '{}' belongs to {}; not restored",
+                    name, session.repository
+                ),
+                Color::Yellow,
+            ));
+            app.set_session_path(None);
+            return false;
+        }
+
+        if app.config.pr_filter.is_empty()
+            && let Ok(filter) = session.pr_filter.parse::<PrFilter>()
+            && app.set_pr_filter(filter)
+        {
+            return true;
+        }
+        let saved: HashSet<i32> = session.selected_pr_ids.iter().copied().collect();
+        let mut restored = 0;
+        for pr_with_wi in app.pull_requests_mut() {
+            if saved.contains(&pr_with_wi.pr.id) {
+                pr_with_wi.selected = true;
+                restored += 1;
+            }
+        }
+        app.deselect_excluded_prs();
+        app.set_pick_order(session.pick_order.clone());
+        self.selected_filter_states = session.filter_states.iter().cloned().collect();
+        if !session.search_query.is_empty() {
+            self.search_input = session.search_query.clone();
+            self.execute_search(app);
+        }
+
+        let noun = if restored == 1 { "PR" } else { "PRs" };
+        let missing = saved.len() - restored;
+        self.import_message = Some(if missing == 0 {
+            (
+                format!(
+                    "Restored session '{}': {} {} selected",
+                    name, restored, noun
+                ),
+                Color::Green,
+            )
+        } else {
+            (
+                format!(
+                    "Restored session '{}': {} {} selected, {} no longer listed",
+                    name, restored, noun, missing
+                ),
+                Color::Yellow,
+            )
+        });
+        self.saved_session = Some(session);
+        false
+    }
+
+    /// Returns the current PR selection as a session snapshot.
+    fn session_snapshot(&self, app: &MergeApp) -> SelectionSession {
+        let mut filter_states: Vec<String> = self.selected_filter_states.iter().cloned().collect();
+        filter_states.sort();
+        SelectionSession {
+            repository: last_run_key(app.organization(), app.project(), app.repository()),
+            saved_at: app.clock().now(),
+            selected_pr_ids: app
+                .pull_requests()
+                .iter()
+                .filter(|pr| pr.selected)
+                .map(|pr| pr.pr.id)
+                .collect(),
+            pick_order: app.pick_order().to_vec(),
+            search_query: self.last_search_query.clone(),
+            filter_states,
+            pr_filter: app.pr_filter().to_string(),
+        }
+    }
+
+    /// Saves the PR selection to the session file if it changed since the
+    /// last save. Called after every key and on every tick.
+    fn save_session(&mut self, app: &MergeApp) {
+        let Some(path) = app.session_path() else {
+            return;
+        };
+        let session = self.session_snapshot(app);
+        if self
+            .saved_session
+            .as_ref()
+            .is_some_and(|saved| saved.same_selection(&session))
+        {
+            return;
+        }
+        if let Err(e) = session.save(path) {
+            tracing::warn!("Failed to save session: {:#}", e);
+            self.import_message = Some((format!("{:#}", e), Color::Red));
+        }
+        // Failed saves are not retried until the selection changes again
+        self.saved_session = Some(session);
+    }
+
+    /// Uses the path typed in the local repository prompt.
+    ///
+    /// An existing repository is used right away. A missing or empty
+    /// directory gets a clone of the dev branch in the background, used once
+    /// [`Self::poll_clone`] finds it finished. Anything else is reported in
+    /// the prompt.
+    pub fn use_local_repo(&mut self, app: &mut MergeApp, path: &Path) {
+        self.local_repo_input = path.display().to_string();
+        if git::is_repository(path) {
+            self.local_repo_mode = false;
+            self.local_repo_message = None;
+            app.set_local_repo(path);
+            return;
+        }
+        let is_empty =
+            std::fs::read_dir(path).map_or(!path.exists(), |mut entries| entries.next().is_none());
+        if !is_empty {
+            self.local_repo_message = Some((
+                format!(
+                    "{} is neither a git repository nor an empty directory",
+                    path.display()
+                ),
+                Color::Red,
+            ));
+            return;
+        }
+
+        let client = app.client().clone();
+        let branch = app.dev_branch().to_string();
+        let target = path.to_path_buf();
+        let (tx, rx) = oneshot::channel();
+        tokio::spawn(async move {
+            let result = async {
+                let details = client.fetch_repo_details().await?;
+                let clone_path = target.clone();
+                tokio::task::spawn_blocking(move || {
+                    git::shallow_clone_repo_into(&details.ssh_url, &branch, &clone_path)
+                })
+                .await??;
+                Ok(target)
+            }
+            .await;
+            let _ = tx.send(result);
+        });
+        self.clone_task = Some(rx);
+        self.local_repo_mode = false;
+        self.local_repo_message = Some((
+            format!("Cloning {} into {}…", app.repository(), path.display()),
+            Color::Cyan,
+        ));
+    }
+
+    /// Switches to the cloned repository once the clone has finished.
+    /// Called on every tick.
+    fn poll_clone(&mut self, app: &mut MergeApp) {
+        let Some(receiver) = self.clone_task.as_mut() else {
+            return;
+        };
+        let result = match receiver.try_recv() {
+            Ok(result) => result,
+            Err(oneshot::error::TryRecvError::Empty) => return,
+            Err(oneshot::error::TryRecvError::Closed) => {
+                Err(anyhow::anyhow!("Clone task stopped unexpectedly"))
+            }
+        };
+        self.clone_task = None;
+        match result {
+            Ok(path) => {
+                self.local_repo_message = None;
+                app.set_local_repo(&path);
+            }
+            Err(e) => {
+                self.local_repo_message = Some((format!("Clone failed: {:#}", e), Color::Red));
+            }
+        }
+    }
+
+    fn enter_import_mode(&mut self, app: &MergeApp) {
+        self.import_mode = true;
+        self.import_message = None;
+        if self.import_input.is_empty()
+            && let Some(path) = app.select_file()
+        {
+            self.import_input = path.display().to_string();
+        }
+    }
+
+    fn enter_filter_mode(&mut self, app: &MergeApp) {
+        self.filter_mode = true;
+        self.filter_input = app.pr_filter().to_string();
+        self.filter_error = None;
+    }
+
+    /// Applies the filter typed in the filter prompt.
+    ///
+    /// A new author or reviewer identity id is filtered by Azure DevOps, so
+    /// the PR list is reloaded. Invalid filters are reported in the prompt.
+    fn apply_filter(&mut self, app: &mut MergeApp) -> StateChange<MergeState> {
+        let filter = match self.filter_input.parse::<PrFilter>() {
+            Ok(filter) => filter,
+            Err(e) => {
+                self.filter_error = Some(e.to_string());
+                return StateChange::Keep;
+            }
+        };
+        self.filter_mode = false;
+        if app.set_pr_filter(filter) {
+            return StateChange::Change(MergeState::DataLoading(DataLoadingState::new()));
+        }
+        self.tab_table_state = TableState::default();
+        self.keep_highlight_visible(app);
+        if self.search_iteration_mode {
+            self.exit_search_mode();
+        }
+        StateChange::Keep
+    }
+
     fn update_scrollbar_state(&mut self, total_items: usize) {
         self.scrollbar_state = self
             .scrollbar_state
@@ -227,6 +706,13 @@ impl PullRequestSelectionState {
             }
         }
 
+        // Only PRs shown in the table can be jumped to, in table order
+        let visible = self.visible_pr_indices(app);
+        self.search_results = visible
+            .into_iter()
+            .filter(|index| self.search_results.contains(index))
+            .collect();
+
         if self.search_results.is_empty() {
             self.search_error_message = Some("No matching items found".to_string());
         } else {
@@ -238,13 +724,18 @@ impl PullRequestSelectionState {
         }
     }
 
-    fn navigate_search_results(&mut self, direction: i32) {
+    fn navigate_search_results(&mut self, app: &MergeApp, direction: i32) {
         if self.search_results.is_empty() || !self.search_iteration_mode {
             return;
         }
 
+        // Compare rows by their position in the (possibly sorted) table
+        let visible = self.visible_pr_indices(app);
+        let row_of = |index: usize| visible.iter().position(|&i| i == index);
+
         // Find the current selection in the search results
         let current_table_selection = self.table_state.selected().unwrap_or(0);
+        let current_row = row_of(current_table_selection).unwrap_or(0);
         let current_search_pos = self
             .search_results
             .iter()
@@ -272,7 +763,7 @@ impl PullRequestSelectionState {
                 match self
                     .search_results
                     .iter()
-                    .position(|&idx| idx > current_table_selection)
+                    .position(|&idx| row_of(idx).is_some_and(|row| row > current_row))
                 {
                     Some(pos) => pos,
                     None => {
@@ -285,7 +776,7 @@ impl PullRequestSelectionState {
                 match self
                     .search_results
                     .iter()
-                    .rposition(|&idx| idx < current_table_selection)
+                    .rposition(|&idx| row_of(idx).is_some_and(|row| row < current_row))
                 {
                     Some(pos) => pos,
                     None => {
@@ -320,93 +811,210 @@ impl PullRequestSelectionState {
     }
 
     fn initialize_selection(&mut self, app: &MergeApp) {
-        if !app.pull_requests().is_empty() && self.table_state.selected().is_none() {
-            self.table_state.select(Some(0));
+        if self.table_state.selected().is_none() {
+            let first = self.visible_pr_indices(app).first().copied();
+            if first.is_some() {
+                self.table_state.select(first);
+            }
         }
         self.update_scrollbar_state(app.pull_requests().len());
     }
 
-    fn next(&mut self, app: &MergeApp) {
-        if app.pull_requests().is_empty() {
-            return;
+    /// Returns the active PR tab, or `None` when all PRs are shown.
+    fn active_pr_tab<'a>(&self, app: &'a MergeApp) -> Option<&'a PrTab> {
+        self.active_tab
+            .checked_sub(1)
+            .and_then(|index| app.pr_tabs().get(index))
+    }
+
+    /// Returns the indices of the PRs shown in the active tab, in table order.
+    ///
+    /// Terminal PRs are left out unless revealed with 'h', and so are PRs
+    /// outside the PR filter. Rows follow the
+    /// sort keys of the table layout, ties keep the loaded order.
+    fn visible_pr_indices(&self, app: &MergeApp) -> Vec<usize> {
+        let prs = app.pull_requests();
+        let tab = self.active_pr_tab(app);
+        let mut visible: Vec<usize> = (0..prs.len())
+            .filter(|&index| tab.is_none_or(|tab| pr_in_tab(app, tab, index)))
+            .filter(|&index| !self.is_hidden(app, &prs[index]))
+            .collect();
+
+        let keys = app.pr_table_layout().sort_keys();
+        if !keys.is_empty() {
+            let pick_positions = app.pick_positions();
+            visible.sort_by(|&a, &b| {
+                keys.iter()
+                    .map(|key| {
+                        let ordering =
+                            compare_prs(app, &pick_positions, key.column, &prs[a], &prs[b]);
+                        if key.descending {
+                            ordering.reverse()
+                        } else {
+                            ordering
+                        }
+                    })
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(Ordering::Equal)
+            });
+        }
+        visible
+    }
+
+    /// Returns true if no overlay or dialog takes the keyboard.
+    fn table_has_focus(&self) -> bool {
+        !(self.search_mode
+            || self.import_mode
+            || self.filter_mode
+            || self.local_repo_mode
+            || self.multi_select_mode
+            || self.show_dependency_dialog
+            || self.show_settings_dialog
+            || self.large_selection_confirm.is_some())
+    }
+
+    /// Applies a change to the table layout and saves it.
+    fn change_layout(&mut self, app: &mut MergeApp, change: impl FnOnce(&mut PrTableLayout)) {
+        change(app.pr_table_layout_mut());
+        if let Err(e) = app.save_pr_table_layout() {
+            app.error_message = Some(format!("Failed to save table layout: {}", e));
+        }
+        if self.search_iteration_mode {
+            self.exit_search_mode();
         }
-        let i = match self.table_state.selected() {
-            Some(i) => {
-                if i >= app.pull_requests().len() - 1 {
-                    0
-                } else {
-                    i + 1
-                }
-            }
-            None => 0,
-        };
-        self.table_state.select(Some(i));
-        self.work_item_index = 0; // Reset work item selection when PR changes
-        self.update_scrollbar_state(app.pull_requests().len());
     }
 
-    fn next_no_wrap(&mut self, app: &MergeApp) {
-        if app.pull_requests().is_empty() {
-            return;
+    /// Widens (`delta > 0`) or narrows the focused column.
+    fn resize_focused_column(&mut self, app: &mut MergeApp, delta: i16) {
+        let column = self.focused_column;
+        let current = self
+            .column_widths
+            .get(column.index())
+            .copied()
+            .unwrap_or_default();
+        self.change_layout(app, |layout| layout.resize(column, current, delta));
+    }
+
+    /// Returns true if the PR is terminal and terminal PRs are hidden, or if
+    /// it does not pass the PR filter.
+    ///
+    /// Hidden PRs are left out of the table and of bulk selection.
+    fn is_hidden(&self, app: &MergeApp, pr: &PullRequestWithWorkItems) -> bool {
+        (!self.show_terminal && app.is_terminal(pr)) || !pr_passes_filter(app, pr)
+    }
+
+    /// Moves the highlight to the first visible PR if the highlighted PR is
+    /// no longer shown.
+    fn keep_highlight_visible(&mut self, app: &MergeApp) {
+        let visible = self.visible_pr_indices(app);
+        let keep = self
+            .table_state
+            .selected()
+            .is_some_and(|selected| visible.contains(&selected));
+        if !keep {
+            self.table_state.select(visible.first().copied());
+            self.work_item_index = 0;
         }
-        let i = match self.table_state.selected() {
-            Some(i) => {
-                if i >= app.pull_requests().len() - 1 {
-                    return;
-                } else {
-                    i + 1
-                }
-            }
-            None => 0,
-        };
-        self.table_state.select(Some(i));
-        self.work_item_index = 0;
-        self.update_scrollbar_state(app.pull_requests().len());
     }
 
-    fn previous(&mut self, app: &MergeApp) {
-        if app.pull_requests().is_empty() {
+    /// Shows or hides PRs whose work items are all in a terminal state.
+    fn toggle_terminal(&mut self, app: &MergeApp) {
+        self.show_terminal = !self.show_terminal;
+        self.tab_table_state = TableState::default();
+        self.keep_highlight_visible(app);
+        if self.search_iteration_mode {
+            self.exit_search_mode();
+        }
+    }
+
+    /// Switches to the next (`1`) or previous (`-1`) PR tab, wrapping around.
+    ///
+    /// The highlight stays on the current PR if the new tab shows it, and
+    /// moves to the tab's first PR otherwise.
+    fn switch_tab(&mut self, app: &MergeApp, direction: isize) {
+        let tab_count = app.pr_tabs().len() + 1;
+        if tab_count == 1 {
             return;
         }
-        let i = match self.table_state.selected() {
-            Some(i) => {
-                if i == 0 {
-                    app.pull_requests().len() - 1
-                } else {
-                    i - 1
-                }
-            }
-            None => 0,
+        self.active_tab =
+            (self.active_tab as isize + direction).rem_euclid(tab_count as isize) as usize;
+        self.tab_table_state = TableState::default();
+        self.keep_highlight_visible(app);
+        if self.search_iteration_mode {
+            self.exit_search_mode();
+        }
+    }
+
+    /// Moves the highlight by one visible row, wrapping or stopping at the ends.
+    fn step(&mut self, app: &MergeApp, forward: bool, wrap: bool) {
+        let visible = self.visible_pr_indices(app);
+        let (Some(&first), Some(&last)) = (visible.first(), visible.last()) else {
+            return;
+        };
+        let position = self
+            .table_state
+            .selected()
+            .and_then(|selected| visible.iter().position(|&index| index == selected));
+        let i = match position {
+            Some(position) if forward => match visible.get(position + 1) {
+                Some(&next) => next,
+                None if wrap => first,
+                None => return,
+            },
+            Some(0) if wrap => last,
+            Some(0) => return,
+            Some(position) => visible[position - 1],
+            None => first,
         };
         self.table_state.select(Some(i));
         self.work_item_index = 0; // Reset work item selection when PR changes
         self.update_scrollbar_state(app.pull_requests().len());
     }
 
+    fn next(&mut self, app: &MergeApp) {
+        self.step(app, true, true);
+    }
+
+    fn next_no_wrap(&mut self, app: &MergeApp) {
+        self.step(app, true, false);
+    }
+
+    fn previous(&mut self, app: &MergeApp) {
+        self.step(app, false, true);
+    }
+
     fn previous_no_wrap(&mut self, app: &MergeApp) {
-        if app.pull_requests().is_empty() {
+        self.step(app, false, false);
+    }
+
+    fn toggle_selection(&mut self, app: &mut MergeApp) {
+        let Some(i) = self.table_state.selected() else {
             return;
-        }
-        let i = match self.table_state.selected() {
-            Some(i) => {
-                if i == 0 {
-                    return;
-                } else {
-                    i - 1
-                }
-            }
-            None => 0,
         };
-        self.table_state.select(Some(i));
-        self.work_item_index = 0;
-        self.update_scrollbar_state(app.pull_requests().len());
+        // PRs failing required build checks cannot be selected
+        if let Some(pr) = app.pull_requests().get(i)
+            && !pr.selected
+            && app.excluded_by_build_checks(pr)
+        {
+            let label = app.build_checks().label(pr.pr.id).unwrap_or_default();
+            self.import_message = Some((
+                format!("PR #{} needs a green build: {}", pr.pr.id, label),
+                Color::Red,
+            ));
+            return;
+        }
+        if let Some(pr) = app.pull_requests_mut().get_mut(i) {
+            pr.selected = !pr.selected;
+        }
     }
 
-    fn toggle_selection(&mut self, app: &mut MergeApp) {
+    /// Moves the highlighted PR one place earlier or later in the cherry-pick
+    /// order, if it is selected.
+    fn move_in_pick_order(&mut self, app: &mut MergeApp, earlier: bool) {
         if let Some(i) = self.table_state.selected()
-            && let Some(pr) = app.pull_requests_mut().get_mut(i)
+            && let Some(pr_id) = app.pull_requests().get(i).map(|pr| pr.pr.id)
         {
-            pr.selected = !pr.selected;
+            app.move_in_pick_order(pr_id, earlier);
         }
     }
 
@@ -427,17 +1035,23 @@ impl PullRequestSelectionState {
             pr.selected = true;
         }
 
-        // Select all related unselected PRs
+        // Select all related unselected PRs that pass the release gate and are shown
         if let Some(ref index) = self.work_item_pr_index {
             let related_indices = index.get_related_pr_indices(highlighted_index);
             for pr_index in related_indices {
+                let passes_gate = app
+                    .pull_requests()
+                    .get(pr_index)
+                    .is_some_and(|pr| app.passes_release_gate(pr) && !self.is_hidden(app, pr));
                 if let Some(pr) = app.pull_requests_mut().get_mut(pr_index)
                     && !pr.selected
+                    && passes_gate
                 {
                     pr.selected = true;
                 }
             }
         }
+        app.deselect_excluded_prs();
     }
 
     /// Select all unselected PRs that share work items with any currently selected PRs.
@@ -469,6 +1083,14 @@ impl PullRequestSelectionState {
             }
         }
 
+        // Drop PRs failing the release gate and hidden terminal PRs; they can
+        // still be selected manually
+        to_select.retain(|&i| {
+            app.pull_requests()
+                .get(i)
+                .is_some_and(|pr| app.passes_release_gate(pr) && !self.is_hidden(app, pr))
+        });
+
         // Select all related unselected PRs
         for pr_index in to_select {
             if let Some(pr) = app.pull_requests_mut().get_mut(pr_index)
@@ -477,6 +1099,7 @@ impl PullRequestSelectionState {
                 pr.selected = true;
             }
         }
+        app.deselect_excluded_prs();
     }
 
     fn next_work_item(&mut self, app: &MergeApp) {
@@ -522,8 +1145,14 @@ impl PullRequestSelectionState {
             return;
         }
 
-        for pr in app.pull_requests_mut() {
-            if pr.work_items.is_empty() {
+        let gate = app.release_gate().cloned();
+        let hidden: Vec<bool> = app
+            .pull_requests()
+            .iter()
+            .map(|pr| self.is_hidden(app, pr))
+            .collect();
+        for (pr, hidden) in app.pull_requests_mut().iter_mut().zip(hidden) {
+            if pr.work_items.is_empty() || hidden {
                 continue;
             }
 
@@ -534,9 +1163,11 @@ impl PullRequestSelectionState {
                     false
                 }
             });
+            let passes_gate = gate.as_ref().is_none_or(|gate| gate.pr_passes(pr));
 
-            pr.selected = all_work_items_match;
+            pr.selected = all_work_items_match && passes_gate;
         }
+        app.deselect_excluded_prs();
     }
 
     fn clear_all_selections(&self, app: &mut MergeApp) {
@@ -586,34 +1217,155 @@ impl PullRequestSelectionState {
         }
     }
 
-    fn render_work_item_details(&self, f: &mut Frame, app: &MergeApp, area: ratatui::layout::Rect) {
-        if let Some(pr_index) = self.table_state.selected() {
-            if let Some(pr) = app.pull_requests().get(pr_index) {
-                if pr.work_items.is_empty() {
-                    let no_items =
-                        Paragraph::new("No work items associated with this pull request.")
-                            .style(Style::default().fg(Color::Gray))
-                            .block(
-                                Block::default()
-                                    .borders(Borders::ALL)
-                                    .title("Work Item Details"),
-                            )
-                            .alignment(Alignment::Center);
-                    f.render_widget(no_items, area);
-                    return;
-                }
+    /// Renders the selection summary side panel with live totals.
+    fn render_selection_summary(&self, f: &mut Frame, app: &MergeApp, area: Rect) {
+        let summary = summarize_selection(app.pull_requests(), app.dependency_graph());
+        let label_style = Style::default().fg(Color::Gray);
+        let value_style = Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD);
+        let risk_color = match summary.conflict_risk {
+            ConflictRisk::Unknown => Color::DarkGray,
+            ConflictRisk::Low => Color::Green,
+            ConflictRisk::Medium => Color::Yellow,
+            ConflictRisk::High => Color::Red,
+        };
 
-                // Ensure work_item_index is within bounds
-                let work_item_index = if self.work_item_index < pr.work_items.len() {
-                    self.work_item_index
-                } else {
-                    0
-                };
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled("PRs:           ", label_style),
+                Span::styled(summary.pr_count.to_string(), value_style),
+            ]),
+            Line::from(vec![
+                Span::styled("Work items:    ", label_style),
+                Span::styled(summary.work_item_count.to_string(), value_style),
+            ]),
+            Line::from(vec![
+                Span::styled("Files:         ", label_style),
+                Span::styled(
+                    summary
+                        .files_touched
+                        .map_or_else(|| "-".to_string(), |n| n.to_string()),
+                    value_style,
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Conflict risk: ", label_style),
+                Span::styled(
+                    summary.conflict_risk.label(),
+                    Style::default().fg(risk_color).add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(""),
+        ];
 
-                if let Some(work_item) = pr.work_items.get(work_item_index) {
-                    // Create layout for header, history, and content
-                    let chunks = Layout::default()
-                        .direction(Direction::Vertical)
+        if summary.missing_dependencies.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No missing dependencies",
+                Style::default().fg(Color::DarkGray),
+            )));
+        } else {
+            lines.push(Line::from(Span::styled(
+                format!("Missing deps ({}):", summary.missing_dependencies.len()),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            for pr_id in &summary.missing_dependencies {
+                let title = app
+                    .pull_requests()
+                    .iter()
+                    .find(|pr| pr.pr.id == *pr_id)
+                    .map(|pr| pr.pr.title.as_str())
+                    .unwrap_or_default();
+                lines.push(Line::from(vec![
+                    Span::styled(format!("#{} ", pr_id), Style::default().fg(Color::Yellow)),
+                    Span::raw(title.to_string()),
+                ]));
+            }
+        }
+
+        let panel = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title("Selection"))
+            .wrap(Wrap { trim: true });
+        f.render_widget(panel, area);
+    }
+
+    fn render_api_diagnostics(&self, f: &mut Frame, app: &MergeApp, area: Rect) {
+        let warnings = app.schema_warnings();
+        let mut lines = Vec::new();
+        if warnings.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "Responses match the expected schema",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        for warning in warnings {
+            let color = if warning.affects_data() {
+                Color::Yellow
+            } else {
+                Color::DarkGray
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{}: ", warning.endpoint),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(warning.message.clone()),
+            ]));
+        }
+        if let Some(dir) = crate::api::schema::raw_response_dump_dir() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!("Responses stored in {}", dir.display()),
+                Style::default().fg(Color::Gray),
+            )));
+        } else if !warnings.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Run with --dump-raw-response <DIR> to store the responses",
+                Style::default().fg(Color::Gray),
+            )));
+        }
+
+        let panel = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("API Diagnostics"),
+            )
+            .wrap(Wrap { trim: true });
+        f.render_widget(panel, area);
+    }
+
+    fn render_work_item_details(&self, f: &mut Frame, app: &MergeApp, area: ratatui::layout::Rect) {
+        if let Some(pr_index) = self.table_state.selected() {
+            if let Some(pr) = app.pull_requests().get(pr_index) {
+                if pr.work_items.is_empty() {
+                    let no_items =
+                        Paragraph::new("No work items associated with this pull request.")
+                            .style(Style::default().fg(Color::Gray))
+                            .block(
+                                Block::default()
+                                    .borders(Borders::ALL)
+                                    .title("Work Item Details"),
+                            )
+                            .alignment(Alignment::Center);
+                    f.render_widget(no_items, area);
+                    return;
+                }
+
+                // Ensure work_item_index is within bounds
+                let work_item_index = if self.work_item_index < pr.work_items.len() {
+                    self.work_item_index
+                } else {
+                    0
+                };
+
+                if let Some(work_item) = pr.work_items.get(work_item_index) {
+                    // Create layout for header, history, and content
+                    let chunks = Layout::default()
+                        .direction(Direction::Vertical)
                         .constraints([
                             Constraint::Length(4), // Header (2 lines + borders)
                             Constraint::Length(3), // History (1 line + borders)
@@ -654,6 +1406,34 @@ impl PullRequestSelectionState {
 
                     // Create header content with spans for different colors and proper alignment
                     use ratatui::text::{Line, Span};
+                    let mut second_line = vec![
+                        Span::styled(
+                            "●",
+                            Style::default()
+                                .fg(state_color)
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled(
+                            format!(" {:<15}", state), // Fixed width for state
+                            Style::default().fg(state_color),
+                        ),
+                        Span::styled(
+                            format!(" | Iteration: {}", iteration_path),
+                            Style::default().fg(Color::Gray),
+                        ),
+                        Span::styled(
+                            format!(" | Assigned: {}", assigned_to),
+                            Style::default().fg(Color::Yellow),
+                        ),
+                    ];
+                    if let Some(gate) = app.release_gate()
+                        && !gate.passes(work_item)
+                    {
+                        second_line.push(Span::styled(
+                            format!(" | ✗ {}", gate),
+                            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                        ));
+                    }
                     let header_lines = vec![
                         Line::from(vec![
                             Span::styled(
@@ -673,26 +1453,7 @@ impl PullRequestSelectionState {
                                     .add_modifier(Modifier::BOLD),
                             ),
                         ]),
-                        Line::from(vec![
-                            Span::styled(
-                                "●",
-                                Style::default()
-                                    .fg(state_color)
-                                    .add_modifier(Modifier::BOLD),
-                            ),
-                            Span::styled(
-                                format!(" {:<15}", state), // Fixed width for state
-                                Style::default().fg(state_color),
-                            ),
-                            Span::styled(
-                                format!(" | Iteration: {}", iteration_path),
-                                Style::default().fg(Color::Gray),
-                            ),
-                            Span::styled(
-                                format!(" | Assigned: {}", assigned_to),
-                                Style::default().fg(Color::Yellow),
-                            ),
-                        ]),
+                        Line::from(second_line),
                     ];
 
                     let header_widget = Paragraph::new(header_lines).block(
@@ -704,6 +1465,12 @@ impl PullRequestSelectionState {
                     );
 
                     f.render_widget(header_widget, chunks[0]);
+                    app.hyperlinks().link(
+                        f.buffer_mut(),
+                        chunks[0],
+                        &format!("#{}", work_item.id),
+                        &app.urls().work_item_url(work_item.id),
+                    );
 
                     // Render history section
                     self.render_work_item_history_linear(f, chunks[1], work_item);
@@ -782,6 +1549,58 @@ impl PullRequestSelectionState {
         }
     }
 
+    fn render_pr_description(&self, f: &mut Frame, app: &MergeApp, area: Rect) {
+        let Some(pr) = self
+            .table_state
+            .selected()
+            .and_then(|index| app.pull_requests().get(index))
+        else {
+            let no_selection = Paragraph::new("No pull request selected.")
+                .style(Style::default().fg(Color::Gray))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("PR Description"),
+                )
+                .alignment(Alignment::Center);
+            f.render_widget(no_selection, area);
+            return;
+        };
+
+        let title = format!("PR #{} Description (Tab: work items)", pr.pr.id);
+        let block = Block::default().borders(Borders::ALL).title(title);
+
+        match pr
+            .pr
+            .description
+            .as_deref()
+            .filter(|d| !d.trim().is_empty())
+        {
+            Some(description) => {
+                let description_widget = Paragraph::new(markdown_to_lines(description))
+                    .style(Style::default().fg(Color::White))
+                    .block(block)
+                    .wrap(Wrap { trim: false });
+                f.render_widget(description_widget, area);
+            }
+            None => {
+                let no_description = Paragraph::new("No description available.")
+                    .style(Style::default().fg(Color::Gray))
+                    .block(block)
+                    .alignment(Alignment::Center);
+                f.render_widget(no_description, area);
+            }
+        }
+
+        // The PR number is part of the block title
+        app.hyperlinks().link(
+            f.buffer_mut(),
+            Rect::new(area.x, area.y, area.width, 1),
+            &format!("#{}", pr.pr.id),
+            &app.urls().pull_request_url(pr.pr.id),
+        );
+    }
+
     fn render_work_item_history_linear(
         &self,
         f: &mut Frame,
@@ -1133,6 +1952,254 @@ impl PullRequestSelectionState {
         f.render_widget(help_widget, chunks[3]);
     }
 
+    /// Render the selection file path prompt
+    fn render_import_overlay(&self, f: &mut Frame, area: Rect) {
+        use ratatui::widgets::Clear;
+
+        let width = 70.min(area.width.saturating_sub(4));
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + area.height.saturating_sub(5) / 2,
+            width,
+            height: 5.min(area.height),
+        };
+        f.render_widget(Clear, popup_area);
+
+        let key_style = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let lines = vec![
+            Line::from(Span::styled(
+                self.import_input.as_str(),
+                Style::default().fg(Color::White),
+            )),
+            Line::from(""),
+            Line::from(vec![
+                Span::raw("One "),
+                Span::styled("!PR", key_style),
+                Span::raw(", "),
+                Span::styled("#WI", key_style),
+                Span::raw(" or PR id per line | "),
+                Span::styled("Enter", key_style),
+                Span::raw(": Import | "),
+                Span::styled("Esc", key_style),
+                Span::raw(": Cancel"),
+            ]),
+        ];
+        let prompt = Paragraph::new(lines)
+            .style(Style::default().bg(Color::Black))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan))
+                    .title("Import Selection From File"),
+            );
+        f.render_widget(prompt, popup_area);
+    }
+
+    /// Render the author, reviewer and path filter prompt
+    fn render_filter_overlay(&self, f: &mut Frame, area: Rect) {
+        use ratatui::widgets::Clear;
+
+        let width = 76.min(area.width.saturating_sub(4));
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + area.height.saturating_sub(6) / 2,
+            width,
+            height: 6.min(area.height),
+        };
+        f.render_widget(Clear, popup_area);
+
+        let key_style = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let lines = vec![
+            Line::from(Span::styled(
+                self.filter_input.as_str(),
+                Style::default().fg(Color::White),
+            )),
+            match &self.filter_error {
+                Some(error) => Line::from(Span::styled(
+                    error.as_str(),
+                    Style::default().fg(Color::Red),
+                )),
+                None => Line::from(""),
+            },
+            Line::from(vec![
+                Span::styled("author:", key_style),
+                Span::raw("NAME "),
+                Span::styled("reviewer:", key_style),
+                Span::raw("NAME "),
+                Span::styled("path:", key_style),
+                Span::raw("GLOB (quote names with spaces)"),
+            ]),
+            Line::from(vec![
+                Span::styled("Enter", key_style),
+                Span::raw(": Apply (empty clears) | "),
+                Span::styled("Esc", key_style),
+                Span::raw(": Cancel"),
+            ]),
+        ];
+        let prompt = Paragraph::new(lines)
+            .style(Style::default().bg(Color::Black))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan))
+                    .title("Filter Pull Requests"),
+            );
+        f.render_widget(prompt, popup_area);
+    }
+
+    /// Render the local repository path prompt
+    fn render_local_repo_overlay(&self, f: &mut Frame, area: Rect, app: &MergeApp) {
+        use ratatui::widgets::Clear;
+
+        let width = 70.min(area.width.saturating_sub(4));
+        let height = if self.local_repo_message.is_some() {
+            7
+        } else {
+            6
+        };
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + area.height.saturating_sub(height) / 2,
+            width,
+            height: height.min(area.height),
+        };
+        f.render_widget(Clear, popup_area);
+
+        let key_style = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let mut lines = vec![
+            Line::from(Span::styled(
+                self.local_repo_input.as_str(),
+                Style::default().fg(Color::White),
+            )),
+            Line::from(""),
+            Line::from(format!(
+                "A clone of {}, or an empty directory to clone into.",
+                app.repository()
+            )),
+            Line::from(vec![
+                Span::styled("Enter", key_style),
+                Span::raw(": Use | "),
+                Span::styled("Esc", key_style),
+                Span::raw(": Cancel"),
+            ]),
+        ];
+        if let Some((message, color)) = &self.local_repo_message {
+            lines.push(Line::from(Span::styled(
+                message.as_str(),
+                Style::default().fg(*color),
+            )));
+        }
+        let prompt = Paragraph::new(lines)
+            .style(Style::default().bg(Color::Black))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan))
+                    .title("Set Local Repository"),
+            );
+        f.render_widget(prompt, popup_area);
+    }
+
+    /// Render the banner listing what is off without a local repository
+    fn render_degraded_banner(&self, f: &mut Frame, area: Rect) {
+        let key_style = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let line = match &self.local_repo_message {
+            Some((message, color)) if !self.local_repo_mode => Line::from(Span::styled(
+                format!(" {}", message),
+                Style::default().fg(*color),
+            )),
+            _ => Line::from(vec![
+                Span::styled(
+                    format!(
+                        " ⚠ No local repo, off: {} | ",
+                        LOCAL_REPO_FEATURES.join(", ")
+                    ),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled("L", key_style),
+                Span::raw(": set/clone"),
+            ]),
+        };
+        f.render_widget(Paragraph::new(line), area);
+    }
+
+    fn render_large_selection_overlay(&self, f: &mut Frame, area: Rect, app: &MergeApp) {
+        use ratatui::widgets::Clear;
+
+        let Some(size) = &self.large_selection_confirm else {
+            return;
+        };
+        let width = 70.min(area.width.saturating_sub(4));
+        let popup_area = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + area.height.saturating_sub(6) / 2,
+            width,
+            height: 6.min(area.height),
+        };
+        f.render_widget(Clear, popup_area);
+
+        let key_style = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let diff_line = match size {
+            SelectionDiffSize::Loading(_) => Line::from(vec![
+                Span::raw("Total diff: "),
+                Span::styled("calculating…", Style::default().fg(Color::Gray)),
+            ]),
+            SelectionDiffSize::Ready(stat) => Line::from(vec![
+                Span::raw(format!("Total diff: {} files changed, ", stat.files)),
+                Span::styled(
+                    format!("+{}", stat.insertions),
+                    Style::default().fg(Color::Green),
+                ),
+                Span::raw(" "),
+                Span::styled(
+                    format!("-{}", stat.deletions),
+                    Style::default().fg(Color::Red),
+                ),
+            ]),
+            SelectionDiffSize::Unavailable(reason) => Line::from(vec![
+                Span::raw("Total diff: "),
+                Span::styled(
+                    format!("unavailable ({})", reason),
+                    Style::default().fg(Color::Gray),
+                ),
+            ]),
+        };
+        let lines = vec![
+            Line::from(format!(
+                "{} PRs selected, more than the limit of {}.",
+                app.get_selected_prs().len(),
+                app.selection_soft_cap().unwrap_or_default()
+            )),
+            diff_line,
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Enter/y", key_style),
+                Span::raw(": Continue | "),
+                Span::styled("Esc/n", key_style),
+                Span::raw(": Back to selection"),
+            ]),
+        ];
+        let dialog = Paragraph::new(lines)
+            .style(Style::default().bg(Color::Black))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Yellow))
+                    .title("Large Selection"),
+            );
+        f.render_widget(dialog, popup_area);
+    }
+
     /// Render the settings overlay dialog
     fn render_settings_overlay(&self, f: &mut Frame, area: ratatui::layout::Rect, app: &MergeApp) {
         use ratatui::text::{Line, Span};
@@ -1279,6 +2346,88 @@ impl PullRequestSelectionState {
         f.render_widget(help_widget, chunks[help_idx]);
     }
 
+    /// Renders the PR tab bar with per-tab PR counts and the selection count
+    /// across all tabs.
+    fn render_tab_bar(&self, f: &mut Frame, app: &MergeApp, area: Rect) {
+        let prs = app.pull_requests();
+        let mut titles = vec![format!("All ({})", prs.len())];
+        titles.extend(app.pr_tabs().iter().map(|tab| {
+            let count = (0..prs.len())
+                .filter(|&index| pr_in_tab(app, tab, index))
+                .count();
+            format!("{} ({})", tab.name(), count)
+        }));
+
+        let selected_count = prs.iter().filter(|pr| pr.selected).count();
+        let summary = format!("{} selected | [/]: switch tab ", selected_count);
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Min(0),
+                Constraint::Length(summary.chars().count() as u16),
+            ])
+            .split(area);
+
+        let tabs = Tabs::new(titles)
+            .select(self.active_tab)
+            .style(Style::default().fg(Color::Gray))
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+            );
+        f.render_widget(tabs, columns[0]);
+        f.render_widget(
+            Paragraph::new(summary).style(Style::default().fg(Color::Green)),
+            columns[1],
+        );
+    }
+
+    /// Links the PR and work item ids of the rows on screen to their pages.
+    fn link_table_rows(&self, f: &mut Frame, app: &MergeApp, visible: &[usize]) {
+        let hyperlinks = app.hyperlinks();
+        let Some(area) = self.table_area.filter(|_| hyperlinks.enabled) else {
+            return;
+        };
+        let offset = if self.rows_by_position {
+            self.tab_table_state.offset()
+        } else {
+            self.table_state.offset()
+        };
+        // Columns start after the left border and the "→ " highlight symbol
+        let column_area = |column: PrColumn, y: u16| {
+            let x = area.x
+                + 3
+                + self.column_widths[..column.index()]
+                    .iter()
+                    .map(|width| width + 1)
+                    .sum::<u16>();
+            Rect::new(x, y, self.column_widths[column.index()], 1)
+        };
+        let urls = app.urls();
+        let buffer = f.buffer_mut();
+        let rows = area.height.saturating_sub(3);
+        for (row, &pr_index) in visible.iter().skip(offset).take(rows as usize).enumerate() {
+            let pr = &app.pull_requests()[pr_index];
+            let y = area.y + 2 + row as u16;
+            hyperlinks.link(
+                buffer,
+                column_area(PrColumn::Id, y),
+                &pr.pr.id.to_string(),
+                &urls.pull_request_url(pr.pr.id),
+            );
+            let work_items_area = column_area(PrColumn::WorkItems, y);
+            for work_item in &pr.work_items {
+                hyperlinks.link(
+                    buffer,
+                    work_items_area,
+                    &format!("#{}", work_item.id),
+                    &urls.work_item_url(work_item.id),
+                );
+            }
+        }
+    }
+
     /// Convert mouse y-coordinate to table row index
     fn mouse_y_to_row(&self, y: u16, pr_count: usize) -> Option<usize> {
         let area = self.table_area?;
@@ -1295,7 +2444,11 @@ impl PullRequestSelectionState {
         let row = (y - first_row_y) as usize;
 
         // Account for table scroll offset
-        let offset = self.table_state.offset();
+        let offset = if self.rows_by_position {
+            self.tab_table_state.offset()
+        } else {
+            self.table_state.offset()
+        };
         let actual_row = row + offset;
 
         if actual_row < pr_count {
@@ -1522,7 +2675,7 @@ impl PullRequestSelectionState {
                 Style::default().fg(Color::Yellow),
             )));
             lines.push(Line::from(Span::styled(
-                "(Requires local_repo to be configured)",
+                "(Requires local_repo; press L to set one)",
                 Style::default().fg(Color::DarkGray),
             )));
         }
@@ -1538,11 +2691,14 @@ impl PullRequestSelectionState {
             .collect();
 
         // Render the dialog
-        let title = format!(
+        let mut title = format!(
             "Dependencies for PR #{} - {}",
             pr_id,
             truncate_title(pr_title, 40)
         );
+        if app.is_refining_dependencies() {
+            title.push_str(" (file-level, refining…)");
+        }
         let dialog = Paragraph::new(visible_lines)
             .block(
                 Block::default()
@@ -1826,6 +2982,8 @@ impl ModeState for PullRequestSelectionState {
     type Mode = MergeState;
 
     fn ui(&mut self, f: &mut Frame, app: &MergeApp) {
+        self.tab_count = app.pr_tabs().len();
+
         // Initialize selection if not already set
         self.initialize_selection(app);
 
@@ -1906,23 +3064,24 @@ impl ModeState for PullRequestSelectionState {
             self.render_search_status(f, chunks[chunk_idx]);
             chunk_idx += 1;
         }
-        // Create table headers
-        let header_cells = [
-            "",
-            "PR #",
-            "Date",
-            "Title",
-            "Author",
-            "Work Items",
-            "PR Dependencies",
-        ]
-        .iter()
-        .map(|h| {
-            Cell::from(*h).style(
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            )
+        // Create table headers, marking sort keys and the focused column
+        let layout = app.pr_table_layout();
+        let numbered = layout.sort_keys().len() > 1;
+        let header_cells = PrColumn::ALL.iter().map(|&column| {
+            let mut header = column.header().to_string();
+            if let Some((rank, descending)) = layout.sort_rank(column) {
+                header.push_str(if descending { " ▼" } else { " ▲" });
+                if numbered {
+                    header.push_str(&(rank + 1).to_string());
+                }
+            }
+            let mut style = Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD);
+            if column == self.focused_column {
+                style = style.add_modifier(Modifier::UNDERLINED);
+            }
+            Cell::from(header).style(style)
         });
         let header = Row::new(header_cells).height(1);
 
@@ -1947,12 +3106,13 @@ impl ModeState for PullRequestSelectionState {
         );
 
         // Create table rows
-        let rows: Vec<Row> = app
-            .pull_requests()
+        let visible = self.visible_pr_indices(app);
+        let pick_positions = app.pick_positions();
+        let mut rows: Vec<Row> = visible
             .iter()
-            .enumerate()
+            .map(|&pr_index| (pr_index, &app.pull_requests()[pr_index]))
             .map(|(pr_index, pr_with_wi)| {
-                let selected = if pr_with_wi.selected { "✓" } else { " " };
+                let fails_gate = !app.passes_release_gate(pr_with_wi);
 
                 let date = if let Some(closed_date) = &pr_with_wi.pr.closed_date {
                     if let Ok(date) = DateTime::parse_from_rfc3339(closed_date) {
@@ -1987,7 +3147,14 @@ impl ModeState for PullRequestSelectionState {
                 // Check if this PR is an unselected dependency (missing dependency warning)
                 let is_unselected_dep = unselected_deps.contains(&pr_with_wi.pr.id);
 
-                // Check if this PR is related to the highlighted PR (dependency graph)
+                let selected = app.accessibility().selection_marker(
+                    pr_with_wi.selected,
+                    fails_gate,
+                    is_unselected_dep,
+                    is_search_result,
+                );
+
+                // Check if this PR is related to the highlighted PR (dependency graph)
                 let highlighted_relationship =
                     highlighted_relationships.get(&pr_with_wi.pr.id).copied();
 
@@ -2076,7 +3243,9 @@ impl ModeState for PullRequestSelectionState {
                 let deps_cell = create_deps_cell(partial_deps, full_deps, pr_with_wi.selected);
 
                 let cells = vec![
-                    Cell::from(selected).style(if pr_with_wi.selected {
+                    Cell::from(selected).style(if fails_gate {
+                        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+                    } else if pr_with_wi.selected {
                         Style::default()
                             .fg(Color::White)
                             .add_modifier(Modifier::BOLD)
@@ -2085,6 +3254,18 @@ impl ModeState for PullRequestSelectionState {
                             .fg(Color::Green)
                             .add_modifier(Modifier::BOLD)
                     }),
+                    match pick_positions.get(&pr_with_wi.pr.id) {
+                        Some(position) => {
+                            Cell::from(position.to_string()).style(if app.has_manual_pick_order() {
+                                Style::default()
+                                    .fg(Color::Magenta)
+                                    .add_modifier(Modifier::BOLD)
+                            } else {
+                                Style::default().fg(Color::White)
+                            })
+                        }
+                        None => Cell::from(""),
+                    },
                     Cell::from(format!("{:<6}", pr_with_wi.pr.id)) // Left-aligned with fixed width
                         .style(if pr_with_wi.selected {
                             Style::default().fg(Color::White)
@@ -2096,11 +3277,34 @@ impl ModeState for PullRequestSelectionState {
                     } else {
                         Style::default()
                     }),
-                    Cell::from(pr_with_wi.pr.title.clone()).style(if pr_with_wi.selected {
-                        Style::default().fg(Color::White)
-                    } else {
-                        Style::default()
-                    }),
+                    {
+                        let title_style = if pr_with_wi.selected {
+                            Style::default().fg(Color::White)
+                        } else {
+                            Style::default()
+                        };
+                        let mut spans = Vec::new();
+                        if let Some(label) = app.revert_index().label(pr_with_wi.pr.id) {
+                            spans.push(Span::styled(
+                                format!("[↺ {}] ", label),
+                                Style::default()
+                                    .fg(Color::Magenta)
+                                    .add_modifier(Modifier::BOLD),
+                            ));
+                        }
+                        if let Some(label) = app.build_checks().label(pr_with_wi.pr.id) {
+                            spans.push(Span::styled(
+                                format!("[✗ {}] ", label),
+                                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                            ));
+                        }
+                        if spans.is_empty() {
+                            Cell::from(pr_with_wi.pr.title.clone()).style(title_style)
+                        } else {
+                            spans.push(Span::styled(pr_with_wi.pr.title.clone(), title_style));
+                            Cell::from(Line::from(spans))
+                        }
+                    },
                     Cell::from(pr_with_wi.pr.created_by.display_name.clone()).style(
                         if pr_with_wi.selected {
                             Style::default().fg(Color::White)
@@ -2120,39 +3324,214 @@ impl ModeState for PullRequestSelectionState {
             })
             .collect();
 
-        let table = Table::new(
-            rows,
-            vec![
-                Constraint::Length(3),      // Selection checkbox
-                Constraint::Length(8),      // PR # (fixed width)
-                Constraint::Length(12),     // Date
-                Constraint::Percentage(25), // Title
-                Constraint::Percentage(15), // Author
-                Constraint::Percentage(20), // Work Items
-                Constraint::Length(12),     // PR Dependencies (e.g., "2 P / 3 F")
-            ],
-        )
-        .header(header)
-        .block({
-            let title = if missing_deps_count > 0 {
-                format!("Pull Requests (⚠ {} missing deps)", missing_deps_count)
-            } else {
-                "Pull Requests".to_string()
-            };
-            let block = Block::default().borders(Borders::ALL).title(title);
-            if missing_deps_count > 0 {
-                block.border_style(Style::default().fg(Color::Yellow))
-            } else {
-                block
-            }
-        })
-        .row_highlight_style(Style::default().bg(Color::DarkGray))
-        .highlight_symbol("→ ");
+        let gate_failing_count = app
+            .pull_requests()
+            .iter()
+            .filter(|pr| !app.passes_release_gate(pr))
+            .count();
+        let terminal_count = app
+            .pull_requests()
+            .iter()
+            .filter(|pr| app.is_terminal(pr))
+            .count();
+        let reverted_count = app
+            .pull_requests()
+            .iter()
+            .filter(|pr| app.revert_index().reverted_by(pr.pr.id).is_some())
+            .count();
+        let build_failing_count = app
+            .pull_requests()
+            .iter()
+            .filter(|pr| app.fails_build_checks(pr))
+            .count();
+        let schema_warning_count = app
+            .schema_warnings()
+            .iter()
+            .filter(|warning| warning.affects_data())
+            .count();
+
+        // Append PRs excluded by .mergers-ignore after the selectable rows so
+        // that row indices keep matching `app.pull_requests()`
+        let ignored_count = app.ignored_pull_requests().len();
+        if self.show_ignored && self.active_tab == 0 {
+            let ignored_style = Style::default().fg(Color::DarkGray);
+            rows.extend(app.ignored_pull_requests().iter().map(|ignored| {
+                let date = ignored
+                    .pr
+                    .closed_date
+                    .as_deref()
+                    .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+                    .map(|d| d.format("%Y-%m-%d").to_string())
+                    .unwrap_or_else(|| "Active".to_string());
+                Row::new(vec![
+                    Cell::from("⊘"),
+                    Cell::from(""),
+                    Cell::from(format!("{:<6}", ignored.pr.id)),
+                    Cell::from(date),
+                    Cell::from(ignored.pr.title.clone()),
+                    Cell::from(ignored.pr.created_by.display_name.clone()),
+                    Cell::from(format!("[ignored] {}", ignored.reason)),
+                    Cell::from(""),
+                ])
+                .height(1)
+                .style(ignored_style.add_modifier(Modifier::DIM))
+            }));
+        }
+
+        let constraints = column_constraints(app.pr_table_layout());
+        let table = Table::new(rows, constraints.clone())
+            .header(header)
+            .block({
+                let mut title = if missing_deps_count > 0 {
+                    format!("Pull Requests (⚠ {} missing deps)", missing_deps_count)
+                } else {
+                    "Pull Requests".to_string()
+                };
+                if gate_failing_count > 0 {
+                    title.push_str(&format!(" (✗ {} fail release gate)", gate_failing_count));
+                }
+                if reverted_count > 0 {
+                    title.push_str(&format!(" (↺ {} reverted)", reverted_count));
+                }
+                if build_failing_count > 0 {
+                    title.push_str(&format!(" (✗ {} fail build checks)", build_failing_count));
+                }
+                if app.has_manual_pick_order() {
+                    title.push_str(" (manual pick order, U: reset)");
+                }
+                if !app.pr_filter().is_empty() {
+                    title.push_str(&format!(" (filter: {}, F: edit)", app.pr_filter()));
+                }
+                if let Some(name) = app.session_name().filter(|_| app.session_path().is_some()) {
+                    title.push_str(&format!(" (session: {})", name));
+                }
+                if app.is_refining_dependencies() {
+                    title.push_str(" (refining deps…)");
+                }
+                if ignored_count > 0 {
+                    let verb = if self.show_ignored { "hide" } else { "show" };
+                    title.push_str(&format!(" ({} ignored, x: {})", ignored_count, verb));
+                }
+                if terminal_count > 0 {
+                    let verb = if self.show_terminal { "hide" } else { "show" };
+                    title.push_str(&format!(" ({} terminal, h: {})", terminal_count, verb));
+                }
+                if schema_warning_count > 0 {
+                    let verb = if self.show_diagnostics {
+                        "hide"
+                    } else {
+                        "show"
+                    };
+                    title.push_str(&format!(
+                        " (⚠ {} API warnings, D: {})",
+                        schema_warning_count, verb
+                    ));
+                }
+                let mut block = Block::default().borders(Borders::ALL).title(title);
+                if !self.new_prs.is_empty() {
+                    let noun = if self.new_prs.len() == 1 { "PR" } else { "PRs" };
+                    block = block.title_bottom(
+                        Line::from(Span::styled(
+                            format!(" {} new {} (a: add) ", self.new_prs.len(), noun),
+                            Style::default().fg(Color::Green),
+                        ))
+                        .right_aligned(),
+                    );
+                } else if let Some(ref error) = self.refresh_error {
+                    block = block.title_bottom(
+                        Line::from(Span::styled(
+                            format!(" Auto-refresh failed: {} ", truncate_str(error, 60)),
+                            Style::default().fg(Color::Red),
+                        ))
+                        .right_aligned(),
+                    );
+                }
+                if let Some((message, color)) = &self.import_message {
+                    block = block.title_bottom(
+                        Line::from(Span::styled(
+                            format!(" {} ", truncate_str(message, 80)),
+                            Style::default().fg(*color),
+                        ))
+                        .left_aligned(),
+                    );
+                }
+                if missing_deps_count > 0 {
+                    block.border_style(Style::default().fg(Color::Yellow))
+                } else {
+                    block
+                }
+            })
+            .row_highlight_style(Style::default().bg(Color::DarkGray))
+            .highlight_symbol("→ ");
+
+        // Split off the degraded-mode banner when there is no local repository
+        let table_chunk = if app.is_degraded() {
+            let parts = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(1), Constraint::Min(0)])
+                .split(chunks[chunk_idx]);
+            self.render_degraded_banner(f, parts[0]);
+            parts[1]
+        } else {
+            chunks[chunk_idx]
+        };
+
+        // Split off the PR tab bar if tabs are configured
+        let table_chunk = if self.tab_count > 0 {
+            let parts = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(1), Constraint::Min(0)])
+                .split(table_chunk);
+            self.render_tab_bar(f, app, parts[0]);
+            parts[1]
+        } else {
+            table_chunk
+        };
+
+        // Split off the selection summary side panel if enabled
+        let table_area = if self.show_summary {
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Min(0), Constraint::Length(SUMMARY_PANEL_WIDTH)])
+                .split(table_chunk);
+            self.render_selection_summary(f, app, columns[1]);
+            columns[0]
+        } else {
+            table_chunk
+        };
+
+        // Split off the API diagnostics side panel if enabled
+        let table_area = if self.show_diagnostics {
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Min(0), Constraint::Length(SUMMARY_PANEL_WIDTH)])
+                .split(table_area);
+            self.render_api_diagnostics(f, app, columns[1]);
+            columns[0]
+        } else {
+            table_area
+        };
 
         // Store the table area for mouse hit-testing
-        let table_area = chunks[chunk_idx];
         self.table_area = Some(table_area);
-        f.render_stateful_widget(table, table_area, &mut self.table_state);
+        self.column_widths = rendered_column_widths(&constraints, table_area);
+        self.rows_by_position = !visible.iter().copied().eq(0..app.pull_requests().len());
+        if !self.rows_by_position {
+            f.render_stateful_widget(table, table_area, &mut self.table_state);
+        } else {
+            // Rows are a subset of the PRs; highlight by row position
+            let row = self
+                .table_state
+                .selected()
+                .and_then(|selected| visible.iter().position(|&index| index == selected));
+            self.tab_table_state.select(row);
+            self.scrollbar_state = self
+                .scrollbar_state
+                .content_length(visible.len())
+                .position(row.unwrap_or(0));
+            f.render_stateful_widget(table, table_area, &mut self.tab_table_state);
+        }
+        self.link_table_rows(f, app, &visible);
 
         // Render scrollbar for the PR list
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
@@ -2172,7 +3551,10 @@ impl ModeState for PullRequestSelectionState {
 
         // Render work item details if enabled
         if self.show_details {
-            self.render_work_item_details(f, app, chunks[chunk_idx]);
+            match self.details_tab {
+                DetailsTab::WorkItems => self.render_work_item_details(f, app, chunks[chunk_idx]),
+                DetailsTab::PullRequest => self.render_pr_description(f, app, chunks[chunk_idx]),
+            }
             chunk_idx += 1;
         }
 
@@ -2211,10 +3593,8 @@ impl ModeState for PullRequestSelectionState {
                 Span::raw(": Exit search | "),
                 Span::styled("Space", key_style),
                 Span::raw(": Toggle | "),
-                Span::styled("Enter", key_style),
-                Span::raw(": Exit search | "),
-                Span::styled("r", key_style),
-                Span::raw(": Refresh | "),
+                Span::styled("?", key_style),
+                Span::raw(": All keys | "),
                 Span::styled("q", key_style),
                 Span::raw(": Quit"),
             ])]
@@ -2224,20 +3604,14 @@ impl ModeState for PullRequestSelectionState {
                 Span::raw(": Navigate | "),
                 Span::styled("Space", key_style),
                 Span::raw(": Toggle | "),
-                Span::styled("i", key_style),
-                Span::raw(": Select+Related | "),
-                Span::styled("I", key_style),
-                Span::raw(": All Related | "),
                 Span::styled("/", key_style),
                 Span::raw(": Search | "),
-                Span::styled("g", key_style),
-                Span::raw(": Graph | "),
                 Span::styled("s", key_style),
                 Span::raw(": Multi-select | "),
-                Span::styled(",", key_style),
-                Span::raw(": Settings | "),
                 Span::styled("Enter", key_style),
                 Span::raw(": Confirm | "),
+                Span::styled("?", key_style),
+                Span::raw(": All keys | "),
                 Span::styled("q", key_style),
                 Span::raw(": Quit"),
             ])]
@@ -2259,6 +3633,19 @@ impl ModeState for PullRequestSelectionState {
             self.render_search_overlay(f, f.area());
         }
 
+        // Render selection import prompt
+        if self.import_mode {
+            self.render_import_overlay(f, f.area());
+        }
+
+        if self.filter_mode {
+            self.render_filter_overlay(f, f.area());
+        }
+
+        if self.local_repo_mode {
+            self.render_local_repo_overlay(f, f.area(), app);
+        }
+
         // Render dependency dialog if open
         if self.show_dependency_dialog {
             self.render_dependency_dialog(f, f.area(), app);
@@ -2268,9 +3655,37 @@ impl ModeState for PullRequestSelectionState {
         if self.show_settings_dialog {
             self.render_settings_overlay(f, f.area(), app);
         }
+
+        if self.large_selection_confirm.is_some() {
+            self.render_large_selection_overlay(f, f.area(), app);
+        }
     }
 
     async fn process_key(&mut self, code: KeyCode, app: &mut MergeApp) -> StateChange<MergeState> {
+        // Ticks drive the background auto-refresh and dependency refinement
+        if code == KeyCode::Null {
+            self.poll_auto_refresh(app);
+            self.poll_selection_diff_size();
+            self.poll_clone(app);
+            app.poll_dependency_refinement().await;
+            self.save_session(app);
+            return StateChange::Keep;
+        }
+
+        if self.large_selection_confirm.is_some() {
+            return match code {
+                KeyCode::Enter | KeyCode::Char('y') => {
+                    self.large_selection_confirm = None;
+                    StateChange::Change(MergeState::VersionInput(VersionInputState::new()))
+                }
+                KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('q') => {
+                    self.large_selection_confirm = None;
+                    StateChange::Keep
+                }
+                _ => StateChange::Keep,
+            };
+        }
+
         // Handle dependency dialog mode first
         if self.show_dependency_dialog {
             match code {
@@ -2304,10 +3719,10 @@ impl ModeState for PullRequestSelectionState {
                 KeyCode::Up | KeyCode::Char('k') => {
                     self.settings_selection = self.settings_selection.saturating_sub(1);
                 }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    if self.settings_selection < NUM_SETTINGS - 1 {
-                        self.settings_selection += 1;
-                    }
+                KeyCode::Down | KeyCode::Char('j')
+                    if self.settings_selection < NUM_SETTINGS - 1 =>
+                {
+                    self.settings_selection += 1;
                 }
                 KeyCode::Enter | KeyCode::Char(' ') => {
                     // Toggle the selected setting
@@ -2326,15 +3741,65 @@ impl ModeState for PullRequestSelectionState {
             return StateChange::Keep;
         }
 
+        if self.import_mode {
+            match code {
+                KeyCode::Char(c) => self.import_input.push(c),
+                KeyCode::Backspace => {
+                    self.import_input.pop();
+                }
+                KeyCode::Enter => {
+                    let path = self.import_input.trim().to_string();
+                    if !path.is_empty() {
+                        self.import_mode = false;
+                        self.import_selection(app, Path::new(&path));
+                    }
+                }
+                KeyCode::Esc => self.import_mode = false,
+                _ => {}
+            }
+            return StateChange::Keep;
+        }
+
+        if self.filter_mode {
+            match code {
+                KeyCode::Char(c) => self.filter_input.push(c),
+                KeyCode::Backspace => {
+                    self.filter_input.pop();
+                }
+                KeyCode::Enter => return self.apply_filter(app),
+                KeyCode::Esc => self.filter_mode = false,
+                _ => {}
+            }
+            return StateChange::Keep;
+        }
+
+        if self.local_repo_mode {
+            match code {
+                KeyCode::Char(c) => self.local_repo_input.push(c),
+                KeyCode::Backspace => {
+                    self.local_repo_input.pop();
+                }
+                KeyCode::Enter => {
+                    let path = self.local_repo_input.trim().to_string();
+                    if !path.is_empty() {
+                        self.use_local_repo(app, Path::new(&path));
+                    }
+                }
+                KeyCode::Esc => self.local_repo_mode = false,
+                _ => {}
+            }
+            return StateChange::Keep;
+        }
+
         // Handle search iteration mode first (even when search_mode is false)
         if self.search_iteration_mode && !self.search_mode {
             match code {
                 KeyCode::Char('n') => {
-                    self.navigate_search_results(1);
+                    self.navigate_search_results(app, 1);
                     return StateChange::Keep;
                 }
                 KeyCode::Char('N') => {
-                    self.navigate_search_results(-1);
+                    self.navigate_search_results(app, -1);
                     return StateChange::Keep;
                 }
                 KeyCode::Esc => {
@@ -2359,11 +3824,11 @@ impl ModeState for PullRequestSelectionState {
                 // In search result navigation mode
                 match code {
                     KeyCode::Char('n') => {
-                        self.navigate_search_results(1);
+                        self.navigate_search_results(app, 1);
                         StateChange::Keep
                     }
                     KeyCode::Char('N') => {
-                        self.navigate_search_results(-1);
+                        self.navigate_search_results(app, -1);
                         StateChange::Keep
                     }
                     KeyCode::Esc | KeyCode::Enter => {
@@ -2454,6 +3919,14 @@ impl ModeState for PullRequestSelectionState {
                     self.previous_work_item(app);
                     StateChange::Keep
                 }
+                KeyCode::Char('[') => {
+                    self.switch_tab(app, -1);
+                    StateChange::Keep
+                }
+                KeyCode::Char(']') => {
+                    self.switch_tab(app, 1);
+                    StateChange::Keep
+                }
                 KeyCode::Right => {
                     self.next_work_item(app);
                     StateChange::Keep
@@ -2462,6 +3935,18 @@ impl ModeState for PullRequestSelectionState {
                     self.toggle_selection(app);
                     StateChange::Keep
                 }
+                KeyCode::Char('K') => {
+                    self.move_in_pick_order(app, true);
+                    StateChange::Keep
+                }
+                KeyCode::Char('J') => {
+                    self.move_in_pick_order(app, false);
+                    StateChange::Keep
+                }
+                KeyCode::Char('U') => {
+                    app.reset_pick_order();
+                    StateChange::Keep
+                }
                 KeyCode::Char('s') => {
                     self.enter_multi_select_mode(app);
                     StateChange::Keep
@@ -2470,6 +3955,19 @@ impl ModeState for PullRequestSelectionState {
                     self.enter_search_mode();
                     StateChange::Keep
                 }
+                KeyCode::Char('f') => {
+                    self.enter_import_mode(app);
+                    StateChange::Keep
+                }
+                KeyCode::Char('F') => {
+                    self.enter_filter_mode(app);
+                    StateChange::Keep
+                }
+                KeyCode::Char('L') if app.is_degraded() && self.clone_task.is_none() => {
+                    self.local_repo_mode = true;
+                    self.local_repo_message = None;
+                    StateChange::Keep
+                }
                 KeyCode::Char('p') => {
                     if let Some(i) = self.table_state.selected()
                         && let Some(pr) = app.pull_requests().get(i)
@@ -2502,6 +4000,58 @@ impl ModeState for PullRequestSelectionState {
                     self.show_details = !self.show_details;
                     StateChange::Keep
                 }
+                KeyCode::Tab if self.show_details => {
+                    // Switch details pane between work items and PR description
+                    self.details_tab = match self.details_tab {
+                        DetailsTab::WorkItems => DetailsTab::PullRequest,
+                        DetailsTab::PullRequest => DetailsTab::WorkItems,
+                    };
+                    StateChange::Keep
+                }
+                KeyCode::Char('t') => {
+                    // Toggle selection summary panel
+                    self.show_summary = !self.show_summary;
+                    StateChange::Keep
+                }
+                KeyCode::Char('D') => {
+                    // Toggle API schema diagnostics panel
+                    self.show_diagnostics = !self.show_diagnostics;
+                    StateChange::Keep
+                }
+                KeyCode::Char('x') => {
+                    // Toggle display of PRs excluded by .mergers-ignore
+                    self.show_ignored = !self.show_ignored;
+                    StateChange::Keep
+                }
+                KeyCode::Char('h') => {
+                    // Toggle display of PRs whose work items are all terminal
+                    self.toggle_terminal(app);
+                    StateChange::Keep
+                }
+                KeyCode::Char('<') => {
+                    self.focused_column = self.focused_column.step(false);
+                    StateChange::Keep
+                }
+                KeyCode::Char('>') => {
+                    self.focused_column = self.focused_column.step(true);
+                    StateChange::Keep
+                }
+                KeyCode::Char('o') => {
+                    // Sort by the focused column alone, or reverse it
+                    let column = self.focused_column;
+                    self.change_layout(app, |layout| layout.sort_by(column));
+                    StateChange::Keep
+                }
+                KeyCode::Char('O') => {
+                    // Add the focused column as a secondary sort key, or reverse it
+                    let column = self.focused_column;
+                    self.change_layout(app, |layout| layout.add_sort_key(column));
+                    StateChange::Keep
+                }
+                KeyCode::Char('=') => {
+                    self.change_layout(app, PrTableLayout::reset);
+                    StateChange::Keep
+                }
                 KeyCode::Char('g') => {
                     // Open dependency graph dialog for highlighted PR
                     if let Some(selected_idx) = self.table_state.selected() {
@@ -2528,7 +4078,12 @@ impl ModeState for PullRequestSelectionState {
                     StateChange::Keep
                 }
                 KeyCode::Enter => {
-                    if app.get_selected_prs().is_empty() {
+                    app.deselect_excluded_prs();
+                    let selected = app.get_selected_prs().len();
+                    if selected == 0 {
+                        StateChange::Keep
+                    } else if app.selection_soft_cap().is_some_and(|cap| selected > cap) {
+                        self.open_large_selection_confirm(app);
                         StateChange::Keep
                     } else {
                         StateChange::Change(MergeState::VersionInput(VersionInputState::new()))
@@ -2538,18 +4093,53 @@ impl ModeState for PullRequestSelectionState {
                     // Refresh: go back to data loading state to re-fetch PRs
                     StateChange::Change(MergeState::DataLoading(DataLoadingState::new()))
                 }
+                KeyCode::Char('a') => {
+                    // Add PRs found by auto-refresh
+                    self.add_new_prs(app);
+                    StateChange::Keep
+                }
                 _ => StateChange::Keep,
             }
         }
     }
 
+    async fn process_key_event(
+        &mut self,
+        key: KeyEvent,
+        app: &mut MergeApp,
+    ) -> StateChange<MergeState> {
+        // Alt+←/→ resizes the focused column
+        if key.modifiers.contains(KeyModifiers::ALT) && self.table_has_focus() {
+            match key.code {
+                KeyCode::Left => {
+                    self.resize_focused_column(app, -COLUMN_RESIZE_STEP);
+                    return StateChange::Keep;
+                }
+                KeyCode::Right => {
+                    self.resize_focused_column(app, COLUMN_RESIZE_STEP);
+                    return StateChange::Keep;
+                }
+                _ => {}
+            }
+        }
+        let change = ModeState::process_key(self, key.code, app).await;
+        // Save right away, the next tick may never come after quitting
+        self.save_session(app);
+        change
+    }
+
     async fn process_mouse(
         &mut self,
         event: MouseEvent,
         app: &mut MergeApp,
     ) -> StateChange<MergeState> {
-        // Don't process mouse events in search mode or multi-select mode
-        if self.search_mode || self.multi_select_mode {
+        // Don't process mouse events in search, import or multi-select mode
+        if self.search_mode
+            || self.import_mode
+            || self.filter_mode
+            || self.local_repo_mode
+            || self.multi_select_mode
+        {
             return StateChange::Keep;
         }
 
@@ -2567,7 +4157,11 @@ impl ModeState for PullRequestSelectionState {
                 StateChange::Keep
             }
             MouseEventKind::Down(MouseButton::Left) => {
-                if let Some(row) = self.mouse_y_to_row(event.row, app.pull_requests().len()) {
+                let visible = self.visible_pr_indices(app);
+                if let Some(row) = self
+                    .mouse_y_to_row(event.row, visible.len())
+                    .map(|row| visible[row])
+                {
                     let now = Instant::now();
                     let is_double_click = self
                         .last_click_time
@@ -2597,64 +4191,487 @@ impl ModeState for PullRequestSelectionState {
         }
     }
 
-    fn name(&self) -> &'static str {
-        "PullRequestSelection"
-    }
-}
+    fn action_map(&self) -> ActionMap {
+        let map = ActionMap::new("Pull Request Selection");
 
-fn get_work_items_color(work_items: &[crate::models::WorkItem]) -> Color {
-    if work_items.is_empty() {
-        return Color::Gray;
-    }
+        if self.large_selection_confirm.is_some() {
+            return map
+                .bind(
+                    ActionCategory::Actions,
+                    &[KeyCode::Enter, KeyCode::Char('y')],
+                    "Continue with all selected PRs",
+                )
+                .bind(
+                    ActionCategory::General,
+                    &[KeyCode::Esc, KeyCode::Char('n'), KeyCode::Char('q')],
+                    "Back to selection",
+                );
+        }
 
-    // Return color based on the most important state
-    for wi in work_items {
-        if let Some(state) = &wi.fields.state {
-            match state.as_str() {
-                "Next Merged" | "Next Closed" => return get_state_color(state),
-                _ => {}
-            }
+        if self.show_dependency_dialog {
+            return map
+                .bind(
+                    ActionCategory::Navigation,
+                    &[
+                        KeyCode::Up,
+                        KeyCode::Char('k'),
+                        KeyCode::Down,
+                        KeyCode::Char('j'),
+                    ],
+                    "Scroll dependencies",
+                )
+                .bind(
+                    ActionCategory::General,
+                    &[KeyCode::Esc, KeyCode::Char('g'), KeyCode::Char('q')],
+                    "Close dependency dialog",
+                );
         }
-    }
 
-    work_items
-        .iter()
-        .filter_map(|wi| wi.fields.state.as_deref())
-        .next()
-        .map(get_state_color)
-        .unwrap_or(Color::White)
-}
+        if self.show_settings_dialog {
+            return map
+                .bind(
+                    ActionCategory::Navigation,
+                    &[
+                        KeyCode::Up,
+                        KeyCode::Char('k'),
+                        KeyCode::Down,
+                        KeyCode::Char('j'),
+                    ],
+                    "Move highlight",
+                )
+                .bind(
+                    ActionCategory::Selection,
+                    &[KeyCode::Enter, KeyCode::Char(' ')],
+                    "Toggle setting",
+                )
+                .bind(
+                    ActionCategory::General,
+                    &[KeyCode::Esc, KeyCode::Char(',')],
+                    "Save and close settings",
+                );
+        }
 
-fn get_state_color(state: &str) -> Color {
-    match state {
-        "Dev Closed" => Color::LightGreen,
-        "Closed" => Color::Green,
-        "Resolved" => Color::Rgb(255, 165, 0),
-        "In Review" => Color::Yellow,
-        "New" => Color::Gray,
-        "Active" => Color::Blue,
-        "Next Merged" => Color::Red,
-        "Next Closed" => Color::Magenta,
-        "Hold" => Color::Cyan,
-        _ => Color::White,
-    }
-}
+        if self.local_repo_mode {
+            return map
+                .with_text_input()
+                .bind(
+                    ActionCategory::Actions,
+                    &[KeyCode::Enter],
+                    "Use or clone repository",
+                )
+                .bind(ActionCategory::General, &[KeyCode::Esc], "Cancel");
+        }
 
-/// Returns the dependency counts (partial, full) for a PR.
-///
-/// Returns (0, 0) if dependency graph is not available.
-fn get_dependency_counts(app: &MergeApp, pr_id: i32) -> (usize, usize) {
-    if let Some(graph) = app.dependency_graph()
-        && let Some(node) = graph.get_node(pr_id)
-    {
-        let mut partial = 0;
-        let mut full = 0;
-        for dep in &node.dependencies {
-            match &dep.category {
-                DependencyCategory::PartiallyDependent { .. } => partial += 1,
-                DependencyCategory::Dependent { .. } => full += 1,
-                DependencyCategory::Independent => {}
-            }
+        if self.import_mode {
+            return map
+                .with_text_input()
+                .bind(
+                    ActionCategory::Actions,
+                    &[KeyCode::Enter],
+                    "Import selection",
+                )
+                .bind(ActionCategory::General, &[KeyCode::Esc], "Cancel import");
+        }
+
+        if self.filter_mode {
+            return map
+                .with_text_input()
+                .bind(ActionCategory::Actions, &[KeyCode::Enter], "Apply filter")
+                .bind(ActionCategory::General, &[KeyCode::Esc], "Cancel filter");
+        }
+
+        if self.search_mode && !self.search_iteration_mode {
+            return map
+                .with_text_input()
+                .bind(ActionCategory::Actions, &[KeyCode::Enter], "Search")
+                .bind(ActionCategory::General, &[KeyCode::Esc], "Cancel search");
+        }
+
+        if self.search_mode {
+            return map
+                .bind(
+                    ActionCategory::Navigation,
+                    &[KeyCode::Char('n'), KeyCode::Char('N')],
+                    "Next/previous search result",
+                )
+                .bind(
+                    ActionCategory::General,
+                    &[KeyCode::Esc, KeyCode::Enter],
+                    "Exit search",
+                );
+        }
+
+        if self.multi_select_mode {
+            return map
+                .bind(
+                    ActionCategory::Navigation,
+                    &[KeyCode::Up, KeyCode::Down],
+                    "Move highlight",
+                )
+                .bind(
+                    ActionCategory::Selection,
+                    &[KeyCode::Char(' ')],
+                    "Toggle work item state",
+                )
+                .bind(
+                    ActionCategory::Selection,
+                    &[KeyCode::Char('a')],
+                    "Mark all states",
+                )
+                .bind(
+                    ActionCategory::Actions,
+                    &[KeyCode::Enter],
+                    "Select PRs with marked states",
+                )
+                .bind(
+                    ActionCategory::Actions,
+                    &[KeyCode::Char('c')],
+                    "Clear all selections",
+                )
+                .bind(
+                    ActionCategory::General,
+                    &[KeyCode::Esc, KeyCode::Char('q')],
+                    "Close state selection",
+                );
+        }
+
+        let mut map = map
+            .bind(
+                ActionCategory::Navigation,
+                &[KeyCode::Up, KeyCode::Down],
+                "Move highlight",
+            )
+            .bind(
+                ActionCategory::Navigation,
+                &[KeyCode::Left, KeyCode::Right],
+                "Previous/next work item",
+            );
+        if self.tab_count > 0 {
+            map = map.bind(
+                ActionCategory::Navigation,
+                &[KeyCode::Char('['), KeyCode::Char(']')],
+                "Previous/next PR tab",
+            );
+        }
+        if self.search_iteration_mode {
+            map = map
+                .bind(
+                    ActionCategory::Navigation,
+                    &[KeyCode::Char('n'), KeyCode::Char('N')],
+                    "Next/previous search result",
+                )
+                .bind(
+                    ActionCategory::Navigation,
+                    &[KeyCode::Esc, KeyCode::Enter],
+                    "Exit search",
+                );
+        }
+
+        map = map
+            .bind(
+                ActionCategory::Selection,
+                &[KeyCode::Char(' ')],
+                "Toggle PR",
+            )
+            .bind(
+                ActionCategory::Selection,
+                &[KeyCode::Char('K'), KeyCode::Char('J')],
+                "Pick selected PR earlier/later",
+            )
+            .bind(
+                ActionCategory::Selection,
+                &[KeyCode::Char('U')],
+                "Reset pick order to closed date",
+            )
+            .bind(
+                ActionCategory::Selection,
+                &[KeyCode::Char('s')],
+                "Select by work item state",
+            )
+            .bind(
+                ActionCategory::Selection,
+                &[KeyCode::Char('i')],
+                "Select PR and related PRs",
+            )
+            .bind(
+                ActionCategory::Selection,
+                &[KeyCode::Char('I')],
+                "Select PRs related to selection",
+            )
+            .bind(
+                ActionCategory::Selection,
+                &[KeyCode::Char('f')],
+                "Import selection from file",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('F')],
+                "Filter by author, reviewer or path",
+            )
+            .bind(ActionCategory::View, &[KeyCode::Char('/')], "Search")
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('d')],
+                "Toggle details",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Tab],
+                "Switch details: work items / PR description",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('t')],
+                "Toggle selection summary",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('D')],
+                "Toggle API schema diagnostics",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('x')],
+                "Show/hide PRs excluded by .mergers-ignore",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('h')],
+                "Show/hide PRs in terminal work item states",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('<'), KeyCode::Char('>')],
+                "Focus previous / next column (Alt+←/→ resizes it)",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('o')],
+                "Sort by focused column (again: reverse)",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('O')],
+                "Add focused column as secondary sort key",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('=')],
+                "Reset column widths and sort",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('g')],
+                "Show dependency graph",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('L')],
+                "Set or clone a local repository (without local_repo)",
+            )
+            .bind(ActionCategory::View, &[KeyCode::Char(',')], "Settings")
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('p')],
+                "Open pull request in browser",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('w')],
+                "Open work item in browser",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Enter],
+                "Continue with selected PRs",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('r')],
+                "Refresh pull requests",
+            );
+        if !self.new_prs.is_empty() {
+            map = map.bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('a')],
+                "Add new pull requests",
+            );
+        }
+        map.bind(ActionCategory::General, &[KeyCode::Char('q')], "Quit")
+    }
+
+    fn name(&self) -> &'static str {
+        "PullRequestSelection"
+    }
+}
+
+fn get_work_items_color(work_items: &[crate::models::WorkItem]) -> Color {
+    if work_items.is_empty() {
+        return Color::Gray;
+    }
+
+    // Return color based on the most important state
+    for wi in work_items {
+        if let Some(state) = &wi.fields.state {
+            match state.as_str() {
+                "Next Merged" | "Next Closed" => return get_state_color(state),
+                _ => {}
+            }
+        }
+    }
+
+    work_items
+        .iter()
+        .filter_map(|wi| wi.fields.state.as_deref())
+        .next()
+        .map(get_state_color)
+        .unwrap_or(Color::White)
+}
+
+fn get_state_color(state: &str) -> Color {
+    match state {
+        "Dev Closed" => Color::LightGreen,
+        "Closed" => Color::Green,
+        "Resolved" => Color::Rgb(255, 165, 0),
+        "In Review" => Color::Yellow,
+        "New" => Color::Gray,
+        "Active" => Color::Blue,
+        "Next Merged" => Color::Red,
+        "Next Closed" => Color::Magenta,
+        "Hold" => Color::Cyan,
+        _ => Color::White,
+    }
+}
+
+/// Returns the dependency counts (partial, full) for a PR.
+///
+/// Returns (0, 0) if dependency graph is not available.
+/// Returns true if the PR at `index` belongs to the tab.
+///
+/// Changed files come from the dependency graph, so without `local_repo`
+/// only title rules can match.
+fn pr_in_tab(app: &MergeApp, tab: &PrTab, index: usize) -> bool {
+    let Some(pr_with_wi) = app.pull_requests().get(index) else {
+        return false;
+    };
+    tab.matches(&pr_with_wi.pr.title, changed_files(app, pr_with_wi.pr.id))
+}
+
+/// Returns true if the PR meets the author, reviewer and path filter.
+fn pr_passes_filter(app: &MergeApp, pr_with_wi: &PullRequestWithWorkItems) -> bool {
+    let filter = app.pr_filter();
+    filter.is_empty() || filter.matches(&pr_with_wi.pr, changed_files(app, pr_with_wi.pr.id))
+}
+
+/// Files changed by a PR, known once dependency analysis has run.
+fn changed_files(app: &MergeApp, pr_id: i32) -> &[String] {
+    app.dependency_graph()
+        .and_then(|graph| graph.nodes.get(&pr_id))
+        .map_or(&[][..], |node| node.changed_files.as_slice())
+}
+
+/// Column widths of the PR table: resized columns keep their width, the
+/// others use their default.
+fn column_constraints(layout: &PrTableLayout) -> Vec<Constraint> {
+    PrColumn::ALL
+        .iter()
+        .map(|&column| match layout.width(column) {
+            Some(width) => Constraint::Length(width),
+            None => match column {
+                PrColumn::Selected => Constraint::Length(3),
+                PrColumn::Order => Constraint::Length(6),
+                PrColumn::Id => Constraint::Length(8),
+                PrColumn::Date => Constraint::Length(12),
+                PrColumn::Title => Constraint::Percentage(25),
+                PrColumn::Author => Constraint::Percentage(15),
+                PrColumn::WorkItems => Constraint::Percentage(20),
+                // e.g., "2 P / 3 F"
+                PrColumn::Dependencies => Constraint::Length(12),
+            },
+        })
+        .collect()
+}
+
+/// Widths the table draws its columns with inside `area`.
+///
+/// Mirrors the table's own layout: borders, the "→ " highlight symbol, and
+/// one space between columns.
+fn rendered_column_widths(constraints: &[Constraint], area: Rect) -> Vec<u16> {
+    let inner = Rect {
+        x: 0,
+        y: 0,
+        width: area.width.saturating_sub(4),
+        height: 1,
+    };
+    Layout::horizontal(constraints.iter().copied())
+        .flex(Flex::Start)
+        .spacing(1)
+        .split(inner)
+        .iter()
+        .map(|rect| rect.width)
+        .collect()
+}
+
+/// Orders two PRs by a table column, ascending.
+///
+/// `pick_positions` is the cherry-pick position of each selected PR, see
+/// [`pick_positions`](crate::ui::app_base::AppBase::pick_positions).
+fn compare_prs(
+    app: &MergeApp,
+    pick_positions: &HashMap<i32, usize>,
+    column: PrColumn,
+    a: &PullRequestWithWorkItems,
+    b: &PullRequestWithWorkItems,
+) -> Ordering {
+    let work_items = |pr: &PullRequestWithWorkItems| -> Vec<(String, i32)> {
+        pr.work_items
+            .iter()
+            .map(|wi| (wi.fields.state.clone().unwrap_or_default(), wi.id))
+            .collect()
+    };
+    match column {
+        PrColumn::Selected => b.selected.cmp(&a.selected),
+        // Unselected PRs have no position and come last
+        PrColumn::Order => {
+            let position = |pr: &PullRequestWithWorkItems| {
+                pick_positions.get(&pr.pr.id).copied().unwrap_or(usize::MAX)
+            };
+            position(a).cmp(&position(b))
+        }
+        PrColumn::Id => a.pr.id.cmp(&b.pr.id),
+        // RFC 3339 dates sort as text; active PRs (no date) come last
+        PrColumn::Date => match (&a.pr.closed_date, &b.pr.closed_date) {
+            (Some(a), Some(b)) => a.cmp(b),
+            (a, b) => b.is_some().cmp(&a.is_some()),
+        },
+        PrColumn::Title => a.pr.title.to_lowercase().cmp(&b.pr.title.to_lowercase()),
+        PrColumn::Author => {
+            a.pr.created_by
+                .display_name
+                .to_lowercase()
+                .cmp(&b.pr.created_by.display_name.to_lowercase())
+        }
+        PrColumn::WorkItems => work_items(a).cmp(&work_items(b)),
+        PrColumn::Dependencies => {
+            let (a_partial, a_full) = get_dependency_counts(app, a.pr.id);
+            let (b_partial, b_full) = get_dependency_counts(app, b.pr.id);
+            (a_full, a_partial).cmp(&(b_full, b_partial))
+        }
+    }
+}
+
+fn get_dependency_counts(app: &MergeApp, pr_id: i32) -> (usize, usize) {
+    if let Some(graph) = app.dependency_graph()
+        && let Some(node) = graph.get_node(pr_id)
+    {
+        let mut partial = 0;
+        let mut full = 0;
+        for dep in &node.dependencies {
+            match &dep.category {
+                DependencyCategory::PartiallyDependent { .. } => partial += 1,
+                DependencyCategory::Dependent { .. } => full += 1,
+                DependencyCategory::Independent => {}
+            }
         }
         return (partial, full);
     }
@@ -2939,7 +4956,7 @@ mod tests {
     use crate::core::operations::{
         DependencyCategory, PRDependency, PRDependencyGraph, PRDependencyNode,
     };
-    use crate::models::PullRequestWithWorkItems;
+    use crate::models::{AppConfig, CreatedBy, PullRequestWithWorkItems};
     use crate::ui::{
         snapshot_testing::with_settings_and_module_path,
         state::typed::AppState,
@@ -3028,128 +5045,1141 @@ mod tests {
         ];
         graph.add_node(node102);
 
-        graph
+        graph
+    }
+
+    /// # PR Selection State - Normal Display
+    ///
+    /// Tests the pull request selection screen with normal data.
+    ///
+    /// ## Test Scenario
+    /// - Creates a PR selection state
+    /// - Loads test pull requests into the app
+    /// - Renders the PR table and work item details
+    ///
+    /// ## Expected Outcome
+    /// - Should display PR table with columns: checkbox, PR#, Date, Title, Author, Work Items
+    /// - Should show work item details panel
+    /// - Should display help text at bottom
+    #[test]
+    fn test_pr_selection_normal() {
+        with_settings_and_module_path(module_path!(), || {
+            let config = create_test_config_default();
+            let mut harness = TuiTestHarness::with_config(config);
+
+            *harness.app.pull_requests_mut() = create_test_pull_requests();
+
+            let mut state = MergeState::PullRequestSelection(PullRequestSelectionState::new());
+            harness.render_merge_state(&mut state);
+
+            assert_snapshot!("normal_display", harness.backend());
+        });
+    }
+
+    /// # PR Selection State - Empty List
+    ///
+    /// Tests the PR selection screen with no pull requests.
+    ///
+    /// ## Test Scenario
+    /// - Creates a PR selection state
+    /// - Does not load any pull requests
+    /// - Renders the empty state
+    ///
+    /// ## Expected Outcome
+    /// - Should display "No pull requests found" message
+    /// - Should show quit instruction
+    #[test]
+    fn test_pr_selection_empty() {
+        with_settings_and_module_path(module_path!(), || {
+            let config = create_test_config_default();
+            let mut harness = TuiTestHarness::with_config(config);
+
+            // Leave pull_requests empty
+
+            let mut state = MergeState::PullRequestSelection(PullRequestSelectionState::new());
+            harness.render_merge_state(&mut state);
+
+            assert_snapshot!("empty_list", harness.backend());
+        });
+    }
+
+    /// # PR Selection State - With Selections
+    ///
+    /// Tests the PR selection screen with some PRs selected.
+    ///
+    /// ## Test Scenario
+    /// - Creates a PR selection state
+    /// - Loads test pull requests
+    /// - Marks some PRs as selected
+    /// - Renders the display
+    ///
+    /// ## Expected Outcome
+    /// - Should display checkmarks for selected PRs
+    /// - Selected rows should have different background color
+    /// - Text color should change for selected items
+    #[test]
+    fn test_pr_selection_with_selections() {
+        with_settings_and_module_path(module_path!(), || {
+            let config = create_test_config_default();
+            let mut harness = TuiTestHarness::with_config(config);
+
+            let mut prs = create_test_pull_requests();
+            prs[0].selected = true;
+            prs[2].selected = true;
+            *harness.app.pull_requests_mut() = prs;
+
+            let mut state = MergeState::PullRequestSelection(PullRequestSelectionState::new());
+            harness.render_merge_state(&mut state);
+
+            assert_snapshot!("with_selections", harness.backend());
+        });
+    }
+
+    /// # PR Selection Accessible Markers
+    ///
+    /// Tests the selection column in accessible mode.
+    ///
+    /// ## Test Scenario
+    /// - Enables textual markers
+    /// - Selects some PRs and renders the display
+    ///
+    /// ## Expected Outcome
+    /// - Selected rows show `[x]` and unselected rows `[ ]` instead of
+    ///   relying on the row color
+    #[test]
+    fn test_pr_selection_accessible_markers() {
+        with_settings_and_module_path(module_path!(), || {
+            let config = create_test_config_default();
+            let mut harness = TuiTestHarness::with_config(config);
+            harness.app.set_accessibility(crate::ui::Accessibility {
+                markers: true,
+                ..Default::default()
+            });
+
+            let mut prs = create_test_pull_requests();
+            prs[0].selected = true;
+            prs[2].selected = true;
+            *harness.app.pull_requests_mut() = prs;
+
+            let mut state = MergeState::PullRequestSelection(PullRequestSelectionState::new());
+            harness.render_merge_state(&mut state);
+
+            assert_snapshot!("accessible_markers", harness.backend());
+        });
+    }
+
+    /// # PR Selection State - With Dependencies
+    ///
+    /// Tests the PR selection screen with dependency information displayed.
+    ///
+    /// ## Test Scenario
+    /// - Creates a PR selection state
+    /// - Loads test pull requests with a dependency graph
+    /// - PR 100: 2 partial deps, 1 full dep (displays "2 P / 1 F")
+    /// - PR 101: 1 partial dep (displays "1 P")
+    /// - PR 102: 3 full deps (displays "3 F")
+    ///
+    /// ## Expected Outcome
+    /// - Should display PR Dependencies column with correct format
+    /// - Partial deps shown with "P" suffix
+    /// - Full deps shown with "F" suffix
+    /// - Combined format: "X P / Y F"
+    #[test]
+    fn test_pr_selection_with_dependencies() {
+        with_settings_and_module_path(module_path!(), || {
+            let config = create_test_config_default();
+            let mut harness = TuiTestHarness::with_config(config);
+
+            *harness.app.pull_requests_mut() = create_test_pull_requests();
+            harness
+                .merge_app_mut()
+                .set_dependency_graph(create_test_dependency_graph());
+
+            let mut state = MergeState::PullRequestSelection(PullRequestSelectionState::new());
+            harness.render_merge_state(&mut state);
+
+            assert_snapshot!("with_dependencies", harness.backend());
+        });
+    }
+
+    /// # PR Selection State - Selection Summary Panel
+    ///
+    /// Tests the selection summary side panel.
+    ///
+    /// ## Test Scenario
+    /// - Loads test pull requests with a dependency graph
+    /// - Selects PR 101, which depends on unselected PR 102
+    /// - Toggles the summary panel with 't'
+    ///
+    /// ## Expected Outcome
+    /// - Panel shows selection totals, High conflict risk and PR 102 as missing
+    #[tokio::test]
+    async fn test_pr_selection_summary_panel() {
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+
+        let mut prs = create_test_pull_requests();
+        for pr in prs.iter_mut() {
+            pr.selected = pr.pr.id == 101;
+        }
+        *harness.app.pull_requests_mut() = prs;
+        let mut graph = crate::ui::testing::create_test_dependency_graph();
+        graph.get_node_mut(101).unwrap().changed_files =
+            vec!["src/user.rs".to_string(), "src/profile.rs".to_string()];
+        harness.merge_app_mut().set_dependency_graph(graph);
+
+        let mut inner_state = PullRequestSelectionState::new();
+        ModeState::process_key(
+            &mut inner_state,
+            KeyCode::Char('t'),
+            harness.merge_app_mut(),
+        )
+        .await;
+        assert!(inner_state.show_summary);
+
+        with_settings_and_module_path(module_path!(), || {
+            let mut state = MergeState::PullRequestSelection(inner_state);
+            harness.render_merge_state(&mut state);
+
+            assert_snapshot!("summary_panel", harness.backend());
+        });
+    }
+
+    /// # PR Selection State - Ignored PRs
+    ///
+    /// Tests showing PRs excluded by `.mergers-ignore`.
+    ///
+    /// ## Test Scenario
+    /// - Loads test pull requests plus one ignored PR with a reason
+    /// - Toggles the ignored rows with 'x'
+    ///
+    /// ## Expected Outcome
+    /// - The ignored PR is listed greyed out below the selectable rows with its reason
+    /// - Navigation stays within the selectable rows
+    #[tokio::test]
+    async fn test_pr_selection_ignored_prs() {
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+
+        let prs = create_test_pull_requests();
+        let mut ignored_pr = prs[0].pr.clone();
+        ignored_pr.id = 999;
+        ignored_pr.title = "[experimental] New search".to_string();
+        let pr_count = prs.len();
+        *harness.app.pull_requests_mut() = prs;
+        harness.merge_app_mut().set_ignored_pull_requests(vec![
+            crate::core::operations::IgnoredPullRequest {
+                pr: ignored_pr,
+                reason: "experimental feature".to_string(),
+            },
+        ]);
+
+        let mut inner_state = PullRequestSelectionState::new();
+        ModeState::process_key(
+            &mut inner_state,
+            KeyCode::Char('x'),
+            harness.merge_app_mut(),
+        )
+        .await;
+        assert!(inner_state.show_ignored);
+
+        for _ in 0..pr_count + 2 {
+            ModeState::process_key(&mut inner_state, KeyCode::Down, harness.merge_app_mut()).await;
+        }
+        assert!(inner_state.table_state.selected().unwrap() < pr_count);
+
+        inner_state.table_state.select(Some(0));
+        with_settings_and_module_path(module_path!(), || {
+            let mut state = MergeState::PullRequestSelection(inner_state);
+            harness.render_merge_state(&mut state);
+
+            assert_snapshot!("ignored_prs", harness.backend());
+        });
+    }
+
+    /// # PR Selection State - Reverted PRs
+    ///
+    /// Tests flagging PRs that were reverted on the dev branch.
+    ///
+    /// ## Test Scenario
+    /// - Loads test pull requests where PR #101 reverts PR #100
+    /// - Renders the PR list
+    ///
+    /// ## Expected Outcome
+    /// - PR #100 is marked "reverted by !101" and PR #101 "reverts !100"
+    /// - The table title counts the reverted PR
+    #[test]
+    fn test_pr_selection_reverted_prs() {
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+
+        let mut prs = create_test_pull_requests();
+        prs[1].pr.title = "Revert \"Fix login bug\"".to_string();
+        prs[1].pr.description = Some("Reverts !100".to_string());
+        let reverts = crate::core::operations::RevertIndex::detect(prs.iter().map(|pr| &pr.pr));
+        *harness.app.pull_requests_mut() = prs;
+        harness.merge_app_mut().set_revert_index(reverts);
+
+        with_settings_and_module_path(module_path!(), || {
+            let mut state = MergeState::PullRequestSelection(PullRequestSelectionState::new());
+            harness.render_merge_state(&mut state);
+
+            assert_snapshot!("reverted_prs", harness.backend());
+        });
+    }
+
+    /// # PR Selection State - API Diagnostics
+    ///
+    /// Tests the panel listing responses that did not match the expected schema.
+    ///
+    /// ## Test Scenario
+    /// - Sets a skipped item and an unknown fields warning
+    /// - Opens the diagnostics panel with 'D'
+    ///
+    /// ## Expected Outcome
+    /// - The table title counts only the warning that affects loaded data
+    /// - The panel lists both warnings with their endpoint
+    #[tokio::test]
+    async fn test_pr_selection_api_diagnostics() {
+        use crate::api::{SchemaWarning, SchemaWarningKind};
+
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+        harness.merge_app_mut().set_schema_warnings(vec![
+            SchemaWarning {
+                kind: SchemaWarningKind::SkippedItem,
+                endpoint: "pull requests".to_string(),
+                message: "skipped item 105: invalid type".to_string(),
+            },
+            SchemaWarning {
+                kind: SchemaWarningKind::UnknownFields,
+                endpoint: "work items".to_string(),
+                message: "unknown fields ignored: `commentVersionRef`".to_string(),
+            },
+        ]);
+
+        let mut inner_state = PullRequestSelectionState::new();
+        ModeState::process_key(
+            &mut inner_state,
+            KeyCode::Char('D'),
+            harness.merge_app_mut(),
+        )
+        .await;
+        assert!(inner_state.show_diagnostics);
+
+        with_settings_and_module_path(module_path!(), || {
+            let mut state = MergeState::PullRequestSelection(inner_state);
+            harness.render_merge_state(&mut state);
+
+            assert_snapshot!("api_diagnostics", harness.backend());
+        });
+    }
+
+    /// # PR Selection State - Release Gate
+    ///
+    /// Tests PRs whose work items fail the configured release gate.
+    ///
+    /// ## Test Scenario
+    /// - Configures a `Custom.QAApproved == true` gate; only PR #100 passes
+    /// - Renders the list, then selects all states in multi-select mode
+    /// - Selects a failing PR manually with Space
+    ///
+    /// ## Expected Outcome
+    /// - Failing PRs show a red ✗ marker and are counted in the title
+    /// - Bulk selection skips failing PRs
+    /// - Manual selection overrides the gate
+    #[tokio::test]
+    async fn test_pr_selection_release_gate() {
+        let mut config = create_test_config_default();
+        if let AppConfig::Default { default, .. } = &mut config {
+            default.release_gate = Some("Custom.QAApproved == true".parse().unwrap());
+        }
+        let mut harness = TuiTestHarness::with_config(config);
+
+        let mut prs = create_test_pull_requests();
+        prs[0].work_items[0]
+            .fields
+            .custom_fields
+            .insert("Custom.QAApproved".to_string(), serde_json::json!(true));
+        *harness.app.pull_requests_mut() = prs;
+
+        let mut inner_state = PullRequestSelectionState::new();
+        for key in [KeyCode::Char('s'), KeyCode::Char('a'), KeyCode::Enter] {
+            ModeState::process_key(&mut inner_state, key, harness.merge_app_mut()).await;
+        }
+        let selected: Vec<i32> = harness
+            .merge_app()
+            .get_selected_prs()
+            .iter()
+            .map(|pr| pr.pr.id)
+            .collect();
+        assert_eq!(selected, vec![100]);
+
+        with_settings_and_module_path(module_path!(), || {
+            let mut state = MergeState::PullRequestSelection(PullRequestSelectionState::new());
+            harness.render_merge_state(&mut state);
+
+            assert_snapshot!("release_gate", harness.backend());
+        });
+
+        inner_state.table_state.select(Some(1));
+        ModeState::process_key(
+            &mut inner_state,
+            KeyCode::Char(' '),
+            harness.merge_app_mut(),
+        )
+        .await;
+        assert_eq!(harness.merge_app().get_selected_prs().len(), 2);
+    }
+
+    /// # PR Selection State - Build Checks
+    ///
+    /// Tests PRs that failed their build checks while green builds are required.
+    ///
+    /// ## Test Scenario
+    /// - Requires green builds; PR #101's build failed and PR #102 has none
+    /// - Renders the list, then selects all states in multi-select mode
+    /// - Tries to select PR #101 manually with Space
+    ///
+    /// ## Expected Outcome
+    /// - Failing PRs show a red ✗ label and are counted in the title
+    /// - Bulk selection skips them and Space refuses to select them
+    #[tokio::test]
+    async fn test_pr_selection_build_checks() {
+        use crate::api::{CheckOutcome, CheckState, PrChecks};
+        use crate::core::operations::{BuildCheckIndex, BuildCheckMode};
+
+        let mut config = create_test_config_default();
+        if let AppConfig::Default { default, .. } = &mut config {
+            default.build_checks = BuildCheckMode::Require;
+        }
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+
+        let mut checks = BuildCheckIndex::default();
+        checks.record(
+            101,
+            Ok(PrChecks {
+                builds: vec![CheckOutcome {
+                    name: "CI".to_string(),
+                    state: CheckState::Failed,
+                }],
+                policies: Vec::new(),
+            }),
+        );
+        checks.record(102, Ok(PrChecks::default()));
+        harness.merge_app_mut().extend_build_checks(checks);
+
+        let mut inner_state = PullRequestSelectionState::new();
+        for key in [KeyCode::Char('s'), KeyCode::Char('a'), KeyCode::Enter] {
+            ModeState::process_key(&mut inner_state, key, harness.merge_app_mut()).await;
+        }
+        let selected: Vec<i32> = harness
+            .merge_app()
+            .get_selected_prs()
+            .iter()
+            .map(|pr| pr.pr.id)
+            .collect();
+        assert!(!selected.contains(&101));
+        assert!(!selected.contains(&102));
+
+        with_settings_and_module_path(module_path!(), || {
+            let mut state = MergeState::PullRequestSelection(PullRequestSelectionState::new());
+            harness.render_merge_state(&mut state);
+
+            assert_snapshot!("build_checks", harness.backend());
+        });
+
+        inner_state.table_state.select(Some(1));
+        ModeState::process_key(
+            &mut inner_state,
+            KeyCode::Char(' '),
+            harness.merge_app_mut(),
+        )
+        .await;
+        assert!(!harness.merge_app().pull_requests()[1].selected);
+    }
+
+    /// # PR Selection State - Terminal PRs
+    ///
+    /// Tests hiding PRs whose work items are all in a terminal state.
+    ///
+    /// ## Test Scenario
+    /// - Configures `Closed` as terminal; PR #100's only work item is closed
+    /// - Renders the list, then selects all states in multi-select mode
+    /// - Reveals terminal PRs with 'h'
+    ///
+    /// ## Expected Outcome
+    /// - PR #100 is hidden, counted in the title and never highlighted
+    /// - Bulk selection skips the hidden PR
+    /// - 'h' shows it again
+    #[tokio::test]
+    async fn test_pr_selection_terminal_prs() {
+        let mut config = create_test_config_default();
+        if let AppConfig::Default { default, .. } = &mut config {
+            default.terminal_states = vec!["closed".to_string()];
+        }
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+        let pr_count = harness.app.pull_requests().len();
+
+        let mut inner_state = PullRequestSelectionState::new();
+        assert_eq!(
+            inner_state.visible_pr_indices(harness.merge_app()),
+            (1..pr_count).collect::<Vec<_>>()
+        );
+        for _ in 0..pr_count + 1 {
+            ModeState::process_key(&mut inner_state, KeyCode::Up, harness.merge_app_mut()).await;
+            assert_ne!(inner_state.table_state.selected(), Some(0));
+        }
+
+        for key in [KeyCode::Char('s'), KeyCode::Char('a'), KeyCode::Enter] {
+            ModeState::process_key(&mut inner_state, key, harness.merge_app_mut()).await;
+        }
+        assert!(
+            harness
+                .merge_app()
+                .get_selected_prs()
+                .iter()
+                .all(|pr| pr.pr.id != 100)
+        );
+
+        with_settings_and_module_path(module_path!(), || {
+            let mut state = MergeState::PullRequestSelection(PullRequestSelectionState::new());
+            harness.render_merge_state(&mut state);
+
+            assert_snapshot!("terminal_prs_hidden", harness.backend());
+        });
+
+        ModeState::process_key(
+            &mut inner_state,
+            KeyCode::Char('h'),
+            harness.merge_app_mut(),
+        )
+        .await;
+        assert!(inner_state.show_terminal);
+        assert_eq!(
+            inner_state.visible_pr_indices(harness.merge_app()).len(),
+            pr_count
+        );
+    }
+
+    /// # PR Selection State - Sorting and Column Resizing
+    ///
+    /// Tests sorting by the focused column and resizing it.
+    ///
+    /// ## Test Scenario
+    /// - Focuses the PR # column and sorts by it twice
+    /// - Adds the title column as a secondary key
+    /// - Renders the list, then widens the focused column with Alt+→
+    /// - Resets the layout with '='
+    ///
+    /// ## Expected Outcome
+    /// - The second sort reverses the order and the header shows both keys
+    /// - Alt+→ widens the column from its drawn width
+    /// - '=' restores the loaded order and default widths
+    #[tokio::test]
+    async fn test_pr_selection_sort_and_resize() {
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+        let pr_count = harness.app.pull_requests().len();
+
+        let mut inner_state = PullRequestSelectionState::new();
+        for key in [
+            KeyCode::Char('<'),
+            KeyCode::Char('<'),
+            KeyCode::Char('o'),
+            KeyCode::Char('o'),
+        ] {
+            ModeState::process_key(&mut inner_state, key, harness.merge_app_mut()).await;
+        }
+        assert_eq!(inner_state.focused_column, PrColumn::Id);
+        assert_eq!(
+            inner_state.visible_pr_indices(harness.merge_app()),
+            (0..pr_count).rev().collect::<Vec<_>>()
+        );
+
+        for key in [KeyCode::Char('>'), KeyCode::Char('>'), KeyCode::Char('O')] {
+            ModeState::process_key(&mut inner_state, key, harness.merge_app_mut()).await;
+        }
+        assert_eq!(
+            harness
+                .merge_app()
+                .pr_table_layout()
+                .sort_rank(PrColumn::Title),
+            Some((1, false))
+        );
+        ModeState::process_key(
+            &mut inner_state,
+            KeyCode::Char('<'),
+            harness.merge_app_mut(),
+        )
+        .await;
+
+        let mut state = MergeState::PullRequestSelection(inner_state);
+        with_settings_and_module_path(module_path!(), || {
+            harness.render_merge_state(&mut state);
+            assert_snapshot!("sorted_by_id_and_title", harness.backend());
+        });
+
+        let MergeState::PullRequestSelection(inner_state) = &mut state else {
+            unreachable!();
+        };
+        let drawn = inner_state.column_widths[PrColumn::Date.index()];
+        ModeState::process_key_event(
+            inner_state,
+            KeyEvent::new(KeyCode::Right, KeyModifiers::ALT),
+            harness.merge_app_mut(),
+        )
+        .await;
+        assert_eq!(
+            harness.merge_app().pr_table_layout().width(PrColumn::Date),
+            Some(drawn + 2)
+        );
+
+        ModeState::process_key(inner_state, KeyCode::Char('='), harness.merge_app_mut()).await;
+        assert_eq!(
+            harness.merge_app().pr_table_layout(),
+            &PrTableLayout::default()
+        );
+        assert_eq!(
+            inner_state.visible_pr_indices(harness.merge_app()),
+            (0..pr_count).collect::<Vec<_>>()
+        );
+    }
+
+    /// # PR Selection State - Manual Pick Order
+    ///
+    /// Tests reordering selected PRs for cherry-picking.
+    ///
+    /// ## Test Scenario
+    /// - Selects all three PRs and moves the newest one up twice with 'K'
+    /// - Presses 'K' on an unselected PR, then 'J' on the first PR
+    /// - Sorts by the pick order column and renders the list
+    /// - Resets the order with 'U'
+    ///
+    /// ## Expected Outcome
+    /// - The pick order follows the moves; unselected PRs are not moved
+    /// - The table shows the pick numbers and the manual order in the title
+    /// - 'U' restores the closed-date order
+    #[tokio::test]
+    async fn test_pr_selection_manual_pick_order() {
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+        for pr in harness.app.pull_requests_mut().iter_mut() {
+            pr.selected = true;
+        }
+        let pick_order = |harness: &TuiTestHarness| -> Vec<i32> {
+            harness
+                .merge_app()
+                .get_selected_prs()
+                .iter()
+                .map(|pr| pr.pr.id)
+                .collect()
+        };
+
+        let mut inner_state = PullRequestSelectionState::new();
+        inner_state.table_state.select(Some(2));
+        for key in [KeyCode::Char('K'), KeyCode::Char('K')] {
+            ModeState::process_key(&mut inner_state, key, harness.merge_app_mut()).await;
+        }
+        assert_eq!(pick_order(&harness), vec![102, 100, 101]);
+
+        harness.app.pull_requests_mut()[1].selected = false;
+        inner_state.table_state.select(Some(1));
+        ModeState::process_key(
+            &mut inner_state,
+            KeyCode::Char('K'),
+            harness.merge_app_mut(),
+        )
+        .await;
+        assert_eq!(pick_order(&harness), vec![102, 100]);
+        harness.app.pull_requests_mut()[1].selected = true;
+
+        inner_state.table_state.select(Some(2));
+        ModeState::process_key(
+            &mut inner_state,
+            KeyCode::Char('J'),
+            harness.merge_app_mut(),
+        )
+        .await;
+        assert_eq!(pick_order(&harness), vec![100, 102, 101]);
+
+        for key in [
+            KeyCode::Char('<'),
+            KeyCode::Char('<'),
+            KeyCode::Char('<'),
+            KeyCode::Char('o'),
+        ] {
+            ModeState::process_key(&mut inner_state, key, harness.merge_app_mut()).await;
+        }
+        assert_eq!(inner_state.focused_column, PrColumn::Order);
+        assert_eq!(
+            inner_state.visible_pr_indices(harness.merge_app()),
+            vec![0, 2, 1]
+        );
+
+        let mut state = MergeState::PullRequestSelection(inner_state);
+        with_settings_and_module_path(module_path!(), || {
+            harness.render_merge_state(&mut state);
+            assert_snapshot!("manual_pick_order", harness.backend());
+        });
+
+        let MergeState::PullRequestSelection(inner_state) = &mut state else {
+            unreachable!();
+        };
+        ModeState::process_key(inner_state, KeyCode::Char('U'), harness.merge_app_mut()).await;
+        assert!(!harness.merge_app().has_manual_pick_order());
+        assert_eq!(pick_order(&harness), vec![100, 101, 102]);
+        ModeState::process_key(inner_state, KeyCode::Char('='), harness.merge_app_mut()).await;
+    }
+
+    /// # PR Selection State - Auto-Refresh New PRs
+    ///
+    /// Tests adding PRs found by the background auto-refresh.
+    ///
+    /// ## Test Scenario
+    /// - Loads two PRs, selects the first and moves the cursor to the second
+    /// - Holds the remaining test PRs as newly found by auto-refresh
+    /// - Renders the list, then presses 'a'
+    /// - Sends a tick with auto-refresh disabled
+    ///
+    /// ## Expected Outcome
+    /// - A "new PRs" banner is shown on the table border
+    /// - 'a' appends the new PRs without changing selections or the cursor
+    /// - No refresh starts when no interval is configured
+    #[tokio::test]
+    async fn test_pr_selection_auto_refresh_new_prs() {
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+
+        let mut prs = create_test_pull_requests();
+        let new_prs = prs.split_off(2);
+        let new_count = new_prs.len();
+        prs[0].selected = true;
+        *harness.app.pull_requests_mut() = prs;
+
+        let mut inner_state = PullRequestSelectionState::new();
+        inner_state.table_state.select(Some(1));
+        inner_state.new_prs = new_prs;
+        let mut state = MergeState::PullRequestSelection(inner_state);
+
+        with_settings_and_module_path(module_path!(), || {
+            harness.render_merge_state(&mut state);
+            assert_snapshot!("auto_refresh_new_prs", harness.backend());
+        });
+
+        let MergeState::PullRequestSelection(inner_state) = &mut state else {
+            unreachable!();
+        };
+        ModeState::process_key(inner_state, KeyCode::Char('a'), harness.merge_app_mut()).await;
+
+        assert_eq!(harness.merge_app().pull_requests().len(), 2 + new_count);
+        assert!(inner_state.new_prs.is_empty());
+        assert_eq!(inner_state.table_state.selected(), Some(1));
+        let selected: Vec<i32> = harness
+            .merge_app()
+            .get_selected_prs()
+            .iter()
+            .map(|pr| pr.pr.id)
+            .collect();
+        assert_eq!(selected, vec![100]);
+
+        ModeState::process_key(inner_state, KeyCode::Null, harness.merge_app_mut()).await;
+        assert!(inner_state.refresh_task.is_none());
+    }
+
+    /// # PR Selection State - Background Dependency Refinement
+    ///
+    /// Tests that the line-range analysis replaces the file-level graph on a tick.
+    ///
+    /// ## Test Scenario
+    /// - Sets a dependency graph and starts its refinement
+    /// - Renders the list, then sends ticks until the refinement finishes
+    ///
+    /// ## Expected Outcome
+    /// - The table title shows that dependencies are being refined
+    /// - The refined graph (no changes readable from the test repo path)
+    ///   replaces the original one
+    #[tokio::test]
+    async fn test_pr_selection_dependency_refinement() {
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+        harness
+            .merge_app_mut()
+            .set_dependency_graph(create_test_dependency_graph());
+        harness.merge_app_mut().refine_dependency_graph();
+        assert!(harness.merge_app().is_refining_dependencies());
+
+        let mut state = PullRequestSelectionState::new();
+        with_settings_and_module_path(module_path!(), || {
+            harness.render_state(&mut state);
+            assert_snapshot!("refining_dependencies", harness.backend());
+        });
+
+        for _ in 0..500 {
+            ModeState::process_key(&mut state, KeyCode::Null, harness.merge_app_mut()).await;
+            if !harness.merge_app().is_refining_dependencies() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert!(!harness.merge_app().is_refining_dependencies());
+        let graph = harness.merge_app().dependency_graph().unwrap();
+        assert!(
+            graph
+                .nodes
+                .values()
+                .all(|node| node.dependencies.is_empty())
+        );
+
+        // A new graph cancels the refinement of the previous one
+        harness.merge_app_mut().refine_dependency_graph();
+        harness
+            .merge_app_mut()
+            .set_dependency_graph(create_test_dependency_graph());
+        assert!(!harness.merge_app().is_refining_dependencies());
+    }
+
+    /// # PR Selection State - PR Tabs
+    ///
+    /// Tests switching between user-defined PR tabs.
+    ///
+    /// ## Test Scenario
+    /// - Configures an "Auth" tab matching titles and a "Tracking" tab
+    ///   matching PR 102's changed files
+    /// - Selects PR 100, switches tabs with ']' and '[' and selects PR 102
+    ///
+    /// ## Expected Outcome
+    /// - The tab bar shows per-tab counts and the global selection count
+    /// - Each tab lists only its PRs and the highlight moves into the tab
+    /// - Navigation stays within the tab and selections persist across tabs
+    #[tokio::test]
+    async fn test_pr_selection_tabs() {
+        let mut config = create_test_config_default();
+        if let AppConfig::Default { default, .. } = &mut config {
+            default.pr_tabs = crate::core::operations::build_pr_tabs(&[
+                crate::core::operations::PrTabConfig {
+                    name: "Auth".to_string(),
+                    titles: vec!["(?i)login|user".to_string()],
+                    ..Default::default()
+                },
+                crate::core::operations::PrTabConfig {
+                    name: "Tracking".to_string(),
+                    paths: vec!["src/analytics/**".to_string()],
+                    ..Default::default()
+                },
+            ])
+            .unwrap();
+        }
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+        let mut graph = PRDependencyGraph::new();
+        let mut node = PRDependencyNode::new(102, "Add analytics tracking".to_string(), false);
+        node.changed_files = vec!["src/analytics/events.rs".to_string()];
+        graph.add_node(node);
+        harness.merge_app_mut().set_dependency_graph(graph);
+
+        let mut state = PullRequestSelectionState::new();
+        harness.render_state(&mut state);
+        ModeState::process_key(&mut state, KeyCode::Char(' '), harness.merge_app_mut()).await;
+        ModeState::process_key(&mut state, KeyCode::Char(']'), harness.merge_app_mut()).await;
+        ModeState::process_key(&mut state, KeyCode::Down, harness.merge_app_mut()).await;
+        assert_eq!(state.table_state.selected(), Some(1));
+        ModeState::process_key(&mut state, KeyCode::Down, harness.merge_app_mut()).await;
+        assert_eq!(
+            state.table_state.selected(),
+            Some(0),
+            "wraps within the tab"
+        );
+
+        with_settings_and_module_path(module_path!(), || {
+            harness.render_state(&mut state);
+            assert_snapshot!("tabs_auth", harness.backend());
+        });
+
+        ModeState::process_key(&mut state, KeyCode::Char(']'), harness.merge_app_mut()).await;
+        assert_eq!(state.table_state.selected(), Some(2));
+        ModeState::process_key(&mut state, KeyCode::Char(' '), harness.merge_app_mut()).await;
+        with_settings_and_module_path(module_path!(), || {
+            harness.render_state(&mut state);
+            assert_snapshot!("tabs_tracking", harness.backend());
+        });
+
+        ModeState::process_key(&mut state, KeyCode::Char('['), harness.merge_app_mut()).await;
+        ModeState::process_key(&mut state, KeyCode::Char('['), harness.merge_app_mut()).await;
+        assert_eq!(state.active_tab, 0);
+        let selected: Vec<i32> = harness
+            .merge_app()
+            .get_selected_prs()
+            .iter()
+            .map(|pr| pr.pr.id)
+            .collect();
+        assert_eq!(selected, vec![100, 102]);
     }
 
-    /// # PR Selection State - Normal Display
+    /// # PR Selection State - Large Selection Confirmation
     ///
-    /// Tests the pull request selection screen with normal data.
+    /// Tests the confirmation shown when more PRs than the soft cap are
+    /// selected.
     ///
     /// ## Test Scenario
-    /// - Creates a PR selection state
-    /// - Loads test pull requests into the app
-    /// - Renders the PR table and work item details
+    /// - Sets a soft cap of 1 and selects two PRs
+    /// - Presses Enter, renders the dialog with a known diff size, then Esc
+    /// - Presses Enter and 'y'
     ///
     /// ## Expected Outcome
-    /// - Should display PR table with columns: checkbox, PR#, Date, Title, Author, Work Items
-    /// - Should show work item details panel
-    /// - Should display help text at bottom
-    #[test]
-    fn test_pr_selection_normal() {
-        with_settings_and_module_path(module_path!(), || {
-            let config = create_test_config_default();
-            let mut harness = TuiTestHarness::with_config(config);
-
-            *harness.app.pull_requests_mut() = create_test_pull_requests();
+    /// - Enter opens the dialog instead of the version input
+    /// - Esc returns to the selection; 'y' continues to the version input
+    #[tokio::test]
+    async fn test_pr_selection_large_selection_confirm() {
+        let mut config = create_test_config_default();
+        if let AppConfig::Default { default, .. } = &mut config {
+            default.selection_soft_cap = Some(1);
+        }
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+        let mut state = PullRequestSelectionState::new();
+        harness.render_state(&mut state);
+        ModeState::process_key(&mut state, KeyCode::Char(' '), harness.merge_app_mut()).await;
+        ModeState::process_key(&mut state, KeyCode::Down, harness.merge_app_mut()).await;
+        ModeState::process_key(&mut state, KeyCode::Char(' '), harness.merge_app_mut()).await;
 
-            let mut state = MergeState::PullRequestSelection(PullRequestSelectionState::new());
-            harness.render_merge_state(&mut state);
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Enter, harness.merge_app_mut()).await;
+        assert!(matches!(result, StateChange::Keep));
+        assert!(state.large_selection_confirm.is_some());
 
-            assert_snapshot!("normal_display", harness.backend());
+        state.large_selection_confirm = Some(SelectionDiffSize::Ready(DiffStat {
+            files: 12,
+            insertions: 340,
+            deletions: 95,
+        }));
+        with_settings_and_module_path(module_path!(), || {
+            harness.render_state(&mut state);
+            assert_snapshot!("large_selection_confirm", harness.backend());
         });
+
+        ModeState::process_key(&mut state, KeyCode::Esc, harness.merge_app_mut()).await;
+        assert!(state.large_selection_confirm.is_none());
+
+        ModeState::process_key(&mut state, KeyCode::Enter, harness.merge_app_mut()).await;
+        let result =
+            ModeState::process_key(&mut state, KeyCode::Char('y'), harness.merge_app_mut()).await;
+        assert!(matches!(
+            result,
+            StateChange::Change(MergeState::VersionInput(_))
+        ));
     }
 
-    /// # PR Selection State - Empty List
+    /// # PR Selection State - Import Selection From File
     ///
-    /// Tests the PR selection screen with no pull requests.
+    /// Tests importing a selection file through the 'f' prompt.
     ///
     /// ## Test Scenario
-    /// - Creates a PR selection state
-    /// - Does not load any pull requests
-    /// - Renders the empty state
+    /// - Writes a file listing PR 100, work item 1002 and the unknown PR 999
+    /// - Presses 'f', types the file path and presses Enter
+    /// - Renders the list
     ///
     /// ## Expected Outcome
-    /// - Should display "No pull requests found" message
-    /// - Should show quit instruction
-    #[test]
-    fn test_pr_selection_empty() {
-        with_settings_and_module_path(module_path!(), || {
-            let config = create_test_config_default();
-            let mut harness = TuiTestHarness::with_config(config);
+    /// - PRs 100 and 101 are selected
+    /// - The table border reports the selection and the id that was not found
+    #[tokio::test]
+    async fn test_pr_selection_import_selection() {
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
 
-            // Leave pull_requests empty
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("picks.txt");
+        std::fs::write(&path, "!100 Fix login\n#1002\n!999\n").unwrap();
 
-            let mut state = MergeState::PullRequestSelection(PullRequestSelectionState::new());
-            harness.render_merge_state(&mut state);
+        let mut inner_state = PullRequestSelectionState::new();
+        ModeState::process_key(
+            &mut inner_state,
+            KeyCode::Char('f'),
+            harness.merge_app_mut(),
+        )
+        .await;
+        assert!(inner_state.import_mode);
+        assert!(inner_state.action_map().captures_text_input());
 
-            assert_snapshot!("empty_list", harness.backend());
+        for c in path.display().to_string().chars() {
+            ModeState::process_key(&mut inner_state, KeyCode::Char(c), harness.merge_app_mut())
+                .await;
+        }
+        ModeState::process_key(&mut inner_state, KeyCode::Enter, harness.merge_app_mut()).await;
+
+        assert!(!inner_state.import_mode);
+        let selected: Vec<i32> = harness
+            .merge_app()
+            .get_selected_prs()
+            .iter()
+            .map(|pr| pr.pr.id)
+            .collect();
+        assert_eq!(selected, vec![100, 101]);
+
+        let mut state = MergeState::PullRequestSelection(inner_state);
+        with_settings_and_module_path(module_path!(), || {
+            harness.render_merge_state(&mut state);
+            assert_snapshot!("import_selection", harness.backend());
         });
     }
 
-    /// # PR Selection State - With Selections
+    /// # PR Selection State - Selection Sessions
     ///
-    /// Tests the PR selection screen with some PRs selected.
+    /// Tests restoring a selection session and saving changes to it.
     ///
     /// ## Test Scenario
-    /// - Creates a PR selection state
-    /// - Loads test pull requests
-    /// - Marks some PRs as selected
-    /// - Renders the display
+    /// - Saves a session selecting PR 101 and the unknown PR 999
+    /// - Restores it into a fresh PR selection state
+    /// - Selects the first PR and ticks, then restores a session of another
+    ///   repository
     ///
     /// ## Expected Outcome
-    /// - Should display checkmarks for selected PRs
-    /// - Selected rows should have different background color
-    /// - Text color should change for selected items
-    #[test]
-    fn test_pr_selection_with_selections() {
-        with_settings_and_module_path(module_path!(), || {
-            let config = create_test_config_default();
-            let mut harness = TuiTestHarness::with_config(config);
-
-            let mut prs = create_test_pull_requests();
-            prs[0].selected = true;
-            prs[2].selected = true;
-            *harness.app.pull_requests_mut() = prs;
+    /// - PR 101 is selected and the table border reports the missing PR
+    /// - The session file is rewritten with the new selection
+    /// - Sessions of another repository are not restored or saved over
+    #[tokio::test]
+    async fn test_pr_selection_session() {
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
 
-            let mut state = MergeState::PullRequestSelection(PullRequestSelectionState::new());
-            harness.render_merge_state(&mut state);
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("release.json");
+        let app = harness.merge_app();
+        let repository = last_run_key(app.organization(), app.project(), app.repository());
+        SelectionSession {
+            repository,
+            selected_pr_ids: vec![101, 999],
+            ..Default::default()
+        }
+        .save(&path)
+        .unwrap();
+        harness.merge_app_mut().set_session_path(Some(path.clone()));
+
+        let mut inner_state = PullRequestSelectionState::new();
+        assert!(!inner_state.restore_session(harness.merge_app_mut()));
+        let selected = |app: &MergeApp| -> Vec<i32> {
+            app.get_selected_prs().iter().map(|pr| pr.pr.id).collect()
+        };
+        assert_eq!(selected(harness.merge_app()), vec![101]);
+        assert_eq!(
+            inner_state.import_message,
+            Some((
+                "Restored session '': 1 PR selected, 1 no longer listed".to_string(),
+                Color::Yellow
+            ))
+        );
 
-            assert_snapshot!("with_selections", harness.backend());
-        });
+        inner_state.table_state.select(Some(0));
+        ModeState::process_key(
+            &mut inner_state,
+            KeyCode::Char(' '),
+            harness.merge_app_mut(),
+        )
+        .await;
+        ModeState::process_key(&mut inner_state, KeyCode::Null, harness.merge_app_mut()).await;
+        let saved = SelectionSession::load(&path).unwrap().unwrap();
+        assert_eq!(saved.selected_pr_ids, selected(harness.merge_app()));
+        assert_eq!(saved.selected_pr_ids, vec![100, 101]);
+
+        let other = temp_dir.path().join("other.json");
+        SelectionSession {
+            repository: "other/proj/repo".to_string(),
+            selected_pr_ids: vec![100],
+            ..Default::default()
+        }
+        .save(&other)
+        .unwrap();
+        harness
+            .merge_app_mut()
+            .set_session_path(Some(other.clone()));
+        let mut inner_state = PullRequestSelectionState::new();
+        assert!(!inner_state.restore_session(harness.merge_app_mut()));
+        assert!(harness.merge_app().session_path().is_none());
+        ModeState::process_key(&mut inner_state, KeyCode::Null, harness.merge_app_mut()).await;
+        let untouched = SelectionSession::load(&other).unwrap().unwrap();
+        assert_eq!(untouched.selected_pr_ids, vec![100]);
     }
 
-    /// # PR Selection State - With Dependencies
+    /// # PR Selection State - Filter By Author, Reviewer Or Path
     ///
-    /// Tests the PR selection screen with dependency information displayed.
+    /// Tests narrowing the PR list through the 'F' prompt.
     ///
     /// ## Test Scenario
-    /// - Creates a PR selection state
-    /// - Loads test pull requests with a dependency graph
-    /// - PR 100: 2 partial deps, 1 full dep (displays "2 P / 1 F")
-    /// - PR 101: 1 partial dep (displays "1 P")
-    /// - PR 102: 3 full deps (displays "3 F")
+    /// - Makes Alice Johnson a reviewer of PR 101
+    /// - Presses 'F', types `reviewer:alice` and presses Enter
+    /// - Renders the list, then enters an invalid filter and an identity id
     ///
     /// ## Expected Outcome
-    /// - Should display PR Dependencies column with correct format
-    /// - Partial deps shown with "P" suffix
-    /// - Full deps shown with "F" suffix
-    /// - Combined format: "X P / Y F"
-    #[test]
-    fn test_pr_selection_with_dependencies() {
-        with_settings_and_module_path(module_path!(), || {
-            let config = create_test_config_default();
-            let mut harness = TuiTestHarness::with_config(config);
+    /// - Only PR 101 is listed and the table title shows the filter
+    /// - The invalid filter is reported in the prompt
+    /// - A new identity id reloads the PR list
+    #[tokio::test]
+    async fn test_pr_selection_filter() {
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+        let mut prs = create_test_pull_requests();
+        prs[1].pr.reviewers = vec![CreatedBy {
+            display_name: "Alice Johnson".to_string(),
+        }];
+        *harness.app.pull_requests_mut() = prs;
 
-            *harness.app.pull_requests_mut() = create_test_pull_requests();
-            harness
-                .merge_app_mut()
-                .set_dependency_graph(create_test_dependency_graph());
+        let mut inner_state = PullRequestSelectionState::new();
+        async fn type_filter(
+            state: &mut PullRequestSelectionState,
+            app: &mut MergeApp,
+            filter: &str,
+        ) -> StateChange<MergeState> {
+            ModeState::process_key(state, KeyCode::Char('F'), app).await;
+            while !state.filter_input.is_empty() {
+                ModeState::process_key(state, KeyCode::Backspace, app).await;
+            }
+            for c in filter.chars() {
+                ModeState::process_key(state, KeyCode::Char(c), app).await;
+            }
+            ModeState::process_key(state, KeyCode::Enter, app).await
+        }
 
-            let mut state = MergeState::PullRequestSelection(PullRequestSelectionState::new());
-            harness.render_merge_state(&mut state);
+        let result = type_filter(&mut inner_state, harness.merge_app_mut(), "reviewer:alice").await;
+        assert!(matches!(result, StateChange::Keep));
+        assert!(!inner_state.filter_mode);
+        assert_eq!(inner_state.visible_pr_indices(harness.merge_app()), vec![1]);
 
-            assert_snapshot!("with_dependencies", harness.backend());
+        let mut state = MergeState::PullRequestSelection(inner_state);
+        with_settings_and_module_path(module_path!(), || {
+            harness.render_merge_state(&mut state);
+            assert_snapshot!("pr_filter", harness.backend());
         });
+        let MergeState::PullRequestSelection(mut inner_state) = state else {
+            unreachable!();
+        };
+
+        type_filter(&mut inner_state, harness.merge_app_mut(), "label:bug").await;
+        assert!(inner_state.filter_mode);
+        assert!(inner_state.filter_error.is_some());
+        ModeState::process_key(&mut inner_state, KeyCode::Esc, harness.merge_app_mut()).await;
+        assert_eq!(
+            harness.merge_app().pr_filter().to_string(),
+            "reviewer:alice"
+        );
+
+        let result = type_filter(
+            &mut inner_state,
+            harness.merge_app_mut(),
+            "author:0b1c2d3e-4f50-6172-8394-a5b6c7d8e9f0",
+        )
+        .await;
+        assert!(matches!(
+            result,
+            StateChange::Change(MergeState::DataLoading(_))
+        ));
     }
 
     /// # PR Selection State - Search Mode
@@ -4468,6 +7498,55 @@ mod tests {
         });
     }
 
+    /// # PR Selection State - Terminal Hyperlinks
+    ///
+    /// Tests linking PR numbers and work item ids when the terminal supports
+    /// hyperlinks.
+    ///
+    /// ## Test Scenario
+    /// - Renders the PR table with the details pane, with hyperlinks
+    ///   disabled and then enabled
+    ///
+    /// ## Expected Outcome
+    /// - Without hyperlinks no cell holds a link
+    /// - With hyperlinks the PR numbers in the table and the work item ids in
+    ///   the table and details header link to their pages
+    /// - The plain text of the screen is the same either way
+    #[test]
+    fn test_pr_selection_hyperlinks() {
+        let linked_urls = |buffer: &ratatui::buffer::Buffer| -> Vec<String> {
+            buffer
+                .content
+                .iter()
+                .filter_map(|cell| cell.symbol().strip_prefix("\x1B]8;;"))
+                .filter_map(|rest| rest.split('\x1B').next())
+                .map(str::to_string)
+                .collect()
+        };
+        let mut harness = TuiTestHarness::with_config(create_test_config_default());
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+
+        let mut state = MergeState::PullRequestSelection(PullRequestSelectionState::new());
+        harness.render_merge_state(&mut state);
+        let plain = crate::ui::screen_dump::buffer_text(harness.backend().buffer());
+        assert!(linked_urls(harness.backend().buffer()).is_empty());
+
+        harness
+            .merge_app_mut()
+            .set_hyperlinks(crate::ui::Hyperlinks { enabled: true });
+        harness.render_merge_state(&mut state);
+        let buffer = harness.backend().buffer();
+        let urls = linked_urls(buffer);
+        let app = harness.merge_app();
+        let pr = &app.pull_requests()[0];
+        let work_item_url = app.urls().work_item_url(pr.work_items[0].id);
+
+        assert!(urls.contains(&app.urls().pull_request_url(pr.pr.id)));
+        // Once in the table, once in the details header
+        assert_eq!(urls.iter().filter(|url| **url == work_item_url).count(), 2);
+        assert_eq!(crate::ui::screen_dump::buffer_text(buffer), plain);
+    }
+
     /// # PR Selection State - Dependency Dialog
     ///
     /// Tests the PR selection screen with dependency dialog open.
@@ -4529,6 +7608,72 @@ mod tests {
         assert!(state.show_details);
     }
 
+    /// # PR Selection State - PR Description Tab
+    ///
+    /// Tests the details pane showing the selected PR's description.
+    ///
+    /// ## Test Scenario
+    /// - Gives the first PR a Markdown description
+    /// - Switches the details pane to the PR description tab
+    /// - Renders the display
+    ///
+    /// ## Expected Outcome
+    /// - Details pane shows the rendered Markdown instead of work items
+    #[test]
+    fn test_pr_selection_pr_description_tab() {
+        with_settings_and_module_path(module_path!(), || {
+            let config = create_test_config_default();
+            let mut harness = TuiTestHarness::with_config(config);
+
+            let mut prs = create_test_pull_requests();
+            prs[0].pr.description = Some(
+                "## Summary\nFixes the **login** timeout.\n\n- Retries `auth` once\n- [x] Tested locally"
+                    .to_string(),
+            );
+            *harness.app.pull_requests_mut() = prs;
+
+            let mut selection_state = PullRequestSelectionState::new();
+            selection_state.table_state.select(Some(0));
+            selection_state.details_tab = DetailsTab::PullRequest;
+            let mut state = MergeState::PullRequestSelection(selection_state);
+            harness.render_merge_state(&mut state);
+
+            assert_snapshot!("pr_description_tab", harness.backend());
+        });
+    }
+
+    /// # PR Selection - Switch Details Tab with Tab Key
+    ///
+    /// Tests that Tab switches the details pane content.
+    ///
+    /// ## Test Scenario
+    /// - Presses Tab twice with details visible
+    /// - Hides details and presses Tab again
+    ///
+    /// ## Expected Outcome
+    /// - Tab alternates between work items and PR description
+    /// - Tab does nothing while the details pane is hidden
+    #[tokio::test]
+    async fn test_pr_selection_switch_details_tab() {
+        let config = create_test_config_default();
+        let mut harness = TuiTestHarness::with_config(config);
+
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+
+        let mut state = PullRequestSelectionState::new();
+        assert_eq!(state.details_tab, DetailsTab::WorkItems);
+
+        ModeState::process_key(&mut state, KeyCode::Tab, harness.merge_app_mut()).await;
+        assert_eq!(state.details_tab, DetailsTab::PullRequest);
+
+        ModeState::process_key(&mut state, KeyCode::Tab, harness.merge_app_mut()).await;
+        assert_eq!(state.details_tab, DetailsTab::WorkItems);
+
+        state.show_details = false;
+        ModeState::process_key(&mut state, KeyCode::Tab, harness.merge_app_mut()).await;
+        assert_eq!(state.details_tab, DetailsTab::WorkItems);
+    }
+
     /// # PR Selection - Open Dependency Dialog with 'g' Key
     ///
     /// Tests that pressing 'g' opens the dependency dialog.
@@ -4945,6 +8090,7 @@ mod tests {
                         commit_id: "abc123".to_string(),
                     }),
                     labels: None,
+                    reviewers: Vec::new(),
                 },
                 work_items: vec![WorkItem {
                     id: 1001,
@@ -4957,6 +8103,7 @@ mod tests {
                         description: None,
                         repro_steps: None,
                         state_color: None,
+                        custom_fields: Default::default(),
                     },
                     history: vec![],
                 }],
@@ -4976,6 +8123,7 @@ mod tests {
                         commit_id: "def456".to_string(),
                     }),
                     labels: None,
+                    reviewers: Vec::new(),
                 },
                 work_items: vec![
                     WorkItem {
@@ -4989,6 +8137,7 @@ mod tests {
                             description: None,
                             repro_steps: None,
                             state_color: None,
+                            custom_fields: Default::default(),
                         },
                         history: vec![],
                     },
@@ -5003,6 +8152,7 @@ mod tests {
                             description: None,
                             repro_steps: None,
                             state_color: None,
+                            custom_fields: Default::default(),
                         },
                         history: vec![],
                     },
@@ -5023,6 +8173,7 @@ mod tests {
                         commit_id: "ghi789".to_string(),
                     }),
                     labels: None,
+                    reviewers: Vec::new(),
                 },
                 work_items: vec![WorkItem {
                     id: 1002,
@@ -5035,6 +8186,7 @@ mod tests {
                         description: None,
                         repro_steps: None,
                         state_color: None,
+                        custom_fields: Default::default(),
                     },
                     history: vec![],
                 }],
@@ -5054,6 +8206,7 @@ mod tests {
                         commit_id: "jkl012".to_string(),
                     }),
                     labels: None,
+                    reviewers: Vec::new(),
                 },
                 work_items: vec![WorkItem {
                     id: 1003,
@@ -5066,6 +8219,7 @@ mod tests {
                         description: None,
                         repro_steps: None,
                         state_color: None,
+                        custom_fields: Default::default(),
                     },
                     history: vec![],
                 }],
@@ -5348,6 +8502,7 @@ mod tests {
     /// ## Expected Outcome
     /// - Settings dialog shows "Dependency analysis: Skipped (not configured)"
     /// - Dependency highlights toggle is dimmed with "(no data)" suffix
+    /// - The degraded-mode banner lists the features that are off
     #[test]
     fn test_pr_selection_settings_dialog_deps_skipped() {
         with_settings_and_module_path(module_path!(), || {
@@ -5367,6 +8522,100 @@ mod tests {
         });
     }
 
+    /// # PR Selection - Local Repository Prompt
+    ///
+    /// Tests the prompt opened from the degraded-mode banner.
+    ///
+    /// ## Test Scenario
+    /// - No local_repo is configured
+    /// - Presses 'L' and types a path to a file that is not a repository
+    /// - Presses Enter and renders the state
+    ///
+    /// ## Expected Outcome
+    /// - The prompt stays open and reports that the path cannot be used
+    /// - The merge stays in degraded mode
+    #[tokio::test]
+    async fn test_pr_selection_local_repo_prompt() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let file = temp_dir.path().join("notes");
+        std::fs::create_dir(&file).unwrap();
+        std::fs::write(file.join("readme.txt"), "not a repository").unwrap();
+
+        let config = create_test_config_all_defaults();
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+
+        let mut state = PullRequestSelectionState::new();
+        ModeState::process_key(&mut state, KeyCode::Char('L'), harness.merge_app_mut()).await;
+        assert!(state.local_repo_mode);
+        state.local_repo_input = "/tmp/mergers-".to_string();
+        for c in "repo".chars() {
+            ModeState::process_key(&mut state, KeyCode::Char(c), harness.merge_app_mut()).await;
+        }
+        assert_eq!(state.local_repo_input, "/tmp/mergers-repo");
+
+        state.local_repo_input = file.display().to_string();
+        ModeState::process_key(&mut state, KeyCode::Enter, harness.merge_app_mut()).await;
+        assert!(state.local_repo_mode);
+        assert!(harness.merge_app().is_degraded());
+
+        state.local_repo_input = "/path/to/notes".to_string();
+        state.local_repo_message = Some((
+            "/path/to/notes is neither a git repository nor an empty directory".to_string(),
+            Color::Red,
+        ));
+        with_settings_and_module_path(module_path!(), || {
+            let mut state = MergeState::PullRequestSelection(state);
+            harness.render_merge_state(&mut state);
+            assert_snapshot!("local_repo_prompt", harness.backend());
+        });
+    }
+
+    /// # PR Selection - Setting a Local Repository
+    ///
+    /// Tests leaving degraded mode with an existing repository.
+    ///
+    /// ## Test Scenario
+    /// - No local_repo is configured
+    /// - Uses a freshly initialized git repository from the prompt
+    ///
+    /// ## Expected Outcome
+    /// - The repository becomes the local repository and the prompt closes
+    /// - Dependency analysis starts without restarting
+    /// - 'L' no longer opens the prompt
+    #[tokio::test]
+    async fn test_pr_selection_set_local_repo() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        let output = std::process::Command::new("git")
+            .current_dir(repo)
+            .args(["init", "-q"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+
+        let config = create_test_config_all_defaults();
+        let mut harness = TuiTestHarness::with_config(config);
+        *harness.app.pull_requests_mut() = create_test_pull_requests();
+        assert!(harness.merge_app().is_degraded());
+
+        let mut state = PullRequestSelectionState::new();
+        state.local_repo_mode = true;
+        state.use_local_repo(harness.merge_app_mut(), repo);
+
+        assert!(!state.local_repo_mode);
+        assert!(state.local_repo_message.is_none());
+        assert!(!harness.merge_app().is_degraded());
+        assert_eq!(
+            harness.merge_app().local_repo(),
+            Some(repo.display().to_string().as_str())
+        );
+        assert!(harness.merge_app().is_refining_dependencies());
+
+        ModeState::process_key(&mut state, KeyCode::Char('L'), harness.merge_app_mut()).await;
+        assert!(!state.local_repo_mode);
+    }
+
     /// # PR Selection - Settings Dialog With Dependency Data Available
     ///
     /// Tests that the settings dialog does NOT show the skip status line
@@ -5400,4 +8649,40 @@ mod tests {
             assert_snapshot!("settings_dialog_deps_available", harness.backend());
         });
     }
+
+    /// # PR Selection Action Map Follows Sub-Mode
+    ///
+    /// Tests that the action map reflects the active sub-mode.
+    ///
+    /// ## Test Scenario
+    /// - Builds the action map in normal mode, search input mode and with
+    ///   the settings dialog open
+    ///
+    /// ## Expected Outcome
+    /// - Normal mode lists selection and quit keys without capturing text
+    /// - Search input captures text so '?' can be typed
+    /// - The settings dialog only lists dialog keys
+    #[test]
+    fn test_pr_selection_action_map() {
+        let mut state = PullRequestSelectionState::new();
+
+        let map = ModeState::action_map(&state);
+        assert!(!map.captures_text_input());
+        assert_eq!(
+            map.lookup(KeyCode::Char(' ')).unwrap().description,
+            "Toggle PR"
+        );
+        assert!(map.lookup(KeyCode::Char('q')).is_some());
+
+        state.enter_search_mode();
+        let map = ModeState::action_map(&state);
+        assert!(map.captures_text_input());
+        assert!(map.lookup(KeyCode::Char('q')).is_none());
+
+        state.exit_search_mode();
+        state.show_settings_dialog = true;
+        let map = ModeState::action_map(&state);
+        assert!(map.lookup(KeyCode::Char(',')).is_some());
+        assert!(map.lookup(KeyCode::Char('/')).is_none());
+    }
 }
@@ -1,5 +1,5 @@
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::{
-    core::state::MergeStatus,
     release_notes,
     ui::apps::MergeApp,
     ui::state::default::{CompletionState, MergeState},
@@ -81,8 +81,11 @@ impl ReleaseNotesExportState {
             version,
             &app.cherry_pick_items,
             &app.pull_requests,
-            app.organization(),
-            app.project(),
+            &app.urls(),
+            &app.picked_commits(),
+            app.package_version(),
+            app.clock().local_now().date_naive(),
+            app.release_notes_locale(),
         );
 
         std::fs::write(&path, &content).map_err(|e| format!("Failed to write file: {e}"))?;
@@ -567,12 +570,7 @@ impl ModeState for ReleaseNotesExportState {
                     StateChange::Change(MergeState::Completion(CompletionState::new()))
                 }
                 KeyCode::Char('q') => {
-                    app.with_state_file_mut(|state_file| {
-                        state_file.final_status = Some(MergeStatus::Success);
-                        state_file.completed_at = Some(chrono::Utc::now());
-                        let _ = state_file.save_for_repo();
-                    });
-                    let _ = app.cleanup_state_file();
+                    app.finish_merge();
                     StateChange::Exit
                 }
                 _ => StateChange::Keep,
@@ -580,6 +578,45 @@ impl ModeState for ReleaseNotesExportState {
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        let map = ActionMap::new("Release Notes Export");
+        match &self.phase {
+            ReleaseNotesPhase::PathInput => map
+                .with_text_input()
+                .bind(
+                    ActionCategory::Navigation,
+                    &[KeyCode::Left, KeyCode::Right, KeyCode::Home, KeyCode::End],
+                    "Move cursor",
+                )
+                .bind(
+                    ActionCategory::Actions,
+                    &[KeyCode::Tab, KeyCode::BackTab],
+                    "Complete path",
+                )
+                .bind(
+                    ActionCategory::Actions,
+                    &[KeyCode::Enter],
+                    "Write release notes",
+                )
+                .bind(
+                    ActionCategory::General,
+                    &[KeyCode::Esc],
+                    "Back to completion summary",
+                ),
+            ReleaseNotesPhase::Success(_) | ReleaseNotesPhase::Error(_) => map
+                .bind(
+                    ActionCategory::General,
+                    &[KeyCode::Enter, KeyCode::Esc],
+                    "Back to completion summary",
+                )
+                .bind(
+                    ActionCategory::General,
+                    &[KeyCode::Char('q')],
+                    "Finish and exit",
+                ),
+        }
+    }
+
     fn name(&self) -> &'static str {
         "ReleaseNotesExport"
     }
@@ -588,6 +625,7 @@ impl ModeState for ReleaseNotesExportState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::UrlBuilder;
     use crate::ui::{
         snapshot_testing::with_settings_and_module_path,
         testing::{
@@ -922,12 +960,15 @@ mod tests {
             "v1.0.0",
             &items,
             &prs,
-            "test-org",
-            "test-project",
+            &UrlBuilder::new("test-org", "test-project", "test-repo"),
+            &std::collections::HashMap::new(),
+            None,
+            chrono::NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
+            &release_notes::ReleaseNotesLocale::default(),
         );
 
         assert!(content.contains("# Release Notes - v1.0.0"));
-        assert!(content.contains("**Release Date:**"));
+        assert!(content.contains("**Release Date:** 2025-01-15"));
         assert!(content.contains("work item(s) included in this release"));
     }
 
@@ -2,14 +2,19 @@
 #![allow(deprecated)]
 
 use super::MergeState;
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::{
     api::AzureDevOpsClient,
-    core::state::{MergePhase, StateCreateConfig, StateManager},
+    core::state::{
+        MergePhase, StateCreateConfig, StateManager, create_run_dir, prune_expired_run_dirs,
+        run_clone_path,
+    },
     git,
     models::CherryPickItem,
     ui::apps::MergeApp,
     ui::state::typed::{ModeState, StateChange},
     ui::state::{CherryPickState, ErrorState},
+    utils::{Clock, IdGen},
 };
 use async_trait::async_trait;
 use crossterm::event::KeyCode;
@@ -111,6 +116,8 @@ pub struct SetupContext {
     pub target_branch: String,
     /// Version string for branch naming
     pub version: String,
+    /// Version the worktree is named after (includes the target in a fan-out)
+    pub worktree_version: String,
     /// Whether to run git hooks
     pub run_hooks: bool,
     /// Selected PRs with their merge commits for cherry-picking
@@ -119,6 +126,10 @@ pub struct SetupContext {
     pub state_manager: Arc<Mutex<StateManager>>,
     /// Configuration for state file creation
     pub state_config: StateCreateConfig,
+    /// Clock naming the run directory and dating pruned ones
+    pub clock: Arc<dyn Clock>,
+    /// Source of the run directory's id
+    pub ids: Arc<dyn IdGen>,
 }
 
 /// Minimal PR info needed for cherry-pick preparation.
@@ -133,6 +144,7 @@ impl SetupContext {
     /// Extracts setup context from a MergeApp instance.
     pub fn from_app(app: &MergeApp) -> Option<Self> {
         let version = app.version()?.to_string();
+        let worktree_version = app.worktree_version()?;
         let selected_prs = app
             .get_selected_prs()
             .iter()
@@ -153,10 +165,13 @@ impl SetupContext {
             local_repo: app.local_repo().map(String::from),
             target_branch: app.target_branch().to_string(),
             version,
+            worktree_version,
             run_hooks: app.run_hooks(),
             selected_prs,
             state_manager: app.state_manager(),
             state_config: app.state_create_config(),
+            clock: app.clock().clone(),
+            ids: app.ids().clone(),
         })
     }
 }
@@ -594,7 +609,7 @@ impl SetupRepoState {
         app: &mut MergeApp,
         error: git::RepositorySetupError,
     ) -> StateChange<MergeState> {
-        let version = app.version().unwrap();
+        let version = app.worktree_version().unwrap();
 
         match error {
             git::RepositorySetupError::BranchExists(branch_name) => {
@@ -615,7 +630,7 @@ impl SetupRepoState {
                 // Force remove the worktree before retrying
                 if let Some(repo_path) = app.local_repo()
                     && let Err(e) =
-                        git::force_remove_worktree(std::path::Path::new(repo_path), version)
+                        git::force_remove_worktree(std::path::Path::new(repo_path), &version)
                 {
                     app.set_error_message(Some(format!("Failed to force remove worktree: {}", e)));
                     return StateChange::Change(MergeState::Error(ErrorState::new()));
@@ -642,7 +657,24 @@ impl SetupRepoState {
 /// This function executes all wizard steps sequentially, sending progress messages
 /// to the UI through the provided channel. The UI can then update the display
 /// as each step starts and completes.
-async fn run_setup_task(ctx: SetupContext, tx: mpsc::Sender<ProgressMessage>) {
+async fn run_setup_task(mut ctx: SetupContext, tx: mpsc::Sender<ProgressMessage>) {
+    // Artifacts of the run, including a clone, go to its run directory
+    if let Err(e) = prune_expired_run_dirs(ctx.clock.as_ref()) {
+        tracing::warn!("Failed to prune run directories: {}", e);
+    }
+    match create_run_dir(ctx.clock.as_ref(), ctx.ids.as_ref()) {
+        Ok(run_dir) => ctx.state_config.run_dir = Some(run_dir),
+        Err(e) => {
+            let _ = tx
+                .send(ProgressMessage::Error(SetupError::Other(format!(
+                    "Failed to create run directory: {}",
+                    e
+                ))))
+                .await;
+            return;
+        }
+    }
+
     // Accumulated data passed between steps
     let mut ssh_url: Option<String> = None;
     let mut repo_path: Option<PathBuf> = None;
@@ -763,9 +795,10 @@ async fn execute_step_impl(
                     }
 
                     // Check 2: Verify worktree doesn't already exist (recoverable via 'f')
-                    match git::worktree_exists(base_path, &ctx.version) {
+                    match git::worktree_exists(base_path, &ctx.worktree_version) {
                         Ok(true) => {
-                            let worktree_path = base_path.join(format!("next-{}", ctx.version));
+                            let worktree_path =
+                                base_path.join(format!("next-{}", ctx.worktree_version));
                             return Err(SetupError::WorktreeExists(
                                 worktree_path.display().to_string(),
                             ));
@@ -832,11 +865,16 @@ async fn execute_step_impl(
             if ctx.is_clone_mode {
                 // Clone mode
                 let url = ssh_url.clone().unwrap_or_default();
-                match git::shallow_clone_repo(&url, &ctx.target_branch, ctx.run_hooks) {
-                    Ok((path, _temp_dir)) => {
-                        // Note: temp_dir ownership is tricky across threads
-                        // For now, we leak it (it will be cleaned up on process exit)
-                        // A better solution would be to pass it back through the channel
+                let Some(run_dir) = &ctx.state_config.run_dir else {
+                    return Err(SetupError::Other(
+                        "No run directory to clone into".to_string(),
+                    ));
+                };
+                let clone_path = run_clone_path(run_dir);
+                let clone = git::shallow_clone_repo_into(&url, &ctx.target_branch, &clone_path)
+                    .map(|()| clone_path);
+                match clone {
+                    Ok(path) => {
                         *repo_path = Some(path.clone());
                         *is_worktree = false;
                         Ok(StepResult {
@@ -854,7 +892,7 @@ async fn execute_step_impl(
                     match git::create_worktree(
                         &base_path,
                         &ctx.target_branch,
-                        &ctx.version,
+                        &ctx.worktree_version,
                         ctx.run_hooks,
                     ) {
                         Ok(worktree_path) => {
@@ -912,6 +950,11 @@ async fn execute_step_impl(
             if let Some(path) = repo_path {
                 match git::create_branch(path, &name) {
                     Ok(()) => {
+                        // Best effort: the tag only helps diffing the release afterwards
+                        if let Err(e) = git::create_base_tag(path, &ctx.target_branch, &ctx.version)
+                        {
+                            tracing::warn!("Failed to tag the patch branch base: {:#}", e);
+                        }
                         *branch_name = Some(name.clone());
                         Ok(StepResult {
                             branch_name: Some(name),
@@ -1356,6 +1399,25 @@ impl ModeState for SetupRepoState {
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        let map = ActionMap::new("Repository Setup");
+        match &self.state {
+            SetupState::Error { .. } => map
+                .bind(
+                    ActionCategory::Actions,
+                    &[KeyCode::Char('r'), KeyCode::Char('R')],
+                    "Retry setup",
+                )
+                .bind(
+                    ActionCategory::Actions,
+                    &[KeyCode::Char('f'), KeyCode::Char('F')],
+                    "Force resolve the error and retry",
+                )
+                .bind(ActionCategory::General, &[KeyCode::Esc], "Give up"),
+            _ => map,
+        }
+    }
+
     fn name(&self) -> &'static str {
         "SetupRepo"
     }
@@ -1384,6 +1446,7 @@ mod tests {
             local_repo: None,
             target_branch: "main".to_string(),
             version: "1.0.0".to_string(),
+            worktree_version: "1.0.0".to_string(),
             run_hooks,
             selected_prs: vec![],
             state_manager: Arc::new(Mutex::new(StateManager::new())),
@@ -1396,7 +1459,14 @@ mod tests {
                 tag_prefix: "merged/".to_string(),
                 work_item_state: "Done".to_string(),
                 run_hooks,
+                sign_commits: false,
+                fan_out: Vec::new(),
+                squash_message: None,
+                mainline_fallback: false,
+                run_dir: None,
             },
+            clock: Arc::new(crate::utils::SystemClock),
+            ids: Arc::new(crate::utils::SequentialIdGen::new()),
         }
     }
 
@@ -1908,6 +1978,8 @@ mod tests {
     ///
     /// ## Expected Outcome
     /// - Should return StateChange::Keep
+    /// - The keys are missing from the action map, which lists both cases of
+    ///   retry and force
     #[tokio::test]
     async fn test_setup_repo_other_keys_in_error() {
         let config = create_test_config_default();
@@ -1916,7 +1988,12 @@ mod tests {
         let mut state = SetupRepoState::new();
         state.set_error(SetupError::Other("Test error".to_string()));
 
+        let map = ModeState::action_map(&state);
+        for key in ['r', 'R', 'f', 'F'] {
+            assert!(map.handles(KeyCode::Char(key)));
+        }
         for key in [KeyCode::Up, KeyCode::Down, KeyCode::Char('x')] {
+            assert!(!map.handles(key));
             let result = ModeState::process_key(&mut state, key, harness.merge_app_mut()).await;
             assert!(matches!(result, StateChange::Keep));
         }
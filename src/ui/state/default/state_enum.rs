@@ -5,15 +5,17 @@
 //! provides compile-time type safety and eliminates virtual dispatch overhead.
 
 use super::{
-    AbortingState, CherryPickContinueState, CherryPickState, CompletionState,
+    AbortingState, BranchLogState, CherryPickContinueState, CherryPickState, CompletionState,
     ConflictResolutionState, DataLoadingState, PostCompletionState, PullRequestSelectionState,
     ReleaseNotesExportState, SetupRepoState, VersionInputState,
 };
+use crate::models::CherryPickStatus;
 use crate::ui::apps::MergeApp;
+use crate::ui::keymap::ActionMap;
 use crate::ui::state::shared::{ErrorState, SettingsConfirmationState};
 use crate::ui::state::typed::{AppState, ModeState, StateChange};
 use async_trait::async_trait;
-use crossterm::event::{KeyCode, MouseEvent};
+use crossterm::event::{KeyCode, KeyEvent, MouseEvent};
 use ratatui::Frame;
 
 /// All possible states for merge (default) mode.
@@ -37,7 +39,8 @@ use ratatui::Frame;
 /// 10. `Completion` - Show completion summary
 /// 11. `PostCompletion` - Handle post-merge tasks
 /// 12. `ReleaseNotesExport` - Export release notes to file
-/// 13. `Error` - Display error messages
+/// 13. `BranchLog` - Compare the target and patch branch logs
+/// 14. `Error` - Display error messages
 ///
 /// # Example
 ///
@@ -77,6 +80,8 @@ pub enum MergeState {
     PostCompletion(PostCompletionState),
     /// Release notes export screen.
     ReleaseNotesExport(ReleaseNotesExportState),
+    /// Side-by-side target and patch branch logs.
+    BranchLog(BranchLogState),
     /// Error display screen.
     Error(ErrorState),
 }
@@ -117,6 +122,7 @@ impl MergeState {
             MergeState::Completion(_) => "Completion",
             MergeState::PostCompletion(_) => "PostCompletion",
             MergeState::ReleaseNotesExport(_) => "ReleaseNotesExport",
+            MergeState::BranchLog(_) => "BranchLog",
             MergeState::Error(_) => "Error",
         }
     }
@@ -147,6 +153,7 @@ impl AppState for MergeState {
             MergeState::Completion(state) => ModeState::ui(state, f, app),
             MergeState::PostCompletion(state) => ModeState::ui(state, f, app),
             MergeState::ReleaseNotesExport(state) => ModeState::ui(state, f, app),
+            MergeState::BranchLog(state) => ModeState::ui(state, f, app),
             MergeState::Error(state) => state.render(f, app.error_message()),
         }
     }
@@ -169,10 +176,20 @@ impl AppState for MergeState {
             MergeState::Completion(state) => ModeState::process_key(state, code, app).await,
             MergeState::PostCompletion(state) => ModeState::process_key(state, code, app).await,
             MergeState::ReleaseNotesExport(state) => ModeState::process_key(state, code, app).await,
+            MergeState::BranchLog(state) => ModeState::process_key(state, code, app).await,
             MergeState::Error(state) => state.handle_key(code),
         }
     }
 
+    async fn process_key_event(&mut self, key: KeyEvent, app: &mut MergeApp) -> StateChange<Self> {
+        match self {
+            MergeState::PullRequestSelection(state) => {
+                ModeState::process_key_event(state, key, app).await
+            }
+            _ => self.process_key(key.code, app).await,
+        }
+    }
+
     async fn process_mouse(&mut self, event: MouseEvent, app: &mut MergeApp) -> StateChange<Self> {
         match self {
             MergeState::SettingsConfirmation(_) => StateChange::Keep,
@@ -195,10 +212,80 @@ impl AppState for MergeState {
             MergeState::ReleaseNotesExport(state) => {
                 ModeState::process_mouse(state, event, app).await
             }
+            MergeState::BranchLog(state) => ModeState::process_mouse(state, event, app).await,
             MergeState::Error(_) => StateChange::Keep,
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        match self {
+            MergeState::SettingsConfirmation(state) => state.action_map(),
+            MergeState::DataLoading(state) => ModeState::action_map(state),
+            MergeState::PullRequestSelection(state) => ModeState::action_map(state),
+            MergeState::VersionInput(state) => ModeState::action_map(state),
+            MergeState::SetupRepo(state) => ModeState::action_map(state),
+            MergeState::CherryPick(state) => ModeState::action_map(state),
+            MergeState::ConflictResolution(state) => ModeState::action_map(state),
+            MergeState::CherryPickContinue(state) => ModeState::action_map(state),
+            MergeState::Aborting(state) => ModeState::action_map(state),
+            MergeState::Completion(state) => ModeState::action_map(state),
+            MergeState::PostCompletion(state) => ModeState::action_map(state),
+            MergeState::ReleaseNotesExport(state) => ModeState::action_map(state),
+            MergeState::BranchLog(state) => ModeState::action_map(state),
+            MergeState::Error(state) => state.action_map(),
+        }
+    }
+
+    fn announcement(&self, app: &MergeApp) -> Option<String> {
+        let items = app.cherry_pick_items();
+        let current = || {
+            app.current_cherry_pick().map(|item| {
+                format!(
+                    "PR #{} ({} of {})",
+                    item.pr_id,
+                    app.current_cherry_pick_index + 1,
+                    items.len()
+                )
+            })
+        };
+        match self {
+            MergeState::PullRequestSelection(_) => Some(format!(
+                "{} of {} pull requests selected",
+                app.pull_requests().iter().filter(|pr| pr.selected).count(),
+                app.pull_requests().len()
+            )),
+            MergeState::CherryPick(state) if state.is_paused() => {
+                current().map(|item| format!("Cherry-picking paused at {}", item))
+            }
+            MergeState::CherryPick(_) | MergeState::CherryPickContinue(_) => {
+                current().map(|item| format!("Cherry-picking {}", item))
+            }
+            MergeState::ConflictResolution(state) => current().map(|item| {
+                format!(
+                    "Conflict in {}: {} conflicted files",
+                    item,
+                    state.conflicted_files().len()
+                )
+            }),
+            MergeState::Completion(_) => {
+                let count = |status: fn(&CherryPickStatus) -> bool| {
+                    items.iter().filter(|item| status(&item.status)).count()
+                };
+                Some(format!(
+                    "Completed: {} succeeded, {} failed, {} skipped",
+                    count(|s| matches!(s, CherryPickStatus::Success)),
+                    count(|s| matches!(
+                        s,
+                        CherryPickStatus::Failed(_) | CherryPickStatus::Conflict
+                    )),
+                    count(|s| matches!(s, CherryPickStatus::Skipped)),
+                ))
+            }
+            MergeState::Error(_) => app.error_message().map(|msg| format!("Error: {}", msg)),
+            _ => None,
+        }
+    }
+
     fn name(&self) -> &'static str {
         MergeState::name(self)
     }
@@ -245,6 +332,7 @@ mod tests {
                 project: ParsedProperty::Default("test-project".to_string()),
                 repository: ParsedProperty::Default("test-repo".to_string()),
                 pat: ParsedProperty::Default("test-pat".to_string()),
+                write_pat: None,
                 dev_branch: ParsedProperty::Default("develop".to_string()),
                 target_branch: ParsedProperty::Default("main".to_string()),
                 local_repo: None,
@@ -253,10 +341,37 @@ mod tests {
                 max_concurrent_processing: ParsedProperty::Default(5),
                 tag_prefix: ParsedProperty::Default("merged/".to_string()),
                 since: None,
+                since_field: Default::default(),
                 skip_confirmation: false,
+                api_version: None,
+                history_depth: None,
+                url_templates: Default::default(),
+                release_notes_locale: Default::default(),
             },
             default: DefaultModeConfig {
                 work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+                strict_states: None,
+                release_gate: None,
+                build_checks: Default::default(),
+                release_tag: Default::default(),
+                sign_commits: false,
+                fan_out: Vec::new(),
+                squash_message: None,
+                mainline_fallback: false,
+                auto_refresh: None,
+                select_file: None,
+                pr_tabs: Vec::new(),
+                selection_soft_cap: None,
+                terminal_states: Vec::new(),
+                work_item_comment: None,
+                artifacts_feed: None,
+                work_item_fields: Vec::new(),
+                notifications: None,
+                binary_conflicts: Default::default(),
+                cherry_pick_order: Default::default(),
+                pr_filter: Default::default(),
+                session: None,
+                clipboard: Default::default(),
                 run_hooks: ParsedProperty::Default(false),
             },
         };
@@ -289,6 +404,54 @@ mod tests {
         assert_eq!(error.name(), "Error");
     }
 
+    /// # MergeState Announcements
+    ///
+    /// Tests the plain-line progress updates for screen readers.
+    ///
+    /// ## Test Scenario
+    /// - Sets up cherry-pick items and an error message
+    /// - Asks several states for their announcement
+    ///
+    /// ## Expected Outcome
+    /// - Cherry-pick, conflict, completion and error states describe the
+    ///   current progress; other states announce nothing
+    #[test]
+    fn test_merge_state_announcements() {
+        use crate::ui::testing::{
+            TuiTestHarness, create_test_cherry_pick_items, create_test_config_default,
+        };
+
+        let mut harness = TuiTestHarness::with_config(create_test_config_default());
+        let app = harness.merge_app_mut();
+        app.cherry_pick_items = create_test_cherry_pick_items();
+        app.current_cherry_pick_index = 1;
+        app.set_error_message(Some("Push rejected".to_string()));
+        let app = &*app;
+
+        assert_eq!(
+            AppState::announcement(&MergeState::CherryPick(CherryPickState::new()), app),
+            Some("Cherry-picking PR #101 (2 of 4)".to_string())
+        );
+        assert_eq!(
+            AppState::announcement(
+                &MergeState::ConflictResolution(ConflictResolutionState::new(vec![
+                    "src/lib.rs".to_string()
+                ])),
+                app
+            ),
+            Some("Conflict in PR #101 (2 of 4): 1 conflicted files".to_string())
+        );
+        assert_eq!(
+            AppState::announcement(&MergeState::Completion(CompletionState::new()), app),
+            Some("Completed: 1 succeeded, 1 failed, 0 skipped".to_string())
+        );
+        assert_eq!(
+            AppState::announcement(&MergeState::Error(ErrorState::new()), app),
+            Some("Error: Push rejected".to_string())
+        );
+        assert_eq!(AppState::announcement(&MergeState::initial(), app), None);
+    }
+
     /// # MergeState Debug Implementation
     ///
     /// Tests that MergeState implements Debug correctly.
@@ -1,4 +1,5 @@
 use super::{MergeState, SetupRepoState};
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::{
     ui::apps::MergeApp,
     ui::state::typed::{ModeState, StateChange},
@@ -90,6 +91,21 @@ impl ModeState for VersionInputState {
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        ActionMap::new("Version Input")
+            .with_text_input()
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Enter],
+                "Confirm version",
+            )
+            .bind(
+                ActionCategory::General,
+                &[KeyCode::Esc],
+                "Back to pull request selection",
+            )
+    }
+
     fn name(&self) -> &'static str {
         "VersionInput"
     }
@@ -1,15 +1,16 @@
 use super::MigrationModeState;
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::{
     api::AzureDevOpsClient,
     git::{
-        cleanup_migration_worktrees, force_remove_worktree, get_target_branch_history,
+        cleanup_migration_worktrees, force_remove_worktree, get_target_branch_history_with_depth,
         setup_repository,
     },
     migration::MigrationAnalyzer,
-    models::{AppConfig, PullRequest, PullRequestWithWorkItems, WorkItem},
+    models::{AppConfig, PRAnalysisResult, PullRequest, PullRequestWithWorkItems, WorkItem},
     ui::apps::MigrationApp,
     ui::state::typed::{ModeState, StateChange},
-    utils::throttle::NetworkProcessor,
+    utils::{IdGen, SystemClock, TimestampIdGen, throttle::NetworkProcessor},
 };
 use anyhow::{Context, Result, bail};
 use async_trait::async_trait;
@@ -19,13 +20,10 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Gauge, Paragraph, Wrap},
+    widgets::{Block, Borders, Cell, Gauge, Paragraph, Row, Table, Wrap},
 };
-use std::sync::{
-    Arc,
-    atomic::{AtomicUsize, Ordering},
-};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::Arc;
+use tokio::sync::mpsc;
 
 type AsyncTaskHandle<T> = tokio::task::JoinHandle<Result<T>>;
 
@@ -78,7 +76,9 @@ pub struct MigrationDataLoadingState {
     work_items_total: usize,
     prs_analyzed: usize,
     prs_to_analyze: usize,
-    analysis_progress: Option<Arc<AtomicUsize>>,
+    analysis_results: Option<mpsc::UnboundedReceiver<PRAnalysisResult>>,
+    /// Per-PR results in the order they finished, shown while analysis runs.
+    analyzed_prs: Vec<PRAnalysisResult>,
     migration_id: String,
 
     // Intermediate results
@@ -92,11 +92,6 @@ pub struct MigrationDataLoadingState {
 
 impl MigrationDataLoadingState {
     pub fn new(config: AppConfig) -> Self {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-
         Self {
             loading_stage: LoadingStage::NotStarted,
             loaded: false,
@@ -113,10 +108,12 @@ impl MigrationDataLoadingState {
             total_prs: 0,
             work_items_fetched: 0,
             work_items_total: 0,
-            migration_id: format!("migration-{}", timestamp),
+            // Replaced with an id from the app's generator when loading starts
+            migration_id: TimestampIdGen::new(Arc::new(SystemClock)).next_id("migration"),
             prs_analyzed: 0,
             prs_to_analyze: 0,
-            analysis_progress: None,
+            analysis_results: None,
+            analyzed_prs: Vec::new(),
             prs: Vec::new(),
             prs_with_work_items: Vec::new(),
             repo_path: None,
@@ -130,6 +127,7 @@ impl MigrationDataLoadingState {
         self.loading_stage = LoadingStage::FetchingPullRequests;
         self.status = "Fetching pull requests...".to_string();
         self.progress = 0.1;
+        self.migration_id = app.ids().next_id("migration");
 
         let client = app.client().clone();
         let dev_branch = app.dev_branch().to_string();
@@ -189,13 +187,20 @@ impl MigrationDataLoadingState {
         migration_id: String,
     ) -> Result<RepoSetupResult> {
         // Create client from config
-        let client = AzureDevOpsClient::new(
+        let client = AzureDevOpsClient::new_with_api_version(
             config.shared().organization.value().clone(),
             config.shared().project.value().clone(),
             config.shared().repository.value().clone(),
-            config.shared().pat.value().clone(),
+            config.shared().pat.value().clone().into(),
+            config
+                .shared()
+                .api_version
+                .as_ref()
+                .map(|p| p.value().clone()),
         )
-        .context("Failed to create client")?;
+        .context("Failed to create client")?
+        .with_max_concurrent_requests(*config.shared().max_concurrent_network.value())
+        .with_disk_cache();
 
         // Setup repository for analysis
         let repo_details = client
@@ -267,10 +272,16 @@ impl MigrationDataLoadingState {
                     if let Some(config) = &self.config {
                         let repo_path_clone = result.repo_path.clone();
                         let target_branch = config.shared().target_branch.clone();
+                        let history_depth =
+                            config.shared().history_depth.as_ref().map(|p| *p.value());
 
                         self.git_history_task = Some(tokio::spawn(async move {
-                            get_target_branch_history(&repo_path_clone, &target_branch)
-                                .context("Failed to get target branch history")
+                            get_target_branch_history_with_depth(
+                                &repo_path_clone,
+                                &target_branch,
+                                history_depth,
+                            )
+                            .context("Failed to get target branch history")
                         }));
                     }
 
@@ -429,12 +440,13 @@ impl MigrationDataLoadingState {
             self.loading_stage = LoadingStage::RunningAnalysis;
             self.prs_to_analyze = self.prs_with_work_items.len();
             self.prs_analyzed = 0;
+            self.analyzed_prs.clear();
 
             self.progress = 0.7;
 
-            // Create shared progress counter
-            let progress_counter = Arc::new(AtomicUsize::new(0));
-            self.analysis_progress = Some(progress_counter.clone());
+            // Per-PR results are streamed back as they finish
+            let (result_tx, result_rx) = mpsc::unbounded_channel();
+            self.analysis_results = Some(result_rx);
 
             let prs_with_work_items = self.prs_with_work_items.clone();
             let repo_path = self.repo_path.clone().unwrap();
@@ -451,7 +463,7 @@ impl MigrationDataLoadingState {
                     commit_history,
                     config,
                     migration_id,
-                    progress_counter,
+                    result_tx,
                 )
                 .await
             }));
@@ -466,33 +478,42 @@ impl MigrationDataLoadingState {
         commit_history: crate::git::CommitHistory,
         config: AppConfig,
         migration_id: String,
-        progress_counter: Arc<AtomicUsize>,
+        result_tx: mpsc::UnboundedSender<PRAnalysisResult>,
     ) -> Result<crate::models::MigrationAnalysis> {
         // Create client from config
-        let client = AzureDevOpsClient::new(
+        let client = AzureDevOpsClient::new_with_api_version(
             config.shared().organization.value().clone(),
             config.shared().project.value().clone(),
             config.shared().repository.value().clone(),
-            config.shared().pat.value().clone(),
+            config.shared().pat.value().clone().into(),
+            config
+                .shared()
+                .api_version
+                .as_ref()
+                .map(|p| p.value().clone()),
         )
-        .context("Failed to create client")?;
+        .context("Failed to create client")?
+        .with_max_concurrent_requests(*config.shared().max_concurrent_network.value());
 
         // Create migration analyzer
         let analyzer = MigrationAnalyzer::new(client, terminal_states);
 
-        // Analyze PRs using pre-fetched commit history (no individual git commands per PR)
-        let mut pr_analyses = Vec::new();
-        for pr_with_work_items in prs_with_work_items {
-            let analysis = analyzer
-                .analyze_single_pr(&pr_with_work_items, &commit_history)
-                .await
-                .with_context(|| format!("Analysis failed for PR {}", pr_with_work_items.pr.id))?;
-
-            pr_analyses.push(analysis);
-
-            // Update progress counter
-            progress_counter.fetch_add(1, Ordering::Relaxed);
-        }
+        // Check PRs against the pre-fetched commit history in parallel, bounded
+        // by max_concurrent_processing
+        let max_concurrency = *config.shared().max_concurrent_processing.value();
+        let pool_analyzer = analyzer.clone();
+        let pr_analyses = tokio::task::spawn_blocking(move || {
+            pool_analyzer.analyze_prs(
+                &prs_with_work_items,
+                &commit_history,
+                max_concurrency,
+                |analysis| {
+                    let _ = result_tx.send(analysis.clone());
+                },
+            )
+        })
+        .await
+        .context("Analysis task failed")??;
 
         // Categorize PRs
         let analysis = analyzer
@@ -511,6 +532,8 @@ impl MigrationDataLoadingState {
         if let Some(task) = &mut self.analysis_task {
             if task.is_finished() {
                 let task = self.analysis_task.take().unwrap();
+                self.drain_analysis_results();
+                self.analysis_results = None;
                 match task.await {
                     Ok(Ok(analysis)) => {
                         self.loading_stage = LoadingStage::Complete;
@@ -530,9 +553,7 @@ impl MigrationDataLoadingState {
             }
 
             // Update progress while analysis is running
-            if let Some(ref progress_counter) = self.analysis_progress {
-                self.prs_analyzed = progress_counter.load(Ordering::Relaxed);
-            }
+            self.drain_analysis_results();
 
             // Calculate progress based on analyzed PRs
             if self.prs_to_analyze > 0 {
@@ -545,6 +566,49 @@ impl MigrationDataLoadingState {
         Ok(false)
     }
 
+    /// Moves finished per-PR results into the live results table.
+    fn drain_analysis_results(&mut self) {
+        if let Some(rx) = &mut self.analysis_results {
+            while let Ok(analysis) = rx.try_recv() {
+                self.analyzed_prs.push(analysis);
+            }
+        }
+        self.prs_analyzed = self.analyzed_prs.len();
+    }
+
+    /// Renders the most recently finished PRs, newest last.
+    fn render_analyzed_prs(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let visible = area.height.saturating_sub(3) as usize;
+        let skip = self.analyzed_prs.len().saturating_sub(visible);
+        let rows = self.analyzed_prs.iter().skip(skip).map(|analysis| {
+            let (outcome, color) = analysis_outcome(analysis);
+            Row::new(vec![
+                Cell::from(format!("#{}", analysis.pr.pr.id)),
+                Cell::from(Span::styled(outcome, Style::default().fg(color))),
+                Cell::from(analysis.pr.pr.title.clone()),
+            ])
+        });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(8),
+                Constraint::Length(11),
+                Constraint::Min(10),
+            ],
+        )
+        .header(
+            Row::new(vec!["PR", "Outcome", "Title"])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            "Results ({}/{})",
+            self.analyzed_prs.len(),
+            self.prs_to_analyze
+        )));
+        f.render_widget(table, area);
+    }
+
     fn get_loading_message(&self) -> String {
         match self.loading_stage {
             LoadingStage::NotStarted => "Initializing...".to_string(),
@@ -601,6 +665,17 @@ impl MigrationDataLoadingState {
     }
 }
 
+/// Category a finished PR lands in, matching `MigrationAnalyzer::categorize_prs`.
+fn analysis_outcome(analysis: &PRAnalysisResult) -> (&'static str, Color) {
+    if analysis.commit_in_target || analysis.commit_title_in_target {
+        ("Eligible", Color::Green)
+    } else if analysis.all_work_items_terminal {
+        ("Unsure", Color::Yellow)
+    } else {
+        ("Not merged", Color::Red)
+    }
+}
+
 // ============================================================================
 // ModeState Implementation
 // ============================================================================
@@ -698,11 +773,23 @@ impl ModeState for MigrationDataLoadingState {
             ]
         };
 
+        // Stream per-PR results above the help text as they finish
+        let help_area = if self.analyzed_prs.is_empty() {
+            chunks[3]
+        } else {
+            let parts = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(3), Constraint::Length(4)])
+                .split(chunks[3]);
+            self.render_analyzed_prs(f, parts[0]);
+            parts[1]
+        };
+
         let help_widget = Paragraph::new(help_text)
             .style(Style::default().fg(Color::Gray))
             .alignment(Alignment::Center)
             .block(Block::default().borders(Borders::ALL).title("Help"));
-        f.render_widget(help_widget, chunks[3]);
+        f.render_widget(help_widget, help_area);
     }
 
     async fn process_key(
@@ -845,6 +932,18 @@ impl ModeState for MigrationDataLoadingState {
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        let mut map = ActionMap::new("Migration Analysis");
+        if self.error.is_some() {
+            map = map.bind(ActionCategory::Actions, &[KeyCode::Char('r')], "Retry");
+        }
+        map = map.bind(ActionCategory::General, &[KeyCode::Char('q')], "Quit");
+        if matches!(self.loading_stage, LoadingStage::Complete) {
+            map = map.bind_any_key(ActionCategory::General, "Continue to results");
+        }
+        map
+    }
+
     fn name(&self) -> &'static str {
         "MigrationDataLoading"
     }
@@ -875,6 +974,7 @@ mod tests {
                 project: crate::parsed_property::ParsedProperty::Default("test".to_string()),
                 repository: crate::parsed_property::ParsedProperty::Default("test".to_string()),
                 pat: crate::parsed_property::ParsedProperty::Default("test".to_string()),
+                write_pat: None,
                 target_branch: crate::parsed_property::ParsedProperty::Default("main".to_string()),
                 dev_branch: crate::parsed_property::ParsedProperty::Default("dev".to_string()),
                 local_repo: None,
@@ -883,7 +983,12 @@ mod tests {
                 parallel_limit: crate::parsed_property::ParsedProperty::Default(5),
                 tag_prefix: crate::parsed_property::ParsedProperty::Default("merged-".to_string()),
                 since: None,
+                since_field: Default::default(),
                 skip_confirmation: false,
+                api_version: None,
+                history_depth: None,
+                url_templates: Default::default(),
+                release_notes_locale: Default::default(),
             },
             migration: crate::models::MigrationModeConfig {
                 terminal_states: crate::parsed_property::ParsedProperty::Default(vec![
@@ -924,6 +1029,7 @@ mod tests {
                 project: crate::parsed_property::ParsedProperty::Default("test".to_string()),
                 repository: crate::parsed_property::ParsedProperty::Default("test".to_string()),
                 pat: crate::parsed_property::ParsedProperty::Default("test".to_string()),
+                write_pat: None,
                 target_branch: crate::parsed_property::ParsedProperty::Default("main".to_string()),
                 dev_branch: crate::parsed_property::ParsedProperty::Default("dev".to_string()),
                 local_repo: None,
@@ -932,7 +1038,12 @@ mod tests {
                 parallel_limit: crate::parsed_property::ParsedProperty::Default(5),
                 tag_prefix: crate::parsed_property::ParsedProperty::Default("merged-".to_string()),
                 since: None,
+                since_field: Default::default(),
                 skip_confirmation: false,
+                api_version: None,
+                history_depth: None,
+                url_templates: Default::default(),
+                release_notes_locale: Default::default(),
             },
             migration: crate::models::MigrationModeConfig {
                 terminal_states: crate::parsed_property::ParsedProperty::Default(vec![
@@ -1059,6 +1170,58 @@ mod tests {
         });
     }
 
+    /// # Migration Data Loading - Streamed Results
+    ///
+    /// Tests per-PR results appearing while the analysis is still running.
+    ///
+    /// ## Test Scenario
+    /// - Starts analysis of 3 PRs and streams results for 2 of them
+    /// - Drains the results and renders the loading display
+    ///
+    /// ## Expected Outcome
+    /// - Progress counts the 2 finished PRs
+    /// - The results table lists them in finishing order with their outcome
+    #[test]
+    fn test_migration_data_loading_streamed_results() {
+        use crate::ui::{
+            snapshot_testing::with_settings_and_module_path,
+            testing::{TuiTestHarness, create_test_config_migration, create_test_pull_requests},
+        };
+        use insta::assert_snapshot;
+
+        let prs = create_test_pull_requests();
+        let result =
+            |pr: &PullRequestWithWorkItems, merged: bool, terminal: bool| PRAnalysisResult {
+                pr: pr.clone(),
+                all_work_items_terminal: terminal,
+                commit_in_target: merged,
+                commit_title_in_target: false,
+                unsure_reason: None,
+                reason: None,
+            };
+
+        let config = create_test_config_migration();
+        let mut inner_state = MigrationDataLoadingState::new(config.clone());
+        inner_state.loading_stage = LoadingStage::RunningAnalysis;
+        inner_state.prs_to_analyze = prs.len();
+        let (tx, rx) = mpsc::unbounded_channel();
+        inner_state.analysis_results = Some(rx);
+        tx.send(result(&prs[2], false, true)).unwrap();
+        tx.send(result(&prs[0], true, false)).unwrap();
+
+        inner_state.drain_analysis_results();
+        assert_eq!(inner_state.prs_analyzed, 2);
+        assert_eq!(inner_state.analyzed_prs[0].pr.pr.id, prs[2].pr.id);
+
+        with_settings_and_module_path(module_path!(), || {
+            let mut harness = TuiTestHarness::with_config(config);
+            let mut state = MigrationModeState::DataLoading(Box::new(inner_state));
+            harness.render_migration_state(&mut state);
+
+            assert_snapshot!("streamed_results", harness.backend());
+        });
+    }
+
     /// Helper to create a test app for async tests
     fn create_test_migration_app(config: AppConfig) -> MigrationApp {
         let client = crate::api::AzureDevOpsClient::new(
@@ -1562,7 +1725,8 @@ mod tests {
         assert_eq!(state.work_items_total, 0);
         assert_eq!(state.prs_analyzed, 0);
         assert_eq!(state.prs_to_analyze, 0);
-        assert!(state.analysis_progress.is_none());
+        assert!(state.analysis_results.is_none());
+        assert!(state.analyzed_prs.is_empty());
         assert!(state.migration_id.starts_with("migration-"));
         assert!(state.prs.is_empty());
         assert!(state.prs_with_work_items.is_empty());
@@ -1570,4 +1734,32 @@ mod tests {
         assert!(state.terminal_states.is_none());
         assert!(state.commit_history.is_none());
     }
+
+    /// # Migration Id From App Generator
+    ///
+    /// Tests that the migration id comes from the app's id generator.
+    ///
+    /// ## Test Scenario
+    /// - Replaces the app's id generator with a sequential one
+    /// - Starts loading with the first poll tick
+    ///
+    /// ## Expected Outcome
+    /// - The migration id is the generator's first id
+    #[tokio::test]
+    async fn test_migration_id_from_app_generator() {
+        use crate::ui::testing::create_test_config_migration;
+        use crate::utils::SequentialIdGen;
+
+        let config = create_test_config_migration();
+        let mut state = MigrationDataLoadingState::new(config.clone());
+        let mut app = create_test_migration_app(config);
+        app.set_id_gen(Arc::new(SequentialIdGen::new()));
+
+        ModeState::process_key(&mut state, KeyCode::Null, &mut app).await;
+
+        assert_eq!(state.migration_id, "migration-1");
+        if let Some(task) = state.pr_fetch_task.take() {
+            task.abort();
+        }
+    }
 }
@@ -1,5 +1,6 @@
 use super::MigrationModeState;
 use crate::ui::apps::MigrationApp;
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::ui::state::typed::{ModeState, StateChange};
 use async_trait::async_trait;
 use crossterm::event::KeyCode;
@@ -587,6 +588,41 @@ impl ModeState for MigrationState {
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        ActionMap::new("Migration Results")
+            .bind(
+                ActionCategory::Navigation,
+                &[KeyCode::Up, KeyCode::Down],
+                "Move highlight",
+            )
+            .bind(
+                ActionCategory::Navigation,
+                &[KeyCode::Left, KeyCode::Right],
+                "Switch tab",
+            )
+            .bind(
+                ActionCategory::Selection,
+                &[KeyCode::Char(' ')],
+                "Toggle eligibility override",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('d')],
+                "Toggle details",
+            )
+            .bind(
+                ActionCategory::View,
+                &[KeyCode::Char('o')],
+                "Open pull request in browser",
+            )
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Enter],
+                "Continue to version input",
+            )
+            .bind(ActionCategory::General, &[KeyCode::Char('q')], "Quit")
+    }
+
     fn name(&self) -> &'static str {
         "MigrationResults"
     }
@@ -12,6 +12,7 @@ use super::{
     MigrationVersionInputState,
 };
 use crate::ui::apps::MigrationApp;
+use crate::ui::keymap::ActionMap;
 use crate::ui::state::shared::{ErrorState, SettingsConfirmationState};
 use crate::ui::state::typed::{AppState, ModeState, StateChange};
 use async_trait::async_trait;
@@ -164,6 +165,17 @@ impl AppState for MigrationModeState {
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        match self {
+            MigrationModeState::SettingsConfirmation(state) => state.action_map(),
+            MigrationModeState::DataLoading(state) => ModeState::action_map(state.as_ref()),
+            MigrationModeState::Results(state) => ModeState::action_map(state),
+            MigrationModeState::VersionInput(state) => ModeState::action_map(state),
+            MigrationModeState::Tagging(state) => ModeState::action_map(state),
+            MigrationModeState::Error(state) => state.action_map(),
+        }
+    }
+
     fn name(&self) -> &'static str {
         MigrationModeState::name(self)
     }
@@ -182,6 +194,7 @@ mod tests {
                 project: ParsedProperty::Default("test-project".to_string()),
                 repository: ParsedProperty::Default("test-repo".to_string()),
                 pat: ParsedProperty::Default("test-pat".to_string()),
+                write_pat: None,
                 dev_branch: ParsedProperty::Default("develop".to_string()),
                 target_branch: ParsedProperty::Default("main".to_string()),
                 local_repo: None,
@@ -190,7 +203,12 @@ mod tests {
                 max_concurrent_processing: ParsedProperty::Default(5),
                 tag_prefix: ParsedProperty::Default("merged/".to_string()),
                 since: None,
+                since_field: Default::default(),
                 skip_confirmation: false,
+                api_version: None,
+                history_depth: None,
+                url_templates: Default::default(),
+                release_notes_locale: Default::default(),
             },
             migration: MigrationModeConfig {
                 terminal_states: ParsedProperty::Default(vec![
@@ -1,9 +1,12 @@
 use super::{MigrationModeState, MigrationResultsState};
+use crate::core::operations::{
+    ChunkPlanner, MAX_CHUNK_SIZE, TaggingProgress, TaggingTarget, TaggingUpdate, tag_prs,
+};
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::{
     ui::apps::MigrationApp,
     ui::state::typed::{ModeState, StateChange},
 };
-use anyhow::Result;
 use async_trait::async_trait;
 use crossterm::event::KeyCode;
 use ratatui::{
@@ -13,7 +16,9 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Gauge, Paragraph, Wrap},
 };
+use std::path::PathBuf;
 use std::time::Instant;
+use tokio::sync::mpsc;
 
 #[derive(Debug, Clone)]
 pub struct TaggingError {
@@ -22,19 +27,38 @@ pub struct TaggingError {
     pub error: String,
 }
 
+/// Tagging run in flight.
+struct TaggingRun {
+    receiver: mpsc::UnboundedReceiver<TaggingUpdate>,
+    task: tokio::task::JoinHandle<()>,
+}
+
+/// Tags the eligible PRs with the migration label.
+///
+/// PRs are tagged in chunks that shrink when the API throttles, and every
+/// result is saved to a [`TaggingProgress`] file so a rerun for the same tag
+/// skips the PRs that are already tagged.
 pub struct MigrationTaggingState {
     total_prs: usize,
     tagged_prs: usize,
-    current_batch: usize,
-    total_batches: usize,
+    /// PRs found already tagged in the progress file.
+    resumed_prs: usize,
+    current_chunk: usize,
+    chunk_size: usize,
+    throttled_until: Option<Instant>,
     errors: Vec<TaggingError>,
     is_complete: bool,
     start_time: Option<Instant>,
     tag_name: String,
     started: bool,
 
+    // Persisted progress
+    progress: TaggingProgress,
+    progress_path: Option<PathBuf>,
+    progress_error: Option<String>,
+
     // Task management
-    tagging_tasks: Option<Vec<tokio::task::JoinHandle<Result<Vec<TaggingError>>>>>,
+    run: Option<TaggingRun>,
 }
 
 impl MigrationTaggingState {
@@ -44,14 +68,19 @@ impl MigrationTaggingState {
         Self {
             total_prs: 0,
             tagged_prs: 0,
-            current_batch: 0,
-            total_batches: 0,
+            resumed_prs: 0,
+            current_chunk: 0,
+            chunk_size: 0,
+            throttled_until: None,
             errors: Vec::new(),
             is_complete: false,
             start_time: None,
+            progress: TaggingProgress::new(tag_name.clone()),
             tag_name,
             started: false,
-            tagging_tasks: None,
+            progress_path: None,
+            progress_error: None,
+            run: None,
         }
     }
 
@@ -60,130 +89,165 @@ impl MigrationTaggingState {
             return;
         }
         self.started = true;
-        if let Some(analysis) = &app.migration_analysis() {
-            let eligible_prs = &analysis.eligible_prs;
-            self.total_prs = eligible_prs.len();
+        let Some(analysis) = app.migration_analysis() else {
+            return;
+        };
 
-            if self.total_prs == 0 {
-                self.is_complete = true;
-                return;
+        // Resume from an earlier run for the same tag, if any
+        match TaggingProgress::path_for(
+            app.organization(),
+            app.project(),
+            app.repository(),
+            &self.tag_name,
+        ) {
+            Ok(path) => {
+                match TaggingProgress::load(&path, &self.tag_name) {
+                    Ok(progress) => self.progress = progress,
+                    Err(e) => self.progress_error = Some(format!("{:#}", e)),
+                }
+                self.progress_path = Some(path);
             }
+            Err(e) => self.progress_error = Some(format!("{:#}", e)),
+        }
 
-            // Get batch size - we'll pass it as parameter since we don't have direct access to config here
-            let batch_size = 50; // Will be passed from version input state
-
-            self.total_batches = self.total_prs.div_ceil(batch_size);
-            self.start_time = Some(Instant::now());
+        let eligible_prs = &analysis.eligible_prs;
+        self.total_prs = eligible_prs.len();
+        let targets: Vec<TaggingTarget> = eligible_prs
+            .iter()
+            .filter(|pr| !self.progress.is_tagged(pr.pr.id))
+            .map(|pr| TaggingTarget {
+                pr_id: pr.pr.id,
+                title: pr.pr.title.clone(),
+            })
+            .collect();
+        self.resumed_prs = self.total_prs - targets.len();
+        self.tagged_prs = self.resumed_prs;
+
+        self.spawn_run(app, targets);
+    }
 
-            // Create batches
-            let batches: Vec<_> = eligible_prs
-                .chunks(batch_size)
-                .map(|chunk| chunk.to_vec())
-                .collect();
+    /// Tags the PRs that failed again.
+    fn retry_failed(&mut self, app: &MigrationApp) {
+        let targets: Vec<TaggingTarget> = self
+            .errors
+            .drain(..)
+            .filter(|err| err.pr_id != 0)
+            .map(|err| TaggingTarget {
+                pr_id: err.pr_id,
+                title: err.pr_title,
+            })
+            .collect();
+        self.is_complete = false;
+        self.spawn_run(app, targets);
+    }
 
-            // Start tagging tasks
-            let mut tasks = Vec::new();
-
-            for batch in batches.into_iter() {
-                let client = app.client().clone();
-                let tag_name = self.tag_name.clone();
-
-                let task = tokio::spawn(async move {
-                    let mut batch_errors = Vec::new();
-
-                    for pr in batch {
-                        match client.add_label_to_pr(pr.pr.id, &tag_name).await {
-                            Ok(_) => {
-                                // Success - no action needed
-                            }
-                            Err(e) => {
-                                batch_errors.push(TaggingError {
-                                    pr_id: pr.pr.id,
-                                    pr_title: pr.pr.title.clone(),
-                                    error: e.to_string(),
-                                });
-                            }
-                        }
-                    }
+    fn spawn_run(&mut self, app: &MigrationApp, targets: Vec<TaggingTarget>) {
+        if targets.is_empty() {
+            self.finish();
+            return;
+        }
 
-                    Ok(batch_errors)
-                });
+        self.start_time = Some(Instant::now());
+        let (tx, receiver) = mpsc::unbounded_channel();
+        let client = app.client().clone();
+        let tag_name = self.tag_name.clone();
+        let planner = ChunkPlanner::new(MAX_CHUNK_SIZE.min(app.max_concurrent_network()));
+
+        let task = tokio::spawn(async move {
+            tag_prs(&client, &tag_name, targets, planner, |update| {
+                let _ = tx.send(update);
+            })
+            .await;
+        });
+        self.run = Some(TaggingRun { receiver, task });
+    }
 
-                tasks.push(task);
-            }
+    pub async fn check_progress(&mut self) -> bool {
+        let Some(run) = self.run.as_mut() else {
+            return false;
+        };
 
-            self.tagging_tasks = Some(tasks);
+        // Check before draining so no update sent before the end is missed
+        let finished = run.task.is_finished();
+        let mut updates = Vec::new();
+        while let Ok(update) = run.receiver.try_recv() {
+            updates.push(update);
+        }
+        let recorded = updates
+            .into_iter()
+            .filter(|update| self.apply_update(update))
+            .count();
+        if recorded > 0 {
+            self.save_progress();
         }
-    }
 
-    pub async fn check_progress(&mut self) -> bool {
-        // Take ownership of tasks to avoid polling completed JoinHandles
-        if let Some(tasks) = self.tagging_tasks.take() {
-            let mut pending_tasks = Vec::new();
-            let mut new_errors = Vec::new();
-            let mut completed_in_this_pass = 0;
-
-            // Check each task - enumerate with original indices for batch tracking
-            for (original_idx, task) in tasks.into_iter().enumerate() {
-                if !task.is_finished() {
-                    // Task still running - keep it for next poll
-                    pending_tasks.push(task);
-                    continue;
-                }
+        if !finished {
+            return false;
+        }
 
-                completed_in_this_pass += 1;
+        let run = self.run.take().expect("tagging run in progress");
+        if let Err(e) = run.task.await {
+            self.errors.push(TaggingError {
+                pr_id: 0,
+                pr_title: "Tagging".to_string(),
+                error: format!("Task failed: {}", e),
+            });
+        }
+        self.finish();
+        true
+    }
 
-                // Process completed task (consumes the JoinHandle)
-                match task.await {
-                    Ok(Ok(batch_errors)) => {
-                        new_errors.extend(batch_errors);
-                    }
-                    Ok(Err(error)) => {
-                        // Task failed entirely
-                        new_errors.push(TaggingError {
-                            pr_id: 0,
-                            pr_title: format!("Batch {}", original_idx + 1),
-                            error: error.to_string(),
-                        });
-                    }
-                    Err(e) => {
-                        // Task panicked
-                        new_errors.push(TaggingError {
-                            pr_id: 0,
-                            pr_title: format!("Batch {}", original_idx + 1),
-                            error: format!("Task failed: {}", e),
-                        });
-                    }
-                }
+    /// Applies one update; returns `true` if a PR result was recorded.
+    fn apply_update(&mut self, update: &TaggingUpdate) -> bool {
+        match update {
+            TaggingUpdate::ChunkStarted { size } => {
+                self.current_chunk += 1;
+                self.chunk_size = *size;
+                self.throttled_until = None;
+                false
             }
-
-            // Update batch tracking if we completed any tasks
-            if completed_in_this_pass > 0 {
-                self.current_batch += completed_in_this_pass;
-                // Estimate tagged PRs based on completed batches
-                let batch_size = if self.total_prs > 0 && self.total_batches > 0 {
-                    self.total_prs.div_ceil(self.total_batches)
-                } else {
-                    50
-                };
-                self.tagged_prs = std::cmp::min(self.current_batch * batch_size, self.total_prs);
+            TaggingUpdate::Throttled { wait } => {
+                self.throttled_until = Some(Instant::now() + *wait);
+                false
             }
-
-            // Add new errors
-            self.errors.extend(new_errors);
-
-            // Check if all tasks are complete
-            if pending_tasks.is_empty() {
-                self.is_complete = true;
-                self.tagged_prs = self.total_prs; // Ensure final count is correct
-                return true;
+            TaggingUpdate::Tagged { target, error } => {
+                self.progress.record(target.pr_id, error.clone());
+                match error {
+                    None => self.tagged_prs += 1,
+                    Some(error) => self.errors.push(TaggingError {
+                        pr_id: target.pr_id,
+                        pr_title: target.title.clone(),
+                        error: error.clone(),
+                    }),
+                }
+                true
             }
+        }
+    }
+
+    fn save_progress(&mut self) {
+        if let Some(path) = &self.progress_path
+            && let Err(e) = self.progress.save(path)
+        {
+            self.progress_error = Some(format!("{:#}", e));
+        }
+    }
 
-            // Put remaining tasks back
-            self.tagging_tasks = Some(pending_tasks);
+    fn finish(&mut self) {
+        self.is_complete = true;
+        self.throttled_until = None;
+        // Nothing left to resume once every PR is tagged
+        if self.errors.is_empty()
+            && let Some(path) = &self.progress_path
+            && path.exists()
+            && let Err(e) = std::fs::remove_file(path)
+        {
+            self.progress_error = Some(format!("Failed to remove tagging progress: {}", e));
         }
+    }
 
-        false
+    fn has_retryable_errors(&self) -> bool {
+        self.errors.iter().any(|err| err.pr_id != 0)
     }
 
     fn render_progress(&self, f: &mut Frame, area: ratatui::layout::Rect) {
@@ -207,7 +271,7 @@ impl MigrationTaggingState {
     }
 
     fn render_status(&self, f: &mut Frame, area: ratatui::layout::Rect) {
-        let status_text = if self.is_complete {
+        let mut status_text = if self.is_complete {
             let successfully_tagged = self.total_prs - self.errors.len();
             if self.errors.is_empty() {
                 vec![
@@ -296,7 +360,7 @@ impl MigrationTaggingState {
                 .start_time
                 .map(|start| start.elapsed().as_secs())
                 .unwrap_or(0);
-            vec![
+            let mut lines = vec![
                 Line::from(vec![Span::styled(
                     "🏃 Tagging in Progress...",
                     Style::default()
@@ -306,7 +370,7 @@ impl MigrationTaggingState {
                 Line::from(""),
                 Line::from(vec![
                     Span::styled(
-                        format!("Batch: {}/{}", self.current_batch, self.total_batches),
+                        format!("Chunk: {} ({} PRs)", self.current_chunk, self.chunk_size),
                         Style::default().fg(Color::Cyan),
                     ),
                     Span::styled(" | ", Style::default().fg(Color::Gray)),
@@ -319,8 +383,32 @@ impl MigrationTaggingState {
                     format!("Tag: '{}'", self.tag_name),
                     Style::default().fg(Color::Cyan),
                 )]),
-            ]
+            ];
+            if self.resumed_prs > 0 {
+                lines.push(Line::from(Span::styled(
+                    format!("Resumed: {} PRs already tagged", self.resumed_prs),
+                    Style::default().fg(Color::Gray),
+                )));
+            }
+            if let Some(until) = self.throttled_until
+                && let Some(wait) = until.checked_duration_since(Instant::now())
+            {
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "⏳ Throttled by the API, resuming in {}s",
+                        wait.as_secs() + 1
+                    ),
+                    Style::default().fg(Color::Yellow),
+                )));
+            }
+            lines
         };
+        if let Some(error) = &self.progress_error {
+            status_text.push(Line::from(Span::styled(
+                format!("Progress not saved: {}", error),
+                Style::default().fg(Color::Red),
+            )));
+        }
 
         let status = Paragraph::new(status_text)
             .block(Block::default().borders(Borders::ALL).title("Status"))
@@ -379,24 +467,34 @@ impl MigrationTaggingState {
                         .fg(Color::Green)
                         .add_modifier(Modifier::BOLD),
                 )]),
-                Line::from(""),
-                Line::from(vec![
-                    Span::raw("Press "),
-                    Span::styled("any key", key_style),
-                    Span::raw(" to return to results | "),
-                    Span::styled("q", key_style),
-                    Span::raw(" to quit"),
-                ]),
+                Line::from(if self.has_retryable_errors() {
+                    vec![
+                        Span::raw("Press "),
+                        Span::styled("r", key_style),
+                        Span::raw(" to retry failed | "),
+                        Span::styled("any key", key_style),
+                        Span::raw(" to return to results | "),
+                        Span::styled("q", key_style),
+                        Span::raw(" to quit"),
+                    ]
+                } else {
+                    vec![
+                        Span::raw("Press "),
+                        Span::styled("any key", key_style),
+                        Span::raw(" to return to results | "),
+                        Span::styled("q", key_style),
+                        Span::raw(" to quit"),
+                    ]
+                }),
             ]
         } else {
             vec![
                 Line::from(vec![Span::styled(
-                    "Tagging PRs in parallel batches...",
+                    "Tagging PRs in chunks...",
                     Style::default().fg(Color::Yellow),
                 )]),
-                Line::from(""),
                 Line::from(vec![Span::styled(
-                    "Please wait for completion",
+                    "Progress is saved; running again resumes where this run stopped",
                     Style::default().fg(Color::Gray),
                 )]),
             ]
@@ -444,6 +542,10 @@ impl ModeState for MigrationTaggingState {
         match code {
             KeyCode::Char('q') if self.is_complete => StateChange::Exit,
             KeyCode::Char('q') => StateChange::Exit,
+            KeyCode::Char('r') if self.is_complete && self.has_retryable_errors() => {
+                self.retry_failed(app);
+                StateChange::Keep
+            }
             KeyCode::Null => {
                 // Auto-start tagging and check progress (but don't transition)
                 if !self.is_complete {
@@ -465,6 +567,21 @@ impl ModeState for MigrationTaggingState {
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        let map = ActionMap::new("Tagging")
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Char('r')],
+                "Retry failed PRs (when complete)",
+            )
+            .bind(ActionCategory::General, &[KeyCode::Char('q')], "Quit");
+        if self.is_complete {
+            map.bind_any_key(ActionCategory::General, "Back to results")
+        } else {
+            map
+        }
+    }
+
     fn name(&self) -> &'static str {
         "MigrationTagging"
     }
@@ -473,220 +590,239 @@ impl ModeState for MigrationTaggingState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::state::STATE_DIR_ENV;
     use crate::ui::{
         snapshot_testing::with_settings_and_module_path,
-        testing::{TuiTestHarness, create_test_config_migration},
+        testing::{TuiTestHarness, create_test_config_migration, create_test_migration_analysis},
     };
     use insta::assert_snapshot;
+    use serial_test::file_serial;
+    use tempfile::TempDir;
+
+    /// Starts a fake tagging run that sends `updates`, then runs `rest`.
+    fn start_fake_run<F>(state: &mut MigrationTaggingState, updates: Vec<TaggingUpdate>, rest: F)
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        let (tx, receiver) = mpsc::unbounded_channel();
+        let task = tokio::spawn(async move {
+            for update in updates {
+                let _ = tx.send(update);
+            }
+            rest.await;
+        });
+        state.started = true;
+        state.run = Some(TaggingRun { receiver, task });
+    }
+
+    fn tagged(pr_id: i32, error: Option<&str>) -> TaggingUpdate {
+        TaggingUpdate::Tagged {
+            target: TaggingTarget {
+                pr_id,
+                title: format!("PR {}", pr_id),
+            },
+            error: error.map(str::to_string),
+        }
+    }
 
     /// # Check Progress Multiple Polls Test
     ///
     /// Tests that check_progress can be called multiple times without panic.
     ///
     /// ## Test Scenario
-    /// - Creates a MigrationTaggingState with pre-completed tasks
+    /// - Creates a MigrationTaggingState with a run that completes at once
     /// - Calls check_progress multiple times
     /// - This simulates KeyCode::Null events arriving repeatedly
     ///
     /// ## Expected Outcome
     /// - Should NOT panic with "JoinHandle polled after completion"
-    /// - Should correctly mark completion after all tasks finish
-    /// - Subsequent calls should be safe (no-op when no tasks remain)
-    ///
-    /// ## Regression Test
-    /// This test verifies the fix for the panic that occurred when
-    /// iter_mut() was used instead of take() - completed JoinHandles
-    /// remained in the vector and panicked on subsequent polls.
+    /// - Should correctly mark completion after the run finishes
+    /// - Subsequent calls should be safe (no-op when no run remains)
     #[tokio::test]
     async fn test_check_progress_multiple_polls_no_panic() {
         let mut state = MigrationTaggingState::new("v1.0.0".to_string(), "merged/".to_string());
         state.total_prs = 2;
-        state.total_batches = 2;
-        state.started = true;
-
-        // Manually set up tasks that complete immediately with no errors
-        let tasks: Vec<tokio::task::JoinHandle<Result<Vec<TaggingError>>>> = vec![
-            tokio::spawn(async { Ok(vec![]) }),
-            tokio::spawn(async { Ok(vec![]) }),
-        ];
-        state.tagging_tasks = Some(tasks);
+        start_fake_run(
+            &mut state,
+            vec![
+                TaggingUpdate::ChunkStarted { size: 2 },
+                tagged(1, None),
+                tagged(2, None),
+            ],
+            async {},
+        );
 
-        // Wait briefly for tasks to complete
+        // Wait briefly for the run to complete
         tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
 
-        // First call - should process completed tasks
         let completed = state.check_progress().await;
-        assert!(completed, "Should report completion after all tasks finish");
+        assert!(completed, "Should report completion after the run finishes");
         assert!(state.is_complete, "State should be marked complete");
+        assert_eq!(state.tagged_prs, 2);
 
-        // Second call - should NOT panic (this is the regression test)
-        // With the old iter_mut() code, this would panic with:
-        // "JoinHandle polled after completion"
-        let completed_again = state.check_progress().await;
-        assert!(
-            !completed_again,
-            "Should return false when no tasks to process"
-        );
-
-        // Third call - still should not panic
-        let completed_third = state.check_progress().await;
-        assert!(
-            !completed_third,
-            "Should return false when no tasks to process"
-        );
+        assert!(!state.check_progress().await);
+        assert!(!state.check_progress().await);
     }
 
-    /// # Check Progress With Pending Tasks Test
+    /// # Check Progress With Pending Run Test
     ///
-    /// Tests that check_progress correctly handles a mix of completed
-    /// and still-running tasks across multiple calls.
+    /// Tests that check_progress reports per-PR progress while the run is
+    /// still going.
     ///
     /// ## Test Scenario
-    /// - Creates tasks where some complete quickly and others take longer
-    /// - Calls check_progress multiple times
-    /// - Verifies pending tasks are preserved for next poll
+    /// - Starts a run that tags one PR and then keeps running
+    /// - Calls check_progress before and after it ends
     ///
     /// ## Expected Outcome
-    /// - Completed tasks should be processed and removed
-    /// - Pending tasks should remain for subsequent polls
-    /// - No panic should occur
+    /// - Progress and chunk count update before completion
+    /// - The run is kept until it finishes
     #[tokio::test]
-    async fn test_check_progress_preserves_pending_tasks() {
+    async fn test_check_progress_preserves_pending_run() {
         let mut state = MigrationTaggingState::new("v1.0.0".to_string(), "merged/".to_string());
-        state.total_prs = 100; // 2 batches of 50
-        state.total_batches = 2;
-        state.started = true;
-
-        // Create tasks: one completes immediately, one takes longer
-        let tasks: Vec<tokio::task::JoinHandle<Result<Vec<TaggingError>>>> = vec![
-            tokio::spawn(async { Ok(vec![]) }), // Completes immediately
-            tokio::spawn(async {
+        state.total_prs = 2;
+        start_fake_run(
+            &mut state,
+            vec![TaggingUpdate::ChunkStarted { size: 2 }, tagged(1, None)],
+            async {
                 tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-                Ok(vec![])
-            }), // Takes longer
-        ];
-        state.tagging_tasks = Some(tasks);
+            },
+        );
 
-        // Wait for first task to complete but not the second
         tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
 
-        // First call - should process completed task, keep pending one
         let completed = state.check_progress().await;
-        assert!(
-            !completed,
-            "Should not be complete - one task still pending"
-        );
-        assert!(
-            state.tagging_tasks.is_some(),
-            "Should still have pending tasks"
-        );
-        assert_eq!(state.current_batch, 1, "Should have processed one batch");
+        assert!(!completed, "Should not be complete - run still going");
+        assert!(state.run.is_some(), "Should still have the run");
+        assert_eq!(state.tagged_prs, 1);
+        assert_eq!(state.current_chunk, 1);
+        assert_eq!(state.chunk_size, 2);
 
-        // Second call - slow task still running, should not panic
-        let completed = state.check_progress().await;
-        assert!(!completed, "Should still not be complete");
-
-        // Wait for slow task to complete
         tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
 
-        // Third call - now should complete
-        let completed = state.check_progress().await;
-        assert!(completed, "Should be complete now");
-        assert_eq!(state.current_batch, 2, "Should have processed both batches");
-
-        // Fourth call - should not panic
-        let completed = state.check_progress().await;
-        assert!(!completed, "Should return false, no more tasks");
+        assert!(state.check_progress().await, "Should be complete now");
+        assert!(!state.check_progress().await);
     }
 
     /// # Check Progress With Errors Test
     ///
-    /// Tests that check_progress correctly handles task errors
-    /// and can still be called multiple times without panic.
+    /// Tests that failed PRs are reported and kept in the progress file.
     ///
     /// ## Test Scenario
-    /// - Creates tasks where some return errors
-    /// - Calls check_progress multiple times
+    /// - Runs a fake tagging where one of two PRs fails
+    /// - Progress is saved to a temporary file
     ///
     /// ## Expected Outcome
-    /// - Errors should be collected
-    /// - No panic should occur on subsequent calls
+    /// - The error is listed with its PR
+    /// - The progress file remains, recording the success and the failure
     #[tokio::test]
     async fn test_check_progress_with_errors() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("tagging.json");
+
         let mut state = MigrationTaggingState::new("v1.0.0".to_string(), "merged/".to_string());
         state.total_prs = 2;
-        state.total_batches = 2;
-        state.started = true;
-
-        let tasks: Vec<tokio::task::JoinHandle<Result<Vec<TaggingError>>>> = vec![
-            tokio::spawn(async { Ok(vec![]) }),
-            tokio::spawn(async {
-                Ok(vec![TaggingError {
-                    pr_id: 123,
-                    pr_title: "Test PR".to_string(),
-                    error: "API error".to_string(),
-                }])
-            }),
-        ];
-        state.tagging_tasks = Some(tasks);
+        state.progress_path = Some(path.clone());
+        start_fake_run(
+            &mut state,
+            vec![tagged(1, None), tagged(123, Some("API error"))],
+            async {},
+        );
 
         tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
 
-        // First call
-        let completed = state.check_progress().await;
-        assert!(completed);
-
-        // Verify errors were collected
+        assert!(state.check_progress().await);
         assert_eq!(state.errors.len(), 1);
         assert_eq!(state.errors[0].pr_id, 123);
+        assert_eq!(state.errors[0].pr_title, "PR 123");
+        assert!(state.has_retryable_errors());
+
+        let saved = TaggingProgress::load(&path, "merged/v1.0.0").unwrap();
+        assert!(saved.is_tagged(1));
+        assert_eq!(
+            saved.failed().get(&123).map(String::as_str),
+            Some("API error")
+        );
 
-        // Second call - should not panic
-        let completed = state.check_progress().await;
-        assert!(!completed);
+        assert!(!state.check_progress().await);
     }
 
     /// # Check Progress With Task Panic Test
     ///
-    /// Tests that check_progress correctly handles tasks that panic
-    /// and can still be called multiple times without panic.
+    /// Tests that a panicking run is reported as an error.
     ///
     /// ## Test Scenario
-    /// - Creates tasks where one panics
+    /// - Starts a run that panics after tagging one PR
     /// - Calls check_progress multiple times
     ///
     /// ## Expected Outcome
-    /// - Panic should be converted to error
+    /// - The panic is converted to an error that cannot be retried
     /// - No panic should occur on subsequent calls
     #[tokio::test]
     async fn test_check_progress_with_task_panic() {
         let mut state = MigrationTaggingState::new("v1.0.0".to_string(), "merged/".to_string());
         state.total_prs = 2;
-        state.total_batches = 2;
-        state.started = true;
-
-        let tasks: Vec<tokio::task::JoinHandle<Result<Vec<TaggingError>>>> = vec![
-            tokio::spawn(async { Ok(vec![]) }),
-            tokio::spawn(async {
-                panic!("Task panicked!");
-                #[allow(unreachable_code)]
-                Ok(vec![])
-            }),
-        ];
-        state.tagging_tasks = Some(tasks);
+        start_fake_run(&mut state, vec![tagged(1, None)], async {
+            panic!("Task panicked!");
+        });
 
         tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
 
-        // First call - should handle panic gracefully
-        let completed = state.check_progress().await;
-        assert!(completed);
-
-        // Verify panic was converted to error
+        assert!(state.check_progress().await);
+        assert_eq!(state.tagged_prs, 1);
         assert_eq!(state.errors.len(), 1);
         assert!(state.errors[0].error.contains("Task failed"));
+        assert!(!state.has_retryable_errors());
 
-        // Second call - should not panic
-        let completed = state.check_progress().await;
-        assert!(!completed);
+        assert!(!state.check_progress().await);
+    }
+
+    /// # Resume Tagging From Progress File
+    ///
+    /// Tests that PRs recorded as tagged are skipped on the next run.
+    ///
+    /// ## Test Scenario
+    /// - Writes a progress file marking both eligible test PRs as tagged
+    /// - Starts tagging with the state directory pointing at it
+    ///
+    /// ## Expected Outcome
+    /// - Tagging completes without tagging anything again
+    /// - Both PRs count as resumed and tagged
+    /// - The progress file is removed once everything is tagged
+    #[tokio::test]
+    #[file_serial(state_env)]
+    async fn test_start_tagging_resumes_from_progress() {
+        let dir = TempDir::new().unwrap();
+        unsafe { std::env::set_var(STATE_DIR_ENV, dir.path()) };
+
+        let config = create_test_config_migration();
+        let mut harness = TuiTestHarness::with_config(config);
+        let analysis = create_test_migration_analysis();
+        let eligible: Vec<i32> = analysis.eligible_prs.iter().map(|pr| pr.pr.id).collect();
+        harness.app.set_migration_analysis(Some(analysis));
+
+        let mut state = MigrationTaggingState::new("v1.0.0".to_string(), "merged/".to_string());
+        let app = harness.migration_app_mut();
+        let path = TaggingProgress::path_for(
+            app.organization(),
+            app.project(),
+            app.repository(),
+            &state.tag_name,
+        )
+        .unwrap();
+        let mut progress = TaggingProgress::new(state.tag_name.clone());
+        for pr_id in &eligible {
+            progress.record(*pr_id, None);
+        }
+        progress.save(&path).unwrap();
+
+        state.start_tagging(app).await;
+
+        assert!(state.is_complete);
+        assert_eq!(state.resumed_prs, eligible.len());
+        assert_eq!(state.tagged_prs, eligible.len());
+        assert!(!path.exists());
+
+        unsafe { std::env::remove_var(STATE_DIR_ENV) };
     }
 
     /// # Migration Tagging State - In Progress
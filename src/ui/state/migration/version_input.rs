@@ -1,4 +1,5 @@
 use super::{MigrationModeState, MigrationResultsState};
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::{
     ui::apps::MigrationApp,
     ui::state::typed::{ModeState, StateChange},
@@ -237,6 +238,17 @@ impl ModeState for MigrationVersionInputState {
         }
     }
 
+    fn action_map(&self) -> ActionMap {
+        ActionMap::new("Version Input")
+            .with_text_input()
+            .bind(
+                ActionCategory::Actions,
+                &[KeyCode::Enter],
+                "Confirm version",
+            )
+            .bind(ActionCategory::General, &[KeyCode::Esc], "Back to results")
+    }
+
     fn name(&self) -> &'static str {
         "MigrationVersionInput"
     }
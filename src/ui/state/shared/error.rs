@@ -1,3 +1,4 @@
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::ui::state::typed::StateChange;
 use crossterm::event::KeyCode;
 use ratatui::{
@@ -72,6 +73,11 @@ impl ErrorState {
             _ => StateChange::Keep,
         }
     }
+
+    /// Key actions available on the error screen.
+    pub fn action_map(&self) -> ActionMap {
+        ActionMap::new("Error").bind(ActionCategory::General, &[KeyCode::Char('q')], "Quit")
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,11 @@
-use crate::{models::AppConfig, parsed_property::ParsedProperty, ui::state::typed::StateChange};
+use crate::ui::keymap::{ActionCategory, ActionMap};
+use crate::{
+    core::operations::{ConfigDiagnostic, ConfigSetting, diagnose_config},
+    core::state::{LastRun, RunSettings, SettingChange},
+    models::{AppConfig, SinceField},
+    parsed_property::ParsedProperty,
+    ui::state::typed::StateChange,
+};
 use crossterm::event::KeyCode;
 use ratatui::{
     Frame,
@@ -10,11 +17,44 @@ use ratatui::{
 
 pub struct SettingsConfirmationState {
     config: AppConfig,
+    /// The previous run, when its settings differ from this run's.
+    previous_run: Option<LastRun>,
+    /// Settings that changed since the previous run.
+    changes: Vec<SettingChange>,
+    /// Whether Enter was pressed once and the changes await a second Enter.
+    confirming_changes: bool,
+    /// Suspicious settings, shown below the setting they concern.
+    diagnostics: Vec<ConfigDiagnostic>,
 }
 
 impl SettingsConfirmationState {
     pub fn new(config: AppConfig) -> Self {
-        Self { config }
+        let diagnostics = diagnose_config(&config);
+        Self {
+            config,
+            previous_run: None,
+            changes: Vec::new(),
+            confirming_changes: false,
+            diagnostics,
+        }
+    }
+
+    /// Compares this run's settings with the previous run of the repository.
+    ///
+    /// When settings changed, they are listed at the top of the screen and
+    /// continuing takes a second Enter.
+    pub fn with_previous_run(
+        mut self,
+        previous_run: Option<LastRun>,
+        current: &RunSettings,
+    ) -> Self {
+        if let Some(previous_run) = previous_run {
+            self.changes = previous_run.settings.changes(current);
+            if !self.changes.is_empty() {
+                self.previous_run = Some(previous_run);
+            }
+        }
+        self
     }
 
     /// Get a reference to the config.
@@ -22,6 +62,21 @@ impl SettingsConfirmationState {
         &self.config
     }
 
+    /// Settings that changed since the previous run.
+    pub fn changes(&self) -> &[SettingChange] {
+        &self.changes
+    }
+
+    /// Whether the changed settings await a second Enter.
+    pub fn is_confirming_changes(&self) -> bool {
+        self.confirming_changes
+    }
+
+    /// Suspicious settings found in the config.
+    pub fn diagnostics(&self) -> &[ConfigDiagnostic] {
+        &self.diagnostics
+    }
+
     /// Render the settings confirmation UI.
     ///
     /// This is a mode-agnostic rendering method that can be called from
@@ -39,12 +94,17 @@ impl SettingsConfirmationState {
             }));
 
         let settings_lines = self.create_settings_display();
+        let title = match self.diagnostics.len() {
+            0 => "Configuration Settings".to_string(),
+            1 => "Configuration Settings (1 warning)".to_string(),
+            count => format!("Configuration Settings ({} warnings)", count),
+        };
 
         let settings_paragraph = Paragraph::new(settings_lines)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title("Configuration Settings")
+                    .title(title)
                     .title_style(
                         Style::default()
                             .fg(Color::White)
@@ -68,14 +128,44 @@ impl SettingsConfirmationState {
     ///
     /// * `code` - The key code pressed
     /// * `make_next_state` - A closure that takes the config and returns the next state
-    pub fn handle_key<S, F>(&self, code: KeyCode, make_next_state: F) -> StateChange<S>
+    ///
+    /// When settings changed since the previous run, the first Enter only
+    /// asks for confirmation and the second one continues.
+    pub fn handle_key<S, F>(&mut self, code: KeyCode, make_next_state: F) -> StateChange<S>
     where
         F: FnOnce(&AppConfig) -> S,
     {
         match code {
+            KeyCode::Enter if !self.changes.is_empty() && !self.confirming_changes => {
+                self.confirming_changes = true;
+                StateChange::Keep
+            }
             KeyCode::Enter => StateChange::Change(make_next_state(&self.config)),
             KeyCode::Char('q') | KeyCode::Esc => StateChange::Exit,
-            _ => StateChange::Keep,
+            _ => {
+                self.confirming_changes = false;
+                StateChange::Keep
+            }
+        }
+    }
+
+    /// Key actions available on the settings confirmation screen.
+    pub fn action_map(&self) -> ActionMap {
+        let map = ActionMap::new("Settings Confirmation")
+            .bind(
+                ActionCategory::General,
+                &[KeyCode::Enter],
+                "Confirm settings and continue (twice if settings changed)",
+            )
+            .bind(
+                ActionCategory::General,
+                &[KeyCode::Char('q'), KeyCode::Esc],
+                "Quit",
+            );
+        if self.confirming_changes {
+            map.bind_any_key(ActionCategory::General, "Keep reviewing the changes")
+        } else {
+            map
         }
     }
 
@@ -181,6 +271,7 @@ impl SettingsConfirmationState {
             AppConfig::Migration { .. } => "Migration",
             AppConfig::Cleanup { .. } => "Cleanup",
             AppConfig::ReleaseNotes { .. } => "Release Notes",
+            AppConfig::Analyze { .. } => "Analyze",
         };
 
         let mut lines = vec![
@@ -194,6 +285,10 @@ impl SettingsConfirmationState {
             Line::from(""),
         ];
 
+        if let Some(previous_run) = &self.previous_run {
+            lines.extend(self.create_changes_display(previous_run));
+        }
+
         let shared = self.config.shared();
 
         // Azure DevOps Settings
@@ -219,8 +314,10 @@ impl SettingsConfirmationState {
         )));
         lines.push(self.format_property_with_source("Dev Branch", &shared.dev_branch));
         lines.push(self.format_property_with_source("Target Branch", &shared.target_branch));
+        self.push_diagnostics(&mut lines, ConfigSetting::TargetBranch);
         if let Some(ref local_repo) = shared.local_repo {
             lines.push(self.format_property_with_source("Local Repo", local_repo));
+            self.push_diagnostics(&mut lines, ConfigSetting::LocalRepo);
         } else {
             lines.push(Line::from("  Local Repo: [None - will clone]"));
         }
@@ -245,6 +342,7 @@ impl SettingsConfirmationState {
             &shared.max_concurrent_processing,
         ));
         lines.push(self.format_property_with_source("Tag Prefix", &shared.tag_prefix));
+        self.push_diagnostics(&mut lines, ConfigSetting::TagPrefix);
 
         // Special handling for since field showing both original and parsed value
         if let Some(ref since) = shared.since {
@@ -271,6 +369,12 @@ impl SettingsConfirmationState {
                     ]));
                 }
             }
+            if shared.since_field != SinceField::Closed {
+                lines.push(Line::from(vec![
+                    Span::styled("  Since Field: ", Style::default()),
+                    Span::styled(shared.since_field.to_string(), Style::default()),
+                ]));
+            }
         }
         lines.push(Line::from(""));
 
@@ -305,11 +409,19 @@ impl SettingsConfirmationState {
                 lines.push(self.format_property_with_source("Target Branch", &cleanup.target));
             }
             AppConfig::ReleaseNotes { .. } => {}
+            AppConfig::Analyze { .. } => {}
         }
         lines.push(Line::from(""));
 
         // Instructions
         lines.push(Line::from(""));
+        let prompt = if self.confirming_changes {
+            " again to continue with the changed settings or "
+        } else if !self.changes.is_empty() {
+            " to confirm the changed settings or "
+        } else {
+            " to continue or "
+        };
         lines.push(Line::from(vec![
             Span::styled("Press ", Style::default().fg(Color::Gray)),
             Span::styled(
@@ -318,7 +430,7 @@ impl SettingsConfirmationState {
                     .fg(Color::Green)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" to continue or ", Style::default().fg(Color::Gray)),
+            Span::styled(prompt, Style::default().fg(Color::Gray)),
             Span::styled(
                 "[q/Esc]",
                 Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
@@ -328,6 +440,68 @@ impl SettingsConfirmationState {
 
         lines
     }
+
+    /// Adds the diagnostics of `setting` below its line.
+    fn push_diagnostics(&self, lines: &mut Vec<Line<'_>>, setting: ConfigSetting) {
+        for diagnostic in self.diagnostics.iter().filter(|d| d.setting == setting) {
+            lines.push(Line::from(Span::styled(
+                format!("    ⚠ {}", diagnostic.message),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            lines.push(Line::from(Span::styled(
+                format!("      → {}", diagnostic.remediation),
+                Style::default().fg(Color::Gray),
+            )));
+        }
+    }
+
+    fn create_changes_display(&self, previous_run: &LastRun) -> Vec<Line<'_>> {
+        let mut heading = format!(
+            "Changed Since Previous Run ({}",
+            previous_run.recorded_at.format("%Y-%m-%d %H:%M UTC")
+        );
+        if let Some(version) = &previous_run.settings.version {
+            heading.push_str(&format!(", version {}", version));
+        }
+        heading.push_str("):");
+
+        let mut lines = vec![Line::from(Span::styled(
+            heading,
+            Style::default()
+                .fg(Color::LightRed)
+                .add_modifier(Modifier::BOLD),
+        ))];
+        for change in &self.changes {
+            lines.push(Line::from(vec![
+                Span::raw(format!("  {}: ", change.name)),
+                Span::styled(
+                    change.previous.clone(),
+                    Style::default()
+                        .fg(Color::Gray)
+                        .add_modifier(Modifier::CROSSED_OUT),
+                ),
+                Span::raw(" → "),
+                Span::styled(
+                    change.current.clone(),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]));
+        }
+        if self.confirming_changes {
+            lines.push(Line::from(Span::styled(
+                "  Press Enter again to confirm these changes.",
+                Style::default()
+                    .fg(Color::LightRed)
+                    .add_modifier(Modifier::BOLD),
+            )));
+        }
+        lines.push(Line::from(""));
+        lines
+    }
 }
 
 #[cfg(test)]
@@ -541,4 +715,121 @@ mod tests {
             assert_snapshot!("file_values", harness.backend());
         });
     }
+
+    /// # Settings Confirmation Changed Since Previous Run Test
+    ///
+    /// Tests the two-phase confirmation when settings differ from the previous run.
+    ///
+    /// ## Test Scenario
+    /// - Creates a state whose previous run used another target branch and tag prefix
+    /// - Presses Enter once, renders, then presses Enter again
+    /// - Repeats with a key in between the two Enters
+    ///
+    /// ## Expected Outcome
+    /// - The changes are listed with their previous and current values
+    /// - The first Enter only asks for confirmation; the second one continues
+    /// - Any other key cancels the pending confirmation
+    /// - Without changes, a single Enter continues
+    #[test]
+    fn test_settings_confirmation_changed_since_previous_run() {
+        use crate::core::state::{LastRun, RunSettings};
+        use crate::ui::snapshot_testing::with_settings_and_module_path;
+        use chrono::TimeZone;
+
+        let current = RunSettings {
+            dev_branch: "dev".to_string(),
+            target_branch: "next".to_string(),
+            version: None,
+            work_item_state: "Next Merged".to_string(),
+            tag_prefix: "merged-".to_string(),
+            run_hooks: false,
+        };
+        let previous = LastRun {
+            recorded_at: chrono::Utc.with_ymd_and_hms(2026, 10, 1, 9, 30, 0).unwrap(),
+            settings: RunSettings {
+                target_branch: "release".to_string(),
+                version: Some("1.4.0".to_string()),
+                tag_prefix: "released-".to_string(),
+                ..current.clone()
+            },
+        };
+
+        with_settings_and_module_path(module_path!(), || {
+            let config = create_test_config_default();
+            let mut harness = TuiTestHarness::with_config(config.clone());
+            let mut state = SettingsConfirmationState::new(config.clone())
+                .with_previous_run(Some(previous.clone()), &current);
+            assert_eq!(state.changes().len(), 2);
+
+            let change = state.handle_key(KeyCode::Enter, |_| ());
+            assert!(matches!(change, StateChange::Keep));
+            assert!(state.is_confirming_changes());
+
+            harness.terminal.draw(|f| state.render(f)).unwrap();
+            assert_snapshot!("changed_since_previous_run", harness.backend());
+
+            let change = state.handle_key(KeyCode::Enter, |_| ());
+            assert!(matches!(change, StateChange::Change(())));
+
+            let mut state = SettingsConfirmationState::new(config.clone())
+                .with_previous_run(Some(previous.clone()), &current);
+            let _ = state.handle_key(KeyCode::Enter, |_| ());
+            assert!(state.action_map().handles(KeyCode::Down));
+            let _ = state.handle_key(KeyCode::Down, |_| ());
+            assert!(!state.is_confirming_changes());
+            assert!(!state.action_map().handles(KeyCode::Down));
+            let change = state.handle_key(KeyCode::Enter, |_| ());
+            assert!(matches!(change, StateChange::Keep));
+
+            let settings = previous.settings.clone();
+            let mut state =
+                SettingsConfirmationState::new(config).with_previous_run(Some(previous), &settings);
+            assert!(state.changes().is_empty());
+            let change = state.handle_key(KeyCode::Enter, |_| ());
+            assert!(matches!(change, StateChange::Change(())));
+        });
+    }
+
+    /// # Settings Confirmation Config Diagnostics Test
+    ///
+    /// Tests the warnings shown for suspicious settings.
+    ///
+    /// ## Test Scenario
+    /// - Creates a default mode config whose target branch equals the dev
+    ///   branch, whose local repo does not exist and whose tag prefix is empty
+    /// - Renders the settings confirmation screen
+    ///
+    /// ## Expected Outcome
+    /// - Each warning is shown below its setting, with the remediation
+    /// - The title counts the warnings
+    /// - Enter still continues
+    #[test]
+    fn test_settings_confirmation_config_diagnostics() {
+        use crate::ui::snapshot_testing::with_settings_and_module_path;
+
+        with_settings_and_module_path(module_path!(), || {
+            let mut config = create_test_config_default();
+            if let AppConfig::Default { shared, .. } = &mut config {
+                shared.target_branch = ParsedProperty::Default("develop".to_string());
+                shared.tag_prefix = ParsedProperty::Default(String::new());
+            }
+            let mut harness = TuiTestHarness::with_config(config.clone());
+            let mut state = SettingsConfirmationState::new(config);
+            let settings: Vec<_> = state.diagnostics().iter().map(|d| d.setting).collect();
+            assert_eq!(
+                settings,
+                vec![
+                    ConfigSetting::TargetBranch,
+                    ConfigSetting::LocalRepo,
+                    ConfigSetting::TagPrefix
+                ]
+            );
+
+            harness.terminal.draw(|f| state.render(f)).unwrap();
+            assert_snapshot!("config_diagnostics", harness.backend());
+
+            let change = state.handle_key(KeyCode::Enter, |_| ());
+            assert!(matches!(change, StateChange::Change(())));
+        });
+    }
 }
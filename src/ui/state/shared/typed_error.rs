@@ -4,6 +4,7 @@
 //! that can work with any mode-specific app type (MergeApp, MigrationApp, CleanupApp).
 
 use crate::ui::AppMode;
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::ui::state::typed::StateChange;
 use crossterm::event::KeyCode;
 use ratatui::{
@@ -120,6 +121,11 @@ where
         }
     }
 
+    /// Key actions available on the error screen.
+    pub fn action_map(&self) -> ActionMap {
+        ActionMap::new("Error").bind(ActionCategory::General, &[KeyCode::Char('q')], "Quit")
+    }
+
     /// Get this state's name for logging/debugging.
     pub fn name(&self) -> &'static str {
         "Error"
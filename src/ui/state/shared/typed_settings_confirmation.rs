@@ -3,9 +3,10 @@
 //! This module provides [`TypedSettingsConfirmationState`], a generic settings
 //! confirmation state that can work with any mode-specific app type.
 
-use crate::models::AppConfig;
+use crate::models::{AppConfig, SinceField};
 use crate::parsed_property::ParsedProperty;
 use crate::ui::AppMode;
+use crate::ui::keymap::{ActionCategory, ActionMap};
 use crate::ui::state::typed::StateChange;
 use crossterm::event::KeyCode;
 use ratatui::{
@@ -173,6 +174,7 @@ impl<A, S> TypedSettingsConfirmationState<A, S> {
             AppConfig::Migration { .. } => "Migration",
             AppConfig::Cleanup { .. } => "Cleanup",
             AppConfig::ReleaseNotes { .. } => "Release Notes",
+            AppConfig::Analyze { .. } => "Analyze",
         };
 
         let mut lines = vec![
@@ -200,6 +202,9 @@ impl<A, S> TypedSettingsConfirmationState<A, S> {
         lines.push(self.format_property_with_source("Project", &shared.project));
         lines.push(self.format_property_with_source("Repository", &shared.repository));
         lines.push(Line::from("  PAT: ****hidden****"));
+        if shared.write_pat.is_some() {
+            lines.push(Line::from("  Write PAT: ****hidden****"));
+        }
         lines.push(Line::from(""));
 
         // Branch Settings
@@ -263,6 +268,12 @@ impl<A, S> TypedSettingsConfirmationState<A, S> {
                     ]));
                 }
             }
+            if shared.since_field != SinceField::Closed {
+                lines.push(Line::from(vec![
+                    Span::styled("  Since Field: ", Style::default()),
+                    Span::styled(shared.since_field.to_string(), Style::default()),
+                ]));
+            }
         }
         lines.push(Line::from(""));
 
@@ -296,6 +307,7 @@ impl<A, S> TypedSettingsConfirmationState<A, S> {
                 lines.push(self.format_property_with_source("Target Branch", &cleanup.target));
             }
             AppConfig::ReleaseNotes { .. } => {}
+            AppConfig::Analyze { .. } => {}
         }
         lines.push(Line::from(""));
 
@@ -374,6 +386,21 @@ where
         }
     }
 
+    /// Key actions available on the settings confirmation screen.
+    pub fn action_map(&self) -> ActionMap {
+        ActionMap::new("Settings Confirmation")
+            .bind(
+                ActionCategory::General,
+                &[KeyCode::Enter],
+                "Confirm settings and continue",
+            )
+            .bind(
+                ActionCategory::General,
+                &[KeyCode::Char('q'), KeyCode::Esc],
+                "Quit",
+            )
+    }
+
     /// Get this state's name for logging/debugging.
     pub fn name(&self) -> &'static str {
         "SettingsConfirmation"
@@ -392,6 +419,7 @@ mod tests {
                 project: ParsedProperty::Default("test-project".to_string()),
                 repository: ParsedProperty::Default("test-repo".to_string()),
                 pat: ParsedProperty::Default("test-pat".to_string()),
+                write_pat: None,
                 dev_branch: ParsedProperty::Default("develop".to_string()),
                 target_branch: ParsedProperty::Default("main".to_string()),
                 local_repo: None,
@@ -400,10 +428,37 @@ mod tests {
                 max_concurrent_processing: ParsedProperty::Default(5),
                 tag_prefix: ParsedProperty::Default("merged/".to_string()),
                 since: None,
+                since_field: Default::default(),
                 skip_confirmation: false,
+                api_version: None,
+                history_depth: None,
+                url_templates: Default::default(),
+                release_notes_locale: Default::default(),
             },
             default: DefaultModeConfig {
                 work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+                strict_states: None,
+                release_gate: None,
+                build_checks: Default::default(),
+                release_tag: Default::default(),
+                sign_commits: false,
+                fan_out: Vec::new(),
+                squash_message: None,
+                mainline_fallback: false,
+                auto_refresh: None,
+                select_file: None,
+                pr_tabs: Vec::new(),
+                selection_soft_cap: None,
+                terminal_states: Vec::new(),
+                work_item_comment: None,
+                artifacts_feed: None,
+                work_item_fields: Vec::new(),
+                notifications: None,
+                binary_conflicts: Default::default(),
+                cherry_pick_order: Default::default(),
+                pr_filter: Default::default(),
+                session: None,
+                clipboard: Default::default(),
                 run_hooks: ParsedProperty::Default(false),
             },
         }
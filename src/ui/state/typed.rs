@@ -23,8 +23,9 @@
 //! This means each sub-state only needs to specify one associated type instead of two.
 
 use crate::ui::AppMode;
+use crate::ui::keymap::ActionMap;
 use async_trait::async_trait;
-use crossterm::event::{KeyCode, MouseEvent};
+use crossterm::event::{KeyCode, KeyEvent, MouseEvent};
 use ratatui::Frame;
 
 /// State change result from state operations.
@@ -165,6 +166,14 @@ pub trait AppState: Send + Sync + Sized {
     /// Returns `StateChange<Self>` for type-safe transitions within the mode.
     async fn process_key(&mut self, code: KeyCode, app: &mut Self::App) -> StateChange<Self>;
 
+    /// Process keyboard input including modifier keys.
+    ///
+    /// Default implementation ignores the modifiers and calls
+    /// [`Self::process_key`]; override it for shortcuts like `Alt+←`.
+    async fn process_key_event(&mut self, key: KeyEvent, app: &mut Self::App) -> StateChange<Self> {
+        self.process_key(key.code, app).await
+    }
+
     /// Process mouse input.
     ///
     /// Default implementation returns `Keep` (no-op).
@@ -185,6 +194,22 @@ pub trait AppState: Send + Sync + Sized {
         StateChange::Keep
     }
 
+    /// Key actions currently available, used to build the `?` help overlay.
+    ///
+    /// Default implementation returns an empty map.
+    fn action_map(&self) -> ActionMap {
+        ActionMap::default()
+    }
+
+    /// A plain-text progress line for screen readers, written when
+    /// `MERGERS_PLAIN_UPDATES` is set.
+    ///
+    /// Default implementation returns `None`; the screen name is announced
+    /// either way.
+    fn announcement(&self, _app: &Self::App) -> Option<String> {
+        None
+    }
+
     /// Get this state's name for logging/debugging.
     fn name(&self) -> &'static str;
 }
@@ -263,6 +288,18 @@ pub trait ModeState: Send + Sync {
         app: &mut <Self::Mode as AppState>::App,
     ) -> StateChange<Self::Mode>;
 
+    /// Process keyboard input including modifier keys.
+    ///
+    /// Default implementation ignores the modifiers and calls
+    /// [`Self::process_key`].
+    async fn process_key_event(
+        &mut self,
+        key: KeyEvent,
+        app: &mut <Self::Mode as AppState>::App,
+    ) -> StateChange<Self::Mode> {
+        self.process_key(key.code, app).await
+    }
+
     /// Process mouse input.
     ///
     /// Default implementation returns `Keep` (no-op).
@@ -283,6 +320,13 @@ pub trait ModeState: Send + Sync {
         StateChange::Keep
     }
 
+    /// Key actions currently available, used to build the `?` help overlay.
+    ///
+    /// Default implementation returns an empty map.
+    fn action_map(&self) -> ActionMap {
+        ActionMap::default()
+    }
+
     /// Get this state's name for logging/debugging.
     fn name(&self) -> &'static str;
 }
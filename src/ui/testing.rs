@@ -1,3 +1,10 @@
+//! Test harness for TUI states.
+//!
+//! [`TuiTestHarness`] renders states into a fixed-size [`TestBackend`] and
+//! drives them with key events against a real [`MergeApp`](crate::ui::MergeApp),
+//! [`MigrationApp`](crate::ui::MigrationApp) or [`CleanupApp`](crate::ui::CleanupApp).
+//! Available to other crates with the `testing` feature.
+
 use crate::{
     api::AzureDevOpsClient,
     models::{
@@ -69,16 +76,22 @@ impl TuiTestHarness {
 
     /// Create a test harness with a specific configuration
     pub fn with_config(config: AppConfig) -> Self {
+        Self::with_config_and_browser(
+            config,
+            Box::new(crate::ui::browser::MockBrowserOpener::new()),
+        )
+    }
+
+    /// Create a test harness with a specific configuration and browser opener
+    pub fn with_config_and_browser(
+        config: AppConfig,
+        browser: Box<dyn crate::ui::browser::BrowserOpener>,
+    ) -> Self {
         let backend = TestBackend::new(TEST_TERMINAL_WIDTH, TEST_TERMINAL_HEIGHT);
         let terminal = Terminal::new(backend).unwrap();
 
         let client = create_test_client();
-        let app = App::new_with_browser(
-            Vec::new(),
-            Arc::new(config),
-            client,
-            Box::new(crate::ui::browser::MockBrowserOpener::new()),
-        );
+        let app = App::new_with_browser(Vec::new(), Arc::new(config), client, browser);
 
         Self {
             terminal,
@@ -404,6 +417,28 @@ pub fn create_test_config_default() -> AppConfig {
         shared: create_test_shared_config(),
         default: DefaultModeConfig {
             work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+            strict_states: None,
+            release_gate: None,
+            build_checks: Default::default(),
+            release_tag: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            pr_tabs: Vec::new(),
+            selection_soft_cap: None,
+            terminal_states: Vec::new(),
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            notifications: None,
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            pr_filter: Default::default(),
+            session: None,
+            clipboard: Default::default(),
             run_hooks: ParsedProperty::Default(false),
         },
     }
@@ -446,6 +481,7 @@ fn create_test_shared_config() -> SharedConfig {
             "repository = \"test-repo\"".to_string(),
         ),
         pat: ParsedProperty::Default("test-pat".to_string()),
+        write_pat: None,
         dev_branch: ParsedProperty::Git("develop".to_string(), "origin/develop".to_string()),
         target_branch: ParsedProperty::Default("main".to_string()),
         local_repo: Some(ParsedProperty::Cli(
@@ -457,7 +493,12 @@ fn create_test_shared_config() -> SharedConfig {
         max_concurrent_processing: ParsedProperty::Default(5),
         tag_prefix: ParsedProperty::Default("merged/".to_string()),
         since: None,
+        since_field: Default::default(),
         skip_confirmation: false,
+        api_version: None,
+        history_depth: None,
+        url_templates: Default::default(),
+        release_notes_locale: Default::default(),
     }
 }
 
@@ -469,6 +510,7 @@ pub fn create_test_config_all_defaults() -> AppConfig {
             project: ParsedProperty::Default("default-project".to_string()),
             repository: ParsedProperty::Default("default-repo".to_string()),
             pat: ParsedProperty::Default("default-pat".to_string()),
+            write_pat: None,
             dev_branch: ParsedProperty::Default("develop".to_string()),
             target_branch: ParsedProperty::Default("main".to_string()),
             local_repo: None,
@@ -477,10 +519,37 @@ pub fn create_test_config_all_defaults() -> AppConfig {
             max_concurrent_processing: ParsedProperty::Default(5),
             tag_prefix: ParsedProperty::Default("merged/".to_string()),
             since: None,
+            since_field: Default::default(),
             skip_confirmation: false,
+            api_version: None,
+            history_depth: None,
+            url_templates: Default::default(),
+            release_notes_locale: Default::default(),
         },
         default: DefaultModeConfig {
             work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+            strict_states: None,
+            release_gate: None,
+            build_checks: Default::default(),
+            release_tag: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            pr_tabs: Vec::new(),
+            selection_soft_cap: None,
+            terminal_states: Vec::new(),
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            notifications: None,
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            pr_filter: Default::default(),
+            session: None,
+            clipboard: Default::default(),
             run_hooks: ParsedProperty::Default(false),
         },
     }
@@ -494,6 +563,7 @@ pub fn create_test_config_cli_values() -> AppConfig {
             project: ParsedProperty::Cli("cli-project".to_string(), "cli-project".to_string()),
             repository: ParsedProperty::Cli("cli-repo".to_string(), "cli-repo".to_string()),
             pat: ParsedProperty::Cli("cli-pat".to_string(), "cli-pat".to_string()),
+            write_pat: None,
             dev_branch: ParsedProperty::Cli(
                 "feature-branch".to_string(),
                 "feature-branch".to_string(),
@@ -516,10 +586,37 @@ pub fn create_test_config_cli_values() -> AppConfig {
                     .into(),
                 "2024-01-01".to_string(),
             )),
+            since_field: Default::default(),
             skip_confirmation: false,
+            api_version: None,
+            history_depth: None,
+            url_templates: Default::default(),
+            release_notes_locale: Default::default(),
         },
         default: DefaultModeConfig {
             work_item_state: ParsedProperty::Cli("Done".to_string(), "Done".to_string()),
+            strict_states: None,
+            release_gate: None,
+            build_checks: Default::default(),
+            release_tag: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            pr_tabs: Vec::new(),
+            selection_soft_cap: None,
+            terminal_states: Vec::new(),
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            notifications: None,
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            pr_filter: Default::default(),
+            session: None,
+            clipboard: Default::default(),
             run_hooks: ParsedProperty::Default(false),
         },
     }
@@ -542,6 +639,7 @@ pub fn create_test_config_env_values() -> AppConfig {
                 "MERGERS_REPOSITORY=env-repo".to_string(),
             ),
             pat: ParsedProperty::Env("env-pat".to_string(), "MERGERS_PAT=env-pat".to_string()),
+            write_pat: None,
             dev_branch: ParsedProperty::Env(
                 "env-dev".to_string(),
                 "MERGERS_DEV_BRANCH=env-dev".to_string(),
@@ -556,10 +654,37 @@ pub fn create_test_config_env_values() -> AppConfig {
             max_concurrent_processing: ParsedProperty::Default(5),
             tag_prefix: ParsedProperty::Default("merged/".to_string()),
             since: None,
+            since_field: Default::default(),
             skip_confirmation: false,
+            api_version: None,
+            history_depth: None,
+            url_templates: Default::default(),
+            release_notes_locale: Default::default(),
         },
         default: DefaultModeConfig {
             work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+            strict_states: None,
+            release_gate: None,
+            build_checks: Default::default(),
+            release_tag: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            pr_tabs: Vec::new(),
+            selection_soft_cap: None,
+            terminal_states: Vec::new(),
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            notifications: None,
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            pr_filter: Default::default(),
+            session: None,
+            clipboard: Default::default(),
             run_hooks: ParsedProperty::Default(false),
         },
     }
@@ -585,6 +710,7 @@ pub fn create_test_config_file_values() -> AppConfig {
                 "repository = \"file-repo\"".to_string(),
             ),
             pat: ParsedProperty::Default("default-pat".to_string()),
+            write_pat: None,
             dev_branch: ParsedProperty::Default("develop".to_string()),
             target_branch: ParsedProperty::Default("main".to_string()),
             local_repo: Some(ParsedProperty::File(
@@ -597,10 +723,37 @@ pub fn create_test_config_file_values() -> AppConfig {
             max_concurrent_processing: ParsedProperty::Default(5),
             tag_prefix: ParsedProperty::Default("merged/".to_string()),
             since: None,
+            since_field: Default::default(),
             skip_confirmation: false,
+            api_version: None,
+            history_depth: None,
+            url_templates: Default::default(),
+            release_notes_locale: Default::default(),
         },
         default: DefaultModeConfig {
             work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+            strict_states: None,
+            release_gate: None,
+            build_checks: Default::default(),
+            release_tag: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            pr_tabs: Vec::new(),
+            selection_soft_cap: None,
+            terminal_states: Vec::new(),
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            notifications: None,
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            pr_filter: Default::default(),
+            session: None,
+            clipboard: Default::default(),
             run_hooks: ParsedProperty::Default(false),
         },
     }
@@ -627,6 +780,7 @@ pub fn create_test_pull_request() -> PullRequest {
                 name: "enhancement".to_string(),
             },
         ]),
+        reviewers: Vec::new(),
     }
 }
 
@@ -647,6 +801,7 @@ pub fn create_test_work_item() -> WorkItem {
             ),
             repro_steps: None,
             state_color: None,
+            custom_fields: Default::default(),
         },
         history: vec![],
     }
@@ -670,6 +825,7 @@ pub fn create_test_pull_requests() -> Vec<PullRequestWithWorkItems> {
                 labels: Some(vec![Label {
                     name: "bug".to_string(),
                 }]),
+                reviewers: Vec::new(),
             },
             work_items: vec![WorkItem {
                 id: 1001,
@@ -684,6 +840,7 @@ pub fn create_test_pull_requests() -> Vec<PullRequestWithWorkItems> {
                     description: Some("<div>Users unable to click login button</div>".to_string()),
                     repro_steps: Some("<div>1. Navigate to login page<br>2. Click login button<br>3. Nothing happens</div>".to_string()),
                     state_color: None,
+                    custom_fields: Default::default(),
                 },
                 history: vec![],
             }],
@@ -709,6 +866,7 @@ pub fn create_test_pull_requests() -> Vec<PullRequestWithWorkItems> {
                         name: "enhancement".to_string(),
                     },
                 ]),
+                reviewers: Vec::new(),
             },
             work_items: vec![WorkItem {
                 id: 1002,
@@ -726,6 +884,7 @@ pub fn create_test_pull_requests() -> Vec<PullRequestWithWorkItems> {
                     ),
                     repro_steps: None,
                     state_color: None,
+                    custom_fields: Default::default(),
                 },
                 history: vec![],
             }],
@@ -746,6 +905,7 @@ pub fn create_test_pull_requests() -> Vec<PullRequestWithWorkItems> {
                 labels: Some(vec![Label {
                     name: "feature".to_string(),
                 }]),
+                reviewers: Vec::new(),
             },
             work_items: vec![
                 WorkItem {
@@ -764,6 +924,7 @@ pub fn create_test_pull_requests() -> Vec<PullRequestWithWorkItems> {
                         ),
                         repro_steps: None,
                         state_color: None,
+                        custom_fields: Default::default(),
                     },
                     history: vec![],
                 },
@@ -782,6 +943,7 @@ pub fn create_test_pull_requests() -> Vec<PullRequestWithWorkItems> {
                         ),
                         repro_steps: None,
                         state_color: None,
+                        custom_fields: Default::default(),
                     },
                     history: vec![],
                 },
@@ -918,6 +1080,7 @@ pub fn create_large_pr_list() -> Vec<PullRequestWithWorkItems> {
                     commit_id: format!("commit{:08x}", i * 12345),
                 }),
                 labels: Some(vec![]),
+                reviewers: Vec::new(),
             },
             work_items: if i % 3 == 0 {
                 vec![WorkItem {
@@ -939,6 +1102,7 @@ pub fn create_large_pr_list() -> Vec<PullRequestWithWorkItems> {
                         description: Some("<div>Test work item</div>".to_string()),
                         repro_steps: None,
                         state_color: None,
+                        custom_fields: Default::default(),
                     },
                     history: vec![],
                 }]
@@ -10,6 +10,16 @@
 //! - **No runtime dispatch**: Direct method calls instead of virtual dispatch
 //! - **Mode isolation**: Each mode has its own state machine
 //!
+//! Each loop also owns a [`HelpOverlay`] which intercepts `?` and renders the
+//! active state's action map on top of the state's UI, and a [`ScreenDump`]
+//! which writes a plain-text snapshot of the screen on `Ctrl+S`. Frames are
+//! coalesced by a [`RenderThrottle`]: the screen is redrawn after input or a
+//! state transition, capped at `MERGERS_MAX_FPS`, and otherwise only at a
+//! slow idle rate. After each frame the app's
+//! [`Accessibility`](crate::ui::Accessibility) settings apply the
+//! high-contrast palette and an [`Announcer`] writes plain-line progress
+//! updates.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -20,12 +30,16 @@
 //! run_merge_mode(&mut terminal, &mut merge_app, &event_source, initial_state).await?;
 //! ```
 
-use crate::ui::EventSource;
 use crate::ui::apps::{CleanupApp, MergeApp, MigrationApp};
 use crate::ui::state::typed::{AppState, StateChange};
 use crate::ui::state::{CleanupModeState, MergeState, MigrationModeState};
+use crate::ui::{Announcer, EventSource, HelpOverlay, RenderThrottle, ScreenDump, shell};
 use crossterm::event::{Event, KeyCode};
 use ratatui::Terminal;
+use std::time::Duration;
+
+/// Interval of the `KeyCode::Null` ticks that drive background work.
+const TICK: Duration = Duration::from_millis(50);
 
 /// Macro to process typed state changes and handle Keep/Change/Exit
 macro_rules! handle_typed_state_change {
@@ -66,18 +80,45 @@ where
     B::Error: Send + Sync + 'static,
 {
     let mut current_state = initial_state;
+    let mut help = HelpOverlay::new();
+    let mut dump = ScreenDump::new();
+    let mut throttle = RenderThrottle::from_env();
+    let accessibility = app.accessibility().clone();
+    let mut announcer = Announcer::new(accessibility.plain_updates.as_deref());
 
     loop {
-        terminal.draw(|f| AppState::ui(&mut current_state, f, app))?;
+        if throttle.should_draw() {
+            terminal.draw(|f| {
+                AppState::ui(&mut current_state, f, app);
+                help.render(f, &current_state.action_map());
+                dump.render(f);
+                accessibility.apply(f.buffer_mut());
+            })?;
+            throttle.drew();
+            announcer.announce_state(&current_state, app);
+        }
 
-        if event_source.poll(std::time::Duration::from_millis(50))? {
+        if event_source.poll(throttle.poll_timeout(TICK))? {
+            throttle.mark_dirty();
             match event_source.read()? {
+                Event::Key(key) if ScreenDump::is_dump_key(&key) => {
+                    // Re-render without the previous notice so it is not captured
+                    let frame = terminal.draw(|f| {
+                        AppState::ui(&mut current_state, f, app);
+                        help.render(f, &current_state.action_map());
+                    })?;
+                    dump.save(frame.buffer, AppState::name(&current_state), app);
+                }
+                Event::Key(key) if help.handle_key(key.code, &current_state.action_map()) => {}
+                // Keys the state does not list in its action map are ignored
+                Event::Key(key) if !current_state.action_map().handles(key.code) => {}
                 Event::Key(key) => {
                     handle_typed_state_change!(
-                        AppState::process_key(&mut current_state, key.code, app).await,
+                        AppState::process_key_event(&mut current_state, key, app).await,
                         current_state
                     );
                 }
+                Event::Mouse(_) if help.is_visible() => {}
                 Event::Mouse(mouse) => {
                     handle_typed_state_change!(
                         AppState::process_mouse(&mut current_state, mouse, app).await,
@@ -87,10 +128,19 @@ where
                 _ => {}
             }
         } else {
-            handle_typed_state_change!(
-                AppState::process_key(&mut current_state, KeyCode::Null, app).await,
-                current_state
-            );
+            let change = AppState::process_key(&mut current_state, KeyCode::Null, app).await;
+            if !matches!(change, StateChange::Keep) {
+                throttle.mark_dirty();
+            }
+            handle_typed_state_change!(change, current_state);
+        }
+
+        if let Some(dir) = app.take_shell_request() {
+            if let Err(e) = shell::run_shell(&dir) {
+                tracing::warn!("Shell in {} failed: {:#}", dir.display(), e);
+            }
+            terminal.clear()?;
+            throttle.mark_dirty();
         }
     }
 
@@ -123,18 +173,45 @@ where
     B::Error: Send + Sync + 'static,
 {
     let mut current_state = initial_state;
+    let mut help = HelpOverlay::new();
+    let mut dump = ScreenDump::new();
+    let mut throttle = RenderThrottle::from_env();
+    let accessibility = app.accessibility().clone();
+    let mut announcer = Announcer::new(accessibility.plain_updates.as_deref());
 
     loop {
-        terminal.draw(|f| AppState::ui(&mut current_state, f, app))?;
+        if throttle.should_draw() {
+            terminal.draw(|f| {
+                AppState::ui(&mut current_state, f, app);
+                help.render(f, &current_state.action_map());
+                dump.render(f);
+                accessibility.apply(f.buffer_mut());
+            })?;
+            throttle.drew();
+            announcer.announce_state(&current_state, app);
+        }
 
-        if event_source.poll(std::time::Duration::from_millis(50))? {
+        if event_source.poll(throttle.poll_timeout(TICK))? {
+            throttle.mark_dirty();
             match event_source.read()? {
+                Event::Key(key) if ScreenDump::is_dump_key(&key) => {
+                    // Re-render without the previous notice so it is not captured
+                    let frame = terminal.draw(|f| {
+                        AppState::ui(&mut current_state, f, app);
+                        help.render(f, &current_state.action_map());
+                    })?;
+                    dump.save(frame.buffer, AppState::name(&current_state), app);
+                }
+                Event::Key(key) if help.handle_key(key.code, &current_state.action_map()) => {}
+                // Keys the state does not list in its action map are ignored
+                Event::Key(key) if !current_state.action_map().handles(key.code) => {}
                 Event::Key(key) => {
                     handle_typed_state_change!(
-                        AppState::process_key(&mut current_state, key.code, app).await,
+                        AppState::process_key_event(&mut current_state, key, app).await,
                         current_state
                     );
                 }
+                Event::Mouse(_) if help.is_visible() => {}
                 Event::Mouse(mouse) => {
                     handle_typed_state_change!(
                         AppState::process_mouse(&mut current_state, mouse, app).await,
@@ -144,10 +221,11 @@ where
                 _ => {}
             }
         } else {
-            handle_typed_state_change!(
-                AppState::process_key(&mut current_state, KeyCode::Null, app).await,
-                current_state
-            );
+            let change = AppState::process_key(&mut current_state, KeyCode::Null, app).await;
+            if !matches!(change, StateChange::Keep) {
+                throttle.mark_dirty();
+            }
+            handle_typed_state_change!(change, current_state);
         }
     }
 
@@ -180,18 +258,45 @@ where
     B::Error: Send + Sync + 'static,
 {
     let mut current_state = initial_state;
+    let mut help = HelpOverlay::new();
+    let mut dump = ScreenDump::new();
+    let mut throttle = RenderThrottle::from_env();
+    let accessibility = app.accessibility().clone();
+    let mut announcer = Announcer::new(accessibility.plain_updates.as_deref());
 
     loop {
-        terminal.draw(|f| AppState::ui(&mut current_state, f, app))?;
+        if throttle.should_draw() {
+            terminal.draw(|f| {
+                AppState::ui(&mut current_state, f, app);
+                help.render(f, &current_state.action_map());
+                dump.render(f);
+                accessibility.apply(f.buffer_mut());
+            })?;
+            throttle.drew();
+            announcer.announce_state(&current_state, app);
+        }
 
-        if event_source.poll(std::time::Duration::from_millis(50))? {
+        if event_source.poll(throttle.poll_timeout(TICK))? {
+            throttle.mark_dirty();
             match event_source.read()? {
+                Event::Key(key) if ScreenDump::is_dump_key(&key) => {
+                    // Re-render without the previous notice so it is not captured
+                    let frame = terminal.draw(|f| {
+                        AppState::ui(&mut current_state, f, app);
+                        help.render(f, &current_state.action_map());
+                    })?;
+                    dump.save(frame.buffer, AppState::name(&current_state), app);
+                }
+                Event::Key(key) if help.handle_key(key.code, &current_state.action_map()) => {}
+                // Keys the state does not list in its action map are ignored
+                Event::Key(key) if !current_state.action_map().handles(key.code) => {}
                 Event::Key(key) => {
                     handle_typed_state_change!(
-                        AppState::process_key(&mut current_state, key.code, app).await,
+                        AppState::process_key_event(&mut current_state, key, app).await,
                         current_state
                     );
                 }
+                Event::Mouse(_) if help.is_visible() => {}
                 Event::Mouse(mouse) => {
                     handle_typed_state_change!(
                         AppState::process_mouse(&mut current_state, mouse, app).await,
@@ -201,10 +306,11 @@ where
                 _ => {}
             }
         } else {
-            handle_typed_state_change!(
-                AppState::process_key(&mut current_state, KeyCode::Null, app).await,
-                current_state
-            );
+            let change = AppState::process_key(&mut current_state, KeyCode::Null, app).await;
+            if !matches!(change, StateChange::Keep) {
+                throttle.mark_dirty();
+            }
+            handle_typed_state_change!(change, current_state);
         }
     }
 
@@ -215,12 +321,15 @@ where
 mod tests {
     use super::*;
     use crate::api::AzureDevOpsClient;
+    use crate::core::state::STATE_DIR_ENV;
     use crate::models::{CleanupConfig, MergeConfig, MigrationConfig, SharedConfig};
     use crate::parsed_property::ParsedProperty;
     use crate::ui::MockEventSource;
     use crate::ui::browser::MockBrowserOpener;
     use crate::ui::state::ErrorState;
+    use crossterm::event::KeyModifiers;
     use ratatui::backend::TestBackend;
+    use serial_test::file_serial;
     use std::sync::Arc;
 
     fn create_shared_config() -> SharedConfig {
@@ -229,6 +338,7 @@ mod tests {
             project: ParsedProperty::Default("test_project".to_string()),
             repository: ParsedProperty::Default("test_repo".to_string()),
             pat: ParsedProperty::Default("test_pat".to_string()),
+            write_pat: None,
             dev_branch: ParsedProperty::Default("dev".to_string()),
             target_branch: ParsedProperty::Default("main".to_string()),
             local_repo: None,
@@ -237,7 +347,12 @@ mod tests {
             max_concurrent_processing: ParsedProperty::Default(5),
             tag_prefix: ParsedProperty::Default("merged/".to_string()),
             since: None,
+            since_field: Default::default(),
             skip_confirmation: false,
+            api_version: None,
+            history_depth: None,
+            url_templates: Default::default(),
+            release_notes_locale: Default::default(),
         }
     }
 
@@ -269,6 +384,28 @@ mod tests {
         let config = Arc::new(MergeConfig {
             shared: create_shared_config(),
             work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+            strict_states: None,
+            release_gate: None,
+            build_checks: Default::default(),
+            release_tag: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            pr_tabs: Vec::new(),
+            selection_soft_cap: None,
+            terminal_states: Vec::new(),
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            notifications: None,
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            pr_filter: Default::default(),
+            session: None,
+            clipboard: Default::default(),
             run_hooks: ParsedProperty::Default(false),
         });
         let client = create_test_client();
@@ -360,6 +497,28 @@ mod tests {
         let config = Arc::new(MergeConfig {
             shared: create_shared_config(),
             work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+            strict_states: None,
+            release_gate: None,
+            build_checks: Default::default(),
+            release_tag: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            pr_tabs: Vec::new(),
+            selection_soft_cap: None,
+            terminal_states: Vec::new(),
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            notifications: None,
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            pr_filter: Default::default(),
+            session: None,
+            clipboard: Default::default(),
             run_hooks: ParsedProperty::Default(false),
         });
         let client = create_test_client();
@@ -377,4 +536,128 @@ mod tests {
         assert!(result.is_ok());
         assert!(events.is_empty());
     }
+
+    /// # Typed Run Help Overlay Consumes Keys
+    ///
+    /// Tests that the help overlay intercepts keys while visible.
+    ///
+    /// ## Test Scenario
+    /// - Creates merge app and Error state
+    /// - Sends '?' to open help, 'q' to close it, then 'q' again
+    ///
+    /// ## Expected Outcome
+    /// - The first 'q' only closes the overlay, the second exits the loop
+    #[tokio::test]
+    async fn test_run_merge_mode_help_overlay() {
+        let backend = TestBackend::new(80, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let config = Arc::new(MergeConfig {
+            shared: create_shared_config(),
+            work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+            strict_states: None,
+            release_gate: None,
+            build_checks: Default::default(),
+            release_tag: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            pr_tabs: Vec::new(),
+            selection_soft_cap: None,
+            terminal_states: Vec::new(),
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            notifications: None,
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            pr_filter: Default::default(),
+            session: None,
+            clipboard: Default::default(),
+            run_hooks: ParsedProperty::Default(false),
+        });
+        let client = create_test_client();
+        let mut app = MergeApp::new(config, client, Box::new(MockBrowserOpener::new()));
+
+        let events = MockEventSource::new()
+            .with_key(KeyCode::Char('?'))
+            .with_key(KeyCode::Char('q'))
+            .with_key(KeyCode::Char('q'))
+            .with_key(KeyCode::Char('x'));
+
+        let initial_state = MergeState::Error(ErrorState::new());
+        let result = run_merge_mode(&mut terminal, &mut app, &events, initial_state).await;
+
+        assert!(result.is_ok());
+        assert!(!events.is_empty());
+    }
+
+    /// # Typed Run UI Snapshot
+    ///
+    /// Tests that `Ctrl+S` writes a snapshot instead of reaching the state.
+    ///
+    /// ## Test Scenario
+    /// - Creates merge app and Error state with the state dir overridden
+    /// - Sends `Ctrl+S`, then 'q'
+    ///
+    /// ## Expected Outcome
+    /// - A snapshot of the error screen is written to the state dir
+    /// - The loop keeps running until 'q'
+    #[tokio::test]
+    #[file_serial(state_env)]
+    async fn test_run_merge_mode_ui_snapshot() {
+        let state_dir = tempfile::tempdir().unwrap();
+        unsafe { std::env::set_var(STATE_DIR_ENV, state_dir.path()) };
+
+        let backend = TestBackend::new(80, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let config = Arc::new(MergeConfig {
+            shared: create_shared_config(),
+            work_item_state: ParsedProperty::Default("Next Merged".to_string()),
+            strict_states: None,
+            release_gate: None,
+            build_checks: Default::default(),
+            release_tag: Default::default(),
+            sign_commits: false,
+            fan_out: Vec::new(),
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            pr_tabs: Vec::new(),
+            selection_soft_cap: None,
+            terminal_states: Vec::new(),
+            work_item_comment: None,
+            artifacts_feed: None,
+            work_item_fields: Vec::new(),
+            notifications: None,
+            binary_conflicts: Default::default(),
+            cherry_pick_order: Default::default(),
+            pr_filter: Default::default(),
+            session: None,
+            clipboard: Default::default(),
+            run_hooks: ParsedProperty::Default(false),
+        });
+        let client = create_test_client();
+        let mut app = MergeApp::new(config, client, Box::new(MockBrowserOpener::new()));
+
+        let events = MockEventSource::new()
+            .with_key_modified(KeyCode::Char('s'), KeyModifiers::CONTROL)
+            .with_key(KeyCode::Char('q'));
+
+        let initial_state = MergeState::Error(ErrorState::new());
+        let result = run_merge_mode(&mut terminal, &mut app, &events, initial_state).await;
+        unsafe { std::env::remove_var(STATE_DIR_ENV) };
+
+        assert!(result.is_ok());
+        assert!(events.is_empty());
+        let snapshots: Vec<_> = std::fs::read_dir(state_dir.path()).unwrap().collect();
+        assert_eq!(snapshots.len(), 1);
+        let contents = std::fs::read_to_string(snapshots[0].as_ref().unwrap().path()).unwrap();
+        assert!(contents.contains("screen: Error"));
+    }
 }
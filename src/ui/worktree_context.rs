@@ -90,6 +90,11 @@ impl WorktreeContext {
     pub fn set_temp_dir(&mut self, temp_dir: Option<TempDir>) {
         self._temp_dir = temp_dir;
     }
+
+    /// Keeps the temporary directory on disk after exit, returning its path.
+    pub fn keep_temp_dir(&mut self) -> Option<PathBuf> {
+        self._temp_dir.take().map(TempDir::keep)
+    }
 }
 
 impl Drop for WorktreeContext {
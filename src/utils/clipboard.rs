@@ -0,0 +1,262 @@
+//! Clipboard access with fallbacks for headless sessions.
+//!
+//! The system clipboard is unavailable on headless Linux, WSL and SSH
+//! sessions. [`copy_text`] tries the system clipboard first, then the OSC 52
+//! terminal escape sequence, and finally writes the text to a file whose path
+//! is reported back to the caller.
+//!
+//! The system clipboard needs the `clipboard` feature; without it only the
+//! OSC 52 and file fallbacks are available.
+
+use std::fmt;
+use std::io::{IsTerminal, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// File name used for the file fallback when no path is configured.
+const DEFAULT_CLIPBOARD_FILE: &str = "mergers-clipboard.txt";
+
+/// How copied text reaches the user.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClipboardMethod {
+    /// Try the system clipboard, then OSC 52, then a file
+    #[default]
+    Auto,
+    /// System clipboard only
+    System,
+    /// OSC 52 terminal escape sequence only
+    Osc52,
+    /// Write to a file only
+    File,
+}
+
+impl FromStr for ClipboardMethod {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "system" => Ok(Self::System),
+            "osc52" => Ok(Self::Osc52),
+            "file" => Ok(Self::File),
+            other => anyhow::bail!(
+                "Invalid clipboard method '{}' (expected auto, system, osc52 or file)",
+                other
+            ),
+        }
+    }
+}
+
+impl fmt::Display for ClipboardMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Auto => "auto",
+            Self::System => "system",
+            Self::Osc52 => "osc52",
+            Self::File => "file",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Clipboard settings resolved from the configuration.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClipboardOptions {
+    /// Method used to copy text.
+    pub method: ClipboardMethod,
+    /// File used by the file fallback; defaults to a file in the temp directory.
+    pub file: Option<PathBuf>,
+}
+
+impl ClipboardOptions {
+    /// Returns the path used by the file fallback.
+    pub fn file_path(&self) -> PathBuf {
+        self.file
+            .clone()
+            .unwrap_or_else(|| std::env::temp_dir().join(DEFAULT_CLIPBOARD_FILE))
+    }
+}
+
+/// Where copied text ended up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CopyDestination {
+    /// The system clipboard.
+    System,
+    /// The terminal clipboard via OSC 52.
+    Osc52,
+    /// A file at the given path.
+    File(PathBuf),
+}
+
+impl fmt::Display for CopyDestination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::System => write!(f, "clipboard"),
+            Self::Osc52 => write!(f, "terminal clipboard (OSC 52)"),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Copies text using the configured method.
+///
+/// With [`ClipboardMethod::Auto`], OSC 52 is only attempted when stderr is a
+/// terminal; otherwise the text is written to [`ClipboardOptions::file_path`].
+pub fn copy_text(text: &str, options: &ClipboardOptions) -> Result<CopyDestination> {
+    match options.method {
+        ClipboardMethod::System => copy_to_system(text).map(|_| CopyDestination::System),
+        ClipboardMethod::Osc52 => copy_with_osc52(text).map(|_| CopyDestination::Osc52),
+        ClipboardMethod::File => write_to_file(text, &options.file_path()),
+        ClipboardMethod::Auto => {
+            match copy_to_system(text) {
+                Ok(()) => return Ok(CopyDestination::System),
+                Err(e) => tracing::debug!("System clipboard unavailable: {:#}", e),
+            }
+            if std::io::stderr().is_terminal() {
+                match copy_with_osc52(text) {
+                    Ok(()) => return Ok(CopyDestination::Osc52),
+                    Err(e) => tracing::debug!("OSC 52 copy failed: {:#}", e),
+                }
+            }
+            write_to_file(text, &options.file_path())
+        }
+    }
+}
+
+#[cfg(feature = "clipboard")]
+fn copy_to_system(text: &str) -> Result<()> {
+    let mut clipboard = arboard::Clipboard::new().context("Failed to access clipboard")?;
+    clipboard
+        .set_text(text)
+        .context("Failed to copy to clipboard")
+}
+
+/// Without the `clipboard` feature there is no system clipboard, so
+/// [`ClipboardMethod::Auto`] falls through to OSC 52 and the file.
+#[cfg(not(feature = "clipboard"))]
+fn copy_to_system(_text: &str) -> Result<()> {
+    anyhow::bail!("System clipboard support is not compiled in (enable the `clipboard` feature)")
+}
+
+fn copy_with_osc52(text: &str) -> Result<()> {
+    let in_tmux = std::env::var_os("TMUX").is_some();
+    let mut stderr = std::io::stderr();
+    stderr
+        .write_all(osc52_sequence(text, in_tmux).as_bytes())
+        .and_then(|_| stderr.flush())
+        .context("Failed to write OSC 52 sequence")
+}
+
+fn write_to_file(text: &str, path: &std::path::Path) -> Result<CopyDestination> {
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+    }
+    std::fs::write(path, text).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(CopyDestination::File(path.to_path_buf()))
+}
+
+/// Builds the OSC 52 escape sequence that sets the terminal clipboard.
+///
+/// Inside tmux the sequence is wrapped in a DCS passthrough so it reaches the
+/// outer terminal.
+fn osc52_sequence(text: &str, in_tmux: bool) -> String {
+    let sequence = format!("\x1b]52;c;{}\x07", base64_encode(text.as_bytes()));
+    if in_tmux {
+        format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+    } else {
+        sequence
+    }
+}
+
+fn base64_encode(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 0x3f] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// # OSC 52 Sequence
+    ///
+    /// Tests the escape sequence used to set the terminal clipboard.
+    ///
+    /// ## Test Scenario
+    /// - Encodes text with and without tmux passthrough
+    /// - Encodes inputs of every padding length
+    ///
+    /// ## Expected Outcome
+    /// - The payload is standard base64 with padding
+    /// - Inside tmux the sequence is wrapped and its escapes doubled
+    #[test]
+    fn test_osc52_sequence() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+
+        assert_eq!(osc52_sequence("foo", false), "\x1b]52;c;Zm9v\x07");
+        assert_eq!(
+            osc52_sequence("foo", true),
+            "\x1bPtmux;\x1b\x1b]52;c;Zm9v\x07\x1b\\"
+        );
+    }
+
+    /// # File Clipboard Method
+    ///
+    /// Tests copying with the file method and parsing method names.
+    ///
+    /// ## Test Scenario
+    /// - Copies text with a configured file path in a missing directory
+    /// - Parses valid and invalid method names
+    ///
+    /// ## Expected Outcome
+    /// - The file is created with the text and its path is returned
+    /// - Method names parse case-insensitively; unknown names are rejected
+    #[test]
+    fn test_copy_text_to_file() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("out").join("notes.md");
+        let options = ClipboardOptions {
+            method: ClipboardMethod::File,
+            file: Some(path.clone()),
+        };
+
+        let destination = copy_text("release notes", &options).unwrap();
+
+        assert_eq!(destination, CopyDestination::File(path.clone()));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "release notes");
+
+        assert_eq!(
+            "OSC52".parse::<ClipboardMethod>().unwrap(),
+            ClipboardMethod::Osc52
+        );
+        assert!("pbcopy".parse::<ClipboardMethod>().is_err());
+    }
+}
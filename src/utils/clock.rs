@@ -0,0 +1,256 @@
+//! Injectable time and id sources.
+//!
+//! Code that stamps state files, snapshots or release notes reads the time
+//! through a [`Clock`] and names runs through an [`IdGen`] instead of calling
+//! `Utc::now()` directly. Tests and demo runs use [`FixedClock`] and
+//! [`SequentialIdGen`] for reproducible output.
+//!
+//! Setting `MERGERS_PRETEND_DATE` (RFC 3339 or `YYYY-MM-DD`) makes
+//! [`default_clock`] run from that moment instead of the real time, which is
+//! useful for release rehearsals.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chrono::{TimeZone, Utc};
+//! use mergers::utils::{Clock, FixedClock, IdGen, SequentialIdGen};
+//!
+//! let clock = FixedClock::new(Utc.with_ymd_and_hms(2025, 1, 15, 12, 0, 0).unwrap());
+//! assert_eq!(clock.now().to_rfc3339(), "2025-01-15T12:00:00+00:00");
+//!
+//! let ids = SequentialIdGen::new();
+//! assert_eq!(ids.next_id("migration"), "migration-1");
+//! assert_eq!(ids.next_id("migration"), "migration-2");
+//! ```
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Environment variable that sets the start time of [`default_clock`].
+pub const PRETEND_DATE_ENV: &str = "MERGERS_PRETEND_DATE";
+
+/// A source of the current time.
+pub trait Clock: Send + Sync + std::fmt::Debug {
+    /// Returns the current time in UTC.
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Returns the current time in the local time zone.
+    fn local_now(&self) -> DateTime<Local> {
+        self.now().with_timezone(&Local)
+    }
+}
+
+/// The real system time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that always returns the same time.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(DateTime<Utc>);
+
+impl FixedClock {
+    /// Creates a clock stopped at `now`.
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self(now)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+/// A clock that starts at a chosen time and then runs at real speed.
+#[derive(Debug, Clone, Copy)]
+pub struct OffsetClock {
+    offset: chrono::Duration,
+}
+
+impl OffsetClock {
+    /// Creates a clock that reads `start` now.
+    pub fn starting_at(start: DateTime<Utc>) -> Self {
+        Self {
+            offset: start - Utc::now(),
+        }
+    }
+}
+
+impl Clock for OffsetClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now() + self.offset
+    }
+}
+
+/// Parses a pretend date given as RFC 3339 or `YYYY-MM-DD` (midnight UTC).
+pub fn parse_pretend_date(value: &str) -> Result<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Ok(date.with_timezone(&Utc));
+    }
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .with_context(|| format!("Invalid pretend date '{}'", value))?;
+    Ok(date
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight is a valid time")
+        .and_utc())
+}
+
+/// Returns the clock for a normal run.
+///
+/// This is the system clock unless `MERGERS_PRETEND_DATE` is set, in which
+/// case time starts at that date. An invalid value is an error so a rehearsal
+/// never silently runs with the real date.
+pub fn default_clock() -> Result<Arc<dyn Clock>> {
+    match std::env::var(PRETEND_DATE_ENV) {
+        Ok(value) if !value.trim().is_empty() => Ok(Arc::new(OffsetClock::starting_at(
+            parse_pretend_date(&value).with_context(|| format!("Invalid {}", PRETEND_DATE_ENV))?,
+        ))),
+        _ => Ok(Arc::new(SystemClock)),
+    }
+}
+
+/// A source of unique ids for runs and temporary resources.
+pub trait IdGen: Send + Sync {
+    /// Returns a new id starting with `prefix`.
+    fn next_id(&self, prefix: &str) -> String;
+}
+
+/// Ids made from the current Unix time in seconds, read from a [`Clock`].
+///
+/// Ids generated within the same second get a `-2`, `-3`, ... suffix so they
+/// stay unique.
+pub struct TimestampIdGen {
+    clock: Arc<dyn Clock>,
+    /// The last timestamp handed out and how many ids used it.
+    last: Mutex<(i64, u32)>,
+}
+
+impl TimestampIdGen {
+    /// Creates an id generator reading time from `clock`.
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            last: Mutex::new((i64::MIN, 0)),
+        }
+    }
+}
+
+impl IdGen for TimestampIdGen {
+    fn next_id(&self, prefix: &str) -> String {
+        let secs = self.clock.now().timestamp();
+        let mut last = self.last.lock().unwrap_or_else(|e| e.into_inner());
+        if last.0 == secs {
+            last.1 += 1;
+            format!("{}-{}-{}", prefix, secs, last.1)
+        } else {
+            *last = (secs, 1);
+            format!("{}-{}", prefix, secs)
+        }
+    }
+}
+
+/// Ids numbered `1`, `2`, `3`, ... in the order they are requested.
+#[derive(Debug, Default)]
+pub struct SequentialIdGen {
+    next: AtomicU64,
+}
+
+impl SequentialIdGen {
+    /// Creates a generator whose first id ends in `1`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl IdGen for SequentialIdGen {
+    fn next_id(&self, prefix: &str) -> String {
+        format!(
+            "{}-{}",
+            prefix,
+            self.next.fetch_add(1, Ordering::SeqCst) + 1
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    /// # Pretend Date Parsing
+    ///
+    /// Tests the accepted `MERGERS_PRETEND_DATE` formats.
+    ///
+    /// ## Test Scenario
+    /// - Parses a plain date, an RFC 3339 time with an offset and garbage
+    ///
+    /// ## Expected Outcome
+    /// - A plain date is midnight UTC, offsets are converted to UTC
+    /// - Garbage is an error
+    #[test]
+    fn test_parse_pretend_date() {
+        assert_eq!(
+            parse_pretend_date("2025-03-01").unwrap(),
+            Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            parse_pretend_date("2025-03-01T10:30:00+02:00").unwrap(),
+            Utc.with_ymd_and_hms(2025, 3, 1, 8, 30, 0).unwrap()
+        );
+        assert!(parse_pretend_date("next tuesday").is_err());
+    }
+
+    /// # Offset Clock
+    ///
+    /// Tests that an offset clock starts at the chosen time and keeps running.
+    ///
+    /// ## Test Scenario
+    /// - Starts a clock in 2020 and reads it twice
+    ///
+    /// ## Expected Outcome
+    /// - Both readings are within a minute of the start, never going backwards
+    #[test]
+    fn test_offset_clock() {
+        let start = Utc.with_ymd_and_hms(2020, 6, 1, 9, 0, 0).unwrap();
+        let clock = OffsetClock::starting_at(start);
+        let first = clock.now();
+        let second = clock.now();
+        assert!(first >= start && first - start < chrono::Duration::minutes(1));
+        assert!(second >= first);
+    }
+
+    /// # Id Generators
+    ///
+    /// Tests timestamp and sequential id generation.
+    ///
+    /// ## Test Scenario
+    /// - Requests three ids from a timestamp generator on a fixed clock
+    /// - Requests ids with different prefixes from a sequential generator
+    ///
+    /// ## Expected Outcome
+    /// - Timestamp ids are unique within the same second
+    /// - Sequential ids share one counter across prefixes
+    #[test]
+    fn test_id_generators() {
+        let clock = Arc::new(FixedClock::new(
+            Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+        ));
+        let ids = TimestampIdGen::new(clock);
+        assert_eq!(ids.next_id("migration"), "migration-1735689600");
+        assert_eq!(ids.next_id("migration"), "migration-1735689600-2");
+        assert_eq!(ids.next_id("migration"), "migration-1735689600-3");
+
+        let ids = SequentialIdGen::new();
+        assert_eq!(ids.next_id("a"), "a-1");
+        assert_eq!(ids.next_id("b"), "b-2");
+    }
+}
@@ -1,10 +1,41 @@
+//! Dates and durations as users write them.
+//!
+//! Every `--since`, timeout and interval in mergers goes through this module,
+//! so the same spellings work on the command line, in environment variables
+//! and in the config file.
+//!
+//! # Dates
+//!
+//! - Compact relative dates: `1mo`, `2w`, `3d`, `4h`
+//! - Natural expressions: `today`, `yesterday`, `last monday`, `3 weeks ago`
+//! - ISO weeks: `2024-W32` (its Monday) or `2024-W32-5` (its Friday)
+//! - Absolute dates: `2025-07-01`, `2025-07-01T12:00:00Z`
+//!
+//! # Durations
+//!
+//! A sequence of amounts and units such as `90s`, `15m`, `1h30m` or
+//! `2 hours 30 minutes`. Fields that historically took a bare number keep
+//! reading it in their own unit through [`parse_duration_in`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use mergers::utils::date_parser::{TimeUnit, parse_duration, parse_duration_in};
+//!
+//! assert_eq!(parse_duration("1h30m").unwrap(), Duration::from_secs(5400));
+//! assert_eq!(parse_duration_in("5", TimeUnit::Minutes).unwrap(), Duration::from_secs(300));
+//! ```
+
 use anyhow::{Context, Result};
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc, Weekday};
 use regex::Regex;
 use std::sync::OnceLock;
 
-// Static regex pattern compiled once using OnceLock
+// Static regex patterns compiled once using OnceLock
 static RELATIVE_DATE_REGEX: OnceLock<Regex> = OnceLock::new();
+static ISO_WEEK_REGEX: OnceLock<Regex> = OnceLock::new();
+static DURATION_PART_REGEX: OnceLock<Regex> = OnceLock::new();
 
 fn get_relative_date_regex() -> &'static Regex {
     RELATIVE_DATE_REGEX.get_or_init(|| {
@@ -12,13 +43,133 @@ fn get_relative_date_regex() -> &'static Regex {
     })
 }
 
+fn get_iso_week_regex() -> &'static Regex {
+    ISO_WEEK_REGEX.get_or_init(|| {
+        Regex::new(r"^(\d{4})-[Ww](\d{2})(?:-([1-7]))?$").expect("Failed to compile ISO week regex")
+    })
+}
+
+fn get_duration_part_regex() -> &'static Regex {
+    DURATION_PART_REGEX.get_or_init(|| {
+        Regex::new(r"^(\d+)\s*([A-Za-z]+)\s*").expect("Failed to compile duration regex")
+    })
+}
+
+/// Unit a bare number is read in by [`parse_duration_in`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+}
+
+impl TimeUnit {
+    /// Length of one unit in seconds.
+    pub fn seconds(self) -> u64 {
+        match self {
+            TimeUnit::Seconds => 1,
+            TimeUnit::Minutes => 60,
+            TimeUnit::Hours => 60 * 60,
+            TimeUnit::Days => 24 * 60 * 60,
+            TimeUnit::Weeks => 7 * 24 * 60 * 60,
+        }
+    }
+
+    /// Returns `amount` units as a duration.
+    pub fn duration(self, amount: u64) -> Result<std::time::Duration> {
+        amount
+            .checked_mul(self.seconds())
+            .map(std::time::Duration::from_secs)
+            .ok_or_else(|| anyhow::anyhow!("Duration too large: {} {:?}", amount, self))
+    }
+}
+
+/// Length in seconds of a duration unit, accepting short and long spellings.
+///
+/// A month counts as 30 days, matching the compact `mo` date suffix.
+fn unit_seconds(unit: &str) -> Option<u64> {
+    let unit = match unit.to_ascii_lowercase().as_str() {
+        "s" | "sec" | "secs" | "second" | "seconds" => TimeUnit::Seconds,
+        "m" | "min" | "mins" | "minute" | "minutes" => TimeUnit::Minutes,
+        "h" | "hr" | "hrs" | "hour" | "hours" => TimeUnit::Hours,
+        "d" | "day" | "days" => TimeUnit::Days,
+        "w" | "wk" | "wks" | "week" | "weeks" => TimeUnit::Weeks,
+        "mo" | "month" | "months" => return Some(30 * TimeUnit::Days.seconds()),
+        _ => return None,
+    };
+    Some(unit.seconds())
+}
+
+/// Parse a duration like "90s", "15m", "1h30m" or "2 hours 30 minutes".
+///
+/// Every amount needs a unit; use [`parse_duration_in`] where a bare number
+/// is allowed.
+#[must_use = "this returns the parsed duration which should be used"]
+pub fn parse_duration(duration_str: &str) -> Result<std::time::Duration> {
+    if duration_str.is_empty() || duration_str.trim() != duration_str {
+        anyhow::bail!("Invalid duration: '{}'", duration_str);
+    }
+
+    let re = get_duration_part_regex();
+    let mut rest = duration_str;
+    let mut total: u64 = 0;
+    while !rest.is_empty() {
+        let caps = re.captures(rest).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid duration: '{}' (expected e.g. 90s, 15m, 2h, 1d or 1h30m)",
+                duration_str
+            )
+        })?;
+        let amount: u64 = caps[1]
+            .parse()
+            .context("Failed to parse number in duration")?;
+        let seconds = unit_seconds(&caps[2])
+            .ok_or_else(|| anyhow::anyhow!("Unsupported duration unit: {}", &caps[2]))?;
+        total = amount
+            .checked_mul(seconds)
+            .and_then(|part| total.checked_add(part))
+            .ok_or_else(|| anyhow::anyhow!("Duration too large: '{}'", duration_str))?;
+        rest = &rest[caps[0].len()..];
+    }
+    Ok(std::time::Duration::from_secs(total))
+}
+
+/// Parse a duration, reading a bare number in `unit`.
+///
+/// Keeps settings such as `auto_refresh = 5` (minutes) working while also
+/// accepting `"90s"` or `"1h"`.
+#[must_use = "this returns the parsed duration which should be used"]
+pub fn parse_duration_in(duration_str: &str, unit: TimeUnit) -> Result<std::time::Duration> {
+    match duration_str.parse::<u64>() {
+        Ok(amount) => unit.duration(amount),
+        Err(_) => parse_duration(duration_str),
+    }
+}
+
 /// Parse a date string that can be either:
 /// - A relative date like "1mo", "2w", "3d", "4h" (month, week, day, hour)
+/// - A natural expression like "yesterday", "last monday" or "3 weeks ago"
+/// - An ISO week like "2024-W32" (its Monday) or "2024-W32-5"
 /// - A specific date like "2025-07-01" or "2025-07-01T12:00:00Z"
 #[must_use = "this returns the parsed date which should be used"]
 pub fn parse_since_date(since_str: &str) -> Result<DateTime<Utc>> {
+    parse_since_date_at(since_str, Utc::now())
+}
+
+/// [`parse_since_date`] relative to `now`.
+pub fn parse_since_date_at(since_str: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
     // Try to parse as a relative date first
-    if let Ok(date) = parse_relative_date(since_str) {
+    if let Ok(date) = parse_relative_date(since_str, now) {
+        return Ok(date);
+    }
+
+    if let Some(date) = parse_natural_date(since_str, now)? {
+        return Ok(date);
+    }
+
+    if let Some(date) = parse_iso_week(since_str)? {
         return Ok(date);
     }
 
@@ -26,7 +177,7 @@ pub fn parse_since_date(since_str: &str) -> Result<DateTime<Utc>> {
     parse_absolute_date(since_str)
 }
 
-fn parse_relative_date(since_str: &str) -> Result<DateTime<Utc>> {
+fn parse_relative_date(since_str: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
     let re = get_relative_date_regex();
 
     let caps = re
@@ -38,7 +189,6 @@ fn parse_relative_date(since_str: &str) -> Result<DateTime<Utc>> {
         .context("Failed to parse number in relative date")?;
     let unit = &caps[2];
 
-    let now = Utc::now();
     let target_date = match unit {
         "mo" => now - Duration::days(amount * 30), // Approximate month as 30 days
         "w" => now - Duration::weeks(amount),
@@ -50,6 +200,59 @@ fn parse_relative_date(since_str: &str) -> Result<DateTime<Utc>> {
     Ok(target_date)
 }
 
+/// Parses "today", "yesterday", "last <weekday>" and "<duration> ago".
+///
+/// Returns `None` when the string is not a natural expression at all.
+fn parse_natural_date(since_str: &str, now: DateTime<Utc>) -> Result<Option<DateTime<Utc>>> {
+    let lower = since_str.to_ascii_lowercase();
+    let midnight = |date: NaiveDate| date.and_hms_opt(0, 0, 0).unwrap().and_utc();
+    let today = now.date_naive();
+
+    let date = match lower.as_str() {
+        "now" => now,
+        "today" => midnight(today),
+        "yesterday" => midnight(today - Duration::days(1)),
+        _ => {
+            if let Some(day) = lower.strip_prefix("last ") {
+                let weekday: Weekday = day
+                    .parse()
+                    .map_err(|_| anyhow::anyhow!("Unknown weekday in '{}'", since_str))?;
+                let days_back = (today.weekday().num_days_from_monday() + 7
+                    - weekday.num_days_from_monday()
+                    - 1)
+                    % 7
+                    + 1;
+                midnight(today - Duration::days(days_back.into()))
+            } else if let Some(amount) = lower.strip_suffix(" ago") {
+                let duration = Duration::from_std(parse_duration(amount)?)
+                    .with_context(|| format!("Date too far back: '{}'", since_str))?;
+                now.checked_sub_signed(duration)
+                    .ok_or_else(|| anyhow::anyhow!("Date too far back: '{}'", since_str))?
+            } else {
+                return Ok(None);
+            }
+        }
+    };
+    Ok(Some(date))
+}
+
+/// Parses an ISO week date; a week without a day stands for its Monday.
+fn parse_iso_week(since_str: &str) -> Result<Option<DateTime<Utc>>> {
+    let Some(caps) = get_iso_week_regex().captures(since_str) else {
+        return Ok(None);
+    };
+    let year: i32 = caps[1].parse().context("Failed to parse ISO week year")?;
+    let week: u32 = caps[2].parse().context("Failed to parse ISO week")?;
+    // The regex only lets days 1-7 through
+    let day = match caps.get(3) {
+        Some(d) => Weekday::try_from(d.as_str().as_bytes()[0] - b'1')?,
+        None => Weekday::Mon,
+    };
+    let date = NaiveDate::from_isoywd_opt(year, week, day)
+        .ok_or_else(|| anyhow::anyhow!("Invalid ISO week: '{}'", since_str))?;
+    Ok(Some(date.and_hms_opt(0, 0, 0).unwrap().and_utc()))
+}
+
 fn parse_absolute_date(since_str: &str) -> Result<DateTime<Utc>> {
     // Try various date formats
     let formats = [
@@ -409,4 +612,135 @@ mod tests {
         assert!(parse_since_date("1d ").is_err());
         assert!(parse_since_date(" 2025-01-01 ").is_err());
     }
+
+    /// # Parse Natural Date Expressions
+    ///
+    /// Tests natural-language dates relative to a fixed point in time.
+    ///
+    /// ## Test Scenario
+    /// - Parses "today", "yesterday", "last <weekday>" and "N units ago"
+    ///   relative to Wednesday 2024-08-07 15:00 UTC
+    ///
+    /// ## Expected Outcome
+    /// - Day expressions resolve to midnight, "last" never means today
+    /// - "ago" expressions subtract the duration from now
+    #[test]
+    fn test_parse_natural_dates() {
+        let now = Utc.with_ymd_and_hms(2024, 8, 7, 15, 0, 0).unwrap();
+        let day = |d| Utc.with_ymd_and_hms(2024, 8, d, 0, 0, 0).unwrap();
+
+        assert_eq!(parse_since_date_at("today", now).unwrap(), day(7));
+        assert_eq!(parse_since_date_at("Yesterday", now).unwrap(), day(6));
+        assert_eq!(parse_since_date_at("last monday", now).unwrap(), day(5));
+        assert_eq!(parse_since_date_at("last tuesday", now).unwrap(), day(6));
+        assert_eq!(
+            parse_since_date_at("last wednesday", now).unwrap(),
+            day(7) - Duration::weeks(1)
+        );
+        assert_eq!(parse_since_date_at("last Fri", now).unwrap(), day(2));
+
+        assert_eq!(
+            parse_since_date_at("3 weeks ago", now).unwrap(),
+            now - Duration::weeks(3)
+        );
+        assert_eq!(
+            parse_since_date_at("1h30m ago", now).unwrap(),
+            now - Duration::minutes(90)
+        );
+        assert_eq!(
+            parse_since_date_at("2 months ago", now).unwrap(),
+            now - Duration::days(60)
+        );
+
+        assert!(parse_since_date_at("last someday", now).is_err());
+        assert!(parse_since_date_at("3 fortnights ago", now).is_err());
+        assert!(parse_since_date_at("ago", now).is_err());
+    }
+
+    /// # Parse ISO Week Dates
+    ///
+    /// Tests ISO 8601 week dates.
+    ///
+    /// ## Test Scenario
+    /// - Parses weeks with and without a day, including a week that starts
+    ///   in the previous year
+    /// - Parses a week that does not exist
+    ///
+    /// ## Expected Outcome
+    /// - A week resolves to its Monday, a week day to that day
+    /// - Week 53 of a 52-week year is rejected
+    #[test]
+    fn test_parse_iso_weeks() {
+        assert_eq!(
+            parse_since_date("2024-W32").unwrap(),
+            Utc.with_ymd_and_hms(2024, 8, 5, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            parse_since_date("2024-w32-5").unwrap(),
+            Utc.with_ymd_and_hms(2024, 8, 9, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            parse_since_date("2026-W01").unwrap(),
+            Utc.with_ymd_and_hms(2025, 12, 29, 0, 0, 0).unwrap()
+        );
+        assert!(parse_since_date("2023-W53").is_err());
+        assert!(parse_since_date("2024-W32-8").is_err());
+    }
+
+    /// # Parse Durations
+    ///
+    /// Tests the duration parser shared by timeouts and intervals.
+    ///
+    /// ## Test Scenario
+    /// - Parses single and compound durations in short and long spellings
+    /// - Parses bare numbers with and without a default unit
+    /// - Parses malformed durations
+    ///
+    /// ## Expected Outcome
+    /// - Units add up, bare numbers are read in the given unit
+    /// - A bare number without a unit and malformed input are rejected
+    #[test]
+    fn test_parse_durations() {
+        use std::time::Duration as StdDuration;
+
+        assert_eq!(parse_duration("90s").unwrap(), StdDuration::from_secs(90));
+        assert_eq!(parse_duration("15m").unwrap(), StdDuration::from_secs(900));
+        assert_eq!(
+            parse_duration("1h30m").unwrap(),
+            StdDuration::from_secs(5400)
+        );
+        assert_eq!(
+            parse_duration("2 hours 30 minutes").unwrap(),
+            StdDuration::from_secs(9000)
+        );
+        assert_eq!(
+            parse_duration("1w").unwrap(),
+            StdDuration::from_secs(604_800)
+        );
+        assert_eq!(
+            parse_duration("1D").unwrap(),
+            StdDuration::from_secs(86_400)
+        );
+
+        assert_eq!(
+            parse_duration_in("5", TimeUnit::Minutes).unwrap(),
+            StdDuration::from_secs(300)
+        );
+        assert_eq!(
+            parse_duration_in("7", TimeUnit::Days).unwrap(),
+            StdDuration::from_secs(604_800)
+        );
+        assert_eq!(
+            parse_duration_in("45s", TimeUnit::Minutes).unwrap(),
+            StdDuration::from_secs(45)
+        );
+
+        assert!(parse_duration("5").is_err());
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration(" 5m").is_err());
+        assert!(parse_duration("5y").is_err());
+        assert!(parse_duration("m5").is_err());
+        assert!(parse_duration("1.5h").is_err());
+        assert!(parse_duration_in("99999999999999999", TimeUnit::Weeks).is_err());
+    }
 }
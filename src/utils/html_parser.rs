@@ -8,6 +8,10 @@ use scraper::{ElementRef, Html, Node};
 /// rendering in a ratatui `Paragraph` widget. Supports common HTML elements
 /// like paragraphs, headers, bold, italic, links, and inline styles.
 ///
+/// Lists keep their bullets and numbering, simple tables are rendered as
+/// aligned columns, and images are shown as `[image N: alt]` with their
+/// sources listed under an `Images:` footer.
+///
 /// # Arguments
 ///
 /// * `html` - HTML string to parse and convert
@@ -38,6 +42,12 @@ struct HtmlConverter {
     lines: Vec<Line<'static>>,
     current_spans: Vec<Span<'static>>,
     style_stack: Vec<Style>,
+    /// Open lists, innermost last; `Some` holds the next number of an `<ol>`.
+    lists: Vec<Option<usize>>,
+    /// Bullet or number of a list item whose text has not started yet.
+    pending_marker: Option<String>,
+    /// Sources of the images seen so far, numbered from 1.
+    images: Vec<String>,
 }
 
 impl HtmlConverter {
@@ -46,6 +56,9 @@ impl HtmlConverter {
             lines: Vec::new(),
             current_spans: Vec::new(),
             style_stack: vec![Style::default()],
+            lists: Vec::new(),
+            pending_marker: None,
+            images: Vec::new(),
         }
     }
 
@@ -79,10 +92,22 @@ impl HtmlConverter {
             }
 
             if !line_text.trim().is_empty() {
+                self.push_span(Span::styled(line_text.to_string(), style));
+            }
+        }
+    }
+
+    /// Adds a span, starting the line with the list marker or indentation.
+    fn push_span(&mut self, span: Span<'static>) {
+        if self.current_spans.is_empty() {
+            if let Some(marker) = self.pending_marker.take() {
+                self.current_spans.push(Span::raw(marker));
+            } else if !self.lists.is_empty() {
                 self.current_spans
-                    .push(Span::styled(line_text.to_string(), style));
+                    .push(Span::raw("  ".repeat(self.lists.len())));
             }
         }
+        self.current_spans.push(span);
     }
 
     fn finish_line(&mut self) {
@@ -96,6 +121,30 @@ impl HtmlConverter {
     fn process_element(&mut self, element: ElementRef) {
         let tag_name = element.value().name();
 
+        match tag_name {
+            "img" => {
+                let placeholder = self.image_placeholder(element);
+                self.push_span(Span::styled(
+                    placeholder,
+                    Style::default().fg(Color::Magenta),
+                ));
+                return;
+            }
+            "table" => {
+                self.process_table(element);
+                return;
+            }
+            "ul" | "ol" => {
+                self.process_list(element);
+                return;
+            }
+            "li" => {
+                self.process_list_item(element);
+                return;
+            }
+            _ => {}
+        }
+
         // Determine style for this element
         let element_style = match tag_name {
             "b" | "strong" => Style::default().add_modifier(Modifier::BOLD),
@@ -171,6 +220,184 @@ impl HtmlConverter {
         }
     }
 
+    fn process_list(&mut self, list: ElementRef) {
+        self.finish_line();
+        // A nested list right at the start of an item keeps the item's marker
+        if let Some(marker) = self.pending_marker.take() {
+            self.lines.push(Line::from(marker.trim_end().to_string()));
+        }
+
+        let start = list
+            .value()
+            .attr("start")
+            .and_then(|s| s.trim().parse::<usize>().ok())
+            .unwrap_or(1);
+        self.lists
+            .push((list.value().name() == "ol").then_some(start));
+        for child in list.children() {
+            self.process_node(child);
+        }
+        self.lists.pop();
+        self.finish_line();
+    }
+
+    fn process_list_item(&mut self, item: ElementRef) {
+        self.finish_line();
+
+        let depth = self.lists.len().max(1);
+        let indent = "  ".repeat(depth - 1);
+        let marker = match self.lists.last_mut() {
+            Some(Some(next)) => {
+                let number = *next;
+                *next += 1;
+                format!("{}{}. ", indent, number)
+            }
+            _ => {
+                let bullet = match depth {
+                    1 => '•',
+                    2 => '◦',
+                    _ => '▪',
+                };
+                format!("{}{} ", indent, bullet)
+            }
+        };
+        self.pending_marker = Some(marker);
+
+        for child in item.children() {
+            self.process_node(child);
+        }
+        self.finish_line();
+        self.pending_marker = None;
+    }
+
+    /// Records an image and returns its inline placeholder.
+    fn image_placeholder(&mut self, image: ElementRef) -> String {
+        let src = image.value().attr("src").unwrap_or("").trim();
+        self.images.push(if src.is_empty() {
+            "(no source)".to_string()
+        } else if src.starts_with("data:") {
+            "(embedded image)".to_string()
+        } else {
+            src.to_string()
+        });
+
+        let number = self.images.len();
+        match image.value().attr("alt").map(str::trim) {
+            Some(alt) if !alt.is_empty() => format!("[image {}: {}]", number, alt),
+            _ => format!("[image {}]", number),
+        }
+    }
+
+    /// Renders a table as aligned text columns.
+    ///
+    /// Cell content is flattened to plain text; header rows (all `<th>` or
+    /// inside `<thead>`) are bold and underlined by a separator line.
+    fn process_table(&mut self, table: ElementRef) {
+        self.finish_line();
+
+        let mut rows: Vec<(bool, Vec<String>)> = Vec::new();
+        for child in table.children().filter_map(ElementRef::wrap) {
+            match child.value().name() {
+                "tr" => rows.push(self.table_row(child, false)),
+                section @ ("thead" | "tbody" | "tfoot") => {
+                    for row in child.children().filter_map(ElementRef::wrap) {
+                        if row.value().name() == "tr" {
+                            rows.push(self.table_row(row, section == "thead"));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        rows.retain(|(_, cells)| !cells.is_empty());
+
+        let columns = rows.iter().map(|(_, cells)| cells.len()).max().unwrap_or(0);
+        let mut widths = vec![0; columns];
+        for (_, cells) in &rows {
+            for (width, cell) in widths.iter_mut().zip(cells) {
+                *width = (*width).max(Span::raw(cell.as_str()).width());
+            }
+        }
+
+        let header_style = Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD);
+        for (index, (is_header, cells)) in rows.iter().enumerate() {
+            let text = cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, &width)| {
+                    let padding = width - Span::raw(cell.as_str()).width();
+                    format!("{}{}", cell, " ".repeat(padding))
+                })
+                .collect::<Vec<_>>()
+                .join(" │ ");
+            let style = if *is_header {
+                self.current_style().patch(header_style)
+            } else {
+                self.current_style()
+            };
+            self.push_span(Span::styled(text.trim_end().to_string(), style));
+            self.finish_line();
+
+            let next_is_header = rows.get(index + 1).is_some_and(|(header, _)| *header);
+            if *is_header && !next_is_header {
+                let separator = widths
+                    .iter()
+                    .map(|&width| "─".repeat(width))
+                    .collect::<Vec<_>>()
+                    .join("─┼─");
+                self.push_span(Span::styled(
+                    separator,
+                    Style::default().fg(Color::DarkGray),
+                ));
+                self.finish_line();
+            }
+        }
+    }
+
+    /// Collects the cells of a table row, returning whether it is a header.
+    fn table_row(&mut self, row: ElementRef, in_thead: bool) -> (bool, Vec<String>) {
+        let mut all_th = true;
+        let mut cells = Vec::new();
+        for cell in row.children().filter_map(ElementRef::wrap) {
+            let name = cell.value().name();
+            if name != "td" && name != "th" {
+                continue;
+            }
+            all_th &= name == "th";
+            let mut text = String::new();
+            self.cell_text(*cell, &mut text);
+            cells.push(text.split_whitespace().collect::<Vec<_>>().join(" "));
+        }
+        (in_thead || (all_th && !cells.is_empty()), cells)
+    }
+
+    fn cell_text(&mut self, node: NodeRef<Node>, text: &mut String) {
+        for child in node.children() {
+            match child.value() {
+                Node::Text(t) => text.push_str(&t.text),
+                Node::Element(element) => match element.name() {
+                    "img" => {
+                        if let Some(image) = ElementRef::wrap(child) {
+                            let placeholder = self.image_placeholder(image);
+                            text.push(' ');
+                            text.push_str(&placeholder);
+                            text.push(' ');
+                        }
+                    }
+                    "br" | "p" | "div" | "li" => {
+                        text.push(' ');
+                        self.cell_text(child, text);
+                        text.push(' ');
+                    }
+                    _ => self.cell_text(child, text),
+                },
+                _ => {}
+            }
+        }
+    }
+
     fn process_document(&mut self, document: &Html) {
         for node in document.tree.root().children() {
             self.process_node(node);
@@ -194,20 +421,16 @@ impl HtmlConverter {
                             style = style.bg(color);
                         }
                     }
-                    "font-weight" => {
-                        if parts[1] == "bold" || parts[1].parse::<u32>().unwrap_or(400) >= 700 {
-                            style = style.add_modifier(Modifier::BOLD);
-                        }
+                    "font-weight"
+                        if parts[1] == "bold" || parts[1].parse::<u32>().unwrap_or(400) >= 700 =>
+                    {
+                        style = style.add_modifier(Modifier::BOLD);
                     }
-                    "font-style" => {
-                        if parts[1] == "italic" {
-                            style = style.add_modifier(Modifier::ITALIC);
-                        }
+                    "font-style" if parts[1] == "italic" => {
+                        style = style.add_modifier(Modifier::ITALIC);
                     }
-                    "text-decoration" => {
-                        if parts[1].contains("underline") {
-                            style = style.add_modifier(Modifier::UNDERLINED);
-                        }
+                    "text-decoration" if parts[1].contains("underline") => {
+                        style = style.add_modifier(Modifier::UNDERLINED);
                     }
                     _ => {}
                 }
@@ -268,6 +491,23 @@ impl HtmlConverter {
             }
         }
 
+        if !self.images.is_empty() {
+            self.lines.push(Line::default());
+            self.lines.push(Line::styled(
+                "Images:",
+                Style::default().add_modifier(Modifier::BOLD),
+            ));
+            let link_style = Style::default()
+                .fg(Color::Blue)
+                .add_modifier(Modifier::UNDERLINED);
+            for (index, src) in self.images.iter().enumerate() {
+                self.lines.push(Line::from(vec![
+                    Span::raw(format!("[{}] ", index + 1)),
+                    Span::styled(src.clone(), link_style),
+                ]));
+            }
+        }
+
         self.lines
     }
 }
@@ -1069,4 +1309,135 @@ mod tests {
             }
         }
     }
+
+    // ========== Lists, Tables and Images ==========
+
+    fn plain_text(lines: &[Line]) -> String {
+        lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// # List Numbering
+    ///
+    /// Tests rendering of ordered and unordered lists.
+    ///
+    /// ## Test Scenario
+    /// - An ordered list with a `start` attribute and a nested bullet list
+    /// - List items wrapping their text in `<div>`, as Azure DevOps does
+    ///
+    /// ## Expected Outcome
+    /// - Items keep their numbers and bullets, nested items are indented
+    /// - Block children stay on the marker's line
+    #[test]
+    fn test_list_numbering() {
+        let html = "<ol start=\"3\"><li><div>Open the app</div></li><li>Log in<ul>\
+                    <li>as admin</li><li>as guest</li></ul></li><li>Crash</li></ol>";
+        assert_eq!(
+            plain_text(&html_to_lines(html)),
+            "3. Open the app\n4. Log in\n  ◦ as admin\n  ◦ as guest\n5. Crash"
+        );
+
+        let html = "<ul><li>One</li><li>Two</li></ul><p>After</p>";
+        assert_eq!(plain_text(&html_to_lines(html)), "• One\n• Two\nAfter");
+    }
+
+    /// # Table Rendering
+    ///
+    /// Tests rendering of simple tables as aligned text.
+    ///
+    /// ## Test Scenario
+    /// - A table with a `<th>` header row and uneven cell widths
+    /// - A table without header and with a missing cell
+    ///
+    /// ## Expected Outcome
+    /// - Columns are padded to the widest cell and separated by `│`
+    /// - The header is bold and followed by a separator line
+    #[test]
+    fn test_table_rendering() {
+        let html = "<table><tr><th>Browser</th><th>Result</th></tr>\
+                    <tr><td>Edge</td><td><b>Fails</b></td></tr>\
+                    <tr><td>Firefox</td><td>Works</td></tr></table>";
+        let lines = html_to_lines(html);
+        assert_eq!(
+            plain_text(&lines),
+            "Browser │ Result\n────────┼───────\nEdge    │ Fails\nFirefox │ Works"
+        );
+        assert!(
+            lines[0].spans[0]
+                .style
+                .add_modifier
+                .contains(Modifier::BOLD)
+        );
+        assert!(
+            !lines[2].spans[0]
+                .style
+                .add_modifier
+                .contains(Modifier::BOLD)
+        );
+
+        let html =
+            "<table><tbody><tr><td>a</td><td>b</td></tr><tr><td>long</td></tr></tbody></table>";
+        assert_eq!(plain_text(&html_to_lines(html)), "a    │ b\nlong");
+    }
+
+    /// # Image Placeholders
+    ///
+    /// Tests rendering of images as numbered placeholders.
+    ///
+    /// ## Test Scenario
+    /// - Images with and without alt text, and an embedded data URI
+    ///
+    /// ## Expected Outcome
+    /// - Images show as `[image N: alt]` inline
+    /// - Their sources are listed under an `Images:` footer
+    #[test]
+    fn test_image_placeholders() {
+        let html = "<p>See <img src=\"https://example.com/a.png\" alt=\"error dialog\"> and \
+                    <img src=\"https://example.com/b.png\"></p><img src=\"data:image/png;base64,AA\">";
+        assert_eq!(
+            plain_text(&html_to_lines(html)),
+            "See [image 1: error dialog] and [image 2]\n[image 3]\n\nImages:\n\
+             [1] https://example.com/a.png\n[2] https://example.com/b.png\n[3] (embedded image)"
+        );
+    }
+
+    /// # Azure DevOps Repro Steps
+    ///
+    /// Snapshot of a representative bug description from Azure DevOps.
+    ///
+    /// ## Test Scenario
+    /// - Repro steps with a numbered list, an environment table, a
+    ///   screenshot and formatted text
+    ///
+    /// ## Expected Outcome
+    /// - The rendered text matches the snapshot
+    #[test]
+    fn test_azure_devops_repro_steps_snapshot() {
+        let html = r#"<div><b>Steps to reproduce:</b></div>
+<ol>
+<li><div>Open <i>Settings</i> &gt; <i>Users</i></div></li>
+<li><div>Click <b>Invite</b> and enter an address</div>
+<ul><li>with a plus sign</li><li>with uppercase letters</li></ul></li>
+<li><div>Press Send</div></li>
+</ol>
+<div><b>Environment:</b></div>
+<table border="1">
+<thead><tr><td>Browser</td><td>Version</td><td>Result</td></tr></thead>
+<tbody>
+<tr><td>Edge</td><td>120</td><td><span style="color:red;">Fails</span></td></tr>
+<tr><td>Firefox</td><td>121</td><td>Works</td></tr>
+</tbody>
+</table>
+<div>Error shown:</div>
+<div><img src="https://dev.azure.com/org/project/_apis/wit/attachments/1234?fileName=image.png" alt="Image"></div>"#;
+        insta::assert_snapshot!(plain_text(&html_to_lines(html)));
+    }
 }
@@ -0,0 +1,362 @@
+use ratatui::prelude::*;
+
+use super::html_to_lines;
+
+/// Convert Markdown content to styled ratatui Lines.
+///
+/// Pull request descriptions come from the API as Markdown. This handles the
+/// subset commonly used in them: headers, bullet, numbered and task lists,
+/// block quotes, fenced code blocks, horizontal rules, and inline bold,
+/// italic, code and links. Content that looks like HTML is handed to
+/// [`html_to_lines`] instead.
+///
+/// # Arguments
+///
+/// * `markdown` - Markdown string to parse and convert
+///
+/// # Returns
+///
+/// A vector of `Line` objects with appropriate styling
+///
+/// # Example
+///
+/// ```
+/// use mergers::utils::markdown_to_lines;
+/// use ratatui::widgets::{Paragraph, Wrap};
+///
+/// let markdown = "## Summary\n- Fixes **login** timeout";
+/// let lines = markdown_to_lines(markdown);
+/// assert_eq!(lines.len(), 2);
+/// let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true });
+/// ```
+#[must_use]
+pub fn markdown_to_lines(markdown: &str) -> Vec<Line<'_>> {
+    if looks_like_html(markdown) {
+        return html_to_lines(markdown);
+    }
+
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    let mut in_code_block = false;
+
+    for raw in markdown.lines() {
+        let trimmed = raw.trim_start();
+
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            lines.push(Line::from(Span::styled(format!("  {}", raw), code_style())));
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            // Collapse runs of blank lines into one separator
+            if lines.last().is_some_and(|line| !line.spans.is_empty()) {
+                lines.push(Line::default());
+            }
+            continue;
+        }
+
+        lines.push(block_line(raw, trimmed));
+    }
+
+    while lines.last().is_some_and(|line| line.spans.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+/// Returns `true` if the content starts with an HTML tag.
+fn looks_like_html(text: &str) -> bool {
+    let text = text.trim_start();
+    text.starts_with('<')
+        && text[1..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '!')
+        && text.contains('>')
+}
+
+fn code_style() -> Style {
+    Style::default().bg(Color::DarkGray).fg(Color::White)
+}
+
+fn link_style() -> Style {
+    Style::default()
+        .fg(Color::Blue)
+        .add_modifier(Modifier::UNDERLINED)
+}
+
+/// Converts one non-blank line outside a code block.
+fn block_line(raw: &str, trimmed: &str) -> Line<'static> {
+    let indent = " ".repeat(raw.len() - trimmed.len());
+
+    // Headers
+    let level = trimmed.chars().take_while(|&c| c == '#').count();
+    if (1..=6).contains(&level) && trimmed[level..].starts_with(' ') {
+        let style = Style::default()
+            .add_modifier(Modifier::BOLD)
+            .fg(Color::Cyan);
+        return Line::from(inline_spans(trimmed[level..].trim(), style));
+    }
+
+    // Horizontal rules
+    let rule: Vec<char> = trimmed.chars().filter(|c| *c != ' ').collect();
+    if rule.len() >= 3 && matches!(rule[0], '-' | '*' | '_') && rule.iter().all(|&c| c == rule[0]) {
+        return Line::from(Span::styled(
+            "─".repeat(20),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    // Block quotes
+    if let Some(rest) = trimmed.strip_prefix('>') {
+        let mut spans = vec![Span::styled(
+            format!("{}│ ", indent),
+            Style::default().fg(Color::DarkGray),
+        )];
+        spans.extend(inline_spans(
+            rest.trim_start(),
+            Style::default().fg(Color::Gray),
+        ));
+        return Line::from(spans);
+    }
+
+    // Bullet and task lists
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(rest) = trimmed.strip_prefix(marker) {
+            let (bullet, rest) = if let Some(rest) = rest.strip_prefix("[ ] ") {
+                ("☐ ", rest)
+            } else if let Some(rest) = rest
+                .strip_prefix("[x] ")
+                .or_else(|| rest.strip_prefix("[X] "))
+            {
+                ("☑ ", rest)
+            } else {
+                ("• ", rest)
+            };
+            let mut spans = vec![Span::raw(format!("{}{}", indent, bullet))];
+            spans.extend(inline_spans(rest, Style::default()));
+            return Line::from(spans);
+        }
+    }
+
+    // Numbered lists
+    let digits = trimmed.chars().take_while(char::is_ascii_digit).count();
+    if digits > 0
+        && let Some(rest) = trimmed[digits..]
+            .strip_prefix(". ")
+            .or_else(|| trimmed[digits..].strip_prefix(") "))
+    {
+        let mut spans = vec![Span::raw(format!("{}{}. ", indent, &trimmed[..digits]))];
+        spans.extend(inline_spans(rest, Style::default()));
+        return Line::from(spans);
+    }
+
+    let mut spans = Vec::new();
+    if !indent.is_empty() {
+        spans.push(Span::raw(indent));
+    }
+    spans.extend(inline_spans(trimmed, Style::default()));
+    Line::from(spans)
+}
+
+/// Converts inline Markdown (emphasis, code, links, images) to spans.
+fn inline_spans(text: &str, base: Style) -> Vec<Span<'static>> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut spans = Vec::new();
+    let mut buffer = String::new();
+    let mut bold = false;
+    let mut italic = false;
+    let mut i = 0;
+
+    let style = |bold: bool, italic: bool| {
+        let mut style = base;
+        if bold {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        if italic {
+            style = style.add_modifier(Modifier::ITALIC);
+        }
+        style
+    };
+    let flush = |buffer: &mut String, spans: &mut Vec<Span<'static>>, style: Style| {
+        if !buffer.is_empty() {
+            spans.push(Span::styled(std::mem::take(buffer), style));
+        }
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '\\' if i + 1 < chars.len() && chars[i + 1].is_ascii_punctuation() => {
+                buffer.push(chars[i + 1]);
+                i += 2;
+            }
+            '`' => {
+                if let Some(end) = find(&chars, i + 1, "`") {
+                    flush(&mut buffer, &mut spans, style(bold, italic));
+                    let code: String = chars[i + 1..end].iter().collect();
+                    spans.push(Span::styled(code, code_style()));
+                    i = end + 1;
+                } else {
+                    buffer.push(c);
+                    i += 1;
+                }
+            }
+            '*' | '_' if chars.get(i + 1) == Some(&c) => {
+                flush(&mut buffer, &mut spans, style(bold, italic));
+                bold = !bold;
+                i += 2;
+            }
+            // A lone underscore is usually part of an identifier, not emphasis
+            '*' => {
+                flush(&mut buffer, &mut spans, style(bold, italic));
+                italic = !italic;
+                i += 1;
+            }
+            '!' | '[' => {
+                let is_image = c == '!';
+                let open = if is_image { i + 1 } else { i };
+                if let Some((label, end)) = parse_link(&chars, open) {
+                    flush(&mut buffer, &mut spans, style(bold, italic));
+                    if is_image {
+                        spans.push(Span::styled(
+                            format!("[image: {}]", label),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                    } else {
+                        spans.push(Span::styled(label, style(bold, italic).patch(link_style())));
+                    }
+                    i = end;
+                } else {
+                    buffer.push(c);
+                    i += 1;
+                }
+            }
+            _ => {
+                buffer.push(c);
+                i += 1;
+            }
+        }
+    }
+    flush(&mut buffer, &mut spans, style(bold, italic));
+    spans
+}
+
+/// Finds `needle` in `chars` at or after `from`.
+fn find(chars: &[char], from: usize, needle: &str) -> Option<usize> {
+    let needle: Vec<char> = needle.chars().collect();
+    (from..chars.len()).find(|&i| chars[i..].starts_with(&needle))
+}
+
+/// Parses `[label](url)` starting at `open`, returning the label and the
+/// index just past the closing parenthesis.
+fn parse_link(chars: &[char], open: usize) -> Option<(String, usize)> {
+    if chars.get(open) != Some(&'[') {
+        return None;
+    }
+    let close = find(chars, open + 1, "](")?;
+    let end = find(chars, close + 2, ")")?;
+    Some((chars[open + 1..close].iter().collect(), end + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    /// # Markdown Blocks
+    ///
+    /// Tests conversion of block-level Markdown.
+    ///
+    /// ## Test Scenario
+    /// - Converts a description with a header, lists, a quote, a code block
+    ///   and repeated blank lines
+    ///
+    /// ## Expected Outcome
+    /// - Markers are replaced by bullets, checkboxes and quote bars
+    /// - Code block lines are kept verbatim with the code style
+    /// - Blank lines collapse to one and trailing blanks are dropped
+    #[test]
+    fn test_markdown_blocks() {
+        let markdown = "# Summary\n\n\n- one\n- [x] done\n  * nested\n2. second\n> quoted\n```\nlet x = *y;\n```\n\n";
+        let lines = markdown_to_lines(markdown);
+        let texts: Vec<String> = lines.iter().map(text).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "Summary",
+                "",
+                "• one",
+                "☑ done",
+                "  • nested",
+                "2. second",
+                "│ quoted",
+                "  let x = *y;",
+            ]
+        );
+        assert!(
+            lines[0].spans[0]
+                .style
+                .add_modifier
+                .contains(Modifier::BOLD)
+        );
+        assert_eq!(lines[7].spans[0].style.bg, Some(Color::DarkGray));
+    }
+
+    /// # Markdown Inline Styles
+    ///
+    /// Tests conversion of inline Markdown.
+    ///
+    /// ## Test Scenario
+    /// - Converts a line with bold, italic, code, a link, an image, an escape
+    ///   and a snake_case identifier
+    ///
+    /// ## Expected Outcome
+    /// - Each element gets its style and links show only their label
+    /// - Escaped and identifier underscores are kept as text
+    #[test]
+    fn test_markdown_inline() {
+        let lines = markdown_to_lines(
+            "**Fix** *now* `code` [docs](http://x) ![shot](a.png) \\*raw\\* my_var",
+        );
+        assert_eq!(lines.len(), 1);
+        assert_eq!(
+            text(&lines[0]),
+            "Fix now code docs [image: shot] *raw* my_var"
+        );
+        let span = |content: &str| {
+            lines[0]
+                .spans
+                .iter()
+                .find(|s| s.content == content)
+                .unwrap()
+                .style
+        };
+        assert!(span("Fix").add_modifier.contains(Modifier::BOLD));
+        assert!(span("now").add_modifier.contains(Modifier::ITALIC));
+        assert_eq!(span("code").bg, Some(Color::DarkGray));
+        assert_eq!(span("docs").fg, Some(Color::Blue));
+    }
+
+    /// # HTML Fallback
+    ///
+    /// Tests that HTML descriptions are still rendered as HTML.
+    ///
+    /// ## Test Scenario
+    /// - Converts a description starting with a paragraph tag
+    ///
+    /// ## Expected Outcome
+    /// - The tags are not shown and bold text keeps its style
+    #[test]
+    fn test_html_fallback() {
+        let lines = markdown_to_lines("<p>Hello <b>world</b></p>");
+        assert_eq!(lines.len(), 1);
+        assert_eq!(text(&lines[0]), "Hello world");
+    }
+}
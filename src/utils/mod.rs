@@ -1,8 +1,23 @@
+pub mod clipboard;
+pub mod clock;
 pub mod date_parser;
+#[cfg(feature = "tui")]
 pub mod html_parser;
+#[cfg(feature = "tui")]
+pub mod markdown;
+pub mod paths;
 pub mod text;
 pub mod throttle;
 
-pub use date_parser::parse_since_date;
+pub use clipboard::{ClipboardMethod, ClipboardOptions, CopyDestination, copy_text};
+pub use clock::{
+    Clock, FixedClock, IdGen, OffsetClock, SequentialIdGen, SystemClock, TimestampIdGen,
+    default_clock,
+};
+pub use date_parser::{TimeUnit, parse_duration, parse_duration_in, parse_since_date};
+#[cfg(feature = "tui")]
 pub use html_parser::html_to_lines;
+#[cfg(feature = "tui")]
+pub use markdown::markdown_to_lines;
+pub use paths::{is_wsl, native_path, path_key, windows_to_wsl, wsl_to_windows};
 pub use text::truncate_str;
@@ -0,0 +1,197 @@
+//! Path handling shared by Windows, WSL and Unix environments.
+//!
+//! A repository on a Windows drive can be reached as `C:\src\app` from
+//! PowerShell and as `/mnt/c/src/app` from WSL. [`native_path`] translates
+//! user-supplied paths into the form the current environment can open, and
+//! [`path_key`] reduces either form to the same identity, so state files
+//! and other per-repository data resolve identically in both environments.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::path::Path;
+//! use mergers::utils::{path_key, windows_to_wsl, wsl_to_windows};
+//!
+//! assert_eq!(wsl_to_windows("/mnt/c/src/app").as_deref(), Some("C:\\src\\app"));
+//! assert_eq!(windows_to_wsl("C:\\src\\app").as_deref(), Some("/mnt/c/src/app"));
+//! assert_eq!(
+//!     path_key(Path::new("/mnt/c/Src/App")),
+//!     path_key(Path::new("C:\\src\\app"))
+//! );
+//! ```
+
+use std::path::{Path, PathBuf};
+
+/// Returns `true` when running inside the Windows Subsystem for Linux.
+pub fn is_wsl() -> bool {
+    if !cfg!(target_os = "linux") {
+        return false;
+    }
+    if std::env::var_os("WSL_DISTRO_NAME").is_some() || std::env::var_os("WSL_INTEROP").is_some() {
+        return true;
+    }
+    std::fs::read_to_string("/proc/sys/kernel/osrelease")
+        .is_ok_and(|release| release.to_lowercase().contains("microsoft"))
+}
+
+/// Splits a Windows drive path (`C:\...` or `C:/...`) into its drive
+/// letter and the rest of the path.
+fn split_drive(path: &str) -> Option<(char, &str)> {
+    let mut chars = path.chars();
+    let drive = chars.next().filter(char::is_ascii_alphabetic)?;
+    if chars.next() != Some(':') {
+        return None;
+    }
+    let rest = &path[2..];
+    if rest.is_empty() || rest.starts_with(['\\', '/']) {
+        Some((drive, rest))
+    } else {
+        // `C:foo` is relative to the drive's current directory
+        None
+    }
+}
+
+/// Translates a WSL mount path (`/mnt/c/...`) into a Windows path.
+///
+/// Returns `None` for paths outside a drive mount.
+pub fn wsl_to_windows(path: &str) -> Option<String> {
+    let rest = path.strip_prefix("/mnt/")?;
+    let mut chars = rest.chars();
+    let drive = chars.next().filter(char::is_ascii_alphabetic)?;
+    let rest = &rest[1..];
+    if !(rest.is_empty() || rest.starts_with('/')) {
+        return None;
+    }
+    let rest = rest.trim_start_matches('/').replace('/', "\\");
+    Some(format!("{}:\\{}", drive.to_ascii_uppercase(), rest))
+}
+
+/// Translates a Windows drive path (`C:\...`) into its WSL mount path.
+///
+/// Returns `None` for paths without a drive letter.
+pub fn windows_to_wsl(path: &str) -> Option<String> {
+    let (drive, rest) = split_drive(path)?;
+    let rest = rest.replace('\\', "/");
+    let rest = rest.trim_start_matches('/');
+    let mut translated = format!("/mnt/{}", drive.to_ascii_lowercase());
+    if !rest.is_empty() {
+        translated.push('/');
+        translated.push_str(rest);
+    }
+    Some(translated)
+}
+
+/// Translates a user-supplied path into the form the current environment
+/// can open.
+///
+/// On Windows, WSL mount paths become drive paths; inside WSL, drive paths
+/// become mount paths. Other paths are returned unchanged.
+pub fn native_path(input: &str) -> PathBuf {
+    let translated = if cfg!(windows) {
+        wsl_to_windows(input)
+    } else if is_wsl() {
+        windows_to_wsl(input)
+    } else {
+        None
+    };
+    PathBuf::from(translated.unwrap_or_else(|| input.to_string()))
+}
+
+/// An environment-independent identity for a path.
+///
+/// The path is canonicalized when it exists. Drive paths and WSL mount
+/// paths of the same location map to the same `c:/...` key, lowercased
+/// since Windows drives are case-insensitive. Other paths keep their case.
+pub fn path_key(path: &Path) -> String {
+    let resolved = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let path = resolved.to_string_lossy();
+    // Canonical Windows paths carry the verbatim prefix
+    let path = path
+        .strip_prefix(r"\\?\UNC\")
+        .map(|unc| format!(r"\\{}", unc))
+        .or_else(|| path.strip_prefix(r"\\?\").map(str::to_string))
+        .unwrap_or_else(|| path.to_string());
+
+    let windows = if split_drive(&path).is_some() {
+        Some(path.clone())
+    } else {
+        wsl_to_windows(&path)
+    };
+    let key = match windows {
+        Some(windows) => windows.replace('\\', "/").to_lowercase(),
+        None => path,
+    };
+    match key.trim_end_matches('/') {
+        "" => "/".to_string(),
+        trimmed if trimmed.ends_with(':') => format!("{}/", trimmed),
+        trimmed => trimmed.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// # WSL and Windows Translation
+    ///
+    /// Tests translating between WSL mount paths and Windows drive paths.
+    ///
+    /// ## Test Scenario
+    /// - Translates mount paths, drive paths and paths of neither kind
+    ///
+    /// ## Expected Outcome
+    /// - Drive letters map to `/mnt/<letter>` and back
+    /// - Paths outside a drive, and drive-relative paths, are not translated
+    #[test]
+    fn test_wsl_windows_translation() {
+        assert_eq!(
+            wsl_to_windows("/mnt/c/Users/dev/repo").as_deref(),
+            Some(r"C:\Users\dev\repo")
+        );
+        assert_eq!(wsl_to_windows("/mnt/d").as_deref(), Some(r"D:\"));
+        assert_eq!(wsl_to_windows("/mnt/wsl/shared"), None);
+        assert_eq!(wsl_to_windows("/home/dev/repo"), None);
+
+        assert_eq!(
+            windows_to_wsl(r"C:\Users\dev\repo").as_deref(),
+            Some("/mnt/c/Users/dev/repo")
+        );
+        assert_eq!(
+            windows_to_wsl("D:/work/repo/").as_deref(),
+            Some("/mnt/d/work/repo/")
+        );
+        assert_eq!(windows_to_wsl(r"E:\").as_deref(), Some("/mnt/e"));
+        assert_eq!(windows_to_wsl("C:repo"), None);
+        assert_eq!(windows_to_wsl("/home/dev/repo"), None);
+    }
+
+    /// # Path Keys
+    ///
+    /// Tests that every spelling of a location has the same key.
+    ///
+    /// ## Test Scenario
+    /// - Computes keys for drive paths, mount paths, verbatim paths and Unix paths
+    ///
+    /// ## Expected Outcome
+    /// - Drive, mount and verbatim spellings of one location share a key
+    /// - Drive keys ignore case; Unix paths keep theirs
+    /// - Trailing separators are ignored
+    #[test]
+    fn test_path_key() {
+        let key = path_key(Path::new(r"C:\Users\Dev\Repo"));
+        assert_eq!(key, "c:/users/dev/repo");
+        assert_eq!(path_key(Path::new("/mnt/c/users/dev/repo/")), key);
+        assert_eq!(path_key(Path::new(r"\\?\C:\Users\dev\repo")), key);
+        assert_eq!(path_key(Path::new("c:/USERS/dev/repo")), key);
+        assert_eq!(path_key(Path::new("/mnt/c")), "c:/");
+
+        assert_eq!(
+            path_key(Path::new("/nonexistent/Repo/")),
+            "/nonexistent/Repo"
+        );
+        assert_eq!(
+            path_key(Path::new(r"\\?\UNC\server\share\repo")),
+            r"\\server\share\repo"
+        );
+    }
+}
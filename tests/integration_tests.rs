@@ -29,6 +29,7 @@ fn create_empty_migrate_args() -> Args {
                 project: None,
                 repository: None,
                 pat: None,
+                write_pat: None,
                 dev_branch: None,
                 target_branch: None,
                 local_repo: None,
@@ -37,10 +38,13 @@ fn create_empty_migrate_args() -> Args {
                 max_concurrent_network: None,
                 max_concurrent_processing: None,
                 since: None,
+                since_field: None,
                 skip_confirmation: false,
                 log_level: None,
                 log_file: None,
                 log_format: None,
+                profile_run: false,
+                dump_raw_response: None,
             },
             terminal_states: "Closed,Next Closed,Next Merged".to_string(),
         })),
@@ -585,6 +589,7 @@ fn test_args_cli_precedence() {
                 project: Some("cli-project".to_string()),
                 repository: None, // Should use env var
                 pat: None,        // Should use env var
+                write_pat: None,
                 dev_branch: None,
                 target_branch: None,
                 local_repo: None,
@@ -594,13 +599,32 @@ fn test_args_cli_precedence() {
                 max_concurrent_processing: None,
                 path: None,
                 since: None,
+                since_field: None,
                 skip_confirmation: false,
                 log_level: None,
                 log_file: None,
                 log_format: None,
+                profile_run: false,
+                dump_raw_response: None,
             },
             ni: NonInteractiveArgs::default(),
             work_item_state: None,
+            strict_states: false,
+            check_builds: false,
+            require_green_build: false,
+            release_tag: false,
+            push_release_tag: false,
+            sign_commits: false,
+            squash: false,
+            squash_message: None,
+            mainline_fallback: false,
+            auto_refresh: None,
+            select_file: None,
+            cherry_pick_order: None,
+            author: None,
+            reviewer: None,
+            path_glob: Vec::new(),
+            session: None,
             run_hooks: false,
             subcommand: None,
         })),
@@ -703,10 +727,12 @@ fn test_client_creation_with_resolved_config() {
 use mergers::core::ExitCode;
 use mergers::core::runner::{MergeRunnerConfig, NonInteractiveRunner, RunResult};
 use mergers::core::state::{
-    LockGuard, MergePhase, MergeStateFile, MergeStatus, STATE_DIR_ENV, StateCherryPickItem,
-    StateItemStatus, lock_path_for_repo, path_for_repo,
+    LockGuard, LockKey, LockKind, MergePhase, MergeStateFile, MergeStatus, STATE_DIR_ENV,
+    StateCherryPickItem, StateItemStatus, lock_path, path_for_repo,
 };
 use mergers::models::OutputFormat;
+use mergers::utils::SystemClock;
+use std::sync::Arc;
 
 /// # State File Lifecycle
 ///
@@ -757,6 +783,11 @@ fn test_state_file_lifecycle() {
             pr_title: "PR 1".to_string(),
             status: StateItemStatus::Pending,
             work_item_ids: vec![100],
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
         },
         StateCherryPickItem {
             commit_id: "def456".to_string(),
@@ -764,6 +795,11 @@ fn test_state_file_lifecycle() {
             pr_title: "PR 2".to_string(),
             status: StateItemStatus::Pending,
             work_item_ids: vec![101, 102],
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
         },
     ];
 
@@ -863,6 +899,11 @@ fn test_state_file_cross_mode_compatibility() {
             pr_title: "Feature A".to_string(),
             status: StateItemStatus::Success,
             work_item_ids: vec![1000, 1001],
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
         },
         StateCherryPickItem {
             commit_id: "commit2".to_string(),
@@ -870,6 +911,11 @@ fn test_state_file_cross_mode_compatibility() {
             pr_title: "Feature B".to_string(),
             status: StateItemStatus::Conflict,
             work_item_ids: vec![1002],
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
         },
         StateCherryPickItem {
             commit_id: "commit3".to_string(),
@@ -877,6 +923,11 @@ fn test_state_file_cross_mode_compatibility() {
             pr_title: "Feature C".to_string(),
             status: StateItemStatus::Pending,
             work_item_ids: vec![],
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
         },
     ];
 
@@ -938,13 +989,14 @@ fn test_lock_guard_prevents_concurrent_access() {
     fs::create_dir_all(&repo_dir).unwrap();
 
     unsafe { std::env::set_var(STATE_DIR_ENV, &state_dir) };
+    let key = LockKey::new("https://dev.azure.com/org/project/_git/repo", "main");
 
     // First lock acquisition
-    let guard1 = LockGuard::acquire(&repo_dir).unwrap();
+    let guard1 = LockGuard::acquire(&key, LockKind::Merge).unwrap();
     assert!(guard1.is_some(), "First lock should succeed");
 
     // Second lock acquisition should fail
-    let guard2 = LockGuard::acquire(&repo_dir).unwrap();
+    let guard2 = LockGuard::acquire(&key, LockKind::Merge).unwrap();
     assert!(
         guard2.is_none(),
         "Second lock should fail while first is held"
@@ -954,7 +1006,7 @@ fn test_lock_guard_prevents_concurrent_access() {
     drop(guard1);
 
     // Now we should be able to acquire the lock again
-    let guard3 = LockGuard::acquire(&repo_dir).unwrap();
+    let guard3 = LockGuard::acquire(&key, LockKind::Merge).unwrap();
     assert!(guard3.is_some(), "Lock should succeed after release");
 
     unsafe { std::env::remove_var(STATE_DIR_ENV) };
@@ -978,12 +1030,24 @@ fn test_runner_configuration() {
         project: "project1".to_string(),
         repository: "repo1".to_string(),
         pat: "pat1".to_string(),
+        write_pat: None,
         dev_branch: "dev".to_string(),
         target_branch: "main".to_string(),
         version: "v1.0.0".to_string(),
         tag_prefix: "merged-".to_string(),
         work_item_state: "Done".to_string(),
         select_by_states: Some("Ready".to_string()),
+        select_file: None,
+        strict_states: None,
+        release_gate: None,
+        build_checks: Default::default(),
+        release_tag: Default::default(),
+        sign_commits: false,
+        fan_out: Vec::new(),
+        squash_message: None,
+        mainline_fallback: false,
+        url_templates: Default::default(),
+        release_notes_locale: Default::default(),
         local_repo: None,
         run_hooks: false,
         output_format: OutputFormat::Text,
@@ -992,6 +1056,23 @@ fn test_runner_configuration() {
         max_concurrent_network: 100,
         max_concurrent_processing: 10,
         since: None,
+        since_field: Default::default(),
+        api_version: None,
+        release_candidate: false,
+        status_port: None,
+        serve: None,
+        selection_hard_cap: None,
+        force: false,
+        work_item_comment: None,
+        artifacts_feed: None,
+        work_item_fields: Vec::new(),
+        notifications: None,
+        binary_conflicts: Default::default(),
+        cherry_pick_order: Default::default(),
+        conflict_timeout: None,
+        dry_run: false,
+        reports: Vec::new(),
+        clock: Arc::new(SystemClock),
     };
 
     let mut buffer1 = Vec::new();
@@ -1003,12 +1084,24 @@ fn test_runner_configuration() {
         project: "project2".to_string(),
         repository: "repo2".to_string(),
         pat: "pat2".to_string(),
+        write_pat: None,
         dev_branch: "develop".to_string(),
         target_branch: "release".to_string(),
         version: "v2.0.0".to_string(),
         tag_prefix: "release-".to_string(),
         work_item_state: "Merged".to_string(),
         select_by_states: None,
+        select_file: None,
+        strict_states: None,
+        release_gate: None,
+        build_checks: Default::default(),
+        release_tag: Default::default(),
+        sign_commits: false,
+        fan_out: Vec::new(),
+        squash_message: None,
+        mainline_fallback: false,
+        url_templates: Default::default(),
+        release_notes_locale: Default::default(),
         local_repo: Some(std::path::PathBuf::from("/path/to/repo")),
         run_hooks: true,
         output_format: OutputFormat::Json,
@@ -1017,6 +1110,23 @@ fn test_runner_configuration() {
         max_concurrent_network: 100,
         max_concurrent_processing: 10,
         since: None,
+        since_field: Default::default(),
+        api_version: None,
+        release_candidate: false,
+        status_port: None,
+        serve: None,
+        selection_hard_cap: None,
+        force: false,
+        work_item_comment: None,
+        artifacts_feed: None,
+        work_item_fields: Vec::new(),
+        notifications: None,
+        binary_conflicts: Default::default(),
+        cherry_pick_order: Default::default(),
+        conflict_timeout: None,
+        dry_run: false,
+        reports: Vec::new(),
+        clock: Arc::new(SystemClock),
     };
 
     let mut buffer2 = Vec::new();
@@ -1028,12 +1138,24 @@ fn test_runner_configuration() {
         project: "project3".to_string(),
         repository: "repo3".to_string(),
         pat: "pat3".to_string(),
+        write_pat: None,
         dev_branch: "dev".to_string(),
         target_branch: "next".to_string(),
         version: "v3.0.0".to_string(),
         tag_prefix: "v".to_string(),
         work_item_state: "Complete".to_string(),
         select_by_states: Some("Ready,Approved".to_string()),
+        select_file: None,
+        strict_states: None,
+        release_gate: None,
+        build_checks: Default::default(),
+        release_tag: Default::default(),
+        sign_commits: false,
+        fan_out: Vec::new(),
+        squash_message: None,
+        mainline_fallback: false,
+        url_templates: Default::default(),
+        release_notes_locale: Default::default(),
         local_repo: None,
         run_hooks: false,
         output_format: OutputFormat::Ndjson,
@@ -1042,6 +1164,23 @@ fn test_runner_configuration() {
         max_concurrent_network: 100,
         max_concurrent_processing: 10,
         since: None,
+        since_field: Default::default(),
+        api_version: None,
+        release_candidate: false,
+        status_port: None,
+        serve: None,
+        selection_hard_cap: None,
+        force: false,
+        work_item_comment: None,
+        artifacts_feed: None,
+        work_item_fields: Vec::new(),
+        notifications: None,
+        binary_conflicts: Default::default(),
+        cherry_pick_order: Default::default(),
+        conflict_timeout: None,
+        dry_run: false,
+        reports: Vec::new(),
+        clock: Arc::new(SystemClock),
     };
 
     let mut buffer3 = Vec::new();
@@ -1196,6 +1335,11 @@ fn test_mixed_item_status_state_file() {
             pr_title: "PR 1 - Pending".to_string(),
             status: StateItemStatus::Pending,
             work_item_ids: vec![],
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
         },
         StateCherryPickItem {
             commit_id: "b2".to_string(),
@@ -1203,6 +1347,11 @@ fn test_mixed_item_status_state_file() {
             pr_title: "PR 2 - Success".to_string(),
             status: StateItemStatus::Success,
             work_item_ids: vec![10],
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
         },
         StateCherryPickItem {
             commit_id: "c3".to_string(),
@@ -1210,6 +1359,11 @@ fn test_mixed_item_status_state_file() {
             pr_title: "PR 3 - Conflict".to_string(),
             status: StateItemStatus::Conflict,
             work_item_ids: vec![20, 21],
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
         },
         StateCherryPickItem {
             commit_id: "d4".to_string(),
@@ -1217,6 +1371,11 @@ fn test_mixed_item_status_state_file() {
             pr_title: "PR 4 - Skipped".to_string(),
             status: StateItemStatus::Skipped,
             work_item_ids: vec![],
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
         },
         StateCherryPickItem {
             commit_id: "e5".to_string(),
@@ -1226,6 +1385,11 @@ fn test_mixed_item_status_state_file() {
                 message: "Cherry-pick failed: merge conflict in lib/core.rs".to_string(),
             },
             work_item_ids: vec![30],
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
         },
     ];
 
@@ -1312,6 +1476,11 @@ fn test_abort_state_file_update() {
             pr_title: "PR 1".to_string(),
             status: StateItemStatus::Success,
             work_item_ids: vec![],
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
         },
         StateCherryPickItem {
             commit_id: "b".to_string(),
@@ -1319,6 +1488,11 @@ fn test_abort_state_file_update() {
             pr_title: "PR 2".to_string(),
             status: StateItemStatus::Pending,
             work_item_ids: vec![],
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
         },
     ];
 
@@ -1385,6 +1559,11 @@ fn test_complete_state_file_update() {
             pr_title: "PR 1".to_string(),
             status: StateItemStatus::Success,
             work_item_ids: vec![100],
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
         },
         StateCherryPickItem {
             commit_id: "b".to_string(),
@@ -1392,6 +1571,11 @@ fn test_complete_state_file_update() {
             pr_title: "PR 2".to_string(),
             status: StateItemStatus::Success,
             work_item_ids: vec![101],
+            work_item_titles: Default::default(),
+            picked_commit_id: None,
+            mainline_parent: None,
+            pr_closed_at: None,
+            had_conflict: false,
         },
     ];
 
@@ -1458,24 +1642,25 @@ fn test_lock_is_locked_check() {
     fs::create_dir_all(&repo_dir).unwrap();
 
     unsafe { std::env::set_var(STATE_DIR_ENV, &state_dir) };
+    let key = LockKey::new("https://dev.azure.com/org/project/_git/repo", "main");
 
     // Initially not locked
-    let is_locked = LockGuard::is_locked(&repo_dir).unwrap();
+    let is_locked = LockGuard::is_locked(&key).unwrap();
     assert!(!is_locked, "Should not be locked initially");
 
     // Acquire a lock
-    let guard = LockGuard::acquire(&repo_dir).unwrap();
+    let guard = LockGuard::acquire(&key, LockKind::Merge).unwrap();
     assert!(guard.is_some());
 
     // Now should be locked
-    let is_locked = LockGuard::is_locked(&repo_dir).unwrap();
+    let is_locked = LockGuard::is_locked(&key).unwrap();
     assert!(is_locked, "Should be locked after acquiring");
 
     // Drop the guard
     drop(guard);
 
     // Should not be locked after release
-    let is_locked = LockGuard::is_locked(&repo_dir).unwrap();
+    let is_locked = LockGuard::is_locked(&key).unwrap();
     assert!(!is_locked, "Should not be locked after release");
 
     unsafe { std::env::remove_var(STATE_DIR_ENV) };
@@ -1503,23 +1688,24 @@ fn test_lock_file_contains_pid() {
     fs::create_dir_all(&repo_dir).unwrap();
 
     unsafe { std::env::set_var(STATE_DIR_ENV, &state_dir) };
+    let key = LockKey::new("https://dev.azure.com/org/project/_git/repo", "main");
 
-    let lock_path = lock_path_for_repo(&repo_dir).unwrap();
+    let lock_path = lock_path(&key).unwrap();
 
     // Lock file should not exist initially
     assert!(!lock_path.exists());
 
     // Acquire lock
-    let guard = LockGuard::acquire(&repo_dir).unwrap();
+    let guard = LockGuard::acquire(&key, LockKind::Merge).unwrap();
     assert!(guard.is_some());
 
     // Lock file should now exist
     assert!(lock_path.exists());
 
-    // Read and verify PID
+    // Read and verify PID and kind
     let content = fs::read_to_string(&lock_path).unwrap();
-    let expected_pid = std::process::id().to_string();
-    assert_eq!(content.trim(), expected_pid);
+    let expected = format!("{}\nmerge\n", std::process::id());
+    assert_eq!(content, expected);
 
     // Drop the guard - file should be removed
     drop(guard);
@@ -1549,18 +1735,19 @@ fn test_stale_lock_file_cleanup() {
     fs::create_dir_all(&repo_dir).unwrap();
 
     unsafe { std::env::set_var(STATE_DIR_ENV, &state_dir) };
+    let key = LockKey::new("https://dev.azure.com/org/project/_git/repo", "main");
 
-    let lock_path = lock_path_for_repo(&repo_dir).unwrap();
+    let lock_path = lock_path(&key).unwrap();
 
     // Create a stale lock file with invalid PID (very high number unlikely to exist)
     fs::write(&lock_path, "999999999").unwrap();
 
     // is_locked should return false for stale lock
-    let is_locked = LockGuard::is_locked(&repo_dir).unwrap();
+    let is_locked = LockGuard::is_locked(&key).unwrap();
     assert!(!is_locked, "Stale lock should not be considered locked");
 
     // Should be able to acquire the lock
-    let guard = LockGuard::acquire(&repo_dir).unwrap();
+    let guard = LockGuard::acquire(&key, LockKind::Merge).unwrap();
     assert!(guard.is_some(), "Should acquire lock over stale lock");
 
     drop(guard);
@@ -1892,6 +2079,11 @@ fn test_valid_state_file_passes_validation() {
         pr_title: "PR 1".to_string(),
         status: StateItemStatus::Success,
         work_item_ids: vec![],
+        work_item_titles: Default::default(),
+        picked_commit_id: None,
+        mainline_parent: None,
+        pr_closed_at: None,
+        had_conflict: false,
     }];
     state.phase = MergePhase::ReadyForCompletion;
     state.current_index = 1;